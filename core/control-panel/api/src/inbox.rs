@@ -0,0 +1,38 @@
+use crate::{TimestampRfc3339, UuidDTO};
+use candid::{CandidType, Deserialize, Principal};
+
+/// A notification that a station pushed to the inbox of the user.
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct InboxNotificationDTO {
+    pub station_id: Principal,
+    /// The id of the notification in the station.
+    pub notification_id: UuidDTO,
+    pub notification_type: String,
+    pub title: String,
+    pub message: Option<String>,
+    pub created_at: TimestampRfc3339,
+    pub received_at: TimestampRfc3339,
+    pub read: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListInboxNotificationsInput {
+    pub only_unread: Option<bool>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListInboxNotificationsResponse {
+    pub notifications: Vec<InboxNotificationDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct InboxNotificationRefDTO {
+    pub station_id: Principal,
+    pub notification_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct MarkInboxNotificationsReadInput {
+    pub notifications: Vec<InboxNotificationRefDTO>,
+    pub read: bool,
+}
@@ -28,3 +28,7 @@ pub use canister::*;
 /// Registry DTOs.
 mod registry;
 pub use registry::*;
+
+/// Inbox DTOs.
+mod inbox;
+pub use inbox::*;
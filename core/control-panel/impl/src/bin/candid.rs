@@ -0,0 +1,5 @@
+//! Prints the candid interface of the control panel as generated from its entrypoints.
+
+fn main() {
+    print!("{}", control_panel::controllers::candid_interface());
+}
@@ -0,0 +1,87 @@
+//! Inbox services.
+use crate::core::middlewares::use_canister_call_metric;
+use crate::core::CallContext;
+use crate::services::{InboxService, INBOX_SERVICE};
+use control_panel_api::{
+    InboxNotificationDTO, ListInboxNotificationsInput, ListInboxNotificationsResponse,
+    MarkInboxNotificationsReadInput,
+};
+use ic_cdk_macros::{query, update};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::StationNotificationDeliveryDTO;
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "push_station_notification")]
+async fn push_station_notification(input: StationNotificationDeliveryDTO) -> ApiResult<()> {
+    CONTROLLER.push_station_notification(input).await
+}
+
+#[query(name = "list_inbox_notifications")]
+async fn list_inbox_notifications(
+    input: ListInboxNotificationsInput,
+) -> ApiResult<ListInboxNotificationsResponse> {
+    CONTROLLER.list_inbox_notifications(input).await
+}
+
+#[update(name = "mark_inbox_notifications_read")]
+async fn mark_inbox_notifications_read(input: MarkInboxNotificationsReadInput) -> ApiResult<()> {
+    CONTROLLER.mark_inbox_notifications_read(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: InboxController = InboxController::new(Arc::clone(&INBOX_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct InboxController {
+    inbox_service: Arc<InboxService>,
+}
+
+impl InboxController {
+    fn new(inbox_service: Arc<InboxService>) -> Self {
+        Self { inbox_service }
+    }
+
+    /// Receives the notifications that the stations push to the inboxes of their users.
+    #[with_middleware(tail = use_canister_call_metric("push_station_notification", &result))]
+    async fn push_station_notification(
+        &self,
+        input: StationNotificationDeliveryDTO,
+    ) -> ApiResult<()> {
+        let ctx = CallContext::get();
+        self.inbox_service.push_station_notification(input, &ctx)?;
+
+        Ok(())
+    }
+
+    /// Returns the notifications in the inbox of the caller.
+    async fn list_inbox_notifications(
+        &self,
+        input: ListInboxNotificationsInput,
+    ) -> ApiResult<ListInboxNotificationsResponse> {
+        let ctx = CallContext::get();
+        let notifications = self.inbox_service.list_notifications(input, &ctx)?;
+
+        Ok(ListInboxNotificationsResponse {
+            notifications: notifications
+                .into_iter()
+                .map(InboxNotificationDTO::from)
+                .collect(),
+        })
+    }
+
+    #[with_middleware(tail = use_canister_call_metric("mark_inbox_notifications_read", &result))]
+    async fn mark_inbox_notifications_read(
+        &self,
+        input: MarkInboxNotificationsReadInput,
+    ) -> ApiResult<()> {
+        let ctx = CallContext::get();
+        self.inbox_service.mark_read(input, &ctx)?;
+
+        Ok(())
+    }
+}
@@ -20,24 +20,28 @@ pub use station::*;
 mod registry;
 pub use registry::*;
 
+/// Inbox entrypoints.
+mod inbox;
+pub use inbox::*;
+
 /// HTTP entrypoints.
 mod http;
 pub use http::*;
 
+/// The generated candid interface, must stay the last entrypoint module.
+mod interface;
+pub use interface::candid_interface;
+
 #[cfg(test)]
 mod tests {
-    use control_panel_api::*;
-    use orbit_essentials::api::*;
+    use super::candid_interface;
 
     #[test]
     fn check_candid_interface() {
         use candid_parser::utils::{service_equal, CandidSource};
 
-        candid::export_service!();
-        let new_interface = __export_service();
-
         service_equal(
-            CandidSource::Text(&new_interface),
+            CandidSource::Text(&candid_interface()),
             CandidSource::Text(include_str!("../../../api/spec.did")),
         )
         .unwrap();
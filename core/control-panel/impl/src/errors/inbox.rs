@@ -0,0 +1,46 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for inbox errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum InboxError {
+    /// The inbox notification failed validation.
+    #[error(r#"The inbox notification failed validation due to {info}."#)]
+    ValidationError { info: String },
+    /// Only the stations of the user can push notifications to the inbox of the user.
+    #[error(r#"The station {station_id} is not associated with the user."#)]
+    StationNotAssociated { station_id: String },
+    /// The inbox notification was not found.
+    #[error(
+        r#"The inbox notification {notification_id} of the station {station_id} was not found."#
+    )]
+    NotFound {
+        station_id: String,
+        notification_id: String,
+    },
+}
+
+impl DetailableError for InboxError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            InboxError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            InboxError::StationNotAssociated { station_id } => {
+                details.insert("station_id".to_string(), station_id.to_string());
+                Some(details)
+            }
+            InboxError::NotFound {
+                station_id,
+                notification_id,
+            } => {
+                details.insert("station_id".to_string(), station_id.to_string());
+                details.insert("notification_id".to_string(), notification_id.to_string());
+                Some(details)
+            }
+        }
+    }
+}
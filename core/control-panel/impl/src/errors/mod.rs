@@ -17,3 +17,6 @@ pub use registry::*;
 
 mod artifact;
 pub use artifact::*;
+
+mod inbox;
+pub use inbox::*;
@@ -0,0 +1,21 @@
+use crate::models::InboxNotification;
+use control_panel_api::InboxNotificationDTO;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use uuid::Uuid;
+
+impl From<InboxNotification> for InboxNotificationDTO {
+    fn from(model: InboxNotification) -> Self {
+        InboxNotificationDTO {
+            station_id: model.station_id,
+            notification_id: Uuid::from_bytes(model.notification_id)
+                .hyphenated()
+                .to_string(),
+            notification_type: model.notification_type,
+            title: model.title,
+            message: model.message,
+            created_at: model.created_at,
+            received_at: timestamp_to_rfc3339(&model.received_at),
+            read: model.read,
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 mod artifact;
 
+mod inbox;
+
 mod user;
 pub use user::*;
 
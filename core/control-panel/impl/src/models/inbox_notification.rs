@@ -0,0 +1,123 @@
+use super::UserId;
+use crate::errors::InboxError;
+use candid::Principal;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The notifications are grouped by user first, so that the inbox of a user can be read with a range.
+#[storable]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct InboxNotificationKey {
+    pub user_id: UserId,
+    pub station_id: Principal,
+    pub notification_id: UUID,
+}
+
+/// A notification that a station pushed to the inbox of the user.
+#[storable]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InboxNotification {
+    /// The user that owns the inbox.
+    pub user_id: UserId,
+    /// The station that sent the notification.
+    pub station_id: Principal,
+    /// The id of the notification in the station, used to ignore repeated deliveries.
+    pub notification_id: UUID,
+    /// The type of the notification as reported by the station, e.g. `request-created`.
+    pub notification_type: String,
+    pub title: String,
+    pub message: Option<String>,
+    /// The time at which the station created the notification, as reported by the station.
+    pub created_at: String,
+    /// The time at which the notification was received.
+    pub received_at: Timestamp,
+    pub read: bool,
+}
+
+impl ModelKey<InboxNotificationKey> for InboxNotification {
+    fn key(&self) -> InboxNotificationKey {
+        InboxNotificationKey {
+            user_id: self.user_id,
+            station_id: self.station_id,
+            notification_id: self.notification_id,
+        }
+    }
+}
+
+impl InboxNotification {
+    /// The oldest notifications of a user are dropped once the inbox is full.
+    pub const MAX_NOTIFICATIONS_PER_USER: usize = 500;
+    pub const MAX_NOTIFICATION_TYPE_LEN: usize = 64;
+    pub const MAX_TITLE_LEN: usize = 255;
+    pub const MAX_MESSAGE_LEN: usize = 4096;
+    pub const MAX_CREATED_AT_LEN: usize = 64;
+}
+
+fn validate_len(field: &str, value: &str, max: usize) -> ModelValidatorResult<InboxError> {
+    if value.len() > max {
+        return Err(InboxError::ValidationError {
+            info: format!("{} exceeds the maximum length of {}", field, max),
+        });
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<InboxError> for InboxNotification {
+    fn validate(&self) -> ModelValidatorResult<InboxError> {
+        validate_len(
+            "notification_type",
+            &self.notification_type,
+            Self::MAX_NOTIFICATION_TYPE_LEN,
+        )?;
+        validate_len("title", &self.title, Self::MAX_TITLE_LEN)?;
+        validate_len("created_at", &self.created_at, Self::MAX_CREATED_AT_LEN)?;
+        if let Some(message) = &self.message {
+            validate_len("message", message, Self::MAX_MESSAGE_LEN)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod inbox_notification_model_utils {
+    use super::*;
+    use crate::core::test_utils::random_principal;
+    use uuid::Uuid;
+
+    pub fn mock_inbox_notification(user_id: UserId) -> InboxNotification {
+        InboxNotification {
+            user_id,
+            station_id: random_principal(),
+            notification_id: *Uuid::new_v4().as_bytes(),
+            notification_type: "request-created".to_string(),
+            title: "title".to_string(),
+            message: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            received_at: 0,
+            read: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inbox_notification_model_utils::mock_inbox_notification;
+    use super::*;
+
+    #[test]
+    fn title_length_is_limited() {
+        let mut notification = mock_inbox_notification([1; 16]);
+        notification.title = "a".repeat(InboxNotification::MAX_TITLE_LEN + 1);
+
+        assert!(notification.validate().is_err());
+
+        notification.title = "a".repeat(InboxNotification::MAX_TITLE_LEN);
+
+        assert!(notification.validate().is_ok());
+    }
+}
@@ -12,4 +12,7 @@ pub use registry_entry::*;
 mod artifact;
 pub use artifact::*;
 
+mod inbox_notification;
+pub use inbox_notification::*;
+
 pub mod indexes;
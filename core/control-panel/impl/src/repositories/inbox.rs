@@ -0,0 +1,109 @@
+use crate::{
+    core::{with_memory_manager, Memory, INBOX_NOTIFICATION_MEMORY_ID},
+    models::{InboxNotification, InboxNotificationKey, UserId},
+};
+use candid::Principal;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<InboxNotificationKey, InboxNotification, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(INBOX_NOTIFICATION_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref INBOX_NOTIFICATION_REPOSITORY: Arc<InboxNotificationRepository> =
+        Arc::new(InboxNotificationRepository::default());
+}
+
+/// A repository that stores the notifications that the stations pushed to the inboxes of the users.
+#[derive(Default, Debug)]
+pub struct InboxNotificationRepository {}
+
+impl StableDb<InboxNotificationKey, InboxNotification, VirtualMemory<Memory>>
+    for InboxNotificationRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<InboxNotificationKey, InboxNotification, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<InboxNotificationKey, InboxNotification, VirtualMemory<Memory>>
+    for InboxNotificationRepository
+{
+}
+
+impl InboxNotificationRepository {
+    fn user_range(user_id: UserId) -> std::ops::RangeInclusive<InboxNotificationKey> {
+        InboxNotificationKey {
+            user_id,
+            station_id: Principal::from_slice(&[]),
+            notification_id: [u8::MIN; 16],
+        }..=InboxNotificationKey {
+            user_id,
+            station_id: Principal::from_slice(&[u8::MAX; 29]),
+            notification_id: [u8::MAX; 16],
+        }
+    }
+
+    /// Returns all the notifications in the inbox of the user.
+    pub fn find_by_user(&self, user_id: UserId) -> Vec<InboxNotification> {
+        DB.with(|m| {
+            m.borrow()
+                .range(Self::user_range(user_id))
+                .map(|(_, notification)| notification)
+                .collect()
+        })
+    }
+
+    pub fn count_by_user(&self, user_id: UserId) -> usize {
+        DB.with(|m| m.borrow().range(Self::user_range(user_id)).count())
+    }
+
+    /// Removes all the notifications in the inbox of the user.
+    pub fn remove_by_user(&self, user_id: UserId) {
+        let keys: Vec<InboxNotificationKey> = DB.with(|m| {
+            m.borrow()
+                .range(Self::user_range(user_id))
+                .map(|(key, _)| key)
+                .collect()
+        });
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::inbox_notification_model_utils::mock_inbox_notification;
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn notifications_are_grouped_by_user() {
+        for user_id in [[1; 16], [2; 16], [1; 16], [3; 16]] {
+            let notification = mock_inbox_notification(user_id);
+            INBOX_NOTIFICATION_REPOSITORY.insert(notification.key(), notification);
+        }
+
+        assert_eq!(INBOX_NOTIFICATION_REPOSITORY.count_by_user([1; 16]), 2);
+        assert_eq!(INBOX_NOTIFICATION_REPOSITORY.find_by_user([2; 16]).len(), 1);
+
+        INBOX_NOTIFICATION_REPOSITORY.remove_by_user([1; 16]);
+
+        assert_eq!(INBOX_NOTIFICATION_REPOSITORY.count_by_user([1; 16]), 0);
+        assert_eq!(INBOX_NOTIFICATION_REPOSITORY.count_by_user([3; 16]), 1);
+    }
+}
@@ -9,4 +9,7 @@ pub use user::*;
 mod registry;
 pub use registry::*;
 
+mod inbox;
+pub use inbox::*;
+
 pub mod indexes;
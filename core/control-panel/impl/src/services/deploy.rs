@@ -108,6 +108,8 @@ impl DeployService {
                 quorum: Some(1),
                 fallback_controller: Some(NNS_ROOT_CANISTER_ID),
                 accounts: None,
+                ledger_canisters: None,
+                mock_blockchains: None,
             }))
             .map_err(|err| DeployError::Failed {
                 reason: err.to_string(),
@@ -0,0 +1,280 @@
+use crate::{
+    core::{ic_cdk::next_time, CallContext},
+    errors::InboxError,
+    mappers::HelperMapper,
+    models::{InboxNotification, InboxNotificationKey},
+    repositories::{
+        InboxNotificationRepository, UserRepository, INBOX_NOTIFICATION_REPOSITORY, USER_REPOSITORY,
+    },
+};
+use control_panel_api::{ListInboxNotificationsInput, MarkInboxNotificationsReadInput};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+};
+use station_api::StationNotificationDeliveryDTO;
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref INBOX_SERVICE: Arc<InboxService> = Arc::new(InboxService::new(
+        Arc::clone(&USER_REPOSITORY),
+        Arc::clone(&INBOX_NOTIFICATION_REPOSITORY)
+    ));
+}
+
+#[derive(Default, Debug)]
+pub struct InboxService {
+    user_repository: Arc<UserRepository>,
+    inbox_repository: Arc<InboxNotificationRepository>,
+}
+
+impl InboxService {
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        inbox_repository: Arc<InboxNotificationRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            inbox_repository,
+        }
+    }
+
+    /// Adds the notification pushed by the calling station to the inbox of the user.
+    ///
+    /// Only the stations of the user can push notifications, and notifications that were already
+    /// received are ignored since the stations can deliver the same notification more than once.
+    pub fn push_station_notification(
+        &self,
+        delivery: StationNotificationDeliveryDTO,
+        ctx: &CallContext,
+    ) -> ServiceResult<()> {
+        let station_not_associated = || InboxError::StationNotAssociated {
+            station_id: ctx.caller().to_text(),
+        };
+
+        if delivery.station_id != ctx.caller() {
+            Err(station_not_associated())?
+        }
+
+        let user = self
+            .user_repository
+            .find_by_identity(&delivery.identity)
+            .ok_or_else(station_not_associated)?;
+
+        if !user
+            .stations
+            .iter()
+            .any(|station| station.canister_id == delivery.station_id)
+        {
+            Err(station_not_associated())?
+        }
+
+        let notification = InboxNotification {
+            user_id: user.id,
+            station_id: delivery.station_id,
+            notification_id: *HelperMapper::to_uuid(delivery.notification_id)?.as_bytes(),
+            notification_type: delivery.notification_type,
+            title: delivery.title,
+            message: delivery.message,
+            created_at: delivery.created_at,
+            received_at: next_time(),
+            read: false,
+        };
+
+        if self.inbox_repository.get(&notification.key()).is_some() {
+            return Ok(());
+        }
+
+        notification.validate()?;
+
+        if self.inbox_repository.count_by_user(user.id)
+            >= InboxNotification::MAX_NOTIFICATIONS_PER_USER
+        {
+            if let Some(oldest) = self
+                .inbox_repository
+                .find_by_user(user.id)
+                .into_iter()
+                .min_by_key(|notification| notification.received_at)
+            {
+                self.inbox_repository.remove(&oldest.key());
+            }
+        }
+
+        self.inbox_repository
+            .insert(notification.key(), notification);
+
+        Ok(())
+    }
+
+    /// Returns the notifications in the inbox of the caller, the most recently received first.
+    pub fn list_notifications(
+        &self,
+        input: ListInboxNotificationsInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Vec<InboxNotification>> {
+        let user = ctx.user()?;
+        let only_unread = input.only_unread.unwrap_or(false);
+
+        let mut notifications: Vec<InboxNotification> = self
+            .inbox_repository
+            .find_by_user(user.id)
+            .into_iter()
+            .filter(|notification| !only_unread || !notification.read)
+            .collect();
+
+        notifications.sort_by(|a, b| b.received_at.cmp(&a.received_at));
+
+        Ok(notifications)
+    }
+
+    pub fn mark_read(
+        &self,
+        input: MarkInboxNotificationsReadInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<()> {
+        let user = ctx.user()?;
+
+        let mut notifications = Vec::new();
+        for notification_ref in input.notifications {
+            let key = InboxNotificationKey {
+                user_id: user.id,
+                station_id: notification_ref.station_id,
+                notification_id: *HelperMapper::to_uuid(notification_ref.notification_id.clone())?
+                    .as_bytes(),
+            };
+
+            let notification = self
+                .inbox_repository
+                .get(&key)
+                .ok_or(InboxError::NotFound {
+                    station_id: notification_ref.station_id.to_text(),
+                    notification_id: notification_ref.notification_id,
+                })?;
+
+            notifications.push(notification);
+        }
+
+        for mut notification in notifications {
+            notification.read = input.read;
+
+            self.inbox_repository
+                .insert(notification.key(), notification);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils::random_principal,
+        models::{user_model_utils::mock_user, user_station_model_utils::mock_user_station, User},
+    };
+    use control_panel_api::InboxNotificationRefDTO;
+    use uuid::Uuid;
+
+    fn setup() -> (User, CallContext) {
+        let mut user = mock_user();
+        let station = mock_user_station();
+        user.stations = vec![station.clone()];
+
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        (user.clone(), CallContext::new(station.canister_id))
+    }
+
+    fn mock_delivery(user: &User, station_ctx: &CallContext) -> StationNotificationDeliveryDTO {
+        StationNotificationDeliveryDTO {
+            station_id: station_ctx.caller(),
+            identity: user.identity,
+            notification_id: Uuid::new_v4().hyphenated().to_string(),
+            notification_type: "request-created".to_string(),
+            title: "title".to_string(),
+            message: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn repeated_deliveries_are_received_once() {
+        let (user, station_ctx) = setup();
+        let service = InboxService::default();
+        let delivery = mock_delivery(&user, &station_ctx);
+
+        service
+            .push_station_notification(delivery.clone(), &station_ctx)
+            .unwrap();
+        service
+            .push_station_notification(delivery, &station_ctx)
+            .unwrap();
+
+        let notifications = service
+            .list_notifications(
+                ListInboxNotificationsInput { only_unread: None },
+                &CallContext::new(user.identity),
+            )
+            .unwrap();
+
+        assert_eq!(notifications.len(), 1);
+    }
+
+    #[test]
+    fn only_the_stations_of_the_user_can_push_notifications() {
+        let (user, station_ctx) = setup();
+        let service = InboxService::default();
+        let other_station_ctx = CallContext::new(random_principal());
+
+        let mut delivery = mock_delivery(&user, &station_ctx);
+        delivery.station_id = other_station_ctx.caller();
+
+        assert!(service
+            .push_station_notification(delivery.clone(), &other_station_ctx)
+            .is_err());
+
+        // the station must push in its own name
+        assert!(service
+            .push_station_notification(mock_delivery(&user, &station_ctx), &other_station_ctx)
+            .is_err());
+
+        assert_eq!(INBOX_NOTIFICATION_REPOSITORY.count_by_user(user.id), 0);
+    }
+
+    #[test]
+    fn mark_notifications_read() {
+        let (user, station_ctx) = setup();
+        let service = InboxService::default();
+        let user_ctx = CallContext::new(user.identity);
+        let delivery = mock_delivery(&user, &station_ctx);
+
+        service
+            .push_station_notification(delivery.clone(), &station_ctx)
+            .unwrap();
+        service
+            .mark_read(
+                MarkInboxNotificationsReadInput {
+                    notifications: vec![InboxNotificationRefDTO {
+                        station_id: delivery.station_id,
+                        notification_id: delivery.notification_id,
+                    }],
+                    read: true,
+                },
+                &user_ctx,
+            )
+            .unwrap();
+
+        let unread = service
+            .list_notifications(
+                ListInboxNotificationsInput {
+                    only_unread: Some(true),
+                },
+                &user_ctx,
+            )
+            .unwrap();
+
+        assert!(unread.is_empty());
+    }
+}
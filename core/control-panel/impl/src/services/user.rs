@@ -3,7 +3,7 @@ use crate::{
     errors::UserError,
     mappers::{SubscribedUser, UserMapper},
     models::{CanDeployStation, User, UserId, UserKey, UserSubscriptionStatus},
-    repositories::{UserRepository, USER_REPOSITORY},
+    repositories::{UserRepository, INBOX_NOTIFICATION_REPOSITORY, USER_REPOSITORY},
     services::canister::FUND_MANAGER,
 };
 use candid::Principal;
@@ -117,6 +117,7 @@ impl UserService {
         self.assert_user_access(&user, ctx)?;
 
         self.user_repository.remove(&user.to_key());
+        INBOX_NOTIFICATION_REPOSITORY.remove_by_user(user.id);
 
         Ok(user)
     }
@@ -1,5 +1,6 @@
 use crate::{
-    AllowDTO, MetadataDTO, PaginationInput, RequestPolicyRuleDTO, RequestPolicyRuleInput, UuidDTO,
+    AllowDTO, MetadataDTO, NetworkDTO, NetworkIdDTO, PaginationInput, RequestPolicyRuleDTO,
+    RequestPolicyRuleInput, TimestampRfc3339, UuidDTO,
 };
 use candid::{CandidType, Deserialize};
 
@@ -24,6 +25,54 @@ pub struct AccountDTO {
     pub transfer_request_policy: Option<RequestPolicyRuleDTO>,
     pub configs_request_policy: Option<RequestPolicyRuleDTO>,
     pub last_modification_timestamp: String,
+    pub version: u64,
+    pub retired_addresses: Vec<RetiredAccountAddressDTO>,
+    pub network: NetworkDTO,
+    pub is_testnet: bool,
+    pub review: Option<AccountReviewDTO>,
+    pub setup_state: AccountSetupStateDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AccountSetupStateDTO {
+    Ready,
+    PendingAddressGeneration {
+        attempts: u32,
+        last_error: String,
+        next_attempt_at: TimestampRfc3339,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountReviewDTO {
+    pub reason: String,
+    pub flagged_by: UuidDTO,
+    pub flagged_at: TimestampRfc3339,
+    pub expires_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountReviewInput {
+    pub reason: String,
+    pub expires_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetAccountReviewInput {
+    pub account_id: UuidDTO,
+    pub review: Option<AccountReviewInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetAccountReviewResponse {
+    pub account: AccountDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RetiredAccountAddressDTO {
+    pub address: String,
+    pub retired_at: TimestampRfc3339,
+    pub grace_period_ends_at: TimestampRfc3339,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -35,6 +84,7 @@ pub struct EditAccountOperationInput {
     pub transfer_permission: Option<AllowDTO>,
     pub configs_request_policy: Option<RequestPolicyRuleInput>,
     pub transfer_request_policy: Option<RequestPolicyRuleInput>,
+    pub expected_version: Option<u64>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -42,6 +92,37 @@ pub struct EditAccountOperationDTO {
     pub input: EditAccountOperationInput,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RotateAccountAddressOperationInput {
+    pub account_id: UuidDTO,
+    pub grace_period_secs: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RotateAccountAddressOperationDTO {
+    pub input: RotateAccountAddressOperationInput,
+    pub new_address: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AccountOwnerDTO {
+    User(UuidDTO),
+    UserGroup(UuidDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferAccountOwnershipOperationInput {
+    pub account_id: UuidDTO,
+    pub from: AccountOwnerDTO,
+    pub to: AccountOwnerDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferAccountOwnershipOperationDTO {
+    pub input: TransferAccountOwnershipOperationInput,
+    pub acknowledged_by: Option<UuidDTO>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct AddAccountOperationInput {
     pub name: String,
@@ -53,6 +134,7 @@ pub struct AddAccountOperationInput {
     pub transfer_permission: AllowDTO,
     pub configs_request_policy: Option<RequestPolicyRuleDTO>,
     pub transfer_request_policy: Option<RequestPolicyRuleDTO>,
+    pub network: Option<NetworkIdDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -81,6 +163,7 @@ pub struct FetchAccountBalancesInput {
 pub struct AccountBalanceDTO {
     pub account_id: String,
     pub balance: candid::Nat,
+    pub formatted_balance: String,
     pub decimals: u32,
     pub last_update_timestamp: String,
 }
@@ -88,6 +171,7 @@ pub struct AccountBalanceDTO {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct AccountBalanceInfoDTO {
     pub balance: candid::Nat,
+    pub formatted_balance: String,
     pub decimals: u32,
     pub last_update_timestamp: String,
 }
@@ -97,6 +181,162 @@ pub struct FetchAccountBalancesResponse {
     pub balances: Vec<AccountBalanceDTO>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountTransactionsInput {
+    pub account_id: UuidDTO,
+    pub cursor: Option<String>,
+    pub limit: Option<u16>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AccountTransactionDirectionDTO {
+    Incoming,
+    Outgoing,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountTransactionDTO {
+    pub id: String,
+    pub direction: AccountTransactionDirectionDTO,
+    pub from_address: Option<String>,
+    pub to_address: Option<String>,
+    pub amount: candid::Nat,
+    pub fee: Option<candid::Nat>,
+    pub memo: Option<String>,
+    pub created_at: Option<TimestampRfc3339>,
+    pub timestamp: Option<TimestampRfc3339>,
+    pub transfer_id: Option<UuidDTO>,
+    pub from_label: Option<String>,
+    pub to_label: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountTransactionsResponse {
+    pub transactions: Vec<AccountTransactionDTO>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GeneratePaymentRequestInput {
+    pub account_id: UuidDTO,
+    pub amount: Option<candid::Nat>,
+    pub memo: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GeneratePaymentRequestResponse {
+    pub uri: String,
+    pub address: String,
+    pub blockchain: String,
+    pub standard: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub amount: Option<candid::Nat>,
+    pub memo: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceHistoryGranularityDTO {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetBalanceHistoryInput {
+    pub account_id: UuidDTO,
+    pub from_dt: Option<TimestampRfc3339>,
+    pub to_dt: Option<TimestampRfc3339>,
+    pub granularity: Option<BalanceHistoryGranularityDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BalanceSnapshotDTO {
+    pub timestamp: TimestampRfc3339,
+    pub balance: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetBalanceHistoryResponse {
+    pub account_id: UuidDTO,
+    pub symbol: String,
+    pub decimals: u32,
+    pub granularity: BalanceHistoryGranularityDTO,
+    pub snapshots: Vec<BalanceSnapshotDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetPortfolioSummaryInput {
+    pub currency: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FiatPriceDTO {
+    pub rate: u64,
+    pub decimals: u32,
+    pub updated_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PortfolioAssetDTO {
+    pub blockchain: String,
+    pub network: NetworkDTO,
+    pub is_testnet: bool,
+    pub standard: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub display_decimals: u32,
+    pub accounts: u64,
+    pub balance: candid::Nat,
+    pub formatted_balance: String,
+    pub fiat_price: Option<FiatPriceDTO>,
+    pub fiat_value: Option<candid::Nat>,
+    pub formatted_fiat_value: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PortfolioBlockchainDTO {
+    pub blockchain: String,
+    pub accounts: u64,
+    pub fiat_value: Option<candid::Nat>,
+    pub formatted_fiat_value: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetPortfolioSummaryResponse {
+    pub currency: Option<String>,
+    pub fiat_decimals: u32,
+    pub total_fiat_value: Option<candid::Nat>,
+    pub formatted_total_fiat_value: Option<String>,
+    pub is_complete: bool,
+    pub assets: Vec<PortfolioAssetDTO>,
+    pub blockchains: Vec<PortfolioBlockchainDTO>,
+    pub accounts_without_balance: u64,
+    pub oldest_balance_at: Option<TimestampRfc3339>,
+}
+
+pub type ValidateAccountCreationInput = AddAccountOperationInput;
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ValidateAccountCreationResponse {
+    pub errors: Vec<ValidationFieldErrorDTO>,
+    pub transfer_policies: Vec<AccountCreationPolicyDTO>,
+    pub configs_policies: Vec<AccountCreationPolicyDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ValidationFieldErrorDTO {
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountCreationPolicyDTO {
+    pub policy_id: Option<UuidDTO>,
+    pub rule: RequestPolicyRuleDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ListAccountsInput {
     pub search_term: Option<String>,
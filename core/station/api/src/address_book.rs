@@ -1,5 +1,5 @@
-use crate::{ChangeMetadataDTO, MetadataDTO, PaginationInput, UuidDTO};
-use candid::{CandidType, Deserialize};
+use crate::{ChangeMetadataDTO, MetadataDTO, PaginationInput, TimestampRfc3339, UuidDTO};
+use candid::{CandidType, Deserialize, Principal};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct AddressBookEntryDTO {
@@ -10,6 +10,14 @@ pub struct AddressBookEntryDTO {
     pub labels: Vec<String>,
     pub metadata: Vec<MetadataDTO>,
     pub last_modification_timestamp: String,
+    pub origin: Option<AddressBookEntryOriginDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AddressBookEntryOriginDTO {
+    pub source_id: Principal,
+    pub source_entry_id: UuidDTO,
+    pub synced_at: TimestampRfc3339,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -74,6 +82,11 @@ pub struct ListAddressBookEntriesInputDTO {
     pub addresses: Option<Vec<String>>,
     pub blockchain: Option<String>,
     pub labels: Option<Vec<String>>,
+    /// Only the entries whose owner or address starts with the search term are returned.
+    #[serde(default)]
+    pub search_term: Option<String>,
+    #[serde(default)]
+    pub address_owners: Option<Vec<String>>,
     pub paginate: Option<PaginationInput>,
 }
 
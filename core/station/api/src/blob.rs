@@ -0,0 +1,44 @@
+use crate::{Sha256HashDTO, TimestampRfc3339, UuidDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BlobDTO {
+    pub id: UuidDTO,
+    pub content_type: String,
+    pub size_bytes: u64,
+    pub sha256: Sha256HashDTO,
+    /// The path of the blob in the HTTP gateway of the station (e.g. `/blobs/<id>`).
+    pub http_path: String,
+    pub uploaded_by: UuidDTO,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UploadBlobInput {
+    pub content_type: String,
+    #[serde(with = "serde_bytes")]
+    pub content: Vec<u8>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UploadBlobResponse {
+    pub blob: BlobDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveBlobInput {
+    pub blob_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveBlobResponse {
+    pub blob: BlobDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListBlobsResponse {
+    pub blobs: Vec<BlobDTO>,
+    pub total_size_bytes: u64,
+    pub max_size_bytes: u64,
+    pub max_total_size_bytes: u64,
+}
@@ -1,4 +1,4 @@
-use crate::MetadataDTO;
+use crate::{MetadataDTO, RequestOperationTypeDTO, UuidDTO};
 use candid::{CandidType, Deserialize};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -12,10 +12,16 @@ pub struct AssetDTO {
     pub standard: String,
     /// The asset name (e.g. `Internet Computer`, `Bitcoin`, `Ethereum`, etc.)
     pub name: String,
+    /// The number of decimals of the minor unit of the asset (e.g. `8` for `ICP`).
+    pub decimals: u32,
+    /// The number of decimals that the amounts of the asset are displayed with (e.g. `4` for `ICP`).
+    pub display_decimals: u32,
     /// The asset metadata (e.g. `{"logo": "https://example.com/logo.png"}`),
     /// also, in the case of non-native assets, it can contain other required
     /// information (e.g. `{"address": "0x1234"}`).
     pub metadata: Vec<MetadataDTO>,
+    /// The blob that holds the logo of the asset, served by the station at `/blobs/<id>`.
+    pub logo_id: Option<UuidDTO>,
 }
 
 /// The capabilities of the canister.
@@ -25,8 +31,14 @@ pub struct CapabilitiesDTO {
     pub name: String,
     /// The current version of the canister.
     pub version: String,
+    /// The blob that holds the logo of the station, served by the station at `/blobs/<id>`.
+    pub logo_id: Option<UuidDTO>,
     /// The list of assets that are supported by the canister (e.g. `ICP`, `BTC`, `ETH`, etc.)
     pub supported_assets: Vec<AssetDTO>,
+    /// The operation types that can still be requested, but that clients should stop offering.
+    pub deprecated_operations: Vec<RequestOperationTypeDTO>,
+    /// The operation types that cannot be requested on the station.
+    pub disabled_operations: Vec<RequestOperationTypeDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -5,6 +5,12 @@ pub type TimestampRfc3339 = String;
 pub type UuidDTO = String;
 pub type Sha256HashDTO = String;
 
+/// The current version of the station API.
+///
+/// It's increased whenever a variant is added to one of the enums returned by the station, so that clients
+/// pinned to an older version can ask for responses that they are able to decode.
+pub const STATION_API_VERSION: u32 = 8;
+
 /// Generic error type used for calls.
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct ApiErrorDTO {
@@ -7,7 +7,7 @@ use candid::{CandidType, Deserialize, Nat, Principal};
 pub type ExternalCanisterPermissionsInput = ExternalCanisterPermissionsDTO;
 
 // Taken from https://internetcomputer.org/docs/current/references/ic-interface-spec/#ic-create_canister
-#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct DefiniteCanisterSettingsInput {
     /// Controllers of the canister.
     pub controllers: Option<Vec<Principal>>,
@@ -19,6 +19,78 @@ pub struct DefiniteCanisterSettingsInput {
     pub freezing_threshold: Option<Nat>,
     /// Reserved cycles limit.
     pub reserved_cycles_limit: Option<Nat>,
+    /// Who can read the logs of the canister.
+    #[serde(default)]
+    pub log_visibility: Option<LogVisibilityDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum LogVisibilityDTO {
+    #[serde(rename = "controllers")]
+    Controllers,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "allowed_viewers")]
+    AllowedViewers(Vec<Principal>),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ControllersChangeDTO {
+    pub added: Vec<Principal>,
+    pub removed: Vec<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct NatSettingChangeDTO {
+    pub current: Nat,
+    pub proposed: Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct LogVisibilityChangeDTO {
+    pub current: Option<LogVisibilityDTO>,
+    pub proposed: LogVisibilityDTO,
+}
+
+/// A change of a native setting, compared to the settings of the canister when the request was created.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum NativeSettingsChangeDTO {
+    Controllers(ControllersChangeDTO),
+    ComputeAllocation(NatSettingChangeDTO),
+    MemoryAllocation(NatSettingChangeDTO),
+    FreezingThreshold(NatSettingChangeDTO),
+    ReservedCyclesLimit(NatSettingChangeDTO),
+    LogVisibility(LogVisibilityChangeDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct NativeCanisterSettingsDTO {
+    pub controllers: Vec<Principal>,
+    pub compute_allocation: Nat,
+    pub memory_allocation: Nat,
+    pub freezing_threshold: Nat,
+    pub reserved_cycles_limit: Nat,
+    pub log_visibility: Option<LogVisibilityDTO>,
+}
+
+/// The state of the canister when the request was created.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CanisterCurrentStateDTO {
+    /// Not set if no module is installed.
+    pub module_hash: Option<Sha256HashDTO>,
+    /// The settings are kept by the module change, they are not set if the station is not a
+    /// controller of the canister.
+    pub settings: Option<NativeCanisterSettingsDTO>,
+}
+
+/// The facts of a module change that are computed when the request is created.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CanisterUpgradeDiffDTO {
+    /// Not set if the state of the canister could not be read.
+    pub current_state: Option<CanisterCurrentStateDTO>,
+    /// The size of the proposed module in bytes, not set if the module was uploaded in chunks.
+    pub module_size: Option<u64>,
+    pub arg_empty: bool,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -45,6 +117,8 @@ pub struct CreateExternalCanisterOperationInput {
     pub labels: Option<Vec<String>>,
     pub permissions: ExternalCanisterPermissionsInput,
     pub request_policies: ExternalCanisterRequestPoliciesInput,
+    #[serde(default)]
+    pub repository_url: Option<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -61,6 +135,16 @@ pub struct ChangeExternalCanisterOperationInput {
     pub module: Vec<u8>,
     #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
     pub arg: Option<Vec<u8>>,
+    #[serde(default)]
+    pub module_chunks: Option<ChunkedWasmModuleDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ChunkedWasmModuleDTO {
+    pub store_canister: Principal,
+    pub chunk_hashes: Vec<Vec<u8>>,
+    #[serde(with = "serde_bytes")]
+    pub wasm_module_hash: Vec<u8>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -69,6 +153,7 @@ pub struct ChangeExternalCanisterOperationDTO {
     pub mode: CanisterInstallMode,
     pub module_checksum: Sha256HashDTO,
     pub arg_checksum: Option<Sha256HashDTO>,
+    pub upgrade_diff: Option<CanisterUpgradeDiffDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -77,7 +162,12 @@ pub struct ConfigureExternalCanisterOperationInput {
     pub kind: ConfigureExternalCanisterOperationKindDTO,
 }
 
-pub type ConfigureExternalCanisterOperationDTO = ConfigureExternalCanisterOperationInput;
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigureExternalCanisterOperationDTO {
+    pub canister_id: Principal,
+    pub kind: ConfigureExternalCanisterOperationKindDTO,
+    pub native_settings_diff: Option<Vec<NativeSettingsChangeDTO>>,
+}
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ConfigureExternalCanisterSettingsInput {
@@ -87,6 +177,8 @@ pub struct ConfigureExternalCanisterSettingsInput {
     pub state: Option<ExternalCanisterStateDTO>,
     pub permissions: Option<ExternalCanisterPermissionsInput>,
     pub request_policies: Option<ExternalCanisterRequestPoliciesInput>,
+    #[serde(default)]
+    pub repository_url: Option<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -122,6 +214,80 @@ pub struct CallExternalCanisterOperationDTO {
     pub execution_method_reply: Option<Vec<u8>>,
 }
 
+/// Commits a batch of assets that was proposed to an asset canister, the evidence is the hash of
+/// the proposed batch as computed by `compute_evidence`.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CommitAssetBatchOperationInput {
+    pub canister_id: Principal,
+    pub batch_id: Nat,
+    #[serde(with = "serde_bytes")]
+    pub evidence: Vec<u8>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CommitAssetBatchOperationDTO {
+    pub canister_id: Principal,
+    pub batch_id: Nat,
+    pub evidence: Sha256HashDTO,
+    pub evidence_rendering: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotExternalCanisterOperationKindDTO {
+    Take { replace_snapshot: Option<String> },
+    Load { snapshot_id: String },
+    Delete { snapshot_id: String },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SnapshotExternalCanisterOperationInput {
+    pub canister_id: Principal,
+    pub kind: SnapshotExternalCanisterOperationKindDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SnapshotExternalCanisterOperationDTO {
+    pub canister_id: Principal,
+    pub kind: SnapshotExternalCanisterOperationKindDTO,
+    pub snapshot_id: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CanisterSnapshotDTO {
+    pub snapshot_id: String,
+    pub module_hash: Option<Sha256HashDTO>,
+    pub total_size: u64,
+    pub taken_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListExternalCanisterSnapshotsInput {
+    pub canister_id: Principal,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListExternalCanisterSnapshotsResponse {
+    pub snapshots: Vec<CanisterSnapshotDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CanisterLogRecordDTO {
+    pub idx: u64,
+    pub timestamp: TimestampRfc3339,
+    pub content: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FetchExternalCanisterLogsInput {
+    pub canister_id: Principal,
+    pub retain: Option<bool>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FetchExternalCanisterLogsResponse {
+    pub logs: Vec<CanisterLogRecordDTO>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ExternalCanisterCallPermissionDTO {
     pub allow: AllowDTO,
@@ -186,6 +352,9 @@ pub struct ExternalCanisterDTO {
     pub state: ExternalCanisterStateDTO,
     pub permissions: ExternalCanisterPermissionsDTO,
     pub request_policies: ExternalCanisterRequestPoliciesDTO,
+    pub repository_url: Option<String>,
+    pub module_hash: Option<Sha256HashDTO>,
+    pub snapshots: Vec<CanisterSnapshotDTO>,
     pub created_at: TimestampRfc3339,
     pub modified_at: Option<TimestampRfc3339>,
 }
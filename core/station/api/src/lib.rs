@@ -4,6 +4,9 @@
 mod capabilities;
 pub use capabilities::*;
 
+mod signer_standards;
+pub use signer_standards::*;
+
 mod address_book;
 pub use address_book::*;
 
@@ -25,6 +28,9 @@ pub use account::*;
 mod transfer;
 pub use transfer::*;
 
+mod network;
+pub use network::*;
+
 mod request;
 pub use request::*;
 
@@ -48,3 +54,12 @@ pub use resource::*;
 
 mod disaster_recovery;
 pub use disaster_recovery::*;
+
+mod station_event;
+pub use station_event::*;
+
+mod principal_label;
+pub use principal_label::*;
+
+mod blob;
+pub use blob::*;
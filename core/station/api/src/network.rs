@@ -0,0 +1,71 @@
+use crate::{NetworkIdDTO, TimestampRfc3339, UuidDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BlockchainNetworkDTO {
+    pub id: NetworkIdDTO,
+    pub blockchain: String,
+    pub name: String,
+    pub chain_id: Option<String>,
+    pub is_testnet: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddNetworkOperationInput {
+    pub id: NetworkIdDTO,
+    pub blockchain: String,
+    pub name: String,
+    pub chain_id: Option<String>,
+    pub is_testnet: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddNetworkOperationDTO {
+    pub input: AddNetworkOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditNetworkOperationInput {
+    pub id: NetworkIdDTO,
+    pub blockchain: String,
+    pub name: Option<String>,
+    pub chain_id: Option<String>,
+    pub is_testnet: Option<bool>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditNetworkOperationDTO {
+    pub input: EditNetworkOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BlockchainFreezeDTO {
+    pub blockchain: String,
+    pub network: Option<NetworkIdDTO>,
+    pub reason: Option<String>,
+    pub request_id: UuidDTO,
+    pub frozen_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FreezeBlockchainOperationInput {
+    pub blockchain: String,
+    pub network: Option<NetworkIdDTO>,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FreezeBlockchainOperationDTO {
+    pub input: FreezeBlockchainOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UnfreezeBlockchainOperationInput {
+    pub blockchain: String,
+    pub network: Option<NetworkIdDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UnfreezeBlockchainOperationDTO {
+    pub input: UnfreezeBlockchainOperationInput,
+}
@@ -1,12 +1,25 @@
 use super::TimestampRfc3339;
-use crate::{EvaluationSummaryReasonDTO, RequestOperationTypeDTO, UuidDTO};
-use candid::{CandidType, Deserialize};
+use crate::{
+    EvaluationSummaryReasonDTO, NetworkIdDTO, RequestOperationTypeDTO, Sha256HashDTO, UuidDTO,
+};
+use candid::{CandidType, Deserialize, Principal};
 use std::fmt::{Display, Formatter};
 
 pub const SYSTEM_MESSAGE_NOTIFICATION_TYPE: &str = "system-message";
 pub const REQUEST_CREATED_NOTIFICATION_TYPE: &str = "request-created";
 pub const REQUEST_FAILED_NOTIFICATION_TYPE: &str = "request-failed";
 pub const REQUEST_REJECTED_NOTIFICATION_TYPE: &str = "request-rejected";
+pub const EXTERNAL_CANISTER_MODULE_DRIFTED_NOTIFICATION_TYPE: &str =
+    "external-canister-module-drifted";
+pub const USER_ACTIVITY_ANOMALY_NOTIFICATION_TYPE: &str = "user-activity-anomaly";
+pub const REQUEST_EXECUTED_NOTIFICATION_TYPE: &str = "request-executed";
+pub const DEPOSIT_DETECTED_NOTIFICATION_TYPE: &str = "deposit-detected";
+pub const REQUEST_POLICY_CHANGED_NOTIFICATION_TYPE: &str = "request-policy-changed";
+pub const REQUEST_APPROVAL_REMINDER_NOTIFICATION_TYPE: &str = "request-approval-reminder";
+pub const USER_GROUP_MEMBERSHIP_CHANGED_NOTIFICATION_TYPE: &str = "user-group-membership-changed";
+pub const JOBS_STALLED_NOTIFICATION_TYPE: &str = "jobs-stalled";
+pub const ACCOUNT_ADDRESS_READY_NOTIFICATION_TYPE: &str = "account-address-ready";
+pub const BLOCKCHAIN_FREEZE_CHANGED_NOTIFICATION_TYPE: &str = "blockchain-freeze-changed";
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum NotificationStatusDTO {
@@ -20,6 +33,16 @@ pub enum NotificationTypeDTO {
     RequestCreated(RequestCreatedNotificationDTO),
     RequestFailed(RequestFailedNotificationDTO),
     RequestRejected(RequestRejectedNotificationDTO),
+    ExternalCanisterModuleDrifted(ExternalCanisterModuleDriftedNotificationDTO),
+    UserActivityAnomaly(UserActivityAnomalyNotificationDTO),
+    RequestExecuted(RequestExecutedNotificationDTO),
+    DepositDetected(DepositDetectedNotificationDTO),
+    RequestPolicyChanged(RequestPolicyChangedNotificationDTO),
+    RequestApprovalReminder(RequestApprovalReminderNotificationDTO),
+    UserGroupMembershipChanged(UserGroupMembershipChangedNotificationDTO),
+    JobsStalled(JobsStalledNotificationDTO),
+    AccountAddressReady(AccountAddressReadyNotificationDTO),
+    BlockchainFreezeChanged(BlockchainFreezeChangedNotificationDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -44,10 +67,98 @@ pub struct RequestRejectedNotificationDTO {
     pub reasons: Option<Vec<EvaluationSummaryReasonDTO>>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestExecutedNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DepositDetectedNotificationDTO {
+    pub account_id: UuidDTO,
+    pub amount: candid::Nat,
+    pub balance: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestPolicyChangedNotificationDTO {
+    pub request_id: UuidDTO,
+    pub policy_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestApprovalReminderNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub expiration_dt: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UserGroupMembershipChangedNotificationDTO {
+    pub user_id: UuidDTO,
+    pub user_group_id: UuidDTO,
+    pub joined: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct JobsStalledNotificationDTO {
+    pub overdue_jobs: Vec<String>,
+    pub stalled_executions: Vec<UuidDTO>,
+    pub stalled_expirations: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountAddressReadyNotificationDTO {
+    pub account_id: UuidDTO,
+    pub address: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BlockchainFreezeChangedNotificationDTO {
+    pub request_id: UuidDTO,
+    pub blockchain: String,
+    pub network: Option<NetworkIdDTO>,
+    pub frozen: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExternalCanisterModuleDriftedNotificationDTO {
+    pub external_canister_id: UuidDTO,
+    pub canister_id: Principal,
+    pub expected_module_hash: Option<Sha256HashDTO>,
+    pub found_module_hash: Option<Sha256HashDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UserActivityAnomalyNotificationDTO {
+    pub user_id: UuidDTO,
+    pub anomaly: UserActivityAnomalyDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum UserActivityAnomalyDTO {
+    RequestCreationSpike {
+        requests_created: u64,
+    },
+    DormantUserApproval {
+        request_id: UuidDTO,
+        last_active_at: TimestampRfc3339,
+    },
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum NotificationTypeInput {
     SystemMessage,
     RequestCreated,
+    RequestExecuted,
+    DepositDetected,
+    RequestPolicyChanged,
+    RequestApprovalReminder,
+    UserGroupMembershipChanged,
+    JobsStalled,
+    AccountAddressReady,
+    BlockchainFreezeChanged,
 }
 
 impl Display for NotificationTypeInput {
@@ -59,6 +170,30 @@ impl Display for NotificationTypeInput {
             NotificationTypeInput::RequestCreated => {
                 write!(f, "{}", REQUEST_CREATED_NOTIFICATION_TYPE)
             }
+            NotificationTypeInput::RequestExecuted => {
+                write!(f, "{}", REQUEST_EXECUTED_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::DepositDetected => {
+                write!(f, "{}", DEPOSIT_DETECTED_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::RequestPolicyChanged => {
+                write!(f, "{}", REQUEST_POLICY_CHANGED_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::RequestApprovalReminder => {
+                write!(f, "{}", REQUEST_APPROVAL_REMINDER_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::UserGroupMembershipChanged => {
+                write!(f, "{}", USER_GROUP_MEMBERSHIP_CHANGED_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::JobsStalled => {
+                write!(f, "{}", JOBS_STALLED_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::AccountAddressReady => {
+                write!(f, "{}", ACCOUNT_ADDRESS_READY_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::BlockchainFreezeChanged => {
+                write!(f, "{}", BLOCKCHAIN_FREEZE_CHANGED_NOTIFICATION_TYPE)
+            }
         }
     }
 }
@@ -92,3 +227,49 @@ pub struct MarkNotificationsReadInput {
     pub notification_ids: Vec<UuidDTO>,
     pub read: bool,
 }
+
+/// The delivery of the notifications of the caller to a canister that aggregates the notifications of
+/// many stations, e.g. the control panel.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct NotificationBridgeDTO {
+    pub inbox_canister: Principal,
+    /// The identity that opted in, the notifications are pushed to its inbox.
+    pub identity: Principal,
+    pub created_at: TimestampRfc3339,
+    pub pending_notifications: u64,
+    pub delivered_notifications: u64,
+    pub last_delivered_at: Option<TimestampRfc3339>,
+    /// The error of the last failed delivery, cleared on the next successful one.
+    pub last_error: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetNotificationBridgeInput {
+    /// The canister that receives the notifications, the caller opts out if it is not set.
+    pub inbox_canister: Option<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetNotificationBridgeResponse {
+    pub bridge: Option<NotificationBridgeDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetNotificationBridgeResponse {
+    pub bridge: Option<NotificationBridgeDTO>,
+}
+
+/// The payload that the station pushes to the inbox canister, the same notification can be delivered
+/// more than once.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StationNotificationDeliveryDTO {
+    pub station_id: Principal,
+    /// The identity of the user that opted in to the delivery.
+    pub identity: Principal,
+    pub notification_id: UuidDTO,
+    /// The type of the notification, e.g. "request-created".
+    pub notification_type: String,
+    pub title: String,
+    pub message: Option<String>,
+    pub created_at: TimestampRfc3339,
+}
@@ -66,3 +66,21 @@ pub struct EditPermissionOperationInput {
     pub users: Option<Vec<UuidDTO>>,
     pub user_groups: Option<Vec<UuidDTO>>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BulkEditPermissionOperationInput {
+    pub changes: Vec<EditPermissionOperationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PermissionChangeDTO {
+    pub resource: ResourceDTO,
+    pub before: AllowDTO,
+    pub after: AllowDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BulkEditPermissionOperationDTO {
+    pub input: BulkEditPermissionOperationInput,
+    pub matrix: Vec<PermissionChangeDTO>,
+}
@@ -0,0 +1,70 @@
+use crate::{PaginationInput, TimestampRfc3339, UuidDTO};
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum PrincipalLabelSourceDTO {
+    Manual { labeled_by: UuidDTO },
+    Imported { list_canister: Principal },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrincipalLabelDTO {
+    pub address: String,
+    pub label: String,
+    pub source: PrincipalLabelSourceDTO,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+/// A label returned by the `list_principal_labels` method of a list canister.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrincipalLabelEntryDTO {
+    pub address: String,
+    pub label: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetPrincipalLabelInput {
+    pub address: String,
+    pub label: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetPrincipalLabelResponse {
+    pub label: PrincipalLabelDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemovePrincipalLabelInput {
+    pub address: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemovePrincipalLabelResponse {
+    pub label: PrincipalLabelDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListPrincipalLabelsInput {
+    /// Only the labels whose address starts with or whose label contains the search term are returned.
+    pub search_term: Option<String>,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListPrincipalLabelsResponse {
+    pub labels: Vec<PrincipalLabelDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ImportPrincipalLabelsInput {
+    pub list_canister_id: Principal,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ImportPrincipalLabelsResponse {
+    pub imported: u64,
+    pub removed: u64,
+    pub skipped: u64,
+}
@@ -3,22 +3,30 @@ use super::{
 };
 use crate::{
     AddAccountOperationDTO, AddAccountOperationInput, AddAddressBookEntryOperationDTO,
-    AddAddressBookEntryOperationInput, AddUserGroupOperationDTO, AddUserGroupOperationInput,
-    AddUserOperationDTO, AddUserOperationInput, CallExternalCanisterOperationDTO,
-    CallExternalCanisterOperationInput, ChangeExternalCanisterOperationDTO,
-    ChangeExternalCanisterOperationInput, ConfigureExternalCanisterOperationDTO,
-    ConfigureExternalCanisterOperationInput, CreateExternalCanisterOperationDTO,
-    CreateExternalCanisterOperationInput, DisplayUserDTO, EditAccountOperationDTO,
-    EditAddressBookEntryOperationDTO, EditAddressBookEntryOperationInput,
-    EditPermissionOperationDTO, EditPermissionOperationInput, EditUserGroupOperationDTO,
-    EditUserGroupOperationInput, EditUserOperationDTO, EditUserOperationInput,
-    FundExternalCanisterOperationDTO, FundExternalCanisterOperationInput,
-    ManageSystemInfoOperationDTO, ManageSystemInfoOperationInput, PaginationInput,
-    RemoveAddressBookEntryOperationDTO, RemoveAddressBookEntryOperationInput,
-    RemoveUserGroupOperationDTO, RemoveUserGroupOperationInput, RequestEvaluationResultDTO,
-    RequestPolicyRuleDTO, RequestSpecifierDTO, SetDisasterRecoveryOperationDTO,
-    SetDisasterRecoveryOperationInput, SortDirection, SystemUpgradeOperationDTO,
-    SystemUpgradeOperationInput, UuidDTO,
+    AddAddressBookEntryOperationInput, AddNetworkOperationDTO, AddNetworkOperationInput,
+    AddUserGroupOperationDTO, AddUserGroupOperationInput, AddUserOperationDTO,
+    AddUserOperationInput, BulkEditPermissionOperationDTO, BulkEditPermissionOperationInput,
+    CallExternalCanisterOperationDTO, CallExternalCanisterOperationInput,
+    ChangeExternalCanisterOperationDTO, ChangeExternalCanisterOperationInput,
+    CommitAssetBatchOperationDTO, CommitAssetBatchOperationInput,
+    ConfigureExternalCanisterOperationDTO, ConfigureExternalCanisterOperationInput,
+    CreateExternalCanisterOperationDTO, CreateExternalCanisterOperationInput,
+    DecommissionStationOperationDTO, DecommissionStationOperationInput, DisplayUserDTO,
+    EditAccountOperationDTO, EditAddressBookEntryOperationDTO, EditAddressBookEntryOperationInput,
+    EditNetworkOperationDTO, EditNetworkOperationInput, EditPermissionOperationDTO,
+    EditPermissionOperationInput, EditUserGroupOperationDTO, EditUserGroupOperationInput,
+    EditUserOperationDTO, EditUserOperationInput, FreezeBlockchainOperationDTO,
+    FreezeBlockchainOperationInput, FundExternalCanisterOperationDTO,
+    FundExternalCanisterOperationInput, ManageSystemInfoOperationDTO,
+    ManageSystemInfoOperationInput, PaginationInput, RemoveAddressBookEntryOperationDTO,
+    RemoveAddressBookEntryOperationInput, RemoveUserGroupOperationDTO,
+    RemoveUserGroupOperationInput, RequestEvaluationResultDTO, RequestPolicyRuleDTO,
+    RequestSpecifierDTO, RotateAccountAddressOperationDTO, RotateAccountAddressOperationInput,
+    SetDisasterRecoveryOperationDTO, SetDisasterRecoveryOperationInput,
+    SnapshotExternalCanisterOperationDTO, SnapshotExternalCanisterOperationInput, SortDirection,
+    SystemUpgradeOperationDTO, SystemUpgradeOperationInput, TransferAccountOwnershipOperationDTO,
+    TransferAccountOwnershipOperationInput, UnfreezeBlockchainOperationDTO,
+    UnfreezeBlockchainOperationInput, UuidDTO,
 };
 use candid::{CandidType, Deserialize, Principal};
 
@@ -55,7 +63,11 @@ pub enum RequestApprovalStatusDTO {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestExecutionScheduleDTO {
     Immediate,
-    Scheduled { execution_time: TimestampRfc3339 },
+    Scheduled {
+        execution_time: TimestampRfc3339,
+        /// The end of the execution window, the request fails if it can't be executed before then.
+        window_end: Option<TimestampRfc3339>,
+    },
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -63,6 +75,8 @@ pub enum RequestOperationDTO {
     Transfer(Box<TransferOperationDTO>),
     AddAccount(Box<AddAccountOperationDTO>),
     EditAccount(Box<EditAccountOperationDTO>),
+    RotateAccountAddress(Box<RotateAccountAddressOperationDTO>),
+    TransferAccountOwnership(Box<TransferAccountOwnershipOperationDTO>),
     AddAddressBookEntry(Box<AddAddressBookEntryOperationDTO>),
     EditAddressBookEntry(Box<EditAddressBookEntryOperationDTO>),
     RemoveAddressBookEntry(Box<RemoveAddressBookEntryOperationDTO>),
@@ -78,11 +92,19 @@ pub enum RequestOperationDTO {
     ConfigureExternalCanister(Box<ConfigureExternalCanisterOperationDTO>),
     CallExternalCanister(Box<CallExternalCanisterOperationDTO>),
     FundExternalCanister(Box<FundExternalCanisterOperationDTO>),
+    CommitAssetBatch(Box<CommitAssetBatchOperationDTO>),
+    SnapshotExternalCanister(Box<SnapshotExternalCanisterOperationDTO>),
     EditPermission(Box<EditPermissionOperationDTO>),
+    BulkEditPermission(Box<BulkEditPermissionOperationDTO>),
     AddRequestPolicy(Box<AddRequestPolicyOperationDTO>),
     EditRequestPolicy(Box<EditRequestPolicyOperationDTO>),
     RemoveRequestPolicy(Box<RemoveRequestPolicyOperationDTO>),
     ManageSystemInfo(Box<ManageSystemInfoOperationDTO>),
+    AddNetwork(Box<AddNetworkOperationDTO>),
+    EditNetwork(Box<EditNetworkOperationDTO>),
+    FreezeBlockchain(Box<FreezeBlockchainOperationDTO>),
+    UnfreezeBlockchain(Box<UnfreezeBlockchainOperationDTO>),
+    DecommissionStation(Box<DecommissionStationOperationDTO>),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -90,6 +112,8 @@ pub enum RequestOperationInput {
     Transfer(TransferOperationInput),
     AddAccount(AddAccountOperationInput),
     EditAccount(EditAccountOperationInput),
+    RotateAccountAddress(RotateAccountAddressOperationInput),
+    TransferAccountOwnership(TransferAccountOwnershipOperationInput),
     AddAddressBookEntry(AddAddressBookEntryOperationInput),
     EditAddressBookEntry(EditAddressBookEntryOperationInput),
     RemoveAddressBookEntry(RemoveAddressBookEntryOperationInput),
@@ -105,11 +129,19 @@ pub enum RequestOperationInput {
     ConfigureExternalCanister(ConfigureExternalCanisterOperationInput),
     CallExternalCanister(CallExternalCanisterOperationInput),
     FundExternalCanister(FundExternalCanisterOperationInput),
+    CommitAssetBatch(CommitAssetBatchOperationInput),
+    SnapshotExternalCanister(SnapshotExternalCanisterOperationInput),
     EditPermission(EditPermissionOperationInput),
+    BulkEditPermission(BulkEditPermissionOperationInput),
     AddRequestPolicy(AddRequestPolicyOperationInput),
     EditRequestPolicy(EditRequestPolicyOperationInput),
     RemoveRequestPolicy(RemoveRequestPolicyOperationInput),
     ManageSystemInfo(ManageSystemInfoOperationInput),
+    AddNetwork(AddNetworkOperationInput),
+    EditNetwork(EditNetworkOperationInput),
+    FreezeBlockchain(FreezeBlockchainOperationInput),
+    UnfreezeBlockchain(UnfreezeBlockchainOperationInput),
+    DecommissionStation(DecommissionStationOperationInput),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -117,6 +149,8 @@ pub enum RequestOperationTypeDTO {
     Transfer,
     AddAccount,
     EditAccount,
+    RotateAccountAddress,
+    TransferAccountOwnership,
     AddAddressBookEntry,
     EditAddressBookEntry,
     RemoveAddressBookEntry,
@@ -131,12 +165,20 @@ pub enum RequestOperationTypeDTO {
     CreateExternalCanister,
     CallExternalCanister,
     FundExternalCanister,
+    CommitAssetBatch,
+    SnapshotExternalCanister,
     EditPermission,
+    BulkEditPermission,
     AddRequestPolicy,
     EditRequestPolicy,
     RemoveRequestPolicy,
     ManageSystemInfo,
+    AddNetwork,
+    EditNetwork,
+    FreezeBlockchain,
+    UnfreezeBlockchain,
     ConfigureExternalCanister,
+    DecommissionStation,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -144,6 +186,8 @@ pub enum ListRequestsOperationTypeDTO {
     Transfer(Option<UuidDTO>),
     AddAccount,
     EditAccount,
+    RotateAccountAddress,
+    TransferAccountOwnership,
     AddAddressBookEntry,
     EditAddressBookEntry,
     RemoveAddressBookEntry,
@@ -157,13 +201,21 @@ pub enum ListRequestsOperationTypeDTO {
     CreateExternalCanister,
     CallExternalCanister(Option<Principal>),
     FundExternalCanister(Option<Principal>),
+    CommitAssetBatch(Option<Principal>),
+    SnapshotExternalCanister(Option<Principal>),
     EditPermission,
+    BulkEditPermission,
     AddRequestPolicy,
     EditRequestPolicy,
     RemoveRequestPolicy,
     ManageSystemInfo,
+    AddNetwork,
+    EditNetwork,
+    FreezeBlockchain,
+    UnfreezeBlockchain,
     SetDisasterRecovery,
     ConfigureExternalCanister(Option<Principal>),
+    DecommissionStation,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -171,6 +223,7 @@ pub struct RequestApprovalDTO {
     pub approver_id: UuidDTO,
     pub status: RequestApprovalStatusDTO,
     pub status_reason: Option<String>,
+    pub acknowledged_checklist: Vec<String>,
     pub decided_at: TimestampRfc3339,
 }
 
@@ -179,6 +232,7 @@ pub struct RequestDTO {
     pub id: UuidDTO,
     pub title: String,
     pub summary: Option<String>,
+    pub summary_format: RequestSummaryFormatDTO,
     pub operation: RequestOperationDTO,
     pub requested_by: UuidDTO,
     pub approvals: Vec<RequestApprovalDTO>,
@@ -186,12 +240,43 @@ pub struct RequestDTO {
     pub status: RequestStatusDTO,
     pub expiration_dt: TimestampRfc3339,
     pub execution_plan: RequestExecutionScheduleDTO,
+    pub execution_steps: Vec<RequestExecutionStepDTO>,
+    pub confidential: bool,
+    pub compacted_at: Option<TimestampRfc3339>,
+    pub depends_on: Vec<RequestDependencyDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestDependencyDTO {
+    pub request_id: UuidDTO,
+    /// The current status of the dependency, none if it no longer exists.
+    pub status: Option<RequestStatusCodeDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RequestExecutionStepStatusDTO {
+    Completed {
+        output: Option<String>,
+        completed_at: TimestampRfc3339,
+    },
+    Failed {
+        reason: String,
+        failed_at: TimestampRfc3339,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestExecutionStepDTO {
+    pub name: String,
+    pub status: RequestExecutionStepStatusDTO,
+    pub attempts: u32,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct RequestCallerPrivilegesDTO {
     pub id: UuidDTO,
     pub can_approve: bool,
+    pub can_cancel: bool,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -200,6 +285,21 @@ pub struct RequestAdditionalInfoDTO {
     pub requester_name: String,
     pub approvers: Vec<DisplayUserDTO>,
     pub evaluation_result: Option<RequestEvaluationResultDTO>,
+    pub approval_checklist: Vec<String>,
+    pub impact: Option<RequestImpactDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestImpactDTO {
+    pub users_gaining_access: Vec<DisplayUserDTO>,
+    pub users_losing_access: Vec<DisplayUserDTO>,
+    pub affected_request_ids: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestSummaryFormatDTO {
+    PlainText,
+    Markdown,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -207,7 +307,12 @@ pub struct CreateRequestInput {
     pub operation: RequestOperationInput,
     pub title: Option<String>,
     pub summary: Option<String>,
+    /// The format of the summary, plain text by default.
+    pub summary_format: Option<RequestSummaryFormatDTO>,
     pub execution_plan: Option<RequestExecutionScheduleDTO>,
+    pub confidential: Option<bool>,
+    /// The requests that must complete successfully before the request can be executed.
+    pub depends_on: Option<Vec<UuidDTO>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -215,6 +320,7 @@ pub struct SubmitRequestApprovalInput {
     pub decision: RequestApprovalStatusDTO,
     pub request_id: UuidDTO,
     pub reason: Option<String>,
+    pub acknowledged_checklist: Option<Vec<String>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -224,9 +330,50 @@ pub struct SubmitRequestApprovalResponse {
     pub additional_info: RequestAdditionalInfoDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RetryRequestExecutionInput {
+    pub request_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RetryRequestExecutionResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RescheduleRequestInput {
+    pub request_id: UuidDTO,
+    pub execution_plan: RequestExecutionScheduleDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RescheduleRequestResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelTimeLockedRequestInput {
+    pub request_id: UuidDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelTimeLockedRequestResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct GetRequestInput {
     pub request_id: UuidDTO,
+    /// The version of the API that the client is pinned to, defaults to the current version.
+    #[serde(default)]
+    pub api_version: Option<u32>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -236,6 +383,74 @@ pub struct GetRequestResponse {
     pub additional_info: RequestAdditionalInfoDTO,
 }
 
+/// A request as it is stored by the archive canister.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ArchivedRequestDTO {
+    pub request: RequestDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
+/// The input of the `archive_requests` method of the archive canister.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ArchiveRequestsInput {
+    pub station_id: Principal,
+    pub requests: Vec<ArchivedRequestDTO>,
+}
+
+/// The input of the `get_archived_request` query of the archive canister.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetArchivedRequestInput {
+    pub station_id: Principal,
+    pub request_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRequestCanonicalInput {
+    pub request_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRequestCanonicalResponse {
+    /// The canonical JSON encoding of the content of the request.
+    pub canonical_json: String,
+    /// The hex encoded sha256 hash of the canonical JSON encoding.
+    pub sha256: String,
+    /// The version of the canonical encoding.
+    pub encoding_version: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct VoteReceiptDTO {
+    pub request_id: UuidDTO,
+    pub approver_id: UuidDTO,
+    /// The identity that the approver voted with.
+    pub voter: Principal,
+    pub decision: RequestApprovalStatusDTO,
+    pub decided_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetVoteCertificateInput {
+    pub request_id: UuidDTO,
+    pub approver_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetVoteCertificateResponse {
+    pub receipt: VoteReceiptDTO,
+    /// The canonical JSON encoding of the receipt, whose sha256 hash is certified.
+    pub canonical_json: String,
+    /// The version of the canonical encoding.
+    pub encoding_version: u32,
+    /// The certificate of the Internet Computer that signs the certified data of the station.
+    #[serde(with = "serde_bytes")]
+    pub certificate: Vec<u8>,
+    /// The CBOR encoded hash tree whose root hash is the certified data of the station, it reveals the
+    /// sha256 hash of the receipt at the path `votes/<request id><approver id>`.
+    #[serde(with = "serde_bytes")]
+    pub witness: Vec<u8>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum ListRequestsSortBy {
     CreatedAt(SortDirection),
@@ -257,6 +472,9 @@ pub struct ListRequestsInput {
     pub sort_by: Option<ListRequestsSortBy>,
     pub only_approvable: bool,
     pub with_evaluation_results: bool,
+    /// The version of the API that the client is pinned to, defaults to the current version.
+    #[serde(default)]
+    pub api_version: Option<u32>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -272,6 +490,9 @@ pub struct ListRequestsResponse {
 pub struct GetNextApprovableRequestInput {
     pub excluded_request_ids: Vec<UuidDTO>,
     pub operation_types: Option<Vec<ListRequestsOperationTypeDTO>>,
+    /// The version of the API that the client is pinned to, defaults to the current version.
+    #[serde(default)]
+    pub api_version: Option<u32>,
 }
 
 pub type GetNextApprovableRequestResponse = Option<GetRequestResponse>;
@@ -287,6 +508,8 @@ pub struct CreateRequestResponse {
 pub struct AddRequestPolicyOperationInput {
     pub specifier: RequestSpecifierDTO,
     pub rule: RequestPolicyRuleDTO,
+    pub checklist: Option<Vec<String>>,
+    pub exclude_requester: Option<bool>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -300,6 +523,9 @@ pub struct EditRequestPolicyOperationInput {
     pub policy_id: UuidDTO,
     pub specifier: Option<RequestSpecifierDTO>,
     pub rule: Option<RequestPolicyRuleDTO>,
+    pub checklist: Option<Vec<String>>,
+    pub exclude_requester: Option<bool>,
+    pub expected_version: Option<u64>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
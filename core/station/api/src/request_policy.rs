@@ -1,6 +1,6 @@
 use crate::{
     resource::ResourceDTO, CallExternalCanisterResourceTargetDTO, ExternalCanisterIdDTO,
-    MetadataDTO, PaginationInput, ResourceIdsDTO, UuidDTO,
+    MetadataDTO, PaginationInput, RequestOperationTypeDTO, ResourceIdsDTO, UuidDTO,
 };
 use candid::{CandidType, Deserialize};
 
@@ -55,6 +55,18 @@ pub struct QuorumDTO {
     pub min_approved: u16,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GroupVoteWeightDTO {
+    pub group_id: UuidDTO,
+    pub weight: u16,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct WeightedQuorumDTO {
+    pub groups: Vec<GroupVoteWeightDTO>,
+    pub min_weight: u16,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestPolicyRuleInput {
     Remove,
@@ -73,6 +85,7 @@ pub enum RequestPolicyRuleDTO {
     AutoApproved,
     QuorumPercentage(QuorumPercentageDTO),
     Quorum(QuorumDTO),
+    WeightedQuorum(WeightedQuorumDTO),
     AllowListedByMetadata(MetadataDTO),
     AllowListed,
     AnyOf(Vec<RequestPolicyRuleDTO>),
@@ -87,11 +100,21 @@ pub enum EvaluatedRequestPolicyRuleDTO {
         total_possible_approvers: usize,
         min_approved: usize,
         approvers: Vec<UuidDTO>,
+        requester_excluded: bool,
     },
     Quorum {
         total_possible_approvers: usize,
         min_approved: usize,
         approvers: Vec<UuidDTO>,
+        requester_excluded: bool,
+    },
+    WeightedQuorum {
+        min_weight: usize,
+        total_possible_weight: usize,
+        approved_weight: usize,
+        rejected_weight: usize,
+        approvers: Vec<UuidDTO>,
+        requester_excluded: bool,
     },
     AllowListedByMetadata {
         metadata: MetadataDTO,
@@ -136,6 +159,9 @@ pub struct RequestPolicyDTO {
     pub id: UuidDTO,
     pub specifier: RequestSpecifierDTO,
     pub rule: RequestPolicyRuleDTO,
+    pub checklist: Vec<String>,
+    pub exclude_requester: bool,
+    pub version: u64,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -158,3 +184,31 @@ pub struct ListRequestPoliciesResponse {
     pub total: u64,
     pub privileges: Vec<RequestPolicyCallerPrivilegesDTO>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RequestPolicyIssueDTO {
+    EmptyApproverGroup {
+        policy_id: UuidDTO,
+        group_id: UuidDTO,
+    },
+    NoPossibleApprovers {
+        policy_id: UuidDTO,
+    },
+    ThresholdAboveApprovers {
+        policy_id: UuidDTO,
+        threshold: u64,
+        possible: u64,
+    },
+    UncoveredOperationType {
+        operation_type: RequestOperationTypeDTO,
+    },
+    ShadowedSpecifier {
+        policy_id: UuidDTO,
+        shadowed_by: UuidDTO,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct LintRequestPoliciesResponse {
+    pub issues: Vec<RequestPolicyIssueDTO>,
+}
@@ -57,6 +57,8 @@ pub enum AccountResourceActionDTO {
     Transfer(ResourceIdDTO),
     Read(ResourceIdDTO),
     Update(ResourceIdDTO),
+    Reconcile(ResourceIdDTO),
+    Review(ResourceIdDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
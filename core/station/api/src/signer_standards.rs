@@ -0,0 +1,24 @@
+use candid::{CandidType, Deserialize, Principal};
+
+/// A signer standard that is supported by the canister, as defined by ICRC-25.
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SupportedStandardDTO {
+    /// The name of the standard (e.g. `ICRC-25`).
+    pub name: String,
+    /// The url of the specification of the standard.
+    pub url: String,
+}
+
+/// An ICRC-1 account, which is the owner principal and an optional 32 bytes subaccount.
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Icrc1AccountDTO {
+    pub owner: Principal,
+    #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
+    pub subaccount: Option<Vec<u8>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct Icrc27AccountsResponse {
+    /// The station managed accounts that the caller has access to.
+    pub accounts: Vec<Icrc1AccountDTO>,
+}
@@ -0,0 +1,77 @@
+use crate::{Sha256HashDTO, TimestampRfc3339, UuidDTO};
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum StationEventKindDTO {
+    RequestExecuted,
+    DepositDetected,
+    ExternalCanisterModuleDrifted,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestExecutedEventDTO {
+    pub request_id: UuidDTO,
+    pub completed_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DepositDetectedEventDTO {
+    pub account_id: UuidDTO,
+    pub amount: Nat,
+    pub balance: Nat,
+    pub detected_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExternalCanisterModuleDriftedEventDTO {
+    pub external_canister_id: UuidDTO,
+    pub canister_id: Principal,
+    pub expected_module_hash: Option<Sha256HashDTO>,
+    pub found_module_hash: Option<Sha256HashDTO>,
+    pub detected_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum StationEventDTO {
+    RequestExecuted(RequestExecutedEventDTO),
+    DepositDetected(DepositDetectedEventDTO),
+    ExternalCanisterModuleDrifted(ExternalCanisterModuleDriftedEventDTO),
+}
+
+/// The payload sent to the subscriber canisters, the same event can be delivered more than once.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StationEventDeliveryDTO {
+    pub event_id: u64,
+    pub station_id: Principal,
+    pub event: StationEventDTO,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EventSubscriberDTO {
+    pub id: UuidDTO,
+    pub canister_id: Principal,
+    pub method_name: String,
+    pub events: Vec<StationEventKindDTO>,
+    pub created_at: TimestampRfc3339,
+    pub pending_events: u64,
+    pub acknowledged_events: u64,
+    pub last_acknowledged_at: Option<TimestampRfc3339>,
+    pub last_error: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SubscribeToEventsInput {
+    pub method_name: String,
+    pub events: Vec<StationEventKindDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SubscribeToEventsResponse {
+    pub subscriber: EventSubscriberDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListEventSubscribersResponse {
+    pub subscribers: Vec<EventSubscriberDTO>,
+}
@@ -1,5 +1,9 @@
 use super::TimestampRfc3339;
-use crate::{DisasterRecoveryCommitteeDTO, MetadataDTO, Sha256HashDTO, UuidDTO};
+use crate::{
+    BlockchainFreezeDTO, BlockchainNetworkDTO, CanisterUpgradeDiffDTO,
+    DisasterRecoveryCommitteeDTO, MetadataDTO, PaginationInput, RequestOperationTypeDTO,
+    Sha256HashDTO, UuidDTO,
+};
 use candid::{CandidType, Deserialize, Principal};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -12,6 +16,223 @@ pub struct SystemInfoDTO {
     pub raw_rand_successful: bool,
     pub disaster_recovery: Option<DisasterRecoveryDTO>,
     pub cycle_obtain_strategy: CycleObtainStrategyDTO,
+    pub index_rebuild: Option<IndexRebuildProgressDTO>,
+    pub config_replica_consistent: bool,
+    pub auditors: Vec<AuditorAccessDTO>,
+    pub address_book_sources: Vec<AddressBookSourceDTO>,
+    pub name_service: NameServiceConfigDTO,
+    pub transfer_compliance_thresholds: Vec<TransferComplianceThresholdDTO>,
+    pub transfer_max_fees: Vec<TransferMaxFeeDTO>,
+    pub transfer_screening: TransferScreeningConfigDTO,
+    pub transfer_time_lock: TransferTimeLockConfigDTO,
+    pub metadata_encryption: MetadataEncryptionConfigDTO,
+    pub data_compaction: DataCompactionConfigDTO,
+    pub request_archival: RequestArchivalConfigDTO,
+    pub branding: BrandingConfigDTO,
+    pub sub_treasuries: Vec<SubTreasuryDTO>,
+    pub maintenance_mode: MaintenanceModeConfigDTO,
+    pub in_maintenance: bool,
+    pub request_text_limits: RequestTextLimitsDTO,
+    pub approval_reminders: ApprovalReminderConfigDTO,
+    pub balance_snapshots: BalanceSnapshotConfigDTO,
+    pub networks: Vec<BlockchainNetworkDTO>,
+    pub fiat_valuation: FiatValuationConfigDTO,
+    pub operation_flags: Vec<OperationFlagDTO>,
+    pub mock_blockchains: bool,
+    pub decommission: Option<StationDecommissionDTO>,
+    pub frozen_blockchains: Vec<BlockchainFreezeDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StationDecommissionDTO {
+    pub request_id: UuidDTO,
+    pub reason: Option<String>,
+    pub decommissioned_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationAvailabilityDTO {
+    Deprecated,
+    Disabled,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct OperationFlagDTO {
+    pub operation_type: RequestOperationTypeDTO,
+    pub availability: OperationAvailabilityDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApprovalReminderConfigDTO {
+    pub intervals_secs: Vec<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BalanceSnapshotConfigDTO {
+    pub interval_secs: Option<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum FiatValuationConfigDTO {
+    Disabled,
+    Canister {
+        canister_id: Principal,
+        currencies: Vec<String>,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestTextLimitsDTO {
+    pub max_title_len: u16,
+    pub max_summary_len: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct MaintenanceModeConfigDTO {
+    pub enabled: bool,
+    pub reason: Option<String>,
+    pub during_migrations: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum SubTreasuryVisibilityDTO {
+    Isolated,
+    Shared,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SubTreasuryDTO {
+    pub name: String,
+    pub account_ids: Vec<UuidDTO>,
+    pub admin_group_id: UuidDTO,
+    pub member_group_ids: Vec<UuidDTO>,
+    pub visibility: SubTreasuryVisibilityDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum DataCompactionConfigDTO {
+    Disabled,
+    Enabled {
+        min_age_days: u32,
+        export_canister_id: Option<Principal>,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RequestArchivalConfigDTO {
+    Disabled,
+    Enabled {
+        min_age_days: u32,
+        archive_canister_id: Principal,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AssetLogoDTO {
+    pub blockchain: String,
+    pub standard: String,
+    pub symbol: String,
+    pub logo_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct BrandingConfigDTO {
+    pub logo_id: Option<UuidDTO>,
+    pub asset_logos: Vec<AssetLogoDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum MetadataEncryptionConfigDTO {
+    Disabled,
+    VetKd {
+        key_name: String,
+        address_book_metadata_keys: Vec<String>,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetMetadataEncryptionKeyInput {
+    #[serde(with = "serde_bytes")]
+    pub transport_public_key: Vec<u8>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetMetadataEncryptionKeyResponse {
+    #[serde(with = "serde_bytes")]
+    pub encrypted_key: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub public_key: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub context: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub input: Vec<u8>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum TransferScreeningConfigDTO {
+    Disabled,
+    Canister {
+        canister_id: Principal,
+        thresholds: Vec<TransferComplianceThresholdDTO>,
+        blocks_approval: bool,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum TransferTimeLockConfigDTO {
+    Disabled,
+    Enabled {
+        thresholds: Vec<TransferComplianceThresholdDTO>,
+        delay_secs: u64,
+        canceller_group_ids: Vec<UuidDTO>,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferComplianceThresholdDTO {
+    pub symbol: String,
+    pub amount: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferMaxFeeDTO {
+    pub symbol: String,
+    pub max_fee: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum NameServiceConfigDTO {
+    Disabled,
+    Canister { canister_id: Principal },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddressBookSourceDTO {
+    pub canister_id: Principal,
+    pub labels: Vec<String>,
+    pub last_synced_at: Option<TimestampRfc3339>,
+    pub last_sync_error: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddressBookSourceInput {
+    pub canister_id: Principal,
+    pub labels: Vec<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AuditorAccessDTO {
+    pub principal: Principal,
+    pub name: String,
+    pub expires_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct IndexRebuildProgressDTO {
+    pub pending: Vec<String>,
+    pub processed_entries: u64,
+    pub started_at: TimestampRfc3339,
+    pub last_progress_at: TimestampRfc3339,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -44,6 +265,34 @@ pub enum CycleObtainStrategyInput {
 pub struct ManageSystemInfoOperationInput {
     pub name: Option<String>,
     pub cycle_obtain_strategy: Option<CycleObtainStrategyInput>,
+    pub auditors: Option<Vec<AuditorAccessDTO>>,
+    pub address_book_sources: Option<Vec<AddressBookSourceInput>>,
+    pub name_service: Option<NameServiceConfigDTO>,
+    pub transfer_compliance_thresholds: Option<Vec<TransferComplianceThresholdDTO>>,
+    pub transfer_max_fees: Option<Vec<TransferMaxFeeDTO>>,
+    pub transfer_screening: Option<TransferScreeningConfigDTO>,
+    pub transfer_time_lock: Option<TransferTimeLockConfigDTO>,
+    pub metadata_encryption: Option<MetadataEncryptionConfigDTO>,
+    pub data_compaction: Option<DataCompactionConfigDTO>,
+    pub request_archival: Option<RequestArchivalConfigDTO>,
+    pub branding: Option<BrandingConfigDTO>,
+    pub sub_treasuries: Option<Vec<SubTreasuryDTO>>,
+    pub maintenance_mode: Option<MaintenanceModeConfigDTO>,
+    pub request_text_limits: Option<RequestTextLimitsDTO>,
+    pub approval_reminders: Option<ApprovalReminderConfigDTO>,
+    pub balance_snapshots: Option<BalanceSnapshotConfigDTO>,
+    pub fiat_valuation: Option<FiatValuationConfigDTO>,
+    pub operation_flags: Option<Vec<OperationFlagDTO>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DecommissionStationOperationInput {
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DecommissionStationOperationDTO {
+    pub input: DecommissionStationOperationInput,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -51,6 +300,118 @@ pub struct SystemInfoResponse {
     pub system: SystemInfoDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct RequestOperationUsageDTO {
+    pub operation_type: RequestOperationTypeDTO,
+    pub count: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct PrincipalUsageDTO {
+    pub principal: Principal,
+    pub user_id: Option<UuidDTO>,
+    pub calls: u64,
+    pub approvals: u64,
+    pub requests_created: Vec<RequestOperationUsageDTO>,
+    pub last_active_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListPrincipalUsageResponse {
+    pub window_secs: u64,
+    pub usage: Vec<PrincipalUsageDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListStaleApproversInput {
+    /// The number of days without activity after which an approver is considered stale.
+    pub inactive_days: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct StaleApproverDTO {
+    pub user_id: UuidDTO,
+    pub name: String,
+    pub last_active_at: Option<TimestampRfc3339>,
+    pub policy_ids: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListStaleApproversResponse {
+    pub approvers: Vec<StaleApproverDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevelDTO {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct StationLogEntryDTO {
+    pub idx: u64,
+    pub timestamp: TimestampRfc3339,
+    pub level: LogLevelDTO,
+    pub module: String,
+    pub message: String,
+    /// The correlation id of the trace the entry was written for, if any.
+    pub correlation_id: Option<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListStationLogsInput {
+    /// Only entries of this level or a more severe one are returned.
+    pub min_level: Option<LogLevelDTO>,
+    /// Only entries of modules starting with this path are returned (e.g. `station::jobs`).
+    pub module: Option<String>,
+    pub from_dt: Option<TimestampRfc3339>,
+    pub to_dt: Option<TimestampRfc3339>,
+    /// Only entries written for the trace of this correlation id are returned.
+    pub correlation_id: Option<UuidDTO>,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListStationLogsResponse {
+    /// The matching entries, newest first.
+    pub entries: Vec<StationLogEntryDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct TraceSpanDTO {
+    pub id: u32,
+    /// The span this span was opened within, spans without a parent are the ingresses of the trace.
+    pub parent_id: Option<u32>,
+    pub name: String,
+    pub details: Option<String>,
+    pub started_at: TimestampRfc3339,
+    pub finished_at: Option<TimestampRfc3339>,
+    pub duration_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct TraceDTO {
+    pub correlation_id: UuidDTO,
+    pub spans: Vec<TraceSpanDTO>,
+    /// The log entries written for the trace, oldest first.
+    pub logs: Vec<StationLogEntryDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct GetTraceInput {
+    pub correlation_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct GetTraceResponse {
+    pub trace: TraceDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct AdminInitInput {
     pub name: String,
@@ -86,11 +447,26 @@ pub struct SystemInit {
     pub fallback_controller: Option<Principal>,
     /// Optionally set the initial accounts.
     pub accounts: Option<Vec<InitAccountInput>>,
+    /// Optionally set the ledger canisters to use instead of the mainnet defaults.
+    pub ledger_canisters: Option<Vec<LedgerCanisterInput>>,
+    /// Optionally simulate the blockchains instead of calling their canisters, for local development and tests.
+    pub mock_blockchains: Option<bool>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct SystemUpgrade {
     pub name: Option<String>,
+    pub ledger_canisters: Option<Vec<LedgerCanisterInput>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct LedgerCanisterInput {
+    /// The asset symbol (e.g. `ICP`).
+    pub symbol: String,
+    /// The network of the asset (e.g. `mainnet`).
+    pub network: String,
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -118,6 +494,16 @@ pub struct SystemUpgradeOperationInput {
     pub module: Vec<u8>,
     #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
     pub arg: Option<Vec<u8>>,
+    /// The current values of the station that are injected into the arg when the upgrade is executed.
+    #[serde(default)]
+    pub arg_injections: Option<Vec<SystemUpgradeArgInjectionDTO>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum SystemUpgradeArgInjectionDTO {
+    /// The current ledger canisters are added to the `ledger_canisters` of the arg, the entries of
+    /// the arg take precedence for the same asset and network.
+    LedgerCanisters,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -125,9 +511,37 @@ pub struct SystemUpgradeOperationDTO {
     pub target: SystemUpgradeTargetDTO,
     pub module_checksum: Sha256HashDTO,
     pub arg_checksum: Option<Sha256HashDTO>,
+    pub arg_injections: Vec<SystemUpgradeArgInjectionDTO>,
+    pub upgrade_diff: Option<CanisterUpgradeDiffDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct NotifyFailedStationUpgradeInput {
     pub reason: String,
 }
+
+/// The steps of the bootstrap that runs after the installation of the station, in the order they run.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum InitStepDTO {
+    DefaultConfigs,
+    Upgrader,
+    Controllers,
+    InitialAccounts,
+    Completed,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct InitStatusDTO {
+    /// The step that the bootstrap runs next, `Completed` once the station is initialized.
+    pub next_step: InitStepDTO,
+    pub upgrader_canister_id: Option<Principal>,
+    pub attempts: u32,
+    pub last_attempt_at: Option<TimestampRfc3339>,
+    /// The error of the last attempt, if it failed.
+    pub last_error: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetInitStatusResponse {
+    pub status: InitStatusDTO,
+}
@@ -1,6 +1,6 @@
 use super::{AccountDTO, TimestampRfc3339};
 use crate::{MetadataDTO, UuidDTO};
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 
 pub type NetworkIdDTO = String;
 
@@ -18,6 +18,32 @@ pub struct TransferOperationInput {
     pub fee: Option<candid::Nat>,
     pub metadata: Vec<MetadataDTO>,
     pub network: Option<NetworkDTO>,
+    pub fee_strategy: Option<TransferFeeStrategyDTO>,
+    pub max_fee: Option<candid::Nat>,
+    pub compliance: Option<TransferComplianceInfoDTO>,
+    pub from_address: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferComplianceInfoDTO {
+    pub beneficiary_name: Option<String>,
+    pub originator_name: Option<String>,
+    pub originator_identifier: Option<String>,
+    pub purpose_code: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferFeeStrategyDTO {
+    Slow,
+    Standard,
+    Fast,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferFeeEstimateDTO {
+    pub fee: candid::Nat,
+    pub strategy: TransferFeeStrategyDTO,
+    pub estimated_at: TimestampRfc3339,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -27,6 +53,50 @@ pub struct TransferOperationDTO {
     pub input: TransferOperationInput,
     pub transfer_id: Option<UuidDTO>,
     pub fee: Option<candid::Nat>,
+    pub fee_estimate: Option<TransferFeeEstimateDTO>,
+    pub to_name: Option<String>,
+    pub screening: Option<TransferScreeningDTO>,
+    pub time_lock: Option<TransferTimeLockDTO>,
+    pub duplicate_warning: Option<TransferDuplicateWarningDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferDuplicateWarningDTO {
+    pub request_ids: Vec<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferTimeLockDTO {
+    pub delay_secs: u64,
+    pub canceller_group_ids: Vec<UuidDTO>,
+    pub unlocks_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferScreeningDTO {
+    pub canister_id: Principal,
+    pub blocks_approval: bool,
+    pub status: TransferScreeningStatusDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum TransferScreeningStatusDTO {
+    Pending {
+        attempts: u32,
+        last_error: Option<String>,
+    },
+    Unavailable {
+        error: String,
+    },
+    Passed {
+        reference: Option<String>,
+        screened_at: TimestampRfc3339,
+    },
+    Flagged {
+        reason: String,
+        reference: Option<String>,
+        screened_at: TimestampRfc3339,
+    },
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -64,6 +134,9 @@ pub struct TransferDTO {
     pub status: TransferStatusDTO,
     pub network: NetworkDTO,
     pub metadata: Vec<MetadataDTO>,
+    pub compliance: Option<TransferComplianceInfoDTO>,
+    pub annotation: Option<TransferAnnotationDTO>,
+    pub to_label: Option<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -97,9 +170,34 @@ pub struct TransferListItemDTO {
     pub to: String,
     pub amount: candid::Nat,
     pub created_at: TimestampRfc3339,
+    pub compliance: Option<TransferComplianceInfoDTO>,
+    pub annotation: Option<TransferAnnotationDTO>,
+    pub to_label: Option<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ListAccountTransfersResponse {
     pub transfers: Vec<TransferListItemDTO>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TransferAnnotationDTO {
+    pub external_invoice_id: Option<String>,
+    pub accounting_period: Option<String>,
+    pub notes: Option<String>,
+    pub annotated_by: UuidDTO,
+    pub annotated_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AnnotateTransferInput {
+    pub transfer_id: UuidDTO,
+    pub external_invoice_id: Option<String>,
+    pub accounting_period: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AnnotateTransferResponse {
+    pub annotation: TransferAnnotationDTO,
+}
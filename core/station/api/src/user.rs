@@ -22,6 +22,18 @@ pub struct UserDTO {
     pub status: UserStatusDTO,
     pub name: String,
     pub last_modification_timestamp: TimestampRfc3339,
+    pub version: u64,
+    pub scheduled_memberships: Vec<ScheduledGroupMembershipDTO>,
+    /// The last time the user was active on the station, not set if no activity was recorded yet.
+    #[serde(default)]
+    pub last_active_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ScheduledGroupMembershipDTO {
+    pub group_id: UuidDTO,
+    pub starts_at: Option<TimestampRfc3339>,
+    pub expires_at: Option<TimestampRfc3339>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -54,6 +66,7 @@ pub struct AddUserOperationInput {
     pub identities: Vec<Principal>,
     pub groups: Vec<String>,
     pub status: UserStatusDTO,
+    pub scheduled_memberships: Option<Vec<ScheduledGroupMembershipDTO>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -69,7 +82,9 @@ pub struct EditUserOperationInput {
     pub identities: Option<Vec<Principal>>,
     pub groups: Option<Vec<String>>,
     pub status: Option<UserStatusDTO>,
+    pub scheduled_memberships: Option<Vec<ScheduledGroupMembershipDTO>>,
     pub cancel_pending_requests: Option<bool>,
+    pub expected_version: Option<u64>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
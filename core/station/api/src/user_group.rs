@@ -1,4 +1,4 @@
-use crate::{PaginationInput, UuidDTO};
+use crate::{PaginationInput, ResourceDTO, UuidDTO};
 use candid::{CandidType, Deserialize};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -12,11 +12,22 @@ pub struct UserGroupCallerPrivilegesDTO {
 pub struct UserGroupDTO {
     pub id: UuidDTO,
     pub name: String,
+    pub quotas: UserGroupQuotasDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UserGroupQuotasDTO {
+    /// The maximum number of requests of the members of the group that can be pending at once.
+    pub max_pending_requests: Option<u32>,
+    /// The maximum number of accounts that the members of the group can create.
+    pub max_accounts: Option<u32>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct AddUserGroupOperationInput {
     pub name: String,
+    #[serde(default)]
+    pub quotas: Option<UserGroupQuotasDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -29,6 +40,8 @@ pub struct AddUserGroupOperationDTO {
 pub struct EditUserGroupOperationInput {
     pub user_group_id: UuidDTO,
     pub name: String,
+    #[serde(default)]
+    pub quotas: Option<UserGroupQuotasDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -39,11 +52,31 @@ pub struct EditUserGroupOperationDTO {
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct RemoveUserGroupOperationInput {
     pub user_group_id: UuidDTO,
+    /// Required if the group is still referenced by request policies, permissions or users.
+    pub reassignment: Option<UserGroupReassignmentDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub enum UserGroupReassignmentDTO {
+    /// The references are moved to the replacement group.
+    ReplaceWith { user_group_id: UuidDTO },
+    /// The references are dropped.
+    Detach,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct UserGroupReferencesDTO {
+    pub request_policy_ids: Vec<UuidDTO>,
+    pub permissions: Vec<ResourceDTO>,
+    pub user_ids: Vec<UuidDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct RemoveUserGroupOperationDTO {
     pub input: RemoveUserGroupOperationInput,
+    /// The entities that referenced the group when the request was created, or the ones that were
+    /// reassigned once it was executed.
+    pub affected: UserGroupReferencesDTO,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -70,3 +103,18 @@ pub struct ListUserGroupsResponse {
     pub total: u64,
     pub privileges: Vec<UserGroupCallerPrivilegesDTO>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UserGroupQuotaUsageDTO {
+    pub user_group_id: UuidDTO,
+    pub quotas: UserGroupQuotasDTO,
+    /// The number of requests of the members of the group that are pending.
+    pub pending_requests: u32,
+    /// The number of accounts that the members of the group created or requested.
+    pub accounts: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListUserGroupQuotaUsageResponse {
+    pub usage: Vec<UserGroupQuotaUsageDTO>,
+}
@@ -0,0 +1,47 @@
+//! Feeds arbitrary bytes into the decoders of the models that are kept in stable memory, which back their
+//! `Storable::from_bytes` implementations, to make sure that malformed entries surface as errors.
+//!
+//! Run with `cargo +nightly fuzz run stable_models_from_bytes` from `core/station/impl`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use orbit_essentials::model::TryFromStableBytes;
+use station::models::{
+    indexes::{
+        request_index::{RequestIndexFields, RequestIndexKey},
+        unique_index::UniqueIndexKey,
+    },
+    permission::Permission,
+    Account, AddressBookEntry, ArchivedRequest, Blob, EventSubscriber, ExternalCanister, Metadata,
+    Notification, PrincipalLabel, Request, RequestExecutionStep, RequestPolicy, SystemInfo,
+    Transfer, User, UserGroup, VoteReceipt,
+};
+
+fn decode<T: TryFromStableBytes>(bytes: &[u8]) {
+    let _ = T::try_from_bytes(bytes);
+}
+
+fuzz_target!(|bytes: &[u8]| {
+    decode::<Account>(bytes);
+    decode::<AddressBookEntry>(bytes);
+    decode::<ArchivedRequest>(bytes);
+    decode::<Blob>(bytes);
+    decode::<EventSubscriber>(bytes);
+    decode::<ExternalCanister>(bytes);
+    decode::<Metadata>(bytes);
+    decode::<Notification>(bytes);
+    decode::<Permission>(bytes);
+    decode::<PrincipalLabel>(bytes);
+    decode::<Request>(bytes);
+    decode::<RequestExecutionStep>(bytes);
+    decode::<RequestIndexFields>(bytes);
+    decode::<RequestIndexKey>(bytes);
+    decode::<RequestPolicy>(bytes);
+    decode::<SystemInfo>(bytes);
+    decode::<Transfer>(bytes);
+    decode::<UniqueIndexKey>(bytes);
+    decode::<User>(bytes);
+    decode::<UserGroup>(bytes);
+    decode::<VoteReceipt>(bytes);
+});
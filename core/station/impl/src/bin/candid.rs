@@ -0,0 +1,5 @@
+//! Prints the candid interface of the station as generated from its entrypoints.
+
+fn main() {
+    print!("{}", station::controllers::candid_interface());
+}
@@ -12,7 +12,12 @@ use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
     AccountCallerPrivilegesDTO, FetchAccountBalancesInput, FetchAccountBalancesResponse,
-    GetAccountInput, GetAccountResponse, ListAccountsInput, ListAccountsResponse,
+    GeneratePaymentRequestInput, GeneratePaymentRequestResponse, GetAccountInput,
+    GetAccountResponse, GetBalanceHistoryInput, GetBalanceHistoryResponse,
+    GetPortfolioSummaryInput, GetPortfolioSummaryResponse, ListAccountTransactionsInput,
+    ListAccountTransactionsResponse, ListAccountsInput, ListAccountsResponse,
+    SetAccountReviewInput, SetAccountReviewResponse, ValidateAccountCreationInput,
+    ValidateAccountCreationResponse,
 };
 
 // Canister entrypoints for the controller.
@@ -33,6 +38,46 @@ async fn fetch_account_balances(
     CONTROLLER.fetch_account_balances(input).await
 }
 
+#[update(name = "list_account_transactions")]
+async fn list_account_transactions(
+    input: ListAccountTransactionsInput,
+) -> ApiResult<ListAccountTransactionsResponse> {
+    CONTROLLER.list_account_transactions(input).await
+}
+
+#[query(name = "generate_payment_request")]
+async fn generate_payment_request(
+    input: GeneratePaymentRequestInput,
+) -> ApiResult<GeneratePaymentRequestResponse> {
+    CONTROLLER.generate_payment_request(input).await
+}
+
+#[query(name = "get_balance_history")]
+async fn get_balance_history(
+    input: GetBalanceHistoryInput,
+) -> ApiResult<GetBalanceHistoryResponse> {
+    CONTROLLER.get_balance_history(input).await
+}
+
+#[query(name = "get_portfolio_summary")]
+async fn get_portfolio_summary(
+    input: GetPortfolioSummaryInput,
+) -> ApiResult<GetPortfolioSummaryResponse> {
+    CONTROLLER.get_portfolio_summary(input).await
+}
+
+#[update(name = "set_account_review")]
+async fn set_account_review(input: SetAccountReviewInput) -> ApiResult<SetAccountReviewResponse> {
+    CONTROLLER.set_account_review(input).await
+}
+
+#[query(name = "validate_account_creation")]
+async fn validate_account_creation(
+    input: ValidateAccountCreationInput,
+) -> ApiResult<ValidateAccountCreationResponse> {
+    CONTROLLER.validate_account_creation(input).await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: AccountController = AccountController::new(AccountService::default());
@@ -103,4 +148,74 @@ impl AccountController {
 
         Ok(FetchAccountBalancesResponse { balances })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("list_account_transactions", &result))]
+    async fn list_account_transactions(
+        &self,
+        input: ListAccountTransactionsInput,
+    ) -> ApiResult<ListAccountTransactionsResponse> {
+        let response = self
+            .account_service
+            .list_account_transactions(input)
+            .await?;
+
+        Ok(response)
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn generate_payment_request(
+        &self,
+        input: GeneratePaymentRequestInput,
+    ) -> ApiResult<GeneratePaymentRequestResponse> {
+        let response = self.account_service.generate_payment_request(input)?;
+
+        Ok(response)
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn get_balance_history(
+        &self,
+        input: GetBalanceHistoryInput,
+    ) -> ApiResult<GetBalanceHistoryResponse> {
+        let response = self.account_service.get_balance_history(input)?;
+
+        Ok(response)
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::List)]))]
+    async fn get_portfolio_summary(
+        &self,
+        input: GetPortfolioSummaryInput,
+    ) -> ApiResult<GetPortfolioSummaryResponse> {
+        let ctx = call_context();
+        let response = self.account_service.get_portfolio_summary(input, &ctx)?;
+
+        Ok(response)
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("set_account_review", &result))]
+    async fn set_account_review(
+        &self,
+        input: SetAccountReviewInput,
+    ) -> ApiResult<SetAccountReviewResponse> {
+        let account = self
+            .account_service
+            .set_account_review(input, &call_context())?;
+
+        Ok(SetAccountReviewResponse {
+            account: account.to_dto(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::Create)]))]
+    async fn validate_account_creation(
+        &self,
+        input: ValidateAccountCreationInput,
+    ) -> ApiResult<ValidateAccountCreationResponse> {
+        let response = self.account_service.validate_account_creation(input)?;
+
+        Ok(response)
+    }
 }
@@ -0,0 +1,82 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    mappers::HelperMapper,
+    models::{
+        resource::{Resource, SystemResourceAction},
+        Blob,
+    },
+    services::{BlobService, BLOB_SERVICE},
+};
+use ic_cdk_macros::{query, update};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    ListBlobsResponse, RemoveBlobInput, RemoveBlobResponse, UploadBlobInput, UploadBlobResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[query(name = "list_blobs")]
+async fn list_blobs() -> ApiResult<ListBlobsResponse> {
+    CONTROLLER.list_blobs().await
+}
+
+#[update(name = "upload_blob")]
+async fn upload_blob(input: UploadBlobInput) -> ApiResult<UploadBlobResponse> {
+    CONTROLLER.upload_blob(input).await
+}
+
+#[update(name = "remove_blob")]
+async fn remove_blob(input: RemoveBlobInput) -> ApiResult<RemoveBlobResponse> {
+    CONTROLLER.remove_blob(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: BlobController = BlobController::new(Arc::clone(&BLOB_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct BlobController {
+    blob_service: Arc<BlobService>,
+}
+
+impl BlobController {
+    fn new(blob_service: Arc<BlobService>) -> Self {
+        Self { blob_service }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    async fn list_blobs(&self) -> ApiResult<ListBlobsResponse> {
+        Ok(ListBlobsResponse {
+            total_size_bytes: self.blob_service.total_size() as u64,
+            blobs: self
+                .blob_service
+                .list_blobs()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            max_size_bytes: Blob::MAX_SIZE_BYTES as u64,
+            max_total_size_bytes: Blob::MAX_TOTAL_SIZE_BYTES as u64,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("upload_blob", &result))]
+    async fn upload_blob(&self, input: UploadBlobInput) -> ApiResult<UploadBlobResponse> {
+        let blob = self.blob_service.upload_blob(input, &call_context())?;
+
+        Ok(UploadBlobResponse { blob: blob.into() })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("remove_blob", &result))]
+    async fn remove_blob(&self, input: RemoveBlobInput) -> ApiResult<RemoveBlobResponse> {
+        let blob = self
+            .blob_service
+            .remove_blob(HelperMapper::to_uuid(input.blob_id)?.as_bytes())?;
+
+        Ok(RemoveBlobResponse { blob: blob.into() })
+    }
+}
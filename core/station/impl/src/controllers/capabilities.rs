@@ -3,14 +3,18 @@ use crate::{
         middlewares::{authorize, call_context},
         read_system_info, ASSETS,
     },
-    models::resource::{Resource, SystemResourceAction},
+    models::{
+        resource::{Resource, SystemResourceAction},
+        system::OperationAvailability,
+    },
     SYSTEM_VERSION,
 };
 use ic_cdk_macros::query;
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
-use station_api::{CapabilitiesDTO, CapabilitiesResponse};
+use station_api::{AssetDTO, CapabilitiesDTO, CapabilitiesResponse, RequestOperationTypeDTO};
+use uuid::Uuid;
 
 #[query(name = "capabilities")]
 async fn capabilities() -> ApiResult<CapabilitiesResponse> {
@@ -34,12 +38,32 @@ impl CapabilitiesController {
     async fn capabilities(&self) -> ApiResult<CapabilitiesResponse> {
         let assets = ASSETS.with(|asset| asset.borrow().clone());
         let system = read_system_info();
+        let operations_of = |availability: OperationAvailability| -> Vec<RequestOperationTypeDTO> {
+            system
+                .get_operation_flags()
+                .iter()
+                .filter(|flag| flag.availability == availability)
+                .map(|flag| flag.operation_type.clone().into())
+                .collect()
+        };
+
+        let branding = system.get_branding();
+        let to_logo_id = |id| Uuid::from_bytes(id).hyphenated().to_string();
 
         Ok(CapabilitiesResponse {
             capabilities: CapabilitiesDTO {
                 name: system.get_name().to_string(),
                 version: SYSTEM_VERSION.to_string(),
-                supported_assets: assets.into_iter().map(|asset| asset.into()).collect(),
+                logo_id: branding.logo_id.map(to_logo_id),
+                supported_assets: assets
+                    .into_iter()
+                    .map(|asset| AssetDTO {
+                        logo_id: branding.find_asset_logo(&asset).map(to_logo_id),
+                        ..asset.into()
+                    })
+                    .collect(),
+                deprecated_operations: operations_of(OperationAvailability::Deprecated),
+                disabled_operations: operations_of(OperationAvailability::Disabled),
             },
         })
     }
@@ -9,8 +9,10 @@ use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    ExternalCanisterCallerPrivilegesDTO, GetExternalCanisterFiltersInput,
+    ExternalCanisterCallerPrivilegesDTO, FetchExternalCanisterLogsInput,
+    FetchExternalCanisterLogsResponse, GetExternalCanisterFiltersInput,
     GetExternalCanisterFiltersResponse, GetExternalCanisterInput, GetExternalCanisterResponse,
+    ListExternalCanisterSnapshotsInput, ListExternalCanisterSnapshotsResponse,
     ListExternalCanistersInput, ListExternalCanistersResponse,
 };
 use std::sync::Arc;
@@ -21,6 +23,20 @@ async fn canister_status(input: CanisterIdRecord) -> ApiResult<CanisterStatusRes
     CONTROLLER.canister_status(input).await
 }
 
+#[update(name = "list_external_canister_snapshots")]
+async fn list_external_canister_snapshots(
+    input: ListExternalCanisterSnapshotsInput,
+) -> ApiResult<ListExternalCanisterSnapshotsResponse> {
+    CONTROLLER.list_external_canister_snapshots(input).await
+}
+
+#[update(name = "fetch_external_canister_logs")]
+async fn fetch_external_canister_logs(
+    input: FetchExternalCanisterLogsInput,
+) -> ApiResult<FetchExternalCanisterLogsResponse> {
+    CONTROLLER.fetch_external_canister_logs(input).await
+}
+
 #[query(name = "get_external_canister")]
 async fn get_external_canister(
     input: GetExternalCanisterInput,
@@ -63,6 +79,36 @@ impl ExternalCanisterController {
         self.canister_service.canister_status(input).await
     }
 
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::ExternalCanister(ExternalCanisterResourceAction::Read(ExternalCanisterId::Canister(input.canister_id)))]))]
+    async fn list_external_canister_snapshots(
+        &self,
+        input: ListExternalCanisterSnapshotsInput,
+    ) -> ApiResult<ListExternalCanisterSnapshotsResponse> {
+        let snapshots = self
+            .canister_service
+            .list_canister_snapshots(input.canister_id)
+            .await?;
+
+        Ok(ListExternalCanisterSnapshotsResponse {
+            snapshots: snapshots.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::ExternalCanister(ExternalCanisterResourceAction::Change(ExternalCanisterId::Canister(input.canister_id)))]))]
+    async fn fetch_external_canister_logs(
+        &self,
+        input: FetchExternalCanisterLogsInput,
+    ) -> ApiResult<FetchExternalCanisterLogsResponse> {
+        let logs = self
+            .canister_service
+            .fetch_canister_logs(input.canister_id, input.retain.unwrap_or(false))
+            .await?;
+
+        Ok(FetchExternalCanisterLogsResponse {
+            logs: logs.into_iter().map(Into::into).collect(),
+        })
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::ExternalCanister(ExternalCanisterResourceAction::Read(ExternalCanisterId::Canister(input.canister_id)))]))]
     async fn get_external_canister(
         &self,
@@ -1,29 +1,52 @@
-use crate::{core::ic_cdk::api::canister_balance, SERVICE_NAME};
+use crate::{
+    core::{certification::http_witness, ic_cdk::api::canister_balance, outcalls},
+    mappers::HelperMapper,
+    services::{BlobService, BLOB_SERVICE},
+    SERVICE_NAME,
+};
 use ic_cdk_macros::query;
 use lazy_static::lazy_static;
 use orbit_essentials::api::{HeaderField, HttpRequest, HttpResponse};
-use orbit_essentials::http::add_skip_certification_headers;
+use orbit_essentials::cdk::api::management_canister::http_request::{
+    HttpResponse as OutcallHttpResponse, TransformArgs,
+};
+use orbit_essentials::http::add_skip_certification_headers_with_witness;
 use orbit_essentials::metrics::with_metrics_registry;
+use std::sync::Arc;
 
 // Canister entrypoints for the controller.
 #[query(name = "http_request", decoding_quota = 10000)]
 async fn http_request(request: HttpRequest) -> HttpResponse {
     let mut resp = CONTROLLER.router(request).await;
-    add_skip_certification_headers(&mut resp);
+    add_skip_certification_headers_with_witness(&mut resp, &http_witness());
     resp
 }
 
+// Strips the parts of the responses of the outcalls of the station that differ between the replicas.
+#[query(name = "transform_outcall_response")]
+fn transform_outcall_response(args: TransformArgs) -> OutcallHttpResponse {
+    outcalls::transform(args)
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: HttpController = HttpController::new();
+    static ref CONTROLLER: HttpController = HttpController::new(Arc::clone(&BLOB_SERVICE));
 }
 
 #[derive(Debug)]
-pub struct HttpController {}
+pub struct HttpController {
+    blob_service: Arc<BlobService>,
+}
 
 impl HttpController {
-    fn new() -> Self {
-        Self {}
+    /// Blobs never change, so they can be cached for as long as browsers and boundary nodes allow.
+    const BLOB_CACHE_CONTROL: &'static str = "public, max-age=31536000, immutable";
+    /// Keeps the SVG blobs from running scripts when they are opened directly from the station domain.
+    const BLOB_CONTENT_SECURITY_POLICY: &'static str =
+        "default-src 'none'; style-src 'unsafe-inline'";
+
+    fn new(blob_service: Arc<BlobService>) -> Self {
+        Self { blob_service }
     }
 
     async fn router(&self, request: HttpRequest) -> HttpResponse {
@@ -31,6 +54,11 @@ impl HttpController {
             return self.metrics(request).await;
         }
 
+        let path = request.url.split('?').next().unwrap_or_default();
+        if let Some(blob_id) = path.strip_prefix("/blobs/") {
+            return self.blob(&request, blob_id);
+        }
+
         return HttpResponse {
             status_code: 404,
             headers: vec![HeaderField("Content-Type".into(), "text/plain".into())],
@@ -38,6 +66,62 @@ impl HttpController {
         };
     }
 
+    /// Serves the blob with cache headers, the blobs are public since they only hold branding images.
+    fn blob(&self, request: &HttpRequest, blob_id: &str) -> HttpResponse {
+        if request.method.to_lowercase() != "get" {
+            return HttpResponse {
+                status_code: 405,
+                headers: vec![HeaderField("Allow".into(), "GET".into())],
+                body: "405 Method Not Allowed".as_bytes().to_owned(),
+            };
+        }
+
+        let Some(blob) = HelperMapper::to_uuid(blob_id.to_string())
+            .ok()
+            .and_then(|id| self.blob_service.get_blob(id.as_bytes()).ok())
+        else {
+            return HttpResponse {
+                status_code: 404,
+                headers: vec![HeaderField("Content-Type".into(), "text/plain".into())],
+                body: "404 Not Found".as_bytes().to_owned(),
+            };
+        };
+
+        let etag = blob.etag();
+        let mut headers = vec![
+            HeaderField("Cache-Control".into(), Self::BLOB_CACHE_CONTROL.into()),
+            HeaderField("ETag".into(), etag.clone()),
+        ];
+
+        let is_cached = request.headers.iter().any(|HeaderField(name, value)| {
+            name.eq_ignore_ascii_case("if-none-match")
+                && value.split(',').any(|tag| tag.trim() == etag)
+        });
+        if is_cached {
+            return HttpResponse {
+                status_code: 304,
+                headers,
+                body: Vec::new(),
+            };
+        }
+
+        headers.extend([
+            HeaderField("Content-Type".into(), blob.content_type),
+            HeaderField("Content-Length".into(), blob.content.len().to_string()),
+            HeaderField("X-Content-Type-Options".into(), "nosniff".into()),
+            HeaderField(
+                "Content-Security-Policy".into(),
+                Self::BLOB_CONTENT_SECURITY_POLICY.into(),
+            ),
+        ]);
+
+        HttpResponse {
+            status_code: 200,
+            headers,
+            body: blob.content,
+        }
+    }
+
     async fn metrics(&self, request: HttpRequest) -> HttpResponse {
         if request.method.to_lowercase() != "get" {
             return HttpResponse {
@@ -0,0 +1,26 @@
+//! The candid interface of the canister, which is generated from its entrypoints so that the interface that
+//! is published for the tools can't drift from the one that the canister serves.
+//!
+//! This module must be declared after all the other entrypoints, since the generated interface only includes
+//! the entrypoints that were expanded before it.
+
+use orbit_essentials::api::*;
+use orbit_essentials::cdk::api::management_canister::main::{
+    CanisterIdRecord, CanisterStatusResponse,
+};
+use station_api::*;
+
+candid::export_service!();
+
+/// Returns the candid interface of the canister as generated from its entrypoints.
+pub fn candid_interface() -> String {
+    __export_service()
+}
+
+/// Exposes the generated interface to the tools that fetch it from the canister, the method is exported
+/// without the query macro so that it is not part of the interface itself.
+#[cfg(target_arch = "wasm32")]
+#[export_name = "canister_query __get_candid_interface_tmp_hack"]
+fn get_candid_interface_tmp_hack() {
+    ic_cdk::api::call::reply((candid_interface(),));
+}
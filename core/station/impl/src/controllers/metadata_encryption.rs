@@ -0,0 +1,62 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    models::resource::{RequestResourceAction, Resource, ResourceAction},
+    services::{MetadataEncryptionService, METADATA_ENCRYPTION_SERVICE},
+};
+use ic_cdk_macros::update;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{GetMetadataEncryptionKeyInput, GetMetadataEncryptionKeyResponse};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "get_metadata_encryption_key")]
+async fn get_metadata_encryption_key(
+    input: GetMetadataEncryptionKeyInput,
+) -> ApiResult<GetMetadataEncryptionKeyResponse> {
+    CONTROLLER.get_metadata_encryption_key(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: MetadataEncryptionController =
+        MetadataEncryptionController::new(Arc::clone(&METADATA_ENCRYPTION_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct MetadataEncryptionController {
+    metadata_encryption_service: Arc<MetadataEncryptionService>,
+}
+
+impl MetadataEncryptionController {
+    fn new(metadata_encryption_service: Arc<MetadataEncryptionService>) -> Self {
+        Self {
+            metadata_encryption_service,
+        }
+    }
+
+    /// Returns the key that encrypts the sensitive metadata, it is only handed out to the users that
+    /// can read both the address book and the requests since the metadata is part of them.
+    ///
+    /// The auditors pass the authorization of the read-only resources, but they are refused by the service.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::AddressBook(ResourceAction::List), Resource::Request(RequestResourceAction::List)]))]
+    #[with_middleware(tail = use_canister_call_metric("get_metadata_encryption_key", &result))]
+    async fn get_metadata_encryption_key(
+        &self,
+        input: GetMetadataEncryptionKeyInput,
+    ) -> ApiResult<GetMetadataEncryptionKeyResponse> {
+        let ctx = call_context();
+        let key = self
+            .metadata_encryption_service
+            .get_encryption_key(input.transport_public_key, &ctx)
+            .await?;
+
+        Ok(GetMetadataEncryptionKeyResponse {
+            encrypted_key: key.encrypted_key,
+            public_key: key.public_key,
+            context: key.context,
+            input: key.input,
+        })
+    }
+}
@@ -9,6 +9,9 @@ pub use system::*;
 mod capabilities;
 pub use capabilities::*;
 
+mod signer_standards;
+pub use signer_standards::*;
+
 mod external_canister;
 pub use external_canister::*;
 
@@ -42,23 +45,32 @@ pub use user_group::*;
 mod http;
 pub use http::*;
 
+mod station_event;
+pub use station_event::*;
+
+mod metadata_encryption;
+pub use metadata_encryption::*;
+
+mod principal_label;
+pub use principal_label::*;
+
+mod blob;
+pub use blob::*;
+
+// Must stay the last entrypoint module, see the module docs.
+mod interface;
+pub use interface::candid_interface;
+
 #[cfg(test)]
 mod tests {
-    use orbit_essentials::api::*;
-    use station_api::*;
+    use super::candid_interface;
 
     #[test]
     fn check_candid_interface() {
         use candid_parser::utils::{service_equal, CandidSource};
-        use orbit_essentials::cdk::api::management_canister::main::{
-            CanisterIdRecord, CanisterStatusResponse,
-        };
-
-        candid::export_service!();
-        let new_interface = __export_service();
 
         service_equal(
-            CandidSource::Text(&new_interface),
+            CandidSource::Text(&candid_interface()),
             CandidSource::Text(include_str!("../../../api/spec.did")),
         )
         .unwrap();
@@ -1,16 +1,19 @@
 use crate::{
     core::middlewares::{authorize, call_context, use_canister_call_metric},
+    log_warn,
     mappers::authorization::MarkNotificationsReadInputRef,
     mappers::notification::NotificationMapperError,
-    models::resource::Resource,
+    models::resource::{NotificationResourceAction, Resource},
     services::NotificationService,
 };
 use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
-use orbit_essentials::{api::ApiResult, cdk::api::print};
 use station_api::{
-    ListNotificationsInput, ListNotificationsResponse, MarkNotificationsReadInput, NotificationDTO,
+    GetNotificationBridgeResponse, ListNotificationsInput, ListNotificationsResponse,
+    MarkNotificationsReadInput, NotificationDTO, SetNotificationBridgeInput,
+    SetNotificationBridgeResponse,
 };
 use uuid::Uuid;
 
@@ -25,6 +28,18 @@ async fn mark_notifications_read(input: MarkNotificationsReadInput) -> ApiResult
     CONTROLLER.mark_notifications_read(input).await
 }
 
+#[update(name = "set_notification_bridge")]
+async fn set_notification_bridge(
+    input: SetNotificationBridgeInput,
+) -> ApiResult<SetNotificationBridgeResponse> {
+    CONTROLLER.set_notification_bridge(input).await
+}
+
+#[query(name = "get_notification_bridge")]
+async fn get_notification_bridge() -> ApiResult<GetNotificationBridgeResponse> {
+    CONTROLLER.get_notification_bridge().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: NotificationController =
@@ -57,15 +72,15 @@ impl NotificationController {
                     Ok(notification_dto) => acc.push(notification_dto),
                     Err(error) => match error {
                         NotificationMapperError::RequestNotFound { request_id } => {
-                            print(format!(
+                            log_warn!(
                                 "Request {} not found when mapping to NotificationDTO",
                                 Uuid::from_bytes(request_id).hyphenated()
-                            ));
+                            );
                         }
                         NotificationMapperError::InvalidRequestStatus {
                             expected, found
                         } => {
-                            print(format!("Invalid request status when mapping to NotificationDTO: expected \"{}\", found \"{}\"", expected, found));
+                            log_warn!("Invalid request status when mapping to NotificationDTO: expected \"{}\", found \"{}\"", expected, found);
                         }
                     },
                 }
@@ -79,8 +94,48 @@ impl NotificationController {
     #[with_middleware(guard = authorize(&call_context(), &MarkNotificationsReadInputRef(&input).to_resources()))]
     #[with_middleware(tail = use_canister_call_metric("mark_notifications_read", &result))]
     async fn mark_notifications_read(&self, input: MarkNotificationsReadInput) -> ApiResult<()> {
-        self.notification_service.mark_read(input).await?;
+        self.notification_service
+            .mark_read(input, &call_context())
+            .await?;
 
         Ok(())
     }
+    /// Every user can manage the inbox of their own notifications.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Notification(NotificationResourceAction::List)]))]
+    #[with_middleware(tail = use_canister_call_metric("set_notification_bridge", &result))]
+    async fn set_notification_bridge(
+        &self,
+        input: SetNotificationBridgeInput,
+    ) -> ApiResult<SetNotificationBridgeResponse> {
+        let ctx = call_context();
+        let bridge = self
+            .notification_service
+            .set_notification_bridge(input, &ctx)?
+            .map(|bridge| {
+                let pending_notifications = self
+                    .notification_service
+                    .count_pending_notifications(&bridge);
+
+                bridge.to_dto(pending_notifications)
+            });
+
+        Ok(SetNotificationBridgeResponse { bridge })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Notification(NotificationResourceAction::List)]))]
+    async fn get_notification_bridge(&self) -> ApiResult<GetNotificationBridgeResponse> {
+        let ctx = call_context();
+        let bridge = self
+            .notification_service
+            .get_notification_bridge(&ctx)?
+            .map(|bridge| {
+                let pending_notifications = self
+                    .notification_service
+                    .count_pending_notifications(&bridge);
+
+                bridge.to_dto(pending_notifications)
+            });
+
+        Ok(GetNotificationBridgeResponse { bridge })
+    }
 }
@@ -0,0 +1,119 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    models::resource::{Resource, SystemResourceAction},
+    services::{PrincipalLabelService, PRINCIPAL_LABEL_SERVICE},
+};
+use ic_cdk_macros::{query, update};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    ImportPrincipalLabelsInput, ImportPrincipalLabelsResponse, ListPrincipalLabelsInput,
+    ListPrincipalLabelsResponse, RemovePrincipalLabelInput, RemovePrincipalLabelResponse,
+    SetPrincipalLabelInput, SetPrincipalLabelResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[query(name = "list_principal_labels")]
+async fn list_principal_labels(
+    input: ListPrincipalLabelsInput,
+) -> ApiResult<ListPrincipalLabelsResponse> {
+    CONTROLLER.list_principal_labels(input).await
+}
+
+#[update(name = "set_principal_label")]
+async fn set_principal_label(
+    input: SetPrincipalLabelInput,
+) -> ApiResult<SetPrincipalLabelResponse> {
+    CONTROLLER.set_principal_label(input).await
+}
+
+#[update(name = "remove_principal_label")]
+async fn remove_principal_label(
+    input: RemovePrincipalLabelInput,
+) -> ApiResult<RemovePrincipalLabelResponse> {
+    CONTROLLER.remove_principal_label(input).await
+}
+
+#[update(name = "import_principal_labels")]
+async fn import_principal_labels(
+    input: ImportPrincipalLabelsInput,
+) -> ApiResult<ImportPrincipalLabelsResponse> {
+    CONTROLLER.import_principal_labels(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: PrincipalLabelController =
+        PrincipalLabelController::new(Arc::clone(&PRINCIPAL_LABEL_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct PrincipalLabelController {
+    principal_label_service: Arc<PrincipalLabelService>,
+}
+
+impl PrincipalLabelController {
+    fn new(principal_label_service: Arc<PrincipalLabelService>) -> Self {
+        Self {
+            principal_label_service,
+        }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    async fn list_principal_labels(
+        &self,
+        input: ListPrincipalLabelsInput,
+    ) -> ApiResult<ListPrincipalLabelsResponse> {
+        let result = self.principal_label_service.list_labels(input)?;
+
+        Ok(ListPrincipalLabelsResponse {
+            labels: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("set_principal_label", &result))]
+    async fn set_principal_label(
+        &self,
+        input: SetPrincipalLabelInput,
+    ) -> ApiResult<SetPrincipalLabelResponse> {
+        let label = self
+            .principal_label_service
+            .set_label(input, &call_context())?;
+
+        Ok(SetPrincipalLabelResponse {
+            label: label.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("remove_principal_label", &result))]
+    async fn remove_principal_label(
+        &self,
+        input: RemovePrincipalLabelInput,
+    ) -> ApiResult<RemovePrincipalLabelResponse> {
+        let label = self.principal_label_service.remove_label(&input.address)?;
+
+        Ok(RemovePrincipalLabelResponse {
+            label: label.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("import_principal_labels", &result))]
+    async fn import_principal_labels(
+        &self,
+        input: ImportPrincipalLabelsInput,
+    ) -> ApiResult<ImportPrincipalLabelsResponse> {
+        let response = self
+            .principal_label_service
+            .import_labels(input.list_canister_id)
+            .await?;
+
+        Ok(response)
+    }
+}
@@ -1,10 +1,15 @@
 use crate::{
-    core::ic_cdk::api::time,
+    core::certification::{is_votes_tree_complete, vote_witness},
+    core::ic_cdk::api::{data_certificate, time},
     core::limiter::Limiter,
     core::middlewares::{authorize, call_context, use_canister_call_metric},
     core::CallContext,
     errors::RequestError,
-    mappers::HelperMapper,
+    mappers::{
+        api_version::ApiVersionMapper,
+        request_canonical::{REQUEST_CANONICAL_ENCODING_VERSION, VOTE_RECEIPT_ENCODING_VERSION},
+        HelperMapper,
+    },
     models::rate_limiter::{RequestRateLimiterKey, RequestRateLimiterSize},
     models::resource::{RequestResourceAction, Resource},
     services::{RequestService, REQUEST_SERVICE},
@@ -14,9 +19,12 @@ use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    CreateRequestInput, CreateRequestResponse, GetNextApprovableRequestInput,
-    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse, ListRequestsInput,
-    ListRequestsResponse, RequestAdditionalInfoDTO, RequestCallerPrivilegesDTO,
+    CancelTimeLockedRequestInput, CancelTimeLockedRequestResponse, CreateRequestInput,
+    CreateRequestResponse, GetNextApprovableRequestInput, GetNextApprovableRequestResponse,
+    GetRequestCanonicalInput, GetRequestCanonicalResponse, GetRequestInput, GetRequestResponse,
+    GetVoteCertificateInput, GetVoteCertificateResponse, ListRequestsInput, ListRequestsResponse,
+    RequestAdditionalInfoDTO, RequestCallerPrivilegesDTO, RescheduleRequestInput,
+    RescheduleRequestResponse, RetryRequestExecutionInput, RetryRequestExecutionResponse,
     SubmitRequestApprovalInput, SubmitRequestApprovalResponse,
 };
 use std::cell::RefCell;
@@ -35,6 +43,26 @@ async fn get_request(input: GetRequestInput) -> ApiResult<GetRequestResponse> {
     CONTROLLER.get_request(input).await
 }
 
+// A composite query, since the archived requests are fetched from the archive canister.
+#[query(name = "get_archived_request", composite = true)]
+async fn get_archived_request(input: GetRequestInput) -> ApiResult<GetRequestResponse> {
+    CONTROLLER.get_archived_request(input).await
+}
+
+#[query(name = "get_request_canonical")]
+async fn get_request_canonical(
+    input: GetRequestCanonicalInput,
+) -> ApiResult<GetRequestCanonicalResponse> {
+    CONTROLLER.get_request_canonical(input).await
+}
+
+#[query(name = "get_vote_certificate")]
+async fn get_vote_certificate(
+    input: GetVoteCertificateInput,
+) -> ApiResult<GetVoteCertificateResponse> {
+    CONTROLLER.get_vote_certificate(input).await
+}
+
 #[query(name = "get_next_approvable_request")]
 async fn get_next_approvable_request(
     input: GetNextApprovableRequestInput,
@@ -49,6 +77,25 @@ async fn submit_request_approval(
     CONTROLLER.submit_request_approval(input).await
 }
 
+#[update(name = "retry_request_execution")]
+async fn retry_request_execution(
+    input: RetryRequestExecutionInput,
+) -> ApiResult<RetryRequestExecutionResponse> {
+    CONTROLLER.retry_request_execution(input).await
+}
+
+#[update(name = "reschedule_request")]
+async fn reschedule_request(input: RescheduleRequestInput) -> ApiResult<RescheduleRequestResponse> {
+    CONTROLLER.reschedule_request(input).await
+}
+
+#[update(name = "cancel_time_locked_request")]
+async fn cancel_time_locked_request(
+    input: CancelTimeLockedRequestInput,
+) -> ApiResult<CancelTimeLockedRequestResponse> {
+    CONTROLLER.cancel_time_locked_request(input).await
+}
+
 #[update(name = "create_request")]
 async fn create_request(input: CreateRequestInput) -> ApiResult<CreateRequestResponse> {
     CONTROLLER.create_request(input).await
@@ -156,6 +203,7 @@ impl RequestController {
     #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
     async fn get_request(&self, input: GetRequestInput) -> ApiResult<GetRequestResponse> {
         let ctx = &call_context();
+        let api_version = ApiVersionMapper::resolve(input.api_version)?;
         let request = self
             .request_service
             .get_request(HelperMapper::to_uuid(input.request_id)?.as_bytes())?;
@@ -168,12 +216,77 @@ impl RequestController {
             .get_request_additional_info(&request, true)?;
 
         Ok(GetRequestResponse {
-            request: request.to_dto(),
+            request: ApiVersionMapper::to_request_dto(request, api_version)?,
             privileges: privileges.into(),
             additional_info: additional_info.into(),
         })
     }
 
+    /// Returns the archived request from the archive canister, it can no longer be approved or cancelled.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn get_archived_request(&self, input: GetRequestInput) -> ApiResult<GetRequestResponse> {
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let archived = self
+            .request_service
+            .find_archived_request(request_id.as_bytes())
+            .await?
+            .ok_or(RequestError::NotFound {
+                request_id: request_id.hyphenated().to_string(),
+            })?;
+
+        Ok(GetRequestResponse {
+            privileges: RequestCallerPrivilegesDTO {
+                id: archived.request.id.clone(),
+                can_approve: false,
+                can_cancel: false,
+            },
+            request: archived.request,
+            additional_info: archived.additional_info,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn get_request_canonical(
+        &self,
+        input: GetRequestCanonicalInput,
+    ) -> ApiResult<GetRequestCanonicalResponse> {
+        let (canonical_json, hash) = self
+            .request_service
+            .get_request_canonical(HelperMapper::to_uuid(input.request_id)?.as_bytes())?;
+
+        Ok(GetRequestCanonicalResponse {
+            canonical_json,
+            sha256: hex::encode(hash),
+            encoding_version: REQUEST_CANONICAL_ENCODING_VERSION,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn get_vote_certificate(
+        &self,
+        input: GetVoteCertificateInput,
+    ) -> ApiResult<GetVoteCertificateResponse> {
+        let receipt = self.request_service.get_vote_receipt(
+            HelperMapper::to_uuid(input.request_id)?.as_bytes(),
+            HelperMapper::to_uuid(input.approver_id)?.as_bytes(),
+        )?;
+        // The witness could not prove the vote until the tree of the votes is rebuilt after an upgrade.
+        if !is_votes_tree_complete() {
+            return Err(RequestError::VoteCertificationPending.into());
+        }
+        // The certificate is only available in query calls.
+        let certificate = data_certificate().ok_or(RequestError::CertificateUnavailable)?;
+        let witness = orbit_essentials::http::cbor_encode(&vote_witness(&receipt.to_key()));
+
+        Ok(GetVoteCertificateResponse {
+            canonical_json: receipt.to_canonical_json(),
+            encoding_version: VOTE_RECEIPT_ENCODING_VERSION,
+            receipt: receipt.into(),
+            certificate,
+            witness,
+        })
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::Request(RequestResourceAction::List)]))]
     async fn list_requests(&self, input: ListRequestsInput) -> ApiResult<ListRequestsResponse> {
         let ctx = call_context();
@@ -262,4 +375,79 @@ impl RequestController {
             additional_info: additional_info.into(),
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("retry_request_execution", &result))]
+    async fn retry_request_execution(
+        &self,
+        input: RetryRequestExecutionInput,
+    ) -> ApiResult<RetryRequestExecutionResponse> {
+        let ctx = &call_context();
+        let request = self
+            .request_service
+            .retry_request_execution(input, ctx)
+            .await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(RetryRequestExecutionResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("reschedule_request", &result))]
+    async fn reschedule_request(
+        &self,
+        input: RescheduleRequestInput,
+    ) -> ApiResult<RescheduleRequestResponse> {
+        let ctx = &call_context();
+        let request = self.request_service.reschedule_request(input, ctx).await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(RescheduleRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("cancel_time_locked_request", &result))]
+    async fn cancel_time_locked_request(
+        &self,
+        input: CancelTimeLockedRequestInput,
+    ) -> ApiResult<CancelTimeLockedRequestResponse> {
+        let ctx = &call_context();
+        let request = self
+            .request_service
+            .cancel_time_locked_request(input, ctx)
+            .await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true)?;
+
+        Ok(CancelTimeLockedRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
 }
@@ -9,8 +9,8 @@ use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetRequestPolicyInput, GetRequestPolicyResponse, ListRequestPoliciesInput,
-    ListRequestPoliciesResponse, RequestPolicyCallerPrivilegesDTO,
+    GetRequestPolicyInput, GetRequestPolicyResponse, LintRequestPoliciesResponse,
+    ListRequestPoliciesInput, ListRequestPoliciesResponse, RequestPolicyCallerPrivilegesDTO,
 };
 use std::sync::Arc;
 
@@ -27,6 +27,11 @@ async fn list_request_policies(
     CONTROLLER.list_request_policies(input).await
 }
 
+#[query(name = "lint_request_policies")]
+async fn lint_request_policies() -> ApiResult<LintRequestPoliciesResponse> {
+    CONTROLLER.lint_request_policies().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: RequestPolicyController =
@@ -90,4 +95,13 @@ impl RequestPolicyController {
             privileges,
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::RequestPolicy(ResourceAction::List)]))]
+    async fn lint_request_policies(&self) -> ApiResult<LintRequestPoliciesResponse> {
+        let issues = self.request_policy_service.lint_request_policies();
+
+        Ok(LintRequestPoliciesResponse {
+            issues: issues.into_iter().map(Into::into).collect(),
+        })
+    }
 }
@@ -0,0 +1,73 @@
+use crate::{
+    core::middlewares::{authorize, call_context},
+    models::resource::{AccountResourceAction, Resource},
+    services::{AccountService, ACCOUNT_SERVICE},
+};
+use ic_cdk_macros::query;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{Icrc27AccountsResponse, SupportedStandardDTO};
+use std::sync::Arc;
+
+/// The signer standards that are implemented by the station.
+const SUPPORTED_STANDARDS: [(&str, &str); 2] = [
+    (
+        "ICRC-25",
+        "https://github.com/dfinity/wg-identity-authentication/blob/main/topics/icrc_25_signer_interaction_standard.md",
+    ),
+    (
+        "ICRC-27",
+        "https://github.com/dfinity/wg-identity-authentication/blob/main/topics/icrc_27_accounts.md",
+    ),
+];
+
+#[query(name = "icrc25_supported_standards")]
+async fn icrc25_supported_standards() -> Vec<SupportedStandardDTO> {
+    CONTROLLER.icrc25_supported_standards().await
+}
+
+#[query(name = "icrc27_accounts")]
+async fn icrc27_accounts() -> ApiResult<Icrc27AccountsResponse> {
+    CONTROLLER.icrc27_accounts().await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: SignerStandardsController =
+        SignerStandardsController::new(Arc::clone(&ACCOUNT_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct SignerStandardsController {
+    account_service: Arc<AccountService>,
+}
+
+impl SignerStandardsController {
+    fn new(account_service: Arc<AccountService>) -> Self {
+        Self { account_service }
+    }
+
+    async fn icrc25_supported_standards(&self) -> Vec<SupportedStandardDTO> {
+        SUPPORTED_STANDARDS
+            .iter()
+            .map(|(name, url)| SupportedStandardDTO {
+                name: name.to_string(),
+                url: url.to_string(),
+            })
+            .collect()
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::List)]))]
+    async fn icrc27_accounts(&self) -> ApiResult<Icrc27AccountsResponse> {
+        let ctx = call_context();
+        let accounts = self.account_service.list_icrc1_accounts(&ctx);
+
+        Ok(Icrc27AccountsResponse {
+            accounts: accounts
+                .iter()
+                .map(|account| account.to_icrc1_account_dto())
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    models::resource::{Resource, SystemResourceAction},
+    services::{StationEventService, STATION_EVENT_SERVICE},
+};
+use ic_cdk_macros::{query, update};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    ListEventSubscribersResponse, SubscribeToEventsInput, SubscribeToEventsResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "subscribe_to_events")]
+async fn subscribe_to_events(
+    input: SubscribeToEventsInput,
+) -> ApiResult<SubscribeToEventsResponse> {
+    CONTROLLER.subscribe_to_events(input).await
+}
+
+#[update(name = "unsubscribe_from_events")]
+async fn unsubscribe_from_events() -> ApiResult<()> {
+    CONTROLLER.unsubscribe_from_events().await
+}
+
+#[query(name = "list_event_subscribers")]
+async fn list_event_subscribers() -> ApiResult<ListEventSubscribersResponse> {
+    CONTROLLER.list_event_subscribers().await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: StationEventController =
+        StationEventController::new(Arc::clone(&STATION_EVENT_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct StationEventController {
+    station_event_service: Arc<StationEventService>,
+}
+
+impl StationEventController {
+    fn new(station_event_service: Arc<StationEventService>) -> Self {
+        Self {
+            station_event_service,
+        }
+    }
+
+    /// Subscribes the calling canister to the station events.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("subscribe_to_events", &result))]
+    async fn subscribe_to_events(
+        &self,
+        input: SubscribeToEventsInput,
+    ) -> ApiResult<SubscribeToEventsResponse> {
+        let subscriber = self
+            .station_event_service
+            .subscribe(
+                call_context().caller(),
+                input.method_name,
+                input.events.into_iter().map(Into::into).collect(),
+            )
+            .await?;
+        let pending_events = self.station_event_service.count_pending_events(&subscriber);
+
+        Ok(SubscribeToEventsResponse {
+            subscriber: subscriber.to_dto(pending_events),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    #[with_middleware(tail = use_canister_call_metric("unsubscribe_from_events", &result))]
+    async fn unsubscribe_from_events(&self) -> ApiResult<()> {
+        self.station_event_service
+            .unsubscribe(call_context().caller())?;
+
+        Ok(())
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    async fn list_event_subscribers(&self) -> ApiResult<ListEventSubscribersResponse> {
+        let subscribers = self
+            .station_event_service
+            .list_subscribers()
+            .into_iter()
+            .map(|subscriber| {
+                let pending_events = self.station_event_service.count_pending_events(&subscriber);
+
+                subscriber.to_dto(pending_events)
+            })
+            .collect();
+
+        Ok(ListEventSubscribersResponse { subscribers })
+    }
+}
@@ -1,33 +1,39 @@
 use crate::{
     core::{
-        ic_cdk::api::{canister_balance, set_certified_data, trap},
+        certification::init_certified_data,
+        ic_cdk::api::{canister_balance, trap},
         middlewares::{authorize, call_context},
+        usage::USAGE_TIME_WINDOW,
+        CallContext,
     },
     errors::AuthorizationError,
+    mappers::HelperMapper,
     migration,
-    models::resource::{Resource, SystemResourceAction},
-    services::{SystemService, SYSTEM_SERVICE},
+    models::{
+        resource::{Resource, SystemResourceAction},
+        ADMIN_GROUP_ID,
+    },
+    services::{SystemService, UsageService, SYSTEM_SERVICE, USAGE_SERVICE},
     SYSTEM_VERSION,
 };
 use ic_cdk_macros::{post_upgrade, query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
-use orbit_essentials::http::certified_data_for_skip_certification;
 use orbit_essentials::with_middleware;
 use station_api::{
-    HealthStatus, NotifyFailedStationUpgradeInput, SystemInfoResponse, SystemInstall, SystemUpgrade,
+    GetInitStatusResponse, GetTraceInput, GetTraceResponse, HealthStatus,
+    ListPrincipalUsageResponse, ListStaleApproversInput, ListStaleApproversResponse,
+    ListStationLogsInput, ListStationLogsResponse, NotifyFailedStationUpgradeInput,
+    SystemInfoResponse, SystemInstall, SystemUpgrade, TraceDTO,
 };
 use std::sync::Arc;
-
-fn set_certified_data_for_skip_certification() {
-    set_certified_data(&certified_data_for_skip_certification());
-}
+use uuid::Uuid;
 
 // Canister entrypoints for the controller.
 #[cfg(any(not(feature = "canbench"), test))]
 #[ic_cdk_macros::init]
 async fn initialize(input: Option<SystemInstall>) {
-    set_certified_data_for_skip_certification();
+    init_certified_data();
     match input {
         Some(SystemInstall::Init(input)) => CONTROLLER.initialize(input).await,
         Some(SystemInstall::Upgrade(_)) | None => trap("Invalid args to initialize canister"),
@@ -64,7 +70,8 @@ async fn post_upgrade(input: Option<SystemInstall>) {
     // datatype from the one that was initially stored.
     migration::MigrationHandler::run();
 
-    set_certified_data_for_skip_certification();
+    // the certified tree of the votes is kept in the heap memory, so it is rebuilt from the receipts
+    init_certified_data();
     match input {
         None => CONTROLLER.post_upgrade(None).await,
         Some(SystemInstall::Upgrade(input)) => CONTROLLER.post_upgrade(Some(input)).await,
@@ -77,11 +84,43 @@ async fn health_status() -> HealthStatus {
     CONTROLLER.health_status().await
 }
 
+#[query(name = "get_init_status")]
+async fn get_init_status() -> ApiResult<GetInitStatusResponse> {
+    CONTROLLER.get_init_status().await
+}
+
+#[update(name = "retry_init")]
+async fn retry_init() -> ApiResult<()> {
+    CONTROLLER.retry_init().await
+}
+
 #[query(name = "system_info")]
 async fn system_info() -> ApiResult<SystemInfoResponse> {
     CONTROLLER.system_info().await
 }
 
+#[query(name = "list_principal_usage")]
+async fn list_principal_usage() -> ApiResult<ListPrincipalUsageResponse> {
+    CONTROLLER.list_principal_usage().await
+}
+
+#[query(name = "list_stale_approvers")]
+async fn list_stale_approvers(
+    input: ListStaleApproversInput,
+) -> ApiResult<ListStaleApproversResponse> {
+    CONTROLLER.list_stale_approvers(input).await
+}
+
+#[query(name = "list_station_logs")]
+async fn list_station_logs(input: ListStationLogsInput) -> ApiResult<ListStationLogsResponse> {
+    CONTROLLER.list_station_logs(input).await
+}
+
+#[query(name = "get_trace")]
+async fn get_trace(input: GetTraceInput) -> ApiResult<GetTraceResponse> {
+    CONTROLLER.get_trace(input).await
+}
+
 #[update(name = "notify_failed_station_upgrade")]
 async fn notify_failed_station_upgrade(input: NotifyFailedStationUpgradeInput) -> ApiResult<()> {
     CONTROLLER.notify_failed_station_upgrade(input).await
@@ -89,17 +128,22 @@ async fn notify_failed_station_upgrade(input: NotifyFailedStationUpgradeInput) -
 
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: SystemController = SystemController::new(Arc::clone(&SYSTEM_SERVICE));
+    static ref CONTROLLER: SystemController =
+        SystemController::new(Arc::clone(&SYSTEM_SERVICE), Arc::clone(&USAGE_SERVICE));
 }
 
 #[derive(Debug)]
 pub struct SystemController {
     system_service: Arc<SystemService>,
+    usage_service: Arc<UsageService>,
 }
 
 impl SystemController {
-    fn new(system_service: Arc<SystemService>) -> Self {
-        Self { system_service }
+    fn new(system_service: Arc<SystemService>, usage_service: Arc<UsageService>) -> Self {
+        Self {
+            system_service,
+            usage_service,
+        }
     }
 
     #[cfg(any(not(feature = "canbench"), test))]
@@ -125,6 +169,24 @@ impl SystemController {
         self.system_service.health_status()
     }
 
+    // No authorization middleware as the permissions are only set up by the bootstrap, the caller is checked
+    // to be a controller or an admin of the station instead.
+    async fn get_init_status(&self) -> ApiResult<GetInitStatusResponse> {
+        authorize_init_caller(&call_context(), "get_init_status")?;
+
+        Ok(GetInitStatusResponse {
+            status: self.system_service.get_init_progress().into(),
+        })
+    }
+
+    // No authorization middleware as the permissions are only set up by the bootstrap, the caller is checked
+    // to be a controller or an admin of the station instead.
+    async fn retry_init(&self) -> ApiResult<()> {
+        authorize_init_caller(&call_context(), "retry_init")?;
+
+        self.system_service.retry_init()
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
     async fn system_info(&self) -> ApiResult<SystemInfoResponse> {
         let system_info = self.system_service.get_system_info();
@@ -135,6 +197,76 @@ impl SystemController {
         })
     }
 
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn list_principal_usage(&self) -> ApiResult<ListPrincipalUsageResponse> {
+        let ctx = call_context();
+        let usage = self
+            .usage_service
+            .list_principal_usage(&ctx)
+            .into_iter()
+            .map(|usage| {
+                let last_active_at = usage
+                    .user_id
+                    .and_then(|user_id| self.usage_service.get_last_active_at(&user_id));
+
+                usage.to_dto(last_active_at)
+            })
+            .collect();
+
+        Ok(ListPrincipalUsageResponse {
+            window_secs: USAGE_TIME_WINDOW.as_secs(),
+            usage,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn list_stale_approvers(
+        &self,
+        input: ListStaleApproversInput,
+    ) -> ApiResult<ListStaleApproversResponse> {
+        let ctx = call_context();
+        let inactive_for_ns =
+            u64::from(input.inactive_days).saturating_mul(24 * 60 * 60 * 1_000_000_000);
+        let approvers = self
+            .usage_service
+            .list_stale_approvers(inactive_for_ns, &ctx)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(ListStaleApproversResponse { approvers })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn list_station_logs(
+        &self,
+        input: ListStationLogsInput,
+    ) -> ApiResult<ListStationLogsResponse> {
+        let result = self.system_service.list_station_logs(input)?;
+
+        Ok(ListStationLogsResponse {
+            entries: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn get_trace(&self, input: GetTraceInput) -> ApiResult<GetTraceResponse> {
+        let correlation_id = HelperMapper::to_uuid(input.correlation_id)?;
+        let (trace, logs) = self.system_service.get_trace(correlation_id.as_bytes())?;
+
+        Ok(GetTraceResponse {
+            trace: TraceDTO {
+                correlation_id: Uuid::from_bytes(trace.correlation_id)
+                    .hyphenated()
+                    .to_string(),
+                spans: trace.spans.into_iter().map(Into::into).collect(),
+                logs: logs.into_iter().map(Into::into).collect(),
+            },
+        })
+    }
+
     // No authorization middleware as the caller is checked to be a controller of the station canister.
     async fn notify_failed_station_upgrade(
         &self,
@@ -154,6 +286,21 @@ impl SystemController {
     }
 }
 
+/// Allows the controllers and the admins of the station to follow and retry its bootstrap.
+fn authorize_init_caller(ctx: &CallContext, resource: &str) -> ApiResult<()> {
+    let caller_is_admin = ctx
+        .user()
+        .is_some_and(|user| user.groups.contains(ADMIN_GROUP_ID));
+
+    if !ctx.caller_is_controller() && !caller_is_admin {
+        Err(AuthorizationError::Unauthorized {
+            resource: resource.to_string(),
+        })?
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
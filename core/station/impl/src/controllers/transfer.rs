@@ -1,17 +1,21 @@
 use crate::{
-    core::middlewares::{authorize, call_context},
-    mappers::{authorization::GetTransfersInputRef, HelperMapper},
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
+    mappers::{
+        authorization::{AnnotateTransferInputRef, GetTransfersInputRef},
+        HelperMapper,
+    },
     models::resource::Resource,
-    services::TransferService,
+    services::{PrincipalLabelService, TransferService, PRINCIPAL_LABEL_SERVICE},
 };
-use ic_cdk_macros::query;
+use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::{ApiError, ApiResult};
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetTransfersInput, GetTransfersResponse, ListAccountTransfersInput,
-    ListAccountTransfersResponse,
+    AnnotateTransferInput, AnnotateTransferResponse, GetTransfersInput, GetTransfersResponse,
+    ListAccountTransfersInput, ListAccountTransfersResponse,
 };
+use std::sync::Arc;
 
 // Canister entrypoints for the controller.
 #[query(name = "get_transfers")]
@@ -26,19 +30,34 @@ async fn list_account_transfers(
     CONTROLLER.list_account_transfers(input).await
 }
 
+#[update(name = "annotate_transfer")]
+async fn annotate_transfer(input: AnnotateTransferInput) -> ApiResult<AnnotateTransferResponse> {
+    CONTROLLER.annotate_transfer(input).await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: TransferController = TransferController::new(TransferService::default());
+    static ref CONTROLLER: TransferController = TransferController::new(
+        TransferService::default(),
+        Arc::clone(&PRINCIPAL_LABEL_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct TransferController {
     transfer_service: TransferService,
+    principal_label_service: Arc<PrincipalLabelService>,
 }
 
 impl TransferController {
-    fn new(transfer_service: TransferService) -> Self {
-        Self { transfer_service }
+    fn new(
+        transfer_service: TransferService,
+        principal_label_service: Arc<PrincipalLabelService>,
+    ) -> Self {
+        Self {
+            transfer_service,
+            principal_label_service,
+        }
     }
 
     #[with_middleware(
@@ -57,7 +76,15 @@ impl TransferController {
         let transfers = self.transfer_service.get_transfers(ids, &call_context())?;
 
         Ok(GetTransfersResponse {
-            transfers: transfers.into_iter().map(|t| t.to_dto()).collect(),
+            transfers: transfers
+                .into_iter()
+                .map(|t| {
+                    t.to_dto(
+                        self.transfer_service.find_annotation(&t.id),
+                        self.principal_label_service.find_label(&t.to_address),
+                    )
+                })
+                .collect(),
         })
     }
 
@@ -66,13 +93,37 @@ impl TransferController {
         &self,
         input: ListAccountTransfersInput,
     ) -> ApiResult<ListAccountTransfersResponse> {
-        let transfers = self.transfer_service.list_account_transfers(input)?;
+        let transfers = self
+            .transfer_service
+            .list_account_transfers(input, &call_context())?;
 
         Ok(ListAccountTransfersResponse {
             transfers: transfers
                 .into_iter()
-                .map(|t| t.to_list_item_dto())
+                .map(|t| {
+                    t.to_list_item_dto(
+                        self.transfer_service.find_annotation(&t.id),
+                        self.principal_label_service.find_label(&t.to_address),
+                    )
+                })
                 .collect(),
         })
     }
+
+    #[with_middleware(
+        guard = authorize(&call_context(), &AnnotateTransferInputRef(&input).to_resources())
+    )]
+    #[with_middleware(tail = use_canister_call_metric("annotate_transfer", &result))]
+    async fn annotate_transfer(
+        &self,
+        input: AnnotateTransferInput,
+    ) -> ApiResult<AnnotateTransferResponse> {
+        let annotation = self
+            .transfer_service
+            .annotate_transfer(input, &call_context())?;
+
+        Ok(AnnotateTransferResponse {
+            annotation: annotation.into(),
+        })
+    }
 }
@@ -134,6 +134,7 @@ mod tests {
                 identities: vec![identity],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                scheduled_memberships: vec![],
             })
             .expect("Failed to add user");
 
@@ -155,6 +156,7 @@ mod tests {
                 identities: vec![identity],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                scheduled_memberships: vec![],
             })
             .expect("Failed to add user");
 
@@ -1,7 +1,7 @@
 use crate::{
     core::middlewares::{authorize, call_context},
     mappers::HelperMapper,
-    models::resource::{Resource, ResourceAction},
+    models::resource::{Resource, ResourceAction, SystemResourceAction},
     services::UserGroupService,
 };
 use ic_cdk_macros::query;
@@ -9,8 +9,8 @@ use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetUserGroupInput, GetUserGroupResponse, ListUserGroupsInput, ListUserGroupsResponse,
-    UserGroupCallerPrivilegesDTO,
+    GetUserGroupInput, GetUserGroupResponse, ListUserGroupQuotaUsageResponse, ListUserGroupsInput,
+    ListUserGroupsResponse, UserGroupCallerPrivilegesDTO,
 };
 
 #[query(name = "get_user_group")]
@@ -23,6 +23,11 @@ async fn list_user_groups(input: ListUserGroupsInput) -> ApiResult<ListUserGroup
     CONTROLLER.list_user_groups(input).await
 }
 
+#[query(name = "list_user_group_quota_usage")]
+async fn list_user_group_quota_usage() -> ApiResult<ListUserGroupQuotaUsageResponse> {
+    CONTROLLER.list_user_group_quota_usage().await
+}
+
 lazy_static! {
     static ref CONTROLLER: UserGroupController =
         UserGroupController::new(UserGroupService::default());
@@ -80,4 +85,13 @@ impl UserGroupController {
             privileges,
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn list_user_group_quota_usage(&self) -> ApiResult<ListUserGroupQuotaUsageResponse> {
+        let usage = self.user_group_service.list_quota_usage();
+
+        Ok(ListUserGroupQuotaUsageResponse {
+            usage: usage.into_iter().map(Into::into).collect(),
+        })
+    }
 }
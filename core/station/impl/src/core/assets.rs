@@ -1,4 +1,6 @@
 use crate::models::{Asset, Blockchain, BlockchainStandard, Metadata};
+use candid::Nat;
+use num_bigint::BigUint;
 use std::{cell::RefCell, collections::HashSet};
 
 thread_local! {
@@ -10,7 +12,126 @@ thread_local! {
           standard: BlockchainStandard::Native,
           symbol: "ICP".to_string(),
           name: "Internet Computer".to_string(),
+          decimals: 8,
+          display_decimals: 4,
           metadata: Metadata::default(),
         },
       ].into_iter().collect());
 }
+
+/// Returns the number of decimals that the amounts of the asset are displayed with, the amounts of the
+/// assets that are not supported by the canister are displayed with all their decimals.
+pub fn display_decimals(
+    blockchain: &Blockchain,
+    standard: &BlockchainStandard,
+    symbol: &str,
+    decimals: u32,
+) -> u32 {
+    ASSETS.with(|assets| {
+        assets
+            .borrow()
+            .iter()
+            .find(|asset| {
+                asset.blockchain == *blockchain
+                    && asset.standard == *standard
+                    && asset.symbol == symbol
+            })
+            .map(|asset| asset.display_decimals.min(decimals))
+            .unwrap_or(decimals)
+    })
+}
+
+/// Formats an amount in minor units as a decimal string with the given number of display decimals,
+/// rounding half up (e.g. `123_456_789` with `8` decimals is `1.2346` with `4` display decimals).
+pub fn format_amount(amount: &Nat, decimals: u32, display_decimals: u32) -> String {
+    let display_decimals = display_decimals.min(decimals);
+    let divisor = BigUint::from(10u32).pow(decimals - display_decimals);
+    let mut rounded = &amount.0 / &divisor;
+    if (&amount.0 % &divisor) * 2u32 >= divisor && divisor > BigUint::from(1u32) {
+        rounded += 1u32;
+    }
+
+    if display_decimals == 0 {
+        return rounded.to_string();
+    }
+
+    let digits = format!(
+        "{:0>width$}",
+        rounded.to_string(),
+        width = display_decimals as usize + 1
+    );
+    let (whole, fraction) = digits.split_at(digits.len() - display_decimals as usize);
+
+    format!("{}.{}", whole, fraction)
+}
+
+/// Converts a decimal string (e.g. `1.5`) to an amount in minor units, returns `None` if the string is not
+/// a decimal number or if it has more fractional digits than the decimals of the asset.
+pub fn to_minor_units(value: &str, decimals: u32) -> Option<Nat> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty()
+        || fraction.len() > decimals as usize
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+
+    BigUint::parse_bytes(digits.as_bytes(), 10).map(Nat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_amounts_with_the_display_decimals() {
+        let amount = Nat::from(123_456_789u64);
+
+        assert_eq!(format_amount(&amount, 8, 8), "1.23456789");
+        assert_eq!(format_amount(&amount, 8, 4), "1.2346");
+        assert_eq!(format_amount(&amount, 8, 0), "1");
+        assert_eq!(format_amount(&amount, 8, 12), "1.23456789");
+        assert_eq!(format_amount(&Nat::from(5u64), 8, 4), "0.0000");
+        assert_eq!(format_amount(&Nat::from(5_000u64), 8, 4), "0.0001");
+        assert_eq!(format_amount(&Nat::from(99_995_000u64), 8, 4), "1.0000");
+        assert_eq!(format_amount(&Nat::from(42u64), 0, 4), "42");
+    }
+
+    #[test]
+    fn converts_decimal_strings_to_minor_units() {
+        assert_eq!(to_minor_units("1.5", 8), Some(Nat::from(150_000_000u64)));
+        assert_eq!(to_minor_units("42", 2), Some(Nat::from(4_200u64)));
+        assert_eq!(to_minor_units("0.00000001", 8), Some(Nat::from(1u64)));
+        assert_eq!(to_minor_units("0.000000001", 8), None);
+        assert_eq!(to_minor_units(".5", 8), None);
+        assert_eq!(to_minor_units("1,5", 8), None);
+        assert_eq!(to_minor_units("-1", 8), None);
+    }
+
+    #[test]
+    fn unsupported_assets_are_displayed_with_all_their_decimals() {
+        assert_eq!(
+            display_decimals(
+                &Blockchain::InternetComputer,
+                &BlockchainStandard::Native,
+                "ICP",
+                8
+            ),
+            4
+        );
+        assert_eq!(
+            display_decimals(
+                &Blockchain::InternetComputer,
+                &BlockchainStandard::ICRC1,
+                "ckBTC",
+                8
+            ),
+            8
+        );
+    }
+}
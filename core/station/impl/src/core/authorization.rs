@@ -1,18 +1,26 @@
 use super::{
     evaluation::{Evaluate, REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR},
+    ic_cdk::api::time,
+    read_system_info, read_system_state,
     request::RequestApprovalRightsEvaluator,
     CallContext,
 };
 use crate::{
     errors::AuthorizationError,
     models::{
+        indexes::request_index::RequestIndexFields,
+        request_specifier::RequestSpecifier,
         resource::{
-            NotificationResourceAction, RequestResourceAction, Resource, ResourceId,
-            UserResourceAction,
+            AccountResourceAction, NotificationResourceAction, RequestResourceAction, Resource,
+            ResourceAction, ResourceId, UserResourceAction,
         },
-        NotificationKey, User,
+        AccountId, NotificationKey, Request, RequestId, RequestViewer, SubTreasury,
+        SubTreasuryVisibility, SystemState, User, ADMIN_GROUP_ID,
+    },
+    repositories::{
+        ARCHIVED_REQUEST_REPOSITORY, NOTIFICATION_REPOSITORY, REQUEST_POLICY_REPOSITORY,
+        REQUEST_REPOSITORY,
     },
-    repositories::{NOTIFICATION_REPOSITORY, REQUEST_REPOSITORY},
     services::permission::PERMISSION_SERVICE,
 };
 use orbit_essentials::repository::Repository;
@@ -26,6 +34,21 @@ impl Authorization {
             return true;
         }
 
+        // Confidential requests are only visible to their participants, regardless of the read permission.
+        if let Resource::Request(RequestResourceAction::Read(ResourceId::Id(request_id))) = resource
+        {
+            if !Self::is_request_visible(ctx, request_id) {
+                return false;
+            }
+        }
+
+        // The accounts of isolated sub-treasuries are only accessible to their members, regardless of the permissions.
+        if let Some(user) = ctx.user() {
+            if is_outside_of_isolated_sub_treasury(user, resource) {
+                return false;
+            }
+        }
+
         // Gets the expanded list of resources.
         // e.g. if the resource is for account(1), then the list will expand to [account(1), account(any)]
         let resources = resource.to_expanded_list();
@@ -60,8 +83,91 @@ impl Authorization {
             }
 
             false
+        }) || is_active_auditor_with_read_access(ctx, resource)
+    }
+
+    /// Checks if the request is visible to the caller, confidential requests are only visible to their
+    /// participants and the admins. Requests that do not exist are considered visible.
+    pub fn is_request_visible(ctx: &CallContext, request_id: &RequestId) -> bool {
+        find_request_fields(request_id).map_or(true, |fields| {
+            fields.is_visible_to(&RequestViewer::from(ctx))
         })
     }
+
+    /// Checks if the caller is an auditor whose access has not expired yet.
+    pub fn is_active_auditor(ctx: &CallContext) -> bool {
+        ctx.user().is_none()
+            && read_system_info()
+                .find_active_auditor(&ctx.caller(), time())
+                .is_some()
+    }
+}
+
+/// Auditors are not users of the station, they can only list and read its data until their access expires.
+fn is_active_auditor_with_read_access(ctx: &CallContext, resource: &Resource) -> bool {
+    resource.is_read_only() && Authorization::is_active_auditor(ctx)
+}
+
+/// Returns the account that the resource targets, if it targets a single account.
+fn targeted_account_id(resource: &Resource) -> Option<&AccountId> {
+    match resource {
+        Resource::Account(
+            AccountResourceAction::Read(ResourceId::Id(account_id))
+            | AccountResourceAction::Update(ResourceId::Id(account_id))
+            | AccountResourceAction::Reconcile(ResourceId::Id(account_id))
+            | AccountResourceAction::Review(ResourceId::Id(account_id))
+            | AccountResourceAction::Transfer(ResourceId::Id(account_id)),
+        ) => Some(account_id),
+        _ => None,
+    }
+}
+
+/// Finds the sub-treasury that the account belongs to, there is none while the station is not initialized.
+fn find_sub_treasury_of_account(account_id: &AccountId) -> Option<SubTreasury> {
+    match read_system_state() {
+        SystemState::Initialized(system_info) => system_info
+            .find_sub_treasury_of_account(account_id)
+            .cloned(),
+        SystemState::Uninitialized => None,
+    }
+}
+
+/// Finds the sub-treasury whose accounts are the only ones targeted by the request specifier, if any.
+fn find_sub_treasury_of_request_specifier(specifier: &RequestSpecifier) -> Option<SubTreasury> {
+    match read_system_state() {
+        SystemState::Initialized(system_info) => system_info
+            .find_sub_treasury_of_request_specifier(specifier)
+            .cloned(),
+        SystemState::Uninitialized => None,
+    }
+}
+
+/// Checks if the resource is an account of an isolated sub-treasury that the user does not belong to,
+/// the station admins can access the accounts of all the sub-treasuries.
+fn is_outside_of_isolated_sub_treasury(user: &User, resource: &Resource) -> bool {
+    let Some(account_id) = targeted_account_id(resource) else {
+        return false;
+    };
+
+    if user.groups.contains(ADMIN_GROUP_ID) {
+        return false;
+    }
+
+    find_sub_treasury_of_account(account_id).is_some_and(|sub_treasury| {
+        sub_treasury.visibility == SubTreasuryVisibility::Isolated
+            && !sub_treasury.is_member(&user.groups)
+    })
+}
+
+/// Returns the indexed fields of the request, or the ones kept by its stub if the request was archived.
+fn find_request_fields(request_id: &RequestId) -> Option<RequestIndexFields> {
+    REQUEST_REPOSITORY
+        .find_indexed_fields_by_request_id(request_id)
+        .or_else(|| {
+            ARCHIVED_REQUEST_REPOSITORY
+                .get(request_id)
+                .map(|archived| archived.fields)
+        })
 }
 
 /// Checks if the user had access to the resource based on default rules (non-permission based).
@@ -70,7 +176,7 @@ impl Authorization {
 fn has_default_resource_access(user: &User, resource: &Resource) -> bool {
     match &resource {
         &Resource::Request(RequestResourceAction::Read(ResourceId::Id(request_id))) => {
-            match REQUEST_REPOSITORY.find_indexed_fields_by_request_id(request_id) {
+            match find_request_fields(request_id) {
                 None => false,
                 Some(request) => {
                     if request.approved_by.iter().any(|id| *id == user.id)
@@ -86,11 +192,34 @@ fn has_default_resource_access(user: &User, resource: &Resource) -> bool {
                         &request,
                     );
 
-                    validator.evaluate().unwrap_or(false)
+                    if validator.evaluate().unwrap_or(false) {
+                        return true;
+                    }
+
+                    // The cancellers of a time-locked transfer can see it until it is unlocked.
+                    REQUEST_REPOSITORY
+                        .get(&Request::key(*request_id))
+                        .is_some_and(|request| request.can_cancel(&user.groups, time()))
                 }
             }
         }
-
+        Resource::Account(_) => {
+            // The admins of a sub-treasury administer its accounts.
+            targeted_account_id(resource)
+                .and_then(find_sub_treasury_of_account)
+                .is_some_and(|sub_treasury| sub_treasury.is_admin(&user.groups))
+        }
+        &Resource::RequestPolicy(
+            ResourceAction::Read(ResourceId::Id(policy_id))
+            | ResourceAction::Update(ResourceId::Id(policy_id))
+            | ResourceAction::Delete(ResourceId::Id(policy_id)),
+        ) => {
+            // The admins of a sub-treasury administer the policies that only apply to its accounts.
+            REQUEST_POLICY_REPOSITORY
+                .get(policy_id)
+                .and_then(|policy| find_sub_treasury_of_request_specifier(&policy.specifier))
+                .is_some_and(|sub_treasury| sub_treasury.is_admin(&user.groups))
+        }
         Resource::User(UserResourceAction::Read(ResourceId::Id(user_id))) => {
             // The user has access to their own user record.
             *user_id == user.id
@@ -138,6 +267,7 @@ pub async fn evaluate_caller_access(
 mod tests {
     use super::*;
     use crate::{
+        core::{test_utils, write_system_info},
         models::{
             account_test_utils,
             permission::{Allow, Permission},
@@ -160,6 +290,7 @@ mod tests {
         let admin_user_group = UserGroup {
             id: *ADMIN_GROUP_ID,
             name: "Admin".to_string(),
+            quotas: Default::default(),
             last_modification_timestamp: 0,
         };
         let finance_user_group = user_group_test_utils::add_group("finance");
@@ -398,4 +529,51 @@ mod tests {
             &Resource::User(UserResourceAction::Read(ResourceId::Any))
         ));
     }
+
+    #[tokio::test]
+    async fn isolated_sub_treasury_accounts_are_only_accessible_to_members() {
+        let test_context = setup();
+        let treasury_admin_group = user_group_test_utils::add_group("treasury-admins");
+        let mut treasury_admin = mock_user();
+        treasury_admin.id = [4; 16];
+        treasury_admin.identities = vec![Principal::from_slice(&[4; 29])];
+        treasury_admin.groups = vec![treasury_admin_group.id];
+        USER_REPOSITORY.insert(treasury_admin.to_key(), treasury_admin.to_owned());
+
+        let mut system_info = test_utils::init_canister_system();
+        system_info.set_sub_treasuries(vec![SubTreasury {
+            name: "Grants".to_string(),
+            account_ids: vec![[1; 16]],
+            admin_group_id: treasury_admin_group.id,
+            member_group_ids: vec![],
+            visibility: SubTreasuryVisibility::Isolated,
+        }]);
+        write_system_info(system_info);
+
+        let permission = Permission::new(
+            Allow::user_groups(vec![test_context.finance_user_group.id]),
+            Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let finance_ctx = CallContext::new(test_context.finance_user.identities[0]);
+        let treasury_admin_ctx = CallContext::new(treasury_admin.identities[0]);
+
+        assert!(!Authorization::is_allowed(
+            &finance_ctx,
+            &Resource::Account(AccountResourceAction::Read(ResourceId::Id([1; 16])))
+        ));
+        assert!(Authorization::is_allowed(
+            &finance_ctx,
+            &Resource::Account(AccountResourceAction::Read(ResourceId::Id([2; 16])))
+        ));
+        assert!(Authorization::is_allowed(
+            &treasury_admin_ctx,
+            &Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([1; 16])))
+        ));
+        assert!(!Authorization::is_allowed(
+            &treasury_admin_ctx,
+            &Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([2; 16])))
+        ));
+    }
 }
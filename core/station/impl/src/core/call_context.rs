@@ -1,7 +1,11 @@
 use crate::core::ic_cdk::api::{id as self_canister_id, is_controller};
+use crate::core::{CanisterTimeProvider, CanisterUuidProvider, TimeProvider, UuidProvider};
 use crate::models::User;
 use crate::repositories::USER_REPOSITORY;
 use candid::Principal;
+use orbit_essentials::types::Timestamp;
+use std::sync::Arc;
+use uuid::Uuid;
 
 #[cfg(not(test))]
 use ic_cdk::api::caller;
@@ -13,6 +17,14 @@ use std::sync::Mutex;
 pub struct CallContext {
     caller: Principal,
     user: Option<User>,
+    /// The providers of the current time and of new ids, used by the service methods that are called with
+    /// the call context (e.g. creating and approving requests, account reviews, transfer annotations,
+    /// notification reads, blob uploads and principal labels).
+    ///
+    /// Most services are only called without a call context, from the request execution or the jobs, and
+    /// use the time and ids of the canister directly.
+    time_provider: Arc<dyn TimeProvider>,
+    uuid_provider: Arc<dyn UuidProvider>,
 }
 
 impl Default for CallContext {
@@ -20,6 +32,8 @@ impl Default for CallContext {
         Self {
             caller: Principal::anonymous(),
             user: None,
+            time_provider: Arc::new(CanisterTimeProvider),
+            uuid_provider: Arc::new(CanisterUuidProvider),
         }
     }
 }
@@ -37,6 +51,7 @@ impl CallContext {
         Self {
             caller,
             user: USER_REPOSITORY.find_by_identity(&caller),
+            ..Default::default()
         }
     }
 
@@ -49,6 +64,7 @@ impl CallContext {
         Self {
             caller,
             user: USER_REPOSITORY.find_by_identity(&caller),
+            ..Default::default()
         }
     }
 
@@ -58,9 +74,32 @@ impl CallContext {
         Self {
             caller: *caller,
             user: USER_REPOSITORY.find_by_identity(&caller),
+            ..Default::default()
         }
     }
 
+    /// Replaces the provider of the current time used within this call context.
+    pub fn with_time_provider(mut self, time_provider: Arc<dyn TimeProvider>) -> Self {
+        self.time_provider = time_provider;
+        self
+    }
+
+    /// Replaces the provider of new ids used within this call context.
+    pub fn with_uuid_provider(mut self, uuid_provider: Arc<dyn UuidProvider>) -> Self {
+        self.uuid_provider = uuid_provider;
+        self
+    }
+
+    /// Returns the current time as given by the time provider of the call context.
+    pub fn now(&self) -> Timestamp {
+        self.time_provider.now()
+    }
+
+    /// Generates a new id with the id provider of the call context.
+    pub async fn generate_uuid(&self) -> Uuid {
+        self.uuid_provider.generate_uuid().await
+    }
+
     pub fn caller(&self) -> Principal {
         self.caller
     }
@@ -82,6 +121,7 @@ impl CallContext {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::core::test_providers::{MockTimeProvider, SequentialUuidProvider};
     use crate::models::{user_test_utils::mock_user, ADMIN_GROUP_ID};
     use orbit_essentials::cdk::mocks::TEST_CANISTER_ID;
     use orbit_essentials::repository::Repository;
@@ -118,6 +158,21 @@ pub mod tests {
         assert!(call_context.caller_is_controller_or_self());
     }
 
+    #[tokio::test]
+    async fn uses_injected_providers() {
+        let time_provider = Arc::new(MockTimeProvider::new(42));
+        let call_context = CallContext::new(Principal::anonymous())
+            .with_time_provider(time_provider.clone())
+            .with_uuid_provider(Arc::new(SequentialUuidProvider::new(1)));
+
+        assert_eq!(call_context.now(), 42);
+        time_provider.advance(8);
+        assert_eq!(call_context.now(), 50);
+
+        assert_eq!(call_context.generate_uuid().await, Uuid::from_u128(1));
+        assert_eq!(call_context.generate_uuid().await, Uuid::from_u128(2));
+    }
+
     #[test]
     fn check_user_is_none() {
         let caller = Principal::from_text("wkt3w-3iaaa-aaaaa-774ba-cai").unwrap();
@@ -0,0 +1,176 @@
+//! Certifies the data that the station serves, so that clients can verify it against the root key of the
+//! Internet Computer without trusting the replica that answered their query.
+//!
+//! The certified data of the station is the root hash of a tree with two branches:
+//!
+//! - `http_expr`, which skips the certification of the HTTP responses;
+//! - `votes`, which holds the sha256 hashes of the canonical encodings of the vote receipts, labeled by the
+//!   request id followed by the approver id.
+//!
+//! The tree is kept in the heap memory. After upgrades it is rebuilt from the hashes that are stored next to
+//! the receipts, in batches of `REBUILD_BATCH_SIZE` receipts per round so that the rebuild never exceeds the
+//! instruction limit, the certificates of the votes are refused until the rebuild completes.
+
+use super::ic_cdk::api::set_certified_data;
+use super::ic_timers::set_timer;
+use crate::{
+    models::{VoteReceipt, VoteReceiptKey, VoteReceiptLabel},
+    repositories::VOTE_RECEIPT_REPOSITORY,
+};
+use ic_certification::{fork, labeled, pruned, AsHashTree, Hash, HashTree, RbTree};
+use orbit_essentials::http::skip_certification_asset_tree;
+use std::{cell::RefCell, time::Duration};
+
+const VOTES_LABEL: &str = "votes";
+
+/// The number of receipts that are added back to the tree of the votes per round of the rebuild.
+const REBUILD_BATCH_SIZE: usize = 10_000;
+
+/// The progress of the rebuild of the tree of the votes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RebuildProgress {
+    /// The rebuild continues after the given label, or from the first receipt.
+    Pending(Option<VoteReceiptLabel>),
+    Completed,
+}
+
+thread_local! {
+    static VOTES: RefCell<RbTree<Vec<u8>, Vec<u8>>> = RefCell::new(RbTree::new());
+    static REBUILD_PROGRESS: RefCell<RebuildProgress> = const { RefCell::new(RebuildProgress::Completed) };
+}
+
+/// Rebuilds the tree of the votes from the stored hashes of the receipts and certifies it, on init and
+/// after upgrades.
+///
+/// The first batch is added right away, the rest of the receipts are added by timers.
+pub fn init_certified_data() {
+    VOTES.with(|votes| *votes.borrow_mut() = RbTree::new());
+    REBUILD_PROGRESS.with(|progress| *progress.borrow_mut() = RebuildProgress::Pending(None));
+
+    rebuild_votes_batch();
+}
+
+/// Adds the next batch of receipts to the tree of the votes and certifies it, scheduling the next batch
+/// until all the receipts are added.
+fn rebuild_votes_batch() {
+    let RebuildProgress::Pending(after) = REBUILD_PROGRESS.with(|progress| *progress.borrow())
+    else {
+        return;
+    };
+
+    let hashes = VOTE_RECEIPT_REPOSITORY.find_certified_hashes_after(after, REBUILD_BATCH_SIZE);
+    let progress = match hashes.last() {
+        Some((label, _)) if hashes.len() == REBUILD_BATCH_SIZE => {
+            RebuildProgress::Pending(Some(*label))
+        }
+        _ => RebuildProgress::Completed,
+    };
+
+    VOTES.with(|votes| {
+        let mut votes = votes.borrow_mut();
+        for (label, hash) in hashes {
+            votes.insert(label.to_vec(), hash.to_vec());
+        }
+    });
+    REBUILD_PROGRESS.with(|current| *current.borrow_mut() = progress);
+
+    set_certified_data(&certified_data());
+
+    if progress != RebuildProgress::Completed {
+        set_timer(Duration::from_secs(0), rebuild_votes_batch);
+    }
+}
+
+/// Whether the tree of the votes holds all the receipts, the witnesses of the votes are incomplete until then.
+pub fn is_votes_tree_complete() -> bool {
+    REBUILD_PROGRESS.with(|progress| *progress.borrow() == RebuildProgress::Completed)
+}
+
+/// Stores the hash of the receipt, adds it to the tree of the votes and certifies the new tree.
+pub fn certify_vote(receipt: &VoteReceipt) {
+    let label = receipt.certified_label();
+    let hash = receipt.receipt_hash();
+
+    VOTE_RECEIPT_REPOSITORY.insert_certified_hash(label, hash);
+    VOTES.with(|votes| votes.borrow_mut().insert(label.to_vec(), hash.to_vec()));
+
+    set_certified_data(&certified_data());
+}
+
+/// Returns the root hash of the certified tree.
+pub fn certified_data() -> Hash {
+    fork(skip_certification_asset_tree(), votes_branch()).digest()
+}
+
+/// Returns the witness that reveals the `http_expr` branch, for the certification of the HTTP responses.
+pub fn http_witness() -> HashTree {
+    fork(
+        skip_certification_asset_tree(),
+        pruned(votes_branch().digest()),
+    )
+}
+
+/// Returns the witness that reveals the hash of the receipt, or its absence, in the `votes` branch.
+pub fn vote_witness(key: &VoteReceiptKey) -> HashTree {
+    let votes_witness = VOTES.with(|votes| votes.borrow().witness(&key.certified_label()));
+
+    fork(
+        pruned(skip_certification_asset_tree().digest()),
+        labeled(VOTES_LABEL, votes_witness),
+    )
+}
+
+fn votes_branch() -> HashTree {
+    let votes_root_hash = VOTES.with(|votes| votes.borrow().root_hash());
+
+    labeled(VOTES_LABEL, pruned(votes_root_hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::vote_receipt_test_utils::mock_vote_receipt;
+
+    #[test]
+    fn witnesses_match_the_certified_data() {
+        let receipt = mock_vote_receipt();
+        let before = certified_data();
+
+        certify_vote(&receipt);
+
+        assert_ne!(before, certified_data());
+        assert_eq!(http_witness().digest(), certified_data());
+        assert_eq!(vote_witness(&receipt.to_key()).digest(), certified_data());
+    }
+
+    #[test]
+    fn rebuilds_the_votes_from_the_stored_hashes() {
+        let receipt = mock_vote_receipt();
+        certify_vote(&receipt);
+        let certified = certified_data();
+
+        VOTES.with(|votes| *votes.borrow_mut() = RbTree::new());
+        assert_ne!(certified, certified_data());
+
+        init_certified_data();
+
+        assert!(is_votes_tree_complete());
+        assert_eq!(certified, certified_data());
+    }
+
+    #[test]
+    fn rebuilds_the_votes_in_batches() {
+        for _ in 0..REBUILD_BATCH_SIZE + 1 {
+            VOTE_RECEIPT_REPOSITORY
+                .insert_certified_hash(mock_vote_receipt().certified_label(), [1; 32]);
+        }
+
+        init_certified_data();
+
+        assert!(!is_votes_tree_complete());
+
+        rebuild_votes_batch();
+
+        assert!(is_votes_tree_complete());
+    }
+}
@@ -0,0 +1,176 @@
+//! Accounting of the instructions spent on the lifecycle of the requests.
+//!
+//! The instructions are read from the performance counter of the call context, which keeps counting
+//! across awaits. Since the futures of a call context can be interleaved, e.g. the executions of the
+//! requests of a job tick, the instructions of a future are only counted while it is being polled.
+//!
+//! The running averages used to flag unusually expensive requests are kept in the heap memory like the
+//! usage of the station, so they start over after an upgrade.
+
+use crate::{
+    core::ic_cdk::api::performance_counter,
+    log_warn,
+    models::{Request, RequestCostPhase},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use uuid::Uuid;
+
+/// The performance counter of the call context, which includes the instructions of previous messages.
+const CALL_CONTEXT_INSTRUCTION_COUNTER: u32 = 1;
+
+/// A phase that spends at least this many instructions is always considered expensive.
+pub const EXPENSIVE_PHASE_MIN_INSTRUCTIONS: u64 = 4_000_000_000;
+/// How many times the average of its operation type a phase must exceed to be considered expensive.
+pub const EXPENSIVE_PHASE_AVERAGE_FACTOR: u64 = 10;
+/// The number of measurements of an operation type needed before comparing against its average.
+pub const EXPENSIVE_PHASE_MIN_SAMPLES: u64 = 10;
+
+thread_local! {
+    static PHASE_AVERAGES: RefCell<HashMap<(String, RequestCostPhase), RunningAverage>> =
+        RefCell::new(HashMap::new());
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct RunningAverage {
+    samples: u64,
+    total: u64,
+}
+
+impl RunningAverage {
+    fn average(&self) -> Option<u64> {
+        match self.samples >= EXPENSIVE_PHASE_MIN_SAMPLES {
+            true => Some(self.total / self.samples),
+            false => None,
+        }
+    }
+}
+
+/// Returns the instructions executed so far in the current call context.
+pub fn instruction_counter() -> u64 {
+    performance_counter(CALL_CONTEXT_INSTRUCTION_COUNTER)
+}
+
+/// A future that counts the instructions executed while polling the inner future.
+pub struct Metered<F: Future> {
+    future: Pin<Box<F>>,
+    instructions: u64,
+}
+
+impl<F: Future> Future for Metered<F> {
+    type Output = (F::Output, u64);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let started_at = instruction_counter();
+        let poll = self.future.as_mut().poll(cx);
+        let spent = instruction_counter().saturating_sub(started_at);
+        self.instructions = self.instructions.saturating_add(spent);
+
+        let instructions = self.instructions;
+        poll.map(|output| (output, instructions))
+    }
+}
+
+/// Wraps the future so that it also returns the instructions spent on it.
+pub fn metered<F: Future>(future: F) -> Metered<F> {
+    Metered {
+        future: Box::pin(future),
+        instructions: 0,
+    }
+}
+
+/// Whether the instructions spent on a phase are unusually expensive compared to the given average.
+pub fn is_unusually_expensive(instructions: u64, average: Option<u64>) -> bool {
+    instructions >= EXPENSIVE_PHASE_MIN_INSTRUCTIONS
+        || average.is_some_and(|average| {
+            instructions
+                > average
+                    .max(1)
+                    .saturating_mul(EXPENSIVE_PHASE_AVERAGE_FACTOR)
+        })
+}
+
+/// Adds the instructions spent on a phase to the cost of the request, flagging the phase if it is
+/// unusually expensive for the operation type of the request.
+pub fn record_request_cost(request: &mut Request, phase: RequestCostPhase, instructions: u64) {
+    let operation_type = request.operation.to_string();
+
+    let average = PHASE_AVERAGES.with(|averages| {
+        let mut averages = averages.borrow_mut();
+        let running_average = averages.entry((operation_type.clone(), phase)).or_default();
+        let average = running_average.average();
+
+        running_average.samples = running_average.samples.saturating_add(1);
+        running_average.total = running_average.total.saturating_add(instructions);
+
+        average
+    });
+
+    request.cost.add(phase, instructions);
+
+    if is_unusually_expensive(instructions, average) && request.cost.flag_expensive(phase) {
+        log_warn!(
+            "the {} of request {} ({}) spent {} instructions, which is unusually expensive (average: {})",
+            phase,
+            Uuid::from_bytes(request.id).hyphenated(),
+            operation_type,
+            instructions,
+            average.map_or("n/a".to_string(), |average| average.to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ic_cdk::api::add_mock_instructions;
+    use crate::models::request_test_utils::mock_request;
+
+    #[test]
+    fn large_phases_are_always_expensive() {
+        assert!(is_unusually_expensive(
+            EXPENSIVE_PHASE_MIN_INSTRUCTIONS,
+            None
+        ));
+        assert!(!is_unusually_expensive(1_000, None));
+        assert!(is_unusually_expensive(1_001, Some(100)));
+        assert!(!is_unusually_expensive(1_000, Some(100)));
+    }
+
+    #[test]
+    fn outliers_are_flagged_once_enough_samples_are_known() {
+        for _ in 0..EXPENSIVE_PHASE_MIN_SAMPLES {
+            let mut request = mock_request();
+            record_request_cost(&mut request, RequestCostPhase::Execution, 1_000);
+
+            assert!(request.cost.expensive_phases.is_empty());
+        }
+
+        let mut request = mock_request();
+        record_request_cost(&mut request, RequestCostPhase::Execution, 20_000);
+
+        assert_eq!(request.cost.execution_instructions, 20_000);
+        assert_eq!(
+            request.cost.expensive_phases,
+            vec![RequestCostPhase::Execution]
+        );
+    }
+
+    #[tokio::test]
+    async fn metered_futures_count_their_instructions() {
+        let (output, instructions) = metered(async {
+            add_mock_instructions(500);
+
+            42
+        })
+        .await;
+
+        assert_eq!(output, 42);
+        assert_eq!(instructions, 500);
+    }
+}
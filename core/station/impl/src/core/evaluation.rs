@@ -24,6 +24,12 @@ lazy_static! {
     pub static ref REQUEST_POLICY_RULE_EVALUATOR: Arc<RequestPolicyRuleEvaluator> = Arc::new(RequestPolicyRuleEvaluator {
         user_matcher: REQUEST_USER_MATCHER.clone(),
         address_book_metadata_matcher: REQUEST_ADDRESS_BOOK_METADATA_MATCHER.clone(),
+        exclude_requester: false,
+    });
+    pub static ref REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR: Arc<RequestPolicyRuleEvaluator> = Arc::new(RequestPolicyRuleEvaluator {
+        user_matcher: REQUEST_USER_MATCHER.clone(),
+        address_book_metadata_matcher: REQUEST_ADDRESS_BOOK_METADATA_MATCHER.clone(),
+        exclude_requester: true,
     });
     pub static ref REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR: Arc<RequesApprovalRightsRequestPolicyRuleEvaluator> = Arc::new(RequesApprovalRightsRequestPolicyRuleEvaluator {
         approver_matcher: REQUEST_USER_MATCHER.clone(),
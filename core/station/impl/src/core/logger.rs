@@ -0,0 +1,77 @@
+//! Structured logging of the station.
+//!
+//! Entries are printed to the canister log like before, and additionally retained in a bounded ring
+//! buffer in stable memory that admins can query, so that they survive upgrades and can be filtered by
+//! level, module and time. Use the `log_debug!`, `log_info!`, `log_warn!` and `log_error!` macros,
+//! which record the module path of the caller and optionally the correlation id of a trace (see
+//! `crate::core::tracing`).
+
+use crate::{
+    core::ic_cdk::api::{print, time},
+    models::{LogLevel, StationLogEntry},
+    repositories::STATION_LOG_REPOSITORY,
+};
+use orbit_essentials::types::UUID;
+use uuid::Uuid;
+
+/// Writes a log entry of the given module, attached to the trace of the correlation id if any.
+pub fn log(level: LogLevel, module: &str, message: String, correlation_id: Option<UUID>) {
+    match correlation_id {
+        Some(correlation_id) => print(format!(
+            "[{}] {} ({}): {}",
+            level,
+            module,
+            Uuid::from_bytes(correlation_id).hyphenated(),
+            message
+        )),
+        None => print(format!("[{}] {}: {}", level, module, message)),
+    }
+
+    let message = match message.char_indices().nth(StationLogEntry::MAX_MESSAGE_LEN) {
+        Some((end, _)) => message[..end].to_string(),
+        None => message,
+    };
+
+    STATION_LOG_REPOSITORY.append(time(), level, module.to_string(), message, correlation_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::tracing::TraceContext;
+    use crate::repositories::StationLogWhereClause;
+
+    #[test]
+    fn records_the_module_of_the_caller() {
+        crate::log_warn!("Failed to do {} things", 2);
+        crate::log_info!("{}", "a".repeat(StationLogEntry::MAX_MESSAGE_LEN + 10));
+
+        let entries = STATION_LOG_REPOSITORY.find_where(StationLogWhereClause {
+            module: Some(module_path!().to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].level, LogLevel::Info);
+        assert_eq!(entries[0].message.len(), StationLogEntry::MAX_MESSAGE_LEN);
+        assert_eq!(entries[1].level, LogLevel::Warn);
+        assert_eq!(entries[1].message, "Failed to do 2 things");
+        assert_eq!(entries[1].module, "station::core::logger::tests");
+    }
+
+    #[test]
+    fn records_the_correlation_id_of_the_trace() {
+        let trace = TraceContext::ingress(*Uuid::new_v4().as_bytes(), "tick");
+
+        crate::log_error!(trace = trace; "Failed to execute request {}", 1);
+        crate::log_error!("Failed to execute request {}", 2);
+
+        let entries = STATION_LOG_REPOSITORY.find_where(StationLogWhereClause {
+            correlation_id: Some(trace.correlation_id()),
+            ..Default::default()
+        });
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].message, "Failed to execute request 1");
+    }
+}
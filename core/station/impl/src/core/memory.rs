@@ -1,13 +1,14 @@
 use super::{MAX_WASM_PAGES, STABLE_MEMORY_BUCKET_SIZE, SYSTEM_RESERVED_MEMORY_PAGES};
-use crate::models::system::{SystemInfo, SystemState};
+use crate::models::system::{SystemInfo, SystemInitProgress, SystemState};
 use ic_stable_structures::{
-    memory_manager::{MemoryId, MemoryManager},
+    memory_manager::{MemoryId, MemoryManager, VirtualMemory},
     Cell, DefaultMemoryImpl, RestrictedMemory,
 };
 use std::cell::RefCell;
 
 pub type Memory = RestrictedMemory<DefaultMemoryImpl>;
 pub type ConfigCell = Cell<SystemState, Memory>;
+pub type InitProgressCell = Cell<SystemInitProgress, VirtualMemory<Memory>>;
 
 // Memory IDs for the main resources.
 pub const USER_MEMORY_ID: MemoryId = MemoryId::new(1);
@@ -29,16 +30,41 @@ pub const REQUEST_RESOURCE_INDEX_MEMORY_ID: MemoryId = MemoryId::new(30);
 pub const POLICY_RESOURCE_INDEX_MEMORY_ID: MemoryId = MemoryId::new(31);
 pub const REQUEST_EVALUATION_RESULT_MEMORY_ID: MemoryId = MemoryId::new(32);
 pub const EXTERNAL_CANISTER_MEMORY_ID: MemoryId = MemoryId::new(33);
+pub const EVENT_SUBSCRIBER_MEMORY_ID: MemoryId = MemoryId::new(34);
+pub const EVENT_OUTBOX_MEMORY_ID: MemoryId = MemoryId::new(35);
+pub const EXTERNAL_CANISTER_LOG_MEMORY_ID: MemoryId = MemoryId::new(36);
+pub const STATION_LOG_MEMORY_ID: MemoryId = MemoryId::new(37);
+pub const TRANSFER_ANNOTATION_MEMORY_ID: MemoryId = MemoryId::new(38);
+pub const ACCOUNT_BALANCE_SNAPSHOT_MEMORY_ID: MemoryId = MemoryId::new(39);
+pub const ADDRESS_BOOK_NAME_INDEX_MEMORY_ID: MemoryId = MemoryId::new(40);
+pub const INIT_PROGRESS_MEMORY_ID: MemoryId = MemoryId::new(41);
+pub const NOTIFICATION_BRIDGE_MEMORY_ID: MemoryId = MemoryId::new(42);
+pub const NOTIFICATION_OUTBOX_MEMORY_ID: MemoryId = MemoryId::new(43);
+pub const PRINCIPAL_LABEL_MEMORY_ID: MemoryId = MemoryId::new(44);
+pub const ARCHIVED_REQUEST_MEMORY_ID: MemoryId = MemoryId::new(45);
+pub const VOTE_RECEIPT_MEMORY_ID: MemoryId = MemoryId::new(46);
+pub const BLOB_MEMORY_ID: MemoryId = MemoryId::new(47);
+pub const VOTE_RECEIPT_HASH_MEMORY_ID: MemoryId = MemoryId::new(48);
 
 thread_local! {
   /// Static configuration of the canister.
   static CONFIG: RefCell<ConfigCell> = RefCell::new(ConfigCell::init(system_state_memory(), SystemState::Uninitialized)
     .expect("failed to initialize stable cell"));
 
+  /// Heap replica of the configuration of the canister, hydrated on init/upgrade and kept up to date
+  /// on every write so that hot paths don't need to go through the stable cell.
+  static CONFIG_REPLICA: RefCell<Option<SystemState>> = const { RefCell::new(None) };
+
   // The memory manager is used for simulating multiple memories. Given a `MemoryId` it can
   // return a memory that can be used by stable structures.
   static MEMORY_MANAGER: RefCell<MemoryManager<Memory>> =
       RefCell::new(MemoryManager::init_with_bucket_size(managed_memory(), STABLE_MEMORY_BUCKET_SIZE));
+
+  /// Checkpoint of the bootstrap of the canister, so that it can be resumed if it failed.
+  static INIT_PROGRESS: RefCell<InitProgressCell> = with_memory_manager(|memory_manager| {
+    RefCell::new(InitProgressCell::init(memory_manager.get(INIT_PROGRESS_MEMORY_ID), SystemInitProgress::default())
+      .expect("failed to initialize stable cell"))
+  });
 }
 
 /// A helper function that executes a closure with the memory manager.
@@ -64,19 +90,99 @@ pub fn managed_memory() -> Memory {
 
 /// A helper function to access the system information.
 pub fn read_system_info() -> SystemInfo {
-    CONFIG.with(|m| m.borrow().get().get().clone())
+    read_system_state().get().clone()
 }
 
-/// A helper function to write the system information to stable memory.
+/// A helper function to write the system information to stable memory, the heap replica is
+/// updated in the same call.
 pub fn write_system_info(config: SystemInfo) {
+    let state = SystemState::Initialized(config);
+
     CONFIG.with(|cell| {
         cell.borrow_mut()
-            .set(SystemState::Initialized(config))
+            .set(state.clone())
             .expect("failed to write system information");
     });
+
+    CONFIG_REPLICA.with(|replica| *replica.borrow_mut() = Some(state));
 }
 
 // A helper function to read the system state, which can be uninitialized.
 pub fn read_system_state() -> SystemState {
-    CONFIG.with(|m| m.borrow().get().clone())
+    CONFIG_REPLICA
+        .with(|replica| replica.borrow().clone())
+        .unwrap_or_else(|| CONFIG.with(|m| m.borrow().get().clone()))
+}
+
+/// A helper function to read the checkpoint of the bootstrap of the canister.
+pub fn read_init_progress() -> SystemInitProgress {
+    INIT_PROGRESS.with(|cell| cell.borrow().get().clone())
+}
+
+/// A helper function to write the checkpoint of the bootstrap of the canister to stable memory.
+pub fn write_init_progress(progress: SystemInitProgress) {
+    INIT_PROGRESS.with(|cell| {
+        cell.borrow_mut()
+            .set(progress)
+            .expect("failed to write the init progress");
+    });
+}
+
+/// Mirrors the system state from stable memory into the heap replica.
+///
+/// Must be called within the canister init or post_upgrade call, so that the replica is hydrated
+/// before serving any query.
+pub fn hydrate_system_state() {
+    let state = CONFIG.with(|m| m.borrow().get().clone());
+
+    CONFIG_REPLICA.with(|replica| *replica.borrow_mut() = Some(state));
+}
+
+/// Checks that the heap replica matches the system state stored in stable memory.
+///
+/// A replica that is not hydrated yet is considered consistent since reads fall back to stable memory.
+pub fn is_system_state_replica_consistent() -> bool {
+    CONFIG_REPLICA.with(|replica| match replica.borrow().as_ref() {
+        Some(state) => CONFIG.with(|m| m.borrow().get() == state),
+        None => true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replica_is_written_through() {
+        hydrate_system_state();
+        assert_eq!(read_system_state(), SystemState::Uninitialized);
+
+        let mut system_info = SystemInfo::default();
+        system_info.set_name("Station".to_string());
+        write_system_info(system_info.clone());
+
+        assert_eq!(read_system_info(), system_info);
+        assert!(is_system_state_replica_consistent());
+    }
+
+    #[test]
+    fn detects_replica_drift() {
+        write_system_info(SystemInfo::default());
+
+        CONFIG.with(|cell| {
+            let mut system_info = SystemInfo::default();
+            system_info.set_name("Drifted".to_string());
+
+            cell.borrow_mut()
+                .set(SystemState::Initialized(system_info))
+                .unwrap();
+        });
+
+        assert!(!is_system_state_replica_consistent());
+
+        hydrate_system_state();
+
+        assert!(is_system_state_replica_consistent());
+        assert_eq!(read_system_info().get_name(), "Drifted");
+    }
 }
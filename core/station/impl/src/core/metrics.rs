@@ -1,5 +1,8 @@
 use crate::{
-    models::{Account, AddressBookEntry, Request, RequestPolicy, Transfer, User, UserGroup},
+    models::{
+        Account, AddressBookEntry, Request, RequestCost, RequestCostPhase, RequestPolicy, Transfer,
+        User, UserGroup,
+    },
     repositories::{
         request_policy::REQUEST_POLICY_REPOSITORY, ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY,
         USER_GROUP_REPOSITORY, USER_REPOSITORY,
@@ -53,6 +56,9 @@ thread_local! {
     /// This list should be updated with new request metrics as they are added.
     pub static REQUEST_METRICS: Vec<Rc<RefCell<dyn ApplicationMetric<Request>>>> = vec![
         Rc::new(RefCell::new(MetricTotalRequestsByType)),
+        Rc::new(RefCell::new(MetricRequestInstructionsByType)),
+        Rc::new(RefCell::new(MetricRequestEstimatedCyclesByType)),
+        Rc::new(RefCell::new(MetricExpensiveRequestsByType)),
     ];
 
     /// A collection of address book entry related metrics.
@@ -408,6 +414,116 @@ impl ApplicationMetric<Request> for MetricTotalRequestsByType {
     }
 }
 
+/// Returns the instructions that were added to each phase of the cost of the request since the previous version.
+fn request_cost_increments(
+    current: &Request,
+    previous: Option<&Request>,
+) -> Vec<(RequestCostPhase, u64)> {
+    RequestCostPhase::ALL
+        .iter()
+        .filter_map(|phase| {
+            let instructions = current
+                .cost
+                .instructions(*phase)
+                .saturating_sub(previous.map_or(0, |previous| previous.cost.instructions(*phase)));
+
+            (instructions > 0).then_some((*phase, instructions))
+        })
+        .collect()
+}
+
+/// Metric for the instructions spent on the requests, labeled by their type and the phase of their lifecycle.
+pub struct MetricRequestInstructionsByType;
+
+impl ApplicationCounterVecMetric<Request> for MetricRequestInstructionsByType {
+    const LABELS: &'static [&'static str] = &["type", "phase"];
+}
+
+impl ApplicationMetric<Request> for MetricRequestInstructionsByType {
+    fn name(&self) -> &'static str {
+        "request_instructions"
+    }
+
+    fn help(&self) -> &'static str {
+        "The instructions spent on the requests, labeled by their type and the phase of their lifecycle."
+    }
+
+    fn sum(&mut self, current: &Request, previous: Option<&Request>) {
+        let operation = current.operation.to_string();
+
+        for (phase, instructions) in request_cost_increments(current, previous) {
+            let phase = phase.to_string();
+            self.inc_by(
+                SERVICE_NAME,
+                &labels! { "type" => operation.as_str(), "phase" => phase.as_str() },
+                instructions as f64,
+            );
+        }
+    }
+}
+
+/// Metric for the estimated cycles spent on the requests, labeled by their type and the phase of their lifecycle.
+pub struct MetricRequestEstimatedCyclesByType;
+
+impl ApplicationCounterVecMetric<Request> for MetricRequestEstimatedCyclesByType {
+    const LABELS: &'static [&'static str] = &["type", "phase"];
+}
+
+impl ApplicationMetric<Request> for MetricRequestEstimatedCyclesByType {
+    fn name(&self) -> &'static str {
+        "request_estimated_cycles"
+    }
+
+    fn help(&self) -> &'static str {
+        "The cycles estimated to be spent on the instructions of the requests, labeled by their type and the phase of their lifecycle."
+    }
+
+    fn sum(&mut self, current: &Request, previous: Option<&Request>) {
+        let operation = current.operation.to_string();
+
+        for (phase, instructions) in request_cost_increments(current, previous) {
+            let phase = phase.to_string();
+            self.inc_by(
+                SERVICE_NAME,
+                &labels! { "type" => operation.as_str(), "phase" => phase.as_str() },
+                RequestCost::estimated_cycles(instructions) as f64,
+            );
+        }
+    }
+}
+
+/// Metric for the number of requests that were unusually expensive, labeled by their type and the phase of
+/// their lifecycle.
+pub struct MetricExpensiveRequestsByType;
+
+impl ApplicationCounterVecMetric<Request> for MetricExpensiveRequestsByType {
+    const LABELS: &'static [&'static str] = &["type", "phase"];
+}
+
+impl ApplicationMetric<Request> for MetricExpensiveRequestsByType {
+    fn name(&self) -> &'static str {
+        "expensive_requests"
+    }
+
+    fn help(&self) -> &'static str {
+        "The number of requests that were unusually expensive, labeled by their type and the phase of their lifecycle."
+    }
+
+    fn sum(&mut self, current: &Request, previous: Option<&Request>) {
+        let operation = current.operation.to_string();
+
+        for phase in current.cost.expensive_phases.iter().filter(|phase| {
+            !previous.is_some_and(|previous| previous.cost.expensive_phases.contains(phase))
+        }) {
+            let phase = phase.to_string();
+            self.inc(
+                SERVICE_NAME,
+                &labels! { "type" => operation.as_str(), "phase" => phase.as_str() },
+            );
+        }
+    }
+}
+
 /// Metric for the total number of address book entries.
 pub struct MetricTotalAddressBookEntries;
 
@@ -673,4 +789,44 @@ mod tests {
 
         assert_eq!(MetricTotalPolicies.get(SERVICE_NAME), 2.0);
     }
+
+    #[test]
+    fn test_request_cost_metrics() {
+        let mut request = mock_request();
+        request.cost.add(RequestCostPhase::Creation, 1_000);
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let operation = request.operation.to_string();
+        let creation_labels = labels! { "type" => operation.as_str(), "phase" => "creation" };
+        let execution_labels = labels! { "type" => operation.as_str(), "phase" => "execution" };
+
+        assert_eq!(
+            MetricRequestInstructionsByType.get(SERVICE_NAME, &creation_labels),
+            1_000.0
+        );
+
+        request.cost.add(RequestCostPhase::Execution, 500);
+        request.cost.flag_expensive(RequestCostPhase::Execution);
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        assert_eq!(
+            MetricRequestInstructionsByType.get(SERVICE_NAME, &creation_labels),
+            1_000.0
+        );
+        assert_eq!(
+            MetricRequestInstructionsByType.get(SERVICE_NAME, &execution_labels),
+            500.0
+        );
+        assert_eq!(
+            MetricRequestEstimatedCyclesByType.get(SERVICE_NAME, &execution_labels),
+            200.0
+        );
+        assert_eq!(
+            MetricExpensiveRequestsByType.get(SERVICE_NAME, &execution_labels),
+            1.0
+        );
+    }
 }
@@ -1,11 +1,19 @@
 use super::authorization::Authorization;
+use super::limiter::Limiter;
+use super::usage;
 use super::CallContext;
-use crate::core::ic_cdk::api::trap;
+use crate::core::ic_cdk::api::{time, trap};
 use crate::models::resource::Resource;
-use crate::services::SYSTEM_SERVICE;
+use crate::services::{SYSTEM_SERVICE, USAGE_SERVICE};
 use crate::SERVICE_NAME;
+use candid::Principal;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::metrics::{labels, with_metrics_registry};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    time::{Duration, UNIX_EPOCH},
+};
 
 /// Creates the call context of the current request
 pub fn call_context() -> CallContext {
@@ -44,6 +52,52 @@ pub fn authorize(ctx: &CallContext, resources: &[Resource]) {
             unauthorized_resources.join(", ")
         ));
     }
+
+    if Authorization::is_active_auditor(ctx) {
+        rate_limit_auditor(ctx);
+    }
+
+    usage::record_call(ctx);
+    USAGE_SERVICE.record_call_activity(ctx);
+}
+
+const AUDITOR_RATE_LIMITER_RESOLUTION: Duration = Duration::from_secs(10);
+const AUDITOR_RATE_LIMITER_TIME_WINDOW: Duration = Duration::from_secs(300);
+const AUDITOR_RATE_LIMITER_MAX_COUNT: u64 = 300; // 300 calls per 5mins
+
+thread_local! {
+    static AUDITOR_RATE_LIMITER: RefCell<HashMap<Principal, Limiter>> = RefCell::new(HashMap::new());
+}
+
+/// Limits the number of replicated calls that an auditor can make to the station (e.g. the list and get
+/// endpoints called as update calls to get a certified response).
+///
+/// The query calls of the auditors are not limited: their state changes are discarded, so they can't be
+/// counted here and are only bounded by the rate limits of the boundary nodes.
+fn rate_limit_auditor(ctx: &CallContext) {
+    let now = UNIX_EPOCH + Duration::from_nanos(time());
+    let exceeded = AUDITOR_RATE_LIMITER.with(|limiters| {
+        let mut limiters = limiters.borrow_mut();
+        let limiter = limiters.entry(ctx.caller()).or_insert_with(|| {
+            Limiter::new(
+                AUDITOR_RATE_LIMITER_RESOLUTION,
+                AUDITOR_RATE_LIMITER_TIME_WINDOW,
+            )
+        });
+
+        limiter.purge_old(now);
+        if limiter.get_count() >= AUDITOR_RATE_LIMITER_MAX_COUNT {
+            return true;
+        }
+
+        limiter.add(now, 1);
+
+        false
+    });
+
+    if exceeded {
+        trap("Rate limit exceeded for the auditor, try again later");
+    }
 }
 
 pub fn use_canister_call_metric<T>(called_method: &str, result: &ApiResult<T>)
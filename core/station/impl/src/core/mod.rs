@@ -16,6 +16,9 @@ pub use memory::*;
 mod call_context;
 pub use call_context::*;
 
+mod providers;
+pub use providers::*;
+
 pub mod middlewares;
 pub mod observer;
 pub mod validation;
@@ -39,10 +42,16 @@ pub async fn generate_uuid_v4() -> uuid::Uuid {
 }
 
 pub mod authorization;
+pub mod certification;
+pub mod cost;
 pub mod evaluation;
 pub mod init;
+pub mod logger;
 pub mod metrics;
+pub mod outcalls;
 pub mod request;
+pub mod tracing;
+pub mod usage;
 pub mod utils;
 
 #[cfg(test)]
@@ -0,0 +1,358 @@
+//! HTTPS outcalls of the station, shared by the features that call services outside of the IC (e.g. webhooks,
+//! price feeds and screening services).
+//!
+//! The outcalls are rate limited per host and their cycles are taken from a daily budget, both are kept in the
+//! heap memory like the other rate limiters. The size of the responses is bounded and the responses go through
+//! a transform that drops what differs between the replicas, e.g. the date header, so that they reach consensus.
+
+use super::{ic_cdk::api::time, limiter::Limiter};
+use crate::errors::OutcallError;
+use candid::{CandidType, Deserialize};
+use orbit_essentials::{
+    cdk::api::management_canister::http_request::{
+        http_request, CanisterHttpRequestArgument, HttpHeader, HttpMethod, HttpResponse,
+        TransformArgs, TransformContext,
+    },
+    types::Timestamp,
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    time::{Duration, UNIX_EPOCH},
+};
+
+const RATE_LIMITER_RESOLUTION: Duration = Duration::from_secs(10);
+const RATE_LIMITER_TIME_WINDOW: Duration = Duration::from_secs(60);
+/// The maximum number of outcalls to the same host within a minute.
+pub const MAX_OUTCALLS_PER_HOST_PER_MINUTE: u64 = 30;
+
+const CYCLE_BUDGET_RESOLUTION: Duration = Duration::from_secs(60 * 60);
+const CYCLE_BUDGET_TIME_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+/// The cycles that the outcalls of the station can spend within a day.
+pub const DAILY_OUTCALL_CYCLE_BUDGET: u128 = 500_000_000_000;
+
+/// The maximum size of the responses if the outcall doesn't set one.
+pub const DEFAULT_MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+/// The largest response that the IC accepts for an outcall.
+pub const MAX_RESPONSE_BYTES: u64 = 2 * 1024 * 1024;
+
+/// The number of nodes of the subnet of the station, which determines the price of the outcalls.
+const SUBNET_SIZE: u128 = 13;
+
+/// The query method of the station that transforms the responses of the outcalls.
+pub const TRANSFORM_METHOD_NAME: &str = "transform_outcall_response";
+
+/// How the response of an outcall is transformed before the replicas agree on it.
+#[derive(CandidType, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutcallTransform {
+    /// Keeps the status and the body of the response, its headers are dropped.
+    #[default]
+    StripHeaders,
+    /// Keeps only the status of the response, for services whose response bodies differ between the
+    /// replicas (e.g. webhooks that echo a request id).
+    StatusOnly,
+}
+
+/// An HTTPS outcall of the station.
+#[derive(Clone, Debug)]
+pub struct Outcall {
+    pub url: String,
+    pub method: HttpMethod,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+    pub max_response_bytes: u64,
+    pub transform: OutcallTransform,
+}
+
+/// The response of an outcall, as it was transformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutcallResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl OutcallResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+impl Outcall {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            method: HttpMethod::GET,
+            headers: Vec::new(),
+            body: None,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            transform: OutcallTransform::default(),
+        }
+    }
+
+    pub fn post(url: impl Into<String>, body: Vec<u8>) -> Self {
+        Self {
+            method: HttpMethod::POST,
+            body: Some(body),
+            ..Self::get(url)
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_max_response_bytes(mut self, max_response_bytes: u64) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: OutcallTransform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Returns the host of the url, which the outcalls are rate limited by.
+    pub fn host(&self) -> Result<String, OutcallError> {
+        let invalid_url = || OutcallError::InvalidUrl {
+            url: self.url.clone(),
+        };
+
+        let authority = self
+            .url
+            .strip_prefix("https://")
+            .ok_or_else(invalid_url)?
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or_default();
+        let host_and_port = authority.rsplit('@').next().unwrap_or_default();
+        let host = host_and_port
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if host.is_empty() {
+            return Err(invalid_url());
+        }
+
+        Ok(host)
+    }
+
+    fn transform_context(&self) -> Vec<u8> {
+        candid::encode_one(self.transform).unwrap_or_default()
+    }
+
+    /// Returns the cycles that the IC charges for the outcall.
+    pub fn cost(&self) -> u128 {
+        let request_bytes = self.url.len()
+            + self
+                .headers
+                .iter()
+                .map(|(name, value)| name.len() + value.len())
+                .sum::<usize>()
+            + self.body.as_ref().map(Vec::len).unwrap_or_default()
+            + TRANSFORM_METHOD_NAME.len()
+            + self.transform_context().len();
+
+        (3_000_000 + 60_000 * SUBNET_SIZE) * SUBNET_SIZE
+            + 400 * SUBNET_SIZE * request_bytes as u128
+            + 800 * SUBNET_SIZE * self.max_response_bytes as u128
+    }
+}
+
+thread_local! {
+    static HOST_RATE_LIMITERS: RefCell<HashMap<String, Limiter>> = RefCell::new(HashMap::new());
+    static CYCLE_BUDGET: RefCell<Limiter> = RefCell::new(Limiter::new(CYCLE_BUDGET_RESOLUTION, CYCLE_BUDGET_TIME_WINDOW));
+}
+
+/// Reserves an outcall to the host and its cycles, failing if the host was called too often or if the
+/// cycles exceed what is left of the daily budget.
+fn reserve(host: &str, cost: u128, now: Timestamp) -> Result<(), OutcallError> {
+    let remaining = remaining_cycle_budget(now);
+    if cost > remaining {
+        return Err(OutcallError::CycleBudgetExceeded { cost, remaining });
+    }
+
+    HOST_RATE_LIMITERS.with(|limiters| {
+        let now = UNIX_EPOCH + Duration::from_nanos(now);
+        let mut limiters = limiters.borrow_mut();
+        limiters.retain(|_, limiter| {
+            limiter.purge_old(now);
+
+            limiter.get_count() > 0
+        });
+
+        let limiter = limiters
+            .entry(host.to_string())
+            .or_insert_with(|| Limiter::new(RATE_LIMITER_RESOLUTION, RATE_LIMITER_TIME_WINDOW));
+        if limiter.get_count() >= MAX_OUTCALLS_PER_HOST_PER_MINUTE {
+            return Err(OutcallError::RateLimited {
+                host: host.to_string(),
+                max_per_minute: MAX_OUTCALLS_PER_HOST_PER_MINUTE,
+            });
+        }
+
+        limiter.add(now, 1);
+
+        Ok(())
+    })?;
+
+    let now = UNIX_EPOCH + Duration::from_nanos(now);
+    CYCLE_BUDGET.with(|budget| budget.borrow_mut().add(now, cost as u64));
+
+    Ok(())
+}
+
+/// Returns the cycles that are left of the daily budget of the outcalls.
+pub fn remaining_cycle_budget(now: Timestamp) -> u128 {
+    CYCLE_BUDGET.with(|budget| {
+        let mut budget = budget.borrow_mut();
+        budget.purge_old(UNIX_EPOCH + Duration::from_nanos(now));
+
+        DAILY_OUTCALL_CYCLE_BUDGET.saturating_sub(budget.get_count() as u128)
+    })
+}
+
+/// Sends the outcall, once it passed the rate limit of its host and the cycle budget.
+pub async fn send(outcall: Outcall) -> Result<OutcallResponse, OutcallError> {
+    if outcall.max_response_bytes > MAX_RESPONSE_BYTES {
+        return Err(OutcallError::ResponseSizeOutOfRange {
+            max: MAX_RESPONSE_BYTES,
+        });
+    }
+
+    let host = outcall.host()?;
+    let cost = outcall.cost();
+    reserve(&host, cost, time())?;
+
+    let argument = CanisterHttpRequestArgument {
+        url: outcall.url.clone(),
+        max_response_bytes: Some(outcall.max_response_bytes),
+        method: outcall.method.clone(),
+        headers: outcall
+            .headers
+            .iter()
+            .map(|(name, value)| HttpHeader {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect(),
+        body: outcall.body.clone(),
+        transform: Some(TransformContext::from_name(
+            TRANSFORM_METHOD_NAME.to_string(),
+            outcall.transform_context(),
+        )),
+    };
+
+    let (response,) = http_request(argument, cost)
+        .await
+        .map_err(|(code, message)| OutcallError::Failed {
+            reason: format!("{:?}: {}", code, message),
+        })?;
+
+    Ok(OutcallResponse {
+        status: u16::try_from(&response.status.0).unwrap_or(u16::MAX),
+        body: response.body,
+    })
+}
+
+/// Transforms the response of an outcall so that the replicas can agree on it.
+pub fn transform(args: TransformArgs) -> HttpResponse {
+    let transform = candid::decode_one::<OutcallTransform>(&args.context).unwrap_or_default();
+
+    HttpResponse {
+        status: args.response.status,
+        headers: Vec::new(),
+        body: match transform {
+            OutcallTransform::StripHeaders => args.response.body,
+            OutcallTransform::StatusOnly => Vec::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: Timestamp = 1_700_000_000_000_000_000;
+
+    #[test]
+    fn parses_the_host_of_https_urls() {
+        assert_eq!(
+            Outcall::get("https://user@API.example.com:8443/v1/prices?symbol=ICP")
+                .host()
+                .unwrap(),
+            "api.example.com"
+        );
+        assert!(Outcall::get("http://api.example.com").host().is_err());
+        assert!(Outcall::get("https:///prices").host().is_err());
+    }
+
+    #[test]
+    fn larger_responses_cost_more_cycles() {
+        let outcall = Outcall::get("https://api.example.com");
+
+        assert!(
+            outcall
+                .clone()
+                .with_max_response_bytes(MAX_RESPONSE_BYTES)
+                .cost()
+                > outcall.cost()
+        );
+    }
+
+    #[test]
+    fn rate_limits_the_outcalls_per_host() {
+        for _ in 0..MAX_OUTCALLS_PER_HOST_PER_MINUTE {
+            reserve("api.example.com", 1, NOW).unwrap();
+        }
+
+        assert!(matches!(
+            reserve("api.example.com", 1, NOW),
+            Err(OutcallError::RateLimited { .. })
+        ));
+        assert!(reserve("hooks.example.com", 1, NOW).is_ok());
+        assert!(reserve("api.example.com", 1, NOW + 61 * 1_000_000_000).is_ok());
+    }
+
+    #[test]
+    fn outcalls_cannot_exceed_the_daily_cycle_budget() {
+        reserve("api.example.com", DAILY_OUTCALL_CYCLE_BUDGET - 10, NOW).unwrap();
+
+        assert_eq!(
+            reserve("hooks.example.com", 11, NOW),
+            Err(OutcallError::CycleBudgetExceeded {
+                cost: 11,
+                remaining: 10
+            })
+        );
+        assert_eq!(remaining_cycle_budget(NOW), 10);
+    }
+
+    #[test]
+    fn transform_drops_what_differs_between_replicas() {
+        let response = HttpResponse {
+            status: candid::Nat::from(200u16),
+            headers: vec![HttpHeader {
+                name: "date".to_string(),
+                value: "Mon, 01 Jan 2024 00:00:00 GMT".to_string(),
+            }],
+            body: b"{\"price\":1}".to_vec(),
+        };
+
+        let transformed = transform(TransformArgs {
+            response: response.clone(),
+            context: candid::encode_one(OutcallTransform::StripHeaders).unwrap(),
+        });
+        assert!(transformed.headers.is_empty());
+        assert_eq!(transformed.body, response.body);
+
+        let transformed = transform(TransformArgs {
+            response,
+            context: candid::encode_one(OutcallTransform::StatusOnly).unwrap(),
+        });
+        assert_eq!(transformed.status, candid::Nat::from(200u16));
+        assert!(transformed.body.is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+//! Providers of the current time and of new ids, which are injected through the `CallContext` so that the
+//! service methods that are called with a call context can be tested with deterministic clocks and ids.
+//!
+//! The code that runs without a call context, such as the request execution, the jobs and the services
+//! they call (e.g. `AccountService::fetch_account_balances` or `StationEventService::publish`), still
+//! uses `next_time` and `generate_uuid_v4` directly and can't be driven by these providers.
+
+use crate::core::{generate_uuid_v4, ic_cdk::next_time};
+use async_trait::async_trait;
+use orbit_essentials::types::Timestamp;
+use std::fmt::Debug;
+use uuid::Uuid;
+
+/// Provides the current time in nanoseconds.
+pub trait TimeProvider: Debug + Send + Sync {
+    fn now(&self) -> Timestamp;
+}
+
+/// Provides new unique ids.
+#[async_trait]
+pub trait UuidProvider: Debug + Send + Sync {
+    async fn generate_uuid(&self) -> Uuid;
+}
+
+/// Uses the time of the canister, which is strictly increasing within the same round.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CanisterTimeProvider;
+
+impl TimeProvider for CanisterTimeProvider {
+    fn now(&self) -> Timestamp {
+        next_time()
+    }
+}
+
+/// Uses the randomness of the canister to generate new v4 uuids.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CanisterUuidProvider;
+
+#[async_trait]
+impl UuidProvider for CanisterUuidProvider {
+    async fn generate_uuid(&self) -> Uuid {
+        generate_uuid_v4().await
+    }
+}
+
+#[cfg(test)]
+pub mod test_providers {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A clock that only moves forward when it is told to.
+    #[derive(Debug, Default)]
+    pub struct MockTimeProvider {
+        now: AtomicU64,
+    }
+
+    impl MockTimeProvider {
+        pub fn new(now: Timestamp) -> Self {
+            Self {
+                now: AtomicU64::new(now),
+            }
+        }
+
+        pub fn set(&self, now: Timestamp) {
+            self.now.store(now, Ordering::SeqCst);
+        }
+
+        pub fn advance(&self, duration_ns: u64) {
+            self.now.fetch_add(duration_ns, Ordering::SeqCst);
+        }
+    }
+
+    impl TimeProvider for MockTimeProvider {
+        fn now(&self) -> Timestamp {
+            self.now.load(Ordering::SeqCst)
+        }
+    }
+
+    /// Generates ids from a sequence, starting with the given seed.
+    #[derive(Debug, Default)]
+    pub struct SequentialUuidProvider {
+        next: AtomicU64,
+    }
+
+    impl SequentialUuidProvider {
+        pub fn new(seed: u64) -> Self {
+            Self {
+                next: AtomicU64::new(seed),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UuidProvider for SequentialUuidProvider {
+        async fn generate_uuid(&self) -> Uuid {
+            let next = self.next.fetch_add(1, Ordering::SeqCst);
+
+            Uuid::from_u128(next as u128)
+        }
+    }
+
+    #[tokio::test]
+    async fn sequential_uuid_provider_is_deterministic() {
+        let provider = SequentialUuidProvider::new(7);
+
+        assert_eq!(provider.generate_uuid().await, Uuid::from_u128(7));
+        assert_eq!(provider.generate_uuid().await, Uuid::from_u128(8));
+    }
+
+    #[test]
+    fn mock_time_provider_advances() {
+        let provider = MockTimeProvider::new(100);
+        assert_eq!(provider.now(), 100);
+
+        provider.advance(50);
+        assert_eq!(provider.now(), 150);
+
+        provider.set(10);
+        assert_eq!(provider.now(), 10);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     models::{
         indexes::request_index::RequestIndexFields,
         request_policy_rule::{
-            EvaluateRequestPolicyRule, RequestEvaluationResult, RequestPolicyRule,
+            EvaluateRequestPolicyRule, GroupVoteWeight, RequestEvaluationResult, RequestPolicyRule,
             RequestPolicyRuleResult,
         },
         request_specifier::{Match, UserInvolvedInPolicyRuleForRequestResource, UserSpecifier},
@@ -20,16 +20,23 @@ use std::{collections::HashSet, sync::Arc};
 
 pub struct RequestEvaluator {
     pub policy_rule_evaluator: Arc<dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>>,
+    /// The evaluator of the policies that exclude the requester from their quorums.
+    pub requester_excluded_policy_rule_evaluator:
+        Arc<dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>>,
     pub request: Request,
 }
 
 impl RequestEvaluator {
     pub fn new(
         policy_rule_evaluator: Arc<dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>>,
+        requester_excluded_policy_rule_evaluator: Arc<
+            dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>,
+        >,
         request: Request,
     ) -> Self {
         Self {
             policy_rule_evaluator,
+            requester_excluded_policy_rule_evaluator,
             request,
         }
     }
@@ -37,13 +44,7 @@ impl RequestEvaluator {
 
 impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
     fn evaluate(&self) -> Result<RequestEvaluationResult, EvaluateError> {
-        let matching_policies = self
-            .request
-            .operation
-            .to_resources()
-            .iter()
-            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
-            .collect::<Vec<_>>();
+        let matching_policies = self.request.matching_policies();
 
         if matching_policies.is_empty() {
             // Since requests handle security critical operations, we want to reject them by default if
@@ -60,9 +61,13 @@ impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
 
         // Evaluate all matching policies to get the full evaluation result.
         for policy in matching_policies {
+            let policy_rule_evaluator = match policy.exclude_requester {
+                true => &self.requester_excluded_policy_rule_evaluator,
+                false => &self.policy_rule_evaluator,
+            };
+
             // Evaluate the request policy rule.
-            let evaluation_status = self
-                .policy_rule_evaluator
+            let evaluation_status = policy_rule_evaluator
                 .evaluate((request.to_owned(), Arc::new(policy.rule)))
                 .context("failed to evaluate policy rule")?;
 
@@ -139,13 +144,7 @@ impl Evaluate<HashSet<UUID>> for RequestPossibleApproversFinder<'_> {
     fn evaluate(&self) -> Result<HashSet<UUID>, EvaluateError> {
         let mut possible_approvers = HashSet::new();
         let mut matching_groups = HashSet::new();
-        let matching_policies = self
-            .request
-            .operation
-            .to_resources()
-            .iter()
-            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
-            .collect::<Vec<_>>();
+        let matching_policies = self.request.matching_policies();
 
         for policy in matching_policies {
             let result = self.possible_approvers_policy_rule_evaluator.evaluate((
@@ -229,6 +228,15 @@ impl
                     Ok(possible_approvers)
                 }
             },
+            RequestPolicyRule::WeightedQuorum(group_weights, _) => {
+                possible_approvers.groups.extend(
+                    group_weights
+                        .iter()
+                        .map(|group_weight| group_weight.group_id),
+                );
+
+                Ok(possible_approvers)
+            }
             RequestPolicyRule::AllowListed | RequestPolicyRule::AllowListedByMetadata(_) => {
                 Ok(possible_approvers)
             }
@@ -304,18 +312,24 @@ impl<'a> RequestApprovalRightsEvaluator<'a> {
 
 impl<'a> Evaluate<bool> for RequestApprovalRightsEvaluator<'a> {
     fn evaluate(&self) -> Result<bool, EvaluateError> {
-        let matching_policies = self
-            .request
-            .resources
-            .iter()
-            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
-            .collect::<Vec<_>>();
+        let matching_rules = match &self.request.policy_rules {
+            Some(rules) => rules.to_owned(),
+            None => self
+                .request
+                .resources
+                .iter()
+                .flat_map(|resource| {
+                    REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned())
+                })
+                .map(|policy| policy.rule)
+                .collect::<Vec<_>>(),
+        };
 
-        for policy in matching_policies {
+        for rule in matching_rules {
             if self.approval_rights_evaluator.evaluate((
                 Arc::new(self.request.id.to_owned()),
                 Arc::new(self.approver_id),
-                Arc::new(policy.rule.to_owned()),
+                Arc::new(rule),
             ))? {
                 return Ok(true);
             }
@@ -329,6 +343,27 @@ pub struct RequesApprovalRightsRequestPolicyRuleEvaluator {
     pub approver_matcher: Arc<dyn Match<UserInvolvedInPolicyRuleForRequestResource>>,
 }
 
+impl RequesApprovalRightsRequestPolicyRuleEvaluator {
+    fn is_approver(
+        &self,
+        request_id: &RequestId,
+        approver_id: &UserId,
+        approver_specifier: UserSpecifier,
+    ) -> Result<bool, EvaluateError> {
+        let can_approve = self
+            .approver_matcher
+            .is_match(UserInvolvedInPolicyRuleForRequestResource {
+                request_operation_resources: REQUEST_REPOSITORY.get_resources(request_id),
+                policy_rule_user_specifier: approver_specifier,
+                user_id: approver_id.to_owned(),
+                request_id: request_id.to_owned(),
+            })
+            .context("failed to match request approvers")?;
+
+        Ok(can_approve)
+    }
+}
+
 impl
     EvaluateRequestPolicyRule<
         bool,
@@ -343,18 +378,13 @@ impl
         match criteria.as_ref() {
             RequestPolicyRule::QuorumPercentage(approver_specifier, _)
             | RequestPolicyRule::Quorum(approver_specifier, _) => {
-                let can_approve = self
-                    .approver_matcher
-                    .is_match(UserInvolvedInPolicyRuleForRequestResource {
-                        request_operation_resources: REQUEST_REPOSITORY.get_resources(&request_id),
-                        policy_rule_user_specifier: approver_specifier.to_owned(),
-                        user_id: approver_id.as_ref().to_owned(),
-                        request_id: request_id.as_ref().to_owned(),
-                    })
-                    .context("failed to match request approvers")?;
-
-                Ok(can_approve)
+                self.is_approver(&request_id, &approver_id, approver_specifier.to_owned())
             }
+            RequestPolicyRule::WeightedQuorum(group_weights, _) => self.is_approver(
+                &request_id,
+                &approver_id,
+                GroupVoteWeight::user_specifier(group_weights),
+            ),
             RequestPolicyRule::AllowListed | RequestPolicyRule::AllowListedByMetadata(_) => {
                 Ok(false)
             }
@@ -395,7 +425,11 @@ mod tests {
     use super::*;
     use crate::{
         core::{
-            evaluation::REQUEST_POLICY_RULE_EVALUATOR, middlewares::call_context, set_mock_caller,
+            evaluation::{
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR, REQUEST_POLICY_RULE_EVALUATOR,
+            },
+            middlewares::call_context,
+            set_mock_caller,
         },
         models::{
             request_approval_test_utils::{mock_approved_with_user, mock_rejected_with_user},
@@ -404,9 +438,11 @@ mod tests {
             request_test_utils::mock_request,
             resource::ResourceIds,
             user_test_utils::{self, mock_user},
-            Account, AccountKey, AddUserGroupOperation, AddUserGroupOperationInput, Blockchain,
-            BlockchainStandard, EvaluatedRequestPolicyRule, Metadata, MetadataItem, Percentage,
-            RequestOperation, RequestPolicy, RequestStatus, ADMIN_GROUP_ID,
+            Account, AccountKey, AccountOwner, AccountSetupState, AddUserGroupOperation,
+            AddUserGroupOperationInput, Blockchain, BlockchainStandard, EvaluatedRequestPolicyRule,
+            Metadata, MetadataItem, Percentage, RequestOperation, RequestPolicy, RequestStatus,
+            TransferAccountOwnershipOperation, TransferAccountOwnershipOperationInput,
+            ADMIN_GROUP_ID,
         },
         repositories::{
             request_policy::REQUEST_POLICY_REPOSITORY, ACCOUNT_REPOSITORY,
@@ -428,6 +464,8 @@ mod tests {
         let evaluator = RequestEvaluator {
             request: request.to_owned(),
             policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
         };
 
         let result = evaluator.evaluate().unwrap();
@@ -445,6 +483,7 @@ mod tests {
             user_group_id: None,
             input: AddUserGroupOperationInput {
                 name: "test".to_string(),
+                quotas: Default::default(),
             },
         });
         request.requested_by = user.id;
@@ -460,6 +499,8 @@ mod tests {
         let evaluator = RequestEvaluator {
             request: request.to_owned(),
             policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
         };
 
         let result = evaluator.evaluate().unwrap();
@@ -478,6 +519,7 @@ mod tests {
             user_group_id: None,
             input: AddUserGroupOperationInput {
                 name: "test".to_string(),
+                quotas: Default::default(),
             },
         });
         request.requested_by = user.id;
@@ -493,6 +535,8 @@ mod tests {
         let evaluator = RequestEvaluator {
             request: request.to_owned(),
             policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
         };
 
         let result = evaluator.evaluate().unwrap();
@@ -500,6 +544,52 @@ mod tests {
         assert_eq!(result.status, EvaluationStatus::Pending);
     }
 
+    #[tokio::test]
+    async fn account_ownership_transfer_waits_for_the_acknowledgment_of_the_recipient() {
+        let mut request = mock_request();
+        let mut policy = mock_request_policy();
+        let owner = user_test_utils::add_user(&[1; 16]);
+        let recipient = user_test_utils::add_user(&[2; 16]);
+
+        request.operation =
+            RequestOperation::TransferAccountOwnership(TransferAccountOwnershipOperation {
+                input: TransferAccountOwnershipOperationInput {
+                    account_id: [9; 16],
+                    from: AccountOwner::User(owner.id),
+                    to: AccountOwner::User(recipient.id),
+                },
+                acknowledged_by: None,
+            });
+        request.requested_by = owner.id;
+        request.approvals = vec![mock_approved_with_user(owner.id)];
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        policy.specifier = RequestSpecifier::EditAccount(ResourceIds::Any);
+        policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![owner.id]), 1);
+
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+
+        let evaluate = |request: &Request| {
+            RequestEvaluator {
+                request: request.to_owned(),
+                policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+                requester_excluded_policy_rule_evaluator:
+                    REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            }
+            .evaluate()
+            .unwrap()
+        };
+
+        assert_eq!(evaluate(&request).status, EvaluationStatus::Pending);
+
+        request
+            .approvals
+            .push(mock_approved_with_user(recipient.id));
+
+        assert_eq!(evaluate(&request).status, EvaluationStatus::Approved);
+    }
+
     #[tokio::test]
     async fn is_rejected_when_approved_is_not_reached() {
         let mut request = mock_request();
@@ -511,6 +601,7 @@ mod tests {
             user_group_id: None,
             input: AddUserGroupOperationInput {
                 name: "test".to_string(),
+                quotas: Default::default(),
             },
         });
         request.requested_by = user.id;
@@ -529,6 +620,8 @@ mod tests {
         let evaluator = RequestEvaluator {
             request: request.to_owned(),
             policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
         };
 
         let result = evaluator.evaluate().unwrap();
@@ -547,6 +640,7 @@ mod tests {
             user_group_id: None,
             input: AddUserGroupOperationInput {
                 name: "test".to_string(),
+                quotas: Default::default(),
             },
         });
         request.requested_by = user.id;
@@ -562,6 +656,8 @@ mod tests {
         let evaluator = RequestEvaluator {
             request: request.to_owned(),
             policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
         };
 
         let result = evaluator.evaluate().unwrap();
@@ -579,6 +675,7 @@ mod tests {
             user_group_id: None,
             input: AddUserGroupOperationInput {
                 name: "test".to_string(),
+                quotas: Default::default(),
             },
         });
         request.requested_by = user.id;
@@ -594,6 +691,77 @@ mod tests {
         let evaluator = RequestEvaluator {
             request: request.to_owned(),
             policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+        };
+
+        let result = evaluator.evaluate().unwrap();
+
+        assert_eq!(result.status, EvaluationStatus::Approved);
+    }
+
+    #[tokio::test]
+    async fn requester_approval_does_not_count_when_excluded() {
+        let mut request = mock_request();
+        let mut policy = mock_request_policy();
+        let requester = user_test_utils::add_user(&[1; 16]);
+
+        request.operation = RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "test".to_string(),
+                quotas: Default::default(),
+            },
+        });
+        request.requested_by = requester.id;
+        request.approvals = vec![mock_approved_with_user(requester.id)];
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        policy.specifier = RequestSpecifier::AddUserGroup;
+        policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Any, 1);
+        policy.exclude_requester = true;
+
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+
+        let evaluator = RequestEvaluator {
+            request: request.to_owned(),
+            policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+        };
+
+        // the requester is the only possible approver, so no one else can approve the request
+        let result = evaluator.evaluate().unwrap();
+
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+
+        let approver = user_test_utils::add_user(&[2; 16]);
+
+        let result = evaluator.evaluate().unwrap();
+
+        assert_eq!(result.status, EvaluationStatus::Pending);
+        assert_eq!(
+            result.policy_results[0].evaluated_rule,
+            EvaluatedRequestPolicyRule::Quorum {
+                min_approved: 1,
+                total_possible_approvers: 1,
+                approvers: vec![],
+                requester_excluded: true,
+            }
+        );
+
+        let evaluator = RequestEvaluator {
+            request: Request {
+                approvals: vec![
+                    mock_approved_with_user(requester.id),
+                    mock_approved_with_user(approver.id),
+                ],
+                ..request
+            },
+            policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            requester_excluded_policy_rule_evaluator:
+                REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
         };
 
         let result = evaluator.evaluate().unwrap();
@@ -638,6 +806,12 @@ mod tests {
                 transfer_request_policy_id: None,
                 configs_request_policy_id: None,
                 last_modification_timestamp: 0,
+                version: 0,
+                derivation_index: 0,
+                retired_addresses: vec![],
+                network: None,
+                review: None,
+                setup_state: AccountSetupState::Ready,
             },
         );
 
@@ -667,6 +841,9 @@ mod tests {
                     }),
                 ]),
                 specifier: RequestSpecifier::Transfer(ResourceIds::Any),
+                version: 0,
+                checklist: Vec::new(),
+                exclude_requester: false,
             },
         );
 
@@ -694,6 +871,7 @@ mod tests {
                             min_approved: 1,
                             approvers: vec![],
                             total_possible_approvers: 3,
+                            requester_excluded: false,
                         }
                     },
                     RequestPolicyRuleResult {
@@ -717,6 +895,7 @@ mod tests {
                     request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
                     decision: RequestApprovalStatusDTO::Approved,
                     reason: None,
+                    acknowledged_checklist: None,
                 },
                 &call_context(),
             )
@@ -741,6 +920,7 @@ mod tests {
                             min_approved: 1,
                             approvers: vec![user_1.id,],
                             total_possible_approvers: 3,
+                            requester_excluded: false,
                         }
                     },
                     RequestPolicyRuleResult {
@@ -0,0 +1,241 @@
+//! Tracing of the work of the station across calls.
+//!
+//! A correlation id is assigned at each ingress, like the creation of a request or a tick of an execution
+//! job, and the spans of the work done for it are recorded under that id, including the inter-canister
+//! calls. Since the work of an ingress can be interleaved with other messages at every await, spans are
+//! threaded explicitly with a `TraceContext` instead of being kept as the current state of the canister.
+//!
+//! Traces are kept on the heap for the most recent correlation ids only and are lost on upgrade, whereas
+//! the log entries written for a correlation id are kept in the station log.
+
+use crate::core::ic_cdk::api::time;
+use orbit_essentials::types::{Timestamp, UUID};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+thread_local! {
+    static TRACES: RefCell<Traces> = RefCell::new(Traces::default());
+}
+
+/// The maximum number of traces retained, the oldest traces are dropped first.
+pub const MAX_RETAINED_TRACES: usize = 1_000;
+/// The maximum number of spans recorded for a trace, further spans are not recorded.
+pub const MAX_SPANS_PER_TRACE: usize = 200;
+
+#[derive(Default)]
+struct Traces {
+    traces: HashMap<UUID, Trace>,
+    /// The correlation ids of the retained traces, oldest first.
+    order: VecDeque<UUID>,
+}
+
+/// The spans recorded for a correlation id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    pub correlation_id: UUID,
+    pub spans: Vec<TraceSpan>,
+}
+
+/// A unit of work of a trace, the spans without a parent are the ingresses of the trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceSpan {
+    /// The id of the span, which is its position within the trace.
+    pub id: u32,
+    pub parent_id: Option<u32>,
+    pub name: String,
+    pub details: Option<String>,
+    pub started_at: Timestamp,
+    /// When the span finished, spans that are still running or never finished have none.
+    pub finished_at: Option<Timestamp>,
+    pub error: Option<String>,
+}
+
+/// A handle to a span of a trace, passed along to the calls made for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceContext {
+    correlation_id: UUID,
+    /// The span of the handle, none if it wasn't recorded because the trace was full.
+    span_id: Option<u32>,
+}
+
+impl TraceContext {
+    /// Opens the span of an ingress, starting the trace of the correlation id if it doesn't exist yet.
+    ///
+    /// Several ingresses can share a correlation id, e.g. the creation and the approvals of a request
+    /// are traced under the id of the request.
+    pub fn ingress(correlation_id: UUID, name: &str) -> Self {
+        Self::open(correlation_id, None, name, None)
+    }
+
+    /// Opens a span within the span of this handle.
+    pub fn child(&self, name: &str, details: Option<String>) -> Self {
+        Self::open(self.correlation_id, self.span_id, name, details)
+    }
+
+    pub fn correlation_id(&self) -> UUID {
+        self.correlation_id
+    }
+
+    /// Marks the span as finished.
+    pub fn finish(&self) {
+        self.close(None);
+    }
+
+    /// Marks the span as finished with the given error.
+    pub fn fail(&self, error: impl Display) {
+        self.close(Some(error.to_string()));
+    }
+
+    /// Finishes the span with the outcome of the result, which is passed through.
+    pub fn record<T, E: Display>(&self, result: Result<T, E>) -> Result<T, E> {
+        match &result {
+            Ok(_) => self.finish(),
+            Err(error) => self.fail(error),
+        }
+
+        result
+    }
+
+    fn open(
+        correlation_id: UUID,
+        parent_id: Option<u32>,
+        name: &str,
+        details: Option<String>,
+    ) -> Self {
+        let started_at = time();
+
+        TRACES.with(|traces| {
+            let mut traces = traces.borrow_mut();
+            if !traces.traces.contains_key(&correlation_id) {
+                traces.order.push_back(correlation_id);
+                traces.traces.insert(
+                    correlation_id,
+                    Trace {
+                        correlation_id,
+                        spans: Vec::new(),
+                    },
+                );
+
+                while traces.order.len() > MAX_RETAINED_TRACES {
+                    if let Some(oldest) = traces.order.pop_front() {
+                        traces.traces.remove(&oldest);
+                    }
+                }
+            }
+
+            let trace = traces
+                .traces
+                .get_mut(&correlation_id)
+                .expect("trace was just inserted");
+
+            let span_id = if trace.spans.len() < MAX_SPANS_PER_TRACE {
+                let span_id = trace.spans.len() as u32;
+                trace.spans.push(TraceSpan {
+                    id: span_id,
+                    parent_id,
+                    name: name.to_string(),
+                    details,
+                    started_at,
+                    finished_at: None,
+                    error: None,
+                });
+
+                Some(span_id)
+            } else {
+                None
+            };
+
+            Self {
+                correlation_id,
+                span_id,
+            }
+        })
+    }
+
+    fn close(&self, error: Option<String>) {
+        let Some(span_id) = self.span_id else {
+            return;
+        };
+
+        let finished_at = time();
+
+        TRACES.with(|traces| {
+            // the trace might have been dropped in the meantime to make room for newer ones
+            if let Some(span) = traces
+                .borrow_mut()
+                .traces
+                .get_mut(&self.correlation_id)
+                .and_then(|trace| trace.spans.get_mut(span_id as usize))
+            {
+                span.finished_at = Some(finished_at);
+                span.error = error;
+            }
+        });
+    }
+}
+
+/// Returns the trace of the correlation id, if it's still retained.
+pub fn get_trace(correlation_id: &UUID) -> Option<Trace> {
+    TRACES.with(|traces| traces.borrow().traces.get(correlation_id).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn records_the_call_tree_of_a_correlation_id() {
+        let correlation_id = *Uuid::new_v4().as_bytes();
+
+        let creation = TraceContext::ingress(correlation_id, "create_request");
+        let evaluation = creation.child("evaluate_request", None);
+        evaluation.finish();
+        creation.finish();
+
+        let execution = TraceContext::ingress(correlation_id, "execute_request");
+        let call = execution.child("submit_transaction", Some("icp".to_string()));
+        let _ = call.record(Err::<(), _>("ledger unavailable"));
+
+        let trace = get_trace(&correlation_id).unwrap();
+
+        assert_eq!(trace.spans.len(), 4);
+        assert_eq!(trace.spans[1].parent_id, Some(0));
+        assert_eq!(trace.spans[2].parent_id, None);
+        assert_eq!(trace.spans[3].parent_id, Some(2));
+        assert_eq!(trace.spans[3].details, Some("icp".to_string()));
+        assert_eq!(trace.spans[3].error, Some("ledger unavailable".to_string()));
+        assert!(trace.spans[3].finished_at.is_some());
+        assert!(trace.spans[2].finished_at.is_none());
+    }
+
+    #[test]
+    fn drops_the_oldest_traces_once_full() {
+        let first = *Uuid::new_v4().as_bytes();
+        TraceContext::ingress(first, "tick").finish();
+
+        for _ in 0..MAX_RETAINED_TRACES {
+            TraceContext::ingress(*Uuid::new_v4().as_bytes(), "tick").finish();
+        }
+
+        assert!(get_trace(&first).is_none());
+    }
+
+    #[test]
+    fn stops_recording_spans_once_the_trace_is_full() {
+        let correlation_id = *Uuid::new_v4().as_bytes();
+        let ingress = TraceContext::ingress(correlation_id, "tick");
+
+        for _ in 0..MAX_SPANS_PER_TRACE {
+            ingress.child("execute_request", None).finish();
+        }
+
+        assert_eq!(
+            get_trace(&correlation_id).unwrap().spans.len(),
+            MAX_SPANS_PER_TRACE
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! Tracks the recent usage of the station by each calling principal.
+//!
+//! The usage is kept in the heap memory like the rate limiters, so it starts over after an upgrade and
+//! only the calls that change the state of the station are counted, since the state changes of query
+//! calls are discarded.
+//!
+//! For the same reason, the principals that no longer used the station within the usage window are
+//! evicted while the calls are recorded, every `EVICTION_INTERVAL` calls.
+
+use super::{limiter::Limiter, CallContext};
+use crate::models::{RequestOperationType, UserId};
+use candid::Principal;
+use orbit_essentials::types::Timestamp;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const USAGE_RESOLUTION: Duration = Duration::from_secs(60 * 60);
+/// The rolling window over which the usage of each principal is kept, which is 1 day.
+pub const USAGE_TIME_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+const SPIKE_RESOLUTION: Duration = Duration::from_secs(60);
+const SPIKE_TIME_WINDOW: Duration = Duration::from_secs(60 * 60);
+/// The minimum number of requests created within an hour for it to be considered a spike.
+pub const REQUEST_CREATION_SPIKE_MIN_COUNT: u64 = 20;
+/// How many times the hourly average of the usage window the creations of the last hour must exceed.
+pub const REQUEST_CREATION_SPIKE_FACTOR: u64 = 4;
+/// The number of recorded calls after which the stale principals are evicted.
+const EVICTION_INTERVAL: u64 = 1_000;
+
+/// The usage of the station by a principal within the usage window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrincipalUsage {
+    pub principal: Principal,
+    pub user_id: Option<UserId>,
+    pub calls: u64,
+    pub approvals: u64,
+    pub requests_created: Vec<(RequestOperationType, u64)>,
+}
+
+#[derive(Debug)]
+struct PrincipalUsageTracker {
+    user_id: Option<UserId>,
+    calls: Limiter,
+    approvals: Limiter,
+    requests_created: BTreeMap<RequestOperationType, Limiter>,
+    recent_requests_created: Limiter,
+    spike_reported_at: Option<SystemTime>,
+}
+
+impl PrincipalUsageTracker {
+    fn new(user_id: Option<UserId>) -> Self {
+        Self {
+            user_id,
+            calls: Limiter::new(USAGE_RESOLUTION, USAGE_TIME_WINDOW),
+            approvals: Limiter::new(USAGE_RESOLUTION, USAGE_TIME_WINDOW),
+            requests_created: BTreeMap::new(),
+            recent_requests_created: Limiter::new(SPIKE_RESOLUTION, SPIKE_TIME_WINDOW),
+            spike_reported_at: None,
+        }
+    }
+
+    fn purge_old(&mut self, now: SystemTime) {
+        self.calls.purge_old(now);
+        self.approvals.purge_old(now);
+        self.recent_requests_created.purge_old(now);
+        self.requests_created.retain(|_, limiter| {
+            limiter.purge_old(now);
+
+            limiter.get_count() > 0
+        });
+    }
+
+    fn is_stale(&self) -> bool {
+        self.calls.get_count() == 0
+            && self.approvals.get_count() == 0
+            && self.requests_created.is_empty()
+    }
+
+    fn to_usage(&self, principal: Principal) -> PrincipalUsage {
+        PrincipalUsage {
+            principal,
+            user_id: self.user_id,
+            calls: self.calls.get_count(),
+            approvals: self.approvals.get_count(),
+            requests_created: self
+                .requests_created
+                .iter()
+                .map(|(operation_type, limiter)| (operation_type.clone(), limiter.get_count()))
+                .collect(),
+        }
+    }
+}
+
+thread_local! {
+    static PRINCIPAL_USAGE: RefCell<HashMap<Principal, PrincipalUsageTracker>> = RefCell::new(HashMap::new());
+    static CALLS_SINCE_EVICTION: Cell<u64> = const { Cell::new(0) };
+}
+
+fn to_system_time(timestamp: Timestamp) -> SystemTime {
+    UNIX_EPOCH + Duration::from_nanos(timestamp)
+}
+
+fn with_tracker<F, R>(ctx: &CallContext, f: F) -> R
+where
+    F: FnOnce(&mut PrincipalUsageTracker, SystemTime) -> R,
+{
+    let now = to_system_time(ctx.now());
+
+    PRINCIPAL_USAGE.with(|usage| {
+        let mut usage = usage.borrow_mut();
+        let tracker = usage
+            .entry(ctx.caller())
+            .or_insert_with(|| PrincipalUsageTracker::new(None));
+
+        tracker.user_id = ctx.user().map(|user| user.id);
+        tracker.purge_old(now);
+
+        f(tracker, now)
+    })
+}
+
+/// Purges the usage outside of the usage window and removes the principals that have none left.
+fn evict_stale(usage: &mut HashMap<Principal, PrincipalUsageTracker>, now: SystemTime) {
+    usage.retain(|_, tracker| {
+        tracker.purge_old(now);
+
+        !tracker.is_stale()
+    });
+}
+
+/// Counts a call of the principal to the station.
+///
+/// The stale principals are evicted every `EVICTION_INTERVAL` calls, so that the cost of the eviction is
+/// amortized over the calls.
+pub fn record_call(ctx: &CallContext) {
+    with_tracker(ctx, |tracker, now| tracker.calls.add(now, 1));
+
+    let calls_since_eviction = CALLS_SINCE_EVICTION.with(|calls| {
+        let count = calls.get() + 1;
+        calls.set(count % EVICTION_INTERVAL);
+
+        count
+    });
+
+    if calls_since_eviction >= EVICTION_INTERVAL {
+        let now = to_system_time(ctx.now());
+
+        PRINCIPAL_USAGE.with(|usage| evict_stale(&mut usage.borrow_mut(), now));
+    }
+}
+
+/// Counts an approval submitted by the principal.
+pub fn record_approval(ctx: &CallContext) {
+    with_tracker(ctx, |tracker, now| tracker.approvals.add(now, 1));
+}
+
+/// Counts a request created by the principal.
+///
+/// Returns the number of requests created within the last hour if they are a spike compared to the
+/// usual usage of the principal that was not reported yet.
+pub fn record_request_created(
+    ctx: &CallContext,
+    operation_type: RequestOperationType,
+) -> Option<u64> {
+    with_tracker(ctx, |tracker, now| {
+        tracker
+            .requests_created
+            .entry(operation_type)
+            .or_insert_with(|| Limiter::new(USAGE_RESOLUTION, USAGE_TIME_WINDOW))
+            .add(now, 1);
+        tracker.recent_requests_created.add(now, 1);
+
+        let recent = tracker.recent_requests_created.get_count();
+        let total = tracker
+            .requests_created
+            .values()
+            .map(Limiter::get_count)
+            .sum::<u64>();
+        let hours_in_window = USAGE_TIME_WINDOW.as_secs() / SPIKE_TIME_WINDOW.as_secs();
+
+        let is_spike = recent >= REQUEST_CREATION_SPIKE_MIN_COUNT
+            && recent * hours_in_window > REQUEST_CREATION_SPIKE_FACTOR * total;
+        let was_reported = tracker
+            .spike_reported_at
+            .is_some_and(|reported_at| reported_at + SPIKE_TIME_WINDOW > now);
+
+        if !is_spike || was_reported {
+            return None;
+        }
+
+        tracker.spike_reported_at = Some(now);
+
+        Some(recent)
+    })
+}
+
+/// Returns the usage of all the principals that used the station within the usage window.
+///
+/// This is called from a query whose state changes are discarded, so the stale principals are only left
+/// out of the result, they are evicted by `record_call`.
+pub fn list_principal_usage(now: Timestamp) -> Vec<PrincipalUsage> {
+    let now = to_system_time(now);
+
+    PRINCIPAL_USAGE.with(|usage| {
+        usage
+            .borrow_mut()
+            .iter_mut()
+            .filter_map(|(principal, tracker)| {
+                tracker.purge_old(now);
+
+                (!tracker.is_stale()).then(|| tracker.to_usage(*principal))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_providers::MockTimeProvider;
+    use std::sync::Arc;
+
+    #[test]
+    fn reports_a_spike_of_request_creations_once() {
+        let ctx = CallContext::new(Principal::from_slice(&[7; 29]))
+            .with_time_provider(Arc::new(MockTimeProvider::new(1_000_000_000_000_000_000)));
+
+        let spikes = (0..REQUEST_CREATION_SPIKE_MIN_COUNT + 5)
+            .filter_map(|_| record_request_created(&ctx, RequestOperationType::Transfer))
+            .collect::<Vec<_>>();
+
+        assert_eq!(spikes, vec![REQUEST_CREATION_SPIKE_MIN_COUNT]);
+
+        let usage = list_principal_usage(ctx.now())
+            .into_iter()
+            .find(|usage| usage.principal == ctx.caller())
+            .unwrap();
+
+        assert_eq!(
+            usage.requests_created,
+            vec![(
+                RequestOperationType::Transfer,
+                REQUEST_CREATION_SPIKE_MIN_COUNT + 5
+            )]
+        );
+    }
+
+    #[test]
+    fn evicts_the_stale_principals_while_recording_calls() {
+        let now = 1_000_000_000_000_000_000;
+        let stale_ctx = CallContext::new(Principal::from_slice(&[1; 29]))
+            .with_time_provider(Arc::new(MockTimeProvider::new(now)));
+        record_call(&stale_ctx);
+
+        let later = now + (USAGE_TIME_WINDOW + USAGE_RESOLUTION).as_nanos() as u64;
+        let ctx = CallContext::new(Principal::from_slice(&[2; 29]))
+            .with_time_provider(Arc::new(MockTimeProvider::new(later)));
+        for _ in 0..EVICTION_INTERVAL - 2 {
+            record_call(&ctx);
+        }
+
+        let is_tracked = |principal: Principal| {
+            PRINCIPAL_USAGE.with(|usage| usage.borrow().contains_key(&principal))
+        };
+
+        assert!(is_tracked(stale_ctx.caller()));
+        assert!(list_principal_usage(later)
+            .iter()
+            .all(|usage| usage.principal != stale_ctx.caller()));
+
+        record_call(&ctx);
+
+        assert!(!is_tracked(stale_ctx.caller()));
+        assert!(is_tracked(ctx.caller()));
+    }
+}
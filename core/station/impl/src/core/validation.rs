@@ -1,10 +1,10 @@
-use std::{hash::Hash, sync::Arc};
+use std::{collections::BTreeMap, fmt::Display, hash::Hash, sync::Arc};
 
 #[cfg(test)]
 use std::cell::RefCell;
 
 use crate::{
-    errors::{ExternalCanisterValidationError, RecordValidationError},
+    errors::{ExternalCanisterValidationError, RecordValidationError, RequestError},
     factories::blockchains::InternetComputer,
     models::{
         resource::{Resource, ResourceId, ResourceIds},
@@ -227,3 +227,54 @@ impl EnsureIdExists<UUID> for EnsureNotification {
 }
 
 impl EnsureResourceIdExists for EnsureNotification {}
+
+/// Collects the field-level errors found while validating the input of a request operation.
+#[derive(Debug, Default)]
+pub struct OperationFieldErrors {
+    errors: BTreeMap<String, String>,
+}
+
+impl OperationFieldErrors {
+    /// Records an error of the field, the errors of a field that already has one are joined.
+    pub fn add(&mut self, field: &str, message: impl Display) {
+        self.errors
+            .entry(field.to_string())
+            .and_modify(|existing| existing.push_str(&format!("; {}", message)))
+            .or_insert_with(|| message.to_string());
+    }
+
+    /// Records the error of the check as an error of the field, if the check failed.
+    pub fn check<E: Display>(&mut self, field: &str, result: Result<(), E>) {
+        if let Err(err) = result {
+            self.add(field, err);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the recorded errors by field, for the callers that report them without failing.
+    pub fn into_errors(self) -> BTreeMap<String, String> {
+        self.errors
+    }
+
+    /// Fails with all the recorded errors, if any.
+    pub fn into_result(self) -> Result<(), RequestError> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(RequestError::InvalidOperationInput {
+            errors: self.errors,
+        })
+    }
+}
+
+/// Validates the input of a request operation when the request is created.
+///
+/// All the invalid fields are reported at once, so that invalid requests are rejected before any
+/// approval is spent on them instead of failing once executed.
+pub trait ValidateOperationInput {
+    fn validate_input(&self, errors: &mut OperationFieldErrors);
+}
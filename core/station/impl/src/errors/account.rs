@@ -39,6 +39,23 @@ pub enum AccountError {
     /// An account with the given name already exists.
     #[error(r#"An account with the given name already exists."#)]
     AccountNameAlreadyExists,
+    /// The account was edited since the edit was requested.
+    #[error(r#"The account was edited since the edit was requested, expected version {expected_version} but found {current_version}."#)]
+    VersionConflict {
+        expected_version: u64,
+        current_version: u64,
+    },
+    /// The addresses of the accounts of the blockchain standard can't be rotated.
+    #[error(
+        r#"The addresses of {blockchain} accounts of the {standard} standard can't be rotated."#
+    )]
+    AddressRotationNotSupported {
+        blockchain: String,
+        standard: String,
+    },
+    /// The address of the account is still being generated.
+    #[error(r#"The address of the account is still being generated."#)]
+    AddressPending { account_id: String },
 }
 
 impl DetailableError for AccountError {
@@ -91,6 +108,26 @@ impl DetailableError for AccountError {
                 details.insert("max".to_string(), max.to_string());
                 Some(details)
             }
+            AccountError::VersionConflict {
+                expected_version,
+                current_version,
+            } => {
+                details.insert("expected_version".to_string(), expected_version.to_string());
+                details.insert("current_version".to_string(), current_version.to_string());
+                Some(details)
+            }
+            AccountError::AddressRotationNotSupported {
+                blockchain,
+                standard,
+            } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("standard".to_string(), standard.to_string());
+                Some(details)
+            }
+            AccountError::AddressPending { account_id } => {
+                details.insert("account_id".to_string(), account_id.to_string());
+                Some(details)
+            }
             _ => None,
         }
     }
@@ -0,0 +1,64 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for blob errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum BlobError {
+    /// The blob was not found.
+    #[error(r#"The blob {id} was not found."#)]
+    NotFound { id: String },
+    /// The media type of the content is not supported.
+    #[error(r#"The content type {content_type} is not supported, it must be one of: {supported_content_types}."#)]
+    UnsupportedContentType {
+        content_type: String,
+        supported_content_types: String,
+    },
+    /// The size of the content is invalid.
+    #[error(
+        r#"The content must not be empty and its size must be at most {max_size_bytes} bytes."#
+    )]
+    InvalidSize { max_size_bytes: usize },
+    /// The blobs of the station would exceed their total size.
+    #[error(r#"The blobs of the station cannot exceed {max_total_size_bytes} bytes in total, remove unused blobs first."#)]
+    StorageFull { max_total_size_bytes: usize },
+    /// The blob is referenced by the branding of the station.
+    #[error(r#"The blob {id} is in use by the branding of the station."#)]
+    InUse { id: String },
+}
+
+impl DetailableError for BlobError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            BlobError::NotFound { id } | BlobError::InUse { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            BlobError::UnsupportedContentType {
+                content_type,
+                supported_content_types,
+            } => {
+                details.insert("content_type".to_string(), content_type.to_string());
+                details.insert(
+                    "supported_content_types".to_string(),
+                    supported_content_types.to_string(),
+                );
+                Some(details)
+            }
+            BlobError::InvalidSize { max_size_bytes } => {
+                details.insert("max_size_bytes".to_string(), max_size_bytes.to_string());
+                Some(details)
+            }
+            BlobError::StorageFull {
+                max_total_size_bytes,
+            } => {
+                details.insert(
+                    "max_total_size_bytes".to_string(),
+                    max_total_size_bytes.to_string(),
+                );
+                Some(details)
+            }
+        }
+    }
+}
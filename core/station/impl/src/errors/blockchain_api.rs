@@ -2,6 +2,9 @@ use orbit_essentials::api::DetailableError;
 use std::collections::HashMap;
 use thiserror::Error;
 
+/// The error code of [`BlockchainApiError::BlockchainNetworkError`] once converted to an `ApiError`.
+pub const BLOCKCHAIN_NETWORK_ERROR_CODE: &str = "BLOCKCHAIN_NETWORK_ERROR";
+
 /// Container for blockchain api errors.
 #[derive(Error, Debug, Eq, PartialEq, Clone)]
 pub enum BlockchainApiError {
@@ -17,6 +20,18 @@ pub enum BlockchainApiError {
     /// The to address is invalid.
     #[error("The to address '{address}' is invalid: {error}")]
     InvalidToAddress { address: String, error: String },
+    /// The transaction history is not available for the blockchain.
+    #[error(r#"The transaction history is not available for the blockchain."#)]
+    TransactionHistoryNotSupported,
+    /// The transaction history could not be fetched.
+    #[error(r#"The transaction history could not be fetched."#)]
+    FetchTransactionsFailed { info: String },
+    /// Payment requests are not available for the blockchain.
+    #[error(r#"Payment requests are not available for the blockchain."#)]
+    PaymentRequestNotSupported,
+    /// The name of the destination could not be resolved to an address.
+    #[error("The name '{name}' could not be resolved: {info}")]
+    NameResolutionFailed { name: String, info: String },
 }
 
 impl DetailableError for BlockchainApiError {
@@ -40,6 +55,17 @@ impl DetailableError for BlockchainApiError {
                 details.insert("error".to_string(), error.to_string());
                 Some(details)
             }
+            BlockchainApiError::TransactionHistoryNotSupported => None,
+            BlockchainApiError::PaymentRequestNotSupported => None,
+            BlockchainApiError::FetchTransactionsFailed { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            BlockchainApiError::NameResolutionFailed { name, info } => {
+                details.insert("name".to_string(), name.to_string());
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
         }
     }
 }
@@ -0,0 +1,40 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for fiat valuation errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum FiatValuationError {
+    /// The fiat valuation of the balances is not enabled.
+    #[error(r#"The fiat valuation of the balances is not enabled."#)]
+    Disabled,
+    /// The balances can't be valued in the currency.
+    #[error(
+        r#"The balances can't be valued in {currency}, the supported currencies are: {supported}."#
+    )]
+    UnsupportedCurrency { currency: String, supported: String },
+    /// The prices could not be fetched from the price feed.
+    #[error(r#"The prices could not be fetched from the price feed: {reason}"#)]
+    PriceFeedFailed { reason: String },
+}
+
+impl DetailableError for FiatValuationError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            FiatValuationError::Disabled => None,
+            FiatValuationError::UnsupportedCurrency {
+                currency,
+                supported,
+            } => {
+                details.insert("currency".to_string(), currency.to_string());
+                details.insert("supported".to_string(), supported.to_string());
+                Some(details)
+            }
+            FiatValuationError::PriceFeedFailed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+        }
+    }
+}
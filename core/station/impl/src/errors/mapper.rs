@@ -29,6 +29,16 @@ pub enum MapperError {
         /// The malformed UUID.
         malformed_uuid: String,
     },
+    /// The provided datetime is not a valid RFC3339 datetime.
+    #[error(r#"The provided {field} is not a valid RFC3339 datetime: {reason}"#)]
+    MalformedTimestamp {
+        /// The field of the input that holds the datetime.
+        field: String,
+        /// The malformed datetime.
+        timestamp: String,
+        /// Why the datetime is invalid.
+        reason: String,
+    },
     /// The provided nat cannot be converted to u64.
     #[error(r#"The provided nat cannot be converted to u64."#)]
     NatConversionError {
@@ -84,6 +94,16 @@ impl DetailableError for MapperError {
                 details.insert("malformed_uuid".to_string(), malformed_uuid.to_string());
                 Some(details)
             }
+            MapperError::MalformedTimestamp {
+                field,
+                timestamp,
+                reason,
+            } => {
+                details.insert("field".to_string(), field.to_string());
+                details.insert("timestamp".to_string(), timestamp.to_string());
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
             MapperError::NatConversionError { nat } => {
                 details.insert("nat".to_string(), nat.to_string());
                 Some(details)
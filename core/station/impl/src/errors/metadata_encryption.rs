@@ -0,0 +1,44 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for metadata encryption errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum MetadataEncryptionError {
+    /// The metadata encryption is not enabled.
+    #[error(r#"The metadata encryption is not enabled."#)]
+    Disabled,
+    /// The transport public key is invalid.
+    #[error(
+        r#"The transport public key must be a compressed G1 point of {expected_length} bytes."#
+    )]
+    InvalidTransportPublicKey { expected_length: usize },
+    /// The key could not be derived.
+    #[error(r#"The key could not be derived: {reason}"#)]
+    KeyDerivationFailed { reason: String },
+    /// The key is only handed out to the users of the station.
+    #[error(r#"The metadata encryption key is only available to the users of the station."#)]
+    NotAUser,
+    /// The caller derived too many keys recently.
+    #[error(r#"Too many metadata encryption keys were requested, try again later."#)]
+    RateLimited,
+}
+
+impl DetailableError for MetadataEncryptionError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            MetadataEncryptionError::InvalidTransportPublicKey { expected_length } => {
+                details.insert("expected_length".to_string(), expected_length.to_string());
+                Some(details)
+            }
+            MetadataEncryptionError::KeyDerivationFailed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+            MetadataEncryptionError::Disabled
+            | MetadataEncryptionError::NotAUser
+            | MetadataEncryptionError::RateLimited => None,
+        }
+    }
+}
@@ -70,3 +70,24 @@ pub use validation::*;
 
 mod disaster_recovery;
 pub use disaster_recovery::*;
+
+mod station_event;
+pub use station_event::*;
+
+mod metadata_encryption;
+pub use metadata_encryption::*;
+
+mod outcall;
+pub use outcall::*;
+
+mod network;
+pub use network::*;
+
+mod fiat_valuation;
+pub use fiat_valuation::*;
+
+mod principal_label;
+pub use principal_label::*;
+
+mod blob;
+pub use blob::*;
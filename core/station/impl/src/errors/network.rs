@@ -0,0 +1,70 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for network errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum NetworkError {
+    /// The network was not found.
+    #[error(r#"The network {id} of the {blockchain} blockchain was not found."#)]
+    NotFound { blockchain: String, id: String },
+    /// The network already exists.
+    #[error(r#"The network {id} of the {blockchain} blockchain already exists."#)]
+    AlreadyExists { blockchain: String, id: String },
+    /// The network is invalid.
+    #[error(r#"The network is invalid: {info}"#)]
+    ValidationError { info: String },
+    /// The accounts of the blockchain network are already frozen.
+    #[error(
+        r#"The accounts of the {blockchain} blockchain are already frozen on the given network."#
+    )]
+    AlreadyFrozen {
+        blockchain: String,
+        network: Option<String>,
+    },
+    /// The accounts of the blockchain network are not frozen.
+    #[error(r#"The accounts of the {blockchain} blockchain are not frozen on the given network."#)]
+    NotFrozen {
+        blockchain: String,
+        network: Option<String>,
+    },
+    /// The station already has too many frozen blockchain networks.
+    #[error(r#"The station cannot have more than {max} frozen blockchain networks."#)]
+    TooManyFreezes { max: usize },
+}
+
+impl DetailableError for NetworkError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            NetworkError::NotFound { blockchain, id }
+            | NetworkError::AlreadyExists { blockchain, id } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            NetworkError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            NetworkError::AlreadyFrozen {
+                blockchain,
+                network,
+            }
+            | NetworkError::NotFrozen {
+                blockchain,
+                network,
+            } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                if let Some(network) = network {
+                    details.insert("network".to_string(), network.to_string());
+                }
+                Some(details)
+            }
+            NetworkError::TooManyFreezes { max } => {
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -14,6 +14,9 @@ pub enum NotificationError {
     /// The notification has failed validation.
     #[error(r#"The notification has failed validation."#)]
     ValidationError { info: String },
+    /// The inbox of the notifications must be a canister.
+    #[error(r#"The inbox {principal} of the notifications must be a canister."#)]
+    InboxNotCanister { principal: String },
 }
 
 impl DetailableError for NotificationError {
@@ -32,6 +35,10 @@ impl DetailableError for NotificationError {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
             }
+            NotificationError::InboxNotCanister { principal } => {
+                details.insert("principal".to_string(), principal.to_string());
+                Some(details)
+            }
         }
     }
 }
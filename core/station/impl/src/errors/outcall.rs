@@ -0,0 +1,56 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for HTTPS outcall errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum OutcallError {
+    /// The url of the outcall is invalid.
+    #[error(r#"The url `{url}` is invalid, outcalls must be made to https urls."#)]
+    InvalidUrl { url: String },
+    /// Too many outcalls were made to the host recently.
+    #[error(r#"Too many outcalls were made to {host} recently, at most {max_per_minute} per minute are allowed."#)]
+    RateLimited { host: String, max_per_minute: u64 },
+    /// The outcall would exceed the daily cycle budget of the outcalls.
+    #[error(r#"The outcall costs {cost} cycles but only {remaining} cycles are left in the daily budget of the outcalls."#)]
+    CycleBudgetExceeded { cost: u128, remaining: u128 },
+    /// The maximum size of the response is out of range.
+    #[error(r#"The maximum size of the response must not exceed {max} bytes."#)]
+    ResponseSizeOutOfRange { max: u64 },
+    /// The outcall failed.
+    #[error(r#"The outcall failed: {reason}"#)]
+    Failed { reason: String },
+}
+
+impl DetailableError for OutcallError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            OutcallError::InvalidUrl { url } => {
+                details.insert("url".to_string(), url.to_string());
+                Some(details)
+            }
+            OutcallError::RateLimited {
+                host,
+                max_per_minute,
+            } => {
+                details.insert("host".to_string(), host.to_string());
+                details.insert("max_per_minute".to_string(), max_per_minute.to_string());
+                Some(details)
+            }
+            OutcallError::CycleBudgetExceeded { cost, remaining } => {
+                details.insert("cost".to_string(), cost.to_string());
+                details.insert("remaining".to_string(), remaining.to_string());
+                Some(details)
+            }
+            OutcallError::ResponseSizeOutOfRange { max } => {
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
+            OutcallError::Failed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for principal label errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum PrincipalLabelError {
+    /// The address has no label.
+    #[error(r#"The address {address} has no label."#)]
+    NotFound { address: String },
+    /// The address length is invalid.
+    #[error(r#"The address length must be between {min_length} and {max_length}."#)]
+    InvalidAddressLength {
+        min_length: usize,
+        max_length: usize,
+    },
+    /// The label length is invalid.
+    #[error(r#"The label length must be between {min_length} and {max_length}."#)]
+    InvalidLabelLength {
+        min_length: usize,
+        max_length: usize,
+    },
+    /// The labels could not be fetched from the list canister.
+    #[error(r#"The labels could not be fetched from the list canister: {reason}"#)]
+    ImportFailed { reason: String },
+}
+
+impl DetailableError for PrincipalLabelError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            PrincipalLabelError::NotFound { address } => {
+                details.insert("address".to_string(), address.to_string());
+                Some(details)
+            }
+            PrincipalLabelError::InvalidAddressLength {
+                min_length,
+                max_length,
+            }
+            | PrincipalLabelError::InvalidLabelLength {
+                min_length,
+                max_length,
+            } => {
+                details.insert("min_length".to_string(), min_length.to_string());
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            PrincipalLabelError::ImportFailed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
-use crate::errors::{ExternalCanisterValidationError, RecordValidationError, ValidationError};
+use crate::errors::{
+    ExternalCanisterValidationError, MapperError, RecordValidationError,
+    RequestPolicyRuleValidationError, ValidationError,
+};
 use orbit_essentials::api::DetailableError;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use thiserror::Error;
 
 /// Container for system request errors.
@@ -35,6 +38,54 @@ pub enum RequestError {
     /// Request policy not found for id `{id}`.
     #[error(r#"Request policy not found for id `{id}`"#)]
     PolicyNotFound { id: String },
+    /// The input of the request operation is invalid, the errors are keyed by the invalid field.
+    #[error(r#"The input of the request operation is invalid."#)]
+    InvalidOperationInput { errors: BTreeMap<String, String> },
+    /// The API version requested by the client is no longer supported.
+    #[error(r#"The API version requested by the client is no longer supported."#)]
+    UnsupportedApiVersion {
+        api_version: u32,
+        min_api_version: u32,
+    },
+    /// The request operation can't be represented in the API version of the client.
+    #[error(r#"The request operation can't be represented in the API version of the client."#)]
+    OperationNotSupportedByApiVersion {
+        request_id: String,
+        api_version: u32,
+        required_api_version: u32,
+    },
+    /// No transfers can be requested from the account while it is under review.
+    #[error(
+        r#"No transfers can be requested from the account while it is under review: {reason}"#
+    )]
+    AccountUnderReview {
+        account_id: String,
+        reason: String,
+        expires_at: String,
+    },
+    /// No transfers can be requested from the account until its address is generated.
+    #[error(r#"No transfers can be requested from the account until its address is generated."#)]
+    AccountAddressPending { account_id: String },
+    /// The content of the request was compacted, so it can no longer be encoded as it was approved.
+    #[error(r#"The content of the request was compacted, its canonical encoding is no longer available."#)]
+    CanonicalContentCompacted { request_id: String },
+    /// The request was archived and could not be fetched from the archive canister.
+    #[error(
+        r#"The request was archived and could not be fetched from the archive canister: {reason}"#
+    )]
+    ArchiveUnavailable { request_id: String, reason: String },
+    /// The approver has no recorded vote on the request.
+    #[error(r#"The approver has no recorded vote on the request."#)]
+    VoteReceiptNotFound {
+        request_id: String,
+        approver_id: String,
+    },
+    /// The certificate is only available in query calls.
+    #[error(r#"The certificate is only available in query calls."#)]
+    CertificateUnavailable,
+    /// The votes are being certified again after an upgrade.
+    #[error(r#"The votes are being certified again after an upgrade, try again later."#)]
+    VoteCertificationPending,
 }
 
 impl DetailableError for RequestError {
@@ -69,6 +120,62 @@ impl DetailableError for RequestError {
                 details.insert("id".to_string(), id.to_string());
                 Some(details)
             }
+            RequestError::InvalidOperationInput { errors } => {
+                details.extend(errors.clone());
+                Some(details)
+            }
+            RequestError::UnsupportedApiVersion {
+                api_version,
+                min_api_version,
+            } => {
+                details.insert("api_version".to_string(), api_version.to_string());
+                details.insert("min_api_version".to_string(), min_api_version.to_string());
+                Some(details)
+            }
+            RequestError::OperationNotSupportedByApiVersion {
+                request_id,
+                api_version,
+                required_api_version,
+            } => {
+                details.insert("request_id".to_string(), request_id.to_string());
+                details.insert("api_version".to_string(), api_version.to_string());
+                details.insert(
+                    "required_api_version".to_string(),
+                    required_api_version.to_string(),
+                );
+                Some(details)
+            }
+            RequestError::AccountUnderReview {
+                account_id,
+                reason,
+                expires_at,
+            } => {
+                details.insert("account_id".to_string(), account_id.to_string());
+                details.insert("reason".to_string(), reason.to_string());
+                details.insert("expires_at".to_string(), expires_at.to_string());
+                Some(details)
+            }
+            RequestError::AccountAddressPending { account_id } => {
+                details.insert("account_id".to_string(), account_id.to_string());
+                Some(details)
+            }
+            RequestError::CanonicalContentCompacted { request_id } => {
+                details.insert("request_id".to_string(), request_id.to_string());
+                Some(details)
+            }
+            RequestError::ArchiveUnavailable { request_id, reason } => {
+                details.insert("request_id".to_string(), request_id.to_string());
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+            RequestError::VoteReceiptNotFound {
+                request_id,
+                approver_id,
+            } => {
+                details.insert("request_id".to_string(), request_id.to_string());
+                details.insert("approver_id".to_string(), approver_id.to_string());
+                Some(details)
+            }
             _ => None,
         }
     }
@@ -96,11 +203,43 @@ impl From<ExternalCanisterValidationError> for RequestError {
     }
 }
 
+impl From<RequestPolicyRuleValidationError> for RequestError {
+    fn from(err: RequestPolicyRuleValidationError) -> RequestError {
+        match err {
+            RequestPolicyRuleValidationError::InvalidPercentage { percentage } => {
+                RequestError::ValidationError {
+                    info: format!(
+                        "Invalid percentage {}, must be between 0 and 100",
+                        percentage
+                    ),
+                }
+            }
+            RequestPolicyRuleValidationError::InvalidVoteWeight { group_id } => {
+                RequestError::ValidationError {
+                    info: format!(
+                        "Invalid vote weight of user group {}, must be greater than 0",
+                        group_id
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl From<MapperError> for RequestError {
+    fn from(err: MapperError) -> RequestError {
+        RequestError::ValidationError {
+            info: err.to_string(),
+        }
+    }
+}
+
 impl From<ValidationError> for RequestError {
     fn from(err: ValidationError) -> RequestError {
         match err {
             ValidationError::RecordValidationError(err) => err.into(),
             ValidationError::ExternalCanisterValidationError(err) => err.into(),
+            ValidationError::RequestPolicyRuleValidationError(err) => err.into(),
         }
     }
 }
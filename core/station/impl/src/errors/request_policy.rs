@@ -1,4 +1,7 @@
-use crate::errors::{ExternalCanisterValidationError, RecordValidationError, ValidationError};
+use crate::errors::{
+    ExternalCanisterValidationError, RecordValidationError, RequestPolicyRuleValidationError,
+    ValidationError,
+};
 use orbit_essentials::api::DetailableError;
 use std::collections::HashMap;
 use thiserror::Error;
@@ -9,6 +12,12 @@ pub enum RequestPolicyError {
     /// The request policy has failed validation.
     #[error(r#"The request policy has failed validation."#)]
     ValidationError { info: String },
+    /// The request policy was edited since the edit was requested.
+    #[error(r#"The request policy was edited since the edit was requested, expected version {expected_version} but found {current_version}."#)]
+    VersionConflict {
+        expected_version: u64,
+        current_version: u64,
+    },
 }
 
 impl DetailableError for RequestPolicyError {
@@ -19,6 +28,14 @@ impl DetailableError for RequestPolicyError {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
             }
+            RequestPolicyError::VersionConflict {
+                expected_version,
+                current_version,
+            } => {
+                details.insert("expected_version".to_string(), expected_version.to_string());
+                details.insert("current_version".to_string(), current_version.to_string());
+                Some(details)
+            }
         }
     }
 }
@@ -47,11 +64,35 @@ impl From<ExternalCanisterValidationError> for RequestPolicyError {
     }
 }
 
+impl From<RequestPolicyRuleValidationError> for RequestPolicyError {
+    fn from(err: RequestPolicyRuleValidationError) -> RequestPolicyError {
+        match err {
+            RequestPolicyRuleValidationError::InvalidPercentage { percentage } => {
+                RequestPolicyError::ValidationError {
+                    info: format!(
+                        "Invalid percentage {}, must be between 0 and 100",
+                        percentage
+                    ),
+                }
+            }
+            RequestPolicyRuleValidationError::InvalidVoteWeight { group_id } => {
+                RequestPolicyError::ValidationError {
+                    info: format!(
+                        "Invalid vote weight of user group {}, must be greater than 0",
+                        group_id
+                    ),
+                }
+            }
+        }
+    }
+}
+
 impl From<ValidationError> for RequestPolicyError {
     fn from(err: ValidationError) -> RequestPolicyError {
         match err {
             ValidationError::RecordValidationError(err) => err.into(),
             ValidationError::ExternalCanisterValidationError(err) => err.into(),
+            ValidationError::RequestPolicyRuleValidationError(err) => err.into(),
         }
     }
 }
@@ -0,0 +1,41 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for station event errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum StationEventError {
+    /// Only canisters can subscribe to station events.
+    #[error(r#"Only canisters can subscribe to station events, {principal} is not a canister."#)]
+    SubscriberNotCanister { principal: String },
+    /// The maximum number of event subscribers was reached.
+    #[error(r#"The maximum number of {max} event subscribers was reached."#)]
+    TooManySubscribers { max: usize },
+    /// The caller is not subscribed to station events.
+    #[error(r#"The caller is not subscribed to station events."#)]
+    SubscriberNotFound,
+    /// The event subscriber has failed validation.
+    #[error(r#"The event subscriber has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for StationEventError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            StationEventError::SubscriberNotCanister { principal } => {
+                details.insert("principal".to_string(), principal.to_string());
+                Some(details)
+            }
+            StationEventError::TooManySubscribers { max } => {
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
+            StationEventError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            StationEventError::SubscriberNotFound => None,
+        }
+    }
+}
@@ -8,6 +8,12 @@ pub enum SystemError {
     /// The initialization of the canister failed.
     #[error(r#"The initialization of the canister failed due to {reason}"#)]
     InitFailed { reason: String },
+    /// The bootstrap of the station can only be retried while it is not completed.
+    #[error(r#"The initialization of the canister has already completed."#)]
+    InitAlreadyCompleted,
+    /// The bootstrap of the station is running already.
+    #[error(r#"The initialization of the canister is already running."#)]
+    InitAlreadyRunning,
     #[error(r#"The canister needs at least one admin"#)]
     NoAdminsSpecified,
     #[error(r#"There are too many admins defined, max allowed is {max}."#)]
@@ -16,6 +22,23 @@ pub enum SystemError {
     UpgradeFailed { reason: String },
     #[error(r#"No station upgrade request is processing."#)]
     NoStationUpgradeProcessing,
+    /// The values of the station can only be injected into a valid station upgrade arg.
+    #[error(r#"The upgrade arg is invalid: {reason}"#)]
+    InvalidUpgradeArg { reason: String },
+    #[error(r#"The ledger canisters configuration is invalid: {reason}"#)]
+    InvalidLedgerCanisters { reason: String },
+    /// The station refuses the calls that change its state while it is in maintenance mode.
+    #[error(r#"The station is in maintenance mode and refuses the calls that change its state."#)]
+    StationInMaintenance { reason: Option<String> },
+    /// The station refuses the calls that change its state once it is decommissioned.
+    #[error(r#"The station is decommissioned and refuses the calls that change its state."#)]
+    StationDecommissioned { reason: Option<String> },
+    /// The operation type was disabled on the station.
+    #[error(r#"The {operation_type} operation is disabled on the station."#)]
+    OperationDisabled { operation_type: String },
+    /// Neither the spans nor the log entries of the trace are retained anymore.
+    #[error(r#"The trace with correlation id {correlation_id} was not found."#)]
+    TraceNotFound { correlation_id: String },
 }
 
 impl DetailableError for SystemError {
@@ -37,6 +60,40 @@ impl DetailableError for SystemError {
 
                 Some(details)
             }
+            SystemError::InvalidUpgradeArg { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+
+                Some(details)
+            }
+            SystemError::InvalidLedgerCanisters { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+
+                Some(details)
+            }
+            SystemError::StationInMaintenance {
+                reason: Some(reason),
+            } => {
+                details.insert("reason".to_string(), reason.to_string());
+
+                Some(details)
+            }
+            SystemError::StationDecommissioned {
+                reason: Some(reason),
+            } => {
+                details.insert("reason".to_string(), reason.to_string());
+
+                Some(details)
+            }
+            SystemError::OperationDisabled { operation_type } => {
+                details.insert("operation_type".to_string(), operation_type.to_string());
+
+                Some(details)
+            }
+            SystemError::TraceNotFound { correlation_id } => {
+                details.insert("correlation_id".to_string(), correlation_id.to_string());
+
+                Some(details)
+            }
             _ => Some(details),
         }
     }
@@ -16,6 +16,12 @@ pub enum TransferError {
     /// Transfer execution failed due to {reason}.
     #[error(r#"Transfer execution failed due to `{reason}`."#)]
     ExecutionError { reason: String },
+    /// The outcome of the transfer submission is unknown, the blockchain might have accepted it.
+    #[error(r#"The outcome of the transfer submission is unknown due to `{reason}`."#)]
+    SubmissionOutcomeUnknown { reason: String },
+    /// Only completed transfers can be annotated.
+    #[error(r#"The transfer {transfer_id} is not completed."#)]
+    TransferNotCompleted { transfer_id: String },
 }
 
 impl DetailableError for TransferError {
@@ -38,6 +44,14 @@ impl DetailableError for TransferError {
                 details.insert("reason".to_string(), reason.to_string());
                 Some(details)
             }
+            TransferError::SubmissionOutcomeUnknown { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+            TransferError::TransferNotCompleted { transfer_id } => {
+                details.insert("transfer_id".to_string(), transfer_id.to_string());
+                Some(details)
+            }
         }
     }
 }
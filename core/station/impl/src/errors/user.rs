@@ -79,6 +79,15 @@ pub enum UserError {
     // error for when non existent user group is getting added
     #[error(r#"The user group {group_id} does not exist."#)]
     UserGroupDoesNotExist { group_id: String },
+    /// The user was edited since the edit was requested.
+    #[error(r#"The user was edited since the edit was requested, expected version {expected_version} but found {current_version}."#)]
+    VersionConflict {
+        expected_version: u64,
+        current_version: u64,
+    },
+    /// The scheduled membership of the user in a group is invalid.
+    #[error(r#"The scheduled membership in the user group {group_id} is invalid: {info}"#)]
+    InvalidScheduledMembership { group_id: String, info: String },
 }
 
 impl DetailableError for UserError {
@@ -129,6 +138,19 @@ impl DetailableError for UserError {
                 details.insert("user".to_string(), user.to_string());
                 Some(details)
             }
+            UserError::VersionConflict {
+                expected_version,
+                current_version,
+            } => {
+                details.insert("expected_version".to_string(), expected_version.to_string());
+                details.insert("current_version".to_string(), current_version.to_string());
+                Some(details)
+            }
+            UserError::InvalidScheduledMembership { group_id, info } => {
+                details.insert("group_id".to_string(), group_id.to_string());
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
             _ => None,
         }
     }
@@ -33,6 +33,36 @@ pub enum UserGroupError {
         /// The user group id.
         id: String,
     },
+    /// The user group is still referenced and no reassignment of its references was given.
+    #[error("The user group with id {id} is still referenced by request policies, permissions or users.")]
+    StillReferenced {
+        /// The user group id.
+        id: String,
+    },
+    /// The replacement of the removed user group is invalid.
+    #[error("The user group with id {id} can't replace the removed user group.")]
+    InvalidReplacement {
+        /// The replacement user group id.
+        id: String,
+    },
+    /// The members of the user group have too many pending requests.
+    #[error(
+        r#"The members of the user group "{name}" reached their quota of {max} pending requests."#
+    )]
+    PendingRequestsQuotaExceeded {
+        /// The user group name.
+        name: String,
+        /// The maximum number of pending requests.
+        max: u32,
+    },
+    /// The members of the user group created too many accounts.
+    #[error(r#"The members of the user group "{name}" reached their quota of {max} accounts."#)]
+    AccountsQuotaExceeded {
+        /// The user group name.
+        name: String,
+        /// The maximum number of accounts.
+        max: u32,
+    },
 }
 
 impl DetailableError for UserGroupError {
@@ -55,10 +85,18 @@ impl DetailableError for UserGroupError {
                 details.insert("id".to_string(), id.to_string());
                 Some(details)
             }
-            UserGroupError::CannotDeleteDisasterRecoveryCommittee { id } => {
+            UserGroupError::CannotDeleteDisasterRecoveryCommittee { id }
+            | UserGroupError::StillReferenced { id }
+            | UserGroupError::InvalidReplacement { id } => {
                 details.insert("id".to_string(), id.to_string());
                 Some(details)
             }
+            UserGroupError::PendingRequestsQuotaExceeded { name, max }
+            | UserGroupError::AccountsQuotaExceeded { name, max } => {
+                details.insert("name".to_string(), name.to_string());
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
         }
     }
 }
@@ -7,6 +7,7 @@ use thiserror::Error;
 pub enum ValidationError {
     RecordValidationError(RecordValidationError),
     ExternalCanisterValidationError(ExternalCanisterValidationError),
+    RequestPolicyRuleValidationError(RequestPolicyRuleValidationError),
 }
 
 impl Display for ValidationError {
@@ -14,6 +15,7 @@ impl Display for ValidationError {
         match self {
             ValidationError::RecordValidationError(err) => write!(f, "{}", err),
             ValidationError::ExternalCanisterValidationError(err) => write!(f, "{}", err),
+            ValidationError::RequestPolicyRuleValidationError(err) => write!(f, "{}", err),
         }
     }
 }
@@ -23,6 +25,7 @@ impl DetailableError for ValidationError {
         match self {
             ValidationError::RecordValidationError(err) => err.details(),
             ValidationError::ExternalCanisterValidationError(err) => err.details(),
+            ValidationError::RequestPolicyRuleValidationError(err) => err.details(),
         }
     }
 }
@@ -39,6 +42,12 @@ impl From<ExternalCanisterValidationError> for ValidationError {
     }
 }
 
+impl From<RequestPolicyRuleValidationError> for ValidationError {
+    fn from(err: RequestPolicyRuleValidationError) -> ValidationError {
+        ValidationError::RequestPolicyRuleValidationError(err)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum RecordValidationError {
     #[error(r#"The {model_name} {id} does not exist."#)]
@@ -77,3 +86,28 @@ impl DetailableError for ExternalCanisterValidationError {
         }
     }
 }
+
+#[derive(Debug, Error)]
+pub enum RequestPolicyRuleValidationError {
+    #[error(r#"The percentage {percentage} is invalid, it must be between 0 and 100."#)]
+    InvalidPercentage { percentage: u16 },
+    #[error(r#"The vote weight of the user group {group_id} must be greater than 0."#)]
+    InvalidVoteWeight { group_id: String },
+}
+
+impl DetailableError for RequestPolicyRuleValidationError {
+    fn details(&self) -> Option<std::collections::HashMap<String, String>> {
+        let mut details = std::collections::HashMap::new();
+
+        match self {
+            RequestPolicyRuleValidationError::InvalidPercentage { percentage } => {
+                details.insert("percentage".to_string(), percentage.to_string());
+                Some(details)
+            }
+            RequestPolicyRuleValidationError::InvalidVoteWeight { group_id } => {
+                details.insert("group_id".to_string(), group_id.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -1,15 +1,17 @@
-use super::InternetComputer;
+use super::{InternetComputer, MockBlockchain};
 use crate::{
-    errors::FactoryError,
-    models::{Account, Blockchain, BlockchainStandard, Metadata, Transfer},
+    errors::{BlockchainApiError, FactoryError},
+    models::{Account, Blockchain, BlockchainStandard, Metadata, Transfer, TransferFeeStrategy},
 };
 use async_trait::async_trait;
 use num_bigint::BigUint;
-use orbit_essentials::api::ApiError;
+use orbit_essentials::{api::ApiError, types::Timestamp};
 use std::collections::HashMap;
 
 pub const TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY: &str = "block_height";
 pub const TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY: &str = "transaction_hash";
+/// Set when the transaction was not submitted again because a previous submission was already accepted.
+pub const TRANSACTION_SUBMITTED_DETAILS_RECONCILED_KEY: &str = "reconciled";
 
 pub type BlockchainApiResult<T> = Result<T, ApiError>;
 
@@ -44,6 +46,32 @@ impl BlockchainTransactionSubmitted {
     }
 }
 
+/// A transaction of an account as recorded by the blockchain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockchainTransaction {
+    /// The blockchain specific id of the transaction (e.g. the block height).
+    pub id: String,
+    /// The address that sent the funds, if any (e.g. mints have no sender).
+    pub from_address: Option<String>,
+    /// The address that received the funds, if any (e.g. burns have no receiver).
+    pub to_address: Option<String>,
+    pub amount: BigUint,
+    pub fee: Option<BigUint>,
+    pub memo: Option<String>,
+    /// The creation time set by the sender of the transaction, if any.
+    pub created_at: Option<Timestamp>,
+    /// The time at which the transaction was added to the blockchain, if known.
+    pub timestamp: Option<Timestamp>,
+}
+
+/// A page of the transactions of an account, the most recent first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockchainTransactionsPage {
+    pub transactions: Vec<BlockchainTransaction>,
+    /// The cursor to fetch the next page, if there are more transactions.
+    pub next_cursor: Option<String>,
+}
+
 #[async_trait]
 pub trait BlockchainApi: Send + Sync {
     /// Generates a new address for the given account.
@@ -63,6 +91,17 @@ pub trait BlockchainApi: Send + Sync {
         account: &Account,
     ) -> Result<BlockchainTransactionFee, ApiError>;
 
+    /// Returns the transaction fee estimate for the given fee strategy.
+    ///
+    /// Blockchains with a fixed transaction fee can rely on the default implementation.
+    async fn estimate_transaction_fee(
+        &self,
+        account: &Account,
+        _strategy: &TransferFeeStrategy,
+    ) -> Result<BlockchainTransactionFee, ApiError> {
+        self.transaction_fee(account).await
+    }
+
     /// Returns the default network.
     fn default_network(&self) -> String;
 
@@ -72,6 +111,38 @@ pub trait BlockchainApi: Send + Sync {
         account: &Account,
         transfer: &Transfer,
     ) -> Result<BlockchainTransactionSubmitted, ApiError>;
+
+    /// Returns a page of the on-chain transactions of the given account, starting after the cursor.
+    ///
+    /// Blockchains without a transaction history source rely on the default implementation.
+    async fn list_transactions(
+        &self,
+        _account: &Account,
+        _cursor: Option<String>,
+        _limit: u16,
+    ) -> Result<BlockchainTransactionsPage, ApiError> {
+        Err(BlockchainApiError::TransactionHistoryNotSupported)?
+    }
+
+    /// Returns the standardized payment URI to request a payment to the given account.
+    ///
+    /// Blockchains without a payment request format rely on the default implementation.
+    fn payment_request_uri(
+        &self,
+        _account: &Account,
+        _amount: Option<&BigUint>,
+        _memo: Option<&str>,
+    ) -> Result<String, ApiError> {
+        Err(BlockchainApiError::PaymentRequestNotSupported)?
+    }
+
+    /// Resolves the destination to an address if it is a human-readable name (e.g. `alice.icp`).
+    ///
+    /// Returns `None` if the destination is not a name, blockchains without a naming service rely on
+    /// the default implementation.
+    async fn resolve_destination_name(&self, _to: &str) -> Result<Option<String>, ApiError> {
+        Ok(None)
+    }
 }
 
 #[derive(Debug)]
@@ -82,6 +153,14 @@ impl BlockchainApiFactory {
         blockchain: &Blockchain,
         standard: &BlockchainStandard,
     ) -> Result<Box<dyn BlockchainApi>, FactoryError> {
+        // the stations installed for local development simulate all the blockchains
+        if MockBlockchain::is_enabled() {
+            return Ok(Box::new(MockBlockchain::create(
+                blockchain.clone(),
+                standard.clone(),
+            )));
+        }
+
         match (blockchain, standard) {
             (Blockchain::InternetComputer, BlockchainStandard::Native) => {
                 Ok(Box::new(InternetComputer::create()))
@@ -0,0 +1,82 @@
+//! Candid types of the ICP index canister that are used to fetch the transaction history of an account.
+
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountIdentifierTransactionsArgs {
+    pub account_identifier: String,
+    /// The id of the most recent transaction to return, the most recent transactions are returned if not set.
+    pub start: Option<u64>,
+    pub max_results: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountIdentifierTransactionsResponse {
+    pub balance: u64,
+    pub transactions: Vec<TransactionWithId>,
+    pub oldest_tx_id: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountIdentifierTransactionsError {
+    pub message: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum GetAccountIdentifierTransactionsResult {
+    Ok(GetAccountIdentifierTransactionsResponse),
+    Err(GetAccountIdentifierTransactionsError),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransactionWithId {
+    pub id: u64,
+    pub transaction: IndexTransaction,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IndexTransaction {
+    pub memo: u64,
+    pub icrc1_memo: Option<serde_bytes::ByteBuf>,
+    pub operation: IndexOperation,
+    pub created_at_time: Option<IndexTimeStamp>,
+    pub timestamp: Option<IndexTimeStamp>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum IndexOperation {
+    Approve {
+        fee: IndexTokens,
+        from: String,
+        allowance: IndexTokens,
+        expires_at: Option<IndexTimeStamp>,
+        spender: String,
+        expected_allowance: Option<IndexTokens>,
+    },
+    Burn {
+        from: String,
+        amount: IndexTokens,
+        spender: Option<String>,
+    },
+    Mint {
+        to: String,
+        amount: IndexTokens,
+    },
+    Transfer {
+        to: String,
+        fee: IndexTokens,
+        from: String,
+        amount: IndexTokens,
+        spender: Option<String>,
+    },
+}
+
+#[derive(CandidType, Deserialize, Clone, Copy, Debug)]
+pub struct IndexTokens {
+    pub e8s: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Copy, Debug)]
+pub struct IndexTimeStamp {
+    pub timestamp_nanos: u64,
+}
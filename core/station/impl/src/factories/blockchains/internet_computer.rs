@@ -1,13 +1,20 @@
 use super::{
-    BlockchainApi, BlockchainApiResult, BlockchainTransactionFee, BlockchainTransactionSubmitted,
-    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    icp_index::{
+        GetAccountIdentifierTransactionsArgs, GetAccountIdentifierTransactionsResult,
+        IndexOperation, TransactionWithId,
+    },
+    BlockchainApi, BlockchainApiResult, BlockchainTransaction, BlockchainTransactionFee,
+    BlockchainTransactionSubmitted, BlockchainTransactionsPage,
+    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY, TRANSACTION_SUBMITTED_DETAILS_RECONCILED_KEY,
     TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
 };
 use crate::{
-    core::ic_cdk::api::{id as station_canister_self_id, print},
+    core::{ic_cdk::api::id as station_canister_self_id, read_system_state},
     errors::BlockchainApiError,
+    log_error,
     mappers::HelperMapper,
     models::{
+        system::{LedgerCanisterConfig, NameServiceConfig, SystemState},
         Account, AccountId, Blockchain, BlockchainStandard, Metadata, Transfer, METADATA_MEMO_KEY,
     },
 };
@@ -15,9 +22,10 @@ use async_trait::async_trait;
 use byteorder::{BigEndian, ByteOrder};
 use candid::Principal;
 use ic_ledger_types::{
-    account_balance, query_blocks, transfer, AccountBalanceArgs, AccountIdentifier, GetBlocksArgs,
-    Memo, QueryBlocksResponse, Subaccount, Timestamp, Tokens, Transaction, TransferArgs,
-    TransferError as LedgerTransferError, DEFAULT_FEE,
+    account_balance, query_blocks, transfer, AccountBalanceArgs, AccountIdentifier, BlockIndex,
+    GetBlocksArgs, Memo, Operation, QueryBlocksResponse, Subaccount, Timestamp, Tokens,
+    Transaction, TransferArgs, TransferError as LedgerTransferError, DEFAULT_FEE,
+    DEFAULT_SUBACCOUNT,
 };
 use num_bigint::BigUint;
 use orbit_essentials::{
@@ -63,14 +71,27 @@ impl Display for InternetComputerNetwork {
 pub struct SubmitTransferResponse {
     pub block_height: u64,
     pub transaction_hash: Option<String>,
+    /// Whether the block height is the one of a previous submission of the same transaction.
+    pub reconciled: bool,
 }
 
 impl InternetComputer {
     pub const BLOCKCHAIN: Blockchain = Blockchain::InternetComputer;
     pub const STANDARD: BlockchainStandard = BlockchainStandard::Native;
-    pub const ICP_LEDGER_CANISTER_ID: &'static str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
+    pub const ICP_SYMBOL: &'static str = "ICP";
+    pub const DEFAULT_ICP_LEDGER_CANISTER_ID: &'static str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
+    pub const DEFAULT_ICP_INDEX_CANISTER_ID: &'static str = "qhbym-qaaaa-aaaaa-aaafq-cai";
     pub const DECIMALS: u32 = 8;
     pub const MAIN_NETWORK: InternetComputerNetwork = InternetComputerNetwork::Mainnet;
+    /// The number of most recent ledger blocks that are searched for a previous submission of a transfer.
+    ///
+    /// The archived blocks are not searched, a previous submission that is older than these blocks is still
+    /// deduplicated by the ledger since the resubmissions are bounded by `Transfer::RESUBMISSION_WINDOW_NS`.
+    pub const DEDUPLICATION_LOOKBACK_BLOCKS: u64 = 2_000;
+    /// The suffix of the destination names that are resolved through the configured name service.
+    pub const NAME_SUFFIX: &'static str = ".icp";
+    /// The record of a name that holds the principal it points to.
+    const NAME_PRINCIPAL_RECORD_KEY: &'static str = "principal.icp";
 
     pub fn create() -> Self {
         Self {
@@ -89,8 +110,88 @@ impl InternetComputer {
         subaccount_id
     }
 
+    /// Generates the subaccount id of the current address of the given station_account.
+    ///
+    /// The derivation index of the account is appended to its id once the address was rotated, so that the
+    /// subaccount of accounts that were never rotated stays the same.
+    pub fn subaccount_from_station_account(station_account: &Account) -> [u8; 32] {
+        let mut subaccount_id = Self::subaccount_from_station_account_id(&station_account.id);
+        if station_account.derivation_index > 0 {
+            let offset = station_account.id.len();
+            subaccount_id[offset..offset + 4]
+                .copy_from_slice(&station_account.derivation_index.to_be_bytes());
+        }
+
+        subaccount_id
+    }
+
+    /// Returns the ICP ledger canister id, which is the mainnet ledger unless another one is configured.
     pub fn ledger_canister_id() -> Principal {
-        Principal::from_text(Self::ICP_LEDGER_CANISTER_ID).unwrap()
+        Self::configured_ledger_canister()
+            .map(|config| config.ledger_canister_id)
+            .unwrap_or_else(|| Principal::from_text(Self::DEFAULT_ICP_LEDGER_CANISTER_ID).unwrap())
+    }
+
+    /// Returns the ICP index canister id, which is the mainnet index unless another one is configured.
+    pub fn index_canister_id() -> Principal {
+        Self::configured_ledger_canister()
+            .and_then(|config| config.index_canister_id)
+            .unwrap_or_else(|| Principal::from_text(Self::DEFAULT_ICP_INDEX_CANISTER_ID).unwrap())
+    }
+
+    fn configured_ledger_canister() -> Option<LedgerCanisterConfig> {
+        match read_system_state() {
+            SystemState::Initialized(system_info) => system_info
+                .find_ledger_canister(Self::ICP_SYMBOL, &Self::MAIN_NETWORK.to_string())
+                .cloned(),
+            SystemState::Uninitialized => None,
+        }
+    }
+
+    /// Whether the destination is a name (e.g. `alice.icp`) rather than an account identifier.
+    ///
+    /// Account identifiers are hex encoded, so they can never end with the name suffix.
+    pub fn is_destination_name(to: &str) -> bool {
+        to.len() > Self::NAME_SUFFIX.len() && to.to_lowercase().ends_with(Self::NAME_SUFFIX)
+    }
+
+    fn configured_name_service() -> NameServiceConfig {
+        match read_system_state() {
+            SystemState::Initialized(system_info) => *system_info.get_name_service(),
+            SystemState::Uninitialized => NameServiceConfig::Disabled,
+        }
+    }
+
+    /// Resolves the name to the account identifier of the default subaccount of the principal it points to.
+    pub async fn resolve_name(&self, name: &str) -> BlockchainApiResult<String> {
+        let name = name.to_lowercase();
+        let resolution_failed = |info: String| BlockchainApiError::NameResolutionFailed {
+            name: name.clone(),
+            info,
+        };
+
+        let canister_id = match Self::configured_name_service() {
+            NameServiceConfig::Canister { canister_id } => canister_id,
+            NameServiceConfig::Disabled => Err(resolution_failed(
+                "No name service is configured.".to_string(),
+            ))?,
+        };
+
+        let (record,): (Option<String>,) = cdk::call(
+            canister_id,
+            "get_record_value",
+            (name.clone(), Self::NAME_PRINCIPAL_RECORD_KEY.to_string()),
+        )
+        .await
+        .map_err(|err| resolution_failed(format!("rejection_code: {:?}, err: {}", err.0, err.1)))?;
+
+        let record =
+            record.ok_or_else(|| resolution_failed("The name is not registered.".to_string()))?;
+        let principal = Principal::from_text(record.trim()).map_err(|err| {
+            resolution_failed(format!("The name points to an invalid principal: {}", err))
+        })?;
+
+        Ok(AccountIdentifier::new(&principal, &DEFAULT_SUBACCOUNT).to_hex())
     }
 
     fn hash_transaction(transaction: &Transaction) -> Result<String, serde_cbor::Error> {
@@ -99,24 +200,24 @@ impl InternetComputer {
         Ok(hex::encode(hasher.finalize()))
     }
 
-    /// Creates the corresponded station_account account id for the given station_account id, which is the concatenation
-    /// of the station canister id and the station_account uuid as the subaccount id.
+    /// Creates the corresponded station_account account id for the given station_account, which is the concatenation
+    /// of the station canister id and the subaccount of the current address of the station_account.
     ///
     /// The station_account account id is used to identify a station_account in the ICP ledger.
     pub fn station_account_to_ledger_account(
         &self,
-        station_account_id: &AccountId,
+        station_account: &Account,
     ) -> AccountIdentifier {
-        let subaccount = InternetComputer::subaccount_from_station_account_id(station_account_id);
+        let subaccount = InternetComputer::subaccount_from_station_account(station_account);
 
         AccountIdentifier::new(&self.station_canister_id, &Subaccount(subaccount))
     }
 
-    /// Generates the corresponded ledger address for the given station_account id.
+    /// Generates the corresponded ledger address for the given station_account.
     ///
     /// This address is used for token transfers.
-    pub fn station_account_address(&self, station_account_id: &AccountId) -> String {
-        let account = self.station_account_to_ledger_account(station_account_id);
+    pub fn station_account_address(&self, station_account: &Account) -> String {
+        let account = self.station_account_to_ledger_account(station_account);
 
         account.to_hex()
     }
@@ -126,7 +227,7 @@ impl InternetComputer {
         let balance = account_balance(
             Self::ledger_canister_id(),
             AccountBalanceArgs {
-                account: self.station_account_to_ledger_account(&station_account.id),
+                account: self.station_account_to_ledger_account(station_account),
             },
         )
         .await
@@ -152,7 +253,14 @@ impl InternetComputer {
         station_account: Account,
         station_transfer: Transfer,
     ) -> Result<SubmitTransferResponse, ApiError> {
-        let current_time = cdk::next_time();
+        // the creation time of the first submission is reused so that the ledger deduplicates the transaction
+        let created_at_time = Timestamp {
+            timestamp_nanos: station_transfer
+                .submission
+                .as_ref()
+                .map(|submission| submission.created_at)
+                .unwrap_or_else(cdk::next_time),
+        };
         let amount: u64 = HelperMapper::nat_to_u64(station_transfer.amount.clone())?;
         let transaction_fee: u64 = HelperMapper::nat_to_u64(station_transfer.fee.clone())?;
         let memo = match station_transfer.metadata_map().get(METADATA_MEMO_KEY) {
@@ -167,46 +275,232 @@ impl InternetComputer {
                 }
             })?;
 
-        let block_height = transfer(
+        // a previous submission might have been accepted by the ledger even though its outcome was unknown,
+        // in which case the transaction must not be submitted again
+        if station_transfer.is_resubmission() {
+            if let Some(block_height) = self
+                .find_submitted_transaction(
+                    self.station_account_to_ledger_account(&station_account),
+                    to_address,
+                    Tokens::from_e8s(amount),
+                    Memo(memo),
+                    created_at_time,
+                )
+                .await?
+            {
+                return Ok(SubmitTransferResponse {
+                    block_height,
+                    transaction_hash: Self::find_transaction_hash(block_height).await,
+                    reconciled: true,
+                });
+            }
+        }
+
+        let (block_height, reconciled) = match transfer(
             Self::ledger_canister_id(),
             TransferArgs {
                 amount: Tokens::from_e8s(amount),
                 fee: Tokens::from_e8s(transaction_fee),
-                created_at_time: Some(Timestamp {
-                    timestamp_nanos: current_time,
-                }),
+                created_at_time: Some(created_at_time),
                 from_subaccount: Some(Subaccount(
-                    InternetComputer::subaccount_from_station_account_id(&station_account.id),
+                    InternetComputer::subaccount_from_station_account(&station_account),
                 )),
                 memo: Memo(memo),
                 to: to_address,
             },
         )
         .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })? {
+            Ok(block_height) => (block_height, false),
+            // the same transaction was already accepted by the ledger in a previous submission
+            Err(LedgerTransferError::TxDuplicate { duplicate_of }) => (duplicate_of, true),
+            Err(err) => Err(BlockchainApiError::TransactionSubmitFailed {
+                info: match err {
+                    LedgerTransferError::BadFee { expected_fee } => {
+                        format!("Bad fee, expected: {}", expected_fee)
+                    }
+                    LedgerTransferError::InsufficientFunds { balance } => {
+                        format!("Insufficient balance, balance: {}", balance)
+                    }
+                    LedgerTransferError::TxTooOld {
+                        allowed_window_nanos,
+                    } => {
+                        format!("Tx too old, allowed_window_nanos: {}", allowed_window_nanos)
+                    }
+                    LedgerTransferError::TxCreatedInFuture => "Tx created in future".to_string(),
+                    LedgerTransferError::TxDuplicate { duplicate_of } => {
+                        format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+                    }
+                },
+            })?,
+        };
+
+        Ok(SubmitTransferResponse {
+            block_height,
+            transaction_hash: Self::find_transaction_hash(block_height).await,
+            reconciled,
+        })
+    }
+
+    /// Searches the most recent ledger blocks for a transfer that matches the given transaction.
+    ///
+    /// Returns the block height of the matching transaction if it was found. Only the last
+    /// `DEDUPLICATION_LOOKBACK_BLOCKS` blocks held by the ledger are searched, not its archives, in which case
+    /// the resubmission is rejected by the ledger as a duplicate of the previous submission.
+    async fn find_submitted_transaction(
+        &self,
+        from: AccountIdentifier,
+        to: AccountIdentifier,
+        amount: Tokens,
+        memo: Memo,
+        created_at_time: Timestamp,
+    ) -> BlockchainApiResult<Option<BlockIndex>> {
+        let chain_length = query_blocks(
+            Self::ledger_canister_id(),
+            GetBlocksArgs {
+                start: 0,
+                length: 0,
+            },
+        )
+        .await
         .map_err(|err| BlockchainApiError::BlockchainNetworkError {
             info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
         })?
-        .map_err(|err| BlockchainApiError::TransactionSubmitFailed {
-            info: match err {
-                LedgerTransferError::BadFee { expected_fee } => {
-                    format!("Bad fee, expected: {}", expected_fee)
-                }
-                LedgerTransferError::InsufficientFunds { balance } => {
-                    format!("Insufficient balance, balance: {}", balance)
-                }
-                LedgerTransferError::TxTooOld {
-                    allowed_window_nanos,
-                } => {
-                    format!("Tx too old, allowed_window_nanos: {}", allowed_window_nanos)
-                }
-                LedgerTransferError::TxCreatedInFuture => "Tx created in future".to_string(),
-                LedgerTransferError::TxDuplicate { duplicate_of } => {
-                    format!("Tx duplicate, duplicate_of: {}", duplicate_of)
-                }
+        .chain_length;
+
+        let start = chain_length.saturating_sub(Self::DEDUPLICATION_LOOKBACK_BLOCKS);
+        let QueryBlocksResponse {
+            blocks,
+            first_block_index,
+            ..
+        } = query_blocks(
+            Self::ledger_canister_id(),
+            GetBlocksArgs {
+                start,
+                length: chain_length - start,
             },
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        Ok(blocks.iter().enumerate().find_map(|(position, block)| {
+            let transaction = &block.transaction;
+            let is_match = transaction.memo == memo
+                && transaction.created_at_time == created_at_time
+                && matches!(
+                    &transaction.operation,
+                    Some(Operation::Transfer {
+                        from: transaction_from,
+                        to: transaction_to,
+                        amount: transaction_amount,
+                        ..
+                    }) if *transaction_from == from && *transaction_to == to && *transaction_amount == amount
+                );
+
+            is_match.then_some(first_block_index + position as u64)
+        }))
+    }
+
+    /// Fetches a page of the transactions of the station account from the ICP index canister.
+    ///
+    /// The cursor is the id of the last transaction of the previous page, the index canister returns the
+    /// transactions from the most recent to the oldest one.
+    pub async fn list_account_transactions(
+        &self,
+        station_account: &Account,
+        cursor: Option<String>,
+        limit: u16,
+    ) -> BlockchainApiResult<BlockchainTransactionsPage> {
+        let start = match cursor {
+            Some(cursor) => Some(cursor.parse::<u64>().map_err(|_| {
+                BlockchainApiError::FetchTransactionsFailed {
+                    info: format!("Invalid cursor: {}", cursor),
+                }
+            })?),
+            None => None,
+        };
+
+        let (result,): (GetAccountIdentifierTransactionsResult,) = cdk::call(
+            Self::index_canister_id(),
+            "get_account_identifier_transactions",
+            (GetAccountIdentifierTransactionsArgs {
+                account_identifier: self.station_account_address(station_account),
+                start,
+                max_results: limit as u64,
+            },),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
         })?;
 
-        let transaction_hash = match query_blocks(
+        let response = match result {
+            GetAccountIdentifierTransactionsResult::Ok(response) => response,
+            GetAccountIdentifierTransactionsResult::Err(err) => {
+                Err(BlockchainApiError::FetchTransactionsFailed { info: err.message })?
+            }
+        };
+
+        // the index canister excludes the start transaction, so the next page starts after the last one
+        let next_cursor = match response.transactions.last() {
+            Some(last)
+                if response.transactions.len() >= limit as usize
+                    && response.oldest_tx_id != Some(last.id) =>
+            {
+                Some(last.id.to_string())
+            }
+            _ => None,
+        };
+
+        Ok(BlockchainTransactionsPage {
+            transactions: response
+                .transactions
+                .into_iter()
+                .map(Self::map_index_transaction)
+                .collect(),
+            next_cursor,
+        })
+    }
+
+    fn map_index_transaction(transaction: TransactionWithId) -> BlockchainTransaction {
+        let (from_address, to_address, amount, fee) = match transaction.transaction.operation {
+            IndexOperation::Transfer {
+                from,
+                to,
+                amount,
+                fee,
+                ..
+            } => (Some(from), Some(to), amount.e8s, Some(fee.e8s)),
+            IndexOperation::Mint { to, amount } => (None, Some(to), amount.e8s, None),
+            IndexOperation::Burn { from, amount, .. } => (Some(from), None, amount.e8s, None),
+            IndexOperation::Approve { from, fee, .. } => (Some(from), None, 0, Some(fee.e8s)),
+        };
+
+        BlockchainTransaction {
+            id: transaction.id.to_string(),
+            from_address,
+            to_address,
+            amount: BigUint::from(amount),
+            fee: fee.map(BigUint::from),
+            memo: Some(transaction.transaction.memo.to_string()),
+            created_at: transaction
+                .transaction
+                .created_at_time
+                .map(|time| time.timestamp_nanos),
+            timestamp: transaction
+                .transaction
+                .timestamp
+                .map(|time| time.timestamp_nanos),
+        }
+    }
+
+    /// Returns the hash of the transaction at the given block height, if it can be found.
+    async fn find_transaction_hash(block_height: BlockIndex) -> Option<String> {
+        match query_blocks(
             Self::ledger_canister_id(),
             GetBlocksArgs {
                 length: 1,
@@ -219,39 +513,33 @@ impl InternetComputer {
                 Some(block) => match Self::hash_transaction(&block.transaction) {
                     Ok(transaction_hash) => Some(transaction_hash),
                     Err(_) => {
-                        print("Error: could not serialize ICP ledger transaction");
+                        log_error!("could not serialize ICP ledger transaction");
                         None
                     }
                 },
                 None => {
-                    print(format!(
-                        "Error: no ICP ledger block found at height {}",
-                        block_height
-                    ));
+                    log_error!("no ICP ledger block found at height {}", block_height);
                     None
                 }
             },
 
             Err(e) => {
-                print(format!(
-                    "Error: could not query ICP ledger block at height {}:\nCode: {:?}\nMessage: {:?}",
-                    block_height, e.0, e.1
-                ));
+                log_error!(
+                    "could not query ICP ledger block at height {}:\nCode: {:?}\nMessage: {:?}",
+                    block_height,
+                    e.0,
+                    e.1
+                );
                 None
             }
-        };
-
-        Ok(SubmitTransferResponse {
-            block_height,
-            transaction_hash,
-        })
+        }
     }
 }
 
 #[async_trait]
 impl BlockchainApi for InternetComputer {
     async fn generate_address(&self, station_account: &Account) -> BlockchainApiResult<String> {
-        Ok(self.station_account_address(&station_account.id))
+        Ok(self.station_account_address(station_account))
     }
 
     async fn balance(&self, station_account: &Account) -> BlockchainApiResult<BigUint> {
@@ -287,17 +575,67 @@ impl BlockchainApi for InternetComputer {
             .submit_transfer(station_account.clone(), transfer.clone())
             .await?;
 
-        Ok(BlockchainTransactionSubmitted {
-            details: vec![
-                (
-                    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
-                    transfer_response.block_height.to_string(),
-                ),
-                (
-                    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY.to_string(),
-                    transfer_response.transaction_hash.unwrap_or("".to_string()),
-                ),
-            ],
-        })
+        let mut details = vec![
+            (
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                transfer_response.block_height.to_string(),
+            ),
+            (
+                TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY.to_string(),
+                transfer_response.transaction_hash.unwrap_or("".to_string()),
+            ),
+        ];
+
+        if transfer_response.reconciled {
+            details.push((
+                TRANSACTION_SUBMITTED_DETAILS_RECONCILED_KEY.to_string(),
+                true.to_string(),
+            ));
+        }
+
+        Ok(BlockchainTransactionSubmitted { details })
+    }
+
+    async fn list_transactions(
+        &self,
+        station_account: &Account,
+        cursor: Option<String>,
+        limit: u16,
+    ) -> BlockchainApiResult<BlockchainTransactionsPage> {
+        self.list_account_transactions(station_account, cursor, limit)
+            .await
+    }
+
+    /// Uses the ICRC-22 payment request format, e.g. `icp:<ledger_canister_id>/transfer?to=<account>&amount=<e8s>`.
+    fn payment_request_uri(
+        &self,
+        station_account: &Account,
+        amount: Option<&BigUint>,
+        memo: Option<&str>,
+    ) -> BlockchainApiResult<String> {
+        let mut uri = format!(
+            "icp:{}/transfer?to={}",
+            Self::ledger_canister_id().to_text(),
+            self.station_account_address(station_account)
+        );
+
+        if let Some(amount) = amount {
+            uri.push_str(&format!("&amount={}", amount));
+        }
+
+        if let Some(memo) = memo {
+            // the ICP ledger only supports numeric memos
+            uri.push_str(&format!("&memo={}", HelperMapper::to_u64(memo)?));
+        }
+
+        Ok(uri)
+    }
+
+    async fn resolve_destination_name(&self, to: &str) -> BlockchainApiResult<Option<String>> {
+        if !Self::is_destination_name(to) {
+            return Ok(None);
+        }
+
+        Ok(Some(self.resolve_name(to).await?))
     }
 }
@@ -0,0 +1,339 @@
+use super::{
+    BlockchainApi, BlockchainApiResult, BlockchainTransaction, BlockchainTransactionFee,
+    BlockchainTransactionSubmitted, BlockchainTransactionsPage,
+    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
+};
+use crate::{
+    core::{read_init_progress, read_system_state},
+    errors::BlockchainApiError,
+    models::{
+        system::SystemState, Account, Blockchain, BlockchainStandard, Metadata, Network, Transfer,
+        TransferStatus, METADATA_MEMO_KEY,
+    },
+    repositories::{ACCOUNT_REPOSITORY, TRANSFER_REPOSITORY},
+};
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use orbit_essentials::repository::Repository;
+use sha2::{Digest, Sha256};
+
+/// A simulated blockchain for local development and tests, which is used for all the accounts of the
+/// stations that were installed with `mock_blockchains`.
+///
+/// The ledger of the simulated blockchain is made of the completed transfers of the station itself, so that
+/// the balances are deterministic and survive upgrades without calling any canister:
+///
+/// - the first address of every account starts with [`MockBlockchain::INITIAL_BALANCE`];
+/// - the completed transfers are taken from the balance of their sender, including their fee, and added to
+///   the balance of their destination if it is an address of the station.
+#[derive(Debug)]
+pub struct MockBlockchain {
+    blockchain: Blockchain,
+    standard: BlockchainStandard,
+}
+
+impl MockBlockchain {
+    /// The balance of the first address of every account, in the smallest unit of the asset.
+    pub const INITIAL_BALANCE: u64 = 1_000_000_000_000;
+    /// The fee of every transfer, in the smallest unit of the asset.
+    pub const TRANSACTION_FEE: u64 = 10_000;
+
+    pub fn create(blockchain: Blockchain, standard: BlockchainStandard) -> Self {
+        Self {
+            blockchain,
+            standard,
+        }
+    }
+
+    /// Whether the station was installed with simulated blockchains.
+    pub fn is_enabled() -> bool {
+        match read_system_state() {
+            SystemState::Initialized(system_info) => system_info.is_mock_blockchains(),
+            // the system info is only written once the bootstrap completed, the initial accounts are
+            // created with the one of the checkpoint
+            SystemState::Uninitialized => read_init_progress().system_info.is_mock_blockchains(),
+        }
+    }
+
+    /// Returns the address of the account, which is derived from its id and derivation index.
+    pub fn address(&self, account: &Account) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(b"orbit-mock-blockchain");
+        hasher.update(self.blockchain.to_string().as_bytes());
+        hasher.update(self.standard.to_string().as_bytes());
+        hasher.update(account.id);
+        hasher.update(account.derivation_index.to_be_bytes());
+        let hash = hex::encode(hasher.finalize());
+
+        match self.blockchain {
+            Blockchain::Ethereum => format!("0x{}", &hash[..40]),
+            _ => hash,
+        }
+    }
+
+    /// Returns the completed transfers of the station, the oldest first.
+    fn completed_transfers() -> Vec<(Transfer, u64)> {
+        let mut transfers = TRANSFER_REPOSITORY
+            .list()
+            .into_iter()
+            .filter_map(|transfer| match transfer.status {
+                TransferStatus::Completed { completed_at, .. } => Some((transfer, completed_at)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        transfers.sort_by_key(|(transfer, completed_at)| (*completed_at, transfer.id));
+
+        transfers
+    }
+
+    /// Returns the address that the transfer was sent from, which is the current address of its account
+    /// unless the transfer names a retired one.
+    fn sender_address(transfer: &Transfer) -> Option<String> {
+        transfer.from_address.clone().or_else(|| {
+            ACCOUNT_REPOSITORY
+                .get(&Account::key(transfer.from_account))
+                .map(|account| account.address)
+        })
+    }
+
+    fn transaction_hash(transfer: &Transfer) -> String {
+        hex::encode(Sha256::digest(transfer.id))
+    }
+
+    fn to_transaction(transfer: &Transfer, completed_at: u64) -> BlockchainTransaction {
+        BlockchainTransaction {
+            id: Self::transaction_hash(transfer),
+            from_address: Self::sender_address(transfer),
+            to_address: Some(transfer.to_address.clone()),
+            amount: transfer.amount.0.clone(),
+            fee: Some(transfer.fee.0.clone()),
+            memo: transfer.metadata.get(METADATA_MEMO_KEY),
+            created_at: Some(transfer.created_timestamp),
+            timestamp: Some(completed_at),
+        }
+    }
+
+    /// Returns the balance of the address of the account in the simulated ledger.
+    fn simulated_balance(account: &Account) -> BigUint {
+        let mut credits = BigUint::from(0u64);
+        if account.derivation_index == 0 {
+            credits += Self::INITIAL_BALANCE;
+        }
+
+        let mut debits = BigUint::from(0u64);
+        for (transfer, _) in Self::completed_transfers() {
+            if transfer.to_address == account.address {
+                credits += &transfer.amount.0;
+            }
+
+            if transfer.from_account == account.id
+                && Self::sender_address(&transfer).as_deref() == Some(account.address.as_str())
+            {
+                debits += &transfer.amount.0 + &transfer.fee.0;
+            }
+        }
+
+        if credits > debits {
+            credits - debits
+        } else {
+            BigUint::from(0u64)
+        }
+    }
+}
+
+#[async_trait]
+impl BlockchainApi for MockBlockchain {
+    async fn generate_address(&self, account: &Account) -> BlockchainApiResult<String> {
+        Ok(self.address(account))
+    }
+
+    async fn balance(&self, account: &Account) -> BlockchainApiResult<BigUint> {
+        Ok(Self::simulated_balance(account))
+    }
+
+    async fn decimals(&self, _account: &Account) -> BlockchainApiResult<u32> {
+        Ok(match self.blockchain {
+            Blockchain::Ethereum => 18,
+            _ => 8,
+        })
+    }
+
+    async fn transaction_fee(
+        &self,
+        _account: &Account,
+    ) -> BlockchainApiResult<BlockchainTransactionFee> {
+        Ok(BlockchainTransactionFee {
+            fee: BigUint::from(Self::TRANSACTION_FEE),
+            metadata: Metadata::default(),
+        })
+    }
+
+    fn default_network(&self) -> String {
+        Network::MAINNET.to_string()
+    }
+
+    async fn submit_transaction(
+        &self,
+        account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        let balance = Self::simulated_balance(account);
+        let total = &transfer.amount.0 + &transfer.fee.0;
+        if balance < total {
+            Err(BlockchainApiError::TransactionSubmitFailed {
+                info: format!(
+                    "Insufficient funds, the balance is {} but {} are needed.",
+                    balance, total
+                ),
+            })?
+        }
+
+        let block_height = Self::completed_transfers().len() + 1;
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                    block_height.to_string(),
+                ),
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY.to_string(),
+                    Self::transaction_hash(transfer),
+                ),
+            ],
+        })
+    }
+
+    async fn list_transactions(
+        &self,
+        account: &Account,
+        cursor: Option<String>,
+        limit: u16,
+    ) -> BlockchainApiResult<BlockchainTransactionsPage> {
+        let offset = match cursor {
+            Some(cursor) => cursor.parse::<usize>().map_err(|_| {
+                BlockchainApiError::FetchTransactionsFailed {
+                    info: format!("Invalid cursor: {}", cursor),
+                }
+            })?,
+            None => 0,
+        };
+
+        let transactions = Self::completed_transfers()
+            .into_iter()
+            .rev()
+            .filter(|(transfer, _)| {
+                transfer.to_address == account.address
+                    || Self::sender_address(transfer).as_deref() == Some(account.address.as_str())
+            })
+            .map(|(transfer, completed_at)| Self::to_transaction(&transfer, completed_at))
+            .collect::<Vec<_>>();
+
+        let end = offset
+            .saturating_add(limit as usize)
+            .min(transactions.len());
+        let page = transactions
+            .get(offset.min(end)..end)
+            .unwrap_or_default()
+            .to_vec();
+
+        Ok(BlockchainTransactionsPage {
+            transactions: page,
+            next_cursor: (end < transactions.len()).then(|| end.to_string()),
+        })
+    }
+
+    fn payment_request_uri(
+        &self,
+        account: &Account,
+        amount: Option<&BigUint>,
+        memo: Option<&str>,
+    ) -> BlockchainApiResult<String> {
+        let mut params = Vec::new();
+        if let Some(amount) = amount {
+            params.push(format!("amount={}", amount));
+        }
+
+        if let Some(memo) = memo {
+            params.push(format!("memo={}", memo));
+        }
+
+        let mut uri = format!("mock:{}", account.address);
+        if !params.is_empty() {
+            uri.push_str(&format!("?{}", params.join("&")));
+        }
+
+        Ok(uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{account_test_utils::mock_account, transfer_test_utils::mock_transfer};
+
+    #[tokio::test]
+    async fn completed_transfers_move_the_simulated_funds() {
+        let blockchain = MockBlockchain::create(Blockchain::Ethereum, BlockchainStandard::Native);
+
+        let mut sender = mock_account();
+        sender.id = [1; 16];
+        sender.address = blockchain.address(&sender);
+        ACCOUNT_REPOSITORY.insert(sender.to_key(), sender.clone());
+
+        let mut receiver = mock_account();
+        receiver.id = [2; 16];
+        receiver.address = blockchain.address(&receiver);
+        ACCOUNT_REPOSITORY.insert(receiver.to_key(), receiver.clone());
+
+        assert!(sender.address.starts_with("0x"));
+        assert_ne!(sender.address, receiver.address);
+
+        let mut transfer = mock_transfer();
+        transfer.from_account = sender.id;
+        transfer.from_address = None;
+        transfer.to_address = receiver.address.clone();
+        transfer.amount = candid::Nat::from(1_000u64);
+        transfer.fee = candid::Nat::from(MockBlockchain::TRANSACTION_FEE);
+
+        assert!(blockchain
+            .submit_transaction(&sender, &transfer)
+            .await
+            .is_ok());
+
+        transfer.status = TransferStatus::Completed {
+            signature: None,
+            hash: None,
+            completed_at: 1,
+        };
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+
+        assert_eq!(
+            blockchain.balance(&sender).await.unwrap(),
+            BigUint::from(
+                MockBlockchain::INITIAL_BALANCE - 1_000 - MockBlockchain::TRANSACTION_FEE
+            )
+        );
+        assert_eq!(
+            blockchain.balance(&receiver).await.unwrap(),
+            BigUint::from(MockBlockchain::INITIAL_BALANCE + 1_000)
+        );
+
+        let page = blockchain
+            .list_transactions(&receiver, None, 10)
+            .await
+            .unwrap();
+        assert_eq!(page.transactions.len(), 1);
+        assert_eq!(
+            page.transactions[0].from_address.as_deref(),
+            Some(sender.address.as_str())
+        );
+
+        transfer.amount = candid::Nat::from(MockBlockchain::INITIAL_BALANCE);
+        assert!(blockchain
+            .submit_transaction(&sender, &transfer)
+            .await
+            .is_err());
+    }
+}
@@ -1,5 +1,10 @@
 mod core;
 pub use core::*;
 
+mod icp_index;
+
 mod internet_computer;
 pub use internet_computer::*;
+
+mod mock;
+pub use mock::*;
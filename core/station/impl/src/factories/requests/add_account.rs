@@ -28,7 +28,8 @@ impl Create<station_api::AddAccountOperationInput> for AddAccountRequestCreate {
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
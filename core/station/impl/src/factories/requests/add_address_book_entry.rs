@@ -1,7 +1,11 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
+    core::read_system_info,
     errors::{RequestError, RequestExecuteError},
-    models::{AddAddressBookEntryOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        AddAddressBookEntryOperation, AddAddressBookEntryOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
     services::ADDRESS_BOOK_SERVICE,
 };
 use async_trait::async_trait;
@@ -18,17 +22,27 @@ impl Create<station_api::AddAddressBookEntryOperationInput> for AddAddressBookEn
         input: station_api::CreateRequestInput,
         operation_input: station_api::AddAddressBookEntryOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input: AddAddressBookEntryOperationInput = operation_input.into();
+
+        ensure_encrypted_metadata(
+            operation_input
+                .metadata
+                .iter()
+                .map(|item| (&item.key, &item.value)),
+        )?;
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::AddAddressBookEntry(AddAddressBookEntryOperation {
                 address_book_entry_id: None,
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -40,6 +54,26 @@ impl Create<station_api::AddAddressBookEntryOperationInput> for AddAddressBookEn
     }
 }
 
+/// Ensures that the address book metadata that must be encrypted at rest is encrypted.
+pub(super) fn ensure_encrypted_metadata<'a>(
+    metadata: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Result<(), RequestError> {
+    let unencrypted_keys = read_system_info()
+        .get_metadata_encryption()
+        .unencrypted_address_book_metadata(metadata);
+
+    if !unencrypted_keys.is_empty() {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The address book metadata must be encrypted: {}",
+                unencrypted_keys.join(", ")
+            ),
+        });
+    }
+
+    Ok(())
+}
+
 pub struct AddAddressBookEntryRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o AddAddressBookEntryOperation,
@@ -0,0 +1,67 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{AddNetworkOperation, Request, RequestExecutionPlan, RequestOperation},
+    services::SYSTEM_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct AddNetworkRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::AddNetworkOperationInput> for AddNetworkRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::AddNetworkOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::AddNetwork(AddNetworkOperation {
+                input: operation_input.into(),
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Network addition".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct AddNetworkRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o AddNetworkOperation,
+}
+
+impl<'p, 'o> AddNetworkRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o AddNetworkOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for AddNetworkRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        SYSTEM_SERVICE
+            .add_network(self.operation.input.network.clone())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to add the network: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -30,7 +30,8 @@ impl Create<station_api::AddRequestPolicyOperationInput> for AddRequestPolicyReq
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -164,6 +165,8 @@ pub mod add_request_policy_test_utils {
         station_api::AddRequestPolicyOperationInput {
             rule: station_api::RequestPolicyRuleDTO::AutoApproved,
             specifier: station_api::RequestSpecifierDTO::AddRequestPolicy,
+            checklist: None,
+            exclude_requester: None,
         }
     }
 
@@ -175,6 +178,9 @@ pub mod add_request_policy_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
         }
     }
 }
@@ -24,11 +24,12 @@ impl Create<station_api::AddUserOperationInput> for AddUserRequestCreate {
             Request::default_expiration_dt_ns(),
             RequestOperation::AddUser(AddUserOperation {
                 user_id: None,
-                input: operation_input.into(),
+                input: operation_input.try_into()?,
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "User creation".to_string()),
             input.summary,
@@ -25,7 +25,8 @@ impl Create<station_api::AddUserGroupOperationInput> for AddUserGroupRequestCrea
             RequestOperation::AddUserGroup(operation_input.into()),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
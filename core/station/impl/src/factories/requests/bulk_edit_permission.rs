@@ -0,0 +1,174 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        BulkEditPermissionOperation, BulkEditPermissionOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::permission::PermissionService,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+use std::sync::Arc;
+
+pub struct BulkEditPermissionRequestCreate {
+    pub permission_service: Arc<PermissionService>,
+}
+
+#[async_trait]
+impl Create<station_api::BulkEditPermissionOperationInput> for BulkEditPermissionRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::BulkEditPermissionOperationInput,
+    ) -> Result<Request, RequestError> {
+        let operation_input = BulkEditPermissionOperationInput::from(operation_input);
+        let matrix = self
+            .permission_service
+            .preview_permission_changes(&operation_input.changes)
+            .map_err(|e| RequestError::ValidationError {
+                info: format!("Invalid permission changes: {}", e),
+            })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::BulkEditPermission(BulkEditPermissionOperation {
+                input: operation_input,
+                matrix,
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Permissions update".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct BulkEditPermissionRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o BulkEditPermissionOperation,
+    permission_service: Arc<PermissionService>,
+}
+
+impl<'p, 'o> BulkEditPermissionRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o BulkEditPermissionOperation,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            request,
+            operation,
+            permission_service,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for BulkEditPermissionRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        self.permission_service
+            .bulk_edit_permissions(self.operation.input.changes.to_owned())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to update the permissions: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.to_owned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        factories::requests::edit_permission::edit_permission_test_utils::{
+            mock_edit_permission_api_input, mock_request_api_input,
+        },
+        models::{
+            permission::AuthScope,
+            resource::{PermissionResourceAction, Resource},
+            user_test_utils::mock_user,
+        },
+        repositories::USER_REPOSITORY,
+        services::permission::PERMISSION_SERVICE,
+    };
+    use orbit_essentials::repository::Repository;
+
+    #[tokio::test]
+    async fn request_shows_the_permissions_before_and_after_the_changes() {
+        let mut user = mock_user();
+        user.id = [1; 16];
+        USER_REPOSITORY.insert(user.to_key(), user.to_owned());
+
+        let mut authenticated_update = mock_edit_permission_api_input();
+        authenticated_update.resource =
+            station_api::ResourceDTO::Permission(station_api::PermissionResourceActionDTO::Update);
+        authenticated_update.users = None;
+        authenticated_update.auth_scope = Some(station_api::AuthScopeDTO::Authenticated);
+        let operation_input = station_api::BulkEditPermissionOperationInput {
+            changes: vec![mock_edit_permission_api_input(), authenticated_update],
+        };
+        let mut request_input = mock_request_api_input();
+        request_input.operation =
+            station_api::RequestOperationInput::BulkEditPermission(operation_input.clone());
+
+        let creator = BulkEditPermissionRequestCreate {
+            permission_service: Arc::clone(&PERMISSION_SERVICE),
+        };
+        let request = creator
+            .create([0; 16], user.id, request_input, operation_input)
+            .await
+            .unwrap();
+
+        let RequestOperation::BulkEditPermission(operation) = &request.operation else {
+            panic!("Expected BulkEditPermission operation");
+        };
+
+        assert_eq!(request.title, "Permissions update".to_string());
+        assert_eq!(operation.matrix.len(), 2);
+        assert!(operation.matrix[0].before.users.is_empty());
+        assert_eq!(operation.matrix[0].after.users, vec![user.id]);
+        assert_eq!(
+            operation.matrix[1].resource,
+            Resource::Permission(PermissionResourceAction::Update)
+        );
+        assert_eq!(
+            operation.matrix[1].after.auth_scope,
+            AuthScope::Authenticated
+        );
+
+        let stage = BulkEditPermissionRequestExecute::new(
+            &request,
+            operation,
+            Arc::clone(&PERMISSION_SERVICE),
+        )
+        .execute()
+        .await
+        .unwrap();
+
+        assert!(matches!(stage, RequestExecuteStage::Completed(_)));
+        assert_eq!(
+            PERMISSION_SERVICE
+                .get_permission(&Resource::Permission(PermissionResourceAction::Read))
+                .allow
+                .users,
+            vec![user.id]
+        );
+        assert!(PERMISSION_SERVICE
+            .get_permission(&Resource::Permission(PermissionResourceAction::Update))
+            .allowed_authenticated());
+    }
+}
@@ -83,7 +83,8 @@ impl Create<CallExternalCanisterOperationInput> for CallExternalCanisterRequestC
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
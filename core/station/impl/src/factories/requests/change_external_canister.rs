@@ -1,16 +1,59 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{ChangeExternalCanisterOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::ChangeCanisterService,
+    log_warn,
+    models::{
+        CanisterUpgradeDiff, ChangeExternalCanisterOperation, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::{ChangeCanisterService, ExternalCanisterService},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
 use sha2::{Digest, Sha256};
-use station_api::{ChangeExternalCanisterOperationInput, CreateRequestInput};
+use station_api::{ChangeExternalCanisterOperationInput, ChunkedWasmModuleDTO, CreateRequestInput};
 use std::sync::Arc;
 
-pub struct ChangeExternalCanisterRequestCreate;
+pub struct ChangeExternalCanisterRequestCreate {
+    pub external_canister_service: Arc<ExternalCanisterService>,
+}
+
+impl ChangeExternalCanisterRequestCreate {
+    /// Compares the proposed module with the state of the canister when the request is created.
+    async fn upgrade_diff(
+        &self,
+        operation_input: &ChangeExternalCanisterOperationInput,
+    ) -> CanisterUpgradeDiff {
+        let current_state = match self
+            .external_canister_service
+            .canister_current_state(operation_input.canister_id)
+            .await
+        {
+            Ok(current_state) => Some(current_state),
+            Err(err) => {
+                log_warn!(
+                    "Failed to read the state of canister {}: {}",
+                    operation_input.canister_id,
+                    err
+                );
+
+                None
+            }
+        };
+
+        CanisterUpgradeDiff {
+            current_state,
+            module_size: match operation_input.module_chunks {
+                Some(_) => None,
+                None => Some(operation_input.module.len() as u64),
+            },
+            arg_empty: operation_input
+                .arg
+                .as_ref()
+                .map_or(true, |arg| arg.is_empty()),
+        }
+    }
+}
 
 #[async_trait]
 impl Create<ChangeExternalCanisterOperationInput> for ChangeExternalCanisterRequestCreate {
@@ -21,6 +64,12 @@ impl Create<ChangeExternalCanisterOperationInput> for ChangeExternalCanisterRequ
         input: CreateRequestInput,
         operation_input: ChangeExternalCanisterOperationInput,
     ) -> Result<Request, RequestError> {
+        if let Some(module_chunks) = &operation_input.module_chunks {
+            validate_module_chunks(&operation_input.module, module_chunks)?;
+        }
+
+        let upgrade_diff = self.upgrade_diff(&operation_input).await;
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -31,16 +80,21 @@ impl Create<ChangeExternalCanisterOperationInput> for ChangeExternalCanisterRequ
                     hasher.update(arg);
                     hasher.finalize().to_vec()
                 }),
-                module_checksum: {
-                    let mut hasher = Sha256::new();
-                    hasher.update(&operation_input.module);
-                    hasher.finalize().to_vec()
+                module_checksum: match &operation_input.module_chunks {
+                    Some(module_chunks) => module_chunks.wasm_module_hash.clone(),
+                    None => {
+                        let mut hasher = Sha256::new();
+                        hasher.update(&operation_input.module);
+                        hasher.finalize().to_vec()
+                    }
                 },
                 input: operation_input.into(),
+                upgrade_diff: Some(upgrade_diff),
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -52,10 +106,47 @@ impl Create<ChangeExternalCanisterOperationInput> for ChangeExternalCanisterRequ
     }
 }
 
+/// The length of the sha256 hashes of a chunked module.
+const SHA256_HASH_LEN: usize = 32;
+
+fn validate_module_chunks(
+    module: &[u8],
+    module_chunks: &ChunkedWasmModuleDTO,
+) -> Result<(), RequestError> {
+    if !module.is_empty() {
+        return Err(RequestError::ValidationError {
+            info: "The module must be empty if it was uploaded in chunks.".to_string(),
+        });
+    }
+
+    if module_chunks.chunk_hashes.is_empty() {
+        return Err(RequestError::ValidationError {
+            info: "A chunked module must have at least one chunk.".to_string(),
+        });
+    }
+
+    if module_chunks.wasm_module_hash.len() != SHA256_HASH_LEN
+        || module_chunks
+            .chunk_hashes
+            .iter()
+            .any(|hash| hash.len() != SHA256_HASH_LEN)
+    {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The hashes of a chunked module must be {} bytes long.",
+                SHA256_HASH_LEN
+            ),
+        });
+    }
+
+    Ok(())
+}
+
 pub struct ChangeExternalCanisterRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o ChangeExternalCanisterOperation,
     change_canister_service: Arc<ChangeCanisterService>,
+    external_canister_service: Arc<ExternalCanisterService>,
 }
 
 impl<'p, 'o> ChangeExternalCanisterRequestExecute<'p, 'o> {
@@ -63,11 +154,13 @@ impl<'p, 'o> ChangeExternalCanisterRequestExecute<'p, 'o> {
         request: &'p Request,
         operation: &'o ChangeExternalCanisterOperation,
         change_canister_service: Arc<ChangeCanisterService>,
+        external_canister_service: Arc<ExternalCanisterService>,
     ) -> Self {
         Self {
             request,
             operation,
             change_canister_service,
+            external_canister_service,
         }
     }
 }
@@ -75,18 +168,46 @@ impl<'p, 'o> ChangeExternalCanisterRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for ChangeExternalCanisterRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
-        self.change_canister_service
-            .install_canister(
-                self.operation.input.canister_id,
-                self.operation.input.mode.clone(),
-                &self.operation.input.module,
-                self.operation.input.arg.clone(),
+        let input = &self.operation.input;
+        let install_result = match &input.module_chunks {
+            Some(module_chunks) => {
+                self.change_canister_service
+                    .install_canister_from_chunks(
+                        input.canister_id,
+                        input.mode.clone(),
+                        module_chunks,
+                        input.arg.clone(),
+                    )
+                    .await
+            }
+            None => {
+                self.change_canister_service
+                    .install_canister(
+                        input.canister_id,
+                        input.mode.clone(),
+                        &input.module,
+                        input.arg.clone(),
+                    )
+                    .await
+            }
+        };
+
+        install_result.map_err(|err| RequestExecuteError::Failed {
+            reason: format!(
+                "failed to install external canister {}: {}",
+                self.operation.input.canister_id, err
+            ),
+        })?;
+
+        self.external_canister_service
+            .record_module_hash(
+                &input.canister_id,
+                Some(self.operation.module_checksum.clone()),
             )
-            .await
             .map_err(|err| RequestExecuteError::Failed {
                 reason: format!(
-                    "failed to install external canister {}: {}",
-                    self.operation.input.canister_id, err
+                    "failed to record the module hash of external canister {}: {}",
+                    input.canister_id, err
                 ),
             })?;
 
@@ -95,3 +216,124 @@ impl Execute for ChangeExternalCanisterRequestExecute<'_, '_> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factories::requests::requests_test_utils::mock_request_api_input;
+    use crate::services::EXTERNAL_CANISTER_SERVICE;
+    use candid::Principal;
+    use station_api::CanisterInstallMode;
+
+    fn mock_chunked_operation_api_input() -> ChangeExternalCanisterOperationInput {
+        ChangeExternalCanisterOperationInput {
+            canister_id: Principal::from_slice(&[1; 29]),
+            mode: CanisterInstallMode::Upgrade,
+            module: vec![],
+            arg: None,
+            module_chunks: Some(ChunkedWasmModuleDTO {
+                store_canister: Principal::from_slice(&[2; 29]),
+                chunk_hashes: vec![vec![3; SHA256_HASH_LEN], vec![4; SHA256_HASH_LEN]],
+                wasm_module_hash: vec![5; SHA256_HASH_LEN],
+            }),
+        }
+    }
+
+    async fn create_request(
+        operation_input: ChangeExternalCanisterOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request_input = mock_request_api_input(
+            station_api::RequestOperationInput::ChangeExternalCanister(operation_input.clone()),
+        );
+
+        ChangeExternalCanisterRequestCreate {
+            external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+        }
+        .create([0; 16], [1; 16], request_input, operation_input)
+        .await
+    }
+
+    #[tokio::test]
+    async fn upgrade_diff_describes_the_proposed_module() {
+        let mut operation_input = mock_chunked_operation_api_input();
+        operation_input.module_chunks = None;
+        operation_input.module = vec![0, 97, 115, 109];
+        operation_input.arg = Some(vec![]);
+
+        let request = create_request(operation_input).await.unwrap();
+
+        match &request.operation {
+            RequestOperation::ChangeExternalCanister(operation) => {
+                let diff = operation.upgrade_diff.as_ref().unwrap();
+                assert_eq!(diff.module_size, Some(4));
+                assert!(diff.arg_empty);
+                // the state of the canister can only be read by a canister
+                assert!(diff.current_state.is_none());
+            }
+            _ => panic!("Expected ChangeExternalCanister operation"),
+        }
+
+        let request = create_request(mock_chunked_operation_api_input())
+            .await
+            .unwrap();
+
+        match &request.operation {
+            RequestOperation::ChangeExternalCanister(operation) => {
+                let diff = operation.upgrade_diff.as_ref().unwrap();
+                assert_eq!(diff.module_size, None);
+                assert!(diff.arg_empty);
+            }
+            _ => panic!("Expected ChangeExternalCanister operation"),
+        }
+    }
+
+    #[tokio::test]
+    async fn chunked_module_checksum_is_the_module_hash() {
+        let request = create_request(mock_chunked_operation_api_input())
+            .await
+            .unwrap();
+
+        match &request.operation {
+            RequestOperation::ChangeExternalCanister(operation) => {
+                assert_eq!(operation.module_checksum, vec![5; SHA256_HASH_LEN]);
+                assert!(operation.input.module_chunks.is_some());
+            }
+            _ => panic!("Expected ChangeExternalCanister operation"),
+        }
+    }
+
+    #[tokio::test]
+    async fn chunked_module_must_not_have_module_bytes() {
+        let mut operation_input = mock_chunked_operation_api_input();
+        operation_input.module = vec![0, 97, 115, 109];
+
+        assert!(matches!(
+            create_request(operation_input).await,
+            Err(RequestError::ValidationError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn chunked_module_hashes_must_be_sha256() {
+        let mut operation_input = mock_chunked_operation_api_input();
+        operation_input
+            .module_chunks
+            .as_mut()
+            .unwrap()
+            .chunk_hashes
+            .push(vec![6; 16]);
+
+        assert!(matches!(
+            create_request(operation_input).await,
+            Err(RequestError::ValidationError { .. })
+        ));
+
+        let mut operation_input = mock_chunked_operation_api_input();
+        operation_input.module_chunks.as_mut().unwrap().chunk_hashes = vec![];
+
+        assert!(matches!(
+            create_request(operation_input).await,
+            Err(RequestError::ValidationError { .. })
+        ));
+    }
+}
@@ -0,0 +1,206 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        CommitAssetBatchOperation, CommitAssetBatchOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::ExternalCanisterService,
+};
+use async_trait::async_trait;
+use candid::{CandidType, Decode, Deserialize, Encode, Nat};
+use orbit_essentials::types::UUID;
+use station_api::CreateRequestInput;
+use std::sync::Arc;
+
+/// The length of the evidence of a proposed batch, which is a sha256 hash.
+const EVIDENCE_LEN: usize = 32;
+
+/// The argument of the `validate_commit_proposed_batch` and `commit_proposed_batch` methods.
+#[derive(CandidType, Deserialize, Debug, PartialEq)]
+struct CommitProposedBatchArguments {
+    batch_id: Nat,
+    evidence: serde_bytes::ByteBuf,
+}
+
+fn commit_proposed_batch_arg(input: &CommitAssetBatchOperationInput) -> Vec<u8> {
+    Encode!(&CommitProposedBatchArguments {
+        batch_id: input.batch_id.clone(),
+        evidence: serde_bytes::ByteBuf::from(input.evidence.clone()),
+    })
+    .expect("failed to encode commit_proposed_batch argument")
+}
+
+pub struct CommitAssetBatchRequestCreate {
+    pub external_canister_service: Arc<ExternalCanisterService>,
+}
+
+#[async_trait]
+impl Create<station_api::CommitAssetBatchOperationInput> for CommitAssetBatchRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: CreateRequestInput,
+        operation_input: station_api::CommitAssetBatchOperationInput,
+    ) -> Result<Request, RequestError> {
+        if operation_input.evidence.len() != EVIDENCE_LEN {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The evidence of the batch must be {} bytes long.",
+                    EVIDENCE_LEN
+                ),
+            });
+        }
+
+        let operation_input: CommitAssetBatchOperationInput = operation_input.into();
+
+        // The asset canister checks the evidence against the proposed batch and renders it for
+        // the reviewers of the request.
+        let rendering_bytes = self
+            .external_canister_service
+            .call_external_canister(
+                operation_input.canister_id,
+                CommitAssetBatchOperationInput::VALIDATION_METHOD.to_string(),
+                Some(commit_proposed_batch_arg(&operation_input)),
+                None,
+            )
+            .await
+            .map_err(|err| RequestError::ValidationError {
+                info: format!(
+                    "failed to call asset canister {}: {}",
+                    operation_input.canister_id, err
+                ),
+            })?;
+        let evidence_rendering =
+            Decode!(&rendering_bytes, Result<String, String>).map_err(|err| {
+                RequestError::ValidationError {
+                    info: format!(
+                        "failed to decode asset canister {} reply: {}",
+                        operation_input.canister_id, err
+                    ),
+                }
+            })?;
+        let evidence_rendering =
+            evidence_rendering.map_err(|err| RequestError::ValidationError {
+                info: format!("failed to validate commit asset batch request: {}", err),
+            })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::CommitAssetBatch(CommitAssetBatchOperation {
+                input: operation_input,
+                evidence_rendering: Some(evidence_rendering),
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "CommitAssetBatch".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct CommitAssetBatchRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o CommitAssetBatchOperation,
+    external_canister_service: Arc<ExternalCanisterService>,
+}
+
+impl<'p, 'o> CommitAssetBatchRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o CommitAssetBatchOperation,
+        external_canister_service: Arc<ExternalCanisterService>,
+    ) -> Self {
+        Self {
+            _request: request,
+            operation,
+            external_canister_service,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for CommitAssetBatchRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        self.external_canister_service
+            .call_external_canister(
+                self.operation.input.canister_id,
+                CommitAssetBatchOperationInput::EXECUTION_METHOD.to_string(),
+                Some(commit_proposed_batch_arg(&self.operation.input)),
+                None,
+            )
+            .await
+            .map_err(|err| RequestExecuteError::Failed {
+                reason: format!(
+                    "failed to commit batch {} of asset canister {}: {}",
+                    self.operation.input.batch_id, self.operation.input.canister_id, err
+                ),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::CommitAssetBatch(self.operation.clone()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factories::requests::requests_test_utils::mock_request_api_input;
+    use crate::services::EXTERNAL_CANISTER_SERVICE;
+    use candid::Principal;
+
+    fn mock_operation_api_input() -> station_api::CommitAssetBatchOperationInput {
+        station_api::CommitAssetBatchOperationInput {
+            canister_id: Principal::from_slice(&[1; 29]),
+            batch_id: Nat::from(7u64),
+            evidence: vec![2; EVIDENCE_LEN],
+        }
+    }
+
+    #[tokio::test]
+    async fn evidence_must_be_a_sha256_hash() {
+        let mut operation_input = mock_operation_api_input();
+        operation_input.evidence = vec![2; 16];
+        let request_input = mock_request_api_input(
+            station_api::RequestOperationInput::CommitAssetBatch(operation_input.clone()),
+        );
+
+        let result = CommitAssetBatchRequestCreate {
+            external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+        }
+        .create([0; 16], [1; 16], request_input, operation_input)
+        .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[test]
+    fn arg_matches_the_asset_canister_interface() {
+        let operation_input: CommitAssetBatchOperationInput = mock_operation_api_input().into();
+
+        let arg = Decode!(
+            &commit_proposed_batch_arg(&operation_input),
+            CommitProposedBatchArguments
+        )
+        .unwrap();
+
+        assert_eq!(
+            arg,
+            CommitProposedBatchArguments {
+                batch_id: Nat::from(7u64),
+                evidence: serde_bytes::ByteBuf::from(vec![2; EVIDENCE_LEN]),
+            }
+        );
+    }
+}
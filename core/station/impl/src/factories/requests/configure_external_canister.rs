@@ -1,18 +1,61 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
+    log_warn,
     models::{
-        ConfigureExternalCanisterOperation, ConfigureExternalCanisterOperationKind, Request,
-        RequestExecutionPlan, RequestOperation,
+        ConfigureExternalCanisterOperation, ConfigureExternalCanisterOperationKind,
+        DefiniteCanisterSettingsInput, NativeSettingsChange, Request, RequestExecutionPlan,
+        RequestOperation,
     },
     services::ExternalCanisterService,
 };
 use async_trait::async_trait;
+use candid::Principal;
 use orbit_essentials::types::UUID;
 use station_api::{ConfigureExternalCanisterOperationInput, CreateRequestInput};
 use std::sync::Arc;
 
-pub struct ConfigureExternalCanisterRequestCreate;
+pub struct ConfigureExternalCanisterRequestCreate {
+    pub external_canister_service: Arc<ExternalCanisterService>,
+}
+
+impl ConfigureExternalCanisterRequestCreate {
+    /// Computes the changes of the native settings against the current settings of the canister.
+    ///
+    /// The settings are only read for active canisters of the station, since the station can't
+    /// change the settings of the other ones.
+    async fn native_settings_diff(
+        &self,
+        canister_id: Principal,
+        settings: &DefiniteCanisterSettingsInput,
+    ) -> Option<Vec<NativeSettingsChange>> {
+        let external_canister = self
+            .external_canister_service
+            .get_external_canister_by_canister_id(&canister_id)
+            .ok()?;
+
+        if external_canister.is_archived() {
+            return None;
+        }
+
+        match self
+            .external_canister_service
+            .canister_ic_settings(canister_id)
+            .await
+        {
+            Ok(current_settings) => Some(current_settings.diff(settings)),
+            Err(err) => {
+                log_warn!(
+                    "Failed to read the settings of canister {}: {}",
+                    canister_id,
+                    err
+                );
+
+                None
+            }
+        }
+    }
+}
 
 #[async_trait]
 impl Create<ConfigureExternalCanisterOperationInput> for ConfigureExternalCanisterRequestCreate {
@@ -23,14 +66,23 @@ impl Create<ConfigureExternalCanisterOperationInput> for ConfigureExternalCanist
         input: CreateRequestInput,
         operation_input: ConfigureExternalCanisterOperationInput,
     ) -> Result<Request, RequestError> {
+        let mut operation: ConfigureExternalCanisterOperation = operation_input.into();
+
+        if let ConfigureExternalCanisterOperationKind::NativeSettings(settings) = &operation.kind {
+            operation.native_settings_diff = self
+                .native_settings_diff(operation.canister_id, settings)
+                .await;
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
-            RequestOperation::ConfigureExternalCanister(operation_input.into()),
+            RequestOperation::ConfigureExternalCanister(operation),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -152,7 +204,9 @@ mod tests {
         let request_input = mock_request_api_input(
             station_api::RequestOperationInput::ConfigureExternalCanister(operation_input.clone()),
         );
-        let creator = Box::new(ConfigureExternalCanisterRequestCreate {});
+        let creator = Box::new(ConfigureExternalCanisterRequestCreate {
+            external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+        });
         let request = creator
             .create(
                 request_id,
@@ -206,6 +260,7 @@ pub mod configure_external_canister_test_utils {
                     memory_allocation: None,
                     freezing_threshold: None,
                     reserved_cycles_limit: None,
+                    log_visibility: None,
                 },
             ),
         }
@@ -1,10 +1,17 @@
-use super::{Create, Execute, RequestExecuteStage};
+use super::{execution_steps::RequestExecutionSteps, Create, Execute, RequestExecuteStage};
 use crate::{
+    core::ic_cdk::next_time,
     errors::{RequestError, RequestExecuteError},
-    models::{CreateExternalCanisterOperation, Request, RequestExecutionPlan, RequestOperation},
+    jobs::schedule_module_drift_detection,
+    models::{
+        CreateExternalCanisterOperation, CreateExternalCanisterOperationKind,
+        CreateExternalCanisterOperationKindAddExisting, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
     services::ExternalCanisterService,
 };
 use async_trait::async_trait;
+use candid::Principal;
 use orbit_essentials::types::UUID;
 use station_api::{CreateExternalCanisterOperationInput, CreateRequestInput};
 use std::sync::Arc;
@@ -30,7 +37,8 @@ impl Create<CreateExternalCanisterOperationInput> for CreateExternalCanisterRequ
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -43,7 +51,7 @@ impl Create<CreateExternalCanisterOperationInput> for CreateExternalCanisterRequ
 }
 
 pub struct CreateExternalCanisterRequestExecute<'p, 'o> {
-    _request: &'p Request,
+    request: &'p Request,
     operation: &'o CreateExternalCanisterOperation,
     create_canister_service: Arc<ExternalCanisterService>,
 }
@@ -55,26 +63,80 @@ impl<'p, 'o> CreateExternalCanisterRequestExecute<'p, 'o> {
         create_canister_service: Arc<ExternalCanisterService>,
     ) -> Self {
         Self {
-            _request: request,
+            request,
             operation,
             create_canister_service,
         }
     }
 }
 
+impl CreateExternalCanisterRequestExecute<'_, '_> {
+    pub const STEP_CREATE_CANISTER: &'static str = "create_canister";
+    pub const STEP_ADD_EXTERNAL_CANISTER: &'static str = "add_external_canister";
+}
+
 #[async_trait]
 impl Execute for CreateExternalCanisterRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
-        let external_canister = self
-            .create_canister_service
-            .add_external_canister(self.operation.input.clone())
-            .await
-            .map_err(|err| RequestExecuteError::Failed {
-                reason: format!("failed to add external canister: {}", err),
-            })?;
+        let steps = RequestExecutionSteps::new(self.request.id);
+        let mut input = self.operation.input.clone();
+
+        // New canisters are created in a separate step, so that a failure to add the canister to the
+        // system does not create another canister when the execution is resumed.
+        if let CreateExternalCanisterOperationKind::CreateNew(opts) = &input.kind {
+            let new_canister_input = &input;
+            let output = steps
+                .run(Self::STEP_CREATE_CANISTER, move || async move {
+                    self.create_canister_service
+                        .validate_new_external_canister(new_canister_input)
+                        .map_err(|err| RequestExecuteError::Failed {
+                            reason: format!("failed to validate external canister: {}", err),
+                        })?;
+
+                    let canister_id = self
+                        .create_canister_service
+                        .create_canister(opts.initial_cycles.map(|cycles| cycles as u128))
+                        .await
+                        .map_err(|err| RequestExecuteError::Failed {
+                            reason: format!("failed to create external canister: {}", err),
+                        })?;
+
+                    Ok(Some(canister_id.to_text()))
+                })
+                .await?;
+
+            let canister_id = output
+                .and_then(|canister_id| Principal::from_text(canister_id).ok())
+                .ok_or(RequestExecuteError::Failed {
+                    reason: "missing id of the created canister".to_string(),
+                })?;
+
+            input.kind = CreateExternalCanisterOperationKind::AddExisting(
+                CreateExternalCanisterOperationKindAddExisting { canister_id },
+            );
+        }
+
+        let add_canister_input = &input;
+        let output = steps
+            .run(Self::STEP_ADD_EXTERNAL_CANISTER, move || async move {
+                let external_canister = self
+                    .create_canister_service
+                    .add_external_canister(add_canister_input.clone())
+                    .await
+                    .map_err(|err| RequestExecuteError::Failed {
+                        reason: format!("failed to add external canister: {}", err),
+                    })?;
+
+                Ok(Some(external_canister.canister_id.to_text()))
+            })
+            .await?;
+
+        // the module hashes of the registered canisters are checked periodically
+        schedule_module_drift_detection(next_time());
 
         let mut create_operation = self.operation.clone();
-        create_operation.canister_id = Some(external_canister.canister_id);
+        create_operation.canister_id =
+            output.and_then(|canister_id| Principal::from_text(canister_id).ok());
 
         Ok(RequestExecuteStage::Completed(
             RequestOperation::CreateExternalCanister(create_operation),
@@ -0,0 +1,67 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{DecommissionStationOperation, Request, RequestExecutionPlan, RequestOperation},
+    services::SYSTEM_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct DecommissionStationRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::DecommissionStationOperationInput> for DecommissionStationRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::DecommissionStationOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::DecommissionStation(DecommissionStationOperation {
+                input: operation_input.into(),
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Station decommission".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct DecommissionStationRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o DecommissionStationOperation,
+}
+
+impl<'p, 'o> DecommissionStationRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o DecommissionStationOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for DecommissionStationRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        SYSTEM_SERVICE
+            .decommission_station(self.request.id, self.operation.input.clone())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to decommission the station: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -1,7 +1,10 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditAccountOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        EditAccountOperation, EditAccountOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
     services::ACCOUNT_SERVICE,
 };
 use async_trait::async_trait;
@@ -18,16 +21,27 @@ impl Create<station_api::EditAccountOperationInput> for EditAccountRequestCreate
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditAccountOperationInput,
     ) -> Result<Request, RequestError> {
+        let mut operation_input = EditAccountOperationInput::from(operation_input);
+
+        // Pins the edit to the current version of the account, so that it can't override concurrent edits.
+        if operation_input.expected_version.is_none() {
+            operation_input.expected_version = ACCOUNT_SERVICE
+                .get_account(&operation_input.account_id)
+                .ok()
+                .map(|account| account.version);
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditAccount(EditAccountOperation {
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "Account edit".to_string()),
             input.summary,
@@ -1,9 +1,11 @@
-use super::{Create, Execute, RequestExecuteStage};
+use super::{
+    add_address_book_entry::ensure_encrypted_metadata, Create, Execute, RequestExecuteStage,
+};
 use crate::{
     errors::{RequestError, RequestExecuteError},
     mappers::HelperMapper,
     models::{
-        EditAddressBookEntryOperation, EditAddressBookEntryOperationInput, Request,
+        ChangeMetadata, EditAddressBookEntryOperation, EditAddressBookEntryOperationInput, Request,
         RequestExecutionPlan, RequestOperation,
     },
     services::ADDRESS_BOOK_SERVICE,
@@ -27,6 +29,17 @@ impl Create<station_api::EditAddressBookEntryOperationInput> for EditAddressBook
                 info: format!("Invalid address book entry id: {}", e),
             })?;
 
+        let change_metadata: Option<ChangeMetadata> =
+            operation_input.change_metadata.map(|m| m.into());
+
+        match &change_metadata {
+            Some(ChangeMetadata::ReplaceAllBy(metadata))
+            | Some(ChangeMetadata::OverrideSpecifiedBy(metadata)) => {
+                ensure_encrypted_metadata(metadata)?
+            }
+            Some(ChangeMetadata::RemoveKeys(_)) | None => {}
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -35,13 +48,14 @@ impl Create<station_api::EditAddressBookEntryOperationInput> for EditAddressBook
                 input: EditAddressBookEntryOperationInput {
                     address_book_entry_id: *address_book_entry_id.as_bytes(),
                     address_owner: operation_input.address_owner,
-                    change_metadata: operation_input.change_metadata.map(|m| m.into()),
+                    change_metadata,
                     labels: operation_input.labels,
                 },
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -0,0 +1,65 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{EditNetworkOperation, Request, RequestExecutionPlan, RequestOperation},
+    services::SYSTEM_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct EditNetworkRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::EditNetworkOperationInput> for EditNetworkRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::EditNetworkOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::EditNetwork(EditNetworkOperation {
+                input: operation_input.into(),
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Network update".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct EditNetworkRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o EditNetworkOperation,
+}
+
+impl<'p, 'o> EditNetworkRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o EditNetworkOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for EditNetworkRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        SYSTEM_SERVICE
+            .edit_network(self.operation.input.clone())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to edit the network: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
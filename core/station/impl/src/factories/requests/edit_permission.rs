@@ -28,7 +28,8 @@ impl Create<station_api::EditPermissionOperationInput> for EditPermissionRequest
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -181,6 +182,9 @@ pub mod edit_permission_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
         }
     }
 }
@@ -1,9 +1,10 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
+    core::read_system_state,
     errors::{RequestError, RequestExecuteError},
     models::{
         EditRequestPolicyOperation, EditRequestPolicyOperationInput, Request, RequestExecutionPlan,
-        RequestOperation,
+        RequestOperation, SystemState,
     },
     services::{RequestPolicyService, REQUEST_POLICY_SERVICE},
 };
@@ -23,8 +24,8 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditRequestPolicyOperationInput,
     ) -> Result<Request, RequestError> {
-        let operation_input = EditRequestPolicyOperationInput::from(operation_input);
-        REQUEST_POLICY_SERVICE
+        let mut operation_input = EditRequestPolicyOperationInput::from(operation_input);
+        let policy = REQUEST_POLICY_SERVICE
             .get_request_policy(&operation_input.policy_id)
             .map_err(|_| RequestError::ValidationError {
                 info: format!(
@@ -33,6 +34,28 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
                 ),
             })?;
 
+        // The policies of a sub-treasury can't be moved out of it, since its admins administer them.
+        if let (Some(specifier), SystemState::Initialized(system_info)) =
+            (&operation_input.specifier, read_system_state())
+        {
+            if let Some(sub_treasury) =
+                system_info.find_sub_treasury_of_request_specifier(&policy.specifier)
+            {
+                if !sub_treasury.scopes_request_specifier(specifier) {
+                    return Err(RequestError::ValidationError {
+                        info: format!(
+                            "The request policy must remain scoped to the accounts of the sub-treasury {}",
+                            sub_treasury.name
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Pins the edit to the current version of the policy, so that it can't override concurrent edits.
+        operation_input.expected_version =
+            operation_input.expected_version.or(Some(policy.version));
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -42,7 +65,8 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -244,6 +268,9 @@ pub mod edit_request_policy_test_utils {
             specifier: Some(station_api::RequestSpecifierDTO::EditRequestPolicy(
                 station_api::ResourceIdsDTO::Any,
             )),
+            checklist: None,
+            exclude_requester: None,
+            expected_version: None,
         }
     }
 
@@ -255,6 +282,9 @@ pub mod edit_request_policy_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
         }
     }
 }
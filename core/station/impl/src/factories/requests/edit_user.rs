@@ -1,7 +1,9 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditUserOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        EditUserOperation, EditUserOperationInput, Request, RequestExecutionPlan, RequestOperation,
+    },
     services::USER_SERVICE,
 };
 use async_trait::async_trait;
@@ -18,16 +20,27 @@ impl Create<station_api::EditUserOperationInput> for EditUserRequestCreate {
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditUserOperationInput,
     ) -> Result<Request, RequestError> {
+        let mut operation_input = EditUserOperationInput::try_from(operation_input)?;
+
+        // Pins the edit to the current version of the user, so that it can't override concurrent edits.
+        if operation_input.expected_version.is_none() {
+            operation_input.expected_version = USER_SERVICE
+                .get_user(&operation_input.user_id)
+                .ok()
+                .map(|user| user.version);
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditUser(EditUserOperation {
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "User edit".to_string()),
             input.summary,
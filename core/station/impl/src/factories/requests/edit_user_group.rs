@@ -25,7 +25,8 @@ impl Create<station_api::EditUserGroupOperationInput> for EditUserGroupRequestCr
             RequestOperation::EditUserGroup(operation_input.into()),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "User group edit".to_string()),
             input.summary,
@@ -0,0 +1,141 @@
+use crate::{
+    core::ic_cdk::next_time,
+    errors::RequestExecuteError,
+    models::{Request, RequestExecutionStep, RequestExecutionStepStatus, RequestId},
+    repositories::REQUEST_REPOSITORY,
+};
+use orbit_essentials::repository::Repository;
+use std::future::Future;
+
+/// Runs the steps of a request operation that is executed in several steps (e.g. several inter-canister calls).
+///
+/// The status of each step is persisted in the request as soon as the step finishes, so that it survives the
+/// failure of a following step. When the execution of the request is resumed, the steps that were already
+/// completed are skipped and their recorded output is returned instead.
+pub struct RequestExecutionSteps {
+    request_id: RequestId,
+}
+
+impl RequestExecutionSteps {
+    pub fn new(request_id: RequestId) -> Self {
+        Self { request_id }
+    }
+
+    /// Runs the step with the given name, unless it was already completed in a previous attempt.
+    ///
+    /// Returns the output of the step, which is recorded so that it can be used when resuming the execution.
+    pub async fn run<F, Fut>(
+        &self,
+        name: &str,
+        step: F,
+    ) -> Result<Option<String>, RequestExecuteError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Option<String>, RequestExecuteError>>,
+    {
+        if let Some(completed_step) = self.find_step(name).filter(|step| step.is_completed()) {
+            return Ok(completed_step.output().cloned());
+        }
+
+        let result = step().await;
+
+        self.record_step(
+            name,
+            match &result {
+                Ok(output) => RequestExecutionStepStatus::Completed {
+                    output: output.clone(),
+                    completed_at: next_time(),
+                },
+                Err(err) => RequestExecutionStepStatus::Failed {
+                    reason: err.to_string(),
+                    failed_at: next_time(),
+                },
+            },
+        );
+
+        result
+    }
+
+    fn find_step(&self, name: &str) -> Option<RequestExecutionStep> {
+        REQUEST_REPOSITORY
+            .get(&Request::key(self.request_id))
+            .and_then(|request| {
+                request
+                    .execution_steps
+                    .into_iter()
+                    .find(|step| step.name == name)
+            })
+    }
+
+    fn record_step(&self, name: &str, status: RequestExecutionStepStatus) {
+        let Some(mut request) = REQUEST_REPOSITORY.get(&Request::key(self.request_id)) else {
+            return;
+        };
+
+        match request
+            .execution_steps
+            .iter_mut()
+            .find(|step| step.name == name)
+        {
+            Some(step) => {
+                step.status = status;
+                step.attempts += 1;
+            }
+            None => request.execution_steps.push(RequestExecutionStep {
+                name: name.to_string(),
+                status,
+                attempts: 1,
+            }),
+        }
+
+        request.last_modification_timestamp = next_time();
+        REQUEST_REPOSITORY.insert(request.to_key(), request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_test_utils::mock_request;
+
+    #[tokio::test]
+    async fn completed_steps_are_not_repeated() {
+        let request = mock_request();
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let steps = RequestExecutionSteps::new(request.id);
+
+        let output = steps
+            .run("first", || async { Ok(Some("output".to_string())) })
+            .await
+            .unwrap();
+        assert_eq!(output, Some("output".to_string()));
+
+        steps
+            .run("second", || async {
+                Err(RequestExecuteError::Failed {
+                    reason: "boom".to_string(),
+                })
+            })
+            .await
+            .unwrap_err();
+
+        // resuming the execution skips the completed step and returns its recorded output
+        let output = steps
+            .run("first", || async { Ok(Some("repeated".to_string())) })
+            .await
+            .unwrap();
+        assert_eq!(output, Some("output".to_string()));
+
+        steps.run("second", || async { Ok(None) }).await.unwrap();
+
+        let request = REQUEST_REPOSITORY.get(&request.to_key()).unwrap();
+        assert_eq!(request.execution_steps.len(), 2);
+        assert_eq!(request.execution_steps[0].attempts, 1);
+        assert_eq!(request.execution_steps[1].attempts, 2);
+        assert!(request
+            .execution_steps
+            .iter()
+            .all(|step| step.is_completed()));
+    }
+}
@@ -0,0 +1,68 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{FreezeBlockchainOperation, Request, RequestExecutionPlan, RequestOperation},
+    services::SYSTEM_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct FreezeBlockchainRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::FreezeBlockchainOperationInput> for FreezeBlockchainRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::FreezeBlockchainOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::FreezeBlockchain(FreezeBlockchainOperation {
+                input: operation_input.into(),
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Blockchain freeze".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct FreezeBlockchainRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o FreezeBlockchainOperation,
+}
+
+impl<'p, 'o> FreezeBlockchainRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o FreezeBlockchainOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for FreezeBlockchainRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        SYSTEM_SERVICE
+            .freeze_blockchain(self.request.id, self.operation.input.clone())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to freeze the accounts of the blockchain: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -29,7 +29,8 @@ impl Create<station_api::FundExternalCanisterOperationInput> for FundExternalCan
             RequestOperation::FundExternalCanister(operation_input.into()),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "Fund canister".to_string()),
             input.summary,
@@ -1,11 +1,28 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
+    core::{
+        ic_cdk::{api::id as self_canister_id, next_time},
+        ASSETS,
+    },
     errors::{RequestError, RequestExecuteError},
-    models::{ManageSystemInfoOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        system::{
+            AddressBookSource, ApprovalReminderConfig, AuditorAccess, BalanceSnapshotConfig,
+            BrandingConfig, DataCompactionConfig, FiatValuationConfig, MaintenanceModeConfig,
+            MetadataEncryptionConfig, OperationAvailability, OperationFlag, RequestArchivalConfig,
+            RequestTextLimits, SubTreasury, TransferComplianceThreshold, TransferMaxFee,
+            TransferScreeningConfig, TransferTimeLockConfig,
+        },
+        Account, AddressBookEntry, ManageSystemInfoOperation, ManageSystemInfoOperationInput,
+        Metadata, Request, RequestExecutionPlan, RequestOperation,
+    },
+    repositories::BLOB_REPOSITORY,
     services::SYSTEM_SERVICE,
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use candid::Principal;
+use orbit_essentials::{repository::Repository, types::UUID};
+use uuid::Uuid;
 
 pub struct ManageSystemInfoRequestCreate {}
 
@@ -18,16 +35,104 @@ impl Create<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoReq
         input: station_api::CreateRequestInput,
         operation_input: station_api::ManageSystemInfoOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = ManageSystemInfoOperationInput::try_from(operation_input)?;
+
+        if let Some(auditors) = &operation_input.auditors {
+            validate_auditors(auditors)?;
+        }
+
+        if let Some(sources) = &operation_input.address_book_sources {
+            validate_address_book_sources(sources)?;
+        }
+
+        if let Some(thresholds) = &operation_input.transfer_compliance_thresholds {
+            validate_transfer_compliance_thresholds(thresholds)?;
+        }
+
+        if let Some(max_fees) = &operation_input.transfer_max_fees {
+            validate_transfer_max_fees(max_fees)?;
+        }
+
+        if let Some(TransferScreeningConfig::Canister {
+            canister_id,
+            thresholds,
+            ..
+        }) = &operation_input.transfer_screening
+        {
+            if *canister_id == Principal::anonymous()
+                || *canister_id == Principal::management_canister()
+                || *canister_id == self_canister_id()
+            {
+                return Err(RequestError::ValidationError {
+                    info: format!(
+                        "The canister {} cannot be a screening service.",
+                        canister_id
+                    ),
+                });
+            }
+
+            validate_transfer_compliance_thresholds(thresholds)?;
+        }
+
+        if let Some(transfer_time_lock) = &operation_input.transfer_time_lock {
+            validate_transfer_time_lock(transfer_time_lock)?;
+        }
+
+        if let Some(metadata_encryption) = &operation_input.metadata_encryption {
+            validate_metadata_encryption(metadata_encryption)?;
+        }
+
+        if let Some(data_compaction) = &operation_input.data_compaction {
+            validate_data_compaction(data_compaction)?;
+        }
+
+        if let Some(request_archival) = &operation_input.request_archival {
+            validate_request_archival(request_archival)?;
+        }
+
+        if let Some(branding) = &operation_input.branding {
+            validate_branding(branding)?;
+        }
+
+        if let Some(sub_treasuries) = &operation_input.sub_treasuries {
+            validate_sub_treasuries(sub_treasuries)?;
+        }
+
+        if let Some(maintenance_mode) = &operation_input.maintenance_mode {
+            validate_maintenance_mode(maintenance_mode)?;
+        }
+
+        if let Some(request_text_limits) = &operation_input.request_text_limits {
+            validate_request_text_limits(request_text_limits)?;
+        }
+
+        if let Some(approval_reminders) = &operation_input.approval_reminders {
+            validate_approval_reminders(approval_reminders)?;
+        }
+
+        if let Some(balance_snapshots) = &operation_input.balance_snapshots {
+            validate_balance_snapshots(balance_snapshots)?;
+        }
+
+        if let Some(fiat_valuation) = &operation_input.fiat_valuation {
+            validate_fiat_valuation(fiat_valuation)?;
+        }
+
+        if let Some(operation_flags) = &operation_input.operation_flags {
+            validate_operation_flags(operation_flags)?;
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::ManageSystemInfo(ManageSystemInfoOperation {
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -39,6 +144,645 @@ impl Create<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoReq
     }
 }
 
+/// Validates the auditors that replace the current ones, each principal can only be set once and the
+/// access must not be already expired.
+fn validate_auditors(auditors: &[AuditorAccess]) -> Result<(), RequestError> {
+    if auditors.len() > AuditorAccess::MAX_AUDITORS {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} auditors can be set.",
+                AuditorAccess::MAX_AUDITORS
+            ),
+        });
+    }
+
+    let now = next_time();
+    for (index, auditor) in auditors.iter().enumerate() {
+        let (min_name_length, max_name_length) = AuditorAccess::NAME_RANGE;
+        let name_length = auditor.name.trim().len();
+        if name_length < min_name_length || name_length > max_name_length {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The auditor name must be between {} and {} characters.",
+                    min_name_length, max_name_length
+                ),
+            });
+        }
+
+        if auditor.principal == Principal::anonymous() {
+            return Err(RequestError::ValidationError {
+                info: "The anonymous principal cannot be an auditor.".to_string(),
+            });
+        }
+
+        if !auditor.is_active(now) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The access of the auditor {} is already expired.",
+                    auditor.name
+                ),
+            });
+        }
+
+        if auditors[..index]
+            .iter()
+            .any(|other| other.principal == auditor.principal)
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The principal {} is set as an auditor more than once.",
+                    auditor.principal
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the address book sources that replace the current ones, each canister can only be set once.
+fn validate_address_book_sources(sources: &[AddressBookSource]) -> Result<(), RequestError> {
+    if sources.len() > AddressBookSource::MAX_SOURCES {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} address book sources can be set.",
+                AddressBookSource::MAX_SOURCES
+            ),
+        });
+    }
+
+    for (index, source) in sources.iter().enumerate() {
+        if source.canister_id == Principal::anonymous()
+            || source.canister_id == Principal::management_canister()
+            || source.canister_id == self_canister_id()
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The canister {} cannot be an address book source.",
+                    source.canister_id
+                ),
+            });
+        }
+
+        if source.labels.len() > AddressBookEntry::MAX_LABELS
+            || source.labels.iter().any(|label| {
+                label.trim().is_empty() || label.len() > AddressBookEntry::MAX_LABEL_LENGTH
+            })
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The address book source can have at most {} labels of at most {} characters.",
+                    AddressBookEntry::MAX_LABELS,
+                    AddressBookEntry::MAX_LABEL_LENGTH
+                ),
+            });
+        }
+
+        if sources[..index]
+            .iter()
+            .any(|other| other.canister_id == source.canister_id)
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The canister {} is set as an address book source more than once.",
+                    source.canister_id
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the transfer compliance thresholds that replace the current ones, each asset can only be set once.
+fn validate_transfer_compliance_thresholds(
+    thresholds: &[TransferComplianceThreshold],
+) -> Result<(), RequestError> {
+    if thresholds.len() > TransferComplianceThreshold::MAX_THRESHOLDS {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} transfer compliance thresholds can be set.",
+                TransferComplianceThreshold::MAX_THRESHOLDS
+            ),
+        });
+    }
+
+    for (index, threshold) in thresholds.iter().enumerate() {
+        let (min_symbol_length, max_symbol_length) = Account::SYMBOL_RANGE;
+        if threshold.symbol.len() < min_symbol_length as usize
+            || threshold.symbol.len() > max_symbol_length as usize
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The asset symbol must be between {} and {} characters.",
+                    min_symbol_length, max_symbol_length
+                ),
+            });
+        }
+
+        if thresholds[..index]
+            .iter()
+            .any(|other| other.symbol == threshold.symbol)
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The asset {} has more than one transfer compliance threshold.",
+                    threshold.symbol
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the transfer maximum fees that replace the current ones, each asset can only be set once.
+fn validate_transfer_max_fees(max_fees: &[TransferMaxFee]) -> Result<(), RequestError> {
+    if max_fees.len() > TransferMaxFee::MAX_FEES {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} transfer maximum fees can be set.",
+                TransferMaxFee::MAX_FEES
+            ),
+        });
+    }
+
+    for (index, max_fee) in max_fees.iter().enumerate() {
+        let (min_symbol_length, max_symbol_length) = Account::SYMBOL_RANGE;
+        if max_fee.symbol.len() < min_symbol_length as usize
+            || max_fee.symbol.len() > max_symbol_length as usize
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The asset symbol must be between {} and {} characters.",
+                    min_symbol_length, max_symbol_length
+                ),
+            });
+        }
+
+        if max_fees[..index]
+            .iter()
+            .any(|other| other.symbol == max_fee.symbol)
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The asset {} has more than one transfer maximum fee.",
+                    max_fee.symbol
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the vetKD key name and the address book metadata keys whose values must be encrypted.
+fn validate_metadata_encryption(config: &MetadataEncryptionConfig) -> Result<(), RequestError> {
+    let MetadataEncryptionConfig::VetKd {
+        key_name,
+        address_book_metadata_keys,
+    } = config
+    else {
+        return Ok(());
+    };
+
+    let (min_key_name_length, max_key_name_length) = MetadataEncryptionConfig::KEY_NAME_RANGE;
+    if key_name.len() < min_key_name_length || key_name.len() > max_key_name_length {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The vetKD key name must be between {} and {} characters.",
+                min_key_name_length, max_key_name_length
+            ),
+        });
+    }
+
+    if address_book_metadata_keys.len() > MetadataEncryptionConfig::MAX_ADDRESS_BOOK_METADATA_KEYS {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} encrypted address book metadata keys can be set.",
+                MetadataEncryptionConfig::MAX_ADDRESS_BOOK_METADATA_KEYS
+            ),
+        });
+    }
+
+    for (index, key) in address_book_metadata_keys.iter().enumerate() {
+        if key.trim().is_empty() || key.len() > Metadata::MAX_METADATA_KEY_LEN as usize {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The encrypted address book metadata keys must have at most {} characters.",
+                    Metadata::MAX_METADATA_KEY_LEN
+                ),
+            });
+        }
+
+        if address_book_metadata_keys[..index].contains(key) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The address book metadata key {} is set to be encrypted more than once.",
+                    key
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the thresholds of the time-locked transfers, their delay and the groups that can cancel them.
+fn validate_transfer_time_lock(config: &TransferTimeLockConfig) -> Result<(), RequestError> {
+    let TransferTimeLockConfig::Enabled {
+        thresholds,
+        delay_secs,
+        canceller_group_ids,
+    } = config
+    else {
+        return Ok(());
+    };
+
+    validate_transfer_compliance_thresholds(thresholds)?;
+
+    let (delay_secs_min, delay_secs_max) = TransferTimeLockConfig::DELAY_SECS_RANGE;
+    if *delay_secs < delay_secs_min || *delay_secs > delay_secs_max {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The delay of the time-locked transfers must be between {} and {} seconds.",
+                delay_secs_min, delay_secs_max
+            ),
+        });
+    }
+
+    if canceller_group_ids.is_empty()
+        || canceller_group_ids.len() > TransferTimeLockConfig::MAX_CANCELLER_GROUPS
+    {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "Between 1 and {} groups must be able to cancel the time-locked transfers.",
+                TransferTimeLockConfig::MAX_CANCELLER_GROUPS
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates the minimum age of the requests that are compacted and the canister that receives their payloads.
+fn validate_data_compaction(config: &DataCompactionConfig) -> Result<(), RequestError> {
+    let DataCompactionConfig::Enabled {
+        min_age_days,
+        export_canister_id,
+    } = config
+    else {
+        return Ok(());
+    };
+
+    let (min_age_days_min, min_age_days_max) = DataCompactionConfig::MIN_AGE_DAYS_RANGE;
+    if *min_age_days < min_age_days_min || *min_age_days > min_age_days_max {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The minimum age of the compacted requests must be between {} and {} days.",
+                min_age_days_min, min_age_days_max
+            ),
+        });
+    }
+
+    if let Some(canister_id) = export_canister_id {
+        if *canister_id == Principal::anonymous()
+            || *canister_id == Principal::management_canister()
+            || *canister_id == self_canister_id()
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The canister {} cannot receive the compacted payloads.",
+                    canister_id
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the minimum age of the archived requests and the canister that stores them.
+fn validate_request_archival(config: &RequestArchivalConfig) -> Result<(), RequestError> {
+    let RequestArchivalConfig::Enabled {
+        min_age_days,
+        archive_canister_id,
+    } = config
+    else {
+        return Ok(());
+    };
+
+    let (min_age_days_min, min_age_days_max) = RequestArchivalConfig::MIN_AGE_DAYS_RANGE;
+    if *min_age_days < min_age_days_min || *min_age_days > min_age_days_max {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The minimum age of the archived requests must be between {} and {} days.",
+                min_age_days_min, min_age_days_max
+            ),
+        });
+    }
+
+    if *archive_canister_id == Principal::anonymous()
+        || *archive_canister_id == Principal::management_canister()
+        || *archive_canister_id == self_canister_id()
+    {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The canister {} cannot store the archived requests.",
+                archive_canister_id
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that the logos are stored blobs and that each one is the logo of a different registered asset.
+fn validate_branding(config: &BrandingConfig) -> Result<(), RequestError> {
+    for blob_id in config
+        .logo_id
+        .iter()
+        .chain(config.asset_logos.iter().map(|logo| &logo.logo_id))
+    {
+        if !BLOB_REPOSITORY.exists(blob_id) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The blob {} does not exist.",
+                    Uuid::from_bytes(*blob_id).hyphenated()
+                ),
+            });
+        }
+    }
+
+    for (index, logo) in config.asset_logos.iter().enumerate() {
+        let is_registered_asset = ASSETS.with(|assets| {
+            assets.borrow().iter().any(|asset| {
+                asset.blockchain == logo.blockchain
+                    && asset.standard == logo.standard
+                    && asset.symbol == logo.symbol
+            })
+        });
+        if !is_registered_asset {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The asset registry has no {} asset of the {} standard on the {} blockchain.",
+                    logo.symbol, logo.standard, logo.blockchain
+                ),
+            });
+        }
+
+        if config.asset_logos[..index].iter().any(|other| {
+            other.blockchain == logo.blockchain
+                && other.standard == logo.standard
+                && other.symbol == logo.symbol
+        }) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The logo of the {} asset is set more than once.",
+                    logo.symbol
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the reason that is returned to the callers while the station is in maintenance mode.
+fn validate_maintenance_mode(config: &MaintenanceModeConfig) -> Result<(), RequestError> {
+    if let Some(reason) = &config.reason {
+        if reason.trim().is_empty()
+            || reason.chars().count() > MaintenanceModeConfig::MAX_REASON_LEN
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The maintenance reason must be between 1 and {} characters.",
+                    MaintenanceModeConfig::MAX_REASON_LEN
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the length limits of the titles and summaries, which can't exceed the upper bounds.
+fn validate_request_text_limits(limits: &RequestTextLimits) -> Result<(), RequestError> {
+    if limits.max_title_len == 0 || limits.max_title_len > RequestTextLimits::MAX_TITLE_LEN {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The maximum title length must be between 1 and {}.",
+                RequestTextLimits::MAX_TITLE_LEN
+            ),
+        });
+    }
+
+    if limits.max_summary_len == 0 || limits.max_summary_len > RequestTextLimits::MAX_SUMMARY_LEN {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The maximum summary length must be between 1 and {}.",
+                RequestTextLimits::MAX_SUMMARY_LEN
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates the reminder intervals, which must be ascending and leave some time between the reminders.
+fn validate_approval_reminders(config: &ApprovalReminderConfig) -> Result<(), RequestError> {
+    if config.intervals_secs.len() > ApprovalReminderConfig::MAX_REMINDERS {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} approval reminders can be configured.",
+                ApprovalReminderConfig::MAX_REMINDERS
+            ),
+        });
+    }
+
+    let mut previous_interval_secs = 0;
+    for interval_secs in &config.intervals_secs {
+        if *interval_secs < previous_interval_secs + ApprovalReminderConfig::MIN_INTERVAL_SECS {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The approval reminder intervals must be ascending and at least {} seconds apart.",
+                    ApprovalReminderConfig::MIN_INTERVAL_SECS
+                ),
+            });
+        }
+
+        previous_interval_secs = *interval_secs;
+    }
+
+    Ok(())
+}
+
+/// Validates the interval between the balance snapshots, which bounds the size of the balance history.
+fn validate_balance_snapshots(config: &BalanceSnapshotConfig) -> Result<(), RequestError> {
+    let (min_interval_secs, max_interval_secs) = BalanceSnapshotConfig::INTERVAL_SECS_RANGE;
+    if let Some(interval_secs) = config.interval_secs {
+        if !(min_interval_secs..=max_interval_secs).contains(&interval_secs) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The balance snapshot interval must be between {} and {} seconds.",
+                    min_interval_secs, max_interval_secs
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that every operation type is flagged at most once and that the operations that change the flags
+/// are not disabled.
+fn validate_operation_flags(flags: &[OperationFlag]) -> Result<(), RequestError> {
+    for (index, flag) in flags.iter().enumerate() {
+        if flags[..index]
+            .iter()
+            .any(|other| other.operation_type == flag.operation_type)
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The {} operation is flagged more than once.",
+                    flag.operation_type
+                ),
+            });
+        }
+
+        if flag.availability == OperationAvailability::Disabled
+            && OperationFlag::ALWAYS_ENABLED.contains(&flag.operation_type)
+        {
+            return Err(RequestError::ValidationError {
+                info: format!("The {} operation cannot be disabled.", flag.operation_type),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the price feed of the fiat valuation and its currencies, which must be unique ISO 4217 codes.
+fn validate_fiat_valuation(config: &FiatValuationConfig) -> Result<(), RequestError> {
+    let FiatValuationConfig::Canister {
+        canister_id,
+        currencies,
+    } = config
+    else {
+        return Ok(());
+    };
+
+    if *canister_id == Principal::anonymous()
+        || *canister_id == Principal::management_canister()
+        || *canister_id == self_canister_id()
+    {
+        return Err(RequestError::ValidationError {
+            info: format!("The canister {} cannot be a price feed.", canister_id),
+        });
+    }
+
+    if currencies.is_empty() || currencies.len() > FiatValuationConfig::MAX_CURRENCIES {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "Between 1 and {} fiat currencies must be set.",
+                FiatValuationConfig::MAX_CURRENCIES
+            ),
+        });
+    }
+
+    for (index, currency) in currencies.iter().enumerate() {
+        if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The fiat currency `{}` must be an uppercase ISO 4217 code (e.g. `USD`).",
+                    currency
+                ),
+            });
+        }
+
+        if currencies[..index].contains(currency) {
+            return Err(RequestError::ValidationError {
+                info: format!("The fiat currency {} is set more than once.", currency),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the sub-treasuries that replace the current ones, the names must be unique and each account
+/// can only belong to one sub-treasury.
+fn validate_sub_treasuries(sub_treasuries: &[SubTreasury]) -> Result<(), RequestError> {
+    if sub_treasuries.len() > SubTreasury::MAX_SUB_TREASURIES {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "At most {} sub-treasuries can be set.",
+                SubTreasury::MAX_SUB_TREASURIES
+            ),
+        });
+    }
+
+    for (index, sub_treasury) in sub_treasuries.iter().enumerate() {
+        let (min_name_length, max_name_length) = SubTreasury::NAME_RANGE;
+        let name_length = sub_treasury.name.trim().len();
+        if name_length < min_name_length || name_length > max_name_length {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The sub-treasury name must be between {} and {} characters.",
+                    min_name_length, max_name_length
+                ),
+            });
+        }
+
+        if sub_treasuries[..index]
+            .iter()
+            .any(|other| other.name.trim() == sub_treasury.name.trim())
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The sub-treasury {} is set more than once.",
+                    sub_treasury.name
+                ),
+            });
+        }
+
+        if sub_treasury.account_ids.len() > SubTreasury::MAX_ACCOUNTS {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "A sub-treasury can have at most {} accounts.",
+                    SubTreasury::MAX_ACCOUNTS
+                ),
+            });
+        }
+
+        if sub_treasury.member_group_ids.len() > SubTreasury::MAX_MEMBER_GROUPS {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "A sub-treasury can have at most {} member groups.",
+                    SubTreasury::MAX_MEMBER_GROUPS
+                ),
+            });
+        }
+
+        for (account_index, account_id) in sub_treasury.account_ids.iter().enumerate() {
+            if sub_treasury.account_ids[..account_index].contains(account_id)
+                || sub_treasuries[..index]
+                    .iter()
+                    .any(|other| other.contains_account(account_id))
+            {
+                return Err(RequestError::ValidationError {
+                    info: format!(
+                        "The account {} belongs to more than one sub-treasury.",
+                        Uuid::from_bytes(*account_id).hyphenated()
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub struct ManageSystemInfoRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o ManageSystemInfoOperation,
@@ -53,6 +797,13 @@ impl<'p, 'o> ManageSystemInfoRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for ManageSystemInfoRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        // the logos could have been removed since the request was created
+        if let Some(branding) = &self.operation.input.branding {
+            validate_branding(branding).map_err(|err| RequestExecuteError::Failed {
+                reason: format!("Failed to set the branding: {}", err),
+            })?;
+        }
+
         SYSTEM_SERVICE.update_system_info(self.operation.input.clone());
 
         Ok(RequestExecuteStage::Completed(
@@ -64,14 +815,11 @@ impl Execute for ManageSystemInfoRequestExecute<'_, '_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{
-        core::{read_system_info, test_utils},
-        models::ManageSystemInfoOperationInput,
-    };
+    use crate::core::{read_system_info, test_utils};
+    use crate::models::blob_test_utils::mock_blob;
     use tests::mnanage_system_info_test_utils::{
         mock_manage_system_info_api_input, mock_request_api_operation,
     };
-    use uuid::Uuid;
 
     #[tokio::test]
     async fn test_create_request() {
@@ -96,6 +844,24 @@ mod tests {
                 input: ManageSystemInfoOperationInput {
                     name: Some("name".to_string()),
                     cycle_obtain_strategy: None,
+                    auditors: None,
+                    address_book_sources: None,
+                    name_service: None,
+                    transfer_compliance_thresholds: None,
+                    transfer_max_fees: None,
+                    transfer_screening: None,
+                    transfer_time_lock: None,
+                    metadata_encryption: None,
+                    data_compaction: None,
+                    request_archival: None,
+                    branding: None,
+                    sub_treasuries: None,
+                    maintenance_mode: None,
+                    request_text_limits: None,
+                    approval_reminders: None,
+                    balance_snapshots: None,
+                    fiat_valuation: None,
+                    operation_flags: None,
                 },
             })
         );
@@ -139,6 +905,326 @@ mod tests {
 
         assert_eq!(info.get_name(), "my-updated-name");
     }
+
+    #[tokio::test]
+    async fn test_create_request_with_expired_auditor_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.auditors = Some(vec![station_api::AuditorAccessDTO {
+            principal: Principal::from_slice(&[1; 29]),
+            name: "Auditor".to_string(),
+            expires_at: "1970-01-01T00:00:00Z".to_string(),
+        }]);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_malformed_auditor_expiry_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.auditors = Some(vec![station_api::AuditorAccessDTO {
+            principal: Principal::from_slice(&[1; 29]),
+            name: "Auditor".to_string(),
+            expires_at: "next week".to_string(),
+        }]);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::ValidationError { info }) if info.contains("expires_at")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_zero_compaction_age_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.data_compaction = Some(station_api::DataCompactionConfigDTO::Enabled {
+            min_age_days: 0,
+            export_canister_id: None,
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_short_archival_age_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.request_archival = Some(station_api::RequestArchivalConfigDTO::Enabled {
+            min_age_days: 1,
+            archive_canister_id: Principal::from_slice(&[1; 29]),
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_missing_logo_fails() {
+        let blob = mock_blob();
+        let mut input = mock_manage_system_info_api_input();
+        input.branding = Some(station_api::BrandingConfigDTO {
+            logo_id: None,
+            asset_logos: vec![station_api::AssetLogoDTO {
+                blockchain: "icp".to_string(),
+                standard: "native".to_string(),
+                symbol: "ICP".to_string(),
+                logo_id: Uuid::from_bytes(blob.id).hyphenated().to_string(),
+            }],
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input.clone(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+
+        BLOB_REPOSITORY.insert(blob.to_key(), blob);
+
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_duplicated_auditor_fails() {
+        let auditor = station_api::AuditorAccessDTO {
+            principal: Principal::from_slice(&[1; 29]),
+            name: "Auditor".to_string(),
+            expires_at: "2999-01-01T00:00:00Z".to_string(),
+        };
+        let mut input = mock_manage_system_info_api_input();
+        input.auditors = Some(vec![auditor.clone(), auditor]);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_duplicated_compliance_threshold_fails() {
+        let threshold = station_api::TransferComplianceThresholdDTO {
+            symbol: "ICP".to_string(),
+            amount: candid::Nat::from(1_000_000_000_u64),
+        };
+        let mut input = mock_manage_system_info_api_input();
+        input.transfer_compliance_thresholds = Some(vec![threshold.clone(), threshold]);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_duplicated_max_fee_fails() {
+        let max_fee = station_api::TransferMaxFeeDTO {
+            symbol: "ICP".to_string(),
+            max_fee: candid::Nat::from(10_000_u64),
+        };
+        let mut input = mock_manage_system_info_api_input();
+        input.transfer_max_fees = Some(vec![max_fee.clone(), max_fee]);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_invalid_metadata_encryption_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.metadata_encryption = Some(station_api::MetadataEncryptionConfigDTO::VetKd {
+            key_name: "key_1".to_string(),
+            address_book_metadata_keys: vec!["notes".to_string(), "notes".to_string()],
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_too_long_summary_limit_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.request_text_limits = Some(station_api::RequestTextLimitsDTO {
+            max_title_len: 255,
+            max_summary_len: RequestTextLimits::MAX_SUMMARY_LEN + 1,
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_unordered_approval_reminders_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.approval_reminders = Some(station_api::ApprovalReminderConfigDTO {
+            intervals_secs: vec![48 * 60 * 60, 24 * 60 * 60],
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_too_short_balance_snapshot_interval_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.balance_snapshots = Some(station_api::BalanceSnapshotConfigDTO {
+            interval_secs: Some(60),
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_with_invalid_fiat_currency_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.fiat_valuation = Some(station_api::FiatValuationConfigDTO::Canister {
+            canister_id: Principal::from_slice(&[1; 29]),
+            currencies: vec!["USD".to_string(), "usd".to_string()],
+        });
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_disabling_system_info_changes_fails() {
+        let mut input = mock_manage_system_info_api_input();
+        input.operation_flags = Some(vec![station_api::OperationFlagDTO {
+            operation_type: station_api::RequestOperationTypeDTO::ManageSystemInfo,
+            availability: station_api::OperationAvailabilityDTO::Disabled,
+        }]);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(
+                *Uuid::new_v4().as_bytes(),
+                *Uuid::new_v4().as_bytes(),
+                mock_request_api_operation(),
+                input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +1233,24 @@ mod mnanage_system_info_test_utils {
         station_api::ManageSystemInfoOperationInput {
             name: Some("name".to_string()),
             cycle_obtain_strategy: None,
+            auditors: None,
+            address_book_sources: None,
+            name_service: None,
+            transfer_compliance_thresholds: None,
+            transfer_max_fees: None,
+            transfer_screening: None,
+            transfer_time_lock: None,
+            metadata_encryption: None,
+            data_compaction: None,
+            request_archival: None,
+            branding: None,
+            sub_treasuries: None,
+            maintenance_mode: None,
+            request_text_limits: None,
+            approval_reminders: None,
+            balance_snapshots: None,
+            fiat_valuation: None,
+            operation_flags: None,
         }
     }
 
@@ -158,6 +1262,9 @@ mod mnanage_system_info_test_utils {
             operation: station_api::RequestOperationInput::ManageSystemInfo(
                 mock_manage_system_info_api_input(),
             ),
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
         }
     }
 }
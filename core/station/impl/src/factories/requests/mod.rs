@@ -1,5 +1,4 @@
 use crate::{
-    core::generate_uuid_v4,
     errors::{RequestError, RequestExecuteError},
     models::{Request, RequestOperation},
     services::{
@@ -18,57 +17,84 @@ use std::sync::Arc;
 
 mod add_account;
 mod add_address_book_entry;
+mod add_network;
 mod add_request_policy;
 mod add_user;
 mod add_user_group;
+mod bulk_edit_permission;
 mod call_canister;
 mod change_external_canister;
+mod commit_asset_batch;
 mod configure_external_canister;
 mod create_canister;
+mod decommission_station;
 mod edit_account;
 mod edit_address_book_entry;
+mod edit_network;
 mod edit_permission;
 mod edit_request_policy;
 mod edit_user;
 mod edit_user_group;
+mod execution_steps;
+mod freeze_blockchain;
 mod fund_external_canister;
 mod manage_system_info;
 mod remove_address_book_entry;
 mod remove_request_policy;
 mod remove_user_group;
+mod rotate_account_address;
 mod set_disaster_recovery;
+mod snapshot_external_canister;
 mod system_upgrade;
 mod transfer;
+mod transfer_account_ownership;
+mod unfreeze_blockchain;
 
 use self::{
     add_account::{AddAccountRequestCreate, AddAccountRequestExecute},
     add_address_book_entry::{AddAddressBookEntryRequestCreate, AddAddressBookEntryRequestExecute},
+    add_network::{AddNetworkRequestCreate, AddNetworkRequestExecute},
     add_request_policy::{AddRequestPolicyRequestCreate, AddRequestPolicyRequestExecute},
     add_user::{AddUserRequestCreate, AddUserRequestExecute},
     add_user_group::{AddUserGroupRequestCreate, AddUserGroupRequestExecute},
+    bulk_edit_permission::{BulkEditPermissionRequestCreate, BulkEditPermissionRequestExecute},
     call_canister::{CallExternalCanisterRequestCreate, CallExternalCanisterRequestExecute},
     change_external_canister::{
         ChangeExternalCanisterRequestCreate, ChangeExternalCanisterRequestExecute,
     },
+    commit_asset_batch::{CommitAssetBatchRequestCreate, CommitAssetBatchRequestExecute},
     configure_external_canister::{
         ConfigureExternalCanisterRequestCreate, ConfigureExternalCanisterRequestExecute,
     },
     create_canister::{CreateExternalCanisterRequestCreate, CreateExternalCanisterRequestExecute},
+    decommission_station::{DecommissionStationRequestCreate, DecommissionStationRequestExecute},
     edit_account::{EditAccountRequestCreate, EditAccountRequestExecute},
     edit_address_book_entry::{
         EditAddressBookEntryRequestCreate, EditAddressBookEntryRequestExecute,
     },
+    edit_network::{EditNetworkRequestCreate, EditNetworkRequestExecute},
     edit_permission::{EditPermissionRequestCreate, EditPermissionRequestExecute},
     edit_request_policy::{EditRequestPolicyRequestCreate, EditRequestPolicyRequestExecute},
     edit_user::{EditUserRequestCreate, EditUserRequestExecute},
     edit_user_group::{EditUserGroupRequestCreate, EditUserGroupRequestExecute},
+    freeze_blockchain::{FreezeBlockchainRequestCreate, FreezeBlockchainRequestExecute},
     remove_address_book_entry::{
         RemoveAddressBookEntryRequestCreate, RemoveAddressBookEntryRequestExecute,
     },
     remove_request_policy::{RemoveRequestPolicyRequestCreate, RemoveRequestPolicyRequestExecute},
     remove_user_group::{RemoveUserGroupRequestCreate, RemoveUserGroupRequestExecute},
+    rotate_account_address::{
+        RotateAccountAddressRequestCreate, RotateAccountAddressRequestExecute,
+    },
+    snapshot_external_canister::{
+        SnapshotExternalCanisterRequestCreate, SnapshotExternalCanisterRequestExecute,
+    },
     system_upgrade::{SystemUpgradeRequestCreate, SystemUpgradeRequestExecute},
     transfer::{TransferRequestCreate, TransferRequestExecute},
+    transfer_account_ownership::{
+        TransferAccountOwnershipRequestCreate, TransferAccountOwnershipRequestExecute,
+    },
+    unfreeze_blockchain::{UnfreezeBlockchainRequestCreate, UnfreezeBlockchainRequestExecute},
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -102,10 +128,10 @@ pub struct RequestFactory {}
 
 impl RequestFactory {
     pub async fn create_request(
+        id: UUID,
         requested_by_user: UUID,
         input: CreateRequestInput,
     ) -> Result<Request, RequestError> {
-        let id = *generate_uuid_v4().await.as_bytes();
         match &input.operation {
             RequestOperationInput::Transfer(operation) => {
                 let creator = Box::new(TransferRequestCreate {});
@@ -125,6 +151,18 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::RotateAccountAddress(operation) => {
+                let creator = Box::new(RotateAccountAddressRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::TransferAccountOwnership(operation) => {
+                let creator = Box::new(TransferAccountOwnershipRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddAddressBookEntry(operation) => {
                 let creator = Box::new(AddAddressBookEntryRequestCreate {});
                 creator
@@ -174,7 +212,9 @@ impl RequestFactory {
                     .await
             }
             RequestOperationInput::SystemUpgrade(operation) => {
-                let creator = Box::new(SystemUpgradeRequestCreate {});
+                let creator = Box::new(SystemUpgradeRequestCreate {
+                    external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                });
                 creator
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
@@ -186,7 +226,9 @@ impl RequestFactory {
                     .await
             }
             RequestOperationInput::ChangeExternalCanister(operation) => {
-                let creator = Box::new(ChangeExternalCanisterRequestCreate {});
+                let creator = Box::new(ChangeExternalCanisterRequestCreate {
+                    external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                });
                 creator
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
@@ -198,7 +240,9 @@ impl RequestFactory {
                     .await
             }
             RequestOperationInput::ConfigureExternalCanister(operation) => {
-                let creator = Box::new(ConfigureExternalCanisterRequestCreate {});
+                let creator = Box::new(ConfigureExternalCanisterRequestCreate {
+                    external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                });
                 creator
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
@@ -217,12 +261,34 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::CommitAssetBatch(operation) => {
+                let creator = Box::new(CommitAssetBatchRequestCreate {
+                    external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                });
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::SnapshotExternalCanister(operation) => {
+                let creator = Box::new(SnapshotExternalCanisterRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::EditPermission(operation) => {
                 let creator = Box::new(EditPermissionRequestCreate {});
                 creator
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::BulkEditPermission(operation) => {
+                let creator = Box::new(BulkEditPermissionRequestCreate {
+                    permission_service: Arc::clone(&PERMISSION_SERVICE),
+                });
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddRequestPolicy(operation) => {
                 let creator = Box::new(AddRequestPolicyRequestCreate {});
                 creator
@@ -247,6 +313,36 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::AddNetwork(operation) => {
+                let creator = Box::new(AddNetworkRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::EditNetwork(operation) => {
+                let creator = Box::new(EditNetworkRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::FreezeBlockchain(operation) => {
+                let creator = Box::new(FreezeBlockchainRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::UnfreezeBlockchain(operation) => {
+                let creator = Box::new(UnfreezeBlockchainRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::DecommissionStation(operation) => {
+                let creator = Box::new(DecommissionStationRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
         }
     }
 
@@ -261,6 +357,12 @@ impl RequestFactory {
             RequestOperation::EditAccount(operation) => {
                 Box::new(EditAccountRequestExecute::new(request, operation))
             }
+            RequestOperation::RotateAccountAddress(operation) => {
+                Box::new(RotateAccountAddressRequestExecute::new(request, operation))
+            }
+            RequestOperation::TransferAccountOwnership(operation) => Box::new(
+                TransferAccountOwnershipRequestExecute::new(request, operation),
+            ),
             RequestOperation::AddAddressBookEntry(operation) => {
                 Box::new(AddAddressBookEntryRequestExecute::new(request, operation))
             }
@@ -301,6 +403,7 @@ impl RequestFactory {
                     request,
                     operation,
                     Arc::clone(&CHANGE_CANISTER_SERVICE),
+                    Arc::clone(&EXTERNAL_CANISTER_SERVICE),
                 ))
             }
             RequestOperation::CreateExternalCanister(operation) => {
@@ -331,6 +434,20 @@ impl RequestFactory {
                     Arc::clone(&EXTERNAL_CANISTER_SERVICE),
                 ))
             }
+            RequestOperation::CommitAssetBatch(operation) => {
+                Box::new(CommitAssetBatchRequestExecute::new(
+                    request,
+                    operation,
+                    Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                ))
+            }
+            RequestOperation::SnapshotExternalCanister(operation) => {
+                Box::new(SnapshotExternalCanisterRequestExecute::new(
+                    request,
+                    operation,
+                    Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                ))
+            }
             RequestOperation::EditPermission(operation) => {
                 Box::new(EditPermissionRequestExecute::new(
                     request,
@@ -338,6 +455,13 @@ impl RequestFactory {
                     Arc::clone(&PERMISSION_SERVICE),
                 ))
             }
+            RequestOperation::BulkEditPermission(operation) => {
+                Box::new(BulkEditPermissionRequestExecute::new(
+                    request,
+                    operation,
+                    Arc::clone(&PERMISSION_SERVICE),
+                ))
+            }
             RequestOperation::AddRequestPolicy(operation) => {
                 Box::new(AddRequestPolicyRequestExecute::new(
                     request,
@@ -362,6 +486,21 @@ impl RequestFactory {
             RequestOperation::ManageSystemInfo(operation) => Box::new(
                 manage_system_info::ManageSystemInfoRequestExecute::new(request, operation),
             ),
+            RequestOperation::AddNetwork(operation) => {
+                Box::new(AddNetworkRequestExecute::new(request, operation))
+            }
+            RequestOperation::EditNetwork(operation) => {
+                Box::new(EditNetworkRequestExecute::new(request, operation))
+            }
+            RequestOperation::FreezeBlockchain(operation) => {
+                Box::new(FreezeBlockchainRequestExecute::new(request, operation))
+            }
+            RequestOperation::UnfreezeBlockchain(operation) => {
+                Box::new(UnfreezeBlockchainRequestExecute::new(request, operation))
+            }
+            RequestOperation::DecommissionStation(operation) => {
+                Box::new(DecommissionStationRequestExecute::new(request, operation))
+            }
         }
     }
 }
@@ -376,6 +515,9 @@ pub mod requests_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
         }
     }
 }
@@ -40,7 +40,8 @@ impl Create<station_api::RemoveAddressBookEntryOperationInput>
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
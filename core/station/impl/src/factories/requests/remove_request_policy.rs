@@ -42,7 +42,8 @@ impl Create<station_api::RemoveRequestPolicyOperationInput> for RemoveRequestPol
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -255,6 +256,9 @@ pub mod remove_request_policy_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
         }
     }
 }
@@ -25,7 +25,8 @@ impl Create<station_api::RemoveUserGroupOperationInput> for RemoveUserGroupReque
             RequestOperation::RemoveUserGroup(operation_input.into()),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input
                 .title
@@ -38,28 +39,37 @@ impl Create<station_api::RemoveUserGroupOperationInput> for RemoveUserGroupReque
 }
 
 pub struct RemoveUserGroupRequestExecute<'p, 'o> {
-    request: &'p Request,
+    _request: &'p Request,
     operation: &'o RemoveUserGroupOperation,
 }
 
 impl<'p, 'o> RemoveUserGroupRequestExecute<'p, 'o> {
     pub fn new(request: &'p Request, operation: &'o RemoveUserGroupOperation) -> Self {
-        Self { request, operation }
+        Self {
+            _request: request,
+            operation,
+        }
     }
 }
 
 #[async_trait]
 impl Execute for RemoveUserGroupRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
-        USER_GROUP_SERVICE
-            .remove(&self.operation.input.user_group_id)
+        let affected = USER_GROUP_SERVICE
+            .remove(
+                &self.operation.input.user_group_id,
+                self.operation.input.reassignment,
+            )
             .await
             .map_err(|e| RequestExecuteError::Failed {
                 reason: format!("Failed to remove user group: {}", e),
             })?;
 
+        let mut operation = self.operation.clone();
+        operation.affected = affected;
+
         Ok(RequestExecuteStage::Completed(
-            self.request.operation.clone(),
+            RequestOperation::RemoveUserGroup(operation),
         ))
     }
 }
@@ -0,0 +1,78 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        Request, RequestExecutionPlan, RequestOperation, RotateAccountAddressOperation,
+        RotateAccountAddressOperationInput,
+    },
+    services::ACCOUNT_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct RotateAccountAddressRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::RotateAccountAddressOperationInput> for RotateAccountAddressRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::RotateAccountAddressOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::RotateAccountAddress(RotateAccountAddressOperation {
+                input: RotateAccountAddressOperationInput::from(operation_input),
+                new_address: None,
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Account address rotation".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct RotateAccountAddressRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o RotateAccountAddressOperation,
+}
+
+impl<'p, 'o> RotateAccountAddressRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o RotateAccountAddressOperation) -> Self {
+        Self {
+            _request: request,
+            operation,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for RotateAccountAddressRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let account = ACCOUNT_SERVICE
+            .rotate_account_address(self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to rotate the account address: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.new_address = Some(account.address);
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::RotateAccountAddress(operation),
+        ))
+    }
+}
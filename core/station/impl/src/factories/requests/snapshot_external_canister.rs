@@ -0,0 +1,213 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        Request, RequestExecutionPlan, RequestOperation, SnapshotExternalCanisterOperation,
+        SnapshotExternalCanisterOperationInput, SnapshotExternalCanisterOperationKind,
+    },
+    services::ExternalCanisterService,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+use station_api::{CreateRequestInput, SnapshotExternalCanisterOperationKindDTO};
+use std::sync::Arc;
+
+fn decode_snapshot_id(snapshot_id: &str) -> Result<Vec<u8>, RequestError> {
+    hex::decode(snapshot_id).map_err(|_| RequestError::ValidationError {
+        info: format!("The snapshot id `{}` is not hex encoded.", snapshot_id),
+    })
+}
+
+fn operation_kind(
+    kind: SnapshotExternalCanisterOperationKindDTO,
+) -> Result<SnapshotExternalCanisterOperationKind, RequestError> {
+    Ok(match kind {
+        SnapshotExternalCanisterOperationKindDTO::Take { replace_snapshot } => {
+            SnapshotExternalCanisterOperationKind::Take {
+                replace_snapshot: replace_snapshot
+                    .as_deref()
+                    .map(decode_snapshot_id)
+                    .transpose()?,
+            }
+        }
+        SnapshotExternalCanisterOperationKindDTO::Load { snapshot_id } => {
+            SnapshotExternalCanisterOperationKind::Load {
+                snapshot_id: decode_snapshot_id(&snapshot_id)?,
+            }
+        }
+        SnapshotExternalCanisterOperationKindDTO::Delete { snapshot_id } => {
+            SnapshotExternalCanisterOperationKind::Delete {
+                snapshot_id: decode_snapshot_id(&snapshot_id)?,
+            }
+        }
+    })
+}
+
+pub struct SnapshotExternalCanisterRequestCreate;
+
+#[async_trait]
+impl Create<station_api::SnapshotExternalCanisterOperationInput>
+    for SnapshotExternalCanisterRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: CreateRequestInput,
+        operation_input: station_api::SnapshotExternalCanisterOperationInput,
+    ) -> Result<Request, RequestError> {
+        let kind = operation_kind(operation_input.kind)?;
+        let default_title = match &kind {
+            SnapshotExternalCanisterOperationKind::Take { .. } => "Take canister snapshot",
+            SnapshotExternalCanisterOperationKind::Load { .. } => "Load canister snapshot",
+            SnapshotExternalCanisterOperationKind::Delete { .. } => "Delete canister snapshot",
+        };
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::SnapshotExternalCanister(SnapshotExternalCanisterOperation {
+                input: SnapshotExternalCanisterOperationInput {
+                    canister_id: operation_input.canister_id,
+                    kind,
+                },
+                snapshot_id: None,
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| default_title.to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct SnapshotExternalCanisterRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o SnapshotExternalCanisterOperation,
+    external_canister_service: Arc<ExternalCanisterService>,
+}
+
+impl<'p, 'o> SnapshotExternalCanisterRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o SnapshotExternalCanisterOperation,
+        external_canister_service: Arc<ExternalCanisterService>,
+    ) -> Self {
+        Self {
+            _request: request,
+            operation,
+            external_canister_service,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for SnapshotExternalCanisterRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let canister_id = self.operation.input.canister_id;
+        let mut operation = self.operation.clone();
+
+        match &self.operation.input.kind {
+            SnapshotExternalCanisterOperationKind::Take { replace_snapshot } => {
+                let snapshot = self
+                    .external_canister_service
+                    .take_canister_snapshot(canister_id, replace_snapshot.clone())
+                    .await
+                    .map_err(|err| RequestExecuteError::Failed {
+                        reason: format!("failed to take canister snapshot: {}", err),
+                    })?;
+
+                operation.snapshot_id = Some(snapshot.snapshot_id);
+            }
+            SnapshotExternalCanisterOperationKind::Load { snapshot_id } => {
+                self.external_canister_service
+                    .load_canister_snapshot(canister_id, snapshot_id.clone())
+                    .await
+                    .map_err(|err| RequestExecuteError::Failed {
+                        reason: format!("failed to load canister snapshot: {}", err),
+                    })?;
+            }
+            SnapshotExternalCanisterOperationKind::Delete { snapshot_id } => {
+                self.external_canister_service
+                    .delete_canister_snapshot(canister_id, snapshot_id.clone())
+                    .await
+                    .map_err(|err| RequestExecuteError::Failed {
+                        reason: format!("failed to delete canister snapshot: {}", err),
+                    })?;
+            }
+        }
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::SnapshotExternalCanister(operation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factories::requests::requests_test_utils::mock_request_api_input;
+    use candid::Principal;
+
+    fn mock_operation_api_input(
+        kind: SnapshotExternalCanisterOperationKindDTO,
+    ) -> station_api::SnapshotExternalCanisterOperationInput {
+        station_api::SnapshotExternalCanisterOperationInput {
+            canister_id: Principal::from_slice(&[1; 29]),
+            kind,
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_snapshot_request_with_decoded_snapshot_id() {
+        let operation_input =
+            mock_operation_api_input(SnapshotExternalCanisterOperationKindDTO::Load {
+                snapshot_id: hex::encode([7; 16]),
+            });
+        let request_input = mock_request_api_input(
+            station_api::RequestOperationInput::SnapshotExternalCanister(operation_input.clone()),
+        );
+
+        let request = SnapshotExternalCanisterRequestCreate
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .unwrap();
+
+        assert_eq!(request.title, "Load canister snapshot");
+        match request.operation {
+            RequestOperation::SnapshotExternalCanister(operation) => {
+                assert_eq!(
+                    operation.input.kind,
+                    SnapshotExternalCanisterOperationKind::Load {
+                        snapshot_id: vec![7; 16]
+                    }
+                );
+                assert_eq!(operation.snapshot_id, None);
+            }
+            _ => panic!("Expected SnapshotExternalCanister operation"),
+        }
+    }
+
+    #[tokio::test]
+    async fn snapshot_id_must_be_hex_encoded() {
+        let operation_input =
+            mock_operation_api_input(SnapshotExternalCanisterOperationKindDTO::Take {
+                replace_snapshot: Some("not a snapshot id".to_string()),
+            });
+        let request_input = mock_request_api_input(
+            station_api::RequestOperationInput::SnapshotExternalCanister(operation_input.clone()),
+        );
+
+        let result = SnapshotExternalCanisterRequestCreate
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+}
@@ -1,20 +1,62 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
+    core::ic_cdk::api::id as self_canister_id,
     errors::{RequestError, RequestExecuteError},
+    log_warn,
     models::{
-        Request, RequestExecutionPlan, RequestOperation, SystemUpgradeOperation,
-        SystemUpgradeTarget,
+        CanisterUpgradeDiff, Request, RequestExecutionPlan, RequestOperation,
+        SystemUpgradeOperation, SystemUpgradeTarget,
     },
-    services::{DisasterRecoveryService, SystemService},
+    services::{DisasterRecoveryService, ExternalCanisterService, SystemService, SYSTEM_SERVICE},
 };
 use async_trait::async_trait;
-use candid::Encode;
 use orbit_essentials::types::UUID;
 use sha2::{Digest, Sha256};
 use station_api::{CreateRequestInput, SystemUpgradeOperationInput};
 use std::sync::Arc;
 
-pub struct SystemUpgradeRequestCreate;
+pub struct SystemUpgradeRequestCreate {
+    pub external_canister_service: Arc<ExternalCanisterService>,
+}
+
+impl SystemUpgradeRequestCreate {
+    /// Compares the proposed module with the state of the target when the request is created.
+    async fn upgrade_diff(
+        &self,
+        operation_input: &crate::models::SystemUpgradeOperationInput,
+    ) -> CanisterUpgradeDiff {
+        let canister_id = match operation_input.target {
+            SystemUpgradeTarget::UpgradeStation => self_canister_id(),
+            SystemUpgradeTarget::UpgradeUpgrader => SYSTEM_SERVICE.get_upgrader_canister_id(),
+        };
+
+        let current_state = match self
+            .external_canister_service
+            .canister_current_state(canister_id)
+            .await
+        {
+            Ok(current_state) => Some(current_state),
+            Err(err) => {
+                log_warn!(
+                    "Failed to read the state of canister {}: {}",
+                    canister_id,
+                    err
+                );
+
+                None
+            }
+        };
+
+        CanisterUpgradeDiff {
+            current_state,
+            module_size: Some(operation_input.module.len() as u64),
+            arg_empty: operation_input
+                .arg
+                .as_ref()
+                .map_or(true, |arg| arg.is_empty()),
+        }
+    }
+}
 
 #[async_trait]
 impl Create<SystemUpgradeOperationInput> for SystemUpgradeRequestCreate {
@@ -25,6 +67,28 @@ impl Create<SystemUpgradeOperationInput> for SystemUpgradeRequestCreate {
         input: CreateRequestInput,
         operation_input: SystemUpgradeOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input: crate::models::SystemUpgradeOperationInput = operation_input.into();
+        if !operation_input.arg_injections.is_empty() {
+            if operation_input.target != SystemUpgradeTarget::UpgradeStation {
+                return Err(RequestError::ValidationError {
+                    info: "Values can only be injected into the arg of a station upgrade."
+                        .to_string(),
+                });
+            }
+
+            // the arg is rendered once to make sure that the injections can be applied to it
+            SYSTEM_SERVICE
+                .inject_upgrade_arg(
+                    operation_input.arg.as_deref(),
+                    &operation_input.arg_injections,
+                )
+                .map_err(|err| RequestError::ValidationError {
+                    info: err.to_string(),
+                })?;
+        }
+
+        let upgrade_diff = self.upgrade_diff(&operation_input).await;
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -40,11 +104,13 @@ impl Create<SystemUpgradeOperationInput> for SystemUpgradeRequestCreate {
                     hasher.update(&operation_input.module);
                     hasher.finalize().to_vec()
                 },
-                input: operation_input.into(),
+                input: operation_input,
+                upgrade_diff: Some(upgrade_diff),
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "ChangeCanister".to_string()),
             input.summary,
@@ -85,15 +151,22 @@ impl Execute for SystemUpgradeRequestExecute<'_, '_> {
                 self.system_service
                     .set_self_upgrade_request(self.request.id);
 
-                let default_arg = Encode!(&()).unwrap();
-                let arg = self.operation.input.arg.as_ref().unwrap_or(&default_arg);
-                let out = self
-                    .system_service
-                    .upgrade_station(&self.operation.input.module, arg)
-                    .await
-                    .map_err(|err| RequestExecuteError::Failed {
-                        reason: format!("failed to upgrade station: {}", err),
-                    });
+                // the current values are injected now, so that they are not stale if the request was open for long
+                let out = match self.system_service.inject_upgrade_arg(
+                    self.operation.input.arg.as_deref(),
+                    &self.operation.input.arg_injections,
+                ) {
+                    Ok(arg) => self
+                        .system_service
+                        .upgrade_station(&self.operation.input.module, &arg)
+                        .await
+                        .map_err(|err| RequestExecuteError::Failed {
+                            reason: format!("failed to upgrade station: {}", err),
+                        }),
+                    Err(err) => Err(RequestExecuteError::Failed {
+                        reason: format!("failed to inject the upgrade arg: {}", err),
+                    }),
+                };
 
                 if out.is_err() {
                     self.system_service.clear_self_upgrade_request();
@@ -1,26 +1,326 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
-    core::generate_uuid_v4,
+    core::{generate_uuid_v4, ic_cdk::next_time, read_system_info},
     errors::{RequestError, RequestExecuteError},
-    factories::blockchains::BlockchainApiFactory,
+    factories::blockchains::{BlockchainApi, BlockchainApiFactory},
+    jobs::schedule_transfer_screening,
     mappers::HelperMapper,
     models::{
-        Account, Metadata, Request, RequestExecutionPlan, RequestOperation, Transfer,
-        TransferOperation, TransferOperationInput,
+        Account, ListRequestsOperationType, Metadata, Network, Request, RequestExecutionPlan,
+        RequestOperation, RequestStatus, RequestStatusCode, Transfer, TransferComplianceInfo,
+        TransferDuplicateWarning, TransferFeeEstimate, TransferFeeStrategy, TransferOperation,
+        TransferOperationInput, TransferScreening, TransferTimeLock,
     },
-    repositories::ACCOUNT_REPOSITORY,
+    repositories::{RequestWhereClause, ACCOUNT_REPOSITORY, REQUEST_REPOSITORY},
     services::TransferService,
 };
 use async_trait::async_trait;
 use orbit_essentials::model::ModelValidator;
 use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
+use orbit_essentials::utils::timestamp_to_rfc3339;
 use uuid::Uuid;
 
 fn get_account(from_account_id: &UUID) -> Option<Account> {
     ACCOUNT_REPOSITORY.get(&Account::key(*from_account_id))
 }
 
+/// Estimates the transaction fee of the account with the given strategy.
+///
+/// Returns `None` if the account does not exist or the fee could not be estimated, in which case
+/// the fee is estimated again when the request is executed.
+async fn estimate_fee(
+    from_account_id: &UUID,
+    strategy: TransferFeeStrategy,
+) -> Option<TransferFeeEstimate> {
+    let account = get_account(from_account_id)?;
+    let blockchain_api =
+        BlockchainApiFactory::build(&account.blockchain, &account.standard).ok()?;
+    let transaction_fee = blockchain_api
+        .estimate_transaction_fee(&account, &strategy)
+        .await
+        .ok()?;
+
+    Some(TransferFeeEstimate {
+        fee: candid::Nat(transaction_fee.fee),
+        strategy,
+        estimated_at: next_time(),
+    })
+}
+
+/// Resolves the destination of the transfer if it is a human-readable name.
+///
+/// Returns the address to transfer to, and the name if the destination was one. Destinations of accounts
+/// that do not exist are kept as they are, since the request fails validation anyway.
+async fn resolve_destination(
+    from_account_id: &UUID,
+    to: String,
+) -> Result<(String, Option<String>), RequestError> {
+    let Some(account) = get_account(from_account_id) else {
+        return Ok((to, None));
+    };
+
+    let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)
+        .map_err(|e| RequestError::ValidationError {
+            info: format!("Failed to build blockchain api: {}", e),
+        })?;
+
+    match blockchain_api
+        .resolve_destination_name(&to)
+        .await
+        .map_err(|e| RequestError::ValidationError {
+            info: format!("Failed to resolve the destination: {}", e),
+        })? {
+        Some(address) => Ok((address, Some(to))),
+        None => Ok((to, None)),
+    }
+}
+
+/// Validates the travel rule information of the transfer, which is mandatory if the amount reaches
+/// the compliance threshold of the asset of the account.
+fn validate_compliance(
+    from_account_id: &UUID,
+    amount: &candid::Nat,
+    compliance: Option<&TransferComplianceInfo>,
+) -> Result<(), RequestError> {
+    if let Some(compliance) = compliance {
+        compliance
+            .validate()
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+
+        let unencrypted_fields = compliance.unencrypted_fields();
+        if read_system_info().get_metadata_encryption().is_enabled()
+            && !unencrypted_fields.is_empty()
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The compliance fields must be encrypted: {}",
+                    unencrypted_fields.join(", ")
+                ),
+            });
+        }
+    }
+
+    let Some(account) = get_account(from_account_id) else {
+        return Ok(());
+    };
+
+    if !read_system_info().requires_transfer_compliance(&account.symbol, amount) {
+        return Ok(());
+    }
+
+    let missing_fields = compliance
+        .map(|compliance| compliance.missing_fields())
+        .unwrap_or_else(|| TransferComplianceInfo::default().missing_fields());
+
+    if !missing_fields.is_empty() {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "Transfers of at least the compliance threshold of {} require the compliance fields: {}",
+                account.symbol,
+                missing_fields.join(", ")
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that the transfer is made from the current address of the account or from a retired address
+/// whose grace period has not ended yet.
+fn validate_from_address(
+    from_account_id: &UUID,
+    from_address: Option<&str>,
+) -> Result<(), RequestError> {
+    let (Some(account), Some(from_address)) = (get_account(from_account_id), from_address) else {
+        return Ok(());
+    };
+
+    if account.spending_from(from_address, next_time()).is_none() {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The address {} is not an address of the account or its grace period has ended",
+                from_address
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that the account is not under review, which blocks the creation of its transfer requests.
+///
+/// The accounts whose address is still being generated can't be transferred from either.
+fn validate_not_under_review(from_account_id: &UUID) -> Result<(), RequestError> {
+    let Some(account) = get_account(from_account_id) else {
+        return Ok(());
+    };
+
+    if !account.is_ready() {
+        return Err(RequestError::AccountAddressPending {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+        });
+    }
+
+    match account.active_review(next_time()) {
+        Some(review) => Err(RequestError::AccountUnderReview {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            reason: review.reason.clone(),
+            expires_at: timestamp_to_rfc3339(&review.expires_at),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the network of the transfer, which is the network of the account that it is made from.
+fn resolve_network(
+    from_account_id: &UUID,
+    network: Option<station_api::NetworkDTO>,
+) -> Result<String, RequestError> {
+    let account_network = get_account(from_account_id)
+        .map(|account| account.network_id().to_string())
+        .unwrap_or_else(|| Network::MAINNET.to_string());
+
+    match network {
+        Some(network) if network.id != account_network => Err(RequestError::ValidationError {
+            info: format!(
+                "The account holds its assets on the network {}, not on {}",
+                account_network, network.id
+            ),
+        }),
+        _ => Ok(account_network),
+    }
+}
+
+/// Returns the maximum fee of the transfer, which is bounded by the maximum fee that the station allows
+/// for the asset of the account, if any.
+///
+/// The maximum fee of the station is used when the transfer does not set one, and a higher one is rejected.
+fn resolve_max_fee(
+    from_account_id: &UUID,
+    max_fee: Option<candid::Nat>,
+) -> Result<Option<candid::Nat>, RequestError> {
+    let Some(ceiling) = get_account(from_account_id)
+        .and_then(|account| read_system_info().get_transfer_max_fee(&account.symbol))
+    else {
+        return Ok(max_fee);
+    };
+
+    match max_fee {
+        Some(max_fee) if max_fee > ceiling => Err(RequestError::ValidationError {
+            info: format!(
+                "The maximum fee {} exceeds the maximum fee {} allowed by the station",
+                max_fee, ceiling
+            ),
+        }),
+        Some(max_fee) => Ok(Some(max_fee)),
+        None => Ok(Some(ceiling)),
+    }
+}
+
+/// Returns the screening of the transfer if its amount requires one, the screening service is called
+/// in the background once the request is created.
+fn new_screening(from_account_id: &UUID, amount: &candid::Nat) -> Option<TransferScreening> {
+    let account = get_account(from_account_id)?;
+    let (canister_id, blocks_approval) = read_system_info()
+        .get_transfer_screening()
+        .screening_of(&account.symbol, amount)?;
+
+    Some(TransferScreening::new(
+        canister_id,
+        blocks_approval,
+        next_time(),
+    ))
+}
+
+/// Returns the time lock of the transfer if its amount requires one, the delay starts once the request is approved.
+fn new_time_lock(from_account_id: &UUID, amount: &candid::Nat) -> Option<TransferTimeLock> {
+    let account = get_account(from_account_id)?;
+    let (delay_secs, canceller_group_ids) = read_system_info()
+        .get_transfer_time_lock()
+        .time_lock_of(&account.symbol, amount)?;
+
+    Some(TransferTimeLock::new(delay_secs, canceller_group_ids))
+}
+
+/// How long completed transfers are considered when looking for duplicates, which is 7 days.
+const DUPLICATE_TRANSFER_WINDOW_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+/// How much the amounts of two transfers can differ for them to be considered duplicates.
+const DUPLICATE_TRANSFER_AMOUNT_TOLERANCE_PERCENT: u32 = 1;
+
+/// Returns whether the amounts differ by at most the duplicate tolerance of the larger one.
+fn is_similar_amount(amount: &candid::Nat, other: &candid::Nat) -> bool {
+    let (larger, smaller) = match amount > other {
+        true => (&amount.0, &other.0),
+        false => (&other.0, &amount.0),
+    };
+
+    (larger - smaller) * 100_u32 <= larger * DUPLICATE_TRANSFER_AMOUNT_TOLERANCE_PERCENT
+}
+
+/// Finds the transfers of the same asset with a similar amount to the same destination that are pending or
+/// were completed recently, so that the approvers are warned before the same invoice is paid twice.
+fn find_duplicate_transfers(
+    from_account_id: &UUID,
+    to: &str,
+    amount: &candid::Nat,
+) -> Option<TransferDuplicateWarning> {
+    let account = get_account(from_account_id)?;
+    let completed_after = next_time().saturating_sub(DUPLICATE_TRANSFER_WINDOW_NS);
+    let is_same_asset = |account_id: &UUID| {
+        *account_id == account.id
+            || get_account(account_id).is_some_and(|other| {
+                other.blockchain == account.blockchain && other.symbol == account.symbol
+            })
+    };
+
+    let request_ids = REQUEST_REPOSITORY
+        .find_ids_where(
+            RequestWhereClause {
+                created_dt_from: None,
+                created_dt_to: None,
+                expiration_dt_from: None,
+                expiration_dt_to: None,
+                operation_types: vec![ListRequestsOperationType::Transfer(None)],
+                statuses: vec![
+                    RequestStatusCode::Created,
+                    RequestStatusCode::Approved,
+                    RequestStatusCode::Scheduled,
+                    RequestStatusCode::Processing,
+                    RequestStatusCode::Completed,
+                ],
+                approvers: vec![],
+                not_approvers: vec![],
+                requesters: vec![],
+                not_requesters: vec![],
+                excluded_ids: vec![],
+                viewer: None,
+            },
+            None,
+        )
+        .ok()?
+        .into_iter()
+        .filter_map(|request_id| REQUEST_REPOSITORY.get(&Request::key(request_id)))
+        .filter(|request| match request.status {
+            RequestStatus::Completed { completed_at } => completed_at >= completed_after,
+            _ => true,
+        })
+        .filter(|request| match &request.operation {
+            RequestOperation::Transfer(operation) => {
+                operation.input.to == to
+                    && is_similar_amount(&operation.input.amount, amount)
+                    && is_same_asset(&operation.input.from_account_id)
+            }
+            _ => false,
+        })
+        .map(|request| request.id)
+        .collect::<Vec<_>>();
+
+    (!request_ids.is_empty()).then_some(TransferDuplicateWarning { request_ids })
+}
+
 pub struct TransferRequestCreate {}
 
 #[async_trait]
@@ -38,6 +338,53 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
                     info: format!("Invalid from_account_id: {}", e),
                 }
             })?;
+        validate_not_under_review(from_account_id.as_bytes())?;
+        let compliance: Option<TransferComplianceInfo> = operation_input.compliance.map(Into::into);
+        validate_compliance(
+            from_account_id.as_bytes(),
+            &operation_input.amount,
+            compliance.as_ref(),
+        )?;
+        validate_from_address(
+            from_account_id.as_bytes(),
+            operation_input.from_address.as_deref(),
+        )?;
+        let network = resolve_network(from_account_id.as_bytes(), operation_input.network)?;
+        let (to, to_name) =
+            resolve_destination(from_account_id.as_bytes(), operation_input.to).await?;
+        let screening = new_screening(from_account_id.as_bytes(), &operation_input.amount);
+        let has_screening = screening.is_some();
+        let time_lock = new_time_lock(from_account_id.as_bytes(), &operation_input.amount);
+        let duplicate_warning =
+            find_duplicate_transfers(from_account_id.as_bytes(), &to, &operation_input.amount);
+        let max_fee = resolve_max_fee(from_account_id.as_bytes(), operation_input.max_fee)?;
+        let fee_strategy: Option<TransferFeeStrategy> =
+            operation_input.fee_strategy.map(Into::into);
+        let fee_estimate = match operation_input.fee {
+            Some(_) => None,
+            None => {
+                estimate_fee(from_account_id.as_bytes(), fee_strategy.unwrap_or_default()).await
+            }
+        };
+
+        if let Some(max_fee) = &max_fee {
+            let fee = operation_input
+                .fee
+                .as_ref()
+                .or(fee_estimate.as_ref().map(|estimate| &estimate.fee));
+
+            if let Some(fee) = fee {
+                if fee > max_fee {
+                    return Err(RequestError::ValidationError {
+                        info: format!(
+                            "The transaction fee {} exceeds the maximum fee {}",
+                            fee, max_fee
+                        ),
+                    });
+                }
+            }
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -45,23 +392,29 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
             RequestOperation::Transfer(TransferOperation {
                 transfer_id: None,
                 fee: None,
+                fee_estimate,
+                to_name,
+                screening,
                 input: TransferOperationInput {
                     from_account_id: *from_account_id.as_bytes(),
-                    to: operation_input.to,
+                    to,
                     amount: operation_input.amount,
                     fee: operation_input.fee,
+                    fee_strategy,
+                    max_fee,
+                    compliance,
                     // todo: add metadata mapping
                     metadata: Metadata::default(),
-                    // todo: add network mapping
-                    network: match operation_input.network {
-                        Some(network) => network.id,
-                        None => "mainnet".to_string(),
-                    },
+                    network,
+                    from_address: operation_input.from_address,
                 },
+                time_lock,
+                duplicate_warning,
             }),
             input
                 .execution_plan
-                .map(Into::into)
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
                 .unwrap_or(RequestExecutionPlan::Immediate),
             input.title.unwrap_or_else(|| "Transfer".to_string()),
             input.summary,
@@ -69,6 +422,10 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
 
         request.validate()?;
 
+        if has_screening {
+            schedule_transfer_screening(next_time());
+        }
+
         Ok(request)
     }
 }
@@ -87,6 +444,74 @@ impl<'p, 'o> TransferRequestExecute<'p, 'o> {
             transfer_service: TransferService::default(),
         }
     }
+
+    /// Resolves the fee of the transfer at execution time.
+    ///
+    /// An explicit fee always takes precedence, otherwise the fee estimate taken at creation time is used
+    /// if it is still recent enough, or a new estimate is taken with the requested fee strategy.
+    async fn resolve_fee(
+        &self,
+        account: &Account,
+        blockchain_api: &dyn BlockchainApi,
+    ) -> Result<candid::Nat, RequestExecuteError> {
+        let fee = match (&self.operation.input.fee, &self.operation.fee_estimate) {
+            (Some(fee), _) => fee.clone(),
+            (None, Some(estimate)) if !estimate.is_stale(next_time()) => estimate.fee.clone(),
+            _ => {
+                let strategy = self.operation.input.fee_strategy.unwrap_or_default();
+                let transaction_fee = blockchain_api
+                    .estimate_transaction_fee(account, &strategy)
+                    .await
+                    .map_err(|e| RequestExecuteError::Failed {
+                        reason: format!("Failed to fetch transaction fee: {}", e),
+                    })?;
+
+                candid::Nat(transaction_fee.fee)
+            }
+        };
+
+        if let Some(max_fee) = &self.operation.input.max_fee {
+            if fee > *max_fee {
+                return Err(RequestExecuteError::Failed {
+                    reason: format!(
+                        "The transaction fee {} exceeds the maximum fee {}",
+                        fee, max_fee
+                    ),
+                });
+            }
+        }
+
+        Ok(fee)
+    }
+
+    /// Verifies that the name of the destination still resolves to the address that was approved,
+    /// since the owner of the name could have changed since the request was created.
+    async fn verify_destination(
+        &self,
+        blockchain_api: &dyn BlockchainApi,
+    ) -> Result<(), RequestExecuteError> {
+        let Some(name) = &self.operation.to_name else {
+            return Ok(());
+        };
+
+        let address = blockchain_api
+            .resolve_destination_name(name)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to resolve the destination: {}", e),
+            })?;
+
+        if address.as_ref() != Some(&self.operation.input.to) {
+            return Err(RequestExecuteError::Failed {
+                reason: format!(
+                    "The name {} no longer resolves to the approved address {}",
+                    name, self.operation.input.to
+                ),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -105,33 +530,36 @@ impl Execute for TransferRequestExecute<'_, '_> {
             .map_err(|e| RequestExecuteError::Failed {
                 reason: format!("Failed to build blockchain api: {}", e),
             })?;
-        let fee = match &self.operation.input.fee {
-            Some(fee) => fee.clone(),
-            None => {
-                let transaction_fee =
-                    blockchain_api
-                        .transaction_fee(&account)
-                        .await
-                        .map_err(|e| RequestExecuteError::Failed {
-                            reason: format!("Failed to fetch transaction fee: {}", e),
-                        })?;
+        if self
+            .operation
+            .screening
+            .as_ref()
+            .is_some_and(|screening| screening.is_blocking_approval())
+        {
+            return Err(RequestExecuteError::Failed {
+                reason: "The transfer did not pass its screening.".to_string(),
+            });
+        }
 
-                candid::Nat(transaction_fee.fee)
-            }
-        };
+        self.verify_destination(blockchain_api.as_ref()).await?;
+        let fee = self.resolve_fee(&account, blockchain_api.as_ref()).await?;
+
+        let mut transfer = Transfer::new(
+            self.request.id,
+            *generate_uuid_v4().await.as_bytes(),
+            self.request.requested_by,
+            self.operation.input.from_account_id,
+            self.operation.input.to.clone(),
+            self.operation.input.metadata.clone(),
+            self.operation.input.amount.clone(),
+            fee,
+            self.operation.input.network.clone(),
+        );
+        transfer.compliance = self.operation.input.compliance.clone();
+        transfer.from_address = self.operation.input.from_address.clone();
 
         self.transfer_service
-            .add_transfer(Transfer::new(
-                self.request.id,
-                *generate_uuid_v4().await.as_bytes(),
-                self.request.requested_by,
-                self.operation.input.from_account_id,
-                self.operation.input.to.clone(),
-                self.operation.input.metadata.clone(),
-                self.operation.input.amount.clone(),
-                fee,
-                self.operation.input.network.clone(),
-            ))
+            .add_transfer(transfer)
             .map_err(|e| RequestExecuteError::Failed {
                 reason: format!("Failed to validate transfer: {}", e),
             })?;
@@ -141,3 +569,388 @@ impl Execute for TransferRequestExecute<'_, '_> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{test_utils, write_system_info},
+        factories::requests::requests_test_utils::mock_request_api_input,
+        models::{
+            account_test_utils::add_account,
+            request_test_utils::mock_request,
+            system::{MetadataEncryptionConfig, TransferComplianceThreshold, TransferMaxFee},
+            AccountReview, AccountSetupState,
+        },
+        repositories::RequestRepository,
+    };
+    use uuid::Uuid;
+
+    fn mock_transfer_api_input(from_account_id: &UUID) -> station_api::TransferOperationInput {
+        station_api::TransferOperationInput {
+            from_account_id: Uuid::from_bytes(*from_account_id).hyphenated().to_string(),
+            to: "0x1234".to_string(),
+            amount: candid::Nat::from(100_u64),
+            fee: None,
+            metadata: vec![],
+            network: None,
+            fee_strategy: None,
+            max_fee: None,
+            compliance: None,
+            from_address: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_create_with_fee_above_max_fee() {
+        test_utils::init_canister_system();
+        let account = add_account(&[1; 16]);
+        let mut operation_input = mock_transfer_api_input(&account.id);
+        operation_input.fee = Some(candid::Nat::from(20_000_u64));
+        operation_input.max_fee = Some(candid::Nat::from(10_000_u64));
+
+        let result = TransferRequestCreate {}
+            .create(
+                [2; 16],
+                [3; 16],
+                mock_request_api_input(station_api::RequestOperationInput::Transfer(
+                    operation_input.clone(),
+                )),
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::ValidationError { info }) if info.contains("exceeds the maximum fee")
+        ));
+    }
+
+    #[test]
+    fn max_fee_is_bounded_by_the_station() {
+        test_utils::init_canister_system();
+        let account = add_account(&[1; 16]);
+
+        assert_eq!(resolve_max_fee(&account.id, None), Ok(None));
+
+        let mut system_info = read_system_info();
+        system_info.set_transfer_max_fees(vec![TransferMaxFee {
+            symbol: account.symbol.clone(),
+            max_fee: candid::Nat::from(10_000_u64),
+        }]);
+        write_system_info(system_info);
+
+        assert_eq!(
+            resolve_max_fee(&account.id, None),
+            Ok(Some(candid::Nat::from(10_000_u64)))
+        );
+        assert_eq!(
+            resolve_max_fee(&account.id, Some(candid::Nat::from(5_000_u64))),
+            Ok(Some(candid::Nat::from(5_000_u64)))
+        );
+        assert!(matches!(
+            resolve_max_fee(&account.id, Some(candid::Nat::from(10_001_u64))),
+            Err(RequestError::ValidationError { info }) if info.contains("allowed by the station")
+        ));
+    }
+
+    #[tokio::test]
+    async fn fail_create_from_account_under_review() {
+        test_utils::init_canister_system();
+        let mut account = add_account(&[1; 16]);
+        account.review = Some(AccountReview {
+            reason: "Q3 policy audit".to_string(),
+            flagged_by: [4; 16],
+            flagged_at: next_time(),
+            expires_at: next_time() + 60 * 1_000_000_000,
+        });
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+        let operation_input = mock_transfer_api_input(&account.id);
+
+        let result = TransferRequestCreate {}
+            .create(
+                [2; 16],
+                [3; 16],
+                mock_request_api_input(station_api::RequestOperationInput::Transfer(
+                    operation_input.clone(),
+                )),
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::AccountUnderReview { reason, .. }) if reason == "Q3 policy audit"
+        ));
+    }
+
+    #[tokio::test]
+    async fn fail_create_from_account_with_pending_address() {
+        test_utils::init_canister_system();
+        let mut account = add_account(&[1; 16]);
+        account.address = String::new();
+        account.setup_state = AccountSetupState::pending_address_generation(
+            1,
+            "signing failed".to_string(),
+            next_time(),
+        );
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+        let operation_input = mock_transfer_api_input(&account.id);
+
+        let result = TransferRequestCreate {}
+            .create(
+                [2; 16],
+                [3; 16],
+                mock_request_api_input(station_api::RequestOperationInput::Transfer(
+                    operation_input.clone(),
+                )),
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::AccountAddressPending { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_warns_of_duplicate_transfers() {
+        test_utils::init_canister_system();
+        let account = add_account(&[1; 16]);
+        let mut completed_transfer = mock_request();
+        completed_transfer.status = RequestStatus::Completed {
+            completed_at: next_time(),
+        };
+        completed_transfer.operation = RequestOperation::Transfer(TransferOperation {
+            transfer_id: None,
+            fee: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
+            input: TransferOperationInput {
+                from_account_id: account.id,
+                to: "0x1234".to_string(),
+                amount: candid::Nat::from(101_u64),
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                fee: None,
+                fee_strategy: None,
+                max_fee: None,
+                compliance: None,
+                from_address: None,
+            },
+            time_lock: None,
+            duplicate_warning: None,
+        });
+        RequestRepository::with_empty_observers()
+            .insert(completed_transfer.to_key(), completed_transfer.clone());
+
+        let mut operation_input = mock_transfer_api_input(&account.id);
+        let request = TransferRequestCreate {}
+            .create(
+                [2; 16],
+                [3; 16],
+                mock_request_api_input(station_api::RequestOperationInput::Transfer(
+                    operation_input.clone(),
+                )),
+                operation_input.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            request.operation,
+            RequestOperation::Transfer(TransferOperation {
+                duplicate_warning: Some(warning),
+                ..
+            }) if warning.request_ids == vec![completed_transfer.id]
+        ));
+
+        operation_input.amount = candid::Nat::from(200_u64);
+        let request = TransferRequestCreate {}
+            .create(
+                [4; 16],
+                [3; 16],
+                mock_request_api_input(station_api::RequestOperationInput::Transfer(
+                    operation_input.clone(),
+                )),
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            request.operation,
+            RequestOperation::Transfer(TransferOperation {
+                duplicate_warning: None,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn estimate_fee_with_requested_strategy() {
+        let account = add_account(&[1; 16]);
+
+        let estimate = estimate_fee(&account.id, TransferFeeStrategy::Fast)
+            .await
+            .expect("Failed to estimate fee");
+
+        assert_eq!(estimate.strategy, TransferFeeStrategy::Fast);
+        assert_eq!(estimate.fee, candid::Nat::from(10_000_u64));
+        assert!(estimate_fee(&[9; 16], TransferFeeStrategy::Fast)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn execution_fee_is_bounded_by_max_fee() {
+        let account = add_account(&[1; 16]);
+        let mut request = mock_request();
+        let operation = TransferOperation {
+            transfer_id: None,
+            fee: None,
+            fee_estimate: Some(TransferFeeEstimate {
+                fee: candid::Nat::from(10_000_u64),
+                strategy: TransferFeeStrategy::Standard,
+                estimated_at: next_time(),
+            }),
+            to_name: None,
+            screening: None,
+            input: TransferOperationInput {
+                from_account_id: account.id,
+                to: "0x1234".to_string(),
+                amount: candid::Nat::from(100_u64),
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                fee: None,
+                fee_strategy: None,
+                max_fee: Some(candid::Nat::from(5_000_u64)),
+                compliance: None,
+                from_address: None,
+            },
+            time_lock: None,
+            duplicate_warning: None,
+        };
+        request.operation = RequestOperation::Transfer(operation.clone());
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard).unwrap();
+        let execute = TransferRequestExecute::new(&request, &operation);
+
+        let result = execute.resolve_fee(&account, blockchain_api.as_ref()).await;
+
+        assert!(matches!(
+            result,
+            Err(RequestExecuteError::Failed { reason }) if reason.contains("exceeds the maximum fee")
+        ));
+    }
+
+    #[tokio::test]
+    async fn fail_create_with_name_without_name_service() {
+        test_utils::init_canister_system();
+        let account = add_account(&[1; 16]);
+        let mut operation_input = mock_transfer_api_input(&account.id);
+        operation_input.to = "alice.icp".to_string();
+
+        let result = TransferRequestCreate {}
+            .create(
+                [2; 16],
+                [3; 16],
+                mock_request_api_input(station_api::RequestOperationInput::Transfer(
+                    operation_input.clone(),
+                )),
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::ValidationError { info }) if info.contains("No name service is configured")
+        ));
+    }
+
+    #[tokio::test]
+    async fn compliance_is_required_from_the_threshold() {
+        test_utils::init_canister_system();
+        let account = add_account(&[1; 16]);
+        let mut system_info = read_system_info();
+        system_info.set_transfer_compliance_thresholds(vec![TransferComplianceThreshold {
+            symbol: account.symbol.clone(),
+            amount: candid::Nat::from(100_u64),
+        }]);
+        write_system_info(system_info);
+
+        let below_threshold = candid::Nat::from(99_u64);
+        let threshold = candid::Nat::from(100_u64);
+
+        assert!(validate_compliance(&account.id, &below_threshold, None).is_ok());
+        assert!(matches!(
+            validate_compliance(&account.id, &threshold, None),
+            Err(RequestError::ValidationError { info }) if info.contains("purpose_code")
+        ));
+
+        let compliance = TransferComplianceInfo {
+            beneficiary_name: Some("Beneficiary".to_string()),
+            originator_name: Some("Originator".to_string()),
+            originator_identifier: Some("CH-123.456.789".to_string()),
+            purpose_code: Some("SUPP".to_string()),
+        };
+
+        assert!(validate_compliance(&account.id, &threshold, Some(&compliance)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn compliance_must_be_encrypted_when_metadata_encryption_is_enabled() {
+        test_utils::init_canister_system();
+        let account = add_account(&[1; 16]);
+        let mut system_info = read_system_info();
+        system_info.set_metadata_encryption(MetadataEncryptionConfig::VetKd {
+            key_name: "key_1".to_string(),
+            address_book_metadata_keys: vec![],
+        });
+        write_system_info(system_info);
+
+        let amount = candid::Nat::from(100_u64);
+        let mut compliance = TransferComplianceInfo {
+            beneficiary_name: Some("vetkd:v1:YmVuZWZpY2lhcnk=".to_string()),
+            originator_name: Some("Originator".to_string()),
+            originator_identifier: None,
+            purpose_code: None,
+        };
+
+        assert!(matches!(
+            validate_compliance(&account.id, &amount, Some(&compliance)),
+            Err(RequestError::ValidationError { info }) if info.contains("originator_name")
+        ));
+
+        compliance.originator_name = Some("vetkd:v1:b3JpZ2luYXRvcg==".to_string());
+
+        assert!(validate_compliance(&account.id, &amount, Some(&compliance)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn addresses_are_not_resolved() {
+        let account = add_account(&[1; 16]);
+
+        let (to, to_name) = resolve_destination(&account.id, "0x1234".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(to, "0x1234");
+        assert!(to_name.is_none());
+    }
+
+    #[test]
+    fn fee_estimate_becomes_stale() {
+        let estimate = TransferFeeEstimate {
+            fee: candid::Nat::from(10_000_u64),
+            strategy: TransferFeeStrategy::Standard,
+            estimated_at: 0,
+        };
+
+        assert!(!estimate.is_stale(TransferFeeEstimate::MAX_AGE_NS));
+        assert!(estimate.is_stale(TransferFeeEstimate::MAX_AGE_NS + 1));
+    }
+}
@@ -0,0 +1,91 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        Request, RequestApprovalStatus, RequestExecutionPlan, RequestOperation,
+        TransferAccountOwnershipOperation, TransferAccountOwnershipOperationInput, User,
+    },
+    repositories::USER_REPOSITORY,
+    services::ACCOUNT_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::{repository::Repository, types::UUID};
+
+pub struct TransferAccountOwnershipRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::TransferAccountOwnershipOperationInput>
+    for TransferAccountOwnershipRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::TransferAccountOwnershipOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::TransferAccountOwnership(TransferAccountOwnershipOperation {
+                input: TransferAccountOwnershipOperationInput::from(operation_input),
+                acknowledged_by: None,
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Account ownership transfer".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct TransferAccountOwnershipRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o TransferAccountOwnershipOperation,
+}
+
+impl<'p, 'o> TransferAccountOwnershipRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o TransferAccountOwnershipOperation) -> Self {
+        Self { request, operation }
+    }
+
+    /// Finds the approval that acknowledged the handover on behalf of the receiving party.
+    fn find_acknowledgment(&self) -> Option<UUID> {
+        self.request
+            .approvals
+            .iter()
+            .filter(|approval| approval.status == RequestApprovalStatus::Approved)
+            .find(|approval| {
+                USER_REPOSITORY
+                    .get(&User::key(approval.approver_id))
+                    .is_some_and(|user| self.operation.input.to.is_represented_by(&user))
+            })
+            .map(|approval| approval.approver_id)
+    }
+}
+
+#[async_trait]
+impl Execute for TransferAccountOwnershipRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ACCOUNT_SERVICE
+            .transfer_account_ownership(self.operation.input.to_owned())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to transfer the account ownership: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.acknowledged_by = self.find_acknowledgment();
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::TransferAccountOwnership(operation),
+        ))
+    }
+}
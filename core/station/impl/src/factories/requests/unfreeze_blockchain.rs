@@ -0,0 +1,68 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{Request, RequestExecutionPlan, RequestOperation, UnfreezeBlockchainOperation},
+    services::SYSTEM_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct UnfreezeBlockchainRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::UnfreezeBlockchainOperationInput> for UnfreezeBlockchainRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::UnfreezeBlockchainOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::UnfreezeBlockchain(UnfreezeBlockchainOperation {
+                input: operation_input.into(),
+            }),
+            input
+                .execution_plan
+                .map(RequestExecutionPlan::try_from)
+                .transpose()?
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Blockchain unfreeze".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct UnfreezeBlockchainRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o UnfreezeBlockchainOperation,
+}
+
+impl<'p, 'o> UnfreezeBlockchainRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o UnfreezeBlockchainOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for UnfreezeBlockchainRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        SYSTEM_SERVICE
+            .unfreeze_blockchain(self.request.id, self.operation.input.clone())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to unfreeze the accounts of the blockchain: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -0,0 +1,169 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    models::{
+        GroupMembershipChange, NotificationType, User, UserGroupMembershipChangedNotification,
+        ADMIN_GROUP_ID,
+    },
+    repositories::{USER_GROUP_REPOSITORY, USER_REPOSITORY},
+    services::{NotificationService, NOTIFICATION_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::repository::Repository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct Job {
+    notification_service: Arc<NotificationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            notification_service: Arc::clone(&NOTIFICATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ApplyGroupMemberships;
+
+    async fn run() -> bool {
+        Self::default().apply_group_memberships().await;
+
+        true
+    }
+}
+
+/// This job is responsible for adding users to and removing them from the groups of their scheduled
+/// memberships once these start or expire, and for notifying the users and the admins of the changes.
+///
+/// The job schedules itself for the next membership change, so that it only runs when there is work to do.
+impl Job {
+    async fn apply_group_memberships(&self) {
+        let now = next_time();
+        let mut next_change_at = None;
+
+        for mut user in USER_REPOSITORY.list() {
+            if user.scheduled_memberships.is_empty() {
+                continue;
+            }
+
+            let changes = user.apply_scheduled_memberships(now);
+            if !changes.is_empty() {
+                user.last_modification_timestamp = now;
+                user.version += 1;
+
+                // the insert goes through the repository so that the group indexes of the user are updated
+                USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+                for change in changes {
+                    self.notify_change(&user, change).await;
+                }
+            }
+
+            next_change_at = [next_change_at, user.next_membership_change_at()]
+                .into_iter()
+                .flatten()
+                .min();
+        }
+
+        if let Some(next_change_at) = next_change_at {
+            schedule_group_membership_changes(next_change_at);
+        }
+    }
+
+    async fn notify_change(&self, user: &User, change: GroupMembershipChange) {
+        let group_name = USER_GROUP_REPOSITORY
+            .get(&change.group_id)
+            .map(|group| group.name)
+            .unwrap_or_else(|| Uuid::from_bytes(change.group_id).hyphenated().to_string());
+
+        let title = match change.joined {
+            true => format!("{} joined the group {}", user.name, group_name),
+            false => format!("{} left the group {}", user.name, group_name),
+        };
+
+        self.notification_service
+            .send_group_notification(
+                [user.id],
+                [*ADMIN_GROUP_ID],
+                NotificationType::UserGroupMembershipChanged(
+                    UserGroupMembershipChangedNotification {
+                        user_id: user.id,
+                        user_group_id: change.group_id,
+                        joined: change.joined,
+                    },
+                ),
+                title,
+                None,
+            )
+            .await;
+    }
+}
+
+/// Schedules the job to apply the scheduled group memberships that are due at the given time.
+pub fn schedule_group_membership_changes(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            user_group_test_utils::add_group, user_test_utils::mock_user, ScheduledGroupMembership,
+        },
+        repositories::{NotificationFindByUserWhereClause, NOTIFICATION_REPOSITORY},
+    };
+
+    #[tokio::test]
+    async fn applies_the_due_memberships_and_notifies_the_user() {
+        test_utils::init_canister_system();
+
+        let contractors = add_group("contractors");
+        let auditors = add_group("auditors");
+
+        let now = next_time();
+        let mut user = mock_user();
+        user.groups = vec![contractors.id];
+        user.scheduled_memberships = vec![
+            ScheduledGroupMembership {
+                group_id: contractors.id,
+                starts_at: None,
+                expires_at: Some(now.saturating_sub(1)),
+            },
+            ScheduledGroupMembership {
+                group_id: auditors.id,
+                starts_at: Some(now.saturating_sub(1)),
+                expires_at: Some(now + 60 * 1_000_000_000),
+            },
+        ];
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        Job::default().apply_group_memberships().await;
+
+        let user = USER_REPOSITORY.get(&user.to_key()).unwrap();
+        assert_eq!(user.groups, vec![auditors.id]);
+        assert_eq!(user.scheduled_memberships.len(), 1);
+        assert_eq!(
+            user.next_membership_change_at(),
+            Some(now + 60 * 1_000_000_000)
+        );
+
+        let notifications = NOTIFICATION_REPOSITORY.find_by_user_where(
+            user.id,
+            NotificationFindByUserWhereClause {
+                created_dt_from: None,
+                created_dt_to: None,
+                notification_type: None,
+                status: None,
+                sort_by: None,
+            },
+        );
+        assert_eq!(notifications.len(), 2);
+    }
+}
@@ -0,0 +1,255 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::{
+        ic_cdk::{api::id as station_canister_self_id, next_time},
+        read_system_info,
+    },
+    log_warn,
+    models::{ArchivedRequest, Request, RequestStatusCode},
+    repositories::{
+        ARCHIVED_REQUEST_REPOSITORY, REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+    },
+    services::{RequestService, REQUEST_SERVICE},
+};
+use async_trait::async_trait;
+use candid::Principal;
+use orbit_essentials::{repository::Repository, types::Timestamp};
+use station_api::{ArchiveRequestsInput, ArchivedRequestDTO};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    request_service: Arc<RequestService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            request_service: Arc::clone(&REQUEST_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ArchiveRequests;
+
+    async fn run() -> bool {
+        Self::default().archive_requests().await
+    }
+}
+
+/// This job is responsible for moving the requests that were finalized long enough ago to the archive
+/// canister, so that the stable memory of the station stays bounded for long-running deployments.
+///
+/// The station keeps a stub of each archived request, which is used to authorize the access to it and to
+/// fetch it from the archive canister. Failed requests are not archived since their execution can be resumed.
+///
+/// The archive canister is not part of the station: it is deployed separately and configured with
+/// `RequestArchivalConfig::Enabled`. The job is only scheduled once one is configured, and it does nothing
+/// and stops if the archival is disabled, so the requests are kept by the station by default.
+impl Job {
+    /// The interval between the archivals, which is 1 day.
+    pub const ARCHIVAL_INTERVAL_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+    /// Kept low since the requests are sent with their operation, which can be bulky if not compacted.
+    pub const MAX_REQUESTS_PER_RUN: usize = 20;
+    const FINAL_STATUSES: [RequestStatusCode; 3] = [
+        RequestStatusCode::Completed,
+        RequestStatusCode::Rejected,
+        RequestStatusCode::Cancelled,
+    ];
+
+    async fn archive_requests(&self) -> bool {
+        let config = read_system_info().get_request_archival().clone();
+        let Some((archive_canister_id, cutoff)) = config.archival_cutoff(next_time()) else {
+            return true;
+        };
+
+        let mut requests = Self::find_archivable_requests(cutoff);
+        let has_more = requests.len() > Self::MAX_REQUESTS_PER_RUN;
+        requests.truncate(Self::MAX_REQUESTS_PER_RUN);
+
+        if requests.is_empty() {
+            Self::schedule_next_archival();
+
+            return true;
+        }
+
+        let archived = match self.to_archived_requests(&requests) {
+            Ok(archived) => archived,
+            Err(error) => {
+                log_warn!("Failed to prepare the requests for the archival: {}", error);
+                Self::schedule_next_archival();
+
+                return true;
+            }
+        };
+
+        if let Err(error) = Self::send_to_archive(archive_canister_id, archived).await {
+            log_warn!(
+                "Failed to send the requests to the archive canister: {}",
+                error
+            );
+
+            // the requests are kept by the station until the archive canister accepts them
+            Self::schedule_next_archival();
+
+            return true;
+        }
+
+        Self::replace_with_stubs(requests, archive_canister_id, next_time());
+
+        if !has_more {
+            Self::schedule_next_archival();
+        }
+
+        !has_more
+    }
+
+    fn find_archivable_requests(cutoff: Timestamp) -> Vec<Request> {
+        Self::FINAL_STATUSES
+            .iter()
+            .flat_map(|status| {
+                REQUEST_REPOSITORY.find_by_status(status.clone(), None, Some(cutoff))
+            })
+            .collect()
+    }
+
+    /// Removes the requests that the archive canister accepted, only their stubs are kept.
+    fn replace_with_stubs(
+        requests: Vec<Request>,
+        archive_canister_id: Principal,
+        archived_at: Timestamp,
+    ) {
+        for request in requests {
+            REQUEST_EVALUATION_RESULT_REPOSITORY.remove(&request.id);
+            ARCHIVED_REQUEST_REPOSITORY.insert(
+                request.id,
+                ArchivedRequest {
+                    fields: request.index_fields(),
+                    archive_canister_id,
+                    archived_at,
+                },
+            );
+
+            REQUEST_REPOSITORY.remove(&request.to_key());
+        }
+    }
+
+    fn to_archived_requests(
+        &self,
+        requests: &[Request],
+    ) -> Result<Vec<ArchivedRequestDTO>, String> {
+        requests
+            .iter()
+            .map(|request| {
+                let additional_info = self
+                    .request_service
+                    .get_request_additional_info(request, true)
+                    .map_err(|error| error.to_string())?;
+
+                Ok(ArchivedRequestDTO {
+                    request: request.clone().to_dto(),
+                    additional_info: additional_info.into(),
+                })
+            })
+            .collect()
+    }
+
+    fn schedule_next_archival() {
+        let now = next_time();
+        if !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now) {
+            schedule_request_archival(now + Self::ARCHIVAL_INTERVAL_NS);
+        }
+    }
+
+    async fn send_to_archive(
+        archive_canister_id: Principal,
+        requests: Vec<ArchivedRequestDTO>,
+    ) -> Result<(), String> {
+        ic_cdk::call::<_, ()>(
+            archive_canister_id,
+            "archive_requests",
+            (ArchiveRequestsInput {
+                station_id: station_canister_self_id(),
+                requests,
+            },),
+        )
+        .await
+        .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))
+    }
+}
+
+pub fn schedule_request_archival(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{request_test_utils::mock_request, RequestStatus},
+    };
+
+    fn mock_finalized_request(status: RequestStatus, finalized_at: Timestamp) -> Request {
+        let mut request = mock_request();
+        request.status = status;
+        request.last_modification_timestamp = finalized_at;
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        request
+    }
+
+    #[tokio::test]
+    async fn does_nothing_without_an_archive_canister() {
+        test_utils::init_canister_system();
+        let old = mock_finalized_request(RequestStatus::Cancelled { reason: None }, 0);
+
+        assert!(Job::default().archive_requests().await);
+
+        assert!(REQUEST_REPOSITORY.get(&old.to_key()).is_some());
+        assert!(ARCHIVED_REQUEST_REPOSITORY.get(&old.id).is_none());
+        assert!(!JobStateDatabase::has_scheduled_task_after(
+            Job::JOB_TYPE,
+            0
+        ));
+    }
+
+    #[test]
+    fn replaces_the_old_finalized_requests_with_stubs() {
+        test_utils::init_canister_system();
+        let archive_canister_id = Principal::from_slice(&[7; 29]);
+
+        let old = mock_finalized_request(RequestStatus::Cancelled { reason: None }, 0);
+        let failed = mock_finalized_request(RequestStatus::Failed { reason: None }, 0);
+        let recent = mock_finalized_request(RequestStatus::Cancelled { reason: None }, next_time());
+
+        let archivable = Job::find_archivable_requests(1);
+        assert_eq!(
+            archivable
+                .iter()
+                .map(|request| request.id)
+                .collect::<Vec<_>>(),
+            vec![old.id]
+        );
+
+        Job::replace_with_stubs(archivable, archive_canister_id, 5);
+
+        assert!(REQUEST_REPOSITORY.get(&old.to_key()).is_none());
+        assert_eq!(
+            ARCHIVED_REQUEST_REPOSITORY.get(&old.id),
+            Some(ArchivedRequest {
+                fields: old.index_fields(),
+                archive_canister_id,
+                archived_at: 5,
+            })
+        );
+
+        for request in [failed, recent] {
+            assert!(REQUEST_REPOSITORY.get(&request.to_key()).is_some());
+            assert!(ARCHIVED_REQUEST_REPOSITORY.get(&request.id).is_none());
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::{
+        ic_cdk::{api::id as station_canister_self_id, next_time},
+        read_system_info,
+    },
+    log_warn,
+    models::{PrunedPayload, Request, RequestStatusCode},
+    repositories::REQUEST_REPOSITORY,
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Principal};
+use orbit_essentials::{repository::Repository, types::Timestamp};
+use uuid::Uuid;
+
+/// The payloads that are sent to the `archive_request_payloads` method of the export canister.
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct ArchiveRequestPayloadsArgs {
+    station_id: Principal,
+    requests: Vec<ArchivedRequestPayloads>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct ArchivedRequestPayloads {
+    request_id: String,
+    operation: String,
+    payloads: Vec<ArchivedPayload>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct ArchivedPayload {
+    name: String,
+    #[serde(with = "serde_bytes")]
+    content: Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+pub struct Job;
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::CompactRequests;
+
+    async fn run() -> bool {
+        Self::compact_requests().await
+    }
+}
+
+/// This job is responsible for pruning the bulky payloads of the requests that were finalized long enough ago,
+/// so that the stable memory does not grow without bounds.
+///
+/// Failed requests are not compacted since their execution can be resumed.
+impl Job {
+    /// The interval between the compactions, which is 1 day.
+    pub const COMPACTION_INTERVAL_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+    pub const MAX_REQUESTS_PER_RUN: usize = 100;
+    const FINAL_STATUSES: [RequestStatusCode; 3] = [
+        RequestStatusCode::Completed,
+        RequestStatusCode::Rejected,
+        RequestStatusCode::Cancelled,
+    ];
+
+    async fn compact_requests() -> bool {
+        let config = read_system_info().get_data_compaction().clone();
+        let Some(cutoff) = config.compaction_cutoff(next_time()) else {
+            return true;
+        };
+
+        let mut requests = Self::find_compactable_requests(cutoff, Self::MAX_REQUESTS_PER_RUN + 1);
+        let has_more = requests.len() > Self::MAX_REQUESTS_PER_RUN;
+        requests.truncate(Self::MAX_REQUESTS_PER_RUN);
+
+        let (already_compacted, requests): (Vec<_>, Vec<_>) = requests
+            .into_iter()
+            .partition(|request| request.compacted_at.is_some());
+
+        // the index entries that were written before the compaction marker existed are refreshed
+        for request in already_compacted {
+            REQUEST_REPOSITORY.insert(request.to_key(), request);
+        }
+
+        let compacted = requests
+            .into_iter()
+            .map(|mut request| {
+                let payloads = request.operation.prune_payloads();
+
+                (request, payloads)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(export_canister_id) = config.export_canister_id() {
+            if let Err(error) = Self::export_payloads(export_canister_id, &compacted).await {
+                log_warn!(
+                    "Failed to export the payloads of the compacted requests: {}",
+                    error
+                );
+
+                // the requests are kept intact until the export canister accepts their payloads
+                Self::schedule_next_compaction();
+
+                return true;
+            }
+        }
+
+        let now = next_time();
+        for (mut request, _) in compacted {
+            request.compacted_at = Some(now);
+
+            REQUEST_REPOSITORY.insert(request.to_key(), request);
+        }
+
+        if !has_more {
+            Self::schedule_next_compaction();
+        }
+
+        !has_more
+    }
+
+    /// Finds up to `limit` finalized requests that are not compacted yet, the statuses are only scanned until
+    /// the limit is reached.
+    fn find_compactable_requests(cutoff: Timestamp, limit: usize) -> Vec<Request> {
+        Self::FINAL_STATUSES
+            .iter()
+            .flat_map(|status| REQUEST_REPOSITORY.find_compactable(status.clone(), cutoff, limit))
+            .take(limit)
+            .collect()
+    }
+
+    fn schedule_next_compaction() {
+        let now = next_time();
+        if !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now) {
+            schedule_request_compaction(now + Self::COMPACTION_INTERVAL_NS);
+        }
+    }
+
+    async fn export_payloads(
+        export_canister_id: Principal,
+        compacted: &[(Request, Vec<PrunedPayload>)],
+    ) -> Result<(), String> {
+        let requests = compacted
+            .iter()
+            .filter(|(_, payloads)| !payloads.is_empty())
+            .map(|(request, payloads)| ArchivedRequestPayloads {
+                request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+                operation: request.operation.to_string(),
+                payloads: payloads
+                    .iter()
+                    .map(|payload| ArchivedPayload {
+                        name: payload.name.to_string(),
+                        content: payload.content.clone(),
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        ic_cdk::call::<_, ()>(
+            export_canister_id,
+            "archive_request_payloads",
+            (ArchiveRequestPayloadsArgs {
+                station_id: station_canister_self_id(),
+                requests,
+            },),
+        )
+        .await
+        .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))
+    }
+}
+
+pub fn schedule_request_compaction(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{test_utils, write_system_info},
+        models::{
+            request_test_utils::mock_request, CallExternalCanisterOperation,
+            CallExternalCanisterOperationInput, CanisterMethod, DataCompactionConfig,
+            RequestOperation, RequestStatus,
+        },
+    };
+
+    fn mock_call_request(status: RequestStatus, finalized_at: Timestamp) -> Request {
+        let mut request = mock_request();
+        request.status = status;
+        request.last_modification_timestamp = finalized_at;
+        request.operation = RequestOperation::CallExternalCanister(CallExternalCanisterOperation {
+            input: CallExternalCanisterOperationInput {
+                validation_method: None,
+                execution_method: CanisterMethod {
+                    canister_id: Principal::from_slice(&[1; 29]),
+                    method_name: "greet".to_string(),
+                },
+                arg: Some(vec![1; 1_000]),
+                execution_method_cycles: None,
+            },
+            arg_checksum: Some(vec![2; 32]),
+            arg_rendering: None,
+            execution_method_reply: Some(vec![3; 1_000]),
+        });
+
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        request
+    }
+
+    #[tokio::test]
+    async fn prunes_the_payloads_of_old_finalized_requests() {
+        test_utils::init_canister_system();
+        let mut system_info = read_system_info();
+        system_info.set_data_compaction(DataCompactionConfig::Enabled {
+            min_age_days: 1,
+            export_canister_id: None,
+        });
+        write_system_info(system_info);
+
+        let old = mock_call_request(RequestStatus::Rejected, 0);
+        let failed = mock_call_request(RequestStatus::Failed { reason: None }, 0);
+        let recent = mock_call_request(RequestStatus::Rejected, next_time());
+
+        assert!(Job::compact_requests().await);
+
+        let old = REQUEST_REPOSITORY.get(&old.to_key()).unwrap();
+        assert!(old.compacted_at.is_some());
+        let RequestOperation::CallExternalCanister(operation) = old.operation else {
+            panic!("Expected CallExternalCanister operation");
+        };
+        assert_eq!(operation.input.arg, None);
+        assert_eq!(operation.execution_method_reply, None);
+        assert_eq!(operation.arg_checksum, Some(vec![2; 32]));
+
+        for request in [failed, recent] {
+            let stored = REQUEST_REPOSITORY.get(&request.to_key()).unwrap();
+            assert!(stored.compacted_at.is_none());
+            assert_eq!(stored.operation, request.operation);
+        }
+    }
+
+    #[test]
+    fn compacted_requests_are_skipped_by_the_index() {
+        let mut compacted = mock_call_request(RequestStatus::Rejected, 0);
+        compacted.compacted_at = Some(1);
+        REQUEST_REPOSITORY.insert(compacted.to_key(), compacted);
+        let pending = mock_call_request(RequestStatus::Rejected, 0);
+
+        let found = Job::find_compactable_requests(1, 10);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, pending.id);
+    }
+
+    #[test]
+    fn stops_scanning_at_the_limit() {
+        for _ in 0..5 {
+            mock_call_request(RequestStatus::Completed, 0);
+            mock_call_request(RequestStatus::Cancelled, 0);
+        }
+
+        assert_eq!(Job::find_compactable_requests(1, 3).len(), 3);
+        assert_eq!(Job::find_compactable_requests(1, 7).len(), 7);
+    }
+}
@@ -0,0 +1,209 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::{api::id as station_canister_self_id, next_time},
+    models::{Notification, NotificationBridge, User},
+    repositories::{
+        NotificationBridgeRepository, NotificationOutboxRepository, NOTIFICATION_REPOSITORY,
+        USER_REPOSITORY,
+    },
+};
+use async_trait::async_trait;
+use futures::future;
+use orbit_essentials::{
+    api::ApiResult, model::ModelKey, repository::Repository, types::Timestamp,
+    utils::timestamp_to_rfc3339,
+};
+use station_api::StationNotificationDeliveryDTO;
+use uuid::Uuid;
+
+#[derive(Debug, Default)]
+pub struct Job {
+    bridge_repository: NotificationBridgeRepository,
+    outbox_repository: NotificationOutboxRepository,
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::DeliverBridgedNotifications;
+
+    async fn run() -> bool {
+        Self::default().deliver_notifications().await
+    }
+}
+
+/// The outcome of delivering the pending notifications of a user.
+enum DeliveryOutcome {
+    /// All the pending notifications were delivered or dropped.
+    Completed,
+    /// There are more notifications that are ready to be delivered.
+    MorePending,
+    /// The next notification can only be delivered at the given time.
+    RetryAt(Timestamp),
+}
+
+/// The reasons why a notification could not be delivered.
+enum DeliveryError {
+    /// The inbox refused the notification, e.g. because the station is not one of the stations of the
+    /// user, so it is not retried.
+    Refused(String),
+    /// The call to the inbox failed and is retried.
+    Failed(String),
+}
+
+/// This job is responsible for pushing the notifications in the outbox to the inbox canisters of the
+/// users that opted in.
+///
+/// The notifications of a user are delivered in order, failed deliveries block the following
+/// notifications of the same user until they succeed or run out of attempts.
+impl Job {
+    pub const MAX_NOTIFICATIONS_PER_USER: usize = 10;
+
+    async fn deliver_notifications(&self) -> bool {
+        let now = next_time();
+        let outcomes = future::join_all(
+            self.bridge_repository
+                .list()
+                .into_iter()
+                .map(|bridge| self.deliver_user_notifications(bridge, now)),
+        )
+        .await;
+
+        let mut is_completed = true;
+        let mut next_attempt_at: Option<Timestamp> = None;
+        for outcome in outcomes {
+            match outcome {
+                DeliveryOutcome::Completed => {}
+                DeliveryOutcome::MorePending => is_completed = false,
+                DeliveryOutcome::RetryAt(retry_at) => {
+                    next_attempt_at = Some(next_attempt_at.map_or(retry_at, |at| at.min(retry_at)));
+                }
+            }
+        }
+
+        if let Some(next_attempt_at) = next_attempt_at {
+            schedule_bridged_notification_delivery(next_attempt_at);
+        }
+
+        is_completed
+    }
+
+    async fn deliver_user_notifications(
+        &self,
+        bridge: NotificationBridge,
+        now: Timestamp,
+    ) -> DeliveryOutcome {
+        // the notifications are only pushed for users that are still active
+        let is_active = USER_REPOSITORY
+            .get(&User::key(bridge.user_id))
+            .is_some_and(|user| user.is_active());
+        if !is_active {
+            self.outbox_repository.remove_by_user(bridge.user_id);
+
+            return DeliveryOutcome::Completed;
+        }
+
+        let entries = self
+            .outbox_repository
+            .find_by_user(bridge.user_id, Self::MAX_NOTIFICATIONS_PER_USER + 1);
+        let has_more_entries = entries.len() > Self::MAX_NOTIFICATIONS_PER_USER;
+
+        for mut entry in entries.into_iter().take(Self::MAX_NOTIFICATIONS_PER_USER) {
+            if entry.next_attempt_at > now {
+                return DeliveryOutcome::RetryAt(entry.next_attempt_at);
+            }
+
+            // the notification could have been removed since it was enqueued
+            let Some(notification) =
+                NOTIFICATION_REPOSITORY.get(&Notification::key(entry.notification_id))
+            else {
+                self.outbox_repository.remove(&entry.key());
+                continue;
+            };
+
+            let result = Self::send_notification(&bridge, &notification).await;
+
+            // the user could have opted out while the call was in flight
+            let Some(mut current_bridge) = self.bridge_repository.get(&bridge.key()) else {
+                return DeliveryOutcome::Completed;
+            };
+
+            match result {
+                Ok(()) => {
+                    self.outbox_repository.remove(&entry.key());
+
+                    current_bridge.delivered_notifications += 1;
+                    current_bridge.last_delivered_at = Some(next_time());
+                    current_bridge.last_error = None;
+                    self.bridge_repository
+                        .insert(current_bridge.key(), current_bridge);
+                }
+                Err(DeliveryError::Refused(error)) => {
+                    self.outbox_repository.remove(&entry.key());
+
+                    current_bridge.last_error = Some(error);
+                    self.bridge_repository
+                        .insert(current_bridge.key(), current_bridge);
+                }
+                Err(DeliveryError::Failed(error)) => {
+                    current_bridge.last_error = Some(error);
+                    self.bridge_repository
+                        .insert(current_bridge.key(), current_bridge);
+
+                    entry.register_failed_attempt(next_time());
+                    if !entry.has_attempts_left() {
+                        self.outbox_repository.remove(&entry.key());
+                        continue;
+                    }
+
+                    let retry_at = entry.next_attempt_at;
+                    self.outbox_repository.insert(entry.key(), entry);
+
+                    return DeliveryOutcome::RetryAt(retry_at);
+                }
+            }
+        }
+
+        match has_more_entries {
+            true => DeliveryOutcome::MorePending,
+            false => DeliveryOutcome::Completed,
+        }
+    }
+
+    async fn send_notification(
+        bridge: &NotificationBridge,
+        notification: &Notification,
+    ) -> Result<(), DeliveryError> {
+        let delivery = StationNotificationDeliveryDTO {
+            station_id: station_canister_self_id(),
+            identity: bridge.identity,
+            notification_id: Uuid::from_bytes(notification.id).hyphenated().to_string(),
+            notification_type: notification.notification_type.to_string(),
+            title: notification.title.clone(),
+            message: notification.message.clone(),
+            created_at: timestamp_to_rfc3339(&notification.created_timestamp),
+        };
+
+        match ic_cdk::call::<_, (ApiResult<()>,)>(
+            bridge.inbox_canister,
+            NotificationBridge::INBOX_METHOD_NAME,
+            (delivery,),
+        )
+        .await
+        {
+            Ok((Ok(()),)) => Ok(()),
+            Ok((Err(err),)) => Err(DeliveryError::Refused(format!(
+                "code: {}, err: {}",
+                err.code,
+                err.message.unwrap_or_default()
+            ))),
+            Err((code, message)) => Err(DeliveryError::Failed(format!(
+                "rejection_code: {:?}, err: {}",
+                code, message
+            ))),
+        }
+    }
+}
+
+pub fn schedule_bridged_notification_delivery(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -0,0 +1,197 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::{
+        authorization::Authorization,
+        ic_cdk::{api::id as station_canister_self_id, next_time, spawn},
+        CallContext,
+    },
+    models::{
+        resource::{Resource, SystemResourceAction},
+        EventOutboxEntry, EventSubscriber, EventSubscriberId,
+    },
+    repositories::{EventOutboxRepository, EventSubscriberRepository},
+};
+use async_trait::async_trait;
+use orbit_essentials::{
+    model::ModelKey, repository::Repository, types::Timestamp, utils::timestamp_to_rfc3339,
+};
+use station_api::StationEventDeliveryDTO;
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+    /// The subscribers that have a delivery in flight, which are skipped until it completes.
+    static IN_FLIGHT_SUBSCRIBERS: RefCell<HashSet<EventSubscriberId>> = RefCell::new(HashSet::new());
+}
+
+#[derive(Debug, Default)]
+pub struct Job {
+    subscriber_repository: EventSubscriberRepository,
+    outbox_repository: EventOutboxRepository,
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::DeliverStationEvents;
+
+    async fn run() -> bool {
+        Self::default().deliver_events().await
+    }
+}
+
+/// The outcome of delivering the pending events of a subscriber.
+enum DeliveryOutcome {
+    /// All the pending events were acknowledged.
+    Completed,
+    /// There are more events that are ready to be delivered.
+    MorePending,
+    /// The next event can only be delivered at the given time.
+    RetryAt(Timestamp),
+}
+
+/// This job is responsible for delivering the events in the outbox to the subscribed canisters.
+///
+/// The events of a subscriber are delivered in order and are only removed from the outbox once the
+/// subscriber acknowledged them by replying to the call, failed deliveries block the following events
+/// of the same subscriber until they succeed.
+///
+/// Each subscriber is delivered to in its own call context, since the calls to the subscribers wait for
+/// their reply without a deadline, a subscriber that never replies only holds up its own events.
+impl Job {
+    pub const MAX_EVENTS_PER_SUBSCRIBER: usize = 10;
+    const SUBSCRIBER_NOT_ALLOWED_ERROR: &'static str =
+        "The subscriber is not allowed to read the system information, its pending events were dropped.";
+
+    async fn deliver_events(&self) -> bool {
+        let now = next_time();
+        for subscriber in self.subscriber_repository.list() {
+            if !Self::start_delivery(subscriber.id) {
+                continue;
+            }
+
+            spawn(async move {
+                Self::default().deliver_to_subscriber(subscriber, now).await;
+            });
+        }
+
+        true
+    }
+
+    /// Delivers the pending events of the subscriber and schedules the next delivery if some are left.
+    async fn deliver_to_subscriber(&self, subscriber: EventSubscriber, now: Timestamp) {
+        let subscriber_id = subscriber.id;
+        let outcome = self.deliver_subscriber_events(subscriber, now).await;
+
+        Self::finish_delivery(&subscriber_id);
+
+        match outcome {
+            DeliveryOutcome::RetryAt(retry_at) => schedule_station_event_delivery(retry_at),
+            // the events that were added while the delivery was in flight were skipped by the other runs
+            DeliveryOutcome::MorePending | DeliveryOutcome::Completed
+                if !self
+                    .outbox_repository
+                    .find_by_subscriber(subscriber_id, 1)
+                    .is_empty() =>
+            {
+                schedule_station_event_delivery(next_time())
+            }
+            DeliveryOutcome::MorePending | DeliveryOutcome::Completed => {}
+        }
+    }
+
+    /// Marks the delivery to the subscriber as in flight, returns false if it already was.
+    fn start_delivery(subscriber_id: EventSubscriberId) -> bool {
+        IN_FLIGHT_SUBSCRIBERS.with(|in_flight| in_flight.borrow_mut().insert(subscriber_id))
+    }
+
+    fn finish_delivery(subscriber_id: &EventSubscriberId) {
+        IN_FLIGHT_SUBSCRIBERS.with(|in_flight| in_flight.borrow_mut().remove(subscriber_id));
+    }
+
+    async fn deliver_subscriber_events(
+        &self,
+        subscriber: EventSubscriber,
+        now: Timestamp,
+    ) -> DeliveryOutcome {
+        // events are only delivered to canisters that are still allowed to read the station information
+        if !Authorization::is_allowed(
+            &CallContext::new(subscriber.canister_id),
+            &Resource::System(SystemResourceAction::SystemInfo),
+        ) {
+            self.outbox_repository.remove_by_subscriber(subscriber.id);
+
+            let mut subscriber = subscriber;
+            subscriber.last_error = Some(Self::SUBSCRIBER_NOT_ALLOWED_ERROR.to_string());
+            self.subscriber_repository
+                .insert(subscriber.key(), subscriber);
+
+            return DeliveryOutcome::Completed;
+        }
+
+        let entries = self
+            .outbox_repository
+            .find_by_subscriber(subscriber.id, Self::MAX_EVENTS_PER_SUBSCRIBER + 1);
+        let has_more_entries = entries.len() > Self::MAX_EVENTS_PER_SUBSCRIBER;
+
+        for mut entry in entries.into_iter().take(Self::MAX_EVENTS_PER_SUBSCRIBER) {
+            if entry.next_attempt_at > now {
+                return DeliveryOutcome::RetryAt(entry.next_attempt_at);
+            }
+
+            let result = Self::send_event(&subscriber, &entry).await;
+
+            // the subscriber could have unsubscribed while the call was in flight
+            let Some(mut current_subscriber) = self.subscriber_repository.get(&subscriber.key())
+            else {
+                return DeliveryOutcome::Completed;
+            };
+
+            match result {
+                Ok(()) => {
+                    self.outbox_repository.remove(&entry.key());
+
+                    current_subscriber.acknowledged_events += 1;
+                    current_subscriber.last_acknowledged_at = Some(next_time());
+                    current_subscriber.last_error = None;
+                    self.subscriber_repository
+                        .insert(current_subscriber.key(), current_subscriber);
+                }
+                Err(error) => {
+                    entry.register_failed_attempt(next_time());
+                    let retry_at = entry.next_attempt_at;
+                    self.outbox_repository.insert(entry.key(), entry);
+
+                    current_subscriber.last_error = Some(error);
+                    self.subscriber_repository
+                        .insert(current_subscriber.key(), current_subscriber);
+
+                    return DeliveryOutcome::RetryAt(retry_at);
+                }
+            }
+        }
+
+        match has_more_entries {
+            true => DeliveryOutcome::MorePending,
+            false => DeliveryOutcome::Completed,
+        }
+    }
+
+    async fn send_event(
+        subscriber: &EventSubscriber,
+        entry: &EventOutboxEntry,
+    ) -> Result<(), String> {
+        let delivery = StationEventDeliveryDTO {
+            event_id: entry.event_id,
+            station_id: station_canister_self_id(),
+            event: entry.event.clone().into(),
+            created_at: timestamp_to_rfc3339(&entry.created_at),
+        };
+
+        ic_cdk::call::<_, ()>(subscriber.canister_id, &subscriber.method_name, (delivery,))
+            .await
+            .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))
+    }
+}
+
+pub fn schedule_station_event_delivery(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
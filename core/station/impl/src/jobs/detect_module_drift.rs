@@ -0,0 +1,257 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    log_warn,
+    models::{
+        ExternalCanister, ExternalCanisterModuleDriftedNotification, NotificationType,
+        RequestOperation, RequestStatusCode, SnapshotExternalCanisterOperationKind, StationEvent,
+        UserStatus, ADMIN_GROUP_ID,
+    },
+    repositories::{REQUEST_REPOSITORY, USER_REPOSITORY},
+    services::{
+        ExternalCanisterService, NotificationService, StationEventService,
+        EXTERNAL_CANISTER_SERVICE, NOTIFICATION_SERVICE, STATION_EVENT_SERVICE,
+    },
+};
+use async_trait::async_trait;
+use ic_cdk::api::management_canister::main::CanisterIdRecord;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    external_canister_service: Arc<ExternalCanisterService>,
+    notification_service: Arc<NotificationService>,
+    station_event_service: Arc<StationEventService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+            notification_service: Arc::clone(&NOTIFICATION_SERVICE),
+            station_event_service: Arc::clone(&STATION_EVENT_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::DetectModuleDrift;
+
+    async fn run() -> bool {
+        Self::default().detect_module_drift().await;
+
+        true
+    }
+}
+
+/// The outcome of comparing the module hash known by the station with the one installed on the canister.
+#[derive(Debug, PartialEq, Eq)]
+enum ModuleHashCheck {
+    Unchanged,
+    /// The station did not know the module hash yet, the installed one is taken as the reference.
+    Untracked,
+    Drifted,
+}
+
+fn check_module_hash(expected: &Option<Vec<u8>>, found: &Option<Vec<u8>>) -> ModuleHashCheck {
+    match expected {
+        None => ModuleHashCheck::Untracked,
+        Some(_) if expected == found => ModuleHashCheck::Unchanged,
+        Some(_) => ModuleHashCheck::Drifted,
+    }
+}
+
+/// This job is responsible for periodically reading the module hash of the registered external canisters
+/// and alerting the admins when a module was changed without an executed request of the station.
+///
+/// Canisters of which the station is not a controller are skipped, since their status can't be read.
+impl Job {
+    /// The interval between the checks of the module hashes, which is 6 hours.
+    pub const CHECK_INTERVAL_NS: u64 = 6 * 60 * 60 * 1_000_000_000;
+
+    async fn detect_module_drift(&self) {
+        let external_canisters = self.external_canister_service.list_all_external_canisters();
+
+        for external_canister in external_canisters.iter() {
+            let found_module_hash = match self
+                .external_canister_service
+                .canister_status(CanisterIdRecord {
+                    canister_id: external_canister.canister_id,
+                })
+                .await
+            {
+                Ok(status) => status.module_hash,
+                Err(error) => {
+                    log_warn!(
+                        "Failed to read the module hash of external canister {}: {}",
+                        external_canister.canister_id,
+                        error
+                    );
+
+                    continue;
+                }
+            };
+
+            // the canister could have been changed or removed while its status was being read
+            let Ok(external_canister) = self
+                .external_canister_service
+                .get_external_canister(&external_canister.id)
+            else {
+                continue;
+            };
+
+            if Self::has_install_in_progress(&external_canister) {
+                continue;
+            }
+
+            match check_module_hash(&external_canister.module_hash, &found_module_hash) {
+                ModuleHashCheck::Unchanged => {}
+                ModuleHashCheck::Untracked => {
+                    self.record_module_hash(&external_canister, found_module_hash);
+                }
+                ModuleHashCheck::Drifted => {
+                    self.report_drift(&external_canister, found_module_hash.clone())
+                        .await;
+
+                    // the drift is only reported once, the found module becomes the new reference
+                    self.record_module_hash(&external_canister, found_module_hash);
+                }
+            }
+        }
+
+        let now = next_time();
+        if !external_canisters.is_empty()
+            && !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now)
+        {
+            schedule_module_drift_detection(now + Self::CHECK_INTERVAL_NS);
+        }
+    }
+
+    /// Checks if a request that installs a module on the canister is being executed.
+    fn has_install_in_progress(external_canister: &ExternalCanister) -> bool {
+        REQUEST_REPOSITORY
+            .find_by_status(RequestStatusCode::Processing, None, None)
+            .iter()
+            .any(|request| match &request.operation {
+                RequestOperation::ChangeExternalCanister(operation) => {
+                    operation.input.canister_id == external_canister.canister_id
+                }
+                RequestOperation::SnapshotExternalCanister(operation) => {
+                    operation.input.canister_id == external_canister.canister_id
+                        && matches!(
+                            operation.input.kind,
+                            SnapshotExternalCanisterOperationKind::Load { .. }
+                        )
+                }
+                _ => false,
+            })
+    }
+
+    fn record_module_hash(
+        &self,
+        external_canister: &ExternalCanister,
+        module_hash: Option<Vec<u8>>,
+    ) {
+        if let Err(error) = self
+            .external_canister_service
+            .record_module_hash(&external_canister.canister_id, module_hash)
+        {
+            log_warn!(
+                "Failed to record the module hash of external canister {}: {}",
+                external_canister.canister_id,
+                error
+            );
+        }
+    }
+
+    async fn report_drift(
+        &self,
+        external_canister: &ExternalCanister,
+        found_module_hash: Option<Vec<u8>>,
+    ) {
+        log_warn!(
+            "Detected a module change of external canister {} that was not done through the station",
+            external_canister.canister_id
+        );
+
+        self.station_event_service
+            .publish(StationEvent::ExternalCanisterModuleDrifted {
+                external_canister_id: external_canister.id,
+                canister_id: external_canister.canister_id,
+                expected_module_hash: external_canister.module_hash.clone(),
+                found_module_hash: found_module_hash.clone(),
+                detected_at: next_time(),
+            });
+
+        let title = format!(
+            "The module of {} was changed outside of the station",
+            external_canister.name
+        );
+        let message = format!(
+            "The module hash of canister {} is {} instead of {}.",
+            external_canister.canister_id,
+            found_module_hash
+                .as_ref()
+                .map(hex::encode)
+                .unwrap_or_else(|| "empty".to_string()),
+            external_canister
+                .module_hash
+                .as_ref()
+                .map(hex::encode)
+                .unwrap_or_default()
+        );
+
+        for admin in USER_REPOSITORY.find_by_group_and_status(ADMIN_GROUP_ID, &UserStatus::Active) {
+            self.notification_service
+                .send_notification(
+                    admin.id,
+                    NotificationType::ExternalCanisterModuleDrifted(
+                        ExternalCanisterModuleDriftedNotification {
+                            external_canister_id: external_canister.id,
+                            canister_id: external_canister.canister_id,
+                            expected_module_hash: external_canister.module_hash.clone(),
+                            found_module_hash: found_module_hash.clone(),
+                        },
+                    ),
+                    title.clone(),
+                    Some(message.clone()),
+                )
+                .await;
+        }
+    }
+}
+
+pub fn schedule_module_drift_detection(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untracked_module_hash_is_not_a_drift() {
+        assert_eq!(
+            check_module_hash(&None, &Some(vec![1; 32])),
+            ModuleHashCheck::Untracked
+        );
+        assert_eq!(check_module_hash(&None, &None), ModuleHashCheck::Untracked);
+    }
+
+    #[test]
+    fn changed_module_hash_is_a_drift() {
+        assert_eq!(
+            check_module_hash(&Some(vec![1; 32]), &Some(vec![1; 32])),
+            ModuleHashCheck::Unchanged
+        );
+        assert_eq!(
+            check_module_hash(&Some(vec![1; 32]), &Some(vec![2; 32])),
+            ModuleHashCheck::Drifted
+        );
+        assert_eq!(
+            check_module_hash(&Some(vec![1; 32]), &None),
+            ModuleHashCheck::Drifted
+        );
+    }
+}
@@ -1,11 +1,14 @@
 use super::{scheduler::Scheduler, JobType, ScheduledJob};
 use crate::{
-    core::ic_cdk::{api::print, next_time},
-    errors::TransferError,
+    core::{generate_uuid_v4, ic_cdk::next_time, read_system_info, tracing::TraceContext},
+    errors::{TransferError, BLOCKCHAIN_NETWORK_ERROR_CODE},
     factories::blockchains::{
         BlockchainApiFactory, BlockchainTransactionSubmitted,
+        TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+        TRANSACTION_SUBMITTED_DETAILS_RECONCILED_KEY,
         TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
     },
+    log_error, log_warn,
     models::{
         Account, Request, RequestOperation, RequestStatus, Transfer, TransferId, TransferStatus,
     },
@@ -40,11 +43,21 @@ impl ScheduledJob for Job {
 /// are ready to be submitted to the blockchain.
 impl Job {
     pub const MAX_BATCH_SIZE: usize = 20;
+    pub const RESUBMISSION_DELAY_NS: u64 = 10 * 1_000_000_000;
+    /// The interval between the checks of the transfers whose accounts are frozen, which is 1 minute.
+    pub const FROZEN_BLOCKCHAIN_CHECK_INTERVAL_NS: u64 = 60 * 1_000_000_000;
 
     /// Executes all the transfers that have been created but are not yet submitted to the blockchain.
     ///
     /// This function will process a maximum of `MAX_BATCH_SIZE` transfers at once.
     async fn execute_created_transfers(&self) -> bool {
+        let system_info = read_system_info();
+
+        // a decommissioned station no longer submits transfers to the blockchains
+        if system_info.is_decommissioned() {
+            return true;
+        }
+
         let current_time = next_time();
         let mut transfers = self.transfer_repository.find_by_status(
             TransferStatus::Created.to_string(),
@@ -52,17 +65,42 @@ impl Job {
             Some(current_time),
         );
 
+        // the transfers from frozen accounts stay created until the accounts are unfrozen
+        let transfers_count = transfers.len();
+        transfers.retain(|transfer| {
+            self.account_repository
+                .get(&Account::key(transfer.from_account))
+                .map_or(true, |account| {
+                    system_info.find_account_freeze(&account).is_none()
+                })
+        });
+
+        if transfers.len() < transfers_count {
+            schedule_process_transfers(current_time + Self::FROZEN_BLOCKCHAIN_CHECK_INTERVAL_NS);
+        }
+
         let processing_all_transfers = transfers.len() <= Self::MAX_BATCH_SIZE;
 
         // truncate the list to avoid processing too many transfers at once
         transfers.truncate(Self::MAX_BATCH_SIZE);
 
+        if transfers.is_empty() {
+            return processing_all_transfers;
+        }
+
+        // each tick that executes transfers is traced under its own correlation id
+        let trace = TraceContext::ingress(
+            *generate_uuid_v4().await.as_bytes(),
+            "execute_created_transfers",
+        );
+
         // update the status of the requests to avoid processing them again
         for transfer in transfers.iter_mut() {
             let transfer_processing_time = next_time();
             transfer.status = TransferStatus::Processing {
                 started_at: transfer_processing_time,
             };
+            transfer.register_submission_attempt(transfer_processing_time);
             transfer.last_modification_timestamp = transfer_processing_time;
             self.transfer_repository
                 .insert(transfer.to_key(), transfer.to_owned());
@@ -80,10 +118,11 @@ impl Job {
                 }
                 None => {
                     // if the request is not found, mark the transfer as failed
-                    print(format!(
-                        "Error: request not found for transfer {}",
+                    log_error!(
+                        trace = trace;
+                        "request not found for transfer {}",
                         Uuid::from_bytes(transfer.id).hyphenated()
-                    ));
+                    );
 
                     let mut transfer = transfer.clone();
                     transfer.status = TransferStatus::Failed {
@@ -101,7 +140,7 @@ impl Job {
             .clone()
             .into_iter()
             .filter(|transfer| requests.contains_key(&transfer.id))
-            .map(|transfer| self.execute_transfer(transfer));
+            .map(|transfer| self.execute_transfer(transfer, trace));
 
         // wait for all the transfers to be executed
         let results = future::join_all(calls).await;
@@ -118,6 +157,23 @@ impl Job {
                         .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY)
                         .map(|(_, value)| value.to_owned());
 
+                    // the transfer was already accepted in a previous submission, so the block height
+                    // of that submission is kept with the transfer
+                    if details
+                        .details
+                        .iter()
+                        .any(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_RECONCILED_KEY)
+                    {
+                        if let Some(block_height) = details
+                            .details
+                            .iter()
+                            .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY)
+                            .and_then(|(_, value)| value.parse::<u64>().ok())
+                        {
+                            transfer.mark_reconciled(block_height);
+                        }
+                    }
+
                     transfer.status = TransferStatus::Completed {
                         completed_at: transfer_completed_time,
                         hash: maybe_transaction_hash,
@@ -143,15 +199,44 @@ impl Job {
                         request.last_modification_timestamp = transfer_completed_time;
                         self.request_repository
                             .insert(request.to_key(), request.to_owned());
+
+                        self.request_service.completed_request_hook(&request).await;
                     } else {
-                        print(format!(
-                            "Error: request not found for transfer {}",
+                        log_error!(
+                            trace = trace;
+                            "request not found for transfer {}",
                             Uuid::from_bytes(transfer.id).hyphenated()
-                        ));
+                        );
                     }
                 }
                 Err(e) => {
                     let mut transfer = transfers[pos].clone();
+
+                    // the blockchain might have accepted the transfer, so it is submitted again which
+                    // checks for the previous submission before sending a new transaction
+                    if matches!(e, TransferError::SubmissionOutcomeUnknown { .. })
+                        && transfer.can_resubmit(next_time())
+                    {
+                        log_warn!(
+                            trace = trace;
+                            "resubmitting transfer {} after an unknown outcome: {}",
+                            Uuid::from_bytes(transfer.id).hyphenated(),
+                            e
+                        );
+
+                        let transfer_requeued_time = next_time();
+                        transfer.status = TransferStatus::Created;
+                        transfer.last_modification_timestamp = transfer_requeued_time;
+                        self.transfer_repository
+                            .insert(transfer.to_key(), transfer.to_owned());
+
+                        schedule_process_transfers(
+                            transfer_requeued_time + Self::RESUBMISSION_DELAY_NS,
+                        );
+
+                        continue;
+                    }
+
                     transfer.status = TransferStatus::Failed {
                         reason: e.to_string(),
                     };
@@ -166,15 +251,18 @@ impl Job {
                             .fail_request(request, e.to_string(), transfer_failed_time)
                             .await;
                     } else {
-                        print(format!(
-                            "Error: request not found for transfer {}",
+                        log_error!(
+                            trace = trace;
+                            "request not found for transfer {}",
                             Uuid::from_bytes(transfer.id).hyphenated()
-                        ));
+                        );
                     }
                 }
             }
         }
 
+        trace.finish();
+
         processing_all_transfers
     }
 
@@ -184,8 +272,27 @@ impl Job {
     async fn execute_transfer(
         &self,
         transfer: Transfer,
+        trace: TraceContext,
+    ) -> Result<(Transfer, BlockchainTransactionSubmitted), TransferError> {
+        let trace = trace.child(
+            "execute_transfer",
+            Some(format!(
+                "transfer {} of request {}",
+                Uuid::from_bytes(transfer.id).hyphenated(),
+                Uuid::from_bytes(transfer.request_id).hyphenated()
+            )),
+        );
+
+        trace.record(self.submit_transfer(transfer, trace).await)
+    }
+
+    /// Submits the transfer to the blockchain of its account.
+    async fn submit_transfer(
+        &self,
+        transfer: Transfer,
+        trace: TraceContext,
     ) -> Result<(Transfer, BlockchainTransactionSubmitted), TransferError> {
-        let account = self
+        let mut account = self
             .account_repository
             .get(&Account::key(transfer.from_account))
             .ok_or(TransferError::ValidationError {
@@ -195,14 +302,37 @@ impl Job {
                 ),
             })?;
 
+        // the grace period of a retired address is checked against the creation of the transfer, so that
+        // resubmissions are made from the same address
+        if let Some(from_address) = &transfer.from_address {
+            account = account
+                .spending_from(from_address, transfer.created_timestamp)
+                .ok_or(TransferError::ValidationError {
+                    info: format!("The grace period of the address {} has ended", from_address),
+                })?;
+        }
+
         let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)
             .map_err(|e| TransferError::ExecutionError {
                 reason: format!("Failed to build blockchain api: {}", e),
             })?;
 
-        match blockchain_api.submit_transaction(&account, &transfer).await {
+        let submission = trace
+            .child(
+                "submit_transaction",
+                Some(format!("{} {}", account.blockchain, account.standard)),
+            )
+            .record(blockchain_api.submit_transaction(&account, &transfer).await);
+
+        match submission {
             Ok(details) => Ok((transfer, details)),
 
+            Err(error) if error.code == BLOCKCHAIN_NETWORK_ERROR_CODE => {
+                Err(TransferError::SubmissionOutcomeUnknown {
+                    reason: error.to_json_string(),
+                })?
+            }
+
             Err(error) => Err(TransferError::ExecutionError {
                 reason: error.to_json_string(),
             })?,
@@ -1,15 +1,23 @@
 use super::{scheduler::Scheduler, JobType, ScheduledJob};
 use crate::{
-    core::ic_cdk::next_time,
+    core::{
+        cost::{metered, record_request_cost},
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        read_system_info,
+        tracing::TraceContext,
+    },
     errors::RequestExecuteError,
     factories::requests::{RequestExecuteStage, RequestFactory},
-    models::{Request, RequestStatus},
+    log_warn,
+    models::{Request, RequestCostPhase, RequestDependencyState, RequestStatus},
     repositories::RequestRepository,
     services::RequestService,
 };
 use async_trait::async_trait;
 use futures::future;
 use orbit_essentials::repository::Repository;
+use uuid::Uuid;
 
 #[derive(Debug, Default)]
 pub struct Job {
@@ -29,11 +37,20 @@ impl ScheduledJob for Job {
 /// are ready to be executed.
 impl Job {
     pub const MAX_BATCH_SIZE: usize = 20;
+    /// The interval between the checks of the dependencies of a request that is waiting for them, which is 1 minute.
+    pub const DEPENDENCY_CHECK_INTERVAL_NS: u64 = 60 * 1_000_000_000;
+    /// The interval between the checks of a request whose accounts are frozen, which is 1 minute.
+    pub const FROZEN_BLOCKCHAIN_CHECK_INTERVAL_NS: u64 = 60 * 1_000_000_000;
 
     /// Processes all the requests that have been approved but are not yet executed.
     ///
     /// This function will process a maximum of `MAX_BATCH_SIZE` requests at once.
     async fn execute_scheduled_requests(&self) -> bool {
+        // a decommissioned station no longer executes the requests that were approved before
+        if read_system_info().is_decommissioned() {
+            return true;
+        }
+
         let current_time = next_time();
         let mut requests = self
             .request_repository
@@ -44,6 +61,18 @@ impl Job {
         // truncate the list to avoid processing too many requests at once
         requests.truncate(Self::MAX_BATCH_SIZE);
 
+        let mut requests = self.select_ready_requests(requests).await;
+
+        if requests.is_empty() {
+            return processing_all_requests;
+        }
+
+        // each tick that executes requests is traced under its own correlation id
+        let trace = TraceContext::ingress(
+            *generate_uuid_v4().await.as_bytes(),
+            "execute_scheduled_requests",
+        );
+
         // update the status of the requests to avoid processing them again
         for request in requests.iter_mut() {
             let request_processing_time = next_time();
@@ -59,7 +88,7 @@ impl Job {
         let calls = requests
             .clone()
             .into_iter()
-            .map(|request| self.execute_request(request));
+            .map(|request| self.execute_request(request, trace));
 
         // wait for all the requests to be executed
         let results = future::join_all(calls).await;
@@ -71,10 +100,25 @@ impl Job {
                 Ok(request) => {
                     self.request_repository
                         .insert(request.to_key(), request.to_owned());
+
+                    if let RequestStatus::Completed { .. } = request.status {
+                        self.request_service.completed_request_hook(request).await;
+                    }
                 }
                 Err(e) => {
+                    log_warn!(
+                        trace = trace;
+                        "execution of request {} failed: {}",
+                        Uuid::from_bytes(requests[pos].id).hyphenated(),
+                        e
+                    );
+
                     let request_failed_time = next_time();
-                    let request = requests[pos].clone();
+                    // reloads the request to keep the execution steps recorded during the execution
+                    let request = self
+                        .request_repository
+                        .get(&requests[pos].to_key())
+                        .unwrap_or_else(|| requests[pos].clone());
                     self.request_service
                         .fail_request(request, e.to_string(), request_failed_time)
                         .await;
@@ -82,19 +126,122 @@ impl Job {
             }
         }
 
+        trace.finish();
+
         processing_all_requests
     }
 
+    /// Returns the requests whose dependencies have completed and whose accounts are not frozen.
+    ///
+    /// The requests that are still waiting for their dependencies or for their accounts to be unfrozen are
+    /// checked again later, and the ones whose dependencies can no longer complete or whose execution window
+    /// closed are failed.
+    async fn select_ready_requests(&self, requests: Vec<Request>) -> Vec<Request> {
+        let system_info = read_system_info();
+        let mut ready_requests = Vec::with_capacity(requests.len());
+        for mut request in requests {
+            let now = next_time();
+            if request
+                .execution_plan
+                .window_end()
+                .is_some_and(|window_end| window_end < now)
+            {
+                self.request_service
+                    .fail_request(
+                        request,
+                        "The execution window closed before the request could be executed"
+                            .to_string(),
+                        now,
+                    )
+                    .await;
+
+                continue;
+            }
+
+            // the requests that target frozen accounts can still be approved, but are only executed once
+            // the accounts are unfrozen
+            if request.find_blockchain_freeze(&system_info).is_some() {
+                let scheduled_at = now + Self::FROZEN_BLOCKCHAIN_CHECK_INTERVAL_NS;
+                request.status = RequestStatus::Scheduled { scheduled_at };
+                request.last_modification_timestamp = now;
+                self.request_repository
+                    .insert(request.to_key(), request.to_owned());
+
+                schedule_request_execution(scheduled_at);
+
+                continue;
+            }
+
+            match request.dependency_state() {
+                RequestDependencyState::Satisfied => ready_requests.push(request),
+                RequestDependencyState::Pending => {
+                    let scheduled_at = now + Self::DEPENDENCY_CHECK_INTERVAL_NS;
+                    request.status = RequestStatus::Scheduled { scheduled_at };
+                    request.last_modification_timestamp = now;
+                    self.request_repository
+                        .insert(request.to_key(), request.to_owned());
+
+                    schedule_request_execution(scheduled_at);
+                }
+                RequestDependencyState::Unsatisfiable { request_id } => {
+                    let reason = format!(
+                        "The dependency {} did not complete successfully",
+                        Uuid::from_bytes(request_id).hyphenated()
+                    );
+
+                    self.request_service
+                        .fail_request(request, reason, next_time())
+                        .await;
+                }
+            }
+        }
+
+        ready_requests
+    }
+
     /// Executes a single request.
     ///
     /// This function will handle the request execution for the given operation type.
-    async fn execute_request(&self, mut request: Request) -> Result<Request, RequestExecuteError> {
+    async fn execute_request(
+        &self,
+        mut request: Request,
+        trace: TraceContext,
+    ) -> Result<Request, RequestExecuteError> {
         let executor = RequestFactory::executor(&request);
+        let span = trace.child(
+            "execute_request",
+            Some(format!(
+                "request {}",
+                Uuid::from_bytes(request.id).hyphenated()
+            )),
+        );
 
-        let execute_state = executor.execute().await?;
+        let (execute_result, execution_instructions) = metered(executor.execute()).await;
 
         drop(executor);
 
+        // keeps the execution steps that were recorded while executing the operation
+        if let Some(stored_request) = self.request_repository.get(&request.to_key()) {
+            request.execution_steps = stored_request.execution_steps;
+        }
+
+        record_request_cost(
+            &mut request,
+            RequestCostPhase::Execution,
+            execution_instructions,
+        );
+
+        let execute_state = match span.record(execute_result) {
+            Ok(execute_state) => execute_state,
+            Err(err) => {
+                // the failed request is reloaded from the repository, so the cost of the failed
+                // execution is stored with it
+                self.request_repository.insert(request.to_key(), request);
+
+                return Err(err);
+            }
+        };
+
         let request_execution_time = next_time();
 
         request.status = match execute_state {
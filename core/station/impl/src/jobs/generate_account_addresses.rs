@@ -0,0 +1,97 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    log_warn,
+    models::AccountSetupState,
+    repositories::ACCOUNT_REPOSITORY,
+    services::{AccountService, ACCOUNT_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::{repository::Repository, types::Timestamp};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct Job {
+    account_service: Arc<AccountService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            account_service: Arc::clone(&ACCOUNT_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::GenerateAccountAddresses;
+
+    async fn run() -> bool {
+        Self::default().generate_account_addresses().await;
+
+        true
+    }
+}
+
+/// This job is responsible for generating the addresses of the accounts that were created before their
+/// address could be generated, e.g. because the signing service was unavailable.
+///
+/// The failed attempts are retried with an increasing delay, the job schedules itself for the next attempt
+/// so that it only runs while there are accounts waiting for their address.
+impl Job {
+    async fn generate_account_addresses(&self) {
+        let now = next_time();
+
+        let due_account_ids = ACCOUNT_REPOSITORY
+            .list()
+            .into_iter()
+            .filter(|account| {
+                matches!(
+                    account.setup_state,
+                    AccountSetupState::PendingAddressGeneration { next_attempt_at, .. }
+                        if next_attempt_at <= now
+                )
+            })
+            .map(|account| account.id)
+            .collect::<Vec<_>>();
+
+        for account_id in due_account_ids {
+            if let Err(error) = self
+                .account_service
+                .generate_pending_address(&account_id)
+                .await
+            {
+                log_warn!(
+                    "Failed to generate the address of the account {}: {}",
+                    Uuid::from_bytes(account_id).hyphenated(),
+                    error
+                );
+            }
+        }
+
+        if let Some(next_attempt_at) = next_address_generation_at() {
+            schedule_account_address_generation(next_attempt_at);
+        }
+    }
+}
+
+/// Returns when the address of the next pending account should be generated, if any account is pending.
+pub(super) fn next_address_generation_at() -> Option<Timestamp> {
+    ACCOUNT_REPOSITORY
+        .list()
+        .into_iter()
+        .filter_map(|account| match account.setup_state {
+            AccountSetupState::PendingAddressGeneration {
+                next_attempt_at, ..
+            } => Some(next_attempt_at),
+            AccountSetupState::Ready => None,
+        })
+        .min()
+}
+
+/// Schedules the job to generate the addresses of the pending accounts at the given time.
+pub fn schedule_account_address_generation(at_ns: Timestamp) {
+    Scheduler::schedule::<Job>(at_ns);
+}
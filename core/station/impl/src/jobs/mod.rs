@@ -6,8 +6,15 @@ use std::collections::HashMap;
 
 use crate::core::ic_cdk::next_time;
 use crate::core::ic_timers::TimerId;
-use crate::models::{RequestExecutionPlan, RequestStatusCode};
-use crate::repositories::TRANSFER_REPOSITORY;
+use crate::core::read_system_state;
+use crate::models::{
+    DataCompactionConfig, FiatValuationConfig, RequestArchivalConfig, RequestExecutionPlan,
+    RequestOperation, RequestStatusCode, SystemState, TransferOperation, TransferScreeningConfig,
+};
+use crate::repositories::{
+    EVENT_OUTBOX_REPOSITORY, EXTERNAL_CANISTER_REPOSITORY, NOTIFICATION_OUTBOX_REPOSITORY,
+    TRANSFER_REPOSITORY, USER_REPOSITORY,
+};
 use crate::{
     core::observer::Observer,
     models::{Request, RequestStatus, Transfer, TransferStatus},
@@ -16,16 +23,59 @@ use crate::{
 use async_trait::async_trait;
 use orbit_essentials::repository::Repository;
 
+mod apply_group_memberships;
+mod archive_requests;
 mod cancel_expired_requests;
+mod compact_requests;
+mod deliver_bridged_notifications;
+mod deliver_station_events;
+mod detect_module_drift;
 mod execute_created_transfers;
 mod execute_scheduled_requests;
+mod generate_account_addresses;
+mod rebuild_indexes;
+mod refresh_fiat_prices;
+mod remind_approvers;
 mod scheduler;
+mod screen_transfers;
+mod snapshot_account_balances;
+mod sync_address_book;
+mod watch_jobs;
+
+pub use apply_group_memberships::schedule_group_membership_changes;
+pub use archive_requests::schedule_request_archival;
+pub use compact_requests::schedule_request_compaction;
+pub use deliver_bridged_notifications::schedule_bridged_notification_delivery;
+pub use deliver_station_events::schedule_station_event_delivery;
+pub use detect_module_drift::schedule_module_drift_detection;
+pub use generate_account_addresses::schedule_account_address_generation;
+pub use rebuild_indexes::queue_index_rebuild;
+pub use refresh_fiat_prices::schedule_fiat_price_refresh;
+pub use remind_approvers::schedule_approver_reminders;
+pub use screen_transfers::schedule_transfer_screening;
+pub use snapshot_account_balances::schedule_balance_snapshots;
+pub use sync_address_book::schedule_address_book_sync;
+pub use watch_jobs::schedule_job_watchdog;
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum JobType {
     CancelExpiredRequests,
     ExecuteScheduledRequests,
     ExecuteCreatedTransfers,
+    RebuildIndexes,
+    DeliverStationEvents,
+    SyncAddressBook,
+    ScreenTransfers,
+    DetectModuleDrift,
+    CompactRequests,
+    ArchiveRequests,
+    RemindApprovers,
+    ApplyGroupMemberships,
+    WatchJobs,
+    SnapshotAccountBalances,
+    RefreshFiatPrices,
+    GenerateAccountAddresses,
+    DeliverBridgedNotifications,
 }
 
 #[async_trait]
@@ -80,6 +130,37 @@ impl JobStateDatabase {
         })
     }
 
+    /// Checks if there is a task of the given job type scheduled after the given time.
+    fn has_scheduled_task_after(job_type: JobType, at_ns: u64) -> bool {
+        TIME_JOB_MAPS.with(|time_job_maps| {
+            time_job_maps
+                .borrow()
+                .get(&job_type)
+                .is_some_and(|job_map| job_map.keys().any(|scheduled_at| *scheduled_at > at_ns))
+        })
+    }
+
+    /// Returns the tasks that should have run before the given time but are still in the database, which
+    /// happens when their timer trapped before the task could be cleaned up.
+    ///
+    /// The tasks of the jobs that are currently running are not included, since these are cleaned up once
+    /// the job ends.
+    fn find_overdue_tasks(before_ns: u64) -> Vec<(JobType, u64)> {
+        TIME_JOB_MAPS.with(|time_job_maps| {
+            time_job_maps
+                .borrow()
+                .iter()
+                .filter(|(job_type, _)| !Self::is_running(**job_type))
+                .flat_map(|(job_type, job_map)| {
+                    job_map
+                        .keys()
+                        .filter(|scheduled_at| **scheduled_at < before_ns)
+                        .map(|scheduled_at| (*job_type, *scheduled_at))
+                })
+                .collect()
+        })
+    }
+
     /// Adds a new scheduled task to the database, or increments the reference count if the task already exists.
     fn add_scheduled_task(job_type: JobType, at_ns: u64, timer_id: TimerId) {
         TIME_JOB_MAPS.with(|time_job_maps| {
@@ -176,11 +257,20 @@ fn schedule_request_for_execution(request: &Request) -> u64 {
     let request_processing_time = next_time();
     let scheduled_at = match &request.execution_plan {
         RequestExecutionPlan::Immediate => request_processing_time,
-        RequestExecutionPlan::Scheduled { execution_time } => *execution_time,
+        RequestExecutionPlan::Scheduled { execution_time, .. } => *execution_time,
     };
 
     let mut request = request.clone();
 
+    // time-locked transfers are held back until their delay since the approval has passed
+    let scheduled_at = match &mut request.operation {
+        RequestOperation::Transfer(TransferOperation {
+            time_lock: Some(time_lock),
+            ..
+        }) => scheduled_at.max(time_lock.lock(request_processing_time)),
+        _ => scheduled_at,
+    };
+
     request.status = RequestStatus::Scheduled { scheduled_at };
     request.last_modification_timestamp = request_processing_time;
 
@@ -192,25 +282,39 @@ fn schedule_request_for_execution(request: &Request) -> u64 {
 pub fn jobs_observe_insert_request(observer: &mut Observer<(Request, Option<Request>)>) {
     observer.add_listener(Box::new(|(request, prev)| match &request.status {
         RequestStatus::Created => {
-            if prev.is_some() {
+            // requests that are rescheduled after their approval go back to the created state
+            if let Some(Request {
+                status: RequestStatus::Created,
+                ..
+            }) = prev
+            {
                 return;
             }
 
             cancel_expired_requests::schedule_expiration(request.expiration_dt);
         }
-        RequestStatus::Approved => {
-            if let Some(Request {
+        RequestStatus::Approved => match prev {
+            Some(Request {
                 status: RequestStatus::Created,
                 ..
-            }) = prev
-            {
+            }) => {
                 cancel_expired_requests::cancel_scheduled_expiration(request.expiration_dt);
 
                 let scheduled_at = schedule_request_for_execution(request);
 
                 execute_scheduled_requests::schedule_request_execution(scheduled_at);
             }
-        }
+            Some(Request {
+                status: RequestStatus::Failed { .. },
+                ..
+            }) => {
+                // the execution of a failed request is being resumed
+                let scheduled_at = schedule_request_for_execution(request);
+
+                execute_scheduled_requests::schedule_request_execution(scheduled_at);
+            }
+            _ => {}
+        },
         RequestStatus::Rejected | RequestStatus::Cancelled { .. } => {
             if let Some(Request {
                 status: RequestStatus::Created,
@@ -284,6 +388,86 @@ pub fn initialize_job_timers() {
         // kick off execution timer for Transfers, once is enough
         execute_created_transfers::schedule_process_transfers(next_time());
     }
+
+    if !EVENT_OUTBOX_REPOSITORY.is_empty() {
+        // resume the delivery of the events that were not acknowledged before the upgrade
+        deliver_station_events::schedule_station_event_delivery(next_time());
+    }
+
+    if !NOTIFICATION_OUTBOX_REPOSITORY.is_empty() {
+        // resume the delivery of the notifications that were not pushed to the inboxes before the upgrade
+        deliver_bridged_notifications::schedule_bridged_notification_delivery(next_time());
+    }
+
+    if !EXTERNAL_CANISTER_REPOSITORY.is_empty() {
+        // the periodic check of the module hashes of the external canisters does not survive upgrades
+        detect_module_drift::schedule_module_drift_detection(
+            next_time() + detect_module_drift::Job::CHECK_INTERVAL_NS,
+        );
+    }
+
+    // the timer of the next scheduled group membership change does not survive upgrades
+    if let Some(next_change_at) = USER_REPOSITORY
+        .list()
+        .iter()
+        .filter_map(|user| user.next_membership_change_at())
+        .min()
+    {
+        apply_group_memberships::schedule_group_membership_changes(next_change_at);
+    }
+
+    // the retries of the address generation of the pending accounts do not survive upgrades
+    if let Some(next_attempt_at) = generate_account_addresses::next_address_generation_at() {
+        generate_account_addresses::schedule_account_address_generation(next_attempt_at);
+    }
+
+    // resume the rebuild of the indexes that was not completed before the upgrade
+    if let SystemState::Initialized(system_info) = read_system_state() {
+        if system_info.get_index_rebuild().is_some() {
+            rebuild_indexes::schedule_index_rebuild(next_time());
+        }
+
+        // the periodic sync of the address book sources does not survive upgrades
+        if !system_info.get_address_book_sources().is_empty() {
+            sync_address_book::schedule_address_book_sync(next_time());
+        }
+
+        // resume the screenings that were pending before the upgrade, the job stops if there are none
+        if *system_info.get_transfer_screening() != TransferScreeningConfig::Disabled {
+            screen_transfers::schedule_transfer_screening(next_time());
+        }
+
+        // the periodic compaction of the old requests does not survive upgrades
+        if *system_info.get_data_compaction() != DataCompactionConfig::Disabled {
+            compact_requests::schedule_request_compaction(next_time());
+        }
+
+        // the periodic archival of the old requests does not survive upgrades
+        if *system_info.get_request_archival() != RequestArchivalConfig::Disabled {
+            archive_requests::schedule_request_archival(next_time());
+        }
+
+        // the periodic reminders of the pending approvers do not survive upgrades
+        if system_info.get_approval_reminders().is_enabled() {
+            remind_approvers::schedule_approver_reminders(next_time());
+        }
+
+        // the periodic snapshots of the account balances do not survive upgrades
+        if let Some(next_snapshot_at) = system_info
+            .get_balance_snapshots()
+            .next_snapshot_at(next_time())
+        {
+            snapshot_account_balances::schedule_balance_snapshots(next_snapshot_at);
+        }
+
+        // the prices are kept in the heap memory, so they are fetched again right after upgrades
+        if *system_info.get_fiat_valuation() != FiatValuationConfig::Disabled {
+            refresh_fiat_prices::schedule_fiat_price_refresh(next_time());
+        }
+
+        // the watchdog of the jobs does not survive upgrades
+        watch_jobs::schedule_job_watchdog(next_time() + watch_jobs::Job::CHECK_INTERVAL_NS);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,188 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::{ic_cdk::next_time, read_system_info, write_system_info, Memory},
+    models::{IndexRebuildProgress, IndexRebuildTarget},
+    repositories::{
+        permission::PERMISSION_REPOSITORY, ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY,
+        EXTERNAL_CANISTER_REPOSITORY, REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY,
+        USER_GROUP_REPOSITORY, USER_REPOSITORY,
+    },
+};
+use async_trait::async_trait;
+use ic_stable_structures::{memory_manager::VirtualMemory, Storable};
+use orbit_essentials::{model::ModelKey, repository::RebuildRepository};
+use std::borrow::Cow;
+
+#[derive(Debug, Default)]
+pub struct Job;
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RebuildIndexes;
+
+    async fn run() -> bool {
+        Self::rebuild_next_chunk()
+    }
+}
+
+/// This job is responsible for rebuilding the indexes of the repositories in chunks, the progress is persisted
+/// in stable memory so that the rebuild continues where it left off even across upgrades.
+impl Job {
+    pub const MAX_CHUNK_SIZE: usize = 1_000;
+
+    /// Rebuilds the indexes of the next chunk of entries.
+    ///
+    /// Returns `true` when there is nothing left to rebuild.
+    fn rebuild_next_chunk() -> bool {
+        let mut system_info = read_system_info();
+        let Some(mut progress) = system_info.get_index_rebuild().cloned() else {
+            return true;
+        };
+
+        if let Some(target) = progress.pending.first().copied() {
+            let (cursor, processed_entries) =
+                rebuild_target_chunk(target, progress.cursor.take(), Self::MAX_CHUNK_SIZE);
+
+            progress.processed_entries += processed_entries;
+            progress.last_progress_at = next_time();
+            progress.cursor = cursor;
+
+            if progress.cursor.is_none() {
+                progress.pending.remove(0);
+            }
+        }
+
+        let is_completed = progress.pending.is_empty();
+
+        system_info.set_index_rebuild(match is_completed {
+            true => None,
+            false => Some(progress),
+        });
+        write_system_info(system_info);
+
+        is_completed
+    }
+}
+
+/// Rebuilds the indexes of a chunk of the given repository, starting after the entry with the serialized key.
+///
+/// Returns the serialized key of the last processed entry if there are entries left, and the number
+/// of processed entries.
+fn rebuild_target_chunk(
+    target: IndexRebuildTarget,
+    cursor: Option<Vec<u8>>,
+    limit: usize,
+) -> (Option<Vec<u8>>, u64) {
+    match target {
+        IndexRebuildTarget::UserGroups => rebuild_chunk(&*USER_GROUP_REPOSITORY, cursor, limit),
+        IndexRebuildTarget::Users => rebuild_chunk(&*USER_REPOSITORY, cursor, limit),
+        IndexRebuildTarget::Accounts => rebuild_chunk(&*ACCOUNT_REPOSITORY, cursor, limit),
+        IndexRebuildTarget::AddressBook => rebuild_chunk(&*ADDRESS_BOOK_REPOSITORY, cursor, limit),
+        IndexRebuildTarget::ExternalCanisters => {
+            rebuild_chunk(&*EXTERNAL_CANISTER_REPOSITORY, cursor, limit)
+        }
+        IndexRebuildTarget::Permissions => rebuild_chunk(&*PERMISSION_REPOSITORY, cursor, limit),
+        IndexRebuildTarget::RequestPolicies => {
+            rebuild_chunk(&*REQUEST_POLICY_REPOSITORY, cursor, limit)
+        }
+        IndexRebuildTarget::Requests => rebuild_chunk(&*REQUEST_REPOSITORY, cursor, limit),
+    }
+}
+
+fn rebuild_chunk<Key, Value, Repo>(
+    repository: &Repo,
+    cursor: Option<Vec<u8>>,
+    limit: usize,
+) -> (Option<Vec<u8>>, u64)
+where
+    Key: Eq + std::hash::Hash + Clone + Ord + Storable,
+    Value: Clone + Storable + ModelKey<Key>,
+    Repo: RebuildRepository<Key, Value, VirtualMemory<Memory>>,
+{
+    let after = cursor.map(|bytes| Key::from_bytes(Cow::Owned(bytes)));
+    let (processed, next) = repository.rebuild_indexes_chunk(after, limit);
+
+    (
+        next.map(|key| key.to_bytes().into_owned()),
+        processed as u64,
+    )
+}
+
+/// Queues the indexes of the given repositories to be rebuilt in the background.
+///
+/// Targets that are already queued are not added again.
+pub fn queue_index_rebuild(targets: Vec<IndexRebuildTarget>) {
+    let mut system_info = read_system_info();
+    let now = next_time();
+    let mut progress = system_info
+        .get_index_rebuild()
+        .cloned()
+        .unwrap_or(IndexRebuildProgress {
+            pending: Vec::new(),
+            cursor: None,
+            processed_entries: 0,
+            started_at: now,
+            last_progress_at: now,
+        });
+
+    for target in targets {
+        if !progress.pending.contains(&target) {
+            progress.pending.push(target);
+        }
+    }
+
+    if progress.pending.is_empty() {
+        return;
+    }
+
+    system_info.set_index_rebuild(Some(progress));
+    write_system_info(system_info);
+
+    schedule_index_rebuild(now);
+}
+
+pub fn schedule_index_rebuild(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::test_utils, models::UserGroup};
+    use orbit_essentials::repository::Repository;
+
+    #[test]
+    fn rebuilds_indexes_in_chunks() {
+        test_utils::init_canister_system();
+
+        let user_groups_count = Job::MAX_CHUNK_SIZE + Job::MAX_CHUNK_SIZE / 2;
+        for i in 0..user_groups_count {
+            let user_group = UserGroup {
+                id: *uuid::Uuid::from_u128(i as u128).as_bytes(),
+                name: format!("group_{}", i),
+                quotas: Default::default(),
+                last_modification_timestamp: 0,
+            };
+
+            USER_GROUP_REPOSITORY.insert(user_group.id, user_group);
+        }
+
+        queue_index_rebuild(vec![IndexRebuildTarget::UserGroups]);
+
+        assert!(!Job::rebuild_next_chunk());
+
+        let progress = read_system_info().get_index_rebuild().cloned().unwrap();
+        assert_eq!(progress.pending, vec![IndexRebuildTarget::UserGroups]);
+        assert_eq!(progress.processed_entries, Job::MAX_CHUNK_SIZE as u64);
+        assert!(progress.cursor.is_some());
+
+        assert!(Job::rebuild_next_chunk());
+        assert!(read_system_info().get_index_rebuild().is_none());
+
+        for i in 0..user_groups_count {
+            assert!(USER_GROUP_REPOSITORY
+                .find_by_name(&format!("group_{}", i))
+                .is_some());
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::{ic_cdk::next_time, read_system_info},
+    log_warn,
+    models::system::FiatValuationConfig,
+    services::{FiatValuationService, FIAT_VALUATION_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::types::Timestamp;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    fiat_valuation_service: Arc<FiatValuationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            fiat_valuation_service: Arc::clone(&FIAT_VALUATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RefreshFiatPrices;
+
+    async fn run() -> bool {
+        Self::default().refresh_fiat_prices().await;
+
+        true
+    }
+}
+
+/// This job is responsible for periodically fetching the fiat prices of the tokens held by the accounts
+/// from the price feed, so that the balances can be valued without calling the price feed on every query.
+///
+/// The prices that could not be fetched are kept until they are too old to be used.
+impl Job {
+    async fn refresh_fiat_prices(&self) {
+        if *read_system_info().get_fiat_valuation() == FiatValuationConfig::Disabled {
+            return;
+        }
+
+        if let Err(error) = self.fiat_valuation_service.refresh_prices().await {
+            log_warn!("Failed to refresh the fiat prices: {}", error);
+        }
+
+        let now = next_time();
+        if !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now) {
+            schedule_fiat_price_refresh(now + FiatValuationConfig::REFRESH_INTERVAL_NS);
+        }
+    }
+}
+
+/// Schedules the job to refresh the fiat prices at the given time.
+pub fn schedule_fiat_price_refresh(at_ns: Timestamp) {
+    Scheduler::schedule::<Job>(at_ns);
+}
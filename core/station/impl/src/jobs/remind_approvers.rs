@@ -0,0 +1,209 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::{ic_cdk::next_time, read_system_info},
+    log_warn,
+    models::{
+        NotificationType, Request, RequestApprovalReminderNotification, RequestStatusCode, UserId,
+    },
+    repositories::{
+        NotificationFindByUserWhereClause, NOTIFICATION_REPOSITORY, REQUEST_REPOSITORY,
+    },
+    services::{NotificationService, NOTIFICATION_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::types::Timestamp;
+use station_api::REQUEST_APPROVAL_REMINDER_NOTIFICATION_TYPE;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct Job {
+    notification_service: Arc<NotificationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            notification_service: Arc::clone(&NOTIFICATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RemindApprovers;
+
+    async fn run() -> bool {
+        Self::default().remind_approvers().await;
+
+        true
+    }
+}
+
+/// This job is responsible for periodically reminding the approvers of the pending requests of the
+/// decisions they haven't submitted yet, at the intervals configured for the station.
+///
+/// The reminders that were already sent are found through the notifications of each approver, so that
+/// an approver gets at most one reminder per interval even if the job runs late or more than once.
+impl Job {
+    /// The interval between the checks of the pending requests, which is 1 hour.
+    pub const CHECK_INTERVAL_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    async fn remind_approvers(&self) {
+        let config = read_system_info().get_approval_reminders().clone();
+        if !config.is_enabled() {
+            return;
+        }
+
+        let now = next_time();
+        for request in REQUEST_REPOSITORY.find_by_status(RequestStatusCode::Created, None, None) {
+            let Some(last_due_at) = config.last_due_at(request.created_timestamp, now) else {
+                continue;
+            };
+
+            let pending_approvers = match Self::find_pending_approvers(&request).await {
+                Ok(approvers) => approvers,
+                Err(error) => {
+                    log_warn!(
+                        "Failed to find the pending approvers of request {}: {}",
+                        Uuid::from_bytes(request.id).hyphenated(),
+                        error
+                    );
+
+                    continue;
+                }
+            };
+
+            for approver in pending_approvers {
+                let reminders = Self::find_sent_reminders(&request, approver);
+                if reminders.len() >= config.intervals_secs.len()
+                    || reminders.iter().any(|sent_at| *sent_at >= last_due_at)
+                {
+                    continue;
+                }
+
+                self.notification_service
+                    .send_notification(
+                        approver,
+                        NotificationType::RequestApprovalReminder(
+                            RequestApprovalReminderNotification {
+                                request_id: request.id,
+                            },
+                        ),
+                        format!("Reminder: {}", request.title),
+                        request.summary.to_owned(),
+                    )
+                    .await;
+            }
+        }
+
+        let now = next_time();
+        if !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now) {
+            schedule_approver_reminders(now + Self::CHECK_INTERVAL_NS);
+        }
+    }
+
+    /// Returns the users that can approve the request but haven't submitted their decision yet.
+    async fn find_pending_approvers(request: &Request) -> Result<Vec<UserId>, String> {
+        let possible_approvers = request
+            .find_all_possible_approvers()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        let mut pending_approvers = possible_approvers
+            .into_iter()
+            .filter(|user_id| {
+                *user_id != request.requested_by
+                    && !request
+                        .approvals
+                        .iter()
+                        .any(|approval| approval.approver_id == *user_id)
+            })
+            .collect::<Vec<_>>();
+        pending_approvers.sort();
+
+        Ok(pending_approvers)
+    }
+
+    /// Returns when the reminders of the request were sent to the user.
+    fn find_sent_reminders(request: &Request, user_id: UserId) -> Vec<Timestamp> {
+        NOTIFICATION_REPOSITORY
+            .find_by_user_where(
+                user_id,
+                NotificationFindByUserWhereClause {
+                    created_dt_from: Some(request.created_timestamp),
+                    created_dt_to: None,
+                    notification_type: Some(
+                        REQUEST_APPROVAL_REMINDER_NOTIFICATION_TYPE.to_string(),
+                    ),
+                    status: None,
+                    sort_by: None,
+                },
+            )
+            .into_iter()
+            .filter(|notification| {
+                matches!(
+                    &notification.notification_type,
+                    NotificationType::RequestApprovalReminder(reminder)
+                        if reminder.request_id == request.id
+                )
+            })
+            .map(|notification| notification.created_timestamp)
+            .collect()
+    }
+}
+
+pub fn schedule_approver_reminders(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{test_utils, write_system_info},
+        models::{
+            request_policy_rule::RequestPolicyRule,
+            request_policy_test_utils::mock_request_policy,
+            request_specifier::{RequestSpecifier, UserSpecifier},
+            request_test_utils::mock_request,
+            resource::ResourceIds,
+            system::ApprovalReminderConfig,
+            user_test_utils::mock_user,
+            RequestStatus,
+        },
+        repositories::{request_policy::REQUEST_POLICY_REPOSITORY, USER_REPOSITORY},
+    };
+    use orbit_essentials::repository::Repository;
+
+    #[tokio::test]
+    async fn reminds_each_pending_approver_once_per_interval() {
+        test_utils::init_canister_system();
+
+        let mut system_info = read_system_info();
+        system_info.set_approval_reminders(ApprovalReminderConfig {
+            intervals_secs: vec![60 * 60, 2 * 60 * 60],
+        });
+        write_system_info(system_info);
+
+        let approver = mock_user();
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![approver.id]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy);
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.approvals = vec![];
+        request.created_timestamp = next_time().saturating_sub(90 * 60 * 1_000_000_000);
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let job = Job::default();
+        job.remind_approvers().await;
+        job.remind_approvers().await;
+
+        assert_eq!(Job::find_sent_reminders(&request, approver.id).len(), 1);
+    }
+}
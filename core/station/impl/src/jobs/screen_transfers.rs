@@ -0,0 +1,197 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::{api::id as station_canister_self_id, next_time},
+    log_error,
+    models::{
+        Account, Request, RequestOperation, RequestStatusCode, TransferOperation,
+        TransferScreening, TransferScreeningStatus,
+    },
+    repositories::{ACCOUNT_REPOSITORY, REQUEST_REPOSITORY},
+    services::{RequestService, REQUEST_SERVICE},
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Principal};
+use futures::future;
+use orbit_essentials::{repository::Repository, types::Timestamp};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The transfer that is sent to the `screen_transfer` method of the screening service.
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct ScreenTransferArgs {
+    station_id: Principal,
+    request_id: String,
+    blockchain: String,
+    standard: String,
+    symbol: String,
+    network: String,
+    from_address: String,
+    to_address: String,
+    amount: candid::Nat,
+}
+
+/// The verdict returned by the `screen_transfer` method of the screening service.
+#[derive(CandidType, Deserialize, Debug, Clone)]
+enum ScreenTransferVerdict {
+    Pass {
+        reference: Option<String>,
+    },
+    Flag {
+        reason: String,
+        reference: Option<String>,
+    },
+}
+
+#[derive(Debug)]
+pub struct Job {
+    request_service: Arc<RequestService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            request_service: Arc::clone(&REQUEST_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ScreenTransfers;
+
+    async fn run() -> bool {
+        Self::default().screen_pending_transfers().await;
+
+        true
+    }
+}
+
+/// This job is responsible for calling the screening service for the transfer requests that are
+/// waiting for their screening verdict, failed calls are retried with an exponential backoff.
+impl Job {
+    pub const MAX_SCREENINGS_PER_RUN: usize = 20;
+
+    async fn screen_pending_transfers(&self) {
+        let now = next_time();
+        let due = REQUEST_REPOSITORY
+            .find_by_status(RequestStatusCode::Created, None, None)
+            .into_iter()
+            .filter(|request| {
+                Self::pending_screening(request)
+                    .and_then(|screening| screening.next_attempt_at())
+                    .is_some_and(|next_attempt_at| next_attempt_at <= now)
+            })
+            .collect::<Vec<_>>();
+
+        let has_more_due = due.len() > Self::MAX_SCREENINGS_PER_RUN;
+        future::join_all(
+            due.into_iter()
+                .take(Self::MAX_SCREENINGS_PER_RUN)
+                .map(|request| self.screen_transfer(request)),
+        )
+        .await;
+
+        // the screenings that failed are retried at their next attempt time
+        let next_run_at = match has_more_due {
+            true => Some(next_time()),
+            false => REQUEST_REPOSITORY
+                .find_by_status(RequestStatusCode::Created, None, None)
+                .iter()
+                .filter_map(|request| Self::pending_screening(request)?.next_attempt_at())
+                .min(),
+        };
+
+        if let Some(next_run_at) = next_run_at {
+            schedule_transfer_screening(next_run_at);
+        }
+    }
+
+    fn pending_screening(request: &Request) -> Option<&TransferScreening> {
+        match &request.operation {
+            RequestOperation::Transfer(TransferOperation {
+                screening: Some(screening),
+                ..
+            }) => Some(screening),
+            _ => None,
+        }
+    }
+
+    async fn screen_transfer(&self, request: Request) {
+        let RequestOperation::Transfer(operation) = &request.operation else {
+            return;
+        };
+        let Some(mut screening) = operation.screening.clone() else {
+            return;
+        };
+
+        let result = match ACCOUNT_REPOSITORY.get(&Account::key(operation.input.from_account_id)) {
+            Some(account) => {
+                Self::call_screening_service(&screening, &request, operation, &account).await
+            }
+            None => Err("The account of the transfer does not exist.".to_string()),
+        };
+
+        let now = next_time();
+        match result {
+            Ok(ScreenTransferVerdict::Pass { reference }) => {
+                screening.status = TransferScreeningStatus::Passed {
+                    reference,
+                    screened_at: now,
+                };
+            }
+            Ok(ScreenTransferVerdict::Flag { reason, reference }) => {
+                screening.status = TransferScreeningStatus::Flagged {
+                    reason,
+                    reference,
+                    screened_at: now,
+                };
+            }
+            Err(error) => screening.register_failed_attempt(error, now),
+        }
+
+        if let Err(error) = self
+            .request_service
+            .update_transfer_screening(&request.id, screening, now)
+            .await
+        {
+            log_error!(
+                "Failed to update the screening of request {}: {}",
+                Uuid::from_bytes(request.id).hyphenated(),
+                error
+            );
+        }
+    }
+
+    async fn call_screening_service(
+        screening: &TransferScreening,
+        request: &Request,
+        operation: &TransferOperation,
+        account: &Account,
+    ) -> Result<ScreenTransferVerdict, String> {
+        let args = ScreenTransferArgs {
+            station_id: station_canister_self_id(),
+            request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+            symbol: account.symbol.clone(),
+            network: operation.input.network.clone(),
+            from_address: account.address.clone(),
+            to_address: operation.input.to.clone(),
+            amount: operation.input.amount.clone(),
+        };
+
+        let (verdict,) = ic_cdk::call::<_, (ScreenTransferVerdict,)>(
+            screening.canister_id,
+            "screen_transfer",
+            (args,),
+        )
+        .await
+        .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))?;
+
+        Ok(verdict)
+    }
+}
+
+pub fn schedule_transfer_screening(at_ns: Timestamp) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -0,0 +1,139 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::{ic_cdk::next_time, read_system_info},
+    log_warn,
+    models::{AccountBalanceSnapshot, AccountBalanceSnapshotKey},
+    repositories::{ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY, ACCOUNT_REPOSITORY},
+    services::{AccountService, ACCOUNT_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::repository::Repository;
+use station_api::FetchAccountBalancesInput;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct Job {
+    account_service: Arc<AccountService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            account_service: Arc::clone(&ACCOUNT_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::SnapshotAccountBalances;
+
+    async fn run() -> bool {
+        Self::default().snapshot_account_balances().await;
+
+        true
+    }
+}
+
+/// This job is responsible for recording the balances of all the accounts at the interval configured for
+/// the station, so that the balance history of the accounts can be charted without external indexers.
+///
+/// The balances are fetched from the blockchains unless they were fetched very recently, the accounts whose
+/// balance can't be fetched are left out of the snapshot.
+impl Job {
+    async fn snapshot_account_balances(&self) {
+        let config = read_system_info().get_balance_snapshots().clone();
+        if config.interval_secs.is_none() {
+            return;
+        }
+
+        for account in ACCOUNT_REPOSITORY.list() {
+            let account_id = Uuid::from_bytes(account.id).hyphenated().to_string();
+            if let Err(error) = self
+                .account_service
+                .fetch_account_balances(FetchAccountBalancesInput {
+                    account_ids: vec![account_id.clone()],
+                })
+                .await
+            {
+                log_warn!(
+                    "Failed to fetch the balance of account {} for its snapshot: {}",
+                    account_id,
+                    error
+                );
+
+                continue;
+            }
+
+            // the fetch stored the balance in the account
+            if let Some(balance) = ACCOUNT_REPOSITORY
+                .get(&account.to_key())
+                .and_then(|account| account.balance)
+            {
+                ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY.insert(
+                    AccountBalanceSnapshotKey {
+                        account_id: account.id,
+                        timestamp: balance.last_modification_timestamp,
+                    },
+                    AccountBalanceSnapshot {
+                        balance: balance.balance,
+                    },
+                );
+            }
+        }
+
+        let now = next_time();
+        if let Some(next_snapshot_at) = config.next_snapshot_at(now) {
+            if !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now) {
+                schedule_balance_snapshots(next_snapshot_at);
+            }
+        }
+    }
+}
+
+/// Schedules the job to take the balance snapshots of the accounts at the given time.
+pub fn schedule_balance_snapshots(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, AccountBalance},
+    };
+
+    #[tokio::test]
+    async fn records_the_balances_of_the_accounts() {
+        test_utils::init_canister_system();
+
+        // a fresh balance is not fetched from the blockchain again
+        let mut account = mock_account();
+        account.balance = Some(AccountBalance {
+            balance: candid::Nat::from(100u64),
+            last_modification_timestamp: next_time(),
+        });
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        Job::default().snapshot_account_balances().await;
+
+        let snapshots =
+            ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY.find_by_account(account.id, 0, next_time());
+
+        assert_eq!(
+            snapshots,
+            vec![(
+                account
+                    .balance
+                    .as_ref()
+                    .unwrap()
+                    .last_modification_timestamp,
+                AccountBalanceSnapshot {
+                    balance: candid::Nat::from(100u64),
+                }
+            )]
+        );
+    }
+}
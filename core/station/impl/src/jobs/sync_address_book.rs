@@ -0,0 +1,155 @@
+use super::{scheduler::Scheduler, JobStateDatabase, JobType, ScheduledJob};
+use crate::{
+    core::{ic_cdk::next_time, read_system_info, write_system_info},
+    log_debug,
+    models::system::AddressBookSource,
+    services::{AddressBookService, ADDRESS_BOOK_SERVICE},
+};
+use async_trait::async_trait;
+use station_api::{
+    AddressBookEntryDTO, ApiErrorDTO, ListAddressBookEntriesInputDTO,
+    ListAddressBookEntriesResponseDTO, PaginationInput,
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    address_book_service: Arc<AddressBookService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            address_book_service: Arc::clone(&ADDRESS_BOOK_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::SyncAddressBook;
+
+    async fn run() -> bool {
+        Self::default().sync_sources().await;
+
+        true
+    }
+}
+
+/// This job is responsible for periodically syncing the entries published by the address book sources
+/// into the address book of the station.
+///
+/// A source that fails to be fetched keeps its previously synced entries until the next successful sync.
+impl Job {
+    /// The interval between the syncs of the address book sources, which is 1 hour.
+    pub const SYNC_INTERVAL_NS: u64 = 60 * 60 * 1_000_000_000;
+    /// The maximum number of entries that are synced from a single source.
+    pub const MAX_ENTRIES_PER_SOURCE: usize = 5_000;
+    const PAGE_SIZE: u16 = 1_000;
+
+    async fn sync_sources(&self) {
+        let sources = read_system_info().get_address_book_sources().to_vec();
+        let source_ids = sources
+            .iter()
+            .map(|source| source.canister_id)
+            .collect::<Vec<_>>();
+
+        self.address_book_service
+            .remove_entries_of_unknown_sources(&source_ids);
+
+        for source in sources.iter() {
+            let result = match Self::fetch_published_entries(source).await {
+                Ok(entries) => {
+                    // the source could have been removed while its entries were being fetched
+                    if !read_system_info()
+                        .get_address_book_sources()
+                        .iter()
+                        .any(|current| current.canister_id == source.canister_id)
+                    {
+                        continue;
+                    }
+
+                    let synced_at = next_time();
+                    let summary = self
+                        .address_book_service
+                        .sync_source_entries(source.canister_id, entries, synced_at)
+                        .await;
+
+                    log_debug!(
+                        "Synced the address book source {}: {:?}",
+                        source.canister_id,
+                        summary
+                    );
+
+                    Ok(synced_at)
+                }
+                Err(error) => Err(error),
+            };
+
+            let mut system_info = read_system_info();
+            system_info.update_address_book_source_status(&source.canister_id, result);
+            write_system_info(system_info);
+        }
+
+        // the sources could have been changed during the sync, in which case a new sync is already scheduled
+        let now = next_time();
+        if !sources.is_empty() && !JobStateDatabase::has_scheduled_task_after(Self::JOB_TYPE, now) {
+            schedule_address_book_sync(now + Self::SYNC_INTERVAL_NS);
+        }
+    }
+
+    /// Fetches the entries published by the source, page by page.
+    async fn fetch_published_entries(
+        source: &AddressBookSource,
+    ) -> Result<Vec<AddressBookEntryDTO>, String> {
+        let mut entries = Vec::new();
+        let mut offset = None;
+
+        loop {
+            let input = ListAddressBookEntriesInputDTO {
+                ids: None,
+                addresses: None,
+                blockchain: None,
+                labels: (!source.labels.is_empty()).then(|| source.labels.clone()),
+                search_term: None,
+                address_owners: None,
+                paginate: Some(PaginationInput {
+                    offset,
+                    limit: Some(Self::PAGE_SIZE),
+                }),
+            };
+
+            let (result,) = ic_cdk::call::<
+                _,
+                (Result<ListAddressBookEntriesResponseDTO, ApiErrorDTO>,),
+            >(source.canister_id, "list_address_book_entries", (input,))
+            .await
+            .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))?;
+
+            let page = result.map_err(|error| {
+                format!(
+                    "code: {}, err: {}",
+                    error.code,
+                    error.message.unwrap_or_default()
+                )
+            })?;
+
+            entries.extend(page.address_book_entries);
+
+            match page.next_offset {
+                Some(next_offset) if entries.len() < Self::MAX_ENTRIES_PER_SOURCE => {
+                    offset = Some(next_offset);
+                }
+                _ => break,
+            }
+        }
+
+        entries.truncate(Self::MAX_ENTRIES_PER_SOURCE);
+
+        Ok(entries)
+    }
+}
+
+pub fn schedule_address_book_sync(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -0,0 +1,214 @@
+use super::{
+    cancel_expired_requests, execute_scheduled_requests, scheduler::Scheduler, JobStateDatabase,
+    JobType, ScheduledJob,
+};
+use crate::{
+    core::ic_cdk::next_time,
+    log_warn,
+    models::{JobsStalledNotification, NotificationType, RequestStatusCode, ADMIN_GROUP_ID},
+    repositories::REQUEST_REPOSITORY,
+    services::{NotificationService, NOTIFICATION_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::{types::Timestamp, utils::timestamp_to_rfc3339};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+    /// The last stall that was reported to the admins and when, so that the same stall is reported once a day.
+    static LAST_ALERT: RefCell<Option<(Timestamp, JobsStalledNotification)>> = RefCell::new(None);
+}
+
+#[derive(Debug)]
+pub struct Job {
+    notification_service: Arc<NotificationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            notification_service: Arc::clone(&NOTIFICATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::WatchJobs;
+
+    async fn run() -> bool {
+        Self::default().watch_jobs().await;
+
+        true
+    }
+}
+
+/// This job is responsible for detecting the background jobs that stopped running, e.g. because their timers
+/// trapped, and for notifying the admins with the details before the users notice that their approved
+/// requests are never executed.
+///
+/// The timers of the stalled requests are also set again, so that a single trapped timer doesn't leave the
+/// requests stuck until the next upgrade.
+impl Job {
+    /// The interval between the checks of the jobs, which is 10 minutes.
+    pub const CHECK_INTERVAL_NS: u64 = 10 * 60 * 1_000_000_000;
+    /// How late a job or a request can be before it is considered stalled, which is 15 minutes.
+    pub const STALL_THRESHOLD_NS: u64 = 15 * 60 * 1_000_000_000;
+    /// The interval between the notifications of the same stall, which is 1 day.
+    pub const ALERT_INTERVAL_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    async fn watch_jobs(&self) {
+        let now = next_time();
+        let stalled_before = now.saturating_sub(Self::STALL_THRESHOLD_NS);
+
+        let overdue_tasks = JobStateDatabase::find_overdue_tasks(stalled_before);
+        let stalled_executions = REQUEST_REPOSITORY.find_scheduled(None, Some(stalled_before));
+        let stalled_expirations = REQUEST_REPOSITORY.find_by_status_and_expiration_dt(
+            RequestStatusCode::Created,
+            None,
+            Some(stalled_before),
+        );
+
+        let mut stall = JobsStalledNotification {
+            overdue_jobs: overdue_tasks
+                .iter()
+                .map(|(job_type, scheduled_at)| {
+                    format!(
+                        "{:?} (due at {})",
+                        job_type,
+                        timestamp_to_rfc3339(scheduled_at)
+                    )
+                })
+                .collect(),
+            stalled_executions: stalled_executions
+                .iter()
+                .map(|request| request.id)
+                .collect(),
+            stalled_expirations: stalled_expirations
+                .iter()
+                .map(|request| request.id)
+                .collect(),
+        };
+        stall.overdue_jobs.sort();
+        stall.stalled_executions.sort();
+        stall.stalled_expirations.sort();
+
+        // the tasks whose timers trapped are never cleaned up otherwise
+        for (job_type, scheduled_at) in overdue_tasks {
+            JobStateDatabase::finalize_scheduled_task(job_type, scheduled_at);
+        }
+
+        if !stall.stalled_executions.is_empty() {
+            execute_scheduled_requests::schedule_request_execution(now);
+        }
+
+        if !stall.stalled_expirations.is_empty() {
+            cancel_expired_requests::schedule_expiration(now);
+        }
+
+        if Self::should_alert(&stall, now) {
+            log_warn!(
+                "Background jobs stalled: {} overdue jobs, {} stalled executions, {} stalled expirations",
+                stall.overdue_jobs.len(),
+                stall.stalled_executions.len(),
+                stall.stalled_expirations.len()
+            );
+
+            LAST_ALERT.with(|last_alert| *last_alert.borrow_mut() = Some((now, stall.clone())));
+
+            let summary = format!(
+                "{} jobs are overdue, {} approved requests were not executed and {} expired requests were not cancelled in time.",
+                stall.overdue_jobs.len(),
+                stall.stalled_executions.len(),
+                stall.stalled_expirations.len()
+            );
+
+            self.notification_service
+                .send_group_notification(
+                    [],
+                    [*ADMIN_GROUP_ID],
+                    NotificationType::JobsStalled(stall),
+                    "Background jobs of the station stalled".to_string(),
+                    Some(summary),
+                )
+                .await;
+        }
+
+        schedule_job_watchdog(next_time() + Self::CHECK_INTERVAL_NS);
+    }
+
+    /// Returns whether the stall should be reported, which is the case if it differs from the last one that
+    /// was reported or if that one was reported more than a day ago.
+    fn should_alert(stall: &JobsStalledNotification, now: Timestamp) -> bool {
+        if stall.overdue_jobs.is_empty()
+            && stall.stalled_executions.is_empty()
+            && stall.stalled_expirations.is_empty()
+        {
+            return false;
+        }
+
+        LAST_ALERT.with(|last_alert| match &*last_alert.borrow() {
+            Some((alerted_at, last_stall)) => {
+                last_stall != stall || alerted_at + Self::ALERT_INTERVAL_NS <= now
+            }
+            None => true,
+        })
+    }
+}
+
+pub fn schedule_job_watchdog(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            request_test_utils::mock_request, user_test_utils::mock_user, RequestStatus, UserStatus,
+        },
+        repositories::{
+            NotificationFindByUserWhereClause, RequestRepository, NOTIFICATION_REPOSITORY,
+            USER_REPOSITORY,
+        },
+    };
+    use orbit_essentials::repository::Repository;
+
+    #[tokio::test]
+    async fn notifies_the_admins_of_stalled_requests_once() {
+        test_utils::init_canister_system();
+
+        let mut admin = mock_user();
+        admin.groups = vec![*ADMIN_GROUP_ID];
+        admin.status = UserStatus::Active;
+        USER_REPOSITORY.insert(admin.to_key(), admin.clone());
+
+        // the observers would set the timers of the request, which is what failed here
+        let request_repository = RequestRepository::with_empty_observers();
+        let mut request = mock_request();
+        request.status = RequestStatus::Scheduled {
+            scheduled_at: next_time().saturating_sub(Job::STALL_THRESHOLD_NS + 1),
+        };
+        request_repository.insert(request.to_key(), request.clone());
+
+        Job::default().watch_jobs().await;
+        Job::default().watch_jobs().await;
+
+        let notifications = NOTIFICATION_REPOSITORY.find_by_user_where(
+            admin.id,
+            NotificationFindByUserWhereClause {
+                created_dt_from: None,
+                created_dt_to: None,
+                notification_type: None,
+                status: None,
+                sort_by: None,
+            },
+        );
+
+        assert_eq!(notifications.len(), 1);
+        assert!(matches!(
+            &notifications[0].notification_type,
+            NotificationType::JobsStalled(stall) if stall.stalled_executions == vec![request.id]
+        ));
+    }
+}
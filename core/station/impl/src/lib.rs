@@ -4,7 +4,7 @@
 
 pub const SERVICE_NAME: &str = "station";
 pub const SYSTEM_VERSION: &str = env!("CARGO_PKG_VERSION");
-pub const STABLE_MEMORY_VERSION: u32 = 1;
+pub const STABLE_MEMORY_VERSION: u32 = 3;
 
 pub mod controllers;
 pub mod core;
@@ -16,3 +16,95 @@ macro_rules! concat_str_arrays {
         result
     }};
 }
+
+/// Writes a `Debug` entry to the station log, formatted like `format!`.
+///
+/// Prefix the arguments with `trace = <TraceContext>;` to attach the entry to the trace of a correlation id.
+#[macro_export]
+macro_rules! log_debug {
+    (trace = $trace:expr; $($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Debug,
+            module_path!(),
+            format!($($arg)*),
+            Some($trace.correlation_id()),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Debug,
+            module_path!(),
+            format!($($arg)*),
+            None,
+        )
+    };
+}
+
+/// Writes an `Info` entry to the station log, formatted like `format!`.
+///
+/// Prefix the arguments with `trace = <TraceContext>;` to attach the entry to the trace of a correlation id.
+#[macro_export]
+macro_rules! log_info {
+    (trace = $trace:expr; $($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Info,
+            module_path!(),
+            format!($($arg)*),
+            Some($trace.correlation_id()),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Info,
+            module_path!(),
+            format!($($arg)*),
+            None,
+        )
+    };
+}
+
+/// Writes a `Warn` entry to the station log, formatted like `format!`.
+///
+/// Prefix the arguments with `trace = <TraceContext>;` to attach the entry to the trace of a correlation id.
+#[macro_export]
+macro_rules! log_warn {
+    (trace = $trace:expr; $($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Warn,
+            module_path!(),
+            format!($($arg)*),
+            Some($trace.correlation_id()),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Warn,
+            module_path!(),
+            format!($($arg)*),
+            None,
+        )
+    };
+}
+
+/// Writes an `Error` entry to the station log, formatted like `format!`.
+///
+/// Prefix the arguments with `trace = <TraceContext>;` to attach the entry to the trace of a correlation id.
+#[macro_export]
+macro_rules! log_error {
+    (trace = $trace:expr; $($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Error,
+            module_path!(),
+            format!($($arg)*),
+            Some($trace.correlation_id()),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::core::logger::log(
+            $crate::models::LogLevel::Error,
+            module_path!(),
+            format!($($arg)*),
+            None,
+        )
+    };
+}
@@ -1,15 +1,24 @@
 use crate::{
-    core::ic_cdk::next_time,
+    core::{
+        format_amount,
+        ic_cdk::{api::id as station_canister_self_id, next_time},
+    },
     errors::MapperError,
+    factories::blockchains::{BlockchainTransaction, InternetComputer},
+    log_warn,
     models::{
-        Account, AccountBalance, AccountCallerPrivileges, AccountId, AddAccountOperationInput,
-        BlockchainStandard, ACCOUNT_METADATA_SYMBOL_KEY,
+        Account, AccountBalance, AccountCallerPrivileges, AccountId, AccountReview,
+        AccountSetupState, AddAccountOperationInput, BalanceHistoryGranularity, BlockchainStandard,
+        Network, TransferId, ACCOUNT_METADATA_SYMBOL_KEY,
     },
     repositories::request_policy::REQUEST_POLICY_REPOSITORY,
 };
-use ic_cdk::print;
 use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
-use station_api::{AccountBalanceDTO, AccountBalanceInfoDTO, AccountDTO};
+use station_api::{
+    AccountBalanceDTO, AccountBalanceInfoDTO, AccountDTO, AccountReviewDTO, AccountSetupStateDTO,
+    AccountTransactionDTO, AccountTransactionDirectionDTO, Icrc1AccountDTO,
+    RetiredAccountAddressDTO,
+};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
@@ -17,12 +26,22 @@ pub struct AccountMapper {}
 
 impl AccountMapper {
     pub fn to_dto(account: Account) -> AccountDTO {
+        let network = Network::resolve(&account.blockchain, account.network_id());
+        let review = account.active_review(next_time()).cloned().map(Into::into);
+        let setup_state = account.setup_state.clone().into();
+        let display_decimals = account.display_decimals();
+
         AccountDTO {
             id: Uuid::from_bytes(account.id).hyphenated().to_string(),
             name: account.name,
             decimals: account.decimals,
             balance: match account.balance {
                 Some(balance) => Some(AccountBalanceInfoDTO {
+                    formatted_balance: format_amount(
+                        &balance.balance,
+                        account.decimals,
+                        display_decimals,
+                    ),
                     balance: balance.balance,
                     decimals: account.decimals,
                     last_update_timestamp: timestamp_to_rfc3339(
@@ -41,10 +60,10 @@ impl AccountMapper {
                     .get(&policy_id)
                     .map(|policy| policy.rule.into())
                     .or_else(|| {
-                        print(format!(
+                        log_warn!(
                             "transfer_request_policy not found for request {}",
                             Uuid::from_bytes(policy_id).hyphenated()
-                        ));
+                        );
                         None
                     })
             }),
@@ -53,14 +72,28 @@ impl AccountMapper {
                     .get(&policy_id)
                     .map(|policy| policy.rule.into())
                     .or_else(|| {
-                        print(format!(
+                        log_warn!(
                             "configs_request_policy not found for request {}",
                             Uuid::from_bytes(policy_id).hyphenated()
-                        ));
+                        );
                         None
                     })
             }),
             last_modification_timestamp: timestamp_to_rfc3339(&account.last_modification_timestamp),
+            version: account.version,
+            retired_addresses: account
+                .retired_addresses
+                .into_iter()
+                .map(|retired| RetiredAccountAddressDTO {
+                    address: retired.address,
+                    retired_at: timestamp_to_rfc3339(&retired.retired_at),
+                    grace_period_ends_at: timestamp_to_rfc3339(&retired.grace_period_ends_at),
+                })
+                .collect(),
+            is_testnet: network.is_testnet,
+            network: network.into(),
+            review,
+            setup_state,
         }
     }
 
@@ -112,29 +145,103 @@ impl AccountMapper {
             balance: None,
             metadata: input.metadata,
             last_modification_timestamp: next_time(),
+            version: 0,
+            derivation_index: 0,
+            retired_addresses: vec![],
+            network: input.network,
+            review: None,
+            setup_state: AccountSetupState::Ready,
         };
 
         Ok(new_account)
     }
 
-    pub fn to_balance_dto(
-        balance: AccountBalance,
-        decimals: u32,
-        account_id: AccountId,
-    ) -> AccountBalanceDTO {
+    pub fn to_balance_dto(balance: AccountBalance, account: &Account) -> AccountBalanceDTO {
         AccountBalanceDTO {
-            account_id: Uuid::from_bytes(account_id).hyphenated().to_string(),
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            formatted_balance: format_amount(
+                &balance.balance,
+                account.decimals,
+                account.display_decimals(),
+            ),
             balance: balance.balance,
-            decimals,
+            decimals: account.decimals,
             last_update_timestamp: timestamp_to_rfc3339(&balance.last_modification_timestamp),
         }
     }
+
+    /// Maps a blockchain transaction of the account, transactions not sent from the account are incoming.
+    pub fn to_transaction_dto(
+        transaction: BlockchainTransaction,
+        account_address: &str,
+        transfer_id: Option<TransferId>,
+        from_label: Option<String>,
+        to_label: Option<String>,
+    ) -> AccountTransactionDTO {
+        AccountTransactionDTO {
+            id: transaction.id,
+            direction: match transaction.from_address.as_deref() == Some(account_address) {
+                true => AccountTransactionDirectionDTO::Outgoing,
+                false => AccountTransactionDirectionDTO::Incoming,
+            },
+            from_address: transaction.from_address,
+            to_address: transaction.to_address,
+            amount: candid::Nat(transaction.amount),
+            fee: transaction.fee.map(candid::Nat),
+            memo: transaction.memo,
+            created_at: transaction
+                .created_at
+                .map(|created_at| timestamp_to_rfc3339(&created_at)),
+            timestamp: transaction
+                .timestamp
+                .map(|timestamp| timestamp_to_rfc3339(&timestamp)),
+            transfer_id: transfer_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            from_label,
+            to_label,
+        }
+    }
 }
 
 impl Account {
     pub fn to_dto(self) -> AccountDTO {
         AccountMapper::to_dto(self)
     }
+
+    /// Maps the account to the ICRC-1 account of the station that holds its funds.
+    pub fn to_icrc1_account_dto(&self) -> Icrc1AccountDTO {
+        Icrc1AccountDTO {
+            owner: station_canister_self_id(),
+            subaccount: Some(InternetComputer::subaccount_from_station_account(self).to_vec()),
+        }
+    }
+}
+
+impl From<AccountSetupState> for AccountSetupStateDTO {
+    fn from(state: AccountSetupState) -> Self {
+        match state {
+            AccountSetupState::Ready => AccountSetupStateDTO::Ready,
+            AccountSetupState::PendingAddressGeneration {
+                attempts,
+                last_error,
+                next_attempt_at,
+            } => AccountSetupStateDTO::PendingAddressGeneration {
+                attempts,
+                last_error,
+                next_attempt_at: timestamp_to_rfc3339(&next_attempt_at),
+            },
+        }
+    }
+}
+
+impl From<AccountReview> for AccountReviewDTO {
+    fn from(review: AccountReview) -> Self {
+        AccountReviewDTO {
+            reason: review.reason,
+            flagged_by: Uuid::from_bytes(review.flagged_by).hyphenated().to_string(),
+            flagged_at: timestamp_to_rfc3339(&review.flagged_at),
+            expires_at: timestamp_to_rfc3339(&review.expires_at),
+        }
+    }
 }
 
 impl From<AccountCallerPrivileges> for station_api::AccountCallerPrivilegesDTO {
@@ -146,3 +253,29 @@ impl From<AccountCallerPrivileges> for station_api::AccountCallerPrivilegesDTO {
         }
     }
 }
+
+impl From<station_api::BalanceHistoryGranularityDTO> for BalanceHistoryGranularity {
+    fn from(granularity: station_api::BalanceHistoryGranularityDTO) -> Self {
+        match granularity {
+            station_api::BalanceHistoryGranularityDTO::Hourly => BalanceHistoryGranularity::Hourly,
+            station_api::BalanceHistoryGranularityDTO::Daily => BalanceHistoryGranularity::Daily,
+            station_api::BalanceHistoryGranularityDTO::Weekly => BalanceHistoryGranularity::Weekly,
+            station_api::BalanceHistoryGranularityDTO::Monthly => {
+                BalanceHistoryGranularity::Monthly
+            }
+        }
+    }
+}
+
+impl From<BalanceHistoryGranularity> for station_api::BalanceHistoryGranularityDTO {
+    fn from(granularity: BalanceHistoryGranularity) -> Self {
+        match granularity {
+            BalanceHistoryGranularity::Hourly => station_api::BalanceHistoryGranularityDTO::Hourly,
+            BalanceHistoryGranularity::Daily => station_api::BalanceHistoryGranularityDTO::Daily,
+            BalanceHistoryGranularity::Weekly => station_api::BalanceHistoryGranularityDTO::Weekly,
+            BalanceHistoryGranularity::Monthly => {
+                station_api::BalanceHistoryGranularityDTO::Monthly
+            }
+        }
+    }
+}
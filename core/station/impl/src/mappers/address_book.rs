@@ -4,12 +4,14 @@ use crate::errors::MapperError;
 use crate::mappers::blockchain::BlockchainMapper;
 use crate::models::{
     AddAddressBookEntryOperationInput, AddressBookEntry, AddressBookEntryCallerPrivileges,
-    ListAddressBookEntriesInput,
+    AddressBookEntryOrigin, ListAddressBookEntriesInput, MetadataItem,
 };
-use orbit_essentials::types::UUID;
+use candid::Principal;
+use orbit_essentials::types::{Timestamp, UUID};
 use orbit_essentials::utils::timestamp_to_rfc3339;
 use station_api::{
-    AddressBookEntryCallerPrivilegesDTO, AddressBookEntryDTO, ListAddressBookEntriesInputDTO,
+    AddressBookEntryCallerPrivilegesDTO, AddressBookEntryDTO, AddressBookEntryOriginDTO,
+    ListAddressBookEntriesInputDTO,
 };
 use uuid::Uuid;
 
@@ -30,6 +32,15 @@ impl AddressBookMapper {
             last_modification_timestamp: timestamp_to_rfc3339(
                 &address_book_entry.last_modification_timestamp,
             ),
+            origin: address_book_entry
+                .origin
+                .map(|origin| AddressBookEntryOriginDTO {
+                    source_id: origin.source_id,
+                    source_entry_id: Uuid::from_bytes(origin.source_entry_id)
+                        .hyphenated()
+                        .to_string(),
+                    synced_at: timestamp_to_rfc3339(&origin.synced_at),
+                }),
         }
     }
 
@@ -44,11 +55,40 @@ impl AddressBookMapper {
             blockchain: input.blockchain,
             labels: input.labels,
             metadata: input.metadata.into(),
+            origin: None,
             last_modification_timestamp: next_time(),
         };
 
         Ok(new_entry)
     }
+
+    /// Maps an entry published by an address book source to an entry of this station.
+    pub fn from_published_dto(
+        dto: AddressBookEntryDTO,
+        entry_id: UUID,
+        source_id: Principal,
+        synced_at: Timestamp,
+    ) -> Result<AddressBookEntry, MapperError> {
+        Ok(AddressBookEntry {
+            id: entry_id,
+            address_owner: dto.address_owner,
+            address: dto.address,
+            blockchain: BlockchainMapper::to_blockchain(dto.blockchain)?,
+            labels: dto.labels,
+            metadata: dto
+                .metadata
+                .into_iter()
+                .map(MetadataItem::from)
+                .collect::<Vec<_>>()
+                .into(),
+            origin: Some(AddressBookEntryOrigin {
+                source_id,
+                source_entry_id: *HelperMapper::to_uuid(dto.id)?.as_bytes(),
+                synced_at,
+            }),
+            last_modification_timestamp: synced_at,
+        })
+    }
 }
 
 impl AddressBookEntry {
@@ -65,6 +105,8 @@ impl From<ListAddressBookEntriesInputDTO> for ListAddressBookEntriesInput {
             }),
             labels: input.labels,
             addresses: input.addresses,
+            search_term: input.search_term,
+            address_owners: input.address_owners,
             ids: input.ids.map(|ids| {
                 ids.into_iter()
                     .map(|id| {
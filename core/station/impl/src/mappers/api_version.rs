@@ -0,0 +1,144 @@
+//! Translates the responses of the station to the shape known by clients that are pinned to an older
+//! version of the station API.
+//!
+//! Records can gain optional fields without breaking older clients, since unknown fields are skipped
+//! when decoding, but a client fails to decode the whole response if it contains an enum variant that
+//! was added after its version. Such entries are therefore left out of the responses for older clients.
+
+use crate::{
+    errors::RequestError,
+    models::{Request, RequestOperation, RequestOperationType},
+};
+use orbit_essentials::api::ServiceResult;
+use station_api::{RequestDTO, STATION_API_VERSION};
+use uuid::Uuid;
+
+#[derive(Default, Clone, Debug)]
+pub struct ApiVersionMapper {}
+
+impl ApiVersionMapper {
+    /// The oldest version of the API that the station can still serve.
+    pub const MIN_API_VERSION: u32 = 1;
+
+    /// Resolves the API version requested by the client, which defaults to the current version.
+    ///
+    /// Clients that are newer than the station are served with the current version.
+    pub fn resolve(api_version: Option<u32>) -> ServiceResult<u32, RequestError> {
+        match api_version {
+            None => Ok(STATION_API_VERSION),
+            Some(api_version) if api_version < Self::MIN_API_VERSION => {
+                Err(RequestError::UnsupportedApiVersion {
+                    api_version,
+                    min_api_version: Self::MIN_API_VERSION,
+                })
+            }
+            Some(api_version) => Ok(api_version.min(STATION_API_VERSION)),
+        }
+    }
+
+    /// Returns the API version in which the operation type was added.
+    pub fn operation_type_api_version(operation_type: &RequestOperationType) -> u32 {
+        match operation_type {
+            RequestOperationType::Transfer
+            | RequestOperationType::AddAccount
+            | RequestOperationType::EditAccount
+            | RequestOperationType::AddUser
+            | RequestOperationType::EditUser
+            | RequestOperationType::AddUserGroup
+            | RequestOperationType::EditUserGroup
+            | RequestOperationType::RemoveUserGroup
+            | RequestOperationType::SystemUpgrade
+            | RequestOperationType::EditPermission
+            | RequestOperationType::AddRequestPolicy
+            | RequestOperationType::EditRequestPolicy
+            | RequestOperationType::RemoveRequestPolicy
+            | RequestOperationType::AddAddressBookEntry
+            | RequestOperationType::EditAddressBookEntry
+            | RequestOperationType::RemoveAddressBookEntry
+            | RequestOperationType::ManageSystemInfo
+            | RequestOperationType::ChangeExternalCanister
+            | RequestOperationType::CreateExternalCanister
+            | RequestOperationType::CallExternalCanister
+            | RequestOperationType::SetDisasterRecovery
+            | RequestOperationType::ConfigureExternalCanister
+            | RequestOperationType::FundExternalCanister => 1,
+            RequestOperationType::CommitAssetBatch
+            | RequestOperationType::SnapshotExternalCanister => 2,
+            RequestOperationType::RotateAccountAddress => 3,
+            RequestOperationType::AddNetwork | RequestOperationType::EditNetwork => 4,
+            RequestOperationType::DecommissionStation => 5,
+            RequestOperationType::TransferAccountOwnership => 6,
+            RequestOperationType::FreezeBlockchain | RequestOperationType::UnfreezeBlockchain => 7,
+            RequestOperationType::BulkEditPermission => 8,
+        }
+    }
+
+    /// Checks if the operation can be decoded by clients pinned to the given API version.
+    pub fn supports_operation(api_version: u32, operation: &RequestOperation) -> bool {
+        Self::operation_type_api_version(&RequestOperationType::from(operation.clone()))
+            <= api_version
+    }
+
+    /// Maps the request to its DTO, failing if the client can't decode its operation.
+    pub fn to_request_dto(request: Request, api_version: u32) -> ServiceResult<RequestDTO> {
+        let required_api_version = Self::operation_type_api_version(&RequestOperationType::from(
+            request.operation.clone(),
+        ));
+
+        if required_api_version > api_version {
+            Err(RequestError::OperationNotSupportedByApiVersion {
+                request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+                api_version,
+                required_api_version,
+            })?
+        }
+
+        Ok(request.to_dto())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        request_test_utils::mock_request, CommitAssetBatchOperation, CommitAssetBatchOperationInput,
+    };
+    use candid::{Nat, Principal};
+
+    #[test]
+    fn resolves_the_requested_api_version() {
+        assert_eq!(
+            ApiVersionMapper::resolve(None).unwrap(),
+            STATION_API_VERSION
+        );
+        assert_eq!(ApiVersionMapper::resolve(Some(1)).unwrap(), 1);
+        assert_eq!(
+            ApiVersionMapper::resolve(Some(STATION_API_VERSION + 1)).unwrap(),
+            STATION_API_VERSION
+        );
+        assert!(matches!(
+            ApiVersionMapper::resolve(Some(0)),
+            Err(RequestError::UnsupportedApiVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn older_clients_cannot_receive_newer_operations() {
+        let transfer = mock_request();
+        assert!(ApiVersionMapper::to_request_dto(transfer, 1).is_ok());
+
+        let mut commit = mock_request();
+        commit.operation = RequestOperation::CommitAssetBatch(CommitAssetBatchOperation {
+            input: CommitAssetBatchOperationInput {
+                canister_id: Principal::from_slice(&[1; 29]),
+                batch_id: Nat::from(1u64),
+                evidence: vec![0; 32],
+            },
+            evidence_rendering: None,
+        });
+
+        assert!(!ApiVersionMapper::supports_operation(1, &commit.operation));
+        assert!(ApiVersionMapper::to_request_dto(commit.clone(), 1).is_err());
+        assert!(ApiVersionMapper::to_request_dto(commit, STATION_API_VERSION).is_ok());
+    }
+}
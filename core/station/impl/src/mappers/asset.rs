@@ -7,7 +7,10 @@ impl From<Asset> for station_api::AssetDTO {
             symbol: asset.symbol.to_string(),
             standard: asset.standard.to_string(),
             name: asset.name,
+            decimals: asset.decimals,
+            display_decimals: asset.display_decimals,
             metadata: asset.metadata.into_vec_dto(),
+            logo_id: None,
         }
     }
 }
@@ -9,7 +9,7 @@ use crate::{
             RequestResourceAction, Resource, ResourceAction, ResourceId, SystemResourceAction,
             UserResourceAction,
         },
-        CanisterMethod, Transfer,
+        CanisterMethod, CommitAssetBatchOperationInput, Transfer,
     },
     repositories::TRANSFER_REPOSITORY,
 };
@@ -97,6 +97,46 @@ impl From<&station_api::ListAccountTransfersInput> for Resource {
     }
 }
 
+impl From<&station_api::ListAccountTransactionsInput> for Resource {
+    fn from(input: &station_api::ListAccountTransactionsInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::GeneratePaymentRequestInput> for Resource {
+    fn from(input: &station_api::GeneratePaymentRequestInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::GetBalanceHistoryInput> for Resource {
+    fn from(input: &station_api::GetBalanceHistoryInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::SetAccountReviewInput> for Resource {
+    fn from(input: &station_api::SetAccountReviewInput) -> Self {
+        Resource::Account(AccountResourceAction::Review(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetUserInput> for Resource {
     fn from(input: &station_api::GetUserInput) -> Self {
         Resource::User(UserResourceAction::Read(ResourceId::Id(
@@ -117,6 +157,26 @@ impl From<&station_api::GetRequestInput> for Resource {
     }
 }
 
+impl From<&station_api::GetVoteCertificateInput> for Resource {
+    fn from(input: &station_api::GetVoteCertificateInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::GetRequestCanonicalInput> for Resource {
+    fn from(input: &station_api::GetRequestCanonicalInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetRequestPolicyInput> for Resource {
     fn from(input: &station_api::GetRequestPolicyInput) -> Self {
         Resource::RequestPolicy(ResourceAction::Read(ResourceId::Id(
@@ -147,6 +207,36 @@ impl From<&station_api::SubmitRequestApprovalInput> for Resource {
     }
 }
 
+impl From<&station_api::RetryRequestExecutionInput> for Resource {
+    fn from(input: &station_api::RetryRequestExecutionInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::RescheduleRequestInput> for Resource {
+    fn from(input: &station_api::RescheduleRequestInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::CancelTimeLockedRequestInput> for Resource {
+    fn from(input: &station_api::CancelTimeLockedRequestInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetAddressBookEntryInputDTO> for Resource {
     fn from(input: &station_api::GetAddressBookEntryInputDTO) -> Self {
         Resource::AddressBook(ResourceAction::Read(ResourceId::Id(
@@ -176,6 +266,20 @@ impl From<&station_api::CreateRequestInput> for Resource {
                         .as_bytes(),
                 )))
             }
+            RequestOperationInput::RotateAccountAddress(input) => {
+                Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::TransferAccountOwnership(input) => {
+                Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
             RequestOperationInput::AddAddressBookEntry(_) => {
                 Resource::AddressBook(ResourceAction::Create)
             }
@@ -238,6 +342,12 @@ impl From<&station_api::CreateRequestInput> for Resource {
                     ExternalCanisterId::Canister(input.canister_id),
                 ))
             }
+            // Snapshots of external canisters share the same `Change` action privilege
+            RequestOperationInput::SnapshotExternalCanister(input) => {
+                Resource::ExternalCanister(ExternalCanisterResourceAction::Change(
+                    ExternalCanisterId::Canister(input.canister_id),
+                ))
+            }
             RequestOperationInput::FundExternalCanister(input) => {
                 Resource::ExternalCanister(ExternalCanisterResourceAction::Fund(
                     ExternalCanisterId::Canister(input.canister_id),
@@ -257,7 +367,18 @@ impl From<&station_api::CreateRequestInput> for Resource {
                     },
                 ))
             }
-            RequestOperationInput::EditPermission(_) => {
+            // Committing an asset batch shares the privilege of calling its method pair
+            RequestOperationInput::CommitAssetBatch(input) => {
+                let input: CommitAssetBatchOperationInput = input.clone().into();
+                Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
+                    CallExternalCanisterResourceTarget {
+                        validation_method: Some(input.validation_method()).into(),
+                        execution_method: input.execution_method().into(),
+                    },
+                ))
+            }
+            RequestOperationInput::EditPermission(_)
+            | RequestOperationInput::BulkEditPermission(_) => {
                 Resource::Permission(PermissionResourceAction::Update)
             }
             RequestOperationInput::AddRequestPolicy(_) => {
@@ -277,7 +398,12 @@ impl From<&station_api::CreateRequestInput> for Resource {
                         .as_bytes(),
                 )))
             }
-            RequestOperationInput::ManageSystemInfo(_) => {
+            RequestOperationInput::ManageSystemInfo(_)
+            | RequestOperationInput::AddNetwork(_)
+            | RequestOperationInput::EditNetwork(_)
+            | RequestOperationInput::FreezeBlockchain(_)
+            | RequestOperationInput::UnfreezeBlockchain(_)
+            | RequestOperationInput::DecommissionStation(_) => {
                 Resource::System(SystemResourceAction::ManageSystemInfo)
             }
         }
@@ -350,6 +476,24 @@ impl GetTransfersInputRef<'_> {
     }
 }
 
+pub(crate) struct AnnotateTransferInputRef<'a>(pub &'a station_api::AnnotateTransferInput);
+
+impl AnnotateTransferInputRef<'_> {
+    pub fn to_resources(&self) -> Vec<Resource> {
+        let transfer_id = *HelperMapper::to_uuid(self.0.transfer_id.to_owned())
+            .expect("Invalid transfer id")
+            .as_bytes();
+
+        let transfer = TRANSFER_REPOSITORY
+            .get(&Transfer::key(transfer_id))
+            .unwrap_or_else(|| trap("Failed to unwrap transfer input"));
+
+        vec![Resource::Account(AccountResourceAction::Reconcile(
+            ResourceId::Id(transfer.from_account),
+        ))]
+    }
+}
+
 pub(crate) struct MarkNotificationsReadInputRef<'a>(
     pub &'a station_api::MarkNotificationsReadInput,
 );
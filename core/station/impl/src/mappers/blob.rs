@@ -0,0 +1,18 @@
+use crate::models::Blob;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::BlobDTO;
+use uuid::Uuid;
+
+impl From<Blob> for BlobDTO {
+    fn from(blob: Blob) -> Self {
+        BlobDTO {
+            http_path: blob.http_path(),
+            id: Uuid::from_bytes(blob.id).hyphenated().to_string(),
+            content_type: blob.content_type,
+            size_bytes: blob.content.len() as u64,
+            sha256: hex::encode(blob.sha256),
+            uploaded_by: Uuid::from_bytes(blob.uploaded_by).hyphenated().to_string(),
+            created_at: timestamp_to_rfc3339(&blob.created_at),
+        }
+    }
+}
@@ -1,19 +1,19 @@
 use crate::{
     core::ic_cdk::next_time,
     models::{
-        ConfigureExternalCanisterOperationInput, ConfigureExternalCanisterOperationKind,
+        ConfigureExternalCanisterOperation, ConfigureExternalCanisterOperationKind,
         ConfigureExternalCanisterSettingsInput, CreateExternalCanisterOperationInput,
         DefiniteCanisterSettingsInput, ExternalCanister, ExternalCanisterCallRequestPolicyRule,
         ExternalCanisterCallerMethodsPrivileges, ExternalCanisterCallerPrivileges,
-        ExternalCanisterChangeRequestPolicyRule, ExternalCanisterPermissions,
-        ExternalCanisterRequestPolicies, ExternalCanisterState, FundExternalCanisterOperation,
-        FundExternalCanisterOperationInput, FundExternalCanisterOperationKind,
-        FundExternalCanisterSendCyclesInput,
+        ExternalCanisterChangeRequestPolicyRule, ExternalCanisterLogRecord,
+        ExternalCanisterPermissions, ExternalCanisterRequestPolicies, ExternalCanisterSnapshot,
+        ExternalCanisterState, FundExternalCanisterOperation, FundExternalCanisterOperationInput,
+        FundExternalCanisterOperationKind, FundExternalCanisterSendCyclesInput, LogVisibility,
+        SnapshotExternalCanisterOperation, SnapshotExternalCanisterOperationKind,
     },
     repositories::ExternalCanisterWhereClauseSort,
 };
 use candid::Principal;
-use ic_cdk::api::management_canister::main::CanisterSettings;
 use orbit_essentials::{repository::SortDirection, utils::timestamp_to_rfc3339};
 use station_api::ExternalCanisterDTO;
 use uuid::Uuid;
@@ -33,6 +33,9 @@ impl ExternalCanisterMapper {
             description: input.description.clone(),
             labels: input.labels.clone().unwrap_or_default(),
             state: ExternalCanisterState::Active,
+            repository_url: input.repository_url.clone(),
+            module_hash: None,
+            snapshots: Vec::new(),
             created_at: next_time(),
             modified_at: None,
         }
@@ -54,6 +57,9 @@ impl ExternalCanister {
             state: self.state.into(),
             permissions: permissions.into(),
             request_policies: policies.into(),
+            repository_url: self.repository_url,
+            module_hash: self.module_hash.map(hex::encode),
+            snapshots: self.snapshots.into_iter().map(Into::into).collect(),
             created_at: timestamp_to_rfc3339(&self.created_at),
             modified_at: self.modified_at.map(|ts| timestamp_to_rfc3339(&ts)),
         }
@@ -141,25 +147,14 @@ impl From<ExternalCanisterCallerMethodsPrivileges>
     }
 }
 
-impl From<DefiniteCanisterSettingsInput> for CanisterSettings {
-    fn from(input: DefiniteCanisterSettingsInput) -> Self {
-        CanisterSettings {
-            controllers: input.controllers,
-            compute_allocation: input.compute_allocation,
-            freezing_threshold: input.freezing_threshold,
-            memory_allocation: input.memory_allocation,
-            reserved_cycles_limit: input.reserved_cycles_limit,
-        }
-    }
-}
-
 impl From<station_api::ConfigureExternalCanisterOperationInput>
-    for ConfigureExternalCanisterOperationInput
+    for ConfigureExternalCanisterOperation
 {
     fn from(input: station_api::ConfigureExternalCanisterOperationInput) -> Self {
-        ConfigureExternalCanisterOperationInput {
+        ConfigureExternalCanisterOperation {
             canister_id: input.canister_id,
             kind: input.kind.into(),
+            native_settings_diff: None,
         }
     }
 }
@@ -193,6 +188,31 @@ impl From<station_api::DefiniteCanisterSettingsInput> for DefiniteCanisterSettin
             freezing_threshold: input.freezing_threshold,
             memory_allocation: input.memory_allocation,
             reserved_cycles_limit: input.reserved_cycles_limit,
+            log_visibility: input.log_visibility.map(Into::into),
+        }
+    }
+}
+
+impl From<station_api::LogVisibilityDTO> for LogVisibility {
+    fn from(input: station_api::LogVisibilityDTO) -> Self {
+        match input {
+            station_api::LogVisibilityDTO::Controllers => LogVisibility::Controllers,
+            station_api::LogVisibilityDTO::Public => LogVisibility::Public,
+            station_api::LogVisibilityDTO::AllowedViewers(viewers) => {
+                LogVisibility::AllowedViewers(viewers)
+            }
+        }
+    }
+}
+
+impl From<LogVisibility> for station_api::LogVisibilityDTO {
+    fn from(input: LogVisibility) -> Self {
+        match input {
+            LogVisibility::Controllers => station_api::LogVisibilityDTO::Controllers,
+            LogVisibility::Public => station_api::LogVisibilityDTO::Public,
+            LogVisibility::AllowedViewers(viewers) => {
+                station_api::LogVisibilityDTO::AllowedViewers(viewers)
+            }
         }
     }
 }
@@ -208,6 +228,7 @@ impl From<station_api::ConfigureExternalCanisterSettingsInput>
             state: input.state.map(Into::into),
             permissions: input.permissions.map(Into::into),
             request_policies: input.request_policies.map(Into::into),
+            repository_url: input.repository_url,
         }
     }
 }
@@ -287,3 +308,58 @@ impl From<station_api::FundExternalCanisterSendCyclesInput>
         }
     }
 }
+
+impl From<ExternalCanisterSnapshot> for station_api::CanisterSnapshotDTO {
+    fn from(snapshot: ExternalCanisterSnapshot) -> Self {
+        station_api::CanisterSnapshotDTO {
+            snapshot_id: hex::encode(snapshot.snapshot_id),
+            module_hash: snapshot.module_hash.map(hex::encode),
+            total_size: snapshot.total_size,
+            taken_at: timestamp_to_rfc3339(&snapshot.taken_at),
+        }
+    }
+}
+
+impl From<ExternalCanisterLogRecord> for station_api::CanisterLogRecordDTO {
+    fn from(record: ExternalCanisterLogRecord) -> Self {
+        station_api::CanisterLogRecordDTO {
+            idx: record.idx,
+            timestamp: timestamp_to_rfc3339(&record.timestamp),
+            content: String::from_utf8_lossy(&record.content).into_owned(),
+        }
+    }
+}
+
+impl From<SnapshotExternalCanisterOperationKind>
+    for station_api::SnapshotExternalCanisterOperationKindDTO
+{
+    fn from(kind: SnapshotExternalCanisterOperationKind) -> Self {
+        match kind {
+            SnapshotExternalCanisterOperationKind::Take { replace_snapshot } => {
+                station_api::SnapshotExternalCanisterOperationKindDTO::Take {
+                    replace_snapshot: replace_snapshot.map(hex::encode),
+                }
+            }
+            SnapshotExternalCanisterOperationKind::Load { snapshot_id } => {
+                station_api::SnapshotExternalCanisterOperationKindDTO::Load {
+                    snapshot_id: hex::encode(snapshot_id),
+                }
+            }
+            SnapshotExternalCanisterOperationKind::Delete { snapshot_id } => {
+                station_api::SnapshotExternalCanisterOperationKindDTO::Delete {
+                    snapshot_id: hex::encode(snapshot_id),
+                }
+            }
+        }
+    }
+}
+
+impl From<SnapshotExternalCanisterOperation> for station_api::SnapshotExternalCanisterOperationDTO {
+    fn from(operation: SnapshotExternalCanisterOperation) -> Self {
+        station_api::SnapshotExternalCanisterOperationDTO {
+            canister_id: operation.input.canister_id,
+            kind: operation.input.kind.into(),
+            snapshot_id: operation.snapshot_id.map(hex::encode),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::errors::MapperError;
 use candid::Nat;
+use orbit_essentials::{types::Timestamp, utils::try_rfc3339_to_timestamp};
 use std::str::FromStr;
 use uuid::Uuid;
 
@@ -15,6 +16,25 @@ impl HelperMapper {
         Ok(uuid)
     }
 
+    /// Converts the RFC3339 datetime of the given input field to a timestamp.
+    pub fn to_timestamp(field: &str, input: &str) -> Result<Timestamp, MapperError> {
+        try_rfc3339_to_timestamp(input).map_err(|err| MapperError::MalformedTimestamp {
+            field: field.to_string(),
+            timestamp: input.to_string(),
+            reason: err.to_string(),
+        })
+    }
+
+    /// Converts the optional RFC3339 datetime of the given input field to a timestamp.
+    pub fn to_optional_timestamp(
+        field: &str,
+        input: Option<String>,
+    ) -> Result<Option<Timestamp>, MapperError> {
+        input
+            .map(|input| Self::to_timestamp(field, &input))
+            .transpose()
+    }
+
     pub fn to_u64(input: &str) -> Result<u64, MapperError> {
         input
             .parse::<u64>()
@@ -46,6 +66,31 @@ mod tests {
         assert_eq!(result.unwrap(), uuid);
     }
 
+    #[test]
+    fn test_to_timestamp() {
+        assert_eq!(
+            HelperMapper::to_timestamp("from_dt", "2024-03-19T10:12:24.77Z").unwrap(),
+            1_710_843_144_770_000_000
+        );
+        assert_eq!(
+            HelperMapper::to_optional_timestamp("from_dt", None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_timestamp_rejects_malformed_input() {
+        for input in ["", "not a date", "2024-03-19", "1969-12-31T23:59:59Z"] {
+            let err = HelperMapper::to_timestamp("to_dt", input).unwrap_err();
+
+            assert!(matches!(
+                err,
+                MapperError::MalformedTimestamp { ref field, ref timestamp, .. }
+                    if field == "to_dt" && timestamp == input
+            ));
+        }
+    }
+
     #[test]
     fn test_to_u64() {
         let input = "100";
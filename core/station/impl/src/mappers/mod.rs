@@ -26,6 +26,8 @@ pub mod request_operation;
 
 mod request;
 
+pub mod request_canonical;
+
 mod request_status;
 pub use request_status::*;
 
@@ -33,6 +35,8 @@ mod request_approval_status;
 
 mod request_approval;
 
+mod vote_receipt;
+
 mod helper;
 pub use helper::*;
 
@@ -49,6 +53,8 @@ mod user_status;
 mod transfer;
 pub use transfer::*;
 
+mod network;
+
 mod transfer_status;
 
 pub mod permission;
@@ -60,3 +66,11 @@ pub mod resource;
 pub mod authorization;
 
 pub mod rate_limiter;
+
+pub mod station_event;
+
+pub mod api_version;
+
+mod principal_label;
+
+mod blob;
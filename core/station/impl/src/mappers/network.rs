@@ -0,0 +1,152 @@
+use super::blockchain::BlockchainMapper;
+use crate::{
+    core::read_system_info,
+    models::{
+        AddNetworkOperation, AddNetworkOperationInput, Blockchain, EditNetworkOperation,
+        EditNetworkOperationInput, FreezeBlockchainOperation, FreezeBlockchainOperationInput,
+        Network, UnfreezeBlockchainOperation, UnfreezeBlockchainOperationInput,
+    },
+};
+use station_api::{
+    AddNetworkOperationDTO, BlockchainNetworkDTO, EditNetworkOperationDTO,
+    FreezeBlockchainOperationDTO, NetworkDTO, UnfreezeBlockchainOperationDTO,
+};
+
+impl Network {
+    /// Returns the network of the blockchain with the given id, or a network named after the id if the
+    /// station doesn't know it.
+    pub fn resolve(blockchain: &Blockchain, id: &str) -> Network {
+        read_system_info()
+            .find_network(blockchain, id)
+            .unwrap_or_else(|| Network {
+                id: id.to_string(),
+                blockchain: blockchain.clone(),
+                name: id.to_string(),
+                chain_id: None,
+                is_testnet: false,
+            })
+    }
+}
+
+impl From<Network> for NetworkDTO {
+    fn from(network: Network) -> Self {
+        NetworkDTO {
+            id: network.id,
+            name: network.name,
+        }
+    }
+}
+
+impl From<Network> for BlockchainNetworkDTO {
+    fn from(network: Network) -> Self {
+        BlockchainNetworkDTO {
+            id: network.id,
+            blockchain: network.blockchain.to_string(),
+            name: network.name,
+            chain_id: network.chain_id,
+            is_testnet: network.is_testnet,
+        }
+    }
+}
+
+impl From<station_api::AddNetworkOperationInput> for AddNetworkOperationInput {
+    fn from(input: station_api::AddNetworkOperationInput) -> Self {
+        AddNetworkOperationInput {
+            network: Network {
+                id: input.id,
+                blockchain: BlockchainMapper::to_blockchain(input.blockchain)
+                    .expect("Invalid blockchain"),
+                name: input.name,
+                chain_id: input.chain_id,
+                is_testnet: input.is_testnet,
+            },
+        }
+    }
+}
+
+impl From<AddNetworkOperation> for AddNetworkOperationDTO {
+    fn from(operation: AddNetworkOperation) -> Self {
+        let network = operation.input.network;
+
+        AddNetworkOperationDTO {
+            input: station_api::AddNetworkOperationInput {
+                id: network.id,
+                blockchain: network.blockchain.to_string(),
+                name: network.name,
+                chain_id: network.chain_id,
+                is_testnet: network.is_testnet,
+            },
+        }
+    }
+}
+
+impl From<station_api::EditNetworkOperationInput> for EditNetworkOperationInput {
+    fn from(input: station_api::EditNetworkOperationInput) -> Self {
+        EditNetworkOperationInput {
+            id: input.id,
+            blockchain: BlockchainMapper::to_blockchain(input.blockchain)
+                .expect("Invalid blockchain"),
+            name: input.name,
+            chain_id: input.chain_id,
+            is_testnet: input.is_testnet,
+        }
+    }
+}
+
+impl From<EditNetworkOperation> for EditNetworkOperationDTO {
+    fn from(operation: EditNetworkOperation) -> Self {
+        EditNetworkOperationDTO {
+            input: station_api::EditNetworkOperationInput {
+                id: operation.input.id,
+                blockchain: operation.input.blockchain.to_string(),
+                name: operation.input.name,
+                chain_id: operation.input.chain_id,
+                is_testnet: operation.input.is_testnet,
+            },
+        }
+    }
+}
+
+impl From<station_api::FreezeBlockchainOperationInput> for FreezeBlockchainOperationInput {
+    fn from(input: station_api::FreezeBlockchainOperationInput) -> Self {
+        FreezeBlockchainOperationInput {
+            blockchain: BlockchainMapper::to_blockchain(input.blockchain)
+                .expect("Invalid blockchain"),
+            network: input.network,
+            reason: input.reason,
+        }
+    }
+}
+
+impl From<FreezeBlockchainOperation> for FreezeBlockchainOperationDTO {
+    fn from(operation: FreezeBlockchainOperation) -> Self {
+        FreezeBlockchainOperationDTO {
+            input: station_api::FreezeBlockchainOperationInput {
+                blockchain: operation.input.blockchain.to_string(),
+                network: operation.input.network,
+                reason: operation.input.reason,
+            },
+        }
+    }
+}
+
+impl From<station_api::UnfreezeBlockchainOperationInput> for UnfreezeBlockchainOperationInput {
+    fn from(input: station_api::UnfreezeBlockchainOperationInput) -> Self {
+        UnfreezeBlockchainOperationInput {
+            blockchain: BlockchainMapper::to_blockchain(input.blockchain)
+                .expect("Invalid blockchain"),
+            network: input.network,
+        }
+    }
+}
+
+impl From<UnfreezeBlockchainOperation> for UnfreezeBlockchainOperationDTO {
+    fn from(operation: UnfreezeBlockchainOperation) -> Self {
+        UnfreezeBlockchainOperationDTO {
+            input: station_api::UnfreezeBlockchainOperationInput {
+                blockchain: operation.input.blockchain.to_string(),
+                network: operation.input.network,
+            },
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use crate::models::{Notification, RequestStatusCode};
+use crate::models::{Notification, NotificationBridge, RequestStatusCode};
 use orbit_essentials::{types::UUID, utils::timestamp_to_rfc3339};
-use station_api::NotificationDTO;
+use station_api::{NotificationBridgeDTO, NotificationDTO};
 use uuid::Uuid;
 
 pub enum NotificationMapperError {
@@ -29,3 +29,19 @@ impl TryFrom<Notification> for NotificationDTO {
         })
     }
 }
+
+impl NotificationBridge {
+    pub fn to_dto(self, pending_notifications: usize) -> NotificationBridgeDTO {
+        NotificationBridgeDTO {
+            inbox_canister: self.inbox_canister,
+            identity: self.identity,
+            created_at: timestamp_to_rfc3339(&self.created_at),
+            pending_notifications: pending_notifications as u64,
+            delivered_notifications: self.delivered_notifications,
+            last_delivered_at: self
+                .last_delivered_at
+                .map(|timestamp| timestamp_to_rfc3339(&timestamp)),
+            last_error: self.last_error,
+        }
+    }
+}
@@ -1,13 +1,20 @@
-use crate::models::{RequestOperation, RequestOperationType, RequestStatus, RequestStatusCode};
+use crate::models::{
+    AccountOwner, RequestOperation, RequestOperationType, RequestStatus, RequestStatusCode,
+    UserActivityAnomaly,
+};
 use crate::repositories::REQUEST_EVALUATION_RESULT_REPOSITORY;
 use crate::{
-    models::{NotificationType, Request},
-    repositories::REQUEST_REPOSITORY,
+    models::{Account, NotificationType, Request},
+    repositories::{ACCOUNT_REPOSITORY, REQUEST_REPOSITORY},
 };
-use orbit_essentials::repository::Repository;
+use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
 use station_api::{
-    NotificationTypeDTO, RequestCreatedNotificationDTO, RequestFailedNotificationDTO,
-    RequestRejectedNotificationDTO,
+    AccountAddressReadyNotificationDTO, BlockchainFreezeChangedNotificationDTO,
+    DepositDetectedNotificationDTO, ExternalCanisterModuleDriftedNotificationDTO,
+    JobsStalledNotificationDTO, NotificationTypeDTO, RequestApprovalReminderNotificationDTO,
+    RequestCreatedNotificationDTO, RequestExecutedNotificationDTO, RequestFailedNotificationDTO,
+    RequestPolicyChangedNotificationDTO, RequestRejectedNotificationDTO, UserActivityAnomalyDTO,
+    UserActivityAnomalyNotificationDTO, UserGroupMembershipChangedNotificationDTO,
 };
 use uuid::Uuid;
 
@@ -73,6 +80,12 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                 let account_id = match &request.operation {
                     RequestOperation::Transfer(operation) => Some(operation.input.from_account_id),
                     RequestOperation::EditAccount(operation) => Some(operation.input.account_id),
+                    RequestOperation::RotateAccountAddress(operation) => {
+                        Some(operation.input.account_id)
+                    }
+                    RequestOperation::TransferAccountOwnership(operation) => {
+                        Some(operation.input.account_id)
+                    }
                     RequestOperation::AddAccount(_)
                     | RequestOperation::AddAddressBookEntry(_)
                     | RequestOperation::EditAddressBookEntry(_)
@@ -82,6 +95,7 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::AddUser(_)
                     | RequestOperation::AddUserGroup(_)
                     | RequestOperation::EditPermission(_)
+                    | RequestOperation::BulkEditPermission(_)
                     | RequestOperation::EditRequestPolicy(_)
                     | RequestOperation::EditUserGroup(_)
                     | RequestOperation::RemoveRequestPolicy(_)
@@ -93,18 +107,33 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::ConfigureExternalCanister(_)
                     | RequestOperation::CreateExternalCanister(_)
                     | RequestOperation::FundExternalCanister(_)
-                    | RequestOperation::CallExternalCanister(_) => None,
+                    | RequestOperation::CallExternalCanister(_)
+                    | RequestOperation::CommitAssetBatch(_)
+                    | RequestOperation::SnapshotExternalCanister(_)
+                    | RequestOperation::AddNetwork(_)
+                    | RequestOperation::EditNetwork(_)
+                    | RequestOperation::FreezeBlockchain(_)
+                    | RequestOperation::UnfreezeBlockchain(_)
+                    | RequestOperation::DecommissionStation(_) => None,
                 };
 
                 let user_id: Option<[u8; 16]> = match &request.operation {
                     RequestOperation::EditUser(operation) => Some(operation.input.user_id),
+                    RequestOperation::TransferAccountOwnership(operation) => {
+                        match operation.input.to {
+                            AccountOwner::User(user_id) => Some(user_id),
+                            AccountOwner::UserGroup(_) => None,
+                        }
+                    }
                     RequestOperation::AddAccount(_)
                     | RequestOperation::AddAddressBookEntry(_)
                     | RequestOperation::AddRequestPolicy(_)
                     | RequestOperation::AddUser(_)
                     | RequestOperation::AddUserGroup(_)
                     | RequestOperation::EditPermission(_)
+                    | RequestOperation::BulkEditPermission(_)
                     | RequestOperation::EditAccount(_)
+                    | RequestOperation::RotateAccountAddress(_)
                     | RequestOperation::EditAddressBookEntry(_)
                     | RequestOperation::RemoveAddressBookEntry(_)
                     | RequestOperation::EditRequestPolicy(_)
@@ -119,7 +148,14 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::ConfigureExternalCanister(_)
                     | RequestOperation::CreateExternalCanister(_)
                     | RequestOperation::FundExternalCanister(_)
-                    | RequestOperation::CallExternalCanister(_) => None,
+                    | RequestOperation::CallExternalCanister(_)
+                    | RequestOperation::CommitAssetBatch(_)
+                    | RequestOperation::SnapshotExternalCanister(_)
+                    | RequestOperation::AddNetwork(_)
+                    | RequestOperation::EditNetwork(_)
+                    | RequestOperation::FreezeBlockchain(_)
+                    | RequestOperation::UnfreezeBlockchain(_)
+                    | RequestOperation::DecommissionStation(_) => None,
                 };
 
                 NotificationTypeDTO::RequestCreated(RequestCreatedNotificationDTO {
@@ -129,6 +165,138 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     user_id: user_id.map(|id| Uuid::from_bytes(id).to_string()),
                 })
             }
+            NotificationType::ExternalCanisterModuleDrifted(ctx) => {
+                NotificationTypeDTO::ExternalCanisterModuleDrifted(
+                    ExternalCanisterModuleDriftedNotificationDTO {
+                        external_canister_id: Uuid::from_bytes(ctx.external_canister_id)
+                            .to_string(),
+                        canister_id: ctx.canister_id,
+                        expected_module_hash: ctx.expected_module_hash.map(hex::encode),
+                        found_module_hash: ctx.found_module_hash.map(hex::encode),
+                    },
+                )
+            }
+            NotificationType::UserActivityAnomaly(ctx) => {
+                NotificationTypeDTO::UserActivityAnomaly(UserActivityAnomalyNotificationDTO {
+                    user_id: Uuid::from_bytes(ctx.user_id).to_string(),
+                    anomaly: ctx.anomaly.into(),
+                })
+            }
+            NotificationType::RequestExecuted(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                match request.status {
+                    RequestStatus::Completed { .. } => {
+                        NotificationTypeDTO::RequestExecuted(RequestExecutedNotificationDTO {
+                            request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                            operation_type: RequestOperationType::from(request.operation).into(),
+                        })
+                    }
+                    status => Err(NotificationMapperError::InvalidRequestStatus {
+                        expected: RequestStatusCode::Completed,
+                        found: status.to_type(),
+                    })?,
+                }
+            }
+            NotificationType::DepositDetected(ctx) => {
+                NotificationTypeDTO::DepositDetected(DepositDetectedNotificationDTO {
+                    account_id: Uuid::from_bytes(ctx.account_id).to_string(),
+                    amount: ctx.amount,
+                    balance: ctx.balance,
+                })
+            }
+            NotificationType::AccountAddressReady(ctx) => {
+                NotificationTypeDTO::AccountAddressReady(AccountAddressReadyNotificationDTO {
+                    account_id: Uuid::from_bytes(ctx.account_id).to_string(),
+                    address: ACCOUNT_REPOSITORY
+                        .get(&Account::key(ctx.account_id))
+                        .map(|account| account.address)
+                        .unwrap_or_default(),
+                })
+            }
+            NotificationType::RequestPolicyChanged(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                NotificationTypeDTO::RequestPolicyChanged(RequestPolicyChangedNotificationDTO {
+                    request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                    policy_id: Uuid::from_bytes(ctx.policy_id).to_string(),
+                    operation_type: RequestOperationType::from(request.operation).into(),
+                })
+            }
+            NotificationType::RequestApprovalReminder(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                NotificationTypeDTO::RequestApprovalReminder(
+                    RequestApprovalReminderNotificationDTO {
+                        request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                        expiration_dt: timestamp_to_rfc3339(&request.expiration_dt),
+                        operation_type: RequestOperationType::from(request.operation).into(),
+                    },
+                )
+            }
+            NotificationType::UserGroupMembershipChanged(ctx) => {
+                NotificationTypeDTO::UserGroupMembershipChanged(
+                    UserGroupMembershipChangedNotificationDTO {
+                        user_id: Uuid::from_bytes(ctx.user_id).to_string(),
+                        user_group_id: Uuid::from_bytes(ctx.user_group_id).to_string(),
+                        joined: ctx.joined,
+                    },
+                )
+            }
+            NotificationType::BlockchainFreezeChanged(ctx) => {
+                NotificationTypeDTO::BlockchainFreezeChanged(
+                    BlockchainFreezeChangedNotificationDTO {
+                        request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                        blockchain: ctx.blockchain.to_string(),
+                        network: ctx.network,
+                        frozen: ctx.frozen,
+                    },
+                )
+            }
+            NotificationType::JobsStalled(ctx) => {
+                NotificationTypeDTO::JobsStalled(JobsStalledNotificationDTO {
+                    overdue_jobs: ctx.overdue_jobs,
+                    stalled_executions: ctx
+                        .stalled_executions
+                        .into_iter()
+                        .map(|id| Uuid::from_bytes(id).to_string())
+                        .collect(),
+                    stalled_expirations: ctx
+                        .stalled_expirations
+                        .into_iter()
+                        .map(|id| Uuid::from_bytes(id).to_string())
+                        .collect(),
+                })
+            }
         })
     }
 }
+
+impl From<UserActivityAnomaly> for UserActivityAnomalyDTO {
+    fn from(anomaly: UserActivityAnomaly) -> Self {
+        match anomaly {
+            UserActivityAnomaly::RequestCreationSpike { requests_created } => {
+                UserActivityAnomalyDTO::RequestCreationSpike { requests_created }
+            }
+            UserActivityAnomaly::DormantUserApproval {
+                request_id,
+                last_active_at,
+            } => UserActivityAnomalyDTO::DormantUserApproval {
+                request_id: Uuid::from_bytes(request_id).to_string(),
+                last_active_at: timestamp_to_rfc3339(&last_active_at),
+            },
+        }
+    }
+}
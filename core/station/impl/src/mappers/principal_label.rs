@@ -0,0 +1,28 @@
+use crate::models::{PrincipalLabel, PrincipalLabelSource};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{PrincipalLabelDTO, PrincipalLabelSourceDTO};
+use uuid::Uuid;
+
+impl From<PrincipalLabelSource> for PrincipalLabelSourceDTO {
+    fn from(source: PrincipalLabelSource) -> Self {
+        match source {
+            PrincipalLabelSource::Manual { labeled_by } => PrincipalLabelSourceDTO::Manual {
+                labeled_by: Uuid::from_bytes(labeled_by).hyphenated().to_string(),
+            },
+            PrincipalLabelSource::Imported { list_canister } => {
+                PrincipalLabelSourceDTO::Imported { list_canister }
+            }
+        }
+    }
+}
+
+impl From<PrincipalLabel> for PrincipalLabelDTO {
+    fn from(label: PrincipalLabel) -> Self {
+        PrincipalLabelDTO {
+            address: label.address,
+            label: label.label,
+            source: label.source.into(),
+            last_modification_timestamp: timestamp_to_rfc3339(&label.last_modification_timestamp),
+        }
+    }
+}
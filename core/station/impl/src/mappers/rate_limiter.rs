@@ -7,6 +7,8 @@ impl From<&RequestOperationInput> for RequestRateLimiterSize {
             RequestOperationInput::Transfer(_) => RequestRateLimiterSize(100),
             RequestOperationInput::AddAccount(_) => RequestRateLimiterSize(100),
             RequestOperationInput::EditAccount(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::RotateAccountAddress(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::TransferAccountOwnership(_) => RequestRateLimiterSize(100),
             RequestOperationInput::AddAddressBookEntry(_) => RequestRateLimiterSize(100),
             RequestOperationInput::EditAddressBookEntry(_) => RequestRateLimiterSize(100),
             RequestOperationInput::RemoveAddressBookEntry(_) => RequestRateLimiterSize(100),
@@ -48,11 +50,21 @@ impl From<&RequestOperationInput> for RequestRateLimiterSize {
                 RequestRateLimiterSize(size)
             }
             RequestOperationInput::FundExternalCanister(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::CommitAssetBatch(commit_asset_batch) => {
+                RequestRateLimiterSize(100 + commit_asset_batch.evidence.len() as u64)
+            }
+            RequestOperationInput::SnapshotExternalCanister(_) => RequestRateLimiterSize(100),
             RequestOperationInput::EditPermission(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::BulkEditPermission(_) => RequestRateLimiterSize(100),
             RequestOperationInput::AddRequestPolicy(_) => RequestRateLimiterSize(100),
             RequestOperationInput::EditRequestPolicy(_) => RequestRateLimiterSize(100),
             RequestOperationInput::RemoveRequestPolicy(_) => RequestRateLimiterSize(100),
             RequestOperationInput::ManageSystemInfo(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::AddNetwork(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::EditNetwork(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::FreezeBlockchain(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::UnfreezeBlockchain(_) => RequestRateLimiterSize(100),
+            RequestOperationInput::DecommissionStation(_) => RequestRateLimiterSize(100),
         }
     }
 }
@@ -1,15 +1,23 @@
 use crate::{
     core::ic_cdk::next_time,
+    errors::MapperError,
+    mappers::HelperMapper,
     models::{
-        Request, RequestAdditionalInfo, RequestCallerPrivileges, RequestExecutionPlan,
-        RequestOperation, RequestStatus, UserId,
+        DisplayRequestImpact, Request, RequestAdditionalInfo, RequestCallerPrivileges, RequestCost,
+        RequestExecutionPlan, RequestExecutionStep, RequestExecutionStepStatus, RequestOperation,
+        RequestStatus, RequestSummaryFormat, UserId,
     },
+    repositories::REQUEST_REPOSITORY,
 };
 use orbit_essentials::{
+    repository::Repository,
     types::{Timestamp, UUID},
-    utils::{rfc3339_to_timestamp, timestamp_to_rfc3339},
+    utils::timestamp_to_rfc3339,
+};
+use station_api::{
+    RequestDTO, RequestDependencyDTO, RequestExecutionScheduleDTO, RequestExecutionStepDTO,
+    RequestExecutionStepStatusDTO, RequestSummaryFormatDTO,
 };
-use station_api::{RequestDTO, RequestExecutionScheduleDTO};
 use uuid::Uuid;
 
 impl Request {
@@ -29,13 +37,20 @@ impl Request {
             title,
             operation,
             summary,
+            summary_format: RequestSummaryFormat::PlainText,
             requested_by: requester,
             status: RequestStatus::Created,
             expiration_dt,
             execution_plan,
             approvals: vec![],
+            execution_steps: vec![],
+            confidential: false,
+            compacted_at: None,
+            cost: RequestCost::default(),
             created_timestamp: now,
             last_modification_timestamp: now,
+            depends_on: vec![],
+            policy_snapshot: None,
         }
     }
 
@@ -47,6 +62,7 @@ impl Request {
             operation: self.operation.into(),
             title: self.title,
             summary: self.summary,
+            summary_format: self.summary_format.into(),
             expiration_dt: timestamp_to_rfc3339(&self.expiration_dt),
             execution_plan: self.execution_plan.into(),
             created_at: timestamp_to_rfc3339(&self.created_timestamp),
@@ -55,27 +71,94 @@ impl Request {
                 .iter()
                 .map(|approval| approval.to_owned().into())
                 .collect(),
+            execution_steps: self.execution_steps.into_iter().map(Into::into).collect(),
+            confidential: self.confidential,
+            compacted_at: self
+                .compacted_at
+                .map(|compacted_at| timestamp_to_rfc3339(&compacted_at)),
+            depends_on: self
+                .depends_on
+                .iter()
+                .map(|dependency_id| RequestDependencyDTO {
+                    request_id: Uuid::from_bytes(*dependency_id).hyphenated().to_string(),
+                    status: REQUEST_REPOSITORY
+                        .get(&Request::key(*dependency_id))
+                        .map(|dependency| dependency.status.into()),
+                })
+                .collect(),
         }
     }
 }
 
-impl From<RequestExecutionScheduleDTO> for RequestExecutionPlan {
-    fn from(dto: RequestExecutionScheduleDTO) -> Self {
-        match dto {
-            RequestExecutionScheduleDTO::Immediate => Self::Immediate,
-            RequestExecutionScheduleDTO::Scheduled { execution_time } => Self::Scheduled {
-                execution_time: rfc3339_to_timestamp(&execution_time),
+impl From<RequestSummaryFormat> for RequestSummaryFormatDTO {
+    fn from(format: RequestSummaryFormat) -> Self {
+        match format {
+            RequestSummaryFormat::PlainText => RequestSummaryFormatDTO::PlainText,
+            RequestSummaryFormat::Markdown => RequestSummaryFormatDTO::Markdown,
+        }
+    }
+}
+
+impl From<RequestSummaryFormatDTO> for RequestSummaryFormat {
+    fn from(format: RequestSummaryFormatDTO) -> Self {
+        match format {
+            RequestSummaryFormatDTO::PlainText => RequestSummaryFormat::PlainText,
+            RequestSummaryFormatDTO::Markdown => RequestSummaryFormat::Markdown,
+        }
+    }
+}
+
+impl From<RequestExecutionStep> for RequestExecutionStepDTO {
+    fn from(step: RequestExecutionStep) -> Self {
+        RequestExecutionStepDTO {
+            name: step.name,
+            status: match step.status {
+                RequestExecutionStepStatus::Completed {
+                    output,
+                    completed_at,
+                } => RequestExecutionStepStatusDTO::Completed {
+                    output,
+                    completed_at: timestamp_to_rfc3339(&completed_at),
+                },
+                RequestExecutionStepStatus::Failed { reason, failed_at } => {
+                    RequestExecutionStepStatusDTO::Failed {
+                        reason,
+                        failed_at: timestamp_to_rfc3339(&failed_at),
+                    }
+                }
             },
+            attempts: step.attempts,
         }
     }
 }
 
+impl TryFrom<RequestExecutionScheduleDTO> for RequestExecutionPlan {
+    type Error = MapperError;
+
+    fn try_from(dto: RequestExecutionScheduleDTO) -> Result<Self, Self::Error> {
+        Ok(match dto {
+            RequestExecutionScheduleDTO::Immediate => Self::Immediate,
+            RequestExecutionScheduleDTO::Scheduled {
+                execution_time,
+                window_end,
+            } => Self::Scheduled {
+                execution_time: HelperMapper::to_timestamp("execution_time", &execution_time)?,
+                window_end: HelperMapper::to_optional_timestamp("window_end", window_end)?,
+            },
+        })
+    }
+}
+
 impl From<RequestExecutionPlan> for RequestExecutionScheduleDTO {
     fn from(plan: RequestExecutionPlan) -> Self {
         match plan {
             RequestExecutionPlan::Immediate => Self::Immediate,
-            RequestExecutionPlan::Scheduled { execution_time } => Self::Scheduled {
+            RequestExecutionPlan::Scheduled {
+                execution_time,
+                window_end,
+            } => Self::Scheduled {
                 execution_time: timestamp_to_rfc3339(&execution_time),
+                window_end: window_end.map(|window_end| timestamp_to_rfc3339(&window_end)),
             },
         }
     }
@@ -86,6 +169,7 @@ impl From<RequestCallerPrivileges> for station_api::RequestCallerPrivilegesDTO {
         Self {
             id: Uuid::from_bytes(privileges.id).hyphenated().to_string(),
             can_approve: privileges.can_approve,
+            can_cancel: privileges.can_cancel,
         }
     }
 }
@@ -101,6 +185,30 @@ impl From<RequestAdditionalInfo> for station_api::RequestAdditionalInfoDTO {
                 .map(|approver| approver.into())
                 .collect(),
             evaluation_result: info.evaluation_result.map(|result| result.into()),
+            approval_checklist: info.approval_checklist,
+            impact: info.impact.map(|impact| impact.into()),
+        }
+    }
+}
+
+impl From<DisplayRequestImpact> for station_api::RequestImpactDTO {
+    fn from(impact: DisplayRequestImpact) -> Self {
+        Self {
+            users_gaining_access: impact
+                .users_gaining_access
+                .into_iter()
+                .map(|user| user.into())
+                .collect(),
+            users_losing_access: impact
+                .users_losing_access
+                .into_iter()
+                .map(|user| user.into())
+                .collect(),
+            affected_request_ids: impact
+                .affected_requests
+                .into_iter()
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                .collect(),
         }
     }
 }
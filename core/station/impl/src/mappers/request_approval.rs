@@ -12,6 +12,7 @@ impl From<RequestApproval> for RequestApprovalDTO {
             decided_at: timestamp_to_rfc3339(&approval.decided_dt),
             status: approval.status.into(),
             status_reason: approval.status_reason,
+            acknowledged_checklist: approval.acknowledged_checklist,
         }
     }
 }
@@ -0,0 +1,292 @@
+//! Encodes the content of a request as canonical JSON, so that external systems (e.g. legal archives or
+//! timestamping services) can notarize the exact content that was approved.
+//!
+//! The encoding follows the JSON Canonicalization Scheme (RFC 8785) for the values that requests hold:
+//! the keys of the objects are sorted, no insignificant whitespace is written and the integers are written
+//! in full. Only the content that was proposed is encoded, the approvals and the results of the execution
+//! are left out so that the encoding doesn't change while the request progresses.
+//!
+//! The bulky payloads of the operations (e.g. wasm modules and call arguments) are represented by their
+//! sha256 checksums, which the station already keeps for them.
+//!
+//! The receipts of the votes are encoded the same way, their sha256 hashes are certified by the station.
+
+use crate::models::{Request, RequestOperation, VoteReceipt, VoteReceiptHash};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// The version of the canonical encoding, which changes whenever the encoding of a request changes.
+pub const REQUEST_CANONICAL_ENCODING_VERSION: u32 = 1;
+
+impl Request {
+    /// Returns the canonical JSON encoding of the content of the request.
+    pub fn to_canonical_json(&self) -> String {
+        let content = json!({
+            "encoding_version": REQUEST_CANONICAL_ENCODING_VERSION,
+            "request_id": Uuid::from_bytes(self.id).hyphenated().to_string(),
+            "requested_by": Uuid::from_bytes(self.requested_by).hyphenated().to_string(),
+            "title": self.title,
+            "summary": self.summary,
+            "summary_format": to_value(&self.summary_format),
+            "operation": canonical_operation(&self.operation),
+            "execution_plan": to_value(&self.execution_plan),
+            "depends_on": self
+                .depends_on
+                .iter()
+                .map(|dependency_id| Uuid::from_bytes(*dependency_id).hyphenated().to_string())
+                .collect::<Vec<_>>(),
+            "expiration_dt": timestamp_to_rfc3339(&self.expiration_dt),
+            "created_at": timestamp_to_rfc3339(&self.created_timestamp),
+        });
+
+        let mut canonical = String::new();
+        write_canonical(&content, &mut canonical);
+
+        canonical
+    }
+
+    /// Returns the sha256 hash of the canonical JSON encoding of the request.
+    pub fn canonical_hash(canonical_json: &str) -> Vec<u8> {
+        Sha256::digest(canonical_json.as_bytes()).to_vec()
+    }
+}
+
+/// The version of the canonical encoding of the vote receipts.
+pub const VOTE_RECEIPT_ENCODING_VERSION: u32 = 1;
+
+impl VoteReceipt {
+    /// Returns the canonical JSON encoding of the receipt.
+    pub fn to_canonical_json(&self) -> String {
+        let content = json!({
+            "encoding_version": VOTE_RECEIPT_ENCODING_VERSION,
+            "request_id": Uuid::from_bytes(self.request_id).hyphenated().to_string(),
+            "approver_id": Uuid::from_bytes(self.approver_id).hyphenated().to_string(),
+            "voter": self.voter.to_text(),
+            "decision": self.decision.to_string(),
+            "decided_at": timestamp_to_rfc3339(&self.decided_at),
+        });
+
+        let mut canonical = String::new();
+        write_canonical(&content, &mut canonical);
+
+        canonical
+    }
+
+    /// Returns the sha256 hash of the canonical JSON encoding of the receipt, which is the certified value.
+    pub fn receipt_hash(&self) -> VoteReceiptHash {
+        Sha256::digest(self.to_canonical_json().as_bytes()).into()
+    }
+}
+
+/// Every operation type is listed explicitly, so that adding an operation requires deciding which of
+/// its fields are part of the approved content.
+fn canonical_operation(operation: &RequestOperation) -> Value {
+    let content = match operation {
+        RequestOperation::Transfer(operation) => json!({
+            "input": to_value(&operation.input),
+            "to_name": operation.to_name,
+        }),
+        RequestOperation::AddAccount(operation) => input(&operation.input),
+        RequestOperation::EditAccount(operation) => input(&operation.input),
+        RequestOperation::RotateAccountAddress(operation) => input(&operation.input),
+        RequestOperation::TransferAccountOwnership(operation) => input(&operation.input),
+        RequestOperation::AddAddressBookEntry(operation) => input(&operation.input),
+        RequestOperation::EditAddressBookEntry(operation) => input(&operation.input),
+        RequestOperation::RemoveAddressBookEntry(operation) => input(&operation.input),
+        RequestOperation::AddUser(operation) => input(&operation.input),
+        RequestOperation::EditUser(operation) => input(&operation.input),
+        RequestOperation::EditPermission(operation) => input(&operation.input),
+        RequestOperation::BulkEditPermission(operation) => input(&operation.input),
+        RequestOperation::AddUserGroup(operation) => input(&operation.input),
+        RequestOperation::EditUserGroup(operation) => input(&operation.input),
+        RequestOperation::RemoveUserGroup(operation) => input(&operation.input),
+        RequestOperation::SystemUpgrade(operation) => json!({
+            "input": without_payloads(&operation.input, &["module", "arg"]),
+            "module_checksum": hex::encode(&operation.module_checksum),
+            "arg_checksum": operation.arg_checksum.as_ref().map(hex::encode),
+        }),
+        RequestOperation::ChangeExternalCanister(operation) => json!({
+            "input": without_payloads(&operation.input, &["module", "arg"]),
+            "module_checksum": hex::encode(&operation.module_checksum),
+            "arg_checksum": operation.arg_checksum.as_ref().map(hex::encode),
+        }),
+        RequestOperation::ConfigureExternalCanister(operation) => json!({
+            "canister_id": operation.canister_id.to_text(),
+            "kind": to_value(&operation.kind),
+        }),
+        RequestOperation::CreateExternalCanister(operation) => input(&operation.input),
+        RequestOperation::CallExternalCanister(operation) => json!({
+            "input": without_payloads(&operation.input, &["arg"]),
+            "arg_checksum": operation.arg_checksum.as_ref().map(hex::encode),
+        }),
+        RequestOperation::FundExternalCanister(operation) => input(operation),
+        RequestOperation::CommitAssetBatch(operation) => input(&operation.input),
+        RequestOperation::SnapshotExternalCanister(operation) => input(&operation.input),
+        RequestOperation::AddRequestPolicy(operation) => input(&operation.input),
+        RequestOperation::EditRequestPolicy(operation) => input(&operation.input),
+        RequestOperation::RemoveRequestPolicy(operation) => input(&operation.input),
+        RequestOperation::ManageSystemInfo(operation) => input(&operation.input),
+        RequestOperation::SetDisasterRecovery(operation) => input(&operation.input),
+        RequestOperation::AddNetwork(operation) => input(&operation.input),
+        RequestOperation::EditNetwork(operation) => input(&operation.input),
+        RequestOperation::FreezeBlockchain(operation) => input(&operation.input),
+        RequestOperation::UnfreezeBlockchain(operation) => input(&operation.input),
+        RequestOperation::DecommissionStation(operation) => input(&operation.input),
+    };
+
+    json!({
+        "type": operation.to_string(),
+        "content": content,
+    })
+}
+
+fn input<T: Serialize>(input: &T) -> Value {
+    json!({ "input": to_value(input) })
+}
+
+fn without_payloads<T: Serialize>(input: &T, payloads: &[&str]) -> Value {
+    let mut value = to_value(input);
+    if let Value::Object(fields) = &mut value {
+        for payload in payloads {
+            fields.remove(*payload);
+        }
+    }
+
+    value
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    // the models only have maps with string keys, which can always be represented in JSON
+    serde_json::to_value(value).expect("Failed to encode the request as JSON")
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(fields) => {
+            let mut fields = fields.iter().collect::<Vec<_>>();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            out.push('{');
+            for (i, (key, value)) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.to_owned()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        request_test_utils::mock_request, vote_receipt_test_utils::mock_vote_receipt,
+        RequestStatus, SystemUpgradeOperation, SystemUpgradeOperationInput, SystemUpgradeTarget,
+    };
+
+    #[test]
+    fn canonical_json_is_stable() {
+        let mut request = mock_request();
+        request.id = [0; 16];
+        request.created_timestamp = 0;
+
+        let canonical = request.to_canonical_json();
+
+        assert_eq!(canonical, request.clone().to_canonical_json());
+        assert!(canonical.starts_with(r#"{"created_at":"1970-01-01T00:00:00Z","depends_on":[],"#));
+        assert!(canonical.contains(r#""request_id":"00000000-0000-0000-0000-000000000000""#));
+        assert!(canonical.contains(r#""type":"transfer""#));
+        assert!(!canonical.contains(' '));
+        assert_eq!(Request::canonical_hash(&canonical).len(), 32);
+    }
+
+    #[test]
+    fn canonical_json_sorts_the_keys_of_nested_objects() {
+        let mut canonical = String::new();
+        write_canonical(
+            &json!({ "b": { "y": 1, "x": [{ "d": null, "c": "\n" }] }, "a": true }),
+            &mut canonical,
+        );
+
+        assert_eq!(
+            canonical,
+            r#"{"a":true,"b":{"x":[{"c":"\n","d":null}],"y":1}}"#
+        );
+    }
+
+    #[test]
+    fn vote_receipt_canonical_json_is_stable() {
+        let mut receipt = mock_vote_receipt();
+        receipt.request_id = [0; 16];
+        receipt.approver_id = [1; 16];
+
+        let canonical = receipt.to_canonical_json();
+
+        assert!(canonical.starts_with(
+            r#"{"approver_id":"01010101-0101-0101-0101-010101010101","decided_at":"1970-01-01T00:00:00Z","decision":"approved","#
+        ));
+        assert!(canonical.ends_with(&format!(
+            r#""encoding_version":1,"request_id":"00000000-0000-0000-0000-000000000000","voter":"{}"}}"#,
+            receipt.voter.to_text()
+        )));
+        assert_eq!(
+            receipt.receipt_hash().to_vec(),
+            Request::canonical_hash(&canonical)
+        );
+    }
+
+    #[test]
+    fn canonical_json_ignores_the_execution_results() {
+        let mut request = mock_request();
+        let canonical = request.to_canonical_json();
+
+        request.status = RequestStatus::Completed { completed_at: 1 };
+        if let RequestOperation::Transfer(operation) = &mut request.operation {
+            operation.transfer_id = Some([2; 16]);
+        }
+
+        assert_eq!(canonical, request.to_canonical_json());
+    }
+
+    #[test]
+    fn canonical_json_replaces_the_payloads_by_their_checksums() {
+        let mut request = mock_request();
+        request.operation = RequestOperation::SystemUpgrade(SystemUpgradeOperation {
+            module_checksum: vec![0xab; 32],
+            arg_checksum: None,
+            input: SystemUpgradeOperationInput {
+                target: SystemUpgradeTarget::UpgradeStation,
+                module: vec![7; 1_000],
+                arg: None,
+                arg_injections: vec![],
+            },
+            upgrade_diff: None,
+        });
+
+        let canonical = request.to_canonical_json();
+        assert!(canonical.contains(&format!(r#""module_checksum":"{}""#, "ab".repeat(32))));
+        assert!(!canonical.contains(r#""module":"#));
+
+        // the payloads are pruned once the request is compacted
+        request.operation.prune_payloads();
+        assert_eq!(canonical, request.to_canonical_json());
+    }
+}
@@ -1,5 +1,6 @@
 use super::{blockchain::BlockchainMapper, HelperMapper};
 use crate::{
+    errors::MapperError,
     models::{
         resource::{
             AccountResourceAction, CallExternalCanisterResourceTarget,
@@ -7,17 +8,21 @@ use crate::{
             PermissionResourceAction, Resource, ResourceAction, ResourceId, SystemResourceAction,
             UserResourceAction,
         },
-        Account, AccountKey, AddAccountOperation, AddAccountOperationInput,
+        system::BrandingConfig,
+        Account, AccountKey, AccountOwner, AddAccountOperation, AddAccountOperationInput,
         AddAddressBookEntryOperation, AddAddressBookEntryOperationInput, AddRequestPolicyOperation,
         AddRequestPolicyOperationInput, AddUserOperation, AddUserOperationInput, AddressBookEntry,
+        AuditorAccess, BulkEditPermissionOperation, BulkEditPermissionOperationInput,
         CallExternalCanisterOperation, CallExternalCanisterOperationInput, CanisterInstallMode,
-        CanisterInstallModeArgs, CanisterMethod, CanisterReinstallModeArgs,
+        CanisterInstallModeArgs, CanisterMethod, CanisterReinstallModeArgs, CanisterUpgradeDiff,
         CanisterUpgradeModeArgs, ChangeExternalCanisterOperation,
-        ChangeExternalCanisterOperationInput, ConfigureExternalCanisterOperation,
+        ChangeExternalCanisterOperationInput, ChunkedWasmModule, CommitAssetBatchOperation,
+        CommitAssetBatchOperationInput, ConfigureExternalCanisterOperation,
         ConfigureExternalCanisterOperationKind, ConfigureExternalCanisterSettingsInput,
         CreateExternalCanisterOperation, CreateExternalCanisterOperationInput,
         CreateExternalCanisterOperationKind, CreateExternalCanisterOperationKindAddExisting,
         CreateExternalCanisterOperationKindCreateNew, CycleObtainStrategy,
+        DecommissionStationOperation, DecommissionStationOperationInput,
         DefiniteCanisterSettingsInput, DisasterRecoveryCommittee, EditAccountOperation,
         EditAccountOperationInput, EditAddressBookEntryOperation, EditPermissionOperation,
         EditPermissionOperationInput, EditRequestPolicyOperation, EditRequestPolicyOperationInput,
@@ -25,11 +30,15 @@ use crate::{
         ExternalCanisterCallPermission, ExternalCanisterCallRequestPolicyRuleInput,
         ExternalCanisterChangeRequestPolicyRuleInput, ExternalCanisterPermissionsInput,
         ExternalCanisterRequestPoliciesInput, FundExternalCanisterOperation,
-        ManageSystemInfoOperation, ManageSystemInfoOperationInput, RemoveAddressBookEntryOperation,
-        RemoveRequestPolicyOperation, RemoveRequestPolicyOperationInput, RemoveUserGroupOperation,
-        RequestOperation, SetDisasterRecoveryOperation, SetDisasterRecoveryOperationInput,
-        SystemUpgradeOperation, SystemUpgradeOperationInput, SystemUpgradeTarget,
-        TransferOperation, User,
+        ManageSystemInfoOperation, ManageSystemInfoOperationInput, NameServiceConfig,
+        NatSettingChange, NativeCanisterSettings, NativeSettingsChange, Network, PermissionChange,
+        RemoveAddressBookEntryOperation, RemoveRequestPolicyOperation,
+        RemoveRequestPolicyOperationInput, RemoveUserGroupOperation, RequestOperation,
+        RotateAccountAddressOperation, RotateAccountAddressOperationInput,
+        ScheduledGroupMembership, SetDisasterRecoveryOperation, SetDisasterRecoveryOperationInput,
+        SnapshotExternalCanisterOperation, SystemUpgradeArgInjection, SystemUpgradeOperation,
+        SystemUpgradeOperationInput, SystemUpgradeTarget, TransferAccountOwnershipOperation,
+        TransferAccountOwnershipOperationInput, TransferOperation, User,
     },
     repositories::{
         AccountRepository, AddressBookRepository, UserRepository, ACCOUNT_REPOSITORY,
@@ -38,22 +47,27 @@ use crate::{
 };
 use orbit_essentials::repository::Repository;
 use station_api::{
-    AddAccountOperationDTO, AddAddressBookEntryOperationDTO, AddUserOperationDTO,
+    AccountOwnerDTO, AddAccountOperationDTO, AddAddressBookEntryOperationDTO, AddUserOperationDTO,
     CallExternalCanisterOperationDTO, CanisterMethodDTO, ChangeExternalCanisterOperationDTO,
     CreateExternalCanisterOperationDTO, EditAccountOperationDTO, EditAddressBookEntryOperationDTO,
     EditUserOperationDTO, NetworkDTO, RemoveAddressBookEntryOperationDTO, RequestOperationDTO,
-    TransferOperationDTO,
+    RotateAccountAddressOperationDTO, TransferOperationDTO,
 };
 use uuid::Uuid;
 
 impl TransferOperation {
     pub fn to_dto(self, account: Option<Account>) -> TransferOperationDTO {
-        TransferOperationDTO {
-            from_account: account.map(|account| account.to_dto()),
-            network: NetworkDTO {
+        let network: NetworkDTO = match &account {
+            Some(account) => Network::resolve(&account.blockchain, &self.input.network).into(),
+            None => NetworkDTO {
                 id: self.input.network.clone(),
                 name: self.input.network.clone(),
             },
+        };
+
+        TransferOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            network: network.clone(),
             input: station_api::TransferOperationInput {
                 from_account_id: Uuid::from_bytes(self.input.from_account_id)
                     .hyphenated()
@@ -62,15 +76,21 @@ impl TransferOperation {
                 to: self.input.to,
                 fee: self.input.fee,
                 metadata: self.input.metadata.into_vec_dto(),
-                network: Some(NetworkDTO {
-                    id: self.input.network.clone(),
-                    name: self.input.network.clone(),
-                }),
+                network: Some(network),
+                fee_strategy: self.input.fee_strategy.map(Into::into),
+                max_fee: self.input.max_fee,
+                compliance: self.input.compliance.map(Into::into),
+                from_address: self.input.from_address,
             },
             transfer_id: self
                 .transfer_id
                 .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
             fee: self.fee,
+            fee_estimate: self.fee_estimate.map(Into::into),
+            to_name: self.to_name,
+            screening: self.screening.map(Into::into),
+            time_lock: self.time_lock.map(Into::into),
+            duplicate_warning: self.duplicate_warning.map(Into::into),
         }
     }
 }
@@ -89,6 +109,7 @@ impl AddAccountOperation {
                 configs_permission: self.input.configs_permission.into(),
                 transfer_request_policy: self.input.transfer_request_policy.map(Into::into),
                 configs_request_policy: self.input.configs_request_policy.map(Into::into),
+                network: self.input.network,
             },
         }
     }
@@ -121,6 +142,7 @@ impl From<station_api::AddAccountOperationInput> for AddAccountOperationInput {
             transfer_permission: input.transfer_permission.into(),
             transfer_request_policy: input.transfer_request_policy.map(Into::into),
             configs_request_policy: input.configs_request_policy.map(Into::into),
+            network: input.network,
         }
     }
 }
@@ -150,11 +172,104 @@ impl From<EditAccountOperation> for EditAccountOperationDTO {
                     .input
                     .configs_request_policy
                     .map(|policy| policy.into()),
+                expected_version: operation.input.expected_version,
             },
         }
     }
 }
 
+impl From<RotateAccountAddressOperation> for RotateAccountAddressOperationDTO {
+    fn from(operation: RotateAccountAddressOperation) -> RotateAccountAddressOperationDTO {
+        RotateAccountAddressOperationDTO {
+            input: station_api::RotateAccountAddressOperationInput {
+                account_id: Uuid::from_bytes(operation.input.account_id)
+                    .hyphenated()
+                    .to_string(),
+                grace_period_secs: operation.input.grace_period_secs,
+            },
+            new_address: operation.new_address,
+        }
+    }
+}
+
+impl From<station_api::RotateAccountAddressOperationInput> for RotateAccountAddressOperationInput {
+    fn from(
+        input: station_api::RotateAccountAddressOperationInput,
+    ) -> RotateAccountAddressOperationInput {
+        RotateAccountAddressOperationInput {
+            account_id: *HelperMapper::to_uuid(input.account_id)
+                .expect("Invalid account id")
+                .as_bytes(),
+            grace_period_secs: input.grace_period_secs,
+        }
+    }
+}
+
+impl From<AccountOwner> for AccountOwnerDTO {
+    fn from(owner: AccountOwner) -> AccountOwnerDTO {
+        match owner {
+            AccountOwner::User(user_id) => {
+                AccountOwnerDTO::User(Uuid::from_bytes(user_id).hyphenated().to_string())
+            }
+            AccountOwner::UserGroup(group_id) => {
+                AccountOwnerDTO::UserGroup(Uuid::from_bytes(group_id).hyphenated().to_string())
+            }
+        }
+    }
+}
+
+impl From<AccountOwnerDTO> for AccountOwner {
+    fn from(owner: AccountOwnerDTO) -> AccountOwner {
+        match owner {
+            AccountOwnerDTO::User(user_id) => AccountOwner::User(
+                *HelperMapper::to_uuid(user_id)
+                    .expect("Invalid user id")
+                    .as_bytes(),
+            ),
+            AccountOwnerDTO::UserGroup(group_id) => AccountOwner::UserGroup(
+                *HelperMapper::to_uuid(group_id)
+                    .expect("Invalid user group id")
+                    .as_bytes(),
+            ),
+        }
+    }
+}
+
+impl From<TransferAccountOwnershipOperation> for station_api::TransferAccountOwnershipOperationDTO {
+    fn from(
+        operation: TransferAccountOwnershipOperation,
+    ) -> station_api::TransferAccountOwnershipOperationDTO {
+        station_api::TransferAccountOwnershipOperationDTO {
+            input: station_api::TransferAccountOwnershipOperationInput {
+                account_id: Uuid::from_bytes(operation.input.account_id)
+                    .hyphenated()
+                    .to_string(),
+                from: operation.input.from.into(),
+                to: operation.input.to.into(),
+            },
+            acknowledged_by: operation
+                .acknowledged_by
+                .map(|user_id| Uuid::from_bytes(user_id).hyphenated().to_string()),
+        }
+    }
+}
+
+impl From<station_api::TransferAccountOwnershipOperationInput>
+    for TransferAccountOwnershipOperationInput
+{
+    fn from(
+        input: station_api::TransferAccountOwnershipOperationInput,
+    ) -> TransferAccountOwnershipOperationInput {
+        TransferAccountOwnershipOperationInput {
+            account_id: *HelperMapper::to_uuid(input.account_id)
+                .expect("Invalid account id")
+                .as_bytes(),
+            from: input.from.into(),
+            to: input.to.into(),
+        }
+    }
+}
+
 impl From<station_api::EditAccountOperationInput> for EditAccountOperationInput {
     fn from(input: station_api::EditAccountOperationInput) -> EditAccountOperationInput {
         EditAccountOperationInput {
@@ -167,6 +282,7 @@ impl From<station_api::EditAccountOperationInput> for EditAccountOperationInput
             configs_permission: input.configs_permission.map(|policy| policy.into()),
             transfer_request_policy: input.transfer_request_policy.map(|policy| policy.into()),
             configs_request_policy: input.configs_request_policy.map(|policy| policy.into()),
+            expected_version: input.expected_version,
         }
     }
 }
@@ -249,6 +365,13 @@ impl AddUserOperation {
                     .map(|group| Uuid::from_bytes(*group).hyphenated().to_string())
                     .collect(),
                 status: self.input.status.into(),
+                scheduled_memberships: Some(
+                    self.input
+                        .scheduled_memberships
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                ),
             },
         }
     }
@@ -270,52 +393,70 @@ impl From<EditUserOperation> for EditUserOperationDTO {
                         .collect()
                 }),
                 status: operation.input.status.map(|status| status.into()),
+                scheduled_memberships: operation
+                    .input
+                    .scheduled_memberships
+                    .map(|memberships| memberships.into_iter().map(Into::into).collect()),
                 cancel_pending_requests: operation.input.cancel_pending_requests,
+                expected_version: operation.input.expected_version,
             },
         }
     }
 }
 
-impl From<station_api::AddUserOperationInput> for AddUserOperationInput {
-    fn from(input: station_api::AddUserOperationInput) -> AddUserOperationInput {
-        AddUserOperationInput {
+impl TryFrom<station_api::AddUserOperationInput> for AddUserOperationInput {
+    type Error = MapperError;
+
+    fn try_from(input: station_api::AddUserOperationInput) -> Result<Self, Self::Error> {
+        Ok(AddUserOperationInput {
             name: input.name,
             identities: input.identities,
             groups: input
                 .groups
-                .iter()
-                .map(|group| {
-                    *HelperMapper::to_uuid(group.clone())
-                        .expect("Invalid group id")
-                        .as_bytes()
-                })
-                .collect(),
+                .into_iter()
+                .map(|group| HelperMapper::to_uuid(group).map(|uuid| *uuid.as_bytes()))
+                .collect::<Result<_, _>>()?,
             status: input.status.into(),
-        }
+            scheduled_memberships: input
+                .scheduled_memberships
+                .unwrap_or_default()
+                .into_iter()
+                .map(ScheduledGroupMembership::try_from)
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
-impl From<station_api::EditUserOperationInput> for EditUserOperationInput {
-    fn from(input: station_api::EditUserOperationInput) -> EditUserOperationInput {
-        EditUserOperationInput {
-            user_id: *HelperMapper::to_uuid(input.id)
-                .expect("Invalid user id")
-                .as_bytes(),
+impl TryFrom<station_api::EditUserOperationInput> for EditUserOperationInput {
+    type Error = MapperError;
+
+    fn try_from(input: station_api::EditUserOperationInput) -> Result<Self, Self::Error> {
+        Ok(EditUserOperationInput {
+            user_id: *HelperMapper::to_uuid(input.id)?.as_bytes(),
             name: input.name,
             identities: input.identities,
-            groups: input.groups.map(|groups| {
-                groups
-                    .iter()
-                    .map(|group| {
-                        *HelperMapper::to_uuid(group.clone())
-                            .expect("Invalid group id")
-                            .as_bytes()
-                    })
-                    .collect()
-            }),
+            groups: input
+                .groups
+                .map(|groups| {
+                    groups
+                        .into_iter()
+                        .map(|group| HelperMapper::to_uuid(group).map(|uuid| *uuid.as_bytes()))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()?,
             status: input.status.map(|status| status.into()),
             cancel_pending_requests: input.cancel_pending_requests,
-        }
+            expected_version: input.expected_version,
+            scheduled_memberships: input
+                .scheduled_memberships
+                .map(|memberships| {
+                    memberships
+                        .into_iter()
+                        .map(ScheduledGroupMembership::try_from)
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()?,
+        })
     }
 }
 
@@ -351,6 +492,7 @@ impl From<SystemUpgradeOperationInput> for station_api::SystemUpgradeOperationIn
             target: input.target.into(),
             module: input.module,
             arg: input.arg,
+            arg_injections: Some(input.arg_injections.into_iter().map(Into::into).collect()),
         }
     }
 }
@@ -361,6 +503,32 @@ impl From<station_api::SystemUpgradeOperationInput> for SystemUpgradeOperationIn
             target: input.target.into(),
             module: input.module,
             arg: input.arg,
+            arg_injections: input
+                .arg_injections
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<SystemUpgradeArgInjection> for station_api::SystemUpgradeArgInjectionDTO {
+    fn from(injection: SystemUpgradeArgInjection) -> Self {
+        match injection {
+            SystemUpgradeArgInjection::LedgerCanisters => {
+                station_api::SystemUpgradeArgInjectionDTO::LedgerCanisters
+            }
+        }
+    }
+}
+
+impl From<station_api::SystemUpgradeArgInjectionDTO> for SystemUpgradeArgInjection {
+    fn from(injection: station_api::SystemUpgradeArgInjectionDTO) -> Self {
+        match injection {
+            station_api::SystemUpgradeArgInjectionDTO::LedgerCanisters => {
+                SystemUpgradeArgInjection::LedgerCanisters
+            }
         }
     }
 }
@@ -371,6 +539,13 @@ impl From<SystemUpgradeOperation> for station_api::SystemUpgradeOperationDTO {
             target: operation.input.target.into(),
             module_checksum: hex::encode(operation.module_checksum),
             arg_checksum: operation.arg_checksum.map(hex::encode),
+            arg_injections: operation
+                .input
+                .arg_injections
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            upgrade_diff: operation.upgrade_diff.map(Into::into),
         }
     }
 }
@@ -461,6 +636,7 @@ impl From<ChangeExternalCanisterOperationInput>
             mode: input.mode.into(),
             module: input.module,
             arg: input.arg,
+            module_chunks: input.module_chunks.map(Into::into),
         }
     }
 }
@@ -476,6 +652,27 @@ impl From<station_api::ChangeExternalCanisterOperationInput>
             mode: input.mode.into(),
             module: input.module,
             arg: input.arg,
+            module_chunks: input.module_chunks.map(Into::into),
+        }
+    }
+}
+
+impl From<ChunkedWasmModule> for station_api::ChunkedWasmModuleDTO {
+    fn from(module: ChunkedWasmModule) -> station_api::ChunkedWasmModuleDTO {
+        station_api::ChunkedWasmModuleDTO {
+            store_canister: module.store_canister,
+            chunk_hashes: module.chunk_hashes,
+            wasm_module_hash: module.wasm_module_hash,
+        }
+    }
+}
+
+impl From<station_api::ChunkedWasmModuleDTO> for ChunkedWasmModule {
+    fn from(module: station_api::ChunkedWasmModuleDTO) -> ChunkedWasmModule {
+        ChunkedWasmModule {
+            store_canister: module.store_canister,
+            chunk_hashes: module.chunk_hashes,
+            wasm_module_hash: module.wasm_module_hash,
         }
     }
 }
@@ -487,6 +684,35 @@ impl From<ChangeExternalCanisterOperation> for ChangeExternalCanisterOperationDT
             mode: operation.input.mode.into(),
             module_checksum: hex::encode(operation.module_checksum),
             arg_checksum: operation.arg_checksum.map(hex::encode),
+            upgrade_diff: operation.upgrade_diff.map(Into::into),
+        }
+    }
+}
+
+impl From<CanisterUpgradeDiff> for station_api::CanisterUpgradeDiffDTO {
+    fn from(diff: CanisterUpgradeDiff) -> station_api::CanisterUpgradeDiffDTO {
+        station_api::CanisterUpgradeDiffDTO {
+            current_state: diff
+                .current_state
+                .map(|state| station_api::CanisterCurrentStateDTO {
+                    module_hash: state.module_hash.map(hex::encode),
+                    settings: state.settings.map(Into::into),
+                }),
+            module_size: diff.module_size,
+            arg_empty: diff.arg_empty,
+        }
+    }
+}
+
+impl From<NativeCanisterSettings> for station_api::NativeCanisterSettingsDTO {
+    fn from(settings: NativeCanisterSettings) -> station_api::NativeCanisterSettingsDTO {
+        station_api::NativeCanisterSettingsDTO {
+            controllers: settings.controllers,
+            compute_allocation: settings.compute_allocation,
+            memory_allocation: settings.memory_allocation,
+            freezing_threshold: settings.freezing_threshold,
+            reserved_cycles_limit: settings.reserved_cycles_limit,
+            log_visibility: settings.log_visibility.map(Into::into),
         }
     }
 }
@@ -500,6 +726,53 @@ impl From<ConfigureExternalCanisterOperation>
         station_api::ConfigureExternalCanisterOperationDTO {
             canister_id: operation.canister_id,
             kind: operation.kind.into(),
+            native_settings_diff: operation
+                .native_settings_diff
+                .map(|diff| diff.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<NativeSettingsChange> for station_api::NativeSettingsChangeDTO {
+    fn from(change: NativeSettingsChange) -> station_api::NativeSettingsChangeDTO {
+        match change {
+            NativeSettingsChange::Controllers(change) => {
+                station_api::NativeSettingsChangeDTO::Controllers(
+                    station_api::ControllersChangeDTO {
+                        added: change.added,
+                        removed: change.removed,
+                    },
+                )
+            }
+            NativeSettingsChange::ComputeAllocation(change) => {
+                station_api::NativeSettingsChangeDTO::ComputeAllocation(change.into())
+            }
+            NativeSettingsChange::MemoryAllocation(change) => {
+                station_api::NativeSettingsChangeDTO::MemoryAllocation(change.into())
+            }
+            NativeSettingsChange::FreezingThreshold(change) => {
+                station_api::NativeSettingsChangeDTO::FreezingThreshold(change.into())
+            }
+            NativeSettingsChange::ReservedCyclesLimit(change) => {
+                station_api::NativeSettingsChangeDTO::ReservedCyclesLimit(change.into())
+            }
+            NativeSettingsChange::LogVisibility(change) => {
+                station_api::NativeSettingsChangeDTO::LogVisibility(
+                    station_api::LogVisibilityChangeDTO {
+                        current: change.current.map(Into::into),
+                        proposed: change.proposed.into(),
+                    },
+                )
+            }
+        }
+    }
+}
+
+impl From<NatSettingChange> for station_api::NatSettingChangeDTO {
+    fn from(change: NatSettingChange) -> station_api::NatSettingChangeDTO {
+        station_api::NatSettingChangeDTO {
+            current: change.current,
+            proposed: change.proposed,
         }
     }
 }
@@ -540,6 +813,7 @@ impl From<ConfigureExternalCanisterSettingsInput>
             state: input.state.map(Into::into),
             permissions: input.permissions.map(Into::into),
             request_policies: input.request_policies.map(Into::into),
+            repository_url: input.repository_url,
         }
     }
 }
@@ -552,6 +826,7 @@ impl From<DefiniteCanisterSettingsInput> for station_api::DefiniteCanisterSettin
             freezing_threshold: input.freezing_threshold,
             memory_allocation: input.memory_allocation,
             reserved_cycles_limit: input.reserved_cycles_limit,
+            log_visibility: input.log_visibility.map(Into::into),
         }
     }
 }
@@ -793,6 +1068,7 @@ impl From<CreateExternalCanisterOperationInput>
             labels: input.labels,
             permissions: input.permissions.into(),
             request_policies: input.request_policies.into(),
+            repository_url: input.repository_url,
         }
     }
 }
@@ -810,6 +1086,7 @@ impl From<station_api::CreateExternalCanisterOperationInput>
             labels: input.labels,
             permissions: input.permissions.into(),
             request_policies: input.request_policies.into(),
+            repository_url: input.repository_url,
         }
     }
 }
@@ -880,6 +1157,37 @@ impl From<CallExternalCanisterOperation> for CallExternalCanisterOperationDTO {
     }
 }
 
+impl From<CommitAssetBatchOperationInput> for station_api::CommitAssetBatchOperationInput {
+    fn from(input: CommitAssetBatchOperationInput) -> station_api::CommitAssetBatchOperationInput {
+        station_api::CommitAssetBatchOperationInput {
+            canister_id: input.canister_id,
+            batch_id: input.batch_id,
+            evidence: input.evidence,
+        }
+    }
+}
+
+impl From<station_api::CommitAssetBatchOperationInput> for CommitAssetBatchOperationInput {
+    fn from(input: station_api::CommitAssetBatchOperationInput) -> CommitAssetBatchOperationInput {
+        CommitAssetBatchOperationInput {
+            canister_id: input.canister_id,
+            batch_id: input.batch_id,
+            evidence: input.evidence,
+        }
+    }
+}
+
+impl From<CommitAssetBatchOperation> for station_api::CommitAssetBatchOperationDTO {
+    fn from(operation: CommitAssetBatchOperation) -> station_api::CommitAssetBatchOperationDTO {
+        station_api::CommitAssetBatchOperationDTO {
+            canister_id: operation.input.canister_id,
+            batch_id: operation.input.batch_id,
+            evidence: hex::encode(operation.input.evidence),
+            evidence_rendering: operation.evidence_rendering,
+        }
+    }
+}
+
 impl From<EditPermissionOperationInput> for station_api::EditPermissionOperationInput {
     fn from(input: EditPermissionOperationInput) -> station_api::EditPermissionOperationInput {
         station_api::EditPermissionOperationInput {
@@ -934,11 +1242,48 @@ impl From<EditPermissionOperation> for station_api::EditPermissionOperationDTO {
     }
 }
 
+impl From<BulkEditPermissionOperationInput> for station_api::BulkEditPermissionOperationInput {
+    fn from(input: BulkEditPermissionOperationInput) -> Self {
+        station_api::BulkEditPermissionOperationInput {
+            changes: input.changes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<station_api::BulkEditPermissionOperationInput> for BulkEditPermissionOperationInput {
+    fn from(input: station_api::BulkEditPermissionOperationInput) -> Self {
+        BulkEditPermissionOperationInput {
+            changes: input.changes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<PermissionChange> for station_api::PermissionChangeDTO {
+    fn from(change: PermissionChange) -> Self {
+        station_api::PermissionChangeDTO {
+            resource: change.resource.into(),
+            before: change.before.into(),
+            after: change.after.into(),
+        }
+    }
+}
+
+impl From<BulkEditPermissionOperation> for station_api::BulkEditPermissionOperationDTO {
+    fn from(operation: BulkEditPermissionOperation) -> Self {
+        station_api::BulkEditPermissionOperationDTO {
+            input: operation.input.into(),
+            matrix: operation.matrix.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 impl From<AddRequestPolicyOperationInput> for station_api::AddRequestPolicyOperationInput {
     fn from(input: AddRequestPolicyOperationInput) -> station_api::AddRequestPolicyOperationInput {
         station_api::AddRequestPolicyOperationInput {
             specifier: input.specifier.into(),
             rule: input.rule.into(),
+            checklist: Some(input.checklist),
+            exclude_requester: Some(input.exclude_requester),
         }
     }
 }
@@ -948,6 +1293,8 @@ impl From<station_api::AddRequestPolicyOperationInput> for AddRequestPolicyOpera
         AddRequestPolicyOperationInput {
             specifier: input.specifier.into(),
             rule: input.rule.into(),
+            checklist: input.checklist.unwrap_or_default(),
+            exclude_requester: input.exclude_requester.unwrap_or_default(),
         }
     }
 }
@@ -971,6 +1318,9 @@ impl From<EditRequestPolicyOperationInput> for station_api::EditRequestPolicyOpe
             policy_id: Uuid::from_bytes(input.policy_id).hyphenated().to_string(),
             specifier: input.specifier.map(|specifier| specifier.into()),
             rule: input.rule.map(|rule| rule.into()),
+            checklist: input.checklist,
+            exclude_requester: input.exclude_requester,
+            expected_version: input.expected_version,
         }
     }
 }
@@ -985,6 +1335,9 @@ impl From<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyOpe
                 .as_bytes(),
             specifier: input.specifier.map(|specifier| specifier.into()),
             rule: input.rule.map(|rule| rule.into()),
+            checklist: input.checklist,
+            exclude_requester: input.exclude_requester,
+            expected_version: input.expected_version,
         }
     }
 }
@@ -1088,21 +1441,111 @@ impl From<CycleObtainStrategy> for station_api::CycleObtainStrategyInput {
     }
 }
 
+impl From<station_api::NameServiceConfigDTO> for NameServiceConfig {
+    fn from(value: station_api::NameServiceConfigDTO) -> Self {
+        match value {
+            station_api::NameServiceConfigDTO::Disabled => NameServiceConfig::Disabled,
+            station_api::NameServiceConfigDTO::Canister { canister_id } => {
+                NameServiceConfig::Canister { canister_id }
+            }
+        }
+    }
+}
+
+impl From<NameServiceConfig> for station_api::NameServiceConfigDTO {
+    fn from(value: NameServiceConfig) -> Self {
+        match value {
+            NameServiceConfig::Disabled => station_api::NameServiceConfigDTO::Disabled,
+            NameServiceConfig::Canister { canister_id } => {
+                station_api::NameServiceConfigDTO::Canister { canister_id }
+            }
+        }
+    }
+}
+
 impl From<ManageSystemInfoOperationInput> for station_api::ManageSystemInfoOperationInput {
     fn from(input: ManageSystemInfoOperationInput) -> station_api::ManageSystemInfoOperationInput {
         station_api::ManageSystemInfoOperationInput {
             name: input.name,
             cycle_obtain_strategy: input.cycle_obtain_strategy.map(|strategy| strategy.into()),
-        }
-    }
-}
-
-impl From<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoOperationInput {
-    fn from(input: station_api::ManageSystemInfoOperationInput) -> ManageSystemInfoOperationInput {
-        ManageSystemInfoOperationInput {
+            auditors: input
+                .auditors
+                .map(|auditors| auditors.into_iter().map(Into::into).collect()),
+            address_book_sources: input
+                .address_book_sources
+                .map(|sources| sources.into_iter().map(Into::into).collect()),
+            name_service: input.name_service.map(Into::into),
+            transfer_compliance_thresholds: input
+                .transfer_compliance_thresholds
+                .map(|thresholds| thresholds.into_iter().map(Into::into).collect()),
+            transfer_max_fees: input
+                .transfer_max_fees
+                .map(|max_fees| max_fees.into_iter().map(Into::into).collect()),
+            transfer_screening: input.transfer_screening.map(Into::into),
+            transfer_time_lock: input.transfer_time_lock.map(Into::into),
+            metadata_encryption: input.metadata_encryption.map(Into::into),
+            data_compaction: input.data_compaction.map(Into::into),
+            request_archival: input.request_archival.map(Into::into),
+            branding: input.branding.map(Into::into),
+            sub_treasuries: input
+                .sub_treasuries
+                .map(|sub_treasuries| sub_treasuries.into_iter().map(Into::into).collect()),
+            maintenance_mode: input.maintenance_mode.map(Into::into),
+            request_text_limits: input.request_text_limits.map(Into::into),
+            approval_reminders: input.approval_reminders.map(Into::into),
+            balance_snapshots: input.balance_snapshots.map(Into::into),
+            fiat_valuation: input.fiat_valuation.map(Into::into),
+            operation_flags: input
+                .operation_flags
+                .map(|flags| flags.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl TryFrom<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoOperationInput {
+    type Error = MapperError;
+
+    fn try_from(input: station_api::ManageSystemInfoOperationInput) -> Result<Self, Self::Error> {
+        Ok(ManageSystemInfoOperationInput {
             name: input.name,
             cycle_obtain_strategy: input.cycle_obtain_strategy.map(|strategy| strategy.into()),
-        }
+            auditors: input
+                .auditors
+                .map(|auditors| {
+                    auditors
+                        .into_iter()
+                        .map(AuditorAccess::try_from)
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()?,
+            address_book_sources: input
+                .address_book_sources
+                .map(|sources| sources.into_iter().map(Into::into).collect()),
+            name_service: input.name_service.map(Into::into),
+            transfer_compliance_thresholds: input
+                .transfer_compliance_thresholds
+                .map(|thresholds| thresholds.into_iter().map(Into::into).collect()),
+            transfer_max_fees: input
+                .transfer_max_fees
+                .map(|max_fees| max_fees.into_iter().map(Into::into).collect()),
+            transfer_screening: input.transfer_screening.map(Into::into),
+            transfer_time_lock: input.transfer_time_lock.map(Into::into),
+            metadata_encryption: input.metadata_encryption.map(Into::into),
+            data_compaction: input.data_compaction.map(Into::into),
+            request_archival: input.request_archival.map(Into::into),
+            branding: input.branding.map(BrandingConfig::try_from).transpose()?,
+            sub_treasuries: input
+                .sub_treasuries
+                .map(|sub_treasuries| sub_treasuries.into_iter().map(Into::into).collect()),
+            maintenance_mode: input.maintenance_mode.map(Into::into),
+            request_text_limits: input.request_text_limits.map(Into::into),
+            approval_reminders: input.approval_reminders.map(Into::into),
+            balance_snapshots: input.balance_snapshots.map(Into::into),
+            fiat_valuation: input.fiat_valuation.map(Into::into),
+            operation_flags: input
+                .operation_flags
+                .map(|flags| flags.into_iter().map(Into::into).collect()),
+        })
     }
 }
 
@@ -1122,6 +1565,28 @@ impl From<station_api::ManageSystemInfoOperationDTO> for ManageSystemInfoOperati
     }
 }
 
+impl From<station_api::DecommissionStationOperationInput> for DecommissionStationOperationInput {
+    fn from(
+        input: station_api::DecommissionStationOperationInput,
+    ) -> DecommissionStationOperationInput {
+        DecommissionStationOperationInput {
+            reason: input.reason,
+        }
+    }
+}
+
+impl From<DecommissionStationOperation> for station_api::DecommissionStationOperationDTO {
+    fn from(
+        operation: DecommissionStationOperation,
+    ) -> station_api::DecommissionStationOperationDTO {
+        station_api::DecommissionStationOperationDTO {
+            input: station_api::DecommissionStationOperationInput {
+                reason: operation.input.reason,
+            },
+        }
+    }
+}
+
 impl From<RequestOperation> for RequestOperationDTO {
     fn from(operation: RequestOperation) -> RequestOperationDTO {
         match operation {
@@ -1141,6 +1606,12 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::EditAccount(operation) => {
                 RequestOperationDTO::EditAccount(Box::new(operation.into()))
             }
+            RequestOperation::RotateAccountAddress(operation) => {
+                RequestOperationDTO::RotateAccountAddress(Box::new(operation.into()))
+            }
+            RequestOperation::TransferAccountOwnership(operation) => {
+                RequestOperationDTO::TransferAccountOwnership(Box::new(operation.into()))
+            }
             RequestOperation::AddAddressBookEntry(operation) => {
                 let address_book_entry = operation.address_book_entry_id.and_then(|id| {
                     AddressBookRepository::default().get(&AddressBookEntry::key(id))
@@ -1200,9 +1671,18 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::CallExternalCanister(operation) => {
                 RequestOperationDTO::CallExternalCanister(Box::new(operation.into()))
             }
+            RequestOperation::CommitAssetBatch(operation) => {
+                RequestOperationDTO::CommitAssetBatch(Box::new(operation.into()))
+            }
+            RequestOperation::SnapshotExternalCanister(operation) => {
+                RequestOperationDTO::SnapshotExternalCanister(Box::new(operation.into()))
+            }
             RequestOperation::EditPermission(operation) => {
                 RequestOperationDTO::EditPermission(Box::new(operation.into()))
             }
+            RequestOperation::BulkEditPermission(operation) => {
+                RequestOperationDTO::BulkEditPermission(Box::new(operation.into()))
+            }
             RequestOperation::AddRequestPolicy(operation) => {
                 RequestOperationDTO::AddRequestPolicy(Box::new(operation.into()))
             }
@@ -1215,6 +1695,21 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::ManageSystemInfo(operation) => {
                 RequestOperationDTO::ManageSystemInfo(Box::new(operation.into()))
             }
+            RequestOperation::AddNetwork(operation) => {
+                RequestOperationDTO::AddNetwork(Box::new(operation.into()))
+            }
+            RequestOperation::EditNetwork(operation) => {
+                RequestOperationDTO::EditNetwork(Box::new(operation.into()))
+            }
+            RequestOperation::FreezeBlockchain(operation) => {
+                RequestOperationDTO::FreezeBlockchain(Box::new(operation.into()))
+            }
+            RequestOperation::UnfreezeBlockchain(operation) => {
+                RequestOperationDTO::UnfreezeBlockchain(Box::new(operation.into()))
+            }
+            RequestOperation::DecommissionStation(operation) => {
+                RequestOperationDTO::DecommissionStation(Box::new(operation.into()))
+            }
         }
     }
 }
@@ -1234,7 +1729,7 @@ impl RequestOperation {
             RequestOperation::AddRequestPolicy(_) => {
                 vec![Resource::RequestPolicy(ResourceAction::Create)]
             }
-            RequestOperation::EditPermission(_) => {
+            RequestOperation::EditPermission(_) | RequestOperation::BulkEditPermission(_) => {
                 vec![Resource::Permission(PermissionResourceAction::Update)]
             }
 
@@ -1255,6 +1750,27 @@ impl RequestOperation {
                     Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
                 ]
             }
+            RequestOperation::RotateAccountAddress(RotateAccountAddressOperation {
+                input, ..
+            }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                        input.account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::TransferAccountOwnership(TransferAccountOwnershipOperation {
+                input,
+                ..
+            }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                        input.account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                ]
+            }
             RequestOperation::EditAddressBookEntry(EditAddressBookEntryOperation {
                 input, ..
             }) => {
@@ -1287,7 +1803,7 @@ impl RequestOperation {
                     Resource::UserGroup(ResourceAction::Update(ResourceId::Any)),
                 ]
             }
-            RequestOperation::RemoveUserGroup(RemoveUserGroupOperation { input }) => {
+            RequestOperation::RemoveUserGroup(RemoveUserGroupOperation { input, .. }) => {
                 vec![
                     Resource::UserGroup(ResourceAction::Delete(ResourceId::Id(
                         input.user_group_id,
@@ -1324,6 +1840,20 @@ impl RequestOperation {
                     )),
                 ]
             }
+            // Snapshots can replace the module and state of the canister, so they are governed like a change
+            RequestOperation::SnapshotExternalCanister(SnapshotExternalCanisterOperation {
+                input,
+                ..
+            }) => {
+                vec![
+                    Resource::ExternalCanister(ExternalCanisterResourceAction::Change(
+                        ExternalCanisterId::Any,
+                    )),
+                    Resource::ExternalCanister(ExternalCanisterResourceAction::Change(
+                        ExternalCanisterId::Canister(input.canister_id),
+                    )),
+                ]
+            }
             RequestOperation::FundExternalCanister(FundExternalCanisterOperation {
                 canister_id,
                 ..
@@ -1346,35 +1876,16 @@ impl RequestOperation {
             }
             RequestOperation::CallExternalCanister(CallExternalCanisterOperation {
                 input, ..
-            }) => {
-                vec![
-                    // Any canister with any method
-                    Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
-                        CallExternalCanisterResourceTarget {
-                            validation_method: input.validation_method.clone().into(),
-                            execution_method: ExecutionMethodResourceTarget::Any,
-                        },
-                    )),
-                    // A specific canister with any execution method
-                    Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
-                        CallExternalCanisterResourceTarget {
-                            validation_method: input.validation_method.clone().into(),
-                            execution_method: ExecutionMethodResourceTarget::ExecutionMethod(
-                                CanisterMethod {
-                                    canister_id: input.execution_method.canister_id,
-                                    method_name: CanisterMethod::WILDCARD.to_string(),
-                                },
-                            ),
-                        },
-                    )),
-                    // A specific canister with a specific execution method
-                    Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
-                        CallExternalCanisterResourceTarget {
-                            validation_method: input.validation_method.clone().into(),
-                            execution_method: input.execution_method.clone().into(),
-                        },
-                    )),
-                ]
+            }) => external_canister_call_resources(
+                input.validation_method.clone(),
+                &input.execution_method,
+            ),
+            // Committing an asset batch is governed like a call of its validated method pair
+            RequestOperation::CommitAssetBatch(CommitAssetBatchOperation { input, .. }) => {
+                external_canister_call_resources(
+                    Some(input.validation_method()),
+                    &input.execution_method(),
+                )
             }
             RequestOperation::EditRequestPolicy(EditRequestPolicyOperation { input }) => {
                 vec![
@@ -1392,9 +1903,46 @@ impl RequestOperation {
                     Resource::RequestPolicy(ResourceAction::Delete(ResourceId::Any)),
                 ]
             }
-            RequestOperation::ManageSystemInfo(_) => {
+            RequestOperation::ManageSystemInfo(_)
+            | RequestOperation::AddNetwork(_)
+            | RequestOperation::EditNetwork(_)
+            | RequestOperation::FreezeBlockchain(_)
+            | RequestOperation::UnfreezeBlockchain(_)
+            | RequestOperation::DecommissionStation(_) => {
                 vec![Resource::System(SystemResourceAction::ManageSystemInfo)]
             }
         }
     }
 }
+
+fn external_canister_call_resources(
+    validation_method: Option<CanisterMethod>,
+    execution_method: &CanisterMethod,
+) -> Vec<Resource> {
+    vec![
+        // Any canister with any method
+        Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
+            CallExternalCanisterResourceTarget {
+                validation_method: validation_method.clone().into(),
+                execution_method: ExecutionMethodResourceTarget::Any,
+            },
+        )),
+        // A specific canister with any execution method
+        Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
+            CallExternalCanisterResourceTarget {
+                validation_method: validation_method.clone().into(),
+                execution_method: ExecutionMethodResourceTarget::ExecutionMethod(CanisterMethod {
+                    canister_id: execution_method.canister_id,
+                    method_name: CanisterMethod::WILDCARD.to_string(),
+                }),
+            },
+        )),
+        // A specific canister with a specific execution method
+        Resource::ExternalCanister(ExternalCanisterResourceAction::Call(
+            CallExternalCanisterResourceTarget {
+                validation_method: validation_method.into(),
+                execution_method: execution_method.clone().into(),
+            },
+        )),
+    ]
+}
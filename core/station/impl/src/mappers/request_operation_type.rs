@@ -1,6 +1,6 @@
 use crate::mappers::HelperMapper;
 use crate::models::{ListRequestsOperationType, RequestOperation, RequestOperationType};
-use station_api::{ListRequestsOperationTypeDTO, RequestOperationTypeDTO};
+use station_api::{ListRequestsOperationTypeDTO, RequestOperationInput, RequestOperationTypeDTO};
 
 impl From<station_api::ListRequestsOperationTypeDTO> for ListRequestsOperationType {
     fn from(value: station_api::ListRequestsOperationTypeDTO) -> Self {
@@ -18,6 +18,12 @@ impl From<station_api::ListRequestsOperationTypeDTO> for ListRequestsOperationTy
             station_api::ListRequestsOperationTypeDTO::EditAccount => {
                 ListRequestsOperationType::EditAccount
             }
+            station_api::ListRequestsOperationTypeDTO::RotateAccountAddress => {
+                ListRequestsOperationType::RotateAccountAddress
+            }
+            station_api::ListRequestsOperationTypeDTO::TransferAccountOwnership => {
+                ListRequestsOperationType::TransferAccountOwnership
+            }
             station_api::ListRequestsOperationTypeDTO::AddAddressBookEntry => {
                 ListRequestsOperationType::AddAddressBookEntry
             }
@@ -60,9 +66,18 @@ impl From<station_api::ListRequestsOperationTypeDTO> for ListRequestsOperationTy
             station_api::ListRequestsOperationTypeDTO::FundExternalCanister(canister_id) => {
                 ListRequestsOperationType::FundExternalCanister(canister_id)
             }
+            station_api::ListRequestsOperationTypeDTO::CommitAssetBatch(canister_id) => {
+                ListRequestsOperationType::CommitAssetBatch(canister_id)
+            }
+            station_api::ListRequestsOperationTypeDTO::SnapshotExternalCanister(canister_id) => {
+                ListRequestsOperationType::SnapshotExternalCanister(canister_id)
+            }
             station_api::ListRequestsOperationTypeDTO::EditPermission => {
                 ListRequestsOperationType::EditPermission
             }
+            station_api::ListRequestsOperationTypeDTO::BulkEditPermission => {
+                ListRequestsOperationType::BulkEditPermission
+            }
             station_api::ListRequestsOperationTypeDTO::AddRequestPolicy => {
                 ListRequestsOperationType::AddRequestPolicy
             }
@@ -75,6 +90,21 @@ impl From<station_api::ListRequestsOperationTypeDTO> for ListRequestsOperationTy
             station_api::ListRequestsOperationTypeDTO::ManageSystemInfo => {
                 ListRequestsOperationType::ManageSystemInfo
             }
+            station_api::ListRequestsOperationTypeDTO::AddNetwork => {
+                ListRequestsOperationType::AddNetwork
+            }
+            station_api::ListRequestsOperationTypeDTO::EditNetwork => {
+                ListRequestsOperationType::EditNetwork
+            }
+            station_api::ListRequestsOperationTypeDTO::FreezeBlockchain => {
+                ListRequestsOperationType::FreezeBlockchain
+            }
+            station_api::ListRequestsOperationTypeDTO::UnfreezeBlockchain => {
+                ListRequestsOperationType::UnfreezeBlockchain
+            }
+            station_api::ListRequestsOperationTypeDTO::DecommissionStation => {
+                ListRequestsOperationType::DecommissionStation
+            }
             station_api::ListRequestsOperationTypeDTO::SetDisasterRecovery => {
                 ListRequestsOperationType::SetDisasterRecovery
             }
@@ -88,6 +118,12 @@ impl From<RequestOperationTypeDTO> for RequestOperationType {
             RequestOperationTypeDTO::Transfer => RequestOperationType::Transfer,
             RequestOperationTypeDTO::AddAccount => RequestOperationType::AddAccount,
             RequestOperationTypeDTO::EditAccount => RequestOperationType::EditAccount,
+            RequestOperationTypeDTO::RotateAccountAddress => {
+                RequestOperationType::RotateAccountAddress
+            }
+            RequestOperationTypeDTO::TransferAccountOwnership => {
+                RequestOperationType::TransferAccountOwnership
+            }
             RequestOperationTypeDTO::AddAddressBookEntry => {
                 RequestOperationType::AddAddressBookEntry
             }
@@ -115,13 +151,25 @@ impl From<RequestOperationTypeDTO> for RequestOperationType {
             RequestOperationTypeDTO::FundExternalCanister => {
                 RequestOperationType::FundExternalCanister
             }
+            RequestOperationTypeDTO::CommitAssetBatch => RequestOperationType::CommitAssetBatch,
+            RequestOperationTypeDTO::SnapshotExternalCanister => {
+                RequestOperationType::SnapshotExternalCanister
+            }
             RequestOperationTypeDTO::EditPermission => RequestOperationType::EditPermission,
+            RequestOperationTypeDTO::BulkEditPermission => RequestOperationType::BulkEditPermission,
             RequestOperationTypeDTO::AddRequestPolicy => RequestOperationType::AddRequestPolicy,
             RequestOperationTypeDTO::EditRequestPolicy => RequestOperationType::EditRequestPolicy,
             RequestOperationTypeDTO::RemoveRequestPolicy => {
                 RequestOperationType::RemoveRequestPolicy
             }
             RequestOperationTypeDTO::ManageSystemInfo => RequestOperationType::ManageSystemInfo,
+            RequestOperationTypeDTO::AddNetwork => RequestOperationType::AddNetwork,
+            RequestOperationTypeDTO::EditNetwork => RequestOperationType::EditNetwork,
+            RequestOperationTypeDTO::FreezeBlockchain => RequestOperationType::FreezeBlockchain,
+            RequestOperationTypeDTO::UnfreezeBlockchain => RequestOperationType::UnfreezeBlockchain,
+            RequestOperationTypeDTO::DecommissionStation => {
+                RequestOperationType::DecommissionStation
+            }
             RequestOperationTypeDTO::SetDisasterRecovery => {
                 RequestOperationType::SetDisasterRecovery
             }
@@ -138,6 +186,12 @@ impl From<RequestOperationType> for RequestOperationTypeDTO {
             RequestOperationType::Transfer => RequestOperationTypeDTO::Transfer,
             RequestOperationType::AddAccount => RequestOperationTypeDTO::AddAccount,
             RequestOperationType::EditAccount => RequestOperationTypeDTO::EditAccount,
+            RequestOperationType::RotateAccountAddress => {
+                RequestOperationTypeDTO::RotateAccountAddress
+            }
+            RequestOperationType::TransferAccountOwnership => {
+                RequestOperationTypeDTO::TransferAccountOwnership
+            }
             RequestOperationType::AddAddressBookEntry => {
                 RequestOperationTypeDTO::AddAddressBookEntry
             }
@@ -165,13 +219,25 @@ impl From<RequestOperationType> for RequestOperationTypeDTO {
             RequestOperationType::FundExternalCanister => {
                 RequestOperationTypeDTO::FundExternalCanister
             }
+            RequestOperationType::CommitAssetBatch => RequestOperationTypeDTO::CommitAssetBatch,
+            RequestOperationType::SnapshotExternalCanister => {
+                RequestOperationTypeDTO::SnapshotExternalCanister
+            }
             RequestOperationType::EditPermission => RequestOperationTypeDTO::EditPermission,
+            RequestOperationType::BulkEditPermission => RequestOperationTypeDTO::BulkEditPermission,
             RequestOperationType::AddRequestPolicy => RequestOperationTypeDTO::AddRequestPolicy,
             RequestOperationType::EditRequestPolicy => RequestOperationTypeDTO::EditRequestPolicy,
             RequestOperationType::RemoveRequestPolicy => {
                 RequestOperationTypeDTO::RemoveRequestPolicy
             }
             RequestOperationType::ManageSystemInfo => RequestOperationTypeDTO::ManageSystemInfo,
+            RequestOperationType::AddNetwork => RequestOperationTypeDTO::AddNetwork,
+            RequestOperationType::EditNetwork => RequestOperationTypeDTO::EditNetwork,
+            RequestOperationType::FreezeBlockchain => RequestOperationTypeDTO::FreezeBlockchain,
+            RequestOperationType::UnfreezeBlockchain => RequestOperationTypeDTO::UnfreezeBlockchain,
+            RequestOperationType::DecommissionStation => {
+                RequestOperationTypeDTO::DecommissionStation
+            }
             RequestOperationType::SetDisasterRecovery => {
                 RequestOperationTypeDTO::SetDisasterRecovery
             }
@@ -188,6 +254,10 @@ impl From<RequestOperation> for RequestOperationType {
             RequestOperation::Transfer(_) => RequestOperationType::Transfer,
             RequestOperation::AddAccount(_) => RequestOperationType::AddAccount,
             RequestOperation::EditAccount(_) => RequestOperationType::EditAccount,
+            RequestOperation::RotateAccountAddress(_) => RequestOperationType::RotateAccountAddress,
+            RequestOperation::TransferAccountOwnership(_) => {
+                RequestOperationType::TransferAccountOwnership
+            }
             RequestOperation::AddAddressBookEntry(_) => RequestOperationType::AddAddressBookEntry,
             RequestOperation::EditAddressBookEntry(_) => RequestOperationType::EditAddressBookEntry,
             RequestOperation::RemoveAddressBookEntry(_) => {
@@ -210,12 +280,94 @@ impl From<RequestOperation> for RequestOperationType {
             }
             RequestOperation::FundExternalCanister(_) => RequestOperationType::FundExternalCanister,
             RequestOperation::CallExternalCanister(_) => RequestOperationType::CallExternalCanister,
+            RequestOperation::CommitAssetBatch(_) => RequestOperationType::CommitAssetBatch,
+            RequestOperation::SnapshotExternalCanister(_) => {
+                RequestOperationType::SnapshotExternalCanister
+            }
             RequestOperation::EditPermission(_) => RequestOperationType::EditPermission,
+            RequestOperation::BulkEditPermission(_) => RequestOperationType::BulkEditPermission,
             RequestOperation::AddRequestPolicy(_) => RequestOperationType::AddRequestPolicy,
             RequestOperation::EditRequestPolicy(_) => RequestOperationType::EditRequestPolicy,
             RequestOperation::RemoveRequestPolicy(_) => RequestOperationType::RemoveRequestPolicy,
             RequestOperation::ManageSystemInfo(_) => RequestOperationType::ManageSystemInfo,
             RequestOperation::SetDisasterRecovery(_) => RequestOperationType::SetDisasterRecovery,
+            RequestOperation::AddNetwork(_) => RequestOperationType::AddNetwork,
+            RequestOperation::EditNetwork(_) => RequestOperationType::EditNetwork,
+            RequestOperation::FreezeBlockchain(_) => RequestOperationType::FreezeBlockchain,
+            RequestOperation::UnfreezeBlockchain(_) => RequestOperationType::UnfreezeBlockchain,
+            RequestOperation::DecommissionStation(_) => RequestOperationType::DecommissionStation,
+        }
+    }
+}
+
+impl From<&RequestOperationInput> for RequestOperationType {
+    fn from(operation: &RequestOperationInput) -> Self {
+        match operation {
+            RequestOperationInput::Transfer(_) => RequestOperationType::Transfer,
+            RequestOperationInput::AddAccount(_) => RequestOperationType::AddAccount,
+            RequestOperationInput::EditAccount(_) => RequestOperationType::EditAccount,
+            RequestOperationInput::RotateAccountAddress(_) => {
+                RequestOperationType::RotateAccountAddress
+            }
+            RequestOperationInput::TransferAccountOwnership(_) => {
+                RequestOperationType::TransferAccountOwnership
+            }
+            RequestOperationInput::AddAddressBookEntry(_) => {
+                RequestOperationType::AddAddressBookEntry
+            }
+            RequestOperationInput::EditAddressBookEntry(_) => {
+                RequestOperationType::EditAddressBookEntry
+            }
+            RequestOperationInput::RemoveAddressBookEntry(_) => {
+                RequestOperationType::RemoveAddressBookEntry
+            }
+            RequestOperationInput::AddUser(_) => RequestOperationType::AddUser,
+            RequestOperationInput::EditUser(_) => RequestOperationType::EditUser,
+            RequestOperationInput::AddUserGroup(_) => RequestOperationType::AddUserGroup,
+            RequestOperationInput::EditUserGroup(_) => RequestOperationType::EditUserGroup,
+            RequestOperationInput::RemoveUserGroup(_) => RequestOperationType::RemoveUserGroup,
+            RequestOperationInput::SystemUpgrade(_) => RequestOperationType::SystemUpgrade,
+            RequestOperationInput::ChangeExternalCanister(_) => {
+                RequestOperationType::ChangeExternalCanister
+            }
+            RequestOperationInput::ConfigureExternalCanister(_) => {
+                RequestOperationType::ConfigureExternalCanister
+            }
+            RequestOperationInput::CreateExternalCanister(_) => {
+                RequestOperationType::CreateExternalCanister
+            }
+            RequestOperationInput::FundExternalCanister(_) => {
+                RequestOperationType::FundExternalCanister
+            }
+            RequestOperationInput::CallExternalCanister(_) => {
+                RequestOperationType::CallExternalCanister
+            }
+            RequestOperationInput::CommitAssetBatch(_) => RequestOperationType::CommitAssetBatch,
+            RequestOperationInput::SnapshotExternalCanister(_) => {
+                RequestOperationType::SnapshotExternalCanister
+            }
+            RequestOperationInput::EditPermission(_) => RequestOperationType::EditPermission,
+            RequestOperationInput::BulkEditPermission(_) => {
+                RequestOperationType::BulkEditPermission
+            }
+            RequestOperationInput::AddRequestPolicy(_) => RequestOperationType::AddRequestPolicy,
+            RequestOperationInput::EditRequestPolicy(_) => RequestOperationType::EditRequestPolicy,
+            RequestOperationInput::RemoveRequestPolicy(_) => {
+                RequestOperationType::RemoveRequestPolicy
+            }
+            RequestOperationInput::ManageSystemInfo(_) => RequestOperationType::ManageSystemInfo,
+            RequestOperationInput::SetDisasterRecovery(_) => {
+                RequestOperationType::SetDisasterRecovery
+            }
+            RequestOperationInput::AddNetwork(_) => RequestOperationType::AddNetwork,
+            RequestOperationInput::EditNetwork(_) => RequestOperationType::EditNetwork,
+            RequestOperationInput::FreezeBlockchain(_) => RequestOperationType::FreezeBlockchain,
+            RequestOperationInput::UnfreezeBlockchain(_) => {
+                RequestOperationType::UnfreezeBlockchain
+            }
+            RequestOperationInput::DecommissionStation(_) => {
+                RequestOperationType::DecommissionStation
+            }
         }
     }
 }
@@ -236,6 +388,14 @@ impl RequestOperation {
             }
             (RequestOperation::AddAccount(_), ListRequestsOperationTypeDTO::AddAccount) => true,
             (RequestOperation::EditAccount(_), ListRequestsOperationTypeDTO::EditAccount) => true,
+            (
+                RequestOperation::RotateAccountAddress(_),
+                ListRequestsOperationTypeDTO::RotateAccountAddress,
+            ) => true,
+            (
+                RequestOperation::TransferAccountOwnership(_),
+                ListRequestsOperationTypeDTO::TransferAccountOwnership,
+            ) => true,
             (
                 RequestOperation::AddAddressBookEntry(_),
                 ListRequestsOperationTypeDTO::AddAddressBookEntry,
@@ -285,9 +445,33 @@ impl RequestOperation {
                     true
                 }
             }
+            (
+                RequestOperation::CommitAssetBatch(operation),
+                ListRequestsOperationTypeDTO::CommitAssetBatch(target),
+            ) => {
+                if let Some(canister_id) = target {
+                    operation.input.canister_id == *canister_id
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::SnapshotExternalCanister(operation),
+                ListRequestsOperationTypeDTO::SnapshotExternalCanister(target),
+            ) => {
+                if let Some(canister_id) = target {
+                    operation.input.canister_id == *canister_id
+                } else {
+                    true
+                }
+            }
             (RequestOperation::EditPermission(_), ListRequestsOperationTypeDTO::EditPermission) => {
                 true
             }
+            (
+                RequestOperation::BulkEditPermission(_),
+                ListRequestsOperationTypeDTO::BulkEditPermission,
+            ) => true,
             (
                 RequestOperation::AddRequestPolicy(_),
                 ListRequestsOperationTypeDTO::AddRequestPolicy,
@@ -304,6 +488,20 @@ impl RequestOperation {
                 RequestOperation::ManageSystemInfo(_),
                 ListRequestsOperationTypeDTO::ManageSystemInfo,
             ) => true,
+            (RequestOperation::AddNetwork(_), ListRequestsOperationTypeDTO::AddNetwork) => true,
+            (RequestOperation::EditNetwork(_), ListRequestsOperationTypeDTO::EditNetwork) => true,
+            (
+                RequestOperation::FreezeBlockchain(_),
+                ListRequestsOperationTypeDTO::FreezeBlockchain,
+            ) => true,
+            (
+                RequestOperation::UnfreezeBlockchain(_),
+                ListRequestsOperationTypeDTO::UnfreezeBlockchain,
+            ) => true,
+            (
+                RequestOperation::DecommissionStation(_),
+                ListRequestsOperationTypeDTO::DecommissionStation,
+            ) => true,
             _ => false,
         }
     }
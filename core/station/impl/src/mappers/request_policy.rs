@@ -1,17 +1,18 @@
 use super::HelperMapper;
 use crate::models::{
-    request_policy_rule::RequestPolicyRule,
+    request_policy_rule::{GroupVoteWeight, RequestPolicyRule},
     request_specifier::{RequestSpecifier, ResourceSpecifier, UserSpecifier},
     resource::{
         AccountResourceAction, ExternalCanisterResourceAction, PermissionResourceAction, Resource,
         ResourceAction, ResourceId, ResourceIds, SystemResourceAction, UserResourceAction,
     },
     EvaluatedRequestPolicyRule, EvaluationStatus, Percentage, RequestEvaluationResult,
-    RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyRuleResult,
+    RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyIssue, RequestPolicyRuleResult,
 };
 use station_api::{
-    EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO, QuorumDTO, QuorumPercentageDTO,
-    RequestEvaluationResultDTO, RequestPolicyRuleDTO, RequestPolicyRuleResultDTO, UserSpecifierDTO,
+    EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO, GroupVoteWeightDTO, QuorumDTO,
+    QuorumPercentageDTO, RequestEvaluationResultDTO, RequestPolicyIssueDTO, RequestPolicyRuleDTO,
+    RequestPolicyRuleResultDTO, UserSpecifierDTO, WeightedQuorumDTO,
 };
 use uuid::Uuid;
 
@@ -31,6 +32,12 @@ impl From<RequestPolicyRule> for RequestPolicyRuleDTO {
                     min_approved,
                 })
             }
+            RequestPolicyRule::WeightedQuorum(groups, min_weight) => {
+                RequestPolicyRuleDTO::WeightedQuorum(WeightedQuorumDTO {
+                    groups: groups.into_iter().map(Into::into).collect(),
+                    min_weight,
+                })
+            }
             RequestPolicyRule::AllowListedByMetadata(metadata) => {
                 RequestPolicyRuleDTO::AllowListedByMetadata(metadata.into())
             }
@@ -59,6 +66,10 @@ impl From<RequestPolicyRuleDTO> for RequestPolicyRule {
             RequestPolicyRuleDTO::Quorum(config) => {
                 RequestPolicyRule::Quorum(config.approvers.into(), config.min_approved)
             }
+            RequestPolicyRuleDTO::WeightedQuorum(config) => RequestPolicyRule::WeightedQuorum(
+                config.groups.into_iter().map(Into::into).collect(),
+                config.min_weight,
+            ),
             RequestPolicyRuleDTO::AllowListedByMetadata(metadata) => {
                 RequestPolicyRule::AllowListedByMetadata(metadata.into())
             }
@@ -114,6 +125,7 @@ impl From<EvaluatedRequestPolicyRule> for EvaluatedRequestPolicyRuleDTO {
                 min_approved,
                 total_possible_approvers,
                 approvers,
+                requester_excluded,
             } => EvaluatedRequestPolicyRuleDTO::QuorumPercentage {
                 min_approved,
                 total_possible_approvers,
@@ -121,11 +133,13 @@ impl From<EvaluatedRequestPolicyRule> for EvaluatedRequestPolicyRuleDTO {
                     .into_iter()
                     .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
                     .collect(),
+                requester_excluded,
             },
             EvaluatedRequestPolicyRule::Quorum {
                 min_approved,
                 total_possible_approvers,
                 approvers,
+                requester_excluded,
             } => EvaluatedRequestPolicyRuleDTO::Quorum {
                 min_approved,
                 total_possible_approvers,
@@ -133,6 +147,25 @@ impl From<EvaluatedRequestPolicyRule> for EvaluatedRequestPolicyRuleDTO {
                     .into_iter()
                     .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
                     .collect(),
+                requester_excluded,
+            },
+            EvaluatedRequestPolicyRule::WeightedQuorum {
+                min_weight,
+                total_possible_weight,
+                approved_weight,
+                rejected_weight,
+                approvers,
+                requester_excluded,
+            } => EvaluatedRequestPolicyRuleDTO::WeightedQuorum {
+                min_weight,
+                total_possible_weight,
+                approved_weight,
+                rejected_weight,
+                approvers: approvers
+                    .into_iter()
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                    .collect(),
+                requester_excluded,
             },
             EvaluatedRequestPolicyRule::AllowListedByMetadata { metadata } => {
                 EvaluatedRequestPolicyRuleDTO::AllowListedByMetadata {
@@ -153,6 +186,28 @@ impl From<EvaluatedRequestPolicyRule> for EvaluatedRequestPolicyRuleDTO {
     }
 }
 
+impl From<GroupVoteWeight> for GroupVoteWeightDTO {
+    fn from(group_weight: GroupVoteWeight) -> Self {
+        GroupVoteWeightDTO {
+            group_id: Uuid::from_bytes(group_weight.group_id)
+                .hyphenated()
+                .to_string(),
+            weight: group_weight.weight,
+        }
+    }
+}
+
+impl From<GroupVoteWeightDTO> for GroupVoteWeight {
+    fn from(dto: GroupVoteWeightDTO) -> Self {
+        GroupVoteWeight {
+            group_id: *HelperMapper::to_uuid(dto.group_id)
+                .expect("invalid uuid")
+                .as_bytes(),
+            weight: dto.weight,
+        }
+    }
+}
+
 impl From<UserSpecifierDTO> for UserSpecifier {
     fn from(dto: UserSpecifierDTO) -> Self {
         match dto {
@@ -217,6 +272,9 @@ impl RequestPolicy {
             id: Uuid::from_bytes(self.id).hyphenated().to_string(),
             specifier: self.specifier.into(),
             rule: self.rule.into(),
+            checklist: self.checklist,
+            exclude_requester: self.exclude_requester,
+            version: self.version,
         }
     }
 }
@@ -497,3 +555,45 @@ impl RequestSpecifier {
         }
     }
 }
+
+impl From<RequestPolicyIssue> for RequestPolicyIssueDTO {
+    fn from(issue: RequestPolicyIssue) -> Self {
+        let uuid = |id| Uuid::from_bytes(id).hyphenated().to_string();
+
+        match issue {
+            RequestPolicyIssue::EmptyApproverGroup {
+                policy_id,
+                group_id,
+            } => RequestPolicyIssueDTO::EmptyApproverGroup {
+                policy_id: uuid(policy_id),
+                group_id: uuid(group_id),
+            },
+            RequestPolicyIssue::NoPossibleApprovers { policy_id } => {
+                RequestPolicyIssueDTO::NoPossibleApprovers {
+                    policy_id: uuid(policy_id),
+                }
+            }
+            RequestPolicyIssue::ThresholdAboveApprovers {
+                policy_id,
+                threshold,
+                possible,
+            } => RequestPolicyIssueDTO::ThresholdAboveApprovers {
+                policy_id: uuid(policy_id),
+                threshold,
+                possible,
+            },
+            RequestPolicyIssue::UncoveredOperationType { operation_type } => {
+                RequestPolicyIssueDTO::UncoveredOperationType {
+                    operation_type: operation_type.into(),
+                }
+            }
+            RequestPolicyIssue::ShadowedSpecifier {
+                policy_id,
+                shadowed_by,
+            } => RequestPolicyIssueDTO::ShadowedSpecifier {
+                policy_id: uuid(policy_id),
+                shadowed_by: uuid(shadowed_by),
+            },
+        }
+    }
+}
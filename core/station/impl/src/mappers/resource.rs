@@ -155,6 +155,12 @@ impl From<station_api::AccountResourceActionDTO> for AccountResourceAction {
             station_api::AccountResourceActionDTO::Update(id) => {
                 AccountResourceAction::Update(id.into())
             }
+            station_api::AccountResourceActionDTO::Reconcile(id) => {
+                AccountResourceAction::Reconcile(id.into())
+            }
+            station_api::AccountResourceActionDTO::Review(id) => {
+                AccountResourceAction::Review(id.into())
+            }
         }
     }
 }
@@ -173,6 +179,12 @@ impl From<AccountResourceAction> for station_api::AccountResourceActionDTO {
             AccountResourceAction::Update(id) => {
                 station_api::AccountResourceActionDTO::Update(id.into())
             }
+            AccountResourceAction::Reconcile(id) => {
+                station_api::AccountResourceActionDTO::Reconcile(id.into())
+            }
+            AccountResourceAction::Review(id) => {
+                station_api::AccountResourceActionDTO::Review(id.into())
+            }
         }
     }
 }
@@ -0,0 +1,91 @@
+use crate::models::{EventSubscriber, StationEvent, StationEventKind};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{
+    DepositDetectedEventDTO, EventSubscriberDTO, ExternalCanisterModuleDriftedEventDTO,
+    RequestExecutedEventDTO, StationEventDTO, StationEventKindDTO,
+};
+use uuid::Uuid;
+
+impl From<StationEventKindDTO> for StationEventKind {
+    fn from(kind: StationEventKindDTO) -> Self {
+        match kind {
+            StationEventKindDTO::RequestExecuted => StationEventKind::RequestExecuted,
+            StationEventKindDTO::DepositDetected => StationEventKind::DepositDetected,
+            StationEventKindDTO::ExternalCanisterModuleDrifted => {
+                StationEventKind::ExternalCanisterModuleDrifted
+            }
+        }
+    }
+}
+
+impl From<StationEventKind> for StationEventKindDTO {
+    fn from(kind: StationEventKind) -> Self {
+        match kind {
+            StationEventKind::RequestExecuted => StationEventKindDTO::RequestExecuted,
+            StationEventKind::DepositDetected => StationEventKindDTO::DepositDetected,
+            StationEventKind::ExternalCanisterModuleDrifted => {
+                StationEventKindDTO::ExternalCanisterModuleDrifted
+            }
+        }
+    }
+}
+
+impl From<StationEvent> for StationEventDTO {
+    fn from(event: StationEvent) -> Self {
+        match event {
+            StationEvent::RequestExecuted {
+                request_id,
+                completed_at,
+            } => StationEventDTO::RequestExecuted(RequestExecutedEventDTO {
+                request_id: Uuid::from_bytes(request_id).hyphenated().to_string(),
+                completed_at: timestamp_to_rfc3339(&completed_at),
+            }),
+            StationEvent::DepositDetected {
+                account_id,
+                amount,
+                balance,
+                detected_at,
+            } => StationEventDTO::DepositDetected(DepositDetectedEventDTO {
+                account_id: Uuid::from_bytes(account_id).hyphenated().to_string(),
+                amount,
+                balance,
+                detected_at: timestamp_to_rfc3339(&detected_at),
+            }),
+            StationEvent::ExternalCanisterModuleDrifted {
+                external_canister_id,
+                canister_id,
+                expected_module_hash,
+                found_module_hash,
+                detected_at,
+            } => StationEventDTO::ExternalCanisterModuleDrifted(
+                ExternalCanisterModuleDriftedEventDTO {
+                    external_canister_id: Uuid::from_bytes(external_canister_id)
+                        .hyphenated()
+                        .to_string(),
+                    canister_id,
+                    expected_module_hash: expected_module_hash.map(hex::encode),
+                    found_module_hash: found_module_hash.map(hex::encode),
+                    detected_at: timestamp_to_rfc3339(&detected_at),
+                },
+            ),
+        }
+    }
+}
+
+impl EventSubscriber {
+    pub fn to_dto(self, pending_events: usize) -> EventSubscriberDTO {
+        EventSubscriberDTO {
+            id: Uuid::from_bytes(self.id).hyphenated().to_string(),
+            canister_id: self.canister_id,
+            method_name: self.method_name,
+            events: self.events.into_iter().map(Into::into).collect(),
+            created_at: timestamp_to_rfc3339(&self.created_at),
+            pending_events: pending_events as u64,
+            acknowledged_events: self.acknowledged_events,
+            last_acknowledged_at: self
+                .last_acknowledged_at
+                .map(|timestamp| timestamp_to_rfc3339(&timestamp)),
+            last_error: self.last_error,
+        }
+    }
+}
@@ -1,9 +1,54 @@
-use crate::{models::system::SystemInfo, repositories::USER_GROUP_REPOSITORY};
+use super::{blockchain::BlockchainMapper, HelperMapper};
+use crate::{
+    core::{is_system_state_replica_consistent, tracing::TraceSpan, usage::PrincipalUsage},
+    errors::MapperError,
+    models::{
+        system::{
+            AddressBookSource, ApprovalReminderConfig, AssetLogo, AuditorAccess,
+            BalanceSnapshotConfig, BrandingConfig, DataCompactionConfig, FiatValuationConfig,
+            InitStep, LedgerCanisterConfig, MaintenanceModeConfig, MetadataEncryptionConfig,
+            OperationAvailability, OperationFlag, RequestArchivalConfig, RequestTextLimits,
+            SystemInfo, SystemInitProgress, TransferComplianceThreshold, TransferMaxFee,
+            TransferScreeningConfig, TransferTimeLockConfig,
+        },
+        LogLevel, StationLogEntry,
+    },
+    repositories::USER_GROUP_REPOSITORY,
+};
 use orbit_essentials::{
     repository::Repository,
+    types::Timestamp,
     utils::{raw_rand_successful, timestamp_to_rfc3339},
 };
-use station_api::DisasterRecoveryDTO;
+use station_api::{
+    DisasterRecoveryDTO, IndexRebuildProgressDTO, InitStatusDTO, InitStepDTO, LogLevelDTO,
+    PrincipalUsageDTO, RequestOperationUsageDTO, StationLogEntryDTO, TraceSpanDTO,
+};
+use uuid::Uuid;
+
+impl From<InitStep> for InitStepDTO {
+    fn from(step: InitStep) -> Self {
+        match step {
+            InitStep::DefaultConfigs => InitStepDTO::DefaultConfigs,
+            InitStep::Upgrader => InitStepDTO::Upgrader,
+            InitStep::Controllers => InitStepDTO::Controllers,
+            InitStep::InitialAccounts => InitStepDTO::InitialAccounts,
+            InitStep::Completed => InitStepDTO::Completed,
+        }
+    }
+}
+
+impl From<SystemInitProgress> for InitStatusDTO {
+    fn from(progress: SystemInitProgress) -> Self {
+        InitStatusDTO {
+            next_step: progress.next_step.into(),
+            upgrader_canister_id: progress.upgrader_canister_id,
+            attempts: progress.attempts,
+            last_attempt_at: progress.last_attempt_at.as_ref().map(timestamp_to_rfc3339),
+            last_error: progress.last_error,
+        }
+    }
+}
 
 impl SystemInfo {
     pub fn to_dto(&self, cycles: &u64, version: &str) -> station_api::SystemInfoDTO {
@@ -23,6 +68,695 @@ impl SystemInfo {
                 }
             }),
             cycle_obtain_strategy: (*self.get_cycle_obtain_strategy()).into(),
+            index_rebuild: self
+                .get_index_rebuild()
+                .map(|progress| IndexRebuildProgressDTO {
+                    pending: progress
+                        .pending
+                        .iter()
+                        .map(|target| target.to_string())
+                        .collect(),
+                    processed_entries: progress.processed_entries,
+                    started_at: timestamp_to_rfc3339(&progress.started_at),
+                    last_progress_at: timestamp_to_rfc3339(&progress.last_progress_at),
+                }),
+            config_replica_consistent: is_system_state_replica_consistent(),
+            auditors: self
+                .get_auditors()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            address_book_sources: self
+                .get_address_book_sources()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            name_service: (*self.get_name_service()).into(),
+            transfer_compliance_thresholds: self
+                .get_transfer_compliance_thresholds()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            transfer_max_fees: self
+                .get_transfer_max_fees()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            transfer_screening: self.get_transfer_screening().clone().into(),
+            transfer_time_lock: self.get_transfer_time_lock().clone().into(),
+            metadata_encryption: self.get_metadata_encryption().clone().into(),
+            data_compaction: self.get_data_compaction().clone().into(),
+            request_archival: self.get_request_archival().clone().into(),
+            branding: self.get_branding().clone().into(),
+            sub_treasuries: self
+                .get_sub_treasuries()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            maintenance_mode: self.get_maintenance_mode().clone().into(),
+            in_maintenance: self.is_in_maintenance(),
+            request_text_limits: self.get_request_text_limits().clone().into(),
+            approval_reminders: self.get_approval_reminders().clone().into(),
+            balance_snapshots: self.get_balance_snapshots().clone().into(),
+            networks: self.get_networks().into_iter().map(Into::into).collect(),
+            fiat_valuation: self.get_fiat_valuation().clone().into(),
+            operation_flags: self
+                .get_operation_flags()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            mock_blockchains: self.is_mock_blockchains(),
+            decommission: self.get_decommission().map(|decommission| {
+                station_api::StationDecommissionDTO {
+                    request_id: Uuid::from_bytes(decommission.request_id)
+                        .hyphenated()
+                        .to_string(),
+                    reason: decommission.reason.clone(),
+                    decommissioned_at: timestamp_to_rfc3339(&decommission.decommissioned_at),
+                }
+            }),
+            frozen_blockchains: self
+                .get_frozen_blockchains()
+                .iter()
+                .map(|freeze| station_api::BlockchainFreezeDTO {
+                    blockchain: freeze.blockchain.to_string(),
+                    network: freeze.network.clone(),
+                    reason: freeze.reason.clone(),
+                    request_id: Uuid::from_bytes(freeze.request_id).hyphenated().to_string(),
+                    frozen_at: timestamp_to_rfc3339(&freeze.frozen_at),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<AddressBookSource> for station_api::AddressBookSourceDTO {
+    fn from(source: AddressBookSource) -> Self {
+        station_api::AddressBookSourceDTO {
+            canister_id: source.canister_id,
+            labels: source.labels,
+            last_synced_at: source
+                .last_synced_at
+                .map(|synced_at| timestamp_to_rfc3339(&synced_at)),
+            last_sync_error: source.last_sync_error,
+        }
+    }
+}
+
+impl From<AddressBookSource> for station_api::AddressBookSourceInput {
+    fn from(source: AddressBookSource) -> Self {
+        station_api::AddressBookSourceInput {
+            canister_id: source.canister_id,
+            labels: source.labels,
+        }
+    }
+}
+
+impl From<station_api::AddressBookSourceInput> for AddressBookSource {
+    fn from(input: station_api::AddressBookSourceInput) -> Self {
+        AddressBookSource::new(input.canister_id, input.labels)
+    }
+}
+
+impl From<AuditorAccess> for station_api::AuditorAccessDTO {
+    fn from(auditor: AuditorAccess) -> Self {
+        station_api::AuditorAccessDTO {
+            principal: auditor.principal,
+            name: auditor.name,
+            expires_at: timestamp_to_rfc3339(&auditor.expires_at),
+        }
+    }
+}
+
+impl TryFrom<station_api::AuditorAccessDTO> for AuditorAccess {
+    type Error = MapperError;
+
+    fn try_from(dto: station_api::AuditorAccessDTO) -> Result<Self, Self::Error> {
+        Ok(AuditorAccess {
+            principal: dto.principal,
+            name: dto.name,
+            expires_at: HelperMapper::to_timestamp("expires_at", &dto.expires_at)?,
+        })
+    }
+}
+
+impl From<station_api::LedgerCanisterInput> for LedgerCanisterConfig {
+    fn from(input: station_api::LedgerCanisterInput) -> Self {
+        LedgerCanisterConfig {
+            symbol: input.symbol,
+            network: input.network,
+            ledger_canister_id: input.ledger_canister_id,
+            index_canister_id: input.index_canister_id,
+        }
+    }
+}
+
+impl From<LedgerCanisterConfig> for station_api::LedgerCanisterInput {
+    fn from(config: LedgerCanisterConfig) -> Self {
+        station_api::LedgerCanisterInput {
+            symbol: config.symbol,
+            network: config.network,
+            ledger_canister_id: config.ledger_canister_id,
+            index_canister_id: config.index_canister_id,
+        }
+    }
+}
+
+impl From<TransferComplianceThreshold> for station_api::TransferComplianceThresholdDTO {
+    fn from(threshold: TransferComplianceThreshold) -> Self {
+        station_api::TransferComplianceThresholdDTO {
+            symbol: threshold.symbol,
+            amount: threshold.amount,
+        }
+    }
+}
+
+impl From<station_api::TransferComplianceThresholdDTO> for TransferComplianceThreshold {
+    fn from(threshold: station_api::TransferComplianceThresholdDTO) -> Self {
+        TransferComplianceThreshold {
+            symbol: threshold.symbol,
+            amount: threshold.amount,
+        }
+    }
+}
+
+impl From<TransferMaxFee> for station_api::TransferMaxFeeDTO {
+    fn from(max_fee: TransferMaxFee) -> Self {
+        station_api::TransferMaxFeeDTO {
+            symbol: max_fee.symbol,
+            max_fee: max_fee.max_fee,
+        }
+    }
+}
+
+impl From<station_api::TransferMaxFeeDTO> for TransferMaxFee {
+    fn from(max_fee: station_api::TransferMaxFeeDTO) -> Self {
+        TransferMaxFee {
+            symbol: max_fee.symbol,
+            max_fee: max_fee.max_fee,
+        }
+    }
+}
+
+impl From<TransferScreeningConfig> for station_api::TransferScreeningConfigDTO {
+    fn from(config: TransferScreeningConfig) -> Self {
+        match config {
+            TransferScreeningConfig::Disabled => station_api::TransferScreeningConfigDTO::Disabled,
+            TransferScreeningConfig::Canister {
+                canister_id,
+                thresholds,
+                blocks_approval,
+            } => station_api::TransferScreeningConfigDTO::Canister {
+                canister_id,
+                thresholds: thresholds.into_iter().map(Into::into).collect(),
+                blocks_approval,
+            },
+        }
+    }
+}
+
+impl From<station_api::TransferScreeningConfigDTO> for TransferScreeningConfig {
+    fn from(config: station_api::TransferScreeningConfigDTO) -> Self {
+        match config {
+            station_api::TransferScreeningConfigDTO::Disabled => TransferScreeningConfig::Disabled,
+            station_api::TransferScreeningConfigDTO::Canister {
+                canister_id,
+                thresholds,
+                blocks_approval,
+            } => TransferScreeningConfig::Canister {
+                canister_id,
+                thresholds: thresholds.into_iter().map(Into::into).collect(),
+                blocks_approval,
+            },
+        }
+    }
+}
+
+impl From<TransferTimeLockConfig> for station_api::TransferTimeLockConfigDTO {
+    fn from(config: TransferTimeLockConfig) -> Self {
+        match config {
+            TransferTimeLockConfig::Disabled => station_api::TransferTimeLockConfigDTO::Disabled,
+            TransferTimeLockConfig::Enabled {
+                thresholds,
+                delay_secs,
+                canceller_group_ids,
+            } => station_api::TransferTimeLockConfigDTO::Enabled {
+                thresholds: thresholds.into_iter().map(Into::into).collect(),
+                delay_secs,
+                canceller_group_ids: canceller_group_ids
+                    .iter()
+                    .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<station_api::TransferTimeLockConfigDTO> for TransferTimeLockConfig {
+    fn from(config: station_api::TransferTimeLockConfigDTO) -> Self {
+        match config {
+            station_api::TransferTimeLockConfigDTO::Disabled => TransferTimeLockConfig::Disabled,
+            station_api::TransferTimeLockConfigDTO::Enabled {
+                thresholds,
+                delay_secs,
+                canceller_group_ids,
+            } => TransferTimeLockConfig::Enabled {
+                thresholds: thresholds.into_iter().map(Into::into).collect(),
+                delay_secs,
+                canceller_group_ids: canceller_group_ids
+                    .into_iter()
+                    .map(|id| {
+                        *HelperMapper::to_uuid(id)
+                            .expect("Invalid user group id")
+                            .as_bytes()
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<SubTreasuryVisibility> for station_api::SubTreasuryVisibilityDTO {
+    fn from(visibility: SubTreasuryVisibility) -> Self {
+        match visibility {
+            SubTreasuryVisibility::Isolated => station_api::SubTreasuryVisibilityDTO::Isolated,
+            SubTreasuryVisibility::Shared => station_api::SubTreasuryVisibilityDTO::Shared,
+        }
+    }
+}
+
+impl From<station_api::SubTreasuryVisibilityDTO> for SubTreasuryVisibility {
+    fn from(visibility: station_api::SubTreasuryVisibilityDTO) -> Self {
+        match visibility {
+            station_api::SubTreasuryVisibilityDTO::Isolated => SubTreasuryVisibility::Isolated,
+            station_api::SubTreasuryVisibilityDTO::Shared => SubTreasuryVisibility::Shared,
+        }
+    }
+}
+
+impl From<SubTreasury> for station_api::SubTreasuryDTO {
+    fn from(sub_treasury: SubTreasury) -> Self {
+        station_api::SubTreasuryDTO {
+            name: sub_treasury.name,
+            account_ids: sub_treasury
+                .account_ids
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
+            admin_group_id: Uuid::from_bytes(sub_treasury.admin_group_id)
+                .hyphenated()
+                .to_string(),
+            member_group_ids: sub_treasury
+                .member_group_ids
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
+            visibility: sub_treasury.visibility.into(),
+        }
+    }
+}
+
+impl From<station_api::SubTreasuryDTO> for SubTreasury {
+    fn from(sub_treasury: station_api::SubTreasuryDTO) -> Self {
+        SubTreasury {
+            name: sub_treasury.name,
+            account_ids: sub_treasury
+                .account_ids
+                .into_iter()
+                .map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                })
+                .collect(),
+            admin_group_id: *HelperMapper::to_uuid(sub_treasury.admin_group_id)
+                .expect("Invalid user group id")
+                .as_bytes(),
+            member_group_ids: sub_treasury
+                .member_group_ids
+                .into_iter()
+                .map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid user group id")
+                        .as_bytes()
+                })
+                .collect(),
+            visibility: sub_treasury.visibility.into(),
+        }
+    }
+}
+
+impl From<MaintenanceModeConfig> for station_api::MaintenanceModeConfigDTO {
+    fn from(config: MaintenanceModeConfig) -> Self {
+        station_api::MaintenanceModeConfigDTO {
+            enabled: config.enabled,
+            reason: config.reason,
+            during_migrations: config.during_migrations,
+        }
+    }
+}
+
+impl From<station_api::MaintenanceModeConfigDTO> for MaintenanceModeConfig {
+    fn from(config: station_api::MaintenanceModeConfigDTO) -> Self {
+        MaintenanceModeConfig {
+            enabled: config.enabled,
+            reason: config.reason,
+            during_migrations: config.during_migrations,
+        }
+    }
+}
+
+impl From<RequestTextLimits> for station_api::RequestTextLimitsDTO {
+    fn from(limits: RequestTextLimits) -> Self {
+        station_api::RequestTextLimitsDTO {
+            max_title_len: limits.max_title_len,
+            max_summary_len: limits.max_summary_len,
+        }
+    }
+}
+
+impl From<station_api::RequestTextLimitsDTO> for RequestTextLimits {
+    fn from(limits: station_api::RequestTextLimitsDTO) -> Self {
+        RequestTextLimits {
+            max_title_len: limits.max_title_len,
+            max_summary_len: limits.max_summary_len,
+        }
+    }
+}
+
+impl From<ApprovalReminderConfig> for station_api::ApprovalReminderConfigDTO {
+    fn from(config: ApprovalReminderConfig) -> Self {
+        station_api::ApprovalReminderConfigDTO {
+            intervals_secs: config.intervals_secs,
+        }
+    }
+}
+
+impl From<station_api::ApprovalReminderConfigDTO> for ApprovalReminderConfig {
+    fn from(config: station_api::ApprovalReminderConfigDTO) -> Self {
+        ApprovalReminderConfig {
+            intervals_secs: config.intervals_secs,
+        }
+    }
+}
+
+impl From<BalanceSnapshotConfig> for station_api::BalanceSnapshotConfigDTO {
+    fn from(config: BalanceSnapshotConfig) -> Self {
+        station_api::BalanceSnapshotConfigDTO {
+            interval_secs: config.interval_secs,
+        }
+    }
+}
+
+impl From<station_api::BalanceSnapshotConfigDTO> for BalanceSnapshotConfig {
+    fn from(config: station_api::BalanceSnapshotConfigDTO) -> Self {
+        BalanceSnapshotConfig {
+            interval_secs: config.interval_secs,
+        }
+    }
+}
+
+impl From<FiatValuationConfig> for station_api::FiatValuationConfigDTO {
+    fn from(config: FiatValuationConfig) -> Self {
+        match config {
+            FiatValuationConfig::Disabled => station_api::FiatValuationConfigDTO::Disabled,
+            FiatValuationConfig::Canister {
+                canister_id,
+                currencies,
+            } => station_api::FiatValuationConfigDTO::Canister {
+                canister_id,
+                currencies,
+            },
+        }
+    }
+}
+
+impl From<station_api::FiatValuationConfigDTO> for FiatValuationConfig {
+    fn from(config: station_api::FiatValuationConfigDTO) -> Self {
+        match config {
+            station_api::FiatValuationConfigDTO::Disabled => FiatValuationConfig::Disabled,
+            station_api::FiatValuationConfigDTO::Canister {
+                canister_id,
+                currencies,
+            } => FiatValuationConfig::Canister {
+                canister_id,
+                currencies,
+            },
+        }
+    }
+}
+
+impl From<OperationFlag> for station_api::OperationFlagDTO {
+    fn from(flag: OperationFlag) -> Self {
+        station_api::OperationFlagDTO {
+            operation_type: flag.operation_type.into(),
+            availability: match flag.availability {
+                OperationAvailability::Deprecated => {
+                    station_api::OperationAvailabilityDTO::Deprecated
+                }
+                OperationAvailability::Disabled => station_api::OperationAvailabilityDTO::Disabled,
+            },
+        }
+    }
+}
+
+impl From<station_api::OperationFlagDTO> for OperationFlag {
+    fn from(flag: station_api::OperationFlagDTO) -> Self {
+        OperationFlag {
+            operation_type: flag.operation_type.into(),
+            availability: match flag.availability {
+                station_api::OperationAvailabilityDTO::Deprecated => {
+                    OperationAvailability::Deprecated
+                }
+                station_api::OperationAvailabilityDTO::Disabled => OperationAvailability::Disabled,
+            },
+        }
+    }
+}
+
+impl From<DataCompactionConfig> for station_api::DataCompactionConfigDTO {
+    fn from(config: DataCompactionConfig) -> Self {
+        match config {
+            DataCompactionConfig::Disabled => station_api::DataCompactionConfigDTO::Disabled,
+            DataCompactionConfig::Enabled {
+                min_age_days,
+                export_canister_id,
+            } => station_api::DataCompactionConfigDTO::Enabled {
+                min_age_days,
+                export_canister_id,
+            },
+        }
+    }
+}
+
+impl From<station_api::DataCompactionConfigDTO> for DataCompactionConfig {
+    fn from(config: station_api::DataCompactionConfigDTO) -> Self {
+        match config {
+            station_api::DataCompactionConfigDTO::Disabled => DataCompactionConfig::Disabled,
+            station_api::DataCompactionConfigDTO::Enabled {
+                min_age_days,
+                export_canister_id,
+            } => DataCompactionConfig::Enabled {
+                min_age_days,
+                export_canister_id,
+            },
+        }
+    }
+}
+
+impl From<RequestArchivalConfig> for station_api::RequestArchivalConfigDTO {
+    fn from(config: RequestArchivalConfig) -> Self {
+        match config {
+            RequestArchivalConfig::Disabled => station_api::RequestArchivalConfigDTO::Disabled,
+            RequestArchivalConfig::Enabled {
+                min_age_days,
+                archive_canister_id,
+            } => station_api::RequestArchivalConfigDTO::Enabled {
+                min_age_days,
+                archive_canister_id,
+            },
+        }
+    }
+}
+
+impl From<station_api::RequestArchivalConfigDTO> for RequestArchivalConfig {
+    fn from(config: station_api::RequestArchivalConfigDTO) -> Self {
+        match config {
+            station_api::RequestArchivalConfigDTO::Disabled => RequestArchivalConfig::Disabled,
+            station_api::RequestArchivalConfigDTO::Enabled {
+                min_age_days,
+                archive_canister_id,
+            } => RequestArchivalConfig::Enabled {
+                min_age_days,
+                archive_canister_id,
+            },
+        }
+    }
+}
+
+impl From<BrandingConfig> for station_api::BrandingConfigDTO {
+    fn from(config: BrandingConfig) -> Self {
+        let to_dto_id = |id| Uuid::from_bytes(id).hyphenated().to_string();
+
+        station_api::BrandingConfigDTO {
+            logo_id: config.logo_id.map(to_dto_id),
+            asset_logos: config
+                .asset_logos
+                .into_iter()
+                .map(|logo| station_api::AssetLogoDTO {
+                    blockchain: logo.blockchain.to_string(),
+                    standard: logo.standard.to_string(),
+                    symbol: logo.symbol,
+                    logo_id: to_dto_id(logo.logo_id),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<station_api::BrandingConfigDTO> for BrandingConfig {
+    type Error = MapperError;
+
+    fn try_from(config: station_api::BrandingConfigDTO) -> Result<Self, Self::Error> {
+        Ok(BrandingConfig {
+            logo_id: config
+                .logo_id
+                .map(|id| HelperMapper::to_uuid(id).map(|id| *id.as_bytes()))
+                .transpose()?,
+            asset_logos: config
+                .asset_logos
+                .into_iter()
+                .map(AssetLogo::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<station_api::AssetLogoDTO> for AssetLogo {
+    type Error = MapperError;
+
+    fn try_from(logo: station_api::AssetLogoDTO) -> Result<Self, Self::Error> {
+        Ok(AssetLogo {
+            blockchain: BlockchainMapper::to_blockchain(logo.blockchain)?,
+            standard: BlockchainMapper::to_blockchain_standard(logo.standard)?,
+            symbol: logo.symbol,
+            logo_id: *HelperMapper::to_uuid(logo.logo_id)?.as_bytes(),
+        })
+    }
+}
+
+impl From<MetadataEncryptionConfig> for station_api::MetadataEncryptionConfigDTO {
+    fn from(config: MetadataEncryptionConfig) -> Self {
+        match config {
+            MetadataEncryptionConfig::Disabled => {
+                station_api::MetadataEncryptionConfigDTO::Disabled
+            }
+            MetadataEncryptionConfig::VetKd {
+                key_name,
+                address_book_metadata_keys,
+            } => station_api::MetadataEncryptionConfigDTO::VetKd {
+                key_name,
+                address_book_metadata_keys,
+            },
+        }
+    }
+}
+
+impl From<station_api::MetadataEncryptionConfigDTO> for MetadataEncryptionConfig {
+    fn from(config: station_api::MetadataEncryptionConfigDTO) -> Self {
+        match config {
+            station_api::MetadataEncryptionConfigDTO::Disabled => {
+                MetadataEncryptionConfig::Disabled
+            }
+            station_api::MetadataEncryptionConfigDTO::VetKd {
+                key_name,
+                address_book_metadata_keys,
+            } => MetadataEncryptionConfig::VetKd {
+                key_name,
+                address_book_metadata_keys,
+            },
+        }
+    }
+}
+
+impl PrincipalUsage {
+    pub fn to_dto(self, last_active_at: Option<Timestamp>) -> PrincipalUsageDTO {
+        PrincipalUsageDTO {
+            principal: self.principal,
+            user_id: self
+                .user_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            calls: self.calls,
+            approvals: self.approvals,
+            requests_created: self
+                .requests_created
+                .into_iter()
+                .map(|(operation_type, count)| RequestOperationUsageDTO {
+                    operation_type: operation_type.into(),
+                    count,
+                })
+                .collect(),
+            last_active_at: last_active_at.map(|timestamp| timestamp_to_rfc3339(&timestamp)),
+        }
+    }
+}
+
+impl From<LogLevelDTO> for LogLevel {
+    fn from(level: LogLevelDTO) -> Self {
+        match level {
+            LogLevelDTO::Debug => LogLevel::Debug,
+            LogLevelDTO::Info => LogLevel::Info,
+            LogLevelDTO::Warn => LogLevel::Warn,
+            LogLevelDTO::Error => LogLevel::Error,
+        }
+    }
+}
+
+impl From<LogLevel> for LogLevelDTO {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Debug => LogLevelDTO::Debug,
+            LogLevel::Info => LogLevelDTO::Info,
+            LogLevel::Warn => LogLevelDTO::Warn,
+            LogLevel::Error => LogLevelDTO::Error,
+        }
+    }
+}
+
+impl From<StationLogEntry> for StationLogEntryDTO {
+    fn from(entry: StationLogEntry) -> Self {
+        StationLogEntryDTO {
+            idx: entry.idx,
+            timestamp: timestamp_to_rfc3339(&entry.timestamp),
+            level: entry.level.into(),
+            module: entry.module,
+            message: entry.message,
+            correlation_id: entry
+                .correlation_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+        }
+    }
+}
+
+impl From<TraceSpan> for TraceSpanDTO {
+    fn from(span: TraceSpan) -> Self {
+        TraceSpanDTO {
+            id: span.id,
+            parent_id: span.parent_id,
+            name: span.name,
+            details: span.details,
+            started_at: timestamp_to_rfc3339(&span.started_at),
+            finished_at: span.finished_at.map(|dt| timestamp_to_rfc3339(&dt)),
+            duration_ns: span
+                .finished_at
+                .map(|dt| dt.saturating_sub(span.started_at)),
+            error: span.error,
         }
     }
 }
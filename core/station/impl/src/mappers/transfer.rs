@@ -1,13 +1,25 @@
-use crate::models::Transfer;
+use crate::models::{
+    Transfer, TransferAnnotation, TransferComplianceInfo, TransferDuplicateWarning,
+    TransferFeeEstimate, TransferFeeStrategy, TransferScreening, TransferScreeningStatus,
+    TransferTimeLock,
+};
 use orbit_essentials::utils::timestamp_to_rfc3339;
-use station_api::{NetworkDTO, TransferDTO, TransferListItemDTO};
+use station_api::{
+    NetworkDTO, TransferAnnotationDTO, TransferComplianceInfoDTO, TransferDTO,
+    TransferDuplicateWarningDTO, TransferFeeEstimateDTO, TransferFeeStrategyDTO,
+    TransferListItemDTO, TransferScreeningDTO, TransferScreeningStatusDTO, TransferTimeLockDTO,
+};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
 pub struct TransferMapper {}
 
 impl TransferMapper {
-    pub fn to_dto(transfer: Transfer) -> TransferDTO {
+    pub fn to_dto(
+        transfer: Transfer,
+        annotation: Option<TransferAnnotation>,
+        to_label: Option<String>,
+    ) -> TransferDTO {
         TransferDTO {
             id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
             request_id: Uuid::from_bytes(transfer.request_id)
@@ -25,10 +37,17 @@ impl TransferMapper {
                 .to_string(),
             to: transfer.to_address,
             status: transfer.status.into(),
+            compliance: transfer.compliance.map(Into::into),
+            annotation: annotation.map(Into::into),
+            to_label,
         }
     }
 
-    pub fn to_list_item_dto(transfer: Transfer) -> TransferListItemDTO {
+    pub fn to_list_item_dto(
+        transfer: Transfer,
+        annotation: Option<TransferAnnotation>,
+        to_label: Option<String>,
+    ) -> TransferListItemDTO {
         TransferListItemDTO {
             transfer_id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
             amount: transfer.amount,
@@ -38,16 +57,159 @@ impl TransferMapper {
             request_id: Uuid::from_bytes(transfer.request_id)
                 .hyphenated()
                 .to_string(),
+            compliance: transfer.compliance.map(Into::into),
+            annotation: annotation.map(Into::into),
+            to_label,
         }
     }
 }
 
 impl Transfer {
-    pub fn to_dto(&self) -> TransferDTO {
-        TransferMapper::to_dto(self.clone())
+    pub fn to_dto(
+        &self,
+        annotation: Option<TransferAnnotation>,
+        to_label: Option<String>,
+    ) -> TransferDTO {
+        TransferMapper::to_dto(self.clone(), annotation, to_label)
     }
 
-    pub fn to_list_item_dto(&self) -> TransferListItemDTO {
-        TransferMapper::to_list_item_dto(self.clone())
+    pub fn to_list_item_dto(
+        &self,
+        annotation: Option<TransferAnnotation>,
+        to_label: Option<String>,
+    ) -> TransferListItemDTO {
+        TransferMapper::to_list_item_dto(self.clone(), annotation, to_label)
+    }
+}
+
+impl From<TransferAnnotation> for TransferAnnotationDTO {
+    fn from(annotation: TransferAnnotation) -> Self {
+        TransferAnnotationDTO {
+            external_invoice_id: annotation.external_invoice_id,
+            accounting_period: annotation.accounting_period,
+            notes: annotation.notes,
+            annotated_by: Uuid::from_bytes(annotation.annotated_by)
+                .hyphenated()
+                .to_string(),
+            annotated_at: timestamp_to_rfc3339(&annotation.annotated_at),
+        }
+    }
+}
+
+impl From<TransferComplianceInfo> for TransferComplianceInfoDTO {
+    fn from(compliance: TransferComplianceInfo) -> Self {
+        TransferComplianceInfoDTO {
+            beneficiary_name: compliance.beneficiary_name,
+            originator_name: compliance.originator_name,
+            originator_identifier: compliance.originator_identifier,
+            purpose_code: compliance.purpose_code,
+        }
+    }
+}
+
+impl From<TransferComplianceInfoDTO> for TransferComplianceInfo {
+    fn from(compliance: TransferComplianceInfoDTO) -> Self {
+        TransferComplianceInfo {
+            beneficiary_name: compliance.beneficiary_name,
+            originator_name: compliance.originator_name,
+            originator_identifier: compliance.originator_identifier,
+            purpose_code: compliance.purpose_code,
+        }
+    }
+}
+
+impl From<TransferScreening> for TransferScreeningDTO {
+    fn from(screening: TransferScreening) -> Self {
+        TransferScreeningDTO {
+            canister_id: screening.canister_id,
+            blocks_approval: screening.blocks_approval,
+            status: match screening.status {
+                TransferScreeningStatus::Pending {
+                    attempts,
+                    last_error,
+                    ..
+                } => TransferScreeningStatusDTO::Pending {
+                    attempts,
+                    last_error,
+                },
+                TransferScreeningStatus::Unavailable { error } => {
+                    TransferScreeningStatusDTO::Unavailable { error }
+                }
+                TransferScreeningStatus::Passed {
+                    reference,
+                    screened_at,
+                } => TransferScreeningStatusDTO::Passed {
+                    reference,
+                    screened_at: timestamp_to_rfc3339(&screened_at),
+                },
+                TransferScreeningStatus::Flagged {
+                    reason,
+                    reference,
+                    screened_at,
+                } => TransferScreeningStatusDTO::Flagged {
+                    reason,
+                    reference,
+                    screened_at: timestamp_to_rfc3339(&screened_at),
+                },
+            },
+        }
+    }
+}
+
+impl From<TransferTimeLock> for TransferTimeLockDTO {
+    fn from(time_lock: TransferTimeLock) -> Self {
+        TransferTimeLockDTO {
+            delay_secs: time_lock.delay_secs,
+            canceller_group_ids: time_lock
+                .canceller_group_ids
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
+            unlocks_at: time_lock
+                .unlocks_at
+                .map(|unlocks_at| timestamp_to_rfc3339(&unlocks_at)),
+        }
+    }
+}
+
+impl From<TransferDuplicateWarning> for TransferDuplicateWarningDTO {
+    fn from(warning: TransferDuplicateWarning) -> Self {
+        TransferDuplicateWarningDTO {
+            request_ids: warning
+                .request_ids
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
+        }
+    }
+}
+
+impl From<TransferFeeStrategy> for TransferFeeStrategyDTO {
+    fn from(strategy: TransferFeeStrategy) -> Self {
+        match strategy {
+            TransferFeeStrategy::Slow => TransferFeeStrategyDTO::Slow,
+            TransferFeeStrategy::Standard => TransferFeeStrategyDTO::Standard,
+            TransferFeeStrategy::Fast => TransferFeeStrategyDTO::Fast,
+        }
+    }
+}
+
+impl From<TransferFeeStrategyDTO> for TransferFeeStrategy {
+    fn from(strategy: TransferFeeStrategyDTO) -> Self {
+        match strategy {
+            TransferFeeStrategyDTO::Slow => TransferFeeStrategy::Slow,
+            TransferFeeStrategyDTO::Standard => TransferFeeStrategy::Standard,
+            TransferFeeStrategyDTO::Fast => TransferFeeStrategy::Fast,
+        }
+    }
+}
+
+impl From<TransferFeeEstimate> for TransferFeeEstimateDTO {
+    fn from(estimate: TransferFeeEstimate) -> Self {
+        TransferFeeEstimateDTO {
+            fee: estimate.fee,
+            strategy: estimate.strategy.into(),
+            estimated_at: timestamp_to_rfc3339(&estimate.estimated_at),
+        }
     }
 }
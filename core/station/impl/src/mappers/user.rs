@@ -1,9 +1,10 @@
 use super::HelperMapper;
 use crate::{
     core::ic_cdk::next_time,
-    errors::UserError,
+    errors::{MapperError, UserError},
     models::{
-        AddUserOperationInput, DisplayUser, EditUserOperationInput, User, UserCallerPrivileges,
+        AddUserOperationInput, DisplayUser, EditUserOperationInput, ScheduledGroupMembership,
+        StaleApprover, User, UserCallerPrivileges,
     },
     repositories::USER_GROUP_REPOSITORY,
 };
@@ -12,7 +13,9 @@ use orbit_essentials::{
     types::UUID,
     utils::{rfc3339_to_timestamp, timestamp_to_rfc3339},
 };
-use station_api::{BasicUserDTO, DisplayUserDTO, UserDTO};
+use station_api::{
+    BasicUserDTO, DisplayUserDTO, ScheduledGroupMembershipDTO, StaleApproverDTO, UserDTO,
+};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
@@ -27,6 +30,9 @@ impl UserMapper {
             name: input.name,
             status: input.status,
             last_modification_timestamp: next_time(),
+            version: 0,
+            last_active_at: None,
+            scheduled_memberships: input.scheduled_memberships,
         }
     }
 }
@@ -45,6 +51,60 @@ impl From<User> for UserDTO {
                 .map(Into::into)
                 .collect(),
             last_modification_timestamp: timestamp_to_rfc3339(&user.last_modification_timestamp),
+            version: user.version,
+            scheduled_memberships: user
+                .scheduled_memberships
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            last_active_at: user
+                .last_active_at
+                .map(|last_active_at| timestamp_to_rfc3339(&last_active_at)),
+        }
+    }
+}
+
+impl From<ScheduledGroupMembership> for ScheduledGroupMembershipDTO {
+    fn from(membership: ScheduledGroupMembership) -> Self {
+        ScheduledGroupMembershipDTO {
+            group_id: Uuid::from_bytes(membership.group_id)
+                .hyphenated()
+                .to_string(),
+            starts_at: membership
+                .starts_at
+                .map(|starts_at| timestamp_to_rfc3339(&starts_at)),
+            expires_at: membership
+                .expires_at
+                .map(|expires_at| timestamp_to_rfc3339(&expires_at)),
+        }
+    }
+}
+
+impl TryFrom<ScheduledGroupMembershipDTO> for ScheduledGroupMembership {
+    type Error = MapperError;
+
+    fn try_from(membership: ScheduledGroupMembershipDTO) -> Result<Self, Self::Error> {
+        Ok(ScheduledGroupMembership {
+            group_id: *HelperMapper::to_uuid(membership.group_id)?.as_bytes(),
+            starts_at: HelperMapper::to_optional_timestamp("starts_at", membership.starts_at)?,
+            expires_at: HelperMapper::to_optional_timestamp("expires_at", membership.expires_at)?,
+        })
+    }
+}
+
+impl From<StaleApprover> for StaleApproverDTO {
+    fn from(approver: StaleApprover) -> Self {
+        StaleApproverDTO {
+            user_id: Uuid::from_bytes(approver.user_id).hyphenated().to_string(),
+            name: approver.name,
+            last_active_at: approver
+                .last_active_at
+                .map(|last_active_at| timestamp_to_rfc3339(&last_active_at)),
+            policy_ids: approver
+                .policy_ids
+                .iter()
+                .map(|policy_id| Uuid::from_bytes(*policy_id).hyphenated().to_string())
+                .collect(),
         }
     }
 }
@@ -87,6 +147,15 @@ impl From<UserDTO> for User {
             last_modification_timestamp: rfc3339_to_timestamp(
                 user.last_modification_timestamp.as_str(),
             ),
+            version: user.version,
+            last_active_at: user
+                .last_active_at
+                .map(|last_active_at| rfc3339_to_timestamp(&last_active_at)),
+            scheduled_memberships: user
+                .scheduled_memberships
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -109,6 +178,10 @@ impl User {
             self.status = new_status;
         }
 
+        if let Some(scheduled_memberships) = input.scheduled_memberships {
+            self.scheduled_memberships = scheduled_memberships;
+        }
+
         Ok(())
     }
 }
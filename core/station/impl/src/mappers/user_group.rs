@@ -2,19 +2,57 @@ use super::HelperMapper;
 use crate::models::{
     AddUserGroupOperation, AddUserGroupOperationInput, EditUserGroupOperation,
     EditUserGroupOperationInput, RemoveUserGroupOperation, RemoveUserGroupOperationInput,
-    UserGroup, UserGroupCallerPrivileges,
+    UserGroup, UserGroupCallerPrivileges, UserGroupQuotaUsage, UserGroupQuotas,
+    UserGroupReassignment, UserGroupReferences,
 };
 use uuid::Uuid;
 
+impl From<station_api::UserGroupQuotasDTO> for UserGroupQuotas {
+    fn from(quotas: station_api::UserGroupQuotasDTO) -> Self {
+        Self {
+            max_pending_requests: quotas.max_pending_requests,
+            max_accounts: quotas.max_accounts,
+        }
+    }
+}
+
+impl From<UserGroupQuotas> for station_api::UserGroupQuotasDTO {
+    fn from(quotas: UserGroupQuotas) -> Self {
+        Self {
+            max_pending_requests: quotas.max_pending_requests,
+            max_accounts: quotas.max_accounts,
+        }
+    }
+}
+
+impl From<UserGroupQuotaUsage> for station_api::UserGroupQuotaUsageDTO {
+    fn from(usage: UserGroupQuotaUsage) -> Self {
+        Self {
+            user_group_id: Uuid::from_bytes(usage.user_group_id)
+                .hyphenated()
+                .to_string(),
+            quotas: usage.quotas.into(),
+            pending_requests: usage.pending_requests,
+            accounts: usage.accounts,
+        }
+    }
+}
+
 impl From<station_api::AddUserGroupOperationInput> for AddUserGroupOperationInput {
     fn from(input: station_api::AddUserGroupOperationInput) -> Self {
-        Self { name: input.name }
+        Self {
+            name: input.name,
+            quotas: input.quotas.map(Into::into).unwrap_or_default(),
+        }
     }
 }
 
 impl From<AddUserGroupOperationInput> for station_api::AddUserGroupOperationInput {
     fn from(input: AddUserGroupOperationInput) -> Self {
-        Self { name: input.name }
+        Self {
+            name: input.name,
+            quotas: Some(input.quotas.into()),
+        }
     }
 }
 
@@ -25,6 +63,7 @@ impl From<station_api::EditUserGroupOperationInput> for EditUserGroupOperationIn
                 .expect("Invalid UUID")
                 .as_bytes(),
             name: input.name,
+            quotas: input.quotas.map(Into::into),
         }
     }
 }
@@ -36,6 +75,53 @@ impl From<EditUserGroupOperationInput> for station_api::EditUserGroupOperationIn
                 .hyphenated()
                 .to_string(),
             name: input.name,
+            quotas: input.quotas.map(Into::into),
+        }
+    }
+}
+
+impl From<station_api::UserGroupReassignmentDTO> for UserGroupReassignment {
+    fn from(dto: station_api::UserGroupReassignmentDTO) -> Self {
+        match dto {
+            station_api::UserGroupReassignmentDTO::ReplaceWith { user_group_id } => {
+                UserGroupReassignment::ReplaceWith(
+                    *HelperMapper::to_uuid(user_group_id)
+                        .expect("Invalid UUID")
+                        .as_bytes(),
+                )
+            }
+            station_api::UserGroupReassignmentDTO::Detach => UserGroupReassignment::Detach,
+        }
+    }
+}
+
+impl From<UserGroupReassignment> for station_api::UserGroupReassignmentDTO {
+    fn from(reassignment: UserGroupReassignment) -> Self {
+        match reassignment {
+            UserGroupReassignment::ReplaceWith(user_group_id) => {
+                station_api::UserGroupReassignmentDTO::ReplaceWith {
+                    user_group_id: Uuid::from_bytes(user_group_id).hyphenated().to_string(),
+                }
+            }
+            UserGroupReassignment::Detach => station_api::UserGroupReassignmentDTO::Detach,
+        }
+    }
+}
+
+impl From<UserGroupReferences> for station_api::UserGroupReferencesDTO {
+    fn from(references: UserGroupReferences) -> Self {
+        Self {
+            request_policy_ids: references
+                .request_policies
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
+            permissions: references.permissions.into_iter().map(Into::into).collect(),
+            user_ids: references
+                .users
+                .iter()
+                .map(|id| Uuid::from_bytes(*id).hyphenated().to_string())
+                .collect(),
         }
     }
 }
@@ -46,6 +132,7 @@ impl From<station_api::RemoveUserGroupOperationInput> for RemoveUserGroupOperati
             user_group_id: *HelperMapper::to_uuid(input.user_group_id)
                 .expect("Invalid UUID")
                 .as_bytes(),
+            reassignment: input.reassignment.map(Into::into),
         }
     }
 }
@@ -56,6 +143,7 @@ impl From<RemoveUserGroupOperationInput> for station_api::RemoveUserGroupOperati
             user_group_id: Uuid::from_bytes(input.user_group_id)
                 .hyphenated()
                 .to_string(),
+            reassignment: input.reassignment.map(Into::into),
         }
     }
 }
@@ -65,6 +153,7 @@ impl From<UserGroup> for station_api::UserGroupDTO {
         Self {
             id: Uuid::from_bytes(user_group.id).hyphenated().to_string(),
             name: user_group.name,
+            quotas: user_group.quotas.into(),
         }
     }
 }
@@ -90,6 +179,7 @@ impl From<RemoveUserGroupOperation> for station_api::RemoveUserGroupOperationDTO
     fn from(operation: RemoveUserGroupOperation) -> Self {
         Self {
             input: operation.input.into(),
+            affected: operation.affected.into(),
         }
     }
 }
@@ -113,8 +203,11 @@ impl From<station_api::EditUserGroupOperationInput> for EditUserGroupOperation {
 
 impl From<station_api::RemoveUserGroupOperationInput> for RemoveUserGroupOperation {
     fn from(input: station_api::RemoveUserGroupOperationInput) -> Self {
+        let input = RemoveUserGroupOperationInput::from(input);
+
         Self {
-            input: input.into(),
+            affected: UserGroupReferences::find(&input.user_group_id),
+            input,
         }
     }
 }
@@ -0,0 +1,20 @@
+use crate::models::VoteReceipt;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::VoteReceiptDTO;
+use uuid::Uuid;
+
+impl From<VoteReceipt> for VoteReceiptDTO {
+    fn from(receipt: VoteReceipt) -> Self {
+        Self {
+            request_id: Uuid::from_bytes(receipt.request_id)
+                .hyphenated()
+                .to_string(),
+            approver_id: Uuid::from_bytes(receipt.approver_id)
+                .hyphenated()
+                .to_string(),
+            voter: receipt.voter,
+            decision: receipt.decision.into(),
+            decided_at: timestamp_to_rfc3339(&receipt.decided_at),
+        }
+    }
+}
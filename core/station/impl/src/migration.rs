@@ -1,22 +1,21 @@
 use crate::core::ic_cdk::api::trap;
-use crate::core::{read_system_info, write_system_info, Memory};
+use crate::core::{read_system_info, with_memory_manager, write_system_info, Memory};
 use crate::models::permission::{Permission, PermissionKey};
 use crate::models::request_specifier::RequestSpecifier;
 use crate::models::resource::{ExternalCanisterResourceAction, Resource, SystemResourceAction};
 use crate::models::{
     Account, AccountKey, AddressBookEntry, AddressBookEntryKey, ExternalCanister,
-    ExternalCanisterKey, Request, RequestKey, RequestOperation, RequestPolicy, User, UserGroup,
-    UserKey,
+    ExternalCanisterKey, Request, RequestKey, RequestOperation, RequestPolicy, RequestStatusCode,
+    User, UserGroup, UserKey,
 };
 use crate::repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY};
 use crate::repositories::{
     AccountRepository, AddressBookRepository, ExternalCanisterRepository, RequestPolicyRepository,
     RequestRepository, UserGroupRepository, UserRepository, ACCOUNT_REPOSITORY,
     ADDRESS_BOOK_REPOSITORY, EXTERNAL_CANISTER_REPOSITORY, REQUEST_POLICY_REPOSITORY,
-    USER_GROUP_REPOSITORY, USER_REPOSITORY,
+    REQUEST_REPOSITORY, USER_GROUP_REPOSITORY, USER_REPOSITORY,
 };
 use crate::{concat_str_arrays, STABLE_MEMORY_VERSION};
-use crate::{core::with_memory_manager, repositories::REQUEST_REPOSITORY};
 use ic_stable_structures::memory_manager::{MemoryId, VirtualMemory};
 use ic_stable_structures::Memory as DefaultMemoryTrait;
 use orbit_essentials::model::ModelKey;
@@ -33,8 +32,8 @@ use strum::VariantNames;
 /// Stable memory migration conditions:
 ///
 /// - The migration is only applied once per each version.
-/// - Stable memory versions can't be skipped, the upgrade must be sequential.
-/// - The migration is applied is the previous version is `STABLE_MEMORY_VERSION - 1`.
+/// - Each step of the migration is applied if the stored version is older than the version that introduced it,
+///   so that a station can be upgraded across several versions at once.
 pub struct MigrationHandler;
 
 impl MigrationHandler {
@@ -42,8 +41,7 @@ impl MigrationHandler {
     ///
     /// WARNING: This needs to be run before any other access to stable memory happens.
     pub fn run() {
-        let mut system_info = read_system_info();
-        let stored_version = system_info.get_stable_memory_version();
+        let stored_version = read_system_info().get_stable_memory_version();
 
         if stored_version == STABLE_MEMORY_VERSION {
             return;
@@ -56,9 +54,10 @@ impl MigrationHandler {
             ));
         }
 
-        apply_migration();
+        apply_migration(stored_version);
 
-        // Update the stable memory version to the latest version.
+        // Update the stable memory version to the latest version, the migration can also change the system info.
+        let mut system_info = read_system_info();
         system_info.set_stable_memory_version(STABLE_MEMORY_VERSION);
         write_system_info(system_info);
     }
@@ -66,59 +65,76 @@ impl MigrationHandler {
 
 /// The migration to apply to the station canister stable memory.
 ///
-/// Please include the migration steps in the `apply_migration` function.
-fn apply_migration() {
-    // step 1: clear unused memory ids
-    with_memory_manager(|memory_manager| {
-        for memory_id in [
-            MemoryId::new(3),  // USER_IDENTITY_INDEX_MEMORY_ID,
-            MemoryId::new(5),  // REQUEST_EXPIRATION_TIME_INDEX_MEMORY_ID
-            MemoryId::new(8),  // REQUEST_APPROVER_INDEX_MEMORY_ID
-            MemoryId::new(9),  // REQUEST_STATUS_INDEX_MEMORY_ID
-            MemoryId::new(10), // REQUEST_SCHEDULED_INDEX_MEMORY_ID
-            MemoryId::new(15), // USER_GROUP_NAME_INDEX_MEMORY_ID
-            MemoryId::new(18), // USER_STATUS_GROUP_INDEX_MEMORY_ID
-            MemoryId::new(20), // ADDRESS_BOOK_INDEX_MEMORY_ID
-            MemoryId::new(21), // REQUEST_REQUESTER_INDEX_MEMORY_ID
-            MemoryId::new(22), // REQUEST_CREATION_TIME_INDEX_MEMORY_ID
-            MemoryId::new(23), // REQUEST_KEY_CREATION_TIME_INDEX_MEMORY_ID
-            MemoryId::new(24), // REQUEST_KEY_EXPIRATION_TIME_INDEX_MEMORY_ID
-            MemoryId::new(25), // REQUEST_SORT_INDEX_MEMORY_ID
-            MemoryId::new(26), // REQUEST_STATUS_MODIFICATION_INDEX_MEMORY_ID
-            MemoryId::new(27), // NAME_TO_ACCOUNT_ID_INDEX_MEMORY_ID
-            MemoryId::new(28), // NAME_TO_USER_ID_INDEX_MEMORY_ID
-            MemoryId::new(29), // OPERATION_TYPE_TO_REQUEST_ID_INDEX_MEMORY_ID
-            MemoryId::new(34), // EXTERNAL_CANISTER_INDEX_MEMORY_ID
-            // The following memory ids are still in use for the same purpose, but the datatype
-            // have changed and the memory needs to be cleaned up and rebuilt later.
-            MemoryId::new(30), // REQUEST_RESOURCE_INDEX_MEMORY_ID
-            MemoryId::new(31), // POLICY_RESOURCE_INDEX_MEMORY_ID
-        ] {
-            // This cleans up the memory by writing a single zero byte to the memory id,
-            // this will make the memory id available for reuse in the future.
-            //
-            // This makes sure that if `init` is called on the memory id, it will make sure
-            // it can be reused with a different datatype.
-            let memory = memory_manager.get(memory_id);
-            if memory.size() > 0 {
+/// Please include the migration steps in the `apply_migration` function, guarded by the version that
+/// introduced them.
+fn apply_migration(stored_version: u32) {
+    if stored_version < 1 {
+        // clear unused memory ids
+        with_memory_manager(|memory_manager| {
+            for memory_id in [
+                MemoryId::new(3),  // USER_IDENTITY_INDEX_MEMORY_ID,
+                MemoryId::new(5),  // REQUEST_EXPIRATION_TIME_INDEX_MEMORY_ID
+                MemoryId::new(8),  // REQUEST_APPROVER_INDEX_MEMORY_ID
+                MemoryId::new(9),  // REQUEST_STATUS_INDEX_MEMORY_ID
+                MemoryId::new(10), // REQUEST_SCHEDULED_INDEX_MEMORY_ID
+                MemoryId::new(15), // USER_GROUP_NAME_INDEX_MEMORY_ID
+                MemoryId::new(18), // USER_STATUS_GROUP_INDEX_MEMORY_ID
+                MemoryId::new(20), // ADDRESS_BOOK_INDEX_MEMORY_ID
+                MemoryId::new(21), // REQUEST_REQUESTER_INDEX_MEMORY_ID
+                MemoryId::new(22), // REQUEST_CREATION_TIME_INDEX_MEMORY_ID
+                MemoryId::new(23), // REQUEST_KEY_CREATION_TIME_INDEX_MEMORY_ID
+                MemoryId::new(24), // REQUEST_KEY_EXPIRATION_TIME_INDEX_MEMORY_ID
+                MemoryId::new(25), // REQUEST_SORT_INDEX_MEMORY_ID
+                MemoryId::new(26), // REQUEST_STATUS_MODIFICATION_INDEX_MEMORY_ID
+                MemoryId::new(27), // NAME_TO_ACCOUNT_ID_INDEX_MEMORY_ID
+                MemoryId::new(28), // NAME_TO_USER_ID_INDEX_MEMORY_ID
+                MemoryId::new(29), // OPERATION_TYPE_TO_REQUEST_ID_INDEX_MEMORY_ID
+                // The old EXTERNAL_CANISTER_INDEX_MEMORY_ID, reused by EVENT_SUBSCRIBER_MEMORY_ID.
+                MemoryId::new(34),
+                // The following memory ids are still in use for the same purpose, but the datatype
+                // have changed and the memory needs to be cleaned up and rebuilt later.
+                MemoryId::new(30), // REQUEST_RESOURCE_INDEX_MEMORY_ID
+                MemoryId::new(31), // POLICY_RESOURCE_INDEX_MEMORY_ID
+            ] {
                 // This marks the memory as unused, this is because the StableBTreeMap
                 // implementation uses the first three bytes of the memory to store the MAGIC value [66, 84, 82]
                 // that indicates that the memory is used by the StableBTreeMap, so adding a single different byte
                 // in those first three bytes will make the memory available for reuse.
-                memory.write(0, &[0]);
+                let memory = memory_manager.get(memory_id);
+                if memory.size() > 0 {
+                    memory.write(0, &[0]);
+                }
+            }
+        });
+
+        // rebuilds the repositories to ensure the data is up-to-date
+        USER_GROUP_REPOSITORY.rebuild();
+        USER_REPOSITORY.rebuild();
+        ACCOUNT_REPOSITORY.rebuild();
+        EXTERNAL_CANISTER_REPOSITORY.rebuild();
+        ADDRESS_BOOK_REPOSITORY.rebuild();
+        PERMISSION_REPOSITORY.rebuild();
+        REQUEST_POLICY_REPOSITORY.rebuild();
+        REQUEST_REPOSITORY.rebuild();
+    }
+
+    if stored_version < 2 {
+        // the requests that are still being approved keep the policies they were submitted with, storing
+        // them again also adds the rules of their snapshot to the request indexes
+        for mut request in REQUEST_REPOSITORY.find_by_status(RequestStatusCode::Created, None, None)
+        {
+            if request.policy_snapshot.is_none() {
+                request.snapshot_policies();
+                REQUEST_REPOSITORY.insert(request.to_key(), request);
             }
         }
-    });
-
-    // step 2: rebuilds the repositories to ensure the data is up-to-date
-    USER_GROUP_REPOSITORY.rebuild();
-    USER_REPOSITORY.rebuild();
-    ACCOUNT_REPOSITORY.rebuild();
-    EXTERNAL_CANISTER_REPOSITORY.rebuild();
-    ADDRESS_BOOK_REPOSITORY.rebuild();
-    PERMISSION_REPOSITORY.rebuild();
-    REQUEST_POLICY_REPOSITORY.rebuild();
-    REQUEST_REPOSITORY.rebuild();
+    }
+
+    if stored_version < 3 {
+        // the address book entries are added to the new name index, the unique index of their addresses
+        // is kept so that duplicated addresses are still rejected during the upgrade
+        ADDRESS_BOOK_REPOSITORY.rebuild_name_index();
+    }
 }
 
 impl<'de> Deserialize<'de> for Resource {
@@ -394,7 +410,7 @@ impl<'de> Deserialize<'de> for RequestOperation {
         const REMOVED_VARIANTS: [&str; 1] = ["ChangeCanister"];
 
         // IMPORTANT: The size of the array must be hardcoded, to make sure it can be checked at compile-time.
-        static EXPECTED_VARIANTS: [&str; 24] = {
+        static EXPECTED_VARIANTS: [&str; 34] = {
             let variants: [&str; CURRENT_VARIANTS.len() + REMOVED_VARIANTS.len()] =
                 concat_str_arrays!(CURRENT_VARIANTS, REMOVED_VARIANTS);
 
@@ -438,6 +454,14 @@ impl<'de> Deserialize<'de> for RequestOperation {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::EditAccount(value))
                     }
+                    "RotateAccountAddress" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::RotateAccountAddress(value))
+                    }
+                    "TransferAccountOwnership" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::TransferAccountOwnership(value))
+                    }
                     "AddAddressBookEntry" => {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::AddAddressBookEntry(value))
@@ -462,6 +486,10 @@ impl<'de> Deserialize<'de> for RequestOperation {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::EditPermission(value))
                     }
+                    "BulkEditPermission" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::BulkEditPermission(value))
+                    }
                     "AddUserGroup" => {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::AddUserGroup(value))
@@ -498,6 +526,14 @@ impl<'de> Deserialize<'de> for RequestOperation {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::FundExternalCanister(value))
                     }
+                    "CommitAssetBatch" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::CommitAssetBatch(value))
+                    }
+                    "SnapshotExternalCanister" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::SnapshotExternalCanister(value))
+                    }
                     "AddRequestPolicy" => {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::AddRequestPolicy(value))
@@ -518,6 +554,26 @@ impl<'de> Deserialize<'de> for RequestOperation {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::SetDisasterRecovery(value))
                     }
+                    "AddNetwork" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::AddNetwork(value))
+                    }
+                    "EditNetwork" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::EditNetwork(value))
+                    }
+                    "FreezeBlockchain" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::FreezeBlockchain(value))
+                    }
+                    "UnfreezeBlockchain" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::UnfreezeBlockchain(value))
+                    }
+                    "DecommissionStation" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::DecommissionStation(value))
+                    }
                     _ => Err(de::Error::unknown_variant(&variant, &EXPECTED_VARIANTS)),
                 }
             }
@@ -571,3 +627,101 @@ impl RebuildRepository<ExternalCanisterKey, ExternalCanister, VirtualMemory<Memo
 impl RebuildRepository<UUID, UserGroup, VirtualMemory<Memory>> for UserGroupRepository {}
 impl RebuildRepository<UserKey, User, VirtualMemory<Memory>> for UserRepository {}
 impl RebuildRepository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRepository {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        address_book_entry_test_utils::mock_address_book_entry, request_test_utils::mock_request,
+        RequestStatus, SystemInfo,
+    };
+    use crate::repositories::indexes::address_book_name_index::AddressBookNameIndexRepository;
+    use candid::Principal;
+
+    #[test]
+    fn upgrade_from_v0_clears_the_unused_memory() {
+        let mut system_info = SystemInfo::new(Principal::management_canister(), Vec::new());
+        system_info.set_stable_memory_version(0);
+        write_system_info(system_info);
+
+        with_memory_manager(|memory_manager| {
+            let memory = memory_manager.get(MemoryId::new(9));
+            memory.grow(1);
+            memory.write(0, &[66, 84, 82]);
+        });
+
+        MigrationHandler::run();
+
+        let mut magic = [0; 3];
+        with_memory_manager(|memory_manager| {
+            memory_manager.get(MemoryId::new(9)).read(0, &mut magic)
+        });
+
+        assert_eq!(magic, [0, 84, 82]);
+        assert_eq!(
+            read_system_info().get_stable_memory_version(),
+            STABLE_MEMORY_VERSION
+        );
+    }
+
+    #[test]
+    fn upgrade_from_v1_applies_all_the_steps() {
+        let mut system_info = SystemInfo::new(Principal::management_canister(), Vec::new());
+        system_info.set_stable_memory_version(1);
+        write_system_info(system_info);
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.policy_snapshot = None;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        MigrationHandler::run();
+
+        let system_info = read_system_info();
+
+        assert_eq!(
+            system_info.get_stable_memory_version(),
+            STABLE_MEMORY_VERSION
+        );
+        assert!(REQUEST_REPOSITORY
+            .get(&request.to_key())
+            .unwrap()
+            .policy_snapshot
+            .is_some());
+        assert!(system_info.get_index_rebuild().is_none());
+    }
+
+    #[test]
+    fn upgrade_from_v2_only_populates_the_address_book_name_index() {
+        let mut system_info = SystemInfo::new(Principal::management_canister(), Vec::new());
+        system_info.set_stable_memory_version(2);
+        write_system_info(system_info);
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.policy_snapshot = None;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        // the name index did not exist before the version 3
+        let entry = mock_address_book_entry();
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+        AddressBookNameIndexRepository::default().clear();
+        assert!(ADDRESS_BOOK_REPOSITORY
+            .find_by_search_term(None, &entry.address_owner)
+            .is_empty());
+
+        MigrationHandler::run();
+
+        assert!(REQUEST_REPOSITORY
+            .get(&request.to_key())
+            .unwrap()
+            .policy_snapshot
+            .is_none());
+        assert_eq!(
+            ADDRESS_BOOK_REPOSITORY.find_by_search_term(None, &entry.address_owner),
+            vec![entry.clone()]
+        );
+        assert!(ADDRESS_BOOK_REPOSITORY.exists(entry.blockchain.clone(), entry.address.clone()));
+        assert!(read_system_info().get_index_rebuild().is_none());
+    }
+}
@@ -1,4 +1,6 @@
-use super::{AccountBalance, Blockchain, BlockchainStandard};
+use super::resource::{AccountResourceAction, Resource, ResourceId};
+use super::{AccountBalance, Blockchain, BlockchainStandard, Network, NetworkId};
+use crate::core::display_decimals;
 use crate::errors::AccountError;
 use crate::models::Metadata;
 use crate::repositories::request_policy::REQUEST_POLICY_REPOSITORY;
@@ -57,6 +59,121 @@ pub struct Account {
     pub configs_request_policy_id: Option<UUID>,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
+    /// The version of the record, incremented on every edit to detect concurrent edits.
+    #[serde(default)]
+    pub version: u64,
+    /// The index under which the current address is derived, which is increased whenever the address is rotated.
+    #[serde(default)]
+    pub derivation_index: u32,
+    /// The previous addresses of the account, which can still be transferred from during their grace period.
+    #[serde(default)]
+    pub retired_addresses: Vec<RetiredAccountAddress>,
+    /// The network of the blockchain that the account holds its assets on, the main network if not set.
+    #[serde(default)]
+    pub network: Option<NetworkId>,
+    /// The review that the account is under, no transfers can be requested from the account while it is active.
+    #[serde(default)]
+    pub review: Option<AccountReview>,
+    /// Whether the account is ready to be used, the accounts whose address could not be generated when they
+    /// were created have no address until it is generated in the background.
+    #[serde(default)]
+    pub setup_state: AccountSetupState,
+}
+
+/// The setup state of an account.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountSetupState {
+    #[default]
+    Ready,
+    /// The address of the account could not be generated yet (e.g. because the signing service failed), the
+    /// generation is retried in the background until it succeeds.
+    PendingAddressGeneration {
+        attempts: u32,
+        last_error: String,
+        next_attempt_at: Timestamp,
+    },
+}
+
+impl AccountSetupState {
+    /// The delay before the first retry of the address generation, which is doubled after every failed attempt.
+    pub const RETRY_DELAY_NS: u64 = 60 * 1_000_000_000;
+    /// The longest delay between two attempts of the address generation, which is 1 hour.
+    pub const MAX_RETRY_DELAY_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    /// Returns the state of an account after the given number of failed attempts to generate its address.
+    pub fn pending_address_generation(attempts: u32, last_error: String, now: Timestamp) -> Self {
+        let delay = Self::RETRY_DELAY_NS
+            .saturating_mul(2u64.saturating_pow(attempts.saturating_sub(1)))
+            .min(Self::MAX_RETRY_DELAY_NS);
+
+        AccountSetupState::PendingAddressGeneration {
+            attempts,
+            last_error,
+            next_attempt_at: now + delay,
+        }
+    }
+}
+
+/// A review of the account (e.g. during a quarterly policy audit), which blocks the creation of its transfer
+/// requests until it is cleared or expires.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountReview {
+    /// Why the account is under review (e.g. `Q3 policy audit`).
+    pub reason: String,
+    /// The user that flagged the account.
+    pub flagged_by: UUID,
+    pub flagged_at: Timestamp,
+    /// The time at which the review ends if it was not cleared before.
+    pub expires_at: Timestamp,
+}
+
+impl AccountReview {
+    pub const REASON_RANGE: (usize, usize) = (1, 500);
+    /// The longest review of an account, which is 90 days.
+    pub const MAX_DURATION_NS: u64 = 90 * 24 * 60 * 60 * 1_000_000_000;
+
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        now < self.expires_at
+    }
+}
+
+impl ModelValidator<AccountError> for AccountReview {
+    fn validate(&self) -> ModelValidatorResult<AccountError> {
+        let (min_reason_length, max_reason_length) = Self::REASON_RANGE;
+        let reason_length = self.reason.trim().len();
+        if reason_length < min_reason_length || reason_length > max_reason_length {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "The review reason must have between {} and {} characters.",
+                    min_reason_length, max_reason_length
+                ),
+            });
+        }
+
+        if self.expires_at <= self.flagged_at
+            || self.expires_at - self.flagged_at > Self::MAX_DURATION_NS
+        {
+            return Err(AccountError::ValidationError {
+                info: "The review must end in the future and last at most 90 days.".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// An address that the account rotated away from, e.g. because it was exposed in a phishing incident.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RetiredAccountAddress {
+    pub address: String,
+    /// The index under which the address is derived.
+    pub derivation_index: u32,
+    pub retired_at: Timestamp,
+    /// The time until which the address can still be transferred from.
+    pub grace_period_ends_at: Timestamp,
 }
 
 #[storable]
@@ -121,7 +238,9 @@ impl ModelValidator<AccountError> for Account {
     fn validate(&self) -> ModelValidatorResult<AccountError> {
         self.metadata.validate()?;
         validate_symbol(&self.symbol)?;
-        validate_address(&self.address)?;
+        if self.is_ready() {
+            validate_address(&self.address)?;
+        }
 
         if let Some(transfer_request_policy_id) = &self.transfer_request_policy_id {
             validate_policy_id(transfer_request_policy_id, "transfer_request_policy_id")?;
@@ -139,6 +258,8 @@ impl Account {
     pub const ADDRESS_RANGE: (u8, u8) = (1, 255);
     pub const SYMBOL_RANGE: (u8, u8) = (1, 8);
     pub const MAX_POLICIES: u8 = 10;
+    /// The longest grace period of a retired address, which is 90 days.
+    pub const MAX_ADDRESS_GRACE_PERIOD_SECS: u64 = 90 * 24 * 60 * 60;
 
     /// Creates a new account key from the given key components.
     pub fn key(id: AccountId) -> AccountKey {
@@ -149,9 +270,90 @@ impl Account {
         Self::key(self.id)
     }
 
+    /// Returns the resources of the permissions that the owners of the account hold on it.
+    pub fn owner_resources(id: AccountId) -> [Resource; 3] {
+        [
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(id))),
+            Resource::Account(AccountResourceAction::Update(ResourceId::Id(id))),
+            Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(id))),
+        ]
+    }
+
     pub fn metadata_map(&self) -> HashMap<String, String> {
         self.metadata.map()
     }
+
+    /// Returns the review that the account is under, unless it expired.
+    pub fn active_review(&self, now: Timestamp) -> Option<&AccountReview> {
+        self.review.as_ref().filter(|review| review.is_active(now))
+    }
+
+    /// Returns the number of decimals that the amounts of the account are displayed with.
+    pub fn display_decimals(&self) -> u32 {
+        display_decimals(
+            &self.blockchain,
+            &self.standard,
+            &self.symbol,
+            self.decimals,
+        )
+    }
+
+    /// Whether the account has an address, the accounts whose address is still being generated can't be used.
+    pub fn is_ready(&self) -> bool {
+        self.setup_state == AccountSetupState::Ready
+    }
+
+    /// Returns the id of the network that the account holds its assets on.
+    pub fn network_id(&self) -> &str {
+        self.network.as_deref().unwrap_or(Network::MAINNET)
+    }
+
+    /// Moves the account to the given address derived under the next index, the current address is retired
+    /// and can still be transferred from until the end of the grace period.
+    ///
+    /// Retired addresses whose grace period has ended are dropped.
+    pub fn rotate_address(&mut self, address: String, grace_period_secs: u64, now: Timestamp) {
+        self.retired_addresses
+            .retain(|retired| retired.grace_period_ends_at > now);
+        self.retired_addresses.push(RetiredAccountAddress {
+            address: std::mem::replace(&mut self.address, address),
+            derivation_index: self.derivation_index,
+            retired_at: now,
+            grace_period_ends_at: now
+                .saturating_add(grace_period_secs.saturating_mul(1_000_000_000)),
+        });
+        self.derivation_index += 1;
+    }
+
+    /// Returns the account as it transfers from the given address, which is either its current address or a
+    /// retired address whose grace period has not ended yet.
+    pub fn spending_from(&self, address: &str, now: Timestamp) -> Option<Account> {
+        if self.address == address {
+            return Some(self.clone());
+        }
+
+        self.retired_addresses
+            .iter()
+            .find(|retired| retired.address == address && retired.grace_period_ends_at > now)
+            .map(|retired| Account {
+                address: retired.address.clone(),
+                derivation_index: retired.derivation_index,
+                ..self.clone()
+            })
+    }
+
+    /// Returns the account as it transfers from each of its addresses that can still be spent from, the
+    /// current address comes first.
+    pub fn spendable_addresses(&self, now: Timestamp) -> Vec<Account> {
+        std::iter::once(self.address.as_str())
+            .chain(
+                self.retired_addresses
+                    .iter()
+                    .map(|retired| retired.address.as_str()),
+            )
+            .filter_map(|address| self.spending_from(address, now))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -159,6 +361,70 @@ mod tests {
     use super::account_test_utils::mock_account;
     use super::*;
 
+    #[test]
+    fn reviews_expire_by_themselves() {
+        let mut account = mock_account();
+        account.review = Some(AccountReview {
+            reason: "Q3 policy audit".to_string(),
+            flagged_by: [1; 16],
+            flagged_at: 0,
+            expires_at: 100,
+        });
+
+        assert!(account.review.as_ref().unwrap().validate().is_ok());
+        assert!(account.active_review(99).is_some());
+        assert!(account.active_review(100).is_none());
+    }
+
+    #[test]
+    fn address_generation_is_retried_with_an_increasing_delay() {
+        let next_attempt_at = |attempts| match AccountSetupState::pending_address_generation(
+            attempts,
+            "signing failed".to_string(),
+            0,
+        ) {
+            AccountSetupState::PendingAddressGeneration {
+                next_attempt_at, ..
+            } => next_attempt_at,
+            AccountSetupState::Ready => unreachable!(),
+        };
+
+        assert_eq!(next_attempt_at(1), AccountSetupState::RETRY_DELAY_NS);
+        assert_eq!(next_attempt_at(3), 4 * AccountSetupState::RETRY_DELAY_NS);
+        assert_eq!(next_attempt_at(100), AccountSetupState::MAX_RETRY_DELAY_NS);
+
+        let mut account = mock_account();
+        account.address = String::new();
+        account.setup_state =
+            AccountSetupState::pending_address_generation(1, "signing failed".to_string(), 0);
+        assert!(!account.is_ready());
+        assert!(account.validate().is_ok());
+    }
+
+    #[test]
+    fn retired_addresses_can_be_spent_from_during_their_grace_period() {
+        let mut account = mock_account();
+        account.rotate_address("0x5678".to_string(), 60, 0);
+
+        assert_eq!(account.address, "0x5678");
+        assert_eq!(account.derivation_index, 1);
+
+        let spending = account.spending_from("0x1234", 59 * 1_000_000_000).unwrap();
+        assert_eq!(spending.derivation_index, 0);
+        assert_eq!(spending.address, "0x1234");
+        assert!(account
+            .spending_from("0x1234", 60 * 1_000_000_000)
+            .is_none());
+        assert_eq!(
+            account.spending_from("0x5678", 0).unwrap().derivation_index,
+            1
+        );
+
+        account.rotate_address("0x9abc".to_string(), 60, 60 * 1_000_000_000);
+        assert_eq!(account.retired_addresses.len(), 1);
+        assert_eq!(account.retired_addresses[0].address, "0x5678");
+    }
+
     #[test]
     fn fail_symbol_validation_too_short() {
         let mut account = mock_account();
@@ -296,6 +562,12 @@ pub mod account_test_utils {
             symbol: "ICP".to_string(),
             transfer_request_policy_id: None,
             configs_request_policy_id: None,
+            version: 0,
+            derivation_index: 0,
+            retired_addresses: vec![],
+            network: None,
+            review: None,
+            setup_state: AccountSetupState::Ready,
         }
     }
 
@@ -1,8 +1,10 @@
-use crate::core::ic_cdk::api::print;
+use super::AccountId;
+use crate::log_warn;
 use crate::mappers::HelperMapper;
 use orbit_essentials::storable;
 use orbit_essentials::types::Timestamp;
 use std::hash::Hash;
+use time::OffsetDateTime;
 
 /// Represents the balance of a account.
 #[storable]
@@ -17,12 +19,101 @@ pub struct AccountBalance {
 impl AccountBalance {
     pub fn to_u64(&self) -> u64 {
         HelperMapper::nat_to_u64(self.balance.clone()).unwrap_or_else(|_| {
-            print(format!(
-                "Failed to convert balance to u64: {}",
-                self.balance
-            ));
+            log_warn!("Failed to convert balance to u64: {}", self.balance);
 
             0u64
         })
     }
 }
+
+/// The key of a balance snapshot, which orders the snapshots of an account by time.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountBalanceSnapshotKey {
+    /// The account of the snapshot.
+    pub account_id: AccountId,
+    /// The time at which the snapshot was taken.
+    pub timestamp: Timestamp,
+}
+
+/// The balance of an account at the time of the snapshot, kept apart from its key so that the
+/// history of the balances stays compact.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountBalanceSnapshot {
+    pub balance: candid::Nat,
+}
+
+impl AccountBalanceSnapshot {
+    /// The default range of the balance history, which is 30 days.
+    pub const DEFAULT_HISTORY_RANGE_NS: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+    /// The maximum number of snapshots of a balance history response.
+    pub const MAX_HISTORY_SNAPSHOTS: usize = 1_000;
+}
+
+/// The time buckets of the balance history, each bucket is represented by its last snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BalanceHistoryGranularity {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl BalanceHistoryGranularity {
+    const HOUR_NS: u64 = 60 * 60 * 1_000_000_000;
+    const DAY_NS: u64 = 24 * Self::HOUR_NS;
+
+    /// Returns the start of the bucket of the given time, in UTC. The weeks start on Mondays.
+    pub fn bucket_start(&self, timestamp: Timestamp) -> Timestamp {
+        match self {
+            BalanceHistoryGranularity::Hourly => timestamp - timestamp % Self::HOUR_NS,
+            BalanceHistoryGranularity::Daily => timestamp - timestamp % Self::DAY_NS,
+            BalanceHistoryGranularity::Weekly => {
+                let days = timestamp / Self::DAY_NS;
+                // the unix epoch was a Thursday
+                let days_since_monday = (days + 3) % 7;
+
+                (days - days_since_monday) * Self::DAY_NS
+            }
+            BalanceHistoryGranularity::Monthly => {
+                let datetime = OffsetDateTime::from_unix_timestamp_nanos(timestamp as i128)
+                    .expect("Invalid timestamp");
+                let first_day = datetime
+                    .date()
+                    .replace_day(1)
+                    .expect("Invalid first day of the month");
+
+                first_day.midnight().assume_utc().unix_timestamp_nanos() as Timestamp
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use orbit_essentials::utils::rfc3339_to_timestamp;
+
+    #[test]
+    fn balance_history_buckets_start_at_the_beginning_of_the_period() {
+        let timestamp = rfc3339_to_timestamp("2024-03-14T15:09:26Z");
+
+        assert_eq!(
+            BalanceHistoryGranularity::Hourly.bucket_start(timestamp),
+            rfc3339_to_timestamp("2024-03-14T15:00:00Z")
+        );
+        assert_eq!(
+            BalanceHistoryGranularity::Daily.bucket_start(timestamp),
+            rfc3339_to_timestamp("2024-03-14T00:00:00Z")
+        );
+        assert_eq!(
+            BalanceHistoryGranularity::Weekly.bucket_start(timestamp),
+            rfc3339_to_timestamp("2024-03-11T00:00:00Z")
+        );
+        assert_eq!(
+            BalanceHistoryGranularity::Monthly.bucket_start(timestamp),
+            rfc3339_to_timestamp("2024-03-01T00:00:00Z")
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use super::Blockchain;
 use crate::errors::AddressBookError;
 use crate::models::Metadata;
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use orbit_essentials::model::ModelKey;
 use orbit_essentials::storable;
 use orbit_essentials::{
@@ -30,10 +30,25 @@ pub struct AddressBookEntry {
     /// The labels associated with the address.
     #[serde(default)]
     pub labels: Vec<String>,
+    /// Where the entry was synced from, not set for the entries that are managed by this station.
+    #[serde(default)]
+    pub origin: Option<AddressBookEntryOrigin>,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
 }
 
+/// The provenance of an address book entry that was synced from an address book source.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddressBookEntryOrigin {
+    /// The canister that published the entry (e.g. another station or a registry canister).
+    pub source_id: Principal,
+    /// The id of the entry in the address book of the source.
+    pub source_entry_id: UUID,
+    /// The last time the entry was added or updated from the source.
+    pub synced_at: Timestamp,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddressBookEntryKey {
@@ -133,6 +148,13 @@ impl AddressBookEntry {
     pub fn metadata_map(&self) -> HashMap<String, String> {
         self.metadata.map()
     }
+
+    /// Whether the entry was synced from the given address book source.
+    pub fn is_synced_from(&self, source_id: &Principal) -> bool {
+        self.origin
+            .as_ref()
+            .is_some_and(|origin| origin.source_id == *source_id)
+    }
 }
 
 #[derive(CandidType, Deserialize, Debug, Clone)]
@@ -141,6 +163,8 @@ pub struct ListAddressBookEntriesInput {
     pub addresses: Option<Vec<String>>,
     pub blockchain: Option<Blockchain>,
     pub labels: Option<Vec<String>>,
+    pub search_term: Option<String>,
+    pub address_owners: Option<Vec<String>>,
 }
 
 #[derive(CandidType, Deserialize, Debug, Clone)]
@@ -268,6 +292,7 @@ pub mod address_book_entry_test_utils {
             labels: Vec::new(),
             blockchain: Blockchain::InternetComputer,
             metadata: Metadata::mock(),
+            origin: None,
             last_modification_timestamp: 0,
         }
     }
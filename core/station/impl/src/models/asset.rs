@@ -13,6 +13,10 @@ pub struct Asset {
     pub symbol: String,
     /// The asset name (e.g. `Internet Computer`, `Bitcoin`, `Ethereum`, etc.)
     pub name: String,
+    /// The number of decimals of the minor unit of the asset (e.g. `8` for `ICP`).
+    pub decimals: u32,
+    /// The number of decimals that the amounts of the asset are displayed with (e.g. `4` for `ICP`).
+    pub display_decimals: u32,
     /// The asset metadata (e.g. `{"logo": "https://example.com/logo.png"}`),
     /// also, in the case of non-native assets, it can contain other required
     /// information (e.g. `{"address": "0x1234"}`).
@@ -25,6 +29,8 @@ impl Hash for Asset {
         self.standard.hash(state);
         self.symbol.hash(state);
         self.name.hash(state);
+        self.decimals.hash(state);
+        self.display_decimals.hash(state);
 
         // For HashMap we need to sort the keys first to ensure that the hash is stable.
         let mut keys: Vec<&String> = self.metadata.keys();
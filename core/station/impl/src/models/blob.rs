@@ -0,0 +1,141 @@
+use super::UserId;
+use crate::errors::BlobError;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+use uuid::Uuid;
+
+/// The blob id, which is a UUID.
+pub type BlobId = UUID;
+
+/// A small binary file stored by the station (e.g. the logo of an asset or of the station), which is served
+/// through the HTTP gateway at `/blobs/<id>`.
+///
+/// Blobs are immutable, a new logo is uploaded as a new blob and the old one is removed once unreferenced.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Blob {
+    pub id: BlobId,
+    /// The media type of the content (e.g. `image/png`).
+    pub content_type: String,
+    #[serde(with = "serde_bytes")]
+    pub content: Vec<u8>,
+    /// The sha256 hash of the content, served as the entity tag of the blob.
+    pub sha256: Vec<u8>,
+    pub uploaded_by: UserId,
+    pub created_at: Timestamp,
+}
+
+impl ModelKey<BlobId> for Blob {
+    fn key(&self) -> BlobId {
+        self.id
+    }
+}
+
+impl Blob {
+    /// The maximum size of the content of a blob.
+    pub const MAX_SIZE_BYTES: usize = 128 * 1024;
+    /// The maximum size of the content of all the blobs of the station.
+    pub const MAX_TOTAL_SIZE_BYTES: usize = 8 * 1024 * 1024;
+    /// The media types of the content that can be stored, blobs are meant for logos and branding images.
+    pub const CONTENT_TYPES: [&'static str; 5] = [
+        "image/png",
+        "image/jpeg",
+        "image/gif",
+        "image/webp",
+        "image/svg+xml",
+    ];
+
+    pub fn to_key(&self) -> BlobId {
+        ModelKey::key(self)
+    }
+
+    /// The path of the blob in the HTTP gateway of the station.
+    pub fn http_path(&self) -> String {
+        format!("/blobs/{}", Uuid::from_bytes(self.id).hyphenated())
+    }
+
+    /// The entity tag of the blob, which changes with its content.
+    pub fn etag(&self) -> String {
+        format!("\"{}\"", hex::encode(&self.sha256))
+    }
+}
+
+impl ModelValidator<BlobError> for Blob {
+    fn validate(&self) -> ModelValidatorResult<BlobError> {
+        if !Self::CONTENT_TYPES.contains(&self.content_type.as_str()) {
+            return Err(BlobError::UnsupportedContentType {
+                content_type: self.content_type.clone(),
+                supported_content_types: Self::CONTENT_TYPES.join(", "),
+            });
+        }
+
+        if self.content.is_empty() || self.content.len() > Self::MAX_SIZE_BYTES {
+            return Err(BlobError::InvalidSize {
+                max_size_bytes: Self::MAX_SIZE_BYTES,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::blob_test_utils::mock_blob;
+    use super::*;
+
+    #[test]
+    fn fail_unsupported_content_type() {
+        let mut blob = mock_blob();
+        blob.content_type = "text/html".to_string();
+
+        assert!(matches!(
+            blob.validate(),
+            Err(BlobError::UnsupportedContentType { .. })
+        ));
+    }
+
+    #[test]
+    fn fail_empty_or_too_large_content() {
+        let mut blob = mock_blob();
+        blob.content = Vec::new();
+
+        assert_eq!(
+            blob.validate(),
+            Err(BlobError::InvalidSize {
+                max_size_bytes: Blob::MAX_SIZE_BYTES,
+            })
+        );
+
+        blob.content = vec![0; Blob::MAX_SIZE_BYTES + 1];
+
+        assert!(blob.validate().is_err());
+
+        blob.content = vec![0; Blob::MAX_SIZE_BYTES];
+
+        assert!(blob.validate().is_ok());
+    }
+}
+
+#[cfg(test)]
+pub mod blob_test_utils {
+    use super::*;
+    use crate::core::ic_cdk::next_time;
+    use sha2::{Digest, Sha256};
+
+    pub fn mock_blob() -> Blob {
+        let content = b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>".to_vec();
+
+        Blob {
+            id: *Uuid::new_v4().as_bytes(),
+            content_type: "image/svg+xml".to_string(),
+            sha256: Sha256::digest(&content).to_vec(),
+            content,
+            uploaded_by: [1; 16],
+            created_at: next_time(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use super::permission::Allow;
 use super::resource::ValidationMethodResourceTarget;
-use super::{ConfigureExternalCanisterSettingsInput, RequestPolicyRule};
+use super::{
+    ConfigureExternalCanisterSettingsInput, ControllersChange, DefiniteCanisterSettingsInput,
+    LogVisibility, LogVisibilityChange, NatSettingChange, NativeSettingsChange, RequestPolicyRule,
+};
 use crate::errors::ExternalCanisterError;
 use candid::Principal;
 use orbit_essentials::model::ModelKey;
@@ -35,12 +38,35 @@ pub struct ExternalCanister {
     pub labels: Vec<String>,
     /// The state of the canister (e.g. active, archived, etc.)
     pub state: ExternalCanisterState,
+    /// The url of the source code repository of the canister.
+    #[serde(default)]
+    pub repository_url: Option<String>,
+    /// The hash of the module last installed on the canister through the station.
+    #[serde(default)]
+    pub module_hash: Option<Vec<u8>>,
+    /// The snapshots of the canister that were taken through the station.
+    #[serde(default)]
+    pub snapshots: Vec<ExternalCanisterSnapshot>,
     /// When the canister was added to the station.
     pub created_at: Timestamp,
     /// The last time the record was updated.
     pub modified_at: Option<Timestamp>,
 }
 
+/// A snapshot of the state of an external canister, taken through the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterSnapshot {
+    /// The id of the snapshot, assigned by the management canister.
+    pub snapshot_id: Vec<u8>,
+    /// The hash of the module that was installed when the snapshot was taken.
+    pub module_hash: Option<Vec<u8>>,
+    /// The size of the snapshot in bytes.
+    pub total_size: u64,
+    /// When the snapshot was taken.
+    pub taken_at: Timestamp,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ExternalCanisterKey {
@@ -53,6 +79,45 @@ impl ModelKey<ExternalCanisterKey> for ExternalCanister {
     }
 }
 
+/// The key of a retained log record, ordered by canister and then by the index of the record.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterLogRecordKey {
+    pub canister_id: Principal,
+    pub idx: u64,
+}
+
+/// A log record of an external canister, as served by the management canister.
+///
+/// Records are only kept by the station when they are retained on fetch, since the management
+/// canister only serves the most recent logs of a canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterLogRecord {
+    /// The canister id of the external canister that emitted the record.
+    pub canister_id: Principal,
+    /// The index of the record, which increases monotonically for each canister.
+    pub idx: u64,
+    /// When the record was emitted.
+    pub timestamp: Timestamp,
+    /// The content of the record.
+    pub content: Vec<u8>,
+}
+
+impl ExternalCanisterLogRecord {
+    /// The maximum number of log records retained per canister, the oldest records are dropped first.
+    pub const MAX_RETAINED_RECORDS_PER_CANISTER: usize = 1_000;
+}
+
+impl ModelKey<ExternalCanisterLogRecordKey> for ExternalCanisterLogRecord {
+    fn key(&self) -> ExternalCanisterLogRecordKey {
+        ExternalCanisterLogRecordKey {
+            canister_id: self.canister_id,
+            idx: self.idx,
+        }
+    }
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ExternalCanisterCallPermission {
@@ -116,6 +181,7 @@ impl ExternalCanister {
     pub const MAX_LABEL_LENGTH: usize = 50;
     pub const MAX_LABELS: usize = 10;
     pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
+    pub const MAX_REPOSITORY_URL_LENGTH: usize = 500;
 
     /// Checks if the external canister is archived.
     pub fn is_archived(&self) -> bool {
@@ -138,6 +204,36 @@ impl ExternalCanister {
         if let Some(state) = changes.state {
             self.state = state;
         }
+
+        if let Some(repository_url) = changes.repository_url {
+            self.repository_url = Some(repository_url);
+        }
+    }
+
+    /// Finds a snapshot of the canister that was taken through the station.
+    pub fn find_snapshot(&self, snapshot_id: &[u8]) -> Option<&ExternalCanisterSnapshot> {
+        self.snapshots
+            .iter()
+            .find(|snapshot| snapshot.snapshot_id == snapshot_id)
+    }
+
+    /// Adds a new snapshot of the canister, removing the snapshot that it replaced.
+    pub fn add_snapshot(
+        &mut self,
+        snapshot: ExternalCanisterSnapshot,
+        replaced_snapshot_id: Option<&[u8]>,
+    ) {
+        if let Some(replaced_snapshot_id) = replaced_snapshot_id {
+            self.remove_snapshot(replaced_snapshot_id);
+        }
+
+        self.snapshots.push(snapshot);
+    }
+
+    /// Removes a snapshot of the canister, unknown snapshots are ignored.
+    pub fn remove_snapshot(&mut self, snapshot_id: &[u8]) {
+        self.snapshots
+            .retain(|snapshot| snapshot.snapshot_id != snapshot_id);
     }
 }
 
@@ -177,6 +273,30 @@ fn validate_description(
     Ok(())
 }
 
+fn validate_repository_url(
+    repository_url: &Option<String>,
+) -> ModelValidatorResult<ExternalCanisterError> {
+    if let Some(repository_url) = repository_url {
+        if !repository_url.starts_with("https://") {
+            return Err(ExternalCanisterError::ValidationError {
+                info: "The repository url of the external canister must start with https://."
+                    .to_string(),
+            });
+        }
+
+        if repository_url.len() > ExternalCanister::MAX_REPOSITORY_URL_LENGTH {
+            return Err(ExternalCanisterError::ValidationError {
+                info: format!(
+                    "The repository url of the external canister cannot be longer than {} characters.",
+                    ExternalCanister::MAX_REPOSITORY_URL_LENGTH
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_labels(labels: &[String]) -> ModelValidatorResult<ExternalCanisterError> {
     if labels.len() > ExternalCanister::MAX_LABELS {
         return Err(ExternalCanisterError::ValidationError {
@@ -214,11 +334,104 @@ impl ModelValidator<ExternalCanisterError> for ExternalCanister {
         validate_name(&self.name)?;
         validate_description(&self.description)?;
         validate_labels(&self.labels)?;
+        validate_repository_url(&self.repository_url)?;
 
         Ok(())
     }
 }
 
+/// The native settings of a canister as reported by the management canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NativeCanisterSettings {
+    pub controllers: Vec<Principal>,
+    pub compute_allocation: candid::Nat,
+    pub memory_allocation: candid::Nat,
+    pub freezing_threshold: candid::Nat,
+    pub reserved_cycles_limit: candid::Nat,
+    /// Not reported by older versions of the management canister.
+    pub log_visibility: Option<LogVisibility>,
+}
+
+impl NativeCanisterSettings {
+    /// Returns the changes that applying the given settings makes to the current settings.
+    ///
+    /// Settings that are not set or are equal to the current value are not part of the changes.
+    pub fn diff(&self, settings: &DefiniteCanisterSettingsInput) -> Vec<NativeSettingsChange> {
+        let mut changes = Vec::new();
+
+        if let Some(controllers) = &settings.controllers {
+            let added: Vec<Principal> = controllers
+                .iter()
+                .filter(|controller| !self.controllers.contains(controller))
+                .cloned()
+                .collect();
+            let removed: Vec<Principal> = self
+                .controllers
+                .iter()
+                .filter(|controller| !controllers.contains(controller))
+                .cloned()
+                .collect();
+
+            if !added.is_empty() || !removed.is_empty() {
+                changes.push(NativeSettingsChange::Controllers(ControllersChange {
+                    added,
+                    removed,
+                }));
+            }
+        }
+
+        let nat_settings: [(
+            &Option<candid::Nat>,
+            &candid::Nat,
+            fn(NatSettingChange) -> NativeSettingsChange,
+        ); 4] = [
+            (
+                &settings.compute_allocation,
+                &self.compute_allocation,
+                NativeSettingsChange::ComputeAllocation,
+            ),
+            (
+                &settings.memory_allocation,
+                &self.memory_allocation,
+                NativeSettingsChange::MemoryAllocation,
+            ),
+            (
+                &settings.freezing_threshold,
+                &self.freezing_threshold,
+                NativeSettingsChange::FreezingThreshold,
+            ),
+            (
+                &settings.reserved_cycles_limit,
+                &self.reserved_cycles_limit,
+                NativeSettingsChange::ReservedCyclesLimit,
+            ),
+        ];
+
+        for (proposed, current, change) in nat_settings {
+            if let Some(proposed) = proposed {
+                if proposed != current {
+                    changes.push(change(NatSettingChange {
+                        current: current.clone(),
+                        proposed: proposed.clone(),
+                    }));
+                }
+            }
+        }
+
+        if let Some(log_visibility) = &settings.log_visibility {
+            if self.log_visibility.as_ref() != Some(log_visibility) {
+                changes.push(NativeSettingsChange::LogVisibility(LogVisibilityChange {
+                    current: self.log_visibility.clone(),
+                    proposed: log_visibility.clone(),
+                }));
+            }
+        }
+
+        changes
+    }
+}
+
 #[cfg(any(test, feature = "canbench"))]
 pub mod external_canister_test_utils {
     use super::*;
@@ -237,6 +450,9 @@ pub mod external_canister_test_utils {
             description: Some("Test canister description".to_string()),
             labels: vec!["test".to_string()],
             state: ExternalCanisterState::Active,
+            repository_url: None,
+            module_hash: None,
+            snapshots: Vec::new(),
             created_at: next_time(),
             modified_at: None,
         }
@@ -365,6 +581,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invalid_external_canister_validation_with_non_https_repository_url() {
+        let result = validate_repository_url(&Some("http://github.com/dfinity/orbit".to_string()));
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            ExternalCanisterError::ValidationError {
+                info: "The repository url of the external canister must start with https://."
+                    .to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn native_settings_diff_only_contains_changes() {
+        let current = NativeCanisterSettings {
+            controllers: vec![
+                Principal::from_slice(&[1; 29]),
+                Principal::from_slice(&[2; 29]),
+            ],
+            compute_allocation: candid::Nat::from(0u64),
+            memory_allocation: candid::Nat::from(0u64),
+            freezing_threshold: candid::Nat::from(2_592_000u64),
+            reserved_cycles_limit: candid::Nat::from(5_000_000_000_000u64),
+            log_visibility: Some(LogVisibility::Controllers),
+        };
+
+        let diff = current.diff(&DefiniteCanisterSettingsInput {
+            controllers: Some(vec![
+                Principal::from_slice(&[2; 29]),
+                Principal::from_slice(&[3; 29]),
+            ]),
+            compute_allocation: Some(candid::Nat::from(0u64)),
+            memory_allocation: None,
+            freezing_threshold: Some(candid::Nat::from(100u64)),
+            reserved_cycles_limit: None,
+            log_visibility: Some(LogVisibility::Public),
+        });
+
+        assert_eq!(
+            diff,
+            vec![
+                NativeSettingsChange::Controllers(ControllersChange {
+                    added: vec![Principal::from_slice(&[3; 29])],
+                    removed: vec![Principal::from_slice(&[1; 29])],
+                }),
+                NativeSettingsChange::FreezingThreshold(NatSettingChange {
+                    current: candid::Nat::from(2_592_000u64),
+                    proposed: candid::Nat::from(100u64),
+                }),
+                NativeSettingsChange::LogVisibility(LogVisibilityChange {
+                    current: Some(LogVisibility::Controllers),
+                    proposed: LogVisibility::Public,
+                }),
+            ]
+        );
+    }
+
     #[test]
     fn update_existing_model_with_changes() {
         let mut model = mock_external_canister();
@@ -375,6 +650,7 @@ mod tests {
             permissions: None,
             request_policies: None,
             state: Some(ExternalCanisterState::Archived),
+            repository_url: Some("https://github.com/dfinity/orbit".to_string()),
         };
 
         model.update_with(changes);
@@ -383,5 +659,32 @@ mod tests {
         assert_eq!(model.description, Some("New description".to_string()));
         assert_eq!(model.labels, vec!["new".to_string()]);
         assert_eq!(model.state, ExternalCanisterState::Archived);
+        assert_eq!(
+            model.repository_url,
+            Some("https://github.com/dfinity/orbit".to_string())
+        );
+    }
+
+    #[test]
+    fn new_snapshot_replaces_the_replaced_snapshot() {
+        let mut model = mock_external_canister();
+        let snapshot = |id: u8| ExternalCanisterSnapshot {
+            snapshot_id: vec![id],
+            module_hash: Some(vec![id; 32]),
+            total_size: 100,
+            taken_at: 0,
+        };
+
+        model.add_snapshot(snapshot(1), None);
+        model.add_snapshot(snapshot(2), None);
+        model.add_snapshot(snapshot(3), Some(&[1]));
+
+        assert_eq!(model.snapshots, vec![snapshot(2), snapshot(3)]);
+        assert_eq!(model.find_snapshot(&[3]), Some(&snapshot(3)));
+        assert_eq!(model.find_snapshot(&[1]), None);
+
+        model.remove_snapshot(&[2]);
+
+        assert_eq!(model.snapshots, vec![snapshot(3)]);
     }
 }
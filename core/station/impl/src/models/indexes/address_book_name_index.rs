@@ -0,0 +1,44 @@
+use crate::models::{AddressBookEntry, AddressBookEntryId};
+use orbit_essentials::storable;
+
+/// Represents an index to facilitate the search of address book entries by the name of their owner.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddressBookNameIndex {
+    /// The lowercased name of the owner of the address.
+    pub name: String,
+    /// The address book entry associated with the name.
+    pub entry_id: AddressBookEntryId,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddressBookNameIndexCriteria {
+    /// The prefix that the name must start with, matched case-insensitively.
+    pub prefix: String,
+}
+
+impl AddressBookEntry {
+    pub fn to_index_by_name(&self) -> AddressBookNameIndex {
+        AddressBookNameIndex {
+            name: self.address_owner.to_lowercase(),
+            entry_id: self.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::address_book_entry_test_utils::mock_address_book_entry;
+
+    #[test]
+    fn test_address_book_entry_to_name_index() {
+        let mut entry = mock_address_book_entry();
+        entry.address_owner = "Acme Corp".to_string();
+
+        let index = entry.to_index_by_name();
+
+        assert_eq!(index.name, "acme corp");
+        assert_eq!(index.entry_id, entry.id);
+    }
+}
@@ -1,6 +1,13 @@
-use crate::models::{
-    resource::Resource, Request, RequestApprovalStatus, RequestId, RequestOperationFilterType,
-    RequestStatus, RequestStatusCode, UserId,
+use crate::{
+    core::{
+        evaluation::{Evaluate, REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR},
+        request::RequestApprovalRightsEvaluator,
+    },
+    models::{
+        request_policy_rule::RequestPolicyRule, resource::Resource, Request, RequestApprovalStatus,
+        RequestId, RequestOperationFilterType, RequestStatus, RequestStatusCode, RequestViewer,
+        UserId,
+    },
 };
 use orbit_essentials::{storable, types::Timestamp};
 use std::collections::BTreeSet;
@@ -19,6 +26,14 @@ pub struct RequestIndexFields {
     pub approved_by: BTreeSet<UserId>,
     pub rejected_by: BTreeSet<UserId>,
     pub resources: Vec<Resource>,
+    #[serde(default)]
+    pub confidential: bool,
+    /// The rules of the policy snapshot of the request, if it has one.
+    #[serde(default)]
+    pub policy_rules: Option<Vec<RequestPolicyRule>>,
+    /// Whether the payloads of the request were already pruned by the compaction job.
+    #[serde(default)]
+    pub compacted: bool,
 }
 
 #[storable]
@@ -39,6 +54,36 @@ pub struct RequestIndexKey {
     pub request_id: RequestId,
 }
 
+impl RequestIndexFields {
+    /// Checks if the request is visible to the viewer.
+    ///
+    /// Confidential requests are only visible to the requester, the users that can approve them and the admins.
+    pub fn is_visible_to(&self, viewer: &RequestViewer) -> bool {
+        if !self.confidential || viewer.is_admin {
+            return true;
+        }
+
+        let Some(user_id) = viewer.user_id else {
+            return false;
+        };
+
+        if self.requested_by == user_id
+            || self.approved_by.contains(&user_id)
+            || self.rejected_by.contains(&user_id)
+        {
+            return true;
+        }
+
+        RequestApprovalRightsEvaluator::new(
+            REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR.clone(),
+            user_id,
+            self,
+        )
+        .evaluate()
+        .unwrap_or(false)
+    }
+}
+
 impl Request {
     /// Converts the request to the corresponding index fields.
     pub fn index_fields(&self) -> RequestIndexFields {
@@ -71,6 +116,14 @@ impl Request {
                 })
                 .collect(),
             resources: self.operation.to_resources(),
+            confidential: self.confidential,
+            policy_rules: self.policy_snapshot.as_ref().map(|policies| {
+                policies
+                    .iter()
+                    .map(|policy| policy.rule.to_owned())
+                    .collect()
+            }),
+            compacted: self.compacted_at.is_some(),
         }
     }
 
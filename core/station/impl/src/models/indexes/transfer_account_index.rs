@@ -58,6 +58,9 @@ mod tests {
             initiator_user: [2; 16],
             last_modification_timestamp: 0,
             metadata: Metadata::default(),
+            submission: None,
+            compliance: None,
+            from_address: None,
         };
 
         let index = transfer.to_index_by_account();
@@ -1,4 +1,5 @@
 use crate::errors::MetadataError;
+use crate::models::MetadataEncryptionConfig;
 use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
 use orbit_essentials::storable;
 use station_api::MetadataDTO;
@@ -27,7 +28,7 @@ pub enum ChangeMetadata {
 
 impl Metadata {
     const MAX_METADATA: u8 = 10;
-    const MAX_METADATA_KEY_LEN: u8 = 24;
+    pub const MAX_METADATA_KEY_LEN: u8 = 24;
     const MAX_METADATA_VALUE_LEN: u8 = 255;
 
     pub fn new(metadata: BTreeMap<String, String>) -> Self {
@@ -118,11 +119,17 @@ impl ModelValidator<MetadataError> for Metadata {
                 });
             }
 
-            if v.len() > Self::MAX_METADATA_VALUE_LEN as usize {
+            // encrypted values are larger than the plaintexts they encrypt
+            let max_value_len = match MetadataEncryptionConfig::is_encrypted_value(v) {
+                true => MetadataEncryptionConfig::MAX_ENCRYPTED_VALUE_LEN,
+                false => Self::MAX_METADATA_VALUE_LEN as usize,
+            };
+
+            if v.len() > max_value_len {
                 return Err(MetadataError::ValidationError {
                     info: format!(
                         "Metadata value length exceeds the maximum allowed: {}",
-                        Self::MAX_METADATA_VALUE_LEN
+                        max_value_len
                     ),
                 });
             }
@@ -204,4 +211,31 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn encrypted_metadata_values_can_exceed_the_plaintext_limit() {
+        let metadata: Metadata = vec![MetadataDTO {
+            key: "notes".to_string(),
+            value: format!(
+                "{}{}",
+                MetadataEncryptionConfig::ENCRYPTED_VALUE_PREFIX,
+                "b".repeat(Metadata::MAX_METADATA_VALUE_LEN as usize + 1)
+            ),
+        }]
+        .into();
+
+        assert!(metadata.validate().is_ok());
+
+        let metadata: Metadata = vec![MetadataDTO {
+            key: "notes".to_string(),
+            value: format!(
+                "{}{}",
+                MetadataEncryptionConfig::ENCRYPTED_VALUE_PREFIX,
+                "b".repeat(MetadataEncryptionConfig::MAX_ENCRYPTED_VALUE_LEN)
+            ),
+        }]
+        .into();
+
+        assert!(metadata.validate().is_err());
+    }
 }
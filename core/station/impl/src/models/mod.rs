@@ -14,6 +14,9 @@ pub use blockchain::*;
 pub mod blockchain_standard;
 pub use blockchain_standard::*;
 
+pub mod network;
+pub use network::*;
+
 pub mod metadata;
 pub use metadata::*;
 
@@ -38,6 +41,12 @@ pub use transfer::*;
 pub mod notification;
 pub use notification::*;
 
+pub mod notification_bridge;
+pub use notification_bridge::*;
+
+pub mod principal_label;
+pub use principal_label::*;
+
 pub mod notification_status;
 pub use notification_status::*;
 
@@ -47,6 +56,12 @@ pub use notification_type::*;
 pub mod request_approval;
 pub use request_approval::*;
 
+pub mod vote_receipt;
+pub use vote_receipt::*;
+
+pub mod blob;
+pub use blob::*;
+
 pub mod request_approval_status;
 pub use request_approval_status::*;
 
@@ -67,12 +82,26 @@ pub use request_operation_filter_type::*;
 mod request_operation_type;
 pub use request_operation_type::*;
 
+mod request_operation_validation;
+
 pub mod request_policy_rule;
 pub use request_policy_rule::*;
 
+pub mod request_policy_lint;
+pub use request_policy_lint::*;
+
 pub mod request;
 pub use request::*;
 
+pub mod request_impact;
+pub use request_impact::*;
+
+pub mod request_execution_step;
+pub use request_execution_step::*;
+
+pub mod request_cost;
+pub use request_cost::*;
+
 pub mod asset;
 pub use asset::*;
 
@@ -85,6 +114,12 @@ pub use system::*;
 pub mod configuration;
 pub use configuration::*;
 
+pub mod station_event;
+pub use station_event::*;
+
+pub mod station_log;
+pub use station_log::*;
+
 pub mod permission;
 
 pub mod resource;
@@ -92,3 +127,9 @@ pub mod resource;
 pub mod indexes;
 
 pub mod rate_limiter;
+
+#[cfg(test)]
+mod storable_tests;
+
+#[cfg(test)]
+mod stable_compat;
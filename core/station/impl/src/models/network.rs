@@ -0,0 +1,142 @@
+use super::Blockchain;
+use crate::errors::NetworkError;
+use orbit_essentials::{model::ModelValidator, storable};
+
+/// The id of a network, which is unique within its blockchain (e.g. `mainnet`, `sepolia`).
+pub type NetworkId = String;
+
+/// A network of a blockchain that the station can hold assets on.
+///
+/// The main networks of the blockchains are always available, other networks (e.g. test networks) are added
+/// by the station with a request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Network {
+    /// The id of the network (e.g. `mainnet`, `sepolia`).
+    pub id: NetworkId,
+    /// The blockchain of the network.
+    pub blockchain: Blockchain,
+    /// The display name of the network (e.g. `Ethereum Sepolia`).
+    pub name: String,
+    /// The chain id of the network, for the blockchains that identify their networks with one (e.g. `11155111`).
+    pub chain_id: Option<String>,
+    /// Whether the assets on the network are test funds without real value.
+    pub is_testnet: bool,
+}
+
+impl Network {
+    /// The id of the main network of every blockchain.
+    pub const MAINNET: &'static str = "mainnet";
+    pub const MAX_ID_LENGTH: usize = 32;
+    pub const MAX_NAME_LENGTH: usize = 64;
+
+    /// The main networks of the blockchains, which are available without being added.
+    pub fn defaults() -> Vec<Network> {
+        vec![
+            Network::mainnet(Blockchain::InternetComputer, "Internet Computer", None),
+            Network::mainnet(Blockchain::Ethereum, "Ethereum", Some("1")),
+            Network::mainnet(Blockchain::Bitcoin, "Bitcoin", None),
+        ]
+    }
+
+    fn mainnet(blockchain: Blockchain, name: &str, chain_id: Option<&str>) -> Network {
+        Network {
+            id: Self::MAINNET.to_string(),
+            blockchain,
+            name: name.to_string(),
+            chain_id: chain_id.map(str::to_string),
+            is_testnet: false,
+        }
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        self.id == Self::MAINNET
+    }
+}
+
+impl ModelValidator<NetworkError> for Network {
+    fn validate(&self) -> Result<(), NetworkError> {
+        if self.id.is_empty()
+            || self.id.len() > Self::MAX_ID_LENGTH
+            || !self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(NetworkError::ValidationError {
+                info: format!(
+                    "The network id must have at most {} lowercase letters, digits or dashes.",
+                    Self::MAX_ID_LENGTH
+                ),
+            });
+        }
+
+        if self.name.trim().is_empty() || self.name.len() > Self::MAX_NAME_LENGTH {
+            return Err(NetworkError::ValidationError {
+                info: format!(
+                    "The network name must not be empty and have at most {} characters.",
+                    Self::MAX_NAME_LENGTH
+                ),
+            });
+        }
+
+        if let Some(chain_id) = &self.chain_id {
+            if chain_id.is_empty() || chain_id.len() > Self::MAX_ID_LENGTH {
+                return Err(NetworkError::ValidationError {
+                    info: format!(
+                        "The chain id must not be empty and have at most {} characters.",
+                        Self::MAX_ID_LENGTH
+                    ),
+                });
+            }
+        }
+
+        if self.is_mainnet() && self.is_testnet {
+            return Err(NetworkError::ValidationError {
+                info: "The main network of a blockchain cannot be a test network.".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sepolia() -> Network {
+        Network {
+            id: "sepolia".to_string(),
+            blockchain: Blockchain::Ethereum,
+            name: "Ethereum Sepolia".to_string(),
+            chain_id: Some("11155111".to_string()),
+            is_testnet: true,
+        }
+    }
+
+    #[test]
+    fn validates_the_network() {
+        assert!(sepolia().validate().is_ok());
+        assert!(Network::defaults().iter().all(|n| n.validate().is_ok()));
+
+        assert!(Network {
+            id: "Sepolia Testnet".to_string(),
+            ..sepolia()
+        }
+        .validate()
+        .is_err());
+        assert!(Network {
+            name: " ".to_string(),
+            ..sepolia()
+        }
+        .validate()
+        .is_err());
+        assert!(Network {
+            id: Network::MAINNET.to_string(),
+            ..sepolia()
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use super::{EventOutboxEntry, NotificationId, NotificationType, UserId};
+use crate::errors::NotificationError;
+use candid::Principal;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::Timestamp,
+};
+
+/// The opt-in of a user to have the important notifications pushed to a canister that aggregates the
+/// notifications of many stations, e.g. the control panel, so that they end up in a single inbox.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationBridge {
+    pub user_id: UserId,
+    /// The canister that receives the notifications.
+    pub inbox_canister: Principal,
+    /// The identity that opted in, the notifications are pushed to its inbox.
+    pub identity: Principal,
+    pub created_at: Timestamp,
+    pub delivered_notifications: u64,
+    pub last_delivered_at: Option<Timestamp>,
+    /// The error of the last failed delivery, cleared on the next successful one.
+    pub last_error: Option<String>,
+}
+
+impl ModelKey<UserId> for NotificationBridge {
+    fn key(&self) -> UserId {
+        self.user_id
+    }
+}
+
+impl NotificationBridge {
+    /// The method of the inbox canister that receives a `StationNotificationDeliveryDTO`.
+    pub const INBOX_METHOD_NAME: &'static str = "push_station_notification";
+
+    /// Whether notifications of the given type are pushed to the inbox canister.
+    ///
+    /// Only the notifications that usually need the attention of the user are pushed.
+    pub fn is_bridged(notification_type: &NotificationType) -> bool {
+        match notification_type {
+            NotificationType::RequestCreated(_)
+            | NotificationType::RequestFailed(_)
+            | NotificationType::RequestRejected(_)
+            | NotificationType::RequestApprovalReminder(_)
+            | NotificationType::ExternalCanisterModuleDrifted(_)
+            | NotificationType::UserActivityAnomaly(_)
+            | NotificationType::JobsStalled(_)
+            | NotificationType::BlockchainFreezeChanged(_) => true,
+            NotificationType::SystemMessage
+            | NotificationType::RequestExecuted(_)
+            | NotificationType::DepositDetected(_)
+            | NotificationType::RequestPolicyChanged(_)
+            | NotificationType::UserGroupMembershipChanged(_)
+            | NotificationType::AccountAddressReady(_) => false,
+        }
+    }
+}
+
+impl ModelValidator<NotificationError> for NotificationBridge {
+    fn validate(&self) -> ModelValidatorResult<NotificationError> {
+        // only canisters can receive the notifications, their ids are opaque principals
+        if self.inbox_canister.as_slice().last() != Some(&0x01) {
+            return Err(NotificationError::InboxNotCanister {
+                principal: self.inbox_canister.to_text(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The entries are ordered by user first, so that the notifications of a user are next to each other.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NotificationOutboxEntryKey {
+    pub user_id: UserId,
+    pub enqueued_at: Timestamp,
+}
+
+/// A notification that is waiting to be delivered to the inbox canister of the user.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationOutboxEntry {
+    pub user_id: UserId,
+    pub notification_id: NotificationId,
+    pub enqueued_at: Timestamp,
+    pub attempts: u32,
+    pub next_attempt_at: Timestamp,
+}
+
+impl ModelKey<NotificationOutboxEntryKey> for NotificationOutboxEntry {
+    fn key(&self) -> NotificationOutboxEntryKey {
+        NotificationOutboxEntryKey {
+            user_id: self.user_id,
+            enqueued_at: self.enqueued_at,
+        }
+    }
+}
+
+impl NotificationOutboxEntry {
+    /// The notification is dropped after this many failed deliveries, so that an inbox that is gone
+    /// doesn't block the following notifications forever.
+    pub const MAX_ATTEMPTS: u32 = 10;
+
+    /// Registers a failed delivery and schedules the next attempt with an exponential backoff.
+    pub fn register_failed_attempt(&mut self, now: Timestamp) {
+        self.attempts = self.attempts.saturating_add(1);
+        self.next_attempt_at = now.saturating_add(EventOutboxEntry::retry_delay(self.attempts));
+    }
+
+    pub fn has_attempts_left(&self) -> bool {
+        self.attempts < Self::MAX_ATTEMPTS
+    }
+}
+
+#[cfg(test)]
+pub mod notification_bridge_test_utils {
+    use super::*;
+    use crate::core::ic_cdk::next_time;
+
+    pub fn mock_notification_bridge(user_id: UserId) -> NotificationBridge {
+        NotificationBridge {
+            user_id,
+            inbox_canister: Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap(),
+            identity: Principal::from_slice(&[1; 29]),
+            created_at: next_time(),
+            delivered_notifications: 0,
+            last_delivered_at: None,
+            last_error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::notification_bridge_test_utils::mock_notification_bridge;
+    use super::*;
+
+    #[test]
+    fn inbox_must_be_a_canister() {
+        let mut bridge = mock_notification_bridge([1; 16]);
+        assert!(bridge.validate().is_ok());
+
+        bridge.inbox_canister = Principal::from_slice(&[1; 29]);
+        assert!(matches!(
+            bridge.validate(),
+            Err(NotificationError::InboxNotCanister { .. })
+        ));
+    }
+
+    #[test]
+    fn entries_are_dropped_after_the_last_attempt() {
+        let mut entry = NotificationOutboxEntry {
+            user_id: [1; 16],
+            notification_id: [2; 16],
+            enqueued_at: 0,
+            attempts: 0,
+            next_attempt_at: 0,
+        };
+
+        for _ in 0..NotificationOutboxEntry::MAX_ATTEMPTS - 1 {
+            entry.register_failed_attempt(100);
+            assert!(entry.has_attempts_left());
+        }
+
+        entry.register_failed_attempt(100);
+        assert!(!entry.has_attempts_left());
+        assert_eq!(
+            entry.next_attempt_at,
+            100 + EventOutboxEntry::RETRY_MAX_DELAY_NS
+        );
+    }
+}
@@ -1,8 +1,15 @@
+use super::{Blockchain, NetworkId};
+use candid::Principal;
 use orbit_essentials::storable;
-use orbit_essentials::types::UUID;
+use orbit_essentials::types::{Timestamp, UUID};
 use station_api::{
-    REQUEST_CREATED_NOTIFICATION_TYPE, REQUEST_FAILED_NOTIFICATION_TYPE,
+    ACCOUNT_ADDRESS_READY_NOTIFICATION_TYPE, BLOCKCHAIN_FREEZE_CHANGED_NOTIFICATION_TYPE,
+    DEPOSIT_DETECTED_NOTIFICATION_TYPE, EXTERNAL_CANISTER_MODULE_DRIFTED_NOTIFICATION_TYPE,
+    JOBS_STALLED_NOTIFICATION_TYPE, REQUEST_APPROVAL_REMINDER_NOTIFICATION_TYPE,
+    REQUEST_CREATED_NOTIFICATION_TYPE, REQUEST_EXECUTED_NOTIFICATION_TYPE,
+    REQUEST_FAILED_NOTIFICATION_TYPE, REQUEST_POLICY_CHANGED_NOTIFICATION_TYPE,
     REQUEST_REJECTED_NOTIFICATION_TYPE, SYSTEM_MESSAGE_NOTIFICATION_TYPE,
+    USER_ACTIVITY_ANOMALY_NOTIFICATION_TYPE, USER_GROUP_MEMBERSHIP_CHANGED_NOTIFICATION_TYPE,
 };
 use std::fmt::{Display, Formatter};
 
@@ -13,6 +20,16 @@ pub enum NotificationType {
     RequestCreated(RequestCreatedNotification),
     RequestFailed(RequestFailedNotification),
     RequestRejected(RequestRejectedNotification),
+    ExternalCanisterModuleDrifted(ExternalCanisterModuleDriftedNotification),
+    UserActivityAnomaly(UserActivityAnomalyNotification),
+    RequestExecuted(RequestExecutedNotification),
+    DepositDetected(DepositDetectedNotification),
+    RequestPolicyChanged(RequestPolicyChangedNotification),
+    RequestApprovalReminder(RequestApprovalReminderNotification),
+    UserGroupMembershipChanged(UserGroupMembershipChangedNotification),
+    JobsStalled(JobsStalledNotification),
+    AccountAddressReady(AccountAddressReadyNotification),
+    BlockchainFreezeChanged(BlockchainFreezeChangedNotification),
 }
 
 #[storable]
@@ -24,6 +41,96 @@ pub struct RequestNotification {
 pub type RequestCreatedNotification = RequestNotification;
 pub type RequestFailedNotification = RequestNotification;
 pub type RequestRejectedNotification = RequestNotification;
+pub type RequestExecutedNotification = RequestNotification;
+pub type RequestApprovalReminderNotification = RequestNotification;
+
+/// The balance of an account increased when it was refreshed.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DepositDetectedNotification {
+    pub account_id: UUID,
+    pub amount: candid::Nat,
+    pub balance: candid::Nat,
+}
+
+/// The address of an account was generated in the background, after it failed when the account was created.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountAddressReadyNotification {
+    pub account_id: UUID,
+}
+
+/// The accounts of a blockchain network were frozen or unfrozen by the execution of a request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlockchainFreezeChangedNotification {
+    pub request_id: UUID,
+    pub blockchain: Blockchain,
+    /// The network of the accounts, all the networks of the blockchain if not set.
+    pub network: Option<NetworkId>,
+    pub frozen: bool,
+}
+
+/// A request policy was added, edited or removed by the execution of a request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestPolicyChangedNotification {
+    pub request_id: UUID,
+    pub policy_id: UUID,
+}
+
+/// A user joined or left a user group because of a scheduled membership.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserGroupMembershipChangedNotification {
+    pub user_id: UUID,
+    pub user_group_id: UUID,
+    pub joined: bool,
+}
+
+/// The background jobs of the station stopped running, so that the approved requests are not executed or
+/// the expired requests are not cancelled.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JobsStalledNotification {
+    /// The jobs whose timers should have fired but didn't complete, with the time they were due at.
+    pub overdue_jobs: Vec<String>,
+    /// The requests that should have been executed a while ago but are still scheduled.
+    pub stalled_executions: Vec<UUID>,
+    /// The requests that expired a while ago but are still pending.
+    pub stalled_expirations: Vec<UUID>,
+}
+
+/// The module of an external canister changed without an executed request of the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterModuleDriftedNotification {
+    pub external_canister_id: UUID,
+    pub canister_id: Principal,
+    pub expected_module_hash: Option<Vec<u8>>,
+    pub found_module_hash: Option<Vec<u8>>,
+}
+
+/// The activity of a user deviates from their usual usage of the station, which could indicate
+/// that one of their identities is compromised.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserActivityAnomalyNotification {
+    pub user_id: UUID,
+    pub anomaly: UserActivityAnomaly,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UserActivityAnomaly {
+    /// The user created many more requests than usual within the last hour.
+    RequestCreationSpike { requests_created: u64 },
+    /// The user approved a request after being inactive for a long time.
+    DormantUserApproval {
+        request_id: UUID,
+        last_active_at: Timestamp,
+    },
+}
 
 impl Display for NotificationType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -38,6 +145,36 @@ impl Display for NotificationType {
             NotificationType::RequestRejected(_) => {
                 write!(f, "{}", REQUEST_REJECTED_NOTIFICATION_TYPE)
             }
+            NotificationType::ExternalCanisterModuleDrifted(_) => {
+                write!(f, "{}", EXTERNAL_CANISTER_MODULE_DRIFTED_NOTIFICATION_TYPE)
+            }
+            NotificationType::UserActivityAnomaly(_) => {
+                write!(f, "{}", USER_ACTIVITY_ANOMALY_NOTIFICATION_TYPE)
+            }
+            NotificationType::RequestExecuted(_) => {
+                write!(f, "{}", REQUEST_EXECUTED_NOTIFICATION_TYPE)
+            }
+            NotificationType::DepositDetected(_) => {
+                write!(f, "{}", DEPOSIT_DETECTED_NOTIFICATION_TYPE)
+            }
+            NotificationType::RequestPolicyChanged(_) => {
+                write!(f, "{}", REQUEST_POLICY_CHANGED_NOTIFICATION_TYPE)
+            }
+            NotificationType::RequestApprovalReminder(_) => {
+                write!(f, "{}", REQUEST_APPROVAL_REMINDER_NOTIFICATION_TYPE)
+            }
+            NotificationType::UserGroupMembershipChanged(_) => {
+                write!(f, "{}", USER_GROUP_MEMBERSHIP_CHANGED_NOTIFICATION_TYPE)
+            }
+            NotificationType::JobsStalled(_) => {
+                write!(f, "{}", JOBS_STALLED_NOTIFICATION_TYPE)
+            }
+            NotificationType::AccountAddressReady(_) => {
+                write!(f, "{}", ACCOUNT_ADDRESS_READY_NOTIFICATION_TYPE)
+            }
+            NotificationType::BlockchainFreezeChanged(_) => {
+                write!(f, "{}", BLOCKCHAIN_FREEZE_CHANGED_NOTIFICATION_TYPE)
+            }
         }
     }
 }
@@ -75,5 +212,103 @@ mod tests {
             .to_string(),
             "request-rejected"
         );
+
+        assert_eq!(
+            NotificationType::ExternalCanisterModuleDrifted(
+                ExternalCanisterModuleDriftedNotification {
+                    external_canister_id: [0; 16],
+                    canister_id: Principal::anonymous(),
+                    expected_module_hash: Some(vec![1; 32]),
+                    found_module_hash: None,
+                }
+            )
+            .to_string(),
+            "external-canister-module-drifted"
+        );
+
+        assert_eq!(
+            NotificationType::UserActivityAnomaly(UserActivityAnomalyNotification {
+                user_id: [0; 16],
+                anomaly: UserActivityAnomaly::RequestCreationSpike {
+                    requests_created: 50
+                },
+            })
+            .to_string(),
+            "user-activity-anomaly"
+        );
+
+        assert_eq!(
+            NotificationType::RequestExecuted(RequestExecutedNotification {
+                request_id: [0; 16]
+            })
+            .to_string(),
+            "request-executed"
+        );
+
+        assert_eq!(
+            NotificationType::DepositDetected(DepositDetectedNotification {
+                account_id: [0; 16],
+                amount: candid::Nat::from(10u64),
+                balance: candid::Nat::from(20u64),
+            })
+            .to_string(),
+            "deposit-detected"
+        );
+
+        assert_eq!(
+            NotificationType::RequestPolicyChanged(RequestPolicyChangedNotification {
+                request_id: [0; 16],
+                policy_id: [1; 16],
+            })
+            .to_string(),
+            "request-policy-changed"
+        );
+
+        assert_eq!(
+            NotificationType::RequestApprovalReminder(RequestApprovalReminderNotification {
+                request_id: [0; 16]
+            })
+            .to_string(),
+            "request-approval-reminder"
+        );
+
+        assert_eq!(
+            NotificationType::UserGroupMembershipChanged(UserGroupMembershipChangedNotification {
+                user_id: [0; 16],
+                user_group_id: [1; 16],
+                joined: true,
+            })
+            .to_string(),
+            "user-group-membership-changed"
+        );
+
+        assert_eq!(
+            NotificationType::JobsStalled(JobsStalledNotification {
+                overdue_jobs: vec![],
+                stalled_executions: vec![[0; 16]],
+                stalled_expirations: vec![],
+            })
+            .to_string(),
+            "jobs-stalled"
+        );
+
+        assert_eq!(
+            NotificationType::AccountAddressReady(AccountAddressReadyNotification {
+                account_id: [0; 16],
+            })
+            .to_string(),
+            "account-address-ready"
+        );
+
+        assert_eq!(
+            NotificationType::BlockchainFreezeChanged(BlockchainFreezeChangedNotification {
+                request_id: [0; 16],
+                blockchain: Blockchain::Ethereum,
+                network: None,
+                frozen: true,
+            })
+            .to_string(),
+            "blockchain-freeze-changed"
+        );
     }
 }
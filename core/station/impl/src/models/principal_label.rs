@@ -0,0 +1,173 @@
+use super::UserId;
+use crate::errors::PrincipalLabelError;
+use candid::Principal;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::Timestamp,
+};
+
+/// Where the label of a principal or address comes from.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrincipalLabelSource {
+    /// The label was set by a user of the station.
+    Manual { labeled_by: UserId },
+    /// The label was imported from a community-maintained list canister.
+    Imported { list_canister: Principal },
+}
+
+/// The label of a well-known principal or address (e.g. "Binance deposit", "NNS governance"), used to
+/// annotate the destinations of the transfers and the sources of the deposits.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrincipalLabel {
+    /// The principal or address in its textual form, as returned by `PrincipalLabel::normalize_address`.
+    pub address: String,
+    pub label: String,
+    pub source: PrincipalLabelSource,
+    pub last_modification_timestamp: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PrincipalLabelKey {
+    pub address: String,
+}
+
+impl ModelKey<PrincipalLabelKey> for PrincipalLabel {
+    fn key(&self) -> PrincipalLabelKey {
+        PrincipalLabelKey {
+            address: self.address.clone(),
+        }
+    }
+}
+
+impl PrincipalLabel {
+    pub const ADDRESS_RANGE: (usize, usize) = (1, 255);
+    pub const LABEL_RANGE: (usize, usize) = (1, 100);
+    /// The method of the list canister that returns its labels as a `vec PrincipalLabelEntry`.
+    pub const LIST_METHOD_NAME: &'static str = "list_principal_labels";
+    /// The maximum number of labels that are imported from a list canister.
+    pub const MAX_IMPORTED_LABELS: usize = 10_000;
+
+    /// Creates the key of the label of the given address, which doesn't need to be normalized.
+    pub fn key(address: &str) -> PrincipalLabelKey {
+        PrincipalLabelKey {
+            address: Self::normalize_address(address),
+        }
+    }
+
+    /// Normalizes the address so that it is labeled regardless of how it is written, the hex addresses
+    /// (e.g. Ethereum) are case insensitive and their checksum casing is dropped.
+    pub fn normalize_address(address: &str) -> String {
+        let address = address.trim();
+        match address.starts_with("0x") || address.starts_with("0X") {
+            true => address.to_ascii_lowercase(),
+            false => address.to_string(),
+        }
+    }
+
+    pub fn to_key(&self) -> PrincipalLabelKey {
+        ModelKey::key(self)
+    }
+
+    pub fn is_imported_from(&self, list_canister: &Principal) -> bool {
+        matches!(&self.source, PrincipalLabelSource::Imported { list_canister: canister } if canister == list_canister)
+    }
+}
+
+impl ModelValidator<PrincipalLabelError> for PrincipalLabel {
+    fn validate(&self) -> ModelValidatorResult<PrincipalLabelError> {
+        let (min, max) = Self::ADDRESS_RANGE;
+        if self.address.len() < min || self.address.len() > max {
+            return Err(PrincipalLabelError::InvalidAddressLength {
+                min_length: min,
+                max_length: max,
+            });
+        }
+
+        let (min, max) = Self::LABEL_RANGE;
+        let label_length = self.label.trim().chars().count();
+        if label_length < min || self.label.chars().count() > max {
+            return Err(PrincipalLabelError::InvalidLabelLength {
+                min_length: min,
+                max_length: max,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::principal_label_test_utils::mock_principal_label;
+    use super::*;
+
+    #[test]
+    fn normalizes_the_hex_addresses() {
+        assert_eq!(
+            PrincipalLabel::normalize_address(" 0xAbC123 "),
+            "0xabc123".to_string()
+        );
+        assert_eq!(
+            PrincipalLabel::normalize_address("rrkah-fqaaa-aaaaa-aaaaq-cai"),
+            "rrkah-fqaaa-aaaaa-aaaaq-cai".to_string()
+        );
+        assert_eq!(PrincipalLabel::key("0XABC"), PrincipalLabel::key("0xabc"));
+    }
+
+    #[test]
+    fn fail_blank_or_too_long_label() {
+        let mut label = mock_principal_label();
+        label.label = "   ".to_string();
+
+        assert_eq!(
+            label.validate(),
+            Err(PrincipalLabelError::InvalidLabelLength {
+                min_length: 1,
+                max_length: 100,
+            })
+        );
+
+        label.label = "a".repeat(PrincipalLabel::LABEL_RANGE.1 + 1);
+
+        assert!(label.validate().is_err());
+
+        label.label = "a".repeat(PrincipalLabel::LABEL_RANGE.1);
+
+        assert!(label.validate().is_ok());
+    }
+
+    #[test]
+    fn fail_empty_address() {
+        let mut label = mock_principal_label();
+        label.address = String::new();
+
+        assert_eq!(
+            label.validate(),
+            Err(PrincipalLabelError::InvalidAddressLength {
+                min_length: 1,
+                max_length: 255,
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod principal_label_test_utils {
+    use super::*;
+    use crate::core::ic_cdk::next_time;
+
+    pub fn mock_principal_label() -> PrincipalLabel {
+        PrincipalLabel {
+            address: "rrkah-fqaaa-aaaaa-aaaaq-cai".to_string(),
+            label: "NNS governance".to_string(),
+            source: PrincipalLabelSource::Manual {
+                labeled_by: [1; 16],
+            },
+            last_modification_timestamp: next_time(),
+        }
+    }
+}
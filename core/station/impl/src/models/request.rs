@@ -1,25 +1,31 @@
-use super::request_policy_rule::{RequestEvaluationResult, RequestPolicyRuleInput};
+use super::indexes::request_index::RequestIndexFields;
+use super::request_policy_rule::{RequestEvaluationResult, RequestPolicyRule};
 use super::{
-    DisplayUser, EvaluationStatus, RequestApproval, RequestApprovalStatus, RequestOperation,
-    RequestStatus, UserId, UserKey,
+    Account, DisplayRequestImpact, DisplayUser, EvaluationStatus, Network, RequestApproval,
+    RequestApprovalStatus, RequestCost, RequestCostPhase, RequestExecutionStep, RequestOperation,
+    RequestPolicy, RequestStatus, RequestStatusCode, TransferOperation, UserGroupId, UserId,
+    UserKey, ADMIN_GROUP_ID,
 };
+use crate::core::cost::{instruction_counter, record_request_cost};
 use crate::core::evaluation::{
-    Evaluate, REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR, REQUEST_POLICY_RULE_EVALUATOR,
+    Evaluate, REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR,
+    REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR, REQUEST_POLICY_RULE_EVALUATOR,
     REQUEST_POSSIBLE_APPROVERS_REQUEST_POLICY_RULE_EVALUATOR,
 };
-use crate::core::ic_cdk::api::print;
 use crate::core::ic_cdk::next_time;
 use crate::core::request::{
     RequestApprovalRightsEvaluator, RequestEvaluator, RequestPossibleApproversFinder,
 };
-use crate::core::validation::{
-    EnsureAccount, EnsureAddressBookEntry, EnsureIdExists, EnsureRequestPolicy, EnsureUser,
-    EnsureUserGroup,
+use crate::core::validation::{OperationFieldErrors, ValidateOperationInput};
+use crate::core::CallContext;
+use crate::errors::{EvaluateError, RequestError};
+use crate::log_error;
+use crate::models::system::{BlockchainFreeze, RequestTextLimits, SystemInfo};
+use crate::repositories::{
+    ACCOUNT_REPOSITORY, ARCHIVED_REQUEST_REPOSITORY, REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY,
+    USER_REPOSITORY,
 };
-use crate::errors::{EvaluateError, RequestError, ValidationError};
-use crate::models::resource::{ExecutionMethodResourceTarget, ValidationMethodResourceTarget};
-use crate::repositories::USER_REPOSITORY;
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use orbit_essentials::model::ModelKey;
 use orbit_essentials::repository::Repository;
 use orbit_essentials::storable;
@@ -28,6 +34,7 @@ use orbit_essentials::{
     types::{Timestamp, UUID},
 };
 use std::collections::HashSet;
+use uuid::Uuid;
 
 /// The request id, which is a UUID.
 pub type RequestId = UUID;
@@ -36,7 +43,22 @@ pub type RequestId = UUID;
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RequestExecutionPlan {
     Immediate,
-    Scheduled { execution_time: Timestamp },
+    Scheduled {
+        execution_time: Timestamp,
+        /// The end of the execution window, the request fails if it can't be executed before then.
+        #[serde(default)]
+        window_end: Option<Timestamp>,
+    },
+}
+
+impl RequestExecutionPlan {
+    /// Returns the time after which the request can no longer be executed, if any.
+    pub fn window_end(&self) -> Option<Timestamp> {
+        match self {
+            RequestExecutionPlan::Immediate => None,
+            RequestExecutionPlan::Scheduled { window_end, .. } => *window_end,
+        }
+    }
 }
 
 /// Represents a request within the system.
@@ -48,7 +70,11 @@ pub struct Request {
     /// The title of the request.
     pub title: String,
     /// The summary of the request, this is a longer description of the request.
+    #[serde(with = "compressed_summary")]
     pub summary: Option<String>,
+    /// How the summary is meant to be rendered.
+    #[serde(default)]
+    pub summary_format: RequestSummaryFormat,
     /// The user id that resulted in the request creation.
     pub requested_by: UserId,
     /// The status that the request is in.
@@ -59,14 +85,137 @@ pub struct Request {
     pub expiration_dt: Timestamp,
     /// The execution plan of the request.
     pub execution_plan: RequestExecutionPlan,
+    /// The requests that must complete successfully before this request can be executed.
+    #[serde(default)]
+    pub depends_on: Vec<RequestId>,
     /// The list of user approvals on the request.
     pub approvals: Vec<RequestApproval>,
+    /// The policies that matched the request when it was submitted for approval, the request keeps being
+    /// evaluated against them even if the policies are edited or removed afterwards.
+    ///
+    /// Requests without a snapshot are evaluated against the policies that currently match them.
+    #[serde(default)]
+    pub policy_snapshot: Option<Vec<RequestPolicy>>,
+    /// The steps of the execution of the operation, only used by operations executed in several steps.
+    #[serde(default)]
+    pub execution_steps: Vec<RequestExecutionStep>,
+    /// Whether the request is only visible to the requester, the users that can approve it and the admins.
+    #[serde(default)]
+    pub confidential: bool,
+    /// The time at which the bulky payloads of the operation were pruned, only the summary of the
+    /// request is kept once compacted.
+    #[serde(default)]
+    pub compacted_at: Option<Timestamp>,
+    /// The instructions that the station spent on the request so far.
+    #[serde(default)]
+    pub cost: RequestCost,
     /// The timestamp of the request creation.
     pub created_timestamp: Timestamp,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
 }
 
+/// The format of the summary of a request, which tells the clients how to render it.
+#[storable]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestSummaryFormat {
+    #[default]
+    PlainText,
+    Markdown,
+}
+
+/// Stores the summaries longer than `Request::SUMMARY_COMPRESSION_THRESHOLD` compressed with deflate, the
+/// shorter ones are stored as text like before, so that both can be read back.
+mod compressed_summary {
+    use super::Request;
+    use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+    use serde::{
+        de::{self, Visitor},
+        ser, Deserializer, Serializer,
+    };
+    use std::{
+        fmt,
+        io::{Read, Write},
+    };
+
+    pub fn serialize<S: Serializer>(
+        summary: &Option<String>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match summary {
+            Some(summary) if summary.len() > Request::SUMMARY_COMPRESSION_THRESHOLD => {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                let compressed = encoder
+                    .write_all(summary.as_bytes())
+                    .and_then(|_| encoder.finish())
+                    .map_err(<S::Error as ser::Error>::custom)?;
+
+                serializer.serialize_some(serde_bytes::Bytes::new(&compressed))
+            }
+            Some(summary) => serializer.serialize_some(summary),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<String>, D::Error> {
+        deserializer.deserialize_option(OptionalSummaryVisitor)
+    }
+
+    struct OptionalSummaryVisitor;
+
+    impl<'de> Visitor<'de> for OptionalSummaryVisitor {
+        type Value = Option<String>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an optional summary")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(SummaryVisitor).map(Some)
+        }
+    }
+
+    struct SummaryVisitor;
+
+    impl<'de> Visitor<'de> for SummaryVisitor {
+        type Value = String;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a summary as text or compressed bytes")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            Ok(value.to_string())
+        }
+
+        fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+            Ok(value)
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            let mut summary = String::new();
+            DeflateDecoder::new(value)
+                .read_to_string(&mut summary)
+                .map_err(E::custom)?;
+
+            Ok(summary)
+        }
+    }
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct RequestKey {
@@ -80,10 +229,57 @@ impl ModelKey<RequestKey> for Request {
     }
 }
 
+/// The caller that lists or reads requests, used to restrict the visibility of confidential requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestViewer {
+    /// The user of the caller, if the caller is a user of the station.
+    pub user_id: Option<UserId>,
+    /// Whether the caller is an admin, admins can see all the requests.
+    pub is_admin: bool,
+}
+
+impl From<&CallContext> for RequestViewer {
+    fn from(ctx: &CallContext) -> Self {
+        RequestViewer {
+            user_id: ctx.user().map(|user| user.id),
+            is_admin: ctx.caller_is_controller_or_self()
+                || ctx
+                    .user()
+                    .is_some_and(|user| user.groups.contains(ADMIN_GROUP_ID)),
+        }
+    }
+}
+
+/// The stub that the station keeps for a request that was moved to the archive canister.
+///
+/// It keeps the participants and the confidentiality of the request so that the access to it can still be
+/// authorized, while the request itself is served by the archive canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchivedRequest {
+    /// The indexed fields of the request at the time it was archived.
+    pub fields: RequestIndexFields,
+    /// The canister that stores the request.
+    pub archive_canister_id: Principal,
+    pub archived_at: Timestamp,
+}
+
+/// Whether a request can be executed as far as its dependencies are concerned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestDependencyState {
+    /// All the dependencies have completed successfully.
+    Satisfied,
+    /// Some dependencies have not completed yet.
+    Pending,
+    /// The dependency will never complete successfully.
+    Unsatisfiable { request_id: RequestId },
+}
+
 #[derive(CandidType, Deserialize, Debug, Clone)]
 pub struct RequestCallerPrivileges {
     pub id: UUID,
     pub can_approve: bool,
+    pub can_cancel: bool,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -92,14 +288,19 @@ pub struct RequestAdditionalInfo {
     pub requester_name: String,
     pub approvers: Vec<DisplayUser>,
     pub evaluation_result: Option<RequestEvaluationResult>,
+    /// The checklist items that the approvers must acknowledge, while the request can still be approved.
+    pub approval_checklist: Vec<String>,
+    /// The impact of the permission or policy change of the request, while the request can still be approved.
+    pub impact: Option<DisplayRequestImpact>,
 }
 
 fn validate_title(title: &str) -> ModelValidatorResult<RequestError> {
-    if title.len() > Request::MAX_TITLE_LEN as usize {
+    let max_title_len = RequestTextLimits::current().max_title_len;
+    if title.len() > max_title_len as usize {
         return Err(RequestError::ValidationError {
             info: format!(
                 "Request title length exceeds the maximum allowed: {}",
-                Request::MAX_TITLE_LEN
+                max_title_len
             ),
         });
     }
@@ -109,11 +310,12 @@ fn validate_title(title: &str) -> ModelValidatorResult<RequestError> {
 
 fn validate_summary(summary: &Option<String>) -> ModelValidatorResult<RequestError> {
     if let Some(summary) = summary {
-        if summary.len() > Request::MAX_SUMMARY_LEN as usize {
+        let max_summary_len = RequestTextLimits::current().max_summary_len;
+        if summary.len() > max_summary_len as usize {
             return Err(RequestError::ValidationError {
                 info: format!(
                     "Request summary length exceeds the maximum allowed: {}",
-                    Request::MAX_SUMMARY_LEN
+                    max_summary_len
                 ),
             });
         }
@@ -122,6 +324,16 @@ fn validate_summary(summary: &Option<String>) -> ModelValidatorResult<RequestErr
     Ok(())
 }
 
+/// Escapes the raw HTML of a markdown summary so that it's rendered as text, and drops the control
+/// characters other than line breaks and tabs.
+fn sanitize_markdown_summary(summary: &str) -> String {
+    summary
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\n' | '\t'))
+        .collect::<String>()
+        .replace('<', "&lt;")
+}
+
 fn validate_requested_by(requested_by: &UserId) -> ModelValidatorResult<RequestError> {
     USER_REPOSITORY
         .get(&UserKey { id: *requested_by })
@@ -131,142 +343,202 @@ fn validate_requested_by(requested_by: &UserId) -> ModelValidatorResult<RequestE
     Ok(())
 }
 
-fn validate_request_operation_foreign_keys(
-    operation: &RequestOperation,
-) -> ModelValidatorResult<ValidationError> {
-    match operation {
-        RequestOperation::ManageSystemInfo(_) => (),
-        RequestOperation::Transfer(op) => {
-            EnsureAccount::id_exists(&op.input.from_account_id)?;
-        }
-        RequestOperation::AddAccount(op) => {
-            op.input.read_permission.validate()?;
-            op.input.configs_permission.validate()?;
-            op.input.transfer_permission.validate()?;
-
-            if let Some(policy_rule) = &op.input.transfer_request_policy {
-                policy_rule.validate()?;
-            }
-
-            if let Some(policy_rule) = &op.input.configs_request_policy {
-                policy_rule.validate()?;
-            }
+fn validate_execution_plan(
+    execution_plan: &RequestExecutionPlan,
+) -> ModelValidatorResult<RequestError> {
+    if let RequestExecutionPlan::Scheduled {
+        execution_time,
+        window_end: Some(window_end),
+    } = execution_plan
+    {
+        if window_end <= execution_time || *window_end <= next_time() {
+            return Err(RequestError::ValidationError {
+                info: "The execution window must end in the future, after the execution time"
+                    .to_owned(),
+            });
         }
-        RequestOperation::EditAccount(op) => {
-            EnsureAccount::id_exists(&op.input.account_id)?;
+    }
 
-            if let Some(allow) = &op.input.read_permission {
-                allow.validate()?;
-            }
+    Ok(())
+}
 
-            if let Some(allow) = &op.input.configs_permission {
-                allow.validate()?;
-            }
+/// Validates that the dependencies of the request exist, can still complete and don't form a cycle.
+fn validate_dependencies(request: &Request) -> ModelValidatorResult<RequestError> {
+    if request.depends_on.len() > Request::MAX_DEPENDENCIES {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "A request can depend on at most {} requests",
+                Request::MAX_DEPENDENCIES
+            ),
+        });
+    }
 
-            if let Some(allow) = &op.input.transfer_permission {
-                allow.validate()?;
-            }
+    let mut unique_dependencies = HashSet::new();
+    for dependency_id in &request.depends_on {
+        if !unique_dependencies.insert(*dependency_id) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The dependency {} is listed more than once",
+                    Uuid::from_bytes(*dependency_id).hyphenated()
+                ),
+            });
+        }
 
-            if let Some(RequestPolicyRuleInput::Set(criteria)) = &op.input.configs_request_policy {
-                criteria.validate()?;
-            }
+        // the archived dependencies are final, their stubs keep their status
+        let status = REQUEST_REPOSITORY
+            .get(&Request::key(*dependency_id))
+            .map(|dependency| RequestStatusCode::from(dependency.status))
+            .or_else(|| {
+                ARCHIVED_REQUEST_REPOSITORY
+                    .get(dependency_id)
+                    .map(|archived| archived.fields.status)
+            })
+            .ok_or(RequestError::ValidationError {
+                info: format!(
+                    "The dependency {} does not exist",
+                    Uuid::from_bytes(*dependency_id).hyphenated()
+                ),
+            })?;
 
-            if let Some(RequestPolicyRuleInput::Set(policy_rule)) =
-                &op.input.transfer_request_policy
-            {
-                policy_rule.validate()?;
-            }
-        }
-        RequestOperation::AddAddressBookEntry(_) => (),
-        RequestOperation::EditAddressBookEntry(op) => {
-            EnsureAddressBookEntry::id_exists(&op.input.address_book_entry_id)?;
-        }
-        RequestOperation::RemoveAddressBookEntry(op) => {
-            EnsureAddressBookEntry::id_exists(&op.input.address_book_entry_id)?;
-        }
-        RequestOperation::AddUser(op) => {
-            EnsureUserGroup::id_list_exists(&op.input.groups)?;
+        if matches!(
+            status,
+            RequestStatusCode::Rejected | RequestStatusCode::Cancelled
+        ) {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The dependency {} can no longer complete",
+                    Uuid::from_bytes(*dependency_id).hyphenated()
+                ),
+            });
         }
-        RequestOperation::EditUser(op) => {
-            EnsureUser::id_exists(&op.input.user_id)?;
+    }
 
-            if let Some(group_ids) = &op.input.groups {
-                EnsureUserGroup::id_list_exists(group_ids)?;
-            }
+    // walks the dependency graph, the request must not be reachable from its own dependencies
+    let mut visited = HashSet::new();
+    let mut pending = request.depends_on.clone();
+    while let Some(dependency_id) = pending.pop() {
+        if dependency_id == request.id {
+            return Err(RequestError::ValidationError {
+                info: "The dependencies of the request form a cycle".to_owned(),
+            });
         }
-        RequestOperation::EditPermission(op) => {
-            op.input.resource.validate()?;
 
-            if let Some(user_ids) = &op.input.users {
-                EnsureUser::id_list_exists(user_ids)?;
-            }
-
-            if let Some(group_ids) = &op.input.user_groups {
-                EnsureUserGroup::id_list_exists(group_ids)?;
-            }
+        if !visited.insert(dependency_id) {
+            continue;
         }
-        RequestOperation::AddUserGroup(_) => (),
-        RequestOperation::EditUserGroup(op) => {
-            EnsureUserGroup::id_exists(&op.input.user_group_id)?;
-        }
-        RequestOperation::RemoveUserGroup(ok) => {
-            EnsureUserGroup::id_exists(&ok.input.user_group_id)?;
-        }
-        RequestOperation::SystemUpgrade(_) => (),
-        RequestOperation::ChangeExternalCanister(_) => (),
-        RequestOperation::ConfigureExternalCanister(_) => (),
-        RequestOperation::FundExternalCanister(_) => (),
-        RequestOperation::CreateExternalCanister(_) => (),
-        RequestOperation::CallExternalCanister(op) => {
-            let validation_method_target: ValidationMethodResourceTarget =
-                op.input.validation_method.clone().into();
-            validation_method_target.validate()?;
-            let execution_method_target: ExecutionMethodResourceTarget =
-                op.input.execution_method.clone().into();
-            execution_method_target.validate()?;
-        }
-        RequestOperation::AddRequestPolicy(op) => {
-            op.input.specifier.validate()?;
-            op.input.rule.validate()?;
-        }
-        RequestOperation::EditRequestPolicy(op) => {
-            EnsureRequestPolicy::id_exists(&op.input.policy_id)?;
-
-            if let Some(specifier) = &op.input.specifier {
-                specifier.validate()?;
-            }
 
-            if let Some(policy_rule) = &op.input.rule {
-                policy_rule.validate()?;
-            }
-        }
-        RequestOperation::RemoveRequestPolicy(op) => {
-            EnsureRequestPolicy::id_exists(&op.input.policy_id)?;
-        }
-        RequestOperation::SetDisasterRecovery(op) => {
-            if let Some(committee) = &op.input.committee {
-                EnsureUserGroup::id_exists(&committee.user_group_id)?;
-            }
+        if let Some(dependency) = REQUEST_REPOSITORY.get(&Request::key(dependency_id)) {
+            pending.extend(dependency.depends_on);
         }
     }
+
     Ok(())
 }
 
+fn validate_request_operation(operation: &RequestOperation) -> ModelValidatorResult<RequestError> {
+    let mut errors = OperationFieldErrors::default();
+    operation.validate_input(&mut errors);
+
+    errors.into_result()
+}
+
 impl ModelValidator<RequestError> for Request {
     fn validate(&self) -> ModelValidatorResult<RequestError> {
         validate_title(&self.title)?;
         validate_summary(&self.summary)?;
         validate_requested_by(&self.requested_by)?;
+        validate_execution_plan(&self.execution_plan)?;
+        validate_dependencies(self)?;
 
-        validate_request_operation_foreign_keys(&self.operation)?;
+        validate_request_operation(&self.operation)?;
 
         Ok(())
     }
 }
 
 impl Request {
+    /// The default length limits of the title and summary, stations can configure others (see `RequestTextLimits`).
     pub const MAX_TITLE_LEN: u8 = 255;
     pub const MAX_SUMMARY_LEN: u16 = 1000;
+    /// The length above which summaries are stored compressed.
+    pub const SUMMARY_COMPRESSION_THRESHOLD: usize = 1024;
+    /// The maximum number of requests that a request can depend on.
+    pub const MAX_DEPENDENCIES: usize = 10;
+
+    /// Sets the format of the summary, markdown summaries are sanitized.
+    pub fn set_summary_format(&mut self, summary_format: RequestSummaryFormat) {
+        if summary_format == RequestSummaryFormat::Markdown {
+            self.summary = self.summary.as_deref().map(sanitize_markdown_summary);
+        }
+
+        self.summary_format = summary_format;
+    }
+
+    /// Checks whether the dependencies of the request have completed successfully.
+    ///
+    /// A dependency that was rejected, cancelled, failed or no longer exists can't be satisfied anymore.
+    pub fn dependency_state(&self) -> RequestDependencyState {
+        let mut state = RequestDependencyState::Satisfied;
+        for dependency_id in &self.depends_on {
+            // the archived dependencies are final, their stubs keep their status
+            match REQUEST_REPOSITORY
+                .get(&Request::key(*dependency_id))
+                .map(|dependency| RequestStatusCode::from(dependency.status))
+                .or_else(|| {
+                    ARCHIVED_REQUEST_REPOSITORY
+                        .get(dependency_id)
+                        .map(|archived| archived.fields.status)
+                }) {
+                Some(RequestStatusCode::Completed) => {}
+                Some(
+                    RequestStatusCode::Created
+                    | RequestStatusCode::Approved
+                    | RequestStatusCode::Scheduled
+                    | RequestStatusCode::Processing,
+                ) => state = RequestDependencyState::Pending,
+                Some(
+                    RequestStatusCode::Rejected
+                    | RequestStatusCode::Cancelled
+                    | RequestStatusCode::Failed,
+                )
+                | None => {
+                    return RequestDependencyState::Unsatisfiable {
+                        request_id: *dependency_id,
+                    }
+                }
+            }
+        }
+
+        state
+    }
+
+    /// Returns the freeze that holds back the execution of the request, if its operation targets the
+    /// accounts of a frozen blockchain network.
+    pub fn find_blockchain_freeze(&self, system_info: &SystemInfo) -> Option<BlockchainFreeze> {
+        let account_id = match &self.operation {
+            RequestOperation::AddAccount(operation) => {
+                return system_info
+                    .find_blockchain_freeze(
+                        &operation.input.blockchain,
+                        operation
+                            .input
+                            .network
+                            .as_deref()
+                            .unwrap_or(Network::MAINNET),
+                    )
+                    .cloned();
+            }
+            RequestOperation::Transfer(operation) => operation.input.from_account_id,
+            RequestOperation::EditAccount(operation) => operation.input.account_id,
+            RequestOperation::RotateAccountAddress(operation) => operation.input.account_id,
+            RequestOperation::TransferAccountOwnership(operation) => operation.input.account_id,
+            _ => return None,
+        };
+
+        ACCOUNT_REPOSITORY
+            .get(&Account::key(account_id))
+            .and_then(|account| system_info.find_account_freeze(&account).cloned())
+    }
 
     /// Creates a new request key from the given key components.
     pub fn key(request_id: RequestId) -> RequestKey {
@@ -290,11 +562,12 @@ impl Request {
         approvers
     }
 
+    /// The default period after which a request expires, which is 30 days.
+    pub const DEFAULT_EXPIRATION_PERIOD_NS: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+
     /// Gives the default expiration date for a request which is 30 days from the current time.
     pub fn default_expiration_dt_ns() -> Timestamp {
-        let time_in_ns: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
-
-        next_time() + time_in_ns
+        next_time() + Self::DEFAULT_EXPIRATION_PERIOD_NS
     }
 
     /// Checks if the user can approve the request.
@@ -322,21 +595,82 @@ impl Request {
         match approval_rights_evaluator.evaluate() {
             Ok(has_approval_right) => has_approval_right,
             Err(_) => {
-                print(format!(
-                    "Failed to evaluate voting rights for request: {:?}",
-                    self
-                ));
+                log_error!("Failed to evaluate voting rights for request: {:?}", self);
 
                 false
             }
         }
     }
 
+    /// Returns the policies that currently match the operation of the request.
+    ///
+    /// The handover of the ownership of an account additionally requires the acknowledgment of the
+    /// receiving party in each of the policies.
+    pub fn find_current_policies(&self) -> Vec<RequestPolicy> {
+        let policies = self
+            .operation
+            .to_resources()
+            .into_iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource));
+
+        match &self.operation {
+            RequestOperation::TransferAccountOwnership(operation) => policies
+                .map(|mut policy| {
+                    policy.rule = RequestPolicyRule::And(vec![
+                        policy.rule,
+                        operation.input.acknowledgment_rule(),
+                    ]);
+                    policy
+                })
+                .collect(),
+            _ => policies.collect(),
+        }
+    }
+
+    /// Returns the policies that apply to the request, which are the snapshot taken when the request was
+    /// submitted for approval, or the policies that currently match it if it has no snapshot.
+    pub fn matching_policies(&self) -> Vec<RequestPolicy> {
+        match &self.policy_snapshot {
+            Some(policies) => policies.clone(),
+            None => self.find_current_policies(),
+        }
+    }
+
+    /// Takes a snapshot of the policies that currently match the request, which then apply to the request
+    /// for the rest of its approval.
+    pub fn snapshot_policies(&mut self) {
+        self.policy_snapshot = Some(self.find_current_policies());
+    }
+
+    /// Returns the checklist items of the policies that match the request, which the approvers must
+    /// acknowledge to approve it.
+    pub fn approval_checklist(&self) -> Vec<String> {
+        let mut checklist: Vec<String> = Vec::new();
+        for policy in self.matching_policies() {
+            for item in policy.checklist {
+                if !checklist.contains(&item) {
+                    checklist.push(item);
+                }
+            }
+        }
+
+        checklist
+    }
+
+    /// Returns the user groups named in the rules of the policies that match the request.
+    pub fn find_policy_groups(&self) -> HashSet<UserGroupId> {
+        self.matching_policies()
+            .into_iter()
+            .flat_map(|policy| policy.rule.user_groups())
+            .collect()
+    }
+
     pub fn add_approval(
         &mut self,
         user_id: UUID,
         decision: RequestApprovalStatus,
         reason: Option<String>,
+        acknowledged_checklist: Vec<String>,
     ) -> ModelValidatorResult<RequestError> {
         if self
             .approvals
@@ -347,11 +681,26 @@ impl Request {
             return Err(RequestError::ApprovalNotAllowed);
         }
 
+        let (acknowledged_checklist, missing_checklist): (Vec<String>, Vec<String>) = self
+            .approval_checklist()
+            .into_iter()
+            .partition(|item| acknowledged_checklist.contains(item));
+
+        if decision == RequestApprovalStatus::Approved && !missing_checklist.is_empty() {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The approval must acknowledge the checklist items: {}",
+                    missing_checklist.join(", ")
+                ),
+            });
+        }
+
         let now = next_time();
         let approval = RequestApproval {
             approver_id: user_id,
             status: decision,
             status_reason: reason,
+            acknowledged_checklist,
             decided_dt: now,
             last_modification_timestamp: now,
         };
@@ -368,12 +717,23 @@ impl Request {
             let evaluator = RequestEvaluator {
                 request: self.to_owned(),
                 policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+                requester_excluded_policy_rule_evaluator:
+                    REQUESTER_EXCLUDED_REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
             };
 
+            let started_at = instruction_counter();
             let evaluation_result = evaluator.evaluate()?;
+            record_request_cost(
+                self,
+                RequestCostPhase::Evaluation,
+                instruction_counter().saturating_sub(started_at),
+            );
 
             if evaluation_result.status == EvaluationStatus::Approved {
-                self.status = RequestStatus::Approved;
+                // the request stays pending until the screening of the transfer passed
+                if !self.is_blocked_by_screening() {
+                    self.status = RequestStatus::Approved;
+                }
             } else if evaluation_result.status == EvaluationStatus::Rejected {
                 self.status = RequestStatus::Rejected;
             }
@@ -394,6 +754,31 @@ impl Request {
         evaluator.evaluate()
     }
 
+    /// Checks if the request is a transfer that can only be approved once its screening passed.
+    pub fn is_blocked_by_screening(&self) -> bool {
+        match &self.operation {
+            RequestOperation::Transfer(TransferOperation {
+                screening: Some(screening),
+                ..
+            }) => screening.is_blocking_approval(),
+            _ => false,
+        }
+    }
+
+    /// Checks if a member of the given groups can cancel the request while its transfer is time-locked.
+    pub fn can_cancel(&self, groups: &[UserGroupId], now: Timestamp) -> bool {
+        match (&self.status, &self.operation) {
+            (
+                RequestStatus::Scheduled { .. },
+                RequestOperation::Transfer(TransferOperation {
+                    time_lock: Some(time_lock),
+                    ..
+                }),
+            ) => time_lock.can_be_cancelled_by(groups, now),
+            _ => false,
+        }
+    }
+
     /// Checks if the request is finalized.
     ///
     /// A request that is finalized won't have its status changed anymore.
@@ -411,12 +796,20 @@ impl Request {
 #[cfg(test)]
 mod tests {
     use crate::core::validation::disable_mock_resource_validation;
+    use crate::core::{test_utils, write_system_info};
     use crate::models::permission::Allow;
+    use crate::models::{
+        account_test_utils, request_policy_test_utils, request_specifier::RequestSpecifier,
+        resource::ResourceIds,
+    };
     use crate::models::{
         AddAccountOperationInput, AddUserOperation, AddUserOperationInput, Metadata,
-        TransferOperation, TransferOperationInput,
+        TransferOperation, TransferOperationInput, TransferScreening, TransferScreeningStatus,
+        TransferTimeLock,
     };
     use crate::services::AccountService;
+    use candid::Principal;
+    use ic_stable_structures::Storable;
 
     use super::request_test_utils::mock_request;
     use super::*;
@@ -431,6 +824,141 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn transfer_request_is_blocked_until_screening_passed() {
+        let mut request = mock_request();
+        let RequestOperation::Transfer(operation) = &mut request.operation else {
+            panic!("Expected transfer operation");
+        };
+        let mut screening = TransferScreening::new(Principal::from_slice(&[1; 29]), true, 0);
+        operation.screening = Some(screening.clone());
+
+        assert!(request.is_blocked_by_screening());
+
+        screening.status = TransferScreeningStatus::Passed {
+            reference: None,
+            screened_at: 10,
+        };
+        if let RequestOperation::Transfer(operation) = &mut request.operation {
+            operation.screening = Some(screening);
+        }
+
+        assert!(!request.is_blocked_by_screening());
+    }
+
+    #[test]
+    fn time_locked_transfer_can_be_cancelled_until_unlocked() {
+        let cancellers = [[1; 16]];
+        let mut request = mock_request();
+        request.status = RequestStatus::Scheduled { scheduled_at: 0 };
+        let RequestOperation::Transfer(operation) = &mut request.operation else {
+            panic!("Expected transfer operation");
+        };
+        let mut time_lock = TransferTimeLock::new(60, cancellers.to_vec());
+
+        assert_eq!(time_lock.lock(100), 100 + 60 * 1_000_000_000);
+        assert_eq!(time_lock.lock(200), 100 + 60 * 1_000_000_000);
+
+        operation.time_lock = Some(time_lock);
+
+        assert!(request.can_cancel(&cancellers, 100));
+        assert!(!request.can_cancel(&[[2; 16]], 100));
+        assert!(!request.can_cancel(&cancellers, 100 + 60 * 1_000_000_000));
+
+        request.status = RequestStatus::Processing { started_at: 100 };
+
+        assert!(!request.can_cancel(&cancellers, 100));
+    }
+
+    #[test]
+    fn approval_must_acknowledge_the_checklist() {
+        let mut policy = request_policy_test_utils::mock_request_policy();
+        policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        policy.checklist = vec!["Verified the invoice".to_string()];
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        let mut request = mock_request();
+        request.approvals = vec![];
+
+        assert_eq!(request.approval_checklist(), policy.checklist);
+        assert!(request
+            .add_approval([1; 16], RequestApprovalStatus::Approved, None, Vec::new())
+            .is_err());
+        assert!(request
+            .add_approval([1; 16], RequestApprovalStatus::Rejected, None, Vec::new())
+            .is_ok());
+
+        request
+            .add_approval(
+                [2; 16],
+                RequestApprovalStatus::Approved,
+                None,
+                vec!["Verified the invoice".to_string()],
+            )
+            .unwrap();
+
+        assert_eq!(
+            request.approvals[1].acknowledged_checklist,
+            policy.checklist
+        );
+    }
+
+    #[test]
+    fn snapshotted_request_keeps_its_policies_when_they_change() {
+        let mut policy = request_policy_test_utils::mock_request_policy();
+        policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        policy.checklist = vec!["Verified the invoice".to_string()];
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.to_owned());
+
+        let mut request = mock_request();
+        request.snapshot_policies();
+
+        let mut edited_policy = policy.clone();
+        edited_policy.checklist = vec!["Called the payee".to_string()];
+        REQUEST_POLICY_REPOSITORY.insert(edited_policy.id, edited_policy.to_owned());
+
+        assert_eq!(request.matching_policies(), vec![policy.clone()]);
+        assert_eq!(request.approval_checklist(), policy.checklist);
+
+        REQUEST_POLICY_REPOSITORY.remove(&policy.id);
+
+        assert_eq!(request.matching_policies(), vec![policy]);
+
+        request.policy_snapshot = None;
+
+        assert!(request.matching_policies().is_empty());
+    }
+
+    #[test]
+    fn screening_is_retried_with_backoff_until_unavailable() {
+        let mut screening = TransferScreening::new(Principal::from_slice(&[1; 29]), false, 0);
+
+        screening.register_failed_attempt("error".to_string(), 100);
+        assert_eq!(
+            screening.next_attempt_at(),
+            Some(100 + TransferScreening::RETRY_BASE_DELAY_NS)
+        );
+
+        screening.register_failed_attempt("error".to_string(), 200);
+        assert_eq!(
+            screening.next_attempt_at(),
+            Some(200 + 2 * TransferScreening::RETRY_BASE_DELAY_NS)
+        );
+
+        for _ in 2..TransferScreening::MAX_ATTEMPTS {
+            screening.register_failed_attempt("error".to_string(), 300);
+        }
+
+        assert_eq!(
+            screening.status,
+            TransferScreeningStatus::Unavailable {
+                error: "error".to_string()
+            }
+        );
+        assert!(screening.next_attempt_at().is_none());
+        assert!(!screening.is_blocking_approval());
+    }
+
     #[test]
     fn test_request_title_is_valid() {
         let mut request = mock_request();
@@ -451,6 +979,210 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn request_text_limits_are_configurable() {
+        let mut system_info = test_utils::init_canister_system();
+        system_info.set_request_text_limits(RequestTextLimits {
+            max_title_len: 10,
+            max_summary_len: 5_000,
+        });
+        write_system_info(system_info);
+
+        assert!(validate_title(&"a".repeat(11)).is_err());
+        assert!(validate_summary(&Some("a".repeat(5_000))).is_ok());
+        assert!(validate_summary(&Some("a".repeat(5_001))).is_err());
+    }
+
+    #[test]
+    fn markdown_summaries_are_sanitized() {
+        let mut request = mock_request();
+        request.summary =
+            Some("## Upgrade\r\n<script>alert(1)</script>\n> quoted\u{7}".to_string());
+
+        request.set_summary_format(RequestSummaryFormat::Markdown);
+
+        assert_eq!(request.summary_format, RequestSummaryFormat::Markdown);
+        assert_eq!(
+            request.summary,
+            Some("## Upgrade\n&lt;script>alert(1)&lt;/script>\n> quoted".to_string())
+        );
+    }
+
+    #[test]
+    fn long_summaries_are_stored_compressed() {
+        let mut request = mock_request();
+        request.summary = Some("Upgrades the ledger. ".repeat(500));
+
+        let bytes = request.to_bytes();
+
+        assert!(bytes.len() < request.summary.as_ref().unwrap().len());
+        assert_eq!(Request::from_bytes(bytes), request);
+
+        let mut short = mock_request();
+        short.summary = Some("Upgrades the ledger.".to_string());
+
+        assert_eq!(Request::from_bytes(short.to_bytes()), short);
+    }
+
+    #[test]
+    fn dependency_state_follows_the_status_of_the_dependencies() {
+        let mut dependency = mock_request();
+        dependency.status = RequestStatus::Scheduled { scheduled_at: 0 };
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        let mut request = mock_request();
+        request.depends_on = vec![dependency.id];
+
+        assert_eq!(request.dependency_state(), RequestDependencyState::Pending);
+
+        dependency.status = RequestStatus::Completed { completed_at: 10 };
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        assert_eq!(
+            request.dependency_state(),
+            RequestDependencyState::Satisfied
+        );
+
+        REQUEST_REPOSITORY.remove(&dependency.to_key());
+        ARCHIVED_REQUEST_REPOSITORY.insert(
+            dependency.id,
+            ArchivedRequest {
+                fields: dependency.index_fields(),
+                archive_canister_id: Principal::from_slice(&[2; 29]),
+                archived_at: 20,
+            },
+        );
+
+        assert_eq!(
+            request.dependency_state(),
+            RequestDependencyState::Satisfied
+        );
+
+        ARCHIVED_REQUEST_REPOSITORY.remove(&dependency.id);
+        dependency.status = RequestStatus::Failed { reason: None };
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        assert_eq!(
+            request.dependency_state(),
+            RequestDependencyState::Unsatisfiable {
+                request_id: dependency.id
+            }
+        );
+    }
+
+    #[test]
+    fn fail_execution_window_ending_before_execution_time() {
+        let now = next_time();
+
+        assert!(validate_execution_plan(&RequestExecutionPlan::Scheduled {
+            execution_time: now + 10,
+            window_end: Some(now + 20),
+        })
+        .is_ok());
+        assert!(validate_execution_plan(&RequestExecutionPlan::Scheduled {
+            execution_time: now + 20,
+            window_end: Some(now + 10),
+        })
+        .is_err());
+        assert!(validate_execution_plan(&RequestExecutionPlan::Scheduled {
+            execution_time: 0,
+            window_end: Some(1),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn fail_request_dependencies_with_cycle() {
+        let mut request = mock_request();
+
+        let mut dependency = mock_request();
+        dependency.depends_on = vec![request.id];
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        request.depends_on = vec![dependency.id];
+
+        assert_eq!(
+            validate_dependencies(&request),
+            Err(RequestError::ValidationError {
+                info: "The dependencies of the request form a cycle".to_owned()
+            })
+        );
+
+        request.depends_on = vec![[0; 16]];
+
+        assert!(validate_dependencies(&request).is_err());
+
+        request.depends_on = vec![];
+
+        assert!(validate_dependencies(&request).is_ok());
+    }
+
+    #[test]
+    fn archived_requests_can_be_dependencies() {
+        let mut dependency = mock_request();
+        dependency.status = RequestStatus::Completed { completed_at: 10 };
+        ARCHIVED_REQUEST_REPOSITORY.insert(
+            dependency.id,
+            ArchivedRequest {
+                fields: dependency.index_fields(),
+                archive_canister_id: Principal::from_slice(&[2; 29]),
+                archived_at: 20,
+            },
+        );
+
+        let mut request = mock_request();
+        request.depends_on = vec![dependency.id];
+
+        assert!(validate_dependencies(&request).is_ok());
+
+        dependency.status = RequestStatus::Cancelled { reason: None };
+        ARCHIVED_REQUEST_REPOSITORY.insert(
+            dependency.id,
+            ArchivedRequest {
+                fields: dependency.index_fields(),
+                archive_canister_id: Principal::from_slice(&[2; 29]),
+                archived_at: 20,
+            },
+        );
+
+        assert!(validate_dependencies(&request).is_err());
+    }
+
+    #[test]
+    fn requests_targeting_frozen_accounts_are_held_back() {
+        let request = mock_request();
+        let RequestOperation::Transfer(operation) = &request.operation else {
+            panic!("Expected transfer operation");
+        };
+        let account = account_test_utils::add_account(&operation.input.from_account_id);
+
+        let mut system_info = SystemInfo::default();
+        assert!(request.find_blockchain_freeze(&system_info).is_none());
+
+        system_info.add_blockchain_freeze(BlockchainFreeze {
+            blockchain: account.blockchain.clone(),
+            network: Some("testnet".to_string()),
+            reason: None,
+            request_id: [2; 16],
+            frozen_at: 0,
+        });
+        assert!(request.find_blockchain_freeze(&system_info).is_none());
+
+        system_info.add_blockchain_freeze(BlockchainFreeze {
+            blockchain: account.blockchain.clone(),
+            network: None,
+            reason: None,
+            request_id: [3; 16],
+            frozen_at: 0,
+        });
+        assert_eq!(
+            request
+                .find_blockchain_freeze(&system_info)
+                .map(|freeze| freeze.request_id),
+            Some([3; 16])
+        );
+    }
+
     #[test]
     fn test_request_summary_is_valid() {
         let mut request = mock_request();
@@ -478,6 +1210,7 @@ mod tests {
                     transfer_permission: Allow::default(),
                     configs_request_policy: None,
                     transfer_request_policy: None,
+                    network: None,
                 },
                 None,
             )
@@ -486,6 +1219,9 @@ mod tests {
 
         let operation = RequestOperation::Transfer(TransferOperation {
             transfer_id: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
             fee: None,
 
             input: TransferOperationInput {
@@ -495,10 +1231,16 @@ mod tests {
                 metadata: Metadata::default(),
                 to: "0x1234".to_string(),
                 from_account_id: account.id,
+                fee_strategy: None,
+                max_fee: None,
+                compliance: None,
+                from_address: None,
             },
+            time_lock: None,
+            duplicate_warning: None,
         });
 
-        let result = validate_request_operation_foreign_keys(&operation);
+        let result = validate_request_operation(&operation);
 
         assert!(result.is_ok());
     }
@@ -507,8 +1249,11 @@ mod tests {
     async fn fail_request_operation_with_invalid_id() {
         disable_mock_resource_validation();
 
-        validate_request_operation_foreign_keys(&RequestOperation::Transfer(TransferOperation {
+        validate_request_operation(&RequestOperation::Transfer(TransferOperation {
             transfer_id: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
             fee: None,
             input: TransferOperationInput {
                 network: "mainnet".to_string(),
@@ -517,40 +1262,50 @@ mod tests {
                 metadata: Metadata::default(),
                 to: "0x1234".to_string(),
                 from_account_id: [0; 16],
+                fee_strategy: None,
+                max_fee: None,
+                compliance: None,
+                from_address: None,
             },
+            time_lock: None,
+            duplicate_warning: None,
         }))
         .expect_err("Invalid account id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::AddUser(AddUserOperation {
+        validate_request_operation(&RequestOperation::AddUser(AddUserOperation {
             user_id: None,
             input: AddUserOperationInput {
                 name: "user-1".to_string(),
                 identities: vec![],
                 groups: vec![[1; 16]],
                 status: crate::models::UserStatus::Active,
+                scheduled_memberships: vec![],
             },
         }))
         .expect_err("Invalid user group id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::EditUserGroup(
+        validate_request_operation(&RequestOperation::EditUserGroup(
             crate::models::EditUserGroupOperation {
                 input: crate::models::EditUserGroupOperationInput {
                     user_group_id: [0; 16],
                     name: "a".to_owned(),
+                    quotas: None,
                 },
             },
         ))
         .expect_err("Invalid user group id should fail");
-        validate_request_operation_foreign_keys(&RequestOperation::RemoveUserGroup(
+        validate_request_operation(&RequestOperation::RemoveUserGroup(
             crate::models::RemoveUserGroupOperation {
                 input: crate::models::RemoveUserGroupOperationInput {
                     user_group_id: [0; 16],
+                    reassignment: None,
                 },
+                affected: Default::default(),
             },
         ))
         .expect_err("Invalid user group id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::AddRequestPolicy(
+        validate_request_operation(&RequestOperation::AddRequestPolicy(
             crate::models::AddRequestPolicyOperation {
                 policy_id: None,
                 input: crate::models::AddRequestPolicyOperationInput {
@@ -558,30 +1313,35 @@ mod tests {
                         crate::models::resource::ResourceIds::Ids(vec![[1; 16]]),
                     ),
                     rule: crate::models::request_policy_rule::RequestPolicyRule::AutoApproved,
+                    checklist: Vec::new(),
+                    exclude_requester: false,
                 },
             },
         ))
         .expect_err("Invalid request specifier should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::EditRequestPolicy(
+        validate_request_operation(&RequestOperation::EditRequestPolicy(
             crate::models::EditRequestPolicyOperation {
                 input: crate::models::EditRequestPolicyOperationInput {
                     policy_id: [0; 16],
                     specifier: None,
                     rule: None,
+                    expected_version: None,
+                    checklist: None,
+                    exclude_requester: None,
                 },
             },
         ))
         .expect_err("Invalid request policy id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::RemoveRequestPolicy(
+        validate_request_operation(&RequestOperation::RemoveRequestPolicy(
             crate::models::RemoveRequestPolicyOperation {
                 input: crate::models::RemoveRequestPolicyOperationInput { policy_id: [0; 16] },
             },
         ))
         .expect_err("Invalid request policy id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::AddAccount(
+        validate_request_operation(&RequestOperation::AddAccount(
             crate::models::AddAccountOperation {
                 account_id: None,
                 input: crate::models::AddAccountOperationInput {
@@ -598,12 +1358,13 @@ mod tests {
                     transfer_permission: Allow::default(),
                     configs_request_policy: None,
                     transfer_request_policy: None,
+                    network: None,
                 },
             },
         ))
         .expect_err("Invalid user id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::EditAccount(
+        validate_request_operation(&RequestOperation::EditAccount(
             crate::models::EditAccountOperation {
                 input: crate::models::EditAccountOperationInput {
                     account_id: [0; 16],
@@ -613,12 +1374,13 @@ mod tests {
                     configs_request_policy: None,
                     transfer_request_policy: None,
                     name: None,
+                    expected_version: None,
                 },
             },
         ))
         .expect_err("Invalid account id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::EditAddressBookEntry(
+        validate_request_operation(&RequestOperation::EditAddressBookEntry(
             crate::models::EditAddressBookEntryOperation {
                 input: crate::models::EditAddressBookEntryOperationInput {
                     address_book_entry_id: [0; 16],
@@ -630,7 +1392,7 @@ mod tests {
         ))
         .expect_err("Invalid address book entry id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::RemoveAddressBookEntry(
+        validate_request_operation(&RequestOperation::RemoveAddressBookEntry(
             crate::models::RemoveAddressBookEntryOperation {
                 input: crate::models::RemoveAddressBookEntryOperationInput {
                     address_book_entry_id: [0; 16],
@@ -639,7 +1401,7 @@ mod tests {
         ))
         .expect_err("Invalid address book entry id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::EditUser(
+        validate_request_operation(&RequestOperation::EditUser(
             crate::models::EditUserOperation {
                 input: crate::models::EditUserOperationInput {
                     user_id: [0; 16],
@@ -648,12 +1410,14 @@ mod tests {
                     identities: None,
                     status: None,
                     cancel_pending_requests: None,
+                    expected_version: None,
+                    scheduled_memberships: None,
                 },
             },
         ))
         .expect_err("Invalid user id should fail");
 
-        validate_request_operation_foreign_keys(&RequestOperation::EditPermission(
+        validate_request_operation(&RequestOperation::EditPermission(
             crate::models::EditPermissionOperation {
                 input: crate::models::EditPermissionOperationInput {
                     resource: crate::models::resource::Resource::Account(
@@ -685,12 +1449,16 @@ pub mod request_test_utils {
             id: *Uuid::new_v4().as_bytes(),
             title: "foo".to_string(),
             summary: Some("bar".to_string()),
+            summary_format: RequestSummaryFormat::PlainText,
             requested_by: [1; 16],
             status: RequestStatus::Approved,
             expiration_dt: 100,
             execution_plan: RequestExecutionPlan::Immediate,
             operation: RequestOperation::Transfer(TransferOperation {
                 transfer_id: None,
+                fee_estimate: None,
+                to_name: None,
+                screening: None,
                 fee: None,
                 input: TransferOperationInput {
                     network: "mainnet".to_string(),
@@ -699,7 +1467,13 @@ pub mod request_test_utils {
                     metadata: Metadata::default(),
                     to: "0x1234".to_string(),
                     from_account_id: [1; 16],
+                    fee_strategy: None,
+                    max_fee: None,
+                    compliance: None,
+                    from_address: None,
                 },
+                time_lock: None,
+                duplicate_warning: None,
             }),
             approvals: vec![RequestApproval {
                 approver_id: [1; 16],
@@ -707,9 +1481,24 @@ pub mod request_test_utils {
                 status_reason: None,
                 decided_dt: 0,
                 last_modification_timestamp: 0,
+                acknowledged_checklist: Vec::new(),
             }],
+            execution_steps: vec![],
+            confidential: false,
+            compacted_at: None,
+            cost: RequestCost::default(),
             created_timestamp: 0,
             last_modification_timestamp: 0,
+            depends_on: vec![],
+            policy_snapshot: None,
+        }
+    }
+
+    pub fn mock_archived_request() -> ArchivedRequest {
+        ArchivedRequest {
+            fields: mock_request().index_fields(),
+            archive_canister_id: Principal::from_slice(&[2; 29]),
+            archived_at: 0,
         }
     }
 }
@@ -15,6 +15,9 @@ pub struct RequestApproval {
     pub status: RequestApprovalStatus,
     /// Optional reason for the decision.
     pub status_reason: Option<String>,
+    /// The checklist items of the matching policies that the approver acknowledged with the decision.
+    #[serde(default)]
+    pub acknowledged_checklist: Vec<String>,
     /// The time at which the decision was made.
     pub decided_dt: Timestamp,
     /// The last time the record was updated or created.
@@ -87,6 +90,7 @@ pub mod request_approval_test_utils {
             status_reason: None,
             decided_dt: 0,
             last_modification_timestamp: 0,
+            acknowledged_checklist: Vec::new(),
         }
     }
 
@@ -97,6 +101,7 @@ pub mod request_approval_test_utils {
             status_reason: None,
             decided_dt: 0,
             last_modification_timestamp: 0,
+            acknowledged_checklist: Vec::new(),
         }
     }
 
@@ -107,6 +112,7 @@ pub mod request_approval_test_utils {
             status_reason: None,
             decided_dt: 0,
             last_modification_timestamp: 0,
+            acknowledged_checklist: Vec::new(),
         }
     }
 }
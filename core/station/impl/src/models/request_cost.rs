@@ -0,0 +1,127 @@
+use orbit_essentials::storable;
+use std::fmt::{Display, Formatter};
+
+/// The phases of the lifecycle of a request whose cost is measured.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestCostPhase {
+    Creation,
+    Evaluation,
+    Execution,
+}
+
+impl RequestCostPhase {
+    pub const ALL: [RequestCostPhase; 3] = [
+        RequestCostPhase::Creation,
+        RequestCostPhase::Evaluation,
+        RequestCostPhase::Execution,
+    ];
+}
+
+impl Display for RequestCostPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestCostPhase::Creation => write!(f, "creation"),
+            RequestCostPhase::Evaluation => write!(f, "evaluation"),
+            RequestCostPhase::Execution => write!(f, "execution"),
+        }
+    }
+}
+
+/// The instructions that the station spent on a request, measured with the performance counter of the
+/// call context, so the instructions of the inter-canister calls made by the station are not included.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCost {
+    /// The instructions spent on creating the request, excluding its first evaluation.
+    pub creation_instructions: u64,
+    /// The instructions spent on all the evaluations of the request against its policies.
+    pub evaluation_instructions: u64,
+    /// The number of times the request was evaluated, e.g. once per approval.
+    pub evaluations: u32,
+    /// The instructions spent on executing the operation, including the failed and resumed executions.
+    pub execution_instructions: u64,
+    /// The phases in which the request was unusually expensive for its operation type.
+    pub expensive_phases: Vec<RequestCostPhase>,
+}
+
+impl RequestCost {
+    /// The cycles charged for ten instructions on a subnet of 13 nodes.
+    pub const CYCLES_PER_TEN_INSTRUCTIONS: u64 = 4;
+
+    pub fn instructions(&self, phase: RequestCostPhase) -> u64 {
+        match phase {
+            RequestCostPhase::Creation => self.creation_instructions,
+            RequestCostPhase::Evaluation => self.evaluation_instructions,
+            RequestCostPhase::Execution => self.execution_instructions,
+        }
+    }
+
+    pub fn total_instructions(&self) -> u64 {
+        RequestCostPhase::ALL.iter().fold(0u64, |total, phase| {
+            total.saturating_add(self.instructions(*phase))
+        })
+    }
+
+    /// Adds the instructions spent on the given phase.
+    pub fn add(&mut self, phase: RequestCostPhase, instructions: u64) {
+        match phase {
+            RequestCostPhase::Creation => {
+                self.creation_instructions = self.creation_instructions.saturating_add(instructions)
+            }
+            RequestCostPhase::Evaluation => {
+                self.evaluation_instructions =
+                    self.evaluation_instructions.saturating_add(instructions);
+                self.evaluations = self.evaluations.saturating_add(1);
+            }
+            RequestCostPhase::Execution => {
+                self.execution_instructions =
+                    self.execution_instructions.saturating_add(instructions)
+            }
+        }
+    }
+
+    /// Flags the phase as unusually expensive, each phase is only flagged once.
+    pub fn flag_expensive(&mut self, phase: RequestCostPhase) -> bool {
+        if self.expensive_phases.contains(&phase) {
+            return false;
+        }
+
+        self.expensive_phases.push(phase);
+
+        true
+    }
+
+    /// Estimates the cycles that the station was charged for the given instructions.
+    pub fn estimated_cycles(instructions: u64) -> u64 {
+        instructions.saturating_mul(Self::CYCLES_PER_TEN_INSTRUCTIONS) / 10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluations_are_accumulated() {
+        let mut cost = RequestCost::default();
+
+        cost.add(RequestCostPhase::Creation, 100);
+        cost.add(RequestCostPhase::Evaluation, 10);
+        cost.add(RequestCostPhase::Evaluation, 20);
+
+        assert_eq!(cost.evaluation_instructions, 30);
+        assert_eq!(cost.evaluations, 2);
+        assert_eq!(cost.total_instructions(), 130);
+        assert_eq!(RequestCost::estimated_cycles(130), 52);
+    }
+
+    #[test]
+    fn phases_are_flagged_once() {
+        let mut cost = RequestCost::default();
+
+        assert!(cost.flag_expensive(RequestCostPhase::Execution));
+        assert!(!cost.flag_expensive(RequestCostPhase::Execution));
+        assert_eq!(cost.expensive_phases, vec![RequestCostPhase::Execution]);
+    }
+}
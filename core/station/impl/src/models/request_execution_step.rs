@@ -0,0 +1,45 @@
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestExecutionStepStatus {
+    Completed {
+        /// The result of the step that is needed by the following steps (e.g. a created canister id).
+        output: Option<String>,
+        completed_at: Timestamp,
+    },
+    Failed {
+        reason: String,
+        failed_at: Timestamp,
+    },
+}
+
+/// Represents one step of a request operation that is executed in several steps.
+///
+/// The steps are recorded in the request so that a failed execution can be resumed from the step
+/// that failed, without repeating the steps that were already completed.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestExecutionStep {
+    /// The name of the step, unique within the operation (e.g. `create_canister`).
+    pub name: String,
+    /// The status of the last attempt to execute the step.
+    pub status: RequestExecutionStepStatus,
+    /// The number of times the step was attempted.
+    pub attempts: u32,
+}
+
+impl RequestExecutionStep {
+    pub fn is_completed(&self) -> bool {
+        matches!(self.status, RequestExecutionStepStatus::Completed { .. })
+    }
+
+    /// Returns the output of the step, if it was completed.
+    pub fn output(&self) -> Option<&String> {
+        match &self.status {
+            RequestExecutionStepStatus::Completed { output, .. } => output.as_ref(),
+            RequestExecutionStepStatus::Failed { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use super::{
+    permission::{Allow, Permission},
+    request_specifier::RequestSpecifier,
+    DisplayUser, EditPermissionOperationInput, Request, RequestId, RequestOperation,
+    RequestPolicyRule, RequestStatusCode, User, UserId, UserStatus,
+};
+use crate::repositories::{
+    permission::PERMISSION_REPOSITORY, UserWhereClause, REQUEST_POLICY_REPOSITORY,
+    REQUEST_REPOSITORY, USER_REPOSITORY,
+};
+use orbit_essentials::repository::Repository;
+use std::collections::HashSet;
+
+/// The changes to the access of the users that executing a permission or request policy change would make,
+/// computed from the current state of the station so that the approvers don't approve such changes blind.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestImpact {
+    /// The users that would gain access to the resource of the permission, or become approvers of the policy.
+    pub users_gaining_access: Vec<UserId>,
+    /// The users that would lose access to the resource of the permission, or stop being approvers of the
+    /// policy.
+    pub users_losing_access: Vec<UserId>,
+    /// The pending requests, other than the request itself, whose matching policies would change.
+    ///
+    /// Requests keep the policies they were submitted with, so only the requests without a policy snapshot
+    /// are affected by policy changes.
+    pub affected_requests: Vec<RequestId>,
+}
+
+impl RequestImpact {
+    /// Computes the impact of the request, if its operation changes a permission or a request policy.
+    ///
+    /// Only the active users are considered, since the others can't access the station either way.
+    pub fn of(request: &Request) -> Option<Self> {
+        let users = USER_REPOSITORY.find_where(UserWhereClause {
+            search_term: None,
+            statuses: Some(vec![UserStatus::Active]),
+            groups: None,
+        });
+
+        let mut impact = match &request.operation {
+            RequestOperation::EditPermission(operation) => {
+                Self::of_permission_change(&operation.input, &users)
+            }
+            RequestOperation::BulkEditPermission(operation) => {
+                let mut gaining = HashSet::new();
+                let mut losing = HashSet::new();
+                for change in &operation.input.changes {
+                    let impact = Self::of_permission_change(change, &users);
+                    gaining.extend(impact.users_gaining_access);
+                    losing.extend(impact.users_losing_access);
+                }
+
+                Self {
+                    users_gaining_access: sorted(gaining.iter()),
+                    users_losing_access: sorted(losing.iter()),
+                    affected_requests: Vec::new(),
+                }
+            }
+            RequestOperation::AddRequestPolicy(operation) => Self::of_policy_change(
+                None,
+                Some((&operation.input.specifier, &operation.input.rule)),
+                &users,
+            ),
+            RequestOperation::EditRequestPolicy(operation) => {
+                let policy = REQUEST_POLICY_REPOSITORY.get(&operation.input.policy_id)?;
+                let specifier = operation
+                    .input
+                    .specifier
+                    .as_ref()
+                    .unwrap_or(&policy.specifier);
+                let rule = operation.input.rule.as_ref().unwrap_or(&policy.rule);
+
+                let mut impact = Self::of_policy_change(
+                    Some((&policy.specifier, &policy.rule)),
+                    Some((specifier, rule)),
+                    &users,
+                );
+
+                let checklist_changed = operation
+                    .input
+                    .checklist
+                    .as_ref()
+                    .is_some_and(|checklist| *checklist != policy.checklist);
+                let exclude_requester_changed = operation
+                    .input
+                    .exclude_requester
+                    .is_some_and(|exclude_requester| exclude_requester != policy.exclude_requester);
+                if !checklist_changed
+                    && !exclude_requester_changed
+                    && *specifier == policy.specifier
+                    && *rule == policy.rule
+                {
+                    impact.affected_requests.clear();
+                }
+
+                impact
+            }
+            RequestOperation::RemoveRequestPolicy(operation) => {
+                let policy = REQUEST_POLICY_REPOSITORY.get(&operation.input.policy_id)?;
+
+                Self::of_policy_change(Some((&policy.specifier, &policy.rule)), None, &users)
+            }
+            _ => return None,
+        };
+
+        impact.affected_requests.retain(|id| *id != request.id);
+
+        Some(impact)
+    }
+
+    fn of_permission_change(input: &EditPermissionOperationInput, users: &[User]) -> Self {
+        let current = PERMISSION_REPOSITORY
+            .get(&input.resource)
+            .unwrap_or_else(|| Permission::new(Allow::default(), input.resource.clone()));
+
+        let mut changed = current.clone();
+        if let Some(auth_scope) = &input.auth_scope {
+            changed.allow.auth_scope = auth_scope.clone();
+        }
+        if let Some(users) = &input.users {
+            changed.allow.users = users.clone();
+        }
+        if let Some(user_groups) = &input.user_groups {
+            changed.allow.user_groups = user_groups.clone();
+        }
+
+        let allowed = |permission: &Permission| {
+            users
+                .iter()
+                .filter(|user| permission.is_allowed(user))
+                .map(|user| user.id)
+                .collect::<HashSet<_>>()
+        };
+
+        let (before, after) = (allowed(&current), allowed(&changed));
+
+        Self {
+            users_gaining_access: sorted(after.difference(&before)),
+            users_losing_access: sorted(before.difference(&after)),
+            affected_requests: Vec::new(),
+        }
+    }
+
+    fn of_policy_change(
+        before: Option<(&RequestSpecifier, &RequestPolicyRule)>,
+        after: Option<(&RequestSpecifier, &RequestPolicyRule)>,
+        users: &[User],
+    ) -> Self {
+        let approvers = |policy: Option<(&RequestSpecifier, &RequestPolicyRule)>| {
+            policy.map_or_else(HashSet::new, |(_, rule)| rule.approvers(users))
+        };
+
+        let (approvers_before, approvers_after) = (approvers(before), approvers(after));
+
+        let resources = [before, after]
+            .into_iter()
+            .flatten()
+            .flat_map(|(specifier, _)| specifier.to_resources())
+            .collect::<HashSet<_>>();
+
+        let mut affected_requests = REQUEST_REPOSITORY
+            .find_by_status(RequestStatusCode::Created, None, None)
+            .into_iter()
+            .filter(|request| {
+                request.policy_snapshot.is_none()
+                    && request
+                        .operation
+                        .to_resources()
+                        .iter()
+                        .any(|resource| resources.contains(resource))
+            })
+            .map(|request| request.id)
+            .collect::<Vec<_>>();
+        affected_requests.sort();
+
+        Self {
+            users_gaining_access: sorted(approvers_after.difference(&approvers_before)),
+            users_losing_access: sorted(approvers_before.difference(&approvers_after)),
+            affected_requests,
+        }
+    }
+}
+
+/// The impact of a request, with the users resolved for display.
+#[derive(Debug, Clone)]
+pub struct DisplayRequestImpact {
+    pub users_gaining_access: Vec<DisplayUser>,
+    pub users_losing_access: Vec<DisplayUser>,
+    pub affected_requests: Vec<RequestId>,
+}
+
+fn sorted<'a>(ids: impl Iterator<Item = &'a UserId>) -> Vec<UserId> {
+    let mut ids = ids.copied().collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        request_specifier::UserSpecifier,
+        request_test_utils::mock_request,
+        resource::{Resource, ResourceAction, ResourceIds},
+        user_test_utils::mock_user,
+        AddRequestPolicyOperation, AddRequestPolicyOperationInput, EditPermissionOperation,
+        RequestStatus,
+    };
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn permission_change_lists_the_users_gaining_and_losing_access() {
+        let resource = Resource::AddressBook(ResourceAction::List);
+
+        let mut current_user = mock_user();
+        current_user.status = UserStatus::Active;
+        USER_REPOSITORY.insert(current_user.to_key(), current_user.clone());
+        let mut new_user = mock_user();
+        new_user.status = UserStatus::Active;
+        USER_REPOSITORY.insert(new_user.to_key(), new_user.clone());
+
+        let permission = Permission::new(Allow::users(vec![current_user.id]), resource.clone());
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let mut request = mock_request();
+        request.operation = RequestOperation::EditPermission(EditPermissionOperation {
+            input: EditPermissionOperationInput {
+                resource,
+                auth_scope: None,
+                users: Some(vec![new_user.id]),
+                user_groups: None,
+            },
+        });
+
+        let impact = RequestImpact::of(&request).unwrap();
+
+        assert_eq!(impact.users_gaining_access, vec![new_user.id]);
+        assert_eq!(impact.users_losing_access, vec![current_user.id]);
+        assert!(impact.affected_requests.is_empty());
+    }
+
+    #[test]
+    fn policy_change_lists_the_affected_pending_requests() {
+        let mut approver = mock_user();
+        approver.status = UserStatus::Active;
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let mut pending_transfer = mock_request();
+        pending_transfer.status = RequestStatus::Created;
+        REQUEST_REPOSITORY.insert(pending_transfer.to_key(), pending_transfer.clone());
+
+        let mut snapshotted_transfer = mock_request();
+        snapshotted_transfer.status = RequestStatus::Created;
+        snapshotted_transfer.policy_snapshot = Some(Vec::new());
+        REQUEST_REPOSITORY.insert(snapshotted_transfer.to_key(), snapshotted_transfer);
+
+        let mut completed_transfer = mock_request();
+        completed_transfer.status = RequestStatus::Completed { completed_at: 0 };
+        REQUEST_REPOSITORY.insert(completed_transfer.to_key(), completed_transfer);
+
+        let mut request = mock_request();
+        request.operation = RequestOperation::AddRequestPolicy(AddRequestPolicyOperation {
+            policy_id: None,
+            input: AddRequestPolicyOperationInput {
+                specifier: RequestSpecifier::Transfer(ResourceIds::Any),
+                rule: RequestPolicyRule::Quorum(UserSpecifier::Id(vec![approver.id]), 1),
+                checklist: Vec::new(),
+                exclude_requester: false,
+            },
+        });
+
+        let impact = RequestImpact::of(&request).unwrap();
+
+        assert_eq!(impact.users_gaining_access, vec![approver.id]);
+        assert!(impact.users_losing_access.is_empty());
+        assert_eq!(impact.affected_requests, vec![pending_transfer.id]);
+    }
+
+    #[test]
+    fn other_operations_have_no_impact() {
+        let request = mock_request();
+
+        assert!(RequestImpact::of(&request).is_none());
+    }
+}
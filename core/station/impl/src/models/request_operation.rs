@@ -1,11 +1,20 @@
 use super::{
     permission::{Allow, AuthScope},
     request_policy_rule::{RequestPolicyRule, RequestPolicyRuleInput},
-    request_specifier::RequestSpecifier,
+    request_specifier::{RequestSpecifier, UserSpecifier},
     resource::{Resource, ValidationMethodResourceTarget},
+    system::{
+        AddressBookSource, ApprovalReminderConfig, AuditorAccess, BalanceSnapshotConfig,
+        BrandingConfig, DataCompactionConfig, FiatValuationConfig, MaintenanceModeConfig,
+        MetadataEncryptionConfig, NameServiceConfig, OperationFlag, RequestArchivalConfig,
+        RequestTextLimits, SubTreasury, TransferComplianceThreshold, TransferMaxFee,
+        TransferScreeningConfig, TransferTimeLockConfig,
+    },
     AccountId, AddressBookEntryId, Blockchain, BlockchainStandard, ChangeMetadata,
     CycleObtainStrategy, DisasterRecoveryCommittee, ExternalCanisterCallPermission,
-    ExternalCanisterState, MetadataItem, UserGroupId, UserId, UserStatus,
+    ExternalCanisterState, MetadataItem, NativeCanisterSettings, Network, NetworkId, RequestId,
+    ScheduledGroupMembership, TransferComplianceInfo, User, UserGroupId, UserGroupQuotas,
+    UserGroupReassignment, UserGroupReferences, UserId, UserStatus,
 };
 use crate::core::validation::EnsureExternalCanister;
 use crate::errors::ValidationError;
@@ -13,8 +22,12 @@ use crate::models::Metadata;
 use candid::Principal;
 use orbit_essentials::cdk::api::management_canister::main::{self as mgmt};
 use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
-use orbit_essentials::{storable, types::UUID};
+use orbit_essentials::{
+    storable,
+    types::{Timestamp, UUID},
+};
 use std::fmt::Display;
+use uuid::Uuid;
 
 #[storable(skip_deserialize = true)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, strum::VariantNames)]
@@ -23,12 +36,15 @@ pub enum RequestOperation {
     Transfer(TransferOperation),
     AddAccount(AddAccountOperation),
     EditAccount(EditAccountOperation),
+    RotateAccountAddress(RotateAccountAddressOperation),
+    TransferAccountOwnership(TransferAccountOwnershipOperation),
     AddAddressBookEntry(AddAddressBookEntryOperation),
     EditAddressBookEntry(EditAddressBookEntryOperation),
     RemoveAddressBookEntry(RemoveAddressBookEntryOperation),
     AddUser(AddUserOperation),
     EditUser(EditUserOperation),
     EditPermission(EditPermissionOperation),
+    BulkEditPermission(BulkEditPermissionOperation),
     AddUserGroup(AddUserGroupOperation),
     EditUserGroup(EditUserGroupOperation),
     RemoveUserGroup(RemoveUserGroupOperation),
@@ -38,11 +54,18 @@ pub enum RequestOperation {
     CreateExternalCanister(CreateExternalCanisterOperation),
     CallExternalCanister(CallExternalCanisterOperation),
     FundExternalCanister(FundExternalCanisterOperation),
+    CommitAssetBatch(CommitAssetBatchOperation),
+    SnapshotExternalCanister(SnapshotExternalCanisterOperation),
     AddRequestPolicy(AddRequestPolicyOperation),
     EditRequestPolicy(EditRequestPolicyOperation),
     RemoveRequestPolicy(RemoveRequestPolicyOperation),
     ManageSystemInfo(ManageSystemInfoOperation),
     SetDisasterRecovery(SetDisasterRecoveryOperation),
+    AddNetwork(AddNetworkOperation),
+    EditNetwork(EditNetworkOperation),
+    FreezeBlockchain(FreezeBlockchainOperation),
+    UnfreezeBlockchain(UnfreezeBlockchainOperation),
+    DecommissionStation(DecommissionStationOperation),
 }
 
 impl Display for RequestOperation {
@@ -51,12 +74,17 @@ impl Display for RequestOperation {
             RequestOperation::Transfer(_) => write!(f, "transfer"),
             RequestOperation::AddAccount(_) => write!(f, "add_account"),
             RequestOperation::EditAccount(_) => write!(f, "edit_account"),
+            RequestOperation::RotateAccountAddress(_) => write!(f, "rotate_account_address"),
+            RequestOperation::TransferAccountOwnership(_) => {
+                write!(f, "transfer_account_ownership")
+            }
             RequestOperation::AddAddressBookEntry(_) => write!(f, "add_address_book_entry"),
             RequestOperation::EditAddressBookEntry(_) => write!(f, "edit_address_book_entry"),
             RequestOperation::RemoveAddressBookEntry(_) => write!(f, "remove_address_book_entry"),
             RequestOperation::AddUser(_) => write!(f, "add_user"),
             RequestOperation::EditUser(_) => write!(f, "edit_user"),
             RequestOperation::EditPermission(_) => write!(f, "edit_permission"),
+            RequestOperation::BulkEditPermission(_) => write!(f, "bulk_edit_permission"),
             RequestOperation::AddUserGroup(_) => write!(f, "add_user_group"),
             RequestOperation::EditUserGroup(_) => write!(f, "adit_user_group"),
             RequestOperation::RemoveUserGroup(_) => write!(f, "remove_user_group"),
@@ -68,12 +96,95 @@ impl Display for RequestOperation {
             RequestOperation::CreateExternalCanister(_) => write!(f, "create_external_canister"),
             RequestOperation::CallExternalCanister(_) => write!(f, "call_external_canister"),
             RequestOperation::FundExternalCanister(_) => write!(f, "fund_external_canister"),
+            RequestOperation::CommitAssetBatch(_) => write!(f, "commit_asset_batch"),
+            RequestOperation::SnapshotExternalCanister(_) => {
+                write!(f, "snapshot_external_canister")
+            }
             RequestOperation::AddRequestPolicy(_) => write!(f, "add_request_policy"),
             RequestOperation::EditRequestPolicy(_) => write!(f, "edit_request_policy"),
             RequestOperation::RemoveRequestPolicy(_) => write!(f, "remove_request_policy"),
             RequestOperation::ManageSystemInfo(_) => write!(f, "manage_system_info"),
             RequestOperation::SetDisasterRecovery(_) => write!(f, "set_disaster_recovery"),
+            RequestOperation::AddNetwork(_) => write!(f, "add_network"),
+            RequestOperation::EditNetwork(_) => write!(f, "edit_network"),
+            RequestOperation::FreezeBlockchain(_) => write!(f, "freeze_blockchain"),
+            RequestOperation::UnfreezeBlockchain(_) => write!(f, "unfreeze_blockchain"),
+            RequestOperation::DecommissionStation(_) => write!(f, "decommission_station"),
+        }
+    }
+}
+
+/// A payload that was pruned from an operation, named after the field that held it (e.g. `module`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrunedPayload {
+    pub name: &'static str,
+    pub content: Vec<u8>,
+}
+
+impl RequestOperation {
+    /// Whether the operation changes the maintenance mode, such requests can still be created and approved
+    /// while the station is in maintenance mode so that it can be left.
+    pub fn is_maintenance_mode_change(&self) -> bool {
+        matches!(
+            self,
+            RequestOperation::ManageSystemInfo(operation) if operation.input.maintenance_mode.is_some()
+        )
+    }
+
+    /// Prunes the bulky payloads of the operation and returns them, the checksums of the payloads are kept.
+    ///
+    /// The metadata and travel rule information of executed transfers are dropped without being returned,
+    /// since they were copied to the transfer that the operation links to.
+    pub fn prune_payloads(&mut self) -> Vec<PrunedPayload> {
+        let mut pruned = Vec::new();
+        let mut prune = |name: &'static str, content: Vec<u8>| {
+            if !content.is_empty() {
+                pruned.push(PrunedPayload { name, content });
+            }
+        };
+
+        match self {
+            RequestOperation::SystemUpgrade(operation) => {
+                prune("module", std::mem::take(&mut operation.input.module));
+                prune("arg", operation.input.arg.take().unwrap_or_default());
+            }
+            RequestOperation::ChangeExternalCanister(operation) => {
+                prune("module", std::mem::take(&mut operation.input.module));
+                prune("arg", operation.input.arg.take().unwrap_or_default());
+            }
+            RequestOperation::CallExternalCanister(operation) => {
+                prune("arg", operation.input.arg.take().unwrap_or_default());
+                prune(
+                    "arg_rendering",
+                    operation
+                        .arg_rendering
+                        .take()
+                        .map(String::into_bytes)
+                        .unwrap_or_default(),
+                );
+                prune(
+                    "execution_method_reply",
+                    operation.execution_method_reply.take().unwrap_or_default(),
+                );
+            }
+            RequestOperation::CommitAssetBatch(operation) => {
+                prune(
+                    "evidence_rendering",
+                    operation
+                        .evidence_rendering
+                        .take()
+                        .map(String::into_bytes)
+                        .unwrap_or_default(),
+                );
+            }
+            RequestOperation::Transfer(operation) if operation.transfer_id.is_some() => {
+                operation.input.metadata = Metadata::default();
+                operation.input.compliance = None;
+            }
+            _ => {}
         }
+
+        pruned
     }
 }
 
@@ -83,6 +194,22 @@ pub struct TransferOperation {
     pub transfer_id: Option<UUID>,
     pub input: TransferOperationInput,
     pub fee: Option<candid::Nat>,
+    /// The fee estimate taken when the request was created, if no explicit fee was provided.
+    #[serde(default)]
+    pub fee_estimate: Option<TransferFeeEstimate>,
+    /// The human-readable name that was given as the destination (e.g. `alice.icp`), in which case
+    /// `input.to` holds the address it resolved to when the request was created.
+    #[serde(default)]
+    pub to_name: Option<String>,
+    /// The screening of the transfer by the screening service, if the transfer required one.
+    #[serde(default)]
+    pub screening: Option<TransferScreening>,
+    /// The delay between the final approval and the execution of the transfer, if its amount requires one.
+    #[serde(default)]
+    pub time_lock: Option<TransferTimeLock>,
+    /// The other transfers that looked like duplicates of this one when the request was created.
+    #[serde(default)]
+    pub duplicate_warning: Option<TransferDuplicateWarning>,
 }
 
 #[storable]
@@ -94,6 +221,198 @@ pub struct TransferOperationInput {
     pub metadata: Metadata,
     pub network: String,
     pub fee: Option<candid::Nat>,
+    /// The strategy used to estimate the fee when no explicit fee is provided.
+    #[serde(default)]
+    pub fee_strategy: Option<TransferFeeStrategy>,
+    /// The maximum fee that the transfer is allowed to pay once executed.
+    #[serde(default)]
+    pub max_fee: Option<candid::Nat>,
+    /// The travel rule information of the transfer, which is copied to the transfer once executed.
+    #[serde(default)]
+    pub compliance: Option<TransferComplianceInfo>,
+    /// The address to transfer from, which is the current address of the account if not set.
+    #[serde(default)]
+    pub from_address: Option<String>,
+}
+
+/// The strategy used to estimate the transaction fee of a transfer.
+///
+/// Blockchains with a fixed transaction fee use the same fee for all strategies.
+#[storable]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferFeeStrategy {
+    Slow,
+    #[default]
+    Standard,
+    Fast,
+}
+
+impl Display for TransferFeeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransferFeeStrategy::Slow => write!(f, "slow"),
+            TransferFeeStrategy::Standard => write!(f, "standard"),
+            TransferFeeStrategy::Fast => write!(f, "fast"),
+        }
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferFeeEstimate {
+    /// The estimated fee.
+    pub fee: candid::Nat,
+    /// The strategy that was used to estimate the fee.
+    pub strategy: TransferFeeStrategy,
+    /// The time at which the fee was estimated.
+    pub estimated_at: Timestamp,
+}
+
+impl TransferFeeEstimate {
+    /// The maximum age of a fee estimate before it needs to be re-estimated at execution time.
+    pub const MAX_AGE_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    /// Checks if the estimate is too old to be used for a transfer executed at the given time.
+    pub fn is_stale(&self, now: Timestamp) -> bool {
+        now.saturating_sub(self.estimated_at) > Self::MAX_AGE_NS
+    }
+}
+
+/// Holds back the execution of an approved transfer, so that it can still be cancelled in the meantime.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferTimeLock {
+    pub delay_secs: u64,
+    /// The user groups whose members can cancel the transfer until it is unlocked.
+    pub canceller_group_ids: Vec<UserGroupId>,
+    /// The time from which the transfer can be executed, which is set once the request is approved.
+    pub unlocks_at: Option<Timestamp>,
+}
+
+impl TransferTimeLock {
+    pub fn new(delay_secs: u64, canceller_group_ids: Vec<UserGroupId>) -> Self {
+        Self {
+            delay_secs,
+            canceller_group_ids,
+            unlocks_at: None,
+        }
+    }
+
+    /// Starts the delay once the request is approved and returns the time from which the transfer can be executed.
+    pub fn lock(&mut self, approved_at: Timestamp) -> Timestamp {
+        *self.unlocks_at.get_or_insert(
+            approved_at.saturating_add(self.delay_secs.saturating_mul(1_000_000_000)),
+        )
+    }
+
+    /// Whether a member of the given groups can still cancel the transfer.
+    pub fn can_be_cancelled_by(&self, groups: &[UserGroupId], now: Timestamp) -> bool {
+        self.unlocks_at.is_some_and(|unlocks_at| now < unlocks_at)
+            && groups
+                .iter()
+                .any(|group_id| self.canceller_group_ids.contains(group_id))
+    }
+}
+
+/// Warns the approvers that transfers of the same asset with a similar amount to the same destination
+/// are pending or were completed recently, which is usually the payment of the same invoice twice.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferDuplicateWarning {
+    /// The requests of the transfers that look like duplicates.
+    pub request_ids: Vec<RequestId>,
+}
+
+/// The screening of a transfer by an external screening service (e.g. a sanctions screening provider).
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferScreening {
+    /// The canister of the screening service that screens the transfer.
+    pub canister_id: Principal,
+    /// Whether the request can only be approved once the screening passed.
+    pub blocks_approval: bool,
+    pub status: TransferScreeningStatus,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferScreeningStatus {
+    /// The verdict of the screening service is pending, failed calls are retried with an exponential backoff.
+    Pending {
+        attempts: u32,
+        next_attempt_at: Timestamp,
+        last_error: Option<String>,
+    },
+    /// The screening service could not be reached after the maximum number of attempts.
+    Unavailable { error: String },
+    /// The screening service found no issues with the transfer.
+    Passed {
+        reference: Option<String>,
+        screened_at: Timestamp,
+    },
+    /// The screening service flagged the transfer (e.g. the destination is a sanctioned address).
+    Flagged {
+        reason: String,
+        reference: Option<String>,
+        screened_at: Timestamp,
+    },
+}
+
+impl TransferScreening {
+    pub const MAX_ATTEMPTS: u32 = 5;
+    pub const RETRY_BASE_DELAY_NS: u64 = 30 * 1_000_000_000;
+    pub const RETRY_MAX_DELAY_NS: u64 = 30 * 60 * 1_000_000_000;
+
+    pub fn new(canister_id: Principal, blocks_approval: bool, now: Timestamp) -> Self {
+        Self {
+            canister_id,
+            blocks_approval,
+            status: TransferScreeningStatus::Pending {
+                attempts: 0,
+                next_attempt_at: now,
+                last_error: None,
+            },
+        }
+    }
+
+    /// Returns the time of the next attempt to screen the transfer, if the verdict is still pending.
+    pub fn next_attempt_at(&self) -> Option<Timestamp> {
+        match &self.status {
+            TransferScreeningStatus::Pending {
+                next_attempt_at, ..
+            } => Some(*next_attempt_at),
+            _ => None,
+        }
+    }
+
+    /// Whether the request must wait for the screening to pass before it can be approved.
+    pub fn is_blocking_approval(&self) -> bool {
+        self.blocks_approval && !matches!(self.status, TransferScreeningStatus::Passed { .. })
+    }
+
+    /// Registers a failed call to the screening service, the screening becomes unavailable once the
+    /// maximum number of attempts is reached.
+    pub fn register_failed_attempt(&mut self, error: String, now: Timestamp) {
+        let TransferScreeningStatus::Pending { attempts, .. } = &self.status else {
+            return;
+        };
+
+        let attempts = attempts.saturating_add(1);
+        if attempts >= Self::MAX_ATTEMPTS {
+            self.status = TransferScreeningStatus::Unavailable { error };
+            return;
+        }
+
+        let delay = Self::RETRY_BASE_DELAY_NS
+            .saturating_mul(2u64.saturating_pow(attempts - 1))
+            .min(Self::RETRY_MAX_DELAY_NS);
+
+        self.status = TransferScreeningStatus::Pending {
+            attempts,
+            next_attempt_at: now.saturating_add(delay),
+            last_error: Some(error),
+        };
+    }
 }
 
 #[storable]
@@ -116,6 +435,9 @@ pub struct AddAccountOperationInput {
     pub transfer_permission: Allow,
     pub configs_request_policy: Option<RequestPolicyRule>,
     pub transfer_request_policy: Option<RequestPolicyRule>,
+    /// The network of the blockchain that the account holds its assets on, the main network if not set.
+    #[serde(default)]
+    pub network: Option<NetworkId>,
 }
 
 #[storable]
@@ -134,6 +456,118 @@ pub struct EditAccountOperationInput {
     pub transfer_permission: Option<Allow>,
     pub configs_request_policy: Option<RequestPolicyRuleInput>,
     pub transfer_request_policy: Option<RequestPolicyRuleInput>,
+    /// The version of the record that the edit applies to, the edit fails if the record changed since.
+    #[serde(default)]
+    pub expected_version: Option<u64>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RotateAccountAddressOperation {
+    pub input: RotateAccountAddressOperationInput,
+    /// The address that replaced the current one, set once the request is executed.
+    pub new_address: Option<String>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RotateAccountAddressOperationInput {
+    pub account_id: AccountId,
+    /// How long the retired address can still be spent from and is still watched for deposits.
+    pub grace_period_secs: u64,
+}
+
+/// A user or user group that holds the permissions of an account.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountOwner {
+    User(UserId),
+    UserGroup(UserGroupId),
+}
+
+impl Display for AccountOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountOwner::User(user_id) => {
+                write!(f, "user {}", Uuid::from_bytes(*user_id).hyphenated())
+            }
+            AccountOwner::UserGroup(group_id) => {
+                write!(f, "user group {}", Uuid::from_bytes(*group_id).hyphenated())
+            }
+        }
+    }
+}
+
+impl AccountOwner {
+    /// Returns the users that can act on behalf of the owner, that is the user or the members of the user group.
+    pub fn to_user_specifier(&self) -> UserSpecifier {
+        match self {
+            AccountOwner::User(user_id) => UserSpecifier::Id(vec![*user_id]),
+            AccountOwner::UserGroup(group_id) => UserSpecifier::Group(vec![*group_id]),
+        }
+    }
+
+    /// Whether the user is the owner or a member of the owner user group.
+    pub fn is_represented_by(&self, user: &User) -> bool {
+        match self {
+            AccountOwner::User(user_id) => user.id == *user_id,
+            AccountOwner::UserGroup(group_id) => user.groups.contains(group_id),
+        }
+    }
+
+    /// Whether the owner is named in the permission.
+    pub fn is_allowed_by(&self, allow: &Allow) -> bool {
+        match self {
+            AccountOwner::User(user_id) => allow.users.contains(user_id),
+            AccountOwner::UserGroup(group_id) => allow.user_groups.contains(group_id),
+        }
+    }
+
+    /// Replaces the owner by the given one in the permission, which is left untouched if the owner
+    /// isn't named in it.
+    pub fn hand_over(&self, to: &AccountOwner, allow: &mut Allow) {
+        if !self.is_allowed_by(allow) {
+            return;
+        }
+
+        match self {
+            AccountOwner::User(user_id) => allow.users.retain(|id| id != user_id),
+            AccountOwner::UserGroup(group_id) => allow.user_groups.retain(|id| id != group_id),
+        }
+
+        if !to.is_allowed_by(allow) {
+            match to {
+                AccountOwner::User(user_id) => allow.users.push(*user_id),
+                AccountOwner::UserGroup(group_id) => allow.user_groups.push(*group_id),
+            }
+        }
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferAccountOwnershipOperation {
+    pub input: TransferAccountOwnershipOperationInput,
+    /// The user that acknowledged the handover on behalf of the receiving party, set once the request is executed.
+    pub acknowledged_by: Option<UserId>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferAccountOwnershipOperationInput {
+    pub account_id: AccountId,
+    /// The owner that hands over its permissions on the account.
+    pub from: AccountOwner,
+    /// The owner that receives the permissions on the account.
+    pub to: AccountOwner,
+}
+
+impl TransferAccountOwnershipOperationInput {
+    /// The rule that requires the receiving party to acknowledge the handover, which is added to each
+    /// of the policies that match the request.
+    pub fn acknowledgment_rule(&self) -> RequestPolicyRule {
+        RequestPolicyRule::Quorum(self.to.to_user_specifier(), 1)
+    }
 }
 
 #[storable]
@@ -197,6 +631,8 @@ pub struct AddUserOperationInput {
     pub identities: Vec<Principal>,
     pub groups: Vec<UUID>,
     pub status: UserStatus,
+    #[serde(default)]
+    pub scheduled_memberships: Vec<ScheduledGroupMembership>,
 }
 
 #[storable]
@@ -213,7 +649,13 @@ pub struct EditUserOperationInput {
     pub identities: Option<Vec<Principal>>,
     pub groups: Option<Vec<UUID>>,
     pub status: Option<UserStatus>,
+    /// Replaces the scheduled memberships of the user.
+    #[serde(default)]
+    pub scheduled_memberships: Option<Vec<ScheduledGroupMembership>>,
     pub cancel_pending_requests: Option<bool>,
+    /// The version of the record that the edit applies to, the edit fails if the record changed since.
+    #[serde(default)]
+    pub expected_version: Option<u64>,
 }
 
 #[storable]
@@ -227,6 +669,8 @@ pub struct AddUserGroupOperation {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddUserGroupOperationInput {
     pub name: String,
+    #[serde(default)]
+    pub quotas: UserGroupQuotas,
 }
 
 #[storable]
@@ -240,18 +684,29 @@ pub struct EditUserGroupOperation {
 pub struct EditUserGroupOperationInput {
     pub user_group_id: UUID,
     pub name: String,
+    /// The quotas of the group are left unchanged if not set.
+    #[serde(default)]
+    pub quotas: Option<UserGroupQuotas>,
 }
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct RemoveUserGroupOperation {
     pub input: RemoveUserGroupOperationInput,
+    /// The entities that referenced the group when the request was created, replaced by the ones that
+    /// were reassigned once the request is executed.
+    #[serde(default)]
+    pub affected: UserGroupReferences,
 }
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct RemoveUserGroupOperationInput {
     pub user_group_id: UUID,
+    /// How the references to the group are handled, a group that is still referenced can't be removed
+    /// without it.
+    #[serde(default)]
+    pub reassignment: Option<UserGroupReassignment>,
 }
 
 #[storable]
@@ -268,6 +723,17 @@ pub struct SystemUpgradeOperationInput {
     /// The module is only available while the operation is not finalized.
     pub module: Vec<u8>,
     pub arg: Option<Vec<u8>>,
+    /// The current values of the station that are injected into the arg when the upgrade is executed.
+    #[serde(default)]
+    pub arg_injections: Vec<SystemUpgradeArgInjection>,
+}
+
+/// A value of the current state of the station that is injected into the upgrade arg at execution
+/// time, so that a request that stays open for long doesn't overwrite the configuration that changed since.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SystemUpgradeArgInjection {
+    LedgerCanisters,
 }
 
 #[storable]
@@ -276,6 +742,31 @@ pub struct SystemUpgradeOperation {
     pub module_checksum: Vec<u8>,
     pub arg_checksum: Option<Vec<u8>>,
     pub input: SystemUpgradeOperationInput,
+    /// Not set for the requests created before the diff was computed.
+    #[serde(default)]
+    pub upgrade_diff: Option<CanisterUpgradeDiff>,
+}
+
+/// The facts of a module change that are computed when the request is created, so that the approvers
+/// can compare the proposed module with the module that is installed.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanisterUpgradeDiff {
+    /// The state of the canister when the request was created, not set if it could not be read.
+    pub current_state: Option<CanisterCurrentState>,
+    /// The size of the proposed module in bytes, not set if the module was uploaded in chunks.
+    pub module_size: Option<u64>,
+    pub arg_empty: bool,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanisterCurrentState {
+    /// Not set if no module is installed.
+    pub module_hash: Option<Vec<u8>>,
+    /// The settings are kept by the module change, they are not set if the station is not a controller
+    /// of the canister.
+    pub settings: Option<NativeCanisterSettings>,
 }
 
 #[storable]
@@ -327,6 +818,22 @@ pub struct ChangeExternalCanisterOperationInput {
     pub mode: CanisterInstallMode,
     pub module: Vec<u8>,
     pub arg: Option<Vec<u8>>,
+    /// The module that was uploaded to a chunk store, `module` is empty if it is set.
+    #[serde(default)]
+    pub module_chunks: Option<ChunkedWasmModule>,
+}
+
+/// A wasm module that was uploaded in chunks to the chunk store of a canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ChunkedWasmModule {
+    /// The canister whose chunk store holds the chunks, it must be on the subnet of the target
+    /// canister and be controlled by the station.
+    pub store_canister: Principal,
+    /// The sha256 hashes of the chunks in the order of the module.
+    pub chunk_hashes: Vec<Vec<u8>>,
+    /// The sha256 hash of the whole module.
+    pub wasm_module_hash: Vec<u8>,
 }
 
 #[storable]
@@ -335,6 +842,9 @@ pub struct ChangeExternalCanisterOperation {
     pub module_checksum: Vec<u8>,
     pub arg_checksum: Option<Vec<u8>>,
     pub input: ChangeExternalCanisterOperationInput,
+    /// Not set for the requests created before the diff was computed.
+    #[serde(default)]
+    pub upgrade_diff: Option<CanisterUpgradeDiff>,
 }
 
 #[storable]
@@ -396,6 +906,8 @@ pub struct CreateExternalCanisterOperationInput {
     pub labels: Option<Vec<String>>,
     pub permissions: ExternalCanisterPermissionsInput,
     pub request_policies: ExternalCanisterRequestPoliciesInput,
+    #[serde(default)]
+    pub repository_url: Option<String>,
 }
 
 #[storable]
@@ -428,13 +940,14 @@ pub type FundExternalCanisterOperation = FundExternalCanisterOperationInput;
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct ConfigureExternalCanisterOperationInput {
+pub struct ConfigureExternalCanisterOperation {
     pub canister_id: Principal,
     pub kind: ConfigureExternalCanisterOperationKind,
+    /// The changes of the native settings, compared to the settings of the canister when the request was created.
+    #[serde(default)]
+    pub native_settings_diff: Option<Vec<NativeSettingsChange>>,
 }
 
-pub type ConfigureExternalCanisterOperation = ConfigureExternalCanisterOperationInput;
-
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ConfigureExternalCanisterOperationKind {
@@ -452,6 +965,48 @@ pub struct DefiniteCanisterSettingsInput {
     pub memory_allocation: Option<candid::Nat>,
     pub freezing_threshold: Option<candid::Nat>,
     pub reserved_cycles_limit: Option<candid::Nat>,
+    #[serde(default)]
+    pub log_visibility: Option<LogVisibility>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LogVisibility {
+    Controllers,
+    Public,
+    AllowedViewers(Vec<Principal>),
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ControllersChange {
+    pub added: Vec<Principal>,
+    pub removed: Vec<Principal>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NatSettingChange {
+    pub current: candid::Nat,
+    pub proposed: candid::Nat,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LogVisibilityChange {
+    pub current: Option<LogVisibility>,
+    pub proposed: LogVisibility,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NativeSettingsChange {
+    Controllers(ControllersChange),
+    ComputeAllocation(NatSettingChange),
+    MemoryAllocation(NatSettingChange),
+    FreezingThreshold(NatSettingChange),
+    ReservedCyclesLimit(NatSettingChange),
+    LogVisibility(LogVisibilityChange),
 }
 
 #[storable]
@@ -463,6 +1018,8 @@ pub struct ConfigureExternalCanisterSettingsInput {
     pub state: Option<ExternalCanisterState>,
     pub permissions: Option<ExternalCanisterPermissionsInput>,
     pub request_policies: Option<ExternalCanisterRequestPoliciesInput>,
+    #[serde(default)]
+    pub repository_url: Option<String>,
 }
 
 #[storable]
@@ -502,6 +1059,69 @@ pub struct CallExternalCanisterOperation {
     pub execution_method_reply: Option<Vec<u8>>,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CommitAssetBatchOperationInput {
+    pub canister_id: Principal,
+    pub batch_id: candid::Nat,
+    pub evidence: Vec<u8>,
+}
+
+impl CommitAssetBatchOperationInput {
+    /// The method of the asset canister that checks the evidence of the proposed batch.
+    pub const VALIDATION_METHOD: &'static str = "validate_commit_proposed_batch";
+    /// The method of the asset canister that commits the proposed batch.
+    pub const EXECUTION_METHOD: &'static str = "commit_proposed_batch";
+
+    pub fn validation_method(&self) -> CanisterMethod {
+        CanisterMethod {
+            canister_id: self.canister_id,
+            method_name: Self::VALIDATION_METHOD.to_string(),
+        }
+    }
+
+    pub fn execution_method(&self) -> CanisterMethod {
+        CanisterMethod {
+            canister_id: self.canister_id,
+            method_name: Self::EXECUTION_METHOD.to_string(),
+        }
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CommitAssetBatchOperation {
+    pub input: CommitAssetBatchOperationInput,
+    /// The rendering of the evidence by the asset canister when the request was created.
+    pub evidence_rendering: Option<String>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SnapshotExternalCanisterOperationKind {
+    /// Takes a snapshot of the canister, replacing the given snapshot if any.
+    Take { replace_snapshot: Option<Vec<u8>> },
+    /// Loads the snapshot into the canister.
+    Load { snapshot_id: Vec<u8> },
+    /// Deletes the snapshot.
+    Delete { snapshot_id: Vec<u8> },
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SnapshotExternalCanisterOperationInput {
+    pub canister_id: Principal,
+    pub kind: SnapshotExternalCanisterOperationKind,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SnapshotExternalCanisterOperation {
+    pub input: SnapshotExternalCanisterOperationInput,
+    /// The id of the snapshot that was taken, set once the request is executed.
+    pub snapshot_id: Option<Vec<u8>>,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditPermissionOperationInput {
@@ -517,11 +1137,47 @@ pub struct EditPermissionOperation {
     pub input: EditPermissionOperationInput,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BulkEditPermissionOperationInput {
+    /// The changes of the permissions, which are applied all at once so that the permissions never
+    /// end up in an intermediate state.
+    pub changes: Vec<EditPermissionOperationInput>,
+}
+
+impl BulkEditPermissionOperationInput {
+    pub const MAX_CHANGES: usize = 100;
+}
+
+/// The change of the permission of a resource, as shown to the reviewers of the request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PermissionChange {
+    pub resource: Resource,
+    pub before: Allow,
+    pub after: Allow,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BulkEditPermissionOperation {
+    pub input: BulkEditPermissionOperationInput,
+    /// The permissions of the changed resources before and after the changes, as of the creation of
+    /// the request.
+    pub matrix: Vec<PermissionChange>,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddRequestPolicyOperationInput {
     pub specifier: RequestSpecifier,
     pub rule: RequestPolicyRule,
+    /// The checks that the approvers must acknowledge when approving the requests of the policy.
+    #[serde(default)]
+    pub checklist: Vec<String>,
+    /// Whether the approval of the requester never counts toward the quorums of the policy.
+    #[serde(default)]
+    pub exclude_requester: bool,
 }
 
 #[storable]
@@ -537,6 +1193,13 @@ pub struct EditRequestPolicyOperationInput {
     pub policy_id: UUID,
     pub specifier: Option<RequestSpecifier>,
     pub rule: Option<RequestPolicyRule>,
+    #[serde(default)]
+    pub checklist: Option<Vec<String>>,
+    #[serde(default)]
+    pub exclude_requester: Option<bool>,
+    /// The version of the record that the edit applies to, the edit fails if the record changed since.
+    #[serde(default)]
+    pub expected_version: Option<u64>,
 }
 
 #[storable]
@@ -562,6 +1225,60 @@ pub struct RemoveRequestPolicyOperation {
 pub struct ManageSystemInfoOperationInput {
     pub name: Option<String>,
     pub cycle_obtain_strategy: Option<CycleObtainStrategy>,
+    /// Replaces the principals with temporary read-only access to the station.
+    #[serde(default)]
+    pub auditors: Option<Vec<AuditorAccess>>,
+    /// Replaces the canisters whose published address book entries are synced into the address book.
+    #[serde(default)]
+    pub address_book_sources: Option<Vec<AddressBookSource>>,
+    /// Changes how the names of transfer destinations are resolved.
+    #[serde(default)]
+    pub name_service: Option<NameServiceConfig>,
+    /// Replaces the amounts from which transfers must include their travel rule information.
+    #[serde(default)]
+    pub transfer_compliance_thresholds: Option<Vec<TransferComplianceThreshold>>,
+    /// Replaces the highest fees that transfers of the assets can pay.
+    #[serde(default)]
+    pub transfer_max_fees: Option<Vec<TransferMaxFee>>,
+    /// Changes how transfers are screened before they can be executed.
+    #[serde(default)]
+    pub transfer_screening: Option<TransferScreeningConfig>,
+    /// Changes whether the approved transfers are held back before they are executed.
+    #[serde(default)]
+    pub transfer_time_lock: Option<TransferTimeLockConfig>,
+    /// Changes how the sensitive metadata is encrypted at rest.
+    #[serde(default)]
+    pub metadata_encryption: Option<MetadataEncryptionConfig>,
+    /// Changes how the data of old requests is compacted.
+    #[serde(default)]
+    pub data_compaction: Option<DataCompactionConfig>,
+    /// Changes how the old requests are moved to an archive canister.
+    #[serde(default)]
+    pub request_archival: Option<RequestArchivalConfig>,
+    /// Replaces the logos of the station and of its assets.
+    #[serde(default)]
+    pub branding: Option<BrandingConfig>,
+    /// Replaces the groups of accounts that are administered apart from the rest of the station.
+    #[serde(default)]
+    pub sub_treasuries: Option<Vec<SubTreasury>>,
+    /// Changes when the station refuses the calls that change its state.
+    #[serde(default)]
+    pub maintenance_mode: Option<MaintenanceModeConfig>,
+    /// Changes the length limits of the titles and summaries of new requests.
+    #[serde(default)]
+    pub request_text_limits: Option<RequestTextLimits>,
+    /// Changes when the approvers of pending requests are reminded.
+    #[serde(default)]
+    pub approval_reminders: Option<ApprovalReminderConfig>,
+    /// Changes how often the balances of the accounts are recorded.
+    #[serde(default)]
+    pub balance_snapshots: Option<BalanceSnapshotConfig>,
+    /// Changes where the fiat prices of the assets are taken from.
+    #[serde(default)]
+    pub fiat_valuation: Option<FiatValuationConfig>,
+    /// Replaces the operation types that are deprecated or disabled on the station.
+    #[serde(default)]
+    pub operation_flags: Option<Vec<OperationFlag>>,
 }
 
 #[storable]
@@ -569,3 +1286,99 @@ pub struct ManageSystemInfoOperationInput {
 pub struct ManageSystemInfoOperation {
     pub input: ManageSystemInfoOperationInput,
 }
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddNetworkOperation {
+    pub input: AddNetworkOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddNetworkOperationInput {
+    pub network: Network,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EditNetworkOperation {
+    pub input: EditNetworkOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EditNetworkOperationInput {
+    pub id: NetworkId,
+    pub blockchain: Blockchain,
+    pub name: Option<String>,
+    pub chain_id: Option<String>,
+    pub is_testnet: Option<bool>,
+}
+
+impl EditNetworkOperationInput {
+    /// Applies the changes of the input to the network.
+    pub fn apply(&self, network: &mut Network) {
+        if let Some(name) = &self.name {
+            network.name = name.clone();
+        }
+
+        if let Some(chain_id) = &self.chain_id {
+            network.chain_id = Some(chain_id.clone());
+        }
+
+        if let Some(is_testnet) = self.is_testnet {
+            network.is_testnet = is_testnet;
+        }
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FreezeBlockchainOperation {
+    pub input: FreezeBlockchainOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FreezeBlockchainOperationInput {
+    pub blockchain: Blockchain,
+    /// The network whose accounts are frozen, all the networks of the blockchain if not set.
+    pub network: Option<NetworkId>,
+    /// The reason of the freeze (e.g. a link to the incident of the RPC providers of the blockchain).
+    pub reason: Option<String>,
+}
+
+impl FreezeBlockchainOperationInput {
+    pub const MAX_REASON_LEN: usize = 200;
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnfreezeBlockchainOperation {
+    pub input: UnfreezeBlockchainOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnfreezeBlockchainOperationInput {
+    pub blockchain: Blockchain,
+    /// The network of the freeze that is lifted, the freeze of all the networks of the blockchain if not set.
+    pub network: Option<NetworkId>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DecommissionStationOperation {
+    pub input: DecommissionStationOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DecommissionStationOperationInput {
+    /// The reason that is returned to the callers whose calls are refused once the station is decommissioned.
+    pub reason: Option<String>,
+}
+
+impl DecommissionStationOperationInput {
+    pub const MAX_REASON_LEN: usize = 200;
+}
@@ -8,6 +8,8 @@ pub enum RequestOperationFilterType {
     Transfer(AccountId),
     AddAccount,
     EditAccount,
+    RotateAccountAddress,
+    TransferAccountOwnership,
     AddUser,
     EditUser,
     AddUserGroup,
@@ -19,6 +21,7 @@ pub enum RequestOperationFilterType {
     CreateExternalCanister,
     CallExternalCanister(Principal),
     EditPermission,
+    BulkEditPermission,
     AddRequestPolicy,
     EditRequestPolicy,
     RemoveRequestPolicy,
@@ -26,8 +29,15 @@ pub enum RequestOperationFilterType {
     EditAddressBookEntry,
     RemoveAddressBookEntry,
     ManageSystemInfo,
+    AddNetwork,
+    EditNetwork,
+    FreezeBlockchain,
+    UnfreezeBlockchain,
+    DecommissionStation,
     ConfigureExternalCanister(Principal),
     FundExternalCanister(Principal),
+    CommitAssetBatch(Principal),
+    SnapshotExternalCanister(Principal),
 }
 
 impl From<RequestOperation> for RequestOperationFilterType {
@@ -38,6 +48,12 @@ impl From<RequestOperation> for RequestOperationFilterType {
             }
             RequestOperation::AddAccount(_) => RequestOperationFilterType::AddAccount,
             RequestOperation::EditAccount(_) => RequestOperationFilterType::EditAccount,
+            RequestOperation::RotateAccountAddress(_) => {
+                RequestOperationFilterType::RotateAccountAddress
+            }
+            RequestOperation::TransferAccountOwnership(_) => {
+                RequestOperationFilterType::TransferAccountOwnership
+            }
             RequestOperation::AddUser(_) => RequestOperationFilterType::AddUser,
             RequestOperation::EditUser(_) => RequestOperationFilterType::EditUser,
             RequestOperation::AddUserGroup(_) => RequestOperationFilterType::AddUserGroup,
@@ -59,6 +75,9 @@ impl From<RequestOperation> for RequestOperationFilterType {
                 )
             }
             RequestOperation::EditPermission(_) => RequestOperationFilterType::EditPermission,
+            RequestOperation::BulkEditPermission(_) => {
+                RequestOperationFilterType::BulkEditPermission
+            }
             RequestOperation::AddRequestPolicy(_) => RequestOperationFilterType::AddRequestPolicy,
             RequestOperation::EditRequestPolicy(_) => RequestOperationFilterType::EditRequestPolicy,
             RequestOperation::RemoveRequestPolicy(_) => {
@@ -74,12 +93,27 @@ impl From<RequestOperation> for RequestOperationFilterType {
                 RequestOperationFilterType::RemoveAddressBookEntry
             }
             RequestOperation::ManageSystemInfo(_) => RequestOperationFilterType::ManageSystemInfo,
+            RequestOperation::AddNetwork(_) => RequestOperationFilterType::AddNetwork,
+            RequestOperation::EditNetwork(_) => RequestOperationFilterType::EditNetwork,
+            RequestOperation::FreezeBlockchain(_) => RequestOperationFilterType::FreezeBlockchain,
+            RequestOperation::UnfreezeBlockchain(_) => {
+                RequestOperationFilterType::UnfreezeBlockchain
+            }
+            RequestOperation::DecommissionStation(_) => {
+                RequestOperationFilterType::DecommissionStation
+            }
             RequestOperation::ConfigureExternalCanister(operation) => {
                 RequestOperationFilterType::ConfigureExternalCanister(operation.canister_id)
             }
             RequestOperation::FundExternalCanister(operation) => {
                 RequestOperationFilterType::FundExternalCanister(operation.canister_id)
             }
+            RequestOperation::CommitAssetBatch(operation) => {
+                RequestOperationFilterType::CommitAssetBatch(operation.input.canister_id)
+            }
+            RequestOperation::SnapshotExternalCanister(operation) => {
+                RequestOperationFilterType::SnapshotExternalCanister(operation.input.canister_id)
+            }
         }
     }
 }
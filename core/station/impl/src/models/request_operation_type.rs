@@ -33,6 +33,16 @@ pub enum RequestOperationType {
     SetDisasterRecovery = 23,
     ConfigureExternalCanister = 24,
     FundExternalCanister = 25,
+    CommitAssetBatch = 26,
+    SnapshotExternalCanister = 27,
+    RotateAccountAddress = 28,
+    AddNetwork = 29,
+    EditNetwork = 30,
+    DecommissionStation = 31,
+    TransferAccountOwnership = 32,
+    FreezeBlockchain = 33,
+    UnfreezeBlockchain = 34,
+    BulkEditPermission = 35,
 }
 
 /// A helper enum to filter the requests based on the operation type and
@@ -42,6 +52,8 @@ pub enum ListRequestsOperationType {
     Transfer(Option<AccountId>),
     AddAccount,
     EditAccount,
+    RotateAccountAddress,
+    TransferAccountOwnership,
     AddUser,
     EditUser,
     AddUserGroup,
@@ -54,7 +66,10 @@ pub enum ListRequestsOperationType {
     CallExternalCanister(Option<Principal>),
     ConfigureExternalCanister(Option<Principal>),
     FundExternalCanister(Option<Principal>),
+    CommitAssetBatch(Option<Principal>),
+    SnapshotExternalCanister(Option<Principal>),
     EditPermission,
+    BulkEditPermission,
     AddRequestPolicy,
     EditRequestPolicy,
     RemoveRequestPolicy,
@@ -62,6 +77,11 @@ pub enum ListRequestsOperationType {
     EditAddressBookEntry,
     RemoveAddressBookEntry,
     ManageSystemInfo,
+    AddNetwork,
+    EditNetwork,
+    FreezeBlockchain,
+    UnfreezeBlockchain,
+    DecommissionStation,
 }
 
 impl PartialEq<ListRequestsOperationType> for RequestOperationFilterType {
@@ -79,6 +99,12 @@ impl PartialEq<ListRequestsOperationType> for RequestOperationFilterType {
             ListRequestsOperationType::EditAccount => {
                 matches!(self, RequestOperationFilterType::EditAccount)
             }
+            ListRequestsOperationType::RotateAccountAddress => {
+                matches!(self, RequestOperationFilterType::RotateAccountAddress)
+            }
+            ListRequestsOperationType::TransferAccountOwnership => {
+                matches!(self, RequestOperationFilterType::TransferAccountOwnership)
+            }
             ListRequestsOperationType::AddUser => {
                 matches!(self, RequestOperationFilterType::AddUser)
             }
@@ -140,9 +166,33 @@ impl PartialEq<ListRequestsOperationType> for RequestOperationFilterType {
                     RequestOperationFilterType::FundExternalCanister(id) if id == canister_id
                 )
             }
+            ListRequestsOperationType::CommitAssetBatch(None) => {
+                matches!(self, RequestOperationFilterType::CommitAssetBatch(_))
+            }
+            ListRequestsOperationType::CommitAssetBatch(Some(canister_id)) => {
+                matches!(
+                    self,
+                    RequestOperationFilterType::CommitAssetBatch(id) if id == canister_id
+                )
+            }
+            ListRequestsOperationType::SnapshotExternalCanister(None) => {
+                matches!(
+                    self,
+                    RequestOperationFilterType::SnapshotExternalCanister(_)
+                )
+            }
+            ListRequestsOperationType::SnapshotExternalCanister(Some(canister_id)) => {
+                matches!(
+                    self,
+                    RequestOperationFilterType::SnapshotExternalCanister(id) if id == canister_id
+                )
+            }
             ListRequestsOperationType::EditPermission => {
                 matches!(self, RequestOperationFilterType::EditPermission)
             }
+            ListRequestsOperationType::BulkEditPermission => {
+                matches!(self, RequestOperationFilterType::BulkEditPermission)
+            }
             ListRequestsOperationType::AddRequestPolicy => {
                 matches!(self, RequestOperationFilterType::AddRequestPolicy)
             }
@@ -164,6 +214,21 @@ impl PartialEq<ListRequestsOperationType> for RequestOperationFilterType {
             ListRequestsOperationType::ManageSystemInfo => {
                 matches!(self, RequestOperationFilterType::ManageSystemInfo)
             }
+            ListRequestsOperationType::AddNetwork => {
+                matches!(self, RequestOperationFilterType::AddNetwork)
+            }
+            ListRequestsOperationType::EditNetwork => {
+                matches!(self, RequestOperationFilterType::EditNetwork)
+            }
+            ListRequestsOperationType::FreezeBlockchain => {
+                matches!(self, RequestOperationFilterType::FreezeBlockchain)
+            }
+            ListRequestsOperationType::UnfreezeBlockchain => {
+                matches!(self, RequestOperationFilterType::UnfreezeBlockchain)
+            }
+            ListRequestsOperationType::DecommissionStation => {
+                matches!(self, RequestOperationFilterType::DecommissionStation)
+            }
         }
     }
 }
@@ -176,6 +241,8 @@ impl FromStr for RequestOperationType {
             "transfer" => Ok(RequestOperationType::Transfer),
             "add_account" => Ok(RequestOperationType::AddAccount),
             "edit_account" => Ok(RequestOperationType::EditAccount),
+            "rotate_account_address" => Ok(RequestOperationType::RotateAccountAddress),
+            "transfer_account_ownership" => Ok(RequestOperationType::TransferAccountOwnership),
             "add_address_book_entry" => Ok(RequestOperationType::AddAddressBookEntry),
             "edit_address_book_entry" => Ok(RequestOperationType::EditAddressBookEntry),
             "remove_address_book_entry" => Ok(RequestOperationType::RemoveAddressBookEntry),
@@ -189,13 +256,21 @@ impl FromStr for RequestOperationType {
             "create_external_canister" => Ok(RequestOperationType::CreateExternalCanister),
             "call_external_canister" => Ok(RequestOperationType::CallExternalCanister),
             "edit_permission" => Ok(RequestOperationType::EditPermission),
+            "bulk_edit_permission" => Ok(RequestOperationType::BulkEditPermission),
             "add_request_policy" => Ok(RequestOperationType::AddRequestPolicy),
             "edit_request_policy" => Ok(RequestOperationType::EditRequestPolicy),
             "remove_request_policy" => Ok(RequestOperationType::RemoveRequestPolicy),
             "manage_system_info" => Ok(RequestOperationType::ManageSystemInfo),
+            "add_network" => Ok(RequestOperationType::AddNetwork),
+            "edit_network" => Ok(RequestOperationType::EditNetwork),
+            "freeze_blockchain" => Ok(RequestOperationType::FreezeBlockchain),
+            "unfreeze_blockchain" => Ok(RequestOperationType::UnfreezeBlockchain),
+            "decommission_station" => Ok(RequestOperationType::DecommissionStation),
             "set_disaster_recovery_committee" => Ok(RequestOperationType::SetDisasterRecovery),
             "configure_external_canister" => Ok(RequestOperationType::ConfigureExternalCanister),
             "fund_external_canister" => Ok(RequestOperationType::FundExternalCanister),
+            "commit_asset_batch" => Ok(RequestOperationType::CommitAssetBatch),
+            "snapshot_external_canister" => Ok(RequestOperationType::SnapshotExternalCanister),
             _ => Err(()),
         }
     }
@@ -207,6 +282,10 @@ impl Display for RequestOperationType {
             RequestOperationType::Transfer => write!(f, "transfer"),
             RequestOperationType::AddAccount => write!(f, "add_account"),
             RequestOperationType::EditAccount => write!(f, "edit_account"),
+            RequestOperationType::RotateAccountAddress => write!(f, "rotate_account_address"),
+            RequestOperationType::TransferAccountOwnership => {
+                write!(f, "transfer_account_ownership")
+            }
             RequestOperationType::AddAddressBookEntry => write!(f, "add_address_book_entry"),
             RequestOperationType::EditAddressBookEntry => write!(f, "edit_address_book_entry"),
             RequestOperationType::RemoveAddressBookEntry => write!(f, "remove_address_book_entry"),
@@ -220,10 +299,16 @@ impl Display for RequestOperationType {
             RequestOperationType::CreateExternalCanister => write!(f, "create_external_canister"),
             RequestOperationType::CallExternalCanister => write!(f, "call_external_canister"),
             RequestOperationType::EditPermission => write!(f, "edit_permission"),
+            RequestOperationType::BulkEditPermission => write!(f, "bulk_edit_permission"),
             RequestOperationType::AddRequestPolicy => write!(f, "add_request_policy"),
             RequestOperationType::EditRequestPolicy => write!(f, "edit_request_policy"),
             RequestOperationType::RemoveRequestPolicy => write!(f, "remove_request_policy"),
             RequestOperationType::ManageSystemInfo => write!(f, "manage_system_info"),
+            RequestOperationType::AddNetwork => write!(f, "add_network"),
+            RequestOperationType::EditNetwork => write!(f, "edit_network"),
+            RequestOperationType::FreezeBlockchain => write!(f, "freeze_blockchain"),
+            RequestOperationType::UnfreezeBlockchain => write!(f, "unfreeze_blockchain"),
+            RequestOperationType::DecommissionStation => write!(f, "decommission_station"),
             RequestOperationType::SetDisasterRecovery => {
                 write!(f, "set_disaster_recovery_committee")
             }
@@ -231,6 +316,10 @@ impl Display for RequestOperationType {
                 write!(f, "configure_external_canister")
             }
             RequestOperationType::FundExternalCanister => write!(f, "fund_external_canister"),
+            RequestOperationType::CommitAssetBatch => write!(f, "commit_asset_batch"),
+            RequestOperationType::SnapshotExternalCanister => {
+                write!(f, "snapshot_external_canister")
+            }
         }
     }
 }
@@ -337,6 +426,14 @@ mod tests {
             RequestOperationType::from_str("edit_permission").unwrap(),
             RequestOperationType::EditPermission
         );
+        assert_eq!(
+            RequestOperationType::BulkEditPermission.to_string(),
+            "bulk_edit_permission"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("bulk_edit_permission").unwrap(),
+            RequestOperationType::BulkEditPermission
+        );
         assert_eq!(
             RequestOperationType::from_str("add_request_policy").unwrap(),
             RequestOperationType::AddRequestPolicy
@@ -365,5 +462,74 @@ mod tests {
             RequestOperationType::from_str("fund_external_canister").unwrap(),
             RequestOperationType::FundExternalCanister
         );
+        assert_eq!(
+            RequestOperationType::CommitAssetBatch.to_string(),
+            "commit_asset_batch"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("commit_asset_batch").unwrap(),
+            RequestOperationType::CommitAssetBatch
+        );
+        assert_eq!(
+            RequestOperationType::SnapshotExternalCanister.to_string(),
+            "snapshot_external_canister"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("snapshot_external_canister").unwrap(),
+            RequestOperationType::SnapshotExternalCanister
+        );
+        assert_eq!(
+            RequestOperationType::RotateAccountAddress.to_string(),
+            "rotate_account_address"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("rotate_account_address").unwrap(),
+            RequestOperationType::RotateAccountAddress
+        );
+        assert_eq!(
+            RequestOperationType::TransferAccountOwnership.to_string(),
+            "transfer_account_ownership"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("transfer_account_ownership").unwrap(),
+            RequestOperationType::TransferAccountOwnership
+        );
+        assert_eq!(RequestOperationType::AddNetwork.to_string(), "add_network");
+        assert_eq!(
+            RequestOperationType::from_str("add_network").unwrap(),
+            RequestOperationType::AddNetwork
+        );
+        assert_eq!(
+            RequestOperationType::EditNetwork.to_string(),
+            "edit_network"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("edit_network").unwrap(),
+            RequestOperationType::EditNetwork
+        );
+        assert_eq!(
+            RequestOperationType::DecommissionStation.to_string(),
+            "decommission_station"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("decommission_station").unwrap(),
+            RequestOperationType::DecommissionStation
+        );
+        assert_eq!(
+            RequestOperationType::FreezeBlockchain.to_string(),
+            "freeze_blockchain"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("freeze_blockchain").unwrap(),
+            RequestOperationType::FreezeBlockchain
+        );
+        assert_eq!(
+            RequestOperationType::UnfreezeBlockchain.to_string(),
+            "unfreeze_blockchain"
+        );
+        assert_eq!(
+            RequestOperationType::from_str("unfreeze_blockchain").unwrap(),
+            RequestOperationType::UnfreezeBlockchain
+        );
     }
 }
@@ -0,0 +1,773 @@
+use super::{
+    resource::{ExecutionMethodResourceTarget, ValidationMethodResourceTarget},
+    Account, AccountOwner, AddAccountOperation, AddAddressBookEntryOperation, AddNetworkOperation,
+    AddRequestPolicyOperation, AddUserGroupOperation, AddUserOperation,
+    BulkEditPermissionOperation, BulkEditPermissionOperationInput, CallExternalCanisterOperation,
+    ChangeExternalCanisterOperation, CommitAssetBatchOperation, ConfigureExternalCanisterOperation,
+    CreateExternalCanisterOperation, DecommissionStationOperation,
+    DecommissionStationOperationInput, EditAccountOperation, EditAddressBookEntryOperation,
+    EditNetworkOperation, EditPermissionOperation, EditRequestPolicyOperation,
+    EditUserGroupOperation, EditUserOperation, FreezeBlockchainOperation,
+    FreezeBlockchainOperationInput, FundExternalCanisterOperation,
+    FundExternalCanisterOperationKind, ManageSystemInfoOperation, RemoveAddressBookEntryOperation,
+    RemoveRequestPolicyOperation, RemoveUserGroupOperation, RequestOperation,
+    RequestPolicyRuleInput, RotateAccountAddressOperation, SetDisasterRecoveryOperation,
+    SnapshotExternalCanisterOperation, SystemUpgradeOperation, TransferAccountOwnershipOperation,
+    TransferOperation, TransferTimeLockConfig, UnfreezeBlockchainOperation, UserGroupReassignment,
+};
+use crate::core::validation::{
+    EnsureAccount, EnsureAddressBookEntry, EnsureExternalCanister, EnsureIdExists,
+    EnsureRequestPolicy, EnsureUser, EnsureUserGroup, OperationFieldErrors, ValidateOperationInput,
+};
+use crate::core::{read_system_info, ASSETS};
+use crate::repositories::{permission::PERMISSION_REPOSITORY, ACCOUNT_REPOSITORY};
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::repository::Repository;
+
+impl ValidateOperationInput for RequestOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        match self {
+            RequestOperation::Transfer(op) => op.validate_input(errors),
+            RequestOperation::AddAccount(op) => op.validate_input(errors),
+            RequestOperation::EditAccount(op) => op.validate_input(errors),
+            RequestOperation::RotateAccountAddress(op) => op.validate_input(errors),
+            RequestOperation::TransferAccountOwnership(op) => op.validate_input(errors),
+            RequestOperation::AddAddressBookEntry(op) => op.validate_input(errors),
+            RequestOperation::EditAddressBookEntry(op) => op.validate_input(errors),
+            RequestOperation::RemoveAddressBookEntry(op) => op.validate_input(errors),
+            RequestOperation::AddUser(op) => op.validate_input(errors),
+            RequestOperation::EditUser(op) => op.validate_input(errors),
+            RequestOperation::EditPermission(op) => op.validate_input(errors),
+            RequestOperation::BulkEditPermission(op) => op.validate_input(errors),
+            RequestOperation::AddUserGroup(op) => op.validate_input(errors),
+            RequestOperation::EditUserGroup(op) => op.validate_input(errors),
+            RequestOperation::RemoveUserGroup(op) => op.validate_input(errors),
+            RequestOperation::SystemUpgrade(op) => op.validate_input(errors),
+            RequestOperation::ChangeExternalCanister(op) => op.validate_input(errors),
+            RequestOperation::ConfigureExternalCanister(op) => op.validate_input(errors),
+            RequestOperation::CreateExternalCanister(op) => op.validate_input(errors),
+            RequestOperation::CallExternalCanister(op) => op.validate_input(errors),
+            RequestOperation::FundExternalCanister(op) => op.validate_input(errors),
+            RequestOperation::CommitAssetBatch(op) => op.validate_input(errors),
+            RequestOperation::SnapshotExternalCanister(op) => op.validate_input(errors),
+            RequestOperation::AddRequestPolicy(op) => op.validate_input(errors),
+            RequestOperation::EditRequestPolicy(op) => op.validate_input(errors),
+            RequestOperation::RemoveRequestPolicy(op) => op.validate_input(errors),
+            RequestOperation::ManageSystemInfo(op) => op.validate_input(errors),
+            RequestOperation::SetDisasterRecovery(op) => op.validate_input(errors),
+            RequestOperation::AddNetwork(op) => op.validate_input(errors),
+            RequestOperation::EditNetwork(op) => op.validate_input(errors),
+            RequestOperation::FreezeBlockchain(op) => op.validate_input(errors),
+            RequestOperation::UnfreezeBlockchain(op) => op.validate_input(errors),
+            RequestOperation::DecommissionStation(op) => op.validate_input(errors),
+        }
+    }
+}
+
+impl ValidateOperationInput for TransferOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "from_account_id",
+            EnsureAccount::id_exists(&self.input.from_account_id),
+        );
+
+        if self.input.to.is_empty() {
+            errors.add("to", "The destination address must not be empty.");
+        }
+
+        if self.input.amount == candid::Nat::from(0u64) {
+            errors.add("amount", "The amount must be greater than zero.");
+        }
+
+        if let (Some(fee), Some(max_fee)) = (&self.input.fee, &self.input.max_fee) {
+            if fee > max_fee {
+                errors.add("fee", "The fee must not exceed the maximum fee.");
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for AddAccountOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if self.input.name.is_empty() {
+            errors.add("name", "The account name must not be empty.");
+        } else if ACCOUNT_REPOSITORY.find_by_name(&self.input.name).is_some() {
+            errors.add("name", "An account with this name already exists.");
+        }
+
+        let is_registered_asset = ASSETS.with(|assets| {
+            assets.borrow().iter().any(|asset| {
+                asset.blockchain == self.input.blockchain && asset.standard == self.input.standard
+            })
+        });
+        if !is_registered_asset {
+            errors.add(
+                "standard",
+                format!(
+                    "The asset registry has no asset of the {} standard on the {} blockchain.",
+                    self.input.standard, self.input.blockchain
+                ),
+            );
+        }
+
+        errors.check("read_permission", self.input.read_permission.validate());
+        errors.check(
+            "configs_permission",
+            self.input.configs_permission.validate(),
+        );
+        errors.check(
+            "transfer_permission",
+            self.input.transfer_permission.validate(),
+        );
+
+        if let Some(policy_rule) = &self.input.transfer_request_policy {
+            errors.check("transfer_request_policy", policy_rule.validate());
+        }
+
+        if let Some(policy_rule) = &self.input.configs_request_policy {
+            errors.check("configs_request_policy", policy_rule.validate());
+        }
+
+        if let Some(network) = &self.input.network {
+            if read_system_info()
+                .find_network(&self.input.blockchain, network)
+                .is_none()
+            {
+                errors.add(
+                    "network",
+                    format!(
+                        "The {} blockchain has no network {}.",
+                        self.input.blockchain, network
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for EditAccountOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "account_id",
+            EnsureAccount::id_exists(&self.input.account_id),
+        );
+
+        if self.input.name.as_ref().is_some_and(|name| name.is_empty()) {
+            errors.add("name", "The account name must not be empty.");
+        }
+
+        if let Some(allow) = &self.input.read_permission {
+            errors.check("read_permission", allow.validate());
+        }
+
+        if let Some(allow) = &self.input.configs_permission {
+            errors.check("configs_permission", allow.validate());
+        }
+
+        if let Some(allow) = &self.input.transfer_permission {
+            errors.check("transfer_permission", allow.validate());
+        }
+
+        if let Some(RequestPolicyRuleInput::Set(policy_rule)) = &self.input.configs_request_policy {
+            errors.check("configs_request_policy", policy_rule.validate());
+        }
+
+        if let Some(RequestPolicyRuleInput::Set(policy_rule)) = &self.input.transfer_request_policy
+        {
+            errors.check("transfer_request_policy", policy_rule.validate());
+        }
+    }
+}
+
+impl ValidateOperationInput for RotateAccountAddressOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "account_id",
+            EnsureAccount::id_exists(&self.input.account_id),
+        );
+
+        if self.input.grace_period_secs > Account::MAX_ADDRESS_GRACE_PERIOD_SECS {
+            errors.add(
+                "grace_period_secs",
+                format!(
+                    "The grace period must not exceed {} seconds.",
+                    Account::MAX_ADDRESS_GRACE_PERIOD_SECS
+                ),
+            );
+        }
+    }
+}
+
+impl ValidateOperationInput for TransferAccountOwnershipOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "account_id",
+            EnsureAccount::id_exists(&self.input.account_id),
+        );
+
+        for (field, owner) in [("from", &self.input.from), ("to", &self.input.to)] {
+            match owner {
+                AccountOwner::User(user_id) => errors.check(field, EnsureUser::id_exists(user_id)),
+                AccountOwner::UserGroup(group_id) => {
+                    errors.check(field, EnsureUserGroup::id_exists(group_id))
+                }
+            }
+        }
+
+        if self.input.from == self.input.to {
+            errors.add("to", "The account must be handed over to another owner.");
+        } else if !Account::owner_resources(self.input.account_id)
+            .iter()
+            .filter_map(|resource| PERMISSION_REPOSITORY.get(resource))
+            .any(|permission| self.input.from.is_allowed_by(&permission.allow))
+        {
+            errors.add("from", "The owner holds no permission on the account.");
+        }
+    }
+}
+
+impl ValidateOperationInput for AddAddressBookEntryOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if self.input.address.is_empty() {
+            errors.add("address", "The address must not be empty.");
+        }
+    }
+}
+
+impl ValidateOperationInput for EditAddressBookEntryOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "address_book_entry_id",
+            EnsureAddressBookEntry::id_exists(&self.input.address_book_entry_id),
+        );
+    }
+}
+
+impl ValidateOperationInput for RemoveAddressBookEntryOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "address_book_entry_id",
+            EnsureAddressBookEntry::id_exists(&self.input.address_book_entry_id),
+        );
+    }
+}
+
+impl ValidateOperationInput for AddUserOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "groups",
+            EnsureUserGroup::id_list_exists(&self.input.groups),
+        );
+        errors.check(
+            "scheduled_memberships",
+            EnsureUserGroup::id_list_exists(
+                &self
+                    .input
+                    .scheduled_memberships
+                    .iter()
+                    .map(|membership| membership.group_id)
+                    .collect::<Vec<_>>(),
+            ),
+        );
+    }
+}
+
+impl ValidateOperationInput for EditUserOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check("user_id", EnsureUser::id_exists(&self.input.user_id));
+
+        if let Some(group_ids) = &self.input.groups {
+            errors.check("groups", EnsureUserGroup::id_list_exists(group_ids));
+        }
+
+        if let Some(memberships) = &self.input.scheduled_memberships {
+            errors.check(
+                "scheduled_memberships",
+                EnsureUserGroup::id_list_exists(
+                    &memberships
+                        .iter()
+                        .map(|membership| membership.group_id)
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+    }
+}
+
+impl ValidateOperationInput for EditPermissionOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check("resource", self.input.resource.validate());
+
+        if let Some(user_ids) = &self.input.users {
+            errors.check("users", EnsureUser::id_list_exists(user_ids));
+        }
+
+        if let Some(group_ids) = &self.input.user_groups {
+            errors.check("user_groups", EnsureUserGroup::id_list_exists(group_ids));
+        }
+    }
+}
+
+impl ValidateOperationInput for BulkEditPermissionOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if self.input.changes.is_empty() {
+            errors.add("changes", "At least one permission change is required.");
+        }
+
+        if self.input.changes.len() > BulkEditPermissionOperationInput::MAX_CHANGES {
+            errors.add(
+                "changes",
+                format!(
+                    "At most {} permissions can be changed at once.",
+                    BulkEditPermissionOperationInput::MAX_CHANGES
+                ),
+            );
+        }
+
+        for (index, change) in self.input.changes.iter().enumerate() {
+            if self.input.changes[..index]
+                .iter()
+                .any(|previous| previous.resource == change.resource)
+            {
+                errors.add(
+                    &format!("changes[{}].resource", index),
+                    "The permission of a resource can only be changed once.",
+                );
+            }
+
+            errors.check(
+                &format!("changes[{}].resource", index),
+                change.resource.validate(),
+            );
+
+            if let Some(user_ids) = &change.users {
+                errors.check(
+                    &format!("changes[{}].users", index),
+                    EnsureUser::id_list_exists(user_ids),
+                );
+            }
+
+            if let Some(group_ids) = &change.user_groups {
+                errors.check(
+                    &format!("changes[{}].user_groups", index),
+                    EnsureUserGroup::id_list_exists(group_ids),
+                );
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for AddUserGroupOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if self.input.name.is_empty() {
+            errors.add("name", "The user group name must not be empty.");
+        }
+    }
+}
+
+impl ValidateOperationInput for EditUserGroupOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "user_group_id",
+            EnsureUserGroup::id_exists(&self.input.user_group_id),
+        );
+
+        if self.input.name.is_empty() {
+            errors.add("name", "The user group name must not be empty.");
+        }
+    }
+}
+
+impl ValidateOperationInput for RemoveUserGroupOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "user_group_id",
+            EnsureUserGroup::id_exists(&self.input.user_group_id),
+        );
+
+        match self.input.reassignment {
+            Some(UserGroupReassignment::ReplaceWith(replacement)) => {
+                if replacement == self.input.user_group_id {
+                    errors.add(
+                        "reassignment",
+                        "The replacement group must not be the group that is removed.",
+                    );
+                } else {
+                    errors.check("reassignment", EnsureUserGroup::id_exists(&replacement));
+                }
+            }
+            Some(UserGroupReassignment::Detach) => {}
+            None => {
+                if !self.affected.is_empty() {
+                    errors.add(
+                        "reassignment",
+                        "The user group is still referenced, a replacement group or detaching it is required.",
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for SystemUpgradeOperation {
+    fn validate_input(&self, _errors: &mut OperationFieldErrors) {}
+}
+
+impl ValidateOperationInput for ChangeExternalCanisterOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "canister_id",
+            EnsureExternalCanister::is_external_canister(self.input.canister_id),
+        );
+
+        if self.input.module.is_empty() && self.input.module_chunks.is_none() {
+            errors.add("module", "The module must not be empty.");
+        }
+    }
+}
+
+impl ValidateOperationInput for ConfigureExternalCanisterOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "canister_id",
+            EnsureExternalCanister::is_external_canister(self.canister_id),
+        );
+    }
+}
+
+impl ValidateOperationInput for CreateExternalCanisterOperation {
+    fn validate_input(&self, _errors: &mut OperationFieldErrors) {}
+}
+
+impl ValidateOperationInput for CallExternalCanisterOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        let validation_method_target: ValidationMethodResourceTarget =
+            self.input.validation_method.clone().into();
+        errors.check("validation_method", validation_method_target.validate());
+
+        let execution_method_target: ExecutionMethodResourceTarget =
+            self.input.execution_method.clone().into();
+        errors.check("execution_method", execution_method_target.validate());
+    }
+}
+
+impl ValidateOperationInput for FundExternalCanisterOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "canister_id",
+            EnsureExternalCanister::is_external_canister(self.canister_id),
+        );
+
+        match &self.kind {
+            FundExternalCanisterOperationKind::Send(send) => {
+                if send.cycles == 0 {
+                    errors.add("cycles", "The cycles must be greater than zero.");
+                }
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for CommitAssetBatchOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "canister_id",
+            EnsureExternalCanister::is_external_canister(self.input.canister_id),
+        );
+    }
+}
+
+impl ValidateOperationInput for SnapshotExternalCanisterOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "canister_id",
+            EnsureExternalCanister::is_external_canister(self.input.canister_id),
+        );
+    }
+}
+
+impl ValidateOperationInput for AddRequestPolicyOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check("specifier", self.input.specifier.validate());
+        errors.check("rule", self.input.rule.validate());
+    }
+}
+
+impl ValidateOperationInput for EditRequestPolicyOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "policy_id",
+            EnsureRequestPolicy::id_exists(&self.input.policy_id),
+        );
+
+        if let Some(specifier) = &self.input.specifier {
+            errors.check("specifier", specifier.validate());
+        }
+
+        if let Some(policy_rule) = &self.input.rule {
+            errors.check("rule", policy_rule.validate());
+        }
+    }
+}
+
+impl ValidateOperationInput for RemoveRequestPolicyOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        errors.check(
+            "policy_id",
+            EnsureRequestPolicy::id_exists(&self.input.policy_id),
+        );
+    }
+}
+
+impl ValidateOperationInput for AddNetworkOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        let network = &self.input.network;
+        errors.check("network", network.validate());
+
+        if read_system_info()
+            .find_network(&network.blockchain, &network.id)
+            .is_some()
+        {
+            errors.add(
+                "network.id",
+                format!(
+                    "The {} blockchain already has a network {}.",
+                    network.blockchain, network.id
+                ),
+            );
+        }
+    }
+}
+
+impl ValidateOperationInput for EditNetworkOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        match read_system_info().find_network(&self.input.blockchain, &self.input.id) {
+            Some(mut network) => {
+                self.input.apply(&mut network);
+                errors.check("network", network.validate());
+            }
+            None => errors.add(
+                "id",
+                format!(
+                    "The {} blockchain has no network {}.",
+                    self.input.blockchain, self.input.id
+                ),
+            ),
+        }
+    }
+}
+
+impl ValidateOperationInput for FreezeBlockchainOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if let Some(network) = &self.input.network {
+            if read_system_info()
+                .find_network(&self.input.blockchain, network)
+                .is_none()
+            {
+                errors.add(
+                    "network",
+                    format!(
+                        "The {} blockchain has no network {}.",
+                        self.input.blockchain, network
+                    ),
+                );
+            }
+        }
+
+        if let Some(reason) = &self.input.reason {
+            if reason.chars().count() > FreezeBlockchainOperationInput::MAX_REASON_LEN {
+                errors.add(
+                    "reason",
+                    format!(
+                        "The reason must not exceed {} characters.",
+                        FreezeBlockchainOperationInput::MAX_REASON_LEN
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for UnfreezeBlockchainOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if read_system_info()
+            .get_blockchain_freeze(&self.input.blockchain, self.input.network.as_deref())
+            .is_none()
+        {
+            errors.add(
+                "network",
+                format!(
+                    "The accounts of the {} blockchain are not frozen on the given network.",
+                    self.input.blockchain
+                ),
+            );
+        }
+    }
+}
+
+impl ValidateOperationInput for DecommissionStationOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if let Some(reason) = &self.input.reason {
+            if reason.chars().count() > DecommissionStationOperationInput::MAX_REASON_LEN {
+                errors.add(
+                    "reason",
+                    format!(
+                        "The reason must not exceed {} characters.",
+                        DecommissionStationOperationInput::MAX_REASON_LEN
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for ManageSystemInfoOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if let Some(TransferTimeLockConfig::Enabled {
+            canceller_group_ids,
+            ..
+        }) = &self.input.transfer_time_lock
+        {
+            for group_id in canceller_group_ids.iter() {
+                errors.check(
+                    "transfer_time_lock.canceller_group_ids",
+                    EnsureUserGroup::id_exists(group_id),
+                );
+            }
+        }
+
+        for (index, sub_treasury) in self.input.sub_treasuries.iter().flatten().enumerate() {
+            for account_id in sub_treasury.account_ids.iter() {
+                errors.check(
+                    &format!("sub_treasuries[{}].account_ids", index),
+                    EnsureAccount::id_exists(account_id),
+                );
+            }
+
+            errors.check(
+                &format!("sub_treasuries[{}].admin_group_id", index),
+                EnsureUserGroup::id_exists(&sub_treasury.admin_group_id),
+            );
+
+            for group_id in sub_treasury.member_group_ids.iter() {
+                errors.check(
+                    &format!("sub_treasuries[{}].member_group_ids", index),
+                    EnsureUserGroup::id_exists(group_id),
+                );
+            }
+        }
+    }
+}
+
+impl ValidateOperationInput for SetDisasterRecoveryOperation {
+    fn validate_input(&self, errors: &mut OperationFieldErrors) {
+        if let Some(committee) = &self.input.committee {
+            errors.check(
+                "committee.user_group_id",
+                EnsureUserGroup::id_exists(&committee.user_group_id),
+            );
+
+            if committee.quorum == 0 {
+                errors.add("committee.quorum", "The quorum must be greater than zero.");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::validation::disable_mock_resource_validation,
+        errors::RequestError,
+        models::{
+            permission::AuthScope,
+            resource::{PermissionResourceAction, Resource},
+            EditPermissionOperationInput, Metadata, TransferOperationInput,
+        },
+    };
+
+    #[test]
+    fn reports_all_invalid_fields_of_the_operation() {
+        disable_mock_resource_validation();
+
+        let mut errors = OperationFieldErrors::default();
+        RequestOperation::Transfer(TransferOperation {
+            transfer_id: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
+            fee: None,
+            input: TransferOperationInput {
+                network: "mainnet".to_string(),
+                amount: 0u64.into(),
+                fee: Some(2u64.into()),
+                metadata: Metadata::default(),
+                to: String::new(),
+                from_account_id: [0; 16],
+                fee_strategy: None,
+                max_fee: Some(1u64.into()),
+                compliance: None,
+                from_address: None,
+            },
+            time_lock: None,
+            duplicate_warning: None,
+        })
+        .validate_input(&mut errors);
+
+        match errors.into_result() {
+            Err(RequestError::InvalidOperationInput { errors }) => {
+                assert_eq!(
+                    errors.keys().collect::<Vec<_>>(),
+                    vec!["amount", "fee", "from_account_id", "to"]
+                );
+            }
+            result => panic!("Expected invalid operation input, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn bulk_permission_changes_change_each_resource_once() {
+        let change = EditPermissionOperationInput {
+            resource: Resource::Permission(PermissionResourceAction::Read),
+            auth_scope: Some(AuthScope::Authenticated),
+            users: None,
+            user_groups: None,
+        };
+
+        let mut errors = OperationFieldErrors::default();
+        BulkEditPermissionOperation {
+            input: BulkEditPermissionOperationInput {
+                changes: vec![change.clone(), change],
+            },
+            matrix: Vec::new(),
+        }
+        .validate_input(&mut errors);
+
+        match errors.into_result() {
+            Err(RequestError::InvalidOperationInput { errors }) => {
+                assert_eq!(
+                    errors.keys().collect::<Vec<_>>(),
+                    vec!["changes[1].resource"]
+                );
+            }
+            result => panic!("Expected invalid operation input, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn errors_of_the_same_field_are_joined() {
+        let mut errors = OperationFieldErrors::default();
+        errors.add("name", "first");
+        errors.check::<String>("name", Err("second".to_string()));
+        errors.check::<String>("other", Ok(()));
+
+        match errors.into_result() {
+            Err(RequestError::InvalidOperationInput { errors }) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors["name"], "first; second");
+            }
+            result => panic!("Expected invalid operation input, got {:?}", result),
+        }
+    }
+}
@@ -22,6 +22,17 @@ pub struct RequestPolicy {
     pub id: UUID,
     pub specifier: RequestSpecifier,
     pub rule: RequestPolicyRule,
+    /// The checks that the approvers must acknowledge when approving the requests of the policy
+    /// (e.g. `Verified the invoice`).
+    #[serde(default)]
+    pub checklist: Vec<String>,
+    /// Whether the approval of the requester never counts toward the quorums of the policy, so that
+    /// the requests of the policy are always approved by someone other than the requester.
+    #[serde(default)]
+    pub exclude_requester: bool,
+    /// The version of the record, incremented on every edit to detect concurrent edits.
+    #[serde(default)]
+    pub version: u64,
 }
 
 impl ModelKey<UUID> for RequestPolicy {
@@ -53,10 +64,46 @@ impl From<MatchError> for EvaluateError {
     }
 }
 
+impl RequestPolicy {
+    pub const MAX_CHECKLIST_ITEMS: usize = 20;
+    pub const MAX_CHECKLIST_ITEM_LEN: usize = 200;
+}
+
+fn validate_checklist(checklist: &[String]) -> ModelValidatorResult<RequestPolicyError> {
+    if checklist.len() > RequestPolicy::MAX_CHECKLIST_ITEMS {
+        return Err(RequestPolicyError::ValidationError {
+            info: format!(
+                "The checklist can have at most {} items.",
+                RequestPolicy::MAX_CHECKLIST_ITEMS
+            ),
+        });
+    }
+
+    for (index, item) in checklist.iter().enumerate() {
+        if item.trim().is_empty() || item.len() > RequestPolicy::MAX_CHECKLIST_ITEM_LEN {
+            return Err(RequestPolicyError::ValidationError {
+                info: format!(
+                    "The checklist items must have between 1 and {} characters.",
+                    RequestPolicy::MAX_CHECKLIST_ITEM_LEN
+                ),
+            });
+        }
+
+        if checklist[..index].contains(item) {
+            return Err(RequestPolicyError::ValidationError {
+                info: format!("The checklist item `{}` is duplicated.", item),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 impl ModelValidator<RequestPolicyError> for RequestPolicy {
     fn validate(&self) -> ModelValidatorResult<RequestPolicyError> {
         self.specifier.validate()?;
         self.rule.validate()?;
+        validate_checklist(&self.checklist)?;
         Ok(())
     }
 }
@@ -74,6 +121,31 @@ pub mod request_policy_test_utils {
             id: *Uuid::new_v4().as_bytes(),
             specifier: RequestSpecifier::AddAccount,
             rule: RequestPolicyRule::AutoApproved,
+            checklist: Vec::new(),
+            exclude_requester: false,
+            version: 0,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::request_policy_test_utils::mock_request_policy;
+    use super::*;
+
+    #[test]
+    fn checklist_items_must_be_unique_and_non_empty() {
+        let mut policy = mock_request_policy();
+        policy.checklist = vec!["Verified the invoice".to_string()];
+
+        assert!(policy.validate().is_ok());
+
+        policy.checklist.push("Verified the invoice".to_string());
+
+        assert!(policy.validate().is_err());
+
+        policy.checklist = vec![" ".to_string()];
+
+        assert!(policy.validate().is_err());
+    }
+}
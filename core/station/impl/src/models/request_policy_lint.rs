@@ -0,0 +1,253 @@
+use super::{
+    request_specifier::{RequestSpecifier, UserSpecifier},
+    GroupVoteWeight, RequestOperationType, RequestPolicy, RequestPolicyRule, User, UserGroupId,
+};
+use orbit_essentials::types::UUID;
+use std::collections::BTreeSet;
+
+/// A problem of the configured request policies, which otherwise only surfaces when a request can never
+/// pass or passes more easily than intended.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RequestPolicyIssue {
+    /// A user group named as approvers by the policy has no active members.
+    EmptyApproverGroup {
+        policy_id: UUID,
+        group_id: UserGroupId,
+    },
+    /// A quorum of the policy has no possible approvers, so its requests can't be approved by it, or for a
+    /// quorum of a fixed number of approvals, are approved without any approval.
+    NoPossibleApprovers { policy_id: UUID },
+    /// A quorum of the policy requires more approvals (or weight) than its possible approvers can cast, the
+    /// quorum is then lowered to what they can cast when the requests are evaluated.
+    ThresholdAboveApprovers {
+        policy_id: UUID,
+        threshold: u64,
+        possible: u64,
+    },
+    /// No policy matches the requests of the operation type, which are therefore always rejected.
+    UncoveredOperationType {
+        operation_type: RequestOperationType,
+    },
+    /// Every request matched by the policy is also matched by another policy, which can approve the
+    /// requests on its own since the matching policies are combined with `OR`.
+    ShadowedSpecifier { policy_id: UUID, shadowed_by: UUID },
+}
+
+/// Examines the request policies against the given active users and returns their problems.
+pub fn lint_request_policies(
+    policies: &[RequestPolicy],
+    active_users: &[User],
+) -> Vec<RequestPolicyIssue> {
+    let mut issues = BTreeSet::new();
+
+    for policy in policies {
+        lint_rule(policy.id, &policy.rule, active_users, &mut issues);
+
+        // the policies with equal specifiers shadow each other, only the second one is reported
+        if let Some(shadowed_by) = policies.iter().find(|other| {
+            other.id != policy.id
+                && other.specifier.covers(&policy.specifier)
+                && (other.specifier != policy.specifier || other.id < policy.id)
+        }) {
+            issues.insert(RequestPolicyIssue::ShadowedSpecifier {
+                policy_id: policy.id,
+                shadowed_by: shadowed_by.id,
+            });
+        }
+    }
+
+    for operation_type in RequestSpecifier::OPERATION_TYPES {
+        if !policies
+            .iter()
+            .any(|policy| RequestOperationType::from(&policy.specifier) == operation_type)
+        {
+            issues.insert(RequestPolicyIssue::UncoveredOperationType { operation_type });
+        }
+    }
+
+    issues.into_iter().collect()
+}
+
+fn lint_rule(
+    policy_id: UUID,
+    rule: &RequestPolicyRule,
+    active_users: &[User],
+    issues: &mut BTreeSet<RequestPolicyIssue>,
+) {
+    for group_id in rule.user_groups() {
+        if !active_users
+            .iter()
+            .any(|user| user.groups.contains(&group_id))
+        {
+            issues.insert(RequestPolicyIssue::EmptyApproverGroup {
+                policy_id,
+                group_id,
+            });
+        }
+    }
+
+    let (threshold, possible) = match rule {
+        RequestPolicyRule::AutoApproved
+        | RequestPolicyRule::AllowListedByMetadata(_)
+        | RequestPolicyRule::AllowListed => return,
+        RequestPolicyRule::Or(rules) | RequestPolicyRule::And(rules) => {
+            for rule in rules {
+                lint_rule(policy_id, rule, active_users, issues);
+            }
+
+            return;
+        }
+        RequestPolicyRule::Not(rule) => return lint_rule(policy_id, rule, active_users, issues),
+
+        RequestPolicyRule::QuorumPercentage(user_specifier, percentage) => (
+            // a percentage is never above the possible approvers, but needs at least one of them
+            (percentage.0 > 0) as u64,
+            count_approvers(user_specifier, active_users),
+        ),
+        RequestPolicyRule::Quorum(user_specifier, min_approved) => (
+            *min_approved as u64,
+            count_approvers(user_specifier, active_users),
+        ),
+        RequestPolicyRule::WeightedQuorum(group_weights, min_weight) => (
+            *min_weight as u64,
+            active_users
+                .iter()
+                .map(|user| GroupVoteWeight::user_weight(group_weights, user) as u64)
+                .sum(),
+        ),
+    };
+
+    if possible == 0 && threshold > 0 {
+        issues.insert(RequestPolicyIssue::NoPossibleApprovers { policy_id });
+    } else if threshold > possible {
+        issues.insert(RequestPolicyIssue::ThresholdAboveApprovers {
+            policy_id,
+            threshold,
+            possible,
+        });
+    }
+}
+
+fn count_approvers(user_specifier: &UserSpecifier, active_users: &[User]) -> u64 {
+    active_users
+        .iter()
+        .filter(|user| match user_specifier {
+            UserSpecifier::Any => true,
+            UserSpecifier::Group(group_ids) => {
+                user.groups.iter().any(|group| group_ids.contains(group))
+            }
+            UserSpecifier::Id(user_ids) => user_ids.contains(&user.id),
+        })
+        .count() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        request_policy_test_utils::mock_request_policy, resource::ResourceIds,
+        user_test_utils::mock_user, Percentage,
+    };
+
+    fn policy(specifier: RequestSpecifier, rule: RequestPolicyRule) -> RequestPolicy {
+        let mut policy = mock_request_policy();
+        policy.specifier = specifier;
+        policy.rule = rule;
+
+        policy
+    }
+
+    #[test]
+    fn reports_the_uncovered_operation_types() {
+        let issues = lint_request_policies(
+            &[policy(
+                RequestSpecifier::AddUser,
+                RequestPolicyRule::AutoApproved,
+            )],
+            &[],
+        );
+
+        assert_eq!(
+            issues
+                .iter()
+                .filter(|issue| matches!(issue, RequestPolicyIssue::UncoveredOperationType { .. }))
+                .count(),
+            RequestSpecifier::OPERATION_TYPES.len() - 1
+        );
+        assert!(
+            !issues.contains(&RequestPolicyIssue::UncoveredOperationType {
+                operation_type: RequestOperationType::AddUser
+            })
+        );
+    }
+
+    #[test]
+    fn reports_the_quorums_that_their_approvers_cannot_meet() {
+        let mut member = mock_user();
+        member.groups = vec![[1; 16]];
+        let empty_group = [2; 16];
+
+        let too_high = policy(
+            RequestSpecifier::AddUser,
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[1; 16]]), 2),
+        );
+        let empty = policy(
+            RequestSpecifier::AddAccount,
+            RequestPolicyRule::Or(vec![
+                RequestPolicyRule::AutoApproved,
+                RequestPolicyRule::QuorumPercentage(
+                    UserSpecifier::Group(vec![empty_group]),
+                    Percentage(50),
+                ),
+            ]),
+        );
+
+        let issues = lint_request_policies(&[too_high.clone(), empty.clone()], &[member]);
+
+        assert!(
+            issues.contains(&RequestPolicyIssue::ThresholdAboveApprovers {
+                policy_id: too_high.id,
+                threshold: 2,
+                possible: 1,
+            })
+        );
+        assert!(issues.contains(&RequestPolicyIssue::EmptyApproverGroup {
+            policy_id: empty.id,
+            group_id: empty_group,
+        }));
+        assert!(issues.contains(&RequestPolicyIssue::NoPossibleApprovers {
+            policy_id: empty.id
+        }));
+        assert!(!issues.contains(&RequestPolicyIssue::NoPossibleApprovers {
+            policy_id: too_high.id
+        }));
+    }
+
+    #[test]
+    fn reports_the_shadowed_specifiers() {
+        let any = policy(
+            RequestSpecifier::Transfer(ResourceIds::Any),
+            RequestPolicyRule::AutoApproved,
+        );
+        let account = policy(
+            RequestSpecifier::Transfer(ResourceIds::Ids(vec![[1; 16]])),
+            RequestPolicyRule::AutoApproved,
+        );
+        let other = policy(
+            RequestSpecifier::EditAccount(ResourceIds::Any),
+            RequestPolicyRule::AutoApproved,
+        );
+
+        let issues = lint_request_policies(&[any.clone(), account.clone(), other.clone()], &[]);
+
+        assert!(issues.contains(&RequestPolicyIssue::ShadowedSpecifier {
+            policy_id: account.id,
+            shadowed_by: any.id,
+        }));
+        assert!(!issues.iter().any(|issue| matches!(
+            issue,
+            RequestPolicyIssue::ShadowedSpecifier { policy_id, .. }
+                if *policy_id == any.id || *policy_id == other.id
+        )));
+    }
+}
@@ -1,13 +1,15 @@
 use super::{
+    reassign_user_group_id,
     request_specifier::{
         Match, RequestHasMetadata, UserInvolvedInPolicyRuleForRequestResource, UserSpecifier,
     },
     EvaluateError, EvaluationStatus, MetadataItem, Percentage, Request, RequestApprovalStatus,
-    RequestId, RequestOperation, UserId, UserStatus,
+    RequestId, RequestOperation, User, UserGroupId, UserId, UserStatus,
 };
 use crate::{
-    core::{ic_cdk::api::print, utils::calculate_minimum_threshold},
-    errors::{MatchError, ValidationError},
+    core::{utils::calculate_minimum_threshold, validation::EnsureUserGroup},
+    errors::{MatchError, RequestPolicyRuleValidationError, ValidationError},
+    log_warn,
     repositories::{UserWhereClause, ADDRESS_BOOK_REPOSITORY, USER_REPOSITORY},
     services::ACCOUNT_SERVICE,
 };
@@ -16,6 +18,7 @@ use orbit_essentials::storable;
 use station_api::EvaluationSummaryReasonDTO;
 use std::{cmp, hash::Hash};
 use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -23,6 +26,7 @@ pub enum RequestPolicyRule {
     AutoApproved,
     QuorumPercentage(UserSpecifier, Percentage),
     Quorum(UserSpecifier, u16),
+    WeightedQuorum(Vec<GroupVoteWeight>, u16),
     AllowListedByMetadata(MetadataItem),
     AllowListed,
     // Logical operators
@@ -31,6 +35,37 @@ pub enum RequestPolicyRule {
     Not(Box<RequestPolicyRule>),
 }
 
+/// The weight that the approvals of the members of a user group count for in a weighted quorum.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GroupVoteWeight {
+    pub group_id: UserGroupId,
+    pub weight: u16,
+}
+
+impl GroupVoteWeight {
+    /// Returns the weight of the approval of the user, which is the highest weight among the groups of
+    /// the user, or 0 if the user is not a member of any of the groups.
+    pub fn user_weight(group_weights: &[GroupVoteWeight], user: &User) -> usize {
+        group_weights
+            .iter()
+            .filter(|group_weight| user.groups.contains(&group_weight.group_id))
+            .map(|group_weight| group_weight.weight as usize)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the user specifier that matches the members of the groups.
+    pub fn user_specifier(group_weights: &[GroupVoteWeight]) -> UserSpecifier {
+        UserSpecifier::Group(
+            group_weights
+                .iter()
+                .map(|group_weight| group_weight.group_id)
+                .collect(),
+        )
+    }
+}
+
 impl ModelValidator<ValidationError> for RequestPolicyRule {
     fn validate(&self) -> ModelValidatorResult<ValidationError> {
         match self {
@@ -38,8 +73,31 @@ impl ModelValidator<ValidationError> for RequestPolicyRule {
             | RequestPolicyRule::AllowListedByMetadata(_)
             | RequestPolicyRule::AllowListed => Ok(()),
 
-            RequestPolicyRule::QuorumPercentage(user_specifier, _)
-            | RequestPolicyRule::Quorum(user_specifier, _) => user_specifier.validate(),
+            RequestPolicyRule::QuorumPercentage(user_specifier, percentage) => {
+                if percentage.0 > 100 {
+                    Err(RequestPolicyRuleValidationError::InvalidPercentage {
+                        percentage: percentage.0,
+                    })?
+                }
+
+                user_specifier.validate()
+            }
+            RequestPolicyRule::Quorum(user_specifier, _) => user_specifier.validate(),
+            RequestPolicyRule::WeightedQuorum(group_weights, _) => {
+                for group_weight in group_weights {
+                    if group_weight.weight == 0 {
+                        Err(RequestPolicyRuleValidationError::InvalidVoteWeight {
+                            group_id: Uuid::from_bytes(group_weight.group_id)
+                                .hyphenated()
+                                .to_string(),
+                        })?
+                    }
+
+                    EnsureUserGroup::id_exists(&group_weight.group_id)?;
+                }
+
+                Ok(())
+            }
 
             RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
                 for rule in policy_rules {
@@ -52,6 +110,125 @@ impl ModelValidator<ValidationError> for RequestPolicyRule {
     }
 }
 
+impl RequestPolicyRule {
+    /// Returns the user groups named in the rule, including the ones of its nested rules.
+    pub fn user_groups(&self) -> HashSet<UserGroupId> {
+        match self {
+            RequestPolicyRule::AutoApproved
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListed => HashSet::new(),
+
+            RequestPolicyRule::QuorumPercentage(user_specifier, _)
+            | RequestPolicyRule::Quorum(user_specifier, _) => match user_specifier {
+                UserSpecifier::Group(group_ids) => group_ids.iter().copied().collect(),
+                UserSpecifier::Any | UserSpecifier::Id(_) => HashSet::new(),
+            },
+            RequestPolicyRule::WeightedQuorum(group_weights, _) => group_weights
+                .iter()
+                .map(|group_weight| group_weight.group_id)
+                .collect(),
+
+            RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
+                policy_rules
+                    .iter()
+                    .flat_map(|rule| rule.user_groups())
+                    .collect()
+            }
+            RequestPolicyRule::Not(rule) => rule.user_groups(),
+        }
+    }
+
+    /// Replaces the user group in the rule and its nested rules with the replacement, or drops it if there
+    /// is none.
+    pub fn reassign_user_group(
+        &mut self,
+        user_group_id: &UserGroupId,
+        replacement: Option<UserGroupId>,
+    ) {
+        match self {
+            RequestPolicyRule::AutoApproved
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListed => {}
+
+            RequestPolicyRule::QuorumPercentage(user_specifier, _)
+            | RequestPolicyRule::Quorum(user_specifier, _) => {
+                if let UserSpecifier::Group(group_ids) = user_specifier {
+                    reassign_user_group_id(group_ids, user_group_id, replacement);
+                }
+            }
+            RequestPolicyRule::WeightedQuorum(group_weights, _) => {
+                let Some(position) = group_weights
+                    .iter()
+                    .position(|group_weight| &group_weight.group_id == user_group_id)
+                else {
+                    return;
+                };
+
+                let removed = group_weights.remove(position);
+
+                if let Some(replacement) = replacement {
+                    // the replacement keeps the highest of the two weights if it was already named
+                    match group_weights
+                        .iter_mut()
+                        .find(|group_weight| group_weight.group_id == replacement)
+                    {
+                        Some(group_weight) => {
+                            group_weight.weight = cmp::max(group_weight.weight, removed.weight)
+                        }
+                        None => group_weights.push(GroupVoteWeight {
+                            group_id: replacement,
+                            weight: removed.weight,
+                        }),
+                    }
+                }
+            }
+
+            RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
+                for rule in policy_rules {
+                    rule.reassign_user_group(user_group_id, replacement);
+                }
+            }
+            RequestPolicyRule::Not(rule) => rule.reassign_user_group(user_group_id, replacement),
+        }
+    }
+
+    /// Returns the users among the given ones that the rule names as approvers, including the ones of its
+    /// nested rules.
+    pub fn approvers(&self, users: &[User]) -> HashSet<UserId> {
+        match self {
+            RequestPolicyRule::AutoApproved
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListed => HashSet::new(),
+
+            RequestPolicyRule::QuorumPercentage(user_specifier, _)
+            | RequestPolicyRule::Quorum(user_specifier, _) => users
+                .iter()
+                .filter(|user| match user_specifier {
+                    UserSpecifier::Any => true,
+                    UserSpecifier::Group(group_ids) => {
+                        user.groups.iter().any(|group| group_ids.contains(group))
+                    }
+                    UserSpecifier::Id(user_ids) => user_ids.contains(&user.id),
+                })
+                .map(|user| user.id)
+                .collect(),
+            RequestPolicyRule::WeightedQuorum(group_weights, _) => users
+                .iter()
+                .filter(|user| GroupVoteWeight::user_weight(group_weights, user) > 0)
+                .map(|user| user.id)
+                .collect(),
+
+            RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
+                policy_rules
+                    .iter()
+                    .flat_map(|rule| rule.approvers(users))
+                    .collect()
+            }
+            RequestPolicyRule::Not(rule) => rule.approvers(users),
+        }
+    }
+}
+
 #[storable]
 #[derive(Debug, Clone, PartialEq)]
 pub enum EvaluatedRequestPolicyRule {
@@ -60,11 +237,27 @@ pub enum EvaluatedRequestPolicyRule {
         min_approved: usize,
         total_possible_approvers: usize,
         approvers: Vec<UserId>,
+        /// Whether the requester was left out of the possible approvers and approvers of the quorum.
+        #[serde(default)]
+        requester_excluded: bool,
     },
     Quorum {
         min_approved: usize,
         total_possible_approvers: usize,
         approvers: Vec<UserId>,
+        /// Whether the requester was left out of the possible approvers and approvers of the quorum.
+        #[serde(default)]
+        requester_excluded: bool,
+    },
+    WeightedQuorum {
+        min_weight: usize,
+        total_possible_weight: usize,
+        approved_weight: usize,
+        rejected_weight: usize,
+        approvers: Vec<UserId>,
+        /// Whether the requester was left out of the possible approvers and approvers of the quorum.
+        #[serde(default)]
+        requester_excluded: bool,
     },
     AllowListedByMetadata {
         metadata: MetadataItem,
@@ -99,7 +292,8 @@ impl RequestPolicyRuleResult {
                 }
             }
             EvaluatedRequestPolicyRule::QuorumPercentage { .. }
-            | EvaluatedRequestPolicyRule::Quorum { .. } => {
+            | EvaluatedRequestPolicyRule::Quorum { .. }
+            | EvaluatedRequestPolicyRule::WeightedQuorum { .. } => {
                 if final_status == self.status {
                     reasons.push(EvaluationSummaryReason::ApprovalQuorum);
                 }
@@ -201,6 +395,8 @@ pub trait EvaluateRequestPolicyRule<
 pub struct RequestPolicyRuleEvaluator {
     pub user_matcher: Arc<dyn Match<UserInvolvedInPolicyRuleForRequestResource>>,
     pub address_book_metadata_matcher: Arc<dyn Match<RequestHasMetadata>>,
+    /// Whether the requester is left out of the quorums, so that their approval never counts.
+    pub exclude_requester: bool,
 }
 
 struct RequestApprovalSummary {
@@ -208,6 +404,7 @@ struct RequestApprovalSummary {
     approvers: Vec<UserId>,
     approved: usize,
     rejected: usize,
+    requester_excluded: bool,
 }
 
 impl RequestApprovalSummary {
@@ -217,8 +414,16 @@ impl RequestApprovalSummary {
     /// If the request does not yet have enough approvals to meet the minimum approvals required but has
     /// enough uncasted approvals that could be casted to meet the minimum approvals required, then the evaluation
     /// is kept in the `Pending` state.
+    ///
+    /// If the requester is excluded, a quorum with no other possible approvers is rejected rather than met,
+    /// so that the requester can't approve their own request by being the only approver.
     fn evaluate(&self, min_approved: usize) -> EvaluationStatus {
-        let min_approved = cmp::min(min_approved, self.total_possible_approvers);
+        let min_approved = match self.requester_excluded {
+            true if min_approved > 0 => {
+                cmp::max(cmp::min(min_approved, self.total_possible_approvers), 1)
+            }
+            _ => cmp::min(min_approved, self.total_possible_approvers),
+        };
         let uncasted_approvals = self
             .total_possible_approvers
             .saturating_sub(self.approved)
@@ -286,6 +491,9 @@ impl RequestPolicyRuleEvaluator {
             request
                 .approvals
                 .iter()
+                .filter(|approval| {
+                    !self.exclude_requester || approval.approver_id != request.requested_by
+                })
                 .map(|approval| {
                     (
                         approval.approver_id.to_owned(),
@@ -307,6 +515,7 @@ impl RequestPolicyRuleEvaluator {
                         search_term: None,
                     })
                     .iter()
+                    .filter(|user| !self.exclude_requester || user.id != request.requested_by)
                     .map(|user| (user.id.to_owned(), ()))
                     .collect::<Vec<(UserId, ())>>()
                     .as_slice(),
@@ -332,8 +541,77 @@ impl RequestPolicyRuleEvaluator {
                 .into_iter()
                 .map(|(user_id, _)| user_id)
                 .collect(),
+            requester_excluded: self.exclude_requester,
         })
     }
+
+    /// Calculates the approvals of a weighted quorum, where the summary counts the weights of the users
+    /// rather than the users themselves.
+    fn calculate_weighted_approvals(
+        &self,
+        request: &Arc<Request>,
+        group_weights: &[GroupVoteWeight],
+    ) -> RequestApprovalSummary {
+        let user_weight = |user_id: &UserId| {
+            USER_REPOSITORY
+                .get(&User::key(*user_id))
+                .map(|user| GroupVoteWeight::user_weight(group_weights, &user))
+                .unwrap_or_default()
+        };
+
+        let casted_approvals = request
+            .approvals
+            .iter()
+            .filter(|approval| {
+                !self.exclude_requester || approval.approver_id != request.requested_by
+            })
+            .map(|approval| {
+                (
+                    approval.approver_id,
+                    approval.status.to_owned(),
+                    user_weight(&approval.approver_id),
+                )
+            })
+            .filter(|(_, _, weight)| *weight > 0)
+            .collect::<Vec<_>>();
+
+        let approved_weight = casted_approvals
+            .iter()
+            .filter(|(_, status, _)| *status == RequestApprovalStatus::Approved)
+            .map(|(_, _, weight)| weight)
+            .sum::<usize>();
+        let rejected_weight = casted_approvals
+            .iter()
+            .filter(|(_, status, _)| *status == RequestApprovalStatus::Rejected)
+            .map(|(_, _, weight)| weight)
+            .sum::<usize>();
+
+        let total_possible_weight = USER_REPOSITORY
+            .find_where(UserWhereClause {
+                statuses: Some(vec![UserStatus::Active]),
+                groups: None,
+                search_term: None,
+            })
+            .iter()
+            .filter(|user| !self.exclude_requester || user.id != request.requested_by)
+            .map(|user| GroupVoteWeight::user_weight(group_weights, user))
+            .sum::<usize>();
+
+        RequestApprovalSummary {
+            // the weights of the casted approvals are kept even if their users became inactive
+            total_possible_approvers: cmp::max(
+                total_possible_weight,
+                approved_weight.saturating_add(rejected_weight),
+            ),
+            approved: approved_weight,
+            rejected: rejected_weight,
+            approvers: casted_approvals
+                .into_iter()
+                .map(|(user_id, _, _)| user_id)
+                .collect(),
+            requester_excluded: self.exclude_requester,
+        }
+    }
 }
 
 impl
@@ -355,17 +633,29 @@ impl
             RequestPolicyRule::QuorumPercentage(user_specifier, percentage) => {
                 let approval_summary: RequestApprovalSummary =
                     self.calculate_approvals(&request, user_specifier)?;
-                let min_approved = calculate_minimum_threshold(
+                let mut min_approved = calculate_minimum_threshold(
                     percentage,
                     &approval_summary.total_possible_approvers,
                 );
 
+                // the possible approvers are the active users at the time of the evaluation, so a non-zero
+                // percentage always needs at least one approval, even if the group was emptied
+                if percentage.0 > 0 {
+                    min_approved = cmp::max(min_approved, 1);
+                }
+
+                let status = match approval_summary.total_possible_approvers {
+                    0 if min_approved > 0 => EvaluationStatus::Rejected,
+                    _ => approval_summary.evaluate(min_approved),
+                };
+
                 Ok(RequestPolicyRuleResult {
-                    status: approval_summary.evaluate(min_approved),
+                    status,
                     evaluated_rule: EvaluatedRequestPolicyRule::QuorumPercentage {
                         total_possible_approvers: approval_summary.total_possible_approvers,
                         approvers: approval_summary.approvers,
                         min_approved,
+                        requester_excluded: approval_summary.requester_excluded,
                     },
                 })
             }
@@ -378,6 +668,28 @@ impl
                         total_possible_approvers: approval_summary.total_possible_approvers,
                         approvers: approval_summary.approvers,
                         min_approved: *min_approved as usize,
+                        requester_excluded: approval_summary.requester_excluded,
+                    },
+                })
+            }
+            RequestPolicyRule::WeightedQuorum(group_weights, min_weight) => {
+                let approval_summary = self.calculate_weighted_approvals(&request, group_weights);
+
+                // the groups that were emptied can't meet a non-zero weight
+                let status = match approval_summary.total_possible_approvers {
+                    0 if *min_weight > 0 => EvaluationStatus::Rejected,
+                    _ => approval_summary.evaluate(*min_weight as usize),
+                };
+
+                Ok(RequestPolicyRuleResult {
+                    status,
+                    evaluated_rule: EvaluatedRequestPolicyRule::WeightedQuorum {
+                        min_weight: *min_weight as usize,
+                        total_possible_weight: approval_summary.total_possible_approvers,
+                        approved_weight: approval_summary.approved,
+                        rejected_weight: approval_summary.rejected,
+                        approvers: approval_summary.approvers,
+                        requester_excluded: approval_summary.requester_excluded,
                     },
                 })
             }
@@ -402,10 +714,7 @@ impl
                     let account = ACCOUNT_SERVICE.get_account(&transfer.input.from_account_id);
                     match account {
                         Err(e) => {
-                            print(format!(
-                                "Rule rejected due to account not being found: {:?}",
-                                e
-                            ));
+                            log_warn!("Rule rejected due to account not being found: {:?}", e);
 
                             return Ok(RequestPolicyRuleResult {
                                 status: EvaluationStatus::Rejected,
@@ -527,6 +836,7 @@ pub mod request_policy_rule_test_utils {
                                 min_approved: 2,
                                 total_possible_approvers: 3,
                                 approvers: vec![[0; 16], [1; 16]],
+                                requester_excluded: false,
                             },
                         },
                     ]),
@@ -537,6 +847,7 @@ pub mod request_policy_rule_test_utils {
                         min_approved: 2,
                         approvers: vec![[0; 16], [1; 16]],
                         total_possible_approvers: 3,
+                        requester_excluded: false,
                     },
                 },
             ],
@@ -547,7 +858,17 @@ pub mod request_policy_rule_test_utils {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::core::validation::disable_mock_resource_validation;
+    use crate::{
+        core::{
+            evaluation::REQUEST_POLICY_RULE_EVALUATOR, validation::disable_mock_resource_validation,
+        },
+        models::{
+            request_approval_test_utils::{mock_approved_with_user, mock_rejected_with_user},
+            request_test_utils::mock_request,
+            user_test_utils::mock_user,
+            RequestApproval,
+        },
+    };
 
     #[test]
     fn fail_critera_with_non_existent_user_specifier() {
@@ -579,6 +900,271 @@ mod test {
         .expect_err("Rule with non-existent user specifier should fail");
     }
 
+    #[test]
+    fn fail_quorum_percentage_above_one_hundred() {
+        RequestPolicyRule::QuorumPercentage(UserSpecifier::Any, Percentage(100))
+            .validate()
+            .expect("Rule with a percentage of 100 should be valid");
+
+        RequestPolicyRule::QuorumPercentage(UserSpecifier::Any, Percentage(101))
+            .validate()
+            .expect_err("Rule with a percentage above 100 should fail");
+    }
+
+    #[test]
+    fn quorum_percentage_follows_the_current_group_members() {
+        let group_id = [7; 16];
+        let members = (10..13u8)
+            .map(|i| {
+                let mut user = mock_user();
+                user.id = [i; 16];
+                user.groups = vec![group_id];
+                USER_REPOSITORY.insert(user.to_key(), user.clone());
+                user
+            })
+            .collect::<Vec<_>>();
+
+        let mut request = mock_request();
+        request.requested_by = [1; 16];
+        request.approvals = vec![mock_approved_with_user(members[0].id)];
+        let request = Arc::new(request);
+        let rule = Arc::new(RequestPolicyRule::QuorumPercentage(
+            UserSpecifier::Group(vec![group_id]),
+            Percentage(50),
+        ));
+        let evaluate = || {
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((request.clone(), rule.clone()))
+                .unwrap()
+        };
+
+        // 50% of 3 members is rounded up to 2 approvals
+        let result = evaluate();
+        assert_eq!(result.status, EvaluationStatus::Pending);
+        assert!(matches!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::QuorumPercentage {
+                min_approved: 2,
+                total_possible_approvers: 3,
+                ..
+            }
+        ));
+
+        // once a member leaves, 50% of 2 members is met by the single approval
+        let mut leaving_member = members[2].clone();
+        leaving_member.groups = vec![];
+        USER_REPOSITORY.insert(leaving_member.to_key(), leaving_member);
+
+        assert_eq!(evaluate().status, EvaluationStatus::Approved);
+
+        // the approval of a user that left the group no longer counts, and an emptied group never
+        // meets the quorum
+        for member in &members[..2] {
+            let mut member = member.clone();
+            member.groups = vec![];
+            USER_REPOSITORY.insert(member.to_key(), member);
+        }
+
+        let result = evaluate();
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert!(matches!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::QuorumPercentage {
+                min_approved: 1,
+                total_possible_approvers: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn fail_weighted_quorum_with_zero_weight() {
+        RequestPolicyRule::WeightedQuorum(
+            vec![GroupVoteWeight {
+                group_id: [0; 16],
+                weight: 0,
+            }],
+            1,
+        )
+        .validate()
+        .expect_err("Rule with a zero vote weight should fail");
+    }
+
+    #[test]
+    fn weighted_quorum_sums_the_weights_of_the_approvals() {
+        let cfo_group_id = [20; 16];
+        let board_group_id = [21; 16];
+        let add_member = |id: u8, groups: Vec<UserGroupId>| {
+            let mut user = mock_user();
+            user.id = [id; 16];
+            user.groups = groups;
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+            user
+        };
+
+        // the cfo is also a board member, but only counts for the highest weight
+        let cfo = add_member(22, vec![cfo_group_id, board_group_id]);
+        let board_member = add_member(23, vec![board_group_id]);
+        let other_board_member = add_member(24, vec![board_group_id]);
+        add_member(25, vec![]);
+
+        let rule = Arc::new(RequestPolicyRule::WeightedQuorum(
+            vec![
+                GroupVoteWeight {
+                    group_id: cfo_group_id,
+                    weight: 2,
+                },
+                GroupVoteWeight {
+                    group_id: board_group_id,
+                    weight: 1,
+                },
+            ],
+            3,
+        ));
+        let evaluate = |approvals: Vec<RequestApproval>| {
+            let mut request = mock_request();
+            request.approvals = approvals;
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((Arc::new(request), rule.clone()))
+                .unwrap()
+        };
+
+        let result = evaluate(vec![mock_approved_with_user(board_member.id)]);
+        assert_eq!(result.status, EvaluationStatus::Pending);
+        assert!(matches!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::WeightedQuorum {
+                min_weight: 3,
+                total_possible_weight: 4,
+                approved_weight: 1,
+                rejected_weight: 0,
+                ..
+            }
+        ));
+
+        let result = evaluate(vec![
+            mock_approved_with_user(board_member.id),
+            mock_approved_with_user(cfo.id),
+        ]);
+        assert_eq!(result.status, EvaluationStatus::Approved);
+
+        // the weight of 3 can't be met once the cfo rejected
+        let result = evaluate(vec![
+            mock_approved_with_user(board_member.id),
+            mock_approved_with_user(other_board_member.id),
+            mock_rejected_with_user(cfo.id),
+        ]);
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert!(matches!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::WeightedQuorum {
+                approved_weight: 2,
+                rejected_weight: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reassigning_a_weighted_user_group_keeps_its_weight() {
+        let mut rule = RequestPolicyRule::WeightedQuorum(
+            vec![
+                GroupVoteWeight {
+                    group_id: [1; 16],
+                    weight: 2,
+                },
+                GroupVoteWeight {
+                    group_id: [2; 16],
+                    weight: 1,
+                },
+            ],
+            2,
+        );
+
+        rule.reassign_user_group(&[1; 16], Some([3; 16]));
+        rule.reassign_user_group(&[2; 16], Some([3; 16]));
+
+        assert_eq!(
+            rule,
+            RequestPolicyRule::WeightedQuorum(
+                vec![GroupVoteWeight {
+                    group_id: [3; 16],
+                    weight: 2,
+                }],
+                2,
+            )
+        );
+    }
+
+    #[test]
+    fn zero_quorum_percentage_is_met_without_possible_approvers() {
+        let mut request = mock_request();
+        request.approvals = vec![];
+        let result = REQUEST_POLICY_RULE_EVALUATOR
+            .evaluate((
+                Arc::new(request),
+                Arc::new(RequestPolicyRule::QuorumPercentage(
+                    UserSpecifier::Group(vec![[8; 16]]),
+                    Percentage(0),
+                )),
+            ))
+            .unwrap();
+
+        assert_eq!(result.status, EvaluationStatus::Approved);
+    }
+
+    #[test]
+    fn user_groups_include_the_groups_of_nested_rules() {
+        let rule = RequestPolicyRule::And(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[1; 16], [2; 16]]), 1),
+            RequestPolicyRule::Or(vec![
+                RequestPolicyRule::QuorumPercentage(
+                    UserSpecifier::Id(vec![[3; 16]]),
+                    Percentage(50),
+                ),
+                RequestPolicyRule::Not(Box::new(RequestPolicyRule::QuorumPercentage(
+                    UserSpecifier::Group(vec![[2; 16], [4; 16]]),
+                    Percentage(100),
+                ))),
+            ]),
+            RequestPolicyRule::AutoApproved,
+        ]);
+
+        assert_eq!(
+            rule.user_groups(),
+            HashSet::from([[1; 16], [2; 16], [4; 16]])
+        );
+        assert!(RequestPolicyRule::Quorum(UserSpecifier::Any, 1)
+            .user_groups()
+            .is_empty());
+    }
+
+    #[test]
+    fn reassigning_a_user_group_updates_the_nested_rules() {
+        let mut rule = RequestPolicyRule::And(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[1; 16], [2; 16]]), 1),
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::QuorumPercentage(
+                UserSpecifier::Group(vec![[2; 16]]),
+                Percentage(100),
+            ))),
+        ]);
+
+        rule.reassign_user_group(&[2; 16], Some([1; 16]));
+        assert_eq!(
+            rule,
+            RequestPolicyRule::And(vec![
+                RequestPolicyRule::Quorum(UserSpecifier::Group(vec![[1; 16]]), 1),
+                RequestPolicyRule::Not(Box::new(RequestPolicyRule::QuorumPercentage(
+                    UserSpecifier::Group(vec![[1; 16]]),
+                    Percentage(100),
+                ))),
+            ])
+        );
+
+        rule.reassign_user_group(&[1; 16], None);
+        assert!(rule.user_groups().is_empty());
+    }
+
     #[test]
     fn test_evaluation_reasons() {
         let result = RequestPolicyRuleResult {
@@ -591,6 +1177,7 @@ mod test {
                         min_approved: 2,
                         total_possible_approvers: 4,
                         approvers: vec![],
+                        requester_excluded: false,
                     },
                 },
                 RequestPolicyRuleResult {
@@ -611,6 +1198,7 @@ mod test {
                                 min_approved: 1,
                                 total_possible_approvers: 1,
                                 approvers: vec![],
+                                requester_excluded: false,
                             },
                         },
                         RequestPolicyRuleResult {
@@ -161,6 +161,89 @@ impl From<&RequestSpecifier> for RequestOperationType {
     }
 }
 
+impl RequestSpecifier {
+    /// The operation types that request policies can be specified for, one per kind of specifier.
+    pub const OPERATION_TYPES: [RequestOperationType; 22] = [
+        RequestOperationType::AddAccount,
+        RequestOperationType::AddUser,
+        RequestOperationType::EditAccount,
+        RequestOperationType::EditUser,
+        RequestOperationType::AddAddressBookEntry,
+        RequestOperationType::EditAddressBookEntry,
+        RequestOperationType::RemoveAddressBookEntry,
+        RequestOperationType::Transfer,
+        RequestOperationType::SetDisasterRecovery,
+        RequestOperationType::CreateExternalCanister,
+        RequestOperationType::ChangeExternalCanister,
+        RequestOperationType::CallExternalCanister,
+        RequestOperationType::FundExternalCanister,
+        RequestOperationType::EditPermission,
+        RequestOperationType::AddRequestPolicy,
+        RequestOperationType::EditRequestPolicy,
+        RequestOperationType::RemoveRequestPolicy,
+        RequestOperationType::AddUserGroup,
+        RequestOperationType::EditUserGroup,
+        RequestOperationType::RemoveUserGroup,
+        RequestOperationType::ManageSystemInfo,
+        RequestOperationType::SystemUpgrade,
+    ];
+
+    /// Whether every request matched by the other specifier is also matched by this one.
+    ///
+    /// Only the specifiers that match any resource or a list of ids are compared, the other ones cover
+    /// each other only if they are equal.
+    pub fn covers(&self, other: &RequestSpecifier) -> bool {
+        fn covers_ids(ids: &ResourceIds, other: &ResourceIds) -> bool {
+            match (ids, other) {
+                (ResourceIds::Any, _) => true,
+                (ResourceIds::Ids(_), ResourceIds::Any) => false,
+                (ResourceIds::Ids(ids), ResourceIds::Ids(other)) => {
+                    other.iter().all(|id| ids.contains(id))
+                }
+            }
+        }
+
+        match (self, other) {
+            (RequestSpecifier::EditAccount(ids), RequestSpecifier::EditAccount(other))
+            | (RequestSpecifier::EditUser(ids), RequestSpecifier::EditUser(other))
+            | (
+                RequestSpecifier::EditAddressBookEntry(ids),
+                RequestSpecifier::EditAddressBookEntry(other),
+            )
+            | (
+                RequestSpecifier::RemoveAddressBookEntry(ids),
+                RequestSpecifier::RemoveAddressBookEntry(other),
+            )
+            | (RequestSpecifier::Transfer(ids), RequestSpecifier::Transfer(other))
+            | (
+                RequestSpecifier::EditRequestPolicy(ids),
+                RequestSpecifier::EditRequestPolicy(other),
+            )
+            | (
+                RequestSpecifier::RemoveRequestPolicy(ids),
+                RequestSpecifier::RemoveRequestPolicy(other),
+            )
+            | (RequestSpecifier::EditUserGroup(ids), RequestSpecifier::EditUserGroup(other))
+            | (RequestSpecifier::RemoveUserGroup(ids), RequestSpecifier::RemoveUserGroup(other)) => {
+                covers_ids(ids, other)
+            }
+            (
+                RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Any),
+                RequestSpecifier::ChangeExternalCanister(_),
+            )
+            | (
+                RequestSpecifier::FundExternalCanister(ExternalCanisterId::Any),
+                RequestSpecifier::FundExternalCanister(_),
+            )
+            | (
+                RequestSpecifier::EditPermission(ResourceSpecifier::Any),
+                RequestSpecifier::EditPermission(_),
+            ) => true,
+            _ => self == other,
+        }
+    }
+}
+
 pub trait Match<T>: Sync + Send {
     fn is_match(&self, v: T) -> Result<bool, MatchError>;
 }
@@ -273,6 +356,36 @@ mod tests {
     use orbit_essentials::cdk::mocks::api::id;
     use orbit_essentials::cdk::mocks::TEST_CANISTER_ID;
     use orbit_essentials::{model::ModelValidator, repository::Repository};
+    use strum::VariantNames;
+
+    #[test]
+    fn every_kind_of_specifier_has_an_operation_type() {
+        assert_eq!(
+            RequestSpecifier::OPERATION_TYPES.len(),
+            RequestSpecifier::VARIANTS.len()
+        );
+    }
+
+    #[test]
+    fn specifiers_cover_the_ones_they_match_entirely() {
+        let any = RequestSpecifier::Transfer(ResourceIds::Any);
+        let both = RequestSpecifier::Transfer(ResourceIds::Ids(vec![[1; 16], [2; 16]]));
+        let one = RequestSpecifier::Transfer(ResourceIds::Ids(vec![[1; 16]]));
+
+        assert!(any.covers(&both));
+        assert!(both.covers(&one));
+        assert!(!one.covers(&both));
+        assert!(!both.covers(&any));
+        assert!(!any.covers(&RequestSpecifier::EditAccount(ResourceIds::Any)));
+        assert!(RequestSpecifier::AddUser.covers(&RequestSpecifier::AddUser));
+        assert!(
+            RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Any).covers(
+                &RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Canister(
+                    Principal::from_slice(&[1; 29])
+                ))
+            )
+        );
+    }
 
     #[tokio::test]
     async fn test_user_matcher() {
@@ -46,7 +46,9 @@ impl ModelValidator<ValidationError> for Resource {
                 AccountResourceAction::List | AccountResourceAction::Create => (),
                 AccountResourceAction::Transfer(resource_id)
                 | AccountResourceAction::Read(resource_id)
-                | AccountResourceAction::Update(resource_id) => {
+                | AccountResourceAction::Update(resource_id)
+                | AccountResourceAction::Reconcile(resource_id)
+                | AccountResourceAction::Review(resource_id) => {
                     EnsureAccount::resource_id_exists(resource_id)?
                 }
             },
@@ -145,6 +147,10 @@ pub enum AccountResourceAction {
     Transfer(ResourceId),
     Read(ResourceId),
     Update(ResourceId),
+    /// Annotating the completed transfers of the account with reconciliation notes.
+    Reconcile(ResourceId),
+    /// Flagging the account as under review, which blocks the creation of its transfer requests.
+    Review(ResourceId),
 }
 
 #[storable]
@@ -273,6 +279,41 @@ impl Resource {
         Resource::UserGroup(ResourceAction::Delete(ResourceId::Id([u8::MAX; 16])))
     }
 
+    /// Whether the resource only grants access to list or read data.
+    pub fn is_read_only(&self) -> bool {
+        match self {
+            Resource::Permission(action) => matches!(action, PermissionResourceAction::Read),
+            Resource::Account(action) => matches!(
+                action,
+                AccountResourceAction::List | AccountResourceAction::Read(_)
+            ),
+            Resource::AddressBook(action)
+            | Resource::RequestPolicy(action)
+            | Resource::UserGroup(action) => {
+                matches!(action, ResourceAction::List | ResourceAction::Read(_))
+            }
+            Resource::ExternalCanister(action) => matches!(
+                action,
+                ExternalCanisterResourceAction::List | ExternalCanisterResourceAction::Read(_)
+            ),
+            Resource::Notification(_) => false,
+            Resource::Request(action) => matches!(
+                action,
+                RequestResourceAction::List | RequestResourceAction::Read(_)
+            ),
+            Resource::System(action) => matches!(
+                action,
+                SystemResourceAction::SystemInfo | SystemResourceAction::Capabilities
+            ),
+            Resource::User(action) => {
+                matches!(
+                    action,
+                    UserResourceAction::List | UserResourceAction::Read(_)
+                )
+            }
+        }
+    }
+
     /// Returns the expanded list of resources that the resource represents.
     ///
     /// E.g. if the resource is for account_id = 1, it will also return the resource for account_id = any.
@@ -301,6 +342,18 @@ impl Resource {
                         Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
                     ]
                 }
+                AccountResourceAction::Reconcile(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Account(AccountResourceAction::Reconcile(ResourceId::Id(*id))),
+                        Resource::Account(AccountResourceAction::Reconcile(ResourceId::Any)),
+                    ]
+                }
+                AccountResourceAction::Review(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Account(AccountResourceAction::Review(ResourceId::Id(*id))),
+                        Resource::Account(AccountResourceAction::Review(ResourceId::Any)),
+                    ]
+                }
                 AccountResourceAction::Transfer(ResourceId::Any) => {
                     vec![Resource::Account(AccountResourceAction::Transfer(
                         ResourceId::Any,
@@ -316,6 +369,16 @@ impl Resource {
                         ResourceId::Any,
                     ))]
                 }
+                AccountResourceAction::Reconcile(ResourceId::Any) => {
+                    vec![Resource::Account(AccountResourceAction::Reconcile(
+                        ResourceId::Any,
+                    ))]
+                }
+                AccountResourceAction::Review(ResourceId::Any) => {
+                    vec![Resource::Account(AccountResourceAction::Review(
+                        ResourceId::Any,
+                    ))]
+                }
             },
             Resource::Permission(action) => match action {
                 PermissionResourceAction::Read => {
@@ -666,6 +729,8 @@ impl Display for AccountResourceAction {
             AccountResourceAction::Transfer(id) => write!(f, "Transfer({})", id),
             AccountResourceAction::Read(id) => write!(f, "Read({})", id),
             AccountResourceAction::Update(id) => write!(f, "Update({})", id),
+            AccountResourceAction::Reconcile(id) => write!(f, "Reconcile({})", id),
+            AccountResourceAction::Review(id) => write!(f, "Review({})", id),
         }
     }
 }
@@ -808,6 +873,19 @@ mod test {
     use candid::Principal;
     use orbit_essentials::model::ModelValidator;
 
+    #[test]
+    fn read_only_resources() {
+        assert!(Resource::Account(AccountResourceAction::List).is_read_only());
+        assert!(Resource::Request(RequestResourceAction::Read(ResourceId::Any)).is_read_only());
+        assert!(Resource::System(SystemResourceAction::SystemInfo).is_read_only());
+        assert!(
+            !Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)).is_read_only()
+        );
+        assert!(!Resource::Permission(PermissionResourceAction::Update).is_read_only());
+        assert!(!Resource::System(SystemResourceAction::Upgrade).is_read_only());
+        assert!(!Resource::UserGroup(ResourceAction::Create).is_read_only());
+    }
+
     #[test]
     fn test_resource_validation() {
         disable_mock_resource_validation();
@@ -818,6 +896,8 @@ mod test {
             Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
             Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
             Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+            Resource::Account(AccountResourceAction::Reconcile(ResourceId::Any)),
+            Resource::Account(AccountResourceAction::Review(ResourceId::Any)),
             Resource::Permission(PermissionResourceAction::Read),
             Resource::Permission(PermissionResourceAction::Update),
             Resource::AddressBook(ResourceAction::List),
@@ -873,6 +953,8 @@ mod test {
             Resource::Account(AccountResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::Account(AccountResourceAction::Update(ResourceId::Id([0; 16]))),
             Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([0; 16]))),
+            Resource::Account(AccountResourceAction::Reconcile(ResourceId::Id([0; 16]))),
+            Resource::Account(AccountResourceAction::Review(ResourceId::Id([0; 16]))),
             Resource::AddressBook(ResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::AddressBook(ResourceAction::Update(ResourceId::Id([0; 16]))),
             Resource::AddressBook(ResourceAction::Delete(ResourceId::Id([0; 16]))),
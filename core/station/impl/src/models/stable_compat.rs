@@ -0,0 +1,654 @@
+//! Compatibility tests of the models that are kept in stable memory.
+//!
+//! The fixtures are the bytes of the models as they were serialized by each stable memory version of the
+//! station, which the current code must still be able to decode after an upgrade. A change that can't decode
+//! the fixtures anymore (e.g. a removed enum variant or a new field without a default) is breaking and needs a
+//! migration in `migration.rs`, the fixtures of the previous versions must never be edited to make it pass.
+//!
+//! When the stable memory version is bumped, the fixtures of the new version are added to a new `v<version>`
+//! folder next to the existing ones, together with the tests that decode them.
+
+use super::{
+    permission::{Allow, AuthScope, Permission},
+    request_specifier::{RequestSpecifier, UserSpecifier},
+    resource::{
+        AccountResourceAction, ExternalCanisterId, ExternalCanisterResourceAction,
+        RequestResourceAction, Resource, ResourceAction, ResourceId, ResourceIds,
+        SystemResourceAction,
+    },
+    Account, AccountAddressReadyNotification, AccountReview, AccountSetupState,
+    AddUserGroupOperation, AddUserGroupOperationInput, AddressBookEntry, AddressBookEntryOrigin,
+    Blockchain, BlockchainStandard, CycleObtainStrategy, DisasterRecoveryCommittee,
+    ExternalCanister, ExternalCanisterModuleDriftedNotification, ExternalCanisterState,
+    JobsStalledNotification, Metadata, Notification, NotificationStatus, NotificationType,
+    Percentage, Request, RequestApproval, RequestApprovalStatus, RequestCost, RequestCostPhase,
+    RequestExecutionPlan, RequestNotification, RequestOperation, RequestPolicy,
+    RequestPolicyChangedNotification, RequestPolicyRule, RequestStatus, RequestSummaryFormat,
+    RetiredAccountAddress, ScheduledGroupMembership, SystemInfo, User, UserActivityAnomaly,
+    UserActivityAnomalyNotification, UserGroup, UserGroupMembershipChangedNotification,
+    UserGroupQuotas, UserStatus,
+};
+use crate::STABLE_MEMORY_VERSION;
+use candid::Principal;
+use orbit_essentials::types::Timestamp;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::BTreeMap, fmt::Debug};
+
+/// The stable memory versions that fixtures were recorded for, in ascending order.
+const FIXTURE_VERSIONS: [u32; 2] = [1, 3];
+
+const T: Timestamp = 1_710_843_144_770_000_000;
+const HOUR: Timestamp = 3_600_000_000_000;
+
+macro_rules! fixture {
+    ($version:literal, $name:literal) => {
+        Fixture {
+            version: $version,
+            name: $name,
+            hex: include_str!(concat!("stable_compat/v", $version, "/", $name, ".hex")),
+        }
+    };
+}
+
+struct Fixture {
+    version: u32,
+    name: &'static str,
+    hex: &'static str,
+}
+
+impl Fixture {
+    fn decode<T: DeserializeOwned>(&self) -> T {
+        let bytes = hex::decode(self.hex.split_whitespace().collect::<String>())
+            .unwrap_or_else(|err| panic!("the {} fixture is not valid hex: {}", self.name, err));
+
+        serde_cbor::from_slice(&bytes).unwrap_or_else(|err| {
+            panic!(
+                "the {} fixture of stable memory version {} can no longer be decoded, the change needs a migration: {}",
+                self.name, self.version, err
+            )
+        })
+    }
+
+    fn assert_decodes_to<T: DeserializeOwned + PartialEq + Debug>(&self, expected: T) {
+        assert_eq!(
+            self.decode::<T>(),
+            expected,
+            "the {} fixture of stable memory version {} is decoded differently",
+            self.name,
+            self.version
+        );
+    }
+}
+
+fn principal(byte: u8) -> Principal {
+    Principal::from_slice(&[byte; 29])
+}
+
+/// The variants of the enums that are stored as part of the models.
+#[derive(Debug, PartialEq, Deserialize)]
+struct EnumVariants {
+    blockchains: Vec<Blockchain>,
+    blockchain_standards: Vec<BlockchainStandard>,
+    user_statuses: Vec<UserStatus>,
+    account_setup_states: Vec<AccountSetupState>,
+    notification_statuses: Vec<NotificationStatus>,
+    notification_types: Vec<NotificationType>,
+    request_statuses: Vec<RequestStatus>,
+    request_approval_statuses: Vec<RequestApprovalStatus>,
+    request_execution_plans: Vec<RequestExecutionPlan>,
+    request_summary_formats: Vec<RequestSummaryFormat>,
+    request_cost_phases: Vec<RequestCostPhase>,
+    resources: Vec<Resource>,
+}
+
+#[test]
+fn fixtures_are_recorded_for_the_current_stable_memory_version() {
+    assert_eq!(
+        FIXTURE_VERSIONS.last(),
+        Some(&STABLE_MEMORY_VERSION),
+        "the fixtures of stable memory version {} must be recorded in `stable_compat/v{}`",
+        STABLE_MEMORY_VERSION,
+        STABLE_MEMORY_VERSION
+    );
+}
+
+#[test]
+fn decodes_users_of_v1() {
+    fixture!(1, "user").assert_decodes_to(User {
+        id: [1; 16],
+        name: "Finance Lead".to_string(),
+        status: UserStatus::Active,
+        identities: vec![principal(7)],
+        groups: vec![[2; 16]],
+        last_modification_timestamp: T,
+        version: 0,
+        last_active_at: None,
+        scheduled_memberships: vec![],
+    });
+}
+
+#[test]
+fn decodes_user_groups_of_v1() {
+    fixture!(1, "user_group").assert_decodes_to(UserGroup {
+        id: [2; 16],
+        name: "Finance".to_string(),
+        quotas: UserGroupQuotas::default(),
+        last_modification_timestamp: T,
+    });
+}
+
+#[test]
+fn decodes_accounts_of_v1() {
+    fixture!(1, "account").assert_decodes_to(Account {
+        id: [4; 16],
+        blockchain: Blockchain::InternetComputer,
+        address: "treasury-address".to_string(),
+        standard: BlockchainStandard::Native,
+        symbol: "ICP".to_string(),
+        decimals: 8,
+        name: "Treasury".to_string(),
+        balance: None,
+        metadata: Metadata::new(BTreeMap::from([(
+            "team".to_string(),
+            "finance".to_string(),
+        )])),
+        transfer_request_policy_id: Some([5; 16]),
+        configs_request_policy_id: Some([6; 16]),
+        last_modification_timestamp: T,
+        version: 0,
+        derivation_index: 0,
+        retired_addresses: vec![],
+        network: None,
+        review: None,
+        setup_state: AccountSetupState::Ready,
+    });
+}
+
+#[test]
+fn decodes_address_book_entries_of_v1() {
+    fixture!(1, "address_book_entry").assert_decodes_to(AddressBookEntry {
+        id: [9; 16],
+        address_owner: "Alice".to_string(),
+        address: "alice-address".to_string(),
+        blockchain: Blockchain::InternetComputer,
+        metadata: Metadata::default(),
+        labels: vec!["vendor".to_string()],
+        origin: None,
+        last_modification_timestamp: T,
+    });
+}
+
+#[test]
+fn decodes_notifications_of_v1() {
+    fixture!(1, "notification").assert_decodes_to(Notification {
+        id: [8; 16],
+        notification_type: NotificationType::RequestFailed(RequestNotification {
+            request_id: [6; 16],
+        }),
+        status: NotificationStatus::Read,
+        target_user_id: [1; 16],
+        title: "Add the finance group".to_string(),
+        message: None,
+        created_timestamp: T,
+        last_modification_timestamp: T,
+    });
+}
+
+#[test]
+fn decodes_requests_of_v1() {
+    fixture!(1, "request").assert_decodes_to(Request {
+        id: [6; 16],
+        title: "Add the finance group".to_string(),
+        summary: Some("Needed for the Q3 budget".to_string()),
+        summary_format: RequestSummaryFormat::PlainText,
+        requested_by: [1; 16],
+        status: RequestStatus::Completed {
+            completed_at: T + HOUR,
+        },
+        operation: RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: Some([2; 16]),
+            input: AddUserGroupOperationInput {
+                name: "Finance".to_string(),
+                quotas: UserGroupQuotas::default(),
+            },
+        }),
+        expiration_dt: T + 24 * HOUR,
+        execution_plan: RequestExecutionPlan::Scheduled {
+            execution_time: T + HOUR,
+            window_end: None,
+        },
+        depends_on: vec![],
+        approvals: vec![RequestApproval {
+            approver_id: [1; 16],
+            status: RequestApprovalStatus::Approved,
+            status_reason: Some("Looks good".to_string()),
+            acknowledged_checklist: vec![],
+            decided_dt: T,
+            last_modification_timestamp: T,
+        }],
+        policy_snapshot: None,
+        execution_steps: vec![],
+        confidential: false,
+        compacted_at: None,
+        cost: RequestCost::default(),
+        created_timestamp: T,
+        last_modification_timestamp: T + HOUR,
+    });
+}
+
+#[test]
+fn decodes_request_policies_of_v1() {
+    fixture!(1, "request_policy").assert_decodes_to(RequestPolicy {
+        id: [5; 16],
+        specifier: RequestSpecifier::Transfer(ResourceIds::Ids(vec![[4; 16]])),
+        rule: RequestPolicyRule::Or(vec![
+            RequestPolicyRule::Quorum(UserSpecifier::Any, 2),
+            RequestPolicyRule::QuorumPercentage(
+                UserSpecifier::Group(vec![[2; 16]]),
+                Percentage(51),
+            ),
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::AllowListed)),
+        ]),
+        checklist: vec![],
+        exclude_requester: false,
+        version: 0,
+    });
+}
+
+#[test]
+fn decodes_permissions_of_v1() {
+    fixture!(1, "permission").assert_decodes_to(Permission::new(
+        Allow {
+            auth_scope: AuthScope::Restricted,
+            users: vec![[1; 16]],
+            user_groups: vec![[2; 16]],
+        },
+        Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([4; 16]))),
+    ));
+}
+
+#[test]
+fn decodes_external_canisters_of_v1() {
+    fixture!(1, "external_canister").assert_decodes_to(ExternalCanister {
+        id: [13; 16],
+        canister_id: principal(14),
+        name: "Treasury dapp".to_string(),
+        description: Some("The frontend".to_string()),
+        labels: vec!["finance".to_string()],
+        state: ExternalCanisterState::Archived,
+        repository_url: None,
+        module_hash: None,
+        snapshots: vec![],
+        created_at: T,
+        modified_at: Some(T + HOUR),
+    });
+}
+
+#[test]
+fn decodes_the_system_info_of_v1() {
+    // The fields of the system info are private, so the decoded value is checked through its getters.
+    let system_info = fixture!(1, "system_info").decode::<SystemInfo>();
+
+    assert_eq!(system_info.get_name(), "Treasury Station");
+    assert_eq!(system_info.get_last_upgrade_timestamp(), T);
+    assert_eq!(system_info.get_change_canister_request(), Some(&[6; 16]));
+    assert_eq!(system_info.get_upgrader_canister_id(), &principal(15));
+    assert_eq!(system_info.get_upgrader_wasm_module(), b"\0asm");
+    assert_eq!(
+        system_info.get_disaster_recovery_committee(),
+        Some(&DisasterRecoveryCommittee {
+            user_group_id: [2; 16],
+            quorum: 1,
+        })
+    );
+    assert_eq!(
+        system_info.get_cycle_obtain_strategy(),
+        &CycleObtainStrategy::MintFromNativeToken {
+            account_id: [4; 16]
+        }
+    );
+    assert_eq!(system_info.get_version(), "0.0.2-alpha.6");
+    assert_eq!(system_info.get_stable_memory_version(), 1);
+}
+
+#[test]
+fn decodes_users_of_v3() {
+    let user = User {
+        id: [1; 16],
+        name: "Finance Lead".to_string(),
+        status: UserStatus::Active,
+        identities: vec![principal(7)],
+        groups: vec![[2; 16]],
+        last_modification_timestamp: T,
+        version: 4,
+        last_active_at: Some(T - HOUR),
+        scheduled_memberships: vec![ScheduledGroupMembership {
+            group_id: [3; 16],
+            starts_at: None,
+            expires_at: Some(T + 24 * HOUR),
+        }],
+    };
+
+    fixture!(3, "user").assert_decodes_to(user.clone());
+    fixture!(3, "user_without_defaults").assert_decodes_to(User {
+        status: UserStatus::Inactive,
+        version: 0,
+        last_active_at: None,
+        scheduled_memberships: vec![],
+        ..user
+    });
+}
+
+#[test]
+fn decodes_user_groups_of_v3() {
+    let user_group = UserGroup {
+        id: [2; 16],
+        name: "Finance".to_string(),
+        quotas: UserGroupQuotas {
+            max_pending_requests: Some(10),
+            max_accounts: None,
+        },
+        last_modification_timestamp: T,
+    };
+
+    fixture!(3, "user_group").assert_decodes_to(user_group.clone());
+    fixture!(3, "user_group_without_defaults").assert_decodes_to(UserGroup {
+        quotas: UserGroupQuotas::default(),
+        ..user_group
+    });
+}
+
+#[test]
+fn decodes_accounts_of_v3() {
+    let account = Account {
+        id: [4; 16],
+        blockchain: Blockchain::InternetComputer,
+        address: "treasury-address".to_string(),
+        standard: BlockchainStandard::ICRC1,
+        symbol: "ckBTC".to_string(),
+        decimals: 8,
+        name: "Treasury".to_string(),
+        balance: None,
+        metadata: Metadata::new(BTreeMap::from([(
+            "team".to_string(),
+            "finance".to_string(),
+        )])),
+        transfer_request_policy_id: Some([5; 16]),
+        configs_request_policy_id: None,
+        last_modification_timestamp: T,
+        version: 2,
+        derivation_index: 1,
+        retired_addresses: vec![RetiredAccountAddress {
+            address: "retired-address".to_string(),
+            derivation_index: 0,
+            retired_at: T - HOUR,
+            grace_period_ends_at: T + HOUR,
+        }],
+        network: Some("mainnet".to_string()),
+        review: Some(AccountReview {
+            reason: "Q3 policy audit".to_string(),
+            flagged_by: [1; 16],
+            flagged_at: T,
+            expires_at: T + 24 * HOUR,
+        }),
+        setup_state: AccountSetupState::PendingAddressGeneration {
+            attempts: 2,
+            last_error: "signing failed".to_string(),
+            next_attempt_at: T + HOUR,
+        },
+    };
+
+    fixture!(3, "account").assert_decodes_to(account.clone());
+    fixture!(3, "account_without_defaults").assert_decodes_to(Account {
+        version: 0,
+        derivation_index: 0,
+        retired_addresses: vec![],
+        network: None,
+        review: None,
+        setup_state: AccountSetupState::Ready,
+        ..account
+    });
+}
+
+#[test]
+fn decodes_address_book_entries_of_v3() {
+    let entry = AddressBookEntry {
+        id: [9; 16],
+        address_owner: "Alice".to_string(),
+        address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+        blockchain: Blockchain::Ethereum,
+        metadata: Metadata::default(),
+        labels: vec!["vendor".to_string()],
+        origin: Some(AddressBookEntryOrigin {
+            source_id: principal(11),
+            source_entry_id: [10; 16],
+            synced_at: T,
+        }),
+        last_modification_timestamp: T,
+    };
+
+    fixture!(3, "address_book_entry").assert_decodes_to(entry.clone());
+    fixture!(3, "address_book_entry_without_defaults").assert_decodes_to(AddressBookEntry {
+        labels: vec![],
+        origin: None,
+        ..entry
+    });
+}
+
+#[test]
+fn decodes_notifications_of_v3() {
+    fixture!(3, "notification").assert_decodes_to(Notification {
+        id: [8; 16],
+        notification_type: NotificationType::RequestCreated(RequestNotification {
+            request_id: [6; 16],
+        }),
+        status: NotificationStatus::Sent,
+        target_user_id: [1; 16],
+        title: "Add the finance group".to_string(),
+        message: Some("Your approval is needed".to_string()),
+        created_timestamp: T,
+        last_modification_timestamp: T,
+    });
+}
+
+#[test]
+fn decodes_requests_of_v3() {
+    let approval = RequestApproval {
+        approver_id: [1; 16],
+        status: RequestApprovalStatus::Approved,
+        status_reason: Some("Looks good".to_string()),
+        acknowledged_checklist: vec!["Budget approved".to_string()],
+        decided_dt: T,
+        last_modification_timestamp: T,
+    };
+    let request = Request {
+        id: [6; 16],
+        title: "Add the finance group".to_string(),
+        summary: Some("Needed for the Q3 budget".to_string()),
+        summary_format: RequestSummaryFormat::Markdown,
+        requested_by: [1; 16],
+        status: RequestStatus::Scheduled {
+            scheduled_at: T + HOUR,
+        },
+        operation: RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "Finance".to_string(),
+                quotas: UserGroupQuotas {
+                    max_pending_requests: None,
+                    max_accounts: Some(5),
+                },
+            },
+        }),
+        expiration_dt: T + 24 * HOUR,
+        execution_plan: RequestExecutionPlan::Scheduled {
+            execution_time: T + HOUR,
+            window_end: Some(T + 2 * HOUR),
+        },
+        depends_on: vec![[12; 16]],
+        approvals: vec![approval.clone()],
+        policy_snapshot: None,
+        execution_steps: vec![],
+        confidential: true,
+        compacted_at: None,
+        cost: RequestCost {
+            creation_instructions: 1_200_000,
+            evaluation_instructions: 3_400_000,
+            evaluations: 2,
+            execution_instructions: 0,
+            expensive_phases: vec![RequestCostPhase::Evaluation],
+        },
+        created_timestamp: T,
+        last_modification_timestamp: T,
+    };
+
+    fixture!(3, "request").assert_decodes_to(request.clone());
+    fixture!(3, "request_without_defaults").assert_decodes_to(Request {
+        summary: None,
+        summary_format: RequestSummaryFormat::PlainText,
+        status: RequestStatus::Created,
+        operation: RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "Finance".to_string(),
+                quotas: UserGroupQuotas::default(),
+            },
+        }),
+        execution_plan: RequestExecutionPlan::Immediate,
+        depends_on: vec![],
+        approvals: vec![RequestApproval {
+            status: RequestApprovalStatus::Rejected,
+            status_reason: None,
+            acknowledged_checklist: vec![],
+            ..approval
+        }],
+        confidential: false,
+        cost: RequestCost::default(),
+        ..request
+    });
+}
+
+#[test]
+fn decodes_enum_variants_of_v3() {
+    let request_notification = RequestNotification {
+        request_id: [6; 16],
+    };
+
+    fixture!(3, "enum_variants").assert_decodes_to(EnumVariants {
+        blockchains: vec![
+            Blockchain::InternetComputer,
+            Blockchain::Ethereum,
+            Blockchain::Bitcoin,
+        ],
+        blockchain_standards: vec![
+            BlockchainStandard::Native,
+            BlockchainStandard::ICRC1,
+            BlockchainStandard::ERC20,
+        ],
+        user_statuses: vec![UserStatus::Active, UserStatus::Inactive],
+        account_setup_states: vec![
+            AccountSetupState::Ready,
+            AccountSetupState::PendingAddressGeneration {
+                attempts: 1,
+                last_error: "signing failed".to_string(),
+                next_attempt_at: T,
+            },
+        ],
+        notification_statuses: vec![NotificationStatus::Sent, NotificationStatus::Read],
+        notification_types: vec![
+            NotificationType::SystemMessage,
+            NotificationType::RequestCreated(request_notification.clone()),
+            NotificationType::RequestFailed(request_notification.clone()),
+            NotificationType::RequestRejected(request_notification.clone()),
+            NotificationType::ExternalCanisterModuleDrifted(
+                ExternalCanisterModuleDriftedNotification {
+                    external_canister_id: [13; 16],
+                    canister_id: principal(14),
+                    expected_module_hash: Some(vec![1, 2, 3]),
+                    found_module_hash: None,
+                },
+            ),
+            NotificationType::UserActivityAnomaly(UserActivityAnomalyNotification {
+                user_id: [1; 16],
+                anomaly: UserActivityAnomaly::RequestCreationSpike {
+                    requests_created: 40,
+                },
+            }),
+            NotificationType::UserActivityAnomaly(UserActivityAnomalyNotification {
+                user_id: [1; 16],
+                anomaly: UserActivityAnomaly::DormantUserApproval {
+                    request_id: [6; 16],
+                    last_active_at: T - HOUR,
+                },
+            }),
+            NotificationType::RequestExecuted(request_notification.clone()),
+            NotificationType::RequestPolicyChanged(RequestPolicyChangedNotification {
+                request_id: [6; 16],
+                policy_id: [5; 16],
+            }),
+            NotificationType::RequestApprovalReminder(request_notification),
+            NotificationType::UserGroupMembershipChanged(UserGroupMembershipChangedNotification {
+                user_id: [1; 16],
+                user_group_id: [3; 16],
+                joined: true,
+            }),
+            NotificationType::JobsStalled(JobsStalledNotification {
+                overdue_jobs: vec!["execute_scheduled_requests".to_string()],
+                stalled_executions: vec![[6; 16]],
+                stalled_expirations: vec![],
+            }),
+            NotificationType::AccountAddressReady(AccountAddressReadyNotification {
+                account_id: [4; 16],
+            }),
+        ],
+        request_statuses: vec![
+            RequestStatus::Created,
+            RequestStatus::Approved,
+            RequestStatus::Rejected,
+            RequestStatus::Scheduled { scheduled_at: T },
+            RequestStatus::Cancelled {
+                reason: Some("No longer needed".to_string()),
+            },
+            RequestStatus::Processing { started_at: T },
+            RequestStatus::Completed { completed_at: T },
+            RequestStatus::Failed { reason: None },
+        ],
+        request_approval_statuses: vec![
+            RequestApprovalStatus::Approved,
+            RequestApprovalStatus::Rejected,
+        ],
+        request_execution_plans: vec![
+            RequestExecutionPlan::Immediate,
+            RequestExecutionPlan::Scheduled {
+                execution_time: T,
+                window_end: Some(T + HOUR),
+            },
+            RequestExecutionPlan::Scheduled {
+                execution_time: T,
+                window_end: None,
+            },
+        ],
+        request_summary_formats: vec![
+            RequestSummaryFormat::PlainText,
+            RequestSummaryFormat::Markdown,
+        ],
+        request_cost_phases: RequestCostPhase::ALL.to_vec(),
+        resources: vec![
+            Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([4; 16]))),
+            Resource::ExternalCanister(ExternalCanisterResourceAction::Change(
+                ExternalCanisterId::Any,
+            )),
+            Resource::Request(RequestResourceAction::List),
+            Resource::System(SystemResourceAction::ManageSystemInfo),
+            Resource::UserGroup(ResourceAction::Read(ResourceId::Any)),
+        ],
+    });
+}
+
+#[test]
+fn decodes_resources_stored_before_the_external_canister_actions() {
+    fixture!(3, "legacy_resources").assert_decodes_to(vec![
+        Resource::System(SystemResourceAction::Upgrade),
+        Resource::ExternalCanister(ExternalCanisterResourceAction::Create),
+    ]);
+}
@@ -0,0 +1,253 @@
+use super::{AccountId, ExternalCanisterEntryId, RequestId};
+use crate::errors::StationEventError;
+use candid::Principal;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The event subscriber id, which is a UUID.
+pub type EventSubscriberId = UUID;
+
+/// The kinds of events that the station emits.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StationEventKind {
+    RequestExecuted,
+    DepositDetected,
+    ExternalCanisterModuleDrifted,
+}
+
+/// An event emitted by the station that is delivered to the subscribed canisters.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StationEvent {
+    RequestExecuted {
+        request_id: RequestId,
+        completed_at: Timestamp,
+    },
+    /// The balance of an account increased when it was refreshed.
+    DepositDetected {
+        account_id: AccountId,
+        amount: candid::Nat,
+        balance: candid::Nat,
+        detected_at: Timestamp,
+    },
+    /// The module of an external canister changed without an executed request of the station.
+    ExternalCanisterModuleDrifted {
+        external_canister_id: ExternalCanisterEntryId,
+        canister_id: Principal,
+        expected_module_hash: Option<Vec<u8>>,
+        found_module_hash: Option<Vec<u8>>,
+        detected_at: Timestamp,
+    },
+}
+
+impl StationEvent {
+    pub fn kind(&self) -> StationEventKind {
+        match self {
+            StationEvent::RequestExecuted { .. } => StationEventKind::RequestExecuted,
+            StationEvent::DepositDetected { .. } => StationEventKind::DepositDetected,
+            StationEvent::ExternalCanisterModuleDrifted { .. } => {
+                StationEventKind::ExternalCanisterModuleDrifted
+            }
+        }
+    }
+}
+
+/// A canister that is subscribed to station events.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSubscriber {
+    pub id: EventSubscriberId,
+    pub canister_id: Principal,
+    /// The method of the canister that receives the events.
+    pub method_name: String,
+    pub events: Vec<StationEventKind>,
+    pub created_at: Timestamp,
+    pub acknowledged_events: u64,
+    pub last_acknowledged_at: Option<Timestamp>,
+    /// The error of the last failed delivery, cleared on the next successful one.
+    pub last_error: Option<String>,
+}
+
+impl ModelKey<EventSubscriberId> for EventSubscriber {
+    fn key(&self) -> EventSubscriberId {
+        self.id
+    }
+}
+
+impl EventSubscriber {
+    pub const METHOD_NAME_RANGE: (usize, usize) = (1, 100);
+    pub const MAX_SUBSCRIBERS: usize = 20;
+
+    pub fn is_subscribed_to(&self, kind: StationEventKind) -> bool {
+        self.events.contains(&kind)
+    }
+}
+
+impl ModelValidator<StationEventError> for EventSubscriber {
+    fn validate(&self) -> ModelValidatorResult<StationEventError> {
+        let (min_length, max_length) = Self::METHOD_NAME_RANGE;
+        if self.method_name.len() < min_length || self.method_name.len() > max_length {
+            return Err(StationEventError::ValidationError {
+                info: format!(
+                    "The method name length must be between {} and {}",
+                    min_length, max_length
+                ),
+            });
+        }
+
+        if self.events.is_empty() {
+            return Err(StationEventError::ValidationError {
+                info: "At least one event kind must be subscribed to".to_string(),
+            });
+        }
+
+        // only canisters can receive events, their ids are opaque principals
+        if self.canister_id.as_slice().last() != Some(&0x01) {
+            return Err(StationEventError::SubscriberNotCanister {
+                principal: self.canister_id.to_text(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The entries are ordered by subscriber first, so that the events of a subscriber are next to each other.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EventOutboxEntryKey {
+    pub subscriber_id: EventSubscriberId,
+    pub event_id: u64,
+}
+
+/// An event that is waiting to be acknowledged by a subscriber.
+///
+/// The entry is removed once the subscriber acknowledged the event, failed deliveries are retried with
+/// an increasing delay.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventOutboxEntry {
+    pub event_id: u64,
+    pub subscriber_id: EventSubscriberId,
+    pub event: StationEvent,
+    pub attempts: u32,
+    pub next_attempt_at: Timestamp,
+    pub created_at: Timestamp,
+}
+
+impl ModelKey<EventOutboxEntryKey> for EventOutboxEntry {
+    fn key(&self) -> EventOutboxEntryKey {
+        EventOutboxEntryKey {
+            subscriber_id: self.subscriber_id,
+            event_id: self.event_id,
+        }
+    }
+}
+
+impl EventOutboxEntry {
+    pub const RETRY_BASE_DELAY_NS: u64 = 10 * 1_000_000_000;
+    pub const RETRY_MAX_DELAY_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    /// Registers a failed delivery and schedules the next attempt with an exponential backoff.
+    pub fn register_failed_attempt(&mut self, now: Timestamp) {
+        self.attempts = self.attempts.saturating_add(1);
+        self.next_attempt_at = now.saturating_add(Self::retry_delay(self.attempts));
+    }
+
+    /// The delay of the next attempt after the given number of failed attempts.
+    pub fn retry_delay(attempts: u32) -> u64 {
+        Self::RETRY_BASE_DELAY_NS
+            .saturating_mul(2u64.saturating_pow(attempts.saturating_sub(1)))
+            .min(Self::RETRY_MAX_DELAY_NS)
+    }
+}
+
+#[cfg(test)]
+pub mod station_event_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_event_subscriber() -> EventSubscriber {
+        EventSubscriber {
+            id: *Uuid::new_v4().as_bytes(),
+            canister_id: Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap(),
+            method_name: "on_station_event".to_string(),
+            events: vec![
+                StationEventKind::RequestExecuted,
+                StationEventKind::DepositDetected,
+            ],
+            created_at: 0,
+            acknowledged_events: 0,
+            last_acknowledged_at: None,
+            last_error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::station_event_test_utils::mock_event_subscriber;
+    use super::*;
+
+    #[test]
+    fn subscriber_must_be_a_canister() {
+        let mut subscriber = mock_event_subscriber();
+        assert!(subscriber.validate().is_ok());
+
+        subscriber.canister_id = Principal::from_slice(&[1; 29]);
+        assert!(matches!(
+            subscriber.validate(),
+            Err(StationEventError::SubscriberNotCanister { .. })
+        ));
+    }
+
+    #[test]
+    fn subscriber_method_name_and_events_are_validated() {
+        let mut subscriber = mock_event_subscriber();
+        subscriber.method_name = String::new();
+        assert!(subscriber.validate().is_err());
+
+        let mut subscriber = mock_event_subscriber();
+        subscriber.events = vec![];
+        assert!(subscriber.validate().is_err());
+    }
+
+    #[test]
+    fn failed_attempts_back_off_exponentially() {
+        let mut entry = EventOutboxEntry {
+            event_id: 1,
+            subscriber_id: [0; 16],
+            event: StationEvent::RequestExecuted {
+                request_id: [1; 16],
+                completed_at: 0,
+            },
+            attempts: 0,
+            next_attempt_at: 0,
+            created_at: 0,
+        };
+
+        entry.register_failed_attempt(100);
+        assert_eq!(
+            entry.next_attempt_at,
+            100 + EventOutboxEntry::RETRY_BASE_DELAY_NS
+        );
+
+        entry.register_failed_attempt(100);
+        assert_eq!(
+            entry.next_attempt_at,
+            100 + 2 * EventOutboxEntry::RETRY_BASE_DELAY_NS
+        );
+
+        for _ in 0..64 {
+            entry.register_failed_attempt(100);
+        }
+        assert_eq!(
+            entry.next_attempt_at,
+            100 + EventOutboxEntry::RETRY_MAX_DELAY_NS
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use orbit_essentials::{
+    model::ModelKey,
+    storable,
+    types::{Timestamp, UUID},
+};
+use std::fmt::{Display, Formatter};
+
+/// The severity of a station log entry.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug = 0,
+    Info = 1,
+    Warn = 2,
+    Error = 3,
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevel::Debug => write!(f, "DEBUG"),
+            LogLevel::Info => write!(f, "INFO"),
+            LogLevel::Warn => write!(f, "WARN"),
+            LogLevel::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// A log entry written by the station, kept in a bounded ring buffer in stable memory so that it's
+/// still available after upgrades for post-incident debugging.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StationLogEntry {
+    /// The index of the entry, which increases monotonically.
+    pub idx: u64,
+    /// When the entry was written.
+    pub timestamp: Timestamp,
+    pub level: LogLevel,
+    /// The module path of the code that wrote the entry (e.g. `station::services::system`).
+    pub module: String,
+    pub message: String,
+    /// The correlation id of the trace the entry was written for, if any.
+    #[serde(default)]
+    pub correlation_id: Option<UUID>,
+}
+
+impl StationLogEntry {
+    /// The maximum number of entries retained by the station, the oldest entries are dropped first.
+    pub const MAX_RETAINED_ENTRIES: u64 = 10_000;
+    /// The maximum length of the message of an entry, longer messages are truncated.
+    pub const MAX_MESSAGE_LEN: usize = 1_000;
+}
+
+impl ModelKey<u64> for StationLogEntry {
+    fn key(&self) -> u64 {
+        self.idx
+    }
+}
@@ -0,0 +1,608 @@
+//! Property based tests for the serialization of the models and indexes that are kept in stable memory.
+//!
+//! Every model is checked to survive a round trip through its stable memory representation, and malformed
+//! bytes are fed into the decoder that backs `from_bytes` to make sure that they surface as errors instead of
+//! traps, the `fuzz` crate next to this one feeds the same decoders with arbitrary bytes.
+
+use super::{
+    blob_test_utils::mock_blob,
+    external_canister_test_utils::mock_external_canister,
+    indexes::{
+        notification_user_index::NotificationUserIndex,
+        request_index::{RequestIndexFields, RequestIndexKey, RequestIndexKeyKind},
+        transfer_account_index::TransferAccountIndex,
+        transfer_status_index::TransferStatusIndex,
+        unique_index::UniqueIndexKey,
+        user_status_group_index::UserStatusGroupIndex,
+    },
+    notification_test_utils::mock_notification,
+    permission::{permission_test_utils::mock_permission, Allow, Permission},
+    principal_label_test_utils::mock_principal_label,
+    request_policy_test_utils::mock_request_policy,
+    request_test_utils::{mock_archived_request, mock_request},
+    station_event_test_utils::mock_event_subscriber,
+    vote_receipt_test_utils::mock_vote_receipt,
+    Account, AccountBalance, AccountSetupState, AddressBookEntry, ArchivedRequest, Blob,
+    Blockchain, BlockchainStandard, EventSubscriber, ExternalCanister, Metadata, Notification,
+    PrincipalLabel, Request, RequestExecutionStep, RequestExecutionStepStatus, RequestPolicy,
+    RequestStatusCode, ScheduledGroupMembership, SystemInfo, Transfer, TransferComplianceInfo,
+    TransferStatus, TransferSubmission, User, UserGroup, UserStatus, VoteReceipt,
+};
+use candid::Principal;
+use ic_stable_structures::Storable;
+use orbit_essentials::{model::TryFromStableBytes, types::UUID};
+use proptest::prelude::*;
+use std::{borrow::Cow, collections::BTreeMap, fmt::Debug};
+
+fn assert_round_trip<T: Storable + PartialEq + Debug>(value: T) {
+    let bytes = value.to_bytes();
+    let decoded = T::from_bytes(Cow::Owned(bytes.into_owned()));
+
+    assert_eq!(decoded, value);
+}
+
+/// Decoding malformed bytes must fail gracefully, since the decoder of `from_bytes` would otherwise trap the
+/// canister with an unhelpful message.
+fn assert_malformed_bytes_do_not_panic<T: TryFromStableBytes>(bytes: &[u8]) {
+    let result = std::panic::catch_unwind(|| T::try_from_bytes(bytes).is_ok());
+
+    assert!(result.is_ok(), "decoding malformed bytes panicked");
+}
+
+/// `from_bytes` either decodes the bytes or panics with the error of the decoder, never with another panic.
+fn assert_from_bytes_matches_the_decoder<T: Storable + TryFromStableBytes + PartialEq + Debug>(
+    bytes: &[u8],
+) {
+    let decoded = T::try_from_bytes(bytes);
+    let result = std::panic::catch_unwind(|| T::from_bytes(Cow::Borrowed(bytes)));
+
+    match (decoded, result) {
+        (Ok(decoded), Ok(from_bytes)) => assert_eq!(decoded, from_bytes),
+        (Err(_), Err(panic)) => {
+            let message = panic
+                .downcast_ref::<String>()
+                .expect("the panic should carry the error of the decoder");
+
+            assert!(message.starts_with("Failed to decode"), "{}", message);
+        }
+        (decoded, result) => panic!(
+            "from_bytes disagrees with the decoder: {:?} vs {:?}",
+            decoded.is_ok(),
+            result.is_ok()
+        ),
+    }
+}
+
+fn arb_uuid() -> impl Strategy<Value = UUID> {
+    any::<[u8; 16]>()
+}
+
+fn arb_principal() -> impl Strategy<Value = Principal> {
+    prop::collection::vec(any::<u8>(), 0..=29).prop_map(|bytes| Principal::from_slice(&bytes))
+}
+
+fn arb_nat() -> impl Strategy<Value = candid::Nat> {
+    any::<u128>().prop_map(candid::Nat::from)
+}
+
+fn arb_metadata() -> impl Strategy<Value = Metadata> {
+    prop::collection::btree_map(".{0,24}", ".{0,255}", 0..10)
+        .prop_map(|metadata: BTreeMap<String, String>| Metadata::new(metadata))
+}
+
+fn arb_blockchain() -> impl Strategy<Value = Blockchain> {
+    prop_oneof![
+        Just(Blockchain::InternetComputer),
+        Just(Blockchain::Ethereum),
+        Just(Blockchain::Bitcoin),
+    ]
+}
+
+fn arb_blockchain_standard() -> impl Strategy<Value = BlockchainStandard> {
+    prop_oneof![
+        Just(BlockchainStandard::Native),
+        Just(BlockchainStandard::ICRC1),
+        Just(BlockchainStandard::ERC20),
+    ]
+}
+
+fn arb_user_status() -> impl Strategy<Value = UserStatus> {
+    prop_oneof![Just(UserStatus::Active), Just(UserStatus::Inactive)]
+}
+
+fn arb_request_status_code() -> impl Strategy<Value = RequestStatusCode> {
+    prop_oneof![
+        Just(RequestStatusCode::Created),
+        Just(RequestStatusCode::Approved),
+        Just(RequestStatusCode::Rejected),
+        Just(RequestStatusCode::Cancelled),
+        Just(RequestStatusCode::Scheduled),
+        Just(RequestStatusCode::Processing),
+        Just(RequestStatusCode::Completed),
+        Just(RequestStatusCode::Failed),
+    ]
+}
+
+prop_compose! {
+    fn arb_user()(
+        id in arb_uuid(),
+        name in ".{0,100}",
+        status in arb_user_status(),
+        identities in prop::collection::vec(arb_principal(), 0..5),
+        groups in prop::collection::vec(arb_uuid(), 0..5),
+        last_modification_timestamp in any::<u64>(),
+        version in any::<u64>(),
+        last_active_at in any::<Option<u64>>(),
+        scheduled_memberships in prop::collection::vec(
+            (arb_uuid(), any::<Option<u64>>(), any::<Option<u64>>()),
+            0..3,
+        ),
+    ) -> User {
+        let scheduled_memberships = scheduled_memberships
+            .into_iter()
+            .map(|(group_id, starts_at, expires_at)| ScheduledGroupMembership { group_id, starts_at, expires_at })
+            .collect();
+
+        User { id, name, status, identities, groups, last_modification_timestamp, version, last_active_at, scheduled_memberships }
+    }
+}
+
+prop_compose! {
+    fn arb_user_group()(
+        id in arb_uuid(),
+        name in ".{0,50}",
+        last_modification_timestamp in any::<u64>(),
+    ) -> UserGroup {
+        UserGroup { id, name, quotas: Default::default(), last_modification_timestamp }
+    }
+}
+
+prop_compose! {
+    fn arb_account()(
+        id in arb_uuid(),
+        blockchain in arb_blockchain(),
+        address in ".{0,255}",
+        standard in arb_blockchain_standard(),
+        symbol in ".{0,8}",
+        decimals in any::<u32>(),
+        name in ".{0,64}",
+        balance in prop::option::of((arb_nat(), any::<u64>())),
+        metadata in arb_metadata(),
+        transfer_request_policy_id in prop::option::of(arb_uuid()),
+        configs_request_policy_id in prop::option::of(arb_uuid()),
+        last_modification_timestamp in any::<u64>(),
+        version in any::<u64>(),
+    ) -> Account {
+        Account {
+            id,
+            blockchain,
+            address,
+            standard,
+            symbol,
+            decimals,
+            name,
+            balance: balance.map(|(balance, last_modification_timestamp)| AccountBalance {
+                balance,
+                last_modification_timestamp,
+            }),
+            metadata,
+            transfer_request_policy_id,
+            configs_request_policy_id,
+            last_modification_timestamp,
+            version,
+            derivation_index: 0,
+            retired_addresses: vec![],
+                network: None,
+            review: None,
+            setup_state: AccountSetupState::Ready,
+        }
+    }
+}
+
+prop_compose! {
+    fn arb_address_book_entry()(
+        id in arb_uuid(),
+        address_owner in ".{0,255}",
+        address in ".{0,255}",
+        blockchain in arb_blockchain(),
+        metadata in arb_metadata(),
+        labels in prop::collection::vec(".{0,150}", 0..10),
+        last_modification_timestamp in any::<u64>(),
+    ) -> AddressBookEntry {
+        AddressBookEntry {
+            id,
+            address_owner,
+            address,
+            blockchain,
+            metadata,
+            labels,
+            origin: None,
+            last_modification_timestamp,
+        }
+    }
+}
+
+fn arb_transfer_status() -> impl Strategy<Value = TransferStatus> {
+    prop_oneof![
+        Just(TransferStatus::Created),
+        any::<u64>().prop_map(|started_at| TransferStatus::Processing { started_at }),
+        (prop::option::of(".*"), prop::option::of(".*"), any::<u64>()).prop_map(
+            |(signature, hash, completed_at)| TransferStatus::Completed {
+                signature,
+                hash,
+                completed_at,
+            }
+        ),
+        ".*".prop_map(|reason| TransferStatus::Failed { reason }),
+    ]
+}
+
+prop_compose! {
+    fn arb_transfer()(
+        id in arb_uuid(),
+        initiator_user in arb_uuid(),
+        from_account in arb_uuid(),
+        to_address in ".{0,255}",
+        status in arb_transfer_status(),
+        amount in arb_nat(),
+        request_id in arb_uuid(),
+        fee in arb_nat(),
+        blockchain_network in ".{0,32}",
+        metadata in arb_metadata(),
+        last_modification_timestamp in any::<u64>(),
+        created_timestamp in any::<u64>(),
+        submission in prop::option::of((any::<u64>(), any::<u32>(), prop::option::of(any::<u64>()))),
+        compliance in prop::option::of((
+            prop::option::of(".{0,32}"),
+            prop::option::of(".{0,32}"),
+            prop::option::of(".{0,32}"),
+            prop::option::of("[A-Z]{4}"),
+        )),
+    ) -> Transfer {
+        Transfer {
+            id,
+            initiator_user,
+            from_account,
+            to_address,
+            status,
+            amount,
+            request_id,
+            fee,
+            blockchain_network,
+            metadata,
+            last_modification_timestamp,
+            created_timestamp,
+            submission: submission.map(|(created_at, attempts, reconciled_block_height)| {
+                TransferSubmission {
+                    created_at,
+                    attempts,
+                    reconciled_block_height,
+                }
+            }),
+            compliance: compliance.map(
+                |(beneficiary_name, originator_name, originator_identifier, purpose_code)| {
+                    TransferComplianceInfo {
+                        beneficiary_name,
+                        originator_name,
+                        originator_identifier,
+                        purpose_code,
+                    }
+                },
+            ),
+            from_address: None,
+        }
+    }
+}
+
+fn arb_request_execution_step() -> impl Strategy<Value = RequestExecutionStep> {
+    let status = prop_oneof![
+        (prop::option::of(".*"), any::<u64>()).prop_map(|(output, completed_at)| {
+            RequestExecutionStepStatus::Completed {
+                output,
+                completed_at,
+            }
+        }),
+        (".*", any::<u64>()).prop_map(|(reason, failed_at)| RequestExecutionStepStatus::Failed {
+            reason,
+            failed_at
+        }),
+    ];
+
+    (".{0,64}", status, any::<u32>()).prop_map(|(name, status, attempts)| RequestExecutionStep {
+        name,
+        status,
+        attempts,
+    })
+}
+
+fn arb_unique_index_key() -> impl Strategy<Value = UniqueIndexKey> {
+    prop_oneof![
+        ".*".prop_map(UniqueIndexKey::AccountName),
+        (".*", ".*").prop_map(|(blockchain, address)| {
+            UniqueIndexKey::AddressBookBlockchainAddress(blockchain, address)
+        }),
+        arb_principal().prop_map(UniqueIndexKey::ExternalCanisterId),
+        ".*".prop_map(UniqueIndexKey::ExternalCanisterName),
+        ".*".prop_map(UniqueIndexKey::UserGroupName),
+        arb_principal().prop_map(UniqueIndexKey::UserIdentity),
+        ".*".prop_map(UniqueIndexKey::UserName),
+    ]
+}
+
+fn arb_request_index_key() -> impl Strategy<Value = RequestIndexKey> {
+    let kind = prop_oneof![
+        any::<u64>().prop_map(RequestIndexKeyKind::CreatedAt),
+        any::<u64>().prop_map(RequestIndexKeyKind::ScheduledAt),
+        arb_request_status_code().prop_map(RequestIndexKeyKind::Status),
+    ];
+
+    (kind, arb_uuid()).prop_map(|(kind, request_id)| RequestIndexKey { kind, request_id })
+}
+
+proptest! {
+    #[test]
+    fn user_round_trip(user in arb_user()) {
+        assert_round_trip(user.to_key());
+        assert_round_trip(user);
+    }
+
+    #[test]
+    fn user_group_round_trip(user_group in arb_user_group()) {
+        assert_round_trip(user_group);
+    }
+
+    #[test]
+    fn account_round_trip(account in arb_account()) {
+        assert_round_trip(account.to_key());
+        assert_round_trip(account);
+    }
+
+    #[test]
+    fn address_book_entry_round_trip(entry in arb_address_book_entry()) {
+        assert_round_trip(entry.to_key());
+        assert_round_trip(entry);
+    }
+
+    #[test]
+    fn transfer_round_trip(transfer in arb_transfer()) {
+        assert_round_trip(transfer.to_key());
+        assert_round_trip(transfer);
+    }
+
+    #[test]
+    fn metadata_round_trip(metadata in arb_metadata()) {
+        assert_round_trip(metadata);
+    }
+
+    #[test]
+    fn request_execution_step_round_trip(step in arb_request_execution_step()) {
+        assert_round_trip(step);
+    }
+
+    #[test]
+    fn unique_index_key_round_trip(key in arb_unique_index_key()) {
+        assert_round_trip(key);
+    }
+
+    #[test]
+    fn request_index_key_round_trip(key in arb_request_index_key()) {
+        assert_round_trip(key);
+    }
+
+    #[test]
+    fn notification_user_index_round_trip(
+        user_id in arb_uuid(),
+        created_at in any::<u64>(),
+        notification_id in arb_uuid(),
+    ) {
+        assert_round_trip(NotificationUserIndex { user_id, created_at, notification_id });
+    }
+
+    #[test]
+    fn transfer_account_index_round_trip(
+        account_id in arb_uuid(),
+        created_timestamp in any::<u64>(),
+        transfer_id in arb_uuid(),
+    ) {
+        assert_round_trip(TransferAccountIndex { account_id, created_timestamp, transfer_id });
+    }
+
+    #[test]
+    fn transfer_status_index_round_trip(
+        status in ".*",
+        last_modification_timestamp in any::<u64>(),
+        transfer_id in arb_uuid(),
+    ) {
+        assert_round_trip(TransferStatusIndex { status, last_modification_timestamp, transfer_id });
+    }
+
+    #[test]
+    fn user_status_group_index_round_trip(
+        group_id in arb_uuid(),
+        user_status in arb_user_status(),
+        user_id in arb_uuid(),
+    ) {
+        assert_round_trip(UserStatusGroupIndex { group_id, user_status, user_id });
+    }
+
+    #[test]
+    fn request_round_trip(
+        title in ".{0,255}",
+        summary in prop::option::of(".{0,1000}"),
+        expiration_dt in any::<u64>(),
+        created_timestamp in any::<u64>(),
+        confidential in any::<bool>(),
+    ) {
+        let mut request = mock_request();
+        request.title = title;
+        request.summary = summary;
+        request.expiration_dt = expiration_dt;
+        request.created_timestamp = created_timestamp;
+        request.confidential = confidential;
+
+        assert_round_trip(request.to_key());
+        assert_round_trip(request.index_fields());
+        assert_round_trip(request);
+    }
+
+    #[test]
+    fn archived_request_round_trip(archive_canister_id in arb_principal()) {
+        let mut archived = mock_archived_request();
+        archived.archive_canister_id = archive_canister_id;
+
+        assert_round_trip(archived);
+    }
+
+    #[test]
+    fn request_policy_round_trip(
+        id in arb_uuid(),
+        exclude_requester in any::<bool>(),
+        version in any::<u64>(),
+    ) {
+        let mut policy = mock_request_policy();
+        policy.id = id;
+        policy.exclude_requester = exclude_requester;
+        policy.version = version;
+
+        assert_round_trip(policy);
+    }
+
+    #[test]
+    fn permission_round_trip(
+        users in prop::collection::vec(arb_uuid(), 0..5),
+        user_groups in prop::collection::vec(arb_uuid(), 0..5),
+    ) {
+        let permission = mock_permission();
+        let permission = Permission::new(
+            Allow { users, user_groups, ..permission.allow },
+            permission.resource,
+        );
+
+        assert_round_trip(permission);
+    }
+
+    #[test]
+    fn external_canister_round_trip(
+        id in arb_uuid(),
+        canister_id in arb_principal(),
+        name in ".{0,100}",
+        description in prop::option::of(".{0,1000}"),
+        labels in prop::collection::vec(".{0,64}", 0..10),
+    ) {
+        let mut external_canister = mock_external_canister();
+        external_canister.id = id;
+        external_canister.canister_id = canister_id;
+        external_canister.name = name;
+        external_canister.description = description;
+        external_canister.labels = labels;
+
+        assert_round_trip(external_canister);
+    }
+
+    #[test]
+    fn notification_round_trip(
+        id in arb_uuid(),
+        target_user_id in arb_uuid(),
+        title in ".{0,255}",
+        message in prop::option::of(".{0,1000}"),
+        created_timestamp in any::<u64>(),
+    ) {
+        let mut notification = mock_notification();
+        notification.id = id;
+        notification.target_user_id = target_user_id;
+        notification.title = title;
+        notification.message = message;
+        notification.created_timestamp = created_timestamp;
+
+        assert_round_trip(notification.to_key());
+        assert_round_trip(notification);
+    }
+
+    #[test]
+    fn blob_round_trip(content in prop::collection::vec(any::<u8>(), 0..1024), created_at in any::<u64>()) {
+        let mut blob = mock_blob();
+        blob.content = content;
+        blob.created_at = created_at;
+
+        assert_round_trip(blob);
+    }
+
+    #[test]
+    fn principal_label_round_trip(address in ".{0,64}", label in ".{0,64}") {
+        let mut principal_label = mock_principal_label();
+        principal_label.address = address;
+        principal_label.label = label;
+
+        assert_round_trip(principal_label);
+    }
+
+    #[test]
+    fn event_subscriber_round_trip(id in arb_uuid(), method_name in ".{0,64}") {
+        let mut subscriber = mock_event_subscriber();
+        subscriber.id = id;
+        subscriber.method_name = method_name;
+
+        assert_round_trip(subscriber);
+    }
+
+    #[test]
+    fn vote_receipt_round_trip(voter in arb_principal(), decided_at in any::<u64>()) {
+        let mut receipt = mock_vote_receipt();
+        receipt.voter = voter;
+        receipt.decided_at = decided_at;
+
+        assert_round_trip(receipt.to_key());
+        assert_round_trip(receipt);
+    }
+
+    #[test]
+    fn malformed_bytes_do_not_panic(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        assert_malformed_bytes_do_not_panic::<User>(&bytes);
+        assert_malformed_bytes_do_not_panic::<UserGroup>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Account>(&bytes);
+        assert_malformed_bytes_do_not_panic::<AddressBookEntry>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Transfer>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Metadata>(&bytes);
+        assert_malformed_bytes_do_not_panic::<RequestExecutionStep>(&bytes);
+        assert_malformed_bytes_do_not_panic::<UniqueIndexKey>(&bytes);
+        assert_malformed_bytes_do_not_panic::<RequestIndexKey>(&bytes);
+        assert_malformed_bytes_do_not_panic::<RequestIndexFields>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Request>(&bytes);
+        assert_malformed_bytes_do_not_panic::<ArchivedRequest>(&bytes);
+        assert_malformed_bytes_do_not_panic::<RequestPolicy>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Permission>(&bytes);
+        assert_malformed_bytes_do_not_panic::<ExternalCanister>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Notification>(&bytes);
+        assert_malformed_bytes_do_not_panic::<Blob>(&bytes);
+        assert_malformed_bytes_do_not_panic::<PrincipalLabel>(&bytes);
+        assert_malformed_bytes_do_not_panic::<EventSubscriber>(&bytes);
+        assert_malformed_bytes_do_not_panic::<VoteReceipt>(&bytes);
+        assert_malformed_bytes_do_not_panic::<SystemInfo>(&bytes);
+    }
+
+    #[test]
+    fn from_bytes_only_panics_with_the_decoder_error(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        assert_from_bytes_matches_the_decoder::<Transfer>(&bytes);
+        assert_from_bytes_matches_the_decoder::<Request>(&bytes);
+        assert_from_bytes_matches_the_decoder::<RequestPolicy>(&bytes);
+        assert_from_bytes_matches_the_decoder::<Notification>(&bytes);
+    }
+
+    #[test]
+    fn truncated_bytes_do_not_panic(transfer in arb_transfer(), cut in any::<prop::sample::Index>()) {
+        let bytes = transfer.to_bytes();
+        let truncated = &bytes[..cut.index(bytes.len())];
+
+        assert_malformed_bytes_do_not_panic::<Transfer>(truncated);
+        assert_from_bytes_matches_the_decoder::<Transfer>(truncated);
+    }
+
+    #[test]
+    fn truncated_requests_do_not_panic(cut in any::<prop::sample::Index>()) {
+        let bytes = mock_request().to_bytes();
+        let truncated = &bytes[..cut.index(bytes.len())];
+
+        assert_malformed_bytes_do_not_panic::<Request>(truncated);
+        assert_from_bytes_matches_the_decoder::<Request>(truncated);
+    }
+}
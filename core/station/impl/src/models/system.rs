@@ -1,7 +1,7 @@
 use crate::{
     core::{
         ic_cdk::api::{time, trap},
-        SYSTEM_RESERVED_MEMORY_BYTES,
+        read_system_state, SYSTEM_RESERVED_MEMORY_BYTES,
     },
     STABLE_MEMORY_VERSION, SYSTEM_VERSION,
 };
@@ -11,7 +11,10 @@ use orbit_essentials::storable;
 use orbit_essentials::types::{Timestamp, UUID};
 use std::borrow::Cow;
 
-use super::{AccountId, UserGroupId};
+use super::{
+    request_specifier::RequestSpecifier, resource::ResourceIds, Account, AccountId, Asset, BlobId,
+    Blockchain, BlockchainStandard, Network, NetworkId, Request, RequestOperationType, UserGroupId,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SystemState {
@@ -36,6 +39,669 @@ pub enum CycleObtainStrategy {
     },
 }
 
+/// Defines how the human-readable names of transfer destinations (e.g. `alice.icp`) are resolved.
+#[storable]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NameServiceConfig {
+    #[default]
+    Disabled,
+    /// The names are resolved by a naming canister that implements `get_record_value`.
+    Canister { canister_id: Principal },
+}
+
+/// The ledger canisters of an asset on a given network, configured at install time to support
+/// deployments that don't use the mainnet ledgers (e.g. local or testnet deployments).
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LedgerCanisterConfig {
+    /// The asset symbol (e.g. `ICP`).
+    pub symbol: String,
+    /// The network of the asset (e.g. `mainnet`).
+    pub network: String,
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
+}
+
+/// A principal that was granted temporary read-only access to the station, without being a user.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AuditorAccess {
+    pub principal: Principal,
+    /// A name to identify the auditor (e.g. the name of the audit firm).
+    pub name: String,
+    /// The time after which the auditor can no longer access the station.
+    pub expires_at: Timestamp,
+}
+
+impl AuditorAccess {
+    pub const NAME_RANGE: (usize, usize) = (1, 100);
+    pub const MAX_AUDITORS: usize = 20;
+
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// A canister that publishes address book entries that are synced into the address book of the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddressBookSource {
+    /// The canister that publishes the entries, it must implement `list_address_book_entries` as the station does.
+    pub canister_id: Principal,
+    /// Only the entries that have all of these labels are synced, all the entries are synced if empty.
+    pub labels: Vec<String>,
+    /// The last time the entries of the source were synced successfully.
+    pub last_synced_at: Option<Timestamp>,
+    /// The error of the last sync attempt, if it failed.
+    pub last_sync_error: Option<String>,
+}
+
+impl AddressBookSource {
+    pub const MAX_SOURCES: usize = 10;
+
+    pub fn new(canister_id: Principal, labels: Vec<String>) -> Self {
+        Self {
+            canister_id,
+            labels,
+            last_synced_at: None,
+            last_sync_error: None,
+        }
+    }
+}
+
+/// Transfers of the asset with an amount of at least the threshold must include their travel rule information.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferComplianceThreshold {
+    /// The asset symbol (e.g. `ICP`).
+    pub symbol: String,
+    /// The amount in the smallest unit of the asset (e.g. e8s for `ICP`).
+    pub amount: candid::Nat,
+}
+
+impl TransferComplianceThreshold {
+    pub const MAX_THRESHOLDS: usize = 50;
+}
+
+/// The highest fee that transfers of the asset can pay.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferMaxFee {
+    /// The asset symbol (e.g. `ICP`).
+    pub symbol: String,
+    /// The maximum fee in the smallest unit of the asset (e.g. e8s for `ICP`).
+    pub max_fee: candid::Nat,
+}
+
+impl TransferMaxFee {
+    pub const MAX_FEES: usize = 50;
+}
+
+/// Defines how transfers are screened by an external screening service (e.g. a sanctions screening provider).
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferScreeningConfig {
+    #[default]
+    Disabled,
+    /// The transfers are screened by a canister that implements `screen_transfer`, it can be the
+    /// screening provider itself or a canister that relays the calls to it through HTTPS outcalls.
+    Canister {
+        canister_id: Principal,
+        /// Only the transfers with an amount of at least the threshold of their asset are screened.
+        thresholds: Vec<TransferComplianceThreshold>,
+        /// Whether the transfer requests can only be approved once their screening passed.
+        blocks_approval: bool,
+    },
+}
+
+impl TransferScreeningConfig {
+    /// Returns the screening service and whether it blocks approvals, if transfers of the given amount
+    /// of the asset must be screened.
+    pub fn screening_of(&self, symbol: &str, amount: &candid::Nat) -> Option<(Principal, bool)> {
+        match self {
+            TransferScreeningConfig::Disabled => None,
+            TransferScreeningConfig::Canister {
+                canister_id,
+                thresholds,
+                blocks_approval,
+            } => thresholds
+                .iter()
+                .any(|threshold| threshold.symbol == symbol && *amount >= threshold.amount)
+                .then_some((*canister_id, *blocks_approval)),
+        }
+    }
+}
+
+/// Defines whether the approved transfers are held back for a while before they are executed, so that
+/// fraudulent transfers can still be cancelled after they were approved.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferTimeLockConfig {
+    #[default]
+    Disabled,
+    Enabled {
+        /// Only the transfers with an amount of at least the threshold of their asset are time-locked.
+        thresholds: Vec<TransferComplianceThreshold>,
+        /// The delay between the final approval and the execution of the transfers.
+        delay_secs: u64,
+        /// The user groups whose members can cancel the transfers during the delay.
+        canceller_group_ids: Vec<UserGroupId>,
+    },
+}
+
+impl TransferTimeLockConfig {
+    pub const DELAY_SECS_RANGE: (u64, u64) = (60, 30 * 24 * 60 * 60);
+    pub const MAX_CANCELLER_GROUPS: usize = 20;
+
+    /// Returns the delay and the canceller groups, if transfers of the given amount of the asset are time-locked.
+    pub fn time_lock_of(
+        &self,
+        symbol: &str,
+        amount: &candid::Nat,
+    ) -> Option<(u64, Vec<UserGroupId>)> {
+        match self {
+            TransferTimeLockConfig::Disabled => None,
+            TransferTimeLockConfig::Enabled {
+                thresholds,
+                delay_secs,
+                canceller_group_ids,
+            } => thresholds
+                .iter()
+                .any(|threshold| threshold.symbol == symbol && *amount >= threshold.amount)
+                .then(|| (*delay_secs, canceller_group_ids.clone())),
+        }
+    }
+}
+
+/// Defines how the sensitive metadata (e.g. address book contact notes, travel rule information) is encrypted at rest.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MetadataEncryptionConfig {
+    #[default]
+    Disabled,
+    /// The values are encrypted by the clients with a key derived through vetKD, which is only handed out to
+    /// the users that can read them, so the station only stores the ciphertexts in its stable memory.
+    VetKd {
+        /// The name of the vetKD master key (e.g. `key_1`).
+        key_name: String,
+        /// The address book metadata keys whose values must be encrypted.
+        address_book_metadata_keys: Vec<String>,
+    },
+}
+
+impl MetadataEncryptionConfig {
+    /// The prefix of the encrypted values, it is followed by the base64 encoded ciphertext.
+    pub const ENCRYPTED_VALUE_PREFIX: &'static str = "vetkd:v1:";
+    /// The max length of an encrypted value, which is larger than the plaintext it encrypts.
+    pub const MAX_ENCRYPTED_VALUE_LEN: usize = 1024;
+    pub const KEY_NAME_RANGE: (usize, usize) = (1, 64);
+    pub const MAX_ADDRESS_BOOK_METADATA_KEYS: usize = 10;
+
+    /// Whether the value is formatted as an encrypted value.
+    pub fn is_encrypted_value(value: &str) -> bool {
+        value
+            .strip_prefix(Self::ENCRYPTED_VALUE_PREFIX)
+            .is_some_and(|ciphertext| !ciphertext.is_empty())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, MetadataEncryptionConfig::Disabled)
+    }
+
+    /// Returns the address book metadata keys whose values must be encrypted but are not.
+    pub fn unencrypted_address_book_metadata<'a>(
+        &self,
+        metadata: impl IntoIterator<Item = (&'a String, &'a String)>,
+    ) -> Vec<&'a str> {
+        metadata
+            .into_iter()
+            .filter(|(key, value)| {
+                self.encrypts_address_book_metadata(key) && !Self::is_encrypted_value(value)
+            })
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    /// Whether the value of the address book metadata key must be encrypted.
+    pub fn encrypts_address_book_metadata(&self, key: &str) -> bool {
+        match self {
+            MetadataEncryptionConfig::Disabled => false,
+            MetadataEncryptionConfig::VetKd {
+                address_book_metadata_keys,
+                ..
+            } => address_book_metadata_keys
+                .iter()
+                .any(|encrypted_key| encrypted_key == key),
+        }
+    }
+}
+
+/// Whether the accounts of a sub-treasury can be accessed by the users outside of it.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SubTreasuryVisibility {
+    /// The accounts can only be accessed by the members of the sub-treasury and the station admins,
+    /// regardless of the permissions of the other users.
+    Isolated,
+    /// The accounts can be accessed by the other users according to their permissions.
+    Shared,
+}
+
+/// A group of accounts with its own admins and request policies, so that several departments can share
+/// a station while keeping their governance apart.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubTreasury {
+    /// The name of the sub-treasury, unique within the station (e.g. `Marketing`).
+    pub name: String,
+    /// The accounts of the sub-treasury, an account can only belong to one sub-treasury.
+    pub account_ids: Vec<AccountId>,
+    /// The user group whose members administer the accounts of the sub-treasury and the request policies
+    /// that only apply to them.
+    pub admin_group_id: UserGroupId,
+    /// The user groups whose members can access the accounts of the sub-treasury according to their permissions.
+    pub member_group_ids: Vec<UserGroupId>,
+    pub visibility: SubTreasuryVisibility,
+}
+
+impl SubTreasury {
+    pub const NAME_RANGE: (usize, usize) = (1, 100);
+    pub const MAX_SUB_TREASURIES: usize = 50;
+    pub const MAX_ACCOUNTS: usize = 500;
+    pub const MAX_MEMBER_GROUPS: usize = 20;
+
+    pub fn contains_account(&self, account_id: &AccountId) -> bool {
+        self.account_ids.contains(account_id)
+    }
+
+    /// Whether a user of the given groups administers the sub-treasury.
+    pub fn is_admin(&self, groups: &[UserGroupId]) -> bool {
+        groups.contains(&self.admin_group_id)
+    }
+
+    /// Whether a user of the given groups belongs to the sub-treasury, the admins of the sub-treasury
+    /// are members as well.
+    pub fn is_member(&self, groups: &[UserGroupId]) -> bool {
+        self.is_admin(groups)
+            || groups
+                .iter()
+                .any(|group_id| self.member_group_ids.contains(group_id))
+    }
+
+    /// Whether the requests matched by the specifier only target accounts of the sub-treasury.
+    pub fn scopes_request_specifier(&self, specifier: &RequestSpecifier) -> bool {
+        match specifier {
+            RequestSpecifier::Transfer(ResourceIds::Ids(account_ids))
+            | RequestSpecifier::EditAccount(ResourceIds::Ids(account_ids)) => {
+                !account_ids.is_empty()
+                    && account_ids
+                        .iter()
+                        .all(|account_id| self.contains_account(account_id))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Defines when the station refuses the calls that change its state, e.g. to quiesce the writes before
+/// a tricky upgrade.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MaintenanceModeConfig {
+    /// Whether the station was put in maintenance mode by an executed request.
+    pub enabled: bool,
+    /// The reason that is returned to the callers whose calls are refused.
+    pub reason: Option<String>,
+    /// Whether the station is in maintenance mode while its indexes are rebuilt after an upgrade.
+    pub during_migrations: bool,
+}
+
+impl MaintenanceModeConfig {
+    pub const MAX_REASON_LEN: usize = 200;
+}
+
+/// Records that the station was decommissioned, after which it refuses to create, approve or execute
+/// requests for good while its data remains readable.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StationDecommission {
+    /// The request that decommissioned the station.
+    pub request_id: UUID,
+    /// The reason that is returned to the callers whose calls are refused.
+    pub reason: Option<String>,
+    /// When the station was decommissioned.
+    pub decommissioned_at: Timestamp,
+}
+
+/// Records that the accounts of a blockchain network were frozen, e.g. during an incident of the RPC providers
+/// of the blockchain, after which the requests that target them are only executed once they are unfrozen.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlockchainFreeze {
+    pub blockchain: Blockchain,
+    /// The network whose accounts are frozen, all the networks of the blockchain if not set.
+    pub network: Option<NetworkId>,
+    /// The reason of the freeze.
+    pub reason: Option<String>,
+    /// The request that froze the accounts.
+    pub request_id: UUID,
+    /// When the accounts were frozen.
+    pub frozen_at: Timestamp,
+}
+
+impl BlockchainFreeze {
+    pub const MAX_FREEZES: usize = 20;
+
+    /// Whether the freeze covers the accounts of the blockchain on the given network.
+    pub fn covers(&self, blockchain: &Blockchain, network_id: &str) -> bool {
+        self.blockchain == *blockchain
+            && self
+                .network
+                .as_deref()
+                .map_or(true, |network| network == network_id)
+    }
+}
+
+/// Defines the length limits of the titles and summaries of new requests, in bytes.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestTextLimits {
+    pub max_title_len: u16,
+    pub max_summary_len: u32,
+}
+
+impl Default for RequestTextLimits {
+    fn default() -> Self {
+        RequestTextLimits {
+            max_title_len: Request::MAX_TITLE_LEN as u16,
+            max_summary_len: Request::MAX_SUMMARY_LEN as u32,
+        }
+    }
+}
+
+impl RequestTextLimits {
+    /// The upper bounds of the configurable limits, so that requests still fit in the responses that list them.
+    pub const MAX_TITLE_LEN: u16 = 1_000;
+    pub const MAX_SUMMARY_LEN: u32 = 50_000;
+
+    /// Returns the limits configured for the station, or the default ones before it's initialized.
+    pub fn current() -> Self {
+        match read_system_state() {
+            SystemState::Initialized(system_info) => system_info.get_request_text_limits().clone(),
+            SystemState::Uninitialized => RequestTextLimits::default(),
+        }
+    }
+}
+
+/// Defines when the approvers of pending requests are reminded of the approvals they haven't submitted yet.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApprovalReminderConfig {
+    /// The delays after the creation of a request at which its approvers are reminded, in seconds and in
+    /// ascending order, which also caps the number of reminders per request. No reminders are sent if empty.
+    pub intervals_secs: Vec<u64>,
+}
+
+impl ApprovalReminderConfig {
+    pub const MAX_REMINDERS: usize = 10;
+    pub const MIN_INTERVAL_SECS: u64 = 60 * 60;
+    const SECOND_NS: u64 = 1_000_000_000;
+
+    pub fn is_enabled(&self) -> bool {
+        !self.intervals_secs.is_empty()
+    }
+
+    /// Returns the time of the latest reminder that is due for a request created at the given time, if any.
+    pub fn last_due_at(&self, created_at: Timestamp, now: Timestamp) -> Option<Timestamp> {
+        self.intervals_secs
+            .iter()
+            .map(|interval_secs| {
+                created_at.saturating_add(interval_secs.saturating_mul(Self::SECOND_NS))
+            })
+            .filter(|due_at| *due_at <= now)
+            .max()
+    }
+}
+
+/// Defines how often the balances of the accounts are recorded in their balance history.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BalanceSnapshotConfig {
+    /// The interval between the snapshots in seconds, the snapshots are aligned to the interval since the
+    /// unix epoch (e.g. daily snapshots are taken at midnight UTC). No snapshots are taken if not set.
+    pub interval_secs: Option<u64>,
+}
+
+impl Default for BalanceSnapshotConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: Some(Self::DEFAULT_INTERVAL_SECS),
+        }
+    }
+}
+
+impl BalanceSnapshotConfig {
+    pub const DEFAULT_INTERVAL_SECS: u64 = 24 * 60 * 60;
+    pub const INTERVAL_SECS_RANGE: (u64, u64) = (60 * 60, 31 * 24 * 60 * 60);
+    const SECOND_NS: u64 = 1_000_000_000;
+
+    /// Returns the time of the first snapshot after the given time, if enabled.
+    pub fn next_snapshot_at(&self, now: Timestamp) -> Option<Timestamp> {
+        self.interval_secs.map(|interval_secs| {
+            let interval_ns = interval_secs.saturating_mul(Self::SECOND_NS).max(1);
+
+            (now / interval_ns + 1).saturating_mul(interval_ns)
+        })
+    }
+}
+
+/// Defines where the fiat prices of the assets are taken from, to value the balances of the accounts in fiat
+/// currencies (e.g. for the portfolio summary).
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FiatValuationConfig {
+    #[default]
+    Disabled,
+    /// The prices are fetched from a canister that implements `get_fiat_prices`, it can be the price
+    /// provider itself or a canister that relays the calls to it through HTTPS outcalls.
+    Canister {
+        canister_id: Principal,
+        /// The fiat currencies that the balances can be valued in (e.g. `USD`, `EUR`).
+        currencies: Vec<String>,
+    },
+}
+
+impl FiatValuationConfig {
+    pub const MAX_CURRENCIES: usize = 10;
+    /// The interval at which the prices are fetched from the price feed, which is 5 minutes.
+    pub const REFRESH_INTERVAL_NS: u64 = 5 * 60 * 1_000_000_000;
+    /// The age after which a price is no longer used to value the balances, which is 1 hour.
+    pub const MAX_PRICE_AGE_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    /// Returns the fiat currencies that the balances can be valued in.
+    pub fn currencies(&self) -> &[String] {
+        match self {
+            FiatValuationConfig::Disabled => &[],
+            FiatValuationConfig::Canister { currencies, .. } => currencies,
+        }
+    }
+}
+
+/// The availability of an operation type that is flagged on the station, the operation types without a flag
+/// are enabled.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OperationAvailability {
+    /// The operation can still be requested, but clients are told to stop offering it.
+    Deprecated,
+    /// The operation cannot be requested, e.g. to reduce the attack surface of the subsystems that the
+    /// station doesn't use.
+    Disabled,
+}
+
+/// Flags an operation type as deprecated or disabled on the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OperationFlag {
+    pub operation_type: RequestOperationType,
+    pub availability: OperationAvailability,
+}
+
+impl OperationFlag {
+    /// The operation types that cannot be disabled, since the flags could no longer be changed without them.
+    pub const ALWAYS_ENABLED: [RequestOperationType; 2] = [
+        RequestOperationType::ManageSystemInfo,
+        RequestOperationType::SystemUpgrade,
+    ];
+}
+
+/// Defines how the data of old requests is compacted, to bound the growth of the stable memory.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DataCompactionConfig {
+    #[default]
+    Disabled,
+    /// The bulky payloads (e.g. wasm modules, call arguments and replies) of the requests that were finalized
+    /// more than `min_age_days` ago are pruned, only their summary records are kept.
+    Enabled {
+        min_age_days: u32,
+        /// The canister that receives the payloads before they are pruned, it must implement
+        /// `archive_request_payloads`, the payloads are kept until it accepted them.
+        export_canister_id: Option<Principal>,
+    },
+}
+
+impl DataCompactionConfig {
+    pub const MIN_AGE_DAYS_RANGE: (u32, u32) = (1, 3650);
+    const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    /// Returns the time before which the requests must have been finalized to be compacted, if enabled.
+    pub fn compaction_cutoff(&self, now: Timestamp) -> Option<Timestamp> {
+        match self {
+            DataCompactionConfig::Disabled => None,
+            DataCompactionConfig::Enabled { min_age_days, .. } => {
+                Some(now.saturating_sub(u64::from(*min_age_days).saturating_mul(Self::DAY_NS)))
+            }
+        }
+    }
+
+    pub fn export_canister_id(&self) -> Option<Principal> {
+        match self {
+            DataCompactionConfig::Disabled => None,
+            DataCompactionConfig::Enabled {
+                export_canister_id, ..
+            } => *export_canister_id,
+        }
+    }
+}
+
+/// Defines how the old requests are moved to an archive canister, to keep the stable memory of the station
+/// bounded for long-running deployments.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestArchivalConfig {
+    #[default]
+    Disabled,
+    /// The requests that were finalized more than `min_age_days` ago are moved to the archive canister with
+    /// their approvals and operation, only a stub is kept by the station.
+    Enabled {
+        min_age_days: u32,
+        /// The canister that stores the archived requests, it must implement `archive_requests` and the
+        /// `get_archived_request` query, and be on the subnet of the station to serve the archived requests.
+        archive_canister_id: Principal,
+    },
+}
+
+impl RequestArchivalConfig {
+    pub const MIN_AGE_DAYS_RANGE: (u32, u32) = (30, 36500);
+    const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    /// Returns the archive canister and the time before which the requests must have been finalized to be
+    /// archived, if enabled.
+    pub fn archival_cutoff(&self, now: Timestamp) -> Option<(Principal, Timestamp)> {
+        match self {
+            RequestArchivalConfig::Disabled => None,
+            RequestArchivalConfig::Enabled {
+                min_age_days,
+                archive_canister_id,
+            } => Some((
+                *archive_canister_id,
+                now.saturating_sub(u64::from(*min_age_days).saturating_mul(Self::DAY_NS)),
+            )),
+        }
+    }
+}
+
+/// The logo of an asset of the registry, which is identified by its blockchain, standard and symbol.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AssetLogo {
+    pub blockchain: Blockchain,
+    pub standard: BlockchainStandard,
+    pub symbol: String,
+    pub logo_id: BlobId,
+}
+
+/// The images that frontends display for the station and its assets, stored as blobs of the station.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BrandingConfig {
+    /// The logo of the station.
+    pub logo_id: Option<BlobId>,
+    pub asset_logos: Vec<AssetLogo>,
+}
+
+impl BrandingConfig {
+    /// Returns the logo of the asset, if it has one.
+    pub fn find_asset_logo(&self, asset: &Asset) -> Option<BlobId> {
+        self.asset_logos
+            .iter()
+            .find(|logo| {
+                logo.blockchain == asset.blockchain
+                    && logo.standard == asset.standard
+                    && logo.symbol == asset.symbol
+            })
+            .map(|logo| logo.logo_id)
+    }
+
+    /// Whether the blob is the logo of the station or of one of its assets.
+    pub fn references(&self, blob_id: &BlobId) -> bool {
+        self.logo_id.as_ref() == Some(blob_id)
+            || self.asset_logos.iter().any(|logo| logo.logo_id == *blob_id)
+    }
+}
+
+/// The repositories whose indexes can be rebuilt in the background.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, strum::Display)]
+pub enum IndexRebuildTarget {
+    UserGroups,
+    Users,
+    Accounts,
+    AddressBook,
+    ExternalCanisters,
+    Permissions,
+    RequestPolicies,
+    Requests,
+}
+
+/// The progress of the indexes that are rebuilt in chunks across several timer ticks, since rebuilding them
+/// within a single message could exceed the instruction limit on large stations.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IndexRebuildProgress {
+    /// The repositories that are waiting to have their indexes rebuilt, the first one is the one in progress.
+    pub pending: Vec<IndexRebuildTarget>,
+    /// The serialized key of the last entry processed of the repository in progress.
+    pub cursor: Option<Vec<u8>>,
+    /// The number of entries that were processed so far.
+    pub processed_entries: u64,
+    /// When the rebuild was started.
+    pub started_at: Timestamp,
+    /// The last time a chunk was processed.
+    pub last_progress_at: Timestamp,
+}
+
 #[storable(size = SYSTEM_RESERVED_MEMORY_BYTES)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SystemInfo {
@@ -54,6 +720,79 @@ pub struct SystemInfo {
     /// Defines how the station tops up itself with cycles.
     #[serde(default)]
     cycle_obtain_strategy: CycleObtainStrategy,
+    /// The indexes that are being rebuilt in the background, if any.
+    #[serde(default)]
+    index_rebuild: Option<IndexRebuildProgress>,
+    /// The ledger canisters that override the default ones.
+    #[serde(default)]
+    ledger_canisters: Vec<LedgerCanisterConfig>,
+    /// The networks that were added to the station or that override the default ones.
+    #[serde(default)]
+    networks: Vec<Network>,
+    /// The principals with temporary read-only access to the station.
+    #[serde(default)]
+    auditors: Vec<AuditorAccess>,
+    /// The canisters whose published address book entries are synced into the address book.
+    #[serde(default)]
+    address_book_sources: Vec<AddressBookSource>,
+    /// Defines how the names of transfer destinations are resolved.
+    #[serde(default)]
+    name_service: NameServiceConfig,
+    /// The amounts from which transfers must include their travel rule information.
+    #[serde(default)]
+    transfer_compliance_thresholds: Vec<TransferComplianceThreshold>,
+    /// The highest fees that transfers of the assets can pay.
+    #[serde(default)]
+    transfer_max_fees: Vec<TransferMaxFee>,
+    /// Defines how transfers are screened before they can be executed.
+    #[serde(default)]
+    transfer_screening: TransferScreeningConfig,
+    /// Defines whether the approved transfers are held back before they are executed.
+    #[serde(default)]
+    transfer_time_lock: TransferTimeLockConfig,
+    /// Defines how the sensitive metadata is encrypted at rest.
+    #[serde(default)]
+    metadata_encryption: MetadataEncryptionConfig,
+    /// Defines how the data of old requests is compacted.
+    #[serde(default)]
+    data_compaction: DataCompactionConfig,
+    /// The groups of accounts that are administered apart from the rest of the station.
+    #[serde(default)]
+    sub_treasuries: Vec<SubTreasury>,
+    /// Defines when the station refuses the calls that change its state.
+    #[serde(default)]
+    maintenance_mode: MaintenanceModeConfig,
+    /// The length limits of the titles and summaries of new requests.
+    #[serde(default)]
+    request_text_limits: RequestTextLimits,
+    /// Defines when the approvers of pending requests are reminded.
+    #[serde(default)]
+    approval_reminders: ApprovalReminderConfig,
+    /// Defines how often the balances of the accounts are recorded.
+    #[serde(default)]
+    balance_snapshots: BalanceSnapshotConfig,
+    /// Defines where the fiat prices of the assets are taken from.
+    #[serde(default)]
+    fiat_valuation: FiatValuationConfig,
+    /// The operation types that are deprecated or disabled on the station.
+    #[serde(default)]
+    operation_flags: Vec<OperationFlag>,
+    /// Whether the blockchains are simulated by the station instead of calling their canisters, which is
+    /// only meant for local development and tests.
+    #[serde(default)]
+    mock_blockchains: bool,
+    /// Set once the station is decommissioned, which can't be reverted.
+    #[serde(default)]
+    decommission: Option<StationDecommission>,
+    /// The blockchain networks whose accounts are frozen.
+    #[serde(default)]
+    frozen_blockchains: Vec<BlockchainFreeze>,
+    /// Defines how the old requests are moved to an archive canister.
+    #[serde(default)]
+    request_archival: RequestArchivalConfig,
+    /// The logos of the station and of its assets.
+    #[serde(default)]
+    branding: BrandingConfig,
     /// The system version.
     version: Option<String>,
     /// Last run migration version.
@@ -72,6 +811,30 @@ impl Default for SystemInfo {
             version: Some(SYSTEM_VERSION.to_string()),
             stable_memory_version: Some(STABLE_MEMORY_VERSION),
             cycle_obtain_strategy: CycleObtainStrategy::default(),
+            index_rebuild: None,
+            ledger_canisters: Vec::new(),
+            networks: Vec::new(),
+            auditors: Vec::new(),
+            address_book_sources: Vec::new(),
+            name_service: NameServiceConfig::default(),
+            transfer_compliance_thresholds: Vec::new(),
+            transfer_max_fees: Vec::new(),
+            transfer_screening: TransferScreeningConfig::default(),
+            transfer_time_lock: TransferTimeLockConfig::default(),
+            metadata_encryption: MetadataEncryptionConfig::default(),
+            data_compaction: DataCompactionConfig::default(),
+            sub_treasuries: Vec::new(),
+            maintenance_mode: MaintenanceModeConfig::default(),
+            request_text_limits: RequestTextLimits::default(),
+            approval_reminders: ApprovalReminderConfig::default(),
+            balance_snapshots: BalanceSnapshotConfig::default(),
+            fiat_valuation: FiatValuationConfig::default(),
+            operation_flags: Vec::new(),
+            mock_blockchains: false,
+            decommission: None,
+            frozen_blockchains: Vec::new(),
+            request_archival: RequestArchivalConfig::default(),
+            branding: BrandingConfig::default(),
         }
     }
 }
@@ -103,6 +866,374 @@ impl SystemInfo {
         self.cycle_obtain_strategy = strategy;
     }
 
+    pub fn get_index_rebuild(&self) -> Option<&IndexRebuildProgress> {
+        self.index_rebuild.as_ref()
+    }
+
+    pub fn set_index_rebuild(&mut self, index_rebuild: Option<IndexRebuildProgress>) {
+        self.index_rebuild = index_rebuild;
+    }
+
+    pub fn get_ledger_canisters(&self) -> &[LedgerCanisterConfig] {
+        &self.ledger_canisters
+    }
+
+    pub fn set_ledger_canisters(&mut self, ledger_canisters: Vec<LedgerCanisterConfig>) {
+        self.ledger_canisters = ledger_canisters;
+    }
+
+    /// Returns the networks of the blockchains, including the default ones that were not overridden.
+    pub fn get_networks(&self) -> Vec<Network> {
+        let mut networks: Vec<Network> = Network::defaults()
+            .into_iter()
+            .filter(|default| {
+                !self.networks.iter().any(|network| {
+                    network.blockchain == default.blockchain && network.id == default.id
+                })
+            })
+            .collect();
+        networks.extend(self.networks.iter().cloned());
+
+        networks
+    }
+
+    pub fn find_network(&self, blockchain: &Blockchain, id: &str) -> Option<Network> {
+        self.get_networks()
+            .into_iter()
+            .find(|network| network.blockchain == *blockchain && network.id == id)
+    }
+
+    /// Adds the network, or replaces the one of the same blockchain with the same id.
+    pub fn upsert_network(&mut self, network: Network) {
+        self.networks
+            .retain(|other| other.blockchain != network.blockchain || other.id != network.id);
+        self.networks.push(network);
+    }
+
+    pub fn get_auditors(&self) -> &[AuditorAccess] {
+        &self.auditors
+    }
+
+    pub fn set_auditors(&mut self, auditors: Vec<AuditorAccess>) {
+        self.auditors = auditors;
+    }
+
+    pub fn get_name_service(&self) -> &NameServiceConfig {
+        &self.name_service
+    }
+
+    pub fn set_name_service(&mut self, name_service: NameServiceConfig) {
+        self.name_service = name_service;
+    }
+
+    pub fn get_transfer_compliance_thresholds(&self) -> &[TransferComplianceThreshold] {
+        &self.transfer_compliance_thresholds
+    }
+
+    pub fn set_transfer_compliance_thresholds(
+        &mut self,
+        thresholds: Vec<TransferComplianceThreshold>,
+    ) {
+        self.transfer_compliance_thresholds = thresholds;
+    }
+
+    /// Whether transfers of the given amount of the asset must include their travel rule information.
+    pub fn requires_transfer_compliance(&self, symbol: &str, amount: &candid::Nat) -> bool {
+        self.transfer_compliance_thresholds
+            .iter()
+            .any(|threshold| threshold.symbol == symbol && *amount >= threshold.amount)
+    }
+
+    pub fn get_transfer_max_fees(&self) -> &[TransferMaxFee] {
+        &self.transfer_max_fees
+    }
+
+    pub fn set_transfer_max_fees(&mut self, max_fees: Vec<TransferMaxFee>) {
+        self.transfer_max_fees = max_fees;
+    }
+
+    /// Returns the highest fee that transfers of the asset can pay, if the station bounds it.
+    pub fn get_transfer_max_fee(&self, symbol: &str) -> Option<candid::Nat> {
+        self.transfer_max_fees
+            .iter()
+            .find(|max_fee| max_fee.symbol == symbol)
+            .map(|max_fee| max_fee.max_fee.clone())
+    }
+
+    pub fn get_transfer_screening(&self) -> &TransferScreeningConfig {
+        &self.transfer_screening
+    }
+
+    pub fn set_transfer_screening(&mut self, transfer_screening: TransferScreeningConfig) {
+        self.transfer_screening = transfer_screening;
+    }
+
+    pub fn get_transfer_time_lock(&self) -> &TransferTimeLockConfig {
+        &self.transfer_time_lock
+    }
+
+    pub fn set_transfer_time_lock(&mut self, transfer_time_lock: TransferTimeLockConfig) {
+        self.transfer_time_lock = transfer_time_lock;
+    }
+
+    pub fn get_metadata_encryption(&self) -> &MetadataEncryptionConfig {
+        &self.metadata_encryption
+    }
+
+    pub fn set_metadata_encryption(&mut self, metadata_encryption: MetadataEncryptionConfig) {
+        self.metadata_encryption = metadata_encryption;
+    }
+
+    pub fn get_data_compaction(&self) -> &DataCompactionConfig {
+        &self.data_compaction
+    }
+
+    pub fn set_data_compaction(&mut self, data_compaction: DataCompactionConfig) {
+        self.data_compaction = data_compaction;
+    }
+
+    pub fn get_sub_treasuries(&self) -> &[SubTreasury] {
+        &self.sub_treasuries
+    }
+
+    pub fn set_sub_treasuries(&mut self, sub_treasuries: Vec<SubTreasury>) {
+        self.sub_treasuries = sub_treasuries;
+    }
+
+    pub fn get_maintenance_mode(&self) -> &MaintenanceModeConfig {
+        &self.maintenance_mode
+    }
+
+    pub fn set_maintenance_mode(&mut self, maintenance_mode: MaintenanceModeConfig) {
+        self.maintenance_mode = maintenance_mode;
+    }
+
+    pub fn get_request_text_limits(&self) -> &RequestTextLimits {
+        &self.request_text_limits
+    }
+
+    pub fn set_request_text_limits(&mut self, request_text_limits: RequestTextLimits) {
+        self.request_text_limits = request_text_limits;
+    }
+
+    pub fn get_approval_reminders(&self) -> &ApprovalReminderConfig {
+        &self.approval_reminders
+    }
+
+    pub fn set_approval_reminders(&mut self, approval_reminders: ApprovalReminderConfig) {
+        self.approval_reminders = approval_reminders;
+    }
+
+    pub fn get_balance_snapshots(&self) -> &BalanceSnapshotConfig {
+        &self.balance_snapshots
+    }
+
+    pub fn set_balance_snapshots(&mut self, balance_snapshots: BalanceSnapshotConfig) {
+        self.balance_snapshots = balance_snapshots;
+    }
+
+    pub fn get_fiat_valuation(&self) -> &FiatValuationConfig {
+        &self.fiat_valuation
+    }
+
+    pub fn set_fiat_valuation(&mut self, fiat_valuation: FiatValuationConfig) {
+        self.fiat_valuation = fiat_valuation;
+    }
+
+    pub fn get_request_archival(&self) -> &RequestArchivalConfig {
+        &self.request_archival
+    }
+
+    pub fn set_request_archival(&mut self, request_archival: RequestArchivalConfig) {
+        self.request_archival = request_archival;
+    }
+
+    pub fn get_branding(&self) -> &BrandingConfig {
+        &self.branding
+    }
+
+    pub fn set_branding(&mut self, branding: BrandingConfig) {
+        self.branding = branding;
+    }
+
+    pub fn get_operation_flags(&self) -> &Vec<OperationFlag> {
+        &self.operation_flags
+    }
+
+    pub fn set_operation_flags(&mut self, operation_flags: Vec<OperationFlag>) {
+        self.operation_flags = operation_flags;
+    }
+
+    pub fn is_mock_blockchains(&self) -> bool {
+        self.mock_blockchains
+    }
+
+    pub fn set_mock_blockchains(&mut self, mock_blockchains: bool) {
+        self.mock_blockchains = mock_blockchains;
+    }
+
+    pub fn get_decommission(&self) -> Option<&StationDecommission> {
+        self.decommission.as_ref()
+    }
+
+    pub fn set_decommission(&mut self, decommission: StationDecommission) {
+        self.decommission = Some(decommission);
+    }
+
+    pub fn is_decommissioned(&self) -> bool {
+        self.decommission.is_some()
+    }
+
+    pub fn get_frozen_blockchains(&self) -> &[BlockchainFreeze] {
+        &self.frozen_blockchains
+    }
+
+    /// Returns the freeze of the blockchain with exactly the given network, which is the freeze of all
+    /// the networks of the blockchain if not set.
+    pub fn get_blockchain_freeze(
+        &self,
+        blockchain: &Blockchain,
+        network: Option<&str>,
+    ) -> Option<&BlockchainFreeze> {
+        self.frozen_blockchains
+            .iter()
+            .find(|freeze| freeze.blockchain == *blockchain && freeze.network.as_deref() == network)
+    }
+
+    /// Returns a freeze that covers the accounts of the blockchain on the given network, if any.
+    pub fn find_blockchain_freeze(
+        &self,
+        blockchain: &Blockchain,
+        network_id: &str,
+    ) -> Option<&BlockchainFreeze> {
+        self.frozen_blockchains
+            .iter()
+            .find(|freeze| freeze.covers(blockchain, network_id))
+    }
+
+    /// Returns a freeze that covers the account, if any.
+    pub fn find_account_freeze(&self, account: &Account) -> Option<&BlockchainFreeze> {
+        self.find_blockchain_freeze(&account.blockchain, account.network_id())
+    }
+
+    pub fn add_blockchain_freeze(&mut self, freeze: BlockchainFreeze) {
+        self.frozen_blockchains.push(freeze);
+    }
+
+    /// Removes the freeze of the blockchain with exactly the given network, returning it if it existed.
+    pub fn remove_blockchain_freeze(
+        &mut self,
+        blockchain: &Blockchain,
+        network: Option<&str>,
+    ) -> Option<BlockchainFreeze> {
+        let position = self.frozen_blockchains.iter().position(|freeze| {
+            freeze.blockchain == *blockchain && freeze.network.as_deref() == network
+        })?;
+
+        Some(self.frozen_blockchains.remove(position))
+    }
+
+    /// Returns the availability of the operation type if it is flagged, the other operation types are enabled.
+    pub fn operation_availability(
+        &self,
+        operation_type: &RequestOperationType,
+    ) -> Option<OperationAvailability> {
+        self.operation_flags
+            .iter()
+            .find(|flag| flag.operation_type == *operation_type)
+            .map(|flag| flag.availability)
+    }
+
+    /// Whether the station refuses the calls that change its state, either because it was put in maintenance
+    /// mode or because its indexes are being rebuilt.
+    pub fn is_in_maintenance(&self) -> bool {
+        self.maintenance_mode.enabled
+            || (self.maintenance_mode.during_migrations && self.index_rebuild.is_some())
+    }
+
+    /// Finds the sub-treasury that the account belongs to, if any.
+    pub fn find_sub_treasury_of_account(&self, account_id: &AccountId) -> Option<&SubTreasury> {
+        self.sub_treasuries
+            .iter()
+            .find(|sub_treasury| sub_treasury.contains_account(account_id))
+    }
+
+    /// Finds the sub-treasury whose accounts are the only ones targeted by the request specifier, if any.
+    pub fn find_sub_treasury_of_request_specifier(
+        &self,
+        specifier: &RequestSpecifier,
+    ) -> Option<&SubTreasury> {
+        self.sub_treasuries
+            .iter()
+            .find(|sub_treasury| sub_treasury.scopes_request_specifier(specifier))
+    }
+
+    pub fn get_address_book_sources(&self) -> &[AddressBookSource] {
+        &self.address_book_sources
+    }
+
+    /// Replaces the address book sources, the sync status of the sources that are kept is preserved.
+    pub fn set_address_book_sources(&mut self, sources: Vec<AddressBookSource>) {
+        self.address_book_sources = sources
+            .into_iter()
+            .map(|mut source| {
+                if let Some(current) = self
+                    .address_book_sources
+                    .iter()
+                    .find(|current| current.canister_id == source.canister_id)
+                {
+                    source.last_synced_at = current.last_synced_at;
+                    source.last_sync_error = current.last_sync_error.clone();
+                }
+
+                source
+            })
+            .collect();
+    }
+
+    /// Records the outcome of syncing the entries of the address book source.
+    pub fn update_address_book_source_status(
+        &mut self,
+        canister_id: &Principal,
+        result: Result<Timestamp, String>,
+    ) {
+        if let Some(source) = self
+            .address_book_sources
+            .iter_mut()
+            .find(|source| source.canister_id == *canister_id)
+        {
+            match result {
+                Ok(synced_at) => {
+                    source.last_synced_at = Some(synced_at);
+                    source.last_sync_error = None;
+                }
+                Err(error) => source.last_sync_error = Some(error),
+            }
+        }
+    }
+
+    /// Finds the auditor access of the principal, if it has not expired yet.
+    pub fn find_active_auditor(
+        &self,
+        principal: &Principal,
+        now: Timestamp,
+    ) -> Option<&AuditorAccess> {
+        self.auditors
+            .iter()
+            .find(|auditor| auditor.principal == *principal && auditor.is_active(now))
+    }
+
+    /// Finds the ledger canisters configured for the asset on the given network.
+    pub fn find_ledger_canister(
+        &self,
+        symbol: &str,
+        network: &str,
+    ) -> Option<&LedgerCanisterConfig> {
+        self.ledger_canisters
+            .iter()
+            .find(|config| config.symbol == symbol && config.network == network)
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
@@ -215,10 +1346,200 @@ impl Storable for SystemState {
     };
 }
 
+/// The steps of the bootstrap that runs after the installation of the station, in the order they run.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum InitStep {
+    /// Adds the default request policies and permissions.
+    DefaultConfigs,
+    /// Deploys the upgrader canister, or takes over the one that was given.
+    Upgrader,
+    /// Sets the upgrader and the fallback controller as the controllers of the station.
+    Controllers,
+    /// Creates the initial accounts.
+    InitialAccounts,
+    Completed,
+}
+
+impl InitStep {
+    pub fn next(&self) -> Self {
+        match self {
+            InitStep::DefaultConfigs => InitStep::Upgrader,
+            InitStep::Upgrader => InitStep::Controllers,
+            InitStep::Controllers => InitStep::InitialAccounts,
+            InitStep::InitialAccounts | InitStep::Completed => InitStep::Completed,
+        }
+    }
+}
+
+/// The checkpoint of the bootstrap of the station, which is resumed from its next step if it failed.
+///
+/// Stations that were installed before the bootstrap was checkpointed report it as completed.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SystemInitProgress {
+    /// The candid encoded init arg of the station, cleared once the bootstrap completed.
+    pub init_arg: Vec<u8>,
+    /// The system info that is written once the bootstrap completed.
+    pub system_info: SystemInfo,
+    pub next_step: InitStep,
+    /// The upgrader canister, set as soon as it was created so that a retry doesn't create another one.
+    pub upgrader_canister_id: Option<Principal>,
+    pub attempts: u32,
+    pub last_attempt_at: Option<Timestamp>,
+    pub last_error: Option<String>,
+}
+
+impl Default for SystemInitProgress {
+    fn default() -> Self {
+        Self {
+            init_arg: Vec::new(),
+            system_info: SystemInfo::default(),
+            next_step: InitStep::Completed,
+            upgrader_canister_id: None,
+            attempts: 0,
+            last_attempt_at: None,
+            last_error: None,
+        }
+    }
+}
+
+impl SystemInitProgress {
+    pub fn new(init_arg: Vec<u8>, system_info: SystemInfo) -> Self {
+        Self {
+            init_arg,
+            system_info,
+            next_step: InitStep::DefaultConfigs,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.next_step == InitStep::Completed
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn maintenance_mode_applies_during_migrations_when_configured() {
+        let mut info = SystemInfo::default();
+        assert!(!info.is_in_maintenance());
+
+        info.set_index_rebuild(Some(IndexRebuildProgress {
+            pending: vec![IndexRebuildTarget::Requests],
+            cursor: None,
+            processed_entries: 0,
+            started_at: 0,
+            last_progress_at: 0,
+        }));
+        assert!(!info.is_in_maintenance());
+
+        info.set_maintenance_mode(MaintenanceModeConfig {
+            enabled: false,
+            reason: None,
+            during_migrations: true,
+        });
+        assert!(info.is_in_maintenance());
+
+        info.set_index_rebuild(None);
+        assert!(!info.is_in_maintenance());
+
+        info.set_maintenance_mode(MaintenanceModeConfig {
+            enabled: true,
+            reason: None,
+            during_migrations: false,
+        });
+        assert!(info.is_in_maintenance());
+    }
+
+    #[test]
+    fn added_networks_override_the_default_ones() {
+        let mut info = SystemInfo::default();
+        assert_eq!(info.get_networks(), Network::defaults());
+
+        let mut mainnet = info
+            .find_network(&Blockchain::Ethereum, Network::MAINNET)
+            .unwrap();
+        mainnet.name = "Ethereum Mainnet".to_string();
+        info.upsert_network(mainnet.clone());
+        info.upsert_network(Network {
+            id: "sepolia".to_string(),
+            blockchain: Blockchain::Ethereum,
+            name: "Ethereum Sepolia".to_string(),
+            chain_id: Some("11155111".to_string()),
+            is_testnet: true,
+        });
+
+        assert_eq!(info.get_networks().len(), Network::defaults().len() + 1);
+        assert_eq!(
+            info.find_network(&Blockchain::Ethereum, Network::MAINNET),
+            Some(mainnet)
+        );
+        assert!(
+            info.find_network(&Blockchain::Ethereum, "sepolia")
+                .unwrap()
+                .is_testnet
+        );
+        assert!(info.find_network(&Blockchain::Bitcoin, "sepolia").is_none());
+    }
+
+    #[test]
+    fn blockchain_freezes_cover_their_networks() {
+        let mut info = SystemInfo::default();
+        assert!(info
+            .find_blockchain_freeze(&Blockchain::Ethereum, Network::MAINNET)
+            .is_none());
+
+        info.add_blockchain_freeze(BlockchainFreeze {
+            blockchain: Blockchain::Ethereum,
+            network: Some("sepolia".to_string()),
+            reason: None,
+            request_id: [1; 16],
+            frozen_at: 0,
+        });
+        assert!(info
+            .find_blockchain_freeze(&Blockchain::Ethereum, "sepolia")
+            .is_some());
+        assert!(info
+            .find_blockchain_freeze(&Blockchain::Ethereum, Network::MAINNET)
+            .is_none());
+        assert!(info
+            .get_blockchain_freeze(&Blockchain::Ethereum, None)
+            .is_none());
+
+        info.add_blockchain_freeze(BlockchainFreeze {
+            blockchain: Blockchain::Ethereum,
+            network: None,
+            reason: Some("RPC providers are down".to_string()),
+            request_id: [2; 16],
+            frozen_at: 0,
+        });
+        assert_eq!(
+            info.find_blockchain_freeze(&Blockchain::Ethereum, Network::MAINNET)
+                .map(|freeze| freeze.request_id),
+            Some([2; 16])
+        );
+        assert!(info
+            .find_blockchain_freeze(&Blockchain::Bitcoin, Network::MAINNET)
+            .is_none());
+
+        assert!(info
+            .remove_blockchain_freeze(&Blockchain::Ethereum, None)
+            .is_some());
+        assert!(info
+            .remove_blockchain_freeze(&Blockchain::Ethereum, None)
+            .is_none());
+        assert!(info
+            .find_blockchain_freeze(&Blockchain::Ethereum, Network::MAINNET)
+            .is_none());
+        assert!(info
+            .find_blockchain_freeze(&Blockchain::Ethereum, "sepolia")
+            .is_some());
+    }
+
     #[test]
     fn test_system_info_name_validation() {
         let mut info = SystemInfo::default();
@@ -242,4 +1563,64 @@ mod tests {
         info.set_name("  test".to_string());
         assert_eq!(info.name, "test");
     }
+
+    #[test]
+    fn test_metadata_encryption_encrypted_values() {
+        assert!(MetadataEncryptionConfig::is_encrypted_value(
+            "vetkd:v1:aGVsbG8="
+        ));
+        assert!(!MetadataEncryptionConfig::is_encrypted_value("vetkd:v1:"));
+        assert!(!MetadataEncryptionConfig::is_encrypted_value("hello"));
+
+        let config = MetadataEncryptionConfig::VetKd {
+            key_name: "key_1".to_string(),
+            address_book_metadata_keys: vec!["notes".to_string()],
+        };
+
+        assert!(config.is_enabled());
+        assert!(config.encrypts_address_book_metadata("notes"));
+        assert!(!config.encrypts_address_book_metadata("kyc"));
+        assert!(!MetadataEncryptionConfig::Disabled.encrypts_address_book_metadata("notes"));
+
+        let metadata = [
+            ("notes".to_string(), "plaintext".to_string()),
+            ("kyc".to_string(), "plaintext".to_string()),
+        ];
+        assert_eq!(
+            config.unencrypted_address_book_metadata(metadata.iter().map(|(k, v)| (k, v))),
+            vec!["notes"]
+        );
+    }
+
+    #[test]
+    fn approval_reminders_are_due_after_their_interval() {
+        let hour_ns = 60 * 60 * 1_000_000_000;
+        let config = ApprovalReminderConfig {
+            intervals_secs: vec![24 * 60 * 60, 48 * 60 * 60],
+        };
+
+        assert_eq!(config.last_due_at(0, 23 * hour_ns), None);
+        assert_eq!(config.last_due_at(0, 30 * hour_ns), Some(24 * hour_ns));
+        assert_eq!(config.last_due_at(0, 100 * hour_ns), Some(48 * hour_ns));
+        assert_eq!(
+            ApprovalReminderConfig::default().last_due_at(0, 100 * hour_ns),
+            None
+        );
+    }
+
+    #[test]
+    fn balance_snapshots_are_aligned_to_their_interval() {
+        let day_ns = 24 * 60 * 60 * 1_000_000_000;
+        let config = BalanceSnapshotConfig::default();
+
+        assert_eq!(config.next_snapshot_at(0), Some(day_ns));
+        assert_eq!(config.next_snapshot_at(day_ns + 1), Some(2 * day_ns));
+        assert_eq!(
+            BalanceSnapshotConfig {
+                interval_secs: None
+            }
+            .next_snapshot_at(day_ns),
+            None
+        );
+    }
 }
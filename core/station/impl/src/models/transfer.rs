@@ -2,7 +2,7 @@ use super::{AccountId, UserId};
 use crate::core::ic_cdk::next_time;
 use crate::core::validation::{EnsureAccount, EnsureIdExists, EnsureRequest, EnsureUser};
 use crate::errors::{RecordValidationError, TransferError};
-use crate::models::Metadata;
+use crate::models::{Metadata, MetadataEncryptionConfig};
 use orbit_essentials::model::ModelKey;
 use orbit_essentials::storable;
 use orbit_essentials::{
@@ -48,6 +48,160 @@ impl Display for TransferStatus {
     }
 }
 
+/// Keeps track of the submissions of a transfer to the blockchain.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferSubmission {
+    /// The creation time of the transaction, it is the same for every attempt so that the
+    /// blockchain can deduplicate the transaction if it was already accepted.
+    pub created_at: Timestamp,
+    /// The number of times the transaction was submitted.
+    pub attempts: u32,
+    /// The block height of a previous submission that was found to be already accepted by the blockchain.
+    #[serde(default)]
+    pub reconciled_block_height: Option<u64>,
+}
+
+/// The travel rule information of a transfer, which regulated entities must record for transfers
+/// above the thresholds set by their jurisdiction.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferComplianceInfo {
+    /// The name of the person or entity that receives the funds.
+    pub beneficiary_name: Option<String>,
+    /// The name of the person or entity on whose behalf the funds are sent.
+    pub originator_name: Option<String>,
+    /// The physical address, national identity number or customer identification number of the originator.
+    pub originator_identifier: Option<String>,
+    /// The purpose of payment code (e.g. the ISO 20022 code `SUPP` for supplier payments).
+    pub purpose_code: Option<String>,
+}
+
+impl TransferComplianceInfo {
+    pub const FIELD_RANGE: (usize, usize) = (1, 255);
+    pub const PURPOSE_CODE_RANGE: (usize, usize) = (1, 35);
+
+    fn fields(&self) -> [(&'static str, &Option<String>); 4] {
+        [
+            ("beneficiary_name", &self.beneficiary_name),
+            ("originator_name", &self.originator_name),
+            ("originator_identifier", &self.originator_identifier),
+            ("purpose_code", &self.purpose_code),
+        ]
+    }
+
+    /// Returns the names of the fields that are not set.
+    pub fn missing_fields(&self) -> Vec<&'static str> {
+        self.fields()
+            .into_iter()
+            .filter(|(_, value)| value.as_ref().map_or(true, |value| value.trim().is_empty()))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Returns the names of the fields that are set but not encrypted.
+    pub fn unencrypted_fields(&self) -> Vec<&'static str> {
+        self.fields()
+            .into_iter()
+            .filter(|(_, value)| {
+                value
+                    .as_ref()
+                    .is_some_and(|value| !MetadataEncryptionConfig::is_encrypted_value(value))
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+impl ModelValidator<TransferError> for TransferComplianceInfo {
+    fn validate(&self) -> ModelValidatorResult<TransferError> {
+        for (name, value) in self.fields() {
+            let Some(value) = value else {
+                continue;
+            };
+
+            let range = match (name, MetadataEncryptionConfig::is_encrypted_value(value)) {
+                (_, true) => (1, MetadataEncryptionConfig::MAX_ENCRYPTED_VALUE_LEN),
+                ("purpose_code", false) => Self::PURPOSE_CODE_RANGE,
+                (_, false) => Self::FIELD_RANGE,
+            };
+
+            let len = value.chars().count();
+            if len < range.0 || len > range.1 {
+                return Err(TransferError::ValidationError {
+                    info: format!(
+                        "The compliance field {} length must be between {} and {}",
+                        name, range.0, range.1
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The reconciliation notes attached to a completed transfer for bookkeeping.
+///
+/// Annotations are kept apart from the transfer record, which is immutable once the transfer is completed,
+/// and are replaced as a whole each time the transfer is annotated.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferAnnotation {
+    /// The transfer that the annotation belongs to.
+    pub transfer_id: TransferId,
+    /// The id of the invoice in the external accounting system (e.g. `INV-2024-0042`).
+    pub external_invoice_id: Option<String>,
+    /// The accounting period that the transfer is booked in (e.g. `2024-Q3`).
+    pub accounting_period: Option<String>,
+    /// Free form reconciliation notes.
+    pub notes: Option<String>,
+    /// The user that last annotated the transfer.
+    pub annotated_by: UserId,
+    /// The last time the transfer was annotated.
+    pub annotated_at: Timestamp,
+}
+
+impl TransferAnnotation {
+    pub const EXTERNAL_INVOICE_ID_RANGE: (usize, usize) = (1, 100);
+    pub const ACCOUNTING_PERIOD_RANGE: (usize, usize) = (1, 50);
+    pub const NOTES_RANGE: (usize, usize) = (1, 2000);
+}
+
+impl ModelValidator<TransferError> for TransferAnnotation {
+    fn validate(&self) -> ModelValidatorResult<TransferError> {
+        for (name, value, range) in [
+            (
+                "external_invoice_id",
+                &self.external_invoice_id,
+                Self::EXTERNAL_INVOICE_ID_RANGE,
+            ),
+            (
+                "accounting_period",
+                &self.accounting_period,
+                Self::ACCOUNTING_PERIOD_RANGE,
+            ),
+            ("notes", &self.notes, Self::NOTES_RANGE),
+        ] {
+            let Some(value) = value else {
+                continue;
+            };
+
+            let len = value.chars().count();
+            if len < range.0 || len > range.1 {
+                return Err(TransferError::ValidationError {
+                    info: format!(
+                        "The annotation field {} length must be between {} and {}",
+                        name, range.0, range.1
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Represents a transfer in the system.
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -76,6 +230,15 @@ pub struct Transfer {
     pub last_modification_timestamp: Timestamp,
     /// The creation timestamp of the transfer.
     pub created_timestamp: Timestamp,
+    /// The submissions of the transfer to the blockchain, if any.
+    #[serde(default)]
+    pub submission: Option<TransferSubmission>,
+    /// The travel rule information of the transfer, if any.
+    #[serde(default)]
+    pub compliance: Option<TransferComplianceInfo>,
+    /// The address of the account that the transfer is from, if not its current address.
+    #[serde(default)]
+    pub from_address: Option<String>,
 }
 
 #[storable]
@@ -94,6 +257,13 @@ impl ModelKey<TransferKey> for Transfer {
 impl Transfer {
     pub const ADDRESS_RANGE: (u8, u8) = (1, 255);
     pub const NETWORK_RANGE: (u8, u8) = (1, 50);
+    pub const MAX_SUBMISSION_ATTEMPTS: u32 = 3;
+    /// How long after its first submission a transfer can be submitted again, which is 1 hour.
+    ///
+    /// The ledgers deduplicate the transactions with the same creation time for 24 hours, so a resubmission
+    /// within this window can't be accepted twice even if the previous submission is not found in the blocks
+    /// that are searched before resubmitting.
+    pub const RESUBMISSION_WINDOW_NS: u64 = 60 * 60 * 1_000_000_000;
 
     /// Creates a new transfer key from the given key components.
     pub fn key(id: TransferId) -> TransferKey {
@@ -135,8 +305,48 @@ impl Transfer {
             metadata,
             last_modification_timestamp: now,
             created_timestamp: now,
+            submission: None,
+            compliance: None,
+            from_address: None,
+        }
+    }
+
+    /// Registers a new attempt to submit the transfer, keeping the creation time of the first attempt.
+    pub fn register_submission_attempt(&mut self, now: Timestamp) {
+        let submission = self.submission.get_or_insert(TransferSubmission {
+            created_at: now,
+            attempts: 0,
+            reconciled_block_height: None,
+        });
+
+        submission.attempts += 1;
+    }
+
+    /// Whether the transfer was already submitted before the current attempt.
+    pub fn is_resubmission(&self) -> bool {
+        self.submission
+            .as_ref()
+            .is_some_and(|submission| submission.attempts > 1)
+    }
+
+    /// Records that the transfer was reconciled with a previous submission at the given block height.
+    pub fn mark_reconciled(&mut self, block_height: u64) {
+        if let Some(submission) = self.submission.as_mut() {
+            submission.reconciled_block_height = Some(block_height);
         }
     }
+
+    /// Whether the transfer can be submitted again after a submission with an unknown outcome, which is
+    /// bounded by the number of attempts and by the resubmission window.
+    pub fn can_resubmit(&self, now: Timestamp) -> bool {
+        self.submission.as_ref().map_or(true, |submission| {
+            submission.attempts < Self::MAX_SUBMISSION_ATTEMPTS
+                && now
+                    < submission
+                        .created_at
+                        .saturating_add(Self::RESUBMISSION_WINDOW_NS)
+        })
+    }
 }
 
 fn validate_to_address(to_address: &str) -> ModelValidatorResult<TransferError> {
@@ -177,6 +387,10 @@ impl ModelValidator<TransferError> for Transfer {
         validate_to_address(&self.to_address)?;
         validate_network(&self.blockchain_network)?;
 
+        if let Some(compliance) = &self.compliance {
+            compliance.validate()?;
+        }
+
         EnsureUser::id_exists(&self.initiator_user).map_err(|err| match err {
             RecordValidationError::NotFound { id, .. } => TransferError::ValidationError {
                 info: format!("The initiator_user {} does not exist", id),
@@ -205,6 +419,40 @@ mod tests {
     use super::*;
     use transfer_test_utils::mock_transfer;
 
+    #[test]
+    fn submission_attempts_keep_the_first_creation_time() {
+        let mut transfer = mock_transfer();
+        assert!(!transfer.is_resubmission());
+
+        transfer.register_submission_attempt(10);
+        assert!(!transfer.is_resubmission());
+        assert!(transfer.can_resubmit(20));
+        assert!(!transfer.can_resubmit(10 + Transfer::RESUBMISSION_WINDOW_NS));
+
+        transfer.register_submission_attempt(20);
+        transfer.register_submission_attempt(30);
+
+        assert!(transfer.is_resubmission());
+        assert!(!transfer.can_resubmit(40));
+        assert_eq!(
+            transfer.submission,
+            Some(TransferSubmission {
+                created_at: 10,
+                attempts: 3,
+                reconciled_block_height: None,
+            })
+        );
+
+        transfer.mark_reconciled(42);
+
+        assert_eq!(
+            transfer
+                .submission
+                .and_then(|submission| submission.reconciled_block_height),
+            Some(42)
+        );
+    }
+
     #[test]
     fn test_address_validation() {
         let mut transfer = mock_transfer();
@@ -304,6 +552,47 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn compliance_info_reports_missing_fields() {
+        let compliance = TransferComplianceInfo {
+            beneficiary_name: Some("Beneficiary".to_string()),
+            originator_name: Some(" ".to_string()),
+            originator_identifier: None,
+            purpose_code: Some("SUPP".to_string()),
+        };
+
+        assert_eq!(
+            compliance.missing_fields(),
+            vec!["originator_name", "originator_identifier"]
+        );
+        assert!(TransferComplianceInfo::default().missing_fields().len() == 4);
+    }
+
+    #[test]
+    fn fail_compliance_purpose_code_too_long() {
+        let compliance = TransferComplianceInfo {
+            purpose_code: Some("A".repeat(TransferComplianceInfo::PURPOSE_CODE_RANGE.1 + 1)),
+            ..Default::default()
+        };
+
+        assert!(compliance.validate().is_err());
+    }
+
+    #[test]
+    fn fail_annotation_notes_too_long() {
+        let mut annotation = transfer_test_utils::mock_transfer_annotation();
+        assert!(annotation.validate().is_ok());
+
+        annotation.notes = Some("a".repeat(TransferAnnotation::NOTES_RANGE.1 + 1));
+
+        assert_eq!(
+            annotation.validate().unwrap_err(),
+            TransferError::ValidationError {
+                info: "The annotation field notes length must be between 1 and 2000".to_string()
+            }
+        );
+    }
 }
 
 #[cfg(test)]
@@ -327,6 +616,20 @@ pub mod transfer_test_utils {
             metadata: Metadata::default(),
             last_modification_timestamp: now,
             created_timestamp: now,
+            submission: None,
+            compliance: None,
+            from_address: None,
+        }
+    }
+
+    pub fn mock_transfer_annotation() -> TransferAnnotation {
+        TransferAnnotation {
+            transfer_id: *Uuid::new_v4().as_bytes(),
+            external_invoice_id: Some("INV-2024-0042".to_string()),
+            accounting_period: Some("2024-Q3".to_string()),
+            notes: Some("Paid supplier invoice".to_string()),
+            annotated_by: [1; 16],
+            annotated_at: next_time(),
         }
     }
 }
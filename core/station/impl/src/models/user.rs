@@ -1,4 +1,4 @@
-use super::UserStatus;
+use super::{UserGroupId, UserStatus};
 use crate::{
     core::validation::{EnsureIdExists, EnsureUserGroup},
     errors::{RecordValidationError, UserError},
@@ -9,6 +9,7 @@ use orbit_essentials::{
     model::{ModelValidator, ModelValidatorResult},
     types::{Timestamp, UUID},
 };
+use uuid::Uuid;
 
 /// The user id, which is a UUID.
 pub type UserId = UUID;
@@ -31,6 +32,36 @@ pub struct User {
     pub groups: Vec<UUID>,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
+    /// The version of the record, incremented on every edit to detect concurrent edits.
+    #[serde(default)]
+    pub version: u64,
+    /// The last time the user was active on the station, i.e. called it or created or approved a request, used
+    /// to detect activity of dormant users.
+    #[serde(default)]
+    pub last_active_at: Option<Timestamp>,
+    /// The memberships of the user in groups that start or end at a later time.
+    #[serde(default)]
+    pub scheduled_memberships: Vec<ScheduledGroupMembership>,
+}
+
+/// A membership of a user in a group that starts or ends at a given time, e.g. the access of a contractor
+/// that ends at the end of their contract.
+#[storable]
+#[derive(CandidType, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScheduledGroupMembership {
+    pub group_id: UserGroupId,
+    /// When the user joins the group, none if the user is a member of the group already.
+    pub starts_at: Option<Timestamp>,
+    /// When the user leaves the group, none if the membership doesn't end.
+    pub expires_at: Option<Timestamp>,
+}
+
+/// A change of the groups of a user made when a scheduled membership started or ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GroupMembershipChange {
+    pub group_id: UserGroupId,
+    /// Whether the user joined the group, or else left it.
+    pub joined: bool,
 }
 
 #[storable]
@@ -57,6 +88,17 @@ pub struct DisplayUser {
     pub name: String,
 }
 
+/// A user that was not active for a while but is named as an approver by request policies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaleApprover {
+    pub user_id: UserId,
+    pub name: String,
+    /// Not set if no activity of the user was recorded yet.
+    pub last_active_at: Option<Timestamp>,
+    /// The request policies that name the user as an approver.
+    pub policy_ids: Vec<UUID>,
+}
+
 impl User {
     pub const IDENTITIES_RANGE: (u8, u8) = (1, 10);
     pub const MAX_USER_GROUPS: u8 = 25;
@@ -74,6 +116,61 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    /// Returns the next time at which a scheduled membership of the user starts or ends.
+    pub fn next_membership_change_at(&self) -> Option<Timestamp> {
+        self.scheduled_memberships
+            .iter()
+            .flat_map(|membership| [membership.starts_at, membership.expires_at])
+            .flatten()
+            .min()
+    }
+
+    /// Applies the scheduled memberships that are due at the given time to the groups of the user.
+    ///
+    /// The memberships that ended are removed from the schedule, and the ones that started are kept until
+    /// they expire, if they do.
+    pub fn apply_scheduled_memberships(&mut self, now: Timestamp) -> Vec<GroupMembershipChange> {
+        let mut changes = Vec::new();
+
+        for membership in self.scheduled_memberships.iter_mut() {
+            if membership
+                .starts_at
+                .is_some_and(|starts_at| starts_at <= now)
+            {
+                membership.starts_at = None;
+
+                if !self.groups.contains(&membership.group_id) {
+                    self.groups.push(membership.group_id);
+                    changes.push(GroupMembershipChange {
+                        group_id: membership.group_id,
+                        joined: true,
+                    });
+                }
+            }
+
+            if membership
+                .expires_at
+                .is_some_and(|expires_at| expires_at <= now)
+            {
+                membership.expires_at = None;
+
+                if self.groups.contains(&membership.group_id) {
+                    self.groups
+                        .retain(|group_id| *group_id != membership.group_id);
+                    changes.push(GroupMembershipChange {
+                        group_id: membership.group_id,
+                        joined: false,
+                    });
+                }
+            }
+        }
+
+        self.scheduled_memberships
+            .retain(|membership| membership.starts_at.is_some() || membership.expires_at.is_some());
+
+        changes
+    }
 }
 
 fn validate_identities(identities: &[Principal]) -> ModelValidatorResult<UserError> {
@@ -114,6 +211,51 @@ fn validate_groups(group_ids: &[UUID]) -> ModelValidatorResult<UserError> {
     Ok(())
 }
 
+fn validate_scheduled_memberships(
+    memberships: &[ScheduledGroupMembership],
+) -> ModelValidatorResult<UserError> {
+    if memberships.len() > User::MAX_USER_GROUPS as usize {
+        return Err(UserError::TooManyUserGroups {
+            max: User::MAX_USER_GROUPS,
+        });
+    }
+
+    for (position, membership) in memberships.iter().enumerate() {
+        let invalid = |info: &str| UserError::InvalidScheduledMembership {
+            group_id: Uuid::from_bytes(membership.group_id)
+                .hyphenated()
+                .to_string(),
+            info: info.to_string(),
+        };
+
+        if membership.starts_at.is_none() && membership.expires_at.is_none() {
+            return Err(invalid(
+                "the membership must start or expire at a given time",
+            ));
+        }
+
+        if let (Some(starts_at), Some(expires_at)) = (membership.starts_at, membership.expires_at) {
+            if expires_at <= starts_at {
+                return Err(invalid("the membership must expire after it starts"));
+            }
+        }
+
+        if memberships[..position]
+            .iter()
+            .any(|other| other.group_id == membership.group_id)
+        {
+            return Err(invalid("the group can only have one scheduled membership"));
+        }
+    }
+
+    validate_groups(
+        &memberships
+            .iter()
+            .map(|membership| membership.group_id)
+            .collect::<Vec<_>>(),
+    )
+}
+
 fn validate_name(name: &str) -> ModelValidatorResult<UserError> {
     if name.len() > User::MAX_NAME_LENGTH as usize {
         return Err(UserError::NameTooLong {
@@ -128,6 +270,7 @@ impl ModelValidator<UserError> for User {
     fn validate(&self) -> ModelValidatorResult<UserError> {
         validate_identities(&self.identities)?;
         validate_groups(&self.groups)?;
+        validate_scheduled_memberships(&self.scheduled_memberships)?;
         validate_name(&self.name)?;
 
         Ok(())
@@ -223,6 +366,7 @@ mod tests {
                         id,
                         last_modification_timestamp: 0,
                         name: format!("group_{}", i),
+                        quotas: Default::default(),
                     },
                 );
                 id
@@ -285,6 +429,25 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn fail_scheduled_membership_expiring_before_it_starts() {
+        let memberships = vec![ScheduledGroupMembership {
+            group_id: [1; 16],
+            starts_at: Some(10),
+            expires_at: Some(5),
+        }];
+
+        let result = validate_scheduled_memberships(&memberships);
+
+        assert_eq!(
+            result.unwrap_err(),
+            UserError::InvalidScheduledMembership {
+                group_id: Uuid::from_bytes([1; 16]).hyphenated().to_string(),
+                info: "the membership must expire after it starts".to_string(),
+            }
+        );
+    }
 }
 
 #[cfg(any(test, feature = "canbench"))]
@@ -306,6 +469,9 @@ pub mod user_test_utils {
             name: format!("user_{}", uuid),
             status: UserStatus::Active,
             last_modification_timestamp: 0,
+            version: 0,
+            last_active_at: None,
+            scheduled_memberships: vec![],
         }
     }
 
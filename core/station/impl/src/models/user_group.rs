@@ -1,6 +1,14 @@
-use crate::{errors::UserGroupError, repositories::USER_GROUP_REPOSITORY};
+use super::{resource::Resource, UserId};
+use crate::{
+    errors::UserGroupError,
+    repositories::{
+        permission::PERMISSION_REPOSITORY, REQUEST_POLICY_REPOSITORY, USER_GROUP_REPOSITORY,
+        USER_REPOSITORY,
+    },
+};
 use candid::{CandidType, Deserialize};
 use orbit_essentials::model::ModelKey;
+use orbit_essentials::repository::Repository;
 use orbit_essentials::storable;
 use orbit_essentials::{
     model::{ModelValidator, ModelValidatorResult},
@@ -21,10 +29,130 @@ pub struct UserGroup {
     pub id: UUID,
     /// The name of the user group (e.g. "Finance").
     pub name: String,
+    /// The limits on the shared resources of the station that the members of the group can use.
+    #[serde(default)]
+    pub quotas: UserGroupQuotas,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
 }
 
+/// The quotas of a user group, which keep the members of a single group from exhausting the
+/// resources of the station that are shared with the other groups.
+#[storable]
+#[derive(CandidType, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserGroupQuotas {
+    /// The maximum number of requests of the members of the group that can be pending at once.
+    pub max_pending_requests: Option<u32>,
+    /// The maximum number of accounts that the members of the group can create.
+    pub max_accounts: Option<u32>,
+}
+
+impl UserGroupQuotas {
+    pub fn is_empty(&self) -> bool {
+        self.max_pending_requests.is_none() && self.max_accounts.is_none()
+    }
+}
+
+/// The current utilization of the quotas of a user group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserGroupQuotaUsage {
+    pub user_group_id: UUID,
+    pub quotas: UserGroupQuotas,
+    /// The number of requests of the members of the group that are pending.
+    pub pending_requests: u32,
+    /// The number of accounts that the members of the group created or requested.
+    pub accounts: u32,
+}
+
+/// What happens to the references to a user group when it is removed.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UserGroupReassignment {
+    /// The references are moved to the replacement group.
+    ReplaceWith(UserGroupId),
+    /// The references are dropped, the members leave the group and the policies and permissions stop
+    /// naming it.
+    Detach,
+}
+
+impl UserGroupReassignment {
+    /// Returns the group that takes over the references, if any.
+    pub fn replacement(&self) -> Option<UserGroupId> {
+        match self {
+            UserGroupReassignment::ReplaceWith(user_group_id) => Some(*user_group_id),
+            UserGroupReassignment::Detach => None,
+        }
+    }
+}
+
+/// The entities that reference a user group.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserGroupReferences {
+    /// The request policies whose rules name the group.
+    pub request_policies: Vec<UUID>,
+    /// The resources whose permissions allow the group.
+    pub permissions: Vec<Resource>,
+    /// The users that are members of the group, or are scheduled to join it.
+    pub users: Vec<UserId>,
+}
+
+impl UserGroupReferences {
+    /// Finds the entities that currently reference the user group.
+    pub fn find(user_group_id: &UserGroupId) -> Self {
+        Self {
+            request_policies: REQUEST_POLICY_REPOSITORY
+                .list()
+                .into_iter()
+                .filter(|policy| policy.rule.user_groups().contains(user_group_id))
+                .map(|policy| policy.id)
+                .collect(),
+            permissions: PERMISSION_REPOSITORY
+                .list()
+                .into_iter()
+                .filter(|permission| permission.allow.user_groups.contains(user_group_id))
+                .map(|permission| permission.resource)
+                .collect(),
+            users: USER_REPOSITORY
+                .list()
+                .into_iter()
+                .filter(|user| {
+                    user.groups.contains(user_group_id)
+                        || user
+                            .scheduled_memberships
+                            .iter()
+                            .any(|membership| membership.group_id == *user_group_id)
+                })
+                .map(|user| user.id)
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.request_policies.is_empty() && self.permissions.is_empty() && self.users.is_empty()
+    }
+}
+
+/// Replaces the user group in the list with the replacement, or drops it if there is none, without
+/// naming the replacement twice.
+pub fn reassign_user_group_id(
+    user_group_ids: &mut Vec<UserGroupId>,
+    user_group_id: &UserGroupId,
+    replacement: Option<UserGroupId>,
+) {
+    if !user_group_ids.contains(user_group_id) {
+        return;
+    }
+
+    user_group_ids.retain(|id| id != user_group_id);
+
+    if let Some(replacement) = replacement {
+        if !user_group_ids.contains(&replacement) {
+            user_group_ids.push(replacement);
+        }
+    }
+}
+
 impl ModelKey<UserGroupId> for UserGroup {
     fn key(&self) -> UserGroupId {
         self.id
@@ -176,6 +304,7 @@ pub mod user_group_test_utils {
         UserGroup {
             id: [0; 16],
             name: "test".to_string(),
+            quotas: UserGroupQuotas::default(),
             last_modification_timestamp: 0,
         }
     }
@@ -0,0 +1,72 @@
+use super::{RequestApprovalStatus, RequestId, UserId};
+use candid::Principal;
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+
+/// The label of a receipt in the certified tree of the votes, the request id followed by the approver id.
+pub type VoteReceiptLabel = [u8; 32];
+/// The sha256 hash of the canonical encoding of a receipt.
+pub type VoteReceiptHash = [u8; 32];
+
+/// The receipt of a vote on a request, which is certified so that the vote can be proven to third parties
+/// (e.g. legal teams) with a certificate of the Internet Computer.
+///
+/// Receipts are kept apart from the requests, so that they remain available once the requests are archived.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VoteReceipt {
+    pub request_id: RequestId,
+    /// The user that voted.
+    pub approver_id: UserId,
+    /// The identity that the user voted with.
+    pub voter: Principal,
+    pub decision: RequestApprovalStatus,
+    pub decided_at: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VoteReceiptKey {
+    pub request_id: RequestId,
+    pub approver_id: UserId,
+}
+
+impl VoteReceipt {
+    pub fn to_key(&self) -> VoteReceiptKey {
+        VoteReceiptKey {
+            request_id: self.request_id,
+            approver_id: self.approver_id,
+        }
+    }
+
+    /// The label of the receipt in the certified tree, which is the request id followed by the approver id.
+    pub fn certified_label(&self) -> VoteReceiptLabel {
+        self.to_key().certified_label()
+    }
+}
+
+impl VoteReceiptKey {
+    pub fn certified_label(&self) -> VoteReceiptLabel {
+        let mut label = [0; 32];
+        label[..16].copy_from_slice(&self.request_id);
+        label[16..].copy_from_slice(&self.approver_id);
+
+        label
+    }
+}
+
+#[cfg(test)]
+pub mod vote_receipt_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_vote_receipt() -> VoteReceipt {
+        VoteReceipt {
+            request_id: *Uuid::new_v4().as_bytes(),
+            approver_id: *Uuid::new_v4().as_bytes(),
+            voter: Principal::from_slice(&[3; 29]),
+            decision: RequestApprovalStatus::Approved,
+            decided_at: 0,
+        }
+    }
+}
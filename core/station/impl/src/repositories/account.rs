@@ -5,7 +5,7 @@ use crate::{
         with_memory_manager, Memory, ACCOUNT_MEMORY_ID,
     },
     models::{indexes::unique_index::UniqueIndexKey, Account, AccountId, AccountKey},
-    services::disaster_recovery_observes_insert_account,
+    services::{disaster_recovery_observes_insert_account, station_events_observe_insert_account},
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
 use lazy_static::lazy_static;
@@ -37,6 +37,7 @@ impl Default for AccountRepository {
     fn default() -> Self {
         let mut change_observer = Observer::default();
         disaster_recovery_observes_insert_account(&mut change_observer);
+        station_events_observe_insert_account(&mut change_observer);
 
         Self {
             change_observer,
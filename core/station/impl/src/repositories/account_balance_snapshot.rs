@@ -0,0 +1,134 @@
+use crate::{
+    core::{with_memory_manager, Memory, ACCOUNT_BALANCE_SNAPSHOT_MEMORY_ID},
+    models::{AccountBalanceSnapshot, AccountBalanceSnapshotKey, AccountId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    repository::{Repository, StableDb},
+    types::Timestamp,
+};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<AccountBalanceSnapshotKey, AccountBalanceSnapshot, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ACCOUNT_BALANCE_SNAPSHOT_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY: Arc<AccountBalanceSnapshotRepository> =
+        Arc::new(AccountBalanceSnapshotRepository::default());
+}
+
+/// A repository that stores the periodic balance snapshots of the accounts in stable memory, ordered by
+/// account and time.
+#[derive(Default, Debug)]
+pub struct AccountBalanceSnapshotRepository {}
+
+impl StableDb<AccountBalanceSnapshotKey, AccountBalanceSnapshot, VirtualMemory<Memory>>
+    for AccountBalanceSnapshotRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<
+                AccountBalanceSnapshotKey,
+                AccountBalanceSnapshot,
+                VirtualMemory<Memory>,
+            >,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<AccountBalanceSnapshotKey, AccountBalanceSnapshot, VirtualMemory<Memory>>
+    for AccountBalanceSnapshotRepository
+{
+}
+
+impl AccountBalanceSnapshotRepository {
+    /// Returns the snapshots of the account that were taken within the given time range, oldest first.
+    pub fn find_by_account(
+        &self,
+        account_id: AccountId,
+        from_dt: Timestamp,
+        to_dt: Timestamp,
+    ) -> Vec<(Timestamp, AccountBalanceSnapshot)> {
+        if from_dt > to_dt {
+            return Vec::new();
+        }
+
+        let start_key = AccountBalanceSnapshotKey {
+            account_id,
+            timestamp: from_dt,
+        };
+        let end_key = AccountBalanceSnapshotKey {
+            account_id,
+            timestamp: to_dt,
+        };
+
+        DB.with(|db| {
+            db.borrow()
+                .range(start_key..=end_key)
+                .map(|(key, snapshot)| (key.timestamp, snapshot))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(balance: u64) -> AccountBalanceSnapshot {
+        AccountBalanceSnapshot {
+            balance: candid::Nat::from(balance),
+        }
+    }
+
+    #[test]
+    fn test_crud() {
+        let repository = AccountBalanceSnapshotRepository::default();
+        let key = AccountBalanceSnapshotKey {
+            account_id: [1; 16],
+            timestamp: 10,
+        };
+
+        assert!(repository.get(&key).is_none());
+
+        repository.insert(key.clone(), snapshot(100));
+
+        assert_eq!(repository.get(&key), Some(snapshot(100)));
+        assert!(repository.remove(&key).is_some());
+        assert!(repository.get(&key).is_none());
+    }
+
+    #[test]
+    fn find_by_account_only_returns_the_snapshots_of_the_range() {
+        let repository = AccountBalanceSnapshotRepository::default();
+        for (account_id, timestamp, balance) in [
+            ([1; 16], 10, 1),
+            ([1; 16], 20, 2),
+            ([1; 16], 30, 3),
+            ([2; 16], 20, 4),
+        ] {
+            repository.insert(
+                AccountBalanceSnapshotKey {
+                    account_id,
+                    timestamp,
+                },
+                snapshot(balance),
+            );
+        }
+
+        assert_eq!(
+            repository.find_by_account([1; 16], 15, 30),
+            vec![(20, snapshot(2)), (30, snapshot(3))]
+        );
+        assert!(repository.find_by_account([1; 16], 30, 15).is_empty());
+    }
+}
@@ -1,18 +1,23 @@
-use super::indexes::unique_index::UniqueIndexRepository;
+use super::indexes::{
+    address_book_name_index::AddressBookNameIndexRepository, unique_index::UniqueIndexRepository,
+};
 use crate::{
     core::{
         metrics::ADDRESS_BOOK_METRICS, utils::max_string_of_size, with_memory_manager, Memory,
         ADDRESS_BOOK_MEMORY_ID,
     },
     models::{
-        indexes::unique_index::UniqueIndexKey, AddressBookEntry, AddressBookEntryId,
-        AddressBookEntryKey, Blockchain,
+        indexes::{
+            address_book_name_index::AddressBookNameIndexCriteria, unique_index::UniqueIndexKey,
+        },
+        AddressBookEntry, AddressBookEntryId, AddressBookEntryKey, Blockchain,
     },
 };
+use candid::Principal;
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
 use lazy_static::lazy_static;
 use orbit_essentials::{
-    repository::{IndexedRepository, Repository, StableDb},
+    repository::{IndexRepository, IndexedRepository, Repository, StableDb},
     types::UUID,
 };
 use std::{cell::RefCell, collections::HashSet, sync::Arc};
@@ -35,6 +40,7 @@ lazy_static! {
 #[derive(Default, Debug)]
 pub struct AddressBookRepository {
     unique_index: UniqueIndexRepository,
+    name_index: AddressBookNameIndexRepository,
 }
 
 impl StableDb<AddressBookEntryKey, AddressBookEntry, VirtualMemory<Memory>>
@@ -57,6 +63,8 @@ impl IndexedRepository<AddressBookEntryKey, AddressBookEntry, VirtualMemory<Memo
         value.to_unique_indexes().iter().for_each(|(index, _)| {
             self.unique_index.remove(index);
         });
+
+        self.name_index.remove(&value.to_index_by_name());
     }
 
     fn add_entry_indexes(&self, value: &AddressBookEntry) {
@@ -66,12 +74,16 @@ impl IndexedRepository<AddressBookEntryKey, AddressBookEntry, VirtualMemory<Memo
             .for_each(|(index, entry_id)| {
                 self.unique_index.insert(index, entry_id);
             });
+
+        self.name_index.insert(value.to_index_by_name());
     }
 
     /// Clears all the indexes for the repository.
     fn clear_indexes(&self) {
         self.unique_index
             .clear_when(|key| matches!(key, UniqueIndexKey::AddressBookBlockchainAddress(_, _)));
+
+        self.name_index.clear();
     }
 }
 
@@ -165,16 +177,97 @@ impl AddressBookRepository {
             .collect::<Vec<_>>()
     }
 
+    /// List all address book entries that were synced from the given address book source.
+    pub fn find_by_origin_source(&self, source_id: &Principal) -> Vec<AddressBookEntry> {
+        self.list()
+            .into_iter()
+            .filter(|entry| entry.is_synced_from(source_id))
+            .collect()
+    }
+
+    /// List all address book entries that were synced from any address book source.
+    pub fn find_synced(&self) -> Vec<AddressBookEntry> {
+        self.list()
+            .into_iter()
+            .filter(|entry| entry.origin.is_some())
+            .collect()
+    }
+
+    /// Adds all the entries to the name index, the unique index of their addresses is left untouched.
+    pub fn rebuild_name_index(&self) {
+        self.name_index.clear();
+
+        DB.with(|m| {
+            m.borrow()
+                .iter()
+                .for_each(|(_, entry)| self.name_index.insert(entry.to_index_by_name()))
+        });
+    }
+
     pub fn find_by_ids(&self, ids: Vec<AddressBookEntryId>) -> Vec<AddressBookEntry> {
         ids.iter()
             .filter_map(|id| self.get(&AddressBookEntry::key(*id)))
             .collect::<Vec<_>>()
     }
 
+    /// List the address book entries whose owner or address starts with the search term, the owner is
+    /// matched case-insensitively.
+    ///
+    /// The owners are matched with the name index, the addresses with the unique index if the blockchain is given.
+    pub fn find_by_search_term(
+        &self,
+        blockchain: Option<Blockchain>,
+        search_term: &str,
+    ) -> Vec<AddressBookEntry> {
+        let owner_matches = self
+            .name_index
+            .find_by_criteria(AddressBookNameIndexCriteria {
+                prefix: search_term.to_string(),
+            });
+
+        let mut entries = match blockchain {
+            Some(blockchain) => {
+                let address_matches = self.unique_index.find_by_criteria(
+                    Some(UniqueIndexKey::AddressBookBlockchainAddress(
+                        blockchain.to_string().to_lowercase(),
+                        search_term.to_string(),
+                    )),
+                    Some(UniqueIndexKey::AddressBookBlockchainAddress(
+                        blockchain.to_string().to_lowercase(),
+                        format!(
+                            "{}{}",
+                            search_term,
+                            max_string_of_size(&(AddressBookEntry::ADDRESS_RANGE.1 as usize))
+                        ),
+                    )),
+                    None,
+                );
+
+                owner_matches
+                    .union(&address_matches.into_iter().collect())
+                    .filter_map(|id| self.get(&AddressBookEntry::key(*id)))
+                    .filter(|entry| entry.blockchain == blockchain)
+                    .collect::<Vec<_>>()
+            }
+            None => self
+                .list()
+                .into_iter()
+                .filter(|entry| {
+                    owner_matches.contains(&entry.id) || entry.address.starts_with(search_term)
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        entries.sort();
+
+        entries
+    }
+
     pub fn find_where(&self, where_clause: AddressBookWhereClause) -> Vec<AddressBookEntry> {
-        let mut entries = match where_clause.blockchain {
-            Some(blockchain) => self.find_by_blockchain(blockchain),
-            _ => self.list(),
+        let mut entries = match (where_clause.search_term, where_clause.blockchain) {
+            (Some(search_term), blockchain) => self.find_by_search_term(blockchain, &search_term),
+            (None, Some(blockchain)) => self.find_by_blockchain(blockchain),
+            (None, None) => self.list(),
         };
 
         let where_labels = where_clause
@@ -207,6 +300,15 @@ impl AddressBookRepository {
             entries.retain(|entry| addresses.contains(&entry.address));
         }
 
+        if let Some(address_owners) = where_clause.address_owners {
+            let address_owners = address_owners
+                .into_iter()
+                .map(|owner| owner.to_lowercase())
+                .collect::<HashSet<_>>();
+
+            entries.retain(|entry| address_owners.contains(&entry.address_owner.to_lowercase()));
+        }
+
         entries.sort();
 
         entries
@@ -219,6 +321,8 @@ pub struct AddressBookWhereClause {
     pub labels: Option<Vec<String>>,
     pub addresses: Option<Vec<String>>,
     pub ids: Option<Vec<UUID>>,
+    pub search_term: Option<String>,
+    pub address_owners: Option<Vec<String>>,
 }
 
 #[cfg(test)]
@@ -280,4 +384,64 @@ mod tests {
         assert!(result.contains(&address_book_entry_0));
         assert!(result.contains(&address_book_entry_1));
     }
+
+    #[test]
+    fn test_find_where_search_term() {
+        let repository = AddressBookRepository::default();
+
+        let mut address_book_entry_0 = address_book_entry_test_utils::mock_address_book_entry();
+        address_book_entry_0.id = [1; 16];
+        address_book_entry_0.address_owner = "Treasury".to_string();
+        address_book_entry_0.address = "abcd".to_string();
+        address_book_entry_0.blockchain = Blockchain::InternetComputer;
+        repository.insert(address_book_entry_0.to_key(), address_book_entry_0.clone());
+
+        let mut address_book_entry_1 = address_book_entry_test_utils::mock_address_book_entry();
+        address_book_entry_1.id = [2; 16];
+        address_book_entry_1.address_owner = "Payroll".to_string();
+        address_book_entry_1.address = "trex".to_string();
+        address_book_entry_1.blockchain = Blockchain::InternetComputer;
+        repository.insert(address_book_entry_1.to_key(), address_book_entry_1.clone());
+
+        let mut address_book_entry_2 = address_book_entry_test_utils::mock_address_book_entry();
+        address_book_entry_2.id = [3; 16];
+        address_book_entry_2.address_owner = "Trading desk".to_string();
+        address_book_entry_2.address = "0x1234".to_string();
+        address_book_entry_2.blockchain = Blockchain::Ethereum;
+        repository.insert(address_book_entry_2.to_key(), address_book_entry_2.clone());
+
+        let where_clause = AddressBookWhereClause {
+            blockchain: None,
+            labels: None,
+            addresses: None,
+            ids: None,
+            search_term: Some("tr".to_string()),
+            address_owners: None,
+        };
+
+        let result = repository.find_where(where_clause.clone());
+        assert_eq!(
+            result,
+            vec![
+                address_book_entry_0.clone(),
+                address_book_entry_1.clone(),
+                address_book_entry_2.clone()
+            ]
+        );
+
+        let result = repository.find_where(AddressBookWhereClause {
+            blockchain: Some(Blockchain::InternetComputer),
+            ..where_clause.clone()
+        });
+        assert_eq!(
+            result,
+            vec![address_book_entry_0.clone(), address_book_entry_1.clone()]
+        );
+
+        let result = repository.find_where(AddressBookWhereClause {
+            address_owners: Some(vec!["payroll".to_string()]),
+            ..where_clause
+        });
+        assert_eq!(result, vec![address_book_entry_1]);
+    }
 }
@@ -0,0 +1,56 @@
+use crate::{
+    core::{with_memory_manager, Memory, ARCHIVED_REQUEST_MEMORY_ID},
+    models::{ArchivedRequest, RequestId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<RequestId, ArchivedRequest, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ARCHIVED_REQUEST_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ARCHIVED_REQUEST_REPOSITORY: Arc<ArchivedRequestRepository> =
+        Arc::new(ArchivedRequestRepository::default());
+}
+
+/// A repository that stores the stubs of the requests that were moved to the archive canister, by request id.
+#[derive(Default, Debug)]
+pub struct ArchivedRequestRepository {}
+
+impl StableDb<RequestId, ArchivedRequest, VirtualMemory<Memory>> for ArchivedRequestRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<RequestId, ArchivedRequest, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<RequestId, ArchivedRequest, VirtualMemory<Memory>> for ArchivedRequestRepository {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_test_utils::mock_archived_request;
+
+    #[test]
+    fn test_crud() {
+        let repository = ArchivedRequestRepository::default();
+        let archived = mock_archived_request();
+
+        assert!(repository.get(&archived.fields.id).is_none());
+
+        repository.insert(archived.fields.id, archived.clone());
+
+        assert_eq!(repository.get(&archived.fields.id), Some(archived.clone()));
+        assert!(repository.remove(&archived.fields.id).is_some());
+        assert!(repository.get(&archived.fields.id).is_none());
+    }
+}
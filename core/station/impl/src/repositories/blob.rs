@@ -0,0 +1,64 @@
+use crate::{
+    core::{with_memory_manager, Memory, BLOB_MEMORY_ID},
+    models::{Blob, BlobId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<BlobId, Blob, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(BLOB_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref BLOB_REPOSITORY: Arc<BlobRepository> = Arc::new(BlobRepository::default());
+}
+
+/// A repository that stores the blobs of the station (e.g. logos) in stable memory, by id.
+#[derive(Default, Debug)]
+pub struct BlobRepository {}
+
+impl StableDb<BlobId, Blob, VirtualMemory<Memory>> for BlobRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<BlobId, Blob, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<BlobId, Blob, VirtualMemory<Memory>> for BlobRepository {}
+
+impl BlobRepository {
+    /// Returns the size of the content of all the stored blobs.
+    pub fn total_size(&self) -> usize {
+        DB.with(|m| m.borrow().iter().map(|(_, blob)| blob.content.len()).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::blob_test_utils::mock_blob;
+
+    #[test]
+    fn test_crud() {
+        let repository = BlobRepository::default();
+        let blob = mock_blob();
+
+        assert!(repository.get(&blob.to_key()).is_none());
+
+        repository.insert(blob.to_key(), blob.clone());
+
+        assert_eq!(repository.get(&blob.to_key()), Some(blob.clone()));
+        assert_eq!(repository.total_size(), blob.content.len());
+        assert!(repository.remove(&blob.to_key()).is_some());
+        assert!(repository.get(&blob.to_key()).is_none());
+        assert_eq!(repository.total_size(), 0);
+    }
+}
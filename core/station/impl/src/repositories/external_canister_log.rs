@@ -0,0 +1,170 @@
+use crate::{
+    core::{with_memory_manager, Memory, EXTERNAL_CANISTER_LOG_MEMORY_ID},
+    models::{ExternalCanisterLogRecord, ExternalCanisterLogRecordKey},
+};
+use candid::Principal;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::model::ModelKey;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<ExternalCanisterLogRecordKey, ExternalCanisterLogRecord, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(EXTERNAL_CANISTER_LOG_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref EXTERNAL_CANISTER_LOG_REPOSITORY: Arc<ExternalCanisterLogRepository> =
+        Arc::new(ExternalCanisterLogRepository::default());
+}
+
+/// A repository that stores the retained log records of external canisters in stable memory.
+///
+/// The records are ordered by canister and then by index, so that the records of each canister
+/// can be read in the order they were emitted.
+#[derive(Default, Debug)]
+pub struct ExternalCanisterLogRepository {}
+
+impl StableDb<ExternalCanisterLogRecordKey, ExternalCanisterLogRecord, VirtualMemory<Memory>>
+    for ExternalCanisterLogRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<
+                ExternalCanisterLogRecordKey,
+                ExternalCanisterLogRecord,
+                VirtualMemory<Memory>,
+            >,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<ExternalCanisterLogRecordKey, ExternalCanisterLogRecord, VirtualMemory<Memory>>
+    for ExternalCanisterLogRepository
+{
+}
+
+impl ExternalCanisterLogRepository {
+    fn canister_range(
+        canister_id: Principal,
+    ) -> std::ops::RangeInclusive<ExternalCanisterLogRecordKey> {
+        ExternalCanisterLogRecordKey {
+            canister_id,
+            idx: u64::MIN,
+        }..=ExternalCanisterLogRecordKey {
+            canister_id,
+            idx: u64::MAX,
+        }
+    }
+
+    /// Returns the retained log records of the canister, oldest first.
+    pub fn find_by_canister_id(&self, canister_id: Principal) -> Vec<ExternalCanisterLogRecord> {
+        DB.with(|m| {
+            m.borrow()
+                .range(Self::canister_range(canister_id))
+                .map(|(_, record)| record)
+                .collect()
+        })
+    }
+
+    /// Retains the given log records of the canister, dropping the oldest records of the canister
+    /// once more than `max_records` are retained.
+    pub fn retain_records(
+        &self,
+        canister_id: Principal,
+        records: Vec<ExternalCanisterLogRecord>,
+        max_records: usize,
+    ) {
+        for record in records {
+            self.insert(record.key(), record);
+        }
+
+        let keys: Vec<ExternalCanisterLogRecordKey> = DB.with(|m| {
+            m.borrow()
+                .range(Self::canister_range(canister_id))
+                .map(|(key, _)| key)
+                .collect()
+        });
+
+        for key in keys.iter().take(keys.len().saturating_sub(max_records)) {
+            self.remove(key);
+        }
+    }
+
+    /// Removes all the retained log records of the canister.
+    pub fn remove_by_canister_id(&self, canister_id: Principal) {
+        let keys: Vec<ExternalCanisterLogRecordKey> = DB.with(|m| {
+            m.borrow()
+                .range(Self::canister_range(canister_id))
+                .map(|(key, _)| key)
+                .collect()
+        });
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_record(canister_id: Principal, idx: u64) -> ExternalCanisterLogRecord {
+        ExternalCanisterLogRecord {
+            canister_id,
+            idx,
+            timestamp: idx,
+            content: format!("record {}", idx).into_bytes(),
+        }
+    }
+
+    #[test]
+    fn retains_the_most_recent_records_of_each_canister() {
+        let canister_id = Principal::from_slice(&[1; 29]);
+        let other_canister_id = Principal::from_slice(&[2; 29]);
+
+        EXTERNAL_CANISTER_LOG_REPOSITORY.retain_records(
+            other_canister_id,
+            vec![mock_record(other_canister_id, 0)],
+            2,
+        );
+        EXTERNAL_CANISTER_LOG_REPOSITORY.retain_records(
+            canister_id,
+            vec![mock_record(canister_id, 2), mock_record(canister_id, 0)],
+            2,
+        );
+        EXTERNAL_CANISTER_LOG_REPOSITORY.retain_records(
+            canister_id,
+            vec![mock_record(canister_id, 2), mock_record(canister_id, 3)],
+            2,
+        );
+
+        let indexes: Vec<u64> = EXTERNAL_CANISTER_LOG_REPOSITORY
+            .find_by_canister_id(canister_id)
+            .iter()
+            .map(|record| record.idx)
+            .collect();
+
+        assert_eq!(indexes, vec![2, 3]);
+
+        EXTERNAL_CANISTER_LOG_REPOSITORY.remove_by_canister_id(canister_id);
+
+        assert!(EXTERNAL_CANISTER_LOG_REPOSITORY
+            .find_by_canister_id(canister_id)
+            .is_empty());
+        assert_eq!(
+            EXTERNAL_CANISTER_LOG_REPOSITORY
+                .find_by_canister_id(other_canister_id)
+                .len(),
+            1
+        );
+    }
+}
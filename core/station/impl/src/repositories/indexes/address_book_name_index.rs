@@ -0,0 +1,89 @@
+use crate::{
+    core::{with_memory_manager, Memory, ADDRESS_BOOK_NAME_INDEX_MEMORY_ID},
+    models::{
+        indexes::address_book_name_index::{AddressBookNameIndex, AddressBookNameIndexCriteria},
+        AddressBookEntryId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::repository::IndexRepository;
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<AddressBookNameIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ADDRESS_BOOK_NAME_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+/// A repository that enables finding address book entries by the prefix of the name of their owner.
+#[derive(Default, Debug)]
+pub struct AddressBookNameIndexRepository {}
+
+impl AddressBookNameIndexRepository {
+    /// Clears the repository by removing all the entries.
+    pub fn clear(&self) {
+        DB.with(|m| m.borrow_mut().clear_new());
+    }
+}
+
+impl IndexRepository<AddressBookNameIndex, AddressBookEntryId> for AddressBookNameIndexRepository {
+    type FindByCriteria = AddressBookNameIndexCriteria;
+
+    fn exists(&self, index: &AddressBookNameIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: AddressBookNameIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &AddressBookNameIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<AddressBookEntryId> {
+        let prefix = criteria.prefix.to_lowercase();
+
+        DB.with(|db| {
+            let start_key = AddressBookNameIndex {
+                name: prefix.clone(),
+                entry_id: [u8::MIN; 16],
+            };
+
+            db.borrow()
+                .range(start_key..)
+                .take_while(|(index, _)| index.name.starts_with(&prefix))
+                .map(|(index, _)| index.entry_id)
+                .collect::<HashSet<AddressBookEntryId>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_by_name_prefix() {
+        let repository = AddressBookNameIndexRepository::default();
+        for (i, name) in ["acme corp", "acme labs", "zeta"].iter().enumerate() {
+            repository.insert(AddressBookNameIndex {
+                name: name.to_string(),
+                entry_id: [i as u8; 16],
+            });
+        }
+
+        let result = repository.find_by_criteria(AddressBookNameIndexCriteria {
+            prefix: "ACME".to_string(),
+        });
+
+        assert_eq!(result, HashSet::from([[0; 16], [1; 16]]));
+        assert!(repository
+            .find_by_criteria(AddressBookNameIndexCriteria {
+                prefix: "b".to_string(),
+            })
+            .is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod address_book_name_index;
 pub mod notification_user_index;
 pub mod request_index;
 pub mod request_policy_resource_index;
@@ -9,7 +9,10 @@ use crate::{
     },
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
-use orbit_essentials::repository::{Repository, StableDb};
+use orbit_essentials::{
+    repository::{Repository, StableDb},
+    types::Timestamp,
+};
 use std::{cell::RefCell, collections::HashMap};
 
 thread_local! {
@@ -84,6 +87,34 @@ impl RequestIndexRepository {
         )
     }
 
+    /// Returns the ids of up to `take_limit` requests in the given status that were last modified at or before
+    /// the given timestamp and that are not compacted yet, the scan stops as soon as the limit is reached.
+    pub fn find_uncompacted_by_status(
+        &self,
+        status: RequestStatusCode,
+        last_modified_until: Timestamp,
+        take_limit: usize,
+    ) -> Vec<RequestId> {
+        DB.with(|m| {
+            m.borrow()
+                .range(
+                    RequestIndexKey {
+                        kind: RequestIndexKeyKind::Status(status.clone()),
+                        request_id: MIN_UUID,
+                    }..=RequestIndexKey {
+                        kind: RequestIndexKeyKind::Status(status),
+                        request_id: MAX_UUID,
+                    },
+                )
+                .filter(|(_, fields)| {
+                    !fields.compacted && fields.last_modified_at <= last_modified_until
+                })
+                .take(take_limit)
+                .map(|(key, _)| key.request_id)
+                .collect()
+        })
+    }
+
     /// Returns all the entries that are between the given keys.
     fn find_by_criteria(
         &self,
@@ -1,8 +1,6 @@
 use crate::{
-    core::{
-        ic_cdk::api::print, ic_cdk::next_time, with_memory_manager, Memory,
-        TRANSFER_ACCOUNT_INDEX_MEMORY_ID,
-    },
+    core::{ic_cdk::next_time, with_memory_manager, Memory, TRANSFER_ACCOUNT_INDEX_MEMORY_ID},
+    log_warn,
     models::{
         indexes::transfer_account_index::{TransferAccountIndex, TransferAccountIndexCriteria},
         TransferId,
@@ -63,7 +61,7 @@ impl IndexRepository<TransferAccountIndex, TransferId> for TransferAccountIndexR
             };
 
             if from_dt > to_dt {
-                print(format!("Invalid TransferAccountIndexRepository::FindByCriteria: from_dt {} is greater than to_dt {}", from_dt, to_dt));
+                log_warn!("Invalid TransferAccountIndexRepository::FindByCriteria: from_dt {} is greater than to_dt {}", from_dt, to_dt);
                 return HashSet::new();
             }
 
@@ -15,12 +15,30 @@ pub use account::*;
 pub mod external_canister;
 pub use external_canister::*;
 
+pub mod external_canister_log;
+pub use external_canister_log::*;
+
+pub mod station_log;
+pub use station_log::*;
+
 pub mod transfer;
 pub use transfer::*;
 
+pub mod transfer_annotation;
+pub use transfer_annotation::*;
+
+pub mod principal_label;
+pub use principal_label::*;
+
+pub mod account_balance_snapshot;
+pub use account_balance_snapshot::*;
+
 pub mod notification;
 pub use notification::*;
 
+pub mod notification_bridge;
+pub use notification_bridge::*;
+
 pub mod request;
 pub use request::*;
 
@@ -30,6 +48,18 @@ pub use request_policy::*;
 pub mod request_evaluation_result;
 pub use request_evaluation_result::*;
 
+pub mod archived_request;
+pub use archived_request::*;
+
+pub mod vote_receipt;
+pub use vote_receipt::*;
+
+pub mod blob;
+pub use blob::*;
+
+pub mod station_event;
+pub use station_event::*;
+
 pub mod permission;
 
 pub mod indexes;
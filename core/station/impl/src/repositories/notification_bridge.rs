@@ -0,0 +1,157 @@
+use crate::{
+    core::{
+        with_memory_manager, Memory, NOTIFICATION_BRIDGE_MEMORY_ID, NOTIFICATION_OUTBOX_MEMORY_ID,
+    },
+    models::{NotificationBridge, NotificationOutboxEntry, NotificationOutboxEntryKey, UserId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static BRIDGES_DB: RefCell<StableBTreeMap<UserId, NotificationBridge, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(NOTIFICATION_BRIDGE_MEMORY_ID))
+    )
+  });
+
+  static OUTBOX_DB: RefCell<StableBTreeMap<NotificationOutboxEntryKey, NotificationOutboxEntry, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(NOTIFICATION_OUTBOX_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref NOTIFICATION_BRIDGE_REPOSITORY: Arc<NotificationBridgeRepository> =
+        Arc::new(NotificationBridgeRepository::default());
+    pub static ref NOTIFICATION_OUTBOX_REPOSITORY: Arc<NotificationOutboxRepository> =
+        Arc::new(NotificationOutboxRepository::default());
+}
+
+/// A repository that stores the users that opted in to have their notifications pushed to an inbox canister.
+#[derive(Default, Debug)]
+pub struct NotificationBridgeRepository {}
+
+impl StableDb<UserId, NotificationBridge, VirtualMemory<Memory>> for NotificationBridgeRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<UserId, NotificationBridge, VirtualMemory<Memory>>) -> R,
+    {
+        BRIDGES_DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<UserId, NotificationBridge, VirtualMemory<Memory>>
+    for NotificationBridgeRepository
+{
+}
+
+/// A repository that stores the notifications waiting to be delivered to the inbox canisters in stable memory.
+///
+/// The entries are ordered by user and then by the time they were enqueued, so that the notifications
+/// of each user are delivered in the order they were sent.
+#[derive(Default, Debug)]
+pub struct NotificationOutboxRepository {}
+
+impl StableDb<NotificationOutboxEntryKey, NotificationOutboxEntry, VirtualMemory<Memory>>
+    for NotificationOutboxRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<
+                NotificationOutboxEntryKey,
+                NotificationOutboxEntry,
+                VirtualMemory<Memory>,
+            >,
+        ) -> R,
+    {
+        OUTBOX_DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<NotificationOutboxEntryKey, NotificationOutboxEntry, VirtualMemory<Memory>>
+    for NotificationOutboxRepository
+{
+}
+
+impl NotificationOutboxRepository {
+    fn user_range(user_id: UserId) -> std::ops::RangeInclusive<NotificationOutboxEntryKey> {
+        NotificationOutboxEntryKey {
+            user_id,
+            enqueued_at: u64::MIN,
+        }..=NotificationOutboxEntryKey {
+            user_id,
+            enqueued_at: u64::MAX,
+        }
+    }
+
+    /// Returns up to `limit` of the oldest pending notifications of the user.
+    pub fn find_by_user(&self, user_id: UserId, limit: usize) -> Vec<NotificationOutboxEntry> {
+        OUTBOX_DB.with(|m| {
+            m.borrow()
+                .range(Self::user_range(user_id))
+                .take(limit)
+                .map(|(_, entry)| entry)
+                .collect()
+        })
+    }
+
+    pub fn count_by_user(&self, user_id: UserId) -> usize {
+        OUTBOX_DB.with(|m| m.borrow().range(Self::user_range(user_id)).count())
+    }
+
+    /// Removes all the pending notifications of the user.
+    pub fn remove_by_user(&self, user_id: UserId) {
+        let keys: Vec<NotificationOutboxEntryKey> = OUTBOX_DB.with(|m| {
+            m.borrow()
+                .range(Self::user_range(user_id))
+                .map(|(key, _)| key)
+                .collect()
+        });
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use orbit_essentials::model::ModelKey;
+
+    fn mock_entry(user_id: UserId, enqueued_at: u64) -> NotificationOutboxEntry {
+        NotificationOutboxEntry {
+            user_id,
+            notification_id: [0; 16],
+            enqueued_at,
+            attempts: 0,
+            next_attempt_at: enqueued_at,
+        }
+    }
+
+    #[test]
+    fn outbox_entries_are_grouped_by_user_in_enqueue_order() {
+        for (user_id, enqueued_at) in [([1; 16], 3), ([2; 16], 1), ([1; 16], 2), ([1; 16], 5)] {
+            let entry = mock_entry(user_id, enqueued_at);
+            NOTIFICATION_OUTBOX_REPOSITORY.insert(entry.key(), entry);
+        }
+
+        let enqueued_at: Vec<u64> = NOTIFICATION_OUTBOX_REPOSITORY
+            .find_by_user([1; 16], 2)
+            .iter()
+            .map(|entry| entry.enqueued_at)
+            .collect();
+
+        assert_eq!(enqueued_at, vec![2, 3]);
+        assert_eq!(NOTIFICATION_OUTBOX_REPOSITORY.count_by_user([1; 16]), 3);
+
+        NOTIFICATION_OUTBOX_REPOSITORY.remove_by_user([1; 16]);
+
+        assert_eq!(NOTIFICATION_OUTBOX_REPOSITORY.count_by_user([1; 16]), 0);
+        assert_eq!(NOTIFICATION_OUTBOX_REPOSITORY.count_by_user([2; 16]), 1);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    core::{cache::Cache, ic_cdk::api::print, with_memory_manager, Memory, PERMISSION_MEMORY_ID},
+    core::{cache::Cache, with_memory_manager, Memory, PERMISSION_MEMORY_ID},
+    log_warn,
     models::{
         permission::{Allow, Permission, PermissionKey},
         resource::{
@@ -138,11 +139,11 @@ impl PermissionRepository {
     /// up-to-date with the repository and that we have enough instructions to rebuild the cache.
     pub fn build_cache(&self) {
         if self.len() > Self::MAX_CACHE_SIZE {
-            print(format!(
+            log_warn!(
                 "Only the first {} permissions will be added to the cache, the reposity has {} permissions.",
                 Self::MAX_CACHE_SIZE,
                 PERMISSION_REPOSITORY.len(),
-            ));
+            );
         }
 
         CACHE.with(|cache| {
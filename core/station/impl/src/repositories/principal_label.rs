@@ -0,0 +1,76 @@
+use crate::{
+    core::{with_memory_manager, Memory, PRINCIPAL_LABEL_MEMORY_ID},
+    models::{PrincipalLabel, PrincipalLabelKey},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<PrincipalLabelKey, PrincipalLabel, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(PRINCIPAL_LABEL_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref PRINCIPAL_LABEL_REPOSITORY: Arc<PrincipalLabelRepository> =
+        Arc::new(PrincipalLabelRepository::default());
+}
+
+/// A repository that stores the labels of the well-known principals and addresses in stable memory,
+/// by normalized address.
+#[derive(Default, Debug)]
+pub struct PrincipalLabelRepository {}
+
+impl StableDb<PrincipalLabelKey, PrincipalLabel, VirtualMemory<Memory>>
+    for PrincipalLabelRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<PrincipalLabelKey, PrincipalLabel, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<PrincipalLabelKey, PrincipalLabel, VirtualMemory<Memory>>
+    for PrincipalLabelRepository
+{
+}
+
+impl PrincipalLabelRepository {
+    /// Returns the label of the address, if it is labeled.
+    pub fn find_label(&self, address: &str) -> Option<String> {
+        self.get(&PrincipalLabel::key(address))
+            .map(|label| label.label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::principal_label_test_utils::mock_principal_label;
+
+    #[test]
+    fn test_crud() {
+        let repository = PrincipalLabelRepository::default();
+        let label = mock_principal_label();
+
+        assert!(repository.get(&label.to_key()).is_none());
+
+        repository.insert(label.to_key(), label.clone());
+
+        assert_eq!(repository.get(&label.to_key()), Some(label.clone()));
+        assert_eq!(
+            repository.find_label(&format!(" {} ", label.address)),
+            Some(label.label.clone())
+        );
+        assert!(repository.remove(&label.to_key()).is_some());
+        assert!(repository.get(&label.to_key()).is_none());
+    }
+}
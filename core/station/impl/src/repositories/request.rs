@@ -16,8 +16,9 @@ use crate::{
         },
         resource::Resource,
         ListRequestsOperationType, Request, RequestId, RequestKey, RequestStatus,
-        RequestStatusCode,
+        RequestStatusCode, RequestViewer,
     },
+    services::station_events_observe_insert_request,
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
 use lazy_static::lazy_static;
@@ -58,6 +59,7 @@ impl Default for RequestRepository {
         let mut change_observer = Observer::default();
         metrics_observe_insert_request(&mut change_observer);
         jobs_observe_insert_request(&mut change_observer);
+        station_events_observe_insert_request(&mut change_observer);
 
         let mut remove_observer = Observer::default();
         metrics_observe_remove_request(&mut remove_observer);
@@ -214,6 +216,21 @@ impl RequestRepository {
             .collect::<Vec<Request>>()
     }
 
+    /// Find up to `limit` requests that have the provided status, were last modified at or before the cutoff
+    /// and are not compacted yet.
+    pub fn find_compactable(
+        &self,
+        status: RequestStatusCode,
+        cutoff: Timestamp,
+        limit: usize,
+    ) -> Vec<Request> {
+        self.index
+            .find_uncompacted_by_status(status, cutoff, limit)
+            .into_iter()
+            .filter_map(|request_id| self.get(&RequestKey { id: request_id }))
+            .collect()
+    }
+
     /// Find requests that are scheduled between the provided timestamps.
     pub fn find_scheduled(
         &self,
@@ -260,6 +277,12 @@ impl RequestRepository {
             })
     }
 
+    /// Checks if the request is visible to the viewer, requests that do not exist are considered visible.
+    pub fn is_visible_to(&self, request_id: &RequestId, viewer: &RequestViewer) -> bool {
+        self.find_indexed_fields_by_request_id(request_id)
+            .map_or(true, |fields| fields.is_visible_to(viewer))
+    }
+
     /// Find request ids based on the provided condition.
     ///
     /// The request ids are sorted based on the provided sort strategy.
@@ -342,6 +365,12 @@ impl RequestRepository {
                     return false;
                 }
 
+                if let Some(viewer) = &condition.viewer {
+                    if !fields.is_visible_to(viewer) {
+                        return false;
+                    }
+                }
+
                 INDEXED_FIELDS_CACHE.with(|cache| {
                     cache.borrow_mut().insert(*id, fields.clone());
                 });
@@ -424,6 +453,8 @@ pub struct RequestWhereClause {
     pub requesters: Vec<UUID>,
     pub not_requesters: Vec<UUID>,
     pub excluded_ids: Vec<UUID>,
+    /// Restricts the result to the requests that are visible to the viewer, if set.
+    pub viewer: Option<RequestViewer>,
 }
 
 #[cfg(test)]
@@ -523,6 +554,7 @@ mod tests {
             requesters: vec![],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -572,6 +604,7 @@ mod tests {
             requesters: vec![],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -624,6 +657,7 @@ mod tests {
             statuses: vec![RequestStatusCode::Created],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -644,6 +678,7 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -664,6 +699,7 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved, RequestStatusCode::Created],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -684,6 +720,7 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -709,6 +746,7 @@ mod tests {
             requesters: vec![],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -762,6 +800,7 @@ mod tests {
             user_group_id: None,
             input: AddUserGroupOperationInput {
                 name: "foo".to_string(),
+                quotas: Default::default(),
             },
         });
         REQUEST_REPOSITORY.insert(add_group_request.to_key(), add_group_request.clone());
@@ -773,6 +812,7 @@ mod tests {
             input: EditUserGroupOperationInput {
                 user_group_id: *Uuid::new_v4().as_bytes(),
                 name: "bar".to_string(),
+                quotas: None,
             },
         });
         REQUEST_REPOSITORY.insert(edit_group_request.to_key(), edit_group_request.clone());
@@ -789,6 +829,7 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved],
             not_requesters: vec![],
             excluded_ids: vec![],
+            viewer: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -798,6 +839,76 @@ mod tests {
         assert_eq!(requests.len(), 1);
         assert_eq!(requests[0], add_group_request.id);
     }
+
+    #[test]
+    fn confidential_requests_are_only_found_by_participants() {
+        let mut confidential_request = mock_request();
+        confidential_request.requested_by = [2; 16];
+        confidential_request.approvals = vec![];
+        confidential_request.confidential = true;
+        REQUEST_REPOSITORY.insert(
+            confidential_request.to_key(),
+            confidential_request.to_owned(),
+        );
+
+        let mut public_request = mock_request();
+        public_request.requested_by = [2; 16];
+        REQUEST_REPOSITORY.insert(public_request.to_key(), public_request.to_owned());
+
+        let find_visible_to = |viewer: RequestViewer| {
+            REQUEST_REPOSITORY
+                .find_ids_where(
+                    RequestWhereClause {
+                        created_dt_from: None,
+                        created_dt_to: None,
+                        expiration_dt_from: None,
+                        expiration_dt_to: None,
+                        operation_types: vec![],
+                        requesters: vec![],
+                        approvers: vec![],
+                        not_approvers: vec![],
+                        statuses: vec![],
+                        not_requesters: vec![],
+                        excluded_ids: vec![],
+                        viewer: Some(viewer),
+                    },
+                    None,
+                )
+                .unwrap()
+        };
+
+        let requests = find_visible_to(RequestViewer {
+            user_id: Some([3; 16]),
+            is_admin: false,
+        });
+        assert_eq!(requests, vec![public_request.id]);
+
+        let requests = find_visible_to(RequestViewer {
+            user_id: None,
+            is_admin: false,
+        });
+        assert_eq!(requests, vec![public_request.id]);
+
+        let requests = find_visible_to(RequestViewer {
+            user_id: Some([2; 16]),
+            is_admin: false,
+        });
+        assert_eq!(requests.len(), 2);
+
+        let requests = find_visible_to(RequestViewer {
+            user_id: Some([3; 16]),
+            is_admin: true,
+        });
+        assert_eq!(requests.len(), 2);
+
+        assert!(!REQUEST_REPOSITORY.is_visible_to(
+            &confidential_request.id,
+            &RequestViewer {
+                user_id: Some([3; 16]),
+                is_admin: false,
+            }
+        ));
+    }
 }
 
 #[cfg(feature = "canbench")]
@@ -890,6 +1001,7 @@ mod benchs {
                     statuses: vec![RequestStatusCode::Created],
                     excluded_ids: vec![],
                     not_requesters: vec![],
+                    viewer: None,
                 },
                 None,
             );
@@ -1,7 +1,8 @@
 use super::indexes::request_policy_resource_index::RequestPolicyResourceIndexRepository;
 use crate::{
     core::{
-        metrics::REQUEST_POLICY_METRICS, with_memory_manager, Memory, REQUEST_POLICIES_MEMORY_ID,
+        cache::Cache, metrics::REQUEST_POLICY_METRICS, with_memory_manager, Memory,
+        REQUEST_POLICIES_MEMORY_ID,
     },
     models::{
         indexes::request_policy_resource_index::RequestPolicyResourceIndexCriteria,
@@ -13,7 +14,7 @@ use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
 use lazy_static::lazy_static;
 use orbit_essentials::repository::{IndexRepository, IndexedRepository, Repository, StableDb};
 use orbit_essentials::types::UUID;
-use std::{cell::RefCell, sync::Arc};
+use std::{cell::RefCell, collections::HashSet, sync::Arc};
 
 thread_local! {
   /// The memory reference to the request policies repository.
@@ -21,7 +22,10 @@ thread_local! {
     RefCell::new(
       StableBTreeMap::init(memory_manager.get(REQUEST_POLICIES_MEMORY_ID))
     )
-  })
+  });
+
+  /// The in-heap cache of the policy ids indexed by resource, used by the access control evaluation.
+  static RESOURCE_CACHE: RefCell<Cache<Resource, HashSet<UUID>>> = RefCell::new(Cache::new(RequestPolicyRepository::MAX_RESOURCE_CACHE_SIZE));
 }
 
 lazy_static! {
@@ -47,18 +51,24 @@ impl StableDb<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRepos
 impl IndexedRepository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRepository {
     fn remove_entry_indexes(&self, entry: &RequestPolicy) {
         entry.to_index_for_resource().iter().for_each(|index| {
+            RESOURCE_CACHE.with(|cache| cache.borrow_mut().remove(&index.resource));
+
             self.resource_index.remove(index);
         });
     }
 
     fn add_entry_indexes(&self, entry: &RequestPolicy) {
         entry.to_index_for_resource().into_iter().for_each(|index| {
+            RESOURCE_CACHE.with(|cache| cache.borrow_mut().remove(&index.resource));
+
             self.resource_index.insert(index);
         });
     }
 
     /// Clears the indexes of the repository.
     fn clear_indexes(&self) {
+        RESOURCE_CACHE.with(|cache| cache.borrow_mut().clear());
+
         self.resource_index.clear();
     }
 }
@@ -102,10 +112,30 @@ impl Repository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRep
 }
 
 impl RequestPolicyRepository {
+    /// The maximum number of resources that have their policy ids cached in the heap.
+    ///
+    /// Each entry is small (a resource and a few policy ids), so the cache takes at most a few MiB.
+    pub const MAX_RESOURCE_CACHE_SIZE: usize = 10_000;
+
+    /// Finds the policies that apply to the given resource.
+    ///
+    /// The policy ids of the resource are served from the heap cache when available, the cache entry
+    /// of a resource is invalidated whenever the resource index of that resource changes.
     pub fn find_by_resource(&self, resource: Resource) -> Vec<RequestPolicy> {
-        let ids = self
-            .resource_index
-            .find_by_criteria(RequestPolicyResourceIndexCriteria { resource });
+        let ids = match RESOURCE_CACHE.with(|cache| cache.borrow().get(&resource).cloned()) {
+            Some(ids) => ids,
+            None => {
+                let ids =
+                    self.resource_index
+                        .find_by_criteria(RequestPolicyResourceIndexCriteria {
+                            resource: resource.clone(),
+                        });
+
+                RESOURCE_CACHE.with(|cache| cache.borrow_mut().insert(resource, ids.clone()));
+
+                ids
+            }
+        };
 
         ids.iter().filter_map(|id| self.get(id)).collect()
     }
@@ -170,6 +200,9 @@ mod tests {
             specifier: RequestSpecifier::Transfer(ResourceIds::Ids(vec![
                 [10; 16], [11; 16], [12; 16],
             ])),
+            version: 0,
+            checklist: Vec::new(),
+            exclude_requester: false,
         };
 
         repository.insert(other_policy.id, other_policy.clone());
@@ -248,6 +281,49 @@ mod tests {
                 resource: Resource::Account(AccountResourceAction::Create),
             }));
     }
+
+    #[test]
+    fn find_by_resource_cache_is_invalidated_on_policy_mutation() {
+        let repository = RequestPolicyRepository::default();
+        let transfer_resource =
+            Resource::Account(AccountResourceAction::Transfer(ResourceId::Id([10; 16])));
+
+        assert!(repository
+            .find_by_resource(transfer_resource.clone())
+            .is_empty());
+
+        let mut policy = RequestPolicy {
+            rule: RequestPolicyRule::AutoApproved,
+            id: [1; 16],
+            specifier: RequestSpecifier::Transfer(ResourceIds::Ids(vec![[10; 16]])),
+            version: 0,
+            checklist: Vec::new(),
+            exclude_requester: false,
+        };
+
+        repository.insert(policy.id, policy.clone());
+
+        assert_eq!(
+            repository.find_by_resource(transfer_resource.clone()),
+            vec![policy.clone()]
+        );
+
+        policy.specifier = RequestSpecifier::Transfer(ResourceIds::Ids(vec![[11; 16]]));
+
+        repository.insert(policy.id, policy.clone());
+
+        assert!(repository
+            .find_by_resource(transfer_resource.clone())
+            .is_empty());
+
+        repository.remove(&policy.id);
+
+        assert!(repository
+            .find_by_resource(Resource::Account(AccountResourceAction::Transfer(
+                ResourceId::Id([11; 16])
+            )))
+            .is_empty());
+    }
 }
 
 #[cfg(feature = "canbench")]
@@ -277,6 +353,9 @@ mod benchs {
                     specifier: RequestSpecifier::ChangeExternalCanister(
                         ExternalCanisterId::Canister(canister_id),
                     ),
+                    version: 0,
+                    checklist: Vec::new(),
+                    exclude_requester: false,
                 });
             }
 
@@ -295,6 +374,9 @@ mod benchs {
                             ),
                         },
                     ),
+                    version: 0,
+                    checklist: Vec::new(),
+                    exclude_requester: false,
                 });
             }
 
@@ -318,6 +400,9 @@ mod benchs {
                             ),
                         },
                     ),
+                    version: 0,
+                    checklist: Vec::new(),
+                    exclude_requester: false,
                 });
             }
 
@@ -336,4 +421,26 @@ mod benchs {
             }
         })
     }
+
+    #[bench(raw)]
+    fn batch_insert_1k_policies_with_resource_index() -> BenchResult {
+        let policies = (0..1_000)
+            .map(|i| RequestPolicy {
+                id: *Uuid::new_v4().as_bytes(),
+                rule: RequestPolicyRule::AutoApproved,
+                specifier: RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Canister(
+                    Principal::from_slice(&[(i % 256) as u8; 29]),
+                )),
+                version: 0,
+                checklist: Vec::new(),
+                exclude_requester: false,
+            })
+            .collect::<Vec<_>>();
+
+        canbench_rs::bench_fn(|| {
+            for policy in policies {
+                REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+            }
+        })
+    }
 }
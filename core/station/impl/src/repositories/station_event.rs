@@ -0,0 +1,196 @@
+use crate::{
+    core::{with_memory_manager, Memory, EVENT_OUTBOX_MEMORY_ID, EVENT_SUBSCRIBER_MEMORY_ID},
+    models::{EventOutboxEntry, EventOutboxEntryKey, EventSubscriber, EventSubscriberId},
+};
+use candid::Principal;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static SUBSCRIBERS_DB: RefCell<StableBTreeMap<EventSubscriberId, EventSubscriber, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(EVENT_SUBSCRIBER_MEMORY_ID))
+    )
+  });
+
+  static OUTBOX_DB: RefCell<StableBTreeMap<EventOutboxEntryKey, EventOutboxEntry, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(EVENT_OUTBOX_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref EVENT_SUBSCRIBER_REPOSITORY: Arc<EventSubscriberRepository> =
+        Arc::new(EventSubscriberRepository::default());
+    pub static ref EVENT_OUTBOX_REPOSITORY: Arc<EventOutboxRepository> =
+        Arc::new(EventOutboxRepository::default());
+}
+
+/// A repository that stores the canisters subscribed to station events in stable memory.
+#[derive(Default, Debug)]
+pub struct EventSubscriberRepository {}
+
+impl StableDb<EventSubscriberId, EventSubscriber, VirtualMemory<Memory>>
+    for EventSubscriberRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<EventSubscriberId, EventSubscriber, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        SUBSCRIBERS_DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<EventSubscriberId, EventSubscriber, VirtualMemory<Memory>>
+    for EventSubscriberRepository
+{
+}
+
+impl EventSubscriberRepository {
+    /// Returns the subscription of the given canister, if any.
+    ///
+    /// The number of subscribers is bounded, so a full scan is cheap.
+    pub fn find_by_canister_id(&self, canister_id: &Principal) -> Option<EventSubscriber> {
+        self.list()
+            .into_iter()
+            .find(|subscriber| subscriber.canister_id == *canister_id)
+    }
+}
+
+/// A repository that stores the events waiting to be acknowledged by the subscribers in stable memory.
+///
+/// The entries are ordered by subscriber and then by event id, so that the events of each subscriber
+/// can be delivered in the order they were emitted.
+#[derive(Default, Debug)]
+pub struct EventOutboxRepository {}
+
+impl StableDb<EventOutboxEntryKey, EventOutboxEntry, VirtualMemory<Memory>>
+    for EventOutboxRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<EventOutboxEntryKey, EventOutboxEntry, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        OUTBOX_DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<EventOutboxEntryKey, EventOutboxEntry, VirtualMemory<Memory>>
+    for EventOutboxRepository
+{
+}
+
+impl EventOutboxRepository {
+    fn subscriber_range(
+        subscriber_id: EventSubscriberId,
+    ) -> std::ops::RangeInclusive<EventOutboxEntryKey> {
+        EventOutboxEntryKey {
+            subscriber_id,
+            event_id: u64::MIN,
+        }..=EventOutboxEntryKey {
+            subscriber_id,
+            event_id: u64::MAX,
+        }
+    }
+
+    /// Returns up to `limit` of the oldest pending events of the subscriber.
+    pub fn find_by_subscriber(
+        &self,
+        subscriber_id: EventSubscriberId,
+        limit: usize,
+    ) -> Vec<EventOutboxEntry> {
+        OUTBOX_DB.with(|m| {
+            m.borrow()
+                .range(Self::subscriber_range(subscriber_id))
+                .take(limit)
+                .map(|(_, entry)| entry)
+                .collect()
+        })
+    }
+
+    pub fn count_by_subscriber(&self, subscriber_id: EventSubscriberId) -> usize {
+        OUTBOX_DB.with(|m| {
+            m.borrow()
+                .range(Self::subscriber_range(subscriber_id))
+                .count()
+        })
+    }
+
+    /// Removes all the pending events of the subscriber.
+    pub fn remove_by_subscriber(&self, subscriber_id: EventSubscriberId) {
+        let keys: Vec<EventOutboxEntryKey> = OUTBOX_DB.with(|m| {
+            m.borrow()
+                .range(Self::subscriber_range(subscriber_id))
+                .map(|(key, _)| key)
+                .collect()
+        });
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{station_event_test_utils::mock_event_subscriber, StationEvent};
+    use orbit_essentials::model::ModelKey;
+
+    fn mock_entry(subscriber_id: EventSubscriberId, event_id: u64) -> EventOutboxEntry {
+        EventOutboxEntry {
+            event_id,
+            subscriber_id,
+            event: StationEvent::RequestExecuted {
+                request_id: [1; 16],
+                completed_at: event_id,
+            },
+            attempts: 0,
+            next_attempt_at: 0,
+            created_at: event_id,
+        }
+    }
+
+    #[test]
+    fn find_subscriber_by_canister_id() {
+        let subscriber = mock_event_subscriber();
+        EVENT_SUBSCRIBER_REPOSITORY.insert(subscriber.id, subscriber.clone());
+
+        assert_eq!(
+            EVENT_SUBSCRIBER_REPOSITORY.find_by_canister_id(&subscriber.canister_id),
+            Some(subscriber)
+        );
+        assert!(EVENT_SUBSCRIBER_REPOSITORY
+            .find_by_canister_id(&Principal::management_canister())
+            .is_none());
+    }
+
+    #[test]
+    fn outbox_entries_are_grouped_by_subscriber_in_event_order() {
+        for (subscriber_id, event_id) in [([1; 16], 3), ([2; 16], 1), ([1; 16], 2), ([1; 16], 5)] {
+            let entry = mock_entry(subscriber_id, event_id);
+            EVENT_OUTBOX_REPOSITORY.insert(entry.key(), entry);
+        }
+
+        let event_ids: Vec<u64> = EVENT_OUTBOX_REPOSITORY
+            .find_by_subscriber([1; 16], 2)
+            .iter()
+            .map(|entry| entry.event_id)
+            .collect();
+
+        assert_eq!(event_ids, vec![2, 3]);
+        assert_eq!(EVENT_OUTBOX_REPOSITORY.count_by_subscriber([1; 16]), 3);
+
+        EVENT_OUTBOX_REPOSITORY.remove_by_subscriber([1; 16]);
+
+        assert_eq!(EVENT_OUTBOX_REPOSITORY.count_by_subscriber([1; 16]), 0);
+        assert_eq!(EVENT_OUTBOX_REPOSITORY.count_by_subscriber([2; 16]), 1);
+    }
+}
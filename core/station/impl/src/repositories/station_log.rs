@@ -0,0 +1,173 @@
+use crate::{
+    core::{with_memory_manager, Memory, STATION_LOG_MEMORY_ID},
+    models::{LogLevel, StationLogEntry},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    repository::{Repository, StableDb},
+    types::{Timestamp, UUID},
+};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<u64, StationLogEntry, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(STATION_LOG_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref STATION_LOG_REPOSITORY: Arc<StationLogRepository> =
+        Arc::new(StationLogRepository::default());
+}
+
+/// A repository that keeps the most recent log entries of the station in stable memory.
+///
+/// The entries are ordered by their index, so the oldest entries are dropped first once the
+/// ring buffer is full.
+#[derive(Default, Debug)]
+pub struct StationLogRepository {}
+
+impl StableDb<u64, StationLogEntry, VirtualMemory<Memory>> for StationLogRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<u64, StationLogEntry, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<u64, StationLogEntry, VirtualMemory<Memory>> for StationLogRepository {}
+
+#[derive(Debug, Clone, Default)]
+pub struct StationLogWhereClause {
+    pub min_level: Option<LogLevel>,
+    pub module: Option<String>,
+    pub from_dt: Option<Timestamp>,
+    pub to_dt: Option<Timestamp>,
+    pub correlation_id: Option<UUID>,
+}
+
+impl StationLogRepository {
+    /// Appends an entry to the ring buffer, dropping the oldest entries once it's full.
+    pub fn append(
+        &self,
+        timestamp: Timestamp,
+        level: LogLevel,
+        module: String,
+        message: String,
+        correlation_id: Option<UUID>,
+    ) -> StationLogEntry {
+        DB.with(|m| {
+            let mut db = m.borrow_mut();
+            let idx = db.last_key_value().map_or(0, |(idx, _)| idx + 1);
+            let entry = StationLogEntry {
+                idx,
+                timestamp,
+                level,
+                module,
+                message,
+                correlation_id,
+            };
+
+            db.insert(idx, entry.clone());
+
+            while db.len() > StationLogEntry::MAX_RETAINED_ENTRIES {
+                match db.first_key_value() {
+                    Some((oldest, _)) => db.remove(&oldest),
+                    None => break,
+                };
+            }
+
+            entry
+        })
+    }
+
+    /// Returns the entries that match the where clause, newest first.
+    ///
+    /// The module filter matches the module path by prefix (e.g. `station::jobs`).
+    pub fn find_where(&self, where_clause: StationLogWhereClause) -> Vec<StationLogEntry> {
+        DB.with(|m| {
+            m.borrow()
+                .iter()
+                .rev()
+                .map(|(_, entry)| entry)
+                .filter(|entry| {
+                    where_clause
+                        .min_level
+                        .map_or(true, |min_level| entry.level >= min_level)
+                        && where_clause
+                            .module
+                            .as_ref()
+                            .map_or(true, |module| entry.module.starts_with(module))
+                        && where_clause
+                            .from_dt
+                            .map_or(true, |from_dt| entry.timestamp >= from_dt)
+                        && where_clause
+                            .to_dt
+                            .map_or(true, |to_dt| entry.timestamp <= to_dt)
+                        && where_clause
+                            .correlation_id
+                            .map_or(true, |id| entry.correlation_id == Some(id))
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_the_oldest_entries_once_full() {
+        for i in 0..StationLogEntry::MAX_RETAINED_ENTRIES + 2 {
+            let level = if i % 2 == 0 {
+                LogLevel::Info
+            } else {
+                LogLevel::Error
+            };
+
+            STATION_LOG_REPOSITORY.append(
+                i,
+                level,
+                "station::jobs".to_string(),
+                i.to_string(),
+                None,
+            );
+        }
+
+        assert_eq!(
+            STATION_LOG_REPOSITORY.len() as u64,
+            StationLogEntry::MAX_RETAINED_ENTRIES
+        );
+
+        let entries = STATION_LOG_REPOSITORY.find_where(StationLogWhereClause::default());
+        assert_eq!(
+            entries.first().unwrap().idx,
+            StationLogEntry::MAX_RETAINED_ENTRIES + 1
+        );
+        assert_eq!(entries.last().unwrap().idx, 2);
+
+        let errors = STATION_LOG_REPOSITORY.find_where(StationLogWhereClause {
+            min_level: Some(LogLevel::Warn),
+            module: Some("station::jobs".to_string()),
+            from_dt: Some(10),
+            to_dt: Some(20),
+            correlation_id: None,
+        });
+        assert_eq!(
+            errors.iter().map(|entry| entry.idx).collect::<Vec<_>>(),
+            vec![19, 17, 15, 13, 11]
+        );
+
+        assert!(STATION_LOG_REPOSITORY
+            .find_where(StationLogWhereClause {
+                module: Some("station::services".to_string()),
+                ..Default::default()
+            })
+            .is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    core::{with_memory_manager, Memory, TRANSFER_ANNOTATION_MEMORY_ID},
+    models::{TransferAnnotation, TransferId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<TransferId, TransferAnnotation, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_ANNOTATION_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref TRANSFER_ANNOTATION_REPOSITORY: Arc<TransferAnnotationRepository> =
+        Arc::new(TransferAnnotationRepository::default());
+}
+
+/// A repository that stores the reconciliation annotations of transfers in stable memory, by transfer id.
+#[derive(Default, Debug)]
+pub struct TransferAnnotationRepository {}
+
+impl StableDb<TransferId, TransferAnnotation, VirtualMemory<Memory>>
+    for TransferAnnotationRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<TransferId, TransferAnnotation, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<TransferId, TransferAnnotation, VirtualMemory<Memory>>
+    for TransferAnnotationRepository
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::transfer_test_utils;
+
+    #[test]
+    fn test_crud() {
+        let repository = TransferAnnotationRepository::default();
+        let annotation = transfer_test_utils::mock_transfer_annotation();
+
+        assert!(repository.get(&annotation.transfer_id).is_none());
+
+        repository.insert(annotation.transfer_id, annotation.clone());
+
+        assert_eq!(
+            repository.get(&annotation.transfer_id),
+            Some(annotation.clone())
+        );
+        assert!(repository.remove(&annotation.transfer_id).is_some());
+        assert!(repository.get(&annotation.transfer_id).is_none());
+    }
+}
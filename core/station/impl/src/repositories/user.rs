@@ -1,12 +1,12 @@
 use super::indexes::{
     unique_index::UniqueIndexRepository, user_status_group_index::UserStatusGroupIndexRepository,
 };
-use crate::core::ic_cdk::api::print;
 use crate::{
     core::{
         cache::Cache, metrics::USER_METRICS, observer::Observer, utils::format_unique_string,
         with_memory_manager, Memory, USER_MEMORY_ID,
     },
+    log_warn,
     models::{
         indexes::{
             unique_index::UniqueIndexKey, user_status_group_index::UserStatusGroupIndexCriteria,
@@ -30,6 +30,9 @@ thread_local! {
   });
 
   static CACHE: RefCell<Cache<UserId, User>> = RefCell::new(Cache::new(UserRepository::MAX_CACHE_SIZE));
+
+  /// The in-heap cache of the group memberships, used by the access control evaluation.
+  static GROUP_MEMBERS_CACHE: RefCell<Cache<(UserGroupId, UserStatus), Vec<UserId>>> = RefCell::new(Cache::new(UserRepository::MAX_GROUP_MEMBERS_CACHE_SIZE));
 }
 
 lazy_static! {
@@ -78,6 +81,8 @@ impl IndexedRepository<UserKey, User, VirtualMemory<Memory>> for UserRepository
         });
 
         entry.to_index_for_groups().iter().for_each(|index| {
+            invalidate_group_members_cache(index.group_id, &index.user_status);
+
             self.group_status_index.remove(index);
         });
     }
@@ -91,12 +96,15 @@ impl IndexedRepository<UserKey, User, VirtualMemory<Memory>> for UserRepository
             });
 
         entry.to_index_for_groups().into_iter().for_each(|index| {
+            invalidate_group_members_cache(index.group_id, &index.user_status);
+
             self.group_status_index.insert(index);
         });
     }
 
     fn clear_indexes(&self) {
         CACHE.with(|cache| cache.borrow_mut().clear());
+        GROUP_MEMBERS_CACHE.with(|cache| cache.borrow_mut().clear());
 
         self.group_status_index.clear();
 
@@ -196,6 +204,9 @@ impl UserRepository {
     /// rebuilding the cache from the repository would take around 1.84B instructions.
     pub const MAX_CACHE_SIZE: usize = 50_000;
 
+    /// The maximum number of (group, status) pairs that have their members cached in the heap.
+    pub const MAX_GROUP_MEMBERS_CACHE_SIZE: usize = 1_000;
+
     /// Checks if every user in the repository is in the cache.
     fn use_only_cache(&self) -> bool {
         self.len() <= Self::MAX_CACHE_SIZE
@@ -207,11 +218,11 @@ impl UserRepository {
     /// up-to-date with the repository and that we have enough instructions to rebuild the cache.
     pub fn build_cache(&self) {
         if self.len() > Self::MAX_CACHE_SIZE {
-            print(format!(
+            log_warn!(
                 "Only the first {} users will be added to the cache, the reposity has {} users.",
                 Self::MAX_CACHE_SIZE,
                 USER_REPOSITORY.len(),
-            ));
+            );
         }
 
         CACHE.with(|cache| {
@@ -239,12 +250,31 @@ impl UserRepository {
     }
 
     /// Returns the users associated with the given group and their user status if they exist.
+    ///
+    /// The members of the group are served from the heap cache when available.
     pub fn find_by_group_and_status(&self, group_id: &UUID, status: &UserStatus) -> Vec<User> {
-        self.group_status_index
-            .find_by_criteria(UserStatusGroupIndexCriteria {
-                group_id: group_id.to_owned(),
-                user_status: status.to_owned(),
-            })
+        let cache_key = (*group_id, status.to_owned());
+        let user_ids =
+            match GROUP_MEMBERS_CACHE.with(|cache| cache.borrow().get(&cache_key).cloned()) {
+                Some(user_ids) => user_ids,
+                None => {
+                    let user_ids = self
+                        .group_status_index
+                        .find_by_criteria(UserStatusGroupIndexCriteria {
+                            group_id: group_id.to_owned(),
+                            user_status: status.to_owned(),
+                        })
+                        .into_iter()
+                        .collect::<Vec<_>>();
+
+                    GROUP_MEMBERS_CACHE
+                        .with(|cache| cache.borrow_mut().insert(cache_key, user_ids.clone()));
+
+                    user_ids
+                }
+            };
+
+        user_ids
             .iter()
             .filter_map(|user_id| self.get(&User::key(*user_id)))
             .collect()
@@ -284,6 +314,15 @@ impl UserRepository {
     }
 }
 
+/// Removes the cached members of the group with the given status, must be called whenever they change.
+fn invalidate_group_members_cache(group_id: UserGroupId, user_status: &UserStatus) {
+    GROUP_MEMBERS_CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .remove(&(group_id, user_status.to_owned()))
+    });
+}
+
 #[derive(Debug, Clone)]
 pub struct UserWhereClause {
     pub search_term: Option<String>,
@@ -334,6 +373,42 @@ mod tests {
 
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn group_members_cache_is_invalidated_on_user_mutation() {
+        let repository = UserRepository::default();
+        let mut user = user_test_utils::mock_user();
+        user.groups = vec![[1; 16]];
+        user.status = UserStatus::Active;
+
+        assert!(repository
+            .find_by_group_and_status(&[1; 16], &UserStatus::Active)
+            .is_empty());
+
+        repository.insert(user.to_key(), user.clone());
+
+        assert_eq!(
+            repository.find_by_group_and_status(&[1; 16], &UserStatus::Active),
+            vec![user.clone()]
+        );
+
+        user.status = UserStatus::Inactive;
+        repository.insert(user.to_key(), user.clone());
+
+        assert!(repository
+            .find_by_group_and_status(&[1; 16], &UserStatus::Active)
+            .is_empty());
+        assert_eq!(
+            repository.find_by_group_and_status(&[1; 16], &UserStatus::Inactive),
+            vec![user.clone()]
+        );
+
+        repository.remove(&user.to_key());
+
+        assert!(repository
+            .find_by_group_and_status(&[1; 16], &UserStatus::Inactive)
+            .is_empty());
+    }
 }
 
 #[cfg(feature = "canbench")]
@@ -1,9 +1,10 @@
 use super::indexes::unique_index::UniqueIndexRepository;
 use crate::{
     core::{
-        cache::Cache, ic_cdk::api::print, metrics::USER_GROUP_METRICS, utils::format_unique_string,
+        cache::Cache, metrics::USER_GROUP_METRICS, utils::format_unique_string,
         with_memory_manager, Memory, USER_GROUP_MEMORY_ID,
     },
+    log_warn,
     models::{indexes::unique_index::UniqueIndexKey, UserGroup, UserGroupId},
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
@@ -159,11 +160,11 @@ impl UserGroupRepository {
     /// up-to-date with the repository and that we have enough instructions to rebuild the cache.
     pub fn build_cache(&self) {
         if self.len() > Self::MAX_CACHE_SIZE {
-            print(format!(
+            log_warn!(
                 "Only the first {} user groups will be added to the cache, the reposity has {} user groups.",
                 Self::MAX_CACHE_SIZE,
                 self.len(),
-            ));
+            );
         }
 
         CACHE.with(|cache| {
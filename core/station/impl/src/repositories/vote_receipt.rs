@@ -0,0 +1,103 @@
+use crate::{
+    core::{with_memory_manager, Memory, VOTE_RECEIPT_HASH_MEMORY_ID, VOTE_RECEIPT_MEMORY_ID},
+    models::{VoteReceipt, VoteReceiptHash, VoteReceiptKey, VoteReceiptLabel},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, ops::Bound, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<VoteReceiptKey, VoteReceipt, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(VOTE_RECEIPT_MEMORY_ID))
+    )
+  });
+
+  static CERTIFIED_HASHES: RefCell<StableBTreeMap<VoteReceiptLabel, VoteReceiptHash, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(VOTE_RECEIPT_HASH_MEMORY_ID))
+    )
+  });
+}
+
+lazy_static! {
+    pub static ref VOTE_RECEIPT_REPOSITORY: Arc<VoteReceiptRepository> =
+        Arc::new(VoteReceiptRepository::default());
+}
+
+/// A repository that stores the receipts of the votes on requests in stable memory, by request and approver.
+#[derive(Default, Debug)]
+pub struct VoteReceiptRepository {}
+
+impl StableDb<VoteReceiptKey, VoteReceipt, VirtualMemory<Memory>> for VoteReceiptRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<VoteReceiptKey, VoteReceipt, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<VoteReceiptKey, VoteReceipt, VirtualMemory<Memory>> for VoteReceiptRepository {}
+
+impl VoteReceiptRepository {
+    /// Stores the certified hash of a receipt by its label, so that the certified tree of the votes can be
+    /// rebuilt after an upgrade without encoding the receipts again.
+    pub fn insert_certified_hash(&self, label: VoteReceiptLabel, hash: VoteReceiptHash) {
+        CERTIFIED_HASHES.with(|m| m.borrow_mut().insert(label, hash));
+    }
+
+    /// Returns up to `limit` certified hashes in the order of their labels, starting after the given label.
+    pub fn find_certified_hashes_after(
+        &self,
+        after: Option<VoteReceiptLabel>,
+        limit: usize,
+    ) -> Vec<(VoteReceiptLabel, VoteReceiptHash)> {
+        let start = after.map_or(Bound::Unbounded, Bound::Excluded);
+
+        CERTIFIED_HASHES.with(|m| {
+            m.borrow()
+                .range((start, Bound::Unbounded))
+                .take(limit)
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::vote_receipt_test_utils::mock_vote_receipt;
+
+    #[test]
+    fn test_crud() {
+        let repository = VoteReceiptRepository::default();
+        let receipt = mock_vote_receipt();
+
+        assert!(repository.get(&receipt.to_key()).is_none());
+
+        repository.insert(receipt.to_key(), receipt.clone());
+
+        assert_eq!(repository.get(&receipt.to_key()), Some(receipt.clone()));
+        assert!(repository.remove(&receipt.to_key()).is_some());
+        assert!(repository.get(&receipt.to_key()).is_none());
+    }
+
+    #[test]
+    fn finds_the_certified_hashes_after_a_label() {
+        let repository = VoteReceiptRepository::default();
+        for i in 0..5 {
+            repository.insert_certified_hash([i; 32], [i + 10; 32]);
+        }
+
+        let first = repository.find_certified_hashes_after(None, 2);
+        let rest = repository.find_certified_hashes_after(Some([1; 32]), 10);
+
+        assert_eq!(first, vec![([0; 32], [10; 32]), ([1; 32], [11; 32])]);
+        assert_eq!(
+            rest.iter().map(|(label, _)| label[0]).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+    }
+}
@@ -1,44 +1,68 @@
 use crate::{
     core::{
         authorization::Authorization,
-        generate_uuid_v4,
+        format_amount, generate_uuid_v4,
         ic_cdk::next_time,
         read_system_info,
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
+        validation::{OperationFieldErrors, ValidateOperationInput},
         write_system_info, CallContext, ACCOUNT_BALANCE_FRESHNESS_IN_MS,
     },
     errors::AccountError,
-    factories::blockchains::BlockchainApiFactory,
-    mappers::{account::AccountMapper, HelperMapper},
+    factories::blockchains::{BlockchainApiFactory, BlockchainTransaction},
+    jobs::schedule_account_address_generation,
+    log_info, log_warn,
+    mappers::{account::AccountMapper, blockchain::BlockchainMapper, HelperMapper},
     models::{
         request_policy_rule::RequestPolicyRuleInput,
         request_specifier::RequestSpecifier,
         resource::{AccountResourceAction, Resource, ResourceId, ResourceIds},
-        Account, AccountBalance, AccountCallerPrivileges, AccountId, AddAccountOperationInput,
-        AddRequestPolicyOperationInput, Blockchain, BlockchainStandard, CycleObtainStrategy,
-        EditAccountOperationInput, EditPermissionOperationInput,
+        Account, AccountAddressReadyNotification, AccountBalance, AccountBalanceSnapshot,
+        AccountCallerPrivileges, AccountId, AccountReview, AccountSetupState, AddAccountOperation,
+        AddAccountOperationInput, AddRequestPolicyOperationInput, BalanceHistoryGranularity,
+        Blockchain, BlockchainStandard, CycleObtainStrategy, DepositDetectedNotification,
+        EditAccountOperationInput, EditPermissionOperationInput, Network, NotificationType,
+        RequestOperation, RotateAccountAddressOperationInput, Transfer,
+        TransferAccountOwnershipOperationInput, TransferId, ADMIN_GROUP_ID,
+    },
+    repositories::{
+        AccountRepository, AccountWhereClause, ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY,
+        ACCOUNT_REPOSITORY, PRINCIPAL_LABEL_REPOSITORY, REQUEST_POLICY_REPOSITORY,
+        TRANSFER_REPOSITORY,
     },
-    repositories::{AccountRepository, AccountWhereClause, ACCOUNT_REPOSITORY},
     services::{
         permission::{PermissionService, PERMISSION_SERVICE},
-        RequestPolicyService, REQUEST_POLICY_SERVICE,
+        NotificationService, RequestPolicyService, NOTIFICATION_SERVICE, REQUEST_POLICY_SERVICE,
     },
 };
 use lazy_static::lazy_static;
+use num_bigint::BigUint;
 use orbit_essentials::{
-    api::ServiceResult, model::ModelValidator, repository::Repository, types::UUID,
+    api::ServiceResult,
+    model::ModelValidator,
+    repository::Repository,
+    types::{Timestamp, UUID},
+    utils::timestamp_to_rfc3339,
+};
+use station_api::{
+    AccountBalanceDTO, AccountCreationPolicyDTO, BalanceSnapshotDTO, FetchAccountBalancesInput,
+    FiatPriceDTO, GeneratePaymentRequestInput, GeneratePaymentRequestResponse,
+    GetBalanceHistoryInput, GetBalanceHistoryResponse, GetPortfolioSummaryInput,
+    GetPortfolioSummaryResponse, ListAccountTransactionsInput, ListAccountTransactionsResponse,
+    ListAccountsInput, PortfolioAssetDTO, PortfolioBlockchainDTO, SetAccountReviewInput,
+    ValidateAccountCreationInput, ValidateAccountCreationResponse, ValidationFieldErrorDTO,
 };
-use station_api::{AccountBalanceDTO, FetchAccountBalancesInput, ListAccountsInput};
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 use uuid::Uuid;
 
-use super::SYSTEM_SERVICE;
+use super::{format_fiat_value, FIAT_DECIMALS, FIAT_VALUATION_SERVICE, SYSTEM_SERVICE};
 
 lazy_static! {
     pub static ref ACCOUNT_SERVICE: Arc<AccountService> = Arc::new(AccountService::new(
         Arc::clone(&REQUEST_POLICY_SERVICE),
         Arc::clone(&PERMISSION_SERVICE),
         Arc::clone(&ACCOUNT_REPOSITORY),
+        Arc::clone(&NOTIFICATION_SERVICE),
     ));
 }
 
@@ -47,21 +71,26 @@ pub struct AccountService {
     request_policy_service: Arc<RequestPolicyService>,
     permission_service: Arc<PermissionService>,
     account_repository: Arc<AccountRepository>,
+    notification_service: Arc<NotificationService>,
 }
 
 impl AccountService {
     const DEFAULT_ACCOUNT_LIST_LIMIT: u16 = 50;
     const MAX_ACCOUNT_LIST_LIMIT: u16 = 1000;
+    const DEFAULT_ACCOUNT_TRANSACTIONS_LIMIT: u16 = 50;
+    const MAX_ACCOUNT_TRANSACTIONS_LIMIT: u16 = 100;
 
     pub fn new(
         request_policy_service: Arc<RequestPolicyService>,
         permission_service: Arc<PermissionService>,
         account_repository: Arc<AccountRepository>,
+        notification_service: Arc<NotificationService>,
     ) -> Self {
         Self {
             request_policy_service,
             permission_service,
             account_repository,
+            notification_service,
         }
     }
 
@@ -123,6 +152,21 @@ impl AccountService {
         Ok(result)
     }
 
+    /// Returns the accounts that can be exposed as ICRC-1 accounts to the signer standards,
+    /// which are the Internet Computer accounts that the caller has access to read.
+    pub fn list_icrc1_accounts(&self, ctx: &CallContext) -> Vec<Account> {
+        let mut accounts = self
+            .account_repository
+            .find_where(AccountWhereClause { search_term: None });
+
+        accounts.retain(|account| account.blockchain == Blockchain::InternetComputer);
+        retain_accessible_resources(ctx, &mut accounts, |account: &Account| {
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(account.id)))
+        });
+
+        accounts
+    }
+
     /// Creates a new account.
     pub async fn create_account(
         &self,
@@ -149,10 +193,25 @@ impl AccountService {
             AccountMapper::from_create_input(input.to_owned(), *uuid.as_bytes(), None)?;
 
         // The account address is generated after the account is created from the user input and
-        // all the validations are successfully completed.
+        // all the validations are successfully completed, if the generation fails the account is still
+        // created and its address is generated in the background.
         if new_account.address.is_empty() {
-            let account_address = blockchain_api.generate_address(&new_account).await?;
-            new_account.address = account_address;
+            match blockchain_api.generate_address(&new_account).await {
+                Ok(account_address) => new_account.address = account_address,
+                Err(error) => {
+                    log_warn!(
+                        "Failed to generate the address of the account {}: {}",
+                        uuid.hyphenated(),
+                        error
+                    );
+
+                    new_account.setup_state = AccountSetupState::pending_address_generation(
+                        1,
+                        error.to_string(),
+                        next_time(),
+                    );
+                }
+            }
         }
 
         if let Some(criteria) = &input.transfer_request_policy {
@@ -187,6 +246,8 @@ impl AccountService {
                             *uuid.as_bytes()
                         ])),
                         rule: policy_rule.clone(),
+                        checklist: Vec::new(),
+                        exclude_requester: false,
                     })?;
 
             new_account.transfer_request_policy_id = Some(transfer_request_policy.id);
@@ -201,6 +262,8 @@ impl AccountService {
                             *uuid.as_bytes()
                         ])),
                         rule: policy_rule.to_owned(),
+                        checklist: Vec::new(),
+                        exclude_requester: false,
                     })?;
 
             new_account.configs_request_policy_id = Some(configs_request_policy.id);
@@ -211,6 +274,13 @@ impl AccountService {
         // happen in an asynchronous way.
         self.account_repository.insert(key, new_account.clone());
 
+        if let AccountSetupState::PendingAddressGeneration {
+            next_attempt_at, ..
+        } = new_account.setup_state
+        {
+            schedule_account_address_generation(next_attempt_at);
+        }
+
         // Adds the access policies for the account.
         self.permission_service
             .edit_permission(EditPermissionOperationInput {
@@ -252,7 +322,7 @@ impl AccountService {
                 && new_account.standard == BlockchainStandard::Native
                 && new_account.symbol == "ICP"
             {
-                ic_cdk::println!("Setting cycle minting account to {}", uuid);
+                log_info!("Setting cycle minting account to {}", uuid);
 
                 system_info.set_cycle_obtain_strategy(CycleObtainStrategy::MintFromNativeToken {
                     account_id: *uuid.as_bytes(),
@@ -277,6 +347,15 @@ impl AccountService {
     pub async fn edit_account(&self, input: EditAccountOperationInput) -> ServiceResult<Account> {
         let mut account = self.get_account(&input.account_id)?;
 
+        if let Some(expected_version) = input.expected_version {
+            if account.version != expected_version {
+                Err(AccountError::VersionConflict {
+                    expected_version,
+                    current_version: account.version,
+                })?
+            }
+        }
+
         if let Some(name) = &input.name {
             account.name = name.to_owned();
 
@@ -324,6 +403,7 @@ impl AccountService {
         account.validate()?;
 
         account.last_modification_timestamp = next_time();
+        account.version += 1;
         self.account_repository
             .insert(account.to_key(), account.to_owned());
 
@@ -367,6 +447,142 @@ impl AccountService {
         Ok(account)
     }
 
+    /// Hands over the permissions that an owner holds on the account to another user or group.
+    ///
+    /// The new permissions are all validated before any of them is written, so that the handover is never
+    /// partially applied.
+    pub fn transfer_account_ownership(
+        &self,
+        input: TransferAccountOwnershipOperationInput,
+    ) -> ServiceResult<Account> {
+        let mut account = self.get_account(&input.account_id)?;
+        let mut permissions = Account::owner_resources(account.id)
+            .into_iter()
+            .map(|resource| self.permission_service.get_permission(&resource))
+            .filter(|permission| input.from.is_allowed_by(&permission.allow))
+            .collect::<Vec<_>>();
+
+        if permissions.is_empty() {
+            Err(AccountError::ValidationError {
+                info: format!("The {} holds no permission on the account.", input.from),
+            })?
+        }
+
+        for permission in permissions.iter_mut() {
+            input.from.hand_over(&input.to, &mut permission.allow);
+            permission.allow.validate()?;
+        }
+
+        for permission in permissions {
+            self.permission_service
+                .edit_permission(EditPermissionOperationInput {
+                    auth_scope: None,
+                    users: Some(permission.allow.users),
+                    user_groups: Some(permission.allow.user_groups),
+                    resource: permission.resource,
+                })?;
+        }
+
+        account.last_modification_timestamp = next_time();
+        account.version += 1;
+        self.account_repository
+            .insert(account.to_key(), account.to_owned());
+
+        log_info!(
+            "Handed over the ownership of account {} from the {} to the {}",
+            Uuid::from_bytes(account.id).hyphenated(),
+            input.from,
+            input.to
+        );
+
+        Ok(account)
+    }
+
+    /// Moves the account to a new address derived under its next derivation index.
+    ///
+    /// The current address is retired and can still be transferred from until the end of the grace period.
+    pub async fn rotate_account_address(
+        &self,
+        input: RotateAccountAddressOperationInput,
+    ) -> ServiceResult<Account> {
+        let mut account = self.get_account(&input.account_id)?;
+        Self::ensure_ready(&account)?;
+
+        if input.grace_period_secs > Account::MAX_ADDRESS_GRACE_PERIOD_SECS {
+            Err(AccountError::ValidationError {
+                info: format!(
+                    "The grace period must not exceed {} seconds.",
+                    Account::MAX_ADDRESS_GRACE_PERIOD_SECS
+                ),
+            })?
+        }
+
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+        let new_address = blockchain_api
+            .generate_address(&Account {
+                derivation_index: account.derivation_index + 1,
+                ..account.clone()
+            })
+            .await?;
+
+        if new_address == account.address {
+            Err(AccountError::AddressRotationNotSupported {
+                blockchain: account.blockchain.to_string(),
+                standard: account.standard.to_string(),
+            })?
+        }
+
+        let now = next_time();
+        account.rotate_address(new_address, input.grace_period_secs, now);
+        account.last_modification_timestamp = now;
+        account.version += 1;
+
+        self.account_repository
+            .insert(account.to_key(), account.to_owned());
+
+        Ok(account)
+    }
+
+    /// Flags the account as under review until the given time, or clears its review if none is given.
+    ///
+    /// No transfers can be requested from the account during the review, the pending transfer requests are
+    /// left untouched. The version of the account is not changed so that the pending edits still apply.
+    pub fn set_account_review(
+        &self,
+        input: SetAccountReviewInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Account> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let mut account = self.get_account(account_id.as_bytes())?;
+
+        let now = ctx.now();
+        account.review = match input.review {
+            Some(review) => {
+                let caller_user = ctx.user().ok_or(AccountError::Forbidden)?;
+                let review = AccountReview {
+                    reason: review.reason,
+                    flagged_by: caller_user.id,
+                    flagged_at: now,
+                    expires_at: HelperMapper::to_timestamp("expires_at", &review.expires_at)?,
+                };
+
+                review.validate()?;
+
+                Some(review)
+            }
+            None => None,
+        };
+        account.last_modification_timestamp = now;
+
+        self.account_repository
+            .insert(account.to_key(), account.to_owned());
+
+        Ok(account)
+    }
+
     /// Returns the balances of the requested accounts.
     ///
     /// If the balance is considered fresh it will be returned, otherwise it will be fetched from the blockchain.
@@ -389,7 +605,8 @@ impl AccountService {
             .find_by_ids(account_ids.iter().map(|id| *id.as_bytes()).collect());
 
         let mut balances = Vec::new();
-        for mut account in accounts {
+        // the accounts whose address is still being generated have no balance yet
+        for mut account in accounts.into_iter().filter(Account::is_ready) {
             let balance_considered_fresh = match &account.balance {
                 Some(balance) => {
                     let balance_age_ns = next_time() - balance.last_modification_timestamp;
@@ -401,47 +618,546 @@ impl AccountService {
                 (None, _) | (_, false) => {
                     let blockchain_api =
                         BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
-                    let fetched_balance = blockchain_api.balance(&account).await?;
+                    // retired addresses are watched until the end of their grace period
+                    let mut fetched_balance = BigUint::from(0u32);
+                    for spending in account.spendable_addresses(next_time()) {
+                        fetched_balance += blockchain_api.balance(&spending).await?;
+                    }
                     let new_balance = AccountBalance {
                         balance: candid::Nat(fetched_balance),
                         last_modification_timestamp: next_time(),
                     };
 
-                    account.balance = Some(new_balance.clone());
+                    let previous_balance = account.balance.replace(new_balance.clone());
 
                     self.account_repository
                         .insert(account.to_key(), account.clone());
 
+                    if let Some(previous_balance) = previous_balance {
+                        if new_balance.balance > previous_balance.balance {
+                            self.deposit_detected_hook(
+                                &account,
+                                new_balance.balance.clone() - previous_balance.balance,
+                                new_balance.balance.clone(),
+                            )
+                            .await;
+                        }
+                    }
+
                     new_balance
                 }
                 (Some(balance), _) => balance.to_owned(),
             };
 
-            balances.push(AccountMapper::to_balance_dto(
-                balance,
-                account.decimals,
-                account.id,
-            ));
+            balances.push(AccountMapper::to_balance_dto(balance, &account));
         }
 
         Ok(balances)
     }
+
+    /// Notifies the admins and the members of the groups named in the transfer policy of the account.
+    async fn deposit_detected_hook(
+        &self,
+        account: &Account,
+        amount: candid::Nat,
+        balance: candid::Nat,
+    ) {
+        self.notification_service
+            .send_group_notification(
+                [],
+                Self::notified_groups(account),
+                NotificationType::DepositDetected(DepositDetectedNotification {
+                    account_id: account.id,
+                    amount: amount.clone(),
+                    balance,
+                }),
+                format!("Deposit detected on {}", account.name),
+                Some(format!(
+                    "The balance of {} increased by {} in the smallest unit of {}.",
+                    account.name, amount, account.symbol
+                )),
+            )
+            .await;
+    }
+
+    /// Returns the admin group and the groups named in the transfer policy of the account, which are
+    /// notified of the events of the account.
+    fn notified_groups(account: &Account) -> Vec<UUID> {
+        account
+            .transfer_request_policy_id
+            .and_then(|policy_id| REQUEST_POLICY_REPOSITORY.get(&policy_id))
+            .map(|policy| policy.rule.user_groups())
+            .unwrap_or_default()
+            .into_iter()
+            .chain([*ADMIN_GROUP_ID])
+            .collect()
+    }
+
+    /// Fails if the address of the account is still being generated.
+    fn ensure_ready(account: &Account) -> ServiceResult<()> {
+        if !account.is_ready() {
+            Err(AccountError::AddressPending {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            })?
+        }
+
+        Ok(())
+    }
+
+    /// Generates the address of an account that was created before its address could be generated.
+    ///
+    /// Once the address is generated the account is ready and the admins are notified, otherwise the next
+    /// attempt is delayed further.
+    pub async fn generate_pending_address(&self, account_id: &AccountId) -> ServiceResult<Account> {
+        let mut account = self.get_account(account_id)?;
+        let AccountSetupState::PendingAddressGeneration { attempts, .. } = account.setup_state
+        else {
+            return Ok(account);
+        };
+
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+        let result = blockchain_api.generate_address(&account).await;
+
+        // the account is read again since it could have been edited while the address was generated
+        let mut account = self.get_account(account_id)?;
+        let now = next_time();
+        match result {
+            Ok(address) => {
+                account.address = address;
+                account.setup_state = AccountSetupState::Ready;
+                account.last_modification_timestamp = now;
+
+                self.account_repository
+                    .insert(account.to_key(), account.clone());
+
+                self.notification_service
+                    .send_group_notification(
+                        [],
+                        Self::notified_groups(&account),
+                        NotificationType::AccountAddressReady(AccountAddressReadyNotification {
+                            account_id: account.id,
+                        }),
+                        format!("The address of {} is ready", account.name),
+                        Some(format!(
+                            "{} can now receive and transfer {}.",
+                            account.name, account.symbol
+                        )),
+                    )
+                    .await;
+            }
+            Err(error) => {
+                log_warn!(
+                    "Failed to generate the address of the account {}: {}",
+                    Uuid::from_bytes(account.id).hyphenated(),
+                    error
+                );
+
+                account.setup_state = AccountSetupState::pending_address_generation(
+                    attempts.saturating_add(1),
+                    error.to_string(),
+                    now,
+                );
+
+                self.account_repository
+                    .insert(account.to_key(), account.clone());
+            }
+        }
+
+        Ok(account)
+    }
+
+    /// Returns a page of the on-chain transactions of the account, including the incoming ones.
+    ///
+    /// Transactions that were submitted by the station are linked to their transfer.
+    pub async fn list_account_transactions(
+        &self,
+        input: ListAccountTransactionsInput,
+    ) -> ServiceResult<ListAccountTransactionsResponse> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.get_account(account_id.as_bytes())?;
+        Self::ensure_ready(&account)?;
+        let limit = input
+            .limit
+            .unwrap_or(Self::DEFAULT_ACCOUNT_TRANSACTIONS_LIMIT)
+            .clamp(1, Self::MAX_ACCOUNT_TRANSACTIONS_LIMIT);
+
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+        let page = blockchain_api
+            .list_transactions(&account, input.cursor, limit)
+            .await?;
+
+        let transfers = TRANSFER_REPOSITORY.find_by_account(account.id, None, None, None);
+
+        Ok(ListAccountTransactionsResponse {
+            transactions: page
+                .transactions
+                .into_iter()
+                .map(|transaction| {
+                    let transfer_id = Self::find_transaction_transfer(&transaction, &transfers);
+                    let find_label = |address: &Option<String>| {
+                        address
+                            .as_deref()
+                            .and_then(|address| PRINCIPAL_LABEL_REPOSITORY.find_label(address))
+                    };
+                    let from_label = find_label(&transaction.from_address);
+                    let to_label = find_label(&transaction.to_address);
+
+                    AccountMapper::to_transaction_dto(
+                        transaction,
+                        &account.address,
+                        transfer_id,
+                        from_label,
+                        to_label,
+                    )
+                })
+                .collect(),
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    /// Returns a payment request to the account in the standardized URI format of its blockchain.
+    pub fn generate_payment_request(
+        &self,
+        input: GeneratePaymentRequestInput,
+    ) -> ServiceResult<GeneratePaymentRequestResponse> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.get_account(account_id.as_bytes())?;
+        Self::ensure_ready(&account)?;
+
+        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
+        let uri = blockchain_api.payment_request_uri(
+            &account,
+            input.amount.as_ref().map(|amount| &amount.0),
+            input.memo.as_deref(),
+        )?;
+
+        Ok(GeneratePaymentRequestResponse {
+            uri,
+            address: account.address,
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+            symbol: account.symbol,
+            decimals: account.decimals,
+            amount: input.amount,
+            memo: input.memo,
+        })
+    }
+
+    /// Returns the balance history of the account within the given range, with the last snapshot of each
+    /// period of the granularity (e.g. the closing balance of each month).
+    pub fn get_balance_history(
+        &self,
+        input: GetBalanceHistoryInput,
+    ) -> ServiceResult<GetBalanceHistoryResponse> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.get_account(account_id.as_bytes())?;
+
+        let to_dt =
+            HelperMapper::to_optional_timestamp("to_dt", input.to_dt)?.unwrap_or_else(next_time);
+        let from_dt = HelperMapper::to_optional_timestamp("from_dt", input.from_dt)?
+            .unwrap_or_else(|| {
+                to_dt.saturating_sub(AccountBalanceSnapshot::DEFAULT_HISTORY_RANGE_NS)
+            });
+
+        if from_dt > to_dt {
+            Err(AccountError::ValidationError {
+                info: "The start of the range must be before its end.".to_string(),
+            })?
+        }
+
+        let granularity = input
+            .granularity
+            .map(BalanceHistoryGranularity::from)
+            .unwrap_or(BalanceHistoryGranularity::Daily);
+
+        let mut snapshots: Vec<(Timestamp, Timestamp, AccountBalanceSnapshot)> = Vec::new();
+        for (timestamp, snapshot) in
+            ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY.find_by_account(account.id, from_dt, to_dt)
+        {
+            let bucket_start = granularity.bucket_start(timestamp);
+            match snapshots.last_mut() {
+                Some(last) if last.0 == bucket_start => *last = (bucket_start, timestamp, snapshot),
+                _ => snapshots.push((bucket_start, timestamp, snapshot)),
+            }
+        }
+
+        if snapshots.len() > AccountBalanceSnapshot::MAX_HISTORY_SNAPSHOTS {
+            Err(AccountError::ValidationError {
+                info: format!(
+                    "The balance history is limited to {} snapshots, use a shorter range or a coarser granularity.",
+                    AccountBalanceSnapshot::MAX_HISTORY_SNAPSHOTS
+                ),
+            })?
+        }
+
+        Ok(GetBalanceHistoryResponse {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            symbol: account.symbol,
+            decimals: account.decimals,
+            granularity: granularity.into(),
+            snapshots: snapshots
+                .into_iter()
+                .map(|(_, timestamp, snapshot)| BalanceSnapshotDTO {
+                    timestamp: timestamp_to_rfc3339(&timestamp),
+                    balance: snapshot.balance,
+                })
+                .collect(),
+        })
+    }
+
+    /// Returns the balances of the accounts that the caller has access to, grouped by asset and by blockchain,
+    /// with their value in the fiat currency if one is selected.
+    ///
+    /// Only the cached balances and prices are used so that the summary is cheap enough to be loaded with
+    /// every dashboard, the summary is not complete if some balances were never fetched or some assets
+    /// have no recent price. The assets on test networks are worth nothing.
+    pub fn get_portfolio_summary(
+        &self,
+        input: GetPortfolioSummaryInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<GetPortfolioSummaryResponse> {
+        if let Some(currency) = &input.currency {
+            FIAT_VALUATION_SERVICE.ensure_supported_currency(currency)?;
+        }
+
+        let mut accounts = self
+            .account_repository
+            .find_where(AccountWhereClause { search_term: None });
+        retain_accessible_resources(ctx, &mut accounts, |account: &Account| {
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(account.id)))
+        });
+
+        let system_info = read_system_info();
+        let mut assets: BTreeMap<(String, String, String, String), PortfolioAssetDTO> =
+            BTreeMap::new();
+        let mut accounts_without_balance = 0;
+        let mut oldest_balance_at: Option<Timestamp> = None;
+        for account in accounts {
+            let network = system_info
+                .find_network(&account.blockchain, account.network_id())
+                .unwrap_or_else(|| Network::resolve(&account.blockchain, account.network_id()));
+            let asset = assets
+                .entry((
+                    account.blockchain.to_string(),
+                    network.id.clone(),
+                    account.standard.to_string(),
+                    account.symbol.clone(),
+                ))
+                .or_insert_with(|| PortfolioAssetDTO {
+                    blockchain: account.blockchain.to_string(),
+                    is_testnet: network.is_testnet,
+                    network: network.into(),
+                    standard: account.standard.to_string(),
+                    symbol: account.symbol.clone(),
+                    decimals: account.decimals,
+                    display_decimals: account.display_decimals(),
+                    accounts: 0,
+                    balance: candid::Nat::from(0u64),
+                    formatted_balance: String::new(),
+                    fiat_price: None,
+                    fiat_value: None,
+                    formatted_fiat_value: None,
+                });
+
+            asset.accounts += 1;
+            match &account.balance {
+                Some(balance) => {
+                    asset.balance += balance.balance.clone();
+                    oldest_balance_at = Some(
+                        oldest_balance_at.map_or(balance.last_modification_timestamp, |oldest| {
+                            oldest.min(balance.last_modification_timestamp)
+                        }),
+                    );
+                }
+                None => accounts_without_balance += 1,
+            }
+        }
+
+        let now = ctx.now();
+        let zero = candid::Nat::from(0u64);
+        let mut is_complete = accounts_without_balance == 0;
+        let mut blockchains: BTreeMap<String, PortfolioBlockchainDTO> = BTreeMap::new();
+        for asset in assets.values_mut() {
+            asset.formatted_balance =
+                format_amount(&asset.balance, asset.decimals, asset.display_decimals);
+            let blockchain = blockchains
+                .entry(asset.blockchain.clone())
+                .or_insert_with(|| PortfolioBlockchainDTO {
+                    blockchain: asset.blockchain.clone(),
+                    accounts: 0,
+                    fiat_value: input.currency.as_ref().map(|_| zero.clone()),
+                    formatted_fiat_value: None,
+                });
+            blockchain.accounts += asset.accounts;
+
+            let Some(currency) = &input.currency else {
+                continue;
+            };
+
+            let price = match asset.is_testnet {
+                true => None,
+                false => FIAT_VALUATION_SERVICE.get_price(&asset.symbol, currency, now),
+            };
+            asset.fiat_value = match &price {
+                Some(price) => Some(price.value_of(&asset.balance, asset.decimals)),
+                None if asset.is_testnet || asset.balance == zero => Some(zero.clone()),
+                None => {
+                    is_complete = false;
+
+                    None
+                }
+            };
+            asset.formatted_fiat_value = asset.fiat_value.as_ref().map(format_fiat_value);
+            asset.fiat_price = price.map(|price| FiatPriceDTO {
+                rate: price.rate,
+                decimals: price.decimals,
+                updated_at: timestamp_to_rfc3339(&price.updated_at),
+            });
+
+            if let (Some(total), Some(value)) = (&mut blockchain.fiat_value, &asset.fiat_value) {
+                *total += value.clone();
+            }
+        }
+
+        for blockchain in blockchains.values_mut() {
+            blockchain.formatted_fiat_value = blockchain.fiat_value.as_ref().map(format_fiat_value);
+        }
+
+        let total_fiat_value = input.currency.as_ref().map(|_| {
+            blockchains
+                .values()
+                .filter_map(|blockchain| blockchain.fiat_value.clone())
+                .fold(zero.clone(), |total, value| total + value)
+        });
+
+        Ok(GetPortfolioSummaryResponse {
+            currency: input.currency,
+            fiat_decimals: FIAT_DECIMALS,
+            formatted_total_fiat_value: total_fiat_value.as_ref().map(format_fiat_value),
+            total_fiat_value,
+            is_complete,
+            assets: assets.into_values().collect(),
+            blockchains: blockchains.into_values().collect(),
+            accounts_without_balance,
+            oldest_balance_at: oldest_balance_at.map(|timestamp| timestamp_to_rfc3339(&timestamp)),
+        })
+    }
+
+    /// Validates the input of an account creation without creating the request, with the same checks that
+    /// the request creation runs, and returns the request policies that would govern the new account.
+    pub fn validate_account_creation(
+        &self,
+        input: ValidateAccountCreationInput,
+    ) -> ServiceResult<ValidateAccountCreationResponse> {
+        let mut errors = OperationFieldErrors::default();
+        let mut transfer_policies = Vec::new();
+        let mut configs_policies = Vec::new();
+
+        for policy in REQUEST_POLICY_REPOSITORY.list() {
+            let policy_dto = AccountCreationPolicyDTO {
+                policy_id: Some(Uuid::from_bytes(policy.id).hyphenated().to_string()),
+                rule: policy.rule.into(),
+            };
+
+            match policy.specifier {
+                RequestSpecifier::Transfer(ResourceIds::Any) => transfer_policies.push(policy_dto),
+                RequestSpecifier::EditAccount(ResourceIds::Any) => {
+                    configs_policies.push(policy_dto)
+                }
+                _ => {}
+            }
+        }
+
+        let blockchain = BlockchainMapper::to_blockchain(input.blockchain.clone());
+        let standard = BlockchainMapper::to_blockchain_standard(input.standard.clone());
+
+        match (blockchain, standard) {
+            (Ok(_), Ok(_)) => {
+                let input = AddAccountOperationInput::from(input);
+
+                if let Some(rule) = &input.transfer_request_policy {
+                    transfer_policies.push(AccountCreationPolicyDTO {
+                        policy_id: None,
+                        rule: rule.clone().into(),
+                    });
+                }
+
+                if let Some(rule) = &input.configs_request_policy {
+                    configs_policies.push(AccountCreationPolicyDTO {
+                        policy_id: None,
+                        rule: rule.clone().into(),
+                    });
+                }
+
+                RequestOperation::AddAccount(AddAccountOperation {
+                    account_id: None,
+                    input,
+                })
+                .validate_input(&mut errors);
+            }
+            (blockchain, standard) => {
+                errors.check("blockchain", blockchain.map(|_| ()));
+                errors.check("standard", standard.map(|_| ()));
+            }
+        }
+
+        Ok(ValidateAccountCreationResponse {
+            errors: errors
+                .into_errors()
+                .into_iter()
+                .map(|(field, message)| ValidationFieldErrorDTO { field, message })
+                .collect(),
+            transfer_policies,
+            configs_policies,
+        })
+    }
+
+    /// Finds the station transfer that submitted the given transaction, if any.
+    fn find_transaction_transfer(
+        transaction: &BlockchainTransaction,
+        transfers: &[Transfer],
+    ) -> Option<TransferId> {
+        transfers
+            .iter()
+            .find(|transfer| {
+                let Some(submission) = &transfer.submission else {
+                    return false;
+                };
+
+                if let Some(block_height) = submission.reconciled_block_height {
+                    return block_height.to_string() == transaction.id;
+                }
+
+                transaction.created_at == Some(submission.created_at)
+                    && transaction.to_address.as_deref() == Some(transfer.to_address.as_str())
+                    && transaction.amount == transfer.amount.0
+            })
+            .map(|transfer| transfer.id)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use candid::Principal;
+    use orbit_essentials::utils::rfc3339_to_timestamp;
 
     use super::*;
     use crate::{
         core::{test_utils, validation::disable_mock_resource_validation, CallContext},
+        factories::blockchains::InternetComputer,
         models::{
-            account_test_utils::mock_account, permission::Allow,
-            request_policy_rule::RequestPolicyRule, request_specifier::UserSpecifier,
-            user_test_utils::mock_user, AddAccountOperation, AddAccountOperationInput, Blockchain,
-            BlockchainStandard, Metadata, User,
+            account_test_utils::mock_account,
+            permission::{Allow, Permission},
+            request_policy_rule::RequestPolicyRule,
+            request_specifier::UserSpecifier,
+            system::StationDecommission,
+            transfer_test_utils::mock_transfer,
+            user_test_utils::mock_user,
+            AccountBalanceSnapshotKey, AccountOwner, AddAccountOperation, AddAccountOperationInput,
+            Blockchain, BlockchainStandard, FiatValuationConfig, Metadata, User,
         },
-        repositories::UserRepository,
+        repositories::{permission::PermissionRepository, UserRepository},
+        services::FiatPrice,
     };
 
     struct TestContext {
@@ -493,6 +1209,7 @@ mod tests {
                 transfer_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_request_policy: Some(RequestPolicyRule::AutoApproved),
                 transfer_request_policy: Some(RequestPolicyRule::AutoApproved),
+                network: None,
             },
         };
 
@@ -501,6 +1218,119 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn rotate_account_address_retires_the_current_address() {
+        let ctx = setup();
+        let mut account = mock_account();
+        account.address = InternetComputer::create().station_account_address(&account);
+
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let rotated = ctx
+            .service
+            .rotate_account_address(RotateAccountAddressOperationInput {
+                account_id: account.id,
+                grace_period_secs: 60,
+            })
+            .await
+            .unwrap();
+
+        assert_ne!(rotated.address, account.address);
+        assert_eq!(rotated.derivation_index, 1);
+        assert_eq!(rotated.retired_addresses.len(), 1);
+        assert_eq!(rotated.retired_addresses[0].address, account.address);
+        assert_eq!(
+            rotated
+                .spending_from(&account.address, next_time())
+                .map(|spending| spending.derivation_index),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn transfer_account_ownership_hands_over_the_permissions_of_the_owner() {
+        let ctx = setup();
+        let account = mock_account();
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let owner = [1; 16];
+        let admin = [2; 16];
+        let recipient = [3; 16];
+        let [read, update, transfer] = Account::owner_resources(account.id);
+        for (resource, allow) in [
+            (read.clone(), Allow::users(vec![owner, admin])),
+            (update.clone(), Allow::users(vec![admin])),
+            (transfer.clone(), Allow::users(vec![owner])),
+        ] {
+            let permission = Permission::new(allow, resource);
+            PermissionRepository::default().insert(permission.key(), permission);
+        }
+
+        let edited = ctx
+            .service
+            .transfer_account_ownership(TransferAccountOwnershipOperationInput {
+                account_id: account.id,
+                from: AccountOwner::User(owner),
+                to: AccountOwner::User(recipient),
+            })
+            .unwrap();
+
+        assert_eq!(edited.version, account.version + 1);
+
+        let allowed_users =
+            |resource: &Resource| PERMISSION_SERVICE.get_permission(resource).allow.users;
+        assert_eq!(allowed_users(&read), vec![admin, recipient]);
+        assert_eq!(allowed_users(&update), vec![admin]);
+        assert_eq!(allowed_users(&transfer), vec![recipient]);
+
+        let error = ctx
+            .service
+            .transfer_account_ownership(TransferAccountOwnershipOperationInput {
+                account_id: account.id,
+                from: AccountOwner::User(owner),
+                to: AccountOwner::User(recipient),
+            })
+            .unwrap_err();
+        assert_eq!(error.code, "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn pending_account_is_ready_once_its_address_is_generated() {
+        let ctx = setup();
+        let mut account = mock_account();
+        account.address = String::new();
+        account.setup_state = AccountSetupState::pending_address_generation(
+            2,
+            "signing failed".to_string(),
+            next_time(),
+        );
+
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let result = ctx
+            .service
+            .generate_payment_request(GeneratePaymentRequestInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                amount: None,
+                memo: None,
+            })
+            .unwrap_err();
+        assert_eq!(result.code, "ADDRESS_PENDING");
+
+        let ready = ctx
+            .service
+            .generate_pending_address(&account.id)
+            .await
+            .unwrap();
+
+        assert!(ready.is_ready());
+        assert_eq!(
+            ready.address,
+            InternetComputer::create().station_account_address(&account)
+        );
+        assert!(ctx.repository.get(&account.to_key()).unwrap().is_ready());
+    }
+
     #[tokio::test]
     async fn add_account_with_existing_name_should_fail() {
         let ctx = setup();
@@ -524,6 +1354,7 @@ mod tests {
                 transfer_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_request_policy: Some(RequestPolicyRule::AutoApproved),
                 transfer_request_policy: Some(RequestPolicyRule::AutoApproved),
+                network: None,
             },
         };
 
@@ -532,6 +1363,58 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn validate_account_creation_reports_the_invalid_fields() {
+        let ctx = setup();
+
+        disable_mock_resource_validation();
+
+        let mut account = mock_account();
+        account.name = "foo".to_string();
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let allow = station_api::AllowDTO {
+            auth_scope: station_api::AuthScopeDTO::Restricted,
+            users: vec![],
+            user_groups: vec![],
+        };
+        let input = ValidateAccountCreationInput {
+            name: account.name,
+            blockchain: "icp".to_string(),
+            standard: "native".to_string(),
+            metadata: vec![],
+            read_permission: allow.clone(),
+            configs_permission: allow.clone(),
+            transfer_permission: allow,
+            configs_request_policy: None,
+            transfer_request_policy: Some(station_api::RequestPolicyRuleDTO::AutoApproved),
+        };
+
+        let response = ctx
+            .service
+            .validate_account_creation(input.clone())
+            .unwrap();
+
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].field, "name");
+        assert_eq!(response.transfer_policies.len(), 1);
+        assert!(response.transfer_policies[0].policy_id.is_none());
+        assert!(response.configs_policies.is_empty());
+
+        let response = ctx
+            .service
+            .validate_account_creation(ValidateAccountCreationInput {
+                name: "bar".to_string(),
+                blockchain: "unknown".to_string(),
+                ..input
+            })
+            .unwrap();
+
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.errors[0].field, "blockchain");
+        assert!(response.transfer_policies.is_empty());
+    }
+
     #[tokio::test]
     async fn add_account_with_missing_policy_should_fail() {
         let ctx = setup();
@@ -548,6 +1431,7 @@ mod tests {
             transfer_permission: Allow::users(vec![ctx.caller_user.id]),
             configs_request_policy: Some(RequestPolicyRule::AutoApproved),
             transfer_request_policy: Some(RequestPolicyRule::AutoApproved),
+            network: None,
         };
 
         assert!(ctx
@@ -635,6 +1519,7 @@ mod tests {
             transfer_permission: Allow::users(vec![ctx.caller_user.id]),
             configs_request_policy: Some(RequestPolicyRule::AutoApproved),
             transfer_request_policy: Some(RequestPolicyRule::AutoApproved),
+            network: None,
         };
 
         let result = ctx.service.create_account(input, Some(account.id)).await;
@@ -661,6 +1546,7 @@ mod tests {
             configs_permission: None,
             transfer_request_policy: None,
             configs_request_policy: None,
+            expected_version: None,
         };
 
         let result = ctx.service.edit_account(operation).await;
@@ -691,6 +1577,7 @@ mod tests {
             configs_permission: None,
             transfer_request_policy: None,
             configs_request_policy: None,
+            expected_version: None,
         };
 
         let result = ctx.service.edit_account(operation).await;
@@ -713,6 +1600,7 @@ mod tests {
                 transfer_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_request_policy: Some(RequestPolicyRule::AutoApproved),
                 transfer_request_policy: Some(RequestPolicyRule::AutoApproved),
+                network: None,
             },
         };
 
@@ -739,6 +1627,7 @@ mod tests {
             configs_permission: None,
             transfer_request_policy: None,
             configs_request_policy: None,
+            expected_version: None,
         };
 
         assert!(ctx.service.edit_account(base_input.clone()).await.is_ok());
@@ -787,4 +1676,321 @@ mod tests {
             .await
             .expect_err("transfer_request_policy should be invalid");
     }
+
+    #[test]
+    fn set_and_clear_the_review_of_the_account() {
+        let ctx = setup();
+        let account = mock_account();
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+        let now = next_time();
+        let review = |expires_at: u64| SetAccountReviewInput {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            review: Some(station_api::AccountReviewInput {
+                reason: "Quarterly audit".to_string(),
+                expires_at: timestamp_to_rfc3339(&expires_at),
+            }),
+        };
+
+        let flagged = ctx
+            .service
+            .set_account_review(review(now + 24 * 60 * 60 * 1_000_000_000), &call_context)
+            .unwrap();
+        let flagged_review = flagged.review.as_ref().unwrap();
+        assert_eq!(flagged_review.flagged_by, ctx.caller_user.id);
+        assert!(flagged.active_review(next_time()).is_some());
+        assert_eq!(flagged.version, account.version);
+
+        assert!(ctx
+            .service
+            .set_account_review(
+                review(now + AccountReview::MAX_DURATION_NS + 60 * 1_000_000_000),
+                &call_context
+            )
+            .is_err());
+
+        let cleared = ctx
+            .service
+            .set_account_review(
+                SetAccountReviewInput {
+                    account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                    review: None,
+                },
+                &call_context,
+            )
+            .unwrap();
+        assert!(cleared.review.is_none());
+        assert!(ctx
+            .repository
+            .get(&account.to_key())
+            .unwrap()
+            .review
+            .is_none());
+    }
+
+    #[test]
+    fn fail_set_review_of_account_of_decommissioned_station() {
+        let ctx = setup();
+        let account = mock_account();
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let mut system_info = read_system_info();
+        system_info.set_decommission(StationDecommission {
+            request_id: [1; 16],
+            reason: None,
+            decommissioned_at: 0,
+        });
+        write_system_info(system_info);
+
+        let result = ctx.service.set_account_review(
+            SetAccountReviewInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                review: None,
+            },
+            &CallContext::new(Principal::from_slice(&[9; 29])),
+        );
+
+        assert_eq!(result.unwrap_err().code, "STATION_DECOMMISSIONED");
+    }
+
+    #[test]
+    fn portfolio_summary_values_the_cached_balances() {
+        let ctx = setup();
+        let mut system_info = read_system_info();
+        system_info.set_fiat_valuation(FiatValuationConfig::Canister {
+            canister_id: Principal::from_slice(&[1; 29]),
+            currencies: vec!["USD".to_string()],
+        });
+        write_system_info(system_info);
+
+        let mut accounts = Vec::new();
+        for (id, blockchain, symbol, balance) in [
+            (1, Blockchain::InternetComputer, "ICP", Some(150_000_000u64)),
+            (2, Blockchain::InternetComputer, "ICP", Some(50_000_000u64)),
+            (3, Blockchain::Ethereum, "ETH", None),
+        ] {
+            let mut account = mock_account();
+            account.id = [id; 16];
+            account.blockchain = blockchain;
+            account.symbol = symbol.to_string();
+            account.decimals = 8;
+            account.balance = balance.map(|balance| AccountBalance {
+                balance: candid::Nat::from(balance),
+                last_modification_timestamp: id as u64,
+            });
+            accounts.push(account);
+        }
+
+        for account in &accounts {
+            ctx.repository.insert(account.to_key(), account.clone());
+
+            let permission = Permission::new(
+                Allow::users(vec![ctx.caller_user.id]),
+                Resource::Account(AccountResourceAction::Read(ResourceId::Id(account.id))),
+            );
+            PermissionRepository::default().insert(permission.key(), permission);
+        }
+
+        let call_context = CallContext::new(ctx.caller_user.identities[0]);
+        FIAT_VALUATION_SERVICE.record_price(
+            "ICP",
+            "USD",
+            FiatPrice {
+                rate: 1000,
+                decimals: 2,
+                updated_at: call_context.now(),
+            },
+        );
+
+        let summary = ctx
+            .service
+            .get_portfolio_summary(
+                GetPortfolioSummaryInput {
+                    currency: Some("USD".to_string()),
+                },
+                &call_context,
+            )
+            .unwrap();
+
+        assert_eq!(summary.assets.len(), 2);
+        let icp = summary
+            .assets
+            .iter()
+            .find(|asset| asset.symbol == "ICP")
+            .unwrap();
+        assert_eq!(icp.accounts, 2);
+        assert_eq!(icp.balance, candid::Nat::from(200_000_000u64));
+        assert_eq!(icp.fiat_value, Some(candid::Nat::from(2_000u64)));
+        assert_eq!(icp.formatted_balance, "2.0000");
+        assert_eq!(icp.formatted_fiat_value.as_deref(), Some("20.00"));
+        assert_eq!(summary.blockchains.len(), 2);
+        assert_eq!(summary.total_fiat_value, Some(candid::Nat::from(2_000u64)));
+        assert_eq!(summary.formatted_total_fiat_value.as_deref(), Some("20.00"));
+        assert_eq!(summary.accounts_without_balance, 1);
+        assert!(!summary.is_complete);
+
+        assert!(ctx
+            .service
+            .get_portfolio_summary(
+                GetPortfolioSummaryInput {
+                    currency: Some("EUR".to_string()),
+                },
+                &call_context,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn list_icrc1_accounts_only_returns_readable_accounts() {
+        let ctx = setup();
+        let mut readable = mock_account();
+        readable.id = [1; 16];
+        let mut hidden = mock_account();
+        hidden.id = [2; 16];
+
+        ctx.repository.insert(readable.to_key(), readable.clone());
+        ctx.repository.insert(hidden.to_key(), hidden.clone());
+
+        let permission = Permission::new(
+            Allow::users(vec![ctx.caller_user.id]),
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(readable.id))),
+        );
+        PermissionRepository::default().insert(permission.key(), permission);
+
+        let accounts = ctx
+            .service
+            .list_icrc1_accounts(&CallContext::new(ctx.caller_user.identities[0]));
+
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].id, readable.id);
+
+        let icrc1_account = accounts[0].to_icrc1_account_dto();
+        assert_eq!(
+            icrc1_account.subaccount,
+            Some(InternetComputer::subaccount_from_station_account_id(&readable.id).to_vec())
+        );
+    }
+
+    #[test]
+    fn generate_payment_request_for_icp_account() {
+        let ctx = setup();
+        let account = mock_account();
+
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let payment_request = ctx
+            .service
+            .generate_payment_request(GeneratePaymentRequestInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                amount: Some(candid::Nat::from(100_000_000u64)),
+                memo: Some("42".to_string()),
+            })
+            .unwrap();
+
+        assert!(payment_request
+            .uri
+            .starts_with("icp:ryjl3-tyaaa-aaaaa-aaaba-cai/transfer?to="));
+        assert!(payment_request.uri.ends_with("&amount=100000000&memo=42"));
+        assert_eq!(payment_request.blockchain, "icp");
+        assert_eq!(payment_request.symbol, account.symbol);
+
+        ctx.service
+            .generate_payment_request(GeneratePaymentRequestInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                amount: None,
+                memo: Some("not a number".to_string()),
+            })
+            .expect_err("the ICP ledger only supports numeric memos");
+    }
+
+    #[test]
+    fn get_balance_history_keeps_the_last_snapshot_of_each_period() {
+        let ctx = setup();
+        let account = mock_account();
+
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        for (timestamp, balance) in [
+            ("2024-01-15T00:00:00Z", 1u64),
+            ("2024-01-31T00:00:00Z", 2),
+            ("2024-02-29T00:00:00Z", 3),
+            ("2024-03-01T00:00:00Z", 4),
+        ] {
+            ACCOUNT_BALANCE_SNAPSHOT_REPOSITORY.insert(
+                AccountBalanceSnapshotKey {
+                    account_id: account.id,
+                    timestamp: rfc3339_to_timestamp(timestamp),
+                },
+                AccountBalanceSnapshot {
+                    balance: candid::Nat::from(balance),
+                },
+            );
+        }
+
+        let history = ctx
+            .service
+            .get_balance_history(GetBalanceHistoryInput {
+                account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                from_dt: Some("2024-01-01T00:00:00Z".to_string()),
+                to_dt: Some("2024-02-29T23:59:59Z".to_string()),
+                granularity: Some(station_api::BalanceHistoryGranularityDTO::Monthly),
+            })
+            .unwrap();
+
+        assert_eq!(
+            history
+                .snapshots
+                .iter()
+                .map(|snapshot| (snapshot.timestamp.as_str(), snapshot.balance.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("2024-01-31T00:00:00Z", candid::Nat::from(2u64)),
+                ("2024-02-29T00:00:00Z", candid::Nat::from(3u64)),
+            ]
+        );
+    }
+
+    #[test]
+    fn links_transactions_to_submitted_transfers() {
+        let mut transfer = mock_transfer();
+        transfer.to_address = "to".to_string();
+        transfer.register_submission_attempt(100);
+
+        let mut reconciled_transfer = mock_transfer();
+        reconciled_transfer.register_submission_attempt(200);
+        reconciled_transfer.mark_reconciled(7);
+
+        let transfers = vec![transfer.clone(), reconciled_transfer.clone()];
+        let mut transaction = BlockchainTransaction {
+            id: "1".to_string(),
+            from_address: Some("from".to_string()),
+            to_address: Some("to".to_string()),
+            amount: transfer.amount.0.clone(),
+            fee: None,
+            memo: None,
+            created_at: Some(100),
+            timestamp: Some(101),
+        };
+
+        assert_eq!(
+            AccountService::find_transaction_transfer(&transaction, &transfers),
+            Some(transfer.id)
+        );
+
+        transaction.id = "7".to_string();
+        transaction.created_at = Some(200);
+        assert_eq!(
+            AccountService::find_transaction_transfer(&transaction, &transfers),
+            Some(reconciled_transfer.id)
+        );
+
+        // incoming transactions are not linked to any transfer
+        transaction.id = "8".to_string();
+        transaction.created_at = Some(300);
+        assert_eq!(
+            AccountService::find_transaction_transfer(&transaction, &transfers),
+            None
+        );
+    }
 }
@@ -15,10 +15,19 @@ use crate::{
     },
     repositories::{AddressBookRepository, AddressBookWhereClause, ADDRESS_BOOK_REPOSITORY},
 };
+use candid::Principal;
 use lazy_static::lazy_static;
-use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
-use station_api::PaginationInput;
-use std::sync::Arc;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::ModelValidator,
+    repository::Repository,
+    types::{Timestamp, UUID},
+};
+use station_api::{AddressBookEntryDTO, PaginationInput};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use uuid::Uuid;
 
 lazy_static! {
@@ -87,6 +96,8 @@ impl AddressBookService {
                 addresses: input.addresses,
                 blockchain: input.blockchain,
                 labels: input.labels,
+                search_term: input.search_term,
+                address_owners: input.address_owners,
             });
 
         Ok(paginated_items(PaginatedItemsArgs {
@@ -149,6 +160,112 @@ impl AddressBookService {
         Ok(entry)
     }
 
+    /// Syncs the entries published by the address book source into the address book.
+    ///
+    /// Conflicts are resolved in favour of the entries that are already in the address book, a published entry
+    /// is skipped if its address is used by an entry managed by this station or synced from another source.
+    /// Only the entries that the source manages itself are synced, so that entries are not propagated through
+    /// chains of sources, and the synced entries that are no longer published by the source are removed.
+    pub async fn sync_source_entries(
+        &self,
+        source_id: Principal,
+        published_entries: Vec<AddressBookEntryDTO>,
+        synced_at: Timestamp,
+    ) -> AddressBookSyncSummary {
+        let mut summary = AddressBookSyncSummary::default();
+        let mut synced_entries: HashMap<UUID, AddressBookEntry> = self
+            .address_book_repository
+            .find_by_origin_source(&source_id)
+            .into_iter()
+            .filter_map(|entry| {
+                let source_entry_id = entry.origin.as_ref()?.source_entry_id;
+
+                Some((source_entry_id, entry))
+            })
+            .collect();
+        let mut published_entry_ids = HashSet::new();
+
+        for published_entry in published_entries {
+            if published_entry.origin.is_some() {
+                summary.skipped += 1;
+                continue;
+            }
+
+            let Ok(mut entry) = AddressBookMapper::from_published_dto(
+                published_entry,
+                *generate_uuid_v4().await.as_bytes(),
+                source_id,
+                synced_at,
+            ) else {
+                summary.skipped += 1;
+                continue;
+            };
+
+            let source_entry_id = entry
+                .origin
+                .as_ref()
+                .map(|origin| origin.source_entry_id)
+                .unwrap_or_default();
+
+            if entry.validate().is_err() || !published_entry_ids.insert(source_entry_id) {
+                summary.skipped += 1;
+                continue;
+            }
+
+            let current = synced_entries.remove(&source_entry_id);
+            if let Some(current) = &current {
+                entry.id = current.id;
+            }
+
+            let conflicting_entry = self
+                .address_book_repository
+                .find_by_address(entry.blockchain.clone(), entry.address.clone())
+                .filter(|other| other.id != entry.id);
+
+            match (current, conflicting_entry) {
+                (None, Some(_)) => summary.skipped += 1,
+                (Some(current), Some(_)) => {
+                    // the address was changed by the source to one that is already in use
+                    self.address_book_repository.remove(&current.to_key());
+                    summary.removed += 1;
+                }
+                (Some(current), None) => {
+                    if is_same_published_entry(&current, &entry) {
+                        continue;
+                    }
+
+                    self.address_book_repository.insert(entry.to_key(), entry);
+                    summary.updated += 1;
+                }
+                (None, None) => {
+                    self.address_book_repository.insert(entry.to_key(), entry);
+                    summary.added += 1;
+                }
+            }
+        }
+
+        // the remaining entries are no longer published by the source
+        for entry in synced_entries.into_values() {
+            self.address_book_repository.remove(&entry.to_key());
+            summary.removed += 1;
+        }
+
+        summary
+    }
+
+    /// Removes the synced entries of the sources that are no longer configured.
+    pub fn remove_entries_of_unknown_sources(&self, source_ids: &[Principal]) -> usize {
+        let mut removed = 0;
+        for entry in self.address_book_repository.find_synced() {
+            if !source_ids.iter().any(|id| entry.is_synced_from(id)) {
+                self.address_book_repository.remove(&entry.to_key());
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
     /// Removes an existing address book entry.
     pub async fn remove_entry(
         &self,
@@ -162,6 +279,24 @@ impl AddressBookService {
     }
 }
 
+/// The outcome of syncing the entries published by an address book source.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AddressBookSyncSummary {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+    pub skipped: usize,
+}
+
+/// Checks if the synced entry already has the published content, in which case it is not updated.
+fn is_same_published_entry(current: &AddressBookEntry, published: &AddressBookEntry) -> bool {
+    current.address_owner == published.address_owner
+        && current.address == published.address
+        && current.blockchain == published.blockchain
+        && current.labels == published.labels
+        && current.metadata == published.metadata
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,4 +466,103 @@ mod tests {
             .get_entry_by_id(&address_book_entry.id)
             .unwrap_err();
     }
+
+    fn mock_published_entry(id: u128, address: &str) -> AddressBookEntryDTO {
+        AddressBookEntryDTO {
+            id: Uuid::from_u128(id).hyphenated().to_string(),
+            address_owner: "counterparty".to_string(),
+            address: address.to_string(),
+            blockchain: "icp".to_string(),
+            labels: vec!["verified".to_string()],
+            metadata: vec![],
+            last_modification_timestamp: "2024-01-01T00:00:00Z".to_string(),
+            origin: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn sync_source_entries_tracks_origin_and_keeps_local_entries() {
+        let ctx = setup();
+        let source_id = Principal::from_slice(&[7; 29]);
+
+        let mut local_entry = mock_address_book_entry();
+        local_entry.address = "local".to_string();
+        ctx.repository
+            .insert(local_entry.to_key(), local_entry.to_owned());
+
+        let mut forwarded_entry = mock_published_entry(3, "forwarded");
+        forwarded_entry.origin = Some(station_api::AddressBookEntryOriginDTO {
+            source_id: Principal::from_slice(&[8; 29]),
+            source_entry_id: Uuid::from_u128(1).hyphenated().to_string(),
+            synced_at: "2024-01-01T00:00:00Z".to_string(),
+        });
+
+        let summary = ctx
+            .service
+            .sync_source_entries(
+                source_id,
+                vec![
+                    mock_published_entry(1, "shared"),
+                    mock_published_entry(2, "local"),
+                    forwarded_entry,
+                ],
+                10,
+            )
+            .await;
+
+        assert_eq!(
+            summary,
+            AddressBookSyncSummary {
+                added: 1,
+                updated: 0,
+                removed: 0,
+                skipped: 2,
+            }
+        );
+
+        let synced_entry = ctx
+            .repository
+            .find_by_address(Blockchain::InternetComputer, "shared".to_string())
+            .unwrap();
+        let origin = synced_entry.origin.clone().unwrap();
+        assert_eq!(origin.source_id, source_id);
+        assert_eq!(origin.source_entry_id, *Uuid::from_u128(1).as_bytes());
+        assert_eq!(origin.synced_at, 10);
+
+        // the local entry is kept as is
+        assert_eq!(ctx.repository.get(&local_entry.to_key()), Some(local_entry));
+
+        // the entry is updated in place and removed once it is no longer published
+        let mut updated_entry = mock_published_entry(1, "shared");
+        updated_entry.address_owner = "renamed".to_string();
+        let summary = ctx
+            .service
+            .sync_source_entries(source_id, vec![updated_entry], 20)
+            .await;
+        assert_eq!(summary.updated, 1);
+
+        let entry = ctx.repository.get(&synced_entry.to_key()).unwrap();
+        assert_eq!(entry.address_owner, "renamed");
+
+        let summary = ctx.service.sync_source_entries(source_id, vec![], 30).await;
+        assert_eq!(summary.removed, 1);
+        assert!(ctx.repository.get(&synced_entry.to_key()).is_none());
+    }
+
+    #[tokio::test]
+    async fn remove_entries_of_unknown_sources() {
+        let ctx = setup();
+        let source_id = Principal::from_slice(&[7; 29]);
+
+        ctx.service
+            .sync_source_entries(source_id, vec![mock_published_entry(1, "shared")], 10)
+            .await;
+
+        assert_eq!(
+            ctx.service.remove_entries_of_unknown_sources(&[source_id]),
+            0
+        );
+        assert_eq!(ctx.service.remove_entries_of_unknown_sources(&[]), 1);
+        assert!(ctx.repository.find_synced().is_empty());
+    }
 }
@@ -0,0 +1,202 @@
+use crate::{
+    core::{read_system_info, CallContext},
+    errors::BlobError,
+    models::{Blob, BlobId},
+    repositories::{BlobRepository, BLOB_REPOSITORY},
+    services::{UserService, SYSTEM_SERVICE, USER_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use sha2::{Digest, Sha256};
+use station_api::UploadBlobInput;
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref BLOB_SERVICE: Arc<BlobService> = Arc::new(BlobService::new(
+        Arc::clone(&BLOB_REPOSITORY),
+        Arc::clone(&USER_SERVICE),
+    ));
+}
+
+/// Keeps the small binary files of the station (e.g. the logos of the station and of its assets), which are
+/// referenced by the branding of the station and served through its HTTP gateway.
+#[derive(Default, Debug)]
+pub struct BlobService {
+    blob_repository: Arc<BlobRepository>,
+    user_service: Arc<UserService>,
+}
+
+impl BlobService {
+    pub fn new(blob_repository: Arc<BlobRepository>, user_service: Arc<UserService>) -> Self {
+        Self {
+            blob_repository,
+            user_service,
+        }
+    }
+
+    /// Returns the blob by id.
+    pub fn get_blob(&self, id: &BlobId) -> ServiceResult<Blob> {
+        let blob = self.blob_repository.get(id).ok_or(BlobError::NotFound {
+            id: Uuid::from_bytes(*id).hyphenated().to_string(),
+        })?;
+
+        Ok(blob)
+    }
+
+    /// Returns all the blobs ordered by id.
+    pub fn list_blobs(&self) -> Vec<Blob> {
+        self.blob_repository.list()
+    }
+
+    /// Returns the size of the content of all the blobs.
+    pub fn total_size(&self) -> usize {
+        self.blob_repository.total_size()
+    }
+
+    /// Stores the content as a new blob on behalf of the caller, within the size limits of the blobs.
+    pub fn upload_blob(&self, input: UploadBlobInput, ctx: &CallContext) -> ServiceResult<Blob> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let caller_user = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let blob = Blob {
+            id: *Uuid::new_v4().as_bytes(),
+            content_type: input.content_type.trim().to_lowercase(),
+            sha256: Sha256::digest(&input.content).to_vec(),
+            content: input.content,
+            uploaded_by: caller_user.id,
+            created_at: ctx.now(),
+        };
+
+        blob.validate()?;
+
+        if self.total_size() + blob.content.len() > Blob::MAX_TOTAL_SIZE_BYTES {
+            Err(BlobError::StorageFull {
+                max_total_size_bytes: Blob::MAX_TOTAL_SIZE_BYTES,
+            })?;
+        }
+
+        self.blob_repository.insert(blob.to_key(), blob.to_owned());
+
+        Ok(blob)
+    }
+
+    /// Removes the blob, unless it is the logo of the station or of one of its assets.
+    pub fn remove_blob(&self, id: &BlobId) -> ServiceResult<Blob> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let blob = self.get_blob(id)?;
+
+        if read_system_info().get_branding().references(id) {
+            Err(BlobError::InUse {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?;
+        }
+
+        self.blob_repository.remove(id);
+
+        Ok(blob)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{test_utils, write_system_info},
+        models::{
+            system::{BrandingConfig, StationDecommission},
+            user_test_utils::mock_user,
+        },
+        repositories::USER_REPOSITORY,
+    };
+    use candid::Principal;
+
+    fn setup() -> CallContext {
+        test_utils::init_canister_system();
+
+        let ctx = CallContext::new(Principal::from_slice(&[9; 29]));
+        let mut user = mock_user();
+        user.identities = vec![ctx.caller()];
+        USER_REPOSITORY.insert(user.to_key(), user);
+
+        ctx
+    }
+
+    fn logo(size: usize) -> UploadBlobInput {
+        UploadBlobInput {
+            content_type: "image/PNG".to_string(),
+            content: vec![1; size],
+        }
+    }
+
+    #[test]
+    fn upload_and_remove_blob() {
+        let ctx = setup();
+
+        let blob = BLOB_SERVICE.upload_blob(logo(10), &ctx).unwrap();
+
+        assert_eq!(blob.content_type, "image/png".to_string());
+        assert_eq!(BLOB_SERVICE.get_blob(&blob.id).unwrap(), blob);
+        assert_eq!(BLOB_SERVICE.total_size(), 10);
+
+        BLOB_SERVICE.remove_blob(&blob.id).unwrap();
+
+        assert!(BLOB_SERVICE.get_blob(&blob.id).is_err());
+        assert!(BLOB_SERVICE.remove_blob(&blob.id).is_err());
+    }
+
+    #[test]
+    fn upload_fails_when_the_storage_is_full() {
+        let ctx = setup();
+
+        for _ in 0..Blob::MAX_TOTAL_SIZE_BYTES / Blob::MAX_SIZE_BYTES {
+            BLOB_SERVICE
+                .upload_blob(logo(Blob::MAX_SIZE_BYTES), &ctx)
+                .unwrap();
+        }
+
+        let result = BLOB_SERVICE.upload_blob(logo(1), &ctx);
+
+        assert!(result.is_err());
+        assert_eq!(BLOB_SERVICE.total_size(), Blob::MAX_TOTAL_SIZE_BYTES);
+    }
+
+    #[test]
+    fn remove_fails_for_the_logo_of_the_station() {
+        let ctx = setup();
+        let blob = BLOB_SERVICE.upload_blob(logo(10), &ctx).unwrap();
+
+        let mut system_info = read_system_info();
+        system_info.set_branding(BrandingConfig {
+            logo_id: Some(blob.id),
+            asset_logos: Vec::new(),
+        });
+        write_system_info(system_info);
+
+        assert!(BLOB_SERVICE.remove_blob(&blob.id).is_err());
+        assert!(BLOB_SERVICE.get_blob(&blob.id).is_ok());
+    }
+
+    #[test]
+    fn blobs_cannot_be_changed_once_decommissioned() {
+        let ctx = setup();
+        let blob = BLOB_SERVICE.upload_blob(logo(10), &ctx).unwrap();
+
+        let mut system_info = read_system_info();
+        system_info.set_decommission(StationDecommission {
+            request_id: [1; 16],
+            reason: None,
+            decommissioned_at: 0,
+        });
+        write_system_info(system_info);
+
+        let result = BLOB_SERVICE.upload_blob(logo(10), &ctx);
+
+        assert_eq!(result.unwrap_err().code, "STATION_DECOMMISSIONED");
+        assert!(BLOB_SERVICE.remove_blob(&blob.id).is_err());
+        assert_eq!(BLOB_SERVICE.list_blobs(), vec![blob]);
+    }
+}
@@ -1,13 +1,24 @@
-use crate::{errors::ChangeCanisterError, models::CanisterInstallMode};
+use crate::{
+    errors::ChangeCanisterError,
+    log_warn,
+    models::{CanisterInstallMode, ChunkedWasmModule},
+};
 use candid::Principal;
 use ic_cdk::api::management_canister::{
-    main::{self as mgmt, InstallCodeArgument},
+    main::{
+        self as mgmt, ChunkHash, ClearChunkStoreArgument, InstallChunkedCodeArgument,
+        InstallCodeArgument, UploadChunkArgument,
+    },
     provisional::CanisterIdRecord,
 };
 use lazy_static::lazy_static;
 use orbit_essentials::api::ServiceResult;
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
+/// The size of the chunks that a module is uploaded in, the maximum of the chunk store.
+const MODULE_CHUNK_SIZE: usize = 1024 * 1024;
+
 lazy_static! {
     pub static ref CHANGE_CANISTER_SERVICE: Arc<ChangeCanisterService> =
         Arc::new(ChangeCanisterService::new());
@@ -29,44 +40,147 @@ impl ChangeCanisterService {
         module: &[u8],
         arg: Option<Vec<u8>>,
     ) -> ServiceResult<(), ChangeCanisterError> {
-        use candid::Encode;
+        self.stop_canister(canister_id).await?;
 
-        // Stop canister
-        let stop_result = mgmt::stop_canister(CanisterIdRecord {
+        // Install or upgrade canister
+        let install_code_result = mgmt::install_code(InstallCodeArgument {
+            mode: mode.into(),
             canister_id: canister_id.to_owned(),
+            wasm_module: module.to_owned(),
+            arg: arg.unwrap_or(default_arg()),
         })
         .await
         .map_err(|(_, err)| ChangeCanisterError::Failed {
             reason: err.to_string(),
         });
 
-        if stop_result.is_err() {
-            // Restart canister if the stop did not succeed (its possible the canister did stop running)
-            mgmt::start_canister(CanisterIdRecord {
-                canister_id: canister_id.to_owned(),
+        // Restart canister (regardless of whether the upgrade succeeded or not)
+        self.start_canister(canister_id).await?;
+
+        install_code_result
+    }
+
+    /// Execute an install or upgrade of a canister with a module that was uploaded to the chunk
+    /// store of another canister.
+    pub async fn install_canister_from_chunks(
+        &self,
+        canister_id: Principal,
+        mode: CanisterInstallMode,
+        module: &ChunkedWasmModule,
+        arg: Option<Vec<u8>>,
+    ) -> ServiceResult<(), ChangeCanisterError> {
+        self.stop_canister(canister_id).await?;
+
+        let install_code_result = mgmt::install_chunked_code(InstallChunkedCodeArgument {
+            mode: mode.into(),
+            target_canister: canister_id.to_owned(),
+            store_canister: Some(module.store_canister),
+            chunk_hashes_list: module
+                .chunk_hashes
+                .iter()
+                .map(|hash| ChunkHash { hash: hash.clone() })
+                .collect(),
+            wasm_module_hash: module.wasm_module_hash.clone(),
+            arg: arg.unwrap_or(default_arg()),
+        })
+        .await
+        .map_err(|(_, err)| ChangeCanisterError::Failed {
+            reason: err.to_string(),
+        });
+
+        self.start_canister(canister_id).await?;
+
+        install_code_result
+    }
+
+    /// Execute an install or upgrade of a canister by uploading the module to its own chunk store
+    /// first, so that the install message is not limited by the size of the module.
+    ///
+    /// The module itself still travels inline with the request that triggers the install, so it
+    /// remains bound by the ingress message limit of 2 MiB.
+    ///
+    /// The station must be a controller of the canister, the chunk store is cleared afterwards.
+    pub async fn install_canister_in_chunks(
+        &self,
+        canister_id: Principal,
+        mode: CanisterInstallMode,
+        module: &[u8],
+        arg: Option<Vec<u8>>,
+    ) -> ServiceResult<(), ChangeCanisterError> {
+        // chunks that were left behind by a previous install would otherwise count towards the limit
+        self.clear_chunk_store(canister_id).await?;
+
+        let mut chunk_hashes = Vec::new();
+        for chunk in module.chunks(MODULE_CHUNK_SIZE) {
+            let (chunk_hash,) = mgmt::upload_chunk(UploadChunkArgument {
+                canister_id,
+                chunk: chunk.to_vec(),
             })
             .await
             .map_err(|(_, err)| ChangeCanisterError::Failed {
-                reason: err.to_string(),
+                reason: format!("failed to upload module chunk: {}", err),
             })?;
 
-            return stop_result;
+            chunk_hashes.push(chunk_hash.hash);
         }
 
-        // Install or upgrade canister
-        let default_bytes = Encode!(&()).unwrap();
-        let install_code_result = mgmt::install_code(InstallCodeArgument {
-            mode: mode.into(),
+        let chunked_module = ChunkedWasmModule {
+            store_canister: canister_id,
+            chunk_hashes,
+            wasm_module_hash: Sha256::digest(module).to_vec(),
+        };
+
+        let install_result = self
+            .install_canister_from_chunks(canister_id, mode, &chunked_module, arg)
+            .await;
+
+        // the outcome of the install is what matters, leftover chunks are cleared by the next install
+        if let Err(err) = self.clear_chunk_store(canister_id).await {
+            log_warn!(
+                "Failed to clear the chunk store of canister {} after the install: {}",
+                canister_id,
+                err
+            );
+        }
+
+        install_result
+    }
+
+    async fn clear_chunk_store(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<(), ChangeCanisterError> {
+        mgmt::clear_chunk_store(ClearChunkStoreArgument { canister_id })
+            .await
+            .map_err(|(_, err)| ChangeCanisterError::Failed {
+                reason: format!("failed to clear the chunk store: {}", err),
+            })
+    }
+
+    async fn stop_canister(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<(), ChangeCanisterError> {
+        let stop_result = mgmt::stop_canister(CanisterIdRecord {
             canister_id: canister_id.to_owned(),
-            wasm_module: module.to_owned(),
-            arg: arg.unwrap_or(default_bytes),
         })
         .await
         .map_err(|(_, err)| ChangeCanisterError::Failed {
             reason: err.to_string(),
         });
 
-        // Restart canister (regardless of whether the upgrade succeeded or not)
+        if stop_result.is_err() {
+            // Restart canister if the stop did not succeed (its possible the canister did stop running)
+            self.start_canister(canister_id).await?;
+        }
+
+        stop_result
+    }
+
+    async fn start_canister(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<(), ChangeCanisterError> {
         mgmt::start_canister(CanisterIdRecord {
             canister_id: canister_id.to_owned(),
         })
@@ -75,6 +189,13 @@ impl ChangeCanisterService {
             reason: err.to_string(),
         })?;
 
-        install_code_result
+        Ok(())
     }
 }
+
+/// The argument that is passed to the canister if none is given.
+fn default_arg() -> Vec<u8> {
+    use candid::Encode;
+
+    Encode!(&()).unwrap()
+}
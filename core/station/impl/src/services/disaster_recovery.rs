@@ -8,6 +8,7 @@ use super::{SystemService, UserService, USER_SERVICE};
 use crate::{
     core::observer::Observer,
     errors::DisasterRecoveryError,
+    log_error,
     models::{Account, User, UserStatus},
     repositories::{AccountRepository, ACCOUNT_REPOSITORY},
     services::SYSTEM_SERVICE,
@@ -104,10 +105,10 @@ impl DisasterRecoveryService {
 
     pub async fn sync_all(&self) {
         if let Err(error) = DISASTER_RECOVERY_SERVICE.sync_committee().await {
-            crate::core::ic_cdk::api::print(format!("Failed to sync committee: {}", error,));
+            log_error!("Failed to sync committee: {}", error);
         }
         if let Err(error) = DISASTER_RECOVERY_SERVICE.sync_accounts().await {
-            crate::core::ic_cdk::api::print(format!("Failed to sync accounts: {}", error,));
+            log_error!("Failed to sync accounts: {}", error);
         }
     }
 }
@@ -140,10 +141,7 @@ pub fn disaster_recovery_observes_insert_user(observer: &mut Observer<(User, Opt
             {
                 crate::core::ic_cdk::spawn(async {
                     if let Err(error) = DISASTER_RECOVERY_SERVICE.sync_committee().await {
-                        crate::core::ic_cdk::api::print(format!(
-                            "Failed to sync committee: {}",
-                            error,
-                        ));
+                        log_error!("Failed to sync committee: {}", error);
                     }
                 });
             }
@@ -170,10 +168,7 @@ pub fn disaster_recovery_observes_remove_user(observer: &mut Observer<User>) {
             if user_was_previously_in_committee {
                 crate::core::ic_cdk::spawn(async {
                     if let Err(error) = DISASTER_RECOVERY_SERVICE.sync_committee().await {
-                        crate::core::ic_cdk::api::print(format!(
-                            "Failed to sync committee: {}",
-                            error,
-                        ));
+                        log_error!("Failed to sync committee: {}", error);
                     }
                 });
             }
@@ -192,7 +187,7 @@ pub fn disaster_recovery_observes_insert_account(
 
         crate::core::ic_cdk::spawn(async {
             if let Err(error) = DISASTER_RECOVERY_SERVICE.sync_accounts().await {
-                crate::core::ic_cdk::api::print(format!("Failed to sync accounts: {}", error,));
+                log_error!("Failed to sync accounts: {}", error);
             }
         });
     }));
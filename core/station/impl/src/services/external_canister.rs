@@ -1,11 +1,12 @@
 use super::permission::{PermissionService, PERMISSION_SERVICE};
 use super::request_policy::{RequestPolicyService, REQUEST_POLICY_SERVICE};
 use crate::core::authorization::Authorization;
-use crate::core::ic_cdk::api::print;
+use crate::core::ic_cdk::next_time;
 use crate::core::utils::{retain_accessible_resources, PaginatedData};
 use crate::core::validation::EnsureExternalCanister;
 use crate::core::CallContext;
 use crate::errors::ExternalCanisterError;
+use crate::log_warn;
 use crate::mappers::ExternalCanisterMapper;
 use crate::models::request_specifier::RequestSpecifier;
 use crate::models::resource::{
@@ -13,26 +14,29 @@ use crate::models::resource::{
     ExternalCanisterResourceAction, Resource, ValidationMethodResourceTarget,
 };
 use crate::models::{
-    AddRequestPolicyOperationInput, CanisterMethod, ConfigureExternalCanisterSettingsInput,
-    CreateExternalCanisterOperationInput, CreateExternalCanisterOperationKind,
-    DefiniteCanisterSettingsInput, EditPermissionOperationInput, EditRequestPolicyOperationInput,
-    ExternalCanister, ExternalCanisterAvailableFilters, ExternalCanisterCallPermission,
+    AddRequestPolicyOperationInput, CanisterCurrentState, CanisterMethod,
+    ConfigureExternalCanisterSettingsInput, CreateExternalCanisterOperationInput,
+    CreateExternalCanisterOperationKind, DefiniteCanisterSettingsInput,
+    EditPermissionOperationInput, EditRequestPolicyOperationInput, ExternalCanister,
+    ExternalCanisterAvailableFilters, ExternalCanisterCallPermission,
     ExternalCanisterCallRequestPolicyRule, ExternalCanisterCallerMethodsPrivileges,
     ExternalCanisterCallerPrivileges, ExternalCanisterChangeRequestPolicyRule,
-    ExternalCanisterEntryId, ExternalCanisterKey, ExternalCanisterPermissions,
-    ExternalCanisterPermissionsInput, ExternalCanisterRequestPolicies,
-    ExternalCanisterRequestPoliciesInput, RequestPolicy,
+    ExternalCanisterEntryId, ExternalCanisterKey, ExternalCanisterLogRecord,
+    ExternalCanisterPermissions, ExternalCanisterPermissionsInput, ExternalCanisterRequestPolicies,
+    ExternalCanisterRequestPoliciesInput, ExternalCanisterSnapshot, NativeCanisterSettings,
+    RequestPolicy,
 };
 use crate::repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY};
 use crate::repositories::{
-    ExternalCanisterRepository, ExternalCanisterWhereClause, RequestPolicyRepository,
-    EXTERNAL_CANISTER_REPOSITORY, REQUEST_POLICY_REPOSITORY,
+    ExternalCanisterLogRepository, ExternalCanisterRepository, ExternalCanisterWhereClause,
+    RequestPolicyRepository, EXTERNAL_CANISTER_LOG_REPOSITORY, EXTERNAL_CANISTER_REPOSITORY,
+    REQUEST_POLICY_REPOSITORY,
 };
-use candid::{Encode, Principal};
+use candid::{CandidType, Deserialize, Encode, Nat, Principal};
 use ic_cdk::api::call::call_raw;
 use ic_cdk::api::management_canister::main::{
-    self as mgmt, delete_canister, deposit_cycles, stop_canister, update_settings,
-    CanisterIdRecord, CanisterStatusResponse, CreateCanisterArgument, UpdateSettingsArgument,
+    self as mgmt, delete_canister, deposit_cycles, stop_canister, CanisterIdRecord,
+    CanisterStatusResponse, CreateCanisterArgument,
 };
 use lazy_static::lazy_static;
 use orbit_essentials::api::ServiceResult;
@@ -43,9 +47,9 @@ use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
 use station_api::{
     GetExternalCanisterFiltersInput, GetExternalCanisterFiltersResponseNameEntry,
-    ListExternalCanistersInput,
+    ListExternalCanistersInput, LogVisibilityDTO,
 };
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -57,11 +61,99 @@ lazy_static! {
             Arc::clone(&PERMISSION_REPOSITORY),
             Arc::clone(&REQUEST_POLICY_SERVICE),
             Arc::clone(&REQUEST_POLICY_REPOSITORY),
+            Arc::clone(&EXTERNAL_CANISTER_LOG_REPOSITORY),
         ));
 }
 
 const CREATE_CANISTER_CYCLES: u128 = 100_000_000_000; // the default fee of 100 B cycles
 
+/// The settings of the `update_settings` method of the management canister, the settings of the cdk
+/// don't support the log visibility yet.
+#[derive(CandidType, Debug)]
+struct UpdateSettingsArgs {
+    canister_id: Principal,
+    settings: CanisterSettingsArgs,
+}
+
+#[derive(CandidType, Debug)]
+struct CanisterSettingsArgs {
+    controllers: Option<Vec<Principal>>,
+    compute_allocation: Option<Nat>,
+    memory_allocation: Option<Nat>,
+    freezing_threshold: Option<Nat>,
+    reserved_cycles_limit: Option<Nat>,
+    log_visibility: Option<LogVisibilityDTO>,
+}
+
+/// The part of the `canister_status` reply of the management canister that holds the settings.
+#[derive(CandidType, Deserialize, Debug)]
+struct CanisterStatusSettingsReply {
+    settings: DefiniteCanisterSettingsReply,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+struct DefiniteCanisterSettingsReply {
+    controllers: Vec<Principal>,
+    compute_allocation: Nat,
+    memory_allocation: Nat,
+    freezing_threshold: Nat,
+    reserved_cycles_limit: Nat,
+    log_visibility: Option<LogVisibilityDTO>,
+}
+
+impl From<DefiniteCanisterSettingsReply> for NativeCanisterSettings {
+    fn from(settings: DefiniteCanisterSettingsReply) -> Self {
+        NativeCanisterSettings {
+            controllers: settings.controllers,
+            compute_allocation: settings.compute_allocation,
+            memory_allocation: settings.memory_allocation,
+            freezing_threshold: settings.freezing_threshold,
+            reserved_cycles_limit: settings.reserved_cycles_limit,
+            log_visibility: settings.log_visibility.map(Into::into),
+        }
+    }
+}
+
+/// The arguments of the snapshot methods of the management canister, which are not part of the cdk yet.
+#[derive(CandidType, Debug)]
+struct TakeCanisterSnapshotArgs {
+    canister_id: Principal,
+    replace_snapshot: Option<Vec<u8>>,
+}
+
+#[derive(CandidType, Debug)]
+struct LoadCanisterSnapshotArgs {
+    canister_id: Principal,
+    snapshot_id: Vec<u8>,
+    sender_canister_version: Option<u64>,
+}
+
+#[derive(CandidType, Debug)]
+struct DeleteCanisterSnapshotArgs {
+    canister_id: Principal,
+    snapshot_id: Vec<u8>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+struct CanisterSnapshotReply {
+    id: Vec<u8>,
+    taken_at_timestamp: u64,
+    total_size: u64,
+}
+
+/// The reply of the `fetch_canister_logs` method of the management canister, which is not part of the cdk yet.
+#[derive(CandidType, Deserialize, Debug)]
+struct FetchCanisterLogsReply {
+    canister_log_records: Vec<CanisterLogRecordReply>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+struct CanisterLogRecordReply {
+    idx: u64,
+    timestamp_nanos: u64,
+    content: Vec<u8>,
+}
+
 #[derive(Default, Debug)]
 pub struct ExternalCanisterService {
     external_canister_repository: Arc<ExternalCanisterRepository>,
@@ -69,6 +161,7 @@ pub struct ExternalCanisterService {
     permission_repository: Arc<PermissionRepository>,
     request_policy_service: Arc<RequestPolicyService>,
     request_policy_repository: Arc<RequestPolicyRepository>,
+    external_canister_log_repository: Arc<ExternalCanisterLogRepository>,
 }
 
 impl ExternalCanisterService {
@@ -81,6 +174,7 @@ impl ExternalCanisterService {
         permission_repository: Arc<PermissionRepository>,
         request_policy_service: Arc<RequestPolicyService>,
         request_policy_repository: Arc<RequestPolicyRepository>,
+        external_canister_log_repository: Arc<ExternalCanisterLogRepository>,
     ) -> Self {
         Self {
             external_canister_repository,
@@ -88,6 +182,7 @@ impl ExternalCanisterService {
             permission_repository,
             request_policy_service,
             request_policy_repository,
+            external_canister_log_repository,
         }
     }
 
@@ -350,11 +445,11 @@ impl ExternalCanisterService {
                 .flat_map(|id| match self.get_external_canister_by_canister_id(&id) {
                     Ok(entry) => Some(entry),
                     Err(error) => {
-                        print(format!(
+                        log_warn!(
                             "Failed to get external canister entry {}: {:?}",
                             id.to_text(),
                             error
-                        ));
+                        );
                         None
                     }
                 })
@@ -472,6 +567,22 @@ impl ExternalCanisterService {
         })
     }
 
+    /// Validates the input of an external canister that is yet to be created in a subnet.
+    ///
+    /// This makes sure that the model can be validated ahead of time without the canister id that
+    /// will be generated, to avoid creating a canister that can't be added to the system.
+    pub fn validate_new_external_canister(
+        &self,
+        input: &CreateExternalCanisterOperationInput,
+    ) -> ServiceResult<()> {
+        self.check_unique_name(input.name.as_str(), None)?;
+
+        ExternalCanisterMapper::from_create_input(Principal::anonymous(), input.clone())
+            .validate()?;
+
+        Ok(())
+    }
+
     /// Adds a new external canister to the system.
     ///
     /// Can be used to create another canister to a subnet or add an existing canister.
@@ -482,15 +593,13 @@ impl ExternalCanisterService {
         self.check_unique_name(input.name.clone().as_str(), None)?;
         let external_canister = match &input.kind {
             CreateExternalCanisterOperationKind::CreateNew(opts) => {
+                self.validate_new_external_canister(&input)?;
+
                 let mut external_canister = ExternalCanisterMapper::from_create_input(
-                    // The canister will be created below, but this makes sure that we can validate the
-                    // model ahead of time without the canister id that will be generated.
                     Principal::anonymous(),
                     input.clone(),
                 );
 
-                external_canister.validate()?;
-
                 // Create the canister in the subnet and update the external canister with the correct id.
                 external_canister.canister_id = self
                     .create_canister(opts.initial_cycles.map(|cycles| cycles as u128))
@@ -629,11 +738,11 @@ impl ExternalCanisterService {
             match policy.policy_id {
                 Some(policy_id) => {
                     if !current_policies.contains(&policy_id) {
-                        print(format!(
+                        log_warn!(
                             "Policy with id {} not found for external canister {}",
                             Uuid::from_bytes(policy_id).hyphenated(),
                             external_canister.canister_id.to_text()
-                        ));
+                        );
 
                         continue;
                     }
@@ -643,6 +752,9 @@ impl ExternalCanisterService {
                             policy_id,
                             rule: Some(policy.rule),
                             specifier: None,
+                            expected_version: None,
+                            checklist: None,
+                            exclude_requester: None,
                         },
                     )?;
                 }
@@ -653,6 +765,8 @@ impl ExternalCanisterService {
                             specifier: RequestSpecifier::ChangeExternalCanister(
                                 ExternalCanisterId::Canister(external_canister.canister_id),
                             ),
+                            checklist: Vec::new(),
+                            exclude_requester: false,
                         },
                     )?;
                 }
@@ -664,11 +778,11 @@ impl ExternalCanisterService {
             match policy.policy_id {
                 Some(policy_id) => {
                     if !current_policies.contains(&policy_id) {
-                        print(format!(
+                        log_warn!(
                             "Policy with id {} not found for external canister {}",
                             Uuid::from_bytes(policy_id).hyphenated(),
                             external_canister.canister_id.to_text()
-                        ));
+                        );
 
                         continue;
                     }
@@ -678,6 +792,9 @@ impl ExternalCanisterService {
                             policy_id,
                             rule: Some(policy.rule),
                             specifier: None,
+                            expected_version: None,
+                            checklist: None,
+                            exclude_requester: None,
                         },
                     )?;
                 }
@@ -709,6 +826,8 @@ impl ExternalCanisterService {
                                     validation_method: policy.validation_method,
                                 },
                             ),
+                            checklist: Vec::new(),
+                            exclude_requester: false,
                         },
                     )?;
                 }
@@ -746,6 +865,49 @@ impl ExternalCanisterService {
         Ok(external_canister)
     }
 
+    /// Returns all the external canisters that are registered in the station.
+    pub fn list_all_external_canisters(&self) -> Vec<ExternalCanister> {
+        self.external_canister_repository.list()
+    }
+
+    /// Records the hash of the module that is installed on the external canister.
+    ///
+    /// Canisters that are not registered in the station are ignored.
+    pub fn record_module_hash(
+        &self,
+        canister_id: &Principal,
+        module_hash: Option<Vec<u8>>,
+    ) -> ServiceResult<()> {
+        self.update_registered_canister(canister_id, |external_canister| {
+            external_canister.module_hash = module_hash;
+        })
+    }
+
+    /// Applies the changes to the registry entry of the external canister.
+    ///
+    /// Canisters that are not registered in the station are ignored.
+    fn update_registered_canister(
+        &self,
+        canister_id: &Principal,
+        update: impl FnOnce(&mut ExternalCanister),
+    ) -> ServiceResult<()> {
+        let Some(id) = self
+            .external_canister_repository
+            .find_by_canister_id(canister_id)
+        else {
+            return Ok(());
+        };
+
+        let mut external_canister = self.get_external_canister(&id)?;
+        update(&mut external_canister);
+        external_canister.modified_at = Some(next_time());
+
+        self.external_canister_repository
+            .insert(external_canister.key(), external_canister);
+
+        Ok(())
+    }
+
     /// Adds cycles to the external canister, the cycles are taken from the station's balance.
     pub async fn top_up_canister(&self, canister_id: Principal, cycles: u128) -> ServiceResult<()> {
         if let Err((err_code, err_msg)) =
@@ -773,6 +935,8 @@ impl ExternalCanisterService {
         let external_canister = self.get_external_canister(id)?;
         self.external_canister_repository
             .remove(&external_canister.key());
+        self.external_canister_log_repository
+            .remove_by_canister_id(external_canister.canister_id);
 
         // Removes the read, change & fund permissions.
         self.permission_service
@@ -812,11 +976,11 @@ impl ExternalCanisterService {
             .for_each(|policy_id| {
                 if let Err(err) = self.request_policy_service.remove_request_policy(policy_id) {
                     // This can be ignored to not block the deletion of the external canister.
-                    print(format!(
+                    log_warn!(
                         "Failed to remove request policy with id {}, reason: {}",
                         Uuid::from_bytes(*policy_id).hyphenated(),
                         err
-                    ));
+                    );
                 }
             });
 
@@ -840,12 +1004,12 @@ impl ExternalCanisterService {
         {
             // We simply log the error and continue, this is because stopped canisters will fail this call
             // but we still want to delete the canister.
-            print(format!(
+            log_warn!(
                 "Failed to stop canister {}, code: {:?} and reason: {:?}",
                 external_canister.canister_id.to_text(),
                 err_code,
                 err_msg
-            ));
+            );
         }
 
         if let Err((err_code, err_msg)) = delete_canister(CanisterIdRecord {
@@ -872,17 +1036,96 @@ impl ExternalCanisterService {
         Ok(external_canister)
     }
 
+    /// Reads the IC settings of the external canister.
+    ///
+    /// The station needs to be a controller of the target canister.
+    pub async fn canister_ic_settings(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<NativeCanisterSettings> {
+        let (reply,): (CanisterStatusSettingsReply,) = ic_cdk::call(
+            Principal::management_canister(),
+            "canister_status",
+            (CanisterIdRecord { canister_id },),
+        )
+        .await
+        .map_err(|(err_code, err_msg)| ExternalCanisterError::Failed {
+            reason: format!(
+                "Failed to read canister {} settings, code: {:?} and reason: {:?}",
+                canister_id.to_text(),
+                err_code,
+                err_msg
+            ),
+        })?;
+
+        Ok(reply.settings.into())
+    }
+
+    /// Reads the installed module and the IC settings of the canister.
+    ///
+    /// The settings are only read if the station is a controller of the canister, otherwise only the
+    /// module hash is read since it's public.
+    #[cfg(target_arch = "wasm32")]
+    pub async fn canister_current_state(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<CanisterCurrentState> {
+        let (info,) = mgmt::canister_info(mgmt::CanisterInfoRequest {
+            canister_id,
+            num_requested_changes: None,
+        })
+        .await
+        .map_err(|(err_code, err_msg)| ExternalCanisterError::Failed {
+            reason: format!(
+                "Failed to read canister {} info, code: {:?} and reason: {:?}",
+                canister_id.to_text(),
+                err_code,
+                err_msg
+            ),
+        })?;
+
+        Ok(CanisterCurrentState {
+            module_hash: info.module_hash,
+            settings: self.canister_ic_settings(canister_id).await.ok(),
+        })
+    }
+
+    /// The management canister can't be called outside of a canister, e.g. in the unit tests.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn canister_current_state(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<CanisterCurrentState> {
+        Err(ExternalCanisterError::Failed {
+            reason: format!(
+                "The state of canister {} can only be read by a canister",
+                canister_id.to_text()
+            ),
+        }
+        .into())
+    }
+
     /// Changes the IC settings of the external canister.
     pub async fn change_canister_ic_settings(
         &self,
         canister_id: Principal,
         settings: DefiniteCanisterSettingsInput,
     ) -> ServiceResult<()> {
-        if let Err((err_code, err_msg)) = update_settings(UpdateSettingsArgument {
+        let args = UpdateSettingsArgs {
             canister_id,
-            settings: settings.into(),
-        })
-        .await
+            settings: CanisterSettingsArgs {
+                controllers: settings.controllers,
+                compute_allocation: settings.compute_allocation,
+                memory_allocation: settings.memory_allocation,
+                freezing_threshold: settings.freezing_threshold,
+                reserved_cycles_limit: settings.reserved_cycles_limit,
+                log_visibility: settings.log_visibility.map(Into::into),
+            },
+        };
+
+        if let Err((err_code, err_msg)) =
+            ic_cdk::call::<_, ()>(Principal::management_canister(), "update_settings", (args,))
+                .await
         {
             Err(ExternalCanisterError::Failed {
                 reason: format!(
@@ -897,6 +1140,223 @@ impl ExternalCanisterService {
         Ok(())
     }
 
+    /// Lists the snapshots of the external canister from the management canister.
+    ///
+    /// The module hash is only known for the snapshots that were taken through the station.
+    pub async fn list_canister_snapshots(
+        &self,
+        canister_id: Principal,
+    ) -> ServiceResult<Vec<ExternalCanisterSnapshot>> {
+        let (snapshots,): (Vec<CanisterSnapshotReply>,) = ic_cdk::call(
+            Principal::management_canister(),
+            "list_canister_snapshots",
+            (CanisterIdRecord { canister_id },),
+        )
+        .await
+        .map_err(|(err_code, err_msg)| ExternalCanisterError::Failed {
+            reason: format!(
+                "Failed to list the snapshots of canister {}, code: {:?} and reason: {:?}",
+                canister_id.to_text(),
+                err_code,
+                err_msg
+            ),
+        })?;
+
+        let external_canister = self.get_external_canister_by_canister_id(&canister_id).ok();
+
+        Ok(snapshots
+            .into_iter()
+            .map(|snapshot| ExternalCanisterSnapshot {
+                module_hash: external_canister
+                    .as_ref()
+                    .and_then(|external_canister| external_canister.find_snapshot(&snapshot.id))
+                    .and_then(|known_snapshot| known_snapshot.module_hash.clone()),
+                snapshot_id: snapshot.id,
+                total_size: snapshot.total_size,
+                taken_at: snapshot.taken_at_timestamp,
+            })
+            .collect())
+    }
+
+    /// Fetches the logs of the external canister from the management canister.
+    ///
+    /// The fetched records are merged with the records previously retained by the station, since the
+    /// management canister only serves the most recent logs. When `retain` is set, the fetched records
+    /// are retained as well, up to `ExternalCanisterLogRecord::MAX_RETAINED_RECORDS_PER_CANISTER`.
+    pub async fn fetch_canister_logs(
+        &self,
+        canister_id: Principal,
+        retain: bool,
+    ) -> ServiceResult<Vec<ExternalCanisterLogRecord>> {
+        self.get_external_canister_by_canister_id(&canister_id)?;
+
+        let (reply,): (FetchCanisterLogsReply,) = ic_cdk::call(
+            Principal::management_canister(),
+            "fetch_canister_logs",
+            (CanisterIdRecord { canister_id },),
+        )
+        .await
+        .map_err(|(err_code, err_msg)| ExternalCanisterError::Failed {
+            reason: format!(
+                "Failed to fetch the logs of canister {}, code: {:?} and reason: {:?}",
+                canister_id.to_text(),
+                err_code,
+                err_msg
+            ),
+        })?;
+
+        let fetched_records: Vec<ExternalCanisterLogRecord> = reply
+            .canister_log_records
+            .into_iter()
+            .map(|record| ExternalCanisterLogRecord {
+                canister_id,
+                idx: record.idx,
+                timestamp: record.timestamp_nanos,
+                content: record.content,
+            })
+            .collect();
+
+        if retain {
+            self.external_canister_log_repository.retain_records(
+                canister_id,
+                fetched_records.clone(),
+                ExternalCanisterLogRecord::MAX_RETAINED_RECORDS_PER_CANISTER,
+            );
+        }
+
+        let mut records: BTreeMap<u64, ExternalCanisterLogRecord> = self
+            .external_canister_log_repository
+            .find_by_canister_id(canister_id)
+            .into_iter()
+            .map(|record| (record.idx, record))
+            .collect();
+
+        records.extend(
+            fetched_records
+                .into_iter()
+                .map(|record| (record.idx, record)),
+        );
+
+        Ok(records.into_values().collect())
+    }
+
+    /// Takes a snapshot of the external canister and records it in the registry.
+    ///
+    /// The replaced snapshot, if any, is deleted by the management canister.
+    pub async fn take_canister_snapshot(
+        &self,
+        canister_id: Principal,
+        replace_snapshot: Option<Vec<u8>>,
+    ) -> ServiceResult<ExternalCanisterSnapshot> {
+        let (reply,): (CanisterSnapshotReply,) = ic_cdk::call(
+            Principal::management_canister(),
+            "take_canister_snapshot",
+            (TakeCanisterSnapshotArgs {
+                canister_id,
+                replace_snapshot: replace_snapshot.clone(),
+            },),
+        )
+        .await
+        .map_err(|(err_code, err_msg)| ExternalCanisterError::Failed {
+            reason: format!(
+                "Failed to take a snapshot of canister {}, code: {:?} and reason: {:?}",
+                canister_id.to_text(),
+                err_code,
+                err_msg
+            ),
+        })?;
+
+        let module_hash = self
+            .get_external_canister_by_canister_id(&canister_id)
+            .ok()
+            .and_then(|external_canister| external_canister.module_hash);
+        let snapshot = ExternalCanisterSnapshot {
+            snapshot_id: reply.id,
+            module_hash,
+            total_size: reply.total_size,
+            taken_at: reply.taken_at_timestamp,
+        };
+
+        self.update_registered_canister(&canister_id, |external_canister| {
+            external_canister.add_snapshot(snapshot.clone(), replace_snapshot.as_deref());
+        })?;
+
+        Ok(snapshot)
+    }
+
+    /// Loads a snapshot into the external canister, restoring the module and the state of the canister.
+    pub async fn load_canister_snapshot(
+        &self,
+        canister_id: Principal,
+        snapshot_id: Vec<u8>,
+    ) -> ServiceResult<()> {
+        if let Err((err_code, err_msg)) = ic_cdk::call::<_, ()>(
+            Principal::management_canister(),
+            "load_canister_snapshot",
+            (LoadCanisterSnapshotArgs {
+                canister_id,
+                snapshot_id: snapshot_id.clone(),
+                sender_canister_version: Some(ic_cdk::api::canister_version()),
+            },),
+        )
+        .await
+        {
+            Err(ExternalCanisterError::Failed {
+                reason: format!(
+                    "Failed to load snapshot {} into canister {}, code: {:?} and reason: {:?}",
+                    hex::encode(&snapshot_id),
+                    canister_id.to_text(),
+                    err_code,
+                    err_msg
+                ),
+            })?;
+        }
+
+        // The restored module becomes the module known by the station, so that it is not reported as a drift.
+        match self.canister_status(CanisterIdRecord { canister_id }).await {
+            Ok(status) => self.record_module_hash(&canister_id, status.module_hash)?,
+            Err(err) => log_warn!(
+                "Failed to read the module hash of canister {} after loading a snapshot: {}",
+                canister_id.to_text(),
+                err
+            ),
+        }
+
+        Ok(())
+    }
+
+    /// Deletes a snapshot of the external canister and removes it from the registry.
+    pub async fn delete_canister_snapshot(
+        &self,
+        canister_id: Principal,
+        snapshot_id: Vec<u8>,
+    ) -> ServiceResult<()> {
+        if let Err((err_code, err_msg)) = ic_cdk::call::<_, ()>(
+            Principal::management_canister(),
+            "delete_canister_snapshot",
+            (DeleteCanisterSnapshotArgs {
+                canister_id,
+                snapshot_id: snapshot_id.clone(),
+            },),
+        )
+        .await
+        {
+            Err(ExternalCanisterError::Failed {
+                reason: format!(
+                    "Failed to delete snapshot {} of canister {}, code: {:?} and reason: {:?}",
+                    hex::encode(&snapshot_id),
+                    canister_id.to_text(),
+                    err_code,
+                    err_msg
+                ),
+            })?;
+        }
+
+        self.update_registered_canister(&canister_id, |external_canister| {
+            external_canister.remove_snapshot(&snapshot_id);
+        })
+    }
+
     /// Verifies that the name is unique among external canisters.
     ///
     /// If `skip_id` is provided, it will be ignored if the match would be the same.
@@ -1000,6 +1460,7 @@ mod tests {
                         rule: RequestPolicyRule::AutoApproved,
                     }],
                 },
+                repository_url: None,
                 kind: CreateExternalCanisterOperationKind::AddExisting(
                     CreateExternalCanisterOperationKindAddExisting {
                         canister_id: Principal::from_slice(&[10; 29]),
@@ -1067,6 +1528,8 @@ mod tests {
                 specifier: RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Canister(
                     Principal::from_slice(&[1; 29]),
                 )),
+                checklist: Vec::new(),
+                exclude_requester: false,
             })
             .unwrap();
 
@@ -1087,6 +1550,7 @@ mod tests {
                     }],
                     calls: Vec::new(),
                 },
+                repository_url: None,
                 kind: CreateExternalCanisterOperationKind::AddExisting(
                     CreateExternalCanisterOperationKindAddExisting {
                         canister_id: Principal::from_slice(&[10; 29]),
@@ -1140,6 +1604,7 @@ mod tests {
                         rule: RequestPolicyRule::AutoApproved,
                     }],
                 },
+                repository_url: None,
                 kind: CreateExternalCanisterOperationKind::AddExisting(
                     CreateExternalCanisterOperationKindAddExisting {
                         canister_id: Principal::from_slice(&[10; 29]),
@@ -1176,6 +1641,7 @@ mod tests {
                         change: Vec::new(),
                         calls: Vec::new(),
                     },
+                    repository_url: None,
                     kind: CreateExternalCanisterOperationKind::AddExisting(
                         CreateExternalCanisterOperationKindAddExisting {
                             canister_id: Principal::from_slice(&[i; 29]),
@@ -1210,6 +1676,7 @@ mod tests {
                         change: Vec::new(),
                         calls: Vec::new(),
                     },
+                    repository_url: None,
                     kind: CreateExternalCanisterOperationKind::AddExisting(
                         CreateExternalCanisterOperationKindAddExisting {
                             canister_id: Principal::from_slice(&[10; 29]),
@@ -1243,6 +1710,7 @@ mod tests {
                     change: Vec::new(),
                     calls: Vec::new(),
                 },
+                repository_url: None,
                 kind: CreateExternalCanisterOperationKind::AddExisting(
                     CreateExternalCanisterOperationKindAddExisting {
                         canister_id: Principal::from_slice(&[10; 29]),
@@ -1281,6 +1749,7 @@ mod tests {
                     change: Vec::new(),
                     calls: Vec::new(),
                 },
+                repository_url: None,
                 kind: CreateExternalCanisterOperationKind::AddExisting(
                     CreateExternalCanisterOperationKindAddExisting {
                         canister_id: Principal::from_slice(&[10; 29]),
@@ -1307,6 +1776,7 @@ mod tests {
                         change: Vec::new(),
                         calls: Vec::new(),
                     }),
+                    repository_url: None,
                 },
             )
             .unwrap();
@@ -1319,6 +1789,55 @@ mod tests {
         assert!(call_permission.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_record_module_hash() {
+        setup();
+        let canister = EXTERNAL_CANISTER_SERVICE
+            .add_external_canister(CreateExternalCanisterOperationInput {
+                name: "test".to_string(),
+                description: None,
+                labels: None,
+                permissions: ExternalCanisterPermissionsInput {
+                    read: Allow::authenticated(),
+                    change: Allow::authenticated(),
+                    calls: Vec::new(),
+                },
+                request_policies: ExternalCanisterRequestPoliciesInput {
+                    change: Vec::new(),
+                    calls: Vec::new(),
+                },
+                repository_url: Some("https://github.com/dfinity/orbit".to_string()),
+                kind: CreateExternalCanisterOperationKind::AddExisting(
+                    CreateExternalCanisterOperationKindAddExisting {
+                        canister_id: Principal::from_slice(&[10; 29]),
+                    },
+                ),
+            })
+            .await
+            .unwrap();
+
+        assert!(canister.module_hash.is_none());
+
+        EXTERNAL_CANISTER_SERVICE
+            .record_module_hash(&canister.canister_id, Some(vec![1; 32]))
+            .unwrap();
+
+        let updated_canister = EXTERNAL_CANISTER_SERVICE
+            .get_external_canister(&canister.id)
+            .unwrap();
+
+        assert_eq!(updated_canister.module_hash, Some(vec![1; 32]));
+        assert_eq!(
+            updated_canister.repository_url,
+            Some("https://github.com/dfinity/orbit".to_string())
+        );
+
+        // unregistered canisters are ignored
+        assert!(EXTERNAL_CANISTER_SERVICE
+            .record_module_hash(&Principal::from_slice(&[11; 29]), Some(vec![1; 32]))
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn finds_all_call_permissions() {
         setup();
@@ -1353,6 +1872,7 @@ mod tests {
                         change: Vec::new(),
                         calls: Vec::new(),
                     },
+                    repository_url: None,
                     kind: CreateExternalCanisterOperationKind::AddExisting(
                         CreateExternalCanisterOperationKindAddExisting {
                             canister_id: Principal::from_slice(&[i; 29]),
@@ -1403,6 +1923,7 @@ mod tests {
                             rule: RequestPolicyRule::AutoApproved,
                         }],
                     },
+                    repository_url: None,
                     kind: CreateExternalCanisterOperationKind::AddExisting(
                         CreateExternalCanisterOperationKindAddExisting {
                             canister_id: Principal::from_slice(&[i; 29]),
@@ -0,0 +1,244 @@
+use crate::{
+    core::{format_amount, ic_cdk::next_time, read_system_info},
+    errors::FiatValuationError,
+    models::system::FiatValuationConfig,
+    repositories::{AccountRepository, ACCOUNT_REPOSITORY},
+};
+use candid::{CandidType, Deserialize};
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use orbit_essentials::{api::ServiceResult, repository::Repository, types::Timestamp};
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
+
+/// The decimals of the fiat values, which are expressed in the cents of the currency.
+pub const FIAT_DECIMALS: u32 = 2;
+
+/// Formats a fiat value in the cents of the currency (e.g. `123456` is `1234.56`).
+pub fn format_fiat_value(value: &candid::Nat) -> String {
+    format_amount(value, FIAT_DECIMALS, FIAT_DECIMALS)
+}
+
+/// The arguments of the `get_fiat_prices` method of the price feed.
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct GetFiatPricesArgs {
+    symbols: Vec<String>,
+    currencies: Vec<String>,
+}
+
+/// A price returned by the `get_fiat_prices` method of the price feed.
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct FiatPriceEntry {
+    symbol: String,
+    currency: String,
+    rate: u64,
+    decimals: u32,
+}
+
+/// The price of one whole token in a fiat currency, which is `rate / 10^decimals`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FiatPrice {
+    pub rate: u64,
+    pub decimals: u32,
+    pub updated_at: Timestamp,
+}
+
+impl FiatPrice {
+    /// Returns the value of the amount of the token in the cents of the currency, rounded down.
+    pub fn value_of(&self, amount: &candid::Nat, token_decimals: u32) -> candid::Nat {
+        let ten = BigUint::from(10u32);
+        let value = &amount.0 * BigUint::from(self.rate) * ten.pow(FIAT_DECIMALS)
+            / ten.pow(token_decimals.saturating_add(self.decimals));
+
+        candid::Nat(value)
+    }
+}
+
+thread_local! {
+    /// The latest prices of the tokens by symbol and currency, they are kept in the heap memory since
+    /// the job fetches them again right after an upgrade.
+    static FIAT_PRICES: RefCell<HashMap<(String, String), FiatPrice>> = RefCell::new(HashMap::new());
+}
+
+lazy_static! {
+    pub static ref FIAT_VALUATION_SERVICE: Arc<FiatValuationService> =
+        Arc::new(FiatValuationService::new(Arc::clone(&ACCOUNT_REPOSITORY)));
+}
+
+/// Values the balances of the accounts in fiat currencies, with the prices that are regularly fetched
+/// from the price feed configured for the station.
+#[derive(Default, Debug)]
+pub struct FiatValuationService {
+    account_repository: Arc<AccountRepository>,
+}
+
+impl FiatValuationService {
+    pub fn new(account_repository: Arc<AccountRepository>) -> Self {
+        Self { account_repository }
+    }
+
+    /// Checks that the balances can be valued in the currency.
+    pub fn ensure_supported_currency(&self, currency: &str) -> ServiceResult<()> {
+        let system_info = read_system_info();
+        let config = system_info.get_fiat_valuation();
+        if *config == FiatValuationConfig::Disabled {
+            Err(FiatValuationError::Disabled)?
+        }
+
+        if !config.currencies().iter().any(|c| c == currency) {
+            Err(FiatValuationError::UnsupportedCurrency {
+                currency: currency.to_string(),
+                supported: config.currencies().join(", "),
+            })?
+        }
+
+        Ok(())
+    }
+
+    /// Returns the latest price of the token in the currency, unless it is too old to value the balances.
+    pub fn get_price(&self, symbol: &str, currency: &str, now: Timestamp) -> Option<FiatPrice> {
+        FIAT_PRICES.with(|prices| {
+            prices
+                .borrow()
+                .get(&(symbol.to_string(), currency.to_string()))
+                .filter(|price| {
+                    now.saturating_sub(price.updated_at) <= FiatValuationConfig::MAX_PRICE_AGE_NS
+                })
+                .cloned()
+        })
+    }
+
+    /// Records the latest price of the token in the currency.
+    pub fn record_price(&self, symbol: &str, currency: &str, price: FiatPrice) {
+        FIAT_PRICES.with(|prices| {
+            prices
+                .borrow_mut()
+                .insert((symbol.to_string(), currency.to_string()), price);
+        });
+    }
+
+    /// Fetches the prices of the tokens held by the accounts from the price feed, the prices that the feed
+    /// doesn't return are left as they were.
+    pub async fn refresh_prices(&self) -> ServiceResult<()> {
+        let FiatValuationConfig::Canister {
+            canister_id,
+            currencies,
+        } = read_system_info().get_fiat_valuation().clone()
+        else {
+            return Err(FiatValuationError::Disabled.into());
+        };
+
+        let symbols = self
+            .account_repository
+            .list()
+            .into_iter()
+            .map(|account| account.symbol)
+            .collect::<BTreeSet<_>>();
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        let (entries,) = ic_cdk::call::<_, (Vec<FiatPriceEntry>,)>(
+            canister_id,
+            "get_fiat_prices",
+            (GetFiatPricesArgs {
+                symbols: symbols.into_iter().collect(),
+                currencies: currencies.clone(),
+            },),
+        )
+        .await
+        .map_err(|(code, message)| FiatValuationError::PriceFeedFailed {
+            reason: format!("rejection_code: {:?}, err: {}", code, message),
+        })?;
+
+        let now = next_time();
+        for entry in entries
+            .into_iter()
+            .filter(|entry| currencies.contains(&entry.currency))
+        {
+            self.record_price(
+                &entry.symbol,
+                &entry.currency,
+                FiatPrice {
+                    rate: entry.rate,
+                    decimals: entry.decimals,
+                    updated_at: now,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils, write_system_info};
+
+    #[test]
+    fn values_the_amount_in_cents() {
+        // 12.34 USD per token
+        let price = FiatPrice {
+            rate: 1234,
+            decimals: 2,
+            updated_at: 0,
+        };
+
+        // 2.5 tokens with 8 decimals
+        assert_eq!(
+            price.value_of(&candid::Nat::from(250_000_000u64), 8),
+            candid::Nat::from(3085u64)
+        );
+        assert_eq!(
+            price.value_of(&candid::Nat::from(1u64), 8),
+            candid::Nat::from(0u64)
+        );
+    }
+
+    #[test]
+    fn old_prices_are_not_used() {
+        let service = FiatValuationService::default();
+        service.record_price(
+            "ICP",
+            "USD",
+            FiatPrice {
+                rate: 1000,
+                decimals: 2,
+                updated_at: 100,
+            },
+        );
+
+        assert!(service.get_price("ICP", "USD", 100).is_some());
+        assert!(service.get_price("ICP", "EUR", 100).is_none());
+        assert!(service
+            .get_price("ICP", "USD", 101 + FiatValuationConfig::MAX_PRICE_AGE_NS)
+            .is_none());
+    }
+
+    #[test]
+    fn only_the_configured_currencies_are_supported() {
+        let mut system_info = test_utils::init_canister_system();
+
+        let service = FiatValuationService::default();
+        assert_eq!(
+            service.ensure_supported_currency("USD").unwrap_err().code,
+            "DISABLED"
+        );
+
+        system_info.set_fiat_valuation(FiatValuationConfig::Canister {
+            canister_id: candid::Principal::from_slice(&[1; 29]),
+            currencies: vec!["USD".to_string()],
+        });
+        write_system_info(system_info);
+
+        assert!(service.ensure_supported_currency("USD").is_ok());
+        assert_eq!(
+            service.ensure_supported_currency("EUR").unwrap_err().code,
+            "UNSUPPORTED_CURRENCY"
+        );
+    }
+}
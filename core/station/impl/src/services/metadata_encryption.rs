@@ -0,0 +1,263 @@
+use crate::{
+    core::{ic_cdk::api::time, limiter::Limiter, read_system_info, CallContext},
+    errors::MetadataEncryptionError,
+    models::MetadataEncryptionConfig,
+};
+use candid::{CandidType, Deserialize, Principal};
+use lazy_static::lazy_static;
+use orbit_essentials::api::ServiceResult;
+use orbit_essentials::types::UUID;
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
+
+lazy_static! {
+    pub static ref METADATA_ENCRYPTION_SERVICE: Arc<MetadataEncryptionService> =
+        Arc::new(MetadataEncryptionService::default());
+}
+
+const KEY_RATE_LIMITER_RESOLUTION: Duration = Duration::from_secs(60);
+const KEY_RATE_LIMITER_TIME_WINDOW: Duration = Duration::from_secs(3600);
+const KEY_RATE_LIMITER_MAX_COUNT: u64 = 10; // 10 derived keys per user per hour
+
+thread_local! {
+    static KEY_RATE_LIMITER: RefCell<HashMap<UUID, Limiter>> = RefCell::new(HashMap::new());
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+enum VetKdCurve {
+    #[serde(rename = "bls12_381_g2")]
+    Bls12_381G2,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct VetKdKeyId {
+    curve: VetKdCurve,
+    name: String,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct VetKdPublicKeyArgs {
+    canister_id: Option<Principal>,
+    context: Vec<u8>,
+    key_id: VetKdKeyId,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct VetKdPublicKeyResult {
+    public_key: Vec<u8>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct VetKdDeriveKeyArgs {
+    input: Vec<u8>,
+    context: Vec<u8>,
+    key_id: VetKdKeyId,
+    transport_public_key: Vec<u8>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+struct VetKdDeriveKeyResult {
+    encrypted_key: Vec<u8>,
+}
+
+/// The key that encrypts the sensitive metadata, encrypted for the transport key of the caller.
+#[derive(Debug, Clone)]
+pub struct MetadataEncryptionKey {
+    pub encrypted_key: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub context: Vec<u8>,
+    pub input: Vec<u8>,
+}
+
+/// Hands out the vetKD derived key that the clients use to encrypt and decrypt the sensitive metadata,
+/// the station itself never has access to the derived key nor to the plaintexts.
+#[derive(Default, Debug)]
+pub struct MetadataEncryptionService {}
+
+impl MetadataEncryptionService {
+    /// The context of the derived key, it separates it from other keys derived by the station.
+    pub const KEY_CONTEXT: &'static [u8] = b"orbit-station:metadata-encryption";
+    /// The input of the derived key, all the users that can read the metadata share the same key.
+    pub const KEY_INPUT: &'static [u8] = b"v1";
+    /// The length of a compressed G1 point, which is the format of the transport public keys.
+    pub const TRANSPORT_PUBLIC_KEY_LENGTH: usize = 48;
+    /// The cycles attached to the key derivation, which covers its cost on the largest subnets.
+    pub const DERIVE_KEY_CYCLES: u128 = 26_153_846_153;
+
+    /// Derives the key for the transport key of the caller.
+    ///
+    /// Every derivation is paid with `DERIVE_KEY_CYCLES`, so the key is only handed out to the users of the
+    /// station (not to the auditors) and each user can only derive a few keys per hour, the clients are
+    /// expected to keep the key for the rest of their session.
+    pub async fn get_encryption_key(
+        &self,
+        transport_public_key: Vec<u8>,
+        ctx: &CallContext,
+    ) -> ServiceResult<MetadataEncryptionKey> {
+        let MetadataEncryptionConfig::VetKd { key_name, .. } =
+            read_system_info().get_metadata_encryption().clone()
+        else {
+            return Err(MetadataEncryptionError::Disabled.into());
+        };
+
+        if transport_public_key.len() != Self::TRANSPORT_PUBLIC_KEY_LENGTH {
+            return Err(MetadataEncryptionError::InvalidTransportPublicKey {
+                expected_length: Self::TRANSPORT_PUBLIC_KEY_LENGTH,
+            }
+            .into());
+        }
+
+        let user_id = ctx
+            .user()
+            .map(|user| user.id)
+            .ok_or(MetadataEncryptionError::NotAUser)?;
+
+        rate_limit_key_derivation(&user_id)?;
+
+        let key_id = VetKdKeyId {
+            curve: VetKdCurve::Bls12_381G2,
+            name: key_name,
+        };
+
+        let (public_key,) = ic_cdk::call::<_, (VetKdPublicKeyResult,)>(
+            Principal::management_canister(),
+            "vetkd_public_key",
+            (VetKdPublicKeyArgs {
+                canister_id: None,
+                context: Self::KEY_CONTEXT.to_vec(),
+                key_id: key_id.clone(),
+            },),
+        )
+        .await
+        .map_err(
+            |(code, message)| MetadataEncryptionError::KeyDerivationFailed {
+                reason: format!("rejection_code: {:?}, err: {}", code, message),
+            },
+        )?;
+
+        let (derived_key,) = ic_cdk::api::call::call_with_payment128::<_, (VetKdDeriveKeyResult,)>(
+            Principal::management_canister(),
+            "vetkd_derive_key",
+            (VetKdDeriveKeyArgs {
+                input: Self::KEY_INPUT.to_vec(),
+                context: Self::KEY_CONTEXT.to_vec(),
+                key_id,
+                transport_public_key,
+            },),
+            Self::DERIVE_KEY_CYCLES,
+        )
+        .await
+        .map_err(
+            |(code, message)| MetadataEncryptionError::KeyDerivationFailed {
+                reason: format!("rejection_code: {:?}, err: {}", code, message),
+            },
+        )?;
+
+        Ok(MetadataEncryptionKey {
+            encrypted_key: derived_key.encrypted_key,
+            public_key: public_key.public_key,
+            context: Self::KEY_CONTEXT.to_vec(),
+            input: Self::KEY_INPUT.to_vec(),
+        })
+    }
+}
+
+/// Counts a key derivation of the user, failing once the user derived too many keys within the time window.
+fn rate_limit_key_derivation(user_id: &UUID) -> Result<(), MetadataEncryptionError> {
+    let now = UNIX_EPOCH + Duration::from_nanos(time());
+
+    KEY_RATE_LIMITER.with(|limiters| {
+        let mut limiters = limiters.borrow_mut();
+        let limiter = limiters.entry(*user_id).or_insert_with(|| {
+            Limiter::new(KEY_RATE_LIMITER_RESOLUTION, KEY_RATE_LIMITER_TIME_WINDOW)
+        });
+
+        limiter.purge_old(now);
+        if limiter.get_count() >= KEY_RATE_LIMITER_MAX_COUNT {
+            return Err(MetadataEncryptionError::RateLimited);
+        }
+
+        limiter.add(now, 1);
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils, write_system_info};
+
+    #[tokio::test]
+    async fn fails_when_metadata_encryption_is_disabled() {
+        test_utils::init_canister_system();
+
+        let result = METADATA_ENCRYPTION_SERVICE
+            .get_encryption_key(
+                vec![0; MetadataEncryptionService::TRANSPORT_PUBLIC_KEY_LENGTH],
+                &CallContext::new(Principal::anonymous()),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "DISABLED".to_string());
+    }
+
+    #[tokio::test]
+    async fn fails_with_invalid_transport_public_key() {
+        test_utils::init_canister_system();
+        let mut system_info = read_system_info();
+        system_info.set_metadata_encryption(MetadataEncryptionConfig::VetKd {
+            key_name: "key_1".to_string(),
+            address_book_metadata_keys: vec![],
+        });
+        write_system_info(system_info);
+
+        let result = METADATA_ENCRYPTION_SERVICE
+            .get_encryption_key(vec![0; 32], &CallContext::new(Principal::anonymous()))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().code,
+            "INVALID_TRANSPORT_PUBLIC_KEY".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_for_callers_that_are_not_users() {
+        test_utils::init_canister_system();
+        let mut system_info = read_system_info();
+        system_info.set_metadata_encryption(MetadataEncryptionConfig::VetKd {
+            key_name: "key_1".to_string(),
+            address_book_metadata_keys: vec![],
+        });
+        write_system_info(system_info);
+
+        let result = METADATA_ENCRYPTION_SERVICE
+            .get_encryption_key(
+                vec![0; MetadataEncryptionService::TRANSPORT_PUBLIC_KEY_LENGTH],
+                &CallContext::new(Principal::from_slice(&[9; 29])),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err().code, "NOT_A_USER".to_string());
+    }
+
+    #[test]
+    fn rate_limits_the_key_derivations_of_a_user() {
+        let user_id = [1; 16];
+
+        for _ in 0..KEY_RATE_LIMITER_MAX_COUNT {
+            assert!(rate_limit_key_derivation(&user_id).is_ok());
+        }
+
+        assert_eq!(
+            rate_limit_key_derivation(&user_id),
+            Err(MetadataEncryptionError::RateLimited)
+        );
+        assert!(rate_limit_key_derivation(&[2; 16]).is_ok());
+    }
+}
@@ -37,3 +37,21 @@ pub mod permission;
 
 mod disaster_recovery;
 pub use disaster_recovery::*;
+
+mod station_event;
+pub use station_event::*;
+
+mod metadata_encryption;
+pub use metadata_encryption::*;
+
+mod usage;
+pub use usage::*;
+
+mod fiat_valuation;
+pub use fiat_valuation::*;
+
+mod principal_label;
+pub use principal_label::*;
+
+mod blob;
+pub use blob::*;
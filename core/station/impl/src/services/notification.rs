@@ -1,20 +1,28 @@
 use crate::{
     core::{generate_uuid_v4, ic_cdk::next_time, utils::SortDirection, CallContext},
     errors::NotificationError,
+    jobs::schedule_bridged_notification_delivery,
     mappers::HelperMapper,
-    models::{Notification, NotificationId, NotificationStatus, NotificationType, UserId},
+    models::{
+        Notification, NotificationBridge, NotificationId, NotificationOutboxEntry,
+        NotificationStatus, NotificationType, UserGroupId, UserId, UserStatus,
+    },
     repositories::{
-        NotificationFindByUserWhereClause, NotificationRepository, NotificationSortBy,
-        NOTIFICATION_REPOSITORY,
+        NotificationBridgeRepository, NotificationFindByUserWhereClause,
+        NotificationOutboxRepository, NotificationRepository, NotificationSortBy,
+        NOTIFICATION_BRIDGE_REPOSITORY, NOTIFICATION_OUTBOX_REPOSITORY, NOTIFICATION_REPOSITORY,
+        USER_REPOSITORY,
     },
-    services::{UserService, USER_SERVICE},
+    services::{UserService, SYSTEM_SERVICE, USER_SERVICE},
 };
 use lazy_static::lazy_static;
 use orbit_essentials::repository::Repository;
-use orbit_essentials::utils::rfc3339_to_timestamp;
-use orbit_essentials::{api::ServiceResult, model::ModelValidator};
-use station_api::{ListNotificationsInput, MarkNotificationsReadInput};
-use std::sync::Arc;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+};
+use station_api::{ListNotificationsInput, MarkNotificationsReadInput, SetNotificationBridgeInput};
+use std::{collections::BTreeSet, sync::Arc};
 use uuid::Uuid;
 
 lazy_static! {
@@ -22,6 +30,8 @@ lazy_static! {
         Arc::new(NotificationService::new(
             Arc::clone(&USER_SERVICE),
             Arc::clone(&NOTIFICATION_REPOSITORY),
+            Arc::clone(&NOTIFICATION_BRIDGE_REPOSITORY),
+            Arc::clone(&NOTIFICATION_OUTBOX_REPOSITORY),
         ));
 }
 
@@ -29,16 +39,26 @@ lazy_static! {
 pub struct NotificationService {
     user_service: Arc<UserService>,
     notification_repository: Arc<NotificationRepository>,
+    bridge_repository: Arc<NotificationBridgeRepository>,
+    outbox_repository: Arc<NotificationOutboxRepository>,
 }
 
 impl NotificationService {
+    /// The oldest pending notifications of a user are dropped once the limit is reached, e.g. when the
+    /// inbox canister is not reachable for a long time.
+    pub const MAX_PENDING_NOTIFICATIONS_PER_USER: usize = 100;
+
     pub fn new(
         user_service: Arc<UserService>,
         notification_repository: Arc<NotificationRepository>,
+        bridge_repository: Arc<NotificationBridgeRepository>,
+        outbox_repository: Arc<NotificationOutboxRepository>,
     ) -> Self {
         Self {
             user_service,
             notification_repository,
+            bridge_repository,
+            outbox_repository,
         }
     }
 
@@ -65,8 +85,8 @@ impl NotificationService {
         let notifications = self.notification_repository.find_by_user_where(
             user.id,
             NotificationFindByUserWhereClause {
-                created_dt_from: input.from_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
-                created_dt_to: input.to_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
+                created_dt_from: HelperMapper::to_optional_timestamp("from_dt", input.from_dt)?,
+                created_dt_to: HelperMapper::to_optional_timestamp("to_dt", input.to_dt)?,
                 notification_type: filter_by_type,
                 status: input.status.map(|status| status.into()),
                 sort_by: Some(NotificationSortBy::CreatedDt(SortDirection::Desc)),
@@ -76,7 +96,14 @@ impl NotificationService {
         Ok(notifications)
     }
 
-    pub async fn mark_read(&self, input: MarkNotificationsReadInput) -> ServiceResult<()> {
+    pub async fn mark_read(
+        &self,
+        input: MarkNotificationsReadInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<()> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
         let mut notifications = input
             .notification_ids
             .iter()
@@ -88,7 +115,7 @@ impl NotificationService {
                 true => NotificationStatus::Read,
                 false => NotificationStatus::Sent,
             };
-            notification.last_modification_timestamp = next_time();
+            notification.last_modification_timestamp = ctx.now();
 
             notification.validate()?;
             self.notification_repository
@@ -125,8 +152,125 @@ impl NotificationService {
             last_modification_timestamp: now,
         };
 
+        let is_bridged = NotificationBridge::is_bridged(&notification.notification_type);
+
         self.notification_repository
             .insert(notification.to_key(), notification);
+
+        if is_bridged && self.bridge_repository.get(&user_id).is_some() {
+            self.enqueue_bridged_notification(user_id, *notification_id.as_bytes());
+        }
+    }
+
+    /// Adds the notification to the outbox of the user and schedules its delivery to the inbox canister.
+    fn enqueue_bridged_notification(&self, user_id: UserId, notification_id: NotificationId) {
+        if self.outbox_repository.count_by_user(user_id) >= Self::MAX_PENDING_NOTIFICATIONS_PER_USER
+        {
+            if let Some(oldest) = self.outbox_repository.find_by_user(user_id, 1).pop() {
+                self.outbox_repository.remove(&oldest.key());
+            }
+        }
+
+        let enqueued_at = next_time();
+        let entry = NotificationOutboxEntry {
+            user_id,
+            notification_id,
+            enqueued_at,
+            attempts: 0,
+            next_attempt_at: enqueued_at,
+        };
+
+        self.outbox_repository.insert(entry.key(), entry);
+
+        schedule_bridged_notification_delivery(enqueued_at);
+    }
+
+    /// Sets the inbox canister that receives the important notifications of the caller, or removes it
+    /// together with the undelivered notifications if no inbox canister is given.
+    pub fn set_notification_bridge(
+        &self,
+        input: SetNotificationBridgeInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Option<NotificationBridge>> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let user = self.user_service.get_user_by_identity(&ctx.caller())?;
+
+        let Some(inbox_canister) = input.inbox_canister else {
+            self.outbox_repository.remove_by_user(user.id);
+            self.bridge_repository.remove(&user.id);
+
+            return Ok(None);
+        };
+
+        let bridge = match self.bridge_repository.get(&user.id) {
+            Some(bridge) if bridge.inbox_canister == inbox_canister => NotificationBridge {
+                identity: ctx.caller(),
+                ..bridge
+            },
+            _ => NotificationBridge {
+                user_id: user.id,
+                inbox_canister,
+                identity: ctx.caller(),
+                created_at: ctx.now(),
+                delivered_notifications: 0,
+                last_delivered_at: None,
+                last_error: None,
+            },
+        };
+
+        bridge.validate()?;
+
+        self.bridge_repository.insert(bridge.key(), bridge.clone());
+
+        Ok(Some(bridge))
+    }
+
+    pub fn get_notification_bridge(
+        &self,
+        ctx: &CallContext,
+    ) -> ServiceResult<Option<NotificationBridge>> {
+        let user = self.user_service.get_user_by_identity(&ctx.caller())?;
+
+        Ok(self.bridge_repository.get(&user.id))
+    }
+
+    pub fn count_pending_notifications(&self, bridge: &NotificationBridge) -> usize {
+        self.outbox_repository.count_by_user(bridge.user_id)
+    }
+
+    /// Sends the notification to the given users and to the active members of the given groups.
+    ///
+    /// Users that are reached more than once, e.g. as members of several of the groups, are only
+    /// notified once.
+    pub async fn send_group_notification(
+        &self,
+        user_ids: impl IntoIterator<Item = UserId>,
+        group_ids: impl IntoIterator<Item = UserGroupId>,
+        notification_type: NotificationType,
+        title: String,
+        message: Option<String>,
+    ) {
+        let mut recipients = user_ids.into_iter().collect::<BTreeSet<UserId>>();
+        for group_id in group_ids {
+            recipients.extend(
+                USER_REPOSITORY
+                    .find_by_group_and_status(&group_id, &UserStatus::Active)
+                    .into_iter()
+                    .map(|user| user.id),
+            );
+        }
+
+        for user_id in recipients {
+            self.send_notification(
+                user_id,
+                notification_type.clone(),
+                title.clone(),
+                message.clone(),
+            )
+            .await;
+        }
     }
 }
 
@@ -134,8 +278,11 @@ impl NotificationService {
 mod tests {
     use super::*;
     use crate::{
-        core::test_utils,
-        models::{notification_test_utils::mock_notification, user_test_utils::mock_user, User},
+        core::{test_providers::MockTimeProvider, test_utils},
+        models::{
+            notification_test_utils::mock_notification, user_test_utils::mock_user,
+            RequestNotification, User,
+        },
         repositories::UserRepository,
     };
     use candid::Principal;
@@ -176,6 +323,30 @@ mod tests {
         assert_eq!(notification, result.unwrap());
     }
 
+    #[test]
+    fn list_notifications_rejects_malformed_datetimes() {
+        let ctx = setup();
+
+        let error = ctx
+            .service
+            .list_notifications(
+                ListNotificationsInput {
+                    status: None,
+                    notification_type: None,
+                    from_dt: Some("yesterday".to_string()),
+                    to_dt: None,
+                },
+                &CallContext::new(ctx.caller_user.identities[0]),
+            )
+            .unwrap_err();
+
+        assert_eq!(error.code, "MALFORMED_TIMESTAMP");
+        assert_eq!(
+            error.details.unwrap().get("field"),
+            Some(&"from_dt".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn read_notification_happy_path() {
         let ctx = setup();
@@ -188,18 +359,176 @@ mod tests {
         ctx.repository
             .insert(notification.to_key(), notification.to_owned());
 
+        let call_context = CallContext::new(ctx.caller_user.identities[0])
+            .with_time_provider(Arc::new(MockTimeProvider::new(1_000)));
         let result = ctx
             .service
-            .mark_read(MarkNotificationsReadInput {
-                notification_ids: vec![notification_id.to_string()],
-                read: true,
-            })
+            .mark_read(
+                MarkNotificationsReadInput {
+                    notification_ids: vec![notification_id.to_string()],
+                    read: true,
+                },
+                &call_context,
+            )
             .await;
 
         assert!(result.is_ok());
+        let notification = ctx.repository.get(&notification.to_key()).unwrap();
+        assert_eq!(notification.status, NotificationStatus::Read);
+        assert_eq!(notification.last_modification_timestamp, 1_000);
+    }
+
+    #[tokio::test]
+    async fn group_notifications_reach_each_member_once() {
+        let ctx = setup();
+        let group_a = [1; 16];
+        let group_b = [2; 16];
+
+        let mut member_of_both = mock_user();
+        member_of_both.groups = vec![group_a, group_b];
+        let mut inactive_member = mock_user();
+        inactive_member.groups = vec![group_a];
+        inactive_member.status = UserStatus::Inactive;
+
+        for user in [&member_of_both, &inactive_member] {
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+        }
+
+        ctx.service
+            .send_group_notification(
+                [ctx.caller_user.id, member_of_both.id],
+                [group_a, group_b],
+                NotificationType::SystemMessage,
+                "title".to_string(),
+                None,
+            )
+            .await;
+
+        let count_notifications = |user_id| {
+            ctx.repository
+                .find_by_user_where(
+                    user_id,
+                    NotificationFindByUserWhereClause {
+                        created_dt_from: None,
+                        created_dt_to: None,
+                        notification_type: None,
+                        status: None,
+                        sort_by: None,
+                    },
+                )
+                .len()
+        };
+
+        assert_eq!(count_notifications(ctx.caller_user.id), 1);
+        assert_eq!(count_notifications(member_of_both.id), 1);
+        assert_eq!(count_notifications(inactive_member.id), 0);
+    }
+
+    #[tokio::test]
+    async fn only_bridged_notifications_are_enqueued_for_opted_in_users() {
+        let ctx = setup();
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+        let other_user = mock_user();
+        USER_REPOSITORY.insert(other_user.to_key(), other_user.clone());
+
+        ctx.service
+            .set_notification_bridge(
+                SetNotificationBridgeInput {
+                    inbox_canister: Some(
+                        Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap(),
+                    ),
+                },
+                &call_context,
+            )
+            .unwrap();
+
+        for user_id in [ctx.caller_user.id, other_user.id] {
+            for notification_type in [
+                NotificationType::SystemMessage,
+                NotificationType::RequestCreated(RequestNotification {
+                    request_id: [1; 16],
+                }),
+            ] {
+                ctx.service
+                    .send_notification(user_id, notification_type, "title".to_string(), None)
+                    .await;
+            }
+        }
+
+        let bridge = ctx
+            .service
+            .get_notification_bridge(&call_context)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(bridge.identity, call_context.caller());
+        assert_eq!(ctx.service.count_pending_notifications(&bridge), 1);
+        assert_eq!(
+            NOTIFICATION_OUTBOX_REPOSITORY.count_by_user(other_user.id),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn opting_out_drops_pending_notifications() {
+        let ctx = setup();
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+
+        ctx.service
+            .set_notification_bridge(
+                SetNotificationBridgeInput {
+                    inbox_canister: Some(
+                        Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap(),
+                    ),
+                },
+                &call_context,
+            )
+            .unwrap();
+        ctx.service
+            .send_notification(
+                ctx.caller_user.id,
+                NotificationType::RequestCreated(RequestNotification {
+                    request_id: [1; 16],
+                }),
+                "title".to_string(),
+                None,
+            )
+            .await;
+
+        let bridge = ctx
+            .service
+            .set_notification_bridge(
+                SetNotificationBridgeInput {
+                    inbox_canister: None,
+                },
+                &call_context,
+            )
+            .unwrap();
+
+        assert!(bridge.is_none());
+        assert!(ctx
+            .service
+            .get_notification_bridge(&call_context)
+            .unwrap()
+            .is_none());
         assert_eq!(
-            ctx.repository.get(&notification.to_key()).unwrap().status,
-            NotificationStatus::Read
+            NOTIFICATION_OUTBOX_REPOSITORY.count_by_user(ctx.caller_user.id),
+            0
         );
     }
+
+    #[test]
+    fn inbox_must_be_a_canister() {
+        let ctx = setup();
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+
+        let result = ctx.service.set_notification_bridge(
+            SetNotificationBridgeInput {
+                inbox_canister: Some(Principal::from_slice(&[1; 29])),
+            },
+            &call_context,
+        );
+
+        assert!(result.is_err());
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
     models::{
         permission::{Allow, Permission},
         resource::Resource,
-        EditPermissionOperationInput, User, UserGroup,
+        EditPermissionOperationInput, PermissionChange, User, UserGroup,
     },
     repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY},
     services::{UserGroupService, UserService, USER_GROUP_SERVICE, USER_SERVICE},
@@ -74,6 +74,55 @@ impl PermissionService {
         &self,
         input: EditPermissionOperationInput,
     ) -> ServiceResult<Permission> {
+        let permission = self.changed_permission(input)?;
+
+        self.permission_repository
+            .insert(permission.key(), permission.to_owned());
+
+        Ok(permission)
+    }
+
+    /// Edits the permissions of several resources at once.
+    ///
+    /// All the changes are validated before any of them is applied, so that either all the permissions
+    /// are changed or none of them is.
+    pub fn bulk_edit_permissions(
+        &self,
+        changes: Vec<EditPermissionOperationInput>,
+    ) -> ServiceResult<Vec<Permission>> {
+        let permissions = changes
+            .into_iter()
+            .map(|input| self.changed_permission(input))
+            .collect::<ServiceResult<Vec<_>>>()?;
+
+        for permission in &permissions {
+            self.permission_repository
+                .insert(permission.key(), permission.to_owned());
+        }
+
+        Ok(permissions)
+    }
+
+    /// Returns the permissions of the resources before and after the changes, without applying them.
+    pub fn preview_permission_changes(
+        &self,
+        changes: &[EditPermissionOperationInput],
+    ) -> ServiceResult<Vec<PermissionChange>> {
+        changes
+            .iter()
+            .map(|input| {
+                let after = self.changed_permission(input.to_owned())?;
+
+                Ok(PermissionChange {
+                    resource: input.resource.to_owned(),
+                    before: self.get_permission(&input.resource).allow,
+                    after: after.allow,
+                })
+            })
+            .collect()
+    }
+
+    fn changed_permission(&self, input: EditPermissionOperationInput) -> ServiceResult<Permission> {
         input.resource.validate()?;
 
         let mut permission = self.get_permission(&input.resource);
@@ -90,9 +139,6 @@ impl PermissionService {
             permission.allow.user_groups = user_groups;
         }
 
-        self.permission_repository
-            .insert(permission.key(), permission.to_owned());
-
         Ok(permission)
     }
 
@@ -329,6 +375,49 @@ mod tests {
             .expect_err("Should fail with invalid Group ID");
     }
 
+    #[test]
+    fn bulk_edit_permissions_applies_all_or_none_of_the_changes() {
+        let service = PERMISSION_SERVICE.clone();
+        let list = Resource::Request(RequestResourceAction::List);
+        let read = Resource::Request(RequestResourceAction::Read(ResourceId::Any));
+        let changes = vec![
+            EditPermissionOperationInput {
+                resource: list.clone(),
+                auth_scope: Some(AuthScope::Authenticated),
+                users: None,
+                user_groups: None,
+            },
+            EditPermissionOperationInput {
+                resource: read.clone(),
+                auth_scope: Some(AuthScope::Public),
+                users: None,
+                user_groups: Some(vec![[9; 16]]),
+            },
+        ];
+
+        service
+            .bulk_edit_permissions(changes.clone())
+            .expect_err("Should fail with invalid Group ID");
+
+        assert_eq!(service.get_permission(&list).allow, Allow::default());
+        assert_eq!(service.get_permission(&read).allow, Allow::default());
+
+        let mut changes = changes;
+        changes[1].user_groups = None;
+
+        let matrix = service.preview_permission_changes(&changes).unwrap();
+
+        assert_eq!(matrix.len(), 2);
+        assert_eq!(matrix[0].before, Allow::default());
+        assert_eq!(matrix[0].after.auth_scope, AuthScope::Authenticated);
+        assert_eq!(service.get_permission(&list).allow, Allow::default());
+
+        service.bulk_edit_permissions(changes).unwrap();
+
+        assert!(service.get_permission(&list).allowed_authenticated());
+        assert!(service.get_permission(&read).allowed_public());
+    }
+
     #[test]
     fn test_remove_permission() {
         let service = PERMISSION_SERVICE.clone();
@@ -0,0 +1,348 @@
+use crate::{
+    core::{
+        ic_cdk::next_time,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+        CallContext,
+    },
+    errors::PrincipalLabelError,
+    models::{PrincipalLabel, PrincipalLabelSource},
+    repositories::{PrincipalLabelRepository, PRINCIPAL_LABEL_REPOSITORY},
+    services::{UserService, SYSTEM_SERVICE, USER_SERVICE},
+};
+use candid::Principal;
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, model::ModelValidator, repository::Repository, types::Timestamp,
+};
+use station_api::{
+    ImportPrincipalLabelsResponse, ListPrincipalLabelsInput, PrincipalLabelEntryDTO,
+    SetPrincipalLabelInput,
+};
+use std::{collections::BTreeSet, sync::Arc};
+
+lazy_static! {
+    pub static ref PRINCIPAL_LABEL_SERVICE: Arc<PrincipalLabelService> =
+        Arc::new(PrincipalLabelService::new(
+            Arc::clone(&PRINCIPAL_LABEL_REPOSITORY),
+            Arc::clone(&USER_SERVICE),
+        ));
+}
+
+/// Keeps the station-wide registry of the labels of well-known principals and addresses (e.g. exchanges
+/// and system canisters), which annotate the destinations of the transfers and the sources of the deposits.
+#[derive(Default, Debug)]
+pub struct PrincipalLabelService {
+    principal_label_repository: Arc<PrincipalLabelRepository>,
+    user_service: Arc<UserService>,
+}
+
+impl PrincipalLabelService {
+    pub const DEFAULT_LABELS_LIMIT: u16 = 100;
+    pub const MAX_LIST_LABELS_LIMIT: u16 = 1000;
+
+    pub fn new(
+        principal_label_repository: Arc<PrincipalLabelRepository>,
+        user_service: Arc<UserService>,
+    ) -> Self {
+        Self {
+            principal_label_repository,
+            user_service,
+        }
+    }
+
+    /// Returns the label of the address, if it is labeled.
+    pub fn find_label(&self, address: &str) -> Option<String> {
+        self.principal_label_repository.find_label(address)
+    }
+
+    /// Returns the labels ordered by address, optionally only the ones that match the search term.
+    pub fn list_labels(
+        &self,
+        input: ListPrincipalLabelsInput,
+    ) -> ServiceResult<PaginatedData<PrincipalLabel>> {
+        let search_term = input
+            .search_term
+            .map(|term| term.trim().to_lowercase())
+            .filter(|term| !term.is_empty());
+        let labels = self
+            .principal_label_repository
+            .list()
+            .into_iter()
+            .filter(|label| {
+                search_term.as_ref().map_or(true, |term| {
+                    label.address.to_lowercase().starts_with(term)
+                        || label.label.to_lowercase().contains(term)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_LABELS_LIMIT),
+            max_limit: Some(Self::MAX_LIST_LABELS_LIMIT),
+            items: &labels,
+        })?)
+    }
+
+    /// Labels the address on behalf of the caller, replacing its previous label.
+    ///
+    /// A label set by a user is kept when the labels are imported again.
+    pub fn set_label(
+        &self,
+        input: SetPrincipalLabelInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<PrincipalLabel> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let caller_user = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let label = PrincipalLabel {
+            address: PrincipalLabel::normalize_address(&input.address),
+            label: input.label.trim().to_string(),
+            source: PrincipalLabelSource::Manual {
+                labeled_by: caller_user.id,
+            },
+            last_modification_timestamp: ctx.now(),
+        };
+
+        label.validate()?;
+
+        self.principal_label_repository
+            .insert(label.to_key(), label.to_owned());
+
+        Ok(label)
+    }
+
+    /// Removes the label of the address.
+    pub fn remove_label(&self, address: &str) -> ServiceResult<PrincipalLabel> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let label = self
+            .principal_label_repository
+            .remove(&PrincipalLabel::key(address))
+            .ok_or(PrincipalLabelError::NotFound {
+                address: address.to_string(),
+            })?;
+
+        Ok(label)
+    }
+
+    /// Imports the labels of the community-maintained list canister, they replace the labels that were
+    /// previously imported from the same canister.
+    pub async fn import_labels(
+        &self,
+        list_canister: Principal,
+    ) -> ServiceResult<ImportPrincipalLabelsResponse> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let (entries,) = ic_cdk::call::<_, (Vec<PrincipalLabelEntryDTO>,)>(
+            list_canister,
+            PrincipalLabel::LIST_METHOD_NAME,
+            (),
+        )
+        .await
+        .map_err(|(code, message)| PrincipalLabelError::ImportFailed {
+            reason: format!("rejection_code: {:?}, err: {}", code, message),
+        })?;
+
+        Ok(self.apply_imported_labels(list_canister, entries, next_time()))
+    }
+
+    /// Replaces the labels imported from the list canister with the given entries.
+    ///
+    /// The entries that are invalid, repeated or that would override a label of another source are skipped.
+    fn apply_imported_labels(
+        &self,
+        list_canister: Principal,
+        entries: Vec<PrincipalLabelEntryDTO>,
+        now: Timestamp,
+    ) -> ImportPrincipalLabelsResponse {
+        let mut response = ImportPrincipalLabelsResponse {
+            imported: 0,
+            removed: 0,
+            skipped: entries
+                .len()
+                .saturating_sub(PrincipalLabel::MAX_IMPORTED_LABELS) as u64,
+        };
+
+        let mut imported_addresses = BTreeSet::new();
+        for entry in entries
+            .into_iter()
+            .take(PrincipalLabel::MAX_IMPORTED_LABELS)
+        {
+            let label = PrincipalLabel {
+                address: PrincipalLabel::normalize_address(&entry.address),
+                label: entry.label.trim().to_string(),
+                source: PrincipalLabelSource::Imported { list_canister },
+                last_modification_timestamp: now,
+            };
+
+            let is_replaceable = self
+                .principal_label_repository
+                .get(&label.to_key())
+                .map_or(true, |existing| existing.is_imported_from(&list_canister));
+
+            if label.validate().is_err()
+                || !is_replaceable
+                || !imported_addresses.insert(label.address.clone())
+            {
+                response.skipped += 1;
+                continue;
+            }
+
+            self.principal_label_repository
+                .insert(label.to_key(), label);
+            response.imported += 1;
+        }
+
+        for label in self.principal_label_repository.list() {
+            if label.is_imported_from(&list_canister)
+                && !imported_addresses.contains(&label.address)
+            {
+                self.principal_label_repository.remove(&label.to_key());
+                response.removed += 1;
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{read_system_info, test_utils, write_system_info},
+        models::{
+            principal_label_test_utils::mock_principal_label, system::MaintenanceModeConfig,
+            user_test_utils::mock_user,
+        },
+        repositories::USER_REPOSITORY,
+    };
+
+    fn entry(address: &str, label: &str) -> PrincipalLabelEntryDTO {
+        PrincipalLabelEntryDTO {
+            address: address.to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn set_label_normalizes_the_address() {
+        test_utils::init_canister_system();
+
+        let ctx = CallContext::new(Principal::from_slice(&[9; 29]));
+        let mut user = mock_user();
+        user.identities = vec![ctx.caller()];
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let label = PRINCIPAL_LABEL_SERVICE
+            .set_label(
+                SetPrincipalLabelInput {
+                    address: "0xAbC".to_string(),
+                    label: " Binance deposit ".to_string(),
+                },
+                &ctx,
+            )
+            .unwrap();
+
+        assert_eq!(label.address, "0xabc".to_string());
+        assert_eq!(
+            label.source,
+            PrincipalLabelSource::Manual {
+                labeled_by: user.id
+            }
+        );
+        assert_eq!(
+            PRINCIPAL_LABEL_SERVICE.find_label("0xABC"),
+            Some("Binance deposit".to_string())
+        );
+
+        PRINCIPAL_LABEL_SERVICE.remove_label("0xABC").unwrap();
+
+        assert!(PRINCIPAL_LABEL_SERVICE.find_label("0xabc").is_none());
+        assert!(PRINCIPAL_LABEL_SERVICE.remove_label("0xabc").is_err());
+    }
+
+    #[test]
+    fn labels_cannot_be_changed_in_maintenance() {
+        test_utils::init_canister_system();
+
+        let label = mock_principal_label();
+        PRINCIPAL_LABEL_REPOSITORY.insert(label.to_key(), label.clone());
+
+        let mut system_info = read_system_info();
+        system_info.set_maintenance_mode(MaintenanceModeConfig {
+            enabled: true,
+            reason: None,
+            during_migrations: false,
+        });
+        write_system_info(system_info);
+
+        let result = PRINCIPAL_LABEL_SERVICE.set_label(
+            SetPrincipalLabelInput {
+                address: "0xabc".to_string(),
+                label: "Binance deposit".to_string(),
+            },
+            &CallContext::new(Principal::from_slice(&[9; 29])),
+        );
+
+        assert_eq!(result.unwrap_err().code, "STATION_IN_MAINTENANCE");
+        assert!(PRINCIPAL_LABEL_SERVICE
+            .remove_label(&label.address)
+            .is_err());
+        assert_eq!(
+            PRINCIPAL_LABEL_SERVICE.find_label(&label.address),
+            Some(label.label)
+        );
+    }
+
+    #[test]
+    fn import_replaces_the_labels_of_the_same_list() {
+        let list_canister = Principal::from_slice(&[1; 10]);
+        let manual = mock_principal_label();
+        PRINCIPAL_LABEL_REPOSITORY.insert(manual.to_key(), manual.clone());
+
+        let response = PRINCIPAL_LABEL_SERVICE.apply_imported_labels(
+            list_canister,
+            vec![
+                entry("exchange", "Exchange"),
+                entry("outdated", "Outdated"),
+                entry(&manual.address, "Overridden"),
+            ],
+            0,
+        );
+
+        assert_eq!(response.imported, 2);
+        assert_eq!(response.skipped, 1);
+        assert_eq!(
+            PRINCIPAL_LABEL_SERVICE.find_label(&manual.address),
+            Some(manual.label.clone())
+        );
+
+        let response = PRINCIPAL_LABEL_SERVICE.apply_imported_labels(
+            list_canister,
+            vec![
+                entry("exchange", "Exchange hot wallet"),
+                entry("exchange", "Duplicate"),
+                entry("blank", " "),
+            ],
+            1,
+        );
+
+        assert_eq!(response.imported, 1);
+        assert_eq!(response.removed, 1);
+        assert_eq!(response.skipped, 2);
+        assert_eq!(
+            PRINCIPAL_LABEL_SERVICE.find_label("exchange"),
+            Some("Exchange hot wallet".to_string())
+        );
+        assert!(PRINCIPAL_LABEL_SERVICE.find_label("outdated").is_none());
+        assert!(PRINCIPAL_LABEL_SERVICE
+            .find_label(&manual.address)
+            .is_some());
+    }
+}
@@ -1,32 +1,49 @@
 use crate::{
     core::{
         authorization::Authorization,
+        certification::certify_vote,
+        cost::{metered, record_request_cost},
+        ic_cdk::api::id as station_canister_self_id,
+        tracing::TraceContext,
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
     errors::RequestError,
     factories::requests::RequestFactory,
-    mappers::HelperMapper,
+    log_warn,
+    mappers::{api_version::ApiVersionMapper, HelperMapper},
     models::{
         resource::{RequestResourceAction, Resource, ResourceId},
-        DisplayUser, NotificationType, Request, RequestAdditionalInfo, RequestApprovalStatus,
-        RequestCallerPrivileges, RequestCreatedNotification, RequestRejectedNotification,
-        RequestStatus, RequestStatusCode,
+        DisplayRequestImpact, DisplayUser, NotificationType, Request, RequestAdditionalInfo,
+        RequestApprovalStatus, RequestCallerPrivileges, RequestCostPhase,
+        RequestCreatedNotification, RequestExecutedNotification, RequestExecutionPlan,
+        RequestFailedNotification, RequestImpact, RequestOperation, RequestOperationType,
+        RequestPolicyChangedNotification, RequestRejectedNotification, RequestStatus,
+        RequestStatusCode, RequestViewer, TransferScreening, TransferScreeningStatus, User, UserId,
+        VoteReceipt, VoteReceiptKey, ADMIN_GROUP_ID,
     },
     repositories::{
         EvaluationResultRepository, RequestRepository, RequestWhereClause,
-        REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+        ARCHIVED_REQUEST_REPOSITORY, REQUEST_EVALUATION_RESULT_REPOSITORY,
+        REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY, VOTE_RECEIPT_REPOSITORY,
+    },
+    services::{
+        NotificationService, UsageService, UserService, NOTIFICATION_SERVICE, SYSTEM_SERVICE,
+        USAGE_SERVICE, USER_GROUP_SERVICE, USER_SERVICE,
     },
-    services::{NotificationService, UserService, NOTIFICATION_SERVICE, USER_SERVICE},
 };
-use ic_cdk::print;
+use candid::Principal;
 use lazy_static::lazy_static;
-use orbit_essentials::utils::rfc3339_to_timestamp;
 use orbit_essentials::{api::ServiceResult, model::ModelValidator};
-use orbit_essentials::{repository::Repository, types::UUID};
+use orbit_essentials::{
+    repository::Repository,
+    types::{Timestamp, UUID},
+};
 use station_api::{
-    CreateRequestInput, GetNextApprovableRequestInput, ListRequestsInput,
-    SubmitRequestApprovalInput,
+    ArchivedRequestDTO, CancelTimeLockedRequestInput, CreateRequestInput, GetArchivedRequestInput,
+    GetNextApprovableRequestInput, ListRequestsInput, RequestOperationInput,
+    RescheduleRequestInput, RetryRequestExecutionInput, SubmitRequestApprovalInput,
+    STATION_API_VERSION,
 };
 use std::sync::Arc;
 use uuid::Uuid;
@@ -37,6 +54,7 @@ lazy_static! {
         Arc::clone(&REQUEST_REPOSITORY),
         Arc::clone(&NOTIFICATION_SERVICE),
         Arc::clone(&REQUEST_EVALUATION_RESULT_REPOSITORY),
+        Arc::clone(&USAGE_SERVICE),
     ));
 }
 
@@ -46,6 +64,7 @@ pub struct RequestService {
     request_repository: Arc<RequestRepository>,
     evaluation_result_repository: Arc<EvaluationResultRepository>,
     notification_service: Arc<NotificationService>,
+    usage_service: Arc<UsageService>,
 }
 
 #[derive(Debug)]
@@ -63,12 +82,14 @@ impl RequestService {
         request_repository: Arc<RequestRepository>,
         notification_service: Arc<NotificationService>,
         evaluation_result_repository: Arc<EvaluationResultRepository>,
+        usage_service: Arc<UsageService>,
     ) -> Self {
         Self {
             user_service,
             request_repository,
             notification_service,
             evaluation_result_repository,
+            usage_service,
         }
     }
 
@@ -83,6 +104,59 @@ impl RequestService {
         Ok(request)
     }
 
+    /// Fetches the request from the archive canister, if the request was archived.
+    ///
+    /// Only the stub of an archived request is kept by the station, the request itself is final so it can no
+    /// longer be approved or cancelled.
+    pub async fn find_archived_request(
+        &self,
+        id: &UUID,
+    ) -> ServiceResult<Option<ArchivedRequestDTO>> {
+        let Some(archived) = ARCHIVED_REQUEST_REPOSITORY.get(id) else {
+            return Ok(None);
+        };
+
+        let request_id = Uuid::from_bytes(*id).hyphenated().to_string();
+        let (request,) = ic_cdk::call::<_, (Option<ArchivedRequestDTO>,)>(
+            archived.archive_canister_id,
+            "get_archived_request",
+            (GetArchivedRequestInput {
+                station_id: station_canister_self_id(),
+                request_id: request_id.clone(),
+            },),
+        )
+        .await
+        .map_err(|(code, message)| RequestError::ArchiveUnavailable {
+            request_id: request_id.clone(),
+            reason: format!("rejection_code: {:?}, err: {}", code, message),
+        })?;
+
+        let request = request.ok_or(RequestError::ArchiveUnavailable {
+            request_id,
+            reason: "The archive canister does not have the request.".to_string(),
+        })?;
+
+        Ok(Some(request))
+    }
+
+    /// Returns the canonical JSON encoding of the request and its sha256 hash.
+    ///
+    /// The encoding is only available until the request is compacted, since the compaction prunes
+    /// some of the content that was approved.
+    pub fn get_request_canonical(&self, id: &UUID) -> ServiceResult<(String, Vec<u8>)> {
+        let request = self.get_request(id)?;
+        if request.compacted_at.is_some() {
+            Err(RequestError::CanonicalContentCompacted {
+                request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+            })?
+        }
+
+        let canonical_json = request.to_canonical_json();
+        let hash = Request::canonical_hash(&canonical_json);
+
+        Ok((canonical_json, hash))
+    }
+
     pub async fn get_caller_privileges_for_request(
         &self,
         request_id: &UUID,
@@ -91,10 +165,12 @@ impl RequestService {
         let approver = self.user_service.get_user_by_identity(&ctx.caller())?;
         let request = self.get_request(request_id)?;
         let can_approve = request.can_approve(&approver.id);
+        let can_cancel = request.can_cancel(&approver.groups, ctx.now());
 
         Ok(RequestCallerPrivileges {
             id: *request_id,
             can_approve,
+            can_cancel,
         })
     }
 
@@ -114,10 +190,10 @@ impl RequestService {
                         id: user.id,
                     }),
                     Err(_) => {
-                        print(format!(
+                        log_warn!(
                             "Failed to get user with id {}",
                             Uuid::from_bytes(approval.approver_id.to_owned()).hyphenated()
-                        ));
+                        );
 
                         None
                     }
@@ -133,11 +209,40 @@ impl RequestService {
             })
             .flatten();
 
+        let approval_checklist = match request.status {
+            RequestStatus::Created => request.approval_checklist(),
+            _ => Vec::new(),
+        };
+
+        let impact = match request.status {
+            RequestStatus::Created => RequestImpact::of(request).map(|impact| {
+                let to_display_users = |user_ids: Vec<UserId>| {
+                    user_ids
+                        .into_iter()
+                        .filter_map(|user_id| self.user_service.get_user(&user_id).ok())
+                        .map(|user| DisplayUser {
+                            name: user.name,
+                            id: user.id,
+                        })
+                        .collect::<Vec<DisplayUser>>()
+                };
+
+                DisplayRequestImpact {
+                    users_gaining_access: to_display_users(impact.users_gaining_access),
+                    users_losing_access: to_display_users(impact.users_losing_access),
+                    affected_requests: impact.affected_requests,
+                }
+            }),
+            _ => None,
+        };
+
         Ok(RequestAdditionalInfo {
             id: request.id,
             requester_name: requester.map_or("Unknown".to_string(), |user| user.name),
             approvers,
             evaluation_result,
+            approval_checklist,
+            impact,
         })
     }
 
@@ -175,18 +280,22 @@ impl RequestService {
 
         let mut request_ids = self.request_repository.find_ids_where(
             RequestWhereClause {
-                created_dt_from: input
-                    .created_from_dt
-                    .map(|dt| rfc3339_to_timestamp(dt.as_str())),
-                created_dt_to: input
-                    .created_to_dt
-                    .map(|dt| rfc3339_to_timestamp(dt.as_str())),
-                expiration_dt_from: input
-                    .expiration_from_dt
-                    .map(|dt| rfc3339_to_timestamp(dt.as_str())),
-                expiration_dt_to: input
-                    .expiration_to_dt
-                    .map(|dt| rfc3339_to_timestamp(dt.as_str())),
+                created_dt_from: HelperMapper::to_optional_timestamp(
+                    "created_from_dt",
+                    input.created_from_dt,
+                )?,
+                created_dt_to: HelperMapper::to_optional_timestamp(
+                    "created_to_dt",
+                    input.created_to_dt,
+                )?,
+                expiration_dt_from: HelperMapper::to_optional_timestamp(
+                    "expiration_from_dt",
+                    input.expiration_from_dt,
+                )?,
+                expiration_dt_to: HelperMapper::to_optional_timestamp(
+                    "expiration_to_dt",
+                    input.expiration_to_dt,
+                )?,
                 operation_types: input
                     .operation_types
                     .map(|types| {
@@ -205,6 +314,7 @@ impl RequestService {
                 not_approvers: filter_by_votable.clone(),
                 not_requesters: filter_by_votable,
                 excluded_ids: vec![],
+                viewer: Some(ctx.into()),
             },
             input.sort_by,
         )?;
@@ -214,6 +324,18 @@ impl RequestService {
             Resource::Request(RequestResourceAction::Read(ResourceId::Id(*id)))
         });
 
+        // leave out the requests that the client can't decode before paginating them
+        let api_version = ApiVersionMapper::resolve(input.api_version)?;
+        if api_version < STATION_API_VERSION {
+            request_ids.retain(|id| {
+                self.request_repository
+                    .get(&Request::key(*id))
+                    .is_some_and(|request| {
+                        ApiVersionMapper::supports_operation(api_version, &request.operation)
+                    })
+            });
+        }
+
         // users have access to a request if they can approve it, or have already send their approval to it,
         // to see if a user can approve a request no further filtering is necessary
 
@@ -234,11 +356,11 @@ impl RequestService {
                 .flat_map(|id| match self.get_request(&id) {
                     Ok(request) => Some(request),
                     Err(error) => {
-                        print(format!(
+                        log_warn!(
                             "Failed to get request {}: {:?}",
                             Uuid::from_bytes(id.to_owned()).hyphenated(),
                             error
-                        ));
+                        );
                         None
                     }
                 })
@@ -251,6 +373,7 @@ impl RequestService {
         input: GetNextApprovableRequestInput,
         ctx: Option<&CallContext>,
     ) -> ServiceResult<Option<Request>> {
+        let api_version = ApiVersionMapper::resolve(input.api_version)?;
         let filter_by_votable = if let Some(ctx) = ctx {
             let user = self.user_service.get_user_by_identity(&ctx.caller())?;
             vec![user.id]
@@ -286,6 +409,7 @@ impl RequestService {
                 not_approvers: filter_by_votable.clone(),
                 not_requesters: filter_by_votable,
                 excluded_ids: exclude_request_ids,
+                viewer: ctx.map(RequestViewer::from),
             },
             None,
         )?;
@@ -299,7 +423,10 @@ impl RequestService {
                         request_id.to_owned(),
                     ))),
                 ) {
-                    return Ok(Some(self.get_request(request_id)?));
+                    let request = self.get_request(request_id)?;
+                    if ApiVersionMapper::supports_operation(api_version, &request.operation) {
+                        return Ok(Some(request));
+                    }
                 }
             }
         }
@@ -316,23 +443,130 @@ impl RequestService {
         ctx: &CallContext,
     ) -> ServiceResult<Request> {
         let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
-        let mut request = RequestFactory::create_request(requester.id, input).await?;
+        let request_id = *ctx.generate_uuid().await.as_bytes();
+
+        // The lifecycle of a request is traced under its id.
+        let trace = TraceContext::ingress(request_id, "create_request");
+
+        trace.record(
+            self.create_traced_request(request_id, requester, input, ctx, trace)
+                .await,
+        )
+    }
+
+    async fn create_traced_request(
+        &self,
+        request_id: UUID,
+        requester: User,
+        input: CreateRequestInput,
+        ctx: &CallContext,
+        trace: TraceContext,
+    ) -> ServiceResult<Request> {
+        let (request, creation_instructions) =
+            metered(self.build_request(request_id, &requester, input, ctx)).await;
+        let mut request = request?;
+        record_request_cost(
+            &mut request,
+            RequestCostPhase::Creation,
+            creation_instructions,
+        );
+
+        self.submit_for_approval(&mut request, &requester, ctx, trace)
+            .await?;
+
+        self.usage_service
+            .record_request_created(&requester, &request, ctx)
+            .await;
+
+        Ok(request)
+    }
+
+    /// Builds and validates the request from the input, without storing it.
+    async fn build_request(
+        &self,
+        request_id: UUID,
+        requester: &User,
+        input: CreateRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let confidential = input.confidential.unwrap_or(false);
+        let summary_format = input.summary_format.map(Into::into).unwrap_or_default();
+        let depends_on = input
+            .depends_on
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|id| HelperMapper::to_uuid(id).map(|uuid| *uuid.as_bytes()))
+            .collect::<Result<Vec<UUID>, _>>()?;
+
+        // The station wide guards run before the factory, since the factory can already do expensive work
+        // (e.g. fetching the fee estimates or the module of a canister) for a request that would be refused.
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+
+        let is_maintenance_mode_change = matches!(
+            &input.operation,
+            RequestOperationInput::ManageSystemInfo(operation) if operation.maintenance_mode.is_some()
+        );
+        if !is_maintenance_mode_change {
+            SYSTEM_SERVICE.assert_not_in_maintenance()?;
+        }
+
+        let operation_type = RequestOperationType::from(&input.operation);
+        SYSTEM_SERVICE.assert_operation_enabled(&operation_type)?;
+
+        USER_GROUP_SERVICE.assert_within_quotas(requester, &operation_type)?;
+
+        let mut request = RequestFactory::create_request(request_id, requester.id, input).await?;
+        request.confidential = confidential;
+        request.depends_on = depends_on;
+        request.set_summary_format(summary_format);
+
+        // The timestamps of the request are taken from the call context so that they follow its clock.
+        let now = ctx.now();
+        request.created_timestamp = now;
+        request.last_modification_timestamp = now;
+        request.expiration_dt = now + Request::DEFAULT_EXPIRATION_PERIOD_NS;
 
         // Different request types may have different validation rules.
         request.validate()?;
 
+        Ok(request)
+    }
+
+    /// Submits a created request to the approval of its policies.
+    ///
+    /// The policies that match the request are snapshotted, so that later edits of the policies don't
+    /// change the approval of the request once submitted.
+    async fn submit_for_approval(
+        &self,
+        request: &mut Request,
+        requester: &User,
+        ctx: &CallContext,
+        trace: TraceContext,
+    ) -> ServiceResult<()> {
+        request.snapshot_policies();
+
         // Insert the request into the repository before adding approvals so checks that depend on the
         // request being in the repository pass.
         self.request_repository
             .insert(request.to_key(), request.to_owned());
 
-        if request.can_approve(&requester.id) {
-            request.add_approval(requester.id, RequestApprovalStatus::Approved, None)?;
+        // The requester approves their own request, unless the matching policies require a checklist
+        // to be acknowledged, in which case the requester has to submit their approval explicitly.
+        if request.can_approve(&requester.id) && request.approval_checklist().is_empty() {
+            request.add_approval(
+                requester.id,
+                RequestApprovalStatus::Approved,
+                None,
+                Vec::new(),
+            )?;
         }
 
-        // When a request is created, it is immediately evaluated to determine its status.
+        // When a request is submitted, it is immediately evaluated to determine its status.
         // This is done because the request may be immediately rejected or approved based on the policies.
-        let maybe_evaluation = request.reevaluate().await?;
+        let maybe_evaluation = trace
+            .child("evaluate_request", None)
+            .record(request.reevaluate().await)?;
 
         self.request_repository
             .insert(request.to_key(), request.to_owned());
@@ -342,12 +576,64 @@ impl RequestService {
                 .insert(request.id, evaluation);
         }
 
+        self.record_vote_receipt(request, &requester.id, ctx.caller());
+
         if request.status == RequestStatus::Created {
-            self.created_request_hook(&request).await;
+            self.created_request_hook(request).await;
         } else if request.status == RequestStatus::Rejected {
-            self.rejected_request_hook(&request).await;
+            self.rejected_request_hook(request).await;
         }
 
+        Ok(())
+    }
+
+    /// Changes the execution plan of a request that is not executed yet.
+    ///
+    /// Only the requester can reschedule a request, which is submitted again to the approval of its policies
+    /// since the approvals that were given so far were for the previous execution plan.
+    pub async fn reschedule_request(
+        &self,
+        input: RescheduleRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let caller = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let mut request = self.get_request(request_id.as_bytes())?;
+
+        if request.requested_by != caller.id {
+            Err(RequestError::Forbidden {
+                request_id: request_id.hyphenated().to_string(),
+            })?
+        }
+
+        let now = ctx.now();
+        match request.status {
+            RequestStatus::Created => {}
+            RequestStatus::Scheduled { .. } => {
+                // the approval period starts over for requests that were already approved
+                request.expiration_dt = now + Request::DEFAULT_EXPIRATION_PERIOD_NS;
+            }
+            _ => Err(RequestError::ValidationError {
+                info: "Only requests that are not executed yet can be rescheduled.".to_string(),
+            })?,
+        }
+
+        request.execution_plan = RequestExecutionPlan::try_from(input.execution_plan)?;
+        request.validate()?;
+
+        request.status = RequestStatus::Created;
+        request.approvals.clear();
+        request.last_modification_timestamp = now;
+
+        let trace = TraceContext::ingress(request.id, "reschedule_request");
+        trace.record(
+            self.submit_for_approval(&mut request, &caller, ctx, trace)
+                .await,
+        )?;
+
         Ok(request)
     }
 
@@ -364,17 +650,64 @@ impl RequestService {
             .await;
     }
 
+    /// Notifies the requester and the members of the groups named in the policies of the request.
     pub async fn failed_request_hook(&self, request: &Request) {
         self.notification_service
-            .send_notification(
-                request.requested_by,
-                NotificationType::RequestFailed(RequestRejectedNotification {
+            .send_group_notification(
+                [request.requested_by],
+                request.find_policy_groups(),
+                NotificationType::RequestFailed(RequestFailedNotification {
+                    request_id: request.id,
+                }),
+                request.title.to_owned(),
+                request.summary.to_owned(),
+            )
+            .await;
+    }
+
+    /// Notifies the requester and the members of the groups named in the policies of the request,
+    /// requests that changed a request policy are also notified to the admins and the members of the
+    /// groups named in the policy.
+    pub async fn completed_request_hook(&self, request: &Request) {
+        self.notification_service
+            .send_group_notification(
+                [request.requested_by],
+                request.find_policy_groups(),
+                NotificationType::RequestExecuted(RequestExecutedNotification {
                     request_id: request.id,
                 }),
                 request.title.to_owned(),
                 request.summary.to_owned(),
             )
             .await;
+
+        let policy_id = match &request.operation {
+            RequestOperation::AddRequestPolicy(operation) => operation.policy_id,
+            RequestOperation::EditRequestPolicy(operation) => Some(operation.input.policy_id),
+            RequestOperation::RemoveRequestPolicy(operation) => Some(operation.input.policy_id),
+            _ => None,
+        };
+
+        if let Some(policy_id) = policy_id {
+            // a removed policy no longer names any group, only the admins are notified then
+            let policy_groups = REQUEST_POLICY_REPOSITORY
+                .get(&policy_id)
+                .map(|policy| policy.rule.user_groups())
+                .unwrap_or_default();
+
+            self.notification_service
+                .send_group_notification(
+                    [],
+                    policy_groups.into_iter().chain([*ADMIN_GROUP_ID]),
+                    NotificationType::RequestPolicyChanged(RequestPolicyChangedNotification {
+                        request_id: request.id,
+                        policy_id,
+                    }),
+                    request.title.to_owned(),
+                    request.summary.to_owned(),
+                )
+                .await;
+        }
     }
 
     /// Handles post processing logic like sending notifications.
@@ -382,10 +715,10 @@ impl RequestService {
         let mut possible_approvers = match request.find_all_possible_approvers().await {
             Ok(approvers) => approvers,
             Err(_) => {
-                print(format!(
+                log_warn!(
                     "Failed to find all possible approvers for request {}",
                     Uuid::from_bytes(request.id).hyphenated()
-                ));
+                );
                 return;
             }
         };
@@ -412,19 +745,48 @@ impl RequestService {
         ctx: &CallContext,
     ) -> ServiceResult<Request> {
         let approver = self.user_service.get_user_by_identity(&ctx.caller())?;
-        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let request_id = HelperMapper::to_uuid(input.request_id.to_owned())?;
+        let trace = TraceContext::ingress(*request_id.as_bytes(), "submit_request_approval");
+
+        trace.record(
+            self.submit_traced_request_approval(request_id, approver, input, ctx, trace)
+                .await,
+        )
+    }
+
+    async fn submit_traced_request_approval(
+        &self,
+        request_id: Uuid,
+        approver: User,
+        input: SubmitRequestApprovalInput,
+        ctx: &CallContext,
+        trace: TraceContext,
+    ) -> ServiceResult<Request> {
         let mut request = self.get_request(request_id.as_bytes())?;
 
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+
+        if !request.operation.is_maintenance_mode_change() {
+            SYSTEM_SERVICE.assert_not_in_maintenance()?;
+        }
+
         if !request.can_approve(&approver.id) {
             Err(RequestError::ApprovalNotAllowed)?
         }
 
         let approval_decision = input.decision.into();
 
-        request.add_approval(approver.id, approval_decision, input.reason)?;
+        request.add_approval(
+            approver.id,
+            approval_decision,
+            input.reason,
+            input.acknowledged_checklist.unwrap_or_default(),
+        )?;
 
         // Must happen after the approval is added to the request to ensure the approval is counted.
-        let maybe_evaluation = request.reevaluate().await?;
+        let maybe_evaluation = trace
+            .child("evaluate_request", None)
+            .record(request.reevaluate().await)?;
 
         self.request_repository
             .insert(request.to_key(), request.to_owned());
@@ -434,13 +796,136 @@ impl RequestService {
                 .insert(request.id, evaluation);
         }
 
+        self.record_vote_receipt(&request, &approver.id, ctx.caller());
+
         if request.status == RequestStatus::Rejected {
             self.rejected_request_hook(&request).await;
         }
 
+        self.usage_service
+            .record_request_approval(&approver, &request, ctx)
+            .await;
+
+        Ok(request)
+    }
+
+    /// Stores and certifies the receipt of the vote of the approver, if they voted on the request.
+    ///
+    /// The receipt replaces the previous one of the approver, e.g. when the request was rescheduled.
+    fn record_vote_receipt(&self, request: &Request, approver_id: &UserId, voter: Principal) {
+        let Some(approval) = request
+            .approvals
+            .iter()
+            .find(|approval| approval.approver_id == *approver_id)
+        else {
+            return;
+        };
+
+        let receipt = VoteReceipt {
+            request_id: request.id,
+            approver_id: *approver_id,
+            voter,
+            decision: approval.status.to_owned(),
+            decided_at: approval.decided_dt,
+        };
+
+        VOTE_RECEIPT_REPOSITORY.insert(receipt.to_key(), receipt.to_owned());
+        certify_vote(&receipt);
+    }
+
+    /// Returns the receipt of the vote of the approver on the request.
+    pub fn get_vote_receipt(
+        &self,
+        request_id: &UUID,
+        approver_id: &UserId,
+    ) -> ServiceResult<VoteReceipt> {
+        let receipt = VOTE_RECEIPT_REPOSITORY
+            .get(&VoteReceiptKey {
+                request_id: *request_id,
+                approver_id: *approver_id,
+            })
+            .ok_or(RequestError::VoteReceiptNotFound {
+                request_id: Uuid::from_bytes(*request_id).hyphenated().to_string(),
+                approver_id: Uuid::from_bytes(*approver_id).hyphenated().to_string(),
+            })?;
+
+        Ok(receipt)
+    }
+
+    /// Resumes the execution of a failed request from the step that failed.
+    ///
+    /// Only the requester can resume the execution, and only requests that are executed in several steps
+    /// can be resumed, since their completed steps are skipped instead of being executed again.
+    pub async fn retry_request_execution(
+        &self,
+        input: RetryRequestExecutionInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let caller = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let mut request = self.get_request(request_id.as_bytes())?;
+
+        if request.requested_by != caller.id {
+            Err(RequestError::Forbidden {
+                request_id: request_id.hyphenated().to_string(),
+            })?
+        }
+
+        if !matches!(request.status, RequestStatus::Failed { .. })
+            || request.execution_steps.is_empty()
+        {
+            Err(RequestError::ValidationError {
+                info: "Only failed requests that are executed in several steps can be retried."
+                    .to_string(),
+            })?
+        }
+
+        // the request is approved again so that it gets scheduled for execution
+        request.status = RequestStatus::Approved;
+        request.last_modification_timestamp = ctx.now();
+
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
         Ok(request)
     }
 
+    /// Cancels a time-locked transfer that was approved but is not executed yet.
+    ///
+    /// Only the members of the canceller groups of the time lock can cancel the transfer, and only until it is unlocked.
+    pub async fn cancel_time_locked_request(
+        &self,
+        input: CancelTimeLockedRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let caller = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let request = self.get_request(request_id.as_bytes())?;
+
+        if !request.can_cancel(&caller.groups, ctx.now()) {
+            Err(RequestError::ValidationError {
+                info: "Only time-locked transfers can be cancelled, by the members of their canceller groups until they are unlocked."
+                    .to_string(),
+            })?
+        }
+
+        self.request_repository.cancel_request(
+            request,
+            input
+                .reason
+                .unwrap_or_else(|| format!("Cancelled by {} during the time lock", caller.name)),
+            ctx.now(),
+        );
+
+        self.get_request(request_id.as_bytes())
+    }
+
     pub async fn fail_request(
         &self,
         mut request: Request,
@@ -456,13 +941,73 @@ impl RequestService {
 
         self.failed_request_hook(&request).await;
     }
+
+    /// Records the screening of a transfer request that is still pending approval.
+    ///
+    /// A screening that passed lets the approvals collected so far take effect, while a flagged or
+    /// unavailable screening rejects the request if it was blocking its approval.
+    pub async fn update_transfer_screening(
+        &self,
+        request_id: &UUID,
+        screening: TransferScreening,
+        now: Timestamp,
+    ) -> ServiceResult<Request> {
+        let mut request = self.get_request(request_id)?;
+
+        let RequestOperation::Transfer(operation) = &mut request.operation else {
+            Err(RequestError::ValidationError {
+                info: "Only transfer requests can be screened.".to_string(),
+            })?
+        };
+
+        if request.status != RequestStatus::Created {
+            return Ok(request);
+        }
+
+        let is_rejected = screening.blocks_approval
+            && matches!(
+                screening.status,
+                TransferScreeningStatus::Flagged { .. }
+                    | TransferScreeningStatus::Unavailable { .. }
+            );
+
+        operation.screening = Some(screening);
+        request.last_modification_timestamp = now;
+
+        let maybe_evaluation = match is_rejected {
+            true => {
+                request.status = RequestStatus::Rejected;
+                None
+            }
+            false => request.reevaluate().await?,
+        };
+
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
+        if let Some(evaluation) = maybe_evaluation {
+            self.evaluation_result_repository
+                .insert(request.id, evaluation);
+        }
+
+        if request.status == RequestStatus::Rejected {
+            self.rejected_request_hook(&request).await;
+        }
+
+        Ok(request)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        core::test_utils,
+        core::{
+            ic_cdk::next_time,
+            read_system_info,
+            test_providers::{MockTimeProvider, SequentialUuidProvider},
+            test_utils, write_system_info,
+        },
         models::{
             account_test_utils::mock_account,
             permission::Allow,
@@ -471,12 +1016,15 @@ mod tests {
             request_specifier::{RequestSpecifier, UserSpecifier},
             request_test_utils::mock_request,
             resource::ResourceIds,
+            system::{OperationAvailability, OperationFlag, StationDecommission},
             user_test_utils::mock_user,
             AddAccountOperationInput, AddAddressBookEntryOperation,
             AddAddressBookEntryOperationInput, AddUserOperation, AddUserOperationInput, Blockchain,
-            BlockchainStandard, Metadata, Percentage, RequestApproval, RequestOperation,
-            RequestPolicy, RequestStatus, TransferOperation, TransferOperationInput, User,
-            UserGroup, UserStatus, ADMIN_GROUP_ID,
+            BlockchainStandard, CommitAssetBatchOperation, CommitAssetBatchOperationInput,
+            MaintenanceModeConfig, Metadata, Percentage, RequestApproval, RequestExecutionPlan,
+            RequestExecutionStep, RequestExecutionStepStatus, RequestOperation,
+            RequestOperationType, RequestPolicy, RequestStatus, TransferOperation,
+            TransferOperationInput, User, UserGroup, UserStatus, ADMIN_GROUP_ID,
         },
         repositories::{
             request_policy::REQUEST_POLICY_REPOSITORY, AccountRepository, NOTIFICATION_REPOSITORY,
@@ -485,9 +1033,10 @@ mod tests {
         services::AccountService,
     };
     use candid::Principal;
-    use orbit_essentials::model::ModelKey;
+    use orbit_essentials::{model::ModelKey, utils::timestamp_to_rfc3339};
     use station_api::{
-        ListRequestsOperationTypeDTO, RequestApprovalStatusDTO, RequestStatusCodeDTO,
+        ListRequestsOperationTypeDTO, RequestApprovalStatusDTO, RequestExecutionScheduleDTO,
+        RequestStatusCodeDTO,
     };
 
     struct TestContext {
@@ -507,6 +1056,7 @@ mod tests {
             UserGroup {
                 id: ADMIN_GROUP_ID.to_owned(),
                 name: "Admin".to_owned(),
+                quotas: Default::default(),
                 last_modification_timestamp: 0,
             },
         );
@@ -530,34 +1080,217 @@ mod tests {
         }
     }
 
-    #[test]
-    fn get_request() {
+    #[test]
+    fn get_request() {
+        let ctx = setup();
+        let account_id = Uuid::new_v4();
+        let mut account = mock_account();
+        account.id = *account_id.as_bytes();
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.operation = RequestOperation::Transfer(TransferOperation {
+            transfer_id: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
+            fee: None,
+            input: TransferOperationInput {
+                from_account_id: *account_id.as_bytes(),
+                amount: candid::Nat(100u32.into()),
+                fee: None,
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                to: "0x1234".to_string(),
+                fee_strategy: None,
+                max_fee: None,
+                compliance: None,
+                from_address: None,
+            },
+            time_lock: None,
+            duplicate_warning: None,
+        });
+
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        let result = ctx.service.get_request(&request.id);
+
+        assert_eq!(request, result.unwrap());
+    }
+
+    #[test]
+    fn get_request_canonical_is_refused_once_compacted() {
+        let ctx = setup();
+        let mut request = mock_request();
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        let (canonical_json, hash) = ctx.service.get_request_canonical(&request.id).unwrap();
+        assert_eq!(canonical_json, request.to_canonical_json());
+        assert_eq!(hash, Request::canonical_hash(&canonical_json));
+
+        request.compacted_at = Some(next_time());
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        assert!(ctx.service.get_request_canonical(&request.id).is_err());
+    }
+
+    #[tokio::test]
+    async fn flagged_screening_rejects_blocked_transfer_request() {
+        let ctx = setup();
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.status = RequestStatus::Created;
+        let mut screening = TransferScreening::new(Principal::from_slice(&[1; 29]), true, 0);
+        if let RequestOperation::Transfer(operation) = &mut request.operation {
+            operation.screening = Some(screening.clone());
+        }
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        screening.status = TransferScreeningStatus::Flagged {
+            reason: "sanctioned address".to_string(),
+            reference: None,
+            screened_at: 10,
+        };
+
+        let request = ctx
+            .service
+            .update_transfer_screening(&request.id, screening.clone(), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Rejected);
+        assert!(matches!(
+            request.operation,
+            RequestOperation::Transfer(TransferOperation {
+                screening: Some(stored),
+                ..
+            }) if stored == screening
+        ));
+    }
+
+    #[tokio::test]
+    async fn retry_failed_request_execution() {
+        let ctx = setup();
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.status = RequestStatus::Failed {
+            reason: Some("failed".to_string()),
+        };
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        let input = RetryRequestExecutionInput {
+            request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+        };
+
+        // requests without execution steps can't be resumed
+        ctx.service
+            .retry_request_execution(input.clone(), &ctx.call_context)
+            .await
+            .expect_err("Request without execution steps should not be retried");
+
+        request.execution_steps = vec![RequestExecutionStep {
+            name: "create_canister".to_string(),
+            status: RequestExecutionStepStatus::Failed {
+                reason: "failed".to_string(),
+                failed_at: 0,
+            },
+            attempts: 1,
+        }];
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        let request = ctx
+            .service
+            .retry_request_execution(input, &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Approved);
+        assert_eq!(request.execution_steps.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn reschedule_request_resets_its_approvals() {
+        let ctx = setup();
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.status = RequestStatus::Scheduled { scheduled_at: 10 };
+
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        let execution_time = next_time() + 60 * 1_000_000_000;
+        let window_end = execution_time + 60 * 1_000_000_000;
+        let input = RescheduleRequestInput {
+            request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+            execution_plan: RequestExecutionScheduleDTO::Scheduled {
+                execution_time: timestamp_to_rfc3339(&execution_time),
+                window_end: Some(timestamp_to_rfc3339(&window_end)),
+            },
+        };
+
+        let rescheduled = ctx
+            .service
+            .reschedule_request(input.clone(), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            rescheduled.execution_plan,
+            RequestExecutionPlan::Scheduled {
+                execution_time,
+                window_end: Some(window_end),
+            }
+        );
+        assert!(!rescheduled
+            .approvals
+            .iter()
+            .any(|approval| approval.approver_id == [1; 16]));
+        assert!(!matches!(
+            rescheduled.status,
+            RequestStatus::Scheduled { .. }
+        ));
+
+        request.status = RequestStatus::Processing { started_at: 20 };
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        ctx.service
+            .reschedule_request(input, &ctx.call_context)
+            .await
+            .expect_err("Requests being executed should not be rescheduled");
+    }
+
+    #[tokio::test]
+    async fn reschedule_request_rejects_malformed_execution_time() {
         let ctx = setup();
-        let account_id = Uuid::new_v4();
-        let mut account = mock_account();
-        account.id = *account_id.as_bytes();
         let mut request = mock_request();
         request.requested_by = ctx.caller_user.id;
-        request.operation = RequestOperation::Transfer(TransferOperation {
-            transfer_id: None,
-            fee: None,
-            input: TransferOperationInput {
-                from_account_id: *account_id.as_bytes(),
-                amount: candid::Nat(100u32.into()),
-                fee: None,
-                metadata: Metadata::default(),
-                network: "mainnet".to_string(),
-                to: "0x1234".to_string(),
-            },
-        });
+        request.status = RequestStatus::Scheduled { scheduled_at: 10 };
 
-        ctx.account_repository
-            .insert(account.to_key(), account.clone());
         ctx.repository.insert(request.to_key(), request.to_owned());
 
-        let result = ctx.service.get_request(&request.id);
+        let error = ctx
+            .service
+            .reschedule_request(
+                RescheduleRequestInput {
+                    request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+                    execution_plan: RequestExecutionScheduleDTO::Scheduled {
+                        execution_time: "2024-02-30T10:00:00Z".to_string(),
+                        window_end: None,
+                    },
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap_err();
 
-        assert_eq!(request, result.unwrap());
+        assert_eq!(error.code, "MALFORMED_TIMESTAMP");
+        assert_eq!(
+            ctx.repository.get(&request.to_key()).unwrap().status,
+            RequestStatus::Scheduled { scheduled_at: 10 }
+        );
     }
 
     #[tokio::test]
@@ -571,6 +1304,9 @@ mod tests {
         request.status = RequestStatus::Created;
         request.operation = RequestOperation::Transfer(TransferOperation {
             transfer_id: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
             fee: None,
             input: TransferOperationInput {
                 from_account_id: *account_id.as_bytes(),
@@ -579,7 +1315,13 @@ mod tests {
                 metadata: Metadata::default(),
                 network: "mainnet".to_string(),
                 to: "0x1234".to_string(),
+                fee_strategy: None,
+                max_fee: None,
+                compliance: None,
+                from_address: None,
             },
+            time_lock: None,
+            duplicate_warning: None,
         });
         request.approvals = vec![];
         let mut request_policy = mock_request_policy();
@@ -603,6 +1345,7 @@ mod tests {
                         .to_string(),
                     decision: RequestApprovalStatusDTO::Rejected,
                     reason: None,
+                    acknowledged_checklist: None,
                 },
                 &ctx.call_context,
             )
@@ -613,6 +1356,15 @@ mod tests {
             result.unwrap().approvals[0].status,
             RequestApprovalStatus::Rejected
         );
+
+        let receipt = ctx
+            .service
+            .get_vote_receipt(&request.id, &ctx.caller_user.id)
+            .unwrap();
+
+        assert_eq!(receipt.voter, ctx.call_context.caller());
+        assert_eq!(receipt.decision, RequestApprovalStatus::Rejected);
+        assert!(ctx.service.get_vote_receipt(&request.id, &[9; 16]).is_err());
     }
 
     #[tokio::test]
@@ -661,11 +1413,18 @@ mod tests {
                             metadata: vec![],
                             network: None,
                             to: "0x1234".to_string(),
+                            fee_strategy: None,
+                            max_fee: None,
+                            compliance: None,
+                            from_address: None,
                         },
                     ),
                     title: None,
                     summary: None,
                     execution_plan: None,
+                    confidential: None,
+                    summary_format: None,
+                    depends_on: None,
                 },
                 &ctx.call_context,
             )
@@ -677,6 +1436,336 @@ mod tests {
         assert_eq!(notifications[0].target_user_id, related_user.id);
     }
 
+    #[tokio::test]
+    async fn request_execution_notifies_the_groups_of_its_policies() {
+        let ctx = setup();
+        let group_id = [30; 16];
+
+        let mut group_member = mock_user();
+        group_member.groups = vec![group_id];
+        USER_REPOSITORY.insert(group_member.to_key(), group_member.clone());
+
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Group(vec![group_id]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Completed { completed_at: 1 };
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        ctx.service.completed_request_hook(&request).await;
+
+        let mut recipients = NOTIFICATION_REPOSITORY
+            .list()
+            .into_iter()
+            .filter(|notification| {
+                matches!(
+                    notification.notification_type,
+                    NotificationType::RequestExecuted(_)
+                )
+            })
+            .map(|notification| notification.target_user_id)
+            .collect::<Vec<_>>();
+        recipients.sort();
+
+        let mut expected = vec![request.requested_by, group_member.id];
+        expected.sort();
+
+        assert_eq!(recipients, expected);
+    }
+
+    #[tokio::test]
+    async fn request_creation_uses_call_context_providers() {
+        let ctx = setup();
+        let time_provider = Arc::new(MockTimeProvider::new(1_000));
+        let call_context = ctx
+            .call_context
+            .clone()
+            .with_time_provider(time_provider.clone())
+            .with_uuid_provider(Arc::new(SequentialUuidProvider::new(1)));
+
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::Transfer(
+                station_api::TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(account.id.to_owned())
+                        .hyphenated()
+                        .to_string(),
+                    amount: candid::Nat(100u32.into()),
+                    fee: Some(candid::Nat(100u32.into())),
+                    metadata: vec![],
+                    network: None,
+                    to: "0x1234".to_string(),
+                    fee_strategy: None,
+                    max_fee: None,
+                    compliance: None,
+                    from_address: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
+        };
+
+        let request = ctx
+            .service
+            .create_request(input.clone(), &call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(request.id, *Uuid::from_u128(1).as_bytes());
+        assert_eq!(request.created_timestamp, 1_000);
+        assert_eq!(
+            request.expiration_dt,
+            1_000 + Request::DEFAULT_EXPIRATION_PERIOD_NS
+        );
+
+        time_provider.advance(Request::DEFAULT_EXPIRATION_PERIOD_NS);
+
+        let next_request = ctx
+            .service
+            .create_request(input, &call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(next_request.id, *Uuid::from_u128(2).as_bytes());
+        assert_eq!(next_request.created_timestamp, request.expiration_dt);
+    }
+
+    #[tokio::test]
+    async fn maintenance_mode_only_allows_maintenance_requests() {
+        let ctx = setup();
+        let mut system_info = read_system_info();
+        system_info.set_maintenance_mode(MaintenanceModeConfig {
+            enabled: true,
+            reason: Some("Upgrading the ledgers".to_string()),
+            during_migrations: false,
+        });
+        write_system_info(system_info);
+
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let transfer_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::Transfer(
+                station_api::TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(account.id.to_owned())
+                        .hyphenated()
+                        .to_string(),
+                    amount: candid::Nat(100u32.into()),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                    to: "0x1234".to_string(),
+                    fee_strategy: None,
+                    max_fee: None,
+                    compliance: None,
+                    from_address: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
+        };
+
+        let error = ctx
+            .service
+            .create_request(transfer_input, &ctx.call_context)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.code, "STATION_IN_MAINTENANCE");
+
+        let leave_maintenance_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::ManageSystemInfo(
+                station_api::ManageSystemInfoOperationInput {
+                    name: None,
+                    cycle_obtain_strategy: None,
+                    auditors: None,
+                    address_book_sources: None,
+                    name_service: None,
+                    transfer_compliance_thresholds: None,
+                    transfer_max_fees: None,
+                    transfer_screening: None,
+                    transfer_time_lock: None,
+                    metadata_encryption: None,
+                    data_compaction: None,
+                    request_archival: None,
+                    branding: None,
+                    sub_treasuries: None,
+                    maintenance_mode: Some(station_api::MaintenanceModeConfigDTO {
+                        enabled: false,
+                        reason: None,
+                        during_migrations: false,
+                    }),
+                    request_text_limits: None,
+                    approval_reminders: None,
+                    balance_snapshots: None,
+                    fiat_valuation: None,
+                    operation_flags: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
+        };
+
+        assert!(ctx
+            .service
+            .create_request(leave_maintenance_input, &ctx.call_context)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn maintenance_mode_is_checked_before_the_request_is_built() {
+        let ctx = setup();
+        let mut system_info = read_system_info();
+        system_info.set_maintenance_mode(MaintenanceModeConfig {
+            enabled: true,
+            reason: None,
+            during_migrations: false,
+        });
+        write_system_info(system_info);
+
+        // the account does not exist, which the factory would report if it ran first
+        let transfer_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::Transfer(
+                station_api::TransferOperationInput {
+                    from_account_id: Uuid::new_v4().hyphenated().to_string(),
+                    amount: candid::Nat(100u32.into()),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                    to: "0x1234".to_string(),
+                    fee_strategy: None,
+                    max_fee: None,
+                    compliance: None,
+                    from_address: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
+        };
+
+        let error = ctx
+            .service
+            .create_request(transfer_input, &ctx.call_context)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.code, "STATION_IN_MAINTENANCE");
+    }
+
+    #[tokio::test]
+    async fn decommissioned_station_refuses_new_requests() {
+        let ctx = setup();
+        let mut system_info = read_system_info();
+        system_info.set_decommission(StationDecommission {
+            request_id: [1; 16],
+            reason: Some("The organization was wound down".to_string()),
+            decommissioned_at: 0,
+        });
+        write_system_info(system_info);
+
+        let decommission_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::DecommissionStation(
+                station_api::DecommissionStationOperationInput { reason: None },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
+        };
+
+        let error = ctx
+            .service
+            .create_request(decommission_input, &ctx.call_context)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.code, "STATION_DECOMMISSIONED");
+        assert_eq!(
+            error.details.unwrap().get("reason").unwrap(),
+            "The organization was wound down"
+        );
+    }
+
+    #[tokio::test]
+    async fn disabled_operations_cannot_be_requested() {
+        let ctx = setup();
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let transfer_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::Transfer(
+                station_api::TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(account.id.to_owned())
+                        .hyphenated()
+                        .to_string(),
+                    amount: candid::Nat(100u32.into()),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                    to: "0x1234".to_string(),
+                    fee_strategy: None,
+                    max_fee: None,
+                    compliance: None,
+                    from_address: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            summary_format: None,
+            depends_on: None,
+        };
+
+        for (availability, is_refused) in [
+            (OperationAvailability::Disabled, true),
+            (OperationAvailability::Deprecated, false),
+        ] {
+            let mut system_info = read_system_info();
+            system_info.set_operation_flags(vec![OperationFlag {
+                operation_type: RequestOperationType::Transfer,
+                availability,
+            }]);
+            write_system_info(system_info);
+
+            let result = ctx
+                .service
+                .create_request(transfer_input.clone(), &ctx.call_context)
+                .await;
+
+            assert_eq!(
+                result.err().map(|error| error.code) == Some("OPERATION_DISABLED".to_string()),
+                is_refused
+            );
+        }
+    }
+
     #[tokio::test]
     async fn user_approvals_on_their_own_request() {
         let ctx = setup();
@@ -688,6 +1777,9 @@ mod tests {
                 UserSpecifier::Group(vec![*ADMIN_GROUP_ID]),
                 Percentage(51),
             )]),
+            version: 0,
+            checklist: Vec::new(),
+            exclude_requester: false,
         };
 
         REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
@@ -708,6 +1800,9 @@ mod tests {
                     title: None,
                     summary: None,
                     execution_plan: Some(station_api::RequestExecutionScheduleDTO::Immediate),
+                    confidential: None,
+                    summary_format: None,
+                    depends_on: None,
                 },
                 &ctx.call_context,
             )
@@ -734,6 +1829,9 @@ mod tests {
                 UserSpecifier::Id(vec![requester.id, approver.id, another_user.id]),
                 2,
             )]),
+            version: 0,
+            checklist: Vec::new(),
+            exclude_requester: false,
         };
 
         REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
@@ -757,6 +1855,7 @@ mod tests {
                 decided_dt: 10,
                 last_modification_timestamp: 10,
                 status_reason: None,
+                acknowledged_checklist: Vec::new(),
             },
             RequestApproval {
                 approver_id: approver.id,
@@ -764,6 +1863,7 @@ mod tests {
                 decided_dt: 10,
                 last_modification_timestamp: 10,
                 status_reason: None,
+                acknowledged_checklist: Vec::new(),
             },
         ];
         request.status = RequestStatus::Failed {
@@ -785,6 +1885,7 @@ mod tests {
             paginate: None,
             sort_by: None,
             statuses: None,
+            api_version: None,
         };
 
         let users = vec![requester, approver, another_user];
@@ -812,6 +1913,9 @@ mod tests {
         request.status = RequestStatus::Created;
         request.operation = RequestOperation::Transfer(TransferOperation {
             transfer_id: None,
+            fee_estimate: None,
+            to_name: None,
+            screening: None,
             fee: None,
             input: TransferOperationInput {
                 from_account_id: [9; 16],
@@ -820,7 +1924,13 @@ mod tests {
                 metadata: Metadata::default(),
                 network: "mainnet".to_string(),
                 to: "0x1234".to_string(),
+                fee_strategy: None,
+                max_fee: None,
+                compliance: None,
+                from_address: None,
             },
+            time_lock: None,
+            duplicate_warning: None,
         });
         request.created_timestamp = 10;
         request.approvals = vec![];
@@ -852,6 +1962,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: false,
                     with_evaluation_results: false,
+                    api_version: None,
                 },
                 &ctx.call_context,
             )
@@ -861,6 +1972,60 @@ mod tests {
         assert_eq!(result.unwrap().items.len(), 1);
     }
 
+    #[tokio::test]
+    async fn older_clients_only_list_requests_they_can_decode() {
+        let ctx = setup();
+        let mut transfer = mock_request();
+        transfer.requested_by = ctx.caller_user.id;
+        ctx.repository
+            .insert(transfer.to_key(), transfer.to_owned());
+
+        let mut commit = mock_request();
+        commit.requested_by = ctx.caller_user.id;
+        commit.operation = RequestOperation::CommitAssetBatch(CommitAssetBatchOperation {
+            input: CommitAssetBatchOperationInput {
+                canister_id: Principal::from_slice(&[1; 29]),
+                batch_id: candid::Nat::from(1u64),
+                evidence: vec![0; 32],
+            },
+            evidence_rendering: None,
+        });
+        ctx.repository.insert(commit.to_key(), commit.to_owned());
+
+        let list_input = |api_version| ListRequestsInput {
+            requester_ids: None,
+            approver_ids: None,
+            created_from_dt: None,
+            created_to_dt: None,
+            expiration_from_dt: None,
+            expiration_to_dt: None,
+            operation_types: None,
+            statuses: None,
+            paginate: None,
+            sort_by: None,
+            only_approvable: false,
+            with_evaluation_results: false,
+            api_version,
+        };
+
+        let result = ctx
+            .service
+            .list_requests(list_input(Some(1)), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].id, transfer.id);
+
+        let result = ctx
+            .service
+            .list_requests(list_input(None), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 2);
+    }
+
     #[tokio::test]
     async fn only_list_votable_requests() {
         let ctx = setup();
@@ -894,6 +2059,7 @@ mod tests {
                     read_permission: Allow::users(account_owners.clone()),
                     configs_permission: Allow::users(account_owners.clone()),
                     transfer_permission: Allow::users(account_owners.clone()),
+                    network: None,
                 },
                 None,
             )
@@ -912,6 +2078,7 @@ mod tests {
                 identities: vec![Principal::from_slice(&[3; 29])],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                scheduled_memberships: vec![],
             },
         });
         irrelevant_request.created_timestamp = 9;
@@ -929,6 +2096,9 @@ mod tests {
                 transfer.status = RequestStatus::Created;
                 transfer.operation = RequestOperation::Transfer(TransferOperation {
                     transfer_id: None,
+                    fee_estimate: None,
+                    to_name: None,
+                    screening: None,
                     fee: None,
                     input: TransferOperationInput {
                         from_account_id: account.id,
@@ -937,7 +2107,13 @@ mod tests {
                         metadata: Metadata::default(),
                         network: "mainnet".to_string(),
                         to: "0x1234".to_string(),
+                        fee_strategy: None,
+                        max_fee: None,
+                        compliance: None,
+                        from_address: None,
                     },
+                    time_lock: None,
+                    duplicate_warning: None,
                 });
                 transfer.created_timestamp = 10 + i as u64;
                 transfer.approvals = vec![RequestApproval {
@@ -946,6 +2122,7 @@ mod tests {
                     status: RequestApprovalStatus::Approved,
                     status_reason: None,
                     approver_id: transfer.requested_by,
+                    acknowledged_checklist: Vec::new(),
                 }];
                 ctx.repository
                     .insert(transfer.to_key(), transfer.to_owned());
@@ -971,6 +2148,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    api_version: None,
                 },
                 &ctx.call_context,
             )
@@ -996,6 +2174,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    api_version: None,
                 },
                 &CallContext::new(transfer_requester_user.identities[0]),
             )
@@ -1020,6 +2199,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    api_version: None,
                 },
                 &CallContext::new(no_access_user.identities[0]),
             )
@@ -1036,6 +2216,7 @@ mod tests {
                         .hyphenated()
                         .to_string(),
                     reason: None,
+                    acknowledged_checklist: None,
                 },
                 &ctx.call_context,
             )
@@ -1059,6 +2240,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    api_version: None,
                 },
                 &ctx.call_context,
             )
@@ -1078,11 +2260,16 @@ mod benchs {
         core::ic_cdk::spawn,
         models::{
             permission::{Allow, Permission},
+            request_specifier::{RequestSpecifier, UserSpecifier},
             request_test_utils::mock_request,
+            resource::ResourceIds,
             user_test_utils::mock_user,
-            UserStatus,
+            Percentage, RequestPolicy, RequestPolicyRule, UserStatus,
+        },
+        repositories::{
+            permission::PERMISSION_REPOSITORY, request_policy::REQUEST_POLICY_REPOSITORY,
+            USER_REPOSITORY,
         },
-        repositories::{permission::PERMISSION_REPOSITORY, USER_REPOSITORY},
     };
     use canbench_rs::{bench, BenchResult};
     use candid::Principal;
@@ -1126,6 +2313,40 @@ mod benchs {
         end_creation_time
     }
 
+    /// Adds the given number of policies that all match the given specifier, alternating between rules so that
+    /// every policy needs to be evaluated.
+    fn create_test_policies(specifier: RequestSpecifier, policies_to_insert: usize) {
+        let approvers = USER_REPOSITORY
+            .list()
+            .into_iter()
+            .map(|user| user.id)
+            .collect::<Vec<_>>();
+
+        for i in 0..policies_to_insert {
+            let rule = match i % 3 {
+                0 => RequestPolicyRule::Quorum(UserSpecifier::Id(approvers.clone()), 5),
+                1 => RequestPolicyRule::QuorumPercentage(UserSpecifier::Any, Percentage(75)),
+                _ => RequestPolicyRule::And(vec![
+                    RequestPolicyRule::Quorum(UserSpecifier::Any, 2),
+                    RequestPolicyRule::QuorumPercentage(
+                        UserSpecifier::Id(approvers.clone()),
+                        Percentage(50),
+                    ),
+                ]),
+            };
+            let policy = RequestPolicy {
+                id: *Uuid::new_v4().as_bytes(),
+                specifier: specifier.clone(),
+                rule,
+                version: 0,
+                checklist: Vec::new(),
+                exclude_requester: false,
+            };
+
+            REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+        }
+    }
+
     #[bench(raw)]
     fn service_find_all_requests_from_2k_dataset() -> BenchResult {
         let end_creation_time = create_test_requests(2000u64);
@@ -1155,6 +2376,7 @@ mod benchs {
                             )),
                             only_approvable: false,
                             with_evaluation_results: false,
+                            api_version: None,
                         },
                         &CallContext::new(Principal::from_slice(&[5; 29])),
                     )
@@ -1200,6 +2422,7 @@ mod benchs {
                             )),
                             only_approvable: false,
                             with_evaluation_results: false,
+                            api_version: None,
                         },
                         &CallContext::new(Principal::from_slice(&[5; 29])),
                     )
@@ -1213,4 +2436,101 @@ mod benchs {
             });
         })
     }
+
+    #[bench(raw)]
+    fn service_create_request_with_1k_policies() -> BenchResult {
+        create_test_requests(0);
+        create_test_policies(RequestSpecifier::AddUserGroup, 1_000);
+
+        canbench_rs::bench_fn(|| {
+            spawn(async move {
+                let request = REQUEST_SERVICE
+                    .create_request(
+                        station_api::CreateRequestInput {
+                            operation: station_api::RequestOperationInput::AddUserGroup(
+                                station_api::AddUserGroupOperationInput {
+                                    name: "finance".to_string(),
+                                    quotas: None,
+                                },
+                            ),
+                            title: None,
+                            summary: None,
+                            execution_plan: None,
+                            confidential: None,
+                            summary_format: None,
+                            depends_on: None,
+                        },
+                        &CallContext::new(Principal::from_slice(&[5; 29])),
+                    )
+                    .await
+                    .unwrap();
+
+                if request.status != RequestStatus::Created {
+                    panic!(
+                        "Expected the request to be pending, got {:?}",
+                        request.status
+                    );
+                }
+            });
+        })
+    }
+
+    #[bench(raw)]
+    fn evaluate_request_with_1k_policies() -> BenchResult {
+        create_test_requests(0);
+        create_test_policies(RequestSpecifier::Transfer(ResourceIds::Any), 1_000);
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.to_owned());
+
+        canbench_rs::bench_fn(|| {
+            spawn(async move {
+                let evaluation = request.reevaluate().await.unwrap();
+
+                if evaluation.is_none() {
+                    panic!("Expected the request to be evaluated");
+                }
+            });
+        })
+    }
+
+    #[bench(raw)]
+    fn service_list_requests_from_100k_dataset_without_filters() -> BenchResult {
+        create_test_requests(100_000u64);
+
+        canbench_rs::bench_fn(|| {
+            spawn(async move {
+                let result = REQUEST_SERVICE
+                    .list_requests(
+                        station_api::ListRequestsInput {
+                            created_from_dt: None,
+                            created_to_dt: None,
+                            statuses: None,
+                            approver_ids: None,
+                            requester_ids: None,
+                            operation_types: None,
+                            expiration_from_dt: None,
+                            expiration_to_dt: None,
+                            paginate: Some(station_api::PaginationInput {
+                                limit: Some(25),
+                                offset: None,
+                            }),
+                            sort_by: None,
+                            only_approvable: false,
+                            with_evaluation_results: false,
+                            api_version: None,
+                        },
+                        &CallContext::new(Principal::from_slice(&[5; 29])),
+                    )
+                    .await;
+
+                let paginated_data = result.unwrap();
+
+                if paginated_data.total == 0 {
+                    panic!("No requests were found");
+                }
+            });
+        })
+    }
 }
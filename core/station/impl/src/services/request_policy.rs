@@ -4,18 +4,23 @@ use crate::{
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
-    errors::RequestError,
+    errors::{RequestError, RequestPolicyError},
+    log_warn,
     models::{
+        lint_request_policies,
         request_policy_rule::RequestPolicyRuleInput,
         request_specifier::RequestSpecifier,
         resource::{Resource, ResourceAction, ResourceId},
         AddRequestPolicyOperationInput, EditRequestPolicyOperationInput, RequestPolicy,
-        RequestPolicyCallerPrivileges,
+        RequestPolicyCallerPrivileges, RequestPolicyIssue, UserStatus,
+    },
+    repositories::{
+        request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
+        UserWhereClause, USER_REPOSITORY,
     },
-    repositories::request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
 };
 use lazy_static::lazy_static;
-use orbit_essentials::{api::ServiceResult, cdk::api::print, types::UUID};
+use orbit_essentials::{api::ServiceResult, types::UUID};
 use orbit_essentials::{model::ModelValidator, repository::Repository};
 use station_api::ListRequestPoliciesInput;
 use std::sync::Arc;
@@ -61,6 +66,9 @@ impl RequestPolicyService {
             id: *Uuid::new_v4().as_bytes(),
             specifier: input.specifier,
             rule: input.rule,
+            checklist: input.checklist,
+            exclude_requester: input.exclude_requester,
+            version: 0,
         };
 
         policy.validate()?;
@@ -86,10 +94,7 @@ impl RequestPolicyService {
                     if let Err(RequestError::PolicyNotFound { id }) =
                         self.remove_request_policy(existing_policy_id)
                     {
-                        print(format!(
-                            "Cannot handle policy change: policy {} not found",
-                            id
-                        ));
+                        log_warn!("Cannot handle policy change: policy {} not found", id);
                     }
 
                     // Directly modify the policy_id in place
@@ -104,6 +109,9 @@ impl RequestPolicyService {
                             policy_id: *existing_policy_id,
                             specifier: Some(specifier),
                             rule: Some(policy_rule),
+                            expected_version: None,
+                            checklist: None,
+                            exclude_requester: None,
                         })?;
                     }
                     None => {
@@ -111,6 +119,8 @@ impl RequestPolicyService {
                         let policy = self.add_request_policy(AddRequestPolicyOperationInput {
                             specifier,
                             rule: policy_rule,
+                            checklist: Vec::new(),
+                            exclude_requester: false,
                         })?;
 
                         *editable_policy_id = Some(policy.id);
@@ -128,6 +138,15 @@ impl RequestPolicyService {
     ) -> ServiceResult<RequestPolicy> {
         let mut policy = self.get_request_policy(&input.policy_id)?;
 
+        if let Some(expected_version) = input.expected_version {
+            if policy.version != expected_version {
+                Err(RequestPolicyError::VersionConflict {
+                    expected_version,
+                    current_version: policy.version,
+                })?
+            }
+        }
+
         if let Some(specifier) = input.specifier {
             policy.specifier = specifier;
         }
@@ -136,7 +155,16 @@ impl RequestPolicyService {
             policy.rule = policy_rule;
         }
 
+        if let Some(checklist) = input.checklist {
+            policy.checklist = checklist;
+        }
+
+        if let Some(exclude_requester) = input.exclude_requester {
+            policy.exclude_requester = exclude_requester;
+        }
+
         policy.validate()?;
+        policy.version += 1;
 
         self.request_policy_repository
             .insert(policy.id, policy.to_owned());
@@ -191,6 +219,18 @@ impl RequestPolicyService {
 
         Ok(result)
     }
+
+    /// Examines all the request policies against the active users, to find the policies that can't pass
+    /// or that pass more easily than intended.
+    pub fn lint_request_policies(&self) -> Vec<RequestPolicyIssue> {
+        let active_users = USER_REPOSITORY.find_where(UserWhereClause {
+            search_term: None,
+            statuses: Some(vec![UserStatus::Active]),
+            groups: None,
+        });
+
+        lint_request_policies(&self.request_policy_repository.list(), &active_users)
+    }
 }
 
 #[cfg(test)]
@@ -211,6 +251,8 @@ mod tests {
         let policy = service.add_request_policy(AddRequestPolicyOperationInput {
             specifier: RequestSpecifier::AddAccount,
             rule: RequestPolicyRule::AutoApproved,
+            checklist: Vec::new(),
+            exclude_requester: false,
         });
 
         assert!(policy.is_ok());
@@ -225,6 +267,9 @@ mod tests {
             policy_id: policy.id,
             specifier: Some(RequestSpecifier::AddAccount),
             rule: Some(RequestPolicyRule::AutoApproved),
+            expected_version: None,
+            checklist: None,
+            exclude_requester: None,
         });
 
         assert!(policy.is_ok());
@@ -236,6 +281,40 @@ mod tests {
         assert_eq!(updated_policy.rule, policy.rule);
     }
 
+    #[test]
+    fn edit_with_outdated_version_fails() {
+        let service = REQUEST_POLICY_SERVICE.clone();
+        let policy = service
+            .add_request_policy(AddRequestPolicyOperationInput {
+                specifier: RequestSpecifier::AddAccount,
+                rule: RequestPolicyRule::AutoApproved,
+                checklist: Vec::new(),
+                exclude_requester: false,
+            })
+            .unwrap();
+
+        let edit = EditRequestPolicyOperationInput {
+            policy_id: policy.id,
+            specifier: None,
+            rule: Some(RequestPolicyRule::AutoApproved),
+            expected_version: Some(policy.version),
+            checklist: None,
+            exclude_requester: None,
+        };
+
+        let edited_policy = service.edit_request_policy(edit.clone()).unwrap();
+
+        assert_eq!(edited_policy.version, policy.version + 1);
+
+        let result = service.edit_request_policy(edit);
+
+        assert!(result.is_err());
+        assert_eq!(
+            service.get_request_policy(&policy.id).unwrap().version,
+            policy.version + 1
+        );
+    }
+
     #[test]
     fn test_get_request_policy_not_found() {
         let service = REQUEST_POLICY_SERVICE.clone();
@@ -272,6 +351,8 @@ mod tests {
             .add_request_policy(AddRequestPolicyOperationInput {
                 specifier: RequestSpecifier::AddAccount,
                 rule: RequestPolicyRule::AutoApproved,
+                checklist: Vec::new(),
+                exclude_requester: false,
             })
             .unwrap();
 
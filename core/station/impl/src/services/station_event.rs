@@ -0,0 +1,351 @@
+use crate::{
+    core::{generate_uuid_v4, ic_cdk::next_time, observer::Observer},
+    errors::StationEventError,
+    jobs::schedule_station_event_delivery,
+    models::{
+        Account, EventOutboxEntry, EventSubscriber, Request, RequestStatus, StationEvent,
+        StationEventKind,
+    },
+    repositories::{
+        EventOutboxRepository, EventSubscriberRepository, EVENT_OUTBOX_REPOSITORY,
+        EVENT_SUBSCRIBER_REPOSITORY,
+    },
+    services::SYSTEM_SERVICE,
+};
+use candid::Principal;
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref STATION_EVENT_SERVICE: Arc<StationEventService> =
+        Arc::new(StationEventService::new(
+            Arc::clone(&EVENT_SUBSCRIBER_REPOSITORY),
+            Arc::clone(&EVENT_OUTBOX_REPOSITORY),
+        ));
+}
+
+#[derive(Default, Debug)]
+pub struct StationEventService {
+    subscriber_repository: Arc<EventSubscriberRepository>,
+    outbox_repository: Arc<EventOutboxRepository>,
+}
+
+impl StationEventService {
+    /// The oldest pending events of a subscriber are dropped once this limit is reached, so that an
+    /// unresponsive subscriber can't grow the outbox indefinitely.
+    ///
+    /// Events are only delivered at least once while the subscriber stays below this limit, the dropped
+    /// events are lost silently and the subscriber can only notice them as gaps in the event ids.
+    pub const MAX_PENDING_EVENTS_PER_SUBSCRIBER: usize = 10_000;
+
+    pub fn new(
+        subscriber_repository: Arc<EventSubscriberRepository>,
+        outbox_repository: Arc<EventOutboxRepository>,
+    ) -> Self {
+        Self {
+            subscriber_repository,
+            outbox_repository,
+        }
+    }
+
+    /// Subscribes the canister to the given events, replacing its previous subscription if any.
+    pub async fn subscribe(
+        &self,
+        canister_id: Principal,
+        method_name: String,
+        events: Vec<StationEventKind>,
+    ) -> ServiceResult<EventSubscriber> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let mut subscriber = match self.subscriber_repository.find_by_canister_id(&canister_id) {
+            Some(subscriber) => subscriber,
+            None => {
+                if self.subscriber_repository.len() >= EventSubscriber::MAX_SUBSCRIBERS {
+                    Err(StationEventError::TooManySubscribers {
+                        max: EventSubscriber::MAX_SUBSCRIBERS,
+                    })?
+                }
+
+                EventSubscriber {
+                    id: *generate_uuid_v4().await.as_bytes(),
+                    canister_id,
+                    method_name: String::new(),
+                    events: Vec::new(),
+                    created_at: next_time(),
+                    acknowledged_events: 0,
+                    last_acknowledged_at: None,
+                    last_error: None,
+                }
+            }
+        };
+
+        subscriber.method_name = method_name;
+        subscriber.events = events;
+        subscriber.events.sort();
+        subscriber.events.dedup();
+
+        subscriber.validate()?;
+
+        self.subscriber_repository
+            .insert(subscriber.key(), subscriber.clone());
+
+        Ok(subscriber)
+    }
+
+    /// Removes the subscription of the canister together with its undelivered events.
+    pub fn unsubscribe(&self, canister_id: Principal) -> ServiceResult<()> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let subscriber = self
+            .subscriber_repository
+            .find_by_canister_id(&canister_id)
+            .ok_or(StationEventError::SubscriberNotFound)?;
+
+        self.outbox_repository.remove_by_subscriber(subscriber.id);
+        self.subscriber_repository.remove(&subscriber.key());
+
+        Ok(())
+    }
+
+    pub fn list_subscribers(&self) -> Vec<EventSubscriber> {
+        self.subscriber_repository.list()
+    }
+
+    pub fn count_pending_events(&self, subscriber: &EventSubscriber) -> usize {
+        self.outbox_repository.count_by_subscriber(subscriber.id)
+    }
+
+    /// Adds the event to the outbox of every subscriber of its kind and schedules the delivery.
+    pub fn publish(&self, event: StationEvent) {
+        let subscribers: Vec<EventSubscriber> = self
+            .subscriber_repository
+            .list()
+            .into_iter()
+            .filter(|subscriber| subscriber.is_subscribed_to(event.kind()))
+            .collect();
+
+        if subscribers.is_empty() {
+            return;
+        }
+
+        let event_id = next_time();
+        for subscriber in subscribers {
+            if self.outbox_repository.count_by_subscriber(subscriber.id)
+                >= Self::MAX_PENDING_EVENTS_PER_SUBSCRIBER
+            {
+                if let Some(oldest) = self
+                    .outbox_repository
+                    .find_by_subscriber(subscriber.id, 1)
+                    .pop()
+                {
+                    self.outbox_repository.remove(&oldest.key());
+                }
+            }
+
+            let entry = EventOutboxEntry {
+                event_id,
+                subscriber_id: subscriber.id,
+                event: event.clone(),
+                attempts: 0,
+                next_attempt_at: event_id,
+                created_at: event_id,
+            };
+
+            self.outbox_repository.insert(entry.key(), entry);
+        }
+
+        schedule_station_event_delivery(event_id);
+    }
+}
+
+pub fn station_events_observe_insert_request(observer: &mut Observer<(Request, Option<Request>)>) {
+    observer.add_listener(Box::new(|(request, prev)| {
+        if let RequestStatus::Completed { completed_at } = &request.status {
+            let was_completed = matches!(
+                prev,
+                Some(Request {
+                    status: RequestStatus::Completed { .. },
+                    ..
+                })
+            );
+
+            if !was_completed {
+                STATION_EVENT_SERVICE.publish(StationEvent::RequestExecuted {
+                    request_id: request.id,
+                    completed_at: *completed_at,
+                });
+            }
+        }
+    }));
+}
+
+pub fn station_events_observe_insert_account(observer: &mut Observer<(Account, Option<Account>)>) {
+    observer.add_listener(Box::new(|(account, prev)| {
+        if !SYSTEM_SERVICE.is_healthy() {
+            // Skip publishing events during system init
+            return;
+        }
+
+        let previous_balance = prev.as_ref().and_then(|prev| prev.balance.as_ref());
+
+        if let (Some(balance), Some(previous_balance)) = (&account.balance, previous_balance) {
+            if balance.balance > previous_balance.balance {
+                STATION_EVENT_SERVICE.publish(StationEvent::DepositDetected {
+                    account_id: account.id,
+                    amount: balance.balance.clone() - previous_balance.balance.clone(),
+                    balance: balance.balance.clone(),
+                    detected_at: balance.last_modification_timestamp,
+                });
+            }
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{read_system_info, test_utils, write_system_info},
+        models::{request_test_utils::mock_request, system::StationDecommission},
+    };
+
+    fn setup_subscriber(events: Vec<StationEventKind>) -> EventSubscriber {
+        let subscriber = EventSubscriber {
+            events,
+            ..crate::models::station_event_test_utils::mock_event_subscriber()
+        };
+        EVENT_SUBSCRIBER_REPOSITORY.insert(subscriber.key(), subscriber.clone());
+
+        subscriber
+    }
+
+    #[tokio::test]
+    async fn subscribe_replaces_previous_subscription() {
+        test_utils::init_canister_system();
+
+        let canister_id = Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap();
+        let subscriber = STATION_EVENT_SERVICE
+            .subscribe(
+                canister_id,
+                "on_event".to_string(),
+                vec![StationEventKind::RequestExecuted],
+            )
+            .await
+            .unwrap();
+
+        let updated_subscriber = STATION_EVENT_SERVICE
+            .subscribe(
+                canister_id,
+                "on_station_event".to_string(),
+                vec![
+                    StationEventKind::DepositDetected,
+                    StationEventKind::DepositDetected,
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated_subscriber.id, subscriber.id);
+        assert_eq!(updated_subscriber.method_name, "on_station_event");
+        assert_eq!(
+            updated_subscriber.events,
+            vec![StationEventKind::DepositDetected]
+        );
+        assert_eq!(STATION_EVENT_SERVICE.list_subscribers().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn decommissioned_station_refuses_subscriptions() {
+        test_utils::init_canister_system();
+        let mut system_info = read_system_info();
+        system_info.set_decommission(StationDecommission {
+            request_id: [1; 16],
+            reason: None,
+            decommissioned_at: 0,
+        });
+        write_system_info(system_info);
+
+        let result = STATION_EVENT_SERVICE
+            .subscribe(
+                Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap(),
+                "on_event".to_string(),
+                vec![StationEventKind::RequestExecuted],
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(STATION_EVENT_SERVICE.list_subscribers().is_empty());
+    }
+
+    #[tokio::test]
+    async fn subscribe_rejects_non_canister_principals() {
+        let result = STATION_EVENT_SERVICE
+            .subscribe(
+                Principal::from_slice(&[1; 29]),
+                "on_event".to_string(),
+                vec![StationEventKind::RequestExecuted],
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn publish_only_enqueues_for_subscribed_kinds() {
+        let request_subscriber = setup_subscriber(vec![StationEventKind::RequestExecuted]);
+        let deposit_subscriber = setup_subscriber(vec![StationEventKind::DepositDetected]);
+
+        STATION_EVENT_SERVICE.publish(StationEvent::RequestExecuted {
+            request_id: [1; 16],
+            completed_at: 0,
+        });
+
+        assert_eq!(
+            STATION_EVENT_SERVICE.count_pending_events(&request_subscriber),
+            1
+        );
+        assert_eq!(
+            STATION_EVENT_SERVICE.count_pending_events(&deposit_subscriber),
+            0
+        );
+    }
+
+    #[test]
+    fn completed_requests_are_published_once() {
+        let subscriber = setup_subscriber(vec![StationEventKind::RequestExecuted]);
+        let mut request = mock_request();
+        request.status = RequestStatus::Completed { completed_at: 1 };
+
+        let mut observer = Observer::default();
+        station_events_observe_insert_request(&mut observer);
+
+        observer.notify(&(request.clone(), None));
+        observer.notify(&(request.clone(), Some(request.clone())));
+
+        assert_eq!(STATION_EVENT_SERVICE.count_pending_events(&subscriber), 1);
+    }
+
+    #[test]
+    fn unsubscribe_drops_pending_events() {
+        let subscriber = setup_subscriber(vec![StationEventKind::RequestExecuted]);
+
+        STATION_EVENT_SERVICE.publish(StationEvent::RequestExecuted {
+            request_id: [1; 16],
+            completed_at: 0,
+        });
+
+        STATION_EVENT_SERVICE
+            .unsubscribe(subscriber.canister_id)
+            .unwrap();
+
+        assert_eq!(STATION_EVENT_SERVICE.count_pending_events(&subscriber), 0);
+        assert!(STATION_EVENT_SERVICE.list_subscribers().is_empty());
+    }
+}
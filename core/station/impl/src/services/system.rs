@@ -1,42 +1,60 @@
 use crate::{
     core::{
-        ic_cdk::{
-            api::{print, trap},
-            next_time,
-        },
+        hydrate_system_state,
+        ic_cdk::{api::trap, next_time},
         metrics::recompute_metrics,
-        read_system_info, read_system_state, write_system_info,
+        read_init_progress, read_system_info, read_system_state,
+        tracing::{self, Trace},
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+        write_init_progress, write_system_info,
     },
-    errors::SystemError,
+    errors::{NetworkError, SystemError},
     factories::blockchains::InternetComputer,
+    jobs::{
+        schedule_address_book_sync, schedule_approver_reminders, schedule_balance_snapshots,
+        schedule_fiat_price_refresh, schedule_request_archival, schedule_request_compaction,
+    },
+    log_error,
+    mappers::HelperMapper,
     models::{
-        system::{DisasterRecoveryCommittee, SystemInfo, SystemState},
-        CanisterInstallMode, CanisterUpgradeModeArgs, CycleObtainStrategy,
-        ManageSystemInfoOperationInput, RequestId, RequestKey, RequestOperation, RequestStatus,
-        SystemUpgradeTarget,
+        system::{
+            BlockchainFreeze, DisasterRecoveryCommittee, LedgerCanisterConfig,
+            OperationAvailability, RequestArchivalConfig, StationDecommission, SystemInfo,
+            SystemInitProgress, SystemState,
+        },
+        Account, Blockchain, BlockchainFreezeChangedNotification, CanisterInstallMode,
+        CanisterUpgradeModeArgs, CycleObtainStrategy, DecommissionStationOperationInput,
+        EditNetworkOperationInput, FreezeBlockchainOperationInput, ManageSystemInfoOperationInput,
+        Network, NetworkId, NotificationType, RequestId, RequestKey, RequestOperation,
+        RequestOperationType, RequestStatus, StationLogEntry, SystemUpgradeArgInjection,
+        SystemUpgradeTarget, UnfreezeBlockchainOperationInput, ADMIN_GROUP_ID,
     },
     repositories::{
-        permission::PERMISSION_REPOSITORY, RequestRepository, REQUEST_REPOSITORY,
+        permission::PERMISSION_REPOSITORY, RequestRepository, StationLogRepository,
+        StationLogWhereClause, ACCOUNT_REPOSITORY, REQUEST_REPOSITORY, STATION_LOG_REPOSITORY,
         USER_GROUP_REPOSITORY, USER_REPOSITORY,
     },
     services::{
         change_canister::{ChangeCanisterService, CHANGE_CANISTER_SERVICE},
         disaster_recovery::DISASTER_RECOVERY_SERVICE,
         request::{RequestService, REQUEST_SERVICE},
+        NotificationService, NOTIFICATION_SERVICE,
     },
     SYSTEM_VERSION,
 };
-use candid::Principal;
+use candid::{Decode, Encode, Principal};
 use canfund::{
     api::{cmc::IcCyclesMintingCanister, ledger::IcLedgerCanister},
     manager::options::ObtainCyclesOptions,
     operations::obtain::MintCycles,
 };
-use ic_ledger_types::{Subaccount, MAINNET_CYCLES_MINTING_CANISTER_ID, MAINNET_LEDGER_CANISTER_ID};
+use ic_ledger_types::{Subaccount, MAINNET_CYCLES_MINTING_CANISTER_ID};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ServiceResult;
+use orbit_essentials::model::ModelValidator;
 use orbit_essentials::repository::Repository;
-use station_api::{HealthStatus, SystemInit, SystemInstall, SystemUpgrade};
+use orbit_essentials::types::UUID;
+use station_api::{HealthStatus, ListStationLogsInput, SystemInit, SystemInstall, SystemUpgrade};
 use std::sync::Arc;
 use upgrader_api::UpgradeParams;
 use uuid::Uuid;
@@ -45,7 +63,9 @@ lazy_static! {
     pub static ref SYSTEM_SERVICE: Arc<SystemService> = Arc::new(SystemService::new(
         Arc::clone(&REQUEST_REPOSITORY),
         Arc::clone(&REQUEST_SERVICE),
-        Arc::clone(&CHANGE_CANISTER_SERVICE)
+        Arc::clone(&CHANGE_CANISTER_SERVICE),
+        Arc::clone(&STATION_LOG_REPOSITORY),
+        Arc::clone(&NOTIFICATION_SERVICE)
     ));
 }
 
@@ -54,18 +74,27 @@ pub struct SystemService {
     request_repository: Arc<RequestRepository>,
     request_service: Arc<RequestService>,
     change_canister_service: Arc<ChangeCanisterService>,
+    station_log_repository: Arc<StationLogRepository>,
+    notification_service: Arc<NotificationService>,
 }
 
 impl SystemService {
+    pub const DEFAULT_LOG_LIST_LIMIT: u16 = 100;
+    pub const MAX_LOG_LIST_LIMIT: u16 = 1000;
+
     pub fn new(
         request_repository: Arc<RequestRepository>,
         request_service: Arc<RequestService>,
         change_canister_service: Arc<ChangeCanisterService>,
+        station_log_repository: Arc<StationLogRepository>,
+        notification_service: Arc<NotificationService>,
     ) -> Self {
         Self {
             request_repository,
             request_service,
             change_canister_service,
+            station_log_repository,
+            notification_service,
         }
     }
 
@@ -111,6 +140,109 @@ impl SystemService {
         }
     }
 
+    /// Refuses the calls that change the state of the station while it is in maintenance mode.
+    pub fn assert_not_in_maintenance(&self) -> ServiceResult<()> {
+        if let SystemState::Initialized(system_info) = read_system_state() {
+            if system_info.is_in_maintenance() {
+                Err(SystemError::StationInMaintenance {
+                    reason: system_info.get_maintenance_mode().reason.clone(),
+                })?
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Refuses the calls that change the state of the station once it is decommissioned, which includes
+    /// creating, approving or executing requests.
+    pub fn assert_not_decommissioned(&self) -> ServiceResult<()> {
+        if let SystemState::Initialized(system_info) = read_system_state() {
+            if let Some(decommission) = system_info.get_decommission() {
+                Err(SystemError::StationDecommissioned {
+                    reason: decommission.reason.clone(),
+                })?
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Refuses the requests of the operation types that are disabled on the station.
+    pub fn assert_operation_enabled(
+        &self,
+        operation_type: &RequestOperationType,
+    ) -> ServiceResult<()> {
+        if let SystemState::Initialized(system_info) = read_system_state() {
+            if system_info.operation_availability(operation_type)
+                == Some(OperationAvailability::Disabled)
+            {
+                Err(SystemError::OperationDisabled {
+                    operation_type: operation_type.to_string(),
+                })?
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lists the entries of the station log that match the filters, newest first.
+    pub fn list_station_logs(
+        &self,
+        input: ListStationLogsInput,
+    ) -> ServiceResult<PaginatedData<StationLogEntry>> {
+        let entries = self
+            .station_log_repository
+            .find_where(StationLogWhereClause {
+                min_level: input.min_level.map(Into::into),
+                module: input.module,
+                from_dt: HelperMapper::to_optional_timestamp("from_dt", input.from_dt)?,
+                to_dt: HelperMapper::to_optional_timestamp("to_dt", input.to_dt)?,
+                correlation_id: input
+                    .correlation_id
+                    .map(HelperMapper::to_uuid)
+                    .transpose()?
+                    .map(|id| *id.as_bytes()),
+            });
+
+        let result = paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_LOG_LIST_LIMIT),
+            max_limit: Some(Self::MAX_LOG_LIST_LIMIT),
+            items: &entries,
+        })?;
+
+        Ok(result)
+    }
+
+    /// Returns the trace of the correlation id along with the log entries written for it, oldest first.
+    ///
+    /// The spans of a trace are lost on upgrade, in which case only its log entries are returned.
+    pub fn get_trace(&self, correlation_id: &UUID) -> ServiceResult<(Trace, Vec<StationLogEntry>)> {
+        let mut logs = self
+            .station_log_repository
+            .find_where(StationLogWhereClause {
+                correlation_id: Some(*correlation_id),
+                ..Default::default()
+            });
+
+        logs.truncate(Self::MAX_LOG_LIST_LIMIT as usize);
+        logs.reverse();
+
+        let trace = match tracing::get_trace(correlation_id) {
+            Some(trace) => trace,
+            None if !logs.is_empty() => Trace {
+                correlation_id: *correlation_id,
+                spans: Vec::new(),
+            },
+            None => Err(SystemError::TraceNotFound {
+                correlation_id: Uuid::from_bytes(*correlation_id).hyphenated().to_string(),
+            })?,
+        };
+
+        Ok((trace, logs))
+    }
+
     pub fn update_system_info(&self, input: ManageSystemInfoOperationInput) {
         let mut system_info = self.get_system_info();
 
@@ -122,7 +254,310 @@ impl SystemService {
             system_info.set_cycle_obtain_strategy(strategy);
         }
 
+        if let Some(thresholds) = input.transfer_compliance_thresholds {
+            system_info.set_transfer_compliance_thresholds(thresholds);
+        }
+
+        if let Some(max_fees) = input.transfer_max_fees {
+            system_info.set_transfer_max_fees(max_fees);
+        }
+
+        if let Some(transfer_screening) = input.transfer_screening {
+            system_info.set_transfer_screening(transfer_screening);
+        }
+
+        if let Some(transfer_time_lock) = input.transfer_time_lock {
+            system_info.set_transfer_time_lock(transfer_time_lock);
+        }
+
+        if let Some(metadata_encryption) = input.metadata_encryption {
+            system_info.set_metadata_encryption(metadata_encryption);
+        }
+
+        if let Some(name_service) = input.name_service {
+            system_info.set_name_service(name_service);
+        }
+
+        if let Some(auditors) = input.auditors {
+            system_info.set_auditors(auditors);
+        }
+
+        if let Some(sub_treasuries) = input.sub_treasuries {
+            system_info.set_sub_treasuries(sub_treasuries);
+        }
+
+        if let Some(maintenance_mode) = input.maintenance_mode {
+            system_info.set_maintenance_mode(maintenance_mode);
+        }
+
+        if let Some(request_text_limits) = input.request_text_limits {
+            system_info.set_request_text_limits(request_text_limits);
+        }
+
+        if let Some(operation_flags) = input.operation_flags {
+            system_info.set_operation_flags(operation_flags);
+        }
+
+        let next_balance_snapshot_at = input
+            .balance_snapshots
+            .as_ref()
+            .and_then(|config| config.next_snapshot_at(next_time()));
+        if let Some(balance_snapshots) = input.balance_snapshots {
+            system_info.set_balance_snapshots(balance_snapshots);
+        }
+
+        let refresh_fiat_prices = input.fiat_valuation.is_some();
+        if let Some(fiat_valuation) = input.fiat_valuation {
+            system_info.set_fiat_valuation(fiat_valuation);
+        }
+
+        let remind_approvers = input.approval_reminders.is_some();
+        if let Some(approval_reminders) = input.approval_reminders {
+            system_info.set_approval_reminders(approval_reminders);
+        }
+
+        let compact_requests = input.data_compaction.is_some();
+        if let Some(data_compaction) = input.data_compaction {
+            system_info.set_data_compaction(data_compaction);
+        }
+
+        let archive_requests = input
+            .request_archival
+            .as_ref()
+            .is_some_and(|config| *config != RequestArchivalConfig::Disabled);
+        if let Some(request_archival) = input.request_archival {
+            system_info.set_request_archival(request_archival);
+        }
+
+        if let Some(branding) = input.branding {
+            system_info.set_branding(branding);
+        }
+
+        let sync_address_book = input.address_book_sources.is_some();
+        if let Some(sources) = input.address_book_sources {
+            system_info.set_address_book_sources(sources);
+        }
+
         write_system_info(system_info);
+
+        if sync_address_book {
+            // syncs the new sources right away, which also removes the entries of the sources that were removed
+            schedule_address_book_sync(next_time());
+        }
+
+        if compact_requests {
+            // the job stops by itself if the compaction was disabled
+            schedule_request_compaction(next_time());
+        }
+
+        if archive_requests {
+            // the job is only scheduled once an archive canister is configured, and it stops by itself if
+            // the archival is disabled later on
+            schedule_request_archival(next_time());
+        }
+
+        if remind_approvers {
+            // the job stops by itself if the reminders were disabled
+            schedule_approver_reminders(next_time());
+        }
+
+        if let Some(next_balance_snapshot_at) = next_balance_snapshot_at {
+            // the next snapshot is aligned to the new interval
+            schedule_balance_snapshots(next_balance_snapshot_at);
+        }
+
+        if refresh_fiat_prices {
+            // fetches the prices of the new price feed right away, the job stops if the valuation was disabled
+            schedule_fiat_price_refresh(next_time());
+        }
+    }
+
+    /// Adds the network to the blockchain of the network.
+    pub fn add_network(&self, network: Network) -> ServiceResult<Network> {
+        network.validate()?;
+
+        let mut system_info = self.get_system_info();
+        if system_info
+            .find_network(&network.blockchain, &network.id)
+            .is_some()
+        {
+            Err(NetworkError::AlreadyExists {
+                blockchain: network.blockchain.to_string(),
+                id: network.id.clone(),
+            })?
+        }
+
+        system_info.upsert_network(network.clone());
+        write_system_info(system_info);
+
+        Ok(network)
+    }
+
+    /// Edits the network, the default networks are overridden by the edited ones.
+    pub fn edit_network(&self, input: EditNetworkOperationInput) -> ServiceResult<Network> {
+        let mut system_info = self.get_system_info();
+        let mut network = system_info
+            .find_network(&input.blockchain, &input.id)
+            .ok_or_else(|| NetworkError::NotFound {
+                blockchain: input.blockchain.to_string(),
+                id: input.id.clone(),
+            })?;
+
+        input.apply(&mut network);
+        network.validate()?;
+
+        system_info.upsert_network(network.clone());
+        write_system_info(system_info);
+
+        Ok(network)
+    }
+
+    /// Freezes the accounts of the blockchain network and notifies the admins, the requests that target the
+    /// accounts are only executed once they are unfrozen.
+    pub async fn freeze_blockchain(
+        &self,
+        request_id: RequestId,
+        input: FreezeBlockchainOperationInput,
+    ) -> ServiceResult<BlockchainFreeze> {
+        let mut system_info = self.get_system_info();
+        if system_info
+            .get_blockchain_freeze(&input.blockchain, input.network.as_deref())
+            .is_some()
+        {
+            Err(NetworkError::AlreadyFrozen {
+                blockchain: input.blockchain.to_string(),
+                network: input.network.clone(),
+            })?
+        }
+
+        if system_info.get_frozen_blockchains().len() >= BlockchainFreeze::MAX_FREEZES {
+            Err(NetworkError::TooManyFreezes {
+                max: BlockchainFreeze::MAX_FREEZES,
+            })?
+        }
+
+        let freeze = BlockchainFreeze {
+            blockchain: input.blockchain,
+            network: input.network,
+            reason: input.reason,
+            request_id,
+            frozen_at: next_time(),
+        };
+
+        system_info.add_blockchain_freeze(freeze.clone());
+        write_system_info(system_info);
+
+        self.notify_blockchain_freeze_change(
+            request_id,
+            &freeze.blockchain,
+            freeze.network.clone(),
+            true,
+            freeze.reason.clone(),
+        )
+        .await;
+
+        Ok(freeze)
+    }
+
+    /// Lifts the freeze of the blockchain network and notifies the admins.
+    pub async fn unfreeze_blockchain(
+        &self,
+        request_id: RequestId,
+        input: UnfreezeBlockchainOperationInput,
+    ) -> ServiceResult<BlockchainFreeze> {
+        let mut system_info = self.get_system_info();
+        let freeze = system_info
+            .remove_blockchain_freeze(&input.blockchain, input.network.as_deref())
+            .ok_or_else(|| NetworkError::NotFrozen {
+                blockchain: input.blockchain.to_string(),
+                network: input.network.clone(),
+            })?;
+
+        write_system_info(system_info);
+
+        self.notify_blockchain_freeze_change(
+            request_id,
+            &freeze.blockchain,
+            freeze.network.clone(),
+            false,
+            None,
+        )
+        .await;
+
+        Ok(freeze)
+    }
+
+    async fn notify_blockchain_freeze_change(
+        &self,
+        request_id: RequestId,
+        blockchain: &Blockchain,
+        network: Option<NetworkId>,
+        frozen: bool,
+        reason: Option<String>,
+    ) {
+        let networks = match &network {
+            Some(network) => format!("the {} network", network),
+            None => "all the networks".to_string(),
+        };
+        let (title, summary) = if frozen {
+            (
+                format!("Accounts frozen on {}", blockchain),
+                format!(
+                    "The accounts of the {} blockchain on {} are frozen, their requests are executed once they are unfrozen.{}",
+                    blockchain,
+                    networks,
+                    reason
+                        .map(|reason| format!(" Reason: {}", reason))
+                        .unwrap_or_default()
+                ),
+            )
+        } else {
+            (
+                format!("Accounts unfrozen on {}", blockchain),
+                format!(
+                    "The accounts of the {} blockchain on {} are unfrozen, their requests are executed again.",
+                    blockchain, networks
+                ),
+            )
+        };
+
+        self.notification_service
+            .send_group_notification(
+                [],
+                [*ADMIN_GROUP_ID],
+                NotificationType::BlockchainFreezeChanged(BlockchainFreezeChangedNotification {
+                    request_id,
+                    blockchain: blockchain.clone(),
+                    network,
+                    frozen,
+                }),
+                title,
+                Some(summary),
+            )
+            .await;
+    }
+
+    /// Decommissions the station, which can't be reverted.
+    pub fn decommission_station(
+        &self,
+        request_id: RequestId,
+        input: DecommissionStationOperationInput,
+    ) -> ServiceResult<()> {
+        let mut system_info = self.get_system_info();
+        if let Some(decommission) = system_info.get_decommission() {
+            Err(SystemError::StationDecommissioned {
+                reason: decommission.reason.clone(),
+            })?
+        }
+
+        system_info.set_decommission(StationDecommission {
+            request_id,
+            reason: input.reason,
+            decommissioned_at: next_time(),
+        });
+        write_system_info(system_info);
+
+        Ok(())
     }
 
     pub fn set_disaster_recovery_committee(committee: Option<DisasterRecoveryCommittee>) {
@@ -156,11 +591,68 @@ impl SystemService {
         Ok(())
     }
 
+    /// Returns the arg of a station upgrade with the current values of the station injected into it,
+    /// the arg is returned as is if there is nothing to inject.
+    pub fn inject_upgrade_arg(
+        &self,
+        arg: Option<&[u8]>,
+        injections: &[SystemUpgradeArgInjection],
+    ) -> ServiceResult<Vec<u8>> {
+        let default_arg = Encode!(&()).unwrap();
+        let arg = arg.unwrap_or(&default_arg);
+        if injections.is_empty() {
+            return Ok(arg.to_vec());
+        }
+
+        let mut upgrade = match Decode!(arg, Option<SystemInstall>) {
+            Ok(Some(SystemInstall::Upgrade(upgrade))) => upgrade,
+            Ok(None) => SystemUpgrade {
+                name: None,
+                ledger_canisters: None,
+            },
+            Ok(Some(SystemInstall::Init(_))) => Err(SystemError::InvalidUpgradeArg {
+                reason: "the arg must not be an init arg".to_string(),
+            })?,
+            Err(err) => Err(SystemError::InvalidUpgradeArg {
+                reason: format!("the arg is not a station upgrade arg: {}", err),
+            })?,
+        };
+
+        let system_info = read_system_info();
+        for injection in injections {
+            match injection {
+                SystemUpgradeArgInjection::LedgerCanisters => {
+                    // the ledger canisters of the arg replace all the current ones otherwise
+                    let mut ledger_canisters = upgrade.ledger_canisters.take().unwrap_or_default();
+                    for config in system_info.get_ledger_canisters() {
+                        if !ledger_canisters.iter().any(|input| {
+                            input.symbol == config.symbol && input.network == config.network
+                        }) {
+                            ledger_canisters.push(config.clone().into());
+                        }
+                    }
+
+                    upgrade.ledger_canisters = Some(ledger_canisters);
+                }
+            }
+        }
+
+        Ok(Encode!(&SystemInstall::Upgrade(upgrade)).map_err(|err| {
+            SystemError::InvalidUpgradeArg {
+                reason: err.to_string(),
+            }
+        })?)
+    }
+
     /// Execute an upgrade of the upgrader canister.
+    ///
+    /// The module is uploaded to the chunk store of the upgrader, which the station controls, so that the
+    /// install message is not limited by the size of the module, the module is still bound by the ingress
+    /// message limit of the request that carries it.
     pub async fn upgrade_upgrader(&self, module: &[u8], arg: Option<Vec<u8>>) -> ServiceResult<()> {
         let upgrader_canister_id = self.get_upgrader_canister_id();
         self.change_canister_service
-            .install_canister(
+            .install_canister_in_chunks(
                 upgrader_canister_id,
                 CanisterInstallMode::Upgrade(CanisterUpgradeModeArgs {}),
                 module,
@@ -182,12 +674,20 @@ impl SystemService {
             CycleObtainStrategy::Disabled => None,
             CycleObtainStrategy::MintFromNativeToken { account_id } => Some(ObtainCyclesOptions {
                 obtain_cycles: Arc::new(MintCycles {
-                    ledger: Arc::new(IcLedgerCanister::new(MAINNET_LEDGER_CANISTER_ID)),
+                    ledger: Arc::new(IcLedgerCanister::new(InternetComputer::ledger_canister_id())),
                     cmc: Arc::new(IcCyclesMintingCanister::new(
                         MAINNET_CYCLES_MINTING_CANISTER_ID,
                     )),
+                    // the cycles are minted from the current address of the account
                     from_subaccount: Subaccount(
-                        InternetComputer::subaccount_from_station_account_id(account_id),
+                        ACCOUNT_REPOSITORY
+                            .get(&Account::key(*account_id))
+                            .map(|account| {
+                                InternetComputer::subaccount_from_station_account(&account)
+                            })
+                            .unwrap_or_else(|| {
+                                InternetComputer::subaccount_from_station_account_id(account_id)
+                            }),
                     ),
                 }),
                 top_up_self: true,
@@ -219,7 +719,7 @@ impl SystemService {
         async fn initialize_rng_timer() {
             use orbit_essentials::utils::initialize_rng;
             if let Err(e) = initialize_rng().await {
-                ic_cdk::print(format!("initializing rng failed: {}", e));
+                log_error!("initializing rng failed: {}", e);
                 crate::core::ic_timers::set_timer(std::time::Duration::from_secs(60), move || {
                     use crate::core::ic_cdk::spawn;
                     spawn(initialize_rng_timer())
@@ -232,110 +732,54 @@ impl SystemService {
             spawn(initialize_rng_timer())
         });
 
-        fn install_canister_post_process_finish(mut system_info: SystemInfo) {
-            use crate::jobs;
-
-            install_canister_handlers::monitor_upgrader_cycles(
-                *system_info.get_upgrader_canister_id(),
-                *system_info.get_cycle_obtain_strategy(),
-            );
-
-            // initializes the job timers after the canister is fully initialized
-            jobs::initialize_job_timers();
-
-            system_info.update_last_upgrade_timestamp();
-            write_system_info(system_info.to_owned());
-        }
-
-        async fn install_canister_post_process_work(
-            init: SystemInit,
-            mut system_info: SystemInfo,
-        ) -> Result<(), String> {
-            use crate::core::ic_cdk::api::id as self_canister_id;
-
-            // registers the default canister configurations such as policies and user groups.
-            print("Adding initial canister configurations");
-            install_canister_handlers::init_post_process(&init).await?;
-
-            print("Init upgrader canister");
-            let canister_id = self_canister_id();
-            let mut upgrader_controllers = vec![canister_id];
-            if let Some(fallback_controller) = init.fallback_controller {
-                upgrader_controllers.push(fallback_controller);
-            }
-            let upgrader_canister_id =
-                install_canister_handlers::init_upgrader(init.upgrader, upgrader_controllers)
-                    .await?;
-            system_info.set_upgrader_canister_id(upgrader_canister_id);
-
-            // sets the upgrader as a controller of the station canister
-            print("Updating canister settings to set the upgrader as the controller");
-            let mut station_controllers = vec![upgrader_canister_id];
-            if let Some(fallback_controller) = init.fallback_controller {
-                station_controllers.push(fallback_controller);
+        match install {
+            // the bootstrap runs from the checkpoint that was written during the init
+            SystemInstall::Init(_) => {
+                install_canister_handlers::schedule_init_bootstrap(
+                    std::time::Duration::from_millis(0),
+                );
             }
-            install_canister_handlers::set_controllers(station_controllers).await?;
-
-            // calculates the initial quorum based on the number of admins and the provided quorum
-            let admin_count = init.admins.len() as u16;
-            let quorum = calc_initial_quorum(admin_count, init.quorum);
-
-            // if provided, creates the initial accounts
-            if let Some(accounts) = init.accounts {
-                print("Adding initial accounts");
-                install_canister_handlers::set_initial_accounts(accounts, quorum).await?;
+            SystemInstall::Upgrade(_) => {
+                install_canister_handlers::install_canister_post_process_finish(system_info);
             }
+        };
+    }
 
-            if SYSTEM_SERVICE.is_healthy() {
-                print("canister reports healthy already before its initialization has finished!");
-            }
+    /// Returns the checkpoint of the bootstrap of the station.
+    pub fn get_init_progress(&self) -> SystemInitProgress {
+        read_init_progress()
+    }
 
-            install_canister_post_process_finish(system_info);
+    /// Resumes the bootstrap of the station from the step that failed, instead of waiting for its next
+    /// automatic retry.
+    pub fn retry_init(&self) -> ServiceResult<()> {
+        if read_init_progress().is_completed() {
+            Err(SystemError::InitAlreadyCompleted)?
+        }
 
-            SystemService::set_disaster_recovery_committee(Some(DisasterRecoveryCommittee {
-                quorum,
-                user_group_id: *crate::models::ADMIN_GROUP_ID,
-            }));
+        if is_init_running() {
+            Err(SystemError::InitAlreadyRunning)?
+        }
 
-            crate::core::ic_cdk::spawn(async {
-                DISASTER_RECOVERY_SERVICE.sync_all().await;
-            });
+        self.resume_init();
 
-            Ok(())
-        }
+        Ok(())
+    }
 
-        async fn install_canister_post_process_timer(init: SystemInit, system_info: SystemInfo) {
-            if let Err(e) =
-                install_canister_post_process_work(init.clone(), system_info.clone()).await
-            {
-                ic_cdk::print(format!("canister initialization failed: {}", e));
-                crate::core::ic_timers::set_timer(
-                    std::time::Duration::from_secs(3600),
-                    move || {
-                        use crate::core::ic_cdk::spawn;
-                        spawn(install_canister_post_process_timer(init, system_info))
-                    },
-                );
-            }
-        }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn resume_init(&self) {}
 
-        match install {
-            SystemInstall::Init(init) => {
-                crate::core::ic_timers::set_timer(std::time::Duration::from_millis(0), move || {
-                    use crate::core::ic_cdk::spawn;
-                    spawn(install_canister_post_process_timer(init, system_info))
-                });
-            }
-            SystemInstall::Upgrade(_) => {
-                install_canister_post_process_finish(system_info);
-            }
-        };
+    #[cfg(target_arch = "wasm32")]
+    fn resume_init(&self) {
+        install_canister_handlers::schedule_init_bootstrap(std::time::Duration::from_millis(0));
     }
 
     /// Initializes the cache of the canister data.
     ///
     /// Must only be called within a canister init or post_upgrade call.
     fn init_cache(&self) {
+        hydrate_system_state();
+
         USER_GROUP_REPOSITORY.build_cache();
         USER_REPOSITORY.build_cache();
         PERMISSION_REPOSITORY.build_cache();
@@ -366,6 +810,22 @@ impl SystemService {
         // sets the name of the canister
         system_info.set_name(input.name.clone());
 
+        // sets the ledger canisters that override the mainnet defaults
+        if let Some(ledger_canisters) = input.ledger_canisters.clone() {
+            system_info.set_ledger_canisters(validate_ledger_canisters(ledger_canisters)?);
+        }
+
+        // simulates the blockchains for local development, which can't be changed once installed
+        system_info.set_mock_blockchains(input.mock_blockchains.unwrap_or(false));
+
+        // checkpoints the bootstrap so that it can be resumed from the step that failed
+        write_init_progress(SystemInitProgress::new(
+            Encode!(&input).map_err(|e| SystemError::InitFailed {
+                reason: e.to_string(),
+            })?,
+            system_info.clone(),
+        ));
+
         // initializes the cache of the canister data, must happen during the same call as the init
         self.init_cache();
 
@@ -391,7 +851,10 @@ impl SystemService {
         let mut system_info = read_system_info();
         let input = match input {
             Some(input) => input,
-            None => SystemUpgrade { name: None },
+            None => SystemUpgrade {
+                name: None,
+                ledger_canisters: None,
+            },
         };
 
         // Version is set to the current global system version, needs to happen after the migrations.
@@ -417,10 +880,10 @@ impl SystemService {
                 None => {
                     // Do not fail the upgrade if the request is not found, even though this should never happen
                     // it's not a critical error
-                    print(format!(
-                        "Error: verifying upgrade failed, request not found {}",
+                    log_error!(
+                        "verifying upgrade failed, request not found {}",
                         Uuid::from_bytes(*request_id).hyphenated()
-                    ));
+                    );
                 }
             };
 
@@ -436,6 +899,12 @@ impl SystemService {
             write_system_info(system_info.clone());
         }
 
+        if let Some(ledger_canisters) = input.ledger_canisters.clone() {
+            system_info.set_ledger_canisters(validate_ledger_canisters(ledger_canisters)?);
+
+            write_system_info(system_info.clone());
+        }
+
         // Handles the post upgrade process in a one-off timer to allow for inter canister calls,
         // this upgrades the upgrader canister if a new upgrader module is provided.
         self.install_canister_post_process(system_info, SystemInstall::Upgrade(input));
@@ -485,11 +954,66 @@ impl SystemService {
     }
 }
 
+/// The maximum number of ledger canister configurations that can be set.
+const MAX_LEDGER_CANISTERS: usize = 20;
+
+/// Validates the ledger canisters input, making sure that each asset is configured at most once per network.
+fn validate_ledger_canisters(
+    ledger_canisters: Vec<station_api::LedgerCanisterInput>,
+) -> ServiceResult<Vec<LedgerCanisterConfig>> {
+    if ledger_canisters.len() > MAX_LEDGER_CANISTERS {
+        Err(SystemError::InvalidLedgerCanisters {
+            reason: format!(
+                "at most {} ledger canisters can be set",
+                MAX_LEDGER_CANISTERS
+            ),
+        })?
+    }
+
+    let mut configs: Vec<LedgerCanisterConfig> = Vec::with_capacity(ledger_canisters.len());
+    for config in ledger_canisters.into_iter().map(LedgerCanisterConfig::from) {
+        if config.symbol.trim().is_empty() || config.network.trim().is_empty() {
+            Err(SystemError::InvalidLedgerCanisters {
+                reason: "the symbol and network must not be empty".to_string(),
+            })?
+        }
+
+        let canister_ids =
+            std::iter::once(config.ledger_canister_id).chain(config.index_canister_id);
+        for canister_id in canister_ids {
+            if canister_id == Principal::management_canister()
+                || canister_id == Principal::anonymous()
+            {
+                Err(SystemError::InvalidLedgerCanisters {
+                    reason: format!("{} is not a valid canister id", canister_id),
+                })?
+            }
+        }
+
+        if configs
+            .iter()
+            .any(|other| other.symbol == config.symbol && other.network == config.network)
+        {
+            Err(SystemError::InvalidLedgerCanisters {
+                reason: format!(
+                    "the asset {} is configured more than once for the network {}",
+                    config.symbol, config.network
+                ),
+            })?
+        }
+
+        configs.push(config);
+    }
+
+    Ok(configs)
+}
+
 mod init_canister_sync_handlers {
-    use crate::core::ic_cdk::{api::print, next_time};
+    use crate::core::ic_cdk::next_time;
     use crate::models::{AddUserOperationInput, UserStatus};
     use crate::services::USER_SERVICE;
     use crate::{
+        log_info,
         models::{UserGroup, ADMIN_GROUP_ID},
         repositories::USER_GROUP_REPOSITORY,
     };
@@ -505,6 +1029,7 @@ mod init_canister_sync_handlers {
             UserGroup {
                 id: ADMIN_GROUP_ID.to_owned(),
                 name: "Admin".to_owned(),
+                quotas: Default::default(),
                 last_modification_timestamp: next_time(),
             },
         );
@@ -512,20 +1037,21 @@ mod init_canister_sync_handlers {
 
     /// Registers the newly added admins of the canister.
     pub fn set_admins(admins: Vec<AdminInitInput>) -> Result<(), ApiError> {
-        print(format!("Registering {} admin users", admins.len()));
+        log_info!("Registering {} admin users", admins.len());
         for admin in admins {
             let user = USER_SERVICE.add_user(AddUserOperationInput {
                 identities: vec![admin.identity.to_owned()],
                 groups: vec![ADMIN_GROUP_ID.to_owned()],
                 name: admin.name.to_owned(),
                 status: UserStatus::Active,
+                scheduled_memberships: vec![],
             })?;
 
-            print(&format!(
+            log_info!(
                 "Added admin user with principal {} and user id {}",
                 admin.identity.to_text(),
                 Uuid::from_bytes(user.id).hyphenated()
-            ));
+            );
         }
         Ok(())
     }
@@ -538,51 +1064,234 @@ pub fn calc_initial_quorum(admin_count: u16, quorum: Option<u16>) -> u16 {
     quorum.unwrap_or(admin_count / 2 + 1).clamp(1, admin_count)
 }
 
+thread_local! {
+    /// Whether the bootstrap of the station is running, kept in the heap so that it can't stay set across upgrades.
+    static INIT_RUNNING: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+fn is_init_running() -> bool {
+    INIT_RUNNING.with(|running| running.get())
+}
+
+/// Marks the bootstrap of the station as running until it is dropped, which also happens if the bootstrap traps.
+#[cfg(target_arch = "wasm32")]
+struct InitRunningGuard;
+
+#[cfg(target_arch = "wasm32")]
+impl InitRunningGuard {
+    fn new() -> Self {
+        INIT_RUNNING.with(|running| running.set(true));
+
+        Self
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for InitRunningGuard {
+    fn drop(&mut self) {
+        INIT_RUNNING.with(|running| running.set(false));
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 mod install_canister_handlers {
-    use crate::core::ic_cdk::api::{id as self_canister_id, print};
+    use crate::core::ic_cdk::api::id as self_canister_id;
+    use crate::core::ic_cdk::next_time;
     use crate::core::init::{default_policies, DEFAULT_PERMISSIONS};
     use crate::core::INITIAL_UPGRADER_CYCLES;
+    use crate::core::{read_init_progress, write_init_progress, write_system_info};
     use crate::mappers::blockchain::BlockchainMapper;
     use crate::mappers::HelperMapper;
     use crate::models::permission::Allow;
     use crate::models::request_specifier::UserSpecifier;
     use crate::models::{
         AddAccountOperationInput, AddRequestPolicyOperationInput, CycleObtainStrategy,
-        EditPermissionOperationInput, RequestPolicyRule, ADMIN_GROUP_ID,
+        DisasterRecoveryCommittee, EditPermissionOperationInput, InitStep, RequestPolicyRule,
+        SystemInfo, SystemInitProgress, ADMIN_GROUP_ID,
     };
+    use crate::repositories::{ACCOUNT_REPOSITORY, REQUEST_POLICY_REPOSITORY};
+    use crate::services::disaster_recovery::DISASTER_RECOVERY_SERVICE;
     use crate::services::permission::PERMISSION_SERVICE;
     use crate::services::ACCOUNT_SERVICE;
     use crate::services::REQUEST_POLICY_SERVICE;
-    use candid::{Encode, Principal};
+    use crate::{log_error, log_info, log_warn};
+    use candid::{Decode, Encode, Principal};
     use canfund::manager::options::{EstimatedRuntime, FundManagerOptions, FundStrategy};
     use canfund::manager::RegisterOpts;
     use canfund::FundManager;
     use ic_cdk::api::management_canister::main::{self as mgmt};
     use ic_cdk::id;
 
+    use orbit_essentials::repository::Repository;
     use orbit_essentials::types::UUID;
-    use station_api::{InitAccountInput, SystemInit};
+    use station_api::{InitAccountInput, SystemInit, SystemUpgraderInput};
     use std::cell::RefCell;
+    use std::time::Duration;
+
+    use super::{InitRunningGuard, SystemService, SYSTEM_SERVICE};
 
-    use super::SYSTEM_SERVICE;
+    /// The delay before a failed bootstrap is retried automatically.
+    const INIT_RETRY_DELAY: Duration = Duration::from_secs(3600);
 
     thread_local! {
         pub static FUND_MANAGER: RefCell<FundManager> = RefCell::new(FundManager::new());
     }
 
+    pub fn install_canister_post_process_finish(mut system_info: SystemInfo) {
+        use crate::jobs;
+
+        monitor_upgrader_cycles(
+            *system_info.get_upgrader_canister_id(),
+            *system_info.get_cycle_obtain_strategy(),
+        );
+
+        // initializes the job timers after the canister is fully initialized
+        jobs::initialize_job_timers();
+
+        system_info.update_last_upgrade_timestamp();
+        write_system_info(system_info.to_owned());
+    }
+
+    /// Schedules the bootstrap of the station to resume from its checkpoint.
+    pub fn schedule_init_bootstrap(delay: Duration) {
+        crate::core::ic_timers::set_timer(delay, || {
+            use crate::core::ic_cdk::spawn;
+            spawn(run_init_bootstrap())
+        });
+    }
+
+    async fn run_init_bootstrap() {
+        // a retry that was requested in the meantime might have completed the bootstrap or still be running
+        if read_init_progress().is_completed() || super::is_init_running() {
+            return;
+        }
+
+        let _running = InitRunningGuard::new();
+
+        let mut progress = read_init_progress();
+        progress.attempts += 1;
+        progress.last_attempt_at = Some(next_time());
+        write_init_progress(progress);
+
+        if let Err(e) = init_bootstrap_work().await {
+            log_error!("canister initialization failed: {}", e);
+
+            let mut progress = read_init_progress();
+            progress.last_error = Some(e);
+            write_init_progress(progress);
+
+            schedule_init_bootstrap(INIT_RETRY_DELAY);
+        }
+    }
+
+    /// Runs the steps of the bootstrap that are left, the progress is checkpointed after every step so that
+    /// the steps that completed are not run again.
+    async fn init_bootstrap_work() -> Result<(), String> {
+        let mut progress = read_init_progress();
+        let mut init = Decode!(&progress.init_arg, SystemInit)
+            .map_err(|e| format!("Failed to decode the init arg: {}", e))?;
+
+        // calculates the initial quorum based on the number of admins and the provided quorum
+        let admin_count = init.admins.len() as u16;
+        let quorum = super::calc_initial_quorum(admin_count, init.quorum);
+
+        while !progress.is_completed() {
+            match progress.next_step {
+                InitStep::DefaultConfigs => {
+                    // registers the default canister configurations such as policies and user groups.
+                    log_info!("Adding initial canister configurations");
+                    init_post_process(&init).await?;
+                }
+                InitStep::Upgrader => {
+                    log_info!("Init upgrader canister");
+                    let mut upgrader_controllers = vec![self_canister_id()];
+                    if let Some(fallback_controller) = init.fallback_controller {
+                        upgrader_controllers.push(fallback_controller);
+                    }
+                    let upgrader_canister_id =
+                        init_upgrader(init.upgrader.clone(), upgrader_controllers, &mut progress)
+                            .await?;
+                    progress.upgrader_canister_id = Some(upgrader_canister_id);
+                    progress
+                        .system_info
+                        .set_upgrader_canister_id(upgrader_canister_id);
+
+                    // the upgrader module is not kept once it is installed
+                    init.upgrader = SystemUpgraderInput::Id(upgrader_canister_id);
+                    progress.init_arg = Encode!(&init)
+                        .map_err(|e| format!("Failed to encode the init arg: {}", e))?;
+                }
+                InitStep::Controllers => {
+                    // sets the upgrader as a controller of the station canister
+                    log_info!("Updating canister settings to set the upgrader as the controller");
+                    let mut station_controllers =
+                        vec![*progress.system_info.get_upgrader_canister_id()];
+                    if let Some(fallback_controller) = init.fallback_controller {
+                        station_controllers.push(fallback_controller);
+                    }
+                    set_controllers(station_controllers).await?;
+                }
+                InitStep::InitialAccounts => {
+                    // if provided, creates the initial accounts
+                    if let Some(accounts) = init.accounts.clone() {
+                        log_info!("Adding initial accounts");
+                        set_initial_accounts(accounts, quorum).await?;
+                    }
+                }
+                InitStep::Completed => {}
+            }
+
+            progress.next_step = progress.next_step.next();
+            progress.last_error = None;
+            write_init_progress(progress.clone());
+        }
+
+        if SYSTEM_SERVICE.is_healthy() {
+            log_warn!("canister reports healthy already before its initialization has finished!");
+        }
+
+        install_canister_post_process_finish(progress.system_info.clone());
+
+        SystemService::set_disaster_recovery_committee(Some(DisasterRecoveryCommittee {
+            quorum,
+            user_group_id: *ADMIN_GROUP_ID,
+        }));
+
+        crate::core::ic_cdk::spawn(async {
+            DISASTER_RECOVERY_SERVICE.sync_all().await;
+        });
+
+        // the init arg is not needed anymore once the bootstrap completed
+        progress.init_arg = Vec::new();
+        write_init_progress(progress);
+
+        Ok(())
+    }
+
     /// Registers the default configurations for the canister.
+    ///
+    /// The policies that were added by a previous attempt of the bootstrap are not added again.
     pub async fn init_post_process(init: &SystemInit) -> Result<(), String> {
         let admin_quorum = super::calc_initial_quorum(init.admins.len() as u16, init.quorum);
 
         let policies_to_create = default_policies(admin_quorum);
+        let existing_policies = REQUEST_POLICY_REPOSITORY.list();
 
         // adds the default request policies which sets safe defaults for the canister
         for policy in policies_to_create.iter() {
+            if existing_policies
+                .iter()
+                .any(|existing| existing.specifier == policy.0 && existing.rule == policy.1)
+            {
+                continue;
+            }
+
             REQUEST_POLICY_SERVICE
                 .add_request_policy(AddRequestPolicyOperationInput {
                     specifier: policy.0.to_owned(),
                     rule: policy.1.to_owned(),
+                    checklist: Vec::new(),
+                    exclude_requester: false,
                 })
                 .map_err(|e| format!("Failed to add default request policy: {:?}", e))?;
         }
@@ -629,6 +1338,7 @@ mod install_canister_handlers {
                     read_permission: Allow::user_groups(vec![*ADMIN_GROUP_ID]),
                     configs_permission: Allow::user_groups(vec![*ADMIN_GROUP_ID]),
                     transfer_permission: Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+                    network: None,
                 };
 
                 (
@@ -641,6 +1351,11 @@ mod install_canister_handlers {
             .collect::<Vec<(AddAccountOperationInput, Option<UUID>)>>();
 
         for (new_account, with_account_id) in add_accounts {
+            // the accounts that were added by a previous attempt of the bootstrap are not added again
+            if ACCOUNT_REPOSITORY.find_by_name(&new_account.name).is_some() {
+                continue;
+            }
+
             ACCOUNT_SERVICE
                 .create_account(new_account, with_account_id)
                 .await
@@ -651,11 +1366,12 @@ mod install_canister_handlers {
     }
 
     pub async fn init_upgrader(
-        input: station_api::SystemUpgraderInput,
+        input: SystemUpgraderInput,
         controllers: Vec<Principal>,
+        progress: &mut SystemInitProgress,
     ) -> Result<Principal, String> {
         match input {
-            station_api::SystemUpgraderInput::Id(upgrader_id) => {
+            SystemUpgraderInput::Id(upgrader_id) => {
                 mgmt::update_settings(mgmt::UpdateSettingsArgument {
                     canister_id: upgrader_id,
                     settings: mgmt::CanisterSettings {
@@ -668,17 +1384,28 @@ mod install_canister_handlers {
 
                 Ok(upgrader_id)
             }
-            station_api::SystemUpgraderInput::WasmModule(upgrader_wasm_module) => {
-                deploy_upgrader(upgrader_wasm_module, controllers).await
+            SystemUpgraderInput::WasmModule(upgrader_wasm_module) => {
+                // reuses the upgrader canister that a previous attempt of the bootstrap created
+                let upgrader_canister_id = match progress.upgrader_canister_id {
+                    Some(upgrader_canister_id) => upgrader_canister_id,
+                    None => {
+                        let upgrader_canister_id = create_upgrader(controllers).await?;
+                        progress.upgrader_canister_id = Some(upgrader_canister_id);
+                        write_init_progress(progress.clone());
+
+                        upgrader_canister_id
+                    }
+                };
+
+                install_upgrader(upgrader_canister_id, upgrader_wasm_module).await?;
+
+                Ok(upgrader_canister_id)
             }
         }
     }
 
-    /// Deploys the station upgrader canister and sets the station as the controller of the upgrader.
-    async fn deploy_upgrader(
-        upgrader_wasm_module: Vec<u8>,
-        controllers: Vec<Principal>,
-    ) -> Result<Principal, String> {
+    /// Creates the station upgrader canister with the station as its controller.
+    async fn create_upgrader(controllers: Vec<Principal>) -> Result<Principal, String> {
         let (upgrader_canister,) = mgmt::create_canister(
             mgmt::CreateCanisterArgument {
                 settings: Some(mgmt::CanisterSettings {
@@ -691,9 +1418,17 @@ mod install_canister_handlers {
         .await
         .map_err(|e| format!("Failed to create upgrader canister: {:?}", e))?;
 
+        Ok(upgrader_canister.canister_id)
+    }
+
+    /// Installs the station upgrader module in the upgrader canister.
+    async fn install_upgrader(
+        upgrader_canister_id: Principal,
+        upgrader_wasm_module: Vec<u8>,
+    ) -> Result<(), String> {
         mgmt::install_code(mgmt::InstallCodeArgument {
             mode: mgmt::CanisterInstallMode::Install,
-            canister_id: upgrader_canister.canister_id,
+            canister_id: upgrader_canister_id,
             wasm_module: upgrader_wasm_module,
             arg: Encode!(&upgrader_api::InitArg {
                 target_canister: self_canister_id(),
@@ -701,9 +1436,7 @@ mod install_canister_handlers {
             .expect("Failed to encode upgrader init arg"),
         })
         .await
-        .map_err(|e| format!("Failed to install upgrader canister: {:?}", e))?;
-
-        Ok(upgrader_canister.canister_id)
+        .map_err(|e| format!("Failed to install upgrader canister: {:?}", e))
     }
 
     /// Sets the only controller of the canister.
@@ -724,11 +1457,11 @@ mod install_canister_handlers {
         upgrader_id: Principal,
         cycle_obtain_strategy: CycleObtainStrategy,
     ) {
-        print(format!(
+        log_info!(
             "Starting fund manager to monitor self {} and upgrader canister {} cycles",
             id(),
             upgrader_id.to_text()
-        ));
+        );
 
         FUND_MANAGER.with(|fund_manager| {
             let mut fund_manager = fund_manager.borrow_mut();
@@ -761,7 +1494,7 @@ mod install_canister_handlers {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::request_test_utils::mock_request;
+    use crate::models::{request_test_utils::mock_request, Blockchain, InitStep};
     use candid::Principal;
     use station_api::AdminInitInput;
 
@@ -778,12 +1511,242 @@ mod tests {
                 upgrader: station_api::SystemUpgraderInput::WasmModule(vec![]),
                 fallback_controller: None,
                 accounts: None,
+                ledger_canisters: None,
+                mock_blockchains: None,
             })
             .await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn canister_init_checkpoints_the_bootstrap() {
+        let init = SystemInit {
+            name: "Station".to_string(),
+            admins: vec![AdminInitInput {
+                name: "Admin".to_string(),
+                identity: Principal::from_slice(&[1; 29]),
+            }],
+            quorum: Some(1),
+            upgrader: station_api::SystemUpgraderInput::WasmModule(vec![]),
+            fallback_controller: None,
+            accounts: None,
+            ledger_canisters: None,
+            mock_blockchains: None,
+        };
+
+        SYSTEM_SERVICE.init_canister(init.clone()).await.unwrap();
+
+        let progress = SYSTEM_SERVICE.get_init_progress();
+        assert_eq!(progress.next_step, InitStep::DefaultConfigs);
+        assert_eq!(
+            Decode!(&progress.init_arg, SystemInit).unwrap().name,
+            init.name
+        );
+
+        assert!(SYSTEM_SERVICE.retry_init().is_ok());
+
+        let mut progress = SYSTEM_SERVICE.get_init_progress();
+        progress.next_step = InitStep::Completed;
+        write_init_progress(progress);
+
+        assert!(SYSTEM_SERVICE.retry_init().is_err());
+    }
+
+    #[test]
+    fn networks_can_be_added_and_edited() {
+        write_system_info(SystemInfo::default());
+        let sepolia = Network {
+            id: "sepolia".to_string(),
+            blockchain: Blockchain::Ethereum,
+            name: "Sepolia".to_string(),
+            chain_id: Some("11155111".to_string()),
+            is_testnet: true,
+        };
+
+        SYSTEM_SERVICE.add_network(sepolia.clone()).unwrap();
+        assert!(SYSTEM_SERVICE.add_network(sepolia.clone()).is_err());
+
+        let edited = SYSTEM_SERVICE
+            .edit_network(EditNetworkOperationInput {
+                id: sepolia.id.clone(),
+                blockchain: Blockchain::Ethereum,
+                name: Some("Ethereum Sepolia".to_string()),
+                chain_id: None,
+                is_testnet: None,
+            })
+            .unwrap();
+        assert_eq!(edited.name, "Ethereum Sepolia");
+        assert!(edited.is_testnet);
+        assert_eq!(
+            read_system_info().find_network(&Blockchain::Ethereum, "sepolia"),
+            Some(edited)
+        );
+
+        assert!(SYSTEM_SERVICE
+            .edit_network(EditNetworkOperationInput {
+                id: Network::MAINNET.to_string(),
+                blockchain: Blockchain::Bitcoin,
+                name: None,
+                chain_id: None,
+                is_testnet: Some(true),
+            })
+            .is_err());
+        assert!(SYSTEM_SERVICE
+            .edit_network(EditNetworkOperationInput {
+                id: "sepolia".to_string(),
+                blockchain: Blockchain::Bitcoin,
+                name: None,
+                chain_id: None,
+                is_testnet: None,
+            })
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn blockchains_can_be_frozen_and_unfrozen() {
+        write_system_info(SystemInfo::default());
+
+        let freeze = SYSTEM_SERVICE
+            .freeze_blockchain(
+                [1; 16],
+                FreezeBlockchainOperationInput {
+                    blockchain: Blockchain::Ethereum,
+                    network: None,
+                    reason: Some("RPC providers are down".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(freeze.request_id, [1; 16]);
+        assert!(read_system_info()
+            .find_blockchain_freeze(&Blockchain::Ethereum, "sepolia")
+            .is_some());
+
+        assert!(SYSTEM_SERVICE
+            .freeze_blockchain(
+                [2; 16],
+                FreezeBlockchainOperationInput {
+                    blockchain: Blockchain::Ethereum,
+                    network: None,
+                    reason: None,
+                },
+            )
+            .await
+            .is_err());
+        assert!(SYSTEM_SERVICE
+            .unfreeze_blockchain(
+                [3; 16],
+                UnfreezeBlockchainOperationInput {
+                    blockchain: Blockchain::Ethereum,
+                    network: Some(Network::MAINNET.to_string()),
+                },
+            )
+            .await
+            .is_err());
+
+        SYSTEM_SERVICE
+            .unfreeze_blockchain(
+                [4; 16],
+                UnfreezeBlockchainOperationInput {
+                    blockchain: Blockchain::Ethereum,
+                    network: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(read_system_info().get_frozen_blockchains().is_empty());
+    }
+
+    #[test]
+    fn validate_ledger_canisters_rejects_duplicates() {
+        let ledger_canister = station_api::LedgerCanisterInput {
+            symbol: "ICP".to_string(),
+            network: "mainnet".to_string(),
+            ledger_canister_id: Principal::from_slice(&[2; 29]),
+            index_canister_id: Some(Principal::from_slice(&[3; 29])),
+        };
+
+        let configs = validate_ledger_canisters(vec![ledger_canister.clone()]).unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(
+            configs[0].ledger_canister_id,
+            Principal::from_slice(&[2; 29])
+        );
+
+        assert!(
+            validate_ledger_canisters(vec![ledger_canister.clone(), ledger_canister.clone()])
+                .is_err()
+        );
+        assert!(
+            validate_ledger_canisters(vec![station_api::LedgerCanisterInput {
+                ledger_canister_id: Principal::management_canister(),
+                ..ledger_canister
+            }])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn inject_upgrade_arg_keeps_the_current_ledger_canisters() {
+        let mut system_info = SystemInfo::new(Principal::management_canister(), Vec::new());
+        system_info.set_ledger_canisters(vec![
+            LedgerCanisterConfig {
+                symbol: "ICP".to_string(),
+                network: "testnet".to_string(),
+                ledger_canister_id: Principal::from_slice(&[2; 29]),
+                index_canister_id: None,
+            },
+            LedgerCanisterConfig {
+                symbol: "CKBTC".to_string(),
+                network: "testnet".to_string(),
+                ledger_canister_id: Principal::from_slice(&[3; 29]),
+                index_canister_id: None,
+            },
+        ]);
+        write_system_info(system_info);
+
+        let arg = Encode!(&SystemInstall::Upgrade(SystemUpgrade {
+            name: None,
+            ledger_canisters: Some(vec![station_api::LedgerCanisterInput {
+                symbol: "ICP".to_string(),
+                network: "testnet".to_string(),
+                ledger_canister_id: Principal::from_slice(&[4; 29]),
+                index_canister_id: None,
+            }]),
+        }))
+        .unwrap();
+
+        let injected = SYSTEM_SERVICE
+            .inject_upgrade_arg(Some(&arg), &[SystemUpgradeArgInjection::LedgerCanisters])
+            .unwrap();
+
+        let Some(SystemInstall::Upgrade(upgrade)) =
+            Decode!(&injected, Option<SystemInstall>).unwrap()
+        else {
+            panic!("expected an upgrade arg");
+        };
+        let ledger_canisters = upgrade.ledger_canisters.unwrap();
+        assert_eq!(ledger_canisters.len(), 2);
+        assert_eq!(
+            ledger_canisters[0].ledger_canister_id,
+            Principal::from_slice(&[4; 29])
+        );
+        assert_eq!(ledger_canisters[1].symbol, "CKBTC");
+
+        // the arg is left untouched without injections
+        assert_eq!(
+            SYSTEM_SERVICE.inject_upgrade_arg(Some(&arg), &[]).unwrap(),
+            arg
+        );
+        assert!(SYSTEM_SERVICE
+            .inject_upgrade_arg(
+                Some(&[1, 2, 3]),
+                &[SystemUpgradeArgInjection::LedgerCanisters]
+            )
+            .is_err());
+    }
+
     #[tokio::test]
     async fn canister_upgrade_marks_request_completed_and_clears_it() {
         let mut request = mock_request();
@@ -810,6 +1773,27 @@ mod tests {
         assert!(system_info.get_change_canister_request().is_none());
     }
 
+    #[test]
+    fn get_trace_returns_the_spans_and_logs_of_the_correlation_id() {
+        let trace = tracing::TraceContext::ingress(*Uuid::new_v4().as_bytes(), "tick");
+        trace.child("execute_request", None).finish();
+        crate::log_info!(trace = trace; "first");
+        crate::log_info!(trace = trace; "second");
+        trace.finish();
+
+        let (found, logs) = SYSTEM_SERVICE.get_trace(&trace.correlation_id()).unwrap();
+
+        assert_eq!(found.spans.len(), 2);
+        assert_eq!(
+            logs.iter()
+                .map(|entry| entry.message.as_str())
+                .collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+
+        assert!(SYSTEM_SERVICE.get_trace(Uuid::new_v4().as_bytes()).is_err());
+    }
+
     #[test]
     fn test_initial_quorum_is_majority() {
         assert_eq!(calc_initial_quorum(1, None), 1);
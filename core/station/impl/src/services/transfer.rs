@@ -1,17 +1,17 @@
-use super::{AccountService, UserService};
+use super::{AccountService, UserService, SYSTEM_SERVICE};
 use crate::{
     core::{authorization::Authorization, CallContext},
     errors::{AccountError, TransferError},
     mappers::HelperMapper,
     models::{
         resource::{AccountResourceAction, Resource, ResourceId},
-        Transfer, TransferId,
+        Transfer, TransferAnnotation, TransferId, TransferStatus,
     },
-    repositories::TransferRepository,
+    repositories::{TransferAnnotationRepository, TransferRepository},
 };
 use orbit_essentials::repository::Repository;
-use orbit_essentials::{api::ServiceResult, model::ModelValidator, utils::rfc3339_to_timestamp};
-use station_api::ListAccountTransfersInput;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator};
+use station_api::{AnnotateTransferInput, ListAccountTransfersInput};
 use uuid::Uuid;
 
 #[derive(Default, Debug)]
@@ -19,6 +19,7 @@ pub struct TransferService {
     user_service: UserService,
     account_service: AccountService,
     transfer_repository: TransferRepository,
+    transfer_annotation_repository: TransferAnnotationRepository,
 }
 
 impl TransferService {
@@ -57,15 +58,22 @@ impl TransferService {
         for transfer_id in transfer_ids.iter() {
             let transfer = self.get_transfer(transfer_id, ctx)?;
             self.assert_transfer_access(&transfer, ctx)?;
-            transfers.push(transfer);
+
+            // the transfers of confidential requests are left out for the callers that can't see the request
+            if Authorization::is_request_visible(ctx, &transfer.request_id) {
+                transfers.push(transfer);
+            }
         }
 
         Ok(transfers)
     }
 
+    /// Lists the transfers of the account, leaving out the ones of confidential requests that the caller
+    /// can't see.
     pub fn list_account_transfers(
         &self,
         input: ListAccountTransfersInput,
+        ctx: &CallContext,
     ) -> ServiceResult<Vec<Transfer>> {
         let account = self
             .account_service
@@ -73,12 +81,63 @@ impl TransferService {
 
         let transfers = self.transfer_repository.find_by_account(
             account.id,
-            input.from_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
-            input.to_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
+            HelperMapper::to_optional_timestamp("from_dt", input.from_dt)?,
+            HelperMapper::to_optional_timestamp("to_dt", input.to_dt)?,
             input.status,
         );
 
-        Ok(transfers)
+        Ok(transfers
+            .into_iter()
+            .filter(|transfer| Authorization::is_request_visible(ctx, &transfer.request_id))
+            .collect())
+    }
+
+    /// Returns the reconciliation annotation of the transfer, if it was annotated.
+    pub fn find_annotation(&self, transfer_id: &TransferId) -> Option<TransferAnnotation> {
+        self.transfer_annotation_repository.get(transfer_id)
+    }
+
+    /// Attaches the reconciliation annotation to a completed transfer, replacing the previous one.
+    ///
+    /// The transfer record itself is left untouched.
+    pub fn annotate_transfer(
+        &self,
+        input: AnnotateTransferInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<TransferAnnotation> {
+        SYSTEM_SERVICE.assert_not_decommissioned()?;
+        SYSTEM_SERVICE.assert_not_in_maintenance()?;
+
+        let transfer_id = *HelperMapper::to_uuid(input.transfer_id)?.as_bytes();
+        let transfer = self
+            .transfer_repository
+            .get(&Transfer::key(transfer_id))
+            .ok_or(TransferError::TransferNotFound {
+                transfer_id: Uuid::from_bytes(transfer_id).hyphenated().to_string(),
+            })?;
+
+        if !matches!(transfer.status, TransferStatus::Completed { .. }) {
+            Err(TransferError::TransferNotCompleted {
+                transfer_id: Uuid::from_bytes(transfer_id).hyphenated().to_string(),
+            })?
+        }
+
+        let caller_user = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let annotation = TransferAnnotation {
+            transfer_id,
+            external_invoice_id: input.external_invoice_id,
+            accounting_period: input.accounting_period,
+            notes: input.notes,
+            annotated_by: caller_user.id,
+            annotated_at: ctx.now(),
+        };
+
+        annotation.validate()?;
+
+        self.transfer_annotation_repository
+            .insert(transfer_id, annotation.to_owned());
+
+        Ok(annotation)
     }
 
     fn assert_transfer_access(&self, transfer: &Transfer, ctx: &CallContext) -> ServiceResult<()> {
@@ -102,16 +161,21 @@ impl TransferService {
 mod tests {
     use super::*;
     use crate::{
-        core::{test_utils, validation::disable_mock_resource_validation},
+        core::{
+            read_system_info, test_providers::MockTimeProvider, test_utils,
+            validation::disable_mock_resource_validation, write_system_info,
+        },
         models::{
             account_test_utils::mock_account, request_test_utils::mock_request,
-            transfer_test_utils::mock_transfer, user_test_utils::mock_user, Account, User,
+            system::MaintenanceModeConfig, transfer_test_utils::mock_transfer,
+            user_test_utils::mock_user, Account, User,
         },
         repositories::{
             ACCOUNT_REPOSITORY, REQUEST_REPOSITORY, TRANSFER_REPOSITORY, USER_REPOSITORY,
         },
     };
     use candid::Principal;
+    use std::sync::Arc;
 
     struct TestContext {
         repository: TransferRepository,
@@ -240,6 +304,53 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn transfers_of_hidden_confidential_requests_are_left_out() {
+        let ctx = setup();
+        let mut request = mock_request();
+        request.confidential = true;
+        request.requested_by = [7; 16];
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let mut transfer = mock_transfer();
+        transfer.from_account = ctx.account.id;
+        transfer.initiator_user = ctx.caller_user.id;
+        transfer.request_id = request.id;
+        ctx.repository.insert(transfer.to_key(), transfer.clone());
+
+        let transfers = ctx
+            .service
+            .get_transfers(vec![transfer.id], &ctx.call_context)
+            .unwrap();
+
+        assert!(transfers.is_empty());
+
+        let transfers = ctx
+            .service
+            .list_account_transfers(
+                ListAccountTransfersInput {
+                    account_id: Uuid::from_bytes(ctx.account.id).hyphenated().to_string(),
+                    from_dt: None,
+                    to_dt: None,
+                    status: None,
+                },
+                &ctx.call_context,
+            )
+            .unwrap();
+
+        assert!(transfers.is_empty());
+
+        request.requested_by = ctx.caller_user.id;
+        REQUEST_REPOSITORY.insert(request.to_key(), request);
+
+        let transfers = ctx
+            .service
+            .get_transfers(vec![transfer.id], &ctx.call_context)
+            .unwrap();
+
+        assert_eq!(transfers.len(), 1);
+    }
+
     #[test]
     fn fail_get_transfer_not_allowed() {
         let ctx = setup();
@@ -262,4 +373,99 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn annotate_completed_transfer() {
+        let ctx = setup();
+        let mut transfer = mock_transfer();
+        transfer.from_account = ctx.account.id;
+        transfer.initiator_user = ctx.caller_user.id;
+        transfer.status = TransferStatus::Completed {
+            signature: None,
+            hash: None,
+            completed_at: 10,
+        };
+
+        ctx.repository.insert(transfer.to_key(), transfer.clone());
+
+        let call_context = ctx
+            .call_context
+            .clone()
+            .with_time_provider(Arc::new(MockTimeProvider::new(1_000)));
+        let annotation = ctx
+            .service
+            .annotate_transfer(
+                AnnotateTransferInput {
+                    transfer_id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
+                    external_invoice_id: Some("INV-2024-0042".to_string()),
+                    accounting_period: Some("2024-Q3".to_string()),
+                    notes: None,
+                },
+                &call_context,
+            )
+            .unwrap();
+
+        assert_eq!(annotation.annotated_by, ctx.caller_user.id);
+        assert_eq!(annotation.annotated_at, 1_000);
+        assert_eq!(ctx.service.find_annotation(&transfer.id), Some(annotation));
+        assert_eq!(ctx.repository.get(&transfer.to_key()), Some(transfer));
+    }
+
+    #[test]
+    fn fail_annotate_transfer_not_completed() {
+        let ctx = setup();
+        let mut transfer = mock_transfer();
+        transfer.from_account = ctx.account.id;
+        transfer.initiator_user = ctx.caller_user.id;
+
+        ctx.repository.insert(transfer.to_key(), transfer.clone());
+
+        let result = ctx.service.annotate_transfer(
+            AnnotateTransferInput {
+                transfer_id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
+                external_invoice_id: None,
+                accounting_period: None,
+                notes: Some("Paid supplier invoice".to_string()),
+            },
+            &ctx.call_context,
+        );
+
+        assert_eq!(result.unwrap_err().code, "TRANSFER_NOT_COMPLETED");
+        assert!(ctx.service.find_annotation(&transfer.id).is_none());
+    }
+
+    #[test]
+    fn fail_annotate_transfer_in_maintenance() {
+        let ctx = setup();
+        let mut transfer = mock_transfer();
+        transfer.from_account = ctx.account.id;
+        transfer.status = TransferStatus::Completed {
+            signature: None,
+            hash: None,
+            completed_at: 10,
+        };
+
+        ctx.repository.insert(transfer.to_key(), transfer.clone());
+
+        let mut system_info = read_system_info();
+        system_info.set_maintenance_mode(MaintenanceModeConfig {
+            enabled: true,
+            reason: None,
+            during_migrations: false,
+        });
+        write_system_info(system_info);
+
+        let result = ctx.service.annotate_transfer(
+            AnnotateTransferInput {
+                transfer_id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
+                external_invoice_id: Some("INV-2024-0042".to_string()),
+                accounting_period: None,
+                notes: None,
+            },
+            &ctx.call_context,
+        );
+
+        assert_eq!(result.unwrap_err().code, "STATION_IN_MAINTENANCE");
+        assert!(ctx.service.find_annotation(&transfer.id).is_none());
+    }
 }
@@ -0,0 +1,353 @@
+use crate::{
+    core::{
+        usage::{self, PrincipalUsage},
+        CallContext,
+    },
+    models::{
+        NotificationType, Request, RequestOperationType, StaleApprover, User, UserActivityAnomaly,
+        UserActivityAnomalyNotification, UserId, UserStatus, ADMIN_GROUP_ID,
+    },
+    repositories::{
+        RequestPolicyRepository, UserRepository, UserWhereClause, REQUEST_POLICY_REPOSITORY,
+        USER_REPOSITORY,
+    },
+    services::{NotificationService, NOTIFICATION_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{repository::Repository, types::Timestamp, utils::timestamp_to_rfc3339};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref USAGE_SERVICE: Arc<UsageService> = Arc::new(UsageService::new(
+        Arc::clone(&USER_REPOSITORY),
+        Arc::clone(&REQUEST_POLICY_REPOSITORY),
+        Arc::clone(&NOTIFICATION_SERVICE),
+    ));
+}
+
+/// Keeps track of how the users use the station and alerts the admins when the activity of a user
+/// deviates from their usual usage, which could indicate that one of their identities is compromised.
+#[derive(Default, Debug)]
+pub struct UsageService {
+    user_repository: Arc<UserRepository>,
+    request_policy_repository: Arc<RequestPolicyRepository>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl UsageService {
+    /// The period of inactivity after which a user is considered dormant, which is 90 days.
+    pub const DORMANT_PERIOD_NS: u64 = 90 * 24 * 60 * 60 * 1_000_000_000;
+    /// The minimum time between two updates of the activity of a user by their calls, which is 1 hour.
+    pub const CALL_ACTIVITY_RESOLUTION_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        request_policy_repository: Arc<RequestPolicyRepository>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            request_policy_repository,
+            notification_service,
+        }
+    }
+
+    /// Returns the usage of the principals that used the station within the usage window.
+    pub fn list_principal_usage(&self, ctx: &CallContext) -> Vec<PrincipalUsage> {
+        let mut usage = usage::list_principal_usage(ctx.now());
+        usage.sort_by(|a, b| b.calls.cmp(&a.calls).then(a.principal.cmp(&b.principal)));
+
+        usage
+    }
+
+    /// Returns the last time the user was active on the station.
+    pub fn get_last_active_at(&self, user_id: &UserId) -> Option<Timestamp> {
+        self.user_repository
+            .get(&User::key(*user_id))
+            .and_then(|user| user.last_active_at)
+    }
+
+    /// Records an authenticated call of the caller as an activity of their user.
+    ///
+    /// The activity is written at most once per `CALL_ACTIVITY_RESOLUTION_NS` to keep the calls cheap. Dormant
+    /// users are only marked active once they create or approve a request, so that the admins are still
+    /// alerted of their first approval.
+    pub fn record_call_activity(&self, ctx: &CallContext) {
+        let Some(user) = ctx.user() else {
+            return;
+        };
+
+        let now = ctx.now();
+        if let Some(last_active_at) = user.last_active_at {
+            let inactive_for = now.saturating_sub(last_active_at);
+            if inactive_for < Self::CALL_ACTIVITY_RESOLUTION_NS
+                || inactive_for >= Self::DORMANT_PERIOD_NS
+            {
+                return;
+            }
+        }
+
+        self.mark_active(user, now);
+    }
+
+    /// Returns the active users that were not active for the given period but are named as approvers by
+    /// request policies, since the quorums of these policies might no longer be attainable.
+    ///
+    /// The users whose activity was never recorded are included as well.
+    pub fn list_stale_approvers(
+        &self,
+        inactive_for_ns: u64,
+        ctx: &CallContext,
+    ) -> Vec<StaleApprover> {
+        let cutoff = ctx.now().saturating_sub(inactive_for_ns);
+        let users = self.user_repository.find_where(UserWhereClause {
+            search_term: None,
+            statuses: Some(vec![UserStatus::Active]),
+            groups: None,
+        });
+        let policy_approvers = self
+            .request_policy_repository
+            .list()
+            .into_iter()
+            .map(|policy| (policy.id, policy.rule.approvers(&users)))
+            .collect::<Vec<_>>();
+
+        let mut stale_approvers = users
+            .iter()
+            .filter(|user| {
+                user.last_active_at
+                    .map_or(true, |last_active_at| last_active_at < cutoff)
+            })
+            .filter_map(|user| {
+                let policy_ids = policy_approvers
+                    .iter()
+                    .filter(|(_, approvers)| approvers.contains(&user.id))
+                    .map(|(policy_id, _)| *policy_id)
+                    .collect::<Vec<_>>();
+
+                (!policy_ids.is_empty()).then(|| StaleApprover {
+                    user_id: user.id,
+                    name: user.name.clone(),
+                    last_active_at: user.last_active_at,
+                    policy_ids,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // the approvers that were inactive for the longest come first
+        stale_approvers.sort_by(|a, b| {
+            a.last_active_at
+                .cmp(&b.last_active_at)
+                .then(a.name.cmp(&b.name))
+        });
+
+        stale_approvers
+    }
+
+    /// Records a request created by the caller and alerts the admins of spikes of request creations.
+    pub async fn record_request_created(
+        &self,
+        requester: &User,
+        request: &Request,
+        ctx: &CallContext,
+    ) {
+        let operation_type = RequestOperationType::from(request.operation.clone());
+        if let Some(requests_created) = usage::record_request_created(ctx, operation_type) {
+            self.notify_admins(
+                requester,
+                UserActivityAnomaly::RequestCreationSpike { requests_created },
+                format!("Unusual number of requests created by {}", requester.name),
+                format!(
+                    "The user created {} requests within the last hour, which is much more than usual.",
+                    requests_created
+                ),
+            )
+            .await;
+        }
+
+        self.mark_active(requester, ctx.now());
+    }
+
+    /// Records an approval submitted by the caller and alerts the admins when the approver was dormant.
+    pub async fn record_request_approval(
+        &self,
+        approver: &User,
+        request: &Request,
+        ctx: &CallContext,
+    ) {
+        usage::record_approval(ctx);
+
+        let now = ctx.now();
+        if let Some(last_active_at) = approver.last_active_at {
+            if now.saturating_sub(last_active_at) >= Self::DORMANT_PERIOD_NS {
+                self.notify_admins(
+                    approver,
+                    UserActivityAnomaly::DormantUserApproval {
+                        request_id: request.id,
+                        last_active_at,
+                    },
+                    format!("Approval from dormant user {}", approver.name),
+                    format!(
+                        "The user submitted an approval for request {} after being inactive since {}.",
+                        Uuid::from_bytes(request.id).hyphenated(),
+                        timestamp_to_rfc3339(&last_active_at)
+                    ),
+                )
+                .await;
+            }
+        }
+
+        self.mark_active(approver, now);
+    }
+
+    fn mark_active(&self, user: &User, now: Timestamp) {
+        // the user is read again since it could have been edited while the request was processed
+        if let Some(mut user) = self.user_repository.get(&User::key(user.id)) {
+            user.last_active_at = Some(now);
+
+            self.user_repository.insert(user.to_key(), user);
+        }
+    }
+
+    async fn notify_admins(
+        &self,
+        user: &User,
+        anomaly: UserActivityAnomaly,
+        title: String,
+        message: String,
+    ) {
+        for admin in self
+            .user_repository
+            .find_by_group_and_status(&ADMIN_GROUP_ID, &UserStatus::Active)
+        {
+            self.notification_service
+                .send_notification(
+                    admin.id,
+                    NotificationType::UserActivityAnomaly(UserActivityAnomalyNotification {
+                        user_id: user.id,
+                        anomaly: anomaly.clone(),
+                    }),
+                    title.clone(),
+                    Some(message.clone()),
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_providers::MockTimeProvider,
+        models::{
+            request_policy_rule::RequestPolicyRule, request_policy_test_utils::mock_request_policy,
+            request_specifier::UserSpecifier, request_test_utils::mock_request,
+            user_test_utils::mock_user,
+        },
+        repositories::NOTIFICATION_REPOSITORY,
+    };
+    use candid::Principal;
+
+    #[tokio::test]
+    async fn notifies_admins_of_approvals_from_dormant_users() {
+        let mut admin = mock_user();
+        admin.groups = vec![ADMIN_GROUP_ID];
+        USER_REPOSITORY.insert(admin.to_key(), admin.clone());
+
+        let mut approver = mock_user();
+        approver.identities = vec![Principal::from_slice(&[9; 29])];
+        approver.last_active_at = Some(1);
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let now = 1 + UsageService::DORMANT_PERIOD_NS;
+        let ctx = CallContext::new(approver.identities[0])
+            .with_time_provider(Arc::new(MockTimeProvider::new(now)));
+
+        USAGE_SERVICE
+            .record_request_approval(&approver, &mock_request(), &ctx)
+            .await;
+
+        let notifications = NOTIFICATION_REPOSITORY.list();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].target_user_id, admin.id);
+        assert!(matches!(
+            notifications[0].notification_type,
+            NotificationType::UserActivityAnomaly(UserActivityAnomalyNotification {
+                anomaly: UserActivityAnomaly::DormantUserApproval { .. },
+                ..
+            })
+        ));
+        assert_eq!(USAGE_SERVICE.get_last_active_at(&approver.id), Some(now));
+
+        // the approver is no longer dormant after their approval
+        let approver = USER_REPOSITORY.get(&approver.to_key()).unwrap();
+        USAGE_SERVICE
+            .record_request_approval(&approver, &mock_request(), &ctx)
+            .await;
+
+        assert_eq!(NOTIFICATION_REPOSITORY.list().len(), 1);
+    }
+
+    #[test]
+    fn calls_mark_the_users_active_at_most_once_per_resolution() {
+        let mut user = mock_user();
+        user.identities = vec![Principal::from_slice(&[10; 29])];
+        user.last_active_at = Some(1);
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let call_at = |now: Timestamp| {
+            let ctx = CallContext::new(user.identities[0])
+                .with_time_provider(Arc::new(MockTimeProvider::new(now)));
+
+            USAGE_SERVICE.record_call_activity(&ctx);
+
+            USAGE_SERVICE.get_last_active_at(&user.id)
+        };
+
+        let now = 1 + UsageService::CALL_ACTIVITY_RESOLUTION_NS;
+        assert_eq!(call_at(now), Some(now));
+        assert_eq!(call_at(now + 1), Some(now));
+
+        // dormant users stay dormant until they create or approve a request
+        let dormant_at = now + UsageService::DORMANT_PERIOD_NS;
+        assert_eq!(call_at(dormant_at), Some(now));
+    }
+
+    #[test]
+    fn lists_the_stale_approvers_named_by_policies() {
+        let day_ns = 24 * 60 * 60 * 1_000_000_000;
+        let now = 100 * day_ns;
+
+        let mut stale = mock_user();
+        stale.name = "stale".to_string();
+        stale.last_active_at = Some(now - 40 * day_ns);
+        let mut active = mock_user();
+        active.name = "active".to_string();
+        active.last_active_at = Some(now - day_ns);
+        let mut not_an_approver = mock_user();
+        not_an_approver.last_active_at = None;
+        for user in [&stale, &active, &not_an_approver] {
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+        }
+
+        let mut policy = mock_request_policy();
+        policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![stale.id, active.id]), 2);
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+
+        let ctx = CallContext::new(Principal::anonymous())
+            .with_time_provider(Arc::new(MockTimeProvider::new(now)));
+        let stale_approvers = USAGE_SERVICE.list_stale_approvers(30 * day_ns, &ctx);
+
+        assert_eq!(
+            stale_approvers,
+            vec![StaleApprover {
+                user_id: stale.id,
+                name: stale.name.clone(),
+                last_active_at: stale.last_active_at,
+                policy_ids: vec![policy.id],
+            }]
+        );
+    }
+}
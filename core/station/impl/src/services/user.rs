@@ -6,6 +6,7 @@ use crate::{
         CallContext,
     },
     errors::UserError,
+    jobs::schedule_group_membership_changes,
     mappers::{authorization::USER_PRIVILEGES, HelperMapper, UserMapper},
     models::{
         resource::{Resource, ResourceId, UserResourceAction},
@@ -122,6 +123,10 @@ impl UserService {
 
         self.user_repository.insert(user.to_key(), user.to_owned());
 
+        if let Some(next_change_at) = user.next_membership_change_at() {
+            schedule_group_membership_changes(next_change_at);
+        }
+
         Ok(user)
     }
 
@@ -131,6 +136,15 @@ impl UserService {
     pub async fn edit_user(&self, input: EditUserOperationInput) -> ServiceResult<User> {
         let mut user = self.get_user(&input.user_id)?;
 
+        if let Some(expected_version) = input.expected_version {
+            if user.version != expected_version {
+                Err(UserError::VersionConflict {
+                    expected_version,
+                    current_version: user.version,
+                })?
+            }
+        }
+
         if let Some(identities) = &input.identities {
             for identity in identities.iter() {
                 self.assert_identity_has_no_associated_user(identity, Some(user.id))?;
@@ -145,9 +159,14 @@ impl UserService {
 
         user.update_with(input)?;
         user.validate()?;
+        user.version += 1;
 
         self.user_repository.insert(user.to_key(), user.to_owned());
 
+        if let Some(next_change_at) = user.next_membership_change_at() {
+            schedule_group_membership_changes(next_change_at);
+        }
+
         if let Some(true) = cancel_pending_requests {
             let pending_requests: Vec<_> = self
                 .request_repository
@@ -393,6 +412,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "user-1".to_string(),
+            scheduled_memberships: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -414,6 +434,7 @@ mod tests {
             groups: vec![[0; 16]],
             status: UserStatus::Active,
             name: "user-1".to_string(),
+            scheduled_memberships: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -432,6 +453,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "Jane Doe".to_string(),
+            scheduled_memberships: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -442,6 +464,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "John Doe".to_string(),
+            scheduled_memberships: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -464,6 +487,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "Jane Doe".to_string(),
+            scheduled_memberships: vec![],
         };
 
         let result = USER_SERVICE.add_user(input);
@@ -493,6 +517,8 @@ mod tests {
             groups: None,
             status: None,
             cancel_pending_requests: None,
+            expected_version: None,
+            scheduled_memberships: None,
         };
 
         let result = USER_SERVICE.edit_user(input).await;
@@ -520,6 +546,8 @@ mod tests {
             name: None,
             status: None,
             cancel_pending_requests: None,
+            expected_version: None,
+            scheduled_memberships: None,
         };
 
         let result = ctx.service.edit_user(input).await;
@@ -546,6 +574,8 @@ mod tests {
             name: None,
             status: None,
             cancel_pending_requests: None,
+            expected_version: None,
+            scheduled_memberships: None,
         };
 
         let result = USER_SERVICE.edit_user(input).await;
@@ -635,6 +665,7 @@ pub mod user_service_test_utils {
                 groups: groups.iter().map(|g| g.id).collect(),
                 status: UserStatus::Active,
                 name: user_id.to_string(),
+                scheduled_memberships: vec![],
             };
 
             users.push(USER_SERVICE.add_user(input).unwrap());
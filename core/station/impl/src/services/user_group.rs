@@ -8,12 +8,18 @@ use crate::core::{generate_uuid_v4, CallContext};
 use crate::errors::UserGroupError;
 use crate::models::resource::{Resource, ResourceAction, ResourceId};
 use crate::models::{
-    AddUserGroupOperationInput, EditUserGroupOperationInput, UserGroup, UserGroupCallerPrivileges,
+    reassign_user_group_id, AddUserGroupOperationInput, EditUserGroupOperationInput,
+    ListRequestsOperationType, RequestOperationType, RequestStatusCode, User, UserGroup,
+    UserGroupCallerPrivileges, UserGroupQuotaUsage, UserGroupReassignment, UserGroupReferences,
+    UserStatus,
+};
+use crate::repositories::{
+    permission::PERMISSION_REPOSITORY, RequestRepository, RequestWhereClause, UseGroupWhereClause,
+    UserGroupRepository, UserRepository, REQUEST_POLICY_REPOSITORY,
 };
-use crate::repositories::{UseGroupWhereClause, UserGroupRepository};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ServiceResult;
-use orbit_essentials::model::ModelValidator;
+use orbit_essentials::model::{ModelKey, ModelValidator};
 use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
 use station_api::ListUserGroupsInput;
@@ -29,6 +35,8 @@ lazy_static! {
 pub struct UserGroupService {
     system_service: Arc<SystemService>,
     user_group_repository: Arc<UserGroupRepository>,
+    user_repository: Arc<UserRepository>,
+    request_repository: Arc<RequestRepository>,
 }
 
 impl UserGroupService {
@@ -96,6 +104,7 @@ impl UserGroupService {
         let user_group = UserGroup {
             id: *user_group_id.as_bytes(),
             name: input.name.to_string(),
+            quotas: input.quotas,
             last_modification_timestamp: next_time(),
         };
 
@@ -111,6 +120,9 @@ impl UserGroupService {
         let mut user_group = self.get(&input.user_group_id)?;
 
         user_group.name = input.name.to_string();
+        if let Some(quotas) = input.quotas {
+            user_group.quotas = quotas;
+        }
         user_group.last_modification_timestamp = next_time();
 
         user_group.validate()?;
@@ -121,7 +133,15 @@ impl UserGroupService {
         Ok(user_group)
     }
 
-    pub async fn remove(&self, id: &UUID) -> ServiceResult<()> {
+    /// Removes the user group along with its references, which are moved to the replacement group or
+    /// dropped in the same call so that nothing is left naming a group that doesn't exist.
+    ///
+    /// Returns the entities whose references were reassigned.
+    pub async fn remove(
+        &self,
+        id: &UUID,
+        reassignment: Option<UserGroupReassignment>,
+    ) -> ServiceResult<UserGroupReferences> {
         let user_group = self.get(id)?;
 
         let system_info = self.system_service.get_system_info();
@@ -135,26 +155,270 @@ impl UserGroupService {
             }
         }
 
+        let references = UserGroupReferences::find(&user_group.id);
+        let replacement = match reassignment {
+            Some(reassignment) => reassignment.replacement(),
+            None if references.is_empty() => None,
+            None => Err(UserGroupError::StillReferenced {
+                id: Uuid::from_bytes(user_group.id).hyphenated().to_string(),
+            })?,
+        };
+
+        if let Some(replacement) = replacement {
+            if replacement == user_group.id
+                || self.user_group_repository.get(&replacement).is_none()
+            {
+                Err(UserGroupError::InvalidReplacement {
+                    id: Uuid::from_bytes(replacement).hyphenated().to_string(),
+                })?
+            }
+        }
+
+        for policy_id in &references.request_policies {
+            if let Some(mut policy) = REQUEST_POLICY_REPOSITORY.get(policy_id) {
+                policy.rule.reassign_user_group(&user_group.id, replacement);
+                policy.version += 1;
+
+                REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+            }
+        }
+
+        for resource in &references.permissions {
+            if let Some(mut permission) = PERMISSION_REPOSITORY.get(resource) {
+                reassign_user_group_id(
+                    &mut permission.allow.user_groups,
+                    &user_group.id,
+                    replacement,
+                );
+
+                PERMISSION_REPOSITORY.insert(permission.key(), permission);
+            }
+        }
+
+        let now = next_time();
+        for user_id in &references.users {
+            if let Some(mut user) = self.user_repository.get(&User::key(*user_id)) {
+                reassign_user_group_id(&mut user.groups, &user_group.id, replacement);
+                user.scheduled_memberships.retain_mut(|membership| {
+                    if membership.group_id != user_group.id {
+                        return true;
+                    }
+
+                    match replacement {
+                        Some(replacement) => {
+                            membership.group_id = replacement;
+                            true
+                        }
+                        None => false,
+                    }
+                });
+                user.version += 1;
+                user.last_modification_timestamp = now;
+
+                self.user_repository.insert(user.to_key(), user);
+            }
+        }
+
         self.user_group_repository.remove(&user_group.id);
 
+        Ok(references)
+    }
+
+    /// Returns the current utilization of the quotas of the user groups that have quotas.
+    pub fn list_quota_usage(&self) -> Vec<UserGroupQuotaUsage> {
+        self.user_group_repository
+            .list()
+            .into_iter()
+            .filter(|user_group| !user_group.quotas.is_empty())
+            .map(|user_group| self.get_quota_usage(&user_group))
+            .collect()
+    }
+
+    /// Returns the current utilization of the quotas of the user group, which is counted over the
+    /// requests of its current members.
+    pub fn get_quota_usage(&self, user_group: &UserGroup) -> UserGroupQuotaUsage {
+        let members = [UserStatus::Active, UserStatus::Inactive]
+            .iter()
+            .flat_map(|status| {
+                self.user_repository
+                    .find_by_group_and_status(&user_group.id, status)
+            })
+            .map(|user| user.id)
+            .collect::<Vec<_>>();
+
+        let (pending_requests, accounts) = match members.is_empty() {
+            true => (0, 0),
+            false => (
+                self.count_requests(members.clone(), vec![RequestStatusCode::Created], vec![]),
+                // the accounts that are still being requested count as well, so that the quota
+                // can't be exceeded by requesting several accounts at once
+                self.count_requests(
+                    members,
+                    vec![
+                        RequestStatusCode::Created,
+                        RequestStatusCode::Approved,
+                        RequestStatusCode::Scheduled,
+                        RequestStatusCode::Processing,
+                        RequestStatusCode::Completed,
+                    ],
+                    vec![ListRequestsOperationType::AddAccount],
+                ),
+            ),
+        };
+
+        UserGroupQuotaUsage {
+            user_group_id: user_group.id,
+            quotas: user_group.quotas.clone(),
+            pending_requests,
+            accounts,
+        }
+    }
+
+    /// Checks that the new request of the requester is within the quotas of all of their groups.
+    pub fn assert_within_quotas(
+        &self,
+        requester: &User,
+        operation_type: &RequestOperationType,
+    ) -> ServiceResult<()> {
+        for user_group_id in &requester.groups {
+            let Some(user_group) = self.user_group_repository.get(user_group_id) else {
+                continue;
+            };
+
+            if user_group.quotas.is_empty() {
+                continue;
+            }
+
+            let usage = self.get_quota_usage(&user_group);
+            if let Some(max) = user_group.quotas.max_pending_requests {
+                if usage.pending_requests >= max {
+                    Err(UserGroupError::PendingRequestsQuotaExceeded {
+                        name: user_group.name.clone(),
+                        max,
+                    })?
+                }
+            }
+
+            if let (Some(max), RequestOperationType::AddAccount) =
+                (user_group.quotas.max_accounts, operation_type)
+            {
+                if usage.accounts >= max {
+                    Err(UserGroupError::AccountsQuotaExceeded {
+                        name: user_group.name.clone(),
+                        max,
+                    })?
+                }
+            }
+        }
+
         Ok(())
     }
+
+    fn count_requests(
+        &self,
+        requesters: Vec<UUID>,
+        statuses: Vec<RequestStatusCode>,
+        operation_types: Vec<ListRequestsOperationType>,
+    ) -> u32 {
+        self.request_repository
+            .find_ids_where(
+                RequestWhereClause {
+                    created_dt_from: None,
+                    created_dt_to: None,
+                    expiration_dt_from: None,
+                    expiration_dt_to: None,
+                    operation_types,
+                    statuses,
+                    approvers: vec![],
+                    not_approvers: vec![],
+                    requesters,
+                    not_requesters: vec![],
+                    excluded_ids: vec![],
+                    viewer: None,
+                },
+                None,
+            )
+            .map(|ids| ids.len() as u32)
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{
         core::write_system_info,
-        models::{AddUserGroupOperationInput, SystemInfo},
+        models::{
+            permission::{Allow, Permission},
+            request_policy_rule::RequestPolicyRule,
+            request_policy_test_utils::mock_request_policy,
+            request_specifier::UserSpecifier,
+            request_test_utils::mock_request,
+            resource::{AccountResourceAction, Resource},
+            user_test_utils::mock_user,
+            AddUserGroupOperationInput, RequestOperationType, RequestStatus, SystemInfo,
+            UserGroupQuotas, UserGroupReassignment, UserGroupReferences,
+        },
+        repositories::{
+            permission::PERMISSION_REPOSITORY, RequestRepository, REQUEST_POLICY_REPOSITORY,
+            USER_REPOSITORY,
+        },
     };
+    use orbit_essentials::repository::Repository;
 
     use super::USER_GROUP_SERVICE;
 
+    #[tokio::test]
+    async fn requests_beyond_the_quota_of_the_group_are_refused() {
+        let user_group = USER_GROUP_SERVICE
+            .create(AddUserGroupOperationInput {
+                name: "Contractors".to_string(),
+                quotas: UserGroupQuotas {
+                    max_pending_requests: Some(1),
+                    max_accounts: None,
+                },
+            })
+            .await
+            .unwrap();
+
+        let mut user = mock_user();
+        user.groups = vec![user_group.id];
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let request = mock_request();
+        assert!(USER_GROUP_SERVICE
+            .assert_within_quotas(
+                &user,
+                &RequestOperationType::from(request.operation.clone())
+            )
+            .is_ok());
+
+        // the observers would set the timers of the request
+        let request_repository = RequestRepository::with_empty_observers();
+        let mut pending_request = mock_request();
+        pending_request.requested_by = user.id;
+        pending_request.status = RequestStatus::Created;
+        request_repository.insert(pending_request.to_key(), pending_request);
+
+        let error = USER_GROUP_SERVICE
+            .assert_within_quotas(
+                &user,
+                &RequestOperationType::from(request.operation.clone()),
+            )
+            .unwrap_err();
+        assert_eq!(error.code, "PENDING_REQUESTS_QUOTA_EXCEEDED");
+
+        let usage = USER_GROUP_SERVICE.list_quota_usage();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].pending_requests, 1);
+        assert_eq!(usage[0].accounts, 0);
+    }
+
     #[tokio::test]
     async fn test_deleting_disaster_recovery_committee_user_group() {
         let user_group = USER_GROUP_SERVICE
             .create(AddUserGroupOperationInput {
                 name: "Test".to_string(),
+                quotas: Default::default(),
             })
             .await
             .expect("Failed to create user group");
@@ -170,7 +434,7 @@ mod test {
 
         write_system_info(system_info);
 
-        let result = USER_GROUP_SERVICE.remove(&user_group.id).await;
+        let result = USER_GROUP_SERVICE.remove(&user_group.id, None).await;
 
         assert!(result.is_err());
 
@@ -178,4 +442,81 @@ mod test {
 
         assert_eq!(error.code, "CANNOT_DELETE_DISASTER_RECOVERY_COMMITTEE");
     }
+
+    #[tokio::test]
+    async fn removing_a_referenced_user_group_reassigns_its_references() {
+        write_system_info(SystemInfo::default());
+
+        let mut user_groups = Vec::new();
+        for name in ["Finance", "Treasury"] {
+            user_groups.push(
+                USER_GROUP_SERVICE
+                    .create(AddUserGroupOperationInput {
+                        name: name.to_string(),
+                        quotas: Default::default(),
+                    })
+                    .await
+                    .unwrap(),
+            );
+        }
+        let (removed, replacement) = (user_groups[0].id, user_groups[1].id);
+
+        let mut user = mock_user();
+        user.groups = vec![removed];
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let mut policy = mock_request_policy();
+        policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Group(vec![removed]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+
+        let resource = Resource::Account(AccountResourceAction::List);
+        PERMISSION_REPOSITORY.insert(
+            resource.clone(),
+            Permission::new(Allow::user_groups(vec![removed]), resource.clone()),
+        );
+
+        let error = USER_GROUP_SERVICE.remove(&removed, None).await.unwrap_err();
+        assert_eq!(error.code, "STILL_REFERENCED");
+
+        let affected = USER_GROUP_SERVICE
+            .remove(
+                &removed,
+                Some(UserGroupReassignment::ReplaceWith(replacement)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(affected.request_policies, vec![policy.id]);
+        assert_eq!(affected.permissions, vec![resource.clone()]);
+        assert_eq!(affected.users, vec![user.id]);
+        assert!(USER_GROUP_SERVICE.get(&removed).is_err());
+        assert_eq!(
+            USER_REPOSITORY.get(&user.to_key()).unwrap().groups,
+            vec![replacement]
+        );
+        assert_eq!(
+            REQUEST_POLICY_REPOSITORY.get(&policy.id).unwrap().rule,
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![replacement]), 1)
+        );
+        assert_eq!(
+            PERMISSION_REPOSITORY
+                .get(&resource)
+                .unwrap()
+                .allow
+                .user_groups,
+            vec![replacement]
+        );
+
+        USER_GROUP_SERVICE
+            .remove(&replacement, Some(UserGroupReassignment::Detach))
+            .await
+            .unwrap();
+
+        assert!(USER_REPOSITORY
+            .get(&user.to_key())
+            .unwrap()
+            .groups
+            .is_empty());
+        assert!(UserGroupReferences::find(&replacement).is_empty());
+    }
 }
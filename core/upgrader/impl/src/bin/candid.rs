@@ -0,0 +1,5 @@
+//! Prints the candid interface of the upgrader as generated from its entrypoints.
+
+fn main() {
+    print!("{}", upgrader::candid_interface());
+}
@@ -9,7 +9,7 @@ use ic_stable_structures::{
     DefaultMemoryImpl, StableBTreeMap,
 };
 use lazy_static::lazy_static;
-use orbit_essentials::storable;
+use orbit_essentials::{api::ApiResult, storable};
 use std::{cell::RefCell, sync::Arc, thread::LocalKey};
 use upgrade::{UpgradeError, UpgradeParams};
 use upgrader_api::{InitArg, TriggerUpgradeError};
@@ -88,20 +88,32 @@ async fn trigger_upgrade(params: upgrader_api::UpgradeParams) -> Result<(), Trig
     })
 }
 
+// The candid interface is generated from the entrypoints above, so it must stay after all of them.
+candid::export_service!();
+
+/// Returns the candid interface of the canister as generated from its entrypoints.
+pub fn candid_interface() -> String {
+    __export_service()
+}
+
+/// Exposes the generated interface to the tools that fetch it from the canister, the method is exported
+/// without the update macro so that it is not part of the interface itself.
+#[cfg(target_arch = "wasm32")]
+#[export_name = "canister_query __get_candid_interface_tmp_hack"]
+fn get_candid_interface_tmp_hack() {
+    ic_cdk::api::call::reply((candid_interface(),));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use orbit_essentials::api::ApiResult;
 
     #[test]
     fn check_candid_interface() {
         use candid_parser::utils::{service_equal, CandidSource};
 
-        candid::export_service!();
-        let new_interface = __export_service();
-
         service_equal(
-            CandidSource::Text(&new_interface),
+            CandidSource::Text(&candid_interface()),
             CandidSource::Text(include_str!("../../api/spec.did")),
         )
         .unwrap();
@@ -1,4 +1,5 @@
 use orbit_essentials::ic_stable_structures::Storable;
+use orbit_essentials::model::TryFromStableBytes;
 use orbit_essentials::storable;
 
 #[storable(serializer = "cbor")]
@@ -92,3 +93,17 @@ fn validate_deserialization_should_fail_for_missing_variant() {
     let serialized_updated = updated.to_bytes();
     let _ = MyInitialEnum::from_bytes(serialized_updated);
 }
+
+#[test]
+fn try_from_bytes_returns_an_error_for_missing_variant() {
+    let updated = MyUpdatedEnum::C(true);
+
+    let serialized_updated = updated.to_bytes();
+
+    assert!(MyInitialEnum::try_from_bytes(serialized_updated.as_ref()).is_err());
+}
+
+#[test]
+fn try_from_bytes_returns_an_error_for_malformed_bytes() {
+    assert!(MyInitialStruct::try_from_bytes(&[0xff, 0x00, 0x13]).is_err());
+}
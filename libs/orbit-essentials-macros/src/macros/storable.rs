@@ -165,6 +165,7 @@ fn expand_candid_impl(
 ) -> Result<proc_macro2::TokenStream, Error> {
     let object_name = input.ident.clone();
     let storage_bounds = storage_bounds(args.size);
+    let from_bytes_impl = from_bytes_impl(&object_name);
     let derive_serialize = quote! { #[derive(candid::CandidType)] };
     let derive_deserialize = if args.skip_deserialize {
         quote! {}
@@ -177,6 +178,14 @@ fn expand_candid_impl(
         #derive_deserialize
         #input
 
+        impl orbit_essentials::model::TryFromStableBytes for #object_name {
+            fn try_from_bytes(bytes: &[u8]) -> Result<Self, String> {
+                use candid::Decode;
+
+                candid::Decode!(bytes, Self).map_err(|err| err.to_string())
+            }
+        }
+
         impl orbit_essentials::ic_stable_structures::Storable for #object_name {
             fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
                 use candid::Encode;
@@ -184,11 +193,7 @@ fn expand_candid_impl(
                 std::borrow::Cow::Owned(candid::Encode!(self).unwrap())
             }
 
-            fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
-                use candid::Decode;
-
-                candid::Decode!(bytes.as_ref(), Self).unwrap()
-            }
+            #from_bytes_impl
 
             #storage_bounds
         }
@@ -203,6 +208,7 @@ fn expand_cbor_impl(
 ) -> Result<proc_macro2::TokenStream, Error> {
     let object_name = input.ident.clone();
     let storage_bounds = storage_bounds(args.size);
+    let from_bytes_impl = from_bytes_impl(&object_name);
     let derive_serialize = quote! { #[derive(serde::Serialize)] };
     let derive_deserialize = if args.skip_deserialize {
         quote! {}
@@ -215,14 +221,18 @@ fn expand_cbor_impl(
         #derive_deserialize
         #input
 
+        impl orbit_essentials::model::TryFromStableBytes for #object_name {
+            fn try_from_bytes(bytes: &[u8]) -> Result<Self, String> {
+                serde_cbor::from_slice(bytes).map_err(|err| err.to_string())
+            }
+        }
+
         impl orbit_essentials::ic_stable_structures::Storable for #object_name {
             fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
                 std::borrow::Cow::Owned(serde_cbor::to_vec(self).unwrap())
             }
 
-            fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
-                serde_cbor::from_slice(bytes.as_ref()).unwrap()
-            }
+            #from_bytes_impl
 
             #storage_bounds
         }
@@ -231,6 +241,18 @@ fn expand_cbor_impl(
     Ok(expanded)
 }
 
+/// The `from_bytes` implementation, which can only fail by panicking, reports which object could not be decoded.
+fn from_bytes_impl(object_name: &syn::Ident) -> proc_macro2::TokenStream {
+    let object_name_str = object_name.to_string();
+
+    quote! {
+        fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+            <Self as orbit_essentials::model::TryFromStableBytes>::try_from_bytes(bytes.as_ref())
+                .unwrap_or_else(|err| panic!("Failed to decode {} from stable memory: {}", #object_name_str, err))
+        }
+    }
+}
+
 fn storage_bounds(size: Option<u32>) -> proc_macro2::TokenStream {
     match size {
         Some(size) => quote! {
@@ -288,13 +310,20 @@ mod tests {
                     pub id: u32,
                 }
 
+                impl orbit_essentials::model::TryFromStableBytes for MyStruct {
+                    fn try_from_bytes(bytes: &[u8]) -> Result<Self, String> {
+                        serde_cbor::from_slice(bytes).map_err(|err| err.to_string())
+                    }
+                }
+
                 impl orbit_essentials::ic_stable_structures::Storable for MyStruct {
                     fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
                         std::borrow::Cow::Owned(serde_cbor::to_vec(self).unwrap())
                     }
 
                     fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
-                        serde_cbor::from_slice(bytes.as_ref()).unwrap()
+                        <Self as orbit_essentials::model::TryFromStableBytes>::try_from_bytes(bytes.as_ref())
+                            .unwrap_or_else(|err| panic!("Failed to decode {} from stable memory: {}", "MyStruct", err))
                     }
 
                     const BOUND: orbit_essentials::ic_stable_structures::storable::Bound = orbit_essentials::ic_stable_structures::storable::Bound::Unbounded;
@@ -324,6 +353,14 @@ mod tests {
                     pub id: u32,
                 }
 
+                impl orbit_essentials::model::TryFromStableBytes for MyStruct {
+                    fn try_from_bytes(bytes: &[u8]) -> Result<Self, String> {
+                        use candid::Decode;
+
+                        candid::Decode!(bytes, Self).map_err(|err| err.to_string())
+                    }
+                }
+
                 impl orbit_essentials::ic_stable_structures::Storable for MyStruct {
                     fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
                         use candid::Encode;
@@ -332,9 +369,8 @@ mod tests {
                     }
 
                     fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
-                        use candid::Decode;
-
-                        candid::Decode!(bytes.as_ref(), Self).unwrap()
+                        <Self as orbit_essentials::model::TryFromStableBytes>::try_from_bytes(bytes.as_ref())
+                            .unwrap_or_else(|err| panic!("Failed to decode {} from stable memory: {}", "MyStruct", err))
                     }
 
                     const BOUND: orbit_essentials::ic_stable_structures::storable::Bound = orbit_essentials::ic_stable_structures::storable::Bound::Unbounded;
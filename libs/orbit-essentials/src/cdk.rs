@@ -61,6 +61,7 @@ pub mod mocks {
         thread_local! {
             static IC_TIME: RefCell<SystemTime> = const { RefCell::new(UNIX_EPOCH) };
             static IC_CANISTER_BALANCE: RefCell<u64> = const { RefCell::new(100_000_000_000) };
+            static IC_PERFORMANCE_COUNTER: RefCell<u64> = const { RefCell::new(0) };
         }
 
         pub fn data_certificate() -> Option<Vec<u8>> {
@@ -101,6 +102,15 @@ pub mod mocks {
             IC_CANISTER_BALANCE.with(|b| *b.borrow())
         }
 
+        /// Advances the mocked performance counter, which is otherwise left unchanged.
+        pub fn add_mock_instructions(instructions: u64) {
+            IC_PERFORMANCE_COUNTER.with(|c| *c.borrow_mut() += instructions);
+        }
+
+        pub fn performance_counter(_counter_type: u32) -> u64 {
+            IC_PERFORMANCE_COUNTER.with(|c| *c.borrow())
+        }
+
         pub mod management_canister {
             pub mod main {
                 use ic_cdk::api::call::CallResult;
@@ -323,6 +323,15 @@ where
                 .inc();
         });
     }
+
+    fn inc_by(&self, service_name: &str, labels: &HashMap<&str, &str>, value: f64) {
+        with_metrics_registry(service_name, |registry| {
+            registry
+                .counter_vec_mut(self.name(), self.labels(), self.help())
+                .with(labels)
+                .inc_by(value);
+        });
+    }
 }
 
 pub trait ApplicationCounterMetric<Model>: ApplicationMetric<Model>
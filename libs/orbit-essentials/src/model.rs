@@ -8,6 +8,15 @@ pub trait ModelValidator<Err = ApiError> {
     fn validate(&self) -> ModelValidatorResult<Err>;
 }
 
+/// A trait for the models that are kept in stable memory to be decoded without panicking.
+///
+/// `Storable::from_bytes` can not return an error, so it panics with the error of this decoder instead, callers
+/// that can recover from corrupted entries (e.g. migrations) should use this method directly.
+pub trait TryFromStableBytes: Sized {
+    /// Decodes the model from its stable memory representation, failing on malformed bytes.
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self, String>;
+}
+
 /// A trait for models to expose their key.
 pub trait ModelKey<Key = UUID> {
     fn key(&self) -> Key;
@@ -1,6 +1,7 @@
 use crate::{model::ModelKey, types::UUID};
 use ic_stable_structures::{Memory, StableBTreeMap, Storable};
 use std::collections::HashSet;
+use std::ops::Bound;
 
 pub trait StableDb<Key, Value, Mem>
 where
@@ -67,6 +68,43 @@ where
             Self::with_db(|db| db.insert(value.key(), value));
         }
     }
+
+    /// Rebuilds the indexes of at most `limit` entries, starting after the entry with the given key.
+    ///
+    /// All the indexes are cleared when the rebuild starts (i.e. `after` is `None`), the entries themselves are
+    /// left untouched. Returns the number of processed entries and the key of the last processed entry if there
+    /// are more entries left, so that the rebuild can be spread over several messages to stay within the
+    /// instruction limit.
+    fn rebuild_indexes_chunk(&self, after: Option<Key>, limit: usize) -> (usize, Option<Key>) {
+        if after.is_none() {
+            self.clear_indexes();
+        }
+
+        let lower_bound = match after {
+            Some(key) => Bound::Excluded(key),
+            None => Bound::Unbounded,
+        };
+        let mut entries = Self::with_db(|db| {
+            db.range((lower_bound, Bound::Unbounded))
+                .take(limit.saturating_add(1))
+                .collect::<Vec<_>>()
+        });
+
+        let has_more = entries.len() > limit;
+        entries.truncate(limit);
+
+        for (_, value) in entries.iter() {
+            self.add_entry_indexes(value);
+        }
+
+        let processed = entries.len();
+        let next = match has_more {
+            true => entries.pop().map(|(key, _)| key),
+            false => None,
+        };
+
+        (processed, next)
+    }
 }
 
 /// A repository is a generic interface for storing and retrieving data.
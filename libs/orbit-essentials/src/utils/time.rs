@@ -12,12 +12,33 @@ pub fn timestamp_to_rfc3339(nanoseconds_since_epoch: &Timestamp) -> String {
         .expect("Invalid datetime Rfc3339 format")
 }
 
-pub fn rfc3339_to_timestamp(rfc3339: &str) -> Timestamp {
-    let datetime = OffsetDateTime::parse(rfc3339, &Rfc3339)
-        .expect("Invalid datetime Rfc3339 format")
+/// The reasons why a RFC3339 datetime cannot be converted to a timestamp.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TimestampParseError {
+    #[error("The datetime is not in the RFC3339 format: {reason}")]
+    Malformed { reason: String },
+    #[error("The datetime is before the unix epoch or too far in the future.")]
+    OutOfRange,
+}
+
+/// Converts the RFC3339 datetime to the nanoseconds since the unix epoch.
+///
+/// Use this function for datetimes that are provided by the callers.
+pub fn try_rfc3339_to_timestamp(rfc3339: &str) -> Result<Timestamp, TimestampParseError> {
+    let nanoseconds = OffsetDateTime::parse(rfc3339, &Rfc3339)
+        .map_err(|err| TimestampParseError::Malformed {
+            reason: err.to_string(),
+        })?
         .unix_timestamp_nanos();
 
-    datetime as Timestamp
+    Timestamp::try_from(nanoseconds).map_err(|_| TimestampParseError::OutOfRange)
+}
+
+/// Converts the RFC3339 datetime to the nanoseconds since the unix epoch.
+///
+/// Panics if the datetime is invalid, so it is only meant for datetimes that are known to be valid.
+pub fn rfc3339_to_timestamp(rfc3339: &str) -> Timestamp {
+    try_rfc3339_to_timestamp(rfc3339).expect("Invalid datetime Rfc3339 format")
 }
 
 thread_local! {
@@ -58,6 +79,25 @@ mod tests {
         assert_eq!(timestamp, 1_710_843_144_770_000_000);
     }
 
+    #[test]
+    fn rejects_malformed_rfc3339() {
+        for rfc3339 in ["", "2024-03-19", "2024-13-19T10:12:24Z", "yesterday"] {
+            assert!(matches!(
+                try_rfc3339_to_timestamp(rfc3339),
+                Err(TimestampParseError::Malformed { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_rfc3339_before_the_epoch() {
+        assert_eq!(
+            try_rfc3339_to_timestamp("1969-12-31T23:59:59Z"),
+            Err(TimestampParseError::OutOfRange)
+        );
+        assert_eq!(try_rfc3339_to_timestamp("1970-01-01T00:00:00Z"), Ok(0));
+    }
+
     #[test]
     fn time_increments_correctly() {
         // The first call to `time` should return the current round time.
@@ -30,10 +30,18 @@ for l in $(ls ${CARGO_HOME}/registry/src/); do
   export RUSTFLAGS="--remap-path-prefix ${CARGO_HOME}/registry/src/${l}=/cargo/registry/src/github ${RUSTFLAGS}"
 done
 
-candid_spec_file=$(cargo metadata --format-version=1 --no-deps | jq -r '.packages[] | select(.name == "'$PACKAGE'") | .manifest_path | gsub("/impl/Cargo.toml$"; "") + "/api/spec.did"')
+candid_bin=$(cargo metadata --format-version=1 --no-deps | jq -r '.packages[] | select(.name == "'$PACKAGE'") | .targets[] | select(.kind == ["bin"] and .name == "'$PACKAGE'-candid") | .name')
 package_version=$(cargo metadata --format-version=1 --no-deps | jq -r '.packages[] | select(.name == "'$PACKAGE'") | .version')
 
-cargo build --locked --target wasm32-unknown-unknown --release --package $PACKAGE $FEATURES
+cargo build --locked --target wasm32-unknown-unknown --release --package $PACKAGE --lib $FEATURES
+
+# the candid interface is generated from the entrypoints, so that the metadata matches what the canister serves
+candid_spec_file=""
+if [ -n "$candid_bin" ]; then
+  mkdir -p ./target/candid
+  candid_spec_file="./target/candid/$PACKAGE.did"
+  cargo run --locked --quiet --package $PACKAGE --bin $candid_bin >$candid_spec_file
+fi
 
 if [[ "$OSTYPE" == "linux"* || "$RUNNER_OS" == "Linux" ]]; then
   URL="https://github.com/dfinity/ic-wasm/releases/download/0.6.0/ic-wasm-linux64"
@@ -49,7 +57,7 @@ chmod +x ic-wasm
 PACKAGE=$(echo $PACKAGE | tr - _)
 
 # if candid file exists, generate metadata
-if [ -f "$candid_spec_file" ]; then
+if [ -n "$candid_spec_file" ] && [ -f "$candid_spec_file" ]; then
   echo Adding wasm metadata: \"candid:service\"
   ./ic-wasm ./target/wasm32-unknown-unknown/release/$PACKAGE.wasm -o ./target/wasm32-unknown-unknown/release/$PACKAGE.wasm metadata candid:service -f $candid_spec_file -v public
 fi
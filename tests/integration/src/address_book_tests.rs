@@ -122,6 +122,8 @@ fn address_book_entry_lifecycle() {
         labels: None,
         addresses: None,
         ids: None,
+        search_term: None,
+        address_owners: None,
         paginate: None,
     };
     let res: (Result<ListAddressBookEntriesResponseDTO, ApiErrorDTO>,) = update_candid_as(
@@ -195,6 +197,8 @@ fn address_book_entry_lifecycle() {
         labels: None,
         addresses: None,
         ids: None,
+        search_term: None,
+        address_owners: None,
         paginate: None,
     };
     let res: (Result<ListAddressBookEntriesResponseDTO, ApiErrorDTO>,) = update_candid_as(
@@ -281,6 +285,7 @@ fn check_address_book_for_transfer() {
             value: "true".to_string(),
         })),
         metadata: vec![],
+        network: None,
     });
     let add_account_request =
         execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_account).unwrap();
@@ -310,6 +315,10 @@ fn check_address_book_for_transfer() {
         fee: None,
         metadata: vec![],
         network: None,
+        fee_strategy: None,
+        max_fee: None,
+        compliance: None,
+        from_address: None,
     });
     let transfer_error = execute_request(
         &env,
@@ -8,13 +8,16 @@ use crate::{
     utils::execute_request,
     CanisterIds, TestEnv,
 };
-use dfx_orbit::DfxOrbit;
+use candid::Principal;
+use dfx_orbit::{args::request::asset::AssetPermissionArgs, DfxOrbit};
 use pocket_ic::PocketIc;
 use rand::{thread_rng, Rng};
 use station_api::{
-    AddRequestPolicyOperationInput, CallExternalCanisterResourceTargetDTO, CreateRequestInput,
-    ExecutionMethodResourceTargetDTO, GetRequestInput, RequestOperationInput, RequestPolicyRuleDTO,
-    RequestSpecifierDTO, ValidationMethodResourceTargetDTO,
+    AddRequestPolicyOperationInput, AuthScopeDTO, CallExternalCanisterResourceTargetDTO,
+    CanisterMethodDTO, CreateRequestInput, EditPermissionOperationInput,
+    ExecutionMethodResourceTargetDTO, ExternalCanisterResourceActionDTO, GetRequestInput,
+    RequestOperationDTO, RequestOperationInput, RequestPolicyRuleDTO, RequestSpecifierDTO,
+    ResourceDTO, ValidationMethodResourceTargetDTO,
 };
 use std::{
     collections::BTreeMap,
@@ -46,6 +49,7 @@ fn assets_upload() {
     // As admin: Grant the user the call permission, set auto-approval for external calls
     permit_call_operation(&env, &canister_ids);
     set_auto_approve(&env, &canister_ids);
+    permit_commit_asset_batch(&env, &canister_ids, asset_canister);
 
     // Setup a tmpdir, and store two assets in it
     // We generate the assets dyniamically, since we want to make sure we are not
@@ -86,11 +90,18 @@ fn assets_upload() {
         let _response = dfx_orbit
             .station
             .request(CreateRequestInput {
-                operation: DfxOrbit::grant_permission_request(asset_canister, dfx_principal)
-                    .unwrap(),
+                operation: DfxOrbit::grant_permission_request(
+                    asset_canister,
+                    dfx_principal,
+                    AssetPermissionArgs::Prepare.into(),
+                )
+                .unwrap(),
                 title: None,
                 summary: None,
                 execution_plan: None,
+                confidential: None,
+                summary_format: None,
+                depends_on: None,
             })
             .await
             .unwrap();
@@ -122,6 +133,9 @@ fn assets_upload() {
                 title: None,
                 summary: None,
                 execution_plan: None,
+                confidential: None,
+                summary_format: None,
+                depends_on: None,
             })
             .await
             .unwrap();
@@ -131,12 +145,19 @@ fn assets_upload() {
             .station
             .review_id(GetRequestInput {
                 request_id: response.request.id,
+                api_version: None,
             })
             .await
             .unwrap();
         DfxOrbit::check_evidence(&response, asset_canister, batch_id, hex::encode(evidence))
             .unwrap();
 
+        // The asset canister rendered the evidence for the reviewers
+        let RequestOperationDTO::CommitAssetBatch(operation) = &response.request.operation else {
+            panic!("Expected a CommitAssetBatch request");
+        };
+        assert!(operation.evidence_rendering.is_some());
+
         // NOTE: We need to wait until the certified state becomes available.
         // Since we are in live mode, we can not simply advance pocketIC by some
         // ticks, but actially need to wait.
@@ -164,6 +185,51 @@ pub(crate) fn set_auto_approve(env: &PocketIc, canister_ids: &CanisterIds) {
                 },
             ),
             rule: RequestPolicyRuleDTO::AutoApproved,
+            checklist: None,
+            exclude_requester: None,
+        });
+    execute_request(
+        env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        add_request_policy,
+    )
+    .unwrap();
+}
+
+/// Permit committing batches of the asset canister and auto-approve them
+pub(crate) fn permit_commit_asset_batch(
+    env: &PocketIc,
+    canister_ids: &CanisterIds,
+    asset_canister: Principal,
+) {
+    let target = CallExternalCanisterResourceTargetDTO {
+        validation_method: ValidationMethodResourceTargetDTO::ValidationMethod(CanisterMethodDTO {
+            canister_id: asset_canister,
+            method_name: String::from("validate_commit_proposed_batch"),
+        }),
+        execution_method: ExecutionMethodResourceTargetDTO::ExecutionMethod(CanisterMethodDTO {
+            canister_id: asset_canister,
+            method_name: String::from("commit_proposed_batch"),
+        }),
+    };
+
+    let add_permission = RequestOperationInput::EditPermission(EditPermissionOperationInput {
+        resource: ResourceDTO::ExternalCanister(ExternalCanisterResourceActionDTO::Call(
+            target.clone(),
+        )),
+        auth_scope: Some(AuthScopeDTO::Authenticated),
+        user_groups: None,
+        users: None,
+    });
+    execute_request(env, WALLET_ADMIN_USER, canister_ids.station, add_permission).unwrap();
+
+    let add_request_policy =
+        RequestOperationInput::AddRequestPolicy(AddRequestPolicyOperationInput {
+            specifier: RequestSpecifierDTO::CallExternalCanister(target),
+            rule: RequestPolicyRuleDTO::AutoApproved,
+            checklist: None,
+            exclude_requester: None,
         });
     execute_request(
         env,
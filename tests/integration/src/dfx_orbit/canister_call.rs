@@ -53,6 +53,9 @@ fn canister_call() {
         title: None,
         summary: None,
         execution_plan: None,
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
     };
 
     let request = dfx_orbit_test(&mut env, DfxOrbitTestConfig::default(), async {
@@ -119,6 +122,8 @@ pub(crate) fn set_four_eyes_on_call(env: &PocketIc, canister_ids: &CanisterIds)
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            checklist: None,
+            exclude_requester: None,
         });
     execute_request(
         env,
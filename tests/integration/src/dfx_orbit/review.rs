@@ -91,6 +91,7 @@ fn review() {
                 sort_by: None,
                 only_approvable: true,
                 with_evaluation_results: false,
+                api_version: None,
             })
             .await
             .unwrap();
@@ -107,6 +108,7 @@ fn review() {
                 operation_types: Some(vec![ListRequestsOperationTypeDTO::CallExternalCanister(
                     Some(canister_id),
                 )]),
+                api_version: None,
             })
             .await
             .unwrap()
@@ -116,6 +118,7 @@ fn review() {
             .station
             .review_id(GetRequestInput {
                 request_id: submitted_request.id.clone(),
+                api_version: None,
             })
             .await
             .unwrap();
@@ -234,6 +234,7 @@ fn auto_syncs_on_account_creation() {
         configs_request_policy: None,
         transfer_request_policy: None,
         metadata: vec![],
+        network: None,
     });
 
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_account)
@@ -462,6 +463,7 @@ fn test_disaster_recovery_flow_recreates_same_accounts() {
                 key: "key".to_string(),
                 value: "value".to_string(),
             }],
+            network: None,
         };
 
         let request = execute_request(
@@ -525,6 +527,8 @@ fn test_disaster_recovery_flow_recreates_same_accounts() {
                 fallback_controller: None,
                 upgrader: station_api::SystemUpgraderInput::Id(upgrader_id),
                 accounts: Some(init_accounts_input),
+                ledger_canisters: None,
+                mock_blockchains: None,
             }))
             .unwrap(),
             install_mode: upgrader_api::InstallMode::Reinstall,
@@ -703,6 +707,8 @@ fn test_disaster_recovery_flow_reuses_same_upgrader() {
                 fallback_controller: Some(fallback_controller),
                 upgrader: station_api::SystemUpgraderInput::Id(upgrader_id),
                 accounts: None,
+                ledger_canisters: None,
+                mock_blockchains: None,
             }))
             .unwrap(),
             install_mode: upgrader_api::InstallMode::Reinstall,
@@ -916,7 +922,10 @@ fn test_disaster_recovery_upgrade() {
     let system_info = get_system_info(&env, WALLET_ADMIN_USER, canister_ids.station);
     let upgrader_id = system_info.upgrader_id;
 
-    let station_init_arg = SystemInstall::Upgrade(SystemUpgrade { name: None });
+    let station_init_arg = SystemInstall::Upgrade(SystemUpgrade {
+        name: None,
+        ledger_canisters: None,
+    });
     let good_request = upgrader_api::RequestDisasterRecoveryInput {
         module: get_canister_wasm("station"),
         arg: Encode!(&station_init_arg).unwrap(),
@@ -966,6 +975,8 @@ fn test_disaster_recovery_failing() {
         name: "Station".to_string(),
         admins: vec![],
         accounts: None,
+        ledger_canisters: None,
+        mock_blockchains: None,
     });
 
     // install with intentionally bad arg to fail
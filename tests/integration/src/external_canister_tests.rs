@@ -13,13 +13,14 @@ use sha2::{Digest, Sha256};
 use station_api::{
     AddRequestPolicyOperationInput, AllowDTO, CallExternalCanisterOperationInput,
     CallExternalCanisterResourceTargetDTO, CanisterInstallMode, CanisterMethodDTO,
-    ChangeExternalCanisterOperationInput, CreateExternalCanisterOperationInput,
-    CreateExternalCanisterOperationKindCreateNewDTO, CreateExternalCanisterOperationKindDTO,
-    EditPermissionOperationInput, ExecutionMethodResourceTargetDTO, ExternalCanisterIdDTO,
-    ExternalCanisterPermissionsInput, ExternalCanisterRequestPoliciesInput, ListRequestsInput,
-    ListRequestsOperationTypeDTO, ListRequestsResponse, QuorumDTO, RequestApprovalStatusDTO,
-    RequestOperationDTO, RequestOperationInput, RequestPolicyRuleDTO, RequestSpecifierDTO,
-    RequestStatusDTO, UserSpecifierDTO, ValidationMethodResourceTargetDTO,
+    ChangeExternalCanisterOperationInput, ChunkedWasmModuleDTO,
+    CreateExternalCanisterOperationInput, CreateExternalCanisterOperationKindCreateNewDTO,
+    CreateExternalCanisterOperationKindDTO, EditPermissionOperationInput,
+    ExecutionMethodResourceTargetDTO, ExternalCanisterIdDTO, ExternalCanisterPermissionsInput,
+    ExternalCanisterRequestPoliciesInput, ListRequestsInput, ListRequestsOperationTypeDTO,
+    ListRequestsResponse, QuorumDTO, RequestApprovalStatusDTO, RequestOperationDTO,
+    RequestOperationInput, RequestPolicyRuleDTO, RequestSpecifierDTO, RequestStatusDTO,
+    UserSpecifierDTO, ValidationMethodResourceTargetDTO,
 };
 
 #[test]
@@ -64,6 +65,7 @@ fn successful_four_eyes_upgrade() {
             mode: CanisterInstallMode::Upgrade,
             module: module_bytes.clone(),
             arg: None,
+            module_chunks: None,
         });
     let trap_message = submit_request_with_expected_trap(
         &env,
@@ -131,6 +133,8 @@ fn successful_four_eyes_upgrade() {
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            checklist: None,
+            exclude_requester: None,
         });
     execute_request(
         &env,
@@ -235,6 +239,7 @@ fn upgrade_reinstall_list_test() {
             mode: CanisterInstallMode::Upgrade,
             module: module_bytes.clone(),
             arg: None,
+            module_chunks: None,
         });
     execute_request(
         &env,
@@ -259,6 +264,7 @@ fn upgrade_reinstall_list_test() {
             mode: CanisterInstallMode::Reinstall,
             module: module_bytes,
             arg: None,
+            module_chunks: None,
         });
     execute_request(
         &env,
@@ -292,6 +298,7 @@ fn upgrade_reinstall_list_test() {
         sort_by: None,
         only_approvable: false,
         with_evaluation_results: false,
+        api_version: None,
     };
     let res: (ApiResult<ListRequestsResponse>,) = update_candid_as(
         &env,
@@ -320,6 +327,7 @@ fn upgrade_reinstall_list_test() {
         sort_by: None,
         only_approvable: false,
         with_evaluation_results: false,
+        api_version: None,
     };
     let res: (ApiResult<ListRequestsResponse>,) = update_candid_as(
         &env,
@@ -346,6 +354,7 @@ fn upgrade_reinstall_list_test() {
         sort_by: None,
         only_approvable: false,
         with_evaluation_results: false,
+        api_version: None,
     };
     let res: (ApiResult<ListRequestsResponse>,) = update_candid_as(
         &env,
@@ -358,6 +367,89 @@ fn upgrade_reinstall_list_test() {
     assert_eq!(res.0.unwrap().total, 2);
 }
 
+#[test]
+fn install_from_chunk_store() {
+    let TestEnv {
+        mut env,
+        canister_ids,
+        ..
+    } = setup_new_env();
+
+    // create an empty canister and a canister that holds the module chunks, both controlled by the station
+    let canister_id = create_canister(&mut env, canister_ids.station);
+    let store_canister = create_canister(&mut env, canister_ids.station);
+    let module_bytes = wat::parse_str(COUNTER_WAT).unwrap();
+    let module_hash = Sha256::digest(&module_bytes).to_vec();
+
+    // upload the module in two chunks to the chunk store
+    let (first_chunk, second_chunk) = module_bytes.split_at(module_bytes.len() / 2);
+    let chunk_hashes = [first_chunk, second_chunk]
+        .into_iter()
+        .map(|chunk| {
+            env.upload_chunk(store_canister, Some(canister_ids.station), chunk.to_vec())
+                .unwrap()
+        })
+        .collect();
+
+    // the module must not be sent with the request if it was uploaded in chunks
+    let mut install_operation = ChangeExternalCanisterOperationInput {
+        canister_id,
+        mode: CanisterInstallMode::Install,
+        module: module_bytes.clone(),
+        arg: None,
+        module_chunks: Some(ChunkedWasmModuleDTO {
+            store_canister,
+            chunk_hashes,
+            wasm_module_hash: module_hash.clone(),
+        }),
+    };
+    let res = submit_request_raw(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        RequestOperationInput::ChangeExternalCanister(install_operation.clone()),
+    )
+    .unwrap();
+    assert!(res.0.is_err());
+
+    // install the canister from the chunk store
+    install_operation.module = vec![];
+    let request = execute_request(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        RequestOperationInput::ChangeExternalCanister(install_operation),
+    )
+    .unwrap();
+
+    match request.operation {
+        RequestOperationDTO::ChangeExternalCanister(operation) => {
+            assert_eq!(operation.module_checksum, hex::encode(&module_hash));
+
+            // the canister was empty and controlled by the station when the request was created
+            let upgrade_diff = operation.upgrade_diff.unwrap();
+            let current_state = upgrade_diff.current_state.unwrap();
+            assert_eq!(current_state.module_hash, None);
+            assert!(current_state
+                .settings
+                .unwrap()
+                .controllers
+                .contains(&canister_ids.station));
+            assert_eq!(upgrade_diff.module_size, None);
+            assert!(upgrade_diff.arg_empty);
+        }
+        _ => panic!("unexpected request operation"),
+    };
+
+    // check canister status and ensure that the WASM matches the counter canister module
+    let status = canister_status(&env, Some(canister_ids.station), canister_id);
+    assert_eq!(status.module_hash, Some(module_hash));
+
+    // the counter should be initially be set at 0
+    let ctr = update_raw(&env, canister_id, Principal::anonymous(), "read", vec![]).unwrap();
+    assert_eq!(ctr, 0_u32.to_le_bytes());
+}
+
 #[test]
 fn create_external_canister_and_check_status() {
     let TestEnv {
@@ -398,6 +490,7 @@ fn create_external_canister_and_check_status() {
                 change: Vec::new(),
                 calls: vec![],
             },
+            repository_url: None,
         });
     let trap_message = submit_request_with_expected_trap(
         &env,
@@ -470,6 +563,8 @@ fn create_external_canister_and_check_status() {
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            checklist: None,
+            exclude_requester: None,
         });
     execute_request(
         &env,
@@ -983,6 +1078,8 @@ fn call_external_canister_test() {
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            checklist: None,
+            exclude_requester: None,
         });
     execute_request(
         &env,
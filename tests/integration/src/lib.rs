@@ -15,6 +15,7 @@ mod migration_tests;
 mod notification;
 mod rate_limiter;
 mod register_tests;
+mod request_execution_tests;
 mod setup;
 mod test_data;
 mod transfer_tests;
@@ -358,6 +358,8 @@ fn assert_can_list_address_book_entries(
             labels: None,
             addresses: None,
             ids: None,
+            search_term: None,
+            address_owners: None,
             paginate: Some(station_api::PaginationInput {
                 offset: Some(0),
                 limit: Some(25),
@@ -398,6 +400,7 @@ fn assert_can_list_requests(
                 offset: Some(0),
                 limit: Some(25),
             }),
+            api_version: None,
         },),
     )
     .unwrap();
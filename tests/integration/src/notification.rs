@@ -24,6 +24,7 @@ fn notification_authorization() {
         target: SystemUpgradeTargetDTO::UpgradeUpgrader,
         module: vec![],
         arg: None,
+        arg_injections: None,
     };
     let request_status = execute_request_with_extra_ticks(
         &env,
@@ -110,6 +111,7 @@ fn notification_authorization() {
         identities: vec![user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        scheduled_memberships: None,
     };
     execute_request(
         &env,
@@ -38,6 +38,7 @@ fn test_request_count_rate_limiter() {
             transfer_request_policy: None,
             configs_request_policy: None,
             metadata: vec![],
+            network: None,
         };
         RequestOperationInput::AddAccount(add_account_operation_input)
     });
@@ -23,12 +23,16 @@ fn register_user_successful() {
         identities: vec![user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        scheduled_memberships: None,
     };
     let add_user_request = CreateRequestInput {
         operation: RequestOperationInput::AddUser(add_user),
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
     };
 
     let res: (Result<CreateRequestResponse, ApiErrorDTO>,) = update_candid_as(
@@ -51,6 +55,7 @@ fn register_user_successful() {
     // check transfer request status
     let get_request_args = GetRequestInput {
         request_id: request_dto.id,
+        api_version: None,
     };
     let res: (Result<GetRequestResponse, ApiErrorDTO>,) = update_candid_as(
         &env,
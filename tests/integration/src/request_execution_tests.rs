@@ -0,0 +1,166 @@
+use crate::interfaces::{
+    default_account, get_icp_account_balance, send_icp_to_account, ICP, ICP_FEE,
+};
+use crate::setup::{setup_new_env, WALLET_ADMIN_USER};
+use crate::utils::{
+    create_icp_account, execute_request, get_user, submit_request, submit_request_raw,
+    user_test_id, wait_for_request_with_extra_ticks,
+};
+use crate::TestEnv;
+use ic_ledger_types::AccountIdentifier;
+use station_api::{
+    AllowDTO, CreateExternalCanisterOperationInput,
+    CreateExternalCanisterOperationKindCreateNewDTO, CreateExternalCanisterOperationKindDTO,
+    ExternalCanisterPermissionsInput, ExternalCanisterRequestPoliciesInput,
+    RequestExecutionStepStatusDTO, RequestOperationDTO, RequestOperationInput,
+    TransferFeeStrategyDTO, TransferOperationInput,
+};
+
+#[test]
+fn transfer_with_fee_strategy_records_fee_estimate() {
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = setup_new_env();
+
+    let beneficiary_id = user_test_id(1);
+
+    let user = get_user(&env, WALLET_ADMIN_USER, canister_ids.station);
+    let account = create_icp_account(&env, canister_ids.station, user.id);
+    let account_address = AccountIdentifier::from_hex(&account.address).unwrap();
+
+    send_icp_to_account(&env, controller, account_address, ICP + ICP_FEE, 0, None).unwrap();
+
+    let transfer = RequestOperationInput::Transfer(TransferOperationInput {
+        from_account_id: account.id.clone(),
+        to: default_account(beneficiary_id),
+        amount: ICP.into(),
+        fee: None,
+        metadata: vec![],
+        network: None,
+        fee_strategy: Some(TransferFeeStrategyDTO::Fast),
+        max_fee: Some(ICP_FEE.into()),
+        compliance: None,
+        from_address: None,
+    });
+    let request = submit_request(&env, WALLET_ADMIN_USER, canister_ids.station, transfer);
+
+    // the fee is estimated with the requested strategy when the request is created
+    let fee_estimate = match &request.operation {
+        RequestOperationDTO::Transfer(transfer) => transfer.fee_estimate.clone().unwrap(),
+        _ => panic!("request must be Transfer"),
+    };
+    assert_eq!(fee_estimate.strategy, TransferFeeStrategyDTO::Fast);
+    assert_eq!(fee_estimate.fee, candid::Nat::from(ICP_FEE));
+
+    wait_for_request_with_extra_ticks(&env, WALLET_ADMIN_USER, canister_ids.station, request, 2)
+        .unwrap();
+
+    assert_eq!(get_icp_account_balance(&env, account_address), 0);
+    assert_eq!(
+        get_icp_account_balance(
+            &env,
+            AccountIdentifier::from_hex(&default_account(beneficiary_id)).unwrap()
+        ),
+        ICP
+    );
+}
+
+#[test]
+fn transfer_with_fee_above_max_fee_is_rejected() {
+    let TestEnv {
+        env, canister_ids, ..
+    } = setup_new_env();
+
+    let user = get_user(&env, WALLET_ADMIN_USER, canister_ids.station);
+    let account = create_icp_account(&env, canister_ids.station, user.id);
+
+    let transfer = RequestOperationInput::Transfer(TransferOperationInput {
+        from_account_id: account.id.clone(),
+        to: default_account(user_test_id(1)),
+        amount: ICP.into(),
+        fee: None,
+        metadata: vec![],
+        network: None,
+        fee_strategy: None,
+        max_fee: Some((ICP_FEE - 1).into()),
+        compliance: None,
+        from_address: None,
+    });
+    let request_error = submit_request_raw(&env, WALLET_ADMIN_USER, canister_ids.station, transfer)
+        .unwrap()
+        .0
+        .unwrap_err();
+
+    assert_eq!(
+        request_error.message,
+        Some("The request has failed validation.".to_string())
+    );
+    assert!(request_error
+        .details
+        .unwrap()
+        .get("info")
+        .unwrap()
+        .contains("exceeds the maximum fee"));
+}
+
+#[test]
+fn create_external_canister_records_execution_steps() {
+    let TestEnv {
+        env, canister_ids, ..
+    } = setup_new_env();
+
+    let create_canister_operation =
+        RequestOperationInput::CreateExternalCanister(CreateExternalCanisterOperationInput {
+            kind: CreateExternalCanisterOperationKindDTO::CreateNew(
+                CreateExternalCanisterOperationKindCreateNewDTO {
+                    initial_cycles: None,
+                },
+            ),
+            name: "test".to_string(),
+            description: None,
+            labels: None,
+            permissions: ExternalCanisterPermissionsInput {
+                calls: vec![],
+                read: AllowDTO {
+                    auth_scope: station_api::AuthScopeDTO::Restricted,
+                    user_groups: vec![],
+                    users: vec![],
+                },
+                change: AllowDTO {
+                    auth_scope: station_api::AuthScopeDTO::Restricted,
+                    user_groups: vec![],
+                    users: vec![],
+                },
+            },
+            request_policies: ExternalCanisterRequestPoliciesInput {
+                change: Vec::new(),
+                calls: vec![],
+            },
+            repository_url: None,
+        });
+    let request = execute_request(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        create_canister_operation,
+    )
+    .unwrap();
+
+    let step_names: Vec<_> = request
+        .execution_steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(step_names, vec!["create_canister", "add_external_canister"]);
+
+    for step in request.execution_steps.iter() {
+        assert_eq!(step.attempts, 1);
+        assert!(matches!(
+            step.status,
+            RequestExecutionStepStatusDTO::Completed { .. }
+        ));
+    }
+}
@@ -243,6 +243,7 @@ fn install_canisters(
         upgrader: station_api::SystemUpgraderInput::WasmModule(upgrader_wasm),
         fallback_controller: config.fallback_controller,
         accounts: None,
+        ledger_canisters: None,
     });
     env.install_canister(
         station,
@@ -40,6 +40,7 @@ pub fn add_account(
                     approvers: station_api::UserSpecifierDTO::Any,
                 },
             )),
+            network: None,
         }),
     );
 
@@ -71,6 +72,7 @@ pub fn edit_account_name(
             transfer_permission: None,
             configs_request_policy: None,
             transfer_request_policy: None,
+            expected_version: None,
         }),
     );
 
@@ -16,6 +16,8 @@ pub fn add_request_policy(
             station_api::AddRequestPolicyOperationInput {
                 specifier,
                 rule: station_api::RequestPolicyRuleDTO::AutoApproved,
+                checklist: None,
+                exclude_requester: None,
             },
         ),
     );
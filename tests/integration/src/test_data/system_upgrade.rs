@@ -19,6 +19,7 @@ pub fn perform_upgrader_update(
                 target: station_api::SystemUpgradeTargetDTO::UpgradeUpgrader,
                 module: upgrader_wasm.clone(),
                 arg: None,
+                arg_injections: None,
             },
         ),
     );
@@ -47,6 +48,7 @@ pub fn perform_station_update(
                 target: station_api::SystemUpgradeTargetDTO::UpgradeStation,
                 module: station_wasm.clone(),
                 arg: None,
+                arg_injections: None,
             },
         ),
     );
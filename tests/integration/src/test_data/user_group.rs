@@ -14,6 +14,7 @@ pub fn add_user_group(
         station_canister_id,
         station_api::RequestOperationInput::AddUserGroup(station_api::AddUserGroupOperationInput {
             name: format!("group-{}", next_unique_id()),
+            quotas: None,
         }),
     );
 
@@ -43,6 +44,7 @@ pub fn edit_user_group(
             station_api::EditUserGroupOperationInput {
                 user_group_id,
                 name,
+                quotas: None,
             },
         ),
     );
@@ -65,12 +65,16 @@ fn make_transfer_successful() {
             },
         )),
         metadata: vec![],
+        network: None,
     };
     let add_account_request = CreateRequestInput {
         operation: RequestOperationInput::AddAccount(create_account_args),
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
     };
     let res: (ApiResult<CreateRequestResponse>,) = update_candid_as(
         &env,
@@ -100,6 +104,7 @@ fn make_transfer_successful() {
     // fetch the created account id from the request
     let get_request_args = GetRequestInput {
         request_id: account_creation_request_dto.id,
+        api_version: None,
     };
     let res: (ApiResult<CreateRequestResponse>,) = update_candid_as(
         &env,
@@ -160,12 +165,19 @@ fn make_transfer_successful() {
         fee: None,
         metadata: vec![],
         network: None,
+        fee_strategy: None,
+        max_fee: None,
+        compliance: None,
+        from_address: None,
     };
     let transfer_request = CreateRequestInput {
         operation: RequestOperationInput::Transfer(transfer),
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
     };
     let res: (Result<CreateRequestResponse, ApiErrorDTO>,) = update_candid_as(
         &env,
@@ -189,6 +201,7 @@ fn make_transfer_successful() {
     // check transfer request status
     let get_request_args = GetRequestInput {
         request_id: request_dto.id.clone(),
+        api_version: None,
     };
     let res: (Result<GetRequestResponse, ApiErrorDTO>,) = update_candid_as(
         &env,
@@ -31,13 +31,17 @@ fn successful_station_upgrade() {
     assert_eq!(health_status, HealthStatus::Healthy);
 
     // submit station upgrade request
-    let station_init_arg = SystemInstall::Upgrade(SystemUpgrade { name: None });
+    let station_init_arg = SystemInstall::Upgrade(SystemUpgrade {
+        name: None,
+        ledger_canisters: None,
+    });
     let station_init_arg_bytes = Encode!(&station_init_arg).unwrap();
     let station_upgrade_operation =
         RequestOperationInput::SystemUpgrade(SystemUpgradeOperationInput {
             target: SystemUpgradeTargetDTO::UpgradeStation,
             module: station_wasm.clone(),
             arg: Some(station_init_arg_bytes),
+            arg_injections: None,
         });
     // extra ticks are necessary to prevent polling on the request status
     // before the station canister is upgraded and running
@@ -65,6 +69,7 @@ fn successful_station_upgrade() {
             target: SystemUpgradeTargetDTO::UpgradeStation,
             module: station_wasm.clone(),
             arg: None,
+            arg_injections: None,
         });
 
     execute_request_with_extra_ticks(
@@ -89,6 +94,40 @@ fn successful_station_upgrade() {
     assert_eq!(status.module_hash.unwrap(), station_wasm_hash);
 }
 
+#[test]
+fn successful_upgrader_upgrade() {
+    let TestEnv {
+        env, canister_ids, ..
+    } = setup_new_env();
+
+    let upgrader_wasm = get_canister_wasm("upgrader").to_vec();
+    let mut hasher = Sha256::new();
+    hasher.update(&upgrader_wasm);
+    let upgrader_wasm_hash = hasher.finalize().to_vec();
+
+    let system_info = get_system_info(&env, WALLET_ADMIN_USER, canister_ids.station);
+
+    // the upgrader is installed by the station and is upgraded with its module uploaded in chunks
+    let upgrader_upgrade_operation =
+        RequestOperationInput::SystemUpgrade(SystemUpgradeOperationInput {
+            target: SystemUpgradeTargetDTO::UpgradeUpgrader,
+            module: upgrader_wasm,
+            arg: None,
+            arg_injections: None,
+        });
+    execute_request_with_extra_ticks(
+        &env,
+        WALLET_ADMIN_USER,
+        canister_ids.station,
+        upgrader_upgrade_operation,
+        10,
+    )
+    .unwrap();
+
+    let status = canister_status(&env, Some(canister_ids.station), system_info.upgrader_id);
+    assert_eq!(status.module_hash.unwrap(), upgrader_wasm_hash);
+}
+
 #[test]
 fn failed_station_upgrade() {
     let TestEnv {
@@ -110,6 +149,7 @@ fn failed_station_upgrade() {
             target: SystemUpgradeTargetDTO::UpgradeStation,
             module: vec![],
             arg: None,
+            arg_injections: None,
         });
     // extra ticks are necessary to prevent polling on the request status
     // before the station canister is upgraded and running
@@ -37,6 +37,7 @@ fn cancel_pending_requests() {
         identities: vec![alice_user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        scheduled_memberships: None,
     });
     let request_dto =
         execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_user).unwrap();
@@ -52,6 +53,7 @@ fn cancel_pending_requests() {
         identities: vec![bob_user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        scheduled_memberships: None,
     });
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_user).unwrap();
 
@@ -60,6 +62,7 @@ fn cancel_pending_requests() {
         target: SystemUpgradeTargetDTO::UpgradeStation,
         module: vec![],
         arg: None,
+        arg_injections: None,
     });
     let mut alice_request_dtos = vec![];
     for _ in 0..10 {
@@ -108,6 +111,8 @@ fn cancel_pending_requests() {
         groups: None,
         status: None,
         cancel_pending_requests: None,
+        expected_version: None,
+        scheduled_memberships: None,
     });
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, edit_user).unwrap();
     for request_dto in alice_request_dtos.clone() {
@@ -126,6 +131,8 @@ fn cancel_pending_requests() {
         groups: None,
         status: None,
         cancel_pending_requests: Some(true),
+        expected_version: None,
+        scheduled_memberships: None,
     });
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, edit_user).unwrap();
     for request_dto in alice_request_dtos {
@@ -118,6 +118,7 @@ pub fn get_request(
 ) -> RequestDTO {
     let get_request_args = GetRequestInput {
         request_id: request.id,
+        api_version: None,
     };
     let res: (Result<GetRequestResponse, ApiErrorDTO>,) = update_candid_as(
         env,
@@ -167,6 +168,9 @@ pub fn submit_request_raw(
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
     };
     update_candid_as(
         env,
@@ -274,6 +278,7 @@ pub fn submit_request_approval(
         request_id: request.id,
         decision,
         reason: None,
+        acknowledged_checklist: None,
     };
     let res: (Result<SubmitRequestApprovalResponse, ApiErrorDTO>,) = update_candid_as(
         env,
@@ -323,6 +328,7 @@ pub fn add_user_with_name(
         identities: vec![identity],
         groups: group_ids,
         status: UserStatusDTO::Active,
+        scheduled_memberships: None,
     });
     let add_user_request = submit_request(env, WALLET_ADMIN_USER, station_canister_id, add_user);
     let new_request = wait_for_request(
@@ -598,12 +604,16 @@ pub fn create_icp_account(env: &PocketIc, station_id: Principal, user_id: UuidDT
             },
         )),
         metadata: vec![],
+        network: None,
     };
     let add_account_request = CreateRequestInput {
         operation: RequestOperationInput::AddAccount(create_account_args),
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
     };
     let res: (ApiResult<CreateRequestResponse>,) = update_candid_as(
         env,
@@ -633,6 +643,7 @@ pub fn create_icp_account(env: &PocketIc, station_id: Principal, user_id: UuidDT
     // fetch the created account id from the request
     let get_request_args = GetRequestInput {
         request_id: account_creation_request_dto.id,
+        api_version: None,
     };
     let res: (ApiResult<CreateRequestResponse>,) = update_candid_as(
         env,
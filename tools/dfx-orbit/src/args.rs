@@ -1,9 +1,12 @@
 //! Command line interface for `dfx-orbit`.
+pub mod asset;
 pub mod request;
 pub mod review;
 pub mod station;
 pub mod verify;
 
+use crate::locale::Locale;
+use asset::AssetArgs;
 use clap::{Parser, Subcommand};
 use request::RequestArgs;
 use review::ReviewArgs;
@@ -31,6 +34,10 @@ pub struct DfxOrbitArgs {
     #[clap(short, long)]
     pub(crate) identity: Option<String>,
 
+    /// The language of the output (Uses `DFX_ORBIT_LOCALE` or the system locale if unspecified)
+    #[clap(long, value_enum)]
+    pub(crate) locale: Option<Locale>,
+
     /// Manage Orbit stations.
     #[clap(subcommand)]
     pub(crate) command: DfxOrbitSubcommands,
@@ -51,6 +58,11 @@ pub enum DfxOrbitSubcommands {
     Review(ReviewArgs),
     /// Gets the caller's profile on an Orbit station.
     Me(MeArgs),
+    /// Fetches the logs of a canister controlled by the station.
+    Logs(LogsArgs),
+    /// Inspect asset canisters.
+    #[clap(subcommand)]
+    Asset(AssetArgs),
 }
 
 #[derive(Debug, Clone, Parser)]
@@ -59,3 +71,15 @@ pub struct MeArgs {
     #[clap(short, long)]
     pub(crate) json: bool,
 }
+
+#[derive(Debug, Clone, Parser)]
+pub struct LogsArgs {
+    /// The canister name or ID.
+    pub(crate) canister: String,
+    /// Retain the fetched log records in the station, so they outlive the logs kept by the IC
+    #[clap(long)]
+    pub(crate) retain: bool,
+    /// Return output as JSON
+    #[clap(short, long)]
+    pub(crate) json: bool,
+}
@@ -0,0 +1,22 @@
+//! Defines the command line arguments for `dfx-orbit asset`.
+use crate::args::request::asset::AssetPermissionArgs;
+use clap::{Parser, Subcommand};
+
+/// Inspect asset canisters.
+#[derive(Debug, Clone, Subcommand)]
+#[clap(version, about, long_about = None)]
+pub enum AssetArgs {
+    /// Lists the principals that hold the permissions of an asset canister
+    Permissions(AssetPermissionsArgs),
+}
+
+/// Lists the principals that hold the permissions of an asset canister.
+#[derive(Debug, Clone, Parser)]
+pub struct AssetPermissionsArgs {
+    /// The name of the asset canister
+    pub(crate) canister: String,
+
+    /// Only list the principals that hold this permission
+    #[clap(short, long, value_enum)]
+    pub(crate) permission: Option<AssetPermissionArgs>,
+}
@@ -1,14 +1,16 @@
 //! Defines the command line arguments for `dfx-orbit request`.  These correspond to Orbit station `create_request` API calls.
+pub mod apply;
 pub mod asset;
 pub mod canister;
 pub mod permission;
 
 use crate::DfxOrbit;
+use apply::RequestApplyArgs;
 use asset::RequestAssetArgs;
 use canister::RequestCanisterArgs;
 use clap::{Parser, Subcommand};
 use permission::RequestPermissionArgs;
-use station_api::CreateRequestInput;
+use station_api::{CreateRequestInput, RequestSummaryFormatDTO};
 
 /// Request canister changes.
 #[derive(Debug, Clone, Parser)]
@@ -22,6 +24,14 @@ pub struct RequestArgs {
     #[clap(long)]
     summary: Option<String>,
 
+    /// Render the summary of the request as markdown
+    #[clap(long)]
+    markdown: bool,
+
+    /// Id of a request that must complete successfully before this request is executed, can be repeated
+    #[clap(long = "depends-on")]
+    depends_on: Vec<String>,
+
     // TODO: Summary file as an alternative to summary
     #[clap(subcommand)]
     action: RequestArgsActions,
@@ -37,16 +47,40 @@ pub enum RequestArgsActions {
     /// Request permissions
     #[clap(subcommand)]
     Permission(RequestPermissionArgs),
+    /// Submit the requests described in a YAML file
+    Apply(RequestApplyArgs),
 }
 
 impl RequestArgs {
+    /// Submits the requested changes and prints the resulting request ids.
+    pub(crate) async fn exec(self, dfx_orbit: &DfxOrbit) -> anyhow::Result<()> {
+        let summary_format = self.summary_format();
+        if let RequestArgsActions::Apply(apply_args) = self.action {
+            return apply_args
+                .apply(dfx_orbit, self.title, self.summary, summary_format)
+                .await;
+        }
+
+        let request = dfx_orbit
+            .station
+            .request(self.into_create_request_input(dfx_orbit).await?)
+            .await?;
+        dfx_orbit.print_create_request_info(&request);
+
+        Ok(())
+    }
+
+    fn summary_format(&self) -> Option<RequestSummaryFormatDTO> {
+        self.markdown.then_some(RequestSummaryFormatDTO::Markdown)
+    }
+
     pub(crate) async fn into_create_request_input(
         self,
         dfx_orbit: &DfxOrbit,
     ) -> anyhow::Result<CreateRequestInput> {
         let operation = match self.action {
             RequestArgsActions::Canister(canister_args) => {
-                canister_args.into_create_request_input(dfx_orbit)?
+                canister_args.into_create_request_input(dfx_orbit).await?
             }
             RequestArgsActions::Asset(asset_args) => {
                 asset_args.into_create_request_input(dfx_orbit).await?
@@ -54,13 +88,21 @@ impl RequestArgs {
             RequestArgsActions::Permission(permission_args) => {
                 permission_args.into_create_request_input(dfx_orbit)?
             }
+            RequestArgsActions::Apply(_) => {
+                anyhow::bail!("A request template file can describe several requests")
+            }
         };
 
+        let summary_format = self.summary_format();
+
         Ok(CreateRequestInput {
             operation,
             title: self.title,
             summary: self.summary,
             execution_plan: None,
+            confidential: None,
+            summary_format,
+            depends_on: (!self.depends_on.is_empty()).then_some(self.depends_on),
         })
     }
 }
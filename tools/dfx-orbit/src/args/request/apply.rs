@@ -0,0 +1,152 @@
+//! Makes the requests that are described in a YAML file.
+//!
+//! The file lists the requests with the same fields as the `create_request` API call, so that
+//! the changes can be reviewed in version control before they are submitted:
+//!
+//! ```yaml
+//! requests:
+//!   - title: Add the release managers group
+//!     summary: The release managers approve the canister upgrades.
+//!     summary_format: Markdown
+//!     operation:
+//!       AddUserGroup:
+//!         name: Release managers
+//! ```
+
+use crate::DfxOrbit;
+use anyhow::Context;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use station_api::{
+    CreateRequestInput, RequestExecutionScheduleDTO, RequestOperationInput, RequestSummaryFormatDTO,
+};
+use std::path::PathBuf;
+
+/// Submits the requests that are described in a YAML file.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestApplyArgs {
+    /// The YAML file that describes the requests
+    pub(crate) file: PathBuf,
+
+    /// Only print the requests that would be submitted
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+}
+
+/// The requests of a request template file.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RequestTemplates {
+    requests: Vec<RequestTemplate>,
+}
+
+/// A request of a request template file.
+///
+/// The enums are written as maps with a single entry, e.g. `AddUserGroup: { ... }`, rather than
+/// with the YAML tags that `serde_yaml` uses by default.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RequestTemplate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary_format: Option<RequestSummaryFormatDTO>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_yaml::with::singleton_map_recursive"
+    )]
+    execution_plan: Option<RequestExecutionScheduleDTO>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidential: Option<bool>,
+    #[serde(with = "serde_yaml::with::singleton_map_recursive")]
+    operation: RequestOperationInput,
+}
+
+impl RequestTemplates {
+    /// Renders the requests as a request template file.
+    pub(crate) fn to_yaml(requests: Vec<CreateRequestInput>) -> anyhow::Result<String> {
+        let templates = RequestTemplates {
+            requests: requests
+                .into_iter()
+                .map(|input| RequestTemplate {
+                    title: input.title,
+                    summary: input.summary,
+                    summary_format: input.summary_format,
+                    execution_plan: input.execution_plan,
+                    confidential: input.confidential,
+                    operation: input.operation,
+                })
+                .collect(),
+        };
+
+        Ok(serde_yaml::to_string(&templates)?)
+    }
+}
+
+impl RequestApplyArgs {
+    /// Parses the request template file, the given title, summary and summary format are used for
+    /// the requests that don't have their own.
+    pub(crate) fn into_create_request_inputs(
+        &self,
+        title: Option<String>,
+        summary: Option<String>,
+        summary_format: Option<RequestSummaryFormatDTO>,
+    ) -> anyhow::Result<Vec<CreateRequestInput>> {
+        let content = std::fs::read_to_string(&self.file)
+            .with_context(|| format!("Could not read {}", self.file.display()))?;
+        let templates: RequestTemplates = serde_yaml::from_str(&content)
+            .with_context(|| format!("Could not parse {}", self.file.display()))?;
+
+        if templates.requests.is_empty() {
+            anyhow::bail!("{} does not describe any requests", self.file.display());
+        }
+
+        Ok(templates
+            .requests
+            .into_iter()
+            .map(|template| CreateRequestInput {
+                operation: template.operation,
+                title: template.title.or_else(|| title.clone()),
+                summary: template.summary.or_else(|| summary.clone()),
+                execution_plan: template.execution_plan,
+                confidential: template.confidential,
+                summary_format: template.summary_format.or(summary_format),
+                depends_on: None,
+            })
+            .collect())
+    }
+
+    /// Submits the requests in the order of the file and prints their ids, it stops at the first
+    /// request that fails to be submitted.
+    pub(crate) async fn apply(
+        self,
+        dfx_orbit: &DfxOrbit,
+        title: Option<String>,
+        summary: Option<String>,
+        summary_format: Option<RequestSummaryFormatDTO>,
+    ) -> anyhow::Result<()> {
+        let inputs = self.into_create_request_inputs(title, summary, summary_format)?;
+
+        if self.dry_run {
+            println!("{}", serde_json::to_string_pretty(&inputs)?);
+            return Ok(());
+        }
+
+        let total = inputs.len();
+        for (index, input) in inputs.into_iter().enumerate() {
+            let response = dfx_orbit.station.request(input).await.with_context(|| {
+                format!(
+                    "Failed to submit request {} of {}, the previous requests were submitted",
+                    index + 1,
+                    total
+                )
+            })?;
+            dfx_orbit.print_create_request_info(&response);
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use crate::DfxOrbit;
-use clap::{Parser, Subcommand};
-use station_api::RequestOperationInput;
+use candid::Principal;
+use clap::{Parser, Subcommand, ValueEnum};
+use ic_certified_assets::types::Permission;
+use station_api::{GetRequestResponse, RequestOperationInput};
 
 #[derive(Debug, Clone, Parser)]
 pub struct RequestAssetArgs {
@@ -13,6 +15,10 @@ pub struct RequestAssetArgs {
 pub enum RequestAssetActionArgs {
     /// Request to grant this user Prepare permission for the asset canister
     PreparePermission(RequestAssetPreparePermissionArgs),
+    /// Request to grant a permission for the asset canister
+    GrantPermission(RequestAssetGrantPermissionArgs),
+    /// Request to revoke a permission for the asset canister
+    RevokePermission(RequestAssetRevokePermissionArgs),
     /// Upload assets to an asset canister
     Upload(RequestAssetUploadArgs),
 }
@@ -26,6 +32,12 @@ impl RequestAssetArgs {
             RequestAssetActionArgs::PreparePermission(args) => {
                 args.into_create_request_input(dfx_orbit)
             }
+            RequestAssetActionArgs::GrantPermission(args) => {
+                args.into_create_request_input(dfx_orbit)
+            }
+            RequestAssetActionArgs::RevokePermission(args) => {
+                args.into_create_request_input(dfx_orbit)
+            }
             RequestAssetActionArgs::Upload(args) => args.into_create_request_input(dfx_orbit).await,
         }
     }
@@ -46,7 +58,120 @@ impl RequestAssetPreparePermissionArgs {
     ) -> anyhow::Result<RequestOperationInput> {
         let me = dfx_orbit.own_principal()?;
         let asset_canister = dfx_orbit.canister_id(&self.canister)?;
-        DfxOrbit::grant_permission_request(asset_canister, me)
+        DfxOrbit::grant_permission_request(asset_canister, me, Permission::Prepare)
+    }
+}
+
+/// The permissions of an asset canister.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, ValueEnum)]
+pub enum AssetPermissionArgs {
+    /// Permits to upload assets, without publishing them
+    Prepare,
+    /// Permits to publish the uploaded assets
+    Commit,
+    /// Permits to grant and revoke permissions
+    ManagePermissions,
+}
+
+impl From<AssetPermissionArgs> for Permission {
+    fn from(permission: AssetPermissionArgs) -> Self {
+        match permission {
+            AssetPermissionArgs::Prepare => Permission::Prepare,
+            AssetPermissionArgs::Commit => Permission::Commit,
+            AssetPermissionArgs::ManagePermissions => Permission::ManagePermissions,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct RequestAssetGrantPermissionArgs {
+    /// The name of the asset canister targeted by this action
+    pub(crate) canister: String,
+
+    /// The permission to grant
+    #[clap(short, long, value_enum)]
+    pub(crate) permission: AssetPermissionArgs,
+
+    /// The principal to grant the permission to (defaults to this user)
+    #[clap(long)]
+    pub(crate) to_principal: Option<Principal>,
+}
+
+impl RequestAssetGrantPermissionArgs {
+    pub(crate) fn into_create_request_input(
+        self,
+        dfx_orbit: &DfxOrbit,
+    ) -> anyhow::Result<RequestOperationInput> {
+        let asset_canister = dfx_orbit.canister_id(&self.canister)?;
+        let to_principal = self
+            .to_principal
+            .map_or_else(|| dfx_orbit.own_principal(), Ok)?;
+
+        DfxOrbit::grant_permission_request(asset_canister, to_principal, self.permission.into())
+    }
+
+    pub(crate) fn verify(
+        &self,
+        dfx_orbit: &DfxOrbit,
+        request: &GetRequestResponse,
+    ) -> anyhow::Result<()> {
+        let asset_canister = dfx_orbit.canister_id(&self.canister)?;
+        let to_principal = self
+            .to_principal
+            .map_or_else(|| dfx_orbit.own_principal(), Ok)?;
+
+        DfxOrbit::check_grant_permission(
+            request,
+            asset_canister,
+            to_principal,
+            self.permission.into(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct RequestAssetRevokePermissionArgs {
+    /// The name of the asset canister targeted by this action
+    pub(crate) canister: String,
+
+    /// The permission to revoke
+    #[clap(short, long, value_enum)]
+    pub(crate) permission: AssetPermissionArgs,
+
+    /// The principal to revoke the permission of (defaults to this user)
+    #[clap(long)]
+    pub(crate) of_principal: Option<Principal>,
+}
+
+impl RequestAssetRevokePermissionArgs {
+    pub(crate) fn into_create_request_input(
+        self,
+        dfx_orbit: &DfxOrbit,
+    ) -> anyhow::Result<RequestOperationInput> {
+        let asset_canister = dfx_orbit.canister_id(&self.canister)?;
+        let of_principal = self
+            .of_principal
+            .map_or_else(|| dfx_orbit.own_principal(), Ok)?;
+
+        DfxOrbit::revoke_permission_request(asset_canister, of_principal, self.permission.into())
+    }
+
+    pub(crate) fn verify(
+        &self,
+        dfx_orbit: &DfxOrbit,
+        request: &GetRequestResponse,
+    ) -> anyhow::Result<()> {
+        let asset_canister = dfx_orbit.canister_id(&self.canister)?;
+        let of_principal = self
+            .of_principal
+            .map_or_else(|| dfx_orbit.own_principal(), Ok)?;
+
+        DfxOrbit::check_revoke_permission(
+            request,
+            asset_canister,
+            of_principal,
+            self.permission.into(),
+        )
     }
 }
 
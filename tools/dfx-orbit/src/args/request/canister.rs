@@ -1,16 +1,27 @@
 //! CLI arguments for `dfx-orbit canister`.
 
-use crate::DfxOrbit;
+use crate::{locale::Message, DfxOrbit};
 use anyhow::{bail, Context};
+use candid::{CandidType, Deserialize, Principal};
 use clap::{Parser, Subcommand, ValueEnum};
 use sha2::{Digest, Sha256};
 use slog::{info, Logger};
 use station_api::{
     CallExternalCanisterOperationInput, CanisterInstallMode, CanisterMethodDTO,
-    ChangeExternalCanisterOperationInput, GetRequestResponse, RequestOperationDTO,
-    RequestOperationInput,
+    ChangeExternalCanisterOperationInput, ChunkedWasmModuleDTO,
+    ConfigureExternalCanisterOperationInput, ConfigureExternalCanisterOperationKindDTO,
+    DefiniteCanisterSettingsInput, GetRequestResponse, LogVisibilityDTO, RequestOperationDTO,
+    RequestOperationInput, SnapshotExternalCanisterOperationInput,
+    SnapshotExternalCanisterOperationKindDTO,
 };
 
+/// The largest module that is sent with the request, larger modules must be uploaded in chunks
+/// to stay below the ingress message size limit.
+const MAX_UNCHUNKED_MODULE_SIZE: usize = 1_800_000;
+
+/// The size of the chunks that a module is uploaded in, the maximum of the chunk store.
+const MODULE_CHUNK_SIZE: usize = 1024 * 1024;
+
 // TODO: Support Canister create + integration test
 // TODO: Canister get response functionality
 
@@ -33,31 +44,41 @@ pub enum RequestCanisterActionArgs {
     Install(RequestCanisterInstallArgs),
     /// Request to call a canister method
     Call(RequestCanisterCallArgs),
+    /// Request to change the settings of the canister
+    Settings(RequestCanisterSettingsArgs),
+    /// Request to take, load or delete a snapshot of the canister
+    Snapshot(RequestCanisterSnapshotArgs),
 }
 
 impl RequestCanisterArgs {
     /// Converts the CLI arg type into the equivalent Orbit API type.
-    pub(crate) fn into_create_request_input(
+    pub(crate) async fn into_create_request_input(
         self,
         dfx_orbit: &DfxOrbit,
     ) -> anyhow::Result<RequestOperationInput> {
-        self.action.into_create_request_input(dfx_orbit)
+        self.action.into_create_request_input(dfx_orbit).await
     }
 }
 
 impl RequestCanisterActionArgs {
     /// Converts the CLI arg type into the equivalent Orbit API type.
-    pub(crate) fn into_create_request_input(
+    pub(crate) async fn into_create_request_input(
         self,
         dfx_orbit: &DfxOrbit,
     ) -> anyhow::Result<RequestOperationInput> {
         match self {
             RequestCanisterActionArgs::Install(change_args) => {
-                change_args.into_create_request_input(dfx_orbit)
+                change_args.into_create_request_input(dfx_orbit).await
             }
             RequestCanisterActionArgs::Call(call_args) => {
                 call_args.into_create_request_input(dfx_orbit)
             }
+            RequestCanisterActionArgs::Settings(settings_args) => {
+                settings_args.into_create_request_input(dfx_orbit)
+            }
+            RequestCanisterActionArgs::Snapshot(snapshot_args) => {
+                snapshot_args.into_create_request_input(dfx_orbit)
+            }
         }
     }
 }
@@ -145,6 +166,177 @@ impl RequestCanisterCallArgs {
     }
 }
 
+/// Requests that the settings of a canister be changed.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestCanisterSettingsArgs {
+    /// The canister name or ID.
+    canister: String,
+    /// The new controllers of the canister, replacing the current ones.
+    #[clap(long = "set-controller", num_args = 1..)]
+    controllers: Option<Vec<Principal>>,
+    /// The compute allocation in percent.
+    #[clap(long)]
+    compute_allocation: Option<u64>,
+    /// The memory allocation in bytes.
+    #[clap(long)]
+    memory_allocation: Option<u64>,
+    /// The freezing threshold in seconds.
+    #[clap(long)]
+    freezing_threshold: Option<u64>,
+    /// The upper limit of the reserved cycles of the canister.
+    #[clap(long)]
+    reserved_cycles_limit: Option<u128>,
+    /// Who can read the logs of the canister.
+    #[clap(long, value_enum, rename_all = "kebab-case")]
+    log_visibility: Option<LogVisibilityArgs>,
+}
+
+impl RequestCanisterSettingsArgs {
+    /// Converts the CLI arg type into the equivalent Orbit API type.
+    pub(crate) fn into_create_request_input(
+        self,
+        dfx_orbit: &DfxOrbit,
+    ) -> anyhow::Result<RequestOperationInput> {
+        let canister_id = dfx_orbit.canister_id(&self.canister)?;
+        let settings = self.settings();
+        if settings == DefiniteCanisterSettingsInput::default() {
+            bail!("At least one setting must be changed");
+        }
+
+        Ok(RequestOperationInput::ConfigureExternalCanister(
+            ConfigureExternalCanisterOperationInput {
+                canister_id,
+                kind: ConfigureExternalCanisterOperationKindDTO::NativeSettings(settings),
+            },
+        ))
+    }
+
+    pub(crate) fn verify(
+        &self,
+        dfx_orbit: &DfxOrbit,
+        request: &GetRequestResponse,
+    ) -> anyhow::Result<()> {
+        let canister_id = dfx_orbit.canister_id(&self.canister)?;
+
+        let RequestOperationDTO::ConfigureExternalCanister(op) = &request.request.operation else {
+            bail!("This request is not a configure external canister request");
+        };
+        if op.canister_id != canister_id {
+            bail!(
+                "Canister id {} does not match expected canister id",
+                op.canister_id
+            );
+        }
+        let ConfigureExternalCanisterOperationKindDTO::NativeSettings(settings) = &op.kind else {
+            bail!("This request does not change the settings of the canister");
+        };
+        if *settings != self.settings() {
+            bail!("Canister settings do not match");
+        }
+
+        Ok(())
+    }
+
+    fn settings(&self) -> DefiniteCanisterSettingsInput {
+        DefiniteCanisterSettingsInput {
+            controllers: self.controllers.clone(),
+            compute_allocation: self.compute_allocation.map(Into::into),
+            memory_allocation: self.memory_allocation.map(Into::into),
+            freezing_threshold: self.freezing_threshold.map(Into::into),
+            reserved_cycles_limit: self.reserved_cycles_limit.map(Into::into),
+            log_visibility: self.log_visibility.map(Into::into),
+        }
+    }
+}
+
+/// Log visibility equivalent to `dfx canister update-settings --log-visibility XXX`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, ValueEnum)]
+pub enum LogVisibilityArgs {
+    /// Only the controllers can read the logs
+    Controllers,
+    /// Everyone can read the logs
+    Public,
+}
+
+impl From<LogVisibilityArgs> for LogVisibilityDTO {
+    fn from(log_visibility: LogVisibilityArgs) -> Self {
+        match log_visibility {
+            LogVisibilityArgs::Controllers => Self::Controllers,
+            LogVisibilityArgs::Public => Self::Public,
+        }
+    }
+}
+
+/// Requests that a snapshot of a canister be taken, loaded or deleted.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestCanisterSnapshotArgs {
+    /// The snapshot operation to request
+    #[clap(subcommand)]
+    action: RequestCanisterSnapshotActionArgs,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum RequestCanisterSnapshotActionArgs {
+    /// Request to take a snapshot of the canister
+    Take {
+        /// The canister name or ID.
+        canister: String,
+        /// The hex encoded ID of the snapshot to replace.
+        #[clap(long)]
+        replace: Option<String>,
+    },
+    /// Request to load a snapshot into the canister, restoring its module and state
+    Load {
+        /// The canister name or ID.
+        canister: String,
+        /// The hex encoded ID of the snapshot.
+        snapshot_id: String,
+    },
+    /// Request to delete a snapshot of the canister
+    Delete {
+        /// The canister name or ID.
+        canister: String,
+        /// The hex encoded ID of the snapshot.
+        snapshot_id: String,
+    },
+}
+
+impl RequestCanisterSnapshotArgs {
+    /// Converts the CLI arg type into the equivalent Orbit API type.
+    pub(crate) fn into_create_request_input(
+        self,
+        dfx_orbit: &DfxOrbit,
+    ) -> anyhow::Result<RequestOperationInput> {
+        let (canister, kind) = match self.action {
+            RequestCanisterSnapshotActionArgs::Take { canister, replace } => (
+                canister,
+                SnapshotExternalCanisterOperationKindDTO::Take {
+                    replace_snapshot: replace,
+                },
+            ),
+            RequestCanisterSnapshotActionArgs::Load {
+                canister,
+                snapshot_id,
+            } => (
+                canister,
+                SnapshotExternalCanisterOperationKindDTO::Load { snapshot_id },
+            ),
+            RequestCanisterSnapshotActionArgs::Delete {
+                canister,
+                snapshot_id,
+            } => (
+                canister,
+                SnapshotExternalCanisterOperationKindDTO::Delete { snapshot_id },
+            ),
+        };
+        let canister_id = dfx_orbit.canister_id(&canister)?;
+
+        Ok(RequestOperationInput::SnapshotExternalCanister(
+            SnapshotExternalCanisterOperationInput { canister_id, kind },
+        ))
+    }
+}
+
 /// Requests that a canister be installed or updated.  Equivalent to `orbit_station_api::CanisterInstallMode`.
 #[derive(Debug, Clone, Parser)]
 pub struct RequestCanisterInstallArgs {
@@ -163,23 +355,67 @@ pub struct RequestCanisterInstallArgs {
     /// The path to a file containing the argument to pass to the canister.
     #[clap(short = 'f', long, conflicts_with = "arg")]
     arg_file: Option<String>,
+    /// The canister name or ID to upload the module to in chunks (required for modules larger
+    /// than 1.8 MB). It must be controlled by you and the station and be on the subnet of the
+    /// canister.
+    #[clap(long)]
+    chunk_store: Option<String>,
 }
 
 impl RequestCanisterInstallArgs {
     /// Converts the CLI arg type into the equivalent Orbit API type.
-    pub(crate) fn into_create_request_input(
+    ///
+    /// The module is uploaded to the chunk store first, if one is given.
+    pub(crate) async fn into_create_request_input(
         self,
         dfx_orbit: &DfxOrbit,
     ) -> anyhow::Result<RequestOperationInput> {
         let canister_id = dfx_orbit.canister_id(&self.canister)?;
 
         let (module, arg) = self.load_module_and_args()?;
+        let wasm_module_hash = Sha256::digest(&module).to_vec();
+        println!(
+            "{}: {}",
+            dfx_orbit.text(Message::ModuleChecksum),
+            hex::encode(&wasm_module_hash)
+        );
+
+        let store_canister = match &self.chunk_store {
+            Some(chunk_store) => Some(dfx_orbit.canister_id(chunk_store)?),
+            None if module.len() > MAX_UNCHUNKED_MODULE_SIZE => bail!(
+                "The module has {} bytes, modules larger than {} bytes must be uploaded with --chunk-store",
+                module.len(),
+                MAX_UNCHUNKED_MODULE_SIZE
+            ),
+            None => None,
+        };
+
+        let (module, module_chunks) = match store_canister {
+            Some(store_canister) => {
+                let chunk_hashes = upload_module_chunks(dfx_orbit, store_canister, &module)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to upload the module to the chunk store of {store_canister}"
+                        )
+                    })?;
+                let module_chunks = ChunkedWasmModuleDTO {
+                    store_canister,
+                    chunk_hashes,
+                    wasm_module_hash,
+                };
+                (vec![], Some(module_chunks))
+            }
+            None => (module, None),
+        };
+
         let mode = self.mode.into();
         let operation = ChangeExternalCanisterOperationInput {
             canister_id,
             mode,
             module,
             arg,
+            module_chunks,
         };
         Ok(RequestOperationInput::ChangeExternalCanister(operation))
     }
@@ -270,6 +506,47 @@ impl From<CanisterInstallMode> for CanisterInstallModeArgs {
     }
 }
 
+/// The argument of the `upload_chunk` method of the management canister.
+#[derive(CandidType)]
+struct UploadChunkArgs {
+    canister_id: Principal,
+    chunk: Vec<u8>,
+}
+
+/// The result of the `upload_chunk` method of the management canister.
+#[derive(Deserialize)]
+struct ChunkHash {
+    hash: Vec<u8>,
+}
+
+/// Uploads the module in chunks to the chunk store of the canister and returns the hashes of
+/// the chunks in the order of the module.
+async fn upload_module_chunks(
+    dfx_orbit: &DfxOrbit,
+    store_canister: Principal,
+    module: &[u8],
+) -> anyhow::Result<Vec<Vec<u8>>> {
+    let agent = dfx_orbit.interface.agent();
+    let mut chunk_hashes = Vec::new();
+
+    for chunk in module.chunks(MODULE_CHUNK_SIZE) {
+        let response = agent
+            .update(&Principal::management_canister(), "upload_chunk")
+            .with_effective_canister_id(store_canister)
+            .with_arg(candid::encode_one(UploadChunkArgs {
+                canister_id: store_canister,
+                chunk: chunk.to_vec(),
+            })?)
+            .call_and_wait()
+            .await?;
+        let ChunkHash { hash } = candid::decode_one(&response)?;
+
+        chunk_hashes.push(hash);
+    }
+
+    Ok(chunk_hashes)
+}
+
 fn candid_from_string_or_file(
     arg_string: &Option<String>,
     arg_path: &Option<String>,
@@ -2,12 +2,14 @@
 pub mod id;
 pub mod list;
 pub mod next;
+pub mod tui;
 
 use clap::{Parser, Subcommand};
 use id::ReviewIdArgs;
 use list::ReviewListArgs;
 use next::ReviewNextArgs;
 use station_api::ListRequestsOperationTypeDTO;
+use tui::ReviewTuiArgs;
 
 /// Station management commands.
 #[derive(Debug, Clone, Parser)]
@@ -28,6 +30,8 @@ pub enum ReviewActionArgs {
     Next(ReviewNextArgs),
     /// Review a specific request.
     Id(ReviewIdArgs),
+    /// Review the pending requests interactively.
+    Tui(ReviewTuiArgs),
 }
 
 fn external_canister_operations() -> Vec<ListRequestsOperationTypeDTO> {
@@ -36,5 +40,7 @@ fn external_canister_operations() -> Vec<ListRequestsOperationTypeDTO> {
         ListRequestsOperationTypeDTO::CreateExternalCanister,
         ListRequestsOperationTypeDTO::CallExternalCanister(None),
         ListRequestsOperationTypeDTO::ConfigureExternalCanister(None),
+        ListRequestsOperationTypeDTO::CommitAssetBatch(None),
+        ListRequestsOperationTypeDTO::SnapshotExternalCanister(None),
     ]
 }
@@ -1,6 +1,8 @@
 //! CLI arguments for `dfx-orbit review next`.
 use clap::Parser;
-use station_api::{GetRequestInput, RequestApprovalStatusDTO, SubmitRequestApprovalInput};
+use station_api::{
+    GetRequestInput, RequestApprovalStatusDTO, SubmitRequestApprovalInput, STATION_API_VERSION,
+};
 
 /// Reviews the next request.
 #[derive(Debug, Clone, PartialEq, Eq, Parser)]
@@ -27,12 +29,16 @@ pub struct ReviewIdArgs {
         default_missing_value = "None"
     )]
     pub(crate) reject: Option<Option<String>>,
+    /// Acknowledge the approval checklist of the request when approving it
+    #[clap(long, requires = "approve")]
+    pub(crate) acknowledge_checklist: bool,
 }
 
 impl From<ReviewIdArgs> for GetRequestInput {
     fn from(args: ReviewIdArgs) -> Self {
         GetRequestInput {
             request_id: args.request_id,
+            api_version: Some(STATION_API_VERSION),
         }
     }
 }
@@ -52,6 +58,7 @@ impl TryFrom<ReviewIdArgs> for SubmitRequestApprovalInput {
             request_id: value.request_id,
             decision,
             reason,
+            acknowledged_checklist: None,
         })
     }
 }
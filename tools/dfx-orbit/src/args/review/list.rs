@@ -2,7 +2,7 @@
 
 use super::external_canister_operations;
 use clap::Parser;
-use station_api::{ListRequestsInput, ListRequestsSortBy, SortDirection};
+use station_api::{ListRequestsInput, ListRequestsSortBy, SortDirection, STATION_API_VERSION};
 
 // TODO: Filter by open only
 /// Reviews the next request.
@@ -32,6 +32,7 @@ impl From<ReviewListArgs> for ListRequestsInput {
             sort_by: Some(ListRequestsSortBy::CreatedAt(SortDirection::Asc)),
             only_approvable: args.only_approvable,
             with_evaluation_results: true,
+            api_version: Some(STATION_API_VERSION),
         }
     }
 }
@@ -2,7 +2,7 @@
 
 use super::external_canister_operations;
 use clap::Parser;
-use station_api::GetNextApprovableRequestInput;
+use station_api::{GetNextApprovableRequestInput, STATION_API_VERSION};
 
 /// Reviews the next request.
 #[derive(Debug, Clone, Parser)]
@@ -17,6 +17,7 @@ impl From<ReviewNextArgs> for GetNextApprovableRequestInput {
         Self {
             excluded_request_ids: vec![],
             operation_types: (!args.any).then(external_canister_operations),
+            api_version: Some(STATION_API_VERSION),
         }
     }
 }
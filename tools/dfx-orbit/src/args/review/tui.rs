@@ -0,0 +1,35 @@
+//! CLI arguments for `dfx-orbit review tui`.
+
+use super::external_canister_operations;
+use clap::Parser;
+use station_api::{
+    ListRequestsInput, ListRequestsSortBy, RequestStatusCodeDTO, SortDirection, STATION_API_VERSION,
+};
+
+/// Reviews the pending requests interactively.
+#[derive(Debug, Clone, Parser)]
+pub struct ReviewTuiArgs {
+    /// Show all request types, not only the ones related to canister management
+    #[clap(short, long)]
+    pub all: bool,
+}
+
+impl From<ReviewTuiArgs> for ListRequestsInput {
+    fn from(args: ReviewTuiArgs) -> Self {
+        Self {
+            requester_ids: None,
+            approver_ids: None,
+            statuses: Some(vec![RequestStatusCodeDTO::Created]),
+            operation_types: (!args.all).then(external_canister_operations),
+            expiration_from_dt: None,
+            expiration_to_dt: None,
+            created_from_dt: None,
+            created_to_dt: None,
+            paginate: None,
+            sort_by: Some(ListRequestsSortBy::CreatedAt(SortDirection::Asc)),
+            only_approvable: true,
+            with_evaluation_results: false,
+            api_version: Some(STATION_API_VERSION),
+        }
+    }
+}
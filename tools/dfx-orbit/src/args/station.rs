@@ -2,7 +2,10 @@
 use crate::station_agent::StationConfig;
 use candid::Principal;
 use clap::{Parser, Subcommand};
-use std::fmt::{self, Display, Formatter};
+use std::{
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+};
 
 /// Station management commands
 #[derive(Debug, Subcommand)]
@@ -22,6 +25,15 @@ pub enum StationArgs {
     Edit(StationEditArgs),
     /// Removes an Orbit station from the local dfx configuration
     Remove(StationRemoveArgs),
+    /// Compares the canisters of the local dfx project with the ones registered in the station
+    Diff(StationDiffArgs),
+}
+
+impl StationArgs {
+    /// Whether the command only works on the local dfx configuration, without calling the station.
+    pub(crate) fn is_local(&self) -> bool {
+        !matches!(self, StationArgs::Diff(_))
+    }
 }
 
 /// Adds an Orbit station to the local dfx configuration
@@ -113,3 +125,12 @@ pub struct StationUseArgs {
     /// Station name
     pub name: String,
 }
+
+/// Compares the canisters of the local dfx project with the ones registered in the station
+#[derive(Debug, Parser)]
+pub struct StationDiffArgs {
+    /// Write the requests that reconcile the station with the local project to this YAML file,
+    /// they can be submitted with `dfx-orbit request apply`
+    #[clap(short, long)]
+    pub(crate) output: Option<PathBuf>,
+}
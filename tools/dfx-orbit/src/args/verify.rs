@@ -1,11 +1,11 @@
 mod asset;
 mod canister;
 
-use crate::DfxOrbit;
+use crate::{locale::Message, DfxOrbit};
 use asset::VerifyAssetArgs;
 use canister::VerifyCanisterArgs;
 use clap::{Parser, Subcommand};
-use station_api::GetRequestInput;
+use station_api::{GetRequestInput, STATION_API_VERSION};
 
 #[derive(Debug, Clone, Parser)]
 pub struct VerifyArgs {
@@ -39,6 +39,7 @@ impl VerifyArgs {
             .station
             .review_id(GetRequestInput {
                 request_id: self.request_id.clone(),
+                api_version: Some(STATION_API_VERSION),
             })
             .await?;
 
@@ -56,16 +57,16 @@ impl VerifyArgs {
 
         match verified {
             Ok(()) => {
-                println!("Verification successful!");
+                println!("{}", dfx_orbit.text(Message::VerificationSuccessful));
                 if self.and_approve {
-                    dfx_core::cli::ask_for_consent("Do you want to approve the request?")?;
+                    dfx_core::cli::ask_for_consent(dfx_orbit.text(Message::ConfirmApproveRequest))?;
                     dfx_orbit.station.approve(self.request_id, None).await?;
                 }
             }
             Err(err) => {
-                println!("Verification failed: {err}");
+                println!("{}: {err}", dfx_orbit.text(Message::VerificationFailed));
                 if self.or_reject {
-                    dfx_core::cli::ask_for_consent("Do you want to reject the request?")?;
+                    dfx_core::cli::ask_for_consent(dfx_orbit.text(Message::ConfirmRejectRequest))?;
                     dfx_orbit.station.reject(self.request_id, None).await?;
                 };
 
@@ -1,4 +1,7 @@
-use crate::DfxOrbit;
+use crate::{
+    args::request::asset::{RequestAssetGrantPermissionArgs, RequestAssetRevokePermissionArgs},
+    DfxOrbit,
+};
 use candid::Nat;
 use clap::{Parser, Subcommand};
 use station_api::GetRequestResponse;
@@ -13,9 +16,12 @@ pub struct VerifyAssetArgs {
 #[derive(Debug, Clone, Subcommand)]
 #[clap(version, about, long_about = None)]
 pub enum VerifyAssetActionArgs {
-    // TODO: Verify Request Permission
     /// Upload assets to an asset canister
     Upload(VerifyAssetUploadArgs),
+    /// Grant a permission for the asset canister
+    GrantPermission(RequestAssetGrantPermissionArgs),
+    /// Revoke a permission for the asset canister
+    RevokePermission(RequestAssetRevokePermissionArgs),
 }
 
 impl VerifyAssetArgs {
@@ -26,6 +32,8 @@ impl VerifyAssetArgs {
     ) -> anyhow::Result<()> {
         match self.action {
             VerifyAssetActionArgs::Upload(args) => args.verify(dfx_orbit, request).await?,
+            VerifyAssetActionArgs::GrantPermission(args) => args.verify(dfx_orbit, request)?,
+            VerifyAssetActionArgs::RevokePermission(args) => args.verify(dfx_orbit, request)?,
         }
 
         Ok(())
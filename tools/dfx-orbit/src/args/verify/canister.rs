@@ -2,7 +2,9 @@ use clap::{Parser, Subcommand};
 use station_api::GetRequestResponse;
 
 use crate::{
-    args::request::canister::{RequestCanisterCallArgs, RequestCanisterInstallArgs},
+    args::request::canister::{
+        RequestCanisterCallArgs, RequestCanisterInstallArgs, RequestCanisterSettingsArgs,
+    },
     DfxOrbit,
 };
 
@@ -20,6 +22,8 @@ pub enum VerifyCanisterActionArgs {
     Install(RequestCanisterInstallArgs),
     ///Verify call a canister method
     Call(RequestCanisterCallArgs),
+    /// Verify change the settings of the canister
+    Settings(RequestCanisterSettingsArgs),
 }
 
 impl VerifyCanisterArgs {
@@ -31,6 +35,7 @@ impl VerifyCanisterArgs {
         match self.action {
             VerifyCanisterActionArgs::Install(args) => args.verify(dfx_orbit, request)?,
             VerifyCanisterActionArgs::Call(args) => args.verify(dfx_orbit, request)?,
+            VerifyCanisterActionArgs::Settings(args) => args.verify(dfx_orbit, request)?,
         }
 
         Ok(())
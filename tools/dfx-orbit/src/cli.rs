@@ -1,12 +1,14 @@
 //! Implementation of the `dfx-orbit` commands.
 pub(crate) mod asset;
+pub(crate) mod logs;
 pub(crate) mod me;
 pub(crate) mod review;
 pub(crate) mod station;
 
 use crate::{
-    args::{DfxOrbitArgs, DfxOrbitSubcommands},
+    args::{station::StationArgs, DfxOrbitArgs, DfxOrbitSubcommands},
     dfx_extension_api::OrbitExtensionAgent,
+    locale::{Locale, Message},
     DfxOrbit,
 };
 use anyhow::Context;
@@ -20,9 +22,12 @@ pub async fn exec(args: DfxOrbitArgs) -> anyhow::Result<()> {
     let orbit_agent = OrbitExtensionAgent::new()?;
 
     // We don't need to instanciate a StationAgent to execute this command directly on the orbit agent
-    if let DfxOrbitSubcommands::Station(station_args) = args.command {
-        station::exec(orbit_agent, station_args)?;
-        return Ok(());
+    match args.command {
+        DfxOrbitSubcommands::Station(station_args) if station_args.is_local() => {
+            station::exec(orbit_agent, station_args)?;
+            return Ok(());
+        }
+        _ => {}
     };
 
     let config = match args.station {
@@ -32,7 +37,10 @@ pub async fn exec(args: DfxOrbitArgs) -> anyhow::Result<()> {
             .ok_or_else(|| anyhow::format_err!("No default station specified"))?,
     };
 
-    let dfx_orbit = DfxOrbit::new(orbit_agent, config, args.identity, logger).await?;
+    let locale = args.locale.unwrap_or_else(Locale::from_env);
+    let dfx_orbit = DfxOrbit::new(orbit_agent, config, args.identity, logger)
+        .await?
+        .with_locale(locale);
 
     match args.command {
         // Nicer display, json optional
@@ -45,22 +53,19 @@ pub async fn exec(args: DfxOrbitArgs) -> anyhow::Result<()> {
             }
             Ok(())
         }
-        DfxOrbitSubcommands::Request(request_args) => {
-            let request = dfx_orbit
-                .station
-                .request(request_args.into_create_request_input(&dfx_orbit).await?)
-                .await?;
-            dfx_orbit.print_create_request_info(&request);
-
-            Ok(())
-        }
+        DfxOrbitSubcommands::Logs(logs_args) => dfx_orbit.exec_logs(logs_args).await,
+        DfxOrbitSubcommands::Request(request_args) => request_args.exec(&dfx_orbit).await,
         DfxOrbitSubcommands::Verify(verify_args) => {
             verify_args.verify(&dfx_orbit).await?;
 
-            println!("Request passes verification");
+            println!("{}", dfx_orbit.text(Message::RequestPassesVerification));
             Ok(())
         }
         DfxOrbitSubcommands::Review(review_args) => dfx_orbit.exec_review(review_args).await,
+        DfxOrbitSubcommands::Asset(asset_args) => dfx_orbit.exec_asset(asset_args).await,
+        DfxOrbitSubcommands::Station(StationArgs::Diff(diff_args)) => {
+            dfx_orbit.exec_station_diff(diff_args).await
+        }
         DfxOrbitSubcommands::Station(_) => unreachable!(),
     }
 }
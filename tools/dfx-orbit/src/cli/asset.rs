@@ -1,6 +1,7 @@
 //! Implements the `dfx-orbit canister upload-http-assets` CLI command.
 
 mod evidence;
+mod permission;
 mod upload;
 mod util;
 
@@ -2,10 +2,7 @@ use super::AssetAgent;
 use crate::DfxOrbit;
 use anyhow::bail;
 use candid::{Nat, Principal};
-use ic_certified_assets::types::CommitProposedBatchArguments;
-use serde_bytes::ByteBuf;
-use sha2::{Digest, Sha256};
-use station_api::{CanisterMethodDTO, GetRequestResponse, RequestOperationDTO};
+use station_api::{GetRequestResponse, RequestOperationDTO};
 use std::path::Path;
 
 impl DfxOrbit {
@@ -16,45 +13,30 @@ impl DfxOrbit {
         evidence: String,
     ) -> anyhow::Result<()> {
         // Check:
-        // - Request is actually a CallExternalCanister
+        // - Request is actually a CommitAssetBatch
         // - Target is the canister we are expecting
-        // - Method is `propose_commit_batch`
-        // - `arg_checksum` exists
-        let RequestOperationDTO::CallExternalCanister(request) = &request.request.operation else {
-            bail!("{} is not an external canister request. Are you sure you have the correct request id?", {&request.request.id});
+        // - Batch and evidence match the ones computed locally
+        let RequestOperationDTO::CommitAssetBatch(request) = &request.request.operation else {
+            bail!("{} is not a commit asset batch request. Are you sure you have the correct request id?", {&request.request.id});
         };
-        let CanisterMethodDTO {
-            canister_id: request_canister_id,
-            method_name,
-        } = &request.execution_method;
-        if *request_canister_id != canister_id {
+        if request.canister_id != canister_id {
             bail!(
                 "Canister id of the request {} does not match canister id of asset canister {}",
-                request_canister_id,
+                request.canister_id,
                 canister_id
             );
         }
-        if method_name != "commit_proposed_batch" {
+        if request.batch_id != batch_id {
             bail!(
-                "Method name if the request is not \"commit_proposed_batch\", but instead \"{}\"",
-                method_name
+                "Batch id of the request {} does not match the expected batch id {}",
+                request.batch_id,
+                batch_id
             );
         }
-        let Some(remote_checksum) = &request.arg_checksum else {
-            bail!("The request has no arguments. This likely means that is is malformed.");
-        };
-
-        // Now we check that the argument that we construct locally matches the hash of the argument
-        let evidence = hex::decode(evidence)?;
-        let args = CommitProposedBatchArguments {
-            batch_id,
-            evidence: ByteBuf::from(evidence),
-        };
-        let arg = candid::encode_one(args)?;
-        let local_checksum = hex::encode(Sha256::digest(arg));
 
-        if &local_checksum != remote_checksum {
-            bail!("Local evidence does not match expected arguments");
+        // The evidence is compared on the decoded bytes, to be independent of the hex casing
+        if hex::decode(&request.evidence)? != hex::decode(evidence)? {
+            bail!("Local evidence does not match the evidence of the request");
         }
 
         Ok(())
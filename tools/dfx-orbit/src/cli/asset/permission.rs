@@ -0,0 +1,197 @@
+use super::AssetAgent;
+use crate::{
+    args::{
+        asset::{AssetArgs, AssetPermissionsArgs},
+        request::asset::AssetPermissionArgs,
+    },
+    DfxOrbit,
+};
+use anyhow::bail;
+use candid::{CandidType, Principal};
+use ic_certified_assets::types::{
+    GrantPermissionArguments, ListPermittedArguments, Permission, RevokePermissionArguments,
+};
+use sha2::{Digest, Sha256};
+use station_api::{
+    CallExternalCanisterOperationInput, CanisterMethodDTO, GetRequestResponse, RequestOperationDTO,
+    RequestOperationInput,
+};
+
+impl DfxOrbit {
+    pub(crate) async fn exec_asset(&self, args: AssetArgs) -> anyhow::Result<()> {
+        match args {
+            AssetArgs::Permissions(permissions_args) => {
+                self.print_asset_permissions(permissions_args).await
+            }
+        }
+    }
+
+    async fn print_asset_permissions(&self, args: AssetPermissionsArgs) -> anyhow::Result<()> {
+        let asset_canister = self.canister_id(&args.canister)?;
+        let asset_agent = self.asset_agent(asset_canister)?;
+        let me = self.own_principal()?;
+        let station_id = self.station.config.station_id;
+
+        let permissions = match args.permission {
+            Some(permission) => vec![permission],
+            None => vec![
+                AssetPermissionArgs::Prepare,
+                AssetPermissionArgs::Commit,
+                AssetPermissionArgs::ManagePermissions,
+            ],
+        };
+
+        for permission in permissions {
+            let permitted = asset_agent.list_permitted(permission.into()).await?;
+
+            println!("{permission:?}:");
+            if permitted.is_empty() {
+                println!("  -");
+            }
+            for principal in permitted {
+                let note = if principal == me {
+                    " (you)"
+                } else if principal == station_id {
+                    " (station)"
+                } else {
+                    ""
+                };
+                println!("  {principal}{note}");
+            }
+        }
+        println!("The controllers of the canister, such as the station, hold all permissions.");
+
+        Ok(())
+    }
+
+    /// Requests the station to grant the permission on the asset canister to the principal.
+    pub fn grant_permission_request(
+        asset_canister: Principal,
+        to_principal: Principal,
+        permission: Permission,
+    ) -> anyhow::Result<RequestOperationInput> {
+        let args = GrantPermissionArguments {
+            to_principal,
+            permission,
+        };
+
+        permission_request(asset_canister, "grant_permission", args)
+    }
+
+    /// Requests the station to revoke the permission on the asset canister of the principal.
+    pub fn revoke_permission_request(
+        asset_canister: Principal,
+        of_principal: Principal,
+        permission: Permission,
+    ) -> anyhow::Result<RequestOperationInput> {
+        let args = RevokePermissionArguments {
+            of_principal,
+            permission,
+        };
+
+        permission_request(asset_canister, "revoke_permission", args)
+    }
+
+    /// Checks that the request grants the permission on the asset canister to the principal.
+    pub fn check_grant_permission(
+        request: &GetRequestResponse,
+        asset_canister: Principal,
+        to_principal: Principal,
+        permission: Permission,
+    ) -> anyhow::Result<()> {
+        let args = GrantPermissionArguments {
+            to_principal,
+            permission,
+        };
+
+        check_permission_request(request, asset_canister, "grant_permission", args)
+    }
+
+    /// Checks that the request revokes the permission on the asset canister of the principal.
+    pub fn check_revoke_permission(
+        request: &GetRequestResponse,
+        asset_canister: Principal,
+        of_principal: Principal,
+        permission: Permission,
+    ) -> anyhow::Result<()> {
+        let args = RevokePermissionArguments {
+            of_principal,
+            permission,
+        };
+
+        check_permission_request(request, asset_canister, "revoke_permission", args)
+    }
+}
+
+impl AssetAgent<'_> {
+    /// Lists the principals that hold the permission on the asset canister.
+    ///
+    /// The controllers of the asset canister, such as the station, hold all permissions without
+    /// being listed.
+    pub async fn list_permitted(&self, permission: Permission) -> anyhow::Result<Vec<Principal>> {
+        let (permitted,) = self
+            .canister_agent
+            .query("list_permitted")
+            .with_arg(ListPermittedArguments { permission })
+            .build::<(Vec<Principal>,)>()
+            .call()
+            .await?;
+
+        Ok(permitted)
+    }
+}
+
+fn permission_request(
+    asset_canister: Principal,
+    method_name: &str,
+    args: impl CandidType,
+) -> anyhow::Result<RequestOperationInput> {
+    let arg = candid::encode_one(args)?;
+
+    Ok(RequestOperationInput::CallExternalCanister(
+        CallExternalCanisterOperationInput {
+            validation_method: None,
+            execution_method: CanisterMethodDTO {
+                canister_id: asset_canister,
+                method_name: String::from(method_name),
+            },
+            arg: Some(arg),
+            execution_method_cycles: None,
+        },
+    ))
+}
+
+fn check_permission_request(
+    request: &GetRequestResponse,
+    asset_canister: Principal,
+    method_name: &str,
+    args: impl CandidType,
+) -> anyhow::Result<()> {
+    let RequestOperationDTO::CallExternalCanister(operation) = &request.request.operation else {
+        bail!(
+            "{} is not an external canister request. Are you sure you have the correct request id?",
+            request.request.id
+        );
+    };
+    if operation.execution_method.canister_id != asset_canister {
+        bail!(
+            "Canister id of the request {} does not match canister id of asset canister {}",
+            operation.execution_method.canister_id,
+            asset_canister
+        );
+    }
+    if operation.execution_method.method_name != method_name {
+        bail!(
+            "Method name of the request is not \"{}\", but instead \"{}\"",
+            method_name,
+            operation.execution_method.method_name
+        );
+    }
+
+    let local_checksum = hex::encode(Sha256::digest(candid::encode_one(args)?));
+    if operation.arg_checksum.as_ref() != Some(&local_checksum) {
+        bail!("The permission or principal of the request does not match");
+    }
+
+    Ok(())
+}
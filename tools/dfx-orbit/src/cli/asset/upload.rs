@@ -2,10 +2,9 @@ use super::AssetAgent;
 use crate::DfxOrbit;
 use anyhow::bail;
 use candid::{Nat, Principal};
-use ic_certified_assets::types::CommitProposedBatchArguments;
 use serde_bytes::ByteBuf;
 use slog::{info, warn};
-use station_api::{CallExternalCanisterOperationInput, CanisterMethodDTO, RequestOperationInput};
+use station_api::{CommitAssetBatchOperationInput, RequestOperationInput};
 use std::path::Path;
 
 impl DfxOrbit {
@@ -38,23 +37,18 @@ impl DfxOrbit {
         Ok((batch_id, evidence))
     }
 
+    /// Requests the station to commit the proposed batch, which the asset canister validates
+    /// against the evidence when the request is created.
     pub fn commit_batch_input(
         canister_id: Principal,
         batch_id: Nat,
         evidence: ByteBuf,
     ) -> anyhow::Result<RequestOperationInput> {
-        let args = CommitProposedBatchArguments { batch_id, evidence };
-        let arg = candid::encode_one(args)?;
-
-        Ok(RequestOperationInput::CallExternalCanister(
-            CallExternalCanisterOperationInput {
-                validation_method: None,
-                execution_method: CanisterMethodDTO {
-                    canister_id,
-                    method_name: String::from("commit_proposed_batch"),
-                },
-                arg: Some(arg),
-                execution_method_cycles: None,
+        Ok(RequestOperationInput::CommitAssetBatch(
+            CommitAssetBatchOperationInput {
+                canister_id,
+                batch_id,
+                evidence: evidence.into_vec(),
             },
         ))
     }
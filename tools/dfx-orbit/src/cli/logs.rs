@@ -0,0 +1,30 @@
+use crate::{args::LogsArgs, DfxOrbit};
+use station_api::{CanisterLogRecordDTO, FetchExternalCanisterLogsInput};
+
+impl DfxOrbit {
+    pub(crate) async fn exec_logs(&self, args: LogsArgs) -> anyhow::Result<()> {
+        let canister_id = self.canister_id(&args.canister)?;
+        let response = self
+            .station
+            .fetch_external_canister_logs(FetchExternalCanisterLogsInput {
+                canister_id,
+                retain: Some(args.retain),
+            })
+            .await?;
+
+        if args.json {
+            println!("{}", serde_json::to_string_pretty(&response)?);
+        } else {
+            for record in response.logs.iter() {
+                println!("{}", display_log_record(record));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays a log record in the same format as `dfx canister logs`.
+fn display_log_record(record: &CanisterLogRecordDTO) -> String {
+    format!("[{}. {}]: {}", record.idx, record.timestamp, record.content)
+}
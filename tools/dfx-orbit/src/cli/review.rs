@@ -1,7 +1,9 @@
 mod display;
+mod tui;
 
 use crate::{
     args::review::{ReviewActionArgs, ReviewArgs},
+    locale::Message,
     DfxOrbit,
 };
 use serde::Serialize;
@@ -46,14 +48,17 @@ impl DfxOrbit {
                 }
 
                 if let RequestStatusDTO::Created = request.request.status {
-                    if let Ok(submit) = SubmitRequestApprovalInput::try_from(args) {
-                        let action = match submit.decision {
-                            RequestApprovalStatusDTO::Approved => "approve",
-                            RequestApprovalStatusDTO::Rejected => "reject",
+                    let acknowledge_checklist = args.acknowledge_checklist;
+                    if let Ok(mut submit) = SubmitRequestApprovalInput::try_from(args) {
+                        if acknowledge_checklist {
+                            submit.acknowledged_checklist =
+                                Some(request.additional_info.approval_checklist.clone());
+                        }
+                        let prompt = match submit.decision {
+                            RequestApprovalStatusDTO::Approved => Message::ConfirmApproveRequest,
+                            RequestApprovalStatusDTO::Rejected => Message::ConfirmRejectRequest,
                         };
-                        dfx_core::cli::ask_for_consent(&format!(
-                            "Would you like to {action} this request?"
-                        ))?;
+                        dfx_core::cli::ask_for_consent(self.text(prompt))?;
                         self.station.submit(submit).await?;
                         info!(self.logger, "Submitted response");
                     };
@@ -66,6 +71,7 @@ impl DfxOrbit {
 
                 Ok(())
             }
+            ReviewActionArgs::Tui(args) => self.exec_review_tui(args.into()).await,
         }
     }
 }
@@ -1,9 +1,14 @@
-use crate::DfxOrbit;
+use crate::{locale::Message, DfxOrbit};
 use candid::Principal;
 use itertools::Itertools;
 use station_api::{
-    CallExternalCanisterOperationDTO, CanisterInstallMode, ChangeExternalCanisterOperationDTO,
-    GetRequestResponse, ListRequestsResponse, RequestOperationDTO, RequestStatusDTO,
+    AllowDTO, BulkEditPermissionOperationDTO, CallExternalCanisterOperationDTO,
+    CanisterInstallMode, CanisterUpgradeDiffDTO, ChangeExternalCanisterOperationDTO,
+    CommitAssetBatchOperationDTO, ConfigureExternalCanisterOperationDTO,
+    ConfigureExternalCanisterOperationKindDTO, GetRequestResponse, ListRequestsResponse,
+    LogVisibilityDTO, NatSettingChangeDTO, NativeSettingsChangeDTO, RequestOperationDTO,
+    RequestStatusDTO, SnapshotExternalCanisterOperationDTO,
+    SnapshotExternalCanisterOperationKindDTO,
 };
 use std::{collections::HashMap, fmt::Write};
 use tabled::{
@@ -34,11 +39,11 @@ impl DfxOrbit {
             ]
         });
         let titled_iter = std::iter::once([
-            String::from("ID"),
-            String::from("Requested by"),
-            String::from("Title"),
-            String::from("Operation"),
-            String::from("Execution Status"),
+            String::from(self.text(Message::Id)),
+            String::from(self.text(Message::RequestedBy)),
+            String::from(self.text(Message::Title)),
+            String::from(self.text(Message::Operation)),
+            String::from(self.text(Message::ExecutionStatus)),
         ])
         .chain(data_iter);
 
@@ -58,30 +63,50 @@ impl DfxOrbit {
         let mut output = String::new();
 
         // General request information
-        writeln!(output, "=== REQUEST ===")?;
-        writeln!(output, "ID: {}", base_info.id)?;
+        writeln!(output, "{}", self.text(Message::RequestHeader))?;
+        writeln!(output, "{}: {}", self.text(Message::Id), base_info.id)?;
         writeln!(
             output,
-            "Operation: {}",
+            "{}: {}",
+            self.text(Message::Operation),
             self.display_request_operation(&base_info.operation)
         )?;
-        writeln!(output, "Title: {}", base_info.title)?;
+        writeln!(output, "{}: {}", self.text(Message::Title), base_info.title)?;
         if let Some(summary) = base_info.summary {
-            writeln!(output, "Summary: {}", summary)?
+            writeln!(output, "{}: {}", self.text(Message::Summary), summary)?
         }
-        writeln!(output, "Requested by: {}", add_info.requester_name)?;
         writeln!(
             output,
-            "Approved by: {}",
+            "{}: {}",
+            self.text(Message::RequestedBy),
+            add_info.requester_name
+        )?;
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::ApprovedBy),
             add_info
                 .approvers
                 .into_iter()
                 .map(|approver| format!("\n\t{}", approver.name))
                 .join("")
         )?;
+        if !add_info.approval_checklist.is_empty() {
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::ApprovalChecklist),
+                add_info
+                    .approval_checklist
+                    .iter()
+                    .map(|item| format!("\n\t[ ] {}", item))
+                    .join("")
+            )?;
+        }
         writeln!(
             output,
-            "Status: {}",
+            "{}: {}",
+            self.text(Message::Status),
             self.display_request_status(&base_info.status)
         )?;
         if let Some(additional_status) = self.display_additional_stats_info(&base_info.status) {
@@ -92,11 +117,27 @@ impl DfxOrbit {
             RequestOperationDTO::ChangeExternalCanister(op) => {
                 self.display_change_canister_operation(&mut output, op.as_ref())?;
             }
+            RequestOperationDTO::SystemUpgrade(op) => {
+                if let Some(diff) = &op.upgrade_diff {
+                    self.display_upgrade_diff(&mut output, &op.module_checksum, diff)?;
+                }
+            }
             RequestOperationDTO::CallExternalCanister(op) => {
                 self.display_call_canister_operation(&mut output, op.as_ref())?;
             }
+            RequestOperationDTO::CommitAssetBatch(op) => {
+                self.display_commit_asset_batch_operation(&mut output, op.as_ref())?;
+            }
+            RequestOperationDTO::ConfigureExternalCanister(op) => {
+                self.display_configure_canister_operation(&mut output, op.as_ref())?;
+            }
+            RequestOperationDTO::SnapshotExternalCanister(op) => {
+                self.display_snapshot_canister_operation(&mut output, op.as_ref())?;
+            }
+            RequestOperationDTO::BulkEditPermission(op) => {
+                self.display_bulk_edit_permission_operation(&mut output, op.as_ref())?;
+            }
             // TODO: CreateCanister Additional information
-            // TODO: ConfigureCanister Additional information
             _ => (),
         };
 
@@ -108,67 +149,441 @@ impl DfxOrbit {
         output: &mut String,
         op: &ChangeExternalCanisterOperationDTO,
     ) -> anyhow::Result<()> {
-        writeln!(output, "=== Change External Canister ===")?;
         writeln!(
             output,
-            "Target: {}",
+            "{}",
+            self.text(Message::ChangeExternalCanisterHeader)
+        )?;
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::Target),
             self.try_reverse_lookup(&op.canister_id)
         )?;
 
         let mode = match op.mode {
-            CanisterInstallMode::Install => "Install",
-            CanisterInstallMode::Reinstall => "Reinstall",
-            CanisterInstallMode::Upgrade => "Upgrade",
+            CanisterInstallMode::Install => Message::ModeInstall,
+            CanisterInstallMode::Reinstall => Message::ModeReinstall,
+            CanisterInstallMode::Upgrade => Message::ModeUpgrade,
         };
-        writeln!(output, "Mode: {}", mode)?;
+        writeln!(output, "{}: {}", self.text(Message::Mode), self.text(mode))?;
 
-        writeln!(output, "Module checksum: {}", &op.module_checksum)?;
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::ModuleChecksum),
+            &op.module_checksum
+        )?;
         if let Some(arg_checksum) = &op.arg_checksum {
-            writeln!(output, "Argument checksum: {}", arg_checksum)?;
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::ArgumentChecksum),
+                arg_checksum
+            )?;
+        }
+        if let Some(diff) = &op.upgrade_diff {
+            self.display_upgrade_diff(output, &op.module_checksum, diff)?;
         }
         Ok(())
     }
 
+    /// Compares the proposed module with the state of the canister when the request was created.
+    fn display_upgrade_diff(
+        &self,
+        output: &mut String,
+        module_checksum: &str,
+        diff: &CanisterUpgradeDiffDTO,
+    ) -> anyhow::Result<()> {
+        writeln!(output, "{}", self.text(Message::UpgradeDiffHeader))?;
+
+        match &diff.current_state {
+            Some(state) => {
+                match &state.module_hash {
+                    Some(installed) if installed.eq_ignore_ascii_case(module_checksum) => {
+                        writeln!(
+                            output,
+                            "  {} ({})",
+                            module_checksum,
+                            self.text(Message::ModuleHashUnchanged)
+                        )?;
+                    }
+                    installed => {
+                        writeln!(
+                            output,
+                            "- {} ({})",
+                            installed.as_deref().unwrap_or("-"),
+                            self.text(Message::ModuleHashInstalled)
+                        )?;
+                        writeln!(
+                            output,
+                            "+ {} ({})",
+                            module_checksum,
+                            self.text(Message::ModuleHashProposed)
+                        )?;
+                    }
+                }
+
+                match &state.settings {
+                    Some(settings) => {
+                        writeln!(output, "{}:", self.text(Message::CurrentSettings))?;
+                        writeln!(
+                            output,
+                            "\t{}: {}",
+                            self.text(Message::Controllers),
+                            settings
+                                .controllers
+                                .iter()
+                                .map(|controller| self.try_reverse_lookup(controller))
+                                .join(", ")
+                        )?;
+                        for (message, value) in [
+                            (Message::ComputeAllocation, &settings.compute_allocation),
+                            (Message::MemoryAllocation, &settings.memory_allocation),
+                            (Message::FreezingThreshold, &settings.freezing_threshold),
+                            (
+                                Message::ReservedCyclesLimit,
+                                &settings.reserved_cycles_limit,
+                            ),
+                        ] {
+                            writeln!(output, "\t{}: {}", self.text(message), value)?;
+                        }
+                        if let Some(log_visibility) = &settings.log_visibility {
+                            writeln!(
+                                output,
+                                "\t{}: {}",
+                                self.text(Message::LogVisibility),
+                                display_log_visibility(log_visibility)
+                            )?;
+                        }
+                    }
+                    None => writeln!(output, "{}", self.text(Message::SettingsUnavailable))?,
+                }
+            }
+            None => writeln!(output, "{}", self.text(Message::ModuleHashUnavailable))?,
+        }
+
+        if let Some(module_size) = diff.module_size {
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::ModuleSize),
+                module_size
+            )?;
+        }
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::ArgumentEmpty),
+            self.text(if diff.arg_empty {
+                Message::Yes
+            } else {
+                Message::No
+            })
+        )?;
+
+        Ok(())
+    }
+
     fn display_call_canister_operation(
         &self,
         output: &mut String,
         op: &CallExternalCanisterOperationDTO,
     ) -> anyhow::Result<()> {
-        writeln!(output, "=== Call External Canister ===")?;
+        writeln!(output, "{}", self.text(Message::CallExternalCanisterHeader))?;
         writeln!(
             output,
-            "Execution method: \"{}\" of {}",
+            "{}: \"{}\" {} {}",
+            self.text(Message::ExecutionMethod),
             op.execution_method.method_name,
+            self.text(Message::MethodOf),
             self.try_reverse_lookup(&op.execution_method.canister_id)
         )?;
         if let Some(validation_method) = &op.validation_method {
             writeln!(
                 output,
-                "Validation method: \"{}\" of {}",
+                "{}: \"{}\" {} {}",
+                self.text(Message::ValidationMethod),
                 validation_method.method_name,
+                self.text(Message::MethodOf),
                 self.try_reverse_lookup(&validation_method.canister_id)
             )?
         }
         if let Some(checksum) = &op.arg_checksum {
-            writeln!(output, "Argument checksum: {}", checksum)?
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::ArgumentChecksum),
+                checksum
+            )?
         }
         if let Some(args) = &op.arg_rendering {
-            writeln!(output, "Argument: {}", args)?
+            writeln!(output, "{}: {}", self.text(Message::Argument), args)?
         }
         if let Some(cycles) = &op.execution_method_cycles {
-            writeln!(output, "Execution method cycles: {}", cycles)?
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::ExecutionMethodCycles),
+                cycles
+            )?
         }
         if let Some(reply) = &op.execution_method_reply {
             match candid_parser::IDLArgs::from_bytes(reply) {
                 // TODO: Check if we can get the type information from somewhere to annotate this with types
-                Ok(response) => writeln!(output, "Execution response: {}", response),
-                Err(_) => writeln!(output, "FAILED TO PARSE EXECUTION RESPONSE"),
+                Ok(response) => writeln!(
+                    output,
+                    "{}: {}",
+                    self.text(Message::ExecutionResponse),
+                    response
+                ),
+                Err(_) => writeln!(
+                    output,
+                    "{}",
+                    self.text(Message::FailedToParseExecutionResponse)
+                ),
             }?;
         }
 
         Ok(())
     }
 
+    fn display_commit_asset_batch_operation(
+        &self,
+        output: &mut String,
+        op: &CommitAssetBatchOperationDTO,
+    ) -> anyhow::Result<()> {
+        writeln!(output, "{}", self.text(Message::CommitAssetBatchHeader))?;
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::Target),
+            self.try_reverse_lookup(&op.canister_id)
+        )?;
+        writeln!(output, "{}: {}", self.text(Message::BatchId), op.batch_id)?;
+        writeln!(output, "{}: {}", self.text(Message::Evidence), op.evidence)?;
+        if let Some(rendering) = &op.evidence_rendering {
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::EvidenceRendering),
+                rendering
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn display_configure_canister_operation(
+        &self,
+        output: &mut String,
+        op: &ConfigureExternalCanisterOperationDTO,
+    ) -> anyhow::Result<()> {
+        writeln!(
+            output,
+            "{}",
+            self.text(Message::ConfigureExternalCanisterHeader)
+        )?;
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::Target),
+            self.try_reverse_lookup(&op.canister_id)
+        )?;
+
+        let ConfigureExternalCanisterOperationKindDTO::NativeSettings(settings) = &op.kind else {
+            return Ok(());
+        };
+        writeln!(output, "{}:", self.text(Message::SettingsChanges))?;
+
+        // Without a diff, e.g. if the station could not read the settings, only the proposed settings are shown
+        let Some(diff) = &op.native_settings_diff else {
+            writeln!(output, "\t{}", self.text(Message::SettingsDiffUnavailable))?;
+            if let Some(controllers) = &settings.controllers {
+                writeln!(
+                    output,
+                    "\t{}: {}",
+                    self.text(Message::Controllers),
+                    controllers.iter().join(", ")
+                )?;
+            }
+            for (message, value) in [
+                (Message::ComputeAllocation, &settings.compute_allocation),
+                (Message::MemoryAllocation, &settings.memory_allocation),
+                (Message::FreezingThreshold, &settings.freezing_threshold),
+                (
+                    Message::ReservedCyclesLimit,
+                    &settings.reserved_cycles_limit,
+                ),
+            ] {
+                if let Some(value) = value {
+                    writeln!(output, "\t{}: {}", self.text(message), value)?;
+                }
+            }
+            if let Some(log_visibility) = &settings.log_visibility {
+                writeln!(
+                    output,
+                    "\t{}: {}",
+                    self.text(Message::LogVisibility),
+                    display_log_visibility(log_visibility)
+                )?;
+            }
+            return Ok(());
+        };
+
+        for change in diff {
+            match change {
+                NativeSettingsChangeDTO::Controllers(change) => {
+                    for controller in &change.added {
+                        writeln!(
+                            output,
+                            "\t{}: {}",
+                            self.text(Message::ControllerAdded),
+                            self.try_reverse_lookup(controller)
+                        )?;
+                    }
+                    for controller in &change.removed {
+                        writeln!(
+                            output,
+                            "\t{}: {}",
+                            self.text(Message::ControllerRemoved),
+                            self.try_reverse_lookup(controller)
+                        )?;
+                    }
+                }
+                NativeSettingsChangeDTO::ComputeAllocation(change) => {
+                    self.display_nat_setting_change(output, Message::ComputeAllocation, change)?
+                }
+                NativeSettingsChangeDTO::MemoryAllocation(change) => {
+                    self.display_nat_setting_change(output, Message::MemoryAllocation, change)?
+                }
+                NativeSettingsChangeDTO::FreezingThreshold(change) => {
+                    self.display_nat_setting_change(output, Message::FreezingThreshold, change)?
+                }
+                NativeSettingsChangeDTO::ReservedCyclesLimit(change) => {
+                    self.display_nat_setting_change(output, Message::ReservedCyclesLimit, change)?
+                }
+                NativeSettingsChangeDTO::LogVisibility(change) => {
+                    writeln!(
+                        output,
+                        "\t{}: {} -> {}",
+                        self.text(Message::LogVisibility),
+                        change
+                            .current
+                            .as_ref()
+                            .map(display_log_visibility)
+                            .unwrap_or_else(|| String::from("-")),
+                        display_log_visibility(&change.proposed)
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn display_snapshot_canister_operation(
+        &self,
+        output: &mut String,
+        op: &SnapshotExternalCanisterOperationDTO,
+    ) -> anyhow::Result<()> {
+        writeln!(
+            output,
+            "{}",
+            self.text(Message::SnapshotExternalCanisterHeader)
+        )?;
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::Target),
+            self.try_reverse_lookup(&op.canister_id)
+        )?;
+
+        let (action, snapshot_id) = match &op.kind {
+            SnapshotExternalCanisterOperationKindDTO::Take { replace_snapshot } => {
+                (Message::SnapshotTake, replace_snapshot.as_ref())
+            }
+            SnapshotExternalCanisterOperationKindDTO::Load { snapshot_id } => {
+                (Message::SnapshotLoad, Some(snapshot_id))
+            }
+            SnapshotExternalCanisterOperationKindDTO::Delete { snapshot_id } => {
+                (Message::SnapshotDelete, Some(snapshot_id))
+            }
+        };
+        writeln!(
+            output,
+            "{}: {}",
+            self.text(Message::Mode),
+            self.text(action)
+        )?;
+        if let Some(snapshot_id) = snapshot_id {
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::SnapshotId),
+                snapshot_id
+            )?;
+        }
+        if let Some(taken_snapshot_id) = &op.snapshot_id {
+            writeln!(
+                output,
+                "{}: {}",
+                self.text(Message::SnapshotTaken),
+                taken_snapshot_id
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn display_bulk_edit_permission_operation(
+        &self,
+        output: &mut String,
+        op: &BulkEditPermissionOperationDTO,
+    ) -> anyhow::Result<()> {
+        writeln!(output, "{}", self.text(Message::BulkEditPermissionHeader))?;
+
+        let rows = op.matrix.iter().map(|change| {
+            [
+                format!("{:?}", change.resource),
+                display_allow(&change.before),
+                display_allow(&change.after),
+            ]
+        });
+        let titled_rows = std::iter::once([
+            String::from(self.text(Message::PermissionResource)),
+            String::from(self.text(Message::PermissionBefore)),
+            String::from(self.text(Message::PermissionAfter)),
+        ])
+        .chain(rows);
+
+        let table_config = Settings::default().with(Style::psql());
+        writeln!(
+            output,
+            "{}",
+            Table::from_iter(titled_rows).with(table_config)
+        )?;
+
+        Ok(())
+    }
+
+    fn display_nat_setting_change(
+        &self,
+        output: &mut String,
+        message: Message,
+        change: &NatSettingChangeDTO,
+    ) -> anyhow::Result<()> {
+        writeln!(
+            output,
+            "\t{}: {} -> {}",
+            self.text(message),
+            change.current,
+            change.proposed
+        )?;
+
+        Ok(())
+    }
+
     fn try_reverse_lookup(&self, canister_id: &Principal) -> String {
         match self.canister_name(canister_id).ok() {
             Some(canister_name) => {
@@ -178,56 +593,104 @@ impl DfxOrbit {
         }
     }
 
-    fn display_request_operation(&self, op: &RequestOperationDTO) -> &'static str {
-        match op {
-            RequestOperationDTO::Transfer(_) => "Transfer",
-            RequestOperationDTO::AddAccount(_) => "AddAccount",
-            RequestOperationDTO::EditAccount(_) => "EditAccount",
-            RequestOperationDTO::AddAddressBookEntry(_) => "AddAddressBookEntry",
-            RequestOperationDTO::EditAddressBookEntry(_) => "EditAddressBookEntry",
-            RequestOperationDTO::RemoveAddressBookEntry(_) => "RemoveAddressBookEntry",
-            RequestOperationDTO::AddUser(_) => "AddUser",
-            RequestOperationDTO::EditUser(_) => "EditUser",
-            RequestOperationDTO::AddUserGroup(_) => "AddUserGroup",
-            RequestOperationDTO::EditUserGroup(_) => "EditUserGroup",
-            RequestOperationDTO::RemoveUserGroup(_) => "RemoveUserGroup",
-            RequestOperationDTO::SystemUpgrade(_) => "SystemUpgrade",
-            RequestOperationDTO::SetDisasterRecovery(_) => "SetDisasterRecovery",
-            RequestOperationDTO::ChangeExternalCanister(_) => "ChangeExternalCanister",
-            RequestOperationDTO::CreateExternalCanister(_) => "CreateExternalCanister",
-            RequestOperationDTO::ConfigureExternalCanister(_) => "ConfigureExternalCanister",
-            RequestOperationDTO::CallExternalCanister(_) => "CallExternalCanister",
-            RequestOperationDTO::FundExternalCanister(_) => "FundExternalCanister",
-            RequestOperationDTO::EditPermission(_) => "EditPermission",
-            RequestOperationDTO::AddRequestPolicy(_) => "AddRequestPolicy",
-            RequestOperationDTO::EditRequestPolicy(_) => "EditRequestPolicy",
-            RequestOperationDTO::RemoveRequestPolicy(_) => "RemoveRequestPolicy",
-            RequestOperationDTO::ManageSystemInfo(_) => "ManageSystemInfo",
-        }
+    pub(super) fn display_request_operation(&self, op: &RequestOperationDTO) -> &'static str {
+        let message = match op {
+            RequestOperationDTO::Transfer(_) => Message::OperationTransfer,
+            RequestOperationDTO::AddAccount(_) => Message::OperationAddAccount,
+            RequestOperationDTO::EditAccount(_) => Message::OperationEditAccount,
+            RequestOperationDTO::RotateAccountAddress(_) => Message::OperationRotateAccountAddress,
+            RequestOperationDTO::TransferAccountOwnership(_) => {
+                Message::OperationTransferAccountOwnership
+            }
+            RequestOperationDTO::AddAddressBookEntry(_) => Message::OperationAddAddressBookEntry,
+            RequestOperationDTO::EditAddressBookEntry(_) => Message::OperationEditAddressBookEntry,
+            RequestOperationDTO::RemoveAddressBookEntry(_) => {
+                Message::OperationRemoveAddressBookEntry
+            }
+            RequestOperationDTO::AddUser(_) => Message::OperationAddUser,
+            RequestOperationDTO::EditUser(_) => Message::OperationEditUser,
+            RequestOperationDTO::AddUserGroup(_) => Message::OperationAddUserGroup,
+            RequestOperationDTO::EditUserGroup(_) => Message::OperationEditUserGroup,
+            RequestOperationDTO::RemoveUserGroup(_) => Message::OperationRemoveUserGroup,
+            RequestOperationDTO::SystemUpgrade(_) => Message::OperationSystemUpgrade,
+            RequestOperationDTO::SetDisasterRecovery(_) => Message::OperationSetDisasterRecovery,
+            RequestOperationDTO::ChangeExternalCanister(_) => {
+                Message::OperationChangeExternalCanister
+            }
+            RequestOperationDTO::CreateExternalCanister(_) => {
+                Message::OperationCreateExternalCanister
+            }
+            RequestOperationDTO::ConfigureExternalCanister(_) => {
+                Message::OperationConfigureExternalCanister
+            }
+            RequestOperationDTO::CallExternalCanister(_) => Message::OperationCallExternalCanister,
+            RequestOperationDTO::FundExternalCanister(_) => Message::OperationFundExternalCanister,
+            RequestOperationDTO::CommitAssetBatch(_) => Message::OperationCommitAssetBatch,
+            RequestOperationDTO::SnapshotExternalCanister(_) => {
+                Message::OperationSnapshotExternalCanister
+            }
+            RequestOperationDTO::EditPermission(_) => Message::OperationEditPermission,
+            RequestOperationDTO::BulkEditPermission(_) => Message::OperationBulkEditPermission,
+            RequestOperationDTO::AddRequestPolicy(_) => Message::OperationAddRequestPolicy,
+            RequestOperationDTO::EditRequestPolicy(_) => Message::OperationEditRequestPolicy,
+            RequestOperationDTO::RemoveRequestPolicy(_) => Message::OperationRemoveRequestPolicy,
+            RequestOperationDTO::ManageSystemInfo(_) => Message::OperationManageSystemInfo,
+            RequestOperationDTO::AddNetwork(_) => Message::OperationAddNetwork,
+            RequestOperationDTO::EditNetwork(_) => Message::OperationEditNetwork,
+            RequestOperationDTO::FreezeBlockchain(_) => Message::OperationFreezeBlockchain,
+            RequestOperationDTO::UnfreezeBlockchain(_) => Message::OperationUnfreezeBlockchain,
+            RequestOperationDTO::DecommissionStation(_) => Message::OperationDecommissionStation,
+        };
+
+        self.text(message)
     }
 
     fn display_request_status(&self, status: &RequestStatusDTO) -> &'static str {
-        match status {
-            RequestStatusDTO::Created => "Created",
-            RequestStatusDTO::Approved => "Approved",
-            RequestStatusDTO::Rejected => "Rejected",
-            RequestStatusDTO::Cancelled { .. } => "Cancelled",
-            RequestStatusDTO::Scheduled { .. } => "Scheduled",
-            RequestStatusDTO::Processing { .. } => "Processing",
-            RequestStatusDTO::Completed { .. } => "Completed",
-            RequestStatusDTO::Failed { .. } => "Failed",
-        }
+        let message = match status {
+            RequestStatusDTO::Created => Message::StatusCreated,
+            RequestStatusDTO::Approved => Message::StatusApproved,
+            RequestStatusDTO::Rejected => Message::StatusRejected,
+            RequestStatusDTO::Cancelled { .. } => Message::StatusCancelled,
+            RequestStatusDTO::Scheduled { .. } => Message::StatusScheduled,
+            RequestStatusDTO::Processing { .. } => Message::StatusProcessing,
+            RequestStatusDTO::Completed { .. } => Message::StatusCompleted,
+            RequestStatusDTO::Failed { .. } => Message::StatusFailed,
+        };
+
+        self.text(message)
     }
 
     fn display_additional_stats_info(&self, status: &RequestStatusDTO) -> Option<String> {
         match status {
-            RequestStatusDTO::Cancelled { reason } => {
-                reason.clone().map(|reason| format!("Reason: {}", reason))
-            }
-            RequestStatusDTO::Failed { reason } => {
-                reason.clone().map(|reason| format!("Reason: {}", reason))
-            }
+            RequestStatusDTO::Cancelled { reason } => reason
+                .clone()
+                .map(|reason| format!("{}: {}", self.text(Message::Reason), reason)),
+            RequestStatusDTO::Failed { reason } => reason
+                .clone()
+                .map(|reason| format!("{}: {}", self.text(Message::Reason), reason)),
             _ => None,
         }
     }
 }
+
+fn display_allow(allow: &AllowDTO) -> String {
+    let mut allowed = vec![format!("{:?}", allow.auth_scope)];
+    if !allow.users.is_empty() {
+        allowed.push(format!("users: {}", allow.users.join(", ")));
+    }
+    if !allow.user_groups.is_empty() {
+        allowed.push(format!("groups: {}", allow.user_groups.join(", ")));
+    }
+
+    allowed.join("\n")
+}
+
+fn display_log_visibility(log_visibility: &LogVisibilityDTO) -> String {
+    match log_visibility {
+        LogVisibilityDTO::Controllers => String::from("controllers"),
+        LogVisibilityDTO::Public => String::from("public"),
+        LogVisibilityDTO::AllowedViewers(viewers) => {
+            format!("allowed viewers ({})", viewers.iter().join(", "))
+        }
+    }
+}
@@ -0,0 +1,399 @@
+//! Interactive review of the pending requests with a keyboard driven terminal UI.
+
+use crate::{locale::Message, DfxOrbit};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use station_api::{
+    GetRequestInput, ListRequestsInput, RequestApprovalStatusDTO, RequestDTO, RequestOperationDTO,
+    SubmitRequestApprovalInput, SystemUpgradeTargetDTO, STATION_API_VERSION,
+};
+use std::{
+    collections::HashMap,
+    io::{self, Stdout},
+};
+
+type ReviewTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// The number of lines that the details are scrolled by with PgUp/PgDn.
+const SCROLL_STEP: u16 = 10;
+
+#[derive(Default)]
+struct ReviewTuiState {
+    requests: Vec<RequestDTO>,
+    /// The rendered details of the requests that were already fetched, by request id.
+    details: HashMap<String, Vec<Line<'static>>>,
+    list_state: ListState,
+    scroll: u16,
+    /// The decision that waits for the confirmation of the reviewer.
+    pending_decision: Option<RequestApprovalStatusDTO>,
+    /// The outcome of the last action, shown in place of the key help.
+    status: Option<String>,
+}
+
+impl ReviewTuiState {
+    fn selected_request(&self) -> Option<&RequestDTO> {
+        self.list_state
+            .selected()
+            .and_then(|index| self.requests.get(index))
+    }
+
+    fn select(&mut self, index: usize) {
+        let index = (!self.requests.is_empty()).then(|| index.min(self.requests.len() - 1));
+        self.list_state.select(index);
+        self.scroll = 0;
+    }
+
+    fn select_next(&mut self) {
+        let next = self.list_state.selected().map_or(0, |index| index + 1);
+        self.select(next);
+    }
+
+    fn select_previous(&mut self) {
+        let previous = self
+            .list_state
+            .selected()
+            .map_or(0, |index| index.saturating_sub(1));
+        self.select(previous);
+    }
+}
+
+impl DfxOrbit {
+    pub(crate) async fn exec_review_tui(&self, input: ListRequestsInput) -> anyhow::Result<()> {
+        let mut terminal = init_terminal()?;
+        let result = self.run_review_tui(&mut terminal, input).await;
+        restore_terminal()?;
+
+        result
+    }
+
+    async fn run_review_tui(
+        &self,
+        terminal: &mut ReviewTerminal,
+        input: ListRequestsInput,
+    ) -> anyhow::Result<()> {
+        let mut state = ReviewTuiState {
+            requests: self.station.review_list(input.clone()).await?.requests,
+            ..Default::default()
+        };
+        state.select(0);
+
+        loop {
+            if let Some(request_id) = state.selected_request().map(|request| request.id.clone()) {
+                if !state.details.contains_key(&request_id) {
+                    terminal.draw(|frame| self.draw_review_tui(frame, &mut state))?;
+                    let details = self.review_details(&request_id).await;
+                    state.details.insert(request_id, details);
+                }
+            }
+
+            terminal.draw(|frame| self.draw_review_tui(frame, &mut state))?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            if let Some(decision) = state.pending_decision.take() {
+                if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                    self.submit_review_decision(&mut state, decision).await;
+                }
+                continue;
+            }
+
+            state.status = None;
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                KeyCode::PageDown => state.scroll = state.scroll.saturating_add(SCROLL_STEP),
+                KeyCode::PageUp => state.scroll = state.scroll.saturating_sub(SCROLL_STEP),
+                KeyCode::Char('a') if state.selected_request().is_some() => {
+                    state.pending_decision = Some(RequestApprovalStatusDTO::Approved);
+                }
+                KeyCode::Char('x') if state.selected_request().is_some() => {
+                    state.pending_decision = Some(RequestApprovalStatusDTO::Rejected);
+                }
+                KeyCode::Char('r') => match self.station.review_list(input.clone()).await {
+                    Ok(response) => {
+                        let selected = state.list_state.selected().unwrap_or_default();
+                        state.requests = response.requests;
+                        state.details.clear();
+                        state.select(selected);
+                    }
+                    Err(err) => state.status = Some(err.to_string()),
+                },
+                _ => {}
+            }
+        }
+    }
+
+    async fn submit_review_decision(
+        &self,
+        state: &mut ReviewTuiState,
+        decision: RequestApprovalStatusDTO,
+    ) {
+        let Some(index) = state.list_state.selected() else {
+            return;
+        };
+        let request_id = state.requests[index].id.clone();
+        let outcome = match decision {
+            RequestApprovalStatusDTO::Approved => Message::TuiApproved,
+            RequestApprovalStatusDTO::Rejected => Message::TuiRejected,
+        };
+
+        let result = self
+            .station
+            .submit(SubmitRequestApprovalInput {
+                decision,
+                request_id: request_id.clone(),
+                reason: None,
+                acknowledged_checklist: None,
+            })
+            .await;
+
+        match result {
+            Ok(_) => {
+                state.requests.remove(index);
+                state.details.remove(&request_id);
+                state.select(index);
+                state.status = Some(format!("{}: {}", self.text(outcome), request_id));
+            }
+            Err(err) => state.status = Some(err.to_string()),
+        }
+    }
+
+    /// Renders the details of the request, followed by the comparison of the module hashes and the
+    /// asset evidence that the operation relies on.
+    async fn review_details(&self, request_id: &str) -> Vec<Line<'static>> {
+        let response = match self
+            .station
+            .review_id(GetRequestInput {
+                request_id: request_id.to_string(),
+                api_version: Some(STATION_API_VERSION),
+            })
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => return vec![error_line(err.to_string())],
+        };
+        let operation = response.request.operation.clone();
+
+        let mut lines = match self.display_get_request_response(response) {
+            Ok(output) => output
+                .lines()
+                .map(|line| Line::from(line.to_string()))
+                .collect(),
+            Err(err) => vec![error_line(err.to_string())],
+        };
+        lines.extend(self.module_hash_lines(&operation).await);
+        lines.extend(self.asset_evidence_lines(request_id, &operation));
+
+        lines
+    }
+
+    /// Compares the module hash that is installed on the target canister with the proposed one.
+    async fn module_hash_lines(&self, operation: &RequestOperationDTO) -> Vec<Line<'static>> {
+        let (canister_id, proposed) = match operation {
+            RequestOperationDTO::ChangeExternalCanister(op) => {
+                (op.canister_id, &op.module_checksum)
+            }
+            RequestOperationDTO::SystemUpgrade(op)
+                if matches!(op.target, SystemUpgradeTargetDTO::UpgradeStation) =>
+            {
+                (self.station.config.station_id, &op.module_checksum)
+            }
+            _ => return vec![],
+        };
+
+        let mut lines = vec![Line::from(self.text(Message::ModuleHashHeader))];
+        let installed = self
+            .interface
+            .agent()
+            .read_state_canister_module_hash(canister_id)
+            .await
+            .map(hex::encode);
+
+        match installed {
+            Ok(installed) if installed.eq_ignore_ascii_case(proposed) => {
+                lines.push(Line::from(format!(
+                    "  {} ({})",
+                    proposed,
+                    self.text(Message::ModuleHashUnchanged)
+                )));
+            }
+            Ok(installed) => {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "- {} ({})",
+                        installed,
+                        self.text(Message::ModuleHashInstalled)
+                    ),
+                    Style::default().fg(Color::Red),
+                )));
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "+ {} ({})",
+                        proposed,
+                        self.text(Message::ModuleHashProposed)
+                    ),
+                    Style::default().fg(Color::Green),
+                )));
+            }
+            Err(_) => {
+                lines.push(Line::from(self.text(Message::ModuleHashUnavailable)));
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "+ {} ({})",
+                        proposed,
+                        self.text(Message::ModuleHashProposed)
+                    ),
+                    Style::default().fg(Color::Green),
+                )));
+            }
+        }
+
+        lines
+    }
+
+    /// Shows the evidence of an asset upload and how to verify it against the local files.
+    fn asset_evidence_lines(
+        &self,
+        request_id: &str,
+        operation: &RequestOperationDTO,
+    ) -> Vec<Line<'static>> {
+        let RequestOperationDTO::CommitAssetBatch(op) = operation else {
+            return vec![];
+        };
+
+        let canister = self
+            .canister_name(&op.canister_id)
+            .unwrap_or_else(|_| op.canister_id.to_string());
+
+        vec![
+            Line::from(self.text(Message::AssetEvidenceHeader)),
+            Line::from(format!("{}: {}", self.text(Message::Evidence), op.evidence)),
+            Line::from(format!(
+                "{}: {}",
+                self.text(Message::EvidenceRendering),
+                op.evidence_rendering.as_deref().unwrap_or("-")
+            )),
+            Line::from(format!("{}:", self.text(Message::AssetEvidenceHint))),
+            Line::from(format!(
+                "  dfx-orbit verify {} asset upload {} {} <FILES>...",
+                request_id, canister, op.batch_id
+            )),
+        ]
+    }
+
+    fn draw_review_tui(&self, frame: &mut Frame, state: &mut ReviewTuiState) {
+        let [main_area, footer_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.size());
+        let [list_area, details_area] =
+            Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)])
+                .areas(main_area);
+
+        let list_block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.text(Message::TuiPendingRequests));
+        if state.requests.is_empty() {
+            frame.render_widget(
+                Paragraph::new(self.text(Message::TuiNoPendingRequests)).block(list_block),
+                list_area,
+            );
+        } else {
+            let items = state
+                .requests
+                .iter()
+                .map(|request| {
+                    ListItem::new(format!(
+                        "[{}] {}",
+                        self.display_request_operation(&request.operation),
+                        request.title
+                    ))
+                })
+                .collect::<Vec<_>>();
+            let list = List::new(items)
+                .block(list_block)
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            frame.render_stateful_widget(list, list_area, &mut state.list_state);
+        }
+
+        let details = state
+            .selected_request()
+            .map(|request| {
+                state
+                    .details
+                    .get(&request.id)
+                    .cloned()
+                    .unwrap_or_else(|| vec![Line::from(self.text(Message::TuiLoading))])
+            })
+            .unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(details)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(self.text(Message::TuiDetails)),
+                )
+                .wrap(Wrap { trim: false })
+                .scroll((state.scroll, 0)),
+            details_area,
+        );
+
+        let footer = match (&state.pending_decision, &state.status) {
+            (Some(RequestApprovalStatusDTO::Approved), _) => Line::from(Span::styled(
+                format!(
+                    "{} {}",
+                    self.text(Message::ConfirmApproveRequest),
+                    self.text(Message::TuiConfirmHint)
+                ),
+                Style::default().fg(Color::Green),
+            )),
+            (Some(RequestApprovalStatusDTO::Rejected), _) => Line::from(Span::styled(
+                format!(
+                    "{} {}",
+                    self.text(Message::ConfirmRejectRequest),
+                    self.text(Message::TuiConfirmHint)
+                ),
+                Style::default().fg(Color::Red),
+            )),
+            (None, Some(status)) => Line::from(status.clone()),
+            (None, None) => Line::from(Span::styled(
+                self.text(Message::TuiHelp),
+                Style::default().add_modifier(Modifier::DIM),
+            )),
+        };
+        frame.render_widget(Paragraph::new(footer), footer_area);
+    }
+}
+
+/// Switches the terminal to the alternate screen in raw mode.
+fn init_terminal() -> io::Result<ReviewTerminal> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+
+    Terminal::new(CrosstermBackend::new(io::stdout()))
+}
+
+/// Restores the terminal that was set up by [`init_terminal`].
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)
+}
+
+fn error_line(error: String) -> Line<'static> {
+    Line::from(Span::styled(error, Style::default().fg(Color::Red)))
+}
@@ -1,4 +1,6 @@
 //! Implements the dfx extension CLI commands for managing stations.
+mod diff;
+
 use anyhow::Context;
 
 use crate::{
@@ -61,6 +63,7 @@ pub fn exec(orbit_agent: OrbitExtensionAgent, args: StationArgs) -> anyhow::Resu
                 )
                 .with_context(|| "Failed to rename station in local dfx config")?;
         }
+        StationArgs::Diff(_) => unreachable!("The diff is made by the station agent"),
     }
     Ok(())
 }
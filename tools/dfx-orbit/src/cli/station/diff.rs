@@ -0,0 +1,320 @@
+//! Compares the canisters of the local dfx project with the ones registered in the station.
+
+use crate::{
+    args::{request::apply::RequestTemplates, station::StationDiffArgs},
+    DfxOrbit,
+};
+use anyhow::Context;
+use candid::Principal;
+use station_api::{
+    AllowDTO, AuthScopeDTO, ConfigureExternalCanisterOperationInput,
+    ConfigureExternalCanisterOperationKindDTO, ConfigureExternalCanisterSettingsInput,
+    CreateExternalCanisterOperationInput, CreateExternalCanisterOperationKindAddExistingDTO,
+    CreateExternalCanisterOperationKindDTO, CreateRequestInput, ExternalCanisterDTO,
+    ExternalCanisterPermissionsDTO, ExternalCanisterRequestPoliciesInput, ExternalCanisterStateDTO,
+    ListExternalCanistersInput, PaginationInput, RequestOperationInput, UuidDTO,
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::{self, Display, Formatter},
+};
+
+/// The number of external canisters that are fetched from the station at once.
+const LIST_EXTERNAL_CANISTERS_LIMIT: u16 = 100;
+
+/// The summary of the requests that reconcile the station with the local project.
+const RECONCILE_SUMMARY: &str = "Generated by `dfx-orbit station diff`.";
+
+/// A difference between the canisters of the local dfx project and the station.
+enum StationDiffEntry {
+    /// The canister is deployed, but not registered in the station.
+    NotRegistered {
+        name: String,
+        canister_id: Principal,
+    },
+    /// The canister is registered in the station under another name.
+    Renamed {
+        name: String,
+        canister_id: Principal,
+        registered_name: String,
+    },
+    /// The canister is archived in the station.
+    Archived {
+        name: String,
+        canister_id: Principal,
+    },
+    /// The caller is not permitted to request changes of the canister.
+    NotChangeable {
+        name: String,
+        canister_id: Principal,
+    },
+    /// The canister is registered in the station, but not defined in `dfx.json`.
+    NotInProject {
+        name: String,
+        canister_id: Principal,
+    },
+    /// The canister has no id on the network of the station.
+    NotDeployed { name: String },
+}
+
+impl Display for StationDiffEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StationDiffEntry::NotRegistered { name, canister_id } => {
+                write!(f, "+ {name} ({canister_id}): not registered in the station")
+            }
+            StationDiffEntry::Renamed {
+                name,
+                canister_id,
+                registered_name,
+            } => write!(
+                f,
+                "~ {name} ({canister_id}): registered as \"{registered_name}\""
+            ),
+            StationDiffEntry::Archived { name, canister_id } => {
+                write!(f, "~ {name} ({canister_id}): archived in the station")
+            }
+            StationDiffEntry::NotChangeable { name, canister_id } => write!(
+                f,
+                "~ {name} ({canister_id}): you are not permitted to request changes"
+            ),
+            StationDiffEntry::NotInProject { name, canister_id } => write!(
+                f,
+                "- {name} ({canister_id}): registered in the station, but not in \"dfx.json\""
+            ),
+            StationDiffEntry::NotDeployed { name } => {
+                write!(f, "? {name}: no canister id on the network of the station")
+            }
+        }
+    }
+}
+
+/// The differences between the local dfx project and the station, with the requests that
+/// reconcile them.
+#[derive(Default)]
+struct StationDiff {
+    entries: Vec<StationDiffEntry>,
+    requests: Vec<CreateRequestInput>,
+}
+
+impl Display for StationDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl DfxOrbit {
+    pub(crate) async fn exec_station_diff(&self, args: StationDiffArgs) -> anyhow::Result<()> {
+        let diff = self.station_diff().await?;
+
+        if diff.entries.is_empty() {
+            println!("The station is in sync with the local project");
+        } else {
+            // Note: The formatted diff is a sequence of complete lines.
+            print!("{diff}");
+        }
+
+        if let Some(output) = args.output {
+            if diff.requests.is_empty() {
+                println!("No requests are needed to reconcile the station");
+                return Ok(());
+            }
+
+            let yaml = RequestTemplates::to_yaml(diff.requests)?;
+            std::fs::write(&output, yaml)
+                .with_context(|| format!("Could not write {}", output.display()))?;
+            println!(
+                "To submit the reconciling requests, run: dfx-orbit request apply {}",
+                output.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn station_diff(&self) -> anyhow::Result<StationDiff> {
+        let config = self.get_config()?;
+        let local_canisters = config
+            .get_config()
+            .canisters
+            .as_ref()
+            .map(|canisters| canisters.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let (mut registered, changeable) = self.registered_canisters().await?;
+        let user_id = self.station.me().await?.me.id;
+
+        let mut diff = StationDiff::default();
+        for name in local_canisters {
+            let Ok(canister_id) = self.canister_id(&name) else {
+                diff.entries.push(StationDiffEntry::NotDeployed { name });
+                continue;
+            };
+
+            let Some(canister) = registered.remove(&canister_id) else {
+                diff.requests
+                    .push(register_canister_request(&name, canister_id, &user_id));
+                diff.entries
+                    .push(StationDiffEntry::NotRegistered { name, canister_id });
+                continue;
+            };
+
+            let mut settings = ConfigureExternalCanisterSettingsInput {
+                name: None,
+                description: None,
+                labels: None,
+                state: None,
+                permissions: None,
+                request_policies: None,
+                repository_url: None,
+            };
+
+            if canister.name != name {
+                settings.name = Some(name.clone());
+                diff.entries.push(StationDiffEntry::Renamed {
+                    name: name.clone(),
+                    canister_id,
+                    registered_name: canister.name.clone(),
+                });
+            }
+
+            if matches!(canister.state, ExternalCanisterStateDTO::Archived) {
+                settings.state = Some(ExternalCanisterStateDTO::Active);
+                diff.entries.push(StationDiffEntry::Archived {
+                    name: name.clone(),
+                    canister_id,
+                });
+            }
+
+            if !changeable.contains(&canister_id) {
+                let mut permissions = canister.permissions.clone();
+                permissions.change.users.push(user_id.clone());
+                settings.permissions = Some(permissions);
+                diff.entries.push(StationDiffEntry::NotChangeable {
+                    name: name.clone(),
+                    canister_id,
+                });
+            }
+
+            if settings.name.is_some() || settings.state.is_some() || settings.permissions.is_some()
+            {
+                diff.requests.push(CreateRequestInput {
+                    operation: RequestOperationInput::ConfigureExternalCanister(
+                        ConfigureExternalCanisterOperationInput {
+                            canister_id,
+                            kind: ConfigureExternalCanisterOperationKindDTO::Settings(settings),
+                        },
+                    ),
+                    title: Some(format!("Reconcile the settings of {name}")),
+                    summary: Some(RECONCILE_SUMMARY.to_string()),
+                    execution_plan: None,
+                    confidential: None,
+                    summary_format: None,
+                    depends_on: None,
+                });
+            }
+        }
+
+        // The canisters that are only known to the station are reported, but never removed.
+        for (canister_id, canister) in registered {
+            diff.entries.push(StationDiffEntry::NotInProject {
+                name: canister.name,
+                canister_id,
+            });
+        }
+
+        Ok(diff)
+    }
+
+    /// Lists the external canisters of the station that the caller can read, together with the
+    /// ids of the ones that the caller can request changes of.
+    async fn registered_canisters(
+        &self,
+    ) -> anyhow::Result<(BTreeMap<Principal, ExternalCanisterDTO>, HashSet<Principal>)> {
+        let mut canisters = BTreeMap::new();
+        let mut changeable = HashSet::new();
+        let mut offset = None;
+
+        loop {
+            let response = self
+                .station
+                .list_external_canisters(ListExternalCanistersInput {
+                    canister_ids: None,
+                    labels: None,
+                    states: None,
+                    paginate: Some(PaginationInput {
+                        offset,
+                        limit: Some(LIST_EXTERNAL_CANISTERS_LIMIT),
+                    }),
+                    sort_by: None,
+                })
+                .await?;
+
+            changeable.extend(
+                response
+                    .privileges
+                    .iter()
+                    .filter(|privileges| privileges.can_change)
+                    .map(|privileges| privileges.canister_id),
+            );
+            canisters.extend(
+                response
+                    .canisters
+                    .into_iter()
+                    .map(|canister| (canister.canister_id, canister)),
+            );
+
+            match response.next_offset {
+                Some(next_offset) => offset = Some(next_offset),
+                None => break,
+            }
+        }
+
+        Ok((canisters, changeable))
+    }
+}
+
+/// Registers an existing canister in the station, the caller is permitted to read and change it.
+fn register_canister_request(
+    name: &str,
+    canister_id: Principal,
+    user_id: &UuidDTO,
+) -> CreateRequestInput {
+    let allow_user = AllowDTO {
+        auth_scope: AuthScopeDTO::Restricted,
+        users: vec![user_id.clone()],
+        user_groups: vec![],
+    };
+
+    CreateRequestInput {
+        operation: RequestOperationInput::CreateExternalCanister(
+            CreateExternalCanisterOperationInput {
+                kind: CreateExternalCanisterOperationKindDTO::AddExisting(
+                    CreateExternalCanisterOperationKindAddExistingDTO { canister_id },
+                ),
+                name: name.to_string(),
+                description: None,
+                labels: None,
+                permissions: ExternalCanisterPermissionsDTO {
+                    read: allow_user.clone(),
+                    change: allow_user,
+                    calls: vec![],
+                },
+                request_policies: ExternalCanisterRequestPoliciesInput {
+                    change: vec![],
+                    calls: vec![],
+                },
+                repository_url: None,
+            },
+        ),
+        title: Some(format!("Register {name} in the station")),
+        summary: Some(RECONCILE_SUMMARY.to_string()),
+        execution_plan: None,
+        confidential: None,
+        summary_format: None,
+        depends_on: None,
+    }
+}
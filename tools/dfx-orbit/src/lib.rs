@@ -8,6 +8,7 @@ pub mod args;
 pub mod cli;
 pub mod dfx_extension_api;
 pub mod local_config;
+pub mod locale;
 pub mod station_agent;
 
 use anyhow::anyhow;
@@ -22,6 +23,7 @@ use dfx_core::{
 };
 use dfx_extension_api::OrbitExtensionAgent;
 use ic_utils::{canister::CanisterBuilder, Canister};
+use locale::{Locale, Message};
 use slog::Logger;
 use station_agent::StationConfig;
 use station_api::CreateRequestResponse;
@@ -38,6 +40,8 @@ pub struct DfxOrbit {
     pub interface: DfxInterface,
     /// A logger; some public `sdk` repository methods require a specific type of logger so this is a compatible logger.
     logger: Logger,
+    /// The locale of the reviewer-facing output.
+    locale: Locale,
 }
 
 impl DfxOrbit {
@@ -60,9 +64,21 @@ impl DfxOrbit {
             dfx: agent,
             interface,
             logger,
+            locale: Locale::default(),
         })
     }
 
+    /// Sets the locale of the reviewer-facing output.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Returns the text of the message in the locale of the output.
+    pub fn text(&self, message: Message) -> &'static str {
+        self.locale.text(message)
+    }
+
     /// Gets the ID of a given canister name.  If the name is already an ID, it is returned as is.
     pub fn canister_id(&self, canister_name: &str) -> anyhow::Result<Principal> {
         let canister_id_store = CanisterIdStore::new(
@@ -111,9 +127,12 @@ impl DfxOrbit {
     pub fn print_create_request_info(&self, response: &CreateRequestResponse) {
         let request_id = &response.request.id;
         let request_url = self.station.request_url(request_id);
-        println!("Created request: {request_id}");
-        println!("Request URL: {request_url}");
-        println!("To view the request, run: dfx-orbit review id {request_id}");
+        println!("{}: {request_id}", self.text(Message::CreatedRequest));
+        println!("{}: {request_url}", self.text(Message::RequestUrl));
+        println!(
+            "{}: dfx-orbit review id {request_id}",
+            self.text(Message::ViewRequestHint)
+        );
     }
 
     pub fn get_config(&self) -> anyhow::Result<Arc<Config>> {
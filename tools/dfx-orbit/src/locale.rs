@@ -0,0 +1,202 @@
+//! Localization of the reviewer-facing output of `dfx-orbit`.
+//!
+//! Each supported locale has its own message catalog, the catalogs match exhaustively on [`Message`]
+//! so that a new message can't be added without translating it.
+mod de;
+mod en;
+mod es;
+mod fr;
+
+use clap::ValueEnum;
+
+/// The environment variable that sets the locale if the `--locale` flag is not given.
+pub const LOCALE_ENV_VAR: &str = "DFX_ORBIT_LOCALE";
+
+/// The locales that the output of `dfx-orbit` is available in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Locale {
+    /// English
+    #[default]
+    En,
+    /// German
+    De,
+    /// French
+    Fr,
+    /// Spanish
+    Es,
+}
+
+impl Locale {
+    /// Detects the locale from `DFX_ORBIT_LOCALE`, then from the standard `LC_ALL`, `LC_MESSAGES`
+    /// and `LANG` environment variables, and falls back to English.
+    pub fn from_env() -> Self {
+        [LOCALE_ENV_VAR, "LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_tag(&value))
+            .unwrap_or_default()
+    }
+
+    /// Parses a locale tag such as `de`, `de-CH` or `de_CH.UTF-8`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(|c| c == '-' || c == '_' || c == '.')
+            .next()?
+            .to_lowercase();
+
+        match language.as_str() {
+            "en" | "c" | "posix" => Some(Locale::En),
+            "de" => Some(Locale::De),
+            "fr" => Some(Locale::Fr),
+            "es" => Some(Locale::Es),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of the message in this locale.
+    pub fn text(&self, message: Message) -> &'static str {
+        match self {
+            Locale::En => en::text(message),
+            Locale::De => de::text(message),
+            Locale::Fr => fr::text(message),
+            Locale::Es => es::text(message),
+        }
+    }
+}
+
+/// The reviewer-facing messages of `dfx-orbit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message {
+    // Request list and details
+    Id,
+    RequestedBy,
+    Title,
+    Operation,
+    ExecutionStatus,
+    RequestHeader,
+    Summary,
+    ApprovedBy,
+    ApprovalChecklist,
+    Status,
+    Reason,
+    // External canister operations
+    ChangeExternalCanisterHeader,
+    CallExternalCanisterHeader,
+    CommitAssetBatchHeader,
+    ConfigureExternalCanisterHeader,
+    SnapshotExternalCanisterHeader,
+    Target,
+    Mode,
+    ModeInstall,
+    ModeReinstall,
+    ModeUpgrade,
+    ModuleChecksum,
+    ArgumentChecksum,
+    ExecutionMethod,
+    ValidationMethod,
+    MethodOf,
+    Argument,
+    ExecutionMethodCycles,
+    ExecutionResponse,
+    FailedToParseExecutionResponse,
+    BatchId,
+    Evidence,
+    EvidenceRendering,
+    SettingsChanges,
+    SettingsDiffUnavailable,
+    Controllers,
+    ControllerAdded,
+    ControllerRemoved,
+    ComputeAllocation,
+    MemoryAllocation,
+    FreezingThreshold,
+    ReservedCyclesLimit,
+    LogVisibility,
+    SnapshotId,
+    SnapshotTaken,
+    SnapshotTake,
+    SnapshotLoad,
+    SnapshotDelete,
+    // Permission operations
+    BulkEditPermissionHeader,
+    PermissionResource,
+    PermissionBefore,
+    PermissionAfter,
+    // Request statuses
+    StatusCreated,
+    StatusApproved,
+    StatusRejected,
+    StatusCancelled,
+    StatusScheduled,
+    StatusProcessing,
+    StatusCompleted,
+    StatusFailed,
+    // Request operations
+    OperationTransfer,
+    OperationAddAccount,
+    OperationEditAccount,
+    OperationRotateAccountAddress,
+    OperationTransferAccountOwnership,
+    OperationAddAddressBookEntry,
+    OperationEditAddressBookEntry,
+    OperationRemoveAddressBookEntry,
+    OperationAddUser,
+    OperationEditUser,
+    OperationAddUserGroup,
+    OperationEditUserGroup,
+    OperationRemoveUserGroup,
+    OperationSystemUpgrade,
+    OperationSetDisasterRecovery,
+    OperationChangeExternalCanister,
+    OperationCreateExternalCanister,
+    OperationConfigureExternalCanister,
+    OperationCallExternalCanister,
+    OperationFundExternalCanister,
+    OperationCommitAssetBatch,
+    OperationSnapshotExternalCanister,
+    OperationEditPermission,
+    OperationBulkEditPermission,
+    OperationAddRequestPolicy,
+    OperationEditRequestPolicy,
+    OperationRemoveRequestPolicy,
+    OperationManageSystemInfo,
+    OperationAddNetwork,
+    OperationEditNetwork,
+    OperationFreezeBlockchain,
+    OperationUnfreezeBlockchain,
+    OperationDecommissionStation,
+    // Module hashes and asset evidence
+    ModuleHashHeader,
+    ModuleHashInstalled,
+    ModuleHashProposed,
+    ModuleHashUnchanged,
+    ModuleHashUnavailable,
+    UpgradeDiffHeader,
+    CurrentSettings,
+    SettingsUnavailable,
+    ModuleSize,
+    ArgumentEmpty,
+    Yes,
+    No,
+    AssetEvidenceHeader,
+    AssetEvidenceHint,
+    // Interactive review
+    TuiPendingRequests,
+    TuiDetails,
+    TuiNoPendingRequests,
+    TuiLoading,
+    TuiHelp,
+    TuiConfirmHint,
+    TuiApproved,
+    TuiRejected,
+    // Prompts and command results
+    ConfirmApproveRequest,
+    ConfirmRejectRequest,
+    CreatedRequest,
+    RequestUrl,
+    ViewRequestHint,
+    VerificationSuccessful,
+    VerificationFailed,
+    RequestPassesVerification,
+}
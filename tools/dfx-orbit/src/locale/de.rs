@@ -0,0 +1,129 @@
+use super::Message;
+
+pub(super) fn text(message: Message) -> &'static str {
+    match message {
+        Message::Id => "ID",
+        Message::RequestedBy => "Beantragt von",
+        Message::Title => "Titel",
+        Message::Operation => "Vorgang",
+        Message::ExecutionStatus => "Ausführungsstatus",
+        Message::RequestHeader => "=== ANTRAG ===",
+        Message::Summary => "Zusammenfassung",
+        Message::ApprovedBy => "Genehmigt von",
+        Message::ApprovalChecklist => "Prüfliste für die Genehmigung",
+        Message::Status => "Status",
+        Message::Reason => "Grund",
+        Message::ChangeExternalCanisterHeader => "=== Externen Canister ändern ===",
+        Message::CallExternalCanisterHeader => "=== Externen Canister aufrufen ===",
+        Message::CommitAssetBatchHeader => "=== Asset-Batch übernehmen ===",
+        Message::ConfigureExternalCanisterHeader => "=== Externen Canister konfigurieren ===",
+        Message::SnapshotExternalCanisterHeader => "=== Snapshot eines externen Canisters ===",
+        Message::Target => "Ziel",
+        Message::Mode => "Modus",
+        Message::ModeInstall => "Installieren",
+        Message::ModeReinstall => "Neu installieren",
+        Message::ModeUpgrade => "Aktualisieren",
+        Message::ModuleChecksum => "Modul-Prüfsumme",
+        Message::ArgumentChecksum => "Argument-Prüfsumme",
+        Message::ExecutionMethod => "Ausführungsmethode",
+        Message::ValidationMethod => "Validierungsmethode",
+        Message::MethodOf => "von",
+        Message::Argument => "Argument",
+        Message::ExecutionMethodCycles => "Cycles der Ausführungsmethode",
+        Message::ExecutionResponse => "Ausführungsantwort",
+        Message::FailedToParseExecutionResponse => "AUSFÜHRUNGSANTWORT KONNTE NICHT GELESEN WERDEN",
+        Message::BatchId => "Batch-ID",
+        Message::Evidence => "Nachweis",
+        Message::EvidenceRendering => "Darstellung durch den Asset-Canister",
+        Message::SettingsChanges => "Änderungen der Einstellungen",
+        Message::SettingsDiffUnavailable => "Die aktuellen Einstellungen konnten nicht gelesen werden, nur die vorgeschlagenen Einstellungen werden angezeigt",
+        Message::Controllers => "Controller",
+        Message::ControllerAdded => "Hinzugefügter Controller",
+        Message::ControllerRemoved => "Entfernter Controller",
+        Message::ComputeAllocation => "Rechenzuteilung",
+        Message::MemoryAllocation => "Speicherzuteilung",
+        Message::FreezingThreshold => "Einfrierschwelle",
+        Message::ReservedCyclesLimit => "Limit der reservierten Cycles",
+        Message::LogVisibility => "Sichtbarkeit der Logs",
+        Message::SnapshotId => "Snapshot-ID",
+        Message::SnapshotTaken => "Erstellter Snapshot",
+        Message::SnapshotTake => "Snapshot erstellen",
+        Message::SnapshotLoad => "Snapshot laden",
+        Message::SnapshotDelete => "Snapshot löschen",
+        Message::BulkEditPermissionHeader => "=== Berechtigungen gesammelt bearbeiten ===",
+        Message::PermissionResource => "Ressource",
+        Message::PermissionBefore => "Vorher",
+        Message::PermissionAfter => "Nachher",
+        Message::StatusCreated => "Erstellt",
+        Message::StatusApproved => "Genehmigt",
+        Message::StatusRejected => "Abgelehnt",
+        Message::StatusCancelled => "Storniert",
+        Message::StatusScheduled => "Geplant",
+        Message::StatusProcessing => "In Bearbeitung",
+        Message::StatusCompleted => "Abgeschlossen",
+        Message::StatusFailed => "Fehlgeschlagen",
+        Message::OperationTransfer => "Überweisung",
+        Message::OperationAddAccount => "Konto hinzufügen",
+        Message::OperationEditAccount => "Konto bearbeiten",
+        Message::OperationRotateAccountAddress => "Kontoadresse rotieren",
+        Message::OperationTransferAccountOwnership => "Kontoinhaberschaft übertragen",
+        Message::OperationAddAddressBookEntry => "Adressbucheintrag hinzufügen",
+        Message::OperationEditAddressBookEntry => "Adressbucheintrag bearbeiten",
+        Message::OperationRemoveAddressBookEntry => "Adressbucheintrag entfernen",
+        Message::OperationAddUser => "Benutzer hinzufügen",
+        Message::OperationEditUser => "Benutzer bearbeiten",
+        Message::OperationAddUserGroup => "Benutzergruppe hinzufügen",
+        Message::OperationEditUserGroup => "Benutzergruppe bearbeiten",
+        Message::OperationRemoveUserGroup => "Benutzergruppe entfernen",
+        Message::OperationSystemUpgrade => "Systemaktualisierung",
+        Message::OperationSetDisasterRecovery => "Notfallwiederherstellung festlegen",
+        Message::OperationChangeExternalCanister => "Externen Canister ändern",
+        Message::OperationCreateExternalCanister => "Externen Canister erstellen",
+        Message::OperationConfigureExternalCanister => "Externen Canister konfigurieren",
+        Message::OperationCallExternalCanister => "Externen Canister aufrufen",
+        Message::OperationFundExternalCanister => "Externen Canister aufladen",
+        Message::OperationCommitAssetBatch => "Asset-Batch übernehmen",
+        Message::OperationSnapshotExternalCanister => "Snapshot eines externen Canisters",
+        Message::OperationEditPermission => "Berechtigung bearbeiten",
+        Message::OperationBulkEditPermission => "Berechtigungen gesammelt bearbeiten",
+        Message::OperationAddRequestPolicy => "Antragsrichtlinie hinzufügen",
+        Message::OperationEditRequestPolicy => "Antragsrichtlinie bearbeiten",
+        Message::OperationRemoveRequestPolicy => "Antragsrichtlinie entfernen",
+        Message::OperationManageSystemInfo => "Systeminformationen verwalten",
+        Message::OperationAddNetwork => "Netzwerk hinzufügen",
+        Message::OperationEditNetwork => "Netzwerk bearbeiten",
+        Message::OperationFreezeBlockchain => "Blockchain einfrieren",
+        Message::OperationUnfreezeBlockchain => "Blockchain auftauen",
+        Message::OperationDecommissionStation => "Station stilllegen",
+        Message::ModuleHashHeader => "=== Modul-Hash ===",
+        Message::ModuleHashInstalled => "installiert",
+        Message::ModuleHashProposed => "vorgeschlagen",
+        Message::ModuleHashUnchanged => "unverändert",
+        Message::ModuleHashUnavailable => "Der Hash des installierten Moduls ist nicht verfügbar",
+        Message::UpgradeDiffHeader => "=== Canister bei Erstellung der Anfrage ===",
+        Message::CurrentSettings => "Aktuelle Einstellungen, von der Änderung beibehalten",
+        Message::SettingsUnavailable => "Die Einstellungen sind nicht verfügbar",
+        Message::ModuleSize => "Modulgröße (Bytes)",
+        Message::ArgumentEmpty => "Leeres Argument",
+        Message::Yes => "ja",
+        Message::No => "nein",
+        Message::AssetEvidenceHeader => "=== Asset-Nachweis ===",
+        Message::AssetEvidenceHint => "Prüfen Sie den Nachweis gegen die lokalen Dateien mit",
+        Message::TuiPendingRequests => "Offene Anträge",
+        Message::TuiDetails => "Details",
+        Message::TuiNoPendingRequests => "Keine offenen Anträge",
+        Message::TuiLoading => "Wird geladen...",
+        Message::TuiHelp => "↑/↓ auswählen  Bild↑/Bild↓ blättern  a genehmigen  x ablehnen  r aktualisieren  q beenden",
+        Message::TuiConfirmHint => "(y/n)",
+        Message::TuiApproved => "Antrag genehmigt",
+        Message::TuiRejected => "Antrag abgelehnt",
+        Message::ConfirmApproveRequest => "Möchten Sie diesen Antrag genehmigen?",
+        Message::ConfirmRejectRequest => "Möchten Sie diesen Antrag ablehnen?",
+        Message::CreatedRequest => "Antrag erstellt",
+        Message::RequestUrl => "Antrags-URL",
+        Message::ViewRequestHint => "Um den Antrag anzuzeigen, führen Sie aus",
+        Message::VerificationSuccessful => "Überprüfung erfolgreich!",
+        Message::VerificationFailed => "Überprüfung fehlgeschlagen",
+        Message::RequestPassesVerification => "Der Antrag hat die Überprüfung bestanden",
+    }
+}
@@ -0,0 +1,131 @@
+use super::Message;
+
+pub(super) fn text(message: Message) -> &'static str {
+    match message {
+        Message::Id => "ID",
+        Message::RequestedBy => "Requested by",
+        Message::Title => "Title",
+        Message::Operation => "Operation",
+        Message::ExecutionStatus => "Execution Status",
+        Message::RequestHeader => "=== REQUEST ===",
+        Message::Summary => "Summary",
+        Message::ApprovedBy => "Approved by",
+        Message::ApprovalChecklist => "Approval checklist",
+        Message::Status => "Status",
+        Message::Reason => "Reason",
+        Message::ChangeExternalCanisterHeader => "=== Change External Canister ===",
+        Message::CallExternalCanisterHeader => "=== Call External Canister ===",
+        Message::CommitAssetBatchHeader => "=== Commit Asset Batch ===",
+        Message::ConfigureExternalCanisterHeader => "=== Configure External Canister ===",
+        Message::SnapshotExternalCanisterHeader => "=== Snapshot External Canister ===",
+        Message::Target => "Target",
+        Message::Mode => "Mode",
+        Message::ModeInstall => "Install",
+        Message::ModeReinstall => "Reinstall",
+        Message::ModeUpgrade => "Upgrade",
+        Message::ModuleChecksum => "Module checksum",
+        Message::ArgumentChecksum => "Argument checksum",
+        Message::ExecutionMethod => "Execution method",
+        Message::ValidationMethod => "Validation method",
+        Message::MethodOf => "of",
+        Message::Argument => "Argument",
+        Message::ExecutionMethodCycles => "Execution method cycles",
+        Message::ExecutionResponse => "Execution response",
+        Message::FailedToParseExecutionResponse => "FAILED TO PARSE EXECUTION RESPONSE",
+        Message::BatchId => "Batch id",
+        Message::Evidence => "Evidence",
+        Message::EvidenceRendering => "Rendering by the asset canister",
+        Message::SettingsChanges => "Settings changes",
+        Message::SettingsDiffUnavailable => {
+            "The current settings could not be read, only the proposed settings are shown"
+        }
+        Message::Controllers => "Controllers",
+        Message::ControllerAdded => "Added controller",
+        Message::ControllerRemoved => "Removed controller",
+        Message::ComputeAllocation => "Compute allocation",
+        Message::MemoryAllocation => "Memory allocation",
+        Message::FreezingThreshold => "Freezing threshold",
+        Message::ReservedCyclesLimit => "Reserved cycles limit",
+        Message::LogVisibility => "Log visibility",
+        Message::SnapshotId => "Snapshot id",
+        Message::SnapshotTaken => "Taken snapshot",
+        Message::SnapshotTake => "Take snapshot",
+        Message::SnapshotLoad => "Load snapshot",
+        Message::SnapshotDelete => "Delete snapshot",
+        Message::BulkEditPermissionHeader => "=== Bulk Edit Permissions ===",
+        Message::PermissionResource => "Resource",
+        Message::PermissionBefore => "Before",
+        Message::PermissionAfter => "After",
+        Message::StatusCreated => "Created",
+        Message::StatusApproved => "Approved",
+        Message::StatusRejected => "Rejected",
+        Message::StatusCancelled => "Cancelled",
+        Message::StatusScheduled => "Scheduled",
+        Message::StatusProcessing => "Processing",
+        Message::StatusCompleted => "Completed",
+        Message::StatusFailed => "Failed",
+        Message::OperationTransfer => "Transfer",
+        Message::OperationAddAccount => "AddAccount",
+        Message::OperationEditAccount => "EditAccount",
+        Message::OperationRotateAccountAddress => "RotateAccountAddress",
+        Message::OperationTransferAccountOwnership => "TransferAccountOwnership",
+        Message::OperationAddAddressBookEntry => "AddAddressBookEntry",
+        Message::OperationEditAddressBookEntry => "EditAddressBookEntry",
+        Message::OperationRemoveAddressBookEntry => "RemoveAddressBookEntry",
+        Message::OperationAddUser => "AddUser",
+        Message::OperationEditUser => "EditUser",
+        Message::OperationAddUserGroup => "AddUserGroup",
+        Message::OperationEditUserGroup => "EditUserGroup",
+        Message::OperationRemoveUserGroup => "RemoveUserGroup",
+        Message::OperationSystemUpgrade => "SystemUpgrade",
+        Message::OperationSetDisasterRecovery => "SetDisasterRecovery",
+        Message::OperationChangeExternalCanister => "ChangeExternalCanister",
+        Message::OperationCreateExternalCanister => "CreateExternalCanister",
+        Message::OperationConfigureExternalCanister => "ConfigureExternalCanister",
+        Message::OperationCallExternalCanister => "CallExternalCanister",
+        Message::OperationFundExternalCanister => "FundExternalCanister",
+        Message::OperationCommitAssetBatch => "CommitAssetBatch",
+        Message::OperationSnapshotExternalCanister => "SnapshotExternalCanister",
+        Message::OperationEditPermission => "EditPermission",
+        Message::OperationBulkEditPermission => "BulkEditPermission",
+        Message::OperationAddRequestPolicy => "AddRequestPolicy",
+        Message::OperationEditRequestPolicy => "EditRequestPolicy",
+        Message::OperationRemoveRequestPolicy => "RemoveRequestPolicy",
+        Message::OperationManageSystemInfo => "ManageSystemInfo",
+        Message::OperationAddNetwork => "AddNetwork",
+        Message::OperationEditNetwork => "EditNetwork",
+        Message::OperationFreezeBlockchain => "FreezeBlockchain",
+        Message::OperationUnfreezeBlockchain => "UnfreezeBlockchain",
+        Message::OperationDecommissionStation => "DecommissionStation",
+        Message::ModuleHashHeader => "=== Module hash ===",
+        Message::ModuleHashInstalled => "installed",
+        Message::ModuleHashProposed => "proposed",
+        Message::ModuleHashUnchanged => "unchanged",
+        Message::ModuleHashUnavailable => "The installed module hash is unavailable",
+        Message::UpgradeDiffHeader => "=== Canister when the request was created ===",
+        Message::CurrentSettings => "Current settings, kept by the change",
+        Message::SettingsUnavailable => "The settings are unavailable",
+        Message::ModuleSize => "Module size (bytes)",
+        Message::ArgumentEmpty => "Empty argument",
+        Message::Yes => "yes",
+        Message::No => "no",
+        Message::AssetEvidenceHeader => "=== Asset evidence ===",
+        Message::AssetEvidenceHint => "Verify the evidence against the local files with",
+        Message::TuiPendingRequests => "Pending requests",
+        Message::TuiDetails => "Details",
+        Message::TuiNoPendingRequests => "No pending requests",
+        Message::TuiLoading => "Loading...",
+        Message::TuiHelp => "↑/↓ select  PgUp/PgDn scroll  a approve  x reject  r refresh  q quit",
+        Message::TuiConfirmHint => "(y/n)",
+        Message::TuiApproved => "Approved request",
+        Message::TuiRejected => "Rejected request",
+        Message::ConfirmApproveRequest => "Would you like to approve this request?",
+        Message::ConfirmRejectRequest => "Would you like to reject this request?",
+        Message::CreatedRequest => "Created request",
+        Message::RequestUrl => "Request URL",
+        Message::ViewRequestHint => "To view the request, run",
+        Message::VerificationSuccessful => "Verification successful!",
+        Message::VerificationFailed => "Verification failed",
+        Message::RequestPassesVerification => "Request passes verification",
+    }
+}
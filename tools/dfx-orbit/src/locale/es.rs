@@ -0,0 +1,135 @@
+use super::Message;
+
+pub(super) fn text(message: Message) -> &'static str {
+    match message {
+        Message::Id => "ID",
+        Message::RequestedBy => "Solicitado por",
+        Message::Title => "Título",
+        Message::Operation => "Operación",
+        Message::ExecutionStatus => "Estado de ejecución",
+        Message::RequestHeader => "=== SOLICITUD ===",
+        Message::Summary => "Resumen",
+        Message::ApprovedBy => "Aprobado por",
+        Message::ApprovalChecklist => "Lista de verificación de la aprobación",
+        Message::Status => "Estado",
+        Message::Reason => "Motivo",
+        Message::ChangeExternalCanisterHeader => "=== Modificar canister externo ===",
+        Message::CallExternalCanisterHeader => "=== Llamar a canister externo ===",
+        Message::CommitAssetBatchHeader => "=== Confirmar lote de assets ===",
+        Message::ConfigureExternalCanisterHeader => "=== Configurar canister externo ===",
+        Message::SnapshotExternalCanisterHeader => "=== Snapshot de canister externo ===",
+        Message::Target => "Destino",
+        Message::Mode => "Modo",
+        Message::ModeInstall => "Instalar",
+        Message::ModeReinstall => "Reinstalar",
+        Message::ModeUpgrade => "Actualizar",
+        Message::ModuleChecksum => "Suma de comprobación del módulo",
+        Message::ArgumentChecksum => "Suma de comprobación del argumento",
+        Message::ExecutionMethod => "Método de ejecución",
+        Message::ValidationMethod => "Método de validación",
+        Message::MethodOf => "de",
+        Message::Argument => "Argumento",
+        Message::ExecutionMethodCycles => "Cycles del método de ejecución",
+        Message::ExecutionResponse => "Respuesta de ejecución",
+        Message::FailedToParseExecutionResponse => {
+            "NO SE PUDO INTERPRETAR LA RESPUESTA DE EJECUCIÓN"
+        }
+        Message::BatchId => "ID del lote",
+        Message::Evidence => "Evidencia",
+        Message::EvidenceRendering => "Representación del canister de assets",
+        Message::SettingsChanges => "Cambios de configuración",
+        Message::SettingsDiffUnavailable => {
+            "No se pudo leer la configuración actual, solo se muestra la configuración propuesta"
+        }
+        Message::Controllers => "Controladores",
+        Message::ControllerAdded => "Controlador añadido",
+        Message::ControllerRemoved => "Controlador eliminado",
+        Message::ComputeAllocation => "Asignación de cómputo",
+        Message::MemoryAllocation => "Asignación de memoria",
+        Message::FreezingThreshold => "Umbral de congelación",
+        Message::ReservedCyclesLimit => "Límite de ciclos reservados",
+        Message::LogVisibility => "Visibilidad de los logs",
+        Message::SnapshotId => "ID del snapshot",
+        Message::SnapshotTaken => "Snapshot creado",
+        Message::SnapshotTake => "Crear snapshot",
+        Message::SnapshotLoad => "Cargar snapshot",
+        Message::SnapshotDelete => "Eliminar snapshot",
+        Message::BulkEditPermissionHeader => "=== Editar permisos en bloque ===",
+        Message::PermissionResource => "Recurso",
+        Message::PermissionBefore => "Antes",
+        Message::PermissionAfter => "Después",
+        Message::StatusCreated => "Creada",
+        Message::StatusApproved => "Aprobada",
+        Message::StatusRejected => "Rechazada",
+        Message::StatusCancelled => "Cancelada",
+        Message::StatusScheduled => "Programada",
+        Message::StatusProcessing => "En proceso",
+        Message::StatusCompleted => "Completada",
+        Message::StatusFailed => "Fallida",
+        Message::OperationTransfer => "Transferencia",
+        Message::OperationAddAccount => "Añadir cuenta",
+        Message::OperationEditAccount => "Editar cuenta",
+        Message::OperationRotateAccountAddress => "Rotar la dirección de la cuenta",
+        Message::OperationTransferAccountOwnership => "Transferir la titularidad de la cuenta",
+        Message::OperationAddAddressBookEntry => "Añadir entrada a la libreta de direcciones",
+        Message::OperationEditAddressBookEntry => "Editar entrada de la libreta de direcciones",
+        Message::OperationRemoveAddressBookEntry => "Eliminar entrada de la libreta de direcciones",
+        Message::OperationAddUser => "Añadir usuario",
+        Message::OperationEditUser => "Editar usuario",
+        Message::OperationAddUserGroup => "Añadir grupo de usuarios",
+        Message::OperationEditUserGroup => "Editar grupo de usuarios",
+        Message::OperationRemoveUserGroup => "Eliminar grupo de usuarios",
+        Message::OperationSystemUpgrade => "Actualización del sistema",
+        Message::OperationSetDisasterRecovery => "Configurar recuperación ante desastres",
+        Message::OperationChangeExternalCanister => "Modificar canister externo",
+        Message::OperationCreateExternalCanister => "Crear canister externo",
+        Message::OperationConfigureExternalCanister => "Configurar canister externo",
+        Message::OperationCallExternalCanister => "Llamar a canister externo",
+        Message::OperationFundExternalCanister => "Recargar canister externo",
+        Message::OperationCommitAssetBatch => "Confirmar lote de assets",
+        Message::OperationSnapshotExternalCanister => "Snapshot de canister externo",
+        Message::OperationEditPermission => "Editar permiso",
+        Message::OperationBulkEditPermission => "Editar permisos en bloque",
+        Message::OperationAddRequestPolicy => "Añadir política de solicitudes",
+        Message::OperationEditRequestPolicy => "Editar política de solicitudes",
+        Message::OperationRemoveRequestPolicy => "Eliminar política de solicitudes",
+        Message::OperationManageSystemInfo => "Gestionar información del sistema",
+        Message::OperationAddNetwork => "Añadir red",
+        Message::OperationEditNetwork => "Editar red",
+        Message::OperationFreezeBlockchain => "Congelar blockchain",
+        Message::OperationUnfreezeBlockchain => "Descongelar blockchain",
+        Message::OperationDecommissionStation => "Desmantelar estación",
+        Message::ModuleHashHeader => "=== Hash del módulo ===",
+        Message::ModuleHashInstalled => "instalado",
+        Message::ModuleHashProposed => "propuesto",
+        Message::ModuleHashUnchanged => "sin cambios",
+        Message::ModuleHashUnavailable => "El hash del módulo instalado no está disponible",
+        Message::UpgradeDiffHeader => "=== Canister al crear la solicitud ===",
+        Message::CurrentSettings => "Configuración actual, conservada por el cambio",
+        Message::SettingsUnavailable => "La configuración no está disponible",
+        Message::ModuleSize => "Tamaño del módulo (bytes)",
+        Message::ArgumentEmpty => "Argumento vacío",
+        Message::Yes => "sí",
+        Message::No => "no",
+        Message::AssetEvidenceHeader => "=== Evidencia de los assets ===",
+        Message::AssetEvidenceHint => "Verifique la evidencia con los archivos locales con",
+        Message::TuiPendingRequests => "Solicitudes pendientes",
+        Message::TuiDetails => "Detalles",
+        Message::TuiNoPendingRequests => "No hay solicitudes pendientes",
+        Message::TuiLoading => "Cargando...",
+        Message::TuiHelp => {
+            "↑/↓ seleccionar  RePág/AvPág desplazar  a aprobar  x rechazar  r actualizar  q salir"
+        }
+        Message::TuiConfirmHint => "(y/n)",
+        Message::TuiApproved => "Solicitud aprobada",
+        Message::TuiRejected => "Solicitud rechazada",
+        Message::ConfirmApproveRequest => "¿Desea aprobar esta solicitud?",
+        Message::ConfirmRejectRequest => "¿Desea rechazar esta solicitud?",
+        Message::CreatedRequest => "Solicitud creada",
+        Message::RequestUrl => "URL de la solicitud",
+        Message::ViewRequestHint => "Para ver la solicitud, ejecute",
+        Message::VerificationSuccessful => "¡Verificación correcta!",
+        Message::VerificationFailed => "La verificación falló",
+        Message::RequestPassesVerification => "La solicitud superó la verificación",
+    }
+}
@@ -0,0 +1,131 @@
+use super::Message;
+
+pub(super) fn text(message: Message) -> &'static str {
+    match message {
+        Message::Id => "ID",
+        Message::RequestedBy => "Demandé par",
+        Message::Title => "Titre",
+        Message::Operation => "Opération",
+        Message::ExecutionStatus => "Statut d'exécution",
+        Message::RequestHeader => "=== DEMANDE ===",
+        Message::Summary => "Résumé",
+        Message::ApprovedBy => "Approuvé par",
+        Message::ApprovalChecklist => "Liste de vérification de l'approbation",
+        Message::Status => "Statut",
+        Message::Reason => "Motif",
+        Message::ChangeExternalCanisterHeader => "=== Modifier un canister externe ===",
+        Message::CallExternalCanisterHeader => "=== Appeler un canister externe ===",
+        Message::CommitAssetBatchHeader => "=== Valider un lot d'assets ===",
+        Message::ConfigureExternalCanisterHeader => "=== Configurer un canister externe ===",
+        Message::SnapshotExternalCanisterHeader => "=== Snapshot d'un canister externe ===",
+        Message::Target => "Cible",
+        Message::Mode => "Mode",
+        Message::ModeInstall => "Installation",
+        Message::ModeReinstall => "Réinstallation",
+        Message::ModeUpgrade => "Mise à niveau",
+        Message::ModuleChecksum => "Somme de contrôle du module",
+        Message::ArgumentChecksum => "Somme de contrôle de l'argument",
+        Message::ExecutionMethod => "Méthode d'exécution",
+        Message::ValidationMethod => "Méthode de validation",
+        Message::MethodOf => "de",
+        Message::Argument => "Argument",
+        Message::ExecutionMethodCycles => "Cycles de la méthode d'exécution",
+        Message::ExecutionResponse => "Réponse d'exécution",
+        Message::FailedToParseExecutionResponse => "IMPOSSIBLE D'ANALYSER LA RÉPONSE D'EXÉCUTION",
+        Message::BatchId => "ID du lot",
+        Message::Evidence => "Preuve",
+        Message::EvidenceRendering => "Rendu par le canister d'assets",
+        Message::SettingsChanges => "Modifications des paramètres",
+        Message::SettingsDiffUnavailable => "Les paramètres actuels n'ont pas pu être lus, seuls les paramètres proposés sont affichés",
+        Message::Controllers => "Contrôleurs",
+        Message::ControllerAdded => "Contrôleur ajouté",
+        Message::ControllerRemoved => "Contrôleur retiré",
+        Message::ComputeAllocation => "Allocation de calcul",
+        Message::MemoryAllocation => "Allocation de mémoire",
+        Message::FreezingThreshold => "Seuil de gel",
+        Message::ReservedCyclesLimit => "Limite des cycles réservés",
+        Message::LogVisibility => "Visibilité des logs",
+        Message::SnapshotId => "ID du snapshot",
+        Message::SnapshotTaken => "Snapshot créé",
+        Message::SnapshotTake => "Créer un snapshot",
+        Message::SnapshotLoad => "Charger un snapshot",
+        Message::SnapshotDelete => "Supprimer un snapshot",
+        Message::BulkEditPermissionHeader => "=== Modifier des permissions en bloc ===",
+        Message::PermissionResource => "Ressource",
+        Message::PermissionBefore => "Avant",
+        Message::PermissionAfter => "Après",
+        Message::StatusCreated => "Créée",
+        Message::StatusApproved => "Approuvée",
+        Message::StatusRejected => "Rejetée",
+        Message::StatusCancelled => "Annulée",
+        Message::StatusScheduled => "Planifiée",
+        Message::StatusProcessing => "En cours",
+        Message::StatusCompleted => "Terminée",
+        Message::StatusFailed => "Échouée",
+        Message::OperationTransfer => "Virement",
+        Message::OperationAddAccount => "Ajouter un compte",
+        Message::OperationEditAccount => "Modifier un compte",
+        Message::OperationRotateAccountAddress => "Renouveler l'adresse d'un compte",
+        Message::OperationTransferAccountOwnership => "Transférer la propriété d'un compte",
+        Message::OperationAddAddressBookEntry => "Ajouter une entrée au carnet d'adresses",
+        Message::OperationEditAddressBookEntry => "Modifier une entrée du carnet d'adresses",
+        Message::OperationRemoveAddressBookEntry => "Supprimer une entrée du carnet d'adresses",
+        Message::OperationAddUser => "Ajouter un utilisateur",
+        Message::OperationEditUser => "Modifier un utilisateur",
+        Message::OperationAddUserGroup => "Ajouter un groupe d'utilisateurs",
+        Message::OperationEditUserGroup => "Modifier un groupe d'utilisateurs",
+        Message::OperationRemoveUserGroup => "Supprimer un groupe d'utilisateurs",
+        Message::OperationSystemUpgrade => "Mise à niveau du système",
+        Message::OperationSetDisasterRecovery => "Configurer la reprise après sinistre",
+        Message::OperationChangeExternalCanister => "Modifier un canister externe",
+        Message::OperationCreateExternalCanister => "Créer un canister externe",
+        Message::OperationConfigureExternalCanister => "Configurer un canister externe",
+        Message::OperationCallExternalCanister => "Appeler un canister externe",
+        Message::OperationFundExternalCanister => "Alimenter un canister externe",
+        Message::OperationCommitAssetBatch => "Valider un lot d'assets",
+        Message::OperationSnapshotExternalCanister => "Snapshot d'un canister externe",
+        Message::OperationEditPermission => "Modifier une permission",
+        Message::OperationBulkEditPermission => "Modifier des permissions en bloc",
+        Message::OperationAddRequestPolicy => "Ajouter une règle de demande",
+        Message::OperationEditRequestPolicy => "Modifier une règle de demande",
+        Message::OperationRemoveRequestPolicy => "Supprimer une règle de demande",
+        Message::OperationManageSystemInfo => "Gérer les informations système",
+        Message::OperationAddNetwork => "Ajouter un réseau",
+        Message::OperationEditNetwork => "Modifier un réseau",
+        Message::OperationFreezeBlockchain => "Geler une blockchain",
+        Message::OperationUnfreezeBlockchain => "Dégeler une blockchain",
+        Message::OperationDecommissionStation => "Démanteler la station",
+        Message::ModuleHashHeader => "=== Hachage du module ===",
+        Message::ModuleHashInstalled => "installé",
+        Message::ModuleHashProposed => "proposé",
+        Message::ModuleHashUnchanged => "inchangé",
+        Message::ModuleHashUnavailable => "Le hachage du module installé n'est pas disponible",
+        Message::UpgradeDiffHeader => "=== Canister à la création de la demande ===",
+        Message::CurrentSettings => "Paramètres actuels, conservés par le changement",
+        Message::SettingsUnavailable => "Les paramètres ne sont pas disponibles",
+        Message::ModuleSize => "Taille du module (octets)",
+        Message::ArgumentEmpty => "Argument vide",
+        Message::Yes => "oui",
+        Message::No => "non",
+        Message::AssetEvidenceHeader => "=== Preuve des assets ===",
+        Message::AssetEvidenceHint => "Vérifiez la preuve avec les fichiers locaux avec",
+        Message::TuiPendingRequests => "Demandes en attente",
+        Message::TuiDetails => "Détails",
+        Message::TuiNoPendingRequests => "Aucune demande en attente",
+        Message::TuiLoading => "Chargement...",
+        Message::TuiHelp => {
+            "↑/↓ sélectionner  PgUp/PgDn défiler  a approuver  x rejeter  r actualiser  q quitter"
+        }
+        Message::TuiConfirmHint => "(y/n)",
+        Message::TuiApproved => "Demande approuvée",
+        Message::TuiRejected => "Demande rejetée",
+        Message::ConfirmApproveRequest => "Voulez-vous approuver cette demande ?",
+        Message::ConfirmRejectRequest => "Voulez-vous rejeter cette demande ?",
+        Message::CreatedRequest => "Demande créée",
+        Message::RequestUrl => "URL de la demande",
+        Message::ViewRequestHint => "Pour afficher la demande, exécutez",
+        Message::VerificationSuccessful => "Vérification réussie !",
+        Message::VerificationFailed => "Échec de la vérification",
+        Message::RequestPassesVerification => "La demande a passé la vérification",
+    }
+}
@@ -4,8 +4,10 @@ pub use crate::station_agent::{config::StationConfig, error::StationAgentResult}
 use candid::CandidType;
 use ic_agent::{agent::UpdateBuilder, Agent};
 use station_api::{
-    ApiErrorDTO, CreateRequestInput, CreateRequestResponse, GetNextApprovableRequestInput,
-    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse, ListRequestsInput,
+    ApiErrorDTO, CreateRequestInput, CreateRequestResponse, FetchExternalCanisterLogsInput,
+    FetchExternalCanisterLogsResponse, GetNextApprovableRequestInput,
+    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse,
+    ListExternalCanistersInput, ListExternalCanistersResponse, ListRequestsInput,
     ListRequestsResponse, MeResponse, RequestApprovalStatusDTO, SubmitRequestApprovalInput,
     SubmitRequestApprovalResponse,
 };
@@ -49,6 +51,7 @@ impl StationAgent {
             decision: RequestApprovalStatusDTO::Approved,
             request_id,
             reason,
+            acknowledged_checklist: None,
         })
         .await?;
         Ok(())
@@ -63,6 +66,7 @@ impl StationAgent {
             decision: RequestApprovalStatusDTO::Rejected,
             request_id,
             reason,
+            acknowledged_checklist: None,
         })
         .await?;
         Ok(())
@@ -91,6 +95,22 @@ impl StationAgent {
             .await
     }
 
+    pub async fn list_external_canisters(
+        &self,
+        args: ListExternalCanistersInput,
+    ) -> StationAgentResult<ListExternalCanistersResponse> {
+        self.update_orbit_typed("list_external_canisters", args)
+            .await
+    }
+
+    pub async fn fetch_external_canister_logs(
+        &self,
+        args: FetchExternalCanisterLogsInput,
+    ) -> StationAgentResult<FetchExternalCanisterLogsResponse> {
+        self.update_orbit_typed("fetch_external_canister_logs", args)
+            .await
+    }
+
     async fn update_orbit(&self, method_name: &str) -> UpdateBuilder {
         self.agent.update(&self.config.station_id, method_name)
     }